@@ -47,12 +47,34 @@ pub(crate) enum Command {
         #[clap(long, action = ArgAction::SetTrue)]
         use_asset_fees: Option<bool>,
 
+        /// The maximum fees to pay, in satoshi (or in the asset's base units if `use_asset_fees`
+        /// is set). The payment fails if the fees exceed it.
+        #[arg(long)]
+        max_fee_sat: Option<u64>,
+
+        /// A token allowing the payment to exceed the configured spend limits
+        #[arg(long)]
+        spend_limit_override: Option<String>,
+
+        /// A key identifying the payment. Retrying with the same key returns the payment already
+        /// sent instead of sending it again
+        #[arg(long)]
+        idempotency_key: Option<String>,
+
         /// The amount to pay, in case of a Liquid payment. The amount is optional if it is already
         /// provided in the BIP21 URI.
         /// The asset id must also be provided.
         #[arg(long)]
         amount: Option<f64>,
 
+        /// The amount to pay, denominated in the fiat currency set by `currency`
+        #[arg(long)]
+        fiat_amount: Option<f64>,
+
+        /// The fiat currency code of `fiat_amount`, e.g. "USD"
+        #[arg(long)]
+        currency: Option<String>,
+
         /// Whether or not this is a drain operation. If true, all available funds will be used.
         #[clap(short, long, action = ArgAction::SetTrue)]
         drain: Option<bool>,
@@ -60,11 +82,88 @@ pub(crate) enum Command {
         /// Delay for the send, in seconds
         #[arg(long)]
         delay: Option<u64>,
+
+        /// Add the payment to the outgoing payment queue instead of sending it right away
+        #[clap(long, action = ArgAction::SetTrue)]
+        queue: Option<bool>,
+
+        /// Schedule the payment to be sent at this UNIX timestamp, in seconds, instead of
+        /// sending it right away
+        #[arg(long)]
+        execute_at: Option<u32>,
+    },
+    /// Replay the events emitted so far
+    ReplayEvents {
+        /// Only replay the events after this sequence number
+        #[arg(long)]
+        since_seq: Option<u64>,
+
+        /// The maximum number of events to replay
+        #[arg(short, long)]
+        limit: Option<u32>,
+    },
+    /// List the payments in the outgoing payment queue
+    ListQueuedPayments,
+    /// Remove a payment from the outgoing payment queue
+    CancelQueuedPayment {
+        /// The id of the queued payment
+        id: String,
+    },
+    /// List the scheduled payments
+    ListScheduledPayments,
+    /// Cancel a scheduled payment which was not executed yet
+    CancelScheduledPayment {
+        /// The id of the scheduled payment
+        id: String,
+    },
+    /// Create a payment repeated at a fixed interval
+    CreateRecurringPayment {
+        /// A Lightning address or BOLT12 offer
+        destination: String,
+
+        /// The amount received on each occurrence, in satoshi
+        amount_sat: u64,
+
+        /// The interval between the occurrences, in seconds
+        interval_sec: u32,
+
+        /// The UNIX timestamp of the first occurrence, in seconds. Defaults to now.
+        #[arg(long)]
+        start_at: Option<u32>,
+
+        /// The maximum fees of each occurrence, in satoshi
+        #[arg(long)]
+        max_fee_sat: Option<u64>,
+    },
+    /// List the recurring payments
+    ListRecurringPayments,
+    /// List the occurrences of a recurring payment
+    ListRecurringPaymentOccurrences {
+        /// The id of the recurring payment
+        id: String,
+    },
+    /// Cancel a recurring payment
+    CancelRecurringPayment {
+        /// The id of the recurring payment
+        id: String,
     },
     /// Fetch the current limits for Send and Receive payments
     FetchLightningLimits,
     /// Fetch the current limits for Onchain Send and Receive payments
     FetchOnchainLimits,
+    /// Get the maximum amount that can currently be sent
+    GetMaxPayable {
+        /// The method to send with. Either "lightning", "bitcoin" or "liquid"
+        payment_method: PaymentMethod,
+    },
+    /// Get the maximum amount that can currently be received
+    GetMaxReceivable {
+        /// The method to receive with. Either "lightning", "bitcoin" or "liquid"
+        payment_method: PaymentMethod,
+    },
+    /// Get a Bitcoin address that can be paid to repeatedly. Confirmed deposits are forwarded
+    /// to chain swaps
+    GetReusableBitcoinAddress,
     /// Send to a Bitcoin onchain address via a swap
     SendOnchainPayment {
         /// Bitcoin onchain address to send to
@@ -80,6 +179,29 @@ pub(crate) enum Command {
         /// The optional fee rate to use, in sat/vbyte
         #[clap(short = 'f', long = "fee_rate")]
         fee_rate_sat_per_vbyte: Option<u32>,
+
+        /// The optional fee speed preset to use. Either "economy", "standard" or "fast"
+        #[clap(long = "fee_speed")]
+        fee_speed: Option<FeeSpeed>,
+
+        /// A token allowing the payment to exceed the configured spend limits
+        #[arg(long)]
+        spend_limit_override: Option<String>,
+    },
+    /// Convert between L-BTC and a Liquid asset, e.g. USDT
+    Convert {
+        /// The asset id to convert from
+        from_asset_id: String,
+
+        /// The asset id to convert to
+        to_asset_id: String,
+
+        /// The amount to convert, in base units of the asset converted from
+        amount: u64,
+
+        /// A token allowing the conversion to exceed the configured spend limits
+        #[arg(long)]
+        spend_limit_override: Option<String>,
     },
     /// Receive a payment directly or via a swap
     ReceivePayment {
@@ -95,6 +217,15 @@ pub(crate) enum Command {
         #[clap(name = "use_description_hash", short = 's', long = "desc_hash")]
         use_description_hash: Option<bool>,
 
+        /// Optional precomputed hex-encoded description hash to commit to in the invoice
+        #[clap(long = "description_hash")]
+        description_hash: Option<String>,
+
+        /// Optional Liquid address to claim the swap to instead of the SDK wallet, when the
+        /// 'payment_method' is "lightning"
+        #[clap(long = "claim_address")]
+        claim_address: Option<String>,
+
         /// The amount the payer should send, in satoshi. If not specified, it will generate a
         /// BIP21 URI/address with no amount.
         #[arg(long)]
@@ -109,6 +240,40 @@ pub(crate) enum Command {
         /// The asset id must also be provided.
         #[arg(long)]
         amount: Option<f64>,
+
+        /// The amount the payer should send, denominated in the fiat currency set by `currency`
+        #[arg(long)]
+        fiat_amount: Option<f64>,
+
+        /// The fiat currency code of `fiat_amount`, e.g. "USD"
+        #[arg(long)]
+        currency: Option<String>,
+
+        /// Optional expiry of the invoice in seconds, when the 'payment_method' is "lightning"
+        #[arg(long)]
+        expiry_secs: Option<u32>,
+    },
+    /// Compare the fees and limits of receiving an amount with each payment method
+    ReceiveQuotes {
+        /// The amount the payer should send, in satoshi
+        #[arg(long)]
+        amount_sat: Option<u64>,
+
+        /// Optional id of the asset to receive. Only a Liquid address can receive an asset
+        #[clap(long = "asset")]
+        asset_id: Option<String>,
+
+        /// The amount the payer should send, in asset units. The asset id must also be provided.
+        #[arg(long)]
+        amount: Option<f64>,
+
+        /// The amount the payer should send, denominated in the fiat currency set by `currency`
+        #[arg(long)]
+        fiat_amount: Option<f64>,
+
+        /// The fiat currency code of `fiat_amount`, e.g. "USD"
+        #[arg(long)]
+        currency: Option<String>,
     },
     /// Generates an URL to buy bitcoin from a 3rd party provider
     BuyBitcoin {
@@ -159,6 +324,16 @@ pub(crate) enum Command {
         #[clap(long = "ascending", action = ArgAction::SetTrue)]
         sort_ascending: Option<bool>,
     },
+    /// Get the totals of the payments in a period, per asset
+    GetReport {
+        /// The optional from unix timestamp
+        #[clap(name = "from_timestamp", short = 'f', long = "from")]
+        from_timestamp: Option<i64>,
+
+        /// The optional to unix timestamp
+        #[clap(name = "to_timestamp", short = 't', long = "to")]
+        to_timestamp: Option<i64>,
+    },
     /// Retrieve a payment
     #[command(group = clap::ArgGroup::new("payment_identifiers").args(&["payment_hash", "swap_id"]).required(true))]
     GetPayment {
@@ -169,6 +344,14 @@ pub(crate) enum Command {
         #[arg(long, short = 's')]
         swap_id: Option<String>,
     },
+    /// Export the unblinded amounts and blinding factors of a payment, e.g. for an audit
+    ExportPaymentUnblindingData {
+        /// The tx id or swap id of the payment
+        payment_id: String,
+        /// The base URL of the explorer to unblind the tx in
+        #[arg(long)]
+        explorer_url: Option<String>,
+    },
     /// Get and potentially accept proposed fees for WaitingFeeAcceptance Payment
     ReviewPaymentProposedFees { swap_id: String },
     /// List refundable chain swaps
@@ -180,7 +363,10 @@ pub(crate) enum Command {
         // Bitcoin onchain address to send the refund to
         refund_address: String,
         // Fee rate to use, in sat/vbyte
-        fee_rate_sat_per_vbyte: u32,
+        fee_rate_sat_per_vbyte: Option<u32>,
+        /// The fee speed preset to use if no fee rate is set. Either "economy", "standard" or "fast"
+        #[clap(long = "fee_speed")]
+        fee_speed: Option<FeeSpeed>,
     },
     /// Broadcast a refund transaction for an incomplete swap
     Refund {
@@ -189,12 +375,59 @@ pub(crate) enum Command {
         // Bitcoin onchain address to send the refund to
         refund_address: String,
         // Fee rate to use, in sat/vbyte
-        fee_rate_sat_per_vbyte: u32,
+        fee_rate_sat_per_vbyte: Option<u32>,
+        /// The fee speed preset to use if no fee rate is set. Either "economy", "standard" or "fast"
+        #[clap(long = "fee_speed")]
+        fee_speed: Option<FeeSpeed>,
     },
     /// Rescan onchain swaps
     RescanOnchainSwaps,
+    /// List all swaps with their full state
+    ListSwaps {
+        /// The optional swap type filter. Either "send", "receive" or "chain"
+        #[clap(name = "swap_type", short = 'r', long = "type")]
+        swap_types: Option<Vec<PaymentSwapType>>,
+
+        /// The optional swap state. Either "created", "pending", "complete", "failed", "timedout", "refundable", "refundpending" or "waitingfeeacceptance"
+        #[clap(name = "state", short = 's', long = "state")]
+        states: Option<Vec<PaymentState>>,
+
+        /// The optional from unix timestamp
+        #[clap(name = "from_timestamp", short = 'f', long = "from")]
+        from_timestamp: Option<i64>,
+
+        /// The optional to unix timestamp
+        #[clap(name = "to_timestamp", short = 't', long = "to")]
+        to_timestamp: Option<i64>,
+
+        /// Optional limit of listed swaps
+        #[clap(short = 'l', long = "limit")]
+        limit: Option<u32>,
+
+        /// Optional offset in swaps
+        #[clap(short = 'o', long = "offset")]
+        offset: Option<u32>,
+    },
+    /// List the swaps that are still in progress
+    ListOngoingSwaps,
+    /// Show the full state and scripts of a swap
+    GetSwap { swap_id: String },
+    /// Claim a Receive or Chain swap without waiting for the swapper status update
+    ClaimSwap { swap_id: String },
+    /// Refund a failed Send swap or outgoing Chain swap
+    RefundSwap { swap_id: String },
+    /// Rebroadcast the Liquid transactions of a swap that are known to the wallet
+    RebroadcastSwapTxs { swap_id: String },
     /// Get the balance and general info of the current instance
     GetInfo,
+    /// Check the reachability of the remote services and the offered swap types
+    CheckHealth,
+    /// Get the wallet descriptor, account xpub and master fingerprint
+    GetWalletInfo,
+    /// Register the AMP account needed to receive and hold AMP assets
+    RegisterAmpAccount,
+    /// Get the registered AMP account
+    GetAmpAccount,
     /// Sign a message using the wallet private key
     SignMessage {
         /// The message to sign
@@ -206,8 +439,34 @@ pub(crate) enum Command {
         pubkey: String,
         signature: String,
     },
+    /// Sign the wallet inputs of an externally created PSET
+    SignPset {
+        /// The base64-encoded PSET
+        pset: String,
+
+        /// The maximum network fee of the transaction, in satoshi
+        #[arg(long)]
+        max_fee_sat: u64,
+
+        /// Optional id of an asset the wallet may spend
+        #[clap(long = "asset", requires = "max_amount_sat")]
+        asset_id: Option<String>,
+
+        /// The maximum amount of the asset the wallet may spend, in satoshi
+        #[arg(long, requires = "asset_id")]
+        max_amount_sat: Option<u64>,
+    },
+    /// Sweep all the assets controlled by a private key into the wallet
+    SweepPrivateKey {
+        /// A WIF encoded private key, or a confidential elwpkh descriptor with a WIF encoded key
+        key: String,
+    },
     /// Sync local data with mempool and onchain data
-    Sync,
+    Sync {
+        /// Only sync the ongoing receive swaps
+        #[arg(short, long)]
+        partial: bool,
+    },
     /// Get the recommended Bitcoin fees based on the configured mempool.space instance
     RecommendedFees,
     /// Empties the encrypted transaction cache
@@ -222,6 +481,38 @@ pub(crate) enum Command {
         #[arg(short, long)]
         backup_path: Option<String>,
     },
+    /// Verifies a backup without restoring it
+    VerifyBackup {
+        #[arg(short, long)]
+        backup_path: Option<String>,
+    },
+    /// Exports the wallet swaps and payment metadata to a file
+    ExportWallet {
+        #[arg(short, long)]
+        export_path: String,
+    },
+    /// Imports a wallet export file
+    ImportWallet {
+        #[arg(short, long)]
+        export_path: String,
+    },
+    /// Adds a Nostr Wallet Connect connection, printing its connection URI
+    AddNwcConnection {
+        /// A unique name identifying the connection
+        name: String,
+
+        /// The maximum amount the connection can spend, including fees
+        #[arg(short, long)]
+        max_amount_sat: Option<u64>,
+
+        /// The period after which the spent amount is reset
+        #[arg(short, long)]
+        renewal_period_sec: Option<u32>,
+    },
+    /// Lists the Nostr Wallet Connect connections
+    ListNwcConnections,
+    /// Removes a Nostr Wallet Connect connection
+    RemoveNwcConnection { name: String },
     /// Shuts down all background threads of this SDK instance
     Disconnect,
     /// Parse a generic string to get its type and relevant metadata
@@ -229,6 +520,32 @@ pub(crate) enum Command {
         /// Generic input (URL, LNURL, BIP-21 Bitcoin Address, LN invoice, etc)
         input: String,
     },
+    /// Decode a BOLT11 or BOLT12 invoice without paying it
+    DecodeInvoice {
+        /// The BOLT11 or BOLT12 invoice
+        invoice: String,
+    },
+    /// Validate a Bitcoin or Liquid address for a network
+    ValidateAddress {
+        /// The Bitcoin or Liquid address
+        address: String,
+
+        /// The network the address should belong to
+        #[clap(short, long, value_parser = crate::parse_network_arg)]
+        network: LiquidNetwork,
+    },
+    /// Generate a random BIP39 mnemonic
+    GenerateMnemonic {
+        /// The number of words: 12, 15, 18, 21 or 24
+        #[arg(short, long, default_value_t = 12)]
+        word_count: u32,
+    },
+    /// Validate the words and checksum of a BIP39 mnemonic
+    ValidateMnemonic {
+        /// The words of the mnemonic
+        #[arg(required = true)]
+        words: Vec<String>,
+    },
     /// Pay using LNURL
     LnurlPay {
         /// LN Address or LNURL-pay endpoint
@@ -238,6 +555,18 @@ pub(crate) enum Command {
         #[clap(short, long, action = ArgAction::SetTrue)]
         drain: Option<bool>,
 
+        /// An optional comment for this payment
+        #[clap(short = 'c', long = "comment")]
+        comment: Option<String>,
+
+        /// An optional payer name to send to the LNURL service (LUD-18)
+        #[clap(long = "payer_name")]
+        payer_name: Option<String>,
+
+        /// An optional payer identifier to send to the LNURL service (LUD-18)
+        #[clap(long = "payer_identifier")]
+        payer_identifier: Option<String>,
+
         /// Validates the success action URL
         #[clap(name = "validate_success_url", short = 'v', long = "validate")]
         validate_success_url: Option<bool>,
@@ -254,10 +583,23 @@ pub(crate) enum Command {
     RegisterWebhook { url: String },
     /// Unregister the webhook URL
     UnregisterWebhook,
+    /// Check whether a lightning address username is available
+    CheckLightningAddress { username: String },
+    /// Register a lightning address, paid through the registered webhook
+    RegisterLightningAddress { username: String },
+    /// Recover the registered lightning address
+    RecoverLightningAddress,
+    /// Delete the registered lightning address
+    DeleteLightningAddress,
     /// List fiat currencies
     ListFiat {},
     /// Fetch available fiat rates
     FetchFiatRates {},
+    /// Fetch the metadata of an asset from the Liquid asset registry
+    FetchRegisteredAsset {
+        /// The id of the asset
+        asset_id: String,
+    },
 }
 
 #[derive(Helper, Completer, Hinter, Validator)]
@@ -313,7 +655,13 @@ pub(crate) async fn handle_command(
             asset_id,
             description,
             use_description_hash,
+            description_hash,
+            claim_address,
+            fiat_amount,
+            currency,
+            expiry_secs,
         } => {
+            let fiat_amount = to_fiat_amount(fiat_amount, currency)?;
             let amount = match asset_id {
                 Some(asset_id) => Some(ReceiveAmount::Asset {
                     asset_id,
@@ -327,11 +675,13 @@ pub(crate) async fn handle_command(
                 .prepare_receive_payment(&PrepareReceiveRequest {
                     payment_method: payment_method.unwrap_or(PaymentMethod::Lightning),
                     amount: amount.clone(),
+                    fiat_amount: fiat_amount.clone(),
+                    expiry_secs,
                 })
                 .await?;
 
             let fees = prepare_response.fees_sat;
-            let confirmation_msg = match amount {
+            let confirmation_msg = match prepare_response.amount {
                 Some(_) => format!("Fees: {fees} sat. Are the fees acceptable? (y/N)"),
                 None => {
                     let min = prepare_response.min_payer_amount_sat;
@@ -351,6 +701,8 @@ pub(crate) async fn handle_command(
                     prepare_response,
                     description,
                     use_description_hash,
+                    description_hash,
+                    claim_address,
                 })
                 .await?;
 
@@ -373,6 +725,31 @@ pub(crate) async fn handle_command(
             }
             result
         }
+        Command::ReceiveQuotes {
+            amount_sat,
+            asset_id,
+            amount,
+            fiat_amount,
+            currency,
+        } => {
+            let fiat_amount = to_fiat_amount(fiat_amount, currency)?;
+            let amount = match asset_id {
+                Some(asset_id) => Some(ReceiveAmount::Asset {
+                    asset_id,
+                    payer_amount: amount,
+                }),
+                None => {
+                    amount_sat.map(|payer_amount_sat| ReceiveAmount::Bitcoin { payer_amount_sat })
+                }
+            };
+            let response = sdk
+                .prepare_receive_payment_quotes(&PrepareReceiveQuotesRequest {
+                    amount,
+                    fiat_amount,
+                })
+                .await?;
+            command_result!(response)
+        }
         Command::FetchLightningLimits => {
             let limits = sdk.fetch_lightning_limits().await?;
             command_result!(limits)
@@ -381,6 +758,21 @@ pub(crate) async fn handle_command(
             let limits = sdk.fetch_onchain_limits().await?;
             command_result!(limits)
         }
+        Command::GetMaxPayable { payment_method } => {
+            let res = sdk
+                .get_max_payable(&GetMaxPayableRequest { payment_method })
+                .await?;
+            command_result!(res)
+        }
+        Command::GetMaxReceivable { payment_method } => {
+            let res = sdk
+                .get_max_receivable(&GetMaxReceivableRequest { payment_method })
+                .await?;
+            command_result!(res)
+        }
+        Command::GetReusableBitcoinAddress => {
+            command_result!(sdk.get_reusable_bitcoin_address().await?)
+        }
         Command::SendPayment {
             invoice,
             offer,
@@ -389,16 +781,24 @@ pub(crate) async fn handle_command(
             amount_sat,
             asset_id,
             use_asset_fees,
+            max_fee_sat,
+            spend_limit_override,
+            idempotency_key,
+            fiat_amount,
+            currency,
             drain,
             delay,
+            queue,
+            execute_at,
         } => {
+            let fiat_amount = to_fiat_amount(fiat_amount, currency)?;
             let destination = match (invoice, offer, address) {
                 (Some(invoice), None, None) => Ok(invoice),
-                (None, Some(offer), None) => match amount_sat {
-                    Some(_) => Ok(offer),
-                    None => Err(anyhow!(
+                (None, Some(offer), None) => match (amount_sat, &fiat_amount) {
+                    (None, None) => Err(anyhow!(
                         "Must specify an amount for a BOLT12 offer."
-                    ))
+                    )),
+                    _ => Ok(offer),
                 },
                 (None, None, Some(address)) => Ok(address),
                 (Some(_), _, Some(_)) => {
@@ -422,13 +822,35 @@ pub(crate) async fn handle_command(
                 (_, _, _, true) => Some(PayAmount::Drain),
                 _ => None,
             };
+            let prepare_request = PrepareSendRequest {
+                destination,
+                amount,
+                fiat_amount,
+            };
 
-            let prepare_response = sdk
-                .prepare_send_payment(&PrepareSendRequest {
-                    destination,
-                    amount,
-                })
-                .await?;
+            if queue.unwrap_or(false) {
+                let queued_payment = sdk
+                    .queue_payment(&QueuePaymentRequest {
+                        prepare_request,
+                        use_asset_fees,
+                        max_fee_sat,
+                    })
+                    .await?;
+                return Ok(command_result!(queued_payment));
+            }
+            if let Some(execute_at) = execute_at {
+                let scheduled_payment = sdk
+                    .schedule_payment(&SchedulePaymentRequest {
+                        prepare_request,
+                        use_asset_fees,
+                        max_fee_sat,
+                        execute_at,
+                    })
+                    .await?;
+                return Ok(command_result!(scheduled_payment));
+            }
+
+            let prepare_response = sdk.prepare_send_payment(&prepare_request).await?;
 
             let confirmation_msg = match (
                 use_asset_fees.unwrap_or(false),
@@ -454,6 +876,9 @@ pub(crate) async fn handle_command(
             let send_payment_req = SendPaymentRequest {
                 prepare_response: prepare_response.clone(),
                 use_asset_fees,
+                max_fee_sat,
+                spend_limit_override,
+                idempotency_key,
             };
 
             if let Some(delay) = delay {
@@ -474,6 +899,8 @@ pub(crate) async fn handle_command(
             receiver_amount_sat,
             drain,
             fee_rate_sat_per_vbyte,
+            fee_speed,
+            spend_limit_override,
         } => {
             let amount = match drain.unwrap_or(false) {
                 true => PayAmount::Drain,
@@ -487,6 +914,7 @@ pub(crate) async fn handle_command(
                 .prepare_pay_onchain(&PreparePayOnchainRequest {
                     amount,
                     fee_rate_sat_per_vbyte,
+                    fee_speed,
                 })
                 .await?;
 
@@ -502,6 +930,37 @@ pub(crate) async fn handle_command(
                 .pay_onchain(&PayOnchainRequest {
                     address,
                     prepare_response,
+                    spend_limit_override,
+                })
+                .await?;
+            command_result!(response)
+        }
+        Command::Convert {
+            from_asset_id,
+            to_asset_id,
+            amount,
+            spend_limit_override,
+        } => {
+            let prepare_response = sdk
+                .prepare_convert(&PrepareConvertRequest {
+                    from_asset_id,
+                    to_asset_id,
+                    amount,
+                })
+                .await?;
+
+            wait_confirmation!(
+                format!(
+                    "Fees: {} sat. Receiver amount: {} base units. Are the fees acceptable? (y/N) ",
+                    prepare_response.fees_sat, prepare_response.receiver_amount
+                ),
+                "Conversion halted"
+            );
+
+            let response = sdk
+                .convert(&ConvertRequest {
+                    prepare_response,
+                    spend_limit_override,
                 })
                 .await?;
             command_result!(response)
@@ -540,10 +999,25 @@ pub(crate) async fn handle_command(
         Command::GetInfo => {
             command_result!(sdk.get_info().await?)
         }
+        Command::CheckHealth => {
+            command_result!(sdk.check_health().await?)
+        }
+        Command::GetWalletInfo => {
+            command_result!(sdk.get_wallet_info()?)
+        }
+        Command::RegisterAmpAccount => {
+            command_result!(sdk.register_amp_account().await?)
+        }
+        Command::GetAmpAccount => {
+            command_result!(sdk.get_amp_account()?)
+        }
         Command::SignMessage { message } => {
             let req = SignMessageRequest { message };
             let res = sdk.sign_message(&req)?;
-            command_result!(format!("Message signature: {}", res.signature))
+            command_result!(format!(
+                "Message signature: {}\nPubkey: {}",
+                res.signature, res.pubkey
+            ))
         }
         Command::CheckMessage {
             message,
@@ -558,6 +1032,35 @@ pub(crate) async fn handle_command(
             let res = sdk.check_message(&req)?;
             command_result!(format!("Message was signed by pubkey: {}", res.is_valid))
         }
+        Command::SweepPrivateKey { key } => {
+            let res = sdk
+                .sweep_private_key(&SweepPrivateKeyRequest { key })
+                .await?;
+            command_result!(res)
+        }
+        Command::SignPset {
+            pset,
+            max_fee_sat,
+            asset_id,
+            max_amount_sat,
+        } => {
+            let spend_limits = asset_id
+                .zip(max_amount_sat)
+                .map(|(asset_id, max_amount_sat)| PsetSpendLimit {
+                    asset_id,
+                    max_amount_sat,
+                })
+                .into_iter()
+                .collect();
+            let res = sdk
+                .sign_pset(&SignPsetRequest {
+                    pset,
+                    max_fee_sat,
+                    spend_limits,
+                })
+                .await?;
+            command_result!(res)
+        }
         Command::ListPayments {
             filters,
             states,
@@ -597,6 +1100,18 @@ pub(crate) async fn handle_command(
                 .await?;
             command_result!(payments)
         }
+        Command::GetReport {
+            from_timestamp,
+            to_timestamp,
+        } => {
+            let report = sdk
+                .get_report(&GetReportRequest {
+                    from_timestamp,
+                    to_timestamp,
+                })
+                .await?;
+            command_result!(report)
+        }
         Command::GetPayment {
             payment_hash,
             swap_id,
@@ -622,6 +1137,18 @@ pub(crate) async fn handle_command(
                 }
             }
         }
+        Command::ExportPaymentUnblindingData {
+            payment_id,
+            explorer_url,
+        } => {
+            let res = sdk
+                .export_payment_unblinding_data(&ExportPaymentUnblindingDataRequest {
+                    payment_id,
+                    explorer_url,
+                })
+                .await?;
+            command_result!(res)
+        }
         Command::ReviewPaymentProposedFees { swap_id } => {
             let fetch_response = sdk
                 .fetch_payment_proposed_fees(&FetchPaymentProposedFeesRequest { swap_id })
@@ -649,12 +1176,14 @@ pub(crate) async fn handle_command(
             swap_address,
             refund_address,
             fee_rate_sat_per_vbyte,
+            fee_speed,
         } => {
             let res = sdk
                 .prepare_refund(&PrepareRefundRequest {
                     swap_address,
                     refund_address,
                     fee_rate_sat_per_vbyte,
+                    fee_speed,
                 })
                 .await?;
             command_result!(res)
@@ -663,12 +1192,14 @@ pub(crate) async fn handle_command(
             swap_address,
             refund_address,
             fee_rate_sat_per_vbyte,
+            fee_speed,
         } => {
             let res = sdk
                 .refund(&RefundRequest {
                     swap_address,
                     refund_address,
                     fee_rate_sat_per_vbyte,
+                    fee_speed,
                 })
                 .await?;
             command_result!(res)
@@ -677,9 +1208,51 @@ pub(crate) async fn handle_command(
             sdk.rescan_onchain_swaps().await?;
             command_result!("Rescanned successfully")
         }
-        Command::Sync => {
-            sdk.sync(false).await?;
-            command_result!("Synced successfully")
+        Command::ListSwaps {
+            swap_types,
+            states,
+            from_timestamp,
+            to_timestamp,
+            limit,
+            offset,
+        } => {
+            let swaps = sdk
+                .list_swaps(&ListSwapsRequest {
+                    swap_types,
+                    states,
+                    from_timestamp,
+                    to_timestamp,
+                    offset,
+                    limit,
+                })
+                .await?;
+            command_result!(swaps)
+        }
+        Command::ListOngoingSwaps => {
+            let swaps = sdk.list_ongoing_swaps().await?;
+            command_result!(swaps)
+        }
+        Command::GetSwap { swap_id } => match sdk.get_swap(&swap_id).await? {
+            Some(swap) => command_result!(swap),
+            None => {
+                return Err(anyhow!("Swap not found."));
+            }
+        },
+        Command::ClaimSwap { swap_id } => {
+            sdk.claim_swap(&swap_id).await?;
+            command_result!("Claim broadcast successfully")
+        }
+        Command::RefundSwap { swap_id } => {
+            let res = sdk.refund_swap(&swap_id).await?;
+            command_result!(res)
+        }
+        Command::RebroadcastSwapTxs { swap_id } => {
+            let tx_ids = sdk.rebroadcast_swap_txs(&swap_id).await?;
+            command_result!(tx_ids)
+        }
+        Command::Sync { partial } => {
+            let res = sdk.sync(partial).await?;
+            command_result!(res)
         }
         Command::RecommendedFees => {
             let res = sdk.recommended_fees().await?;
@@ -697,6 +1270,40 @@ pub(crate) async fn handle_command(
             sdk.restore(RestoreRequest { backup_path })?;
             command_result!("Backup restored successfully!")
         }
+        Command::VerifyBackup { backup_path } => {
+            sdk.verify_backup(RestoreRequest { backup_path })?;
+            command_result!("Backup is valid")
+        }
+        Command::ExportWallet { export_path } => {
+            sdk.export_wallet(ExportWalletRequest { export_path })?;
+            command_result!("Wallet exported successfully")
+        }
+        Command::ImportWallet { export_path } => {
+            let res = sdk.import_wallet(ImportWalletRequest { export_path })?;
+            command_result!(res)
+        }
+        Command::AddNwcConnection {
+            name,
+            max_amount_sat,
+            renewal_period_sec,
+        } => {
+            let res = sdk.add_nwc_connection(AddNwcConnectionRequest {
+                name,
+                budget: max_amount_sat.map(|max_amount_sat| NwcBudget {
+                    max_amount_sat,
+                    renewal_period_sec,
+                }),
+            })?;
+            command_result!(res)
+        }
+        Command::ListNwcConnections => {
+            let res = sdk.list_nwc_connections()?;
+            command_result!(res)
+        }
+        Command::RemoveNwcConnection { name } => {
+            sdk.remove_nwc_connection(name)?;
+            command_result!("NWC connection removed successfully")
+        }
         Command::Disconnect => {
             sdk.disconnect().await?;
             command_result!("Liquid SDK instance disconnected")
@@ -705,9 +1312,25 @@ pub(crate) async fn handle_command(
             let res = sdk.parse(&input).await?;
             command_result!(res)
         }
+        Command::DecodeInvoice { invoice } => {
+            command_result!(LiquidSdk::decode_invoice(&invoice)?)
+        }
+        Command::ValidateAddress { address, network } => {
+            command_result!(LiquidSdk::validate_address(&address, network)?)
+        }
+        Command::GenerateMnemonic { word_count } => {
+            command_result!(LiquidSdk::generate_mnemonic(word_count)?)
+        }
+        Command::ValidateMnemonic { words } => {
+            LiquidSdk::validate_mnemonic(&words.join(" "))?;
+            command_result!("Mnemonic is valid")
+        }
         Command::LnurlPay {
             lnurl,
             drain,
+            comment,
+            payer_name,
+            payer_identifier,
             validate_success_url,
         } => {
             let input = sdk.parse(&lnurl).await?;
@@ -732,12 +1355,22 @@ pub(crate) async fn handle_command(
                         }
                     };
 
+                    let payer_data = match (payer_name, payer_identifier) {
+                        (None, None) => None,
+                        (name, identifier) => Some(LnUrlPayerData {
+                            name,
+                            identifier,
+                            ..Default::default()
+                        }),
+                    };
+
                     let prepare_response = sdk
                         .prepare_lnurl_pay(PrepareLnUrlPayRequest {
                             data: pd,
                             amount,
                             bip353_address,
-                            comment: None,
+                            comment,
+                            payer_data,
                             validate_success_action_url: validate_success_url,
                         })
                         .await?;
@@ -801,14 +1434,88 @@ pub(crate) async fn handle_command(
             sdk.register_webhook(url).await?;
             command_result!("Url registered successfully")
         }
+        Command::ReplayEvents { since_seq, limit } => {
+            let events = sdk
+                .replay_events(&ReplayEventsRequest { since_seq, limit })
+                .await?;
+            command_result!(events)
+        }
+        Command::ListQueuedPayments => {
+            let queued_payments = sdk.list_queued_payments().await?;
+            command_result!(queued_payments)
+        }
+        Command::CancelQueuedPayment { id } => {
+            sdk.cancel_queued_payment(id).await?;
+            command_result!("Queued payment cancelled successfully")
+        }
+        Command::ListScheduledPayments => {
+            let scheduled_payments = sdk.list_scheduled_payments().await?;
+            command_result!(scheduled_payments)
+        }
+        Command::CancelScheduledPayment { id } => {
+            sdk.cancel_scheduled_payment(id).await?;
+            command_result!("Scheduled payment cancelled successfully")
+        }
+        Command::CreateRecurringPayment {
+            destination,
+            amount_sat,
+            interval_sec,
+            start_at,
+            max_fee_sat,
+        } => {
+            let recurring_payment = sdk
+                .create_recurring_payment(&CreateRecurringPaymentRequest {
+                    destination,
+                    amount_sat,
+                    interval_sec,
+                    start_at,
+                    max_fee_sat,
+                })
+                .await?;
+            command_result!(recurring_payment)
+        }
+        Command::ListRecurringPayments => {
+            let recurring_payments = sdk.list_recurring_payments().await?;
+            command_result!(recurring_payments)
+        }
+        Command::ListRecurringPaymentOccurrences { id } => {
+            let occurrences = sdk.list_recurring_payment_occurrences(id).await?;
+            command_result!(occurrences)
+        }
+        Command::CancelRecurringPayment { id } => {
+            sdk.cancel_recurring_payment(id).await?;
+            command_result!("Recurring payment cancelled successfully")
+        }
         Command::UnregisterWebhook => {
             sdk.unregister_webhook().await?;
             command_result!("Url unregistered successfully")
         }
+        Command::CheckLightningAddress { username } => {
+            let available = sdk.check_lightning_address_available(username).await?;
+            command_result!(available)
+        }
+        Command::RegisterLightningAddress { username } => {
+            let info = sdk
+                .register_lightning_address(&RegisterLightningAddressRequest { username })
+                .await?;
+            command_result!(info)
+        }
+        Command::RecoverLightningAddress => {
+            let info = sdk.recover_lightning_address().await?;
+            command_result!(info)
+        }
+        Command::DeleteLightningAddress => {
+            sdk.delete_lightning_address().await?;
+            command_result!("Lightning address deleted successfully")
+        }
         Command::FetchFiatRates {} => {
             let res = sdk.fetch_fiat_rates().await?;
             command_result!(res)
         }
+        Command::FetchRegisteredAsset { asset_id } => {
+            let res = sdk.fetch_registered_asset(&asset_id).await?;
+            command_result!(res)
+        }
         Command::ListFiat {} => {
             let res = sdk.list_fiat_currencies().await?;
             command_result!(res)
@@ -816,6 +1523,16 @@ pub(crate) async fn handle_command(
     })
 }
 
+fn to_fiat_amount(amount: Option<f64>, currency: Option<String>) -> Result<Option<FiatAmount>> {
+    match (amount, currency) {
+        (Some(amount), Some(currency)) => Ok(Some(FiatAmount { amount, currency })),
+        (None, None) => Ok(None),
+        _ => Err(anyhow!(
+            "Must specify both `fiat_amount` and `currency` to use a fiat amount"
+        )),
+    }
+}
+
 fn build_qr_text(text: &str) -> String {
     QrCode::with_error_correction_level(text, EcLevel::L)
         .unwrap()
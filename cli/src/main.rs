@@ -30,9 +30,27 @@ pub(crate) struct Args {
 
     #[clap(short, long)]
     pub(crate) passphrase: Option<String>,
+
+    /// Simulates the swap lifecycles instead of using the swapper, with the given delay in
+    /// seconds between the status updates
+    #[clap(long)]
+    pub(crate) simulate_swaps: Option<u32>,
+
+    /// The probability that a simulated swap fails, between 0 and 1
+    #[clap(long, default_value_t = 0.0)]
+    pub(crate) simulated_failure_rate: f64,
+
+    /// Disables the automatic claim of incoming swaps, which are then claimed with `claim-swap`
+    #[clap(long, action)]
+    pub(crate) manual_claim: bool,
+
+    /// The default Liquid address to claim the Lightning receive swaps to, instead of the SDK
+    /// wallet
+    #[clap(long)]
+    pub(crate) claim_address: Option<String>,
 }
 
-fn parse_network_arg(s: &str) -> Result<LiquidNetwork, String> {
+pub(crate) fn parse_network_arg(s: &str) -> Result<LiquidNetwork, String> {
     LiquidNetwork::try_from(s).map_err(|e| e.to_string())
 }
 
@@ -95,15 +113,24 @@ async fn main() -> Result<()> {
     } else if data_sync_url.is_some() {
         config.sync_service_url = data_sync_url;
     }
+    config.simulation = args
+        .simulate_swaps
+        .map(|status_update_delay_sec| SimulationConfig {
+            status_update_delay_sec,
+            failure_rate: args.simulated_failure_rate,
+        });
+    config.manual_claim = args.manual_claim;
+    config.claim_address = args.claim_address;
     let sdk = LiquidSdk::connect(ConnectRequest {
         config,
         mnemonic: Some(mnemonic.to_string()),
         passphrase,
         seed: None,
+        xprv: None,
     })
     .await?;
     let listener_id = sdk
-        .add_event_listener(Box::new(CliEventListener {}))
+        .add_event_listener(Box::new(CliEventListener {}), None)
         .await?;
 
     let cli_prompt = match network {
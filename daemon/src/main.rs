@@ -0,0 +1,117 @@
+mod server;
+
+use std::{fs, net::SocketAddr, sync::Arc};
+
+use anyhow::Result;
+use breez_sdk_liquid::prelude::*;
+use clap::Parser;
+use log::{error, info};
+use server::{proto::breez_liquid_server::BreezLiquidServer, DaemonServer};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use tonic::transport::Server;
+
+#[derive(Parser, Debug)]
+pub(crate) struct Args {
+    #[clap(short, long)]
+    pub(crate) data_dir: Option<String>,
+
+    #[clap(short, long, value_parser = parse_network_arg)]
+    pub(crate) network: Option<LiquidNetwork>,
+
+    /// The address the gRPC server listens on
+    #[clap(short, long, default_value = "127.0.0.1:50051")]
+    pub(crate) listen: SocketAddr,
+
+    /// The address the Prometheus metrics endpoint listens on. Disabled if not set
+    #[clap(long)]
+    pub(crate) metrics_listen: Option<SocketAddr>,
+
+    #[clap(long, env = "MNEMONIC", hide_env_values = true)]
+    pub(crate) mnemonic: String,
+
+    #[clap(short, long, env = "PASSPHRASE", hide_env_values = true)]
+    pub(crate) passphrase: Option<String>,
+
+    #[clap(long, env = "BREEZ_API_KEY", hide_env_values = true)]
+    pub(crate) breez_api_key: Option<String>,
+}
+
+fn parse_network_arg(s: &str) -> Result<LiquidNetwork, String> {
+    LiquidNetwork::try_from(s).map_err(|e| e.to_string())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+
+    let data_dir = args.data_dir.unwrap_or(DEFAULT_DATA_DIR.to_string());
+    fs::create_dir_all(&data_dir)?;
+
+    LiquidSdk::init_logging(&data_dir, None)?;
+
+    let network = args.network.unwrap_or(LiquidNetwork::Testnet);
+    let mut config = LiquidSdk::default_config(network, args.breez_api_key)?;
+    config.working_dir = data_dir;
+    let sdk = LiquidSdk::connect(ConnectRequest {
+        config,
+        mnemonic: Some(args.mnemonic),
+        passphrase: args.passphrase,
+        seed: None,
+        xprv: None,
+    })
+    .await?;
+
+    if let Some(metrics_listen) = args.metrics_listen {
+        let listener = TcpListener::bind(metrics_listen).await?;
+        info!("Serving metrics on {metrics_listen}");
+        tokio::spawn(serve_metrics(listener, sdk.clone()));
+    }
+
+    info!("Listening on {}", args.listen);
+    Server::builder()
+        .add_service(BreezLiquidServer::new(DaemonServer::new(sdk.clone())))
+        .serve_with_shutdown(args.listen, shutdown_signal())
+        .await?;
+
+    info!("Shutting down");
+    sdk.disconnect().await?;
+    Ok(())
+}
+
+/// Answers every request on the listener with the SDK metrics in the Prometheus text format
+async fn serve_metrics(listener: TcpListener, sdk: Arc<LiquidSdk>) {
+    loop {
+        let mut stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(err) => {
+                error!("Failed to accept metrics connection: {err:?}");
+                continue;
+            }
+        };
+        let sdk = sdk.clone();
+        tokio::spawn(async move {
+            // The request itself is ignored, only its headers need to be drained
+            let mut buf = [0u8; 1024];
+            let _ = stream.read(&mut buf).await;
+
+            let body = sdk.prometheus_metrics();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            if let Err(err) = stream.write_all(response.as_bytes()).await {
+                error!("Failed to write metrics response: {err:?}");
+            }
+            let _ = stream.shutdown().await;
+        });
+    }
+}
+
+async fn shutdown_signal() {
+    if let Err(err) = tokio::signal::ctrl_c().await {
+        error!("Failed to listen for the shutdown signal: {err:?}");
+    }
+}
@@ -0,0 +1,319 @@
+use std::{pin::Pin, sync::Arc};
+
+use breez_sdk_liquid::prelude::*;
+use log::{info, warn};
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::UnboundedReceiverStream, Stream};
+use tonic::{Request, Response, Status};
+
+pub(crate) mod proto {
+    tonic::include_proto!("breez_liquid");
+}
+
+use proto::breez_liquid_server::BreezLiquid;
+
+pub(crate) struct DaemonServer {
+    sdk: Arc<LiquidSdk>,
+}
+
+impl DaemonServer {
+    pub(crate) fn new(sdk: Arc<LiquidSdk>) -> Self {
+        Self { sdk }
+    }
+}
+
+/// Forwards the SDK events to the stream of a single `SubscribeEvents` call, queueing them
+/// without bound so that a slow subscriber neither blocks the SDK nor misses events
+struct SubscriberEventListener {
+    sender: mpsc::UnboundedSender<Result<proto::Event, Status>>,
+}
+
+impl EventListener for SubscriberEventListener {
+    fn on_event(&self, e: SdkEvent) {
+        if let Err(err) = self.sender.send(Ok(e.into())) {
+            warn!("Failed to forward event to subscriber: {err}");
+        }
+    }
+}
+
+/// Maps an SDK error to the gRPC status code that best describes it to the client
+fn sdk_error_status(err: SdkError) -> Status {
+    let message = err.to_string();
+    match err {
+        SdkError::AlreadyStarted | SdkError::DataDirLocked { .. } | SdkError::NotStarted => {
+            Status::failed_precondition(message)
+        }
+        SdkError::InvalidBackup { .. } => Status::invalid_argument(message),
+        SdkError::ServiceConnectivity { .. } => Status::unavailable(message),
+        SdkError::Generic { .. } => Status::internal(message),
+    }
+}
+
+/// Maps a payment error to the gRPC status code that best describes it to the client
+fn payment_error_status(err: PaymentError) -> Status {
+    let message = err.to_string();
+    match err {
+        PaymentError::AlreadyClaimed
+        | PaymentError::AlreadyPaid
+        | PaymentError::PaymentInProgress => Status::already_exists(message),
+        PaymentError::AmountOutOfRange
+        | PaymentError::AmountMissing { .. }
+        | PaymentError::AssetError { .. }
+        | PaymentError::InvalidNetwork { .. }
+        | PaymentError::InvalidDescription { .. }
+        | PaymentError::InvalidInvoice { .. }
+        | PaymentError::SelfTransferNotSupported => Status::invalid_argument(message),
+        PaymentError::InvalidOrExpiredFees
+        | PaymentError::FeeLimitExceeded { .. }
+        | PaymentError::InsufficientFunds
+        | PaymentError::SpendLimitExceeded { .. } => Status::failed_precondition(message),
+        PaymentError::PairsNotFound | PaymentError::ServiceUnavailable { .. } => {
+            Status::unavailable(message)
+        }
+        PaymentError::PaymentTimeout => Status::deadline_exceeded(message),
+        PaymentError::Refunded { .. } => Status::aborted(message),
+        PaymentError::Generic { .. }
+        | PaymentError::InvalidPreimage
+        | PaymentError::PersistError
+        | PaymentError::ReceiveError { .. }
+        | PaymentError::SendError { .. }
+        | PaymentError::SignerError { .. } => Status::internal(message),
+    }
+}
+
+impl From<&Payment> for proto::Payment {
+    fn from(payment: &Payment) -> Self {
+        Self {
+            tx_id: payment.tx_id.clone(),
+            destination: payment.destination.clone(),
+            timestamp: payment.timestamp,
+            amount_sat: payment.amount_sat,
+            fees_sat: payment.fees_sat,
+            payment_type: payment.payment_type.to_string(),
+            status: format!("{:?}", payment.status).to_lowercase(),
+        }
+    }
+}
+
+impl From<SdkEvent> for proto::Event {
+    fn from(e: SdkEvent) -> Self {
+        Self {
+            kind: format!("{:?}", e.kind()),
+            payment: e.payment().map(Into::into),
+        }
+    }
+}
+
+impl From<proto::PaymentMethod> for PaymentMethod {
+    fn from(method: proto::PaymentMethod) -> Self {
+        match method {
+            proto::PaymentMethod::Lightning => PaymentMethod::Lightning,
+            proto::PaymentMethod::BitcoinAddress => PaymentMethod::BitcoinAddress,
+            proto::PaymentMethod::LiquidAddress => PaymentMethod::LiquidAddress,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl BreezLiquid for DaemonServer {
+    async fn get_info(
+        &self,
+        _request: Request<proto::GetInfoRequest>,
+    ) -> Result<Response<proto::GetInfoResponse>, Status> {
+        let info = self.sdk.get_info().await.map_err(sdk_error_status)?;
+        Ok(Response::new(proto::GetInfoResponse {
+            balance_sat: info.wallet_info.balance_sat,
+            pending_send_sat: info.wallet_info.pending_send_sat,
+            pending_receive_sat: info.wallet_info.pending_receive_sat,
+            fingerprint: info.wallet_info.fingerprint,
+            pubkey: info.wallet_info.pubkey,
+            liquid_tip: info.blockchain_info.liquid_tip,
+            bitcoin_tip: info.blockchain_info.bitcoin_tip,
+        }))
+    }
+
+    async fn receive_payment(
+        &self,
+        request: Request<proto::ReceivePaymentRequest>,
+    ) -> Result<Response<proto::ReceivePaymentResponse>, Status> {
+        let req = request.into_inner();
+        let payment_method = proto::PaymentMethod::try_from(req.payment_method)
+            .map_err(|_| Status::invalid_argument("Unknown payment method"))?;
+        let prepare_response = self
+            .sdk
+            .prepare_receive_payment(&PrepareReceiveRequest {
+                payment_method: payment_method.into(),
+                amount: req
+                    .amount_sat
+                    .map(|payer_amount_sat| ReceiveAmount::Bitcoin { payer_amount_sat }),
+                fiat_amount: None,
+                expiry_secs: None,
+            })
+            .await
+            .map_err(payment_error_status)?;
+        let fees_sat = prepare_response.fees_sat;
+        let res = self
+            .sdk
+            .receive_payment(&ReceivePaymentRequest {
+                prepare_response,
+                description: req.description,
+                use_description_hash: None,
+                description_hash: None,
+                claim_address: None,
+            })
+            .await
+            .map_err(payment_error_status)?;
+        Ok(Response::new(proto::ReceivePaymentResponse {
+            destination: res.destination,
+            fees_sat,
+        }))
+    }
+
+    async fn send_payment(
+        &self,
+        request: Request<proto::SendPaymentRequest>,
+    ) -> Result<Response<proto::SendPaymentResponse>, Status> {
+        let req = request.into_inner();
+        let prepare_response = self
+            .sdk
+            .prepare_send_payment(&PrepareSendRequest {
+                destination: req.destination,
+                amount: req
+                    .amount_sat
+                    .map(|receiver_amount_sat| PayAmount::Bitcoin {
+                        receiver_amount_sat,
+                    }),
+                fiat_amount: None,
+            })
+            .await
+            .map_err(payment_error_status)?;
+        let res = self
+            .sdk
+            .send_payment(&SendPaymentRequest {
+                prepare_response,
+                use_asset_fees: None,
+                max_fee_sat: req.max_fee_sat,
+                spend_limit_override: None,
+                idempotency_key: req.idempotency_key,
+            })
+            .await
+            .map_err(payment_error_status)?;
+        Ok(Response::new(proto::SendPaymentResponse {
+            payment: Some((&res.payment).into()),
+        }))
+    }
+
+    async fn list_payments(
+        &self,
+        request: Request<proto::ListPaymentsRequest>,
+    ) -> Result<Response<proto::ListPaymentsResponse>, Status> {
+        let req = request.into_inner();
+        let payments = self
+            .sdk
+            .list_payments(&ListPaymentsRequest {
+                from_timestamp: req.from_timestamp,
+                to_timestamp: req.to_timestamp,
+                offset: req.offset,
+                limit: req.limit,
+                ..Default::default()
+            })
+            .await
+            .map_err(payment_error_status)?;
+        Ok(Response::new(proto::ListPaymentsResponse {
+            payments: payments.iter().map(Into::into).collect(),
+        }))
+    }
+
+    type SubscribeEventsStream =
+        Pin<Box<dyn Stream<Item = Result<proto::Event, Status>> + Send + 'static>>;
+
+    async fn subscribe_events(
+        &self,
+        _request: Request<proto::SubscribeEventsRequest>,
+    ) -> Result<Response<Self::SubscribeEventsStream>, Status> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let listener_id = self
+            .sdk
+            .add_event_listener(
+                Box::new(SubscriberEventListener {
+                    sender: sender.clone(),
+                }),
+                None,
+            )
+            .await
+            .map_err(sdk_error_status)?;
+        info!("Added event subscriber {listener_id}");
+
+        // Remove the listener once the subscriber disconnects and the stream is dropped
+        let sdk = self.sdk.clone();
+        tokio::spawn(async move {
+            sender.closed().await;
+            if let Err(err) = sdk.remove_event_listener(listener_id.clone()).await {
+                warn!("Failed to remove event subscriber {listener_id}: {err:?}");
+            }
+            info!("Removed event subscriber {listener_id}");
+        });
+
+        Ok(Response::new(Box::pin(UnboundedReceiverStream::new(
+            receiver,
+        ))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscriber_event_listener() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let listener = SubscriberEventListener { sender };
+
+        // The events are queued in order while the subscriber is behind
+        listener.on_event(SdkEvent::Synced);
+        listener.on_event(SdkEvent::SyncStarted);
+
+        let event = receiver.try_recv().unwrap().unwrap();
+        assert_eq!(event.kind, "Synced");
+        assert!(event.payment.is_none());
+        let event = receiver.try_recv().unwrap().unwrap();
+        assert_eq!(event.kind, "SyncStarted");
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_error_status() {
+        assert_eq!(
+            sdk_error_status(SdkError::NotStarted).code(),
+            tonic::Code::FailedPrecondition
+        );
+        assert_eq!(
+            sdk_error_status(SdkError::generic("error")).code(),
+            tonic::Code::Internal
+        );
+
+        let status = payment_error_status(PaymentError::InvalidInvoice {
+            err: "bad checksum".to_string(),
+        });
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(
+            status.message(),
+            "The specified invoice is not valid: bad checksum"
+        );
+        assert_eq!(
+            payment_error_status(PaymentError::InsufficientFunds).code(),
+            tonic::Code::FailedPrecondition
+        );
+        assert_eq!(
+            payment_error_status(PaymentError::PaymentTimeout).code(),
+            tonic::Code::DeadlineExceeded
+        );
+        assert_eq!(
+            payment_error_status(PaymentError::ServiceUnavailable {
+                err: "down".to_string()
+            })
+            .code(),
+            tonic::Code::Unavailable
+        );
+    }
+}
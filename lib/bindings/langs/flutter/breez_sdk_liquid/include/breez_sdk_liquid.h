@@ -18,10 +18,25 @@ typedef struct _Dart_Handle* Dart_Handle;
 
 #define ESTIMATED_BTC_LOCKUP_TX_VSIZE 154
 
+/**
+ * The default size at which the log file is rotated. See [LogFileConfig::max_file_size_bytes]
+ */
+#define DEFAULT_MAX_LOG_FILE_SIZE_BYTES ((10 * 1024) * 1024)
+
+/**
+ * The default number of rotated log files kept. See [LogFileConfig::max_rotated_files]
+ */
+#define DEFAULT_MAX_ROTATED_LOG_FILES 5
+
 #define LIQUID_FEE_RATE_SAT_PER_VBYTE 0.1
 
 #define LIQUID_FEE_RATE_MSAT_PER_VBYTE (float)(LIQUID_FEE_RATE_SAT_PER_VBYTE * 1000.0)
 
+/**
+ * The number of decimals used to display amounts of fiat-backed assets, e.g. USDT
+ */
+#define FIAT_ASSET_DISPLAY_PRECISION 2
+
 #define MIN_FEE_RATE 0.1
 
 #define WEIGHT_FIXED 222
@@ -37,11 +52,61 @@ typedef struct _Dart_Handle* Dart_Handle;
  */
 #define DEFAULT_ZERO_CONF_MAX_SAT 1000000
 
+/**
+ * The minimum expiry in seconds of an invoice created when receiving via a swap
+ */
+#define MIN_INVOICE_EXPIRY_SECS 60
+
+/**
+ * The maximum expiry in seconds of an invoice created when receiving via a swap.
+ * It is kept well below the swap timeout so the swap can still be claimed once paid.
+ */
+#define MAX_INVOICE_EXPIRY_SECS 43200
+
 /**
  * Number of blocks to monitor a swap after its timeout block height
  */
 #define CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS 4320
 
+/**
+ * The default interval in seconds between background syncs. See [Config::sync_interval_sec]
+ */
+#define DEFAULT_SYNC_INTERVAL_SEC 10
+
+/**
+ * The default number of seconds after which a still unconfirmed tx is reported. See
+ * [Config::unconfirmed_tx_alert_sec]
+ */
+#define DEFAULT_UNCONFIRMED_TX_ALERT_SEC 600
+
+/**
+ * The default timeout in seconds of the Electrum and Esplora requests. See
+ * [Config::chain_service_timeout_sec]
+ */
+#define DEFAULT_CHAIN_SERVICE_TIMEOUT_SEC 3
+
+/**
+ * The default timeout in seconds of each retried swapper call attempt. See
+ * [Config::swapper_timeout_sec]
+ */
+#define DEFAULT_SWAPPER_TIMEOUT_SEC 30
+
+/**
+ * The default interval in seconds between the keep-alive pings of the swapper status stream.
+ * See [Config::swapper_ping_interval_sec]
+ */
+#define DEFAULT_SWAPPER_PING_INTERVAL_SEC 15
+
+/**
+ * The upper bound of the network timeouts and intervals set in [Config], in seconds
+ */
+#define MAX_NETWORK_TIMEOUT_SEC 120
+
+/**
+ * The default number of attempts made for a swapper call. See [Config::swapper_max_attempts](crate::model::Config::swapper_max_attempts)
+ */
+#define DEFAULT_SWAPPER_MAX_ATTEMPTS 3
+
 typedef struct wire_cst_list_prim_u_8_strict {
   uint8_t *ptr;
   int32_t len;
@@ -58,6 +123,37 @@ typedef struct wire_cst_accept_payment_proposed_fees_request {
   struct wire_cst_fetch_payment_proposed_fees_response response;
 } wire_cst_accept_payment_proposed_fees_request;
 
+typedef struct wire_cst_list_sdk_event_kind {
+  int32_t *ptr;
+  int32_t len;
+} wire_cst_list_sdk_event_kind;
+
+typedef struct wire_cst_list_String {
+  struct wire_cst_list_prim_u_8_strict **ptr;
+  int32_t len;
+} wire_cst_list_String;
+
+typedef struct wire_cst_list_payment_swap_type {
+  int32_t *ptr;
+  int32_t len;
+} wire_cst_list_payment_swap_type;
+
+typedef struct wire_cst_event_filter {
+  struct wire_cst_list_sdk_event_kind *kinds;
+  struct wire_cst_list_String *payment_ids;
+  struct wire_cst_list_payment_swap_type *swap_types;
+} wire_cst_event_filter;
+
+typedef struct wire_cst_nwc_budget {
+  uint64_t max_amount_sat;
+  uint32_t *renewal_period_sec;
+} wire_cst_nwc_budget;
+
+typedef struct wire_cst_add_nwc_connection_request {
+  struct wire_cst_list_prim_u_8_strict *name;
+  struct wire_cst_nwc_budget *budget;
+} wire_cst_add_nwc_connection_request;
+
 typedef struct wire_cst_backup_request {
   struct wire_cst_list_prim_u_8_strict *backup_path;
 } wire_cst_backup_request;
@@ -79,10 +175,48 @@ typedef struct wire_cst_check_message_request {
   struct wire_cst_list_prim_u_8_strict *signature;
 } wire_cst_check_message_request;
 
+typedef struct wire_cst_prepare_convert_response {
+  struct wire_cst_list_prim_u_8_strict *from_asset_id;
+  struct wire_cst_list_prim_u_8_strict *to_asset_id;
+  uint64_t amount;
+  uint64_t receiver_amount;
+  uint64_t fees_sat;
+} wire_cst_prepare_convert_response;
+
+typedef struct wire_cst_convert_request {
+  struct wire_cst_prepare_convert_response prepare_response;
+  struct wire_cst_list_prim_u_8_strict *spend_limit_override;
+} wire_cst_convert_request;
+
+typedef struct wire_cst_create_recurring_payment_request {
+  struct wire_cst_list_prim_u_8_strict *destination;
+  uint64_t amount_sat;
+  uint32_t interval_sec;
+  uint32_t *start_at;
+  uint64_t *max_fee_sat;
+} wire_cst_create_recurring_payment_request;
+
+typedef struct wire_cst_export_payment_unblinding_data_request {
+  struct wire_cst_list_prim_u_8_strict *payment_id;
+  struct wire_cst_list_prim_u_8_strict *explorer_url;
+} wire_cst_export_payment_unblinding_data_request;
+
+typedef struct wire_cst_export_wallet_request {
+  struct wire_cst_list_prim_u_8_strict *export_path;
+} wire_cst_export_wallet_request;
+
 typedef struct wire_cst_fetch_payment_proposed_fees_request {
   struct wire_cst_list_prim_u_8_strict *swap_id;
 } wire_cst_fetch_payment_proposed_fees_request;
 
+typedef struct wire_cst_get_max_payable_request {
+  int32_t payment_method;
+} wire_cst_get_max_payable_request;
+
+typedef struct wire_cst_get_max_receivable_request {
+  int32_t payment_method;
+} wire_cst_get_max_receivable_request;
+
 typedef struct wire_cst_GetPaymentRequest_PaymentHash {
   struct wire_cst_list_prim_u_8_strict *payment_hash;
 } wire_cst_GetPaymentRequest_PaymentHash;
@@ -101,6 +235,15 @@ typedef struct wire_cst_get_payment_request {
   union GetPaymentRequestKind kind;
 } wire_cst_get_payment_request;
 
+typedef struct wire_cst_get_report_request {
+  int64_t *from_timestamp;
+  int64_t *to_timestamp;
+} wire_cst_get_report_request;
+
+typedef struct wire_cst_import_wallet_request {
+  struct wire_cst_list_prim_u_8_strict *export_path;
+} wire_cst_import_wallet_request;
+
 typedef struct wire_cst_list_payment_type {
   int32_t *ptr;
   int32_t len;
@@ -141,6 +284,15 @@ typedef struct wire_cst_list_payments_request {
   bool *sort_ascending;
 } wire_cst_list_payments_request;
 
+typedef struct wire_cst_list_swaps_request {
+  struct wire_cst_list_payment_swap_type *swap_types;
+  struct wire_cst_list_payment_state *states;
+  int64_t *from_timestamp;
+  int64_t *to_timestamp;
+  uint32_t *offset;
+  uint32_t *limit;
+} wire_cst_list_swaps_request;
+
 typedef struct wire_cst_ln_url_auth_request_data {
   struct wire_cst_list_prim_u_8_strict *k1;
   struct wire_cst_list_prim_u_8_strict *action;
@@ -161,6 +313,7 @@ typedef struct wire_cst_liquid_address_data {
 typedef struct wire_cst_SendDestination_LiquidAddress {
   struct wire_cst_liquid_address_data *address_data;
   struct wire_cst_list_prim_u_8_strict *bip353_address;
+  struct wire_cst_list_prim_u_8_strict *payjoin_url;
 } wire_cst_SendDestination_LiquidAddress;
 
 typedef struct wire_cst_route_hint_hop {
@@ -207,11 +360,6 @@ typedef struct wire_cst_SendDestination_Bolt11 {
   struct wire_cst_list_prim_u_8_strict *bip353_address;
 } wire_cst_SendDestination_Bolt11;
 
-typedef struct wire_cst_list_String {
-  struct wire_cst_list_prim_u_8_strict **ptr;
-  int32_t len;
-} wire_cst_list_String;
-
 typedef struct wire_cst_Amount_Bitcoin {
   uint64_t amount_msat;
 } wire_cst_Amount_Bitcoin;
@@ -268,6 +416,23 @@ typedef struct wire_cst_send_destination {
   union SendDestinationKind kind;
 } wire_cst_send_destination;
 
+typedef struct wire_cst_asset_amount {
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  uint64_t base_units;
+  uint8_t precision;
+  uint8_t display_precision;
+} wire_cst_asset_amount;
+
+typedef struct wire_cst_fee_breakdown {
+  uint64_t service_fee_sat;
+  uint64_t swapper_network_fee_sat;
+  uint64_t lockup_fee_sat;
+  bool lockup_fee_is_estimate;
+  uint64_t claim_fee_sat;
+  bool claim_fee_is_estimate;
+  struct wire_cst_asset_amount *asset_fee;
+} wire_cst_fee_breakdown;
+
 typedef struct wire_cst_ln_url_pay_request_data {
   struct wire_cst_list_prim_u_8_strict *callback;
   uint64_t min_sendable;
@@ -322,9 +487,11 @@ typedef struct wire_cst_success_action {
 typedef struct wire_cst_prepare_ln_url_pay_response {
   struct wire_cst_send_destination destination;
   uint64_t fees_sat;
+  struct wire_cst_fee_breakdown fee_breakdown;
   struct wire_cst_ln_url_pay_request_data data;
   struct wire_cst_list_prim_u_8_strict *comment;
   struct wire_cst_success_action *success_action;
+  struct wire_cst_list_prim_u_8_strict *verify_url;
 } wire_cst_prepare_ln_url_pay_response;
 
 typedef struct wire_cst_ln_url_pay_request {
@@ -354,6 +521,7 @@ typedef struct wire_cst_prepare_pay_onchain_response {
 typedef struct wire_cst_pay_onchain_request {
   struct wire_cst_list_prim_u_8_strict *address;
   struct wire_cst_prepare_pay_onchain_response prepare_response;
+  struct wire_cst_list_prim_u_8_strict *spend_limit_override;
 } wire_cst_pay_onchain_request;
 
 typedef struct wire_cst_prepare_buy_bitcoin_request {
@@ -361,6 +529,12 @@ typedef struct wire_cst_prepare_buy_bitcoin_request {
   uint64_t amount_sat;
 } wire_cst_prepare_buy_bitcoin_request;
 
+typedef struct wire_cst_prepare_convert_request {
+  struct wire_cst_list_prim_u_8_strict *from_asset_id;
+  struct wire_cst_list_prim_u_8_strict *to_asset_id;
+  uint64_t amount;
+} wire_cst_prepare_convert_request;
+
 typedef struct wire_cst_PayAmount_Bitcoin {
   uint64_t receiver_amount_sat;
 } wire_cst_PayAmount_Bitcoin;
@@ -371,9 +545,15 @@ typedef struct wire_cst_PayAmount_Asset {
   bool *estimate_asset_fees;
 } wire_cst_PayAmount_Asset;
 
+typedef struct wire_cst_PayAmount_AssetUnits {
+  struct wire_cst_asset_amount *receiver_amount;
+  bool *estimate_asset_fees;
+} wire_cst_PayAmount_AssetUnits;
+
 typedef union PayAmountKind {
   struct wire_cst_PayAmount_Bitcoin Bitcoin;
   struct wire_cst_PayAmount_Asset Asset;
+  struct wire_cst_PayAmount_AssetUnits AssetUnits;
 } PayAmountKind;
 
 typedef struct wire_cst_pay_amount {
@@ -381,17 +561,26 @@ typedef struct wire_cst_pay_amount {
   union PayAmountKind kind;
 } wire_cst_pay_amount;
 
+typedef struct wire_cst_ln_url_payer_data {
+  struct wire_cst_list_prim_u_8_strict *name;
+  struct wire_cst_list_prim_u_8_strict *pubkey;
+  struct wire_cst_list_prim_u_8_strict *identifier;
+  struct wire_cst_list_prim_u_8_strict *email;
+} wire_cst_ln_url_payer_data;
+
 typedef struct wire_cst_prepare_ln_url_pay_request {
   struct wire_cst_ln_url_pay_request_data data;
   struct wire_cst_pay_amount amount;
   struct wire_cst_list_prim_u_8_strict *bip353_address;
   struct wire_cst_list_prim_u_8_strict *comment;
+  struct wire_cst_ln_url_payer_data *payer_data;
   bool *validate_success_action_url;
 } wire_cst_prepare_ln_url_pay_request;
 
 typedef struct wire_cst_prepare_pay_onchain_request {
   struct wire_cst_pay_amount amount;
   uint32_t *fee_rate_sat_per_vbyte;
+  int32_t *fee_speed;
 } wire_cst_prepare_pay_onchain_request;
 
 typedef struct wire_cst_ReceiveAmount_Bitcoin {
@@ -403,9 +592,14 @@ typedef struct wire_cst_ReceiveAmount_Asset {
   double *payer_amount;
 } wire_cst_ReceiveAmount_Asset;
 
+typedef struct wire_cst_ReceiveAmount_AssetUnits {
+  struct wire_cst_asset_amount *payer_amount;
+} wire_cst_ReceiveAmount_AssetUnits;
+
 typedef union ReceiveAmountKind {
   struct wire_cst_ReceiveAmount_Bitcoin Bitcoin;
   struct wire_cst_ReceiveAmount_Asset Asset;
+  struct wire_cst_ReceiveAmount_AssetUnits AssetUnits;
 } ReceiveAmountKind;
 
 typedef struct wire_cst_receive_amount {
@@ -413,65 +607,301 @@ typedef struct wire_cst_receive_amount {
   union ReceiveAmountKind kind;
 } wire_cst_receive_amount;
 
+typedef struct wire_cst_fiat_amount {
+  double amount;
+  struct wire_cst_list_prim_u_8_strict *currency;
+} wire_cst_fiat_amount;
+
 typedef struct wire_cst_prepare_receive_request {
   int32_t payment_method;
   struct wire_cst_receive_amount *amount;
+  struct wire_cst_fiat_amount *fiat_amount;
+  uint32_t *expiry_secs;
 } wire_cst_prepare_receive_request;
 
+typedef struct wire_cst_prepare_receive_quotes_request {
+  struct wire_cst_receive_amount *amount;
+  struct wire_cst_fiat_amount *fiat_amount;
+} wire_cst_prepare_receive_quotes_request;
+
 typedef struct wire_cst_prepare_refund_request {
   struct wire_cst_list_prim_u_8_strict *swap_address;
   struct wire_cst_list_prim_u_8_strict *refund_address;
-  uint32_t fee_rate_sat_per_vbyte;
+  uint32_t *fee_rate_sat_per_vbyte;
+  int32_t *fee_speed;
 } wire_cst_prepare_refund_request;
 
 typedef struct wire_cst_prepare_send_request {
   struct wire_cst_list_prim_u_8_strict *destination;
   struct wire_cst_pay_amount *amount;
+  struct wire_cst_fiat_amount *fiat_amount;
 } wire_cst_prepare_send_request;
 
+typedef struct wire_cst_queue_payment_request {
+  struct wire_cst_prepare_send_request prepare_request;
+  bool *use_asset_fees;
+  uint64_t *max_fee_sat;
+} wire_cst_queue_payment_request;
+
+typedef struct wire_cst_fiat_conversion {
+  struct wire_cst_fiat_amount fiat_amount;
+  double rate;
+  uint64_t amount_sat;
+} wire_cst_fiat_conversion;
+
 typedef struct wire_cst_prepare_receive_response {
   int32_t payment_method;
   struct wire_cst_receive_amount *amount;
   uint64_t fees_sat;
+  struct wire_cst_fee_breakdown fee_breakdown;
   uint64_t *min_payer_amount_sat;
   uint64_t *max_payer_amount_sat;
   double *swapper_feerate;
+  struct wire_cst_fiat_conversion *fiat_conversion;
+  uint32_t *expiry_secs;
 } wire_cst_prepare_receive_response;
 
 typedef struct wire_cst_receive_payment_request {
   struct wire_cst_prepare_receive_response prepare_response;
   struct wire_cst_list_prim_u_8_strict *description;
   bool *use_description_hash;
+  struct wire_cst_list_prim_u_8_strict *description_hash;
+  struct wire_cst_list_prim_u_8_strict *claim_address;
 } wire_cst_receive_payment_request;
 
 typedef struct wire_cst_refund_request {
   struct wire_cst_list_prim_u_8_strict *swap_address;
   struct wire_cst_list_prim_u_8_strict *refund_address;
-  uint32_t fee_rate_sat_per_vbyte;
+  uint32_t *fee_rate_sat_per_vbyte;
+  int32_t *fee_speed;
 } wire_cst_refund_request;
 
+typedef struct wire_cst_register_lightning_address_request {
+  struct wire_cst_list_prim_u_8_strict *username;
+} wire_cst_register_lightning_address_request;
+
+typedef struct wire_cst_replay_events_request {
+  uint64_t *since_seq;
+  uint32_t *limit;
+} wire_cst_replay_events_request;
+
 typedef struct wire_cst_restore_request {
   struct wire_cst_list_prim_u_8_strict *backup_path;
 } wire_cst_restore_request;
 
+typedef struct wire_cst_schedule_payment_request {
+  struct wire_cst_prepare_send_request prepare_request;
+  bool *use_asset_fees;
+  uint64_t *max_fee_sat;
+  uint32_t execute_at;
+} wire_cst_schedule_payment_request;
+
+typedef struct wire_cst_batch_send_item {
+  struct wire_cst_list_prim_u_8_strict *destination;
+  uint64_t *amount_sat;
+} wire_cst_batch_send_item;
+
+typedef struct wire_cst_list_batch_send_item {
+  struct wire_cst_batch_send_item *ptr;
+  int32_t len;
+} wire_cst_list_batch_send_item;
+
+typedef struct wire_cst_send_batch_request {
+  struct wire_cst_list_batch_send_item *items;
+  uint64_t *max_total_fee_sat;
+} wire_cst_send_batch_request;
+
 typedef struct wire_cst_prepare_send_response {
   struct wire_cst_send_destination destination;
   uint64_t *fees_sat;
+  struct wire_cst_fee_breakdown *fee_breakdown;
   double *estimated_asset_fees;
+  struct wire_cst_asset_amount *estimated_asset_fees_amount;
+  struct wire_cst_fiat_conversion *fiat_conversion;
 } wire_cst_prepare_send_response;
 
 typedef struct wire_cst_send_payment_request {
   struct wire_cst_prepare_send_response prepare_response;
   bool *use_asset_fees;
+  uint64_t *max_fee_sat;
+  struct wire_cst_list_prim_u_8_strict *spend_limit_override;
+  struct wire_cst_list_prim_u_8_strict *idempotency_key;
 } wire_cst_send_payment_request;
 
 typedef struct wire_cst_sign_message_request {
   struct wire_cst_list_prim_u_8_strict *message;
 } wire_cst_sign_message_request;
 
-typedef struct wire_cst_binding_event_listener {
-  struct wire_cst_list_prim_u_8_strict *stream;
-} wire_cst_binding_event_listener;
+typedef struct wire_cst_pset_spend_limit {
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  uint64_t max_amount_sat;
+} wire_cst_pset_spend_limit;
+
+typedef struct wire_cst_list_pset_spend_limit {
+  struct wire_cst_pset_spend_limit *ptr;
+  int32_t len;
+} wire_cst_list_pset_spend_limit;
+
+typedef struct wire_cst_sign_pset_request {
+  struct wire_cst_list_prim_u_8_strict *pset;
+  uint64_t max_fee_sat;
+  struct wire_cst_list_pset_spend_limit *spend_limits;
+} wire_cst_sign_pset_request;
+
+typedef struct wire_cst_sweep_private_key_request {
+  struct wire_cst_list_prim_u_8_strict *key;
+} wire_cst_sweep_private_key_request;
+
+typedef struct wire_cst_sync_request {
+  bool *full;
+} wire_cst_sync_request;
+
+typedef struct wire_cst_BlockchainExplorer_Electrum {
+  struct wire_cst_list_prim_u_8_strict *url;
+} wire_cst_BlockchainExplorer_Electrum;
+
+typedef struct wire_cst_BlockchainExplorer_Esplora {
+  struct wire_cst_list_prim_u_8_strict *url;
+  bool use_waterfalls;
+} wire_cst_BlockchainExplorer_Esplora;
+
+typedef union BlockchainExplorerKind {
+  struct wire_cst_BlockchainExplorer_Electrum Electrum;
+  struct wire_cst_BlockchainExplorer_Esplora Esplora;
+} BlockchainExplorerKind;
+
+typedef struct wire_cst_blockchain_explorer {
+  int32_t tag;
+  union BlockchainExplorerKind kind;
+} wire_cst_blockchain_explorer;
+
+typedef struct wire_cst_external_input_parser {
+  struct wire_cst_list_prim_u_8_strict *provider_id;
+  struct wire_cst_list_prim_u_8_strict *input_regex;
+  struct wire_cst_list_prim_u_8_strict *parser_url;
+} wire_cst_external_input_parser;
+
+typedef struct wire_cst_list_external_input_parser {
+  struct wire_cst_external_input_parser *ptr;
+  int32_t len;
+} wire_cst_list_external_input_parser;
+
+typedef struct wire_cst_asset_metadata {
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  struct wire_cst_list_prim_u_8_strict *name;
+  struct wire_cst_list_prim_u_8_strict *ticker;
+  uint8_t precision;
+  struct wire_cst_list_prim_u_8_strict *fiat_id;
+  bool is_amp;
+} wire_cst_asset_metadata;
+
+typedef struct wire_cst_list_asset_metadata {
+  struct wire_cst_asset_metadata *ptr;
+  int32_t len;
+} wire_cst_list_asset_metadata;
+
+typedef struct wire_cst_event_webhook_config {
+  struct wire_cst_list_prim_u_8_strict *url;
+  struct wire_cst_list_prim_u_8_strict *secret;
+} wire_cst_event_webhook_config;
+
+typedef struct wire_cst_BackupPolicy_CompletedSwaps {
+  uint32_t swaps;
+} wire_cst_BackupPolicy_CompletedSwaps;
+
+typedef struct wire_cst_BackupPolicy_Interval {
+  uint32_t hours;
+} wire_cst_BackupPolicy_Interval;
+
+typedef union BackupPolicyKind {
+  struct wire_cst_BackupPolicy_CompletedSwaps CompletedSwaps;
+  struct wire_cst_BackupPolicy_Interval Interval;
+} BackupPolicyKind;
+
+typedef struct wire_cst_backup_policy {
+  int32_t tag;
+  union BackupPolicyKind kind;
+} wire_cst_backup_policy;
+
+typedef struct wire_cst_SpendLimit_Bitcoin {
+  uint64_t amount_sat;
+} wire_cst_SpendLimit_Bitcoin;
+
+typedef struct wire_cst_SpendLimit_Fiat {
+  struct wire_cst_fiat_amount *amount;
+} wire_cst_SpendLimit_Fiat;
+
+typedef union SpendLimitKind {
+  struct wire_cst_SpendLimit_Bitcoin Bitcoin;
+  struct wire_cst_SpendLimit_Fiat Fiat;
+} SpendLimitKind;
+
+typedef struct wire_cst_spend_limit {
+  int32_t tag;
+  union SpendLimitKind kind;
+} wire_cst_spend_limit;
+
+typedef struct wire_cst_spend_limits {
+  struct wire_cst_spend_limit *per_payment;
+  struct wire_cst_spend_limit *per_day;
+  struct wire_cst_list_prim_u_8_strict *override_secret;
+} wire_cst_spend_limits;
+
+typedef struct wire_cst_simulation_config {
+  uint32_t status_update_delay_sec;
+  double failure_rate;
+} wire_cst_simulation_config;
+
+typedef struct wire_cst_config {
+  struct wire_cst_blockchain_explorer liquid_explorer;
+  struct wire_cst_blockchain_explorer bitcoin_explorer;
+  struct wire_cst_list_prim_u_8_strict *working_dir;
+  struct wire_cst_list_prim_u_8_strict *cache_dir;
+  int32_t network;
+  uint64_t payment_timeout_sec;
+  struct wire_cst_list_prim_u_8_strict *sync_service_url;
+  uint64_t *zero_conf_max_amount_sat;
+  struct wire_cst_list_prim_u_8_strict *breez_api_key;
+  struct wire_cst_list_external_input_parser *external_input_parsers;
+  bool use_default_external_input_parsers;
+  uint32_t *onchain_fee_rate_leeway_sat_per_vbyte;
+  struct wire_cst_list_asset_metadata *asset_metadata;
+  struct wire_cst_list_prim_u_8_strict *sideswap_api_key;
+  struct wire_cst_event_webhook_config *event_webhook;
+  struct wire_cst_list_prim_u_8_strict *fiat_currency;
+  uint32_t *sync_interval_sec;
+  struct wire_cst_list_prim_u_8_strict *bitcoin_explorer_authorization;
+  uint32_t *swapper_max_attempts;
+  struct wire_cst_backup_policy *backup_policy;
+  struct wire_cst_list_prim_u_8_strict *nwc_relay_url;
+  struct wire_cst_spend_limits *spend_limits;
+  struct wire_cst_list_prim_u_8_strict *lnurl_service_url;
+  uint32_t *outgoing_min_confirmations;
+  uint32_t *unconfirmed_tx_alert_sec;
+  uint32_t *chain_service_timeout_sec;
+  uint32_t *swapper_timeout_sec;
+  uint32_t *swapper_ping_interval_sec;
+  struct wire_cst_list_prim_u_8_strict *amp_server_url;
+  struct wire_cst_simulation_config *simulation;
+  bool manual_claim;
+  struct wire_cst_list_prim_u_8_strict *claim_address;
+  struct wire_cst_list_prim_u_8_strict *dns_over_https_url;
+} wire_cst_config;
+
+typedef struct wire_cst_connect_request {
+  struct wire_cst_config config;
+  struct wire_cst_list_prim_u_8_strict *mnemonic;
+  struct wire_cst_list_prim_u_8_strict *passphrase;
+  struct wire_cst_list_prim_u_8_strict *seed;
+  struct wire_cst_list_prim_u_8_strict *xprv;
+} wire_cst_connect_request;
+
+typedef struct wire_cst_process_notification_request {
+  struct wire_cst_connect_request connect_request;
+  struct wire_cst_list_prim_u_8_strict *payload;
+  bool *accept_proposed_fees;
+  uint32_t *timeout_sec;
+} wire_cst_process_notification_request;
 
 typedef struct wire_cst_aes_success_action_data_decrypted {
   struct wire_cst_list_prim_u_8_strict *description;
@@ -496,70 +926,188 @@ typedef struct wire_cst_aes_success_action_data_result {
   union AesSuccessActionDataResultKind kind;
 } wire_cst_aes_success_action_data_result;
 
-typedef struct wire_cst_SuccessActionProcessed_Aes {
-  struct wire_cst_aes_success_action_data_result *result;
-} wire_cst_SuccessActionProcessed_Aes;
-
-typedef struct wire_cst_SuccessActionProcessed_Message {
-  struct wire_cst_message_success_action_data *data;
-} wire_cst_SuccessActionProcessed_Message;
-
-typedef struct wire_cst_SuccessActionProcessed_Url {
-  struct wire_cst_url_success_action_data *data;
-} wire_cst_SuccessActionProcessed_Url;
-
-typedef union SuccessActionProcessedKind {
-  struct wire_cst_SuccessActionProcessed_Aes Aes;
-  struct wire_cst_SuccessActionProcessed_Message Message;
-  struct wire_cst_SuccessActionProcessed_Url Url;
-} SuccessActionProcessedKind;
-
-typedef struct wire_cst_success_action_processed {
-  int32_t tag;
-  union SuccessActionProcessedKind kind;
-} wire_cst_success_action_processed;
-
-typedef struct wire_cst_ln_url_info {
-  struct wire_cst_list_prim_u_8_strict *ln_address;
-  struct wire_cst_list_prim_u_8_strict *lnurl_pay_comment;
-  struct wire_cst_list_prim_u_8_strict *lnurl_pay_domain;
-  struct wire_cst_list_prim_u_8_strict *lnurl_pay_metadata;
-  struct wire_cst_success_action_processed *lnurl_pay_success_action;
-  struct wire_cst_success_action *lnurl_pay_unprocessed_success_action;
-  struct wire_cst_list_prim_u_8_strict *lnurl_withdraw_endpoint;
-} wire_cst_ln_url_info;
+typedef struct wire_cst_amp_account {
+  struct wire_cst_list_prim_u_8_strict *amp_id;
+  struct wire_cst_list_prim_u_8_strict *descriptor;
+} wire_cst_amp_account;
 
-typedef struct wire_cst_PaymentDetails_Lightning {
-  struct wire_cst_list_prim_u_8_strict *swap_id;
-  struct wire_cst_list_prim_u_8_strict *description;
-  uint32_t liquid_expiration_blockheight;
-  struct wire_cst_list_prim_u_8_strict *preimage;
-  struct wire_cst_list_prim_u_8_strict *invoice;
-  struct wire_cst_list_prim_u_8_strict *bolt12_offer;
-  struct wire_cst_list_prim_u_8_strict *payment_hash;
-  struct wire_cst_list_prim_u_8_strict *destination_pubkey;
-  struct wire_cst_ln_url_info *lnurl_info;
-  struct wire_cst_list_prim_u_8_strict *bip353_address;
-  struct wire_cst_list_prim_u_8_strict *claim_tx_id;
-  struct wire_cst_list_prim_u_8_strict *refund_tx_id;
-  uint64_t *refund_tx_amount_sat;
-} wire_cst_PaymentDetails_Lightning;
+typedef struct wire_cst_asset_conversion {
+  struct wire_cst_list_prim_u_8_strict *from_asset_id;
+  uint64_t from_amount;
+  struct wire_cst_list_prim_u_8_strict *to_asset_id;
+  uint64_t to_amount;
+} wire_cst_asset_conversion;
 
 typedef struct wire_cst_asset_info {
   struct wire_cst_list_prim_u_8_strict *name;
   struct wire_cst_list_prim_u_8_strict *ticker;
   double amount;
   double *fees;
+  struct wire_cst_asset_amount asset_amount;
+  struct wire_cst_asset_amount *asset_fees;
 } wire_cst_asset_info;
 
-typedef struct wire_cst_PaymentDetails_Liquid {
-  struct wire_cst_list_prim_u_8_strict *destination;
-  struct wire_cst_list_prim_u_8_strict *description;
-  struct wire_cst_list_prim_u_8_strict *asset_id;
-  struct wire_cst_asset_info *asset_info;
-  struct wire_cst_ln_url_info *lnurl_info;
-  struct wire_cst_list_prim_u_8_strict *bip353_address;
-} wire_cst_PaymentDetails_Liquid;
+typedef struct wire_cst_bitcoin_address_data {
+  struct wire_cst_list_prim_u_8_strict *address;
+  int32_t network;
+  uint64_t *amount_sat;
+  struct wire_cst_list_prim_u_8_strict *label;
+  struct wire_cst_list_prim_u_8_strict *message;
+} wire_cst_bitcoin_address_data;
+
+typedef struct wire_cst_block_info {
+  uint32_t height;
+  struct wire_cst_list_prim_u_8_strict *hash;
+  uint32_t timestamp;
+} wire_cst_block_info;
+
+typedef struct wire_cst_InputType_BitcoinAddress {
+  struct wire_cst_bitcoin_address_data *address;
+} wire_cst_InputType_BitcoinAddress;
+
+typedef struct wire_cst_InputType_LiquidAddress {
+  struct wire_cst_liquid_address_data *address;
+} wire_cst_InputType_LiquidAddress;
+
+typedef struct wire_cst_InputType_Bolt11 {
+  struct wire_cst_ln_invoice *invoice;
+} wire_cst_InputType_Bolt11;
+
+typedef struct wire_cst_InputType_Bolt12Offer {
+  struct wire_cst_ln_offer *offer;
+  struct wire_cst_list_prim_u_8_strict *bip353_address;
+} wire_cst_InputType_Bolt12Offer;
+
+typedef struct wire_cst_InputType_NodeId {
+  struct wire_cst_list_prim_u_8_strict *node_id;
+} wire_cst_InputType_NodeId;
+
+typedef struct wire_cst_InputType_Url {
+  struct wire_cst_list_prim_u_8_strict *url;
+} wire_cst_InputType_Url;
+
+typedef struct wire_cst_InputType_LnUrlPay {
+  struct wire_cst_ln_url_pay_request_data *data;
+  struct wire_cst_list_prim_u_8_strict *bip353_address;
+} wire_cst_InputType_LnUrlPay;
+
+typedef struct wire_cst_InputType_LnUrlWithdraw {
+  struct wire_cst_ln_url_withdraw_request_data *data;
+} wire_cst_InputType_LnUrlWithdraw;
+
+typedef struct wire_cst_InputType_LnUrlAuth {
+  struct wire_cst_ln_url_auth_request_data *data;
+} wire_cst_InputType_LnUrlAuth;
+
+typedef struct wire_cst_ln_url_error_data {
+  struct wire_cst_list_prim_u_8_strict *reason;
+} wire_cst_ln_url_error_data;
+
+typedef struct wire_cst_InputType_LnUrlError {
+  struct wire_cst_ln_url_error_data *data;
+} wire_cst_InputType_LnUrlError;
+
+typedef union InputTypeKind {
+  struct wire_cst_InputType_BitcoinAddress BitcoinAddress;
+  struct wire_cst_InputType_LiquidAddress LiquidAddress;
+  struct wire_cst_InputType_Bolt11 Bolt11;
+  struct wire_cst_InputType_Bolt12Offer Bolt12Offer;
+  struct wire_cst_InputType_NodeId NodeId;
+  struct wire_cst_InputType_Url Url;
+  struct wire_cst_InputType_LnUrlPay LnUrlPay;
+  struct wire_cst_InputType_LnUrlWithdraw LnUrlWithdraw;
+  struct wire_cst_InputType_LnUrlAuth LnUrlAuth;
+  struct wire_cst_InputType_LnUrlError LnUrlError;
+} InputTypeKind;
+
+typedef struct wire_cst_input_type {
+  int32_t tag;
+  union InputTypeKind kind;
+} wire_cst_input_type;
+
+typedef struct wire_cst_lightning_address_info {
+  struct wire_cst_list_prim_u_8_strict *username;
+  struct wire_cst_list_prim_u_8_strict *lightning_address;
+  struct wire_cst_list_prim_u_8_strict *lnurl;
+} wire_cst_lightning_address_info;
+
+typedef struct wire_cst_ln_bolt_12_invoice {
+  struct wire_cst_list_prim_u_8_strict *invoice;
+  struct wire_cst_list_prim_u_8_strict *signing_pubkey;
+  struct wire_cst_list_prim_u_8_strict *payment_hash;
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *payer_note;
+  uint64_t amount_msat;
+  uint64_t created_at;
+  uint64_t expiry;
+  uint32_t payment_path_count;
+} wire_cst_ln_bolt_12_invoice;
+
+typedef struct wire_cst_SuccessActionProcessed_Aes {
+  struct wire_cst_aes_success_action_data_result *result;
+} wire_cst_SuccessActionProcessed_Aes;
+
+typedef struct wire_cst_SuccessActionProcessed_Message {
+  struct wire_cst_message_success_action_data *data;
+} wire_cst_SuccessActionProcessed_Message;
+
+typedef struct wire_cst_SuccessActionProcessed_Url {
+  struct wire_cst_url_success_action_data *data;
+} wire_cst_SuccessActionProcessed_Url;
+
+typedef union SuccessActionProcessedKind {
+  struct wire_cst_SuccessActionProcessed_Aes Aes;
+  struct wire_cst_SuccessActionProcessed_Message Message;
+  struct wire_cst_SuccessActionProcessed_Url Url;
+} SuccessActionProcessedKind;
+
+typedef struct wire_cst_success_action_processed {
+  int32_t tag;
+  union SuccessActionProcessedKind kind;
+} wire_cst_success_action_processed;
+
+typedef struct wire_cst_ln_url_info {
+  struct wire_cst_list_prim_u_8_strict *ln_address;
+  struct wire_cst_list_prim_u_8_strict *lnurl_pay_comment;
+  struct wire_cst_list_prim_u_8_strict *lnurl_pay_domain;
+  struct wire_cst_list_prim_u_8_strict *lnurl_pay_metadata;
+  struct wire_cst_success_action_processed *lnurl_pay_success_action;
+  struct wire_cst_success_action *lnurl_pay_unprocessed_success_action;
+  struct wire_cst_list_prim_u_8_strict *lnurl_withdraw_endpoint;
+  struct wire_cst_list_prim_u_8_strict *lnurl_pay_verify_url;
+  struct wire_cst_list_prim_u_8_strict *lnurl_pay_verified_preimage;
+} wire_cst_ln_url_info;
+
+typedef struct wire_cst_ln_url_pay_error_data {
+  struct wire_cst_list_prim_u_8_strict *payment_hash;
+  struct wire_cst_list_prim_u_8_strict *reason;
+} wire_cst_ln_url_pay_error_data;
+
+typedef struct wire_cst_PaymentDetails_Lightning {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  struct wire_cst_list_prim_u_8_strict *description;
+  uint32_t liquid_expiration_blockheight;
+  struct wire_cst_list_prim_u_8_strict *preimage;
+  struct wire_cst_list_prim_u_8_strict *invoice;
+  struct wire_cst_list_prim_u_8_strict *bolt12_offer;
+  struct wire_cst_list_prim_u_8_strict *payment_hash;
+  struct wire_cst_list_prim_u_8_strict *destination_pubkey;
+  struct wire_cst_ln_url_info *lnurl_info;
+  struct wire_cst_list_prim_u_8_strict *bip353_address;
+  struct wire_cst_list_prim_u_8_strict *claim_tx_id;
+  struct wire_cst_list_prim_u_8_strict *refund_tx_id;
+  uint64_t *refund_tx_amount_sat;
+} wire_cst_PaymentDetails_Lightning;
+
+typedef struct wire_cst_PaymentDetails_Liquid {
+  struct wire_cst_list_prim_u_8_strict *destination;
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  struct wire_cst_asset_info *asset_info;
+  struct wire_cst_ln_url_info *lnurl_info;
+  struct wire_cst_list_prim_u_8_strict *bip353_address;
+  struct wire_cst_asset_conversion *conversion;
+} wire_cst_PaymentDetails_Liquid;
 
 typedef struct wire_cst_PaymentDetails_Bitcoin {
   struct wire_cst_list_prim_u_8_strict *swap_id;
@@ -570,6 +1118,7 @@ typedef struct wire_cst_PaymentDetails_Bitcoin {
   struct wire_cst_list_prim_u_8_strict *claim_tx_id;
   struct wire_cst_list_prim_u_8_strict *refund_tx_id;
   uint64_t *refund_tx_amount_sat;
+  int32_t *buy_bitcoin_provider;
 } wire_cst_PaymentDetails_Bitcoin;
 
 typedef union PaymentDetailsKind {
@@ -594,145 +1143,11 @@ typedef struct wire_cst_payment {
   int32_t payment_type;
   int32_t status;
   struct wire_cst_payment_details details;
+  double *fiat_amount;
+  struct wire_cst_list_prim_u_8_strict *fiat_currency;
+  struct wire_cst_list_prim_u_8_strict *recurring_payment_id;
 } wire_cst_payment;
 
-typedef struct wire_cst_SdkEvent_PaymentFailed {
-  struct wire_cst_payment *details;
-} wire_cst_SdkEvent_PaymentFailed;
-
-typedef struct wire_cst_SdkEvent_PaymentPending {
-  struct wire_cst_payment *details;
-} wire_cst_SdkEvent_PaymentPending;
-
-typedef struct wire_cst_SdkEvent_PaymentRefundable {
-  struct wire_cst_payment *details;
-} wire_cst_SdkEvent_PaymentRefundable;
-
-typedef struct wire_cst_SdkEvent_PaymentRefunded {
-  struct wire_cst_payment *details;
-} wire_cst_SdkEvent_PaymentRefunded;
-
-typedef struct wire_cst_SdkEvent_PaymentRefundPending {
-  struct wire_cst_payment *details;
-} wire_cst_SdkEvent_PaymentRefundPending;
-
-typedef struct wire_cst_SdkEvent_PaymentSucceeded {
-  struct wire_cst_payment *details;
-} wire_cst_SdkEvent_PaymentSucceeded;
-
-typedef struct wire_cst_SdkEvent_PaymentWaitingConfirmation {
-  struct wire_cst_payment *details;
-} wire_cst_SdkEvent_PaymentWaitingConfirmation;
-
-typedef struct wire_cst_SdkEvent_PaymentWaitingFeeAcceptance {
-  struct wire_cst_payment *details;
-} wire_cst_SdkEvent_PaymentWaitingFeeAcceptance;
-
-typedef struct wire_cst_SdkEvent_DataSynced {
-  bool did_pull_new_records;
-} wire_cst_SdkEvent_DataSynced;
-
-typedef union SdkEventKind {
-  struct wire_cst_SdkEvent_PaymentFailed PaymentFailed;
-  struct wire_cst_SdkEvent_PaymentPending PaymentPending;
-  struct wire_cst_SdkEvent_PaymentRefundable PaymentRefundable;
-  struct wire_cst_SdkEvent_PaymentRefunded PaymentRefunded;
-  struct wire_cst_SdkEvent_PaymentRefundPending PaymentRefundPending;
-  struct wire_cst_SdkEvent_PaymentSucceeded PaymentSucceeded;
-  struct wire_cst_SdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
-  struct wire_cst_SdkEvent_PaymentWaitingFeeAcceptance PaymentWaitingFeeAcceptance;
-  struct wire_cst_SdkEvent_DataSynced DataSynced;
-} SdkEventKind;
-
-typedef struct wire_cst_sdk_event {
-  int32_t tag;
-  union SdkEventKind kind;
-} wire_cst_sdk_event;
-
-typedef struct wire_cst_BlockchainExplorer_Electrum {
-  struct wire_cst_list_prim_u_8_strict *url;
-} wire_cst_BlockchainExplorer_Electrum;
-
-typedef struct wire_cst_BlockchainExplorer_Esplora {
-  struct wire_cst_list_prim_u_8_strict *url;
-  bool use_waterfalls;
-} wire_cst_BlockchainExplorer_Esplora;
-
-typedef union BlockchainExplorerKind {
-  struct wire_cst_BlockchainExplorer_Electrum Electrum;
-  struct wire_cst_BlockchainExplorer_Esplora Esplora;
-} BlockchainExplorerKind;
-
-typedef struct wire_cst_blockchain_explorer {
-  int32_t tag;
-  union BlockchainExplorerKind kind;
-} wire_cst_blockchain_explorer;
-
-typedef struct wire_cst_external_input_parser {
-  struct wire_cst_list_prim_u_8_strict *provider_id;
-  struct wire_cst_list_prim_u_8_strict *input_regex;
-  struct wire_cst_list_prim_u_8_strict *parser_url;
-} wire_cst_external_input_parser;
-
-typedef struct wire_cst_list_external_input_parser {
-  struct wire_cst_external_input_parser *ptr;
-  int32_t len;
-} wire_cst_list_external_input_parser;
-
-typedef struct wire_cst_asset_metadata {
-  struct wire_cst_list_prim_u_8_strict *asset_id;
-  struct wire_cst_list_prim_u_8_strict *name;
-  struct wire_cst_list_prim_u_8_strict *ticker;
-  uint8_t precision;
-  struct wire_cst_list_prim_u_8_strict *fiat_id;
-} wire_cst_asset_metadata;
-
-typedef struct wire_cst_list_asset_metadata {
-  struct wire_cst_asset_metadata *ptr;
-  int32_t len;
-} wire_cst_list_asset_metadata;
-
-typedef struct wire_cst_config {
-  struct wire_cst_blockchain_explorer liquid_explorer;
-  struct wire_cst_blockchain_explorer bitcoin_explorer;
-  struct wire_cst_list_prim_u_8_strict *working_dir;
-  struct wire_cst_list_prim_u_8_strict *cache_dir;
-  int32_t network;
-  uint64_t payment_timeout_sec;
-  struct wire_cst_list_prim_u_8_strict *sync_service_url;
-  uint64_t *zero_conf_max_amount_sat;
-  struct wire_cst_list_prim_u_8_strict *breez_api_key;
-  struct wire_cst_list_external_input_parser *external_input_parsers;
-  bool use_default_external_input_parsers;
-  uint32_t *onchain_fee_rate_leeway_sat_per_vbyte;
-  struct wire_cst_list_asset_metadata *asset_metadata;
-  struct wire_cst_list_prim_u_8_strict *sideswap_api_key;
-} wire_cst_config;
-
-typedef struct wire_cst_connect_request {
-  struct wire_cst_config config;
-  struct wire_cst_list_prim_u_8_strict *mnemonic;
-  struct wire_cst_list_prim_u_8_strict *passphrase;
-  struct wire_cst_list_prim_u_8_strict *seed;
-} wire_cst_connect_request;
-
-typedef struct wire_cst_bitcoin_address_data {
-  struct wire_cst_list_prim_u_8_strict *address;
-  int32_t network;
-  uint64_t *amount_sat;
-  struct wire_cst_list_prim_u_8_strict *label;
-  struct wire_cst_list_prim_u_8_strict *message;
-} wire_cst_bitcoin_address_data;
-
-typedef struct wire_cst_ln_url_error_data {
-  struct wire_cst_list_prim_u_8_strict *reason;
-} wire_cst_ln_url_error_data;
-
-typedef struct wire_cst_ln_url_pay_error_data {
-  struct wire_cst_list_prim_u_8_strict *payment_hash;
-  struct wire_cst_list_prim_u_8_strict *reason;
-} wire_cst_ln_url_pay_error_data;
-
 typedef struct wire_cst_ln_url_pay_success_data {
   struct wire_cst_payment payment;
   struct wire_cst_success_action_processed *success_action;
@@ -742,6 +1157,22 @@ typedef struct wire_cst_ln_url_withdraw_success_data {
   struct wire_cst_ln_invoice invoice;
 } wire_cst_ln_url_withdraw_success_data;
 
+typedef struct wire_cst_registered_asset {
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  struct wire_cst_list_prim_u_8_strict *name;
+  struct wire_cst_list_prim_u_8_strict *ticker;
+  uint8_t precision;
+  struct wire_cst_list_prim_u_8_strict *domain;
+  struct wire_cst_list_prim_u_8_strict *icon_url;
+} wire_cst_registered_asset;
+
+typedef struct wire_cst_swap_service_status {
+  bool send_available;
+  bool receive_available;
+  bool chain_send_available;
+  bool chain_receive_available;
+} wire_cst_swap_service_status;
+
 typedef struct wire_cst_symbol {
   struct wire_cst_list_prim_u_8_strict *grapheme;
   struct wire_cst_list_prim_u_8_strict *template_;
@@ -749,12 +1180,22 @@ typedef struct wire_cst_symbol {
   uint32_t *position;
 } wire_cst_symbol;
 
+typedef struct wire_cst_list_asset_amount {
+  struct wire_cst_asset_amount *ptr;
+  int32_t len;
+} wire_cst_list_asset_amount;
+
 typedef struct wire_cst_asset_balance {
   struct wire_cst_list_prim_u_8_strict *asset_id;
   uint64_t balance_sat;
   struct wire_cst_list_prim_u_8_strict *name;
   struct wire_cst_list_prim_u_8_strict *ticker;
   double *balance;
+  uint64_t pending_send_sat;
+  uint64_t pending_receive_sat;
+  double *pending_send;
+  double *pending_receive;
+  struct wire_cst_asset_amount *asset_balance;
 } wire_cst_asset_balance;
 
 typedef struct wire_cst_list_asset_balance {
@@ -762,6 +1203,32 @@ typedef struct wire_cst_list_asset_balance {
   int32_t len;
 } wire_cst_list_asset_balance;
 
+typedef struct wire_cst_asset_report {
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  uint64_t received_amount;
+  uint64_t sent_amount;
+  uint64_t swap_fees_sat;
+  uint64_t onchain_fees_sat;
+  uint32_t received_count;
+  uint32_t sent_count;
+} wire_cst_asset_report;
+
+typedef struct wire_cst_list_asset_report {
+  struct wire_cst_asset_report *ptr;
+  int32_t len;
+} wire_cst_list_asset_report;
+
+typedef struct wire_cst_batch_send_item_result {
+  struct wire_cst_list_prim_u_8_strict *destination;
+  struct wire_cst_payment *payment;
+  struct wire_cst_list_prim_u_8_strict *error;
+} wire_cst_batch_send_item_result;
+
+typedef struct wire_cst_list_batch_send_item_result {
+  struct wire_cst_batch_send_item_result *ptr;
+  int32_t len;
+} wire_cst_list_batch_send_item_result;
+
 typedef struct wire_cst_localized_name {
   struct wire_cst_list_prim_u_8_strict *locale;
   struct wire_cst_list_prim_u_8_strict *name;
@@ -801,13 +1268,205 @@ typedef struct wire_cst_fiat_currency {
 typedef struct wire_cst_list_fiat_currency {
   struct wire_cst_fiat_currency *ptr;
   int32_t len;
-} wire_cst_list_fiat_currency;
+} wire_cst_list_fiat_currency;
+
+typedef struct wire_cst_SdkEvent_PaymentFailed {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentFailed;
+
+typedef struct wire_cst_SdkEvent_PaymentPending {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentPending;
+
+typedef struct wire_cst_SdkEvent_PaymentRefundable {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentRefundable;
+
+typedef struct wire_cst_SdkEvent_PaymentRefunded {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentRefunded;
+
+typedef struct wire_cst_SdkEvent_PaymentRefundPending {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentRefundPending;
+
+typedef struct wire_cst_SdkEvent_PaymentSucceeded {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentSucceeded;
+
+typedef struct wire_cst_SdkEvent_PaymentWaitingConfirmation {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentWaitingConfirmation;
+
+typedef struct wire_cst_SdkEvent_PaymentWaitingFeeAcceptance {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentWaitingFeeAcceptance;
+
+typedef struct wire_cst_SdkEvent_PaymentExpired {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentExpired;
+
+typedef struct wire_cst_SdkEvent_PaymentReorged {
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_PaymentReorged;
+
+typedef struct wire_cst_SdkEvent_SwapUpdated {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  int32_t swap_type;
+  int32_t state;
+} wire_cst_SdkEvent_SwapUpdated;
+
+typedef struct wire_cst_SdkEvent_SwapClaimBroadcast {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  struct wire_cst_list_prim_u_8_strict *tx_id;
+} wire_cst_SdkEvent_SwapClaimBroadcast;
+
+typedef struct wire_cst_SdkEvent_SwapRefundBroadcast {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  struct wire_cst_list_prim_u_8_strict *tx_id;
+} wire_cst_SdkEvent_SwapRefundBroadcast;
+
+typedef struct wire_cst_SdkEvent_TipsUpdated {
+  uint32_t liquid_tip;
+  uint32_t bitcoin_tip;
+} wire_cst_SdkEvent_TipsUpdated;
+
+typedef struct wire_cst_SdkEvent_DataSynced {
+  bool did_pull_new_records;
+} wire_cst_SdkEvent_DataSynced;
+
+typedef struct wire_cst_SdkEvent_ConnectivityChanged {
+  bool is_online;
+} wire_cst_SdkEvent_ConnectivityChanged;
+
+typedef struct wire_cst_SdkEvent_BackupFailed {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkEvent_BackupFailed;
+
+typedef struct wire_cst_SdkEvent_BatchSendCompleted {
+  struct wire_cst_list_batch_send_item_result *results;
+} wire_cst_SdkEvent_BatchSendCompleted;
+
+typedef struct wire_cst_SdkEvent_ScheduledPaymentExecuted {
+  struct wire_cst_list_prim_u_8_strict *id;
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_ScheduledPaymentExecuted;
+
+typedef struct wire_cst_SdkEvent_ScheduledPaymentFailed {
+  struct wire_cst_list_prim_u_8_strict *id;
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkEvent_ScheduledPaymentFailed;
+
+typedef struct wire_cst_SdkEvent_RecurringPaymentExecuted {
+  struct wire_cst_list_prim_u_8_strict *recurring_payment_id;
+  struct wire_cst_payment *details;
+} wire_cst_SdkEvent_RecurringPaymentExecuted;
+
+typedef struct wire_cst_SdkEvent_RecurringPaymentFailed {
+  struct wire_cst_list_prim_u_8_strict *recurring_payment_id;
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkEvent_RecurringPaymentFailed;
+
+typedef struct wire_cst_SdkEvent_TransactionDropped {
+  struct wire_cst_list_prim_u_8_strict *tx_id;
+} wire_cst_SdkEvent_TransactionDropped;
+
+typedef struct wire_cst_SdkEvent_TransactionUnconfirmed {
+  struct wire_cst_list_prim_u_8_strict *tx_id;
+  uint32_t unconfirmed_sec;
+} wire_cst_SdkEvent_TransactionUnconfirmed;
+
+typedef struct wire_cst_SdkEvent_NewLiquidBlock {
+  struct wire_cst_block_info *block;
+} wire_cst_SdkEvent_NewLiquidBlock;
+
+typedef struct wire_cst_SdkEvent_NewBitcoinBlock {
+  struct wire_cst_block_info *block;
+} wire_cst_SdkEvent_NewBitcoinBlock;
+
+typedef union SdkEventKind {
+  struct wire_cst_SdkEvent_PaymentFailed PaymentFailed;
+  struct wire_cst_SdkEvent_PaymentPending PaymentPending;
+  struct wire_cst_SdkEvent_PaymentRefundable PaymentRefundable;
+  struct wire_cst_SdkEvent_PaymentRefunded PaymentRefunded;
+  struct wire_cst_SdkEvent_PaymentRefundPending PaymentRefundPending;
+  struct wire_cst_SdkEvent_PaymentSucceeded PaymentSucceeded;
+  struct wire_cst_SdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
+  struct wire_cst_SdkEvent_PaymentWaitingFeeAcceptance PaymentWaitingFeeAcceptance;
+  struct wire_cst_SdkEvent_PaymentExpired PaymentExpired;
+  struct wire_cst_SdkEvent_PaymentReorged PaymentReorged;
+  struct wire_cst_SdkEvent_SwapUpdated SwapUpdated;
+  struct wire_cst_SdkEvent_SwapClaimBroadcast SwapClaimBroadcast;
+  struct wire_cst_SdkEvent_SwapRefundBroadcast SwapRefundBroadcast;
+  struct wire_cst_SdkEvent_TipsUpdated TipsUpdated;
+  struct wire_cst_SdkEvent_DataSynced DataSynced;
+  struct wire_cst_SdkEvent_ConnectivityChanged ConnectivityChanged;
+  struct wire_cst_SdkEvent_BackupFailed BackupFailed;
+  struct wire_cst_SdkEvent_BatchSendCompleted BatchSendCompleted;
+  struct wire_cst_SdkEvent_ScheduledPaymentExecuted ScheduledPaymentExecuted;
+  struct wire_cst_SdkEvent_ScheduledPaymentFailed ScheduledPaymentFailed;
+  struct wire_cst_SdkEvent_RecurringPaymentExecuted RecurringPaymentExecuted;
+  struct wire_cst_SdkEvent_RecurringPaymentFailed RecurringPaymentFailed;
+  struct wire_cst_SdkEvent_TransactionDropped TransactionDropped;
+  struct wire_cst_SdkEvent_TransactionUnconfirmed TransactionUnconfirmed;
+  struct wire_cst_SdkEvent_NewLiquidBlock NewLiquidBlock;
+  struct wire_cst_SdkEvent_NewBitcoinBlock NewBitcoinBlock;
+} SdkEventKind;
+
+typedef struct wire_cst_sdk_event {
+  int32_t tag;
+  union SdkEventKind kind;
+} wire_cst_sdk_event;
+
+typedef struct wire_cst_journaled_event {
+  uint64_t seq;
+  uint32_t timestamp;
+  struct wire_cst_sdk_event event;
+} wire_cst_journaled_event;
+
+typedef struct wire_cst_list_journaled_event {
+  struct wire_cst_journaled_event *ptr;
+  int32_t len;
+} wire_cst_list_journaled_event;
+
+typedef struct wire_cst_nwc_connection {
+  struct wire_cst_list_prim_u_8_strict *name;
+  struct wire_cst_list_prim_u_8_strict *client_pubkey;
+  struct wire_cst_nwc_budget *budget;
+  uint64_t spent_sat;
+  uint32_t created_at;
+} wire_cst_nwc_connection;
+
+typedef struct wire_cst_list_nwc_connection {
+  struct wire_cst_nwc_connection *ptr;
+  int32_t len;
+} wire_cst_list_nwc_connection;
 
 typedef struct wire_cst_list_payment {
   struct wire_cst_payment *ptr;
   int32_t len;
 } wire_cst_list_payment;
 
+typedef struct wire_cst_list_prepare_receive_response {
+  struct wire_cst_prepare_receive_response *ptr;
+  int32_t len;
+} wire_cst_list_prepare_receive_response;
+
+typedef struct wire_cst_queued_payment {
+  struct wire_cst_list_prim_u_8_strict *id;
+  struct wire_cst_queue_payment_request request;
+  int32_t state;
+  uint32_t attempts;
+  uint32_t created_at;
+  uint32_t next_attempt_at;
+  struct wire_cst_list_prim_u_8_strict *last_error;
+} wire_cst_queued_payment;
+
+typedef struct wire_cst_list_queued_payment {
+  struct wire_cst_queued_payment *ptr;
+  int32_t len;
+} wire_cst_list_queued_payment;
+
 typedef struct wire_cst_rate {
   struct wire_cst_list_prim_u_8_strict *coin;
   double value;
@@ -818,11 +1477,43 @@ typedef struct wire_cst_list_rate {
   int32_t len;
 } wire_cst_list_rate;
 
+typedef struct wire_cst_recurring_payment {
+  struct wire_cst_list_prim_u_8_strict *id;
+  struct wire_cst_list_prim_u_8_strict *destination;
+  uint64_t amount_sat;
+  uint32_t interval_sec;
+  uint64_t *max_fee_sat;
+  uint32_t created_at;
+  uint32_t next_occurrence_at;
+} wire_cst_recurring_payment;
+
+typedef struct wire_cst_list_recurring_payment {
+  struct wire_cst_recurring_payment *ptr;
+  int32_t len;
+} wire_cst_list_recurring_payment;
+
+typedef struct wire_cst_recurring_payment_occurrence {
+  struct wire_cst_list_prim_u_8_strict *recurring_payment_id;
+  uint32_t occurrence_at;
+  uint32_t executed_at;
+  struct wire_cst_list_prim_u_8_strict *tx_id;
+  struct wire_cst_list_prim_u_8_strict *error;
+} wire_cst_recurring_payment_occurrence;
+
+typedef struct wire_cst_list_recurring_payment_occurrence {
+  struct wire_cst_recurring_payment_occurrence *ptr;
+  int32_t len;
+} wire_cst_list_recurring_payment_occurrence;
+
 typedef struct wire_cst_refundable_swap {
   struct wire_cst_list_prim_u_8_strict *swap_address;
   uint32_t timestamp;
   uint64_t amount_sat;
   struct wire_cst_list_prim_u_8_strict *last_refund_tx_id;
+  int32_t state;
+  uint32_t refundable_since;
+  uint32_t refund_eligible_block_height;
+  bool is_cooperative_refund_possible;
 } wire_cst_refundable_swap;
 
 typedef struct wire_cst_list_refundable_swap {
@@ -830,15 +1521,109 @@ typedef struct wire_cst_list_refundable_swap {
   int32_t len;
 } wire_cst_list_refundable_swap;
 
+typedef struct wire_cst_scheduled_payment {
+  struct wire_cst_list_prim_u_8_strict *id;
+  struct wire_cst_schedule_payment_request request;
+  int32_t state;
+  uint32_t attempts;
+  uint32_t created_at;
+  uint32_t next_attempt_at;
+  struct wire_cst_list_prim_u_8_strict *tx_id;
+  struct wire_cst_list_prim_u_8_strict *last_error;
+} wire_cst_scheduled_payment;
+
+typedef struct wire_cst_list_scheduled_payment {
+  struct wire_cst_scheduled_payment *ptr;
+  int32_t len;
+} wire_cst_list_scheduled_payment;
+
+typedef struct wire_cst_service_health {
+  int32_t service;
+  bool is_reachable;
+  uint64_t *latency_ms;
+  struct wire_cst_list_prim_u_8_strict *error;
+} wire_cst_service_health;
+
+typedef struct wire_cst_list_service_health {
+  struct wire_cst_service_health *ptr;
+  int32_t len;
+} wire_cst_list_service_health;
+
+typedef struct wire_cst_swap_info {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  int32_t swap_type;
+  int32_t *direction;
+  int32_t state;
+  uint32_t created_at;
+  uint64_t timeout_block_height;
+  uint64_t payer_amount_sat;
+  uint64_t receiver_amount_sat;
+  struct wire_cst_list_prim_u_8_strict *invoice;
+  struct wire_cst_list_prim_u_8_strict *lockup_address;
+  struct wire_cst_list_prim_u_8_strict *user_lockup_tx_id;
+  struct wire_cst_list_prim_u_8_strict *server_lockup_tx_id;
+  struct wire_cst_list_prim_u_8_strict *claim_address;
+  struct wire_cst_list_prim_u_8_strict *claim_tx_id;
+  struct wire_cst_list_prim_u_8_strict *refund_address;
+  struct wire_cst_list_prim_u_8_strict *refund_tx_id;
+  struct wire_cst_list_prim_u_8_strict *create_response_json;
+} wire_cst_swap_info;
+
+typedef struct wire_cst_list_swap_info {
+  struct wire_cst_swap_info *ptr;
+  int32_t len;
+} wire_cst_list_swap_info;
+
+typedef struct wire_cst_unblinded_tx_out {
+  struct wire_cst_list_prim_u_8_strict *outpoint;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  uint64_t amount;
+  struct wire_cst_list_prim_u_8_strict *asset_blinding_factor;
+  struct wire_cst_list_prim_u_8_strict *amount_blinding_factor;
+} wire_cst_unblinded_tx_out;
+
+typedef struct wire_cst_list_unblinded_tx_out {
+  struct wire_cst_unblinded_tx_out *ptr;
+  int32_t len;
+} wire_cst_list_unblinded_tx_out;
+
+typedef struct wire_cst_add_nwc_connection_response {
+  struct wire_cst_list_prim_u_8_strict *connection_uri;
+} wire_cst_add_nwc_connection_response;
+
 typedef struct wire_cst_blockchain_info {
   uint32_t liquid_tip;
   uint32_t bitcoin_tip;
 } wire_cst_blockchain_info;
 
+typedef struct wire_cst_check_health_response {
+  bool is_degraded;
+  struct wire_cst_list_service_health *services;
+  struct wire_cst_swap_service_status *swap_service_status;
+} wire_cst_check_health_response;
+
 typedef struct wire_cst_check_message_response {
   bool is_valid;
 } wire_cst_check_message_response;
 
+typedef struct wire_cst_DecodedInvoice_Bolt11 {
+  struct wire_cst_ln_invoice *invoice;
+} wire_cst_DecodedInvoice_Bolt11;
+
+typedef struct wire_cst_DecodedInvoice_Bolt12 {
+  struct wire_cst_ln_bolt_12_invoice *invoice;
+} wire_cst_DecodedInvoice_Bolt12;
+
+typedef union DecodedInvoiceKind {
+  struct wire_cst_DecodedInvoice_Bolt11 Bolt11;
+  struct wire_cst_DecodedInvoice_Bolt12 Bolt12;
+} DecodedInvoiceKind;
+
+typedef struct wire_cst_decoded_invoice {
+  int32_t tag;
+  union DecodedInvoiceKind kind;
+} wire_cst_decoded_invoice;
+
 typedef struct wire_cst_wallet_info {
   uint64_t balance_sat;
   uint64_t pending_send_sat;
@@ -853,65 +1638,30 @@ typedef struct wire_cst_get_info_response {
   struct wire_cst_blockchain_info blockchain_info;
 } wire_cst_get_info_response;
 
-typedef struct wire_cst_InputType_BitcoinAddress {
-  struct wire_cst_bitcoin_address_data *address;
-} wire_cst_InputType_BitcoinAddress;
-
-typedef struct wire_cst_InputType_LiquidAddress {
-  struct wire_cst_liquid_address_data *address;
-} wire_cst_InputType_LiquidAddress;
-
-typedef struct wire_cst_InputType_Bolt11 {
-  struct wire_cst_ln_invoice *invoice;
-} wire_cst_InputType_Bolt11;
-
-typedef struct wire_cst_InputType_Bolt12Offer {
-  struct wire_cst_ln_offer *offer;
-  struct wire_cst_list_prim_u_8_strict *bip353_address;
-} wire_cst_InputType_Bolt12Offer;
-
-typedef struct wire_cst_InputType_NodeId {
-  struct wire_cst_list_prim_u_8_strict *node_id;
-} wire_cst_InputType_NodeId;
-
-typedef struct wire_cst_InputType_Url {
-  struct wire_cst_list_prim_u_8_strict *url;
-} wire_cst_InputType_Url;
-
-typedef struct wire_cst_InputType_LnUrlPay {
-  struct wire_cst_ln_url_pay_request_data *data;
-  struct wire_cst_list_prim_u_8_strict *bip353_address;
-} wire_cst_InputType_LnUrlPay;
-
-typedef struct wire_cst_InputType_LnUrlWithdraw {
-  struct wire_cst_ln_url_withdraw_request_data *data;
-} wire_cst_InputType_LnUrlWithdraw;
+typedef struct wire_cst_get_max_payable_response {
+  uint64_t receiver_amount_sat;
+  uint64_t fees_sat;
+} wire_cst_get_max_payable_response;
 
-typedef struct wire_cst_InputType_LnUrlAuth {
-  struct wire_cst_ln_url_auth_request_data *data;
-} wire_cst_InputType_LnUrlAuth;
+typedef struct wire_cst_get_max_receivable_response {
+  uint64_t *payer_amount_sat;
+  uint64_t fees_sat;
+} wire_cst_get_max_receivable_response;
 
-typedef struct wire_cst_InputType_LnUrlError {
-  struct wire_cst_ln_url_error_data *data;
-} wire_cst_InputType_LnUrlError;
+typedef struct wire_cst_get_report_response {
+  struct wire_cst_list_asset_report *assets;
+} wire_cst_get_report_response;
 
-typedef union InputTypeKind {
-  struct wire_cst_InputType_BitcoinAddress BitcoinAddress;
-  struct wire_cst_InputType_LiquidAddress LiquidAddress;
-  struct wire_cst_InputType_Bolt11 Bolt11;
-  struct wire_cst_InputType_Bolt12Offer Bolt12Offer;
-  struct wire_cst_InputType_NodeId NodeId;
-  struct wire_cst_InputType_Url Url;
-  struct wire_cst_InputType_LnUrlPay LnUrlPay;
-  struct wire_cst_InputType_LnUrlWithdraw LnUrlWithdraw;
-  struct wire_cst_InputType_LnUrlAuth LnUrlAuth;
-  struct wire_cst_InputType_LnUrlError LnUrlError;
-} InputTypeKind;
+typedef struct wire_cst_get_wallet_info_response {
+  struct wire_cst_list_prim_u_8_strict *descriptor;
+  struct wire_cst_list_prim_u_8_strict *xpub;
+  struct wire_cst_list_prim_u_8_strict *derivation_path;
+  struct wire_cst_list_prim_u_8_strict *fingerprint;
+} wire_cst_get_wallet_info_response;
 
-typedef struct wire_cst_input_type {
-  int32_t tag;
-  union InputTypeKind kind;
-} wire_cst_input_type;
+typedef struct wire_cst_import_wallet_response {
+  uint32_t imported_records;
+} wire_cst_import_wallet_response;
 
 typedef struct wire_cst_limits {
   uint64_t min_sat;
@@ -1110,6 +1860,8 @@ typedef struct wire_cst_ln_url_withdraw_result {
 typedef struct wire_cst_log_entry {
   struct wire_cst_list_prim_u_8_strict *line;
   struct wire_cst_list_prim_u_8_strict *level;
+  struct wire_cst_list_prim_u_8_strict *target;
+  uint64_t timestamp;
 } wire_cst_log_entry;
 
 typedef struct wire_cst_onchain_payment_limits_response {
@@ -1133,6 +1885,16 @@ typedef struct wire_cst_PaymentError_Generic {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_PaymentError_Generic;
 
+typedef struct wire_cst_PaymentError_FeeLimitExceeded {
+  uint64_t fees_sat;
+  uint64_t max_fee_sat;
+} wire_cst_PaymentError_FeeLimitExceeded;
+
+typedef struct wire_cst_PaymentError_SpendLimitExceeded {
+  uint64_t amount_sat;
+  uint64_t remaining_sat;
+} wire_cst_PaymentError_SpendLimitExceeded;
+
 typedef struct wire_cst_PaymentError_InvalidDescription {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_PaymentError_InvalidDescription;
@@ -1158,17 +1920,24 @@ typedef struct wire_cst_PaymentError_SignerError {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_PaymentError_SignerError;
 
+typedef struct wire_cst_PaymentError_ServiceUnavailable {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_ServiceUnavailable;
+
 typedef union PaymentErrorKind {
   struct wire_cst_PaymentError_AmountMissing AmountMissing;
   struct wire_cst_PaymentError_AssetError AssetError;
   struct wire_cst_PaymentError_InvalidNetwork InvalidNetwork;
   struct wire_cst_PaymentError_Generic Generic;
+  struct wire_cst_PaymentError_FeeLimitExceeded FeeLimitExceeded;
+  struct wire_cst_PaymentError_SpendLimitExceeded SpendLimitExceeded;
   struct wire_cst_PaymentError_InvalidDescription InvalidDescription;
   struct wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
   struct wire_cst_PaymentError_ReceiveError ReceiveError;
   struct wire_cst_PaymentError_Refunded Refunded;
   struct wire_cst_PaymentError_SendError SendError;
   struct wire_cst_PaymentError_SignerError SignerError;
+  struct wire_cst_PaymentError_ServiceUnavailable ServiceUnavailable;
 } PaymentErrorKind;
 
 typedef struct wire_cst_payment_error {
@@ -1176,12 +1945,32 @@ typedef struct wire_cst_payment_error {
   union PaymentErrorKind kind;
 } wire_cst_payment_error;
 
+typedef struct wire_cst_payment_unblinding_data {
+  struct wire_cst_list_prim_u_8_strict *tx_id;
+  struct wire_cst_list_prim_u_8_strict *unblinding_data;
+  struct wire_cst_list_prim_u_8_strict *unblinded_url;
+  struct wire_cst_list_unblinded_tx_out *inputs;
+  struct wire_cst_list_unblinded_tx_out *outputs;
+} wire_cst_payment_unblinding_data;
+
+typedef struct wire_cst_prepare_receive_quotes_response {
+  struct wire_cst_list_prepare_receive_response *quotes;
+} wire_cst_prepare_receive_quotes_response;
+
 typedef struct wire_cst_prepare_refund_response {
   uint32_t tx_vsize;
   uint64_t tx_fee_sat;
+  uint32_t fee_rate_sat_per_vbyte;
+  uint64_t refundable_amount_sat;
+  uint64_t refund_amount_sat;
   struct wire_cst_list_prim_u_8_strict *last_refund_tx_id;
 } wire_cst_prepare_refund_response;
 
+typedef struct wire_cst_process_notification_response {
+  struct wire_cst_payment payment;
+  bool is_resolved;
+} wire_cst_process_notification_response;
+
 typedef struct wire_cst_receive_payment_response {
   struct wire_cst_list_prim_u_8_strict *destination;
 } wire_cst_receive_payment_response;
@@ -1198,16 +1987,32 @@ typedef struct wire_cst_refund_response {
   struct wire_cst_list_prim_u_8_strict *refund_tx_id;
 } wire_cst_refund_response;
 
+typedef struct wire_cst_reusable_bitcoin_address_response {
+  struct wire_cst_list_prim_u_8_strict *address;
+  uint64_t min_deposit_sat;
+  uint64_t max_deposit_sat;
+} wire_cst_reusable_bitcoin_address_response;
+
+typedef struct wire_cst_SdkError_DataDirLocked {
+  struct wire_cst_list_prim_u_8_strict *dir;
+} wire_cst_SdkError_DataDirLocked;
+
 typedef struct wire_cst_SdkError_Generic {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_SdkError_Generic;
 
+typedef struct wire_cst_SdkError_InvalidBackup {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_SdkError_InvalidBackup;
+
 typedef struct wire_cst_SdkError_ServiceConnectivity {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_SdkError_ServiceConnectivity;
 
 typedef union SdkErrorKind {
+  struct wire_cst_SdkError_DataDirLocked DataDirLocked;
   struct wire_cst_SdkError_Generic Generic;
+  struct wire_cst_SdkError_InvalidBackup InvalidBackup;
   struct wire_cst_SdkError_ServiceConnectivity ServiceConnectivity;
 } SdkErrorKind;
 
@@ -1216,21 +2021,58 @@ typedef struct wire_cst_sdk_error {
   union SdkErrorKind kind;
 } wire_cst_sdk_error;
 
+typedef struct wire_cst_send_batch_response {
+  struct wire_cst_list_batch_send_item_result *results;
+} wire_cst_send_batch_response;
+
 typedef struct wire_cst_send_payment_response {
   struct wire_cst_payment payment;
 } wire_cst_send_payment_response;
 
 typedef struct wire_cst_sign_message_response {
   struct wire_cst_list_prim_u_8_strict *signature;
+  struct wire_cst_list_prim_u_8_strict *pubkey;
 } wire_cst_sign_message_response;
 
+typedef struct wire_cst_sign_pset_response {
+  struct wire_cst_list_prim_u_8_strict *pset;
+  uint64_t fees_sat;
+} wire_cst_sign_pset_response;
+
+typedef struct wire_cst_sweep_private_key_response {
+  struct wire_cst_payment payment;
+  uint64_t amount_sat;
+  struct wire_cst_list_asset_amount *asset_amounts;
+} wire_cst_sweep_private_key_response;
+
+typedef struct wire_cst_sync_response {
+  uint32_t new_tx_count;
+  uint32_t updated_swap_count;
+  uint64_t duration_ms;
+} wire_cst_sync_response;
+
+typedef struct wire_cst_validate_address_response {
+  int32_t address_type;
+  int32_t network;
+  bool is_confidential;
+  bool supports_assets;
+} wire_cst_validate_address_response;
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees(int64_t port_,
                                                                                               uintptr_t that,
                                                                                               struct wire_cst_accept_payment_proposed_fees_request *req);
 
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener(int64_t port_,
                                                                                     uintptr_t that,
-                                                                                    struct wire_cst_list_prim_u_8_strict *listener);
+                                                                                    struct wire_cst_list_prim_u_8_strict *listener,
+                                                                                    struct wire_cst_event_filter *filter);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_input_parser(int64_t port_,
+                                                                                  uintptr_t that,
+                                                                                  const void *parse_input);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_nwc_connection(uintptr_t that,
+                                                                                                    struct wire_cst_add_nwc_connection_request *req);
 
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_backup(uintptr_t that,
                                                                                         struct wire_cst_backup_request *req);
@@ -1239,14 +2081,55 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_buy_bitcoin(int
                                                                              uintptr_t that,
                                                                              struct wire_cst_buy_bitcoin_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_cancel_queued_payment(int64_t port_,
+                                                                                       uintptr_t that,
+                                                                                       struct wire_cst_list_prim_u_8_strict *id);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_cancel_recurring_payment(int64_t port_,
+                                                                                          uintptr_t that,
+                                                                                          struct wire_cst_list_prim_u_8_strict *id);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_cancel_scheduled_payment(int64_t port_,
+                                                                                          uintptr_t that,
+                                                                                          struct wire_cst_list_prim_u_8_strict *id);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_check_health(int64_t port_,
+                                                                              uintptr_t that);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_check_lightning_address_available(int64_t port_,
+                                                                                                   uintptr_t that,
+                                                                                                   struct wire_cst_list_prim_u_8_strict *username);
+
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_check_message(uintptr_t that,
                                                                                                struct wire_cst_check_message_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_claim_swap(int64_t port_,
+                                                                            uintptr_t that,
+                                                                            struct wire_cst_list_prim_u_8_strict *swap_id);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_convert(int64_t port_,
+                                                                         uintptr_t that,
+                                                                         struct wire_cst_convert_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_create_recurring_payment(int64_t port_,
+                                                                                          uintptr_t that,
+                                                                                          struct wire_cst_create_recurring_payment_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_lightning_address(int64_t port_,
+                                                                                          uintptr_t that);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect(int64_t port_,
                                                                             uintptr_t that);
 
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payment_unblinding_data(int64_t port_,
+                                                                                                uintptr_t that,
+                                                                                                struct wire_cst_export_payment_unblinding_data_request *req);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_wallet(uintptr_t that,
+                                                                                               struct wire_cst_export_wallet_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(int64_t port_,
                                                                                   uintptr_t that);
 
@@ -1260,23 +2143,68 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_p
                                                                                              uintptr_t that,
                                                                                              struct wire_cst_fetch_payment_proposed_fees_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_registered_asset(int64_t port_,
+                                                                                        uintptr_t that,
+                                                                                        struct wire_cst_list_prim_u_8_strict *asset_id);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_amp_account(uintptr_t that);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(int64_t port_,
                                                                           uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_max_payable(int64_t port_,
+                                                                                 uintptr_t that,
+                                                                                 struct wire_cst_get_max_payable_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_max_receivable(int64_t port_,
+                                                                                    uintptr_t that,
+                                                                                    struct wire_cst_get_max_receivable_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(int64_t port_,
                                                                              uintptr_t that,
                                                                              struct wire_cst_get_payment_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_report(int64_t port_,
+                                                                            uintptr_t that,
+                                                                            struct wire_cst_get_report_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_reusable_bitcoin_address(int64_t port_,
+                                                                                              uintptr_t that);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_wallet_info(uintptr_t that);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_wallet(uintptr_t that,
+                                                                                               struct wire_cst_import_wallet_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(int64_t port_,
                                                                                       uintptr_t that);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_nwc_connections(uintptr_t that);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments(int64_t port_,
                                                                                uintptr_t that,
                                                                                struct wire_cst_list_payments_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_queued_payments(int64_t port_,
+                                                                                      uintptr_t that);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_recurring_payment_occurrences(int64_t port_,
+                                                                                                    uintptr_t that,
+                                                                                                    struct wire_cst_list_prim_u_8_strict *recurring_payment_id);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_recurring_payments(int64_t port_,
+                                                                                         uintptr_t that);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables(int64_t port_,
                                                                                   uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_scheduled_payments(int64_t port_,
+                                                                                         uintptr_t that);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_swaps(int64_t port_,
+                                                                            uintptr_t that,
+                                                                            struct wire_cst_list_swaps_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_auth(int64_t port_,
                                                                             uintptr_t that,
                                                                             struct wire_cst_ln_url_auth_request_data *req_data);
@@ -1301,6 +2229,10 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_buy_bit
                                                                                      uintptr_t that,
                                                                                      struct wire_cst_prepare_buy_bitcoin_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_convert(int64_t port_,
+                                                                                 uintptr_t that,
+                                                                                 struct wire_cst_prepare_convert_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(int64_t port_,
                                                                                    uintptr_t that,
                                                                                    struct wire_cst_prepare_ln_url_pay_request *req);
@@ -1313,6 +2245,10 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive
                                                                                          uintptr_t that,
                                                                                          struct wire_cst_prepare_receive_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_quotes(int64_t port_,
+                                                                                                uintptr_t that,
+                                                                                                struct wire_cst_prepare_receive_quotes_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund(int64_t port_,
                                                                                 uintptr_t that,
                                                                                 struct wire_cst_prepare_refund_request *req);
@@ -1321,6 +2257,10 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_pa
                                                                                       uintptr_t that,
                                                                                       struct wire_cst_prepare_send_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_queue_payment(int64_t port_,
+                                                                               uintptr_t that,
+                                                                               struct wire_cst_queue_payment_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment(int64_t port_,
                                                                                  uintptr_t that,
                                                                                  struct wire_cst_receive_payment_request *req);
@@ -1328,36 +2268,74 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_recommended_fees(int64_t port_,
                                                                                   uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_recover_lightning_address(int64_t port_,
+                                                                                           uintptr_t that);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund(int64_t port_,
                                                                         uintptr_t that,
                                                                         struct wire_cst_refund_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_register_amp_account(int64_t port_,
+                                                                                      uintptr_t that);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_register_lightning_address(int64_t port_,
+                                                                                            uintptr_t that,
+                                                                                            struct wire_cst_register_lightning_address_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_register_webhook(int64_t port_,
                                                                                   uintptr_t that,
                                                                                   struct wire_cst_list_prim_u_8_strict *webhook_url);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_remove_nwc_connection(uintptr_t that,
+                                                                                                       struct wire_cst_list_prim_u_8_strict *name);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_replay_events(int64_t port_,
+                                                                               uintptr_t that,
+                                                                               struct wire_cst_replay_events_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps(int64_t port_,
                                                                                       uintptr_t that);
 
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore(uintptr_t that,
                                                                                          struct wire_cst_restore_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_schedule_payment(int64_t port_,
+                                                                                  uintptr_t that,
+                                                                                  struct wire_cst_schedule_payment_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_batch(int64_t port_,
+                                                                            uintptr_t that,
+                                                                            struct wire_cst_send_batch_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment(int64_t port_,
                                                                               uintptr_t that,
                                                                               struct wire_cst_send_payment_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_broadcaster(int64_t port_,
+                                                                                 uintptr_t that,
+                                                                                 const void *broadcast_liquid_tx,
+                                                                                 const void *broadcast_bitcoin_tx);
+
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sign_message(uintptr_t that,
                                                                                               struct wire_cst_sign_message_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sign_pset(int64_t port_,
+                                                                           uintptr_t that,
+                                                                           struct wire_cst_sign_pset_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sweep_private_key(int64_t port_,
+                                                                                   uintptr_t that,
+                                                                                   struct wire_cst_sweep_private_key_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync(int64_t port_,
-                                                                      uintptr_t that);
+                                                                      uintptr_t that,
+                                                                      struct wire_cst_sync_request *req);
 
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_unregister_webhook(int64_t port_,
                                                                                     uintptr_t that);
 
-void frbgen_breez_liquid_wire__crate__bindings__binding_event_listener_on_event(int64_t port_,
-                                                                                struct wire_cst_binding_event_listener *that,
-                                                                                struct wire_cst_sdk_event *e);
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_verify_backup(uintptr_t that,
+                                                                                               struct wire_cst_restore_request *req);
 
 void frbgen_breez_liquid_wire__crate__bindings__breez_log_stream(int64_t port_,
                                                                  struct wire_cst_list_prim_u_8_strict *s);
@@ -1365,17 +2343,34 @@ void frbgen_breez_liquid_wire__crate__bindings__breez_log_stream(int64_t port_,
 void frbgen_breez_liquid_wire__crate__bindings__connect(int64_t port_,
                                                         struct wire_cst_connect_request *req);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__create_spend_limit_override(struct wire_cst_list_prim_u_8_strict *secret,
+                                                                                            uint32_t expires_at);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__decode_invoice(struct wire_cst_list_prim_u_8_strict *input);
+
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__default_config(int32_t network,
                                                                                struct wire_cst_list_prim_u_8_strict *breez_api_key);
 
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__generate_mnemonic(uint32_t word_count);
+
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__parse_invoice(struct wire_cst_list_prim_u_8_strict *input);
 
+void frbgen_breez_liquid_wire__crate__bindings__process_notification(int64_t port_,
+                                                                     struct wire_cst_process_notification_request *req);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__validate_address(struct wire_cst_list_prim_u_8_strict *address,
+                                                                                 int32_t network);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__validate_mnemonic(struct wire_cst_list_prim_u_8_strict *phrase);
+
 void frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(const void *ptr);
 
 void frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(const void *ptr);
 
 struct wire_cst_accept_payment_proposed_fees_request *frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request(void);
 
+struct wire_cst_add_nwc_connection_request *frbgen_breez_liquid_cst_new_box_autoadd_add_nwc_connection_request(void);
+
 struct wire_cst_aes_success_action_data *frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data(void);
 
 struct wire_cst_aes_success_action_data_decrypted *frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_decrypted(void);
@@ -1384,36 +2379,84 @@ struct wire_cst_aes_success_action_data_result *frbgen_breez_liquid_cst_new_box_
 
 struct wire_cst_amount *frbgen_breez_liquid_cst_new_box_autoadd_amount(void);
 
+struct wire_cst_amp_account *frbgen_breez_liquid_cst_new_box_autoadd_amp_account(void);
+
+struct wire_cst_asset_amount *frbgen_breez_liquid_cst_new_box_autoadd_asset_amount(void);
+
+struct wire_cst_asset_conversion *frbgen_breez_liquid_cst_new_box_autoadd_asset_conversion(void);
+
 struct wire_cst_asset_info *frbgen_breez_liquid_cst_new_box_autoadd_asset_info(void);
 
-struct wire_cst_backup_request *frbgen_breez_liquid_cst_new_box_autoadd_backup_request(void);
+struct wire_cst_backup_policy *frbgen_breez_liquid_cst_new_box_autoadd_backup_policy(void);
 
-struct wire_cst_binding_event_listener *frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listener(void);
+struct wire_cst_backup_request *frbgen_breez_liquid_cst_new_box_autoadd_backup_request(void);
 
 struct wire_cst_bitcoin_address_data *frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data(void);
 
+struct wire_cst_block_info *frbgen_breez_liquid_cst_new_box_autoadd_block_info(void);
+
 bool *frbgen_breez_liquid_cst_new_box_autoadd_bool(bool value);
 
+int32_t *frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_provider(int32_t value);
+
 struct wire_cst_buy_bitcoin_request *frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_request(void);
 
 struct wire_cst_check_message_request *frbgen_breez_liquid_cst_new_box_autoadd_check_message_request(void);
 
 struct wire_cst_connect_request *frbgen_breez_liquid_cst_new_box_autoadd_connect_request(void);
 
+struct wire_cst_convert_request *frbgen_breez_liquid_cst_new_box_autoadd_convert_request(void);
+
+struct wire_cst_create_recurring_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_create_recurring_payment_request(void);
+
+int32_t *frbgen_breez_liquid_cst_new_box_autoadd_direction(int32_t value);
+
+struct wire_cst_event_filter *frbgen_breez_liquid_cst_new_box_autoadd_event_filter(void);
+
+struct wire_cst_event_webhook_config *frbgen_breez_liquid_cst_new_box_autoadd_event_webhook_config(void);
+
+struct wire_cst_export_payment_unblinding_data_request *frbgen_breez_liquid_cst_new_box_autoadd_export_payment_unblinding_data_request(void);
+
+struct wire_cst_export_wallet_request *frbgen_breez_liquid_cst_new_box_autoadd_export_wallet_request(void);
+
 double *frbgen_breez_liquid_cst_new_box_autoadd_f_64(double value);
 
+struct wire_cst_fee_breakdown *frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown(void);
+
+int32_t *frbgen_breez_liquid_cst_new_box_autoadd_fee_speed(int32_t value);
+
 struct wire_cst_fetch_payment_proposed_fees_request *frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request(void);
 
+struct wire_cst_fiat_amount *frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount(void);
+
+struct wire_cst_fiat_conversion *frbgen_breez_liquid_cst_new_box_autoadd_fiat_conversion(void);
+
+struct wire_cst_get_max_payable_request *frbgen_breez_liquid_cst_new_box_autoadd_get_max_payable_request(void);
+
+struct wire_cst_get_max_receivable_request *frbgen_breez_liquid_cst_new_box_autoadd_get_max_receivable_request(void);
+
 struct wire_cst_get_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request(void);
 
+struct wire_cst_get_report_request *frbgen_breez_liquid_cst_new_box_autoadd_get_report_request(void);
+
 int64_t *frbgen_breez_liquid_cst_new_box_autoadd_i_64(int64_t value);
 
+struct wire_cst_import_wallet_request *frbgen_breez_liquid_cst_new_box_autoadd_import_wallet_request(void);
+
+struct wire_cst_input_type *frbgen_breez_liquid_cst_new_box_autoadd_input_type(void);
+
+struct wire_cst_lightning_address_info *frbgen_breez_liquid_cst_new_box_autoadd_lightning_address_info(void);
+
 struct wire_cst_liquid_address_data *frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data(void);
 
 struct wire_cst_list_payment_details *frbgen_breez_liquid_cst_new_box_autoadd_list_payment_details(void);
 
 struct wire_cst_list_payments_request *frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request(void);
 
+struct wire_cst_list_swaps_request *frbgen_breez_liquid_cst_new_box_autoadd_list_swaps_request(void);
+
+struct wire_cst_ln_bolt_12_invoice *frbgen_breez_liquid_cst_new_box_autoadd_ln_bolt_12_invoice(void);
+
 struct wire_cst_ln_invoice *frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice(void);
 
 struct wire_cst_ln_offer *frbgen_breez_liquid_cst_new_box_autoadd_ln_offer(void);
@@ -1432,6 +2475,8 @@ struct wire_cst_ln_url_pay_request_data *frbgen_breez_liquid_cst_new_box_autoadd
 
 struct wire_cst_ln_url_pay_success_data *frbgen_breez_liquid_cst_new_box_autoadd_ln_url_pay_success_data(void);
 
+struct wire_cst_ln_url_payer_data *frbgen_breez_liquid_cst_new_box_autoadd_ln_url_payer_data(void);
+
 struct wire_cst_ln_url_withdraw_request *frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request(void);
 
 struct wire_cst_ln_url_withdraw_request_data *frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request_data(void);
@@ -1440,6 +2485,8 @@ struct wire_cst_ln_url_withdraw_success_data *frbgen_breez_liquid_cst_new_box_au
 
 struct wire_cst_message_success_action_data *frbgen_breez_liquid_cst_new_box_autoadd_message_success_action_data(void);
 
+struct wire_cst_nwc_budget *frbgen_breez_liquid_cst_new_box_autoadd_nwc_budget(void);
+
 struct wire_cst_pay_amount *frbgen_breez_liquid_cst_new_box_autoadd_pay_amount(void);
 
 struct wire_cst_pay_onchain_request *frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request(void);
@@ -1448,36 +2495,66 @@ struct wire_cst_payment *frbgen_breez_liquid_cst_new_box_autoadd_payment(void);
 
 struct wire_cst_prepare_buy_bitcoin_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request(void);
 
+struct wire_cst_prepare_convert_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_convert_request(void);
+
 struct wire_cst_prepare_ln_url_pay_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request(void);
 
 struct wire_cst_prepare_pay_onchain_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request(void);
 
+struct wire_cst_prepare_receive_quotes_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_quotes_request(void);
+
 struct wire_cst_prepare_receive_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_request(void);
 
 struct wire_cst_prepare_refund_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request(void);
 
 struct wire_cst_prepare_send_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request(void);
 
+struct wire_cst_process_notification_request *frbgen_breez_liquid_cst_new_box_autoadd_process_notification_request(void);
+
+struct wire_cst_queue_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_queue_payment_request(void);
+
 struct wire_cst_receive_amount *frbgen_breez_liquid_cst_new_box_autoadd_receive_amount(void);
 
 struct wire_cst_receive_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request(void);
 
 struct wire_cst_refund_request *frbgen_breez_liquid_cst_new_box_autoadd_refund_request(void);
 
+struct wire_cst_register_lightning_address_request *frbgen_breez_liquid_cst_new_box_autoadd_register_lightning_address_request(void);
+
+struct wire_cst_registered_asset *frbgen_breez_liquid_cst_new_box_autoadd_registered_asset(void);
+
+struct wire_cst_replay_events_request *frbgen_breez_liquid_cst_new_box_autoadd_replay_events_request(void);
+
 struct wire_cst_restore_request *frbgen_breez_liquid_cst_new_box_autoadd_restore_request(void);
 
-struct wire_cst_sdk_event *frbgen_breez_liquid_cst_new_box_autoadd_sdk_event(void);
+struct wire_cst_schedule_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_schedule_payment_request(void);
+
+struct wire_cst_send_batch_request *frbgen_breez_liquid_cst_new_box_autoadd_send_batch_request(void);
 
 struct wire_cst_send_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_send_payment_request(void);
 
 struct wire_cst_sign_message_request *frbgen_breez_liquid_cst_new_box_autoadd_sign_message_request(void);
 
+struct wire_cst_sign_pset_request *frbgen_breez_liquid_cst_new_box_autoadd_sign_pset_request(void);
+
+struct wire_cst_simulation_config *frbgen_breez_liquid_cst_new_box_autoadd_simulation_config(void);
+
+struct wire_cst_spend_limit *frbgen_breez_liquid_cst_new_box_autoadd_spend_limit(void);
+
+struct wire_cst_spend_limits *frbgen_breez_liquid_cst_new_box_autoadd_spend_limits(void);
+
 struct wire_cst_success_action *frbgen_breez_liquid_cst_new_box_autoadd_success_action(void);
 
 struct wire_cst_success_action_processed *frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed(void);
 
+struct wire_cst_swap_service_status *frbgen_breez_liquid_cst_new_box_autoadd_swap_service_status(void);
+
+struct wire_cst_sweep_private_key_request *frbgen_breez_liquid_cst_new_box_autoadd_sweep_private_key_request(void);
+
 struct wire_cst_symbol *frbgen_breez_liquid_cst_new_box_autoadd_symbol(void);
 
+struct wire_cst_sync_request *frbgen_breez_liquid_cst_new_box_autoadd_sync_request(void);
+
 uint32_t *frbgen_breez_liquid_cst_new_box_autoadd_u_32(uint32_t value);
 
 uint64_t *frbgen_breez_liquid_cst_new_box_autoadd_u_64(uint64_t value);
@@ -1486,57 +2563,116 @@ struct wire_cst_url_success_action_data *frbgen_breez_liquid_cst_new_box_autoadd
 
 struct wire_cst_list_String *frbgen_breez_liquid_cst_new_list_String(int32_t len);
 
+struct wire_cst_list_asset_amount *frbgen_breez_liquid_cst_new_list_asset_amount(int32_t len);
+
 struct wire_cst_list_asset_balance *frbgen_breez_liquid_cst_new_list_asset_balance(int32_t len);
 
 struct wire_cst_list_asset_metadata *frbgen_breez_liquid_cst_new_list_asset_metadata(int32_t len);
 
+struct wire_cst_list_asset_report *frbgen_breez_liquid_cst_new_list_asset_report(int32_t len);
+
+struct wire_cst_list_batch_send_item *frbgen_breez_liquid_cst_new_list_batch_send_item(int32_t len);
+
+struct wire_cst_list_batch_send_item_result *frbgen_breez_liquid_cst_new_list_batch_send_item_result(int32_t len);
+
 struct wire_cst_list_external_input_parser *frbgen_breez_liquid_cst_new_list_external_input_parser(int32_t len);
 
 struct wire_cst_list_fiat_currency *frbgen_breez_liquid_cst_new_list_fiat_currency(int32_t len);
 
+struct wire_cst_list_journaled_event *frbgen_breez_liquid_cst_new_list_journaled_event(int32_t len);
+
 struct wire_cst_list_ln_offer_blinded_path *frbgen_breez_liquid_cst_new_list_ln_offer_blinded_path(int32_t len);
 
 struct wire_cst_list_locale_overrides *frbgen_breez_liquid_cst_new_list_locale_overrides(int32_t len);
 
 struct wire_cst_list_localized_name *frbgen_breez_liquid_cst_new_list_localized_name(int32_t len);
 
+struct wire_cst_list_nwc_connection *frbgen_breez_liquid_cst_new_list_nwc_connection(int32_t len);
+
 struct wire_cst_list_payment *frbgen_breez_liquid_cst_new_list_payment(int32_t len);
 
 struct wire_cst_list_payment_state *frbgen_breez_liquid_cst_new_list_payment_state(int32_t len);
 
+struct wire_cst_list_payment_swap_type *frbgen_breez_liquid_cst_new_list_payment_swap_type(int32_t len);
+
 struct wire_cst_list_payment_type *frbgen_breez_liquid_cst_new_list_payment_type(int32_t len);
 
+struct wire_cst_list_prepare_receive_response *frbgen_breez_liquid_cst_new_list_prepare_receive_response(int32_t len);
+
 struct wire_cst_list_prim_u_8_strict *frbgen_breez_liquid_cst_new_list_prim_u_8_strict(int32_t len);
 
+struct wire_cst_list_pset_spend_limit *frbgen_breez_liquid_cst_new_list_pset_spend_limit(int32_t len);
+
+struct wire_cst_list_queued_payment *frbgen_breez_liquid_cst_new_list_queued_payment(int32_t len);
+
 struct wire_cst_list_rate *frbgen_breez_liquid_cst_new_list_rate(int32_t len);
 
+struct wire_cst_list_recurring_payment *frbgen_breez_liquid_cst_new_list_recurring_payment(int32_t len);
+
+struct wire_cst_list_recurring_payment_occurrence *frbgen_breez_liquid_cst_new_list_recurring_payment_occurrence(int32_t len);
+
 struct wire_cst_list_refundable_swap *frbgen_breez_liquid_cst_new_list_refundable_swap(int32_t len);
 
 struct wire_cst_list_route_hint *frbgen_breez_liquid_cst_new_list_route_hint(int32_t len);
 
 struct wire_cst_list_route_hint_hop *frbgen_breez_liquid_cst_new_list_route_hint_hop(int32_t len);
+
+struct wire_cst_list_scheduled_payment *frbgen_breez_liquid_cst_new_list_scheduled_payment(int32_t len);
+
+struct wire_cst_list_sdk_event_kind *frbgen_breez_liquid_cst_new_list_sdk_event_kind(int32_t len);
+
+struct wire_cst_list_service_health *frbgen_breez_liquid_cst_new_list_service_health(int32_t len);
+
+struct wire_cst_list_swap_info *frbgen_breez_liquid_cst_new_list_swap_info(int32_t len);
+
+struct wire_cst_list_unblinded_tx_out *frbgen_breez_liquid_cst_new_list_unblinded_tx_out(int32_t len);
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_accept_payment_proposed_fees_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_add_nwc_connection_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_decrypted);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_aes_success_action_data_result);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_amount);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_amp_account);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_asset_amount);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_asset_conversion);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_asset_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_backup_policy);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_backup_request);
-    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listener);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_block_info);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_bool);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_provider);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_check_message_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_connect_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_convert_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_create_recurring_payment_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_direction);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_event_filter);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_event_webhook_config);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_export_payment_unblinding_data_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_export_wallet_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_f_64);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_fee_speed);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_fetch_payment_proposed_fees_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_fiat_conversion);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_get_max_payable_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_get_max_receivable_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_get_report_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_i_64);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_import_wallet_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_input_type);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_lightning_address_info);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_list_payment_details);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_list_swaps_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_bolt_12_invoice);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_offer);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_url_auth_request_data);
@@ -1546,92 +2682,172 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_url_pay_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_url_pay_request_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_url_pay_success_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_url_payer_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_success_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_message_success_action_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_nwc_budget);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_pay_amount);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_buy_bitcoin_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_convert_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_quotes_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_process_notification_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_queue_payment_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_receive_amount);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_receive_payment_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_refund_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_register_lightning_address_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_registered_asset);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_replay_events_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_restore_request);
-    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sdk_event);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_schedule_payment_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_send_batch_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_send_payment_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sign_message_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sign_pset_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_simulation_config);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_spend_limit);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_spend_limits);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_success_action);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_swap_service_status);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sweep_private_key_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_symbol);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_sync_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_u_32);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_u_64);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_url_success_action_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_String);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_asset_amount);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_asset_balance);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_asset_metadata);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_asset_report);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_batch_send_item);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_batch_send_item_result);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_external_input_parser);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_fiat_currency);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_journaled_event);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_ln_offer_blinded_path);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_locale_overrides);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_localized_name);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_nwc_connection);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_state);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_swap_type);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_type);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_prepare_receive_response);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_prim_u_8_strict);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_pset_spend_limit);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_queued_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_rate);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_recurring_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_recurring_payment_occurrence);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_refundable_swap);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_route_hint);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_route_hint_hop);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_scheduled_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_sdk_event_kind);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_service_health);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_swap_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_unblinded_tx_out);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_accept_payment_proposed_fees);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_input_parser);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_nwc_connection);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_backup);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_buy_bitcoin);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_cancel_queued_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_cancel_recurring_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_cancel_scheduled_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_check_health);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_check_lightning_address_available);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_check_message);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_claim_swap);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_convert);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_create_recurring_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_lightning_address);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payment_unblinding_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_wallet);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_registered_asset);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_amp_account);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_max_payable);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_max_receivable);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_report);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_reusable_bitcoin_address);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_wallet_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_wallet);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_nwc_connections);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_queued_payments);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_recurring_payment_occurrences);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_recurring_payments);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_scheduled_payments);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_swaps);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_auth);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parse);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_buy_bitcoin);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_convert);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_quotes);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_queue_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_recommended_fees);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_recover_lightning_address);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_register_amp_account);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_register_lightning_address);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_register_webhook);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_remove_nwc_connection);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_replay_events);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_schedule_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_batch);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_broadcaster);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sign_message);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sign_pset);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sweep_private_key);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_unregister_webhook);
-    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__binding_event_listener_on_event);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_verify_backup);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__breez_log_stream);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__connect);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__create_spend_limit_override);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__decode_invoice);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__default_config);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__generate_mnemonic);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__parse_invoice);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__process_notification);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__validate_address);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__validate_mnemonic);
     dummy_var ^= ((int64_t) (void*) store_dart_post_cobject);
     return dummy_var;
 }
@@ -0,0 +1,30 @@
+package technology.breez.liquid
+
+import breez_sdk_liquid.BindingLiquidSdk
+import breez_sdk_liquid.EventFilter
+import breez_sdk_liquid.EventListener
+import breez_sdk_liquid.SdkEvent
+import kotlinx.coroutines.channels.Channel
+import kotlinx.coroutines.channels.awaitClose
+import kotlinx.coroutines.flow.Flow
+import kotlinx.coroutines.flow.buffer
+import kotlinx.coroutines.flow.callbackFlow
+
+/**
+ * Emits the SDK events as a [Flow], so shared wallet logic can collect them the same way on
+ * Android and iOS. The event listener is added when the flow is collected and removed once the
+ * collection is cancelled. Events are buffered without bound, so a slow collector doesn't miss any.
+ */
+fun BindingLiquidSdk.events(filter: EventFilter? = null): Flow<SdkEvent> =
+    callbackFlow {
+        val listenerId =
+            addEventListener(
+                object : EventListener {
+                    override fun onEvent(e: SdkEvent) {
+                        trySend(e)
+                    }
+                },
+                filter,
+            )
+        awaitClose { removeEventListener(listenerId) }
+    }.buffer(Channel.UNLIMITED)
@@ -72,6 +72,22 @@ impl<'a> ModuleGenerator<'a> {
     }
 }
 
+/// Generates the module base class used without the New Architecture, declaring
+/// the methods of the TurboModule spec which the module overrides
+#[derive(Template)]
+#[template(syntax = "rn", escape = "none", path = "spec.kt")]
+#[allow(dead_code)]
+pub struct SpecGenerator<'a> {
+    config: RNConfig,
+    ci: &'a ComponentInterface,
+}
+
+impl<'a> SpecGenerator<'a> {
+    pub fn new(config: RNConfig, ci: &'a ComponentInterface) -> Self {
+        Self { config, ci }
+    }
+}
+
 pub mod filters {
     use heck::*;
     use uniffi_bindgen::backend::CodeOracle;
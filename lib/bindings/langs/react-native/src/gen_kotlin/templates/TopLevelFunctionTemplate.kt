@@ -1,6 +1,6 @@
     
     @ReactMethod
-    fun {{ func.name()|fn_name|unquote }}({%- call kt::arg_list_decl(func) -%}promise: Promise) {
+    override fun {{ func.name()|fn_name|unquote }}({%- call kt::arg_list_decl(func) -%}promise: Promise) {
         executor.execute {
             try {
 {%- for arg in func.arguments() -%}
@@ -9,7 +9,7 @@ import java.util.concurrent.ExecutorService
 import java.util.concurrent.Executors
 {% import "macros.kt" as kt %}
 
-class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContextBaseJavaModule(reactContext) {
+class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : BreezSDKLiquidSpec(reactContext) {
     private lateinit var executor: ExecutorService
     private var bindingLiquidSdk: BindingLiquidSdk? = null
 
@@ -50,10 +50,10 @@ class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContext
     }
 
     @ReactMethod
-    fun addListener(eventName: String) {}
+    override fun addListener(eventName: String) {}
 
     @ReactMethod
-    fun removeListeners(count: Int) {}
+    override fun removeListeners(count: Int) {}
 
     {% let obj_interface = "" -%}
     {% for func in ci.function_definitions() %}
@@ -61,8 +61,22 @@ class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContext
     {% include "TopLevelFunctionTemplate.kt" %}
     {% endif -%}
     {%- endfor %}
+    @ReactMethod(isBlockingSynchronousMethod = true)
+    override fun parseInvoiceSync(input: String): WritableMap =
+        try {
+            readableMapOf("invoice" to readableMapOf(parseInvoice(input))) as WritableMap
+        } catch (e: Exception) {
+            readableMapOf(
+                "error" to
+                    readableMapOf(
+                        "code" to e.javaClass.simpleName.replace("Exception", "Error"),
+                        "message" to e.message,
+                    ),
+            ) as WritableMap
+        }
+
     @ReactMethod
-    fun setLogger(promise: Promise) {
+    override fun setLogger(promise: Promise) {
         executor.execute {
             try {
                 val emitter = reactApplicationContext.getJSModule(RCTDeviceEventEmitter::class.java)
@@ -77,7 +91,7 @@ class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContext
     }
 
     @ReactMethod
-    fun connect(req: ReadableMap, promise: Promise) {
+    override fun connect(req: ReadableMap, promise: Promise) {
         if (bindingLiquidSdk != null) {
             promise.reject("Generic", "Already initialized")
             return
@@ -98,7 +112,7 @@ class BreezSDKLiquidModule(reactContext: ReactApplicationContext) : ReactContext
     }
 
     @ReactMethod
-    fun addEventListener(promise: Promise) {
+    override fun addEventListener(promise: Promise) {
         executor.execute {
             try {
                 val emitter = reactApplicationContext.getJSModule(RCTDeviceEventEmitter::class.java)
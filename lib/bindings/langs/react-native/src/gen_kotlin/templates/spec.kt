@@ -0,0 +1,35 @@
+package com.breezsdkliquid
+
+import com.facebook.react.bridge.*
+{% import "macros.kt" as kt %}
+
+abstract class BreezSDKLiquidSpec(reactContext: ReactApplicationContext) : ReactContextBaseJavaModule(reactContext) {
+    abstract fun addListener(eventName: String)
+
+    abstract fun removeListeners(count: Int)
+    {% for func in ci.function_definitions() %}
+    {%- if func.name()|ignored_function == false %}
+    abstract fun {{ func.name()|fn_name|unquote }}({%- call kt::arg_list_decl(func) -%}promise: Promise)
+    {% endif -%}
+    {%- endfor %}
+    abstract fun parseInvoiceSync(input: String): WritableMap
+
+    abstract fun setLogger(promise: Promise)
+
+    abstract fun connect(req: ReadableMap, promise: Promise)
+
+    abstract fun addEventListener(promise: Promise)
+    {%- for type_ in ci.iter_types() %}
+    {%- match type_ %}
+    {%- when Type::Object ( name ) %}
+    {%- let obj = ci.get_object_definition(name).unwrap() %}
+    {%- for func in obj.methods() %}
+    {%- if func.name()|ignored_function == false %}
+
+    abstract fun {{ func.name()|fn_name|unquote }}({%- call kt::arg_list_decl(func) -%}promise: Promise)
+    {%- endif %}
+    {%- endfor %}
+    {%- else %}
+    {%- endmatch %}
+    {%- endfor %}
+}
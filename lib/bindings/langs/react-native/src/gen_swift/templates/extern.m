@@ -1,5 +1,8 @@
 #import <React/RCTBridgeModule.h>
 #import <React/RCTEventEmitter.h>
+#ifdef RCT_NEW_ARCH_ENABLED
+#import <RNBreezSDKLiquidSpec/RNBreezSDKLiquidSpec.h>
+#endif
 
 @interface RCT_EXTERN_MODULE(RNBreezSDKLiquid, RCTEventEmitter)
 {% for func in ci.function_definitions() %}
@@ -7,6 +10,10 @@
 {% include "ExternFunctionTemplate.m" %}
 {% endif %}
 {%- endfor %}  
+RCT_EXTERN__BLOCKING_SYNCHRONOUS_METHOD(
+    parseInvoiceSync: (NSString*)input
+)
+
 RCT_EXTERN_METHOD(
     setLogger: (RCTPromiseResolveBlock)resolve
     reject: (RCTPromiseRejectBlock)reject
@@ -36,3 +43,15 @@ RCT_EXTERN_METHOD(
 {%- endmatch -%}    
 {%- endfor %}
 @end
+
+#ifdef RCT_NEW_ARCH_ENABLED
+@interface RNBreezSDKLiquid (TurboModule) <NativeBreezSDKLiquidSpec>
+@end
+
+@implementation RNBreezSDKLiquid (TurboModule)
+- (std::shared_ptr<facebook::react::TurboModule>)getTurboModule:(const facebook::react::ObjCTurboModule::InitParams &)params
+{
+    return std::make_shared<facebook::react::NativeBreezSDKLiquidSpecJSI>(params);
+}
+@end
+#endif
@@ -78,6 +78,17 @@ class RNBreezSDKLiquid: RCTEventEmitter {
     {% include "TopLevelFunctionTemplate.swift" %}
     {% endif -%}
     {%- endfor %}  
+    @objc(parseInvoiceSync:)
+    func parseInvoiceSync(_ input: String) -> NSDictionary {
+        do {
+            let res = try BreezSDKLiquid.parseInvoice(input: input)
+            return ["invoice": BreezSDKLiquidMapper.dictionaryOf(lnInvoice: res)]
+        } catch let err {
+            let (errorName, message) = errorInfo(err: err)
+            return ["error": ["code": errorName, "message": message]]
+        }
+    }
+
     @objc(setLogger:reject:)
     func setLogger(_ resolve: @escaping RCTPromiseResolveBlock, reject: @escaping RCTPromiseRejectBlock) -> Void {
         do {
@@ -121,6 +132,11 @@ class RNBreezSDKLiquid: RCTEventEmitter {
     {%- include "Objects.swift" %}
     
     func rejectErr(err: Error, reject: @escaping RCTPromiseRejectBlock) {
+        let (errorName, message) = errorInfo(err: err)
+        reject(errorName, message, err)
+    }
+
+    func errorInfo(err: Error) -> (String, String) {
         var errorName = "Generic"
         var message = "\(err)"
         if let errAssociated = Mirror(reflecting: err).children.first {
@@ -129,7 +145,7 @@ class RNBreezSDKLiquid: RCTEventEmitter {
                 message = associatedMessage.value as! String
             }
         }
-        reject(errorName, message, err)
+        return (errorName, message)
     }
 }
 
@@ -49,6 +49,20 @@ impl<'a> ModuleGenerator<'a> {
     }
 }
 
+#[derive(Template)]
+#[template(syntax = "rn", escape = "none", path = "NativeModuleSpec.ts")]
+#[allow(dead_code)]
+pub struct SpecGenerator<'a> {
+    config: RNConfig,
+    ci: &'a ComponentInterface,
+}
+
+impl<'a> SpecGenerator<'a> {
+    pub fn new(config: RNConfig, ci: &'a ComponentInterface) -> Self {
+        Self { config, ci }
+    }
+}
+
 fn fixup_keyword(name: String, append: String) -> String {
     if KEYWORDS.contains(&name) {
         format!("{name}{append}")
@@ -235,7 +249,74 @@ pub mod filters {
         res
     }
 
+    /// Get the type of a value in the TurboModule spec. Records, non-flat enums and collections
+    /// are passed as plain objects, as the codegen doesn't know their types.
+    ///
+    /// The types match the arguments of the native module methods, which the Android module
+    /// overrides from the spec generated by the codegen. Optional values are passed as their
+    /// default value, see `default_value`.
+    pub fn spec_type_name(
+        t: &TypeIdentifier,
+        ci: &ComponentInterface,
+    ) -> Result<String, askama::Error> {
+        let res = match t {
+            Type::UInt8 | Type::Int8 | Type::UInt16 | Type::Int16 | Type::UInt32 | Type::Int32 => {
+                "Int32".to_string()
+            }
+            Type::UInt64 | Type::Int64 | Type::Float32 | Type::Float64 => "Double".to_string(),
+            Type::Boolean => "boolean".to_string(),
+            Type::String => "string".to_string(),
+            Type::Enum(name) if ci.get_enum_definition(name).is_some_and(|e| e.is_flat()) => {
+                "string".to_string()
+            }
+            Type::Optional(inner) => spec_type_name(inner, ci)?,
+            Type::Sequence(inner) => format!("Array<{}>", spec_type_name(inner, ci)?),
+            _ => "Object".to_string(),
+        };
+        Ok(res)
+    }
+
     pub fn ignored_function(nm: &str) -> Result<bool, askama::Error> {
         Ok(IGNORED_FUNCTIONS.contains(nm))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::filters::spec_type_name;
+    use super::*;
+
+    const UDL: &str = r#"
+        namespace test {};
+        enum Network { "Mainnet", "Testnet" };
+        [Enum]
+        interface Amount {
+            Bitcoin(u64 amount_sat);
+        };
+        dictionary Payment { u64 amount_sat; };
+    "#;
+
+    #[test]
+    fn test_spec_type_name() {
+        let ci = ComponentInterface::from_webidl(UDL).unwrap();
+        let spec_type = |t: Type| spec_type_name(&t, &ci).unwrap();
+
+        assert_eq!(spec_type(Type::UInt32), "Int32");
+        assert_eq!(spec_type(Type::UInt64), "Double");
+        assert_eq!(spec_type(Type::Boolean), "boolean");
+        assert_eq!(spec_type(Type::String), "string");
+        // Flat enums are passed by their variant name
+        assert_eq!(spec_type(Type::Enum("Network".to_string())), "string");
+        // Optional values are passed as their default value
+        assert_eq!(
+            spec_type(Type::Optional(Box::new(Type::Enum("Network".to_string())))),
+            "string"
+        );
+        assert_eq!(spec_type(Type::Enum("Amount".to_string())), "Object");
+        assert_eq!(spec_type(Type::Record("Payment".to_string())), "Object");
+        assert_eq!(
+            spec_type(Type::Sequence(Box::new(Type::String))),
+            "Array<string>"
+        );
+    }
+}
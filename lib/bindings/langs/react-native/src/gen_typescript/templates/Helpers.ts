@@ -15,6 +15,16 @@ export const addEventListener = async (listener: EventListener): Promise<string>
     return response
 }
 
+export const parseInvoiceSync = (input: string): LnInvoice => {
+    const response = BreezSDKLiquid.parseInvoiceSync(input)
+    if (response.error != null) {
+        const error = new Error(response.error.message)
+        error.name = response.error.code
+        throw error
+    }
+    return response.invoice
+}
+
 export const setLogger = async (logger: Logger): Promise<EmitterSubscription> => {
     const subscription = BreezSDKLiquidEmitter.addListener("breezSdkLiquidLog", logger)
 
@@ -0,0 +1,33 @@
+import type { TurboModule } from "react-native"
+import { TurboModuleRegistry } from "react-native"
+import type { Double, Int32 } from "react-native/Libraries/Types/CodegenTypes"
+
+// The spec of the TurboModule, read by the React Native codegen. Values are passed as plain
+// objects, typed by the wrappers in index.ts
+export interface Spec extends TurboModule {
+    connect(req: Object): Promise<Object>
+    addEventListener(): Promise<string>
+    setLogger(): Promise<Object>
+    parseInvoiceSync(input: string): Object
+    addListener(eventName: string): void
+    removeListeners(count: Int32): void
+{%- for func in ci.function_definitions() %}
+{%- if func.name()|ignored_function == false %}
+    {% include "SpecFunctionTemplate.ts" %}
+{%- endif %}
+{%- endfor %}
+{%- for type_ in ci.iter_types() %}
+{%- match type_ %}
+{%- when Type::Object ( name ) %}
+{%- let obj = ci.get_object_definition(name).unwrap() %}
+{%- for func in obj.methods() %}
+{%- if func.name()|ignored_function == false %}
+    {% include "SpecFunctionTemplate.ts" %}
+{%- endif %}
+{%- endfor %}
+{%- else %}
+{%- endmatch %}
+{%- endfor %}
+}
+
+export default TurboModuleRegistry.get<Spec>("RNBreezSDKLiquid")
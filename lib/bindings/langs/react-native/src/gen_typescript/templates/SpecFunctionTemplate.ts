@@ -0,0 +1,6 @@
+{{ func.name()|fn_name }}({%- for arg in func.arguments() -%}{{ arg.name()|var_name }}: {{ arg.type_()|spec_type_name(ci) }}{%- if !loop.last %}, {% endif -%}{%- endfor -%}): Promise<
+{%- match func.return_type() -%}
+{%- when Some with (return_type) -%}{{ return_type|spec_type_name(ci) }}
+{%- when None -%}Object
+{%- endmatch -%}
+>
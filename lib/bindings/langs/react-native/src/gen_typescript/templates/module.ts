@@ -1,4 +1,5 @@
 import { NativeModules, Platform, EmitterSubscription, NativeEventEmitter } from "react-native"
+import NativeBreezSDKLiquid from "./NativeBreezSDKLiquid"
 
 const LINKING_ERROR =
     `The package 'react-native-breez-sdk-liquid' doesn't seem to be linked. Make sure: \n\n` +
@@ -6,7 +7,10 @@ const LINKING_ERROR =
     "- You rebuilt the app after installing the package\n" +
     "- You are not using Expo managed workflow\n"
 
-const BreezSDKLiquid = NativeModules.RNBreezSDKLiquid
+// The TurboModule is used when the New Architecture is enabled, the bridge module otherwise
+const BreezSDKLiquid = NativeBreezSDKLiquid
+    ? NativeBreezSDKLiquid
+    : NativeModules.RNBreezSDKLiquid
     ? NativeModules.RNBreezSDKLiquid
     : new Proxy(
           {},
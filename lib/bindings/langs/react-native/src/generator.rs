@@ -79,6 +79,31 @@ impl RNBindingGenerator {
         Ok(())
     }
 
+    fn write_kotlin_spec_bindings(
+        &self,
+        ci: &ComponentInterface,
+        config: RNConfig,
+        base_output_path: &Utf8Path,
+    ) -> Result<()> {
+        // Create the path
+        let output_path =
+            base_output_path.join(Utf8Path::new("android/src/oldarch/com/breezsdkliquid"));
+        // Generate and write the old architecture spec to file
+        let bindings_output = self::gen_kotlin::SpecGenerator::new(config.clone(), ci)
+            .render()
+            .map_err(anyhow::Error::new)?;
+        let bindings_file = self
+            .write_bindings(
+                &bindings_output,
+                &output_path,
+                Utf8Path::new("BreezSDKLiquidSpec.kt"),
+            )
+            .unwrap();
+        // Lint binding
+        self.lint_kotlin_bindings(&bindings_file);
+        Ok(())
+    }
+
     fn lint_kotlin_bindings(&self, bindings_file: &Utf8PathBuf) {
         if let Err(e) = Command::new("ktlint").arg("-F").arg(bindings_file).output() {
             println!(
@@ -129,7 +154,7 @@ impl RNBindingGenerator {
             .write_bindings(
                 &bindings_output,
                 &output_path,
-                Utf8Path::new("RNBreezSDKLiquid.m"),
+                Utf8Path::new("RNBreezSDKLiquid.mm"),
             )
             .unwrap();
         // Lint binding
@@ -194,6 +219,30 @@ impl RNBindingGenerator {
         Ok(())
     }
 
+    fn write_typescript_spec_bindings(
+        &self,
+        ci: &ComponentInterface,
+        config: RNConfig,
+        base_output_path: &Utf8Path,
+    ) -> Result<()> {
+        // Create the path
+        let output_path = base_output_path.join(Utf8Path::new("ts/src"));
+        // Generate and write the TurboModule spec to file
+        let bindings_output = self::gen_typescript::SpecGenerator::new(config.clone(), ci)
+            .render()
+            .map_err(anyhow::Error::new)?;
+        let bindings_file = self
+            .write_bindings(
+                &bindings_output,
+                &output_path,
+                Utf8Path::new("NativeBreezSDKLiquid.ts"),
+            )
+            .unwrap();
+        // Lint binding
+        self.lint_typescript_bindings(&bindings_file);
+        Ok(())
+    }
+
     fn lint_typescript_bindings(&self, bindings_file: &Utf8PathBuf) {
         if let Err(e) = Command::new("tslint")
             .arg("--fix")
@@ -253,6 +302,7 @@ impl BindingGenerator for RNBindingGenerator {
         // generate kotlin
         self.write_kotlin_mapper_bindings(&ci, config.clone(), out_dir)?;
         self.write_kotlin_module_bindings(&ci, config.clone(), out_dir)?;
+        self.write_kotlin_spec_bindings(&ci, config.clone(), out_dir)?;
 
         // generate ios
         self.write_swift_mapper_bindings(&ci, config.clone(), out_dir)?;
@@ -261,6 +311,7 @@ impl BindingGenerator for RNBindingGenerator {
 
         // generate typescript
         self.write_typescript_bindings(&ci, config.clone(), out_dir)?;
+        self.write_typescript_spec_bindings(&ci, config.clone(), out_dir)?;
         Ok(())
     }
 }
@@ -0,0 +1,223 @@
+//! Async callback interfaces, for foreign implementations that need to await network or hardware
+//! operations. Only available with uniffi 0.28, as older versions don't support async callbacks.
+use std::{future::Future, sync::Arc};
+
+use breez_sdk_liquid::prelude::*;
+use log::warn;
+use tokio::sync::mpsc;
+
+use crate::{connect_with_signer, rt, BindingLiquidSdk};
+
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait AsyncEventListener: Send + Sync {
+    async fn on_event(&self, e: SdkEvent);
+}
+
+/// Queues the SDK events for an [AsyncEventListener], so that emitting an event doesn't wait
+/// for the foreign listener and no event is dropped while it is busy
+struct AsyncEventListenerAdapter {
+    sender: mpsc::UnboundedSender<SdkEvent>,
+}
+
+impl EventListener for AsyncEventListenerAdapter {
+    fn on_event(&self, e: SdkEvent) {
+        if let Err(err) = self.sender.send(e) {
+            warn!("Failed to forward event to async listener: {err}");
+        }
+    }
+}
+
+#[uniffi::export(with_foreign)]
+#[async_trait::async_trait]
+pub trait AsyncSigner: Send + Sync {
+    async fn xpub(&self) -> Result<Vec<u8>, SignerError>;
+
+    async fn derive_xpub(&self, derivation_path: String) -> Result<Vec<u8>, SignerError>;
+
+    async fn sign_ecdsa(
+        &self,
+        msg: Vec<u8>,
+        derivation_path: String,
+    ) -> Result<Vec<u8>, SignerError>;
+
+    async fn sign_ecdsa_recoverable(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError>;
+
+    async fn slip77_master_blinding_key(&self) -> Result<Vec<u8>, SignerError>;
+
+    async fn hmac_sha256(
+        &self,
+        msg: Vec<u8>,
+        derivation_path: String,
+    ) -> Result<Vec<u8>, SignerError>;
+
+    async fn ecies_encrypt(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError>;
+
+    async fn ecies_decrypt(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError>;
+}
+
+/// Exposes an [AsyncSigner] as the [Signer] the SDK expects
+struct AsyncSignerAdapter {
+    signer: Arc<dyn AsyncSigner>,
+}
+
+impl AsyncSignerAdapter {
+    /// Waits for a foreign signer call. If called from a runtime thread, its other tasks are
+    /// moved to another thread while waiting, so they keep running.
+    fn wait<T>(future: impl Future<Output = T>) -> T {
+        tokio::task::block_in_place(|| rt().block_on(future))
+    }
+}
+
+impl Signer for AsyncSignerAdapter {
+    fn xpub(&self) -> Result<Vec<u8>, SignerError> {
+        Self::wait(self.signer.xpub())
+    }
+
+    fn derive_xpub(&self, derivation_path: String) -> Result<Vec<u8>, SignerError> {
+        Self::wait(self.signer.derive_xpub(derivation_path))
+    }
+
+    fn sign_ecdsa(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError> {
+        Self::wait(self.signer.sign_ecdsa(msg, derivation_path))
+    }
+
+    fn sign_ecdsa_recoverable(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError> {
+        Self::wait(self.signer.sign_ecdsa_recoverable(msg))
+    }
+
+    fn slip77_master_blinding_key(&self) -> Result<Vec<u8>, SignerError> {
+        Self::wait(self.signer.slip77_master_blinding_key())
+    }
+
+    fn hmac_sha256(&self, msg: Vec<u8>, derivation_path: String) -> Result<Vec<u8>, SignerError> {
+        Self::wait(self.signer.hmac_sha256(msg, derivation_path))
+    }
+
+    fn ecies_encrypt(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError> {
+        Self::wait(self.signer.ecies_encrypt(msg))
+    }
+
+    fn ecies_decrypt(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError> {
+        Self::wait(self.signer.ecies_decrypt(msg))
+    }
+}
+
+#[uniffi::export]
+pub fn connect_with_async_signer(
+    req: ConnectWithSignerRequest,
+    signer: Arc<dyn AsyncSigner>,
+) -> Result<Arc<BindingLiquidSdk>, SdkError> {
+    connect_with_signer(req, Box::new(AsyncSignerAdapter { signer }))
+}
+
+#[uniffi::export]
+impl BindingLiquidSdk {
+    /// Adds an [AsyncEventListener]. Its events are delivered one at a time and in order, each
+    /// once the listener finished handling the previous one.
+    pub fn add_async_event_listener(
+        &self,
+        listener: Arc<dyn AsyncEventListener>,
+        filter: Option<EventFilter>,
+    ) -> SdkResult<String> {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let listener_id = rt().block_on(
+            self.sdk
+                .add_event_listener(Box::new(AsyncEventListenerAdapter { sender }), filter),
+        )?;
+        // Stops once the listener is removed, which drops the sender
+        rt().spawn(async move {
+            while let Some(e) = receiver.recv().await {
+                listener.on_event(e).await;
+            }
+        });
+        Ok(listener_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockAsyncSigner {}
+
+    #[async_trait::async_trait]
+    impl AsyncSigner for MockAsyncSigner {
+        async fn xpub(&self) -> Result<Vec<u8>, SignerError> {
+            Ok(vec![1])
+        }
+
+        async fn derive_xpub(&self, derivation_path: String) -> Result<Vec<u8>, SignerError> {
+            Ok(derivation_path.into_bytes())
+        }
+
+        async fn sign_ecdsa(
+            &self,
+            msg: Vec<u8>,
+            _derivation_path: String,
+        ) -> Result<Vec<u8>, SignerError> {
+            Ok(msg)
+        }
+
+        async fn sign_ecdsa_recoverable(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError> {
+            Ok(msg)
+        }
+
+        async fn slip77_master_blinding_key(&self) -> Result<Vec<u8>, SignerError> {
+            Ok(vec![2])
+        }
+
+        async fn hmac_sha256(
+            &self,
+            msg: Vec<u8>,
+            _derivation_path: String,
+        ) -> Result<Vec<u8>, SignerError> {
+            Ok(msg)
+        }
+
+        async fn ecies_encrypt(&self, _msg: Vec<u8>) -> Result<Vec<u8>, SignerError> {
+            Err(SignerError::Generic {
+                err: "Not supported".to_string(),
+            })
+        }
+
+        async fn ecies_decrypt(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError> {
+            Ok(msg)
+        }
+    }
+
+    #[test]
+    fn test_async_signer_adapter() {
+        let adapter = Arc::new(AsyncSignerAdapter {
+            signer: Arc::new(MockAsyncSigner {}),
+        });
+
+        assert_eq!(adapter.xpub().unwrap(), vec![1]);
+        assert_eq!(
+            adapter.derive_xpub("m/84'".to_string()).unwrap(),
+            b"m/84'".to_vec()
+        );
+        assert!(adapter.ecies_encrypt(vec![3]).is_err());
+
+        // The signer is also called from the SDK tasks running on the runtime
+        let task_adapter = adapter.clone();
+        let res = rt()
+            .block_on(rt().spawn(async move { task_adapter.slip77_master_blinding_key() }))
+            .unwrap();
+        assert_eq!(res.unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn test_async_event_listener_adapter() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let adapter = AsyncEventListenerAdapter { sender };
+
+        // The events are queued in order while the listener is busy, without blocking the SDK
+        adapter.on_event(SdkEvent::Synced);
+        adapter.on_event(SdkEvent::SyncStarted);
+
+        assert!(matches!(receiver.try_recv(), Ok(SdkEvent::Synced)));
+        assert!(matches!(receiver.try_recv(), Ok(SdkEvent::SyncStarted)));
+        assert!(receiver.try_recv().is_err());
+    }
+}
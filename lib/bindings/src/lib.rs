@@ -4,6 +4,9 @@ extern crate uniffi_25 as uniffi;
 #[cfg(feature = "uniffi-28")]
 extern crate uniffi_28 as uniffi;
 
+#[cfg(feature = "uniffi-28")]
+mod async_callbacks;
+
 use std::sync::Arc;
 
 use anyhow::Result;
@@ -38,10 +41,7 @@ impl log::Log for UniffiBindingLogger {
     }
 
     fn log(&self, record: &Record) {
-        self.logger.log(LogEntry {
-            line: record.args().to_string(),
-            level: record.level().as_str().to_string(),
-        });
+        self.logger.log(LogEntry::from(record));
     }
     fn flush(&self) {}
 }
@@ -51,6 +51,64 @@ pub fn set_logger(logger: Box<dyn Logger>) -> Result<(), SdkError> {
     UniffiBindingLogger::init(logger).map_err(|_| SdkError::generic("Logger already created"))
 }
 
+/// The foreign counterpart of the SDK [InputParser](breez_sdk_liquid::prelude::InputParser).
+/// As callback interfaces are sync, it is called on a blocking thread.
+pub trait InputParser: Send + Sync {
+    fn parse_input(&self, input: String) -> Option<InputType>;
+}
+
+struct UniffiInputParser {
+    parser: Arc<dyn InputParser>,
+}
+
+#[async_trait::async_trait]
+impl breez_sdk_liquid::prelude::InputParser for UniffiInputParser {
+    async fn parse_input(&self, input: String) -> Option<InputType> {
+        let parser = self.parser.clone();
+        tokio::task::spawn_blocking(move || parser.parse_input(input))
+            .await
+            .ok()
+            .flatten()
+    }
+}
+
+/// The foreign counterpart of the SDK [Broadcaster](breez_sdk_liquid::prelude::Broadcaster).
+/// As callback interfaces are sync, it is called on a blocking thread.
+pub trait Broadcaster: Send + Sync {
+    fn broadcast_liquid_tx(&self, tx_hex: String) -> Result<String, BroadcasterError>;
+
+    fn broadcast_bitcoin_tx(&self, tx_hex: String) -> Result<String, BroadcasterError>;
+}
+
+struct UniffiBroadcaster {
+    broadcaster: Arc<dyn Broadcaster>,
+}
+
+impl UniffiBroadcaster {
+    async fn broadcast(
+        &self,
+        broadcast: impl FnOnce(&dyn Broadcaster) -> Result<String, BroadcasterError> + Send + 'static,
+    ) -> Result<String, BroadcasterError> {
+        let broadcaster = self.broadcaster.clone();
+        tokio::task::spawn_blocking(move || broadcast(broadcaster.as_ref()))
+            .await
+            .map_err(|e| BroadcasterError::Generic { err: e.to_string() })?
+    }
+}
+
+#[async_trait::async_trait]
+impl breez_sdk_liquid::prelude::Broadcaster for UniffiBroadcaster {
+    async fn broadcast_liquid_tx(&self, tx_hex: String) -> Result<String, BroadcasterError> {
+        self.broadcast(move |broadcaster| broadcaster.broadcast_liquid_tx(tx_hex))
+            .await
+    }
+
+    async fn broadcast_bitcoin_tx(&self, tx_hex: String) -> Result<String, BroadcasterError> {
+        self.broadcast(move |broadcaster| broadcaster.broadcast_bitcoin_tx(tx_hex))
+            .await
+    }
+}
+
 pub fn connect(req: ConnectRequest) -> Result<Arc<BindingLiquidSdk>, SdkError> {
     rt().block_on(async {
         let sdk = LiquidSdk::connect(req).await?;
@@ -68,6 +126,12 @@ pub fn connect_with_signer(
     })
 }
 
+pub fn process_notification(
+    req: ProcessNotificationRequest,
+) -> Result<ProcessNotificationResponse, SdkError> {
+    rt().block_on(LiquidSdk::process_notification(req))
+}
+
 pub fn default_config(
     network: LiquidNetwork,
     breez_api_key: Option<String>,
@@ -79,23 +143,70 @@ pub fn parse_invoice(input: String) -> Result<LNInvoice, PaymentError> {
     LiquidSdk::parse_invoice(&input)
 }
 
+pub fn decode_invoice(input: String) -> Result<DecodedInvoice, PaymentError> {
+    LiquidSdk::decode_invoice(&input)
+}
+
+pub fn validate_address(
+    address: String,
+    network: LiquidNetwork,
+) -> Result<ValidateAddressResponse, PaymentError> {
+    LiquidSdk::validate_address(&address, network)
+}
+
+pub fn generate_mnemonic(word_count: u32) -> Result<String, SdkError> {
+    LiquidSdk::generate_mnemonic(word_count)
+}
+
+pub fn validate_mnemonic(phrase: String) -> Result<(), SdkError> {
+    LiquidSdk::validate_mnemonic(&phrase)
+}
+
+pub fn create_spend_limit_override(secret: String, expires_at: u32) -> String {
+    LiquidSdk::create_spend_limit_override(&secret, expires_at)
+}
+
 pub struct BindingLiquidSdk {
     sdk: Arc<LiquidSdk>,
 }
 
 impl BindingLiquidSdk {
-    pub fn add_event_listener(&self, listener: Box<dyn EventListener>) -> SdkResult<String> {
-        rt().block_on(self.sdk.add_event_listener(listener))
+    pub fn add_event_listener(
+        &self,
+        listener: Box<dyn EventListener>,
+        filter: Option<EventFilter>,
+    ) -> SdkResult<String> {
+        rt().block_on(self.sdk.add_event_listener(listener, filter))
     }
 
     pub fn remove_event_listener(&self, id: String) -> SdkResult<()> {
         rt().block_on(self.sdk.remove_event_listener(id))
     }
 
+    pub fn replay_events(&self, req: ReplayEventsRequest) -> SdkResult<Vec<JournaledEvent>> {
+        rt().block_on(self.sdk.replay_events(&req))
+    }
+
     pub fn get_info(&self) -> Result<GetInfoResponse, SdkError> {
         rt().block_on(self.sdk.get_info())
     }
 
+    pub fn check_health(&self) -> SdkResult<CheckHealthResponse> {
+        rt().block_on(self.sdk.check_health())
+    }
+
+    pub fn get_wallet_info(&self) -> SdkResult<GetWalletInfoResponse> {
+        self.sdk.get_wallet_info()
+    }
+
+    pub fn register_amp_account(&self) -> SdkResult<AmpAccount> {
+        rt().block_on(self.sdk.register_amp_account())
+    }
+
+    pub fn get_amp_account(&self) -> SdkResult<Option<AmpAccount>> {
+        self.sdk.get_amp_account()
+    }
+
     pub fn sign_message(&self, req: SignMessageRequest) -> SdkResult<SignMessageResponse> {
         self.sdk.sign_message(&req)
     }
@@ -104,6 +215,17 @@ impl BindingLiquidSdk {
         self.sdk.check_message(&req)
     }
 
+    pub fn sign_pset(&self, req: SignPsetRequest) -> Result<SignPsetResponse, PaymentError> {
+        rt().block_on(self.sdk.sign_pset(&req))
+    }
+
+    pub fn sweep_private_key(
+        &self,
+        req: SweepPrivateKeyRequest,
+    ) -> Result<SweepPrivateKeyResponse, PaymentError> {
+        rt().block_on(self.sdk.sweep_private_key(&req))
+    }
+
     pub fn parse(&self, input: String) -> Result<InputType, PaymentError> {
         rt().block_on(async { self.sdk.parse(&input).await })
     }
@@ -122,6 +244,59 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.send_payment(&req))
     }
 
+    pub fn send_batch(&self, req: SendBatchRequest) -> Result<SendBatchResponse, PaymentError> {
+        rt().block_on(self.sdk.send_batch(&req))
+    }
+
+    pub fn queue_payment(&self, req: QueuePaymentRequest) -> SdkResult<QueuedPayment> {
+        rt().block_on(self.sdk.queue_payment(&req))
+    }
+
+    pub fn list_queued_payments(&self) -> SdkResult<Vec<QueuedPayment>> {
+        rt().block_on(self.sdk.list_queued_payments())
+    }
+
+    pub fn cancel_queued_payment(&self, id: String) -> SdkResult<()> {
+        rt().block_on(self.sdk.cancel_queued_payment(id))
+    }
+
+    pub fn schedule_payment(&self, req: SchedulePaymentRequest) -> SdkResult<ScheduledPayment> {
+        rt().block_on(self.sdk.schedule_payment(&req))
+    }
+
+    pub fn list_scheduled_payments(&self) -> SdkResult<Vec<ScheduledPayment>> {
+        rt().block_on(self.sdk.list_scheduled_payments())
+    }
+
+    pub fn cancel_scheduled_payment(&self, id: String) -> SdkResult<()> {
+        rt().block_on(self.sdk.cancel_scheduled_payment(id))
+    }
+
+    pub fn create_recurring_payment(
+        &self,
+        req: CreateRecurringPaymentRequest,
+    ) -> SdkResult<RecurringPayment> {
+        rt().block_on(self.sdk.create_recurring_payment(&req))
+    }
+
+    pub fn list_recurring_payments(&self) -> SdkResult<Vec<RecurringPayment>> {
+        rt().block_on(self.sdk.list_recurring_payments())
+    }
+
+    pub fn list_recurring_payment_occurrences(
+        &self,
+        recurring_payment_id: String,
+    ) -> SdkResult<Vec<RecurringPaymentOccurrence>> {
+        rt().block_on(
+            self.sdk
+                .list_recurring_payment_occurrences(recurring_payment_id),
+        )
+    }
+
+    pub fn cancel_recurring_payment(&self, id: String) -> SdkResult<()> {
+        rt().block_on(self.sdk.cancel_recurring_payment(id))
+    }
+
     pub fn prepare_receive_payment(
         &self,
         req: PrepareReceiveRequest,
@@ -129,6 +304,13 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.prepare_receive_payment(&req))
     }
 
+    pub fn prepare_receive_payment_quotes(
+        &self,
+        req: PrepareReceiveQuotesRequest,
+    ) -> Result<PrepareReceiveQuotesResponse, PaymentError> {
+        rt().block_on(self.sdk.prepare_receive_payment_quotes(&req))
+    }
+
     pub fn receive_payment(
         &self,
         req: ReceivePaymentRequest,
@@ -144,6 +326,26 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.fetch_onchain_limits())
     }
 
+    pub fn get_max_payable(
+        &self,
+        req: GetMaxPayableRequest,
+    ) -> Result<GetMaxPayableResponse, PaymentError> {
+        rt().block_on(self.sdk.get_max_payable(&req))
+    }
+
+    pub fn get_max_receivable(
+        &self,
+        req: GetMaxReceivableRequest,
+    ) -> Result<GetMaxReceivableResponse, PaymentError> {
+        rt().block_on(self.sdk.get_max_receivable(&req))
+    }
+
+    pub fn get_reusable_bitcoin_address(
+        &self,
+    ) -> Result<ReusableBitcoinAddressResponse, PaymentError> {
+        rt().block_on(self.sdk.get_reusable_bitcoin_address())
+    }
+
     pub fn prepare_pay_onchain(
         &self,
         req: PreparePayOnchainRequest,
@@ -155,6 +357,17 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.pay_onchain(&req))
     }
 
+    pub fn prepare_convert(
+        &self,
+        req: PrepareConvertRequest,
+    ) -> Result<PrepareConvertResponse, PaymentError> {
+        rt().block_on(self.sdk.prepare_convert(&req))
+    }
+
+    pub fn convert(&self, req: ConvertRequest) -> Result<SendPaymentResponse, PaymentError> {
+        rt().block_on(self.sdk.convert(&req))
+    }
+
     pub fn prepare_buy_bitcoin(
         &self,
         req: PrepareBuyBitcoinRequest,
@@ -170,10 +383,25 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.list_payments(&req))
     }
 
+    pub fn list_swaps(&self, req: ListSwapsRequest) -> SdkResult<Vec<SwapInfo>> {
+        rt().block_on(self.sdk.list_swaps(&req))
+    }
+
+    pub fn get_report(&self, req: GetReportRequest) -> SdkResult<GetReportResponse> {
+        rt().block_on(self.sdk.get_report(&req))
+    }
+
     pub fn get_payment(&self, req: GetPaymentRequest) -> Result<Option<Payment>, PaymentError> {
         rt().block_on(self.sdk.get_payment(&req))
     }
 
+    pub fn export_payment_unblinding_data(
+        &self,
+        req: ExportPaymentUnblindingDataRequest,
+    ) -> SdkResult<PaymentUnblindingData> {
+        rt().block_on(self.sdk.export_payment_unblinding_data(&req))
+    }
+
     pub fn fetch_payment_proposed_fees(
         &self,
         req: FetchPaymentProposedFeesRequest,
@@ -188,6 +416,10 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.accept_payment_proposed_fees(&req))
     }
 
+    pub fn claim_swap(&self, swap_id: String) -> Result<(), PaymentError> {
+        rt().block_on(self.sdk.claim_swap(&swap_id))
+    }
+
     pub fn prepare_lnurl_pay(
         &self,
         req: PrepareLnUrlPayRequest,
@@ -221,6 +453,32 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.unregister_webhook())
     }
 
+    pub fn check_lightning_address_available(&self, username: String) -> SdkResult<bool> {
+        rt().block_on(self.sdk.check_lightning_address_available(username))
+    }
+
+    pub fn register_lightning_address(
+        &self,
+        req: RegisterLightningAddressRequest,
+    ) -> SdkResult<LightningAddressInfo> {
+        rt().block_on(self.sdk.register_lightning_address(&req))
+    }
+
+    pub fn recover_lightning_address(&self) -> SdkResult<Option<LightningAddressInfo>> {
+        rt().block_on(self.sdk.recover_lightning_address())
+    }
+
+    pub fn delete_lightning_address(&self) -> SdkResult<()> {
+        rt().block_on(self.sdk.delete_lightning_address())
+    }
+
+    pub fn fetch_registered_asset(
+        &self,
+        asset_id: String,
+    ) -> Result<Option<RegisteredAsset>, SdkError> {
+        rt().block_on(self.sdk.fetch_registered_asset(&asset_id))
+    }
+
     pub fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, SdkError> {
         rt().block_on(self.sdk.fetch_fiat_rates())
     }
@@ -229,6 +487,17 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.list_fiat_currencies())
     }
 
+    pub fn set_fiat_rate_provider(&self, provider: Box<dyn FiatRateProvider>) -> SdkResult<()> {
+        rt().block_on(self.sdk.set_fiat_rate_provider(provider))
+    }
+
+    pub fn add_input_parser(&self, parser: Box<dyn InputParser>) -> SdkResult<()> {
+        let parser = UniffiInputParser {
+            parser: Arc::from(parser),
+        };
+        rt().block_on(self.sdk.add_input_parser(Box::new(parser)))
+    }
+
     pub fn list_refundables(&self) -> SdkResult<Vec<RefundableSwap>> {
         rt().block_on(self.sdk.list_refundables())
     }
@@ -245,8 +514,9 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.rescan_onchain_swaps())
     }
 
-    pub fn sync(&self) -> SdkResult<()> {
-        rt().block_on(self.sdk.sync(false))
+    pub fn sync(&self, req: Option<SyncRequest>) -> SdkResult<SyncResponse> {
+        let full = req.and_then(|req| req.full).unwrap_or(true);
+        rt().block_on(self.sdk.sync(!full))
     }
 
     pub fn recommended_fees(&self) -> SdkResult<RecommendedFees> {
@@ -262,7 +532,53 @@ impl BindingLiquidSdk {
     }
 
     pub fn restore(&self, req: RestoreRequest) -> SdkResult<()> {
-        self.sdk.restore(req).map_err(Into::into)
+        self.sdk.restore(req)
+    }
+
+    pub fn verify_backup(&self, req: RestoreRequest) -> SdkResult<()> {
+        self.sdk.verify_backup(req)
+    }
+
+    pub fn export_wallet(&self, req: ExportWalletRequest) -> SdkResult<()> {
+        self.sdk.export_wallet(req)
+    }
+
+    pub fn import_wallet(&self, req: ImportWalletRequest) -> SdkResult<ImportWalletResponse> {
+        self.sdk.import_wallet(req)
+    }
+
+    pub fn set_broadcaster(&self, broadcaster: Box<dyn Broadcaster>) -> SdkResult<()> {
+        let broadcaster = UniffiBroadcaster {
+            broadcaster: Arc::from(broadcaster),
+        };
+        rt().block_on(self.sdk.set_broadcaster(Box::new(broadcaster)))
+    }
+
+    pub fn set_backup_transport(&self, transport: Box<dyn BackupTransport>) -> SdkResult<()> {
+        rt().block_on(self.sdk.set_backup_transport(transport))
+    }
+
+    pub fn list_backup_versions(&self) -> SdkResult<Vec<String>> {
+        rt().block_on(self.sdk.list_backup_versions())
+    }
+
+    pub fn restore_from_backup_transport(&self, version: Option<String>) -> SdkResult<()> {
+        rt().block_on(self.sdk.restore_from_backup_transport(version))
+    }
+
+    pub fn add_nwc_connection(
+        &self,
+        req: AddNwcConnectionRequest,
+    ) -> SdkResult<AddNwcConnectionResponse> {
+        self.sdk.add_nwc_connection(req)
+    }
+
+    pub fn list_nwc_connections(&self) -> SdkResult<Vec<NwcConnection>> {
+        self.sdk.list_nwc_connections()
+    }
+
+    pub fn remove_nwc_connection(&self, name: String) -> SdkResult<()> {
+        self.sdk.remove_nwc_connection(name)
     }
 
     pub fn disconnect(&self) -> SdkResult<()> {
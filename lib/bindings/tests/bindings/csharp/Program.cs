@@ -9,11 +9,30 @@ try
     var connectReq = new ConnectRequest(config, mnemonic);
     BindingLiquidSdk sdk = BreezSdkLiquidMethods.Connect(connectReq);
 
-    GetInfoResponse? info = sdk.GetInfo();
+    var listenerId = sdk.AddEventListener(new SdkListener(), null);
 
+    GetInfoResponse? info = sdk.GetInfo();
     Console.WriteLine(info!.walletInfo.pubkey);
+
+    List<Payment> payments = sdk.ListPayments(new ListPaymentsRequest());
+    Console.WriteLine($"Payments: {payments.Count}");
+
+    LightningPaymentLimitsResponse limits = sdk.FetchLightningLimits();
+    Console.WriteLine($"Lightning send limits: {limits.send.minSat} - {limits.send.maxSat} sat");
+
+    sdk.RemoveEventListener(listenerId);
+    sdk.Disconnect();
 }
 catch (Exception e)
 {
     Console.WriteLine(e.Message);
+    Environment.Exit(1);
+}
+
+class SdkListener : EventListener
+{
+    public void OnEvent(SdkEvent e)
+    {
+        Console.WriteLine($"Received event: {e}");
+    }
 }
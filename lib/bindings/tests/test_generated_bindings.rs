@@ -20,10 +20,13 @@ fn test_csharp() {
         .arg("tests/bindings/csharp/sdk-cs-demo.csproj")
         .output()
         .expect("failed to execute process");
+    let stdout = String::from_utf8_lossy(&output.stdout);
     println!("status: {}", output.status);
-    println!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+    println!("stdout: {}", stdout);
     println!("stderr: {}", String::from_utf8_lossy(&output.stderr));
     assert!(output.status.success());
+    assert!(stdout.contains("Payments:"));
+    assert!(stdout.contains("Lightning send limits:"));
 }
 
 #[test]
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use anyhow::{ensure, Result};
+use log::debug;
+use lwk_wollet::WolletDescriptor;
+use sdk_common::prelude::RestClient;
+use sdk_common::utils::Arc;
+use serde::{Deserialize, Serialize};
+
+use crate::model::LiquidNetwork;
+
+/// The key origin prefixed xpub of the AMP2 server key on testnet
+const AMP2_SERVER_KEY_TESTNET: &str = "[3d970d04/87'/1'/0']tpubDC347GyKEGtyd4swZDaEmBTcNuqseyX7E3Yw58FoeV1njuBcUmBMr5vBeBh6eRsxKYHeCAEkKj8J2p2dBQQJwB8n33uyAPrdgwFxLFTCXRd";
+/// The SLIP77 blinding key of AMP2 account descriptors, shared with the server so it can
+/// unblind the transactions it is asked to cosign
+const AMP2_BLINDING_KEY: &str = "0684e43749a3a3eb0362dcef8c66994bd51d33f8ce6b055126a800a626fc0d67";
+
+/// Returns the AMP2 server key of the network, or `None` if AMP2 is not available on it
+pub(crate) fn amp_server_key(network: LiquidNetwork) -> Option<&'static str> {
+    match network {
+        LiquidNetwork::Testnet => Some(AMP2_SERVER_KEY_TESTNET),
+        LiquidNetwork::Mainnet | LiquidNetwork::Regtest => None,
+    }
+}
+
+#[derive(Serialize)]
+struct RegisterRequest {
+    descriptor: String,
+}
+
+#[derive(Deserialize)]
+struct RegisterResponse {
+    wid: String,
+}
+
+/// A client of the AMP2 (Asset Management Platform) server.
+///
+/// AMP assets can only be held in accounts registered with the server, which co-signs their
+/// spends so the asset issuer can enforce its transfer restrictions. The account is a 2-of-2
+/// multisig of the server key and a user key, derived at
+/// [amp_account_derivation_path](crate::wallet::amp_account_derivation_path).
+pub(crate) struct AmpClient {
+    url: String,
+    server_key: String,
+    rest_client: Arc<dyn RestClient>,
+}
+
+impl AmpClient {
+    pub(crate) fn new(url: String, server_key: String, rest_client: Arc<dyn RestClient>) -> Self {
+        Self {
+            url,
+            server_key,
+            rest_client,
+        }
+    }
+
+    /// Returns the descriptor of the AMP account of the key origin prefixed user xpub
+    pub(crate) fn descriptor(&self, user_key: &str) -> Result<WolletDescriptor> {
+        Ok(format!(
+            "ct(slip77({AMP2_BLINDING_KEY}),elwsh(multi(2,{}/<0;1>/*,{user_key}/<0;1>/*)))",
+            self.server_key
+        )
+        .parse()?)
+    }
+
+    /// Registers the AMP account descriptor with the server, returning the account id
+    pub(crate) async fn register(&self, descriptor: &WolletDescriptor) -> Result<String> {
+        let url = format!("{}/wallets/register", self.url.trim_end_matches('/'));
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let body = serde_json::to_string(&RegisterRequest {
+            descriptor: descriptor.to_string(),
+        })?;
+        debug!("Registering AMP account with descriptor {descriptor}");
+        let (response, status_code) = self
+            .rest_client
+            .post(&url, Some(headers), Some(body))
+            .await?;
+        ensure!(
+            status_code == 200,
+            "AMP server returned status code {status_code}: {response}"
+        );
+        let response: RegisterResponse = serde_json::from_str(&response)?;
+        ensure!(
+            !response.wid.is_empty(),
+            "AMP server returned an empty account id"
+        );
+        Ok(response.wid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::prelude::{MockResponse, MockRestClient};
+    use sdk_common::utils::Arc;
+
+    use super::{amp_server_key, AmpClient};
+    use crate::model::{LiquidNetwork, Signer};
+    use crate::signer::{SdkLwkSigner, SdkSigner};
+    use crate::wallet::get_amp_account_key;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::async_test_all]
+    async fn test_register() -> Result<()> {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let sdk_signer: Box<dyn Signer> = Box::new(SdkSigner::new(mnemonic, "", false)?);
+        let signer = SdkLwkSigner::new(Arc::new(sdk_signer))?;
+        let user_key = get_amp_account_key(&signer, LiquidNetwork::Testnet)?;
+        assert!(user_key.starts_with("[73c5da0a/87'/1'/0']tpub"));
+
+        let rest_client = Arc::new(MockRestClient::new());
+        let server_key = amp_server_key(LiquidNetwork::Testnet).unwrap();
+        let client = AmpClient::new(
+            "https://amp2.testnet.blockstream.com/".to_string(),
+            server_key.to_string(),
+            rest_client.clone(),
+        );
+
+        // The account is a 2-of-2 multisig of the server key and the user key
+        let descriptor = client.descriptor(&user_key)?;
+        let descriptor_str = descriptor.to_string();
+        assert!(descriptor_str.contains(&format!(
+            "elwsh(multi(2,{server_key}/<0;1>/*,{user_key}/<0;1>/*))"
+        )));
+
+        rest_client.add_response(MockResponse::new(
+            200,
+            serde_json::json!({ "wid": "a5c2b8e1" }).to_string(),
+        ));
+        assert_eq!(client.register(&descriptor).await?, "a5c2b8e1");
+
+        rest_client.add_response(MockResponse::new(
+            200,
+            serde_json::json!({ "wid": "" }).to_string(),
+        ));
+        assert!(client.register(&descriptor).await.is_err());
+
+        rest_client.add_response(MockResponse::new(500, "Internal error".to_string()));
+        assert!(client.register(&descriptor).await.is_err());
+
+        assert!(amp_server_key(LiquidNetwork::Mainnet).is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+use anyhow::{ensure, Result};
+use log::debug;
+use sdk_common::prelude::RestClient;
+use sdk_common::utils::Arc;
+use serde::Deserialize;
+
+use crate::model::{LiquidNetwork, RegisteredAsset};
+
+const MAINNET_REGISTRY_URL: &str = "https://assets.blockstream.info";
+const TESTNET_REGISTRY_URL: &str = "https://assets-testnet.blockstream.info";
+const MAINNET_ICON_URL: &str = "https://liquid.network/api/v1/asset";
+const TESTNET_ICON_URL: &str = "https://liquid.network/liquidtestnet/api/v1/asset";
+
+#[derive(Deserialize)]
+struct RegistryAsset {
+    asset_id: String,
+    name: String,
+    ticker: Option<String>,
+    precision: u8,
+    entity: Option<RegistryEntity>,
+}
+
+#[derive(Deserialize)]
+struct RegistryEntity {
+    domain: String,
+}
+
+/// A client of the [Liquid Asset Registry](https://docs.liquid.net/docs/blockstream-liquid-asset-registry)
+pub(crate) struct AssetRegistryClient {
+    registry_url: &'static str,
+    icon_url: &'static str,
+    rest_client: Arc<dyn RestClient>,
+}
+
+impl AssetRegistryClient {
+    /// Returns `None` if there is no registry for the network
+    pub(crate) fn new(network: LiquidNetwork, rest_client: Arc<dyn RestClient>) -> Option<Self> {
+        let (registry_url, icon_url) = match network {
+            LiquidNetwork::Mainnet => (MAINNET_REGISTRY_URL, MAINNET_ICON_URL),
+            LiquidNetwork::Testnet => (TESTNET_REGISTRY_URL, TESTNET_ICON_URL),
+            LiquidNetwork::Regtest => return None,
+        };
+        Some(Self {
+            registry_url,
+            icon_url,
+            rest_client,
+        })
+    }
+
+    /// Fetches the registered metadata of an asset, or `None` if the asset is not registered
+    pub(crate) async fn fetch_asset(&self, asset_id: &str) -> Result<Option<RegisteredAsset>> {
+        let url = format!("{}/{asset_id}", self.registry_url);
+        debug!("Fetching asset {asset_id} from the asset registry");
+        let (response, status_code) = self.rest_client.get(&url).await?;
+        if status_code == 404 {
+            return Ok(None);
+        }
+        ensure!(
+            status_code == 200,
+            "Asset registry returned status code {status_code}: {response}"
+        );
+        let asset: RegistryAsset = serde_json::from_str(&response)?;
+        ensure!(
+            asset.asset_id == asset_id,
+            "Asset registry returned a different asset"
+        );
+        Ok(Some(RegisteredAsset {
+            icon_url: format!("{}/{asset_id}/icon", self.icon_url),
+            asset_id: asset.asset_id,
+            name: asset.name,
+            ticker: asset.ticker,
+            precision: asset.precision,
+            domain: asset.entity.map(|entity| entity.domain),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::prelude::{MockResponse, MockRestClient};
+    use sdk_common::utils::Arc;
+
+    use super::AssetRegistryClient;
+    use crate::model::LiquidNetwork;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    const USDT_ASSET_ID: &str = "ce091c998b83c78bb71a632313ba3760f1763d9cfcffae02258ffa9865a37bd2";
+
+    #[sdk_macros::async_test_all]
+    async fn test_fetch_asset() -> Result<()> {
+        let rest_client = Arc::new(MockRestClient::new());
+        let client = AssetRegistryClient::new(LiquidNetwork::Mainnet, rest_client.clone()).unwrap();
+
+        rest_client.add_response(MockResponse::new(
+            200,
+            serde_json::json!({
+                "asset_id": USDT_ASSET_ID,
+                "name": "Tether USD",
+                "ticker": "USDt",
+                "precision": 8,
+                "entity": { "domain": "tether.to" },
+            })
+            .to_string(),
+        ));
+        let asset = client.fetch_asset(USDT_ASSET_ID).await?.unwrap();
+        assert_eq!(asset.name, "Tether USD");
+        assert_eq!(asset.ticker, Some("USDt".to_string()));
+        assert_eq!(asset.precision, 8);
+        assert_eq!(asset.domain, Some("tether.to".to_string()));
+        assert_eq!(
+            asset.icon_url,
+            format!("https://liquid.network/api/v1/asset/{USDT_ASSET_ID}/icon")
+        );
+
+        rest_client.add_response(MockResponse::new(404, "Not Found".to_string()));
+        assert!(client.fetch_asset(USDT_ASSET_ID).await?.is_none());
+
+        assert!(AssetRegistryClient::new(LiquidNetwork::Regtest, rest_client).is_none());
+        Ok(())
+    }
+}
@@ -3,19 +3,76 @@
 use std::sync::Arc;
 
 use anyhow::Result;
-use flutter_rust_bridge::frb;
-use log::{Level, LevelFilter, Metadata, Record, SetLoggerError};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::{warn, Level, LevelFilter, Metadata, Record, SetLoggerError};
 pub use sdk_common::prelude::*;
+use tokio::sync::mpsc;
+use tokio_with_wasm::alias as tokio;
 
 use crate::{error::*, frb_generated::StreamSink, model::*, sdk::LiquidSdk};
 
-pub struct BindingEventListener {
-    pub stream: StreamSink<SdkEvent>,
+/// Buffers the events in an unbounded channel, so that a slow Dart stream never blocks the SDK
+/// from emitting events nor misses any. The events are forwarded to the stream in order by
+/// [forward_events].
+#[frb(ignore)]
+pub(crate) struct BindingEventListener {
+    sender: mpsc::UnboundedSender<SdkEvent>,
 }
 
 impl EventListener for BindingEventListener {
     fn on_event(&self, e: SdkEvent) {
-        let _ = self.stream.add(e);
+        // The send only fails once the stream is closed, after which the events are ignored
+        let _ = self.sender.send(e);
+    }
+}
+
+/// Forwards the buffered events to the Dart stream. Once the stream is closed on the Dart side,
+/// the listener is removed from the SDK.
+async fn forward_events(
+    sdk: Arc<LiquidSdk>,
+    listener_id: String,
+    mut receiver: mpsc::UnboundedReceiver<SdkEvent>,
+    stream: StreamSink<SdkEvent>,
+) {
+    while let Some(e) = receiver.recv().await {
+        if stream.add(e).is_err() {
+            break;
+        }
+    }
+    if let Err(e) = sdk.remove_event_listener(listener_id).await {
+        warn!("Failed to remove event listener of a closed event stream: {e:?}");
+    }
+}
+
+/// Exposes a Dart callback as an [InputParser]
+#[frb(ignore)]
+struct DartInputParser {
+    parse_input: Box<dyn Fn(String) -> DartFnFuture<Option<InputType>> + Send + Sync>,
+}
+
+#[sdk_macros::async_trait]
+impl InputParser for DartInputParser {
+    async fn parse_input(&self, input: String) -> Option<InputType> {
+        (self.parse_input)(input).await
+    }
+}
+
+/// Exposes the Dart callbacks as a [Broadcaster]. The callbacks return the txid of the
+/// broadcast tx, any other value failing the broadcast.
+#[frb(ignore)]
+struct DartBroadcaster {
+    broadcast_liquid_tx: Box<dyn Fn(String) -> DartFnFuture<String> + Send + Sync>,
+    broadcast_bitcoin_tx: Box<dyn Fn(String) -> DartFnFuture<String> + Send + Sync>,
+}
+
+#[sdk_macros::async_trait]
+impl Broadcaster for DartBroadcaster {
+    async fn broadcast_liquid_tx(&self, tx_hex: String) -> Result<String, BroadcasterError> {
+        Ok((self.broadcast_liquid_tx)(tx_hex).await)
+    }
+
+    async fn broadcast_bitcoin_tx(&self, tx_hex: String) -> Result<String, BroadcasterError> {
+        Ok((self.broadcast_bitcoin_tx)(tx_hex).await)
     }
 }
 
@@ -38,10 +95,7 @@ impl log::Log for DartBindingLogger {
 
     fn log(&self, record: &Record) {
         if self.enabled(record.metadata()) {
-            let _ = self.log_stream.add(LogEntry {
-                line: record.args().to_string(),
-                level: record.level().as_str().to_string(),
-            });
+            let _ = self.log_stream.add(LogEntry::from(record));
         }
     }
     fn flush(&self) {}
@@ -52,6 +106,12 @@ pub async fn connect(req: ConnectRequest) -> Result<BindingLiquidSdk, SdkError>
     Ok(BindingLiquidSdk { sdk: ln_sdk })
 }
 
+pub async fn process_notification(
+    req: ProcessNotificationRequest,
+) -> Result<ProcessNotificationResponse, SdkError> {
+    LiquidSdk::process_notification(req).await
+}
+
 /// If used, this must be called before `connect`. It can only be called once.
 pub fn breez_log_stream(s: StreamSink<LogEntry>) -> Result<()> {
     DartBindingLogger::init(s).map_err(|_| SdkError::generic("Log stream already created"))?;
@@ -71,6 +131,34 @@ pub fn parse_invoice(input: String) -> Result<LNInvoice, PaymentError> {
     LiquidSdk::parse_invoice(&input)
 }
 
+#[frb(sync)]
+pub fn decode_invoice(input: String) -> Result<DecodedInvoice, PaymentError> {
+    LiquidSdk::decode_invoice(&input)
+}
+
+#[frb(sync)]
+pub fn validate_address(
+    address: String,
+    network: LiquidNetwork,
+) -> Result<ValidateAddressResponse, PaymentError> {
+    LiquidSdk::validate_address(&address, network)
+}
+
+#[frb(sync)]
+pub fn generate_mnemonic(word_count: u32) -> Result<String, SdkError> {
+    LiquidSdk::generate_mnemonic(word_count)
+}
+
+#[frb(sync)]
+pub fn validate_mnemonic(phrase: String) -> Result<(), SdkError> {
+    LiquidSdk::validate_mnemonic(&phrase)
+}
+
+#[frb(sync)]
+pub fn create_spend_limit_override(secret: String, expires_at: u32) -> String {
+    LiquidSdk::create_spend_limit_override(&secret, expires_at)
+}
+
 pub struct BindingLiquidSdk {
     sdk: Arc<LiquidSdk>,
 }
@@ -80,6 +168,24 @@ impl BindingLiquidSdk {
         self.sdk.get_info().await
     }
 
+    pub async fn check_health(&self) -> Result<CheckHealthResponse, SdkError> {
+        self.sdk.check_health().await
+    }
+
+    #[frb(sync)]
+    pub fn get_wallet_info(&self) -> Result<GetWalletInfoResponse, SdkError> {
+        self.sdk.get_wallet_info()
+    }
+
+    pub async fn register_amp_account(&self) -> Result<AmpAccount, SdkError> {
+        self.sdk.register_amp_account().await
+    }
+
+    #[frb(sync)]
+    pub fn get_amp_account(&self) -> Result<Option<AmpAccount>, SdkError> {
+        self.sdk.get_amp_account()
+    }
+
     #[frb(sync)]
     pub fn sign_message(&self, req: SignMessageRequest) -> Result<SignMessageResponse, SdkError> {
         self.sdk.sign_message(&req)
@@ -93,6 +199,17 @@ impl BindingLiquidSdk {
         self.sdk.check_message(&req)
     }
 
+    pub async fn sign_pset(&self, req: SignPsetRequest) -> Result<SignPsetResponse, PaymentError> {
+        self.sdk.sign_pset(&req).await
+    }
+
+    pub async fn sweep_private_key(
+        &self,
+        req: SweepPrivateKeyRequest,
+    ) -> Result<SweepPrivateKeyResponse, PaymentError> {
+        self.sdk.sweep_private_key(&req).await
+    }
+
     pub async fn parse(&self, input: String) -> Result<InputType, PaymentError> {
         self.sdk.parse(&input).await
     }
@@ -100,10 +217,27 @@ impl BindingLiquidSdk {
     pub async fn add_event_listener(
         &self,
         listener: StreamSink<SdkEvent>,
+        filter: Option<EventFilter>,
     ) -> Result<String, SdkError> {
-        self.sdk
-            .add_event_listener(Box::new(BindingEventListener { stream: listener }))
-            .await
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let listener_id = self
+            .sdk
+            .add_event_listener(Box::new(BindingEventListener { sender }), filter)
+            .await?;
+        tokio::spawn(forward_events(
+            self.sdk.clone(),
+            listener_id.clone(),
+            receiver,
+            listener,
+        ));
+        Ok(listener_id)
+    }
+
+    pub async fn replay_events(
+        &self,
+        req: ReplayEventsRequest,
+    ) -> Result<Vec<JournaledEvent>, SdkError> {
+        self.sdk.replay_events(&req).await
     }
 
     pub async fn prepare_send_payment(
@@ -120,6 +254,64 @@ impl BindingLiquidSdk {
         self.sdk.send_payment(&req).await
     }
 
+    pub async fn send_batch(
+        &self,
+        req: SendBatchRequest,
+    ) -> Result<SendBatchResponse, PaymentError> {
+        self.sdk.send_batch(&req).await
+    }
+
+    pub async fn queue_payment(&self, req: QueuePaymentRequest) -> Result<QueuedPayment, SdkError> {
+        self.sdk.queue_payment(&req).await
+    }
+
+    pub async fn list_queued_payments(&self) -> Result<Vec<QueuedPayment>, SdkError> {
+        self.sdk.list_queued_payments().await
+    }
+
+    pub async fn cancel_queued_payment(&self, id: String) -> Result<(), SdkError> {
+        self.sdk.cancel_queued_payment(id).await
+    }
+
+    pub async fn schedule_payment(
+        &self,
+        req: SchedulePaymentRequest,
+    ) -> Result<ScheduledPayment, SdkError> {
+        self.sdk.schedule_payment(&req).await
+    }
+
+    pub async fn list_scheduled_payments(&self) -> Result<Vec<ScheduledPayment>, SdkError> {
+        self.sdk.list_scheduled_payments().await
+    }
+
+    pub async fn cancel_scheduled_payment(&self, id: String) -> Result<(), SdkError> {
+        self.sdk.cancel_scheduled_payment(id).await
+    }
+
+    pub async fn create_recurring_payment(
+        &self,
+        req: CreateRecurringPaymentRequest,
+    ) -> Result<RecurringPayment, SdkError> {
+        self.sdk.create_recurring_payment(&req).await
+    }
+
+    pub async fn list_recurring_payments(&self) -> Result<Vec<RecurringPayment>, SdkError> {
+        self.sdk.list_recurring_payments().await
+    }
+
+    pub async fn list_recurring_payment_occurrences(
+        &self,
+        recurring_payment_id: String,
+    ) -> Result<Vec<RecurringPaymentOccurrence>, SdkError> {
+        self.sdk
+            .list_recurring_payment_occurrences(recurring_payment_id)
+            .await
+    }
+
+    pub async fn cancel_recurring_payment(&self, id: String) -> Result<(), SdkError> {
+        self.sdk.cancel_recurring_payment(id).await
+    }
+
     pub async fn prepare_receive_payment(
         &self,
         req: PrepareReceiveRequest,
@@ -127,6 +319,13 @@ impl BindingLiquidSdk {
         self.sdk.prepare_receive_payment(&req).await
     }
 
+    pub async fn prepare_receive_payment_quotes(
+        &self,
+        req: PrepareReceiveQuotesRequest,
+    ) -> Result<PrepareReceiveQuotesResponse, PaymentError> {
+        self.sdk.prepare_receive_payment_quotes(&req).await
+    }
+
     pub async fn receive_payment(
         &self,
         req: ReceivePaymentRequest,
@@ -144,6 +343,26 @@ impl BindingLiquidSdk {
         self.sdk.fetch_onchain_limits().await
     }
 
+    pub async fn get_max_payable(
+        &self,
+        req: GetMaxPayableRequest,
+    ) -> Result<GetMaxPayableResponse, PaymentError> {
+        self.sdk.get_max_payable(&req).await
+    }
+
+    pub async fn get_max_receivable(
+        &self,
+        req: GetMaxReceivableRequest,
+    ) -> Result<GetMaxReceivableResponse, PaymentError> {
+        self.sdk.get_max_receivable(&req).await
+    }
+
+    pub async fn get_reusable_bitcoin_address(
+        &self,
+    ) -> Result<ReusableBitcoinAddressResponse, PaymentError> {
+        self.sdk.get_reusable_bitcoin_address().await
+    }
+
     pub async fn prepare_pay_onchain(
         &self,
         req: PreparePayOnchainRequest,
@@ -158,6 +377,17 @@ impl BindingLiquidSdk {
         self.sdk.pay_onchain(&req).await
     }
 
+    pub async fn prepare_convert(
+        &self,
+        req: PrepareConvertRequest,
+    ) -> Result<PrepareConvertResponse, PaymentError> {
+        self.sdk.prepare_convert(&req).await
+    }
+
+    pub async fn convert(&self, req: ConvertRequest) -> Result<SendPaymentResponse, PaymentError> {
+        self.sdk.convert(&req).await
+    }
+
     pub async fn prepare_buy_bitcoin(
         &self,
         req: PrepareBuyBitcoinRequest,
@@ -176,6 +406,14 @@ impl BindingLiquidSdk {
         self.sdk.list_payments(&req).await
     }
 
+    pub async fn list_swaps(&self, req: ListSwapsRequest) -> Result<Vec<SwapInfo>, SdkError> {
+        self.sdk.list_swaps(&req).await
+    }
+
+    pub async fn get_report(&self, req: GetReportRequest) -> Result<GetReportResponse, SdkError> {
+        self.sdk.get_report(&req).await
+    }
+
     pub async fn get_payment(
         &self,
         req: GetPaymentRequest,
@@ -183,6 +421,13 @@ impl BindingLiquidSdk {
         self.sdk.get_payment(&req).await
     }
 
+    pub async fn export_payment_unblinding_data(
+        &self,
+        req: ExportPaymentUnblindingDataRequest,
+    ) -> Result<PaymentUnblindingData, SdkError> {
+        self.sdk.export_payment_unblinding_data(&req).await
+    }
+
     pub async fn fetch_payment_proposed_fees(
         &self,
         req: FetchPaymentProposedFeesRequest,
@@ -197,6 +442,10 @@ impl BindingLiquidSdk {
         self.sdk.accept_payment_proposed_fees(&req).await
     }
 
+    pub async fn claim_swap(&self, swap_id: String) -> Result<(), PaymentError> {
+        self.sdk.claim_swap(&swap_id).await
+    }
+
     pub async fn prepare_lnurl_pay(
         &self,
         req: PrepareLnUrlPayRequest,
@@ -241,6 +490,37 @@ impl BindingLiquidSdk {
         self.sdk.unregister_webhook().await
     }
 
+    pub async fn check_lightning_address_available(
+        &self,
+        username: String,
+    ) -> Result<bool, SdkError> {
+        self.sdk.check_lightning_address_available(username).await
+    }
+
+    pub async fn register_lightning_address(
+        &self,
+        req: RegisterLightningAddressRequest,
+    ) -> Result<LightningAddressInfo, SdkError> {
+        self.sdk.register_lightning_address(&req).await
+    }
+
+    pub async fn recover_lightning_address(
+        &self,
+    ) -> Result<Option<LightningAddressInfo>, SdkError> {
+        self.sdk.recover_lightning_address().await
+    }
+
+    pub async fn delete_lightning_address(&self) -> Result<(), SdkError> {
+        self.sdk.delete_lightning_address().await
+    }
+
+    pub async fn fetch_registered_asset(
+        &self,
+        asset_id: String,
+    ) -> Result<Option<RegisteredAsset>, SdkError> {
+        self.sdk.fetch_registered_asset(&asset_id).await
+    }
+
     pub async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, SdkError> {
         self.sdk.fetch_fiat_rates().await
     }
@@ -249,6 +529,28 @@ impl BindingLiquidSdk {
         self.sdk.list_fiat_currencies().await
     }
 
+    pub async fn add_input_parser(
+        &self,
+        parse_input: impl Fn(String) -> DartFnFuture<Option<InputType>> + Send + Sync + 'static,
+    ) -> Result<(), SdkError> {
+        let parser = DartInputParser {
+            parse_input: Box::new(parse_input),
+        };
+        self.sdk.add_input_parser(Box::new(parser)).await
+    }
+
+    pub async fn set_broadcaster(
+        &self,
+        broadcast_liquid_tx: impl Fn(String) -> DartFnFuture<String> + Send + Sync + 'static,
+        broadcast_bitcoin_tx: impl Fn(String) -> DartFnFuture<String> + Send + Sync + 'static,
+    ) -> Result<(), SdkError> {
+        let broadcaster = DartBroadcaster {
+            broadcast_liquid_tx: Box::new(broadcast_liquid_tx),
+            broadcast_bitcoin_tx: Box::new(broadcast_bitcoin_tx),
+        };
+        self.sdk.set_broadcaster(Box::new(broadcaster)).await
+    }
+
     pub async fn list_refundables(&self) -> Result<Vec<RefundableSwap>, SdkError> {
         self.sdk.list_refundables().await
     }
@@ -269,8 +571,9 @@ impl BindingLiquidSdk {
     }
 
     #[frb(name = "sync")]
-    pub async fn sync(&self) -> Result<(), SdkError> {
-        self.sdk.sync(false).await.map_err(Into::into)
+    pub async fn sync(&self, req: Option<SyncRequest>) -> Result<SyncResponse, SdkError> {
+        let full = req.and_then(|req| req.full).unwrap_or(true);
+        self.sdk.sync(!full).await
     }
 
     pub async fn recommended_fees(&self) -> Result<RecommendedFees, SdkError> {
@@ -289,7 +592,43 @@ impl BindingLiquidSdk {
 
     #[frb(sync)]
     pub fn restore(&self, req: RestoreRequest) -> Result<(), SdkError> {
-        self.sdk.restore(req).map_err(Into::into)
+        self.sdk.restore(req)
+    }
+
+    #[frb(sync)]
+    pub fn verify_backup(&self, req: RestoreRequest) -> Result<(), SdkError> {
+        self.sdk.verify_backup(req)
+    }
+
+    #[frb(sync)]
+    pub fn export_wallet(&self, req: ExportWalletRequest) -> Result<(), SdkError> {
+        self.sdk.export_wallet(req)
+    }
+
+    #[frb(sync)]
+    pub fn import_wallet(
+        &self,
+        req: ImportWalletRequest,
+    ) -> Result<ImportWalletResponse, SdkError> {
+        self.sdk.import_wallet(req)
+    }
+
+    #[frb(sync)]
+    pub fn add_nwc_connection(
+        &self,
+        req: AddNwcConnectionRequest,
+    ) -> Result<AddNwcConnectionResponse, SdkError> {
+        self.sdk.add_nwc_connection(req)
+    }
+
+    #[frb(sync)]
+    pub fn list_nwc_connections(&self) -> Result<Vec<NwcConnection>, SdkError> {
+        self.sdk.list_nwc_connections()
+    }
+
+    #[frb(sync)]
+    pub fn remove_nwc_connection(&self, name: String) -> Result<(), SdkError> {
+        self.sdk.remove_nwc_connection(name)
     }
 
     pub async fn disconnect(&self) -> Result<(), SdkError> {
@@ -814,3 +1153,31 @@ pub mod duplicates {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::async_test_all]
+    async fn test_binding_event_listener_buffer() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let listener = BindingEventListener { sender };
+
+        // Events are buffered without blocking the SDK, however many the stream is behind
+        for _ in 0..1_000 {
+            listener.on_event(SdkEvent::Synced);
+        }
+        let mut received = 0;
+        while let Ok(SdkEvent::Synced) = receiver.try_recv() {
+            received += 1;
+        }
+        assert_eq!(received, 1_000);
+
+        // Once the stream is closed, events are ignored
+        drop(receiver);
+        listener.on_event(SdkEvent::Synced);
+    }
+}
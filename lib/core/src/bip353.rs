@@ -0,0 +1,255 @@
+use std::fmt;
+
+use anyhow::{anyhow, bail, ensure, Result};
+use log::debug;
+use sdk_common::prelude::RestClient;
+use serde::Deserialize;
+use url::Url;
+
+const DNS_STATUS_NO_ERROR: u32 = 0;
+const DNS_STATUS_NXDOMAIN: u32 = 3;
+const DNS_TYPE_TXT: u32 = 16;
+const BIP353_PREFIX: char = '₿';
+const BITCOIN_URI_SCHEME: &str = "bitcoin:";
+
+/// A human-readable name as defined by [BIP353](https://github.com/bitcoin/bips/blob/master/bip-0353.mediawiki),
+/// i.e. `₿user@domain`, with the `₿` prefix being optional
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Bip353Name {
+    user: String,
+    domain: String,
+}
+
+impl Bip353Name {
+    pub(crate) fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let input = input.strip_prefix(BIP353_PREFIX).unwrap_or(input);
+        let (user, domain) = input.split_once('@')?;
+        let is_label = |label: &str| {
+            !label.is_empty()
+                && label
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+        };
+        if !is_label(user) || !is_label(domain) || !domain.contains('.') {
+            return None;
+        }
+        Some(Self {
+            user: user.to_lowercase(),
+            domain: domain.to_lowercase(),
+        })
+    }
+
+    /// The DNS name holding the payment instructions
+    fn dns_name(&self) -> String {
+        format!("{}.user._bitcoin-payment.{}", self.user, self.domain)
+    }
+
+    /// The LNURL-pay endpoint of the name when used as a lightning address, as a LUD-17 URL
+    pub(crate) fn lnurl_pay_url(&self) -> String {
+        format!("lnurlp://{}/.well-known/lnurlp/{}", self.domain, self.user)
+    }
+}
+
+impl fmt::Display for Bip353Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.user, self.domain)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct DnsResponse {
+    status: u32,
+    #[serde(rename = "AD", default)]
+    authenticated_data: bool,
+    #[serde(default)]
+    answer: Vec<DnsAnswer>,
+}
+
+#[derive(Deserialize)]
+struct DnsAnswer {
+    #[serde(rename = "type")]
+    record_type: u32,
+    data: String,
+}
+
+/// Looks up the BIP21 URI of the name with a DNSSEC-validated TXT query, using the
+/// DNS-over-HTTPS JSON API at `dns_over_https_url`. The resolver validates the DNSSEC chain and
+/// reports the result in the `AD` flag of the response.
+///
+/// Returns `None` if the name has no payment instructions, so the input can be parsed otherwise,
+/// e.g. as a lightning address. Fails if the answer is not authenticated by DNSSEC.
+pub(crate) async fn resolve(
+    rest_client: &dyn RestClient,
+    dns_over_https_url: &str,
+    name: &Bip353Name,
+) -> Result<Option<String>> {
+    let mut url = Url::parse(dns_over_https_url)?;
+    url.query_pairs_mut()
+        .append_pair("name", &name.dns_name())
+        .append_pair("type", "TXT")
+        .append_pair("do", "true");
+    debug!("Resolving BIP353 name {name}");
+    let (response, status_code) = rest_client.get(url.as_str()).await?;
+    ensure!(
+        status_code == 200,
+        "DNS resolver returned status code {status_code}: {response}"
+    );
+    let response: DnsResponse = serde_json::from_str(&response)?;
+    match response.status {
+        DNS_STATUS_NO_ERROR => {}
+        DNS_STATUS_NXDOMAIN => return Ok(None),
+        status => bail!("DNS lookup of {name} failed with status {status}"),
+    }
+
+    let mut uris = response
+        .answer
+        .into_iter()
+        .filter(|answer| answer.record_type == DNS_TYPE_TXT)
+        .map(|answer| decode_txt_data(&answer.data))
+        .filter(|data| {
+            data.get(..BITCOIN_URI_SCHEME.len())
+                .is_some_and(|scheme| scheme.eq_ignore_ascii_case(BITCOIN_URI_SCHEME))
+        });
+    let Some(uri) = uris.next() else {
+        return Ok(None);
+    };
+    ensure!(
+        uris.next().is_none(),
+        "Multiple payment instructions found for {name}"
+    );
+    ensure!(
+        response.authenticated_data,
+        "Payment instructions of {name} are not authenticated by DNSSEC"
+    );
+    Ok(Some(uri))
+}
+
+/// Joins the character strings of TXT data, which are quoted when the record is split
+fn decode_txt_data(data: &str) -> String {
+    if !data.starts_with('"') {
+        return data.to_string();
+    }
+    data.split('"')
+        .enumerate()
+        .filter(|(i, _)| i % 2 == 1)
+        .map(|(_, part)| part)
+        .collect()
+}
+
+/// Picks the instruction the SDK pays from the BIP21 URI, preferring a BOLT12 offer, then a
+/// BOLT11 invoice, and otherwise the URI itself
+pub(crate) fn payment_instruction(uri: &str) -> Result<String> {
+    let url = Url::parse(uri).map_err(|e| anyhow!("Invalid BIP21 URI: {e}"))?;
+    for key in ["lno", "lightning"] {
+        if let Some((_, value)) = url.query_pairs().find(|(k, _)| k.eq_ignore_ascii_case(key)) {
+            return Ok(value.into_owned());
+        }
+    }
+    Ok(uri.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::prelude::{MockResponse, MockRestClient};
+
+    use super::{decode_txt_data, payment_instruction, resolve, Bip353Name};
+    use crate::sdk::DEFAULT_DNS_OVER_HTTPS_URL;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_parse_name() {
+        let name = Bip353Name::parse("₿Satoshi@Example.com").unwrap();
+        assert_eq!(name.to_string(), "satoshi@example.com");
+        assert_eq!(name.dns_name(), "satoshi.user._bitcoin-payment.example.com");
+        assert_eq!(
+            name.lnurl_pay_url(),
+            "lnurlp://example.com/.well-known/lnurlp/satoshi"
+        );
+        assert_eq!(Bip353Name::parse("satoshi@example.com"), Some(name));
+
+        assert!(Bip353Name::parse("satoshi").is_none());
+        assert!(Bip353Name::parse("@example.com").is_none());
+        assert!(Bip353Name::parse("satoshi@localhost").is_none());
+        assert!(Bip353Name::parse("lightning:satoshi@example.com").is_none());
+    }
+
+    #[sdk_macros::test_all]
+    fn test_decode_txt_data() {
+        assert_eq!(decode_txt_data("bitcoin:?lno=lno1"), "bitcoin:?lno=lno1");
+        assert_eq!(
+            decode_txt_data(r#""bitcoin:?lno=" "lno1""#),
+            "bitcoin:?lno=lno1"
+        );
+    }
+
+    #[sdk_macros::test_all]
+    fn test_payment_instruction() -> Result<()> {
+        assert_eq!(payment_instruction("bitcoin:?lno=lno1abc")?, "lno1abc");
+        assert_eq!(
+            payment_instruction("bitcoin:bc1qaddr?lightning=lnbc1abc&lno=lno1abc")?,
+            "lno1abc"
+        );
+        assert_eq!(
+            payment_instruction("bitcoin:bc1qaddr?LIGHTNING=lnbc1abc")?,
+            "lnbc1abc"
+        );
+        assert_eq!(
+            payment_instruction("bitcoin:bc1qaddr?amount=1")?,
+            "bitcoin:bc1qaddr?amount=1"
+        );
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_resolve() -> Result<()> {
+        let rest_client = MockRestClient::new();
+        let name = Bip353Name::parse("₿satoshi@example.com").unwrap();
+        let response = |ad: bool, records: &[&str]| {
+            let answer: Vec<_> = records
+                .iter()
+                .map(|data| serde_json::json!({ "type": 16, "data": data }))
+                .collect();
+            serde_json::json!({ "Status": 0, "AD": ad, "Answer": answer }).to_string()
+        };
+
+        rest_client.add_response(MockResponse::new(
+            200,
+            response(true, &["v=spf1 -all", "bitcoin:?lno=lno1abc"]),
+        ));
+        assert_eq!(
+            resolve(&rest_client, DEFAULT_DNS_OVER_HTTPS_URL, &name).await?,
+            Some("bitcoin:?lno=lno1abc".to_string())
+        );
+
+        // Answers not validated by DNSSEC are rejected
+        rest_client.add_response(MockResponse::new(
+            200,
+            response(false, &["bitcoin:?lno=lno1abc"]),
+        ));
+        assert!(resolve(&rest_client, DEFAULT_DNS_OVER_HTTPS_URL, &name)
+            .await
+            .is_err());
+
+        // Multiple payment instructions are ambiguous
+        rest_client.add_response(MockResponse::new(
+            200,
+            response(true, &["bitcoin:?lno=lno1abc", "bitcoin:?lno=lno1def"]),
+        ));
+        assert!(resolve(&rest_client, DEFAULT_DNS_OVER_HTTPS_URL, &name)
+            .await
+            .is_err());
+
+        rest_client.add_response(MockResponse::new(200, r#"{"Status":3}"#.to_string()));
+        assert_eq!(
+            resolve(&rest_client, DEFAULT_DNS_OVER_HTTPS_URL, &name).await?,
+            None
+        );
+
+        Ok(())
+    }
+}
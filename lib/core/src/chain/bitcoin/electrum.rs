@@ -11,7 +11,7 @@ use crate::{
         hashes::{sha256, Hash},
         Address, OutPoint, Script, ScriptBuf, Transaction, Txid,
     },
-    model::{BlockchainExplorer, Config, RecommendedFees, Utxo},
+    model::{BlockInfo, BlockchainExplorer, Config, RecommendedFees, Utxo},
 };
 
 use electrum_client::{Client, ElectrumApi, HeaderNotification};
@@ -19,7 +19,7 @@ use log::info;
 use lwk_wollet::{ElectrumOptions, ElectrumUrl};
 use sdk_common::bitcoin::hashes::hex::ToHex as _;
 
-use super::{BitcoinChainService, BtcScriptBalance, History};
+use super::{get_history_transactions, BitcoinChainService, BtcScriptBalance, History};
 
 pub(crate) struct ElectrumBitcoinChainService {
     config: Config,
@@ -46,7 +46,9 @@ impl ElectrumBitcoinChainService {
             BlockchainExplorer::Electrum { url } => ElectrumUrl::new(url, tls, validate_domain)?,
             _ => bail!("Cannot start Bitcoin Electrum chain service without an Electrum url"),
         };
-        let client = electrum_url.build_client(&ElectrumOptions { timeout: Some(3) })?;
+        let client = electrum_url.build_client(&ElectrumOptions {
+            timeout: Some(self.config.chain_service_timeout_sec()),
+        })?;
 
         let client = self.client.get_or_init(|| client);
         Ok(client)
@@ -88,6 +90,18 @@ impl BitcoinChainService for ElectrumBitcoinChainService {
         }
     }
 
+    async fn tip_block(&self) -> Result<BlockInfo> {
+        let header: HeaderNotification = self
+            .get_client()?
+            .block_headers_subscribe_raw()?
+            .try_into()?;
+        Ok(BlockInfo {
+            height: header.height as u32,
+            hash: header.header.block_hash().to_string(),
+            timestamp: header.header.time,
+        })
+    }
+
     async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
         let txid = self
             .get_client()?
@@ -165,9 +179,8 @@ impl BitcoinChainService for ElectrumBitcoinChainService {
             .flatten()
             .map(|h| (Txid::from_raw_hash(h.txid.to_raw_hash()), h.height > 0))
             .collect();
-        let txs = self
-            .get_transactions(&tx_confirmed_map.keys().cloned().collect::<Vec<_>>())
-            .await?;
+        let txids: Vec<Txid> = tx_confirmed_map.keys().cloned().collect();
+        let txs = get_history_transactions(self, &txids).await;
         let script_txs_map: HashMap<ScriptBuf, Vec<Transaction>> = scripts
             .iter()
             .map(|script| ScriptBuf::from_bytes(script.to_bytes().to_vec()))
@@ -1,6 +1,7 @@
-use std::{collections::HashMap, sync::OnceLock, time::Duration};
+use std::{collections::HashMap, future::Future, sync::OnceLock, time::Duration};
 
 use esplora_client::{AsyncClient, Builder};
+use futures_util::{stream, StreamExt as _, TryStreamExt as _};
 use tokio::sync::Mutex;
 use tokio_with_wasm::alias as tokio;
 
@@ -10,7 +11,7 @@ use crate::{
         hashes::{sha256, Hash},
         Address, OutPoint, Script, ScriptBuf, Transaction, Txid,
     },
-    model::{BlockchainExplorer, Config},
+    model::{BlockInfo, BlockchainExplorer, Config},
 };
 
 use anyhow::{anyhow, Context, Result};
@@ -19,7 +20,23 @@ use crate::model::{RecommendedFees, Utxo};
 use log::info;
 use sdk_common::bitcoin::hashes::hex::ToHex as _;
 
-use super::{BitcoinChainService, BtcScriptBalance, History};
+use super::{get_history_transactions, BitcoinChainService, BtcScriptBalance, History};
+
+/// The number of confirmed transactions Esplora returns per page of a script history
+const ESPLORA_CONFIRMED_TXS_PAGE_SIZE: usize = 25;
+/// The maximum number of requests sent concurrently to emulate a batch request
+const ESPLORA_MAX_CONCURRENT_REQUESTS: usize = 10;
+
+/// Runs the requests with at most [ESPLORA_MAX_CONCURRENT_REQUESTS] in flight, returning the
+/// results in the order of the requests
+async fn try_join_bounded<T>(
+    requests: impl IntoIterator<Item = impl Future<Output = Result<T>>>,
+) -> Result<Vec<T>> {
+    stream::iter(requests)
+        .buffered(ESPLORA_MAX_CONCURRENT_REQUESTS)
+        .try_collect()
+        .await
+}
 
 pub(crate) struct EsploraBitcoinChainService {
     config: Config,
@@ -41,6 +58,12 @@ impl EsploraBitcoinChainService {
             return Ok(c);
         }
 
+        let client = self.client_builder()?.build_async()?;
+        let client = self.client.get_or_init(|| client);
+        Ok(client)
+    }
+
+    fn client_builder(&self) -> Result<Builder> {
         let esplora_url = match &self.config.bitcoin_explorer {
             BlockchainExplorer::Esplora { url, .. } => url,
             #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
@@ -48,12 +71,13 @@ impl EsploraBitcoinChainService {
                 anyhow::bail!("Cannot start Bitcoin Esplora chain service without an Esplora url")
             }
         };
-        let client = Builder::new(esplora_url)
-            .timeout(3)
-            .max_retries(10)
-            .build_async()?;
-        let client = self.client.get_or_init(|| client);
-        Ok(client)
+        let mut builder = Builder::new(esplora_url)
+            .timeout(self.config.chain_service_timeout_sec().into())
+            .max_retries(10);
+        if let Some(authorization) = &self.config.bitcoin_explorer_authorization {
+            builder = builder.header("Authorization", authorization);
+        }
+        Ok(builder)
     }
 }
 
@@ -73,47 +97,57 @@ impl BitcoinChainService for EsploraBitcoinChainService {
         }
     }
 
+    async fn tip_block(&self) -> Result<BlockInfo> {
+        let client = self.get_client()?;
+        let height = client.get_height().await?;
+        let hash = client.get_block_hash(height).await?;
+        let header = client.get_header_by_hash(&hash).await?;
+        Ok(BlockInfo {
+            height,
+            hash: hash.to_string(),
+            timestamp: header.time,
+        })
+    }
+
     async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
         self.get_client()?.broadcast(tx).await?;
         Ok(tx.compute_txid())
     }
 
-    // TODO Switch to batch search
+    // Esplora has no batch endpoints, so the transactions are fetched concurrently instead
     async fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>> {
         let client = self.get_client()?;
-        let mut result = vec![];
-        for txid in txids {
-            let tx = client
-                .get_tx(txid)
-                .await?
-                .context("Transaction not found")?;
-            result.push(tx);
-        }
-        Ok(result)
+        try_join_bounded(
+            txids.iter().map(|txid| async move {
+                client.get_tx(txid).await?.context("Transaction not found")
+            }),
+        )
+        .await
     }
 
     async fn get_script_history(&self, script: &Script) -> Result<Vec<History>> {
         let client = self.get_client()?;
-        let history = client
-            .scripthash_txs(script, None)
-            .await?
-            .into_iter()
-            .map(|tx| History {
+        let mut history = vec![];
+        let mut last_seen = None;
+        // The first page includes the mempool transactions, followed by pages of confirmed ones
+        loop {
+            let txs = client.scripthash_txs(script, last_seen).await?;
+            let confirmed_count = txs.iter().filter(|tx| tx.status.confirmed).count();
+            last_seen = txs.last().map(|tx| tx.txid);
+            history.extend(txs.into_iter().map(|tx| History {
                 txid: tx.txid,
                 height: tx.status.block_height.map(|h| h as i32).unwrap_or(-1),
-            })
-            .collect();
+            }));
+            if confirmed_count < ESPLORA_CONFIRMED_TXS_PAGE_SIZE {
+                break;
+            }
+        }
         Ok(history)
     }
 
-    // TODO Switch to batch search
+    // Esplora has no batch endpoints, so the histories are fetched concurrently instead
     async fn get_scripts_history(&self, scripts: &[&Script]) -> Result<Vec<Vec<History>>> {
-        let mut result = vec![];
-        for script in scripts {
-            let history = self.get_script_history(script).await?;
-            result.push(history);
-        }
-        Ok(result)
+        try_join_bounded(scripts.iter().map(|script| self.get_script_history(script))).await
     }
 
     async fn get_script_history_with_retry(
@@ -159,9 +193,8 @@ impl BitcoinChainService for EsploraBitcoinChainService {
             .flatten()
             .map(|h| (h.txid, h.height > 0))
             .collect();
-        let txs = self
-            .get_transactions(&tx_confirmed_map.keys().cloned().collect::<Vec<_>>())
-            .await?;
+        let txids: Vec<Txid> = tx_confirmed_map.keys().cloned().collect();
+        let txs = get_history_transactions(self, &txids).await;
         let script_txs_map: HashMap<ScriptBuf, Vec<Transaction>> = scripts
             .iter()
             .map(|script| ScriptBuf::from_bytes(script.to_bytes().to_vec()))
@@ -238,14 +271,9 @@ impl BitcoinChainService for EsploraBitcoinChainService {
         Ok(balance)
     }
 
-    // TODO Switch to batch search
+    // Esplora has no batch endpoints, so the balances are fetched concurrently instead
     async fn scripts_get_balance(&self, scripts: &[&Script]) -> Result<Vec<BtcScriptBalance>> {
-        let mut result = vec![];
-        for script in scripts {
-            let balance = self.script_get_balance(script).await?;
-            result.push(balance);
-        }
-        Ok(result)
+        try_join_bounded(scripts.iter().map(|script| self.script_get_balance(script))).await
     }
 
     async fn script_get_balance_with_retry(
@@ -334,3 +362,31 @@ impl BitcoinChainService for EsploraBitcoinChainService {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::Config;
+
+    use super::EsploraBitcoinChainService;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_client_authorization() -> Result<()> {
+        let mut config = Config::testnet_esplora(None);
+        let builder = EsploraBitcoinChainService::new(config.clone()).client_builder()?;
+        assert!(builder.headers.is_empty());
+
+        config.bitcoin_explorer_authorization = Some("Bearer token".to_string());
+        let builder = EsploraBitcoinChainService::new(config).client_builder()?;
+        assert_eq!(
+            builder.headers.get("Authorization"),
+            Some(&"Bearer token".to_string())
+        );
+
+        Ok(())
+    }
+}
@@ -7,17 +7,43 @@ use maybe_sync::{MaybeSend, MaybeSync};
 
 use crate::{
     bitcoin,
-    model::{BtcHistory, BtcScriptBalance, RecommendedFees, Utxo},
+    model::{BlockInfo, BtcHistory, BtcScriptBalance, RecommendedFees, Utxo},
 };
 
 pub(crate) type History = BtcHistory;
 
+/// Fetches the transactions of a script history in a single batch request. If the batch fails,
+/// e.g. because one of the transactions can't be fetched, they're fetched one by one instead,
+/// skipping the ones that fail.
+pub(crate) async fn get_history_transactions<S: BitcoinChainService + ?Sized>(
+    service: &S,
+    txids: &[Txid],
+) -> Vec<Transaction> {
+    match service.get_transactions(txids).await {
+        Ok(txs) => txs,
+        Err(e) => {
+            log::warn!("Batch fetch of history txs failed, fetching them one by one: {e:?}");
+            let mut txs = vec![];
+            for txid in txids {
+                match service.get_transactions(&[*txid]).await {
+                    Ok(tx) => txs.extend(tx),
+                    Err(e) => log::warn!("Failed to fetch history transaction {txid}: {e:?}"),
+                }
+            }
+            txs
+        }
+    }
+}
+
 /// Trait implemented by types that can fetch data from a blockchain data source.
 #[sdk_macros::async_trait]
 pub trait BitcoinChainService: MaybeSend + MaybeSync {
     /// Get the blockchain latest block
     async fn tip(&self) -> Result<u32>;
 
+    /// Get the details of the blockchain latest block
+    async fn tip_block(&self) -> Result<BlockInfo>;
+
     /// Broadcast a transaction
     async fn broadcast(&self, tx: &Transaction) -> Result<Txid>;
 
@@ -0,0 +1,317 @@
+use anyhow::{anyhow, ensure, Result};
+use sdk_common::utils::Arc;
+use tokio::sync::RwLock;
+use tokio_with_wasm::alias as tokio;
+
+use crate::{
+    bitcoin, elements,
+    model::{BlockInfo, Broadcaster, BtcScriptBalance, RecommendedFees, Utxo},
+};
+
+use super::{
+    bitcoin::{BitcoinChainService, History as BtcHistory},
+    liquid::{History as LBtcHistory, LiquidChainService},
+};
+
+/// The [Broadcaster] of the chain services, shared with the SDK so it can be set after connecting
+pub(crate) type SharedBroadcaster = Arc<RwLock<Option<Arc<dyn Broadcaster>>>>;
+
+/// Checks that a [Broadcaster] returned the txid of the broadcast tx
+fn ensure_txid(broadcast_tx_id: &str, expected_tx_id: &str) -> Result<()> {
+    ensure!(
+        broadcast_tx_id == expected_tx_id,
+        "Broadcaster returned txid {broadcast_tx_id}, expected {expected_tx_id}"
+    );
+    Ok(())
+}
+
+/// A [LiquidChainService] which broadcasts through the [Broadcaster], if set, and delegates
+/// everything else to the wrapped chain service
+pub(crate) struct BroadcastingLiquidChainService {
+    inner: Arc<dyn LiquidChainService>,
+    broadcaster: SharedBroadcaster,
+}
+
+impl BroadcastingLiquidChainService {
+    pub(crate) fn new(inner: Arc<dyn LiquidChainService>, broadcaster: SharedBroadcaster) -> Self {
+        Self { inner, broadcaster }
+    }
+}
+
+#[sdk_macros::async_trait]
+impl LiquidChainService for BroadcastingLiquidChainService {
+    async fn tip(&self) -> Result<u32> {
+        self.inner.tip().await
+    }
+
+    async fn tip_block(&self) -> Result<BlockInfo> {
+        self.inner.tip_block().await
+    }
+
+    async fn broadcast(&self, tx: &elements::Transaction) -> Result<elements::Txid> {
+        let Some(broadcaster) = self.broadcaster.read().await.clone() else {
+            return self.inner.broadcast(tx).await;
+        };
+        let tx_hex = hex::encode(elements::encode::serialize(tx));
+        let tx_id = broadcaster
+            .broadcast_liquid_tx(tx_hex)
+            .await
+            .map_err(|e| anyhow!("{e}"))?;
+        ensure_txid(&tx_id, &tx.txid().to_string())?;
+        Ok(tx.txid())
+    }
+
+    async fn get_transaction_hex(
+        &self,
+        txid: &elements::Txid,
+    ) -> Result<Option<elements::Transaction>> {
+        self.inner.get_transaction_hex(txid).await
+    }
+
+    async fn get_transactions(
+        &self,
+        txids: &[elements::Txid],
+    ) -> Result<Vec<elements::Transaction>> {
+        self.inner.get_transactions(txids).await
+    }
+
+    async fn get_script_history(&self, script: &elements::Script) -> Result<Vec<LBtcHistory>> {
+        self.inner.get_script_history(script).await
+    }
+
+    async fn get_scripts_history(
+        &self,
+        scripts: &[elements::Script],
+    ) -> Result<Vec<Vec<LBtcHistory>>> {
+        self.inner.get_scripts_history(scripts).await
+    }
+
+    async fn get_script_history_with_retry(
+        &self,
+        script: &elements::Script,
+        retries: u64,
+    ) -> Result<Vec<LBtcHistory>> {
+        self.inner
+            .get_script_history_with_retry(script, retries)
+            .await
+    }
+
+    async fn get_script_utxos(&self, script: &elements::Script) -> Result<Vec<Utxo>> {
+        self.inner.get_script_utxos(script).await
+    }
+
+    async fn verify_tx(
+        &self,
+        address: &elements::Address,
+        tx_id: &str,
+        tx_hex: &str,
+        verify_confirmation: bool,
+    ) -> Result<elements::Transaction> {
+        self.inner
+            .verify_tx(address, tx_id, tx_hex, verify_confirmation)
+            .await
+    }
+}
+
+/// A [BitcoinChainService] which broadcasts through the [Broadcaster], if set, and delegates
+/// everything else to the wrapped chain service
+pub(crate) struct BroadcastingBitcoinChainService {
+    inner: Arc<dyn BitcoinChainService>,
+    broadcaster: SharedBroadcaster,
+}
+
+impl BroadcastingBitcoinChainService {
+    pub(crate) fn new(inner: Arc<dyn BitcoinChainService>, broadcaster: SharedBroadcaster) -> Self {
+        Self { inner, broadcaster }
+    }
+}
+
+#[sdk_macros::async_trait]
+impl BitcoinChainService for BroadcastingBitcoinChainService {
+    async fn tip(&self) -> Result<u32> {
+        self.inner.tip().await
+    }
+
+    async fn tip_block(&self) -> Result<BlockInfo> {
+        self.inner.tip_block().await
+    }
+
+    async fn broadcast(&self, tx: &bitcoin::Transaction) -> Result<bitcoin::Txid> {
+        let Some(broadcaster) = self.broadcaster.read().await.clone() else {
+            return self.inner.broadcast(tx).await;
+        };
+        let tx_hex = hex::encode(bitcoin::consensus::serialize(tx));
+        let tx_id = broadcaster
+            .broadcast_bitcoin_tx(tx_hex)
+            .await
+            .map_err(|e| anyhow!("{e}"))?;
+        ensure_txid(&tx_id, &tx.compute_txid().to_string())?;
+        Ok(tx.compute_txid())
+    }
+
+    async fn get_transactions(&self, txids: &[bitcoin::Txid]) -> Result<Vec<bitcoin::Transaction>> {
+        self.inner.get_transactions(txids).await
+    }
+
+    async fn get_script_history(&self, script: &bitcoin::Script) -> Result<Vec<BtcHistory>> {
+        self.inner.get_script_history(script).await
+    }
+
+    async fn get_scripts_history(
+        &self,
+        scripts: &[&bitcoin::Script],
+    ) -> Result<Vec<Vec<BtcHistory>>> {
+        self.inner.get_scripts_history(scripts).await
+    }
+
+    async fn get_script_history_with_retry(
+        &self,
+        script: &bitcoin::Script,
+        retries: u64,
+    ) -> Result<Vec<BtcHistory>> {
+        self.inner
+            .get_script_history_with_retry(script, retries)
+            .await
+    }
+
+    async fn get_script_utxos(&self, script: &bitcoin::Script) -> Result<Vec<Utxo>> {
+        self.inner.get_script_utxos(script).await
+    }
+
+    async fn get_scripts_utxos(&self, scripts: &[&bitcoin::Script]) -> Result<Vec<Vec<Utxo>>> {
+        self.inner.get_scripts_utxos(scripts).await
+    }
+
+    async fn script_get_balance(&self, script: &bitcoin::Script) -> Result<BtcScriptBalance> {
+        self.inner.script_get_balance(script).await
+    }
+
+    async fn scripts_get_balance(
+        &self,
+        scripts: &[&bitcoin::Script],
+    ) -> Result<Vec<BtcScriptBalance>> {
+        self.inner.scripts_get_balance(scripts).await
+    }
+
+    async fn script_get_balance_with_retry(
+        &self,
+        script: &bitcoin::Script,
+        retries: u64,
+    ) -> Result<BtcScriptBalance> {
+        self.inner
+            .script_get_balance_with_retry(script, retries)
+            .await
+    }
+
+    async fn verify_tx(
+        &self,
+        address: &bitcoin::Address,
+        tx_id: &str,
+        tx_hex: &str,
+        verify_confirmation: bool,
+    ) -> Result<bitcoin::Transaction> {
+        self.inner
+            .verify_tx(address, tx_id, tx_hex, verify_confirmation)
+            .await
+    }
+
+    async fn recommended_fees(&self) -> Result<RecommendedFees> {
+        self.inner.recommended_fees().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use anyhow::Result;
+    use sdk_common::utils::Arc;
+    use tokio::sync::RwLock;
+    use tokio_with_wasm::alias as tokio;
+
+    use crate::{
+        chain::liquid::LiquidChainService,
+        elements,
+        model::{Broadcaster, BroadcasterError},
+        test_utils::chain::MockLiquidChainService,
+    };
+
+    use super::BroadcastingLiquidChainService;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    /// Records the broadcast txs, returning their txid or, if set, a fixed txid
+    #[derive(Default)]
+    struct RecordingBroadcaster {
+        tx_hexes: Mutex<Vec<String>>,
+        fixed_tx_id: Option<String>,
+    }
+
+    #[sdk_macros::async_trait]
+    impl Broadcaster for RecordingBroadcaster {
+        async fn broadcast_liquid_tx(&self, tx_hex: String) -> Result<String, BroadcasterError> {
+            let tx: elements::Transaction =
+                elements::encode::deserialize(&hex::decode(&tx_hex).unwrap()).unwrap();
+            self.tx_hexes.lock().unwrap().push(tx_hex);
+            Ok(self
+                .fixed_tx_id
+                .clone()
+                .unwrap_or_else(|| tx.txid().to_string()))
+        }
+
+        async fn broadcast_bitcoin_tx(&self, _tx_hex: String) -> Result<String, BroadcasterError> {
+            Err(BroadcasterError::Generic {
+                err: "Unexpected Bitcoin tx".to_string(),
+            })
+        }
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_broadcast_uses_broadcaster() -> Result<()> {
+        let broadcaster = Arc::new(RecordingBroadcaster::default());
+        let chain_service = BroadcastingLiquidChainService::new(
+            Arc::new(MockLiquidChainService::new()),
+            Arc::new(RwLock::new(Some(broadcaster.clone()))),
+        );
+
+        let tx = elements::Transaction {
+            version: 2,
+            lock_time: elements::LockTime::ZERO,
+            input: vec![],
+            output: vec![],
+        };
+        let broadcast_tx_id = chain_service.broadcast(&tx).await?;
+        assert_eq!(broadcast_tx_id, tx.txid());
+        assert_eq!(
+            *broadcaster.tx_hexes.lock().unwrap(),
+            vec![hex::encode(elements::encode::serialize(&tx))]
+        );
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_broadcast_rejects_unexpected_txid() -> Result<()> {
+        let broadcaster = Arc::new(RecordingBroadcaster {
+            fixed_tx_id: Some(
+                "1111111111111111111111111111111111111111111111111111111111111111".to_string(),
+            ),
+            ..Default::default()
+        });
+        let chain_service = BroadcastingLiquidChainService::new(
+            Arc::new(MockLiquidChainService::new()),
+            Arc::new(RwLock::new(Some(broadcaster))),
+        );
+
+        let tx = elements::Transaction {
+            version: 2,
+            lock_time: elements::LockTime::ZERO,
+            input: vec![],
+            output: vec![],
+        };
+        assert!(chain_service.broadcast(&tx).await.is_err());
+
+        Ok(())
+    }
+}
@@ -6,8 +6,8 @@ use anyhow::{anyhow, bail, Context as _, Result};
 use tokio::sync::RwLock;
 
 use crate::{
-    elements::{Address, OutPoint, Script, Transaction, Txid},
-    model::{BlockchainExplorer, Config, Utxo},
+    elements::{Address, Script, Transaction, Txid},
+    model::{BlockInfo, BlockchainExplorer, Config, Utxo},
     utils,
 };
 
@@ -17,7 +17,7 @@ use lwk_wollet::{
 };
 use sdk_common::bitcoin::hashes::hex::ToHex as _;
 
-use super::{History, LiquidChainService};
+use super::{get_history_transactions, history_utxos, History, LiquidChainService};
 
 pub(crate) struct ElectrumLiquidChainService {
     config: Config,
@@ -57,6 +57,15 @@ impl LiquidChainService for ElectrumLiquidChainService {
             .map(|header| header.height)?)
     }
 
+    async fn tip_block(&self) -> Result<BlockInfo> {
+        let header = self.get_client()?.write().await.tip()?;
+        Ok(BlockInfo {
+            height: header.height,
+            hash: header.block_hash().to_string(),
+            timestamp: header.time,
+        })
+    }
+
     async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
         Ok(self.get_client()?.read().await.broadcast(tx)?)
     }
@@ -116,31 +125,10 @@ impl LiquidChainService for ElectrumLiquidChainService {
     async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
         let history = self.get_script_history_with_retry(script, 10).await?;
 
-        let mut utxos: Vec<Utxo> = vec![];
-        for history_item in history {
-            match self.get_transaction_hex(&history_item.txid).await {
-                Ok(Some(tx)) => {
-                    let mut new_utxos = tx
-                        .output
-                        .iter()
-                        .enumerate()
-                        .map(|(vout, output)| {
-                            Utxo::Liquid(Box::new((
-                                OutPoint::new(history_item.txid, vout as u32),
-                                output.clone(),
-                            )))
-                        })
-                        .collect();
-                    utxos.append(&mut new_utxos);
-                }
-                _ => {
-                    log::warn!("Could not retrieve transaction from history item");
-                    continue;
-                }
-            }
-        }
-
-        Ok(utxos)
+        // Fetch all the history transactions in a single batch request
+        let txids: Vec<Txid> = history.iter().map(|h| h.txid).collect();
+        let txs = get_history_transactions(self, &txids).await;
+        Ok(history_utxos(&txids, &txs))
     }
 
     async fn verify_tx(
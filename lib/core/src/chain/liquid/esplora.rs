@@ -5,7 +5,7 @@ use tokio::sync::RwLock;
 use tokio_with_wasm::alias as tokio;
 
 use crate::{
-    elements::{Address, OutPoint, Script, Transaction, Txid},
+    elements::{Address, Script, Transaction, Txid},
     model::{BlockchainExplorer, Config, Utxo, BREEZ_LIQUID_ESPLORA_URL},
     utils,
 };
@@ -16,7 +16,7 @@ use lwk_wollet::{
 };
 use sdk_common::bitcoin::hashes::hex::ToHex as _;
 
-use super::{History, LiquidChainService};
+use super::{get_history_transactions, history_utxos, History, LiquidChainService};
 
 pub(crate) struct EsploraLiquidChainService {
     config: Config,
@@ -55,7 +55,10 @@ impl EsploraLiquidChainService {
                         }
                     };
                 }
-                builder.timeout(3).waterfalls(*use_waterfalls).build()
+                builder
+                    .timeout(self.config.chain_service_timeout_sec())
+                    .waterfalls(*use_waterfalls)
+                    .build()
             }
             #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
             BlockchainExplorer::Electrum { .. } => {
@@ -80,6 +83,15 @@ impl LiquidChainService for EsploraLiquidChainService {
             .map(|header| header.height)?)
     }
 
+    async fn tip_block(&self) -> Result<BlockInfo> {
+        let header = self.get_client()?.write().await.tip().await?;
+        Ok(BlockInfo {
+            height: header.height,
+            hash: header.block_hash().to_string(),
+            timestamp: header.time,
+        })
+    }
+
     async fn broadcast(&self, tx: &Transaction) -> Result<Txid> {
         Ok(self.get_client()?.read().await.broadcast(tx).await?)
     }
@@ -145,31 +157,10 @@ impl LiquidChainService for EsploraLiquidChainService {
     async fn get_script_utxos(&self, script: &Script) -> Result<Vec<Utxo>> {
         let history = self.get_script_history_with_retry(script, 10).await?;
 
-        let mut utxos: Vec<Utxo> = vec![];
-        for history_item in history {
-            match self.get_transaction_hex(&history_item.txid).await {
-                Ok(Some(tx)) => {
-                    let mut new_utxos = tx
-                        .output
-                        .iter()
-                        .enumerate()
-                        .map(|(vout, output)| {
-                            Utxo::Liquid(Box::new((
-                                OutPoint::new(history_item.txid, vout as u32),
-                                output.clone(),
-                            )))
-                        })
-                        .collect();
-                    utxos.append(&mut new_utxos);
-                }
-                _ => {
-                    log::warn!("Could not retrieve transaction from history item");
-                    continue;
-                }
-            }
-        }
-
-        return Ok(utxos);
+        // Fetch all the history transactions in a single batch request
+        let txids: Vec<Txid> = history.iter().map(|h| h.txid).collect();
+        let txs = get_history_transactions(self, &txids).await;
+        Ok(history_utxos(&txids, &txs))
     }
 
     async fn verify_tx(
@@ -2,23 +2,69 @@ pub(crate) mod electrum;
 pub(crate) mod esplora;
 
 use anyhow::Result;
-use elements::{Address, Script, Transaction, Txid};
+use elements::{Address, OutPoint, Script, Transaction, Txid};
 use maybe_sync::{MaybeSend, MaybeSync};
 use mockall::automock;
 
 use crate::{
     elements,
-    model::{LBtcHistory, Utxo},
+    model::{BlockInfo, LBtcHistory, Utxo},
 };
 
 pub(crate) type History = LBtcHistory;
 
+/// Fetches the transactions of a script history in a single batch request. If the batch fails,
+/// e.g. because one of the transactions can't be fetched, they're fetched one by one instead,
+/// skipping the ones that fail.
+pub(crate) async fn get_history_transactions<S: LiquidChainService + ?Sized>(
+    service: &S,
+    txids: &[Txid],
+) -> Vec<Transaction> {
+    match service.get_transactions(txids).await {
+        Ok(txs) => txs,
+        Err(e) => {
+            log::warn!("Batch fetch of history txs failed, fetching them one by one: {e:?}");
+            let mut txs = vec![];
+            for txid in txids {
+                match service.get_transactions(&[*txid]).await {
+                    Ok(tx) => txs.extend(tx),
+                    Err(e) => log::warn!("Failed to fetch history transaction {txid}: {e:?}"),
+                }
+            }
+            txs
+        }
+    }
+}
+
+/// Lists the outputs of the transactions in a script history as utxos. The transactions that
+/// could not be fetched are skipped.
+pub(crate) fn history_utxos(txids: &[Txid], txs: &[Transaction]) -> Vec<Utxo> {
+    if txs.len() < txids.len() {
+        log::warn!(
+            "Could not retrieve {} transactions from history items",
+            txids.len() - txs.len()
+        );
+    }
+
+    txs.iter()
+        .flat_map(|tx| {
+            let txid = tx.txid();
+            tx.output.iter().enumerate().map(move |(vout, output)| {
+                Utxo::Liquid(Box::new((OutPoint::new(txid, vout as u32), output.clone())))
+            })
+        })
+        .collect()
+}
+
 #[automock]
 #[sdk_macros::async_trait]
 pub trait LiquidChainService: MaybeSend + MaybeSync {
     /// Get the blockchain latest block
     async fn tip(&self) -> Result<u32>;
 
+    /// Get the details of the blockchain latest block
+    async fn tip_block(&self) -> Result<BlockInfo>;
+
     /// Broadcast a transaction
     async fn broadcast(&self, tx: &Transaction) -> Result<Txid>;
 
@@ -55,3 +101,84 @@ pub trait LiquidChainService: MaybeSend + MaybeSync {
         verify_confirmation: bool,
     ) -> Result<Transaction>;
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::elements::{LockTime, OutPoint, Transaction, TxOut};
+    use crate::model::Utxo;
+
+    use super::{get_history_transactions, history_utxos, MockLiquidChainService};
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_history_utxos() {
+        let new_tx = |version: u32, outputs: usize| Transaction {
+            version,
+            lock_time: LockTime::ZERO,
+            input: vec![],
+            output: vec![TxOut::default(); outputs],
+        };
+        let tx_1 = new_tx(1, 2);
+        let tx_2 = new_tx(2, 1);
+        let missing_tx = new_tx(3, 1);
+
+        // The missing tx is skipped
+        let utxos = history_utxos(
+            &[tx_1.txid(), missing_tx.txid(), tx_2.txid()],
+            &[tx_1.clone(), tx_2.clone()],
+        );
+        let outpoints: Vec<OutPoint> = utxos
+            .iter()
+            .map(|utxo| match utxo {
+                Utxo::Liquid(utxo) => utxo.0,
+                Utxo::Bitcoin(_) => panic!("Expected a Liquid utxo"),
+            })
+            .collect();
+        assert_eq!(
+            outpoints,
+            vec![
+                OutPoint::new(tx_1.txid(), 0),
+                OutPoint::new(tx_1.txid(), 1),
+                OutPoint::new(tx_2.txid(), 0),
+            ]
+        );
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_get_history_transactions() {
+        let new_tx = |version: u32| Transaction {
+            version,
+            lock_time: LockTime::ZERO,
+            input: vec![],
+            output: vec![TxOut::default()],
+        };
+        let tx_1 = new_tx(1);
+        let tx_2 = new_tx(2);
+        let missing_tx = new_tx(3);
+        let txids = [tx_1.txid(), missing_tx.txid(), tx_2.txid()];
+
+        // The batch fails because of the missing tx, so the other txs are fetched one by one
+        let mut service = MockLiquidChainService::new();
+        let available_txs = vec![tx_1.clone(), tx_2.clone()];
+        service.expect_get_transactions().returning(move |txids| {
+            txids
+                .iter()
+                .map(|txid| {
+                    available_txs
+                        .iter()
+                        .find(|tx| tx.txid() == *txid)
+                        .cloned()
+                        .ok_or(anyhow!("Transaction not found"))
+                })
+                .collect()
+        });
+        assert_eq!(
+            get_history_transactions(&service, &txids).await,
+            vec![tx_1, tx_2]
+        );
+    }
+}
@@ -1,2 +1,3 @@
 pub(crate) mod bitcoin;
+pub(crate) mod broadcaster;
 pub(crate) mod liquid;
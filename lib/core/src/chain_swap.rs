@@ -11,19 +11,20 @@ use futures_util::TryFutureExt;
 use log::{debug, error, info, warn};
 use lwk_wollet::hashes::hex::DisplayHex;
 use sdk_common::utils::Arc;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, Mutex};
 
 use crate::{
     chain::{bitcoin::BitcoinChainService, liquid::LiquidChainService},
     elements, ensure_sdk,
     error::{PaymentError, SdkError, SdkResult},
+    in_flight::InFlightOperations,
     model::{
         BlockListener, BtcHistory, ChainSwap, ChainSwapUpdate, Config, Direction, LBtcHistory,
         PaymentState::{self, *},
-        PaymentTxData, PaymentType, Swap, SwapScriptV2, Transaction as SdkTransaction,
-        LIQUID_FEE_RATE_MSAT_PER_VBYTE,
+        PaymentTxData, PaymentType, PrepareRefundResponse, Swap, SwapScriptV2,
+        Transaction as SdkTransaction, LIQUID_FEE_RATE_MSAT_PER_VBYTE,
     },
-    persist::Persister,
+    persist::{swap_intent::SwapIntentKind, Persister},
     swapper::Swapper,
     utils,
     wallet::OnchainWallet,
@@ -41,6 +42,10 @@ pub(crate) struct ChainSwapHandler {
     liquid_chain_service: Arc<dyn LiquidChainService>,
     bitcoin_chain_service: Arc<dyn BitcoinChainService>,
     subscription_notifier: broadcast::Sender<String>,
+    in_flight: Arc<InFlightOperations>,
+    /// Serializes the user lockups, as the status updates of different swaps are handled
+    /// concurrently and their lockup txs would otherwise spend the same wallet UTXOs
+    lockup_lock: Mutex<()>,
 }
 
 #[sdk_macros::async_trait]
@@ -69,6 +74,7 @@ impl ChainSwapHandler {
         swapper: Arc<dyn Swapper>,
         liquid_chain_service: Arc<dyn LiquidChainService>,
         bitcoin_chain_service: Arc<dyn BitcoinChainService>,
+        in_flight: Arc<InFlightOperations>,
     ) -> Result<Self> {
         let (subscription_notifier, _) = broadcast::channel::<String>(30);
         Ok(Self {
@@ -79,6 +85,8 @@ impl ChainSwapHandler {
             liquid_chain_service,
             bitcoin_chain_service,
             subscription_notifier,
+            in_flight,
+            lockup_lock: Mutex::new(()),
         })
     }
 
@@ -107,12 +115,52 @@ impl ChainSwapHandler {
             }
         }
 
+        if self.config.simulation.is_some() {
+            return self.on_new_simulated_status(&swap, update);
+        }
+
         match swap.direction {
             Direction::Incoming => self.on_new_incoming_status(&swap, update).await,
             Direction::Outgoing => self.on_new_outgoing_status(&swap, update).await,
         }
     }
 
+    /// A simulated swap has no lockup txs to verify, claim or refund, so only its state is
+    /// updated.
+    ///
+    /// An outgoing swap is only completed once its claim tx has the
+    /// [Config::outgoing_min_confirmations](crate::model::Config::outgoing_min_confirmations),
+    /// signalled by the simulation with [ChainSwapStates::TransactionClaimed].
+    fn on_new_simulated_status(&self, swap: &ChainSwap, update: &boltz::SwapStatus) -> Result<()> {
+        let id = &update.id;
+        let status = &update.status;
+        let swap_state = ChainSwapStates::from_str(status)
+            .map_err(|_| anyhow!("Invalid ChainSwapState for Chain Swap {id}: {status}"))?;
+
+        let to_state = match swap_state {
+            ChainSwapStates::TransactionMempool
+            | ChainSwapStates::TransactionConfirmed
+            | ChainSwapStates::TransactionServerMempool => Pending,
+            ChainSwapStates::TransactionServerConfirmed => match swap.direction {
+                Direction::Incoming => Complete,
+                Direction::Outgoing => Pending,
+            },
+            ChainSwapStates::TransactionClaimed => Complete,
+            ChainSwapStates::TransactionLockupFailed => Failed,
+            _ => {
+                debug!("Unhandled state for simulated Chain Swap {id}: {swap_state:?}");
+                return Ok(());
+            }
+        };
+        info!("Handling simulated Chain Swap transition to {swap_state:?} for swap {id}");
+        self.update_swap_info(&ChainSwapUpdate {
+            swap_id: id.clone(),
+            to_state,
+            ..Default::default()
+        })?;
+        Ok(())
+    }
+
     async fn claim_incoming(&self, height: u32) -> Result<()> {
         let chain_swaps: Vec<ChainSwap> = self
             .persister
@@ -198,7 +246,7 @@ impl ChainSwapHandler {
                 ))?;
         if *tx_height > 0 {
             info!("Chain Swap {swap_id} server lockup tx is confirmed");
-            self.claim(swap_id)
+            self.auto_claim(swap)
                 .await
                 .map_err(|e| anyhow!("Could not claim Chain Swap {swap_id}: {e:?}"))?;
         }
@@ -277,8 +325,18 @@ impl ChainSwapHandler {
                             ..Default::default()
                         })?;
 
+                        // If the amount is greater than the zero-conf limit
+                        let max_amount_sat = self.config.zero_conf_max_amount_sat();
+                        let receiver_amount_sat = swap
+                            .accepted_receiver_amount_sat
+                            .unwrap_or(swap.receiver_amount_sat);
+                        if receiver_amount_sat > max_amount_sat {
+                            warn!("[Chain Swap {id}] Amount is too high to claim with zero-conf ({receiver_amount_sat} sat > {max_amount_sat} sat). Waiting for confirmation...");
+                            return Ok(());
+                        }
+
                         if swap.accept_zero_conf && swap.metadata.is_local {
-                            self.claim(&id).await.map_err(|e| {
+                            self.auto_claim(swap).await.map_err(|e| {
                                 error!("Could not cooperate Chain Swap {id} claim: {e}");
                                 anyhow!("Could not post claim details. Err: {e:?}")
                             })?;
@@ -322,7 +380,7 @@ impl ChainSwapHandler {
                             Ok(_) => {
                                 info!("Server lockup transaction was verified for incoming Chain Swap {}", swap.id);
                                 if swap.metadata.is_local {
-                                    self.claim(&id).await.map_err(|e| {
+                                    self.auto_claim(swap).await.map_err(|e| {
                                         error!("Could not cooperate Chain Swap {id} claim: {e}");
                                         anyhow!("Could not post claim details. Err: {e:?}")
                                     })?;
@@ -431,6 +489,8 @@ impl ChainSwapHandler {
                 debug!("Zero-amount swap validated. Auto-accepting...");
                 self.persister
                     .update_actual_payer_amount(&id, user_lockup_amount_sat)?;
+                self.persister
+                    .insert_swap_intent(&id, SwapIntentKind::FeesAccepted, None)?;
                 self.persister
                     .update_accepted_receiver_amount(&id, Some(receiver_amount_sat))?;
                 self.swapper
@@ -438,9 +498,15 @@ impl ChainSwapHandler {
                     .inspect_err(|e| {
                         error!("Failed to accept zero-amount swap {id} quote: {e} - trying to erase the accepted receiver amount...");
                         let _ = self.persister.update_accepted_receiver_amount(&id, None);
+                        let _ = self
+                            .persister
+                            .delete_swap_intent(&id, SwapIntentKind::FeesAccepted);
                     })
                     .await?;
-                self.persister.set_chain_swap_auto_accepted_fees(&id)
+                self.persister.set_chain_swap_auto_accepted_fees(&id)?;
+                self.persister
+                    .delete_swap_intent(&id, SwapIntentKind::FeesAccepted)?;
+                Ok(())
             }
             ValidateAmountlessSwapResult::RequiresUserAction {
                 user_lockup_amount_sat,
@@ -550,6 +616,8 @@ impl ChainSwapHandler {
 
                     // Create the user lockup tx
                     (_, None) => {
+                        // The lockup broadcast and the swap update that follows it are not interrupted
+                        let _in_flight = self.in_flight.start();
                         let create_response = swap.get_boltz_create_response()?;
                         let user_lockup_tx = self.lockup_funds(&id, &create_response).await?;
                         let lockup_tx_id = user_lockup_tx.txid().to_string();
@@ -772,6 +840,7 @@ impl ChainSwapHandler {
             lockup_details.amount, lockup_details.lockup_address
         );
 
+        let _lockup_guard = self.lockup_lock.lock().await;
         let lockup_tx = self
             .onchain_wallet
             .build_tx_or_drain_tx(
@@ -791,6 +860,11 @@ impl ChainSwapHandler {
         debug!(
           "Successfully broadcast lockup transaction for Chain Swap {swap_id}. Lockup tx id: {lockup_tx_id}"
         );
+        // The wallet is scanned before releasing the lock, so the next lockup doesn't spend the
+        // UTXOs spent by this one
+        if let Err(e) = self.onchain_wallet.full_scan().await {
+            warn!("Failed to scan the wallet after the lockup of Chain Swap {swap_id}: {e:?}");
+        }
         Ok(lockup_tx)
     }
 
@@ -831,6 +905,11 @@ impl ChainSwapHandler {
         let swap = self.fetch_chain_swap_by_id(&swap_update.swap_id)?;
         Self::validate_state_transition(swap.state, swap_update.to_state)?;
         self.persister.try_handle_chain_swap_update(swap_update)?;
+        if swap_update.refund_tx_id.is_some() {
+            // The refund tx id is now persisted, so the journaled refund is no longer needed
+            self.persister
+                .delete_swap_intent(&swap_update.swap_id, SwapIntentKind::Refund)?;
+        }
         let updated_swap = self.fetch_chain_swap_by_id(&swap_update.swap_id)?;
         if updated_swap != swap {
             let _ = self.subscription_notifier.send(updated_swap.id);
@@ -838,7 +917,23 @@ impl ChainSwapHandler {
         Ok(())
     }
 
-    async fn claim(&self, swap_id: &str) -> Result<(), PaymentError> {
+    /// Claims the swap once the server lockup is verified, unless it is incoming and
+    /// [Config::manual_claim] is set
+    #[tracing::instrument(skip_all, fields(swap_id = %swap.id))]
+    async fn auto_claim(&self, swap: &ChainSwap) -> Result<(), PaymentError> {
+        if self.config.manual_claim && swap.direction == Direction::Incoming {
+            debug!(
+                "Chain Swap {} is claimable, waiting for a manual claim",
+                swap.id
+            );
+            return Ok(());
+        }
+        self.claim(&swap.id).await
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub(crate) async fn claim(&self, swap_id: &str) -> Result<(), PaymentError> {
+        let _in_flight = self.in_flight.start();
         let swap = self.fetch_chain_swap_by_id(swap_id)?;
         ensure_sdk!(swap.claim_tx_id.is_none(), PaymentError::AlreadyClaimed);
 
@@ -859,7 +954,11 @@ impl ChainSwapHandler {
         // Set the swap claim_tx_id before broadcasting.
         // If another claim_tx_id has been set in the meantime, don't broadcast the claim tx
         let tx_id = claim_tx.txid();
-        match self
+        // Journal the claim, so that a claim_tx_id left set without a broadcast tx is reconciled
+        // at startup
+        self.persister
+            .insert_swap_intent(swap_id, SwapIntentKind::Claim, Some(&tx_id))?;
+        let claim_res = match self
             .persister
             .set_chain_swap_claim(swap_id, claim_address, &tx_id)
         {
@@ -940,7 +1039,10 @@ impl ChainSwapHandler {
                 );
                 Err(err)
             }
-        }
+        };
+        self.persister
+            .delete_swap_intent(swap_id, SwapIntentKind::Claim)?;
+        claim_res
     }
 
     pub(crate) async fn prepare_refund(
@@ -948,22 +1050,30 @@ impl ChainSwapHandler {
         lockup_address: &str,
         refund_address: &str,
         fee_rate_sat_per_vb: u32,
-    ) -> SdkResult<(u32, u64, Option<String>)> {
+    ) -> SdkResult<PrepareRefundResponse> {
         let swap = self
             .persister
             .fetch_chain_swap_by_lockup_address(lockup_address)?
             .ok_or(SdkError::generic(format!(
                 "Chain Swap with lockup address {lockup_address} not found"
             )))?;
+        let id = swap.id.clone();
 
         let refund_tx_id = swap.refund_tx_id.clone();
         if let Some(refund_tx_id) = &refund_tx_id {
-            warn!(
-                "A refund tx for Chain Swap {} was already broadcast: txid {refund_tx_id}",
-                swap.id
-            );
+            warn!("A refund tx for Chain Swap {id} was already broadcast: txid {refund_tx_id}");
         }
 
+        let script_pubkey = swap.get_receive_lockup_swap_script_pubkey(self.config.network)?;
+        let refundable_amount_sat = self
+            .bitcoin_chain_service
+            .get_script_utxos(&script_pubkey)
+            .await?
+            .iter()
+            .filter_map(|utxo| utxo.as_bitcoin())
+            .map(|(_, txo)| txo.value.to_sat())
+            .sum::<u64>();
+
         let (refund_tx_size, refund_tx_fees_sat) = self
             .swapper
             .estimate_refund_broadcast(
@@ -973,10 +1083,24 @@ impl ChainSwapHandler {
                 true,
             )
             .await?;
+        ensure_sdk!(
+            refundable_amount_sat > refund_tx_fees_sat,
+            SdkError::generic(format!(
+                "Refund fees of {refund_tx_fees_sat} sats exceed the {refundable_amount_sat} sats refundable from Chain Swap {id}"
+            ))
+        );
 
-        Ok((refund_tx_size, refund_tx_fees_sat, refund_tx_id))
+        Ok(PrepareRefundResponse {
+            tx_vsize: refund_tx_size,
+            tx_fee_sat: refund_tx_fees_sat,
+            fee_rate_sat_per_vbyte: fee_rate_sat_per_vb,
+            refundable_amount_sat,
+            refund_amount_sat: refundable_amount_sat - refund_tx_fees_sat,
+            last_refund_tx_id: refund_tx_id,
+        })
     }
 
+    #[tracing::instrument(skip(self, refund_address))]
     pub(crate) async fn refund_incoming_swap(
         &self,
         lockup_address: &str,
@@ -984,6 +1108,7 @@ impl ChainSwapHandler {
         broadcast_fee_rate_sat_per_vb: u32,
         is_cooperative: bool,
     ) -> Result<String, PaymentError> {
+        let _in_flight = self.in_flight.start();
         let swap = self
             .persister
             .fetch_chain_swap_by_lockup_address(lockup_address)?
@@ -1031,6 +1156,11 @@ impl ChainSwapHandler {
                 err: format!("Unexpected refund tx type returned for incoming Chain swap {id}",),
             });
         };
+        self.persister.insert_swap_intent(
+            &swap.id,
+            SwapIntentKind::Refund,
+            Some(&refund_tx.txid().to_string()),
+        )?;
         let refund_tx_id = self
             .bitcoin_chain_service
             .broadcast(&refund_tx)
@@ -1052,11 +1182,13 @@ impl ChainSwapHandler {
         Ok(refund_tx_id)
     }
 
+    #[tracing::instrument(skip_all, fields(swap_id = %swap.id))]
     pub(crate) async fn refund_outgoing_swap(
         &self,
         swap: &ChainSwap,
         is_cooperative: bool,
     ) -> Result<String, PaymentError> {
+        let _in_flight = self.in_flight.start();
         ensure_sdk!(
             swap.refund_tx_id.is_none(),
             PaymentError::Generic {
@@ -1117,6 +1249,11 @@ impl ChainSwapHandler {
                 ),
             });
         };
+        self.persister.insert_swap_intent(
+            &swap.id,
+            SwapIntentKind::Refund,
+            Some(&refund_tx.txid().to_string()),
+        )?;
         let refund_tx_id = self
             .liquid_chain_service
             .broadcast(&refund_tx)
@@ -1509,13 +1646,18 @@ mod tests {
     use anyhow::Result;
     use std::collections::{HashMap, HashSet};
 
+    use boltz_client::boltz::{self, ChainSwapStates};
+
     use crate::{
         model::{
-            ChainSwapUpdate, Direction,
+            ChainSwapUpdate, Config, Direction,
             PaymentState::{self, *},
+            SimulationConfig,
         },
         test_utils::{
-            chain_swap::{new_chain_swap, new_chain_swap_handler},
+            chain_swap::{
+                new_chain_swap, new_chain_swap_handler, new_chain_swap_handler_with_config,
+            },
             persist::create_persister,
         },
     };
@@ -1641,4 +1783,85 @@ mod tests {
 
         Ok(())
     }
+
+    #[sdk_macros::async_test_all]
+    async fn test_simulated_outgoing_chain_swap_confirmations() -> Result<()> {
+        create_persister!(persister);
+        let mut config = Config::testnet_esplora(None);
+        config.simulation = Some(SimulationConfig {
+            status_update_delay_sec: 0,
+            failure_rate: 0.0,
+        });
+        let chain_swap_handler = new_chain_swap_handler_with_config(persister.clone(), config)?;
+        let status = |id: &str, state: ChainSwapStates| boltz::SwapStatus {
+            id: id.to_string(),
+            status: state.to_string(),
+            ..Default::default()
+        };
+
+        // An incoming swap completes once the server lockup is confirmed
+        let incoming_swap = new_chain_swap(
+            Direction::Incoming,
+            Some(Pending),
+            false,
+            None,
+            false,
+            false,
+            None,
+        );
+        persister.insert_or_update_chain_swap(&incoming_swap)?;
+        chain_swap_handler
+            .on_new_status(&status(
+                &incoming_swap.id,
+                ChainSwapStates::TransactionServerConfirmed,
+            ))
+            .await?;
+        assert_eq!(
+            persister
+                .fetch_chain_swap_by_id(&incoming_swap.id)?
+                .unwrap()
+                .state,
+            Complete
+        );
+
+        // An outgoing swap stays pending until its claim tx has the outgoing confirmations
+        let outgoing_swap = new_chain_swap(
+            Direction::Outgoing,
+            Some(Pending),
+            false,
+            None,
+            false,
+            false,
+            None,
+        );
+        persister.insert_or_update_chain_swap(&outgoing_swap)?;
+        chain_swap_handler
+            .on_new_status(&status(
+                &outgoing_swap.id,
+                ChainSwapStates::TransactionServerConfirmed,
+            ))
+            .await?;
+        assert_eq!(
+            persister
+                .fetch_chain_swap_by_id(&outgoing_swap.id)?
+                .unwrap()
+                .state,
+            Pending
+        );
+        chain_swap_handler
+            .on_new_status(&status(
+                &outgoing_swap.id,
+                ChainSwapStates::TransactionClaimed,
+            ))
+            .await?;
+        assert_eq!(
+            persister
+                .fetch_chain_swap_by_id(&outgoing_swap.id)?
+                .unwrap()
+                .state,
+            Complete
+        );
+
+        Ok(())
+    }
 }
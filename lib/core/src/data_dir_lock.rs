@@ -0,0 +1,69 @@
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::Path;
+
+use crate::error::SdkError;
+
+/// The name of the lock file created in the wallet data directory
+const LOCK_FILE_NAME: &str = ".lock";
+
+/// An exclusive advisory lock on a wallet data directory, held while an SDK instance is
+/// connected, so that another process (e.g. a notification extension racing the main app)
+/// cannot use the same LWK cache and database at the same time.
+///
+/// The lock is released when dropped, or by the OS when the process exits.
+pub(crate) struct DataDirLock {
+    _file: File,
+}
+
+impl DataDirLock {
+    /// Acquires the lock on the directory without waiting, creating the directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// * [SdkError::DataDirLocked] - if the directory is locked by another process or SDK instance
+    pub(crate) fn acquire(dir: &str) -> Result<Self, SdkError> {
+        let to_sdk_error = |e: std::io::Error| {
+            SdkError::generic(format!("Could not lock the data directory {dir}: {e}"))
+        };
+        std::fs::create_dir_all(dir).map_err(to_sdk_error)?;
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(false)
+            .open(Path::new(dir).join(LOCK_FILE_NAME))
+            .map_err(to_sdk_error)?;
+        match file.try_lock() {
+            Ok(()) => Ok(Self { _file: file }),
+            Err(TryLockError::WouldBlock) => Err(SdkError::DataDirLocked {
+                dir: dir.to_string(),
+            }),
+            Err(TryLockError::Error(e)) => Err(to_sdk_error(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::error::SdkError;
+
+    use super::DataDirLock;
+
+    #[sdk_macros::test_not_wasm]
+    fn test_data_dir_lock() -> Result<()> {
+        let temp_dir = tempdir::TempDir::new("data-dir-lock")?;
+        let dir = temp_dir.path().join("wallet").to_string_lossy().to_string();
+
+        let lock = DataDirLock::acquire(&dir)?;
+        assert!(matches!(
+            DataDirLock::acquire(&dir),
+            Err(SdkError::DataDirLocked { .. })
+        ));
+
+        drop(lock);
+        assert!(DataDirLock::acquire(&dir).is_ok());
+
+        Ok(())
+    }
+}
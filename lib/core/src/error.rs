@@ -2,7 +2,10 @@ use anyhow::Error;
 use lwk_wollet::secp256k1;
 use sdk_common::prelude::{LnUrlAuthError, LnUrlPayError, LnUrlWithdrawError};
 
-use crate::payjoin::error::PayjoinError;
+use crate::{
+    model::{BackupTransportError, FiatRateProviderError},
+    payjoin::error::PayjoinError,
+};
 
 pub type SdkResult<T, E = SdkError> = Result<T, E>;
 
@@ -21,9 +24,15 @@ pub enum SdkError {
     #[error("Liquid SDK instance is already running")]
     AlreadyStarted,
 
+    #[error("The data directory {dir} is already in use by another process or SDK instance")]
+    DataDirLocked { dir: String },
+
     #[error("Error: {err}")]
     Generic { err: String },
 
+    #[error("Invalid backup: {err}")]
+    InvalidBackup { err: String },
+
     #[error("Liquid SDK instance is not running")]
     NotStarted,
 
@@ -40,21 +49,36 @@ impl SdkError {
 
 impl From<anyhow::Error> for SdkError {
     fn from(e: Error) -> Self {
-        SdkError::generic(e.to_string())
+        match e.downcast::<SdkError>() {
+            Ok(sdk_err) => sdk_err,
+            Err(e) => SdkError::generic(e.to_string()),
+        }
     }
 }
 
 impl From<boltz_client::error::Error> for SdkError {
     fn from(err: boltz_client::error::Error) -> Self {
         match err {
-            boltz_client::error::Error::HTTP(e) => {
-                SdkError::generic(format!("Could not contact servers: {e:?}"))
-            }
+            boltz_client::error::Error::HTTP(e) => SdkError::ServiceConnectivity {
+                err: format!("Could not contact servers: {e:?}"),
+            },
             _ => SdkError::generic(format!("{err:?}")),
         }
     }
 }
 
+impl From<BackupTransportError> for SdkError {
+    fn from(err: BackupTransportError) -> Self {
+        SdkError::generic(err.to_string())
+    }
+}
+
+impl From<FiatRateProviderError> for SdkError {
+    fn from(err: FiatRateProviderError) -> Self {
+        SdkError::generic(err.to_string())
+    }
+}
+
 impl From<secp256k1::Error> for SdkError {
     fn from(err: secp256k1::Error) -> Self {
         SdkError::generic(format!("{err:?}"))
@@ -90,9 +114,15 @@ pub enum PaymentError {
     #[error("The provided fees have expired")]
     InvalidOrExpiredFees,
 
+    #[error("Fees of {fees_sat} sat exceed the maximum of {max_fee_sat} sat")]
+    FeeLimitExceeded { fees_sat: u64, max_fee_sat: u64 },
+
     #[error("Cannot pay: not enough funds")]
     InsufficientFunds,
 
+    #[error("Payment of {amount_sat} sat exceeds the {remaining_sat} sat left in the spend limit")]
+    SpendLimitExceeded { amount_sat: u64, remaining_sat: u64 },
+
     #[error("Invalid description: {err}")]
     InvalidDescription { err: String },
 
@@ -125,6 +155,9 @@ pub enum PaymentError {
 
     #[error("Could not sign the transaction: {err}")]
     SignerError { err: String },
+
+    #[error("Service unavailable: {err}")]
+    ServiceUnavailable { err: String },
 }
 impl PaymentError {
     pub(crate) fn asset_error(err: &str) -> Self {
@@ -162,12 +195,47 @@ impl PaymentError {
             err: err.to_string(),
         }
     }
+
+    /// Whether the payment may succeed if retried later, e.g. once connectivity returns.
+    ///
+    /// A [PaymentError::PaymentTimeout] is not transient: the payment may still complete, so
+    /// retrying it risks paying twice.
+    pub(crate) fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::InvalidOrExpiredFees
+                | Self::FeeLimitExceeded { .. }
+                | Self::PairsNotFound
+                | Self::ServiceUnavailable { .. }
+        )
+    }
+
+    /// Whether the error is known to be returned before any funds are sent, i.e. before a
+    /// lockup or payment tx is broadcast.
+    pub(crate) fn is_before_broadcast(&self) -> bool {
+        matches!(
+            self,
+            Self::AlreadyPaid
+                | Self::AmountOutOfRange
+                | Self::AmountMissing { .. }
+                | Self::AssetError { .. }
+                | Self::InvalidNetwork { .. }
+                | Self::InvalidOrExpiredFees
+                | Self::FeeLimitExceeded { .. }
+                | Self::InsufficientFunds
+                | Self::SpendLimitExceeded { .. }
+                | Self::InvalidDescription { .. }
+                | Self::InvalidInvoice { .. }
+                | Self::PairsNotFound
+                | Self::SelfTransferNotSupported
+        )
+    }
 }
 
 impl From<boltz_client::error::Error> for PaymentError {
     fn from(err: boltz_client::error::Error) -> Self {
         match err {
-            boltz_client::error::Error::HTTP(e) => PaymentError::Generic {
+            boltz_client::error::Error::HTTP(e) => PaymentError::ServiceUnavailable {
                 err: format!("Could not contact servers: {e:?}"),
             },
             _ => PaymentError::Generic {
@@ -240,8 +308,11 @@ impl From<rusqlite::Error> for PaymentError {
 
 impl From<SdkError> for PaymentError {
     fn from(err: SdkError) -> Self {
-        Self::Generic {
-            err: err.to_string(),
+        match err {
+            SdkError::ServiceConnectivity { err } => Self::ServiceUnavailable { err },
+            _ => Self::Generic {
+                err: err.to_string(),
+            },
         }
     }
 }
@@ -2,32 +2,43 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use anyhow::Result;
-use log::{debug, info};
+use log::{debug, info, warn};
+use sdk_common::utils::Arc;
 use tokio::sync::{broadcast, RwLock};
 use uuid::Uuid;
 
-use crate::model::{EventListener, SdkEvent};
+use crate::model::{EventFilter, EventListener, SdkEvent};
+use crate::persist::Persister;
 
 pub(crate) struct EventManager {
-    listeners: RwLock<HashMap<String, Box<dyn EventListener>>>,
+    listeners: RwLock<HashMap<String, (Box<dyn EventListener>, Option<EventFilter>)>>,
     notifier: broadcast::Sender<SdkEvent>,
     is_paused: AtomicBool,
+    persister: Arc<Persister>,
+    /// Whether the journaled events are kept until they are dispatched to the event webhook
+    keep_undispatched: bool,
 }
 
 impl EventManager {
-    pub fn new() -> Self {
+    pub fn new(persister: Arc<Persister>, keep_undispatched: bool) -> Self {
         let (notifier, _) = broadcast::channel::<SdkEvent>(100);
 
         Self {
             listeners: Default::default(),
             notifier,
             is_paused: AtomicBool::new(false),
+            persister,
+            keep_undispatched,
         }
     }
 
-    pub async fn add(&self, listener: Box<dyn EventListener>) -> Result<String> {
+    pub async fn add(
+        &self,
+        listener: Box<dyn EventListener>,
+        filter: Option<EventFilter>,
+    ) -> Result<String> {
         let id = Uuid::new_v4().to_string();
-        (*self.listeners.write().await).insert(id.clone(), listener);
+        (*self.listeners.write().await).insert(id.clone(), (listener, filter));
         Ok(id)
     }
 
@@ -40,9 +51,20 @@ impl EventManager {
             true => info!("Event notifications are paused, not emitting event {e:?}"),
             false => {
                 debug!("Emitting event: {e:?}");
+                if let Err(err) = self
+                    .persister
+                    .insert_journaled_event(&e, self.keep_undispatched)
+                {
+                    warn!("Failed to journal event {e:?}: {err:?}");
+                }
                 let _ = self.notifier.send(e.clone());
 
-                for listener in (*self.listeners.read().await).values() {
+                for (listener, filter) in (*self.listeners.read().await).values() {
+                    if let Some(filter) = filter {
+                        if !filter.matches(&e) {
+                            continue;
+                        }
+                    }
                     listener.on_event(e.clone());
                 }
             }
@@ -58,8 +80,123 @@ impl EventManager {
         self.is_paused.store(true, Ordering::SeqCst);
     }
 
+    pub(crate) fn is_paused(&self) -> bool {
+        self.is_paused.load(Ordering::SeqCst)
+    }
+
     pub(crate) fn resume_notifications(&self) {
         info!("Resuming event notifications");
         self.is_paused.store(false, Ordering::SeqCst);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use sdk_common::utils::Arc;
+
+    use crate::model::{
+        EventFilter, EventListener, PaymentState, PaymentSwapType, SdkEvent, SdkEventKind,
+    };
+    use crate::test_utils::persist::create_persister;
+
+    use super::EventManager;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    struct CollectingEventListener {
+        events: Arc<Mutex<Vec<SdkEvent>>>,
+    }
+
+    impl EventListener for CollectingEventListener {
+        fn on_event(&self, e: SdkEvent) {
+            self.events.lock().unwrap().push(e);
+        }
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_filtered_listeners() -> anyhow::Result<()> {
+        create_persister!(persister);
+        let event_manager = EventManager::new(persister, false);
+
+        let all_events = Arc::new(Mutex::new(vec![]));
+        event_manager
+            .add(
+                Box::new(CollectingEventListener {
+                    events: all_events.clone(),
+                }),
+                None,
+            )
+            .await?;
+        let swap_events = Arc::new(Mutex::new(vec![]));
+        event_manager
+            .add(
+                Box::new(CollectingEventListener {
+                    events: swap_events.clone(),
+                }),
+                Some(EventFilter {
+                    kinds: Some(vec![SdkEventKind::SwapUpdated]),
+                    payment_ids: Some(vec!["swap-1".to_string()]),
+                    swap_types: Some(vec![PaymentSwapType::Receive]),
+                }),
+            )
+            .await?;
+
+        let matching = SdkEvent::SwapUpdated {
+            swap_id: "swap-1".to_string(),
+            swap_type: PaymentSwapType::Receive,
+            state: PaymentState::Pending,
+        };
+        event_manager.notify(matching.clone()).await;
+        event_manager
+            .notify(SdkEvent::SwapUpdated {
+                swap_id: "swap-2".to_string(),
+                swap_type: PaymentSwapType::Receive,
+                state: PaymentState::Pending,
+            })
+            .await;
+        event_manager
+            .notify(SdkEvent::SwapClaimBroadcast {
+                swap_id: "swap-1".to_string(),
+                tx_id: "tx-id".to_string(),
+            })
+            .await;
+        event_manager.notify(SdkEvent::Synced).await;
+
+        assert_eq!(all_events.lock().unwrap().len(), 4);
+        assert_eq!(*swap_events.lock().unwrap(), vec![matching]);
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_removed_listener() -> anyhow::Result<()> {
+        create_persister!(persister);
+        let event_manager = EventManager::new(persister, false);
+
+        let events = Arc::new(Mutex::new(vec![]));
+        let listener_id = event_manager
+            .add(
+                Box::new(CollectingEventListener {
+                    events: events.clone(),
+                }),
+                Some(EventFilter {
+                    kinds: Some(vec![SdkEventKind::Synced]),
+                    payment_ids: None,
+                    swap_types: None,
+                }),
+            )
+            .await?;
+
+        event_manager.notify(SdkEvent::Synced).await;
+        event_manager.remove(listener_id).await;
+        event_manager.notify(SdkEvent::Synced).await;
+
+        // Only the event emitted before the listener was removed is delivered
+        assert_eq!(*events.lock().unwrap(), vec![SdkEvent::Synced]);
+
+        Ok(())
+    }
+}
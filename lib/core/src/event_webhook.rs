@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use log::{debug, info};
+use lwk_wollet::hashes::{sha256, HashEngine, Hmac, HmacEngine};
+use sdk_common::prelude::RestClient;
+use sdk_common::utils::Arc;
+
+use crate::model::{EventWebhookConfig, ReplayEventsRequest};
+use crate::persist::Persister;
+
+/// The maximum number of events dispatched in a single round
+const EVENT_WEBHOOK_BATCH_SIZE: u32 = 100;
+const SIGNATURE_HEADER: &str = "X-Breez-Signature";
+
+/// Dispatches the journaled events to the configured [EventWebhookConfig] endpoint.
+///
+/// The sequence number of the last delivered event is persisted only after the endpoint accepts
+/// it, so an event is delivered again if the SDK stops before recording the delivery.
+pub(crate) struct EventWebhookDispatcher {
+    config: EventWebhookConfig,
+    persister: Arc<Persister>,
+    rest_client: Arc<dyn RestClient>,
+}
+
+impl EventWebhookDispatcher {
+    pub(crate) fn new(
+        config: EventWebhookConfig,
+        persister: Arc<Persister>,
+        rest_client: Arc<dyn RestClient>,
+    ) -> Self {
+        Self {
+            config,
+            persister,
+            rest_client,
+        }
+    }
+
+    /// Posts the events that were not yet delivered, in order. Stops at the first failed
+    /// delivery, which is retried on the next call.
+    pub(crate) async fn dispatch_pending_events(&self) -> Result<()> {
+        loop {
+            let events = self.persister.list_journaled_events(&ReplayEventsRequest {
+                since_seq: self.persister.get_last_dispatched_event_seq()?,
+                limit: Some(EVENT_WEBHOOK_BATCH_SIZE),
+            })?;
+            if events.is_empty() {
+                return Ok(());
+            }
+
+            for event in events {
+                let body = serde_json::to_string(&event)?;
+                let headers = HashMap::from([
+                    ("Content-Type".to_string(), "application/json".to_string()),
+                    (SIGNATURE_HEADER.to_string(), self.sign(&body)),
+                ]);
+                debug!("Dispatching event {} to webhook", event.seq);
+                let (response, status_code) = self
+                    .rest_client
+                    .post(&self.config.url, Some(headers), Some(body))
+                    .await?;
+                if !(200..300).contains(&status_code) {
+                    return Err(anyhow!(
+                        "Webhook rejected event {} with status code {status_code}: {response}",
+                        event.seq
+                    ));
+                }
+                self.persister.set_last_dispatched_event_seq(event.seq)?;
+                info!("Dispatched event {} to webhook", event.seq);
+            }
+        }
+    }
+
+    fn sign(&self, body: &str) -> String {
+        let mut engine = HmacEngine::<sha256::Hash>::new(self.config.secret.as_bytes());
+        engine.input(body.as_bytes());
+        Hmac::<sha256::Hash>::from_engine(engine).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::prelude::{MockResponse, MockRestClient};
+    use sdk_common::utils::Arc;
+
+    use crate::model::{EventWebhookConfig, SdkEvent};
+    use crate::test_utils::persist::create_persister;
+
+    use super::EventWebhookDispatcher;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::async_test_all]
+    async fn test_dispatch_pending_events() -> Result<()> {
+        create_persister!(persister);
+        let rest_client = Arc::new(MockRestClient::new());
+        let dispatcher = EventWebhookDispatcher::new(
+            EventWebhookConfig {
+                url: "https://example.com/events".to_string(),
+                secret: "secret".to_string(),
+            },
+            persister.clone(),
+            rest_client.clone(),
+        );
+
+        persister.insert_journaled_event(&SdkEvent::Synced, true)?;
+        persister.insert_journaled_event(&SdkEvent::Synced, true)?;
+
+        // A rejected event is not marked as delivered
+        rest_client.add_response(MockResponse::new(500, "".to_string()));
+        assert!(dispatcher.dispatch_pending_events().await.is_err());
+        assert_eq!(persister.get_last_dispatched_event_seq()?, None);
+
+        // On retry, all the pending events are delivered
+        rest_client.add_response(MockResponse::new(200, "".to_string()));
+        rest_client.add_response(MockResponse::new(204, "".to_string()));
+        dispatcher.dispatch_pending_events().await?;
+        assert_eq!(persister.get_last_dispatched_event_seq()?, Some(2));
+
+        // Without new events, nothing is dispatched
+        dispatcher.dispatch_pending_events().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use log::info;
+use sdk_common::utils::Arc;
+use tokio::sync::watch;
+use tokio_with_wasm::alias as tokio;
+
+/// Tracks the operations that should not be interrupted, such as lockups, claims, refunds and
+/// broadcasts followed by a state update, so that disconnecting can wait for them to complete.
+pub(crate) struct InFlightOperations {
+    count: watch::Sender<usize>,
+}
+
+impl Default for InFlightOperations {
+    fn default() -> Self {
+        let (count, _) = watch::channel(0);
+        Self { count }
+    }
+}
+
+impl InFlightOperations {
+    /// Marks an operation as in flight until the returned guard is dropped
+    pub(crate) fn start(self: &Arc<Self>) -> InFlightGuard {
+        self.count.send_modify(|count| *count += 1);
+        InFlightGuard {
+            operations: self.clone(),
+        }
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        *self.count.borrow()
+    }
+
+    /// Waits until no operation is in flight, returning `false` if the `timeout` elapses first
+    pub(crate) async fn wait_idle(&self, timeout: Duration) -> bool {
+        let mut count = self.count.subscribe();
+        let in_flight = self.count();
+        if in_flight > 0 {
+            info!("Waiting for {in_flight} in-flight operations to complete");
+        }
+        tokio::select! {
+            res = count.wait_for(|count| *count == 0) => res.is_ok(),
+            _ = tokio::time::sleep(timeout) => false,
+        }
+    }
+}
+
+pub(crate) struct InFlightGuard {
+    operations: Arc<InFlightOperations>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.operations
+            .count
+            .send_modify(|count| *count = count.saturating_sub(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use sdk_common::utils::Arc;
+    use tokio_with_wasm::alias as tokio;
+
+    use super::InFlightOperations;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::async_test_all]
+    async fn test_wait_idle() {
+        let operations = Arc::new(InFlightOperations::default());
+        assert!(operations.wait_idle(Duration::from_millis(10)).await);
+
+        let guard = operations.start();
+        assert_eq!(operations.count(), 1);
+        assert!(!operations.wait_idle(Duration::from_millis(10)).await);
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            drop(guard);
+        });
+        assert!(operations.wait_idle(Duration::from_secs(5)).await);
+        assert_eq!(operations.count(), 0);
+    }
+}
@@ -136,6 +136,7 @@
 //! * [parse] the LNURL endpoint URL to get the workflow parameters
 //! * [sdk::LiquidSdk::lnurl_pay] to pay to the parsed LNURL
 //! * [sdk::LiquidSdk::lnurl_withdraw] to withdraw from the parsed LNURL
+//! * [sdk::LiquidSdk::lnurl_auth] to authenticate with the parsed LNURL
 //!
 //! ### Supporting fiat currencies
 //!
@@ -162,28 +163,44 @@
 //!
 //! Join this [telegram group](https://t.me/breezsdk).
 
+pub(crate) mod amp;
+pub(crate) mod asset_registry;
 #[cfg(feature = "frb")]
 #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
 pub(crate) mod bindings;
+pub(crate) mod bip353;
 pub(crate) mod buy;
 pub(crate) mod chain;
 pub(crate) mod chain_swap;
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+pub(crate) mod data_dir_lock;
 pub mod error;
 pub(crate) mod event;
+pub(crate) mod event_webhook;
+pub(crate) mod in_flight;
 #[cfg(feature = "frb")]
 #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
 pub(crate) mod frb_generated;
 pub(crate) mod lnurl;
 #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
 pub mod logger;
+pub(crate) mod metrics;
 pub mod model;
+pub(crate) mod notification;
+#[cfg(feature = "nwc")]
+pub(crate) mod nwc;
+#[cfg(feature = "otel")]
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+pub mod otel;
 pub(crate) mod payjoin;
 pub mod persist;
 pub mod receive_swap;
 pub(crate) mod recover;
+pub(crate) mod reusable_address;
 pub mod sdk;
 pub(crate) mod send_swap;
 pub mod signer;
+pub(crate) mod spend_limits;
 pub(crate) mod swapper;
 pub(crate) mod sync;
 #[cfg(feature = "test-utils")]
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, ensure, Result};
+use log::debug;
+use sdk_common::prelude::RestClient;
+use sdk_common::utils::Arc;
+use serde::{Deserialize, Serialize};
+
+use crate::model::LightningAddressInfo;
+
+const MAX_USERNAME_LEN: usize = 64;
+
+/// A client of the LNURL service hosting the lightning addresses, set with
+/// [Config::lnurl_service_url](crate::model::Config::lnurl_service_url).
+///
+/// Requests changing a registration are authenticated with a signature by the wallet key of
+/// `<time>-<webhook_url>-<username>`, so only the wallet owning the registration can update it.
+pub(crate) struct LightningAddressClient {
+    base_url: String,
+    rest_client: Arc<dyn RestClient>,
+}
+
+/// The body of the requests changing a registration
+#[derive(Serialize)]
+pub(crate) struct SignedRequest {
+    pub(crate) time: u64,
+    pub(crate) webhook_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) username: Option<String>,
+    pub(crate) signature: String,
+}
+
+impl SignedRequest {
+    /// The message signed to authenticate the request
+    pub(crate) fn message(time: u64, webhook_url: &str, username: Option<&str>) -> String {
+        [&time.to_string(), webhook_url, username.unwrap_or_default()].join("-")
+    }
+}
+
+/// Ensures the username is valid in a lightning address, as defined by
+/// [LUD-16](https://github.com/lnurl/luds/blob/luds/16.md)
+pub(crate) fn validate_username(username: &str) -> Result<()> {
+    ensure!(
+        !username.is_empty() && username.len() <= MAX_USERNAME_LEN,
+        "Username must be between 1 and {MAX_USERNAME_LEN} characters"
+    );
+    ensure!(
+        username
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "-_.".contains(c)),
+        "Username can only contain lowercase letters, digits, '-', '_' and '.'"
+    );
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct AvailabilityResponse {
+    available: bool,
+}
+
+#[derive(Deserialize)]
+struct RegistrationResponse {
+    username: String,
+    lightning_address: String,
+    lnurl: String,
+}
+
+impl From<RegistrationResponse> for LightningAddressInfo {
+    fn from(res: RegistrationResponse) -> Self {
+        LightningAddressInfo {
+            username: res.username,
+            lightning_address: res.lightning_address,
+            lnurl: res.lnurl,
+        }
+    }
+}
+
+impl LightningAddressClient {
+    pub(crate) fn new(base_url: &str, rest_client: Arc<dyn RestClient>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            rest_client,
+        }
+    }
+
+    pub(crate) async fn is_username_available(&self, username: &str) -> Result<bool> {
+        let url = format!("{}/lnurlpay/available/{username}", self.base_url);
+        let (response, status_code) = self.rest_client.get(&url).await?;
+        Self::ensure_success(status_code, &response)?;
+        Ok(serde_json::from_str::<AvailabilityResponse>(&response)?.available)
+    }
+
+    pub(crate) async fn register(
+        &self,
+        pubkey: &str,
+        req: SignedRequest,
+    ) -> Result<LightningAddressInfo> {
+        let url = format!("{}/lnurlpay/{pubkey}", self.base_url);
+        let response = self.post(&url, &req).await?;
+        Ok(serde_json::from_str::<RegistrationResponse>(&response)?.into())
+    }
+
+    /// Returns the registration of the wallet, if any
+    pub(crate) async fn recover(
+        &self,
+        pubkey: &str,
+        req: SignedRequest,
+    ) -> Result<Option<LightningAddressInfo>> {
+        let url = format!("{}/lnurlpay/{pubkey}/recover", self.base_url);
+        let body = serde_json::to_string(&req)?;
+        let (response, status_code) = self
+            .rest_client
+            .post(&url, Some(Self::json_headers()), Some(body))
+            .await?;
+        if status_code == 404 {
+            return Ok(None);
+        }
+        Self::ensure_success(status_code, &response)?;
+        Ok(Some(
+            serde_json::from_str::<RegistrationResponse>(&response)?.into(),
+        ))
+    }
+
+    pub(crate) async fn delete(&self, pubkey: &str, req: SignedRequest) -> Result<()> {
+        let url = format!("{}/lnurlpay/{pubkey}/delete", self.base_url);
+        self.post(&url, &req).await?;
+        Ok(())
+    }
+
+    async fn post(&self, url: &str, req: &SignedRequest) -> Result<String> {
+        debug!("Sending lightning address request to {url}");
+        let body = serde_json::to_string(req)?;
+        let (response, status_code) = self
+            .rest_client
+            .post(url, Some(Self::json_headers()), Some(body))
+            .await?;
+        Self::ensure_success(status_code, &response)?;
+        Ok(response)
+    }
+
+    fn json_headers() -> HashMap<String, String> {
+        HashMap::from([("Content-Type".to_string(), "application/json".to_string())])
+    }
+
+    fn ensure_success(status_code: u16, response: &str) -> Result<()> {
+        match status_code {
+            200..=299 => Ok(()),
+            409 => Err(anyhow!("Username is already taken")),
+            _ => Err(anyhow!(
+                "LNURL service returned status code {status_code}: {response}"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::prelude::{MockResponse, MockRestClient};
+    use sdk_common::utils::Arc;
+
+    use super::{validate_username, LightningAddressClient, SignedRequest};
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn signed_request(username: Option<&str>) -> SignedRequest {
+        SignedRequest {
+            time: 1,
+            webhook_url: "https://example.com/notify".to_string(),
+            username: username.map(ToString::to_string),
+            signature: "signature".to_string(),
+        }
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_lightning_address_client() -> Result<()> {
+        let rest_client = Arc::new(MockRestClient::new());
+        let client = LightningAddressClient::new("https://example.com/", rest_client.clone());
+
+        rest_client.add_response(MockResponse::new(200, r#"{"available":true}"#.to_string()));
+        assert!(client.is_username_available("satoshi").await?);
+
+        rest_client.add_response(MockResponse::new(
+            200,
+            r#"{"username":"satoshi","lightning_address":"satoshi@example.com","lnurl":"lnurl1"}"#
+                .to_string(),
+        ));
+        let info = client
+            .register("pubkey", signed_request(Some("satoshi")))
+            .await?;
+        assert_eq!(info.lightning_address, "satoshi@example.com");
+
+        // A taken username is rejected
+        rest_client.add_response(MockResponse::new(409, "".to_string()));
+        assert!(client
+            .register("pubkey", signed_request(Some("satoshi")))
+            .await
+            .is_err());
+
+        // Without a registration, nothing is recovered
+        rest_client.add_response(MockResponse::new(404, "".to_string()));
+        assert!(client
+            .recover("pubkey", signed_request(None))
+            .await?
+            .is_none());
+
+        rest_client.add_response(MockResponse::new(200, "".to_string()));
+        client.delete("pubkey", signed_request(None)).await?;
+
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_validate_username() {
+        assert!(validate_username("satoshi.n_1-2").is_ok());
+        assert!(validate_username("").is_err());
+        assert!(validate_username("Satoshi").is_err());
+        assert!(validate_username("sat@shi").is_err());
+        assert!(validate_username(&"a".repeat(65)).is_err());
+    }
+
+    #[sdk_macros::test_all]
+    fn test_signed_request_message() {
+        assert_eq!(
+            SignedRequest::message(1, "https://example.com/notify", Some("satoshi")),
+            "1-https://example.com/notify-satoshi"
+        );
+        assert_eq!(
+            SignedRequest::message(1, "https://example.com/notify", None),
+            "1-https://example.com/notify-"
+        );
+    }
+}
@@ -49,3 +49,54 @@ impl LnurlAuthSigner for SdkLnurlAuthSigner {
             .map_err(|e| sdk_common::prelude::LnUrlError::Generic(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use lwk_wollet::bitcoin::bip32::Xpub;
+    use lwk_wollet::secp256k1::{ecdsa::Signature, Message, Secp256k1};
+    use sdk_common::bitcoin::util::bip32::ChildNumber;
+    use sdk_common::prelude::LnurlAuthSigner;
+    use sdk_common::utils::Arc;
+
+    use super::SdkLnurlAuthSigner;
+    use crate::model::Signer;
+    use crate::signer::SdkSigner;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn new_auth_signer() -> Result<SdkLnurlAuthSigner> {
+        let sdk_signer: Box<dyn Signer> = Box::new(SdkSigner::new(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+            false,
+        )?);
+        Ok(SdkLnurlAuthSigner::new(Arc::new(sdk_signer)))
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_lnurl_auth_signature_is_verifiable() -> Result<()> {
+        let auth_signer = new_auth_signer()?;
+        let path = [
+            ChildNumber::from_hardened_idx(138)?,
+            ChildNumber::from_normal_idx(0)?,
+        ];
+
+        let xpub_bytes = auth_signer.derive_bip32_pub_key(&path).await?;
+        // The same seed always yields the same linking key
+        assert_eq!(xpub_bytes, auth_signer.derive_bip32_pub_key(&path).await?);
+
+        let k1 = [7u8; 32];
+        let sig_bytes = auth_signer.sign_ecdsa(&k1, &path).await?;
+
+        let xpub = Xpub::decode(&xpub_bytes)?;
+        let sig = Signature::from_der(&sig_bytes)?;
+        Secp256k1::verification_only().verify_ecdsa(
+            &Message::from_digest(k1),
+            &sig,
+            &xpub.public_key,
+        )?;
+        Ok(())
+    }
+}
@@ -1 +1,3 @@
+pub(crate) mod address;
 pub mod auth;
+pub(crate) mod pay;
@@ -0,0 +1,639 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, ensure, Result};
+use log::{debug, info, warn};
+use lwk_wollet::hashes::{sha256, Hash};
+use sdk_common::invoice::parse_invoice;
+use sdk_common::prelude::{
+    AesSuccessActionData, AesSuccessActionDataResult, LnUrlErrorData, LnUrlPayError,
+    LnUrlPayRequestData, MessageSuccessActionData, Network, RestClient, SuccessAction,
+    SuccessActionProcessed, UrlSuccessActionData,
+};
+use serde::Deserialize;
+use tokio_with_wasm::alias as tokio;
+use url::Url;
+
+use crate::{model::LnUrlPayerData, utils};
+
+/// The maximum length of the message and description of a LUD-09 success action
+const SUCCESS_ACTION_MAX_TEXT_LEN: usize = 144;
+
+/// A LUD-09 success action, as returned by the LNURL-pay callback
+#[derive(Deserialize)]
+#[serde(tag = "tag", rename_all = "lowercase")]
+enum CallbackSuccessAction {
+    Aes {
+        description: String,
+        ciphertext: String,
+        iv: String,
+    },
+    Message {
+        message: String,
+    },
+    Url {
+        description: String,
+        url: String,
+    },
+}
+
+/// The response of a LNURL-pay callback, see LUD-06, LUD-09 and LUD-21
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CallbackResponse {
+    pr: String,
+    success_action: Option<CallbackSuccessAction>,
+    verify: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CallbackErrorResponse {
+    status: String,
+    reason: String,
+}
+
+/// The invoice returned by a LNURL-pay callback, once validated
+pub(crate) struct PayCallbackData {
+    pub(crate) pr: String,
+    pub(crate) success_action: Option<SuccessAction>,
+    /// The LUD-21 verify URL of the invoice, if supported by the LNURL service
+    pub(crate) verify_url: Option<String>,
+}
+
+pub(crate) enum PayCallbackResult {
+    EndpointSuccess { data: PayCallbackData },
+    EndpointError { data: LnUrlErrorData },
+}
+
+/// The response of a [LUD-21](https://github.com/lnurl/luds/blob/luds/21.md) verify URL
+#[derive(Deserialize)]
+#[serde(tag = "status", rename_all = "UPPERCASE")]
+enum VerifyResponse {
+    Ok {
+        settled: bool,
+        preimage: Option<String>,
+    },
+    Error {
+        reason: String,
+    },
+}
+
+/// Processes the LUD-09 success action of a paid invoice. AES success actions are decrypted
+/// with the invoice `preimage`, so they are only processed once it is known.
+pub(crate) fn process_success_action(
+    success_action: SuccessAction,
+    preimage: Option<&str>,
+) -> Result<Option<SuccessActionProcessed>> {
+    let processed = match success_action {
+        SuccessAction::Aes { data } => {
+            let Some(preimage) = preimage else {
+                return Ok(None);
+            };
+            let preimage = sha256::Hash::from_str(preimage)?;
+            let preimage_arr = preimage.to_byte_array();
+            let result = match (data, &preimage_arr).try_into() {
+                Ok(data) => AesSuccessActionDataResult::Decrypted { data },
+                Err(e) => AesSuccessActionDataResult::ErrorStatus {
+                    reason: e.to_string(),
+                },
+            };
+            SuccessActionProcessed::Aes { result }
+        }
+        SuccessAction::Message { data } => SuccessActionProcessed::Message { data },
+        SuccessAction::Url { data } => SuccessActionProcessed::Url { data },
+    };
+    Ok(Some(processed))
+}
+
+/// Requests an invoice of `amount_msat` from the LNURL-pay `callback`, which may differ from the
+/// one of the `data` as it can include the payer data, and validates it.
+///
+/// The callback response is parsed here rather than by [sdk_common], so that its LUD-21 `verify`
+/// URL is kept.
+pub(crate) async fn fetch_pay_callback(
+    rest_client: &dyn RestClient,
+    data: &LnUrlPayRequestData,
+    callback: &str,
+    amount_msat: u64,
+    comment: &Option<String>,
+    network: Network,
+    validate_success_action_url: Option<bool>,
+) -> Result<PayCallbackResult, LnUrlPayError> {
+    if amount_msat < data.min_sendable || amount_msat > data.max_sendable {
+        return Err(LnUrlPayError::InvalidAmount {
+            err: format!(
+                "Amount must be between {} and {} msat",
+                data.min_sendable, data.max_sendable
+            ),
+        });
+    }
+    let comment = comment.as_deref().filter(|comment| !comment.is_empty());
+    if let Some(comment) = comment {
+        if comment.len() > data.comment_allowed as usize {
+            return Err(LnUrlPayError::Generic {
+                err: format!(
+                    "Comment is longer than the allowed {} characters",
+                    data.comment_allowed
+                ),
+            });
+        }
+    }
+
+    let mut url =
+        Url::parse(callback).map_err(|e| LnUrlPayError::InvalidUri { err: e.to_string() })?;
+    url.query_pairs_mut()
+        .append_pair("amount", &amount_msat.to_string());
+    if let Some(comment) = comment {
+        url.query_pairs_mut().append_pair("comment", comment);
+    }
+    let (response, _) = rest_client
+        .get(url.as_str())
+        .await
+        .map_err(|e| LnUrlPayError::ServiceConnectivity { err: e.err })?;
+
+    if let Ok(error) = serde_json::from_str::<CallbackErrorResponse>(&response) {
+        if error.status.eq_ignore_ascii_case("ERROR") {
+            return Ok(PayCallbackResult::EndpointError {
+                data: LnUrlErrorData {
+                    reason: error.reason,
+                },
+            });
+        }
+    }
+    let response: CallbackResponse =
+        serde_json::from_str(&response).map_err(|e| LnUrlPayError::Generic {
+            err: format!("Invalid LNURL-pay callback response: {e}"),
+        })?;
+
+    let invoice = parse_invoice(&response.pr)
+        .map_err(|e| LnUrlPayError::InvalidInvoice { err: e.to_string() })?;
+    if invoice.amount_msat != Some(amount_msat) {
+        return Err(LnUrlPayError::InvalidInvoice {
+            err: "Invoice amount doesn't match the requested amount".to_string(),
+        });
+    }
+    if invoice.network != network {
+        return Err(LnUrlPayError::InvalidNetwork {
+            err: format!("Invoice is for {:?}, not {network:?}", invoice.network),
+        });
+    }
+
+    let success_action = response
+        .success_action
+        .map(|success_action| {
+            validate_success_action(success_action, &url, validate_success_action_url)
+        })
+        .transpose()?;
+    Ok(PayCallbackResult::EndpointSuccess {
+        data: PayCallbackData {
+            pr: response.pr,
+            success_action,
+            verify_url: response.verify,
+        },
+    })
+}
+
+/// Validates a LUD-09 success action. A URL success action must be on the domain of the
+/// `callback`, unless `validate_success_action_url` is false.
+fn validate_success_action(
+    success_action: CallbackSuccessAction,
+    callback: &Url,
+    validate_success_action_url: Option<bool>,
+) -> Result<SuccessAction, LnUrlPayError> {
+    let invalid = |err: &str| LnUrlPayError::Generic {
+        err: format!("Invalid success action: {err}"),
+    };
+    match success_action {
+        CallbackSuccessAction::Aes {
+            description,
+            ciphertext,
+            iv,
+        } => {
+            if description.len() > SUCCESS_ACTION_MAX_TEXT_LEN {
+                return Err(invalid("description is too long"));
+            }
+            if ciphertext.len() > 4096 || iv.len() != 24 {
+                return Err(invalid("invalid ciphertext or iv"));
+            }
+            Ok(SuccessAction::Aes {
+                data: AesSuccessActionData {
+                    description,
+                    ciphertext,
+                    iv,
+                },
+            })
+        }
+        CallbackSuccessAction::Message { message } => {
+            if message.len() > SUCCESS_ACTION_MAX_TEXT_LEN {
+                return Err(invalid("message is too long"));
+            }
+            Ok(SuccessAction::Message {
+                data: MessageSuccessActionData { message },
+            })
+        }
+        CallbackSuccessAction::Url { description, url } => {
+            if description.len() > SUCCESS_ACTION_MAX_TEXT_LEN {
+                return Err(invalid("description is too long"));
+            }
+            let action_url = Url::parse(&url).map_err(|_| invalid("invalid URL"))?;
+            let matches_callback_domain = action_url.domain() == callback.domain();
+            if validate_success_action_url.unwrap_or(true) && !matches_callback_domain {
+                return Err(invalid("URL domain doesn't match the callback domain"));
+            }
+            Ok(SuccessAction::Url {
+                data: UrlSuccessActionData {
+                    description,
+                    url,
+                    matches_callback_domain,
+                },
+            })
+        }
+    }
+}
+
+/// Checks the LUD-21 `verify_url` of a paid invoice, returning its preimage once settled.
+/// The preimage is only returned if it matches the `payment_hash` of the invoice.
+pub(crate) async fn fetch_verified_preimage(
+    rest_client: &dyn RestClient,
+    verify_url: &str,
+    payment_hash: &str,
+) -> Result<Option<String>> {
+    let (response, status_code) = rest_client.get(verify_url).await?;
+    ensure!(
+        status_code == 200,
+        "LNURL verify URL returned status code {status_code}: {response}"
+    );
+    match serde_json::from_str(&response)? {
+        VerifyResponse::Ok {
+            settled: true,
+            preimage: Some(preimage),
+        } => {
+            let hash = sha256::Hash::hash(&hex::decode(&preimage)?);
+            ensure!(
+                hash.to_string() == payment_hash,
+                "LNURL verify URL returned a preimage not matching the payment hash"
+            );
+            Ok(Some(preimage))
+        }
+        VerifyResponse::Ok { .. } => Ok(None),
+        VerifyResponse::Error { reason } => {
+            Err(anyhow!("LNURL verify URL returned an error: {reason}"))
+        }
+    }
+}
+
+/// Checks the LUD-21 `verify_url` of a paid invoice once, returning its preimage if it is
+/// already settled
+pub(crate) async fn check_verified_preimage(
+    rest_client: &dyn RestClient,
+    verify_url: &str,
+    payment_hash: &str,
+) -> Option<String> {
+    match fetch_verified_preimage(rest_client, verify_url, payment_hash).await {
+        Ok(Some(preimage)) => Some(preimage),
+        Ok(None) => {
+            debug!("LNURL-pay invoice not settled yet");
+            None
+        }
+        Err(e) => {
+            warn!("Failed to verify LNURL-pay invoice: {e:?}");
+            None
+        }
+    }
+}
+
+/// Polls the LUD-21 `verify_url` of a paid invoice every `interval` until it is settled,
+/// returning its preimage, or until the invoice expires at `expires_at`
+pub(crate) async fn poll_verified_preimage(
+    rest_client: &dyn RestClient,
+    verify_url: &str,
+    payment_hash: &str,
+    expires_at: u64,
+    interval: Duration,
+) -> Option<String> {
+    loop {
+        if let Some(preimage) = check_verified_preimage(rest_client, verify_url, payment_hash).await
+        {
+            return Some(preimage);
+        }
+        if u64::from(utils::now()) >= expires_at {
+            info!("LNURL-pay invoice expired before being verified as settled");
+            return None;
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Appends the LUD-18 `payerdata` query parameter to the LNURL-pay `callback`
+pub(crate) fn add_payer_data_to_callback(
+    callback: &str,
+    payer_data: &LnUrlPayerData,
+) -> Result<String, LnUrlPayError> {
+    let mut url =
+        Url::parse(callback).map_err(|e| LnUrlPayError::InvalidUri { err: e.to_string() })?;
+    let payer_data_json =
+        serde_json::to_string(payer_data).map_err(|e| LnUrlPayError::Generic {
+            err: format!("Failed to serialize payer data: {e}"),
+        })?;
+    url.query_pairs_mut()
+        .append_pair("payerdata", &payer_data_json);
+    Ok(url.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::prelude::{
+        AesSuccessActionData, MessageSuccessActionData, MockResponse, MockRestClient,
+    };
+
+    use super::*;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_add_payer_data_to_callback() -> Result<()> {
+        let payer_data = LnUrlPayerData {
+            name: Some("Satoshi".to_string()),
+            identifier: Some("satoshi@example.com".to_string()),
+            ..Default::default()
+        };
+
+        let callback =
+            add_payer_data_to_callback("https://example.com/lnurlp/cb?id=1", &payer_data)?;
+        let url = Url::parse(&callback)?;
+        let mut pairs = url.query_pairs();
+        assert_eq!(pairs.next().unwrap().1, "1");
+        let (key, value) = pairs.next().unwrap();
+        assert_eq!(key, "payerdata");
+        assert_eq!(
+            value,
+            r#"{"name":"Satoshi","identifier":"satoshi@example.com"}"#
+        );
+
+        assert!(add_payer_data_to_callback("not a url", &payer_data).is_err());
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_process_success_action() -> Result<()> {
+        let message = SuccessAction::Message {
+            data: MessageSuccessActionData {
+                message: "Thanks".to_string(),
+            },
+        };
+        assert!(matches!(
+            process_success_action(message, None)?,
+            Some(SuccessActionProcessed::Message { data }) if data.message == "Thanks"
+        ));
+
+        let aes = SuccessAction::Aes {
+            data: AesSuccessActionData {
+                description: "Voucher".to_string(),
+                ciphertext: "invalid".to_string(),
+                iv: "invalid".to_string(),
+            },
+        };
+        assert!(process_success_action(aes.clone(), None)?.is_none());
+        assert!(process_success_action(aes.clone(), Some("invalid")).is_err());
+        assert!(matches!(
+            process_success_action(aes, Some(&"01".repeat(32)))?,
+            Some(SuccessActionProcessed::Aes {
+                result: AesSuccessActionDataResult::ErrorStatus { .. }
+            })
+        ));
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_fetch_pay_callback() -> Result<()> {
+        let rest_client = MockRestClient::new();
+        let data = LnUrlPayRequestData {
+            callback: "https://example.com/lnurlp/cb".to_string(),
+            min_sendable: 1_000,
+            max_sendable: 100_000_000,
+            metadata_str: "[]".to_string(),
+            comment_allowed: 10,
+            domain: "example.com".to_string(),
+            allows_nostr: false,
+            nostr_pubkey: None,
+            ln_address: None,
+        };
+        let fetch = |amount_msat: u64, comment: Option<String>| {
+            let rest_client = &rest_client;
+            let data = &data;
+            async move {
+                fetch_pay_callback(
+                    rest_client,
+                    data,
+                    &data.callback,
+                    amount_msat,
+                    &comment,
+                    Network::Bitcoin,
+                    None,
+                )
+                .await
+            }
+        };
+
+        // The amount and comment are validated before requesting an invoice
+        assert!(matches!(
+            fetch(100, None).await,
+            Err(LnUrlPayError::InvalidAmount { .. })
+        ));
+        assert!(fetch(1_000_000, Some("Too long comment".to_string()))
+            .await
+            .is_err());
+
+        rest_client.add_response(MockResponse::new(
+            200,
+            r#"{"status":"ERROR","reason":"Unavailable"}"#.to_string(),
+        ));
+        assert!(matches!(
+            fetch(1_000_000, None).await?,
+            PayCallbackResult::EndpointError { data } if data.reason == "Unavailable"
+        ));
+
+        // The invoice of 10 sat doesn't match the requested amount
+        let bolt11 = "lnbc10u1pnczjaupp55392fur38rc2y9vzmhdy0tclvfels0lvlmzgvmhpg6q2mndxzmrsdqqcqzzsxqyz5vqsp5ya6pvchlsvl3mzqh3zw4hg3tz5pww77q6rcwfr52qchyrp7s6krs9p4gqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqysgqgnp0sskk0ljjew8vkc3udhzgquzs79evf5wezfaex9q4gjk5qcn8m3luauyte93lgassd8skh5m90glhtt52ry2wtftzrjn4h076z7sqdjry3d";
+        let response = serde_json::json!({
+            "pr": bolt11,
+            "routes": [],
+            "successAction": { "tag": "message", "message": "Thanks" },
+            "verify": "https://example.com/verify/1",
+        })
+        .to_string();
+        rest_client.add_response(MockResponse::new(200, response.clone()));
+        assert!(matches!(
+            fetch(2_000_000, None).await,
+            Err(LnUrlPayError::InvalidInvoice { .. })
+        ));
+
+        // The verify URL is kept with the validated invoice
+        rest_client.add_response(MockResponse::new(200, response));
+        let PayCallbackResult::EndpointSuccess { data } = fetch(1_000_000, None).await? else {
+            panic!("Expected a successful callback response");
+        };
+        assert_eq!(data.pr, bolt11);
+        assert_eq!(
+            data.verify_url,
+            Some("https://example.com/verify/1".to_string())
+        );
+        assert!(matches!(
+            data.success_action,
+            Some(SuccessAction::Message { data }) if data.message == "Thanks"
+        ));
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_validate_success_action() -> Result<()> {
+        let callback = Url::parse("https://example.com/lnurlp/cb")?;
+        let url_action = |url: &str| CallbackSuccessAction::Url {
+            description: "Receipt".to_string(),
+            url: url.to_string(),
+        };
+
+        assert!(matches!(
+            validate_success_action(url_action("https://example.com/receipt"), &callback, None)?,
+            SuccessAction::Url { data } if data.matches_callback_domain
+        ));
+        assert!(
+            validate_success_action(url_action("https://other.com/receipt"), &callback, None)
+                .is_err()
+        );
+        assert!(matches!(
+            validate_success_action(
+                url_action("https://other.com/receipt"),
+                &callback,
+                Some(false)
+            )?,
+            SuccessAction::Url { data } if !data.matches_callback_domain
+        ));
+        assert!(validate_success_action(
+            CallbackSuccessAction::Message {
+                message: "a".repeat(145),
+            },
+            &callback,
+            None
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_fetch_verified_preimage() -> Result<()> {
+        let rest_client = MockRestClient::new();
+        let verify_url = "https://example.com/verify/1";
+        let preimage = hex::encode([1; 32]);
+        let payment_hash = sha256::Hash::hash(&[1; 32]).to_string();
+
+        rest_client.add_response(MockResponse::new(
+            200,
+            r#"{"status":"OK","settled":false,"preimage":null,"pr":"lnbc1"}"#.to_string(),
+        ));
+        assert_eq!(
+            fetch_verified_preimage(&rest_client, verify_url, &payment_hash).await?,
+            None
+        );
+
+        let settled =
+            format!(r#"{{"status":"OK","settled":true,"preimage":"{preimage}","pr":"lnbc1"}}"#);
+        rest_client.add_response(MockResponse::new(200, settled.clone()));
+        assert_eq!(
+            fetch_verified_preimage(&rest_client, verify_url, &payment_hash).await?,
+            Some(preimage)
+        );
+
+        rest_client.add_response(MockResponse::new(200, settled));
+        assert!(
+            fetch_verified_preimage(&rest_client, verify_url, &"00".repeat(32))
+                .await
+                .is_err()
+        );
+
+        rest_client.add_response(MockResponse::new(
+            200,
+            r#"{"status":"ERROR","reason":"Not found"}"#.to_string(),
+        ));
+        assert!(
+            fetch_verified_preimage(&rest_client, verify_url, &payment_hash)
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_check_verified_preimage() -> Result<()> {
+        let rest_client = MockRestClient::new();
+        let verify_url = "https://example.com/verify/1";
+        let preimage = hex::encode([1; 32]);
+        let payment_hash = sha256::Hash::hash(&[1; 32]).to_string();
+
+        // The verify URL is checked once, without waiting for the invoice to be settled
+        rest_client.add_response(MockResponse::new(
+            200,
+            r#"{"status":"OK","settled":false,"preimage":null,"pr":"lnbc1"}"#.to_string(),
+        ));
+        assert_eq!(
+            check_verified_preimage(&rest_client, verify_url, &payment_hash).await,
+            None
+        );
+
+        rest_client.add_response(MockResponse::new(500, "Internal error".to_string()));
+        assert_eq!(
+            check_verified_preimage(&rest_client, verify_url, &payment_hash).await,
+            None
+        );
+
+        rest_client.add_response(MockResponse::new(
+            200,
+            format!(r#"{{"status":"OK","settled":true,"preimage":"{preimage}","pr":"lnbc1"}}"#),
+        ));
+        assert_eq!(
+            check_verified_preimage(&rest_client, verify_url, &payment_hash).await,
+            Some(preimage)
+        );
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_poll_verified_preimage() -> Result<()> {
+        let rest_client = MockRestClient::new();
+        let verify_url = "https://example.com/verify/1";
+        let preimage = hex::encode([1; 32]);
+        let payment_hash = sha256::Hash::hash(&[1; 32]).to_string();
+        let not_settled = r#"{"status":"OK","settled":false,"preimage":null,"pr":"lnbc1"}"#;
+
+        // An expired invoice is not polled again
+        rest_client.add_response(MockResponse::new(200, not_settled.to_string()));
+        assert_eq!(
+            poll_verified_preimage(&rest_client, verify_url, &payment_hash, 0, Duration::ZERO)
+                .await,
+            None
+        );
+
+        // The verify URL is polled until the invoice is settled
+        let expires_at = u64::from(utils::now()) + 3_600;
+        rest_client.add_response(MockResponse::new(200, not_settled.to_string()));
+        rest_client.add_response(MockResponse::new(500, "Internal error".to_string()));
+        rest_client.add_response(MockResponse::new(
+            200,
+            format!(r#"{{"status":"OK","settled":true,"preimage":"{preimage}","pr":"lnbc1"}}"#),
+        ));
+        assert_eq!(
+            poll_verified_preimage(
+                &rest_client,
+                verify_url,
+                &payment_hash,
+                expires_at,
+                Duration::ZERO
+            )
+            .await,
+            Some(preimage)
+        );
+        Ok(())
+    }
+}
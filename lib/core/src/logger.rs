@@ -1,5 +1,6 @@
-use std::fs::OpenOptions;
-use std::io::Write;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
 
 use anyhow::{anyhow, Result};
 use chrono::Local;
@@ -7,6 +8,109 @@ use log::{LevelFilter, Metadata, Record};
 
 use crate::model::LogEntry;
 
+/// The name of the current SDK log file. Rotated files get a numeric suffix, e.g. `sdk.log.1`.
+const LOG_FILE_NAME: &str = "sdk.log";
+/// The default size at which the log file is rotated. See [LogFileConfig::max_file_size_bytes]
+pub const DEFAULT_MAX_LOG_FILE_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+/// The default number of rotated log files kept. See [LogFileConfig::max_rotated_files]
+pub const DEFAULT_MAX_ROTATED_LOG_FILES: u32 = 5;
+/// The default log levels, per module
+const DEFAULT_LOG_FILTERS: &str = r#"
+    debug,
+    breez_sdk_liquid=debug,
+    breez_sdk_liquid::swapper::boltz_status_stream=info,
+    electrum_client::raw_client=warn,
+    lwk_wollet=info,
+    rustls=warn,
+    rustyline=warn,
+    ureq=info,
+    tungstenite=warn
+"#;
+
+/// The configuration of the SDK log file. See [crate::sdk::LiquidSdk::init_logging_with_config]
+#[derive(Clone, Debug, Default)]
+pub struct LogFileConfig {
+    /// Comma-separated log level directives, applied on top of the default levels, e.g.
+    /// `breez_sdk_liquid::swapper=trace,lwk_wollet=debug`
+    pub filters: Option<String>,
+    /// The size in bytes at which the log file is rotated.
+    /// Defaults to [DEFAULT_MAX_LOG_FILE_SIZE_BYTES].
+    pub max_file_size_bytes: Option<u64>,
+    /// The number of rotated log files kept besides the current one. Older files are deleted.
+    /// Defaults to [DEFAULT_MAX_ROTATED_LOG_FILES].
+    pub max_rotated_files: Option<u32>,
+    /// Whether to write the log entries as JSON lines instead of plain text
+    pub json: bool,
+}
+
+/// A log file writer which rotates the file once it reaches a maximum size
+struct RotatingFileWriter {
+    path: PathBuf,
+    file: File,
+    size: u64,
+    max_file_size_bytes: u64,
+    max_rotated_files: u32,
+}
+
+impl RotatingFileWriter {
+    fn new(path: PathBuf, max_file_size_bytes: u64, max_rotated_files: u32) -> io::Result<Self> {
+        let file = Self::open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file,
+            size,
+            max_file_size_bytes,
+            max_rotated_files,
+        })
+    }
+
+    fn open(path: &PathBuf) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn rotated_path(&self, index: u32) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        path.into()
+    }
+
+    /// Shifts the rotated files by one, dropping the oldest, and starts a new log file
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_rotated_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let _ = fs::remove_file(self.rotated_path(self.max_rotated_files));
+            for index in (1..self.max_rotated_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(from, self.rotated_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.rotated_path(1))?;
+        }
+        self.file = Self::open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.size > 0 && self.size + buf.len() as u64 > self.max_file_size_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
 pub(crate) struct GlobalSdkLogger {
     /// SDK internal logger, which logs to file
     pub(crate) logger: env_logger::Logger,
@@ -33,30 +137,42 @@ impl log::Log for GlobalSdkLogger {
     fn flush(&self) {}
 }
 
-pub(super) fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>) -> Result<()> {
+pub(super) fn init_logging(
+    log_dir: &str,
+    config: LogFileConfig,
+    app_logger: Option<Box<dyn log::Log>>,
+) -> Result<()> {
     let target_log_file = Box::new(
-        OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(format!("{log_dir}/sdk.log"))
-            .map_err(|e| anyhow!("Can't create log file: {e}"))?,
+        RotatingFileWriter::new(
+            PathBuf::from(log_dir).join(LOG_FILE_NAME),
+            config
+                .max_file_size_bytes
+                .unwrap_or(DEFAULT_MAX_LOG_FILE_SIZE_BYTES),
+            config
+                .max_rotated_files
+                .unwrap_or(DEFAULT_MAX_ROTATED_LOG_FILES),
+        )
+        .map_err(|e| anyhow!("Can't create log file: {e}"))?,
     );
-    let logger = env_logger::Builder::new()
+    let mut builder = env_logger::Builder::new();
+    builder
         .target(env_logger::Target::Pipe(target_log_file))
-        .parse_filters(
-            r#"
-                debug,
-                breez_sdk_liquid=debug,
-                breez_sdk_liquid::swapper::boltz_status_stream=info,
-                electrum_client::raw_client=warn,
-                lwk_wollet=info,
-                rustls=warn,
-                rustyline=warn,
-                ureq=info,
-                tungstenite=warn
-            "#,
-        )
-        .format(|buf, record| {
+        .parse_filters(DEFAULT_LOG_FILTERS);
+    if let Some(filters) = &config.filters {
+        builder.parse_filters(filters);
+    }
+    match config.json {
+        true => builder.format(|buf, record| {
+            let entry = serde_json::json!({
+                "timestamp": Local::now().to_rfc3339(),
+                "level": record.level().to_string(),
+                "module": record.module_path().unwrap_or("unknown"),
+                "line": record.line().unwrap_or(0),
+                "message": record.args().to_string(),
+            });
+            writeln!(buf, "{entry}")
+        }),
+        false => builder.format(|buf, record| {
             writeln!(
                 buf,
                 "[{} {} {}:{}] {}",
@@ -66,8 +182,9 @@ pub(super) fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>)
                 record.line().unwrap_or(0),
                 record.args()
             )
-        })
-        .build();
+        }),
+    };
+    let logger = builder.build();
 
     let global_logger = GlobalSdkLogger {
         logger,
@@ -84,3 +201,42 @@ pub(super) fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>)
 pub trait Logger: Send + Sync {
     fn log(&self, l: LogEntry);
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use anyhow::Result;
+
+    use super::RotatingFileWriter;
+
+    #[sdk_macros::test_not_wasm]
+    fn test_rotating_file_writer() -> Result<()> {
+        let log_dir = tempdir::TempDir::new("logs")?;
+        let path = log_dir.path().join("sdk.log");
+        let mut writer = RotatingFileWriter::new(path.clone(), 10, 2)?;
+
+        for line in [
+            "first-line\n",
+            "second-line\n",
+            "third-line\n",
+            "fourth-line\n",
+        ] {
+            writer.write_all(line.as_bytes())?;
+        }
+        writer.flush()?;
+
+        assert_eq!(std::fs::read_to_string(&path)?, "fourth-line\n");
+        assert_eq!(
+            std::fs::read_to_string(writer.rotated_path(1))?,
+            "third-line\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(writer.rotated_path(2))?,
+            "second-line\n"
+        );
+        assert!(!writer.rotated_path(3).exists());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,293 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::model::{PaymentState, PaymentSwapType, PaymentType, SdkEvent};
+
+/// The upper bounds of the sync duration histogram buckets, in seconds
+const SYNC_DURATION_BUCKETS_SEC: [f64; 8] = [0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0];
+
+#[derive(Default)]
+struct Histogram {
+    /// The number of observations in each bucket of [SYNC_DURATION_BUCKETS_SEC]
+    bucket_counts: [u64; SYNC_DURATION_BUCKETS_SEC.len()],
+    count: u64,
+    sum: f64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        for (bucket_count, upper_bound) in self
+            .bucket_counts
+            .iter_mut()
+            .zip(SYNC_DURATION_BUCKETS_SEC.iter())
+        {
+            if value <= *upper_bound {
+                *bucket_count += 1;
+            }
+        }
+        self.count += 1;
+        self.sum += value;
+    }
+}
+
+/// The operational metrics of the SDK, rendered in the Prometheus text format by
+/// [crate::sdk::LiquidSdk::prometheus_metrics]. The metrics are kept in memory and reset when
+/// the SDK is restarted.
+#[derive(Default)]
+pub(crate) struct SdkMetrics {
+    payments_sent: AtomicU64,
+    payments_received: AtomicU64,
+    /// The swap failure events, by swap type and reason
+    swap_failures: Mutex<BTreeMap<(String, String), u64>>,
+    sync_duration: Mutex<Histogram>,
+    /// Whether the last call of each chain service succeeded, and the number of times it
+    /// succeeded again after failing
+    chain_services: Mutex<BTreeMap<&'static str, (bool, u64)>>,
+}
+
+impl SdkMetrics {
+    pub(crate) fn record_event(&self, e: &SdkEvent) {
+        match e {
+            SdkEvent::PaymentSucceeded { details } => {
+                let counter = match details.payment_type {
+                    PaymentType::Send => &self.payments_sent,
+                    PaymentType::Receive => &self.payments_received,
+                };
+                counter.fetch_add(1, Ordering::Relaxed);
+            }
+            // A swap is only counted once it failed for good. A refundable swap is not, as it
+            // can still be refunded, after which it fails with `PaymentRefunded`.
+            SdkEvent::PaymentFailed { details }
+            | SdkEvent::PaymentExpired { details }
+            | SdkEvent::PaymentRefunded { details }
+                if matches!(
+                    details.status,
+                    PaymentState::Failed | PaymentState::TimedOut
+                ) =>
+            {
+                let reason = match e {
+                    SdkEvent::PaymentExpired { .. } => "expired",
+                    SdkEvent::PaymentRefunded { .. } => "refunded",
+                    _ => "failed",
+                };
+                self.record_swap_failure(e.swap_type(), reason)
+            }
+            _ => {}
+        }
+    }
+
+    /// Records the outcome of a call to the `chain` service, counting a reconnect when it
+    /// succeeds after having failed
+    pub(crate) fn record_chain_service_call(&self, chain: &'static str, is_success: bool) {
+        let mut chain_services = self
+            .chain_services
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let (was_success, reconnects) = chain_services.entry(chain).or_insert((true, 0));
+        if is_success && !*was_success {
+            *reconnects += 1;
+        }
+        *was_success = is_success;
+    }
+
+    fn record_swap_failure(&self, swap_type: Option<PaymentSwapType>, reason: &str) {
+        let Some(swap_type) = swap_type else {
+            return;
+        };
+        let key = (format!("{swap_type:?}").to_lowercase(), reason.to_string());
+        *self
+            .swap_failures
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(key)
+            .or_default() += 1;
+    }
+
+    pub(crate) fn observe_sync_duration(&self, duration: Duration) {
+        self.sync_duration
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .observe(duration.as_secs_f64());
+    }
+
+    /// Renders the metrics in the Prometheus text exposition format
+    pub(crate) fn render(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(
+            out,
+            "# HELP breez_sdk_payments_sent_total The number of payments sent"
+        );
+        let _ = writeln!(out, "# TYPE breez_sdk_payments_sent_total counter");
+        let _ = writeln!(
+            out,
+            "breez_sdk_payments_sent_total {}",
+            self.payments_sent.load(Ordering::Relaxed)
+        );
+        let _ = writeln!(
+            out,
+            "# HELP breez_sdk_payments_received_total The number of payments received"
+        );
+        let _ = writeln!(out, "# TYPE breez_sdk_payments_received_total counter");
+        let _ = writeln!(
+            out,
+            "breez_sdk_payments_received_total {}",
+            self.payments_received.load(Ordering::Relaxed)
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP breez_sdk_swap_failures_total The number of failed swaps, by swap type and reason"
+        );
+        let _ = writeln!(out, "# TYPE breez_sdk_swap_failures_total counter");
+        for ((swap_type, reason), count) in self
+            .swap_failures
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+        {
+            let _ = writeln!(
+                out,
+                "breez_sdk_swap_failures_total{{swap_type=\"{swap_type}\",reason=\"{reason}\"}} {count}"
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP breez_sdk_sync_duration_seconds The duration of the syncs with the onchain data"
+        );
+        let _ = writeln!(out, "# TYPE breez_sdk_sync_duration_seconds histogram");
+        {
+            let histogram = self.sync_duration.lock().unwrap_or_else(|e| e.into_inner());
+            for (bucket_count, upper_bound) in histogram
+                .bucket_counts
+                .iter()
+                .zip(SYNC_DURATION_BUCKETS_SEC.iter())
+            {
+                let _ = writeln!(
+                    out,
+                    "breez_sdk_sync_duration_seconds_bucket{{le=\"{upper_bound}\"}} {bucket_count}"
+                );
+            }
+            let _ = writeln!(
+                out,
+                "breez_sdk_sync_duration_seconds_bucket{{le=\"+Inf\"}} {}",
+                histogram.count
+            );
+            let _ = writeln!(out, "breez_sdk_sync_duration_seconds_sum {}", histogram.sum);
+            let _ = writeln!(
+                out,
+                "breez_sdk_sync_duration_seconds_count {}",
+                histogram.count
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP breez_sdk_chain_reconnects_total The number of times a chain service was reachable again after failing, by chain"
+        );
+        let _ = writeln!(out, "# TYPE breez_sdk_chain_reconnects_total counter");
+        for (chain, (_, reconnects)) in self
+            .chain_services
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+        {
+            let _ = writeln!(
+                out,
+                "breez_sdk_chain_reconnects_total{{chain=\"{chain}\"}} {reconnects}"
+            );
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::model::{PaymentSwapType, SdkEvent};
+
+    use super::SdkMetrics;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_render_metrics() {
+        let metrics = SdkMetrics::default();
+        metrics.record_swap_failure(Some(PaymentSwapType::Send), "refundable");
+        metrics.record_swap_failure(Some(PaymentSwapType::Send), "refundable");
+        metrics.record_swap_failure(None, "failed");
+        metrics.record_chain_service_call("liquid", true);
+        metrics.record_chain_service_call("liquid", false);
+        metrics.record_chain_service_call("liquid", false);
+        metrics.record_chain_service_call("liquid", true);
+        metrics.record_chain_service_call("bitcoin", true);
+        metrics.observe_sync_duration(Duration::from_millis(300));
+        metrics.observe_sync_duration(Duration::from_secs(20));
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("breez_sdk_payments_sent_total 0\n"));
+        assert!(rendered.contains(
+            "breez_sdk_swap_failures_total{swap_type=\"send\",reason=\"refundable\"} 2\n"
+        ));
+        assert!(!rendered.contains("reason=\"failed\""));
+        assert!(rendered.contains("breez_sdk_sync_duration_seconds_bucket{le=\"0.1\"} 0\n"));
+        assert!(rendered.contains("breez_sdk_sync_duration_seconds_bucket{le=\"0.5\"} 1\n"));
+        assert!(rendered.contains("breez_sdk_sync_duration_seconds_bucket{le=\"30\"} 2\n"));
+        assert!(rendered.contains("breez_sdk_sync_duration_seconds_count 2\n"));
+        assert!(rendered.contains("breez_sdk_chain_reconnects_total{chain=\"liquid\"} 1\n"));
+        assert!(rendered.contains("breez_sdk_chain_reconnects_total{chain=\"bitcoin\"} 0\n"));
+    }
+
+    #[sdk_macros::test_all]
+    fn test_record_swap_failures() {
+        let metrics = SdkMetrics::default();
+        let new_chain_payment = |status| {
+            let mut payment = Payment::from_tx_data(
+                new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Send),
+                None,
+                PaymentDetails::Bitcoin {
+                    swap_id: "swap".to_string(),
+                    description: "Bitcoin transfer".to_string(),
+                    auto_accepted_fees: false,
+                    liquid_expiration_blockheight: None,
+                    bitcoin_expiration_blockheight: None,
+                    claim_tx_id: None,
+                    refund_tx_id: None,
+                    refund_tx_amount_sat: None,
+                    buy_bitcoin_provider: None,
+                },
+            );
+            payment.status = status;
+            payment
+        };
+
+        // A swap which becomes refundable and is then refunded is counted once, when refunded
+        metrics.record_event(&SdkEvent::PaymentRefundable {
+            details: new_chain_payment(PaymentState::Refundable),
+        });
+        metrics.record_event(&SdkEvent::PaymentRefundPending {
+            details: new_chain_payment(PaymentState::RefundPending),
+        });
+        metrics.record_event(&SdkEvent::PaymentRefunded {
+            details: new_chain_payment(PaymentState::Failed),
+        });
+        metrics.record_event(&SdkEvent::PaymentFailed {
+            details: new_chain_payment(PaymentState::Failed),
+        });
+
+        let rendered = metrics.render();
+        assert!(!rendered.contains("reason=\"refundable\""));
+        assert!(rendered.contains(
+            "breez_sdk_swap_failures_total{swap_type=\"chain\",reason=\"refunded\"} 1\n"
+        ));
+        assert!(rendered
+            .contains("breez_sdk_swap_failures_total{swap_type=\"chain\",reason=\"failed\"} 1\n"));
+    }
+}
@@ -21,9 +21,11 @@ use serde::{Deserialize, Serialize};
 use std::cmp::PartialEq;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 use strum_macros::{Display, EnumString};
 
 use crate::receive_swap::DEFAULT_ZERO_CONF_MAX_SAT;
+use crate::swapper::boltz::retry::DEFAULT_SWAPPER_MAX_ATTEMPTS;
 use crate::utils;
 use crate::{
     bitcoin,
@@ -55,6 +57,71 @@ pub enum BlockchainExplorer {
     },
 }
 
+/// An endpoint to which the SDK events are POSTed as [JournaledEvent]s, in the order they were
+/// emitted. Each event is delivered at least once: failed deliveries are retried until the
+/// endpoint responds with a success status, so the endpoint should deduplicate events by their
+/// sequence number. The undelivered events are kept in the event journal until delivered.
+#[derive(Clone, Debug, Serialize)]
+pub struct EventWebhookConfig {
+    /// The url of the endpoint
+    pub url: String,
+    /// The secret used to sign the requests. The hex-encoded HMAC-SHA256 of the request body
+    /// is sent in the `X-Breez-Signature` header.
+    pub secret: String,
+}
+
+/// A spending limit, see [SpendLimits]
+#[derive(Clone, Debug, Serialize)]
+pub enum SpendLimit {
+    Bitcoin {
+        amount_sat: u64,
+    },
+    /// Converted to satoshi at the current exchange rate whenever a payment is sent
+    Fiat {
+        amount: FiatAmount,
+    },
+}
+
+/// The limits on the amounts sent, enforced by
+/// [LiquidSdk::send_payment](crate::sdk::LiquidSdk::send_payment),
+/// [LiquidSdk::pay_onchain](crate::sdk::LiquidSdk::pay_onchain) and
+/// [LiquidSdk::convert](crate::sdk::LiquidSdk::convert). A payment exceeding them fails with
+/// [PaymentError::SpendLimitExceeded](crate::error::PaymentError::SpendLimitExceeded), unless a
+/// valid `spend_limit_override` is given.
+#[derive(Clone, Debug, Serialize)]
+pub struct SpendLimits {
+    /// The maximum amount of a single payment, including fees
+    pub per_payment: Option<SpendLimit>,
+    /// The maximum amount sent in the last 24 hours, including fees
+    pub per_day: Option<SpendLimit>,
+    /// The secret with which override tokens are created, see
+    /// [LiquidSdk::create_spend_limit_override](crate::sdk::LiquidSdk::create_spend_limit_override).
+    /// If not set, the limits can't be overridden.
+    pub override_secret: Option<String>,
+}
+
+/// The simulated swap lifecycles, see [Config::simulation].
+///
+/// Each swap goes through the status updates of the swapper, ending either in success or in
+/// failure. No funds are moved: a successful Send swap is completed without a lockup tx, and a
+/// successful Receive swap without a claim tx. Failed swaps are marked as failed.
+#[derive(Clone, Debug, Serialize)]
+pub struct SimulationConfig {
+    /// The delay in seconds between the status updates of a swap
+    pub status_update_delay_sec: u32,
+    /// The probability that a swap fails, between 0 and 1
+    pub failure_rate: f64,
+}
+
+/// When the local state is backed up automatically, see [Config::backup_policy]
+#[derive(Clone, Debug, Serialize)]
+pub enum BackupPolicy {
+    /// After every `swaps` completed swaps
+    CompletedSwaps { swaps: u32 },
+    /// Every `hours` hours
+    Interval { hours: u32 },
+}
+
 /// Configuration for the Liquid SDK
 #[derive(Clone, Debug, Serialize)]
 pub struct Config {
@@ -72,7 +139,9 @@ pub struct Config {
     /// The url of the real-time sync service. Defaults to [BREEZ_SYNC_SERVICE_URL]
     /// Setting this field to `None` will disable the service
     pub sync_service_url: Option<String>,
-    /// Maximum amount in satoshi to accept zero-conf payments with
+    /// Maximum amount in satoshi to accept zero-conf payments with, i.e. to claim incoming
+    /// Lightning and Bitcoin swaps before the swapper's lockup tx is confirmed. Larger payments
+    /// complete once the lockup tx is confirmed. Set to 0 to always wait for confirmation.
     /// Defaults to [DEFAULT_ZERO_CONF_MAX_SAT]
     pub zero_conf_max_amount_sat: Option<u64>,
     /// The Breez API key used for making requests to the sync service
@@ -99,6 +168,131 @@ pub struct Config {
     pub asset_metadata: Option<Vec<AssetMetadata>>,
     /// The SideSwap API key used for making requests to the SideSwap payjoin service
     pub sideswap_api_key: Option<String>,
+    /// An optional webhook to which every [SdkEvent] is dispatched, turning the SDK into an
+    /// event source for backend systems. See [EventWebhookConfig] for more details.
+    ///
+    /// Defaults to `None`.
+    pub event_webhook: Option<EventWebhookConfig>,
+    /// The fiat currency in which the value of each payment is recorded when it completes,
+    /// e.g. `USD`. See [Payment::fiat_amount].
+    ///
+    /// Defaults to `None`.
+    pub fiat_currency: Option<String>,
+    /// The interval in seconds at which the SDK checks for new blocks and syncs with the
+    /// mempool and onchain data. A sync can also be triggered at any time with
+    /// [LiquidSdk::sync](crate::sdk::LiquidSdk::sync), e.g. when the app is brought to the foreground.
+    ///
+    /// Defaults to [DEFAULT_SYNC_INTERVAL_SEC](crate::sdk::DEFAULT_SYNC_INTERVAL_SEC).
+    pub sync_interval_sec: Option<u32>,
+    /// The value of the `Authorization` header sent with each request to the Bitcoin
+    /// [Esplora](BlockchainExplorer::Esplora) explorer, e.g. `Bearer <token>`. Allows using a
+    /// self-hosted Esplora or mempool.space instance that requires authentication.
+    ///
+    /// Defaults to `None`.
+    pub bitcoin_explorer_authorization: Option<String>,
+    /// The maximum number of attempts made for a swapper call that fails because the swapper is
+    /// temporarily unreachable or doesn't respond. Calls rejected by the swapper are not retried.
+    /// Retries are delayed with a jittered exponential backoff.
+    ///
+    /// Defaults to [DEFAULT_SWAPPER_MAX_ATTEMPTS].
+    pub swapper_max_attempts: Option<u32>,
+    /// An optional policy to automatically back up the local state, so that a recent backup is
+    /// available for restore even if [LiquidSdk::backup](crate::sdk::LiquidSdk::backup) is
+    /// never called. The backup is written to the default backup path and uploaded with the
+    /// [BackupTransport], if one is set. Each backup is reported with an
+    /// [SdkEvent::BackupSucceeded] or [SdkEvent::BackupFailed] event.
+    ///
+    /// Defaults to `None`.
+    pub backup_policy: Option<BackupPolicy>,
+    /// The Nostr relay on which the [Nostr Wallet Connect](https://nips.nostr.com/47) service
+    /// listens for requests, e.g. `wss://relay.getalby.com/v1`. Once set, connections can be
+    /// added with [LiquidSdk::add_nwc_connection](crate::sdk::LiquidSdk::add_nwc_connection).
+    ///
+    /// The service is only available with the `nwc` feature. Defaults to `None`, which disables
+    /// the service.
+    pub nwc_relay_url: Option<String>,
+    /// The limits on the amounts sent, e.g. for kiosk or allowance wallets. See [SpendLimits].
+    ///
+    /// Defaults to `None`, which doesn't limit the payments.
+    pub spend_limits: Option<SpendLimits>,
+    /// The LNURL service hosting the lightning address registered with
+    /// [LiquidSdk::register_lightning_address](crate::sdk::LiquidSdk::register_lightning_address).
+    ///
+    /// Defaults to `None`, which disables the lightning address APIs.
+    pub lnurl_service_url: Option<String>,
+    /// The number of confirmations required before an outgoing payment is marked as
+    /// [Complete](PaymentState::Complete): the confirmations of the tx of a direct Liquid payment,
+    /// or of the Bitcoin claim tx of an outgoing chain swap. Until then, the payment stays
+    /// [Pending](PaymentState::Pending) and [SdkEvent::PaymentWaitingConfirmation] is emitted once
+    /// the tx is seen in the mempool, followed by [SdkEvent::PaymentSucceeded] once it has enough
+    /// confirmations.
+    ///
+    /// Defaults to 1.
+    pub outgoing_min_confirmations: Option<u32>,
+    /// The number of seconds after which an [SdkEvent::TransactionUnconfirmed] is emitted for a
+    /// tx of the wallet that is still not confirmed.
+    ///
+    /// Defaults to [DEFAULT_UNCONFIRMED_TX_ALERT_SEC](crate::sdk::DEFAULT_UNCONFIRMED_TX_ALERT_SEC).
+    pub unconfirmed_tx_alert_sec: Option<u32>,
+    /// The timeout in seconds of the requests to the Electrum or Esplora
+    /// [BlockchainExplorer]s. Can be raised on high-latency networks. It must be between 1 and
+    /// [MAX_NETWORK_TIMEOUT_SEC](crate::sdk::MAX_NETWORK_TIMEOUT_SEC).
+    ///
+    /// Defaults to [DEFAULT_CHAIN_SERVICE_TIMEOUT_SEC](crate::sdk::DEFAULT_CHAIN_SERVICE_TIMEOUT_SEC).
+    pub chain_service_timeout_sec: Option<u32>,
+    /// The timeout in seconds of each swapper call. It must be between 1 and
+    /// [MAX_NETWORK_TIMEOUT_SEC](crate::sdk::MAX_NETWORK_TIMEOUT_SEC).
+    ///
+    /// The calls that are safe to retry, e.g. fetching the pairs or broadcasting txs, are retried
+    /// up to [Config::swapper_max_attempts] times after timing out. The other calls, e.g. creating
+    /// a swap, fail once timed out.
+    ///
+    /// Defaults to [DEFAULT_SWAPPER_TIMEOUT_SEC](crate::sdk::DEFAULT_SWAPPER_TIMEOUT_SEC).
+    pub swapper_timeout_sec: Option<u32>,
+    /// The interval in seconds between the keep-alive pings sent on the swapper status stream.
+    /// It must be between 1 and [MAX_NETWORK_TIMEOUT_SEC](crate::sdk::MAX_NETWORK_TIMEOUT_SEC).
+    ///
+    /// Defaults to [DEFAULT_SWAPPER_PING_INTERVAL_SEC](crate::sdk::DEFAULT_SWAPPER_PING_INTERVAL_SEC).
+    pub swapper_ping_interval_sec: Option<u32>,
+    /// The URL of the AMP2 (Asset Management Platform) server used to register the AMP account
+    /// needed to receive and hold AMP-restricted assets, e.g. `https://amp2.testnet.blockstream.com`.
+    /// AMP2 is only available on testnet. If not set, AMP assets are not supported.
+    ///
+    /// See [LiquidSdk::register_amp_account](crate::sdk::LiquidSdk::register_amp_account).
+    pub amp_server_url: Option<String>,
+    /// Replaces the swapper with a local simulation of the swap lifecycles, to build and demo
+    /// apps without using the swapper or real funds. See [SimulationConfig]. It can't be used on
+    /// [LiquidNetwork::Mainnet].
+    ///
+    /// Defaults to `None`, which uses the swapper.
+    pub simulation: Option<SimulationConfig>,
+    /// Disables the automatic claim of the incoming swaps, i.e. Receive swaps and incoming
+    /// Chain swaps. Once the swapper lockup is verified, the swap stays
+    /// [Pending](PaymentState::Pending) until it is claimed with
+    /// [LiquidSdk::claim_swap](crate::sdk::LiquidSdk::claim_swap), e.g. to batch claims at
+    /// low-fee times or after an operator approval. Claimable swaps are those of
+    /// [LiquidSdk::list_ongoing_swaps](crate::sdk::LiquidSdk::list_ongoing_swaps) with a
+    /// `server_lockup_tx_id` and no `claim_tx_id`.
+    ///
+    /// Note that an unclaimed swap can be refunded by the swapper once it expires.
+    ///
+    /// Defaults to `false`.
+    pub manual_claim: bool,
+    /// A Liquid address of [Config::network] where the Receive swaps are claimed to, e.g. a cold
+    /// storage or treasury wallet, instead of the SDK wallet. It can be overridden per swap with
+    /// [ReceivePaymentRequest::claim_address].
+    ///
+    /// Note that direct payments to the magic routing hint address of the invoice, as well as
+    /// Chain swaps, are still received in the SDK wallet.
+    ///
+    /// Defaults to `None`, which claims to the SDK wallet.
+    pub claim_address: Option<String>,
+    /// The DNS-over-HTTPS JSON API used to look up BIP353 names, e.g. `₿user@domain`. It must
+    /// validate the DNSSEC chain and report it in the `AD` flag of the response, as names that
+    /// are not authenticated by DNSSEC are rejected.
+    ///
+    /// Defaults to [DEFAULT_DNS_OVER_HTTPS_URL](crate::sdk::DEFAULT_DNS_OVER_HTTPS_URL).
+    pub dns_over_https_url: Option<String>,
 }
 
 impl Config {
@@ -123,6 +317,25 @@ impl Config {
             onchain_fee_rate_leeway_sat_per_vbyte: None,
             asset_metadata: None,
             sideswap_api_key: Some(SIDESWAP_API_KEY.to_string()),
+            event_webhook: None,
+            fiat_currency: None,
+            sync_interval_sec: None,
+            bitcoin_explorer_authorization: None,
+            swapper_max_attempts: None,
+            backup_policy: None,
+            nwc_relay_url: None,
+            spend_limits: None,
+            lnurl_service_url: None,
+            outgoing_min_confirmations: None,
+            unconfirmed_tx_alert_sec: None,
+            chain_service_timeout_sec: None,
+            swapper_timeout_sec: None,
+            swapper_ping_interval_sec: None,
+            amp_server_url: None,
+            simulation: None,
+            manual_claim: false,
+            claim_address: None,
+            dns_over_https_url: None,
         }
     }
 
@@ -148,6 +361,25 @@ impl Config {
             onchain_fee_rate_leeway_sat_per_vbyte: None,
             asset_metadata: None,
             sideswap_api_key: Some(SIDESWAP_API_KEY.to_string()),
+            event_webhook: None,
+            fiat_currency: None,
+            sync_interval_sec: None,
+            bitcoin_explorer_authorization: None,
+            swapper_max_attempts: None,
+            backup_policy: None,
+            nwc_relay_url: None,
+            spend_limits: None,
+            lnurl_service_url: None,
+            outgoing_min_confirmations: None,
+            unconfirmed_tx_alert_sec: None,
+            chain_service_timeout_sec: None,
+            swapper_timeout_sec: None,
+            swapper_ping_interval_sec: None,
+            amp_server_url: None,
+            simulation: None,
+            manual_claim: false,
+            claim_address: None,
+            dns_over_https_url: None,
         }
     }
 
@@ -172,6 +404,25 @@ impl Config {
             onchain_fee_rate_leeway_sat_per_vbyte: None,
             asset_metadata: None,
             sideswap_api_key: Some(SIDESWAP_API_KEY.to_string()),
+            event_webhook: None,
+            fiat_currency: None,
+            sync_interval_sec: None,
+            bitcoin_explorer_authorization: None,
+            swapper_max_attempts: None,
+            backup_policy: None,
+            nwc_relay_url: None,
+            spend_limits: None,
+            lnurl_service_url: None,
+            outgoing_min_confirmations: None,
+            unconfirmed_tx_alert_sec: None,
+            chain_service_timeout_sec: None,
+            swapper_timeout_sec: None,
+            swapper_ping_interval_sec: None,
+            amp_server_url: None,
+            simulation: None,
+            manual_claim: false,
+            claim_address: None,
+            dns_over_https_url: None,
         }
     }
 
@@ -197,6 +448,25 @@ impl Config {
             onchain_fee_rate_leeway_sat_per_vbyte: None,
             asset_metadata: None,
             sideswap_api_key: Some(SIDESWAP_API_KEY.to_string()),
+            event_webhook: None,
+            fiat_currency: None,
+            sync_interval_sec: None,
+            bitcoin_explorer_authorization: None,
+            swapper_max_attempts: None,
+            backup_policy: None,
+            nwc_relay_url: None,
+            spend_limits: None,
+            lnurl_service_url: None,
+            outgoing_min_confirmations: None,
+            unconfirmed_tx_alert_sec: None,
+            chain_service_timeout_sec: None,
+            swapper_timeout_sec: None,
+            swapper_ping_interval_sec: None,
+            amp_server_url: None,
+            simulation: None,
+            manual_claim: false,
+            claim_address: None,
+            dns_over_https_url: None,
         }
     }
 
@@ -221,6 +491,25 @@ impl Config {
             onchain_fee_rate_leeway_sat_per_vbyte: None,
             asset_metadata: None,
             sideswap_api_key: None,
+            event_webhook: None,
+            fiat_currency: None,
+            sync_interval_sec: None,
+            bitcoin_explorer_authorization: None,
+            swapper_max_attempts: None,
+            backup_policy: None,
+            nwc_relay_url: None,
+            spend_limits: None,
+            lnurl_service_url: None,
+            outgoing_min_confirmations: None,
+            unconfirmed_tx_alert_sec: None,
+            chain_service_timeout_sec: None,
+            swapper_timeout_sec: None,
+            swapper_ping_interval_sec: None,
+            amp_server_url: None,
+            simulation: None,
+            manual_claim: false,
+            claim_address: None,
+            dns_over_https_url: None,
         }
     }
 
@@ -246,6 +535,25 @@ impl Config {
             onchain_fee_rate_leeway_sat_per_vbyte: None,
             asset_metadata: None,
             sideswap_api_key: None,
+            event_webhook: None,
+            fiat_currency: None,
+            sync_interval_sec: None,
+            bitcoin_explorer_authorization: None,
+            swapper_max_attempts: None,
+            backup_policy: None,
+            nwc_relay_url: None,
+            spend_limits: None,
+            lnurl_service_url: None,
+            outgoing_min_confirmations: None,
+            unconfirmed_tx_alert_sec: None,
+            chain_service_timeout_sec: None,
+            swapper_timeout_sec: None,
+            swapper_ping_interval_sec: None,
+            amp_server_url: None,
+            simulation: None,
+            manual_claim: false,
+            claim_address: None,
+            dns_over_https_url: None,
         }
     }
 
@@ -269,6 +577,58 @@ impl Config {
             .unwrap_or(DEFAULT_ZERO_CONF_MAX_SAT)
     }
 
+    pub fn swapper_max_attempts(&self) -> u32 {
+        self.swapper_max_attempts
+            .unwrap_or(DEFAULT_SWAPPER_MAX_ATTEMPTS)
+    }
+
+    pub fn outgoing_min_confirmations(&self) -> u32 {
+        self.outgoing_min_confirmations.unwrap_or(1).max(1)
+    }
+
+    pub(crate) fn dns_over_https_url(&self) -> &str {
+        self.dns_over_https_url
+            .as_deref()
+            .unwrap_or(crate::sdk::DEFAULT_DNS_OVER_HTTPS_URL)
+    }
+
+    pub(crate) fn unconfirmed_tx_alert_sec(&self) -> u32 {
+        self.unconfirmed_tx_alert_sec
+            .unwrap_or(crate::sdk::DEFAULT_UNCONFIRMED_TX_ALERT_SEC)
+    }
+
+    /// The timeout of the chain service requests, in the seconds expected by the clients
+    pub(crate) fn chain_service_timeout_sec(&self) -> u8 {
+        self.chain_service_timeout_sec
+            .unwrap_or(crate::sdk::DEFAULT_CHAIN_SERVICE_TIMEOUT_SEC)
+            .try_into()
+            .unwrap_or(u8::MAX)
+    }
+
+    pub(crate) fn swapper_timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.swapper_timeout_sec
+                .unwrap_or(crate::sdk::DEFAULT_SWAPPER_TIMEOUT_SEC)
+                .into(),
+        )
+    }
+
+    pub(crate) fn swapper_ping_interval(&self) -> Duration {
+        Duration::from_secs(
+            self.swapper_ping_interval_sec
+                .unwrap_or(crate::sdk::DEFAULT_SWAPPER_PING_INTERVAL_SEC)
+                .into(),
+        )
+    }
+
+    pub(crate) fn sync_interval(&self) -> Duration {
+        Duration::from_secs(
+            self.sync_interval_sec
+                .unwrap_or(crate::sdk::DEFAULT_SYNC_INTERVAL_SEC)
+                .into(),
+        )
+    }
+
     pub(crate) fn lbtc_asset_id(&self) -> String {
         utils::lbtc_asset_id(self.network).to_string()
     }
@@ -347,7 +707,9 @@ impl Config {
         let electrum_url = lwk_wollet::ElectrumUrl::new(url, tls, validate_domain)?;
         lwk_wollet::ElectrumClient::with_options(
             &electrum_url,
-            lwk_wollet::ElectrumOptions { timeout: Some(3) },
+            lwk_wollet::ElectrumOptions {
+                timeout: Some(self.chain_service_timeout_sec()),
+            },
         )
     }
 }
@@ -454,7 +816,7 @@ pub trait EventListener: MaybeSend + MaybeSync {
 
 /// Event emitted by the SDK. Add an [EventListener] by calling [crate::sdk::LiquidSdk::add_event_listener]
 /// to listen for emitted events.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum SdkEvent {
     PaymentFailed {
         details: Payment,
@@ -480,6 +842,38 @@ pub enum SdkEvent {
     PaymentWaitingFeeAcceptance {
         details: Payment,
     },
+    /// A Lightning invoice created when receiving a payment expired without being paid
+    PaymentExpired {
+        details: Payment,
+    },
+    /// The confirmed tx of a completed payment was reorged out of the chain. The payment is
+    /// pending again until its tx confirms again.
+    PaymentReorged {
+        details: Payment,
+    },
+    /// The state of a swap changed
+    SwapUpdated {
+        swap_id: String,
+        swap_type: PaymentSwapType,
+        state: PaymentState,
+    },
+    /// A claim transaction was broadcast for a swap
+    SwapClaimBroadcast {
+        swap_id: String,
+        tx_id: String,
+    },
+    /// A refund transaction was broadcast for a swap
+    SwapRefundBroadcast {
+        swap_id: String,
+        tx_id: String,
+    },
+    /// A new Liquid or Bitcoin block was found
+    TipsUpdated {
+        liquid_tip: u32,
+        bitcoin_tip: u32,
+    },
+    /// Started syncing with mempool and onchain data
+    SyncStarted,
     /// Synced with mempool and onchain data
     Synced,
     /// Synced with real-time data sync
@@ -487,6 +881,291 @@ pub enum SdkEvent {
         /// Indicates new data was pulled from other instances.
         did_pull_new_records: bool,
     },
+    /// The chain services became reachable again, or stopped being reachable. While offline,
+    /// the calls relying on local data keep working, while the others fail with a
+    /// [ServiceUnavailable](crate::error::PaymentError::ServiceUnavailable) error.
+    ConnectivityChanged {
+        is_online: bool,
+    },
+    /// The local state was backed up according to the [Config::backup_policy]
+    BackupSucceeded,
+    /// The local state failed to be backed up according to the [Config::backup_policy]
+    BackupFailed {
+        err: String,
+    },
+    /// All the items of a [crate::sdk::LiquidSdk::send_batch] call were processed
+    BatchSendCompleted {
+        results: Vec<BatchSendItemResult>,
+    },
+    /// A [ScheduledPayment] was sent at its execution time
+    ScheduledPaymentExecuted {
+        id: String,
+        details: Payment,
+    },
+    /// A [ScheduledPayment] failed with a permanent error or ran out of attempts
+    ScheduledPaymentFailed {
+        id: String,
+        err: String,
+    },
+    /// An occurrence of a [RecurringPayment] was sent
+    RecurringPaymentExecuted {
+        recurring_payment_id: String,
+        details: Payment,
+    },
+    /// An occurrence of a [RecurringPayment] failed. The next occurrences are still attempted.
+    RecurringPaymentFailed {
+        recurring_payment_id: String,
+        err: String,
+    },
+    /// An unconfirmed tx of the wallet, e.g. a payment or a swap lockup or claim tx, is no
+    /// longer in the mempool because it was evicted or double-spent
+    TransactionDropped {
+        tx_id: String,
+    },
+    /// A tx of the wallet is still unconfirmed after [Config::unconfirmed_tx_alert_sec].
+    /// Emitted once per tx.
+    TransactionUnconfirmed {
+        tx_id: String,
+        /// The number of seconds since the tx was first seen
+        unconfirmed_sec: u32,
+    },
+    /// A new Liquid block was found
+    NewLiquidBlock {
+        block: BlockInfo,
+    },
+    /// A new Bitcoin block was found
+    NewBitcoinBlock {
+        block: BlockInfo,
+    },
+}
+
+impl SdkEvent {
+    /// The kind of this event
+    pub fn kind(&self) -> SdkEventKind {
+        match self {
+            SdkEvent::PaymentFailed { .. } => SdkEventKind::PaymentFailed,
+            SdkEvent::PaymentPending { .. } => SdkEventKind::PaymentPending,
+            SdkEvent::PaymentRefundable { .. } => SdkEventKind::PaymentRefundable,
+            SdkEvent::PaymentRefunded { .. } => SdkEventKind::PaymentRefunded,
+            SdkEvent::PaymentRefundPending { .. } => SdkEventKind::PaymentRefundPending,
+            SdkEvent::PaymentSucceeded { .. } => SdkEventKind::PaymentSucceeded,
+            SdkEvent::PaymentWaitingConfirmation { .. } => SdkEventKind::PaymentWaitingConfirmation,
+            SdkEvent::PaymentWaitingFeeAcceptance { .. } => {
+                SdkEventKind::PaymentWaitingFeeAcceptance
+            }
+            SdkEvent::PaymentExpired { .. } => SdkEventKind::PaymentExpired,
+            SdkEvent::PaymentReorged { .. } => SdkEventKind::PaymentReorged,
+            SdkEvent::SwapUpdated { .. } => SdkEventKind::SwapUpdated,
+            SdkEvent::SwapClaimBroadcast { .. } => SdkEventKind::SwapClaimBroadcast,
+            SdkEvent::SwapRefundBroadcast { .. } => SdkEventKind::SwapRefundBroadcast,
+            SdkEvent::TipsUpdated { .. } => SdkEventKind::TipsUpdated,
+            SdkEvent::SyncStarted => SdkEventKind::SyncStarted,
+            SdkEvent::Synced => SdkEventKind::Synced,
+            SdkEvent::DataSynced { .. } => SdkEventKind::DataSynced,
+            SdkEvent::ConnectivityChanged { .. } => SdkEventKind::ConnectivityChanged,
+            SdkEvent::BackupSucceeded => SdkEventKind::BackupSucceeded,
+            SdkEvent::BackupFailed { .. } => SdkEventKind::BackupFailed,
+            SdkEvent::BatchSendCompleted { .. } => SdkEventKind::BatchSendCompleted,
+            SdkEvent::ScheduledPaymentExecuted { .. } => SdkEventKind::ScheduledPaymentExecuted,
+            SdkEvent::ScheduledPaymentFailed { .. } => SdkEventKind::ScheduledPaymentFailed,
+            SdkEvent::RecurringPaymentExecuted { .. } => SdkEventKind::RecurringPaymentExecuted,
+            SdkEvent::RecurringPaymentFailed { .. } => SdkEventKind::RecurringPaymentFailed,
+            SdkEvent::TransactionDropped { .. } => SdkEventKind::TransactionDropped,
+            SdkEvent::TransactionUnconfirmed { .. } => SdkEventKind::TransactionUnconfirmed,
+            SdkEvent::NewLiquidBlock { .. } => SdkEventKind::NewLiquidBlock,
+            SdkEvent::NewBitcoinBlock { .. } => SdkEventKind::NewBitcoinBlock,
+        }
+    }
+
+    /// The payment this event relates to, if any
+    pub fn payment(&self) -> Option<&Payment> {
+        match self {
+            SdkEvent::PaymentFailed { details }
+            | SdkEvent::PaymentPending { details }
+            | SdkEvent::PaymentRefundable { details }
+            | SdkEvent::PaymentRefunded { details }
+            | SdkEvent::PaymentRefundPending { details }
+            | SdkEvent::PaymentSucceeded { details }
+            | SdkEvent::PaymentWaitingConfirmation { details }
+            | SdkEvent::PaymentWaitingFeeAcceptance { details }
+            | SdkEvent::PaymentExpired { details }
+            | SdkEvent::PaymentReorged { details } => Some(details),
+            _ => None,
+        }
+    }
+
+    /// Whether this event reports a change in the state of a payment, swap or tx, as opposed to
+    /// a periodic status update like a sync or a new block
+    pub(crate) fn is_state_change(&self) -> bool {
+        !matches!(
+            self,
+            SdkEvent::TipsUpdated { .. }
+                | SdkEvent::SyncStarted
+                | SdkEvent::Synced
+                | SdkEvent::DataSynced { .. }
+                | SdkEvent::ConnectivityChanged { .. }
+                | SdkEvent::BackupSucceeded
+                | SdkEvent::BackupFailed { .. }
+                | SdkEvent::NewLiquidBlock { .. }
+                | SdkEvent::NewBitcoinBlock { .. }
+        )
+    }
+
+    /// The ids of the payment or swap this event relates to: swap ids and tx ids
+    pub(crate) fn payment_ids(&self) -> Vec<String> {
+        if let Some(payment) = self.payment() {
+            return payment
+                .details
+                .get_swap_id()
+                .into_iter()
+                .chain(payment.tx_id.clone())
+                .collect();
+        }
+        match self {
+            SdkEvent::SwapUpdated { swap_id, .. } => vec![swap_id.clone()],
+            SdkEvent::SwapClaimBroadcast { swap_id, tx_id }
+            | SdkEvent::SwapRefundBroadcast { swap_id, tx_id } => {
+                vec![swap_id.clone(), tx_id.clone()]
+            }
+            SdkEvent::TransactionDropped { tx_id }
+            | SdkEvent::TransactionUnconfirmed { tx_id, .. } => vec![tx_id.clone()],
+            SdkEvent::ScheduledPaymentExecuted { details, .. }
+            | SdkEvent::RecurringPaymentExecuted { details, .. } => details
+                .details
+                .get_swap_id()
+                .into_iter()
+                .chain(details.tx_id.clone())
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Whether the event follows a change of the persisted payments or swaps
+    pub(crate) fn is_state_change(&self) -> bool {
+        match self {
+            SdkEvent::SyncStarted
+            | SdkEvent::TipsUpdated { .. }
+            | SdkEvent::ConnectivityChanged { .. }
+            | SdkEvent::BackupSucceeded
+            | SdkEvent::BackupFailed { .. }
+            | SdkEvent::BatchSendCompleted { .. }
+            | SdkEvent::TransactionUnconfirmed { .. }
+            | SdkEvent::NewLiquidBlock { .. }
+            | SdkEvent::NewBitcoinBlock { .. } => false,
+            SdkEvent::DataSynced {
+                did_pull_new_records,
+            } => *did_pull_new_records,
+            _ => true,
+        }
+    }
+
+    /// The type of the swap this event relates to, if any
+    pub(crate) fn swap_type(&self) -> Option<PaymentSwapType> {
+        if let Some(payment) = self.payment() {
+            return match (&payment.details, payment.payment_type) {
+                (PaymentDetails::Lightning { .. }, PaymentType::Receive) => {
+                    Some(PaymentSwapType::Receive)
+                }
+                (PaymentDetails::Lightning { .. }, PaymentType::Send) => {
+                    Some(PaymentSwapType::Send)
+                }
+                (PaymentDetails::Bitcoin { .. }, _) => Some(PaymentSwapType::Chain),
+                (PaymentDetails::Liquid { .. }, _) => None,
+            };
+        }
+        match self {
+            SdkEvent::SwapUpdated { swap_type, .. } => Some(*swap_type),
+            _ => None,
+        }
+    }
+}
+
+/// The kind of an [SdkEvent], used to filter events with an [EventFilter]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum SdkEventKind {
+    PaymentFailed,
+    PaymentPending,
+    PaymentRefundable,
+    PaymentRefunded,
+    PaymentRefundPending,
+    PaymentSucceeded,
+    PaymentWaitingConfirmation,
+    PaymentWaitingFeeAcceptance,
+    PaymentExpired,
+    PaymentReorged,
+    SwapUpdated,
+    SwapClaimBroadcast,
+    SwapRefundBroadcast,
+    TipsUpdated,
+    SyncStarted,
+    Synced,
+    DataSynced,
+    ConnectivityChanged,
+    BackupSucceeded,
+    BackupFailed,
+    BatchSendCompleted,
+    ScheduledPaymentExecuted,
+    ScheduledPaymentFailed,
+    RecurringPaymentExecuted,
+    RecurringPaymentFailed,
+    TransactionDropped,
+    TransactionUnconfirmed,
+    NewLiquidBlock,
+    NewBitcoinBlock,
+}
+
+/// A filter for the events received by an [EventListener]. See
+/// [crate::sdk::LiquidSdk::add_event_listener]. An event matches the filter if it matches
+/// all of the set criteria.
+#[derive(Clone, Debug, Default)]
+pub struct EventFilter {
+    /// Only events of these kinds
+    pub kinds: Option<Vec<SdkEventKind>>,
+    /// Only events related to a payment or swap with one of these ids, either swap ids or tx ids
+    pub payment_ids: Option<Vec<String>>,
+    /// Only events related to a swap of one of these types
+    pub swap_types: Option<Vec<PaymentSwapType>>,
+}
+
+impl EventFilter {
+    pub(crate) fn matches(&self, e: &SdkEvent) -> bool {
+        if let Some(kinds) = &self.kinds {
+            if !kinds.contains(&e.kind()) {
+                return false;
+            }
+        }
+        if let Some(payment_ids) = &self.payment_ids {
+            if !e.payment_ids().iter().any(|id| payment_ids.contains(id)) {
+                return false;
+            }
+        }
+        if let Some(swap_types) = &self.swap_types {
+            if !e.swap_type().is_some_and(|t| swap_types.contains(&t)) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::replay_events].
+#[derive(Debug, Default)]
+pub struct ReplayEventsRequest {
+    /// Only events with a greater sequence number are returned. If not set, all journaled
+    /// events are returned.
+    pub since_seq: Option<u64>,
+    /// The maximum number of events to return
+    pub limit: Option<u32>,
+}
+
+/// An [SdkEvent] persisted in the event journal. See [crate::sdk::LiquidSdk::replay_events].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct JournaledEvent {
+    /// The sequence number of the event, increasing in the order events were emitted
+    pub seq: u64,
+    /// The time the event was emitted, as a UNIX timestamp in seconds
+    pub timestamp: u32,
+    pub event: SdkEvent,
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -545,8 +1224,71 @@ pub trait Signer: MaybeSend + MaybeSync {
     fn ecies_decrypt(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError>;
 }
 
+/// An additional parser of payment inputs, e.g. for regional payment schemes or merchant QR
+/// formats. It is consulted by [crate::sdk::LiquidSdk::parse] when the input is not recognized
+/// by the built-in and external input parsers. See [crate::sdk::LiquidSdk::add_input_parser].
+#[sdk_macros::async_trait]
+pub trait InputParser: MaybeSend + MaybeSync {
+    /// Returns the parsed [InputType], or `None` if the input is not recognized
+    async fn parse_input(&self, input: String) -> Option<InputType>;
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum BroadcasterError {
+    #[error("Broadcaster error: {err}")]
+    Generic { err: String },
+}
+
+/// A broadcaster of signed transactions, e.g. through an own Elements or Bitcoin node, or through
+/// multiple redundant endpoints. When set with [crate::sdk::LiquidSdkBuilder::broadcaster] or
+/// [crate::sdk::LiquidSdk::set_broadcaster], it replaces the default broadcast through the
+/// Liquid and Bitcoin chain services.
+#[sdk_macros::async_trait]
+pub trait Broadcaster: MaybeSend + MaybeSync {
+    /// Broadcasts a signed Liquid transaction, given as hex, returning its txid
+    async fn broadcast_liquid_tx(&self, tx_hex: String) -> Result<String, BroadcasterError>;
+
+    /// Broadcasts a signed Bitcoin transaction, given as hex, returning its txid
+    async fn broadcast_bitcoin_tx(&self, tx_hex: String) -> Result<String, BroadcasterError>;
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum FiatRateProviderError {
+    #[error("Fiat rate provider error: {err}")]
+    Generic { err: String },
+}
+
+/// A source of fiat exchange rates. The sdk user can implement this trait to use their own
+/// rates, e.g. from an exchange API or internal treasury rates, instead of the Breez rate service.
+/// See [crate::sdk::LiquidSdk::set_fiat_rate_provider].
+pub trait FiatRateProvider: MaybeSend + MaybeSync {
+    /// Returns the current BTC exchange rates of the supported fiat currencies
+    fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, FiatRateProviderError>;
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum BackupTransportError {
+    #[error("Backup transport error: {err}")]
+    Generic { err: String },
+}
+
+/// A remote storage for the SDK backups, e.g. iCloud, Google Drive or S3. Once set with
+/// [crate::sdk::LiquidSdk::set_backup_transport], a new backup version is uploaded after each
+/// change of the payments or swaps. The backups are encrypted by the SDK before they are
+/// uploaded.
+pub trait BackupTransport: MaybeSend + MaybeSync {
+    /// Uploads a new backup version, returning its id
+    fn upload(&self, backup: Vec<u8>) -> Result<String, BackupTransportError>;
+
+    /// Downloads the backup version with the given id
+    fn download(&self, version: String) -> Result<Vec<u8>, BackupTransportError>;
+
+    /// Lists the ids of the uploaded backup versions, from newest to oldest
+    fn list_versions(&self) -> Result<Vec<String>, BackupTransportError>;
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::connect].
-/// The resquest takes either a `mnemonic` and `passphrase`, or a `seed`.
+/// The resquest takes either a `mnemonic` and `passphrase`, a `seed`, or an `xprv`.
 pub struct ConnectRequest {
     /// The SDK [Config]
     pub config: Config,
@@ -554,14 +1296,64 @@ pub struct ConnectRequest {
     pub mnemonic: Option<String>,
     /// The optional passphrase for the mnemonic
     pub passphrase: Option<String>,
-    /// The optional Liquid wallet seed
+    /// The optional Liquid wallet seed, e.g. the 64-byte BIP39 seed
     pub seed: Option<Vec<u8>>,
+    /// The optional BIP32 master extended private key of the Liquid wallet, for key management
+    /// not based on a mnemonic or seed.
+    /// See [SdkSigner::new_with_xprv](crate::signer::SdkSigner::new_with_xprv).
+    pub xprv: Option<String>,
 }
 
 pub struct ConnectWithSignerRequest {
     pub config: Config,
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::process_notification].
+pub struct ProcessNotificationRequest {
+    /// The request used to connect the short-lived SDK session
+    pub connect_request: ConnectRequest,
+    /// The hex-encoded push payload, encrypted with ECIES to the wallet pubkey
+    /// ([WalletInfo::pubkey])
+    pub payload: String,
+    /// Whether the fees proposed by the swapper for an amountless chain swap are accepted, see
+    /// [crate::sdk::LiquidSdk::accept_payment_proposed_fees].
+    ///
+    /// Defaults to `false`, leaving the decision to the user.
+    pub accept_proposed_fees: Option<bool>,
+    /// How long the session runs at most.
+    ///
+    /// Defaults to 25 seconds.
+    pub timeout_sec: Option<u32>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::process_notification].
+#[derive(Debug, Serialize)]
+pub struct ProcessNotificationResponse {
+    /// The payment of the swap the notification is about, in the state reached when the
+    /// session ended. For a lightning address invoice request, it's the created receive swap.
+    pub payment: Payment,
+    /// Whether the SDK completed its part of the swap, e.g. by broadcasting the claim tx.
+    /// Otherwise, the swap is handled the next time the SDK runs.
+    pub is_resolved: bool,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::register_lightning_address].
+#[derive(Debug, Serialize)]
+pub struct RegisterLightningAddressRequest {
+    /// The username of the lightning address, i.e. `username` in `username@domain`
+    pub username: String,
+}
+
+/// A lightning address registered with the [Config::lnurl_service_url] service.
+#[derive(Clone, Debug, Serialize)]
+pub struct LightningAddressInfo {
+    pub username: String,
+    /// The lightning address, e.g. `satoshi@example.com`
+    pub lightning_address: String,
+    /// The bech32-encoded LNURL-pay of the lightning address
+    pub lnurl: String,
+}
+
 /// A reserved address. Once an address is reserved, it can only be
 /// reallocated to another payment after the block height expiration.
 #[derive(Clone, Debug)]
@@ -593,6 +1385,10 @@ pub enum ReceiveAmount {
         asset_id: String,
         payer_amount: Option<f64>,
     },
+
+    /// The amount of an asset that should be paid, in its base units. Its precision must match
+    /// the precision of the asset.
+    AssetUnits { payer_amount: AssetAmount },
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
@@ -602,6 +1398,15 @@ pub struct PrepareReceiveRequest {
 
     /// The amount to be paid in either Bitcoin or another asset
     pub amount: Option<ReceiveAmount>,
+
+    /// The amount to be paid denominated in a fiat currency. It is converted to a Bitcoin amount
+    /// at preparation time. Cannot be set together with `amount`.
+    pub fiat_amount: Option<FiatAmount>,
+
+    /// The expiry of the generated invoice in seconds, between [crate::receive_swap::MIN_INVOICE_EXPIRY_SECS]
+    /// and [crate::receive_swap::MAX_INVOICE_EXPIRY_SECS]. Only supported when the method is [PaymentMethod::Lightning].
+    /// Defaults to the swapper's invoice expiry.
+    pub expiry_secs: Option<u32>,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
@@ -620,6 +1425,9 @@ pub struct PrepareReceiveResponse {
     /// In all other types of swaps, the swapper service fee is included in `fees_sat`.
     pub fees_sat: u64,
 
+    /// The breakdown of `fees_sat` into its components
+    pub fee_breakdown: FeeBreakdown,
+
     /// The minimum amount the payer can send for this swap to succeed.
     ///
     /// When the method is [PaymentMethod::LiquidAddress], this is empty.
@@ -634,6 +1442,29 @@ pub struct PrepareReceiveResponse {
     ///
     /// When the method is [PaymentMethod::LiquidAddress], this is empty.
     pub swapper_feerate: Option<f64>,
+
+    /// The fiat conversion used, if the amount was requested in a fiat currency
+    pub fiat_conversion: Option<FiatConversion>,
+
+    /// The expiry of the generated invoice in seconds, if set in the request
+    pub expiry_secs: Option<u32>,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_payment_quotes].
+#[derive(Debug, Serialize)]
+pub struct PrepareReceiveQuotesRequest {
+    /// The amount to be paid in either Bitcoin or another asset
+    pub amount: Option<ReceiveAmount>,
+
+    /// The amount to be paid denominated in a fiat currency. Cannot be set together with `amount`.
+    pub fiat_amount: Option<FiatAmount>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_receive_payment_quotes].
+#[derive(Debug, Serialize, Clone)]
+pub struct PrepareReceiveQuotesResponse {
+    /// The prepared receive of each payment method able to receive the amount
+    pub quotes: Vec<PrepareReceiveResponse>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::receive_payment].
@@ -644,6 +1475,12 @@ pub struct ReceivePaymentRequest {
     pub description: Option<String>,
     /// If set to true, then the hash of the description will be used.
     pub use_description_hash: Option<bool>,
+    /// A precomputed hex-encoded SHA256 hash to commit to in the invoice instead of a description,
+    /// as required by LNURL-pay servers (LUD-06). Only valid for [PaymentMethod::Lightning].
+    pub description_hash: Option<String>,
+    /// A Liquid address where the swap is claimed to instead of the SDK wallet, overriding
+    /// [Config::claim_address]. Only valid for [PaymentMethod::Lightning].
+    pub claim_address: Option<String>,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::receive_payment].
@@ -680,8 +1517,96 @@ pub struct OnchainPaymentLimitsResponse {
     pub receive: Limits,
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::get_max_payable].
+#[derive(Debug, Serialize)]
+pub struct GetMaxPayableRequest {
+    pub payment_method: PaymentMethod,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::get_max_payable].
+#[derive(Debug, Default, Serialize)]
+pub struct GetMaxPayableResponse {
+    /// The maximum amount the recipient can receive, or zero if the balance is too low to pay
+    /// with this payment method
+    pub receiver_amount_sat: u64,
+    /// The total fees paid when sending the maximum amount
+    pub fees_sat: u64,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::get_max_receivable].
+#[derive(Debug, Serialize)]
+pub struct GetMaxReceivableRequest {
+    pub payment_method: PaymentMethod,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::get_max_receivable].
+#[derive(Debug, Serialize)]
+pub struct GetMaxReceivableResponse {
+    /// The maximum amount the payer can pay, or `None` if there is no maximum, as when
+    /// receiving to a Liquid address
+    pub payer_amount_sat: Option<u64>,
+    /// The total fees deducted when receiving the maximum amount
+    pub fees_sat: u64,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::get_reusable_bitcoin_address].
+#[derive(Debug, Serialize)]
+pub struct ReusableBitcoinAddressResponse {
+    /// The Bitcoin address, which can be paid to repeatedly
+    pub address: String,
+    /// The minimum deposit that can be forwarded to a chain swap. The forwarding tx fee is
+    /// deducted from each deposit before the chain swap limits apply.
+    pub min_deposit_sat: u64,
+    /// The maximum deposit that can be forwarded to a chain swap
+    pub max_deposit_sat: u64,
+}
+
+/// A BOLT12 invoice decoded by [crate::sdk::LiquidSdk::decode_invoice]
+#[derive(Clone, Debug, Serialize)]
+pub struct LNBolt12Invoice {
+    pub invoice: String,
+    /// The pubkey the invoice is signed with
+    pub signing_pubkey: String,
+    pub payment_hash: String,
+    pub description: Option<String>,
+    /// The note set by the payer when requesting the invoice
+    pub payer_note: Option<String>,
+    pub amount_msat: u64,
+    /// The creation time of the invoice, in seconds since the Unix epoch
+    pub created_at: u64,
+    /// The number of seconds after `created_at` at which the invoice expires
+    pub expiry: u64,
+    /// The number of blinded payment paths, which replace the BOLT11 routing hints
+    pub payment_path_count: u32,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::decode_invoice].
+#[derive(Clone, Debug, Serialize)]
+pub enum DecodedInvoice {
+    Bolt11 { invoice: LNInvoice },
+    Bolt12 { invoice: LNBolt12Invoice },
+}
+
+/// The chain of an address validated by [crate::sdk::LiquidSdk::validate_address]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum AddressType {
+    Bitcoin,
+    Liquid,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::validate_address].
+#[derive(Clone, Debug, Serialize)]
+pub struct ValidateAddressResponse {
+    pub address_type: AddressType,
+    pub network: LiquidNetwork,
+    /// Whether the address is a confidential Liquid address, i.e. includes a blinding key
+    pub is_confidential: bool,
+    /// Whether the address can receive Liquid assets other than L-BTC
+    pub supports_assets: bool,
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_send_payment].
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PrepareSendRequest {
     /// The destination we intend to pay to.
     /// Supports BIP21 URIs, BOLT11 invoices, BOLT12 offers and Liquid addresses
@@ -690,6 +1615,30 @@ pub struct PrepareSendRequest {
     /// Should only be set when paying directly onchain or to a BIP21 URI
     /// where no amount is specified, or when the caller wishes to drain
     pub amount: Option<PayAmount>,
+
+    /// The amount to send denominated in a fiat currency. It is converted to a Bitcoin amount
+    /// at preparation time. Cannot be set together with `amount`.
+    pub fiat_amount: Option<FiatAmount>,
+}
+
+/// An amount denominated in a fiat currency
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FiatAmount {
+    /// The amount in the fiat currency, e.g. `5.00`
+    pub amount: f64,
+    /// The fiat currency code, e.g. `USD`
+    pub currency: String,
+}
+
+/// The conversion of a [FiatAmount] to satoshi, done when preparing a payment
+#[derive(Clone, Debug, Serialize)]
+pub struct FiatConversion {
+    /// The requested fiat amount
+    pub fiat_amount: FiatAmount,
+    /// The exchange rate used, in units of the fiat currency per BTC
+    pub rate: f64,
+    /// The resulting amount in satoshi
+    pub amount_sat: u64,
 }
 
 /// Specifies the supported destinations which can be payed by the SDK
@@ -699,6 +1648,9 @@ pub enum SendDestination {
         address_data: liquid::LiquidAddressData,
         /// A BIP353 address, in case one was used to resolve this Liquid address
         bip353_address: Option<String>,
+        /// The payjoin endpoint advertised with the `pj` parameter of the BIP21 URI. If set, an
+        /// L-BTC payment is sent as a payjoin, falling back to a regular transaction on failure.
+        payjoin_url: Option<String>,
     },
     Bolt11 {
         invoice: LNInvoice,
@@ -713,6 +1665,56 @@ pub enum SendDestination {
     },
 }
 
+/// The breakdown of the fees of a payment into the swapper service fee and the onchain fees
+/// of the swapper's, lockup and claim txs. The fees sum up to the `fees_sat` of the prepare
+/// response.
+///
+/// When the payment fees can be paid using the asset, `asset_fee` holds the cost of having
+/// the payjoin service pay the L-BTC network fee in exchange for an amount of the asset. If
+/// the payment can only be paid using the asset, `fees_sat` is not set and the breakdown holds
+/// the payjoin service fee and the network fee it pays instead.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FeeBreakdown {
+    /// The fee charged by the swapper for its service. It is fixed once the swap is created.
+    pub service_fee_sat: u64,
+    /// The onchain fees of the swapper's own txs in a chain swap, i.e. its lockup tx and its claim
+    /// of the payer's lockup tx, which the swapper charges on top of its service fee. It is fixed
+    /// by the swapper.
+    pub swapper_network_fee_sat: u64,
+    /// The fee of the tx locking up the funds of the swap, or of the tx paying the destination
+    /// directly when no swap is used
+    pub lockup_fee_sat: u64,
+    /// Whether `lockup_fee_sat` is an estimate, which can change until the tx is broadcast,
+    /// rather than a fee fixed by the swapper
+    pub lockup_fee_is_estimate: bool,
+    /// The fee of the tx claiming the funds of the swap
+    pub claim_fee_sat: u64,
+    /// Whether `claim_fee_sat` is an estimate, which can change until the tx is broadcast,
+    /// rather than a fee fixed by the swapper
+    pub claim_fee_is_estimate: bool,
+    /// The estimated fee paid in the asset instead of L-BTC. Is set when
+    /// [PayAmount::Asset::estimate_asset_fees] is set to `true` and the asset can pay fees.
+    pub asset_fee: Option<AssetAmount>,
+}
+
+impl FeeBreakdown {
+    /// The breakdown of the estimated fee of a tx paying the destination directly
+    pub(crate) fn direct(tx_fee_sat: u64) -> Self {
+        FeeBreakdown {
+            lockup_fee_sat: tx_fee_sat,
+            lockup_fee_is_estimate: true,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn total(&self) -> u64 {
+        self.service_fee_sat
+            + self.swapper_network_fee_sat
+            + self.lockup_fee_sat
+            + self.claim_fee_sat
+    }
+}
+
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_send_payment].
 #[derive(Debug, Serialize, Clone)]
 pub struct PrepareSendResponse {
@@ -720,10 +1722,17 @@ pub struct PrepareSendResponse {
     /// The optional estimated fee in satoshi. Is set when there is Bitcoin available
     /// to pay fees. When not set, there are asset fees available to pay fees.
     pub fees_sat: Option<u64>,
+    /// The breakdown of `fees_sat` into its components. Is set when `fees_sat` or
+    /// `estimated_asset_fees` is set.
+    pub fee_breakdown: Option<FeeBreakdown>,
     /// The optional estimated fee in the asset. Is set when [PayAmount::Asset::estimate_asset_fees]
     /// is set to `true`, the Payjoin service accepts this asset to pay fees and there
     /// are funds available in this asset to pay fees.
     pub estimated_asset_fees: Option<f64>,
+    /// The `estimated_asset_fees` carrying the asset precision
+    pub estimated_asset_fees_amount: Option<AssetAmount>,
+    /// The fiat conversion used, if the amount was requested in a fiat currency
+    pub fiat_conversion: Option<FiatConversion>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::send_payment].
@@ -731,6 +1740,17 @@ pub struct PrepareSendResponse {
 pub struct SendPaymentRequest {
     pub prepare_response: PrepareSendResponse,
     pub use_asset_fees: Option<bool>,
+    /// The maximum fees to pay. If the fees computed when sending, which may have changed since
+    /// the payment was prepared, would exceed it, the payment fails with
+    /// [PaymentError::FeeLimitExceeded]. When `use_asset_fees` is set, the limit applies to the
+    /// asset fees, in the asset's base units.
+    pub max_fee_sat: Option<u64>,
+    /// A token allowing the payment to exceed the [Config::spend_limits], created with
+    /// [crate::sdk::LiquidSdk::create_spend_limit_override]
+    pub spend_limit_override: Option<String>,
+    /// A caller-supplied key identifying the payment. If a payment was already sent with the
+    /// same key, e.g. before an app crash or timeout, it's returned instead of being sent again.
+    pub idempotency_key: Option<String>,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::send_payment].
@@ -739,8 +1759,187 @@ pub struct SendPaymentResponse {
     pub payment: Payment,
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::queue_payment].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QueuePaymentRequest {
+    /// The request used to prepare the payment on each attempt
+    pub prepare_request: PrepareSendRequest,
+    pub use_asset_fees: Option<bool>,
+    /// See [SendPaymentRequest::max_fee_sat]
+    pub max_fee_sat: Option<u64>,
+}
+
+/// The state of a [QueuedPayment]
+#[derive(Clone, Copy, Debug, EnumString, Eq, PartialEq, Serialize)]
+#[strum(serialize_all = "lowercase")]
+pub enum QueuedPaymentState {
+    /// The payment is waiting for its next attempt
+    Pending = 0,
+    /// The payment failed with a permanent error or ran out of attempts
+    Failed = 1,
+}
+
+/// A payment in the outgoing payment queue. See [crate::sdk::LiquidSdk::queue_payment].
+#[derive(Clone, Debug, Serialize)]
+pub struct QueuedPayment {
+    pub id: String,
+    pub request: QueuePaymentRequest,
+    pub state: QueuedPaymentState,
+    /// The number of send attempts made so far
+    pub attempts: u32,
+    pub created_at: u32,
+    /// The earliest time of the next attempt, as a UNIX timestamp in seconds
+    pub next_attempt_at: u32,
+    /// The error returned by the last attempt, if any
+    pub last_error: Option<String>,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::schedule_payment].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SchedulePaymentRequest {
+    /// The request used to prepare the payment at execution time
+    pub prepare_request: PrepareSendRequest,
+    pub use_asset_fees: Option<bool>,
+    /// See [SendPaymentRequest::max_fee_sat]
+    pub max_fee_sat: Option<u64>,
+    /// The time at which the payment is executed, as a UNIX timestamp in seconds
+    pub execute_at: u32,
+}
+
+/// The state of a [ScheduledPayment]
+#[derive(Clone, Copy, Debug, EnumString, Eq, PartialEq, Serialize)]
+#[strum(serialize_all = "lowercase")]
+pub enum ScheduledPaymentState {
+    /// The payment is waiting for its execution time or for a retry
+    Pending = 0,
+    /// The payment was sent
+    Executed = 1,
+    /// The payment failed with a permanent error or ran out of attempts
+    Failed = 2,
+    /// An attempt is running. If the attempt was interrupted, it's resumed on the next start,
+    /// without sending the payment twice.
+    InProgress = 3,
+}
+
+/// A future-dated payment. See [crate::sdk::LiquidSdk::schedule_payment].
+#[derive(Clone, Debug, Serialize)]
+pub struct ScheduledPayment {
+    pub id: String,
+    pub request: SchedulePaymentRequest,
+    pub state: ScheduledPaymentState,
+    /// The number of send attempts made so far
+    pub attempts: u32,
+    pub created_at: u32,
+    /// The earliest time of the next attempt, as a UNIX timestamp in seconds
+    pub next_attempt_at: u32,
+    /// The tx id of the sent payment, once [Executed](ScheduledPaymentState::Executed)
+    pub tx_id: Option<String>,
+    /// The error returned by the last attempt, if any
+    pub last_error: Option<String>,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::create_recurring_payment].
+#[derive(Clone, Debug, Serialize)]
+pub struct CreateRecurringPaymentRequest {
+    /// A Lightning address or BOLT12 offer, which can be paid repeatedly
+    pub destination: String,
+    /// The amount received on each occurrence
+    pub amount_sat: u64,
+    /// The interval between the occurrences, in seconds
+    pub interval_sec: u32,
+    /// The time of the first occurrence, as a UNIX timestamp in seconds. Defaults to now.
+    pub start_at: Option<u32>,
+    /// The maximum fees of each occurrence. See [SendPaymentRequest::max_fee_sat].
+    pub max_fee_sat: Option<u64>,
+}
+
+/// A payment repeated at a fixed interval, e.g. for a subscription or an allowance. See
+/// [crate::sdk::LiquidSdk::create_recurring_payment].
+#[derive(Clone, Debug, Serialize)]
+pub struct RecurringPayment {
+    pub id: String,
+    pub destination: String,
+    pub amount_sat: u64,
+    pub interval_sec: u32,
+    pub max_fee_sat: Option<u64>,
+    pub created_at: u32,
+    /// The time of the next occurrence, as a UNIX timestamp in seconds
+    pub next_occurrence_at: u32,
+}
+
+/// A payment made for an occurrence of a [RecurringPayment]
+#[derive(Clone, Debug, Serialize)]
+pub struct RecurringPaymentOccurrence {
+    pub recurring_payment_id: String,
+    /// The time the occurrence was due, as a UNIX timestamp in seconds
+    pub occurrence_at: u32,
+    /// The time the payment was made, as a UNIX timestamp in seconds
+    pub executed_at: u32,
+    /// The tx id of the payment, if it was sent
+    pub tx_id: Option<String>,
+    /// The error of the payment, if it failed
+    pub error: Option<String>,
+}
+
+/// A destination of a [SendBatchRequest]
+#[derive(Clone, Debug, Serialize)]
+pub struct BatchSendItem {
+    /// A Lightning invoice or offer, a Liquid address or BIP21 URI, a BIP353 address or a
+    /// Lightning address
+    pub destination: String,
+    /// The amount received. Required unless the destination already contains it.
+    pub amount_sat: Option<u64>,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::send_batch].
+#[derive(Clone, Debug, Serialize)]
+pub struct SendBatchRequest {
+    pub items: Vec<BatchSendItem>,
+    /// The maximum total fees of the batch. If the estimated fees exceed it, nothing is sent.
+    pub max_total_fee_sat: Option<u64>,
+}
+
+/// The result of a [BatchSendItem]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchSendItemResult {
+    pub destination: String,
+    /// The payment, if it was sent
+    pub payment: Option<Payment>,
+    /// The error, if the item failed to be prepared or sent
+    pub error: Option<String>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::send_batch].
+#[derive(Debug, Serialize)]
+pub struct SendBatchResponse {
+    /// The result of each item, in the order of the request
+    pub results: Vec<BatchSendItemResult>,
+}
+
+/// A prepared [BatchSendItem]
+pub(crate) enum PreparedBatchItem {
+    Send(PrepareSendResponse),
+    LnUrlPay(PrepareLnUrlPayResponse),
+}
+
+impl PreparedBatchItem {
+    pub(crate) fn destination(&self) -> &SendDestination {
+        match self {
+            PreparedBatchItem::Send(res) => &res.destination,
+            PreparedBatchItem::LnUrlPay(res) => &res.destination,
+        }
+    }
+
+    pub(crate) fn fees_sat(&self) -> u64 {
+        match self {
+            PreparedBatchItem::Send(res) => res.fees_sat.unwrap_or_default(),
+            PreparedBatchItem::LnUrlPay(res) => res.fees_sat,
+        }
+    }
+}
+
 /// Used to specify the amount to sent or to send all funds.
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum PayAmount {
     /// The amount in satoshi that will be received
     Bitcoin { receiver_amount_sat: u64 },
@@ -752,6 +1951,13 @@ pub enum PayAmount {
         estimate_asset_fees: Option<bool>,
     },
 
+    /// The amount of an asset that will be received, in its base units. Its precision must
+    /// match the precision of the asset.
+    AssetUnits {
+        receiver_amount: AssetAmount,
+        estimate_asset_fees: Option<bool>,
+    },
+
     /// Indicates that all available Bitcoin funds should be sent
     Drain,
 }
@@ -763,6 +1969,34 @@ pub struct PreparePayOnchainRequest {
     pub amount: PayAmount,
     /// The optional fee rate of the Bitcoin claim transaction in sat/vB. Defaults to the swapper estimated claim fee.
     pub fee_rate_sat_per_vbyte: Option<u32>,
+    /// The optional [FeeSpeed] preset of the Bitcoin claim transaction. Cannot be set together
+    /// with `fee_rate_sat_per_vbyte`.
+    pub fee_speed: Option<FeeSpeed>,
+}
+
+/// A fee rate preset, resolved using the current [RecommendedFees] of the Bitcoin chain service
+#[derive(Clone, Copy, Debug, EnumString, PartialEq, Serialize)]
+pub enum FeeSpeed {
+    /// Targets confirmation within about a day
+    #[strum(serialize = "economy")]
+    Economy,
+    /// Targets confirmation within about half an hour
+    #[strum(serialize = "standard")]
+    Standard,
+    /// Targets confirmation in the next block
+    #[strum(serialize = "fast")]
+    Fast,
+}
+
+impl FeeSpeed {
+    pub(crate) fn fee_rate_sat_per_vbyte(&self, recommended_fees: &RecommendedFees) -> u32 {
+        let fee_rate = match self {
+            FeeSpeed::Economy => recommended_fees.economy_fee,
+            FeeSpeed::Standard => recommended_fees.half_hour_fee,
+            FeeSpeed::Fast => recommended_fees.fastest_fee,
+        };
+        fee_rate.max(recommended_fees.minimum_fee).max(1) as u32
+    }
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
@@ -773,11 +2007,51 @@ pub struct PreparePayOnchainResponse {
     pub total_fees_sat: u64,
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::pay_onchain].
+/// An argument when calling [crate::sdk::LiquidSdk::pay_onchain].
+#[derive(Debug, Serialize)]
+pub struct PayOnchainRequest {
+    pub address: String,
+    pub prepare_response: PreparePayOnchainResponse,
+    /// A token allowing the payment to exceed the [Config::spend_limits], created with
+    /// [crate::sdk::LiquidSdk::create_spend_limit_override]
+    pub spend_limit_override: Option<String>,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_convert].
+///
+/// One of the assets has to be L-BTC and the other a Liquid asset set in the
+/// [Config::asset_metadata].
+#[derive(Debug, Serialize)]
+pub struct PrepareConvertRequest {
+    /// The asset id to convert from
+    pub from_asset_id: String,
+    /// The asset id to convert to
+    pub to_asset_id: String,
+    /// The amount to convert, in base units of `from_asset_id`
+    pub amount: u64,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_convert].
+#[derive(Clone, Debug, Serialize)]
+pub struct PrepareConvertResponse {
+    pub from_asset_id: String,
+    pub to_asset_id: String,
+    /// The amount to convert, in base units of `from_asset_id`
+    pub amount: u64,
+    /// The quoted amount received, in base units of `to_asset_id`. When converting to
+    /// L-BTC, the network fee is already deducted from it.
+    pub receiver_amount: u64,
+    /// The L-BTC paid on top of `amount` when converting from L-BTC, in satoshi
+    pub fees_sat: u64,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::convert].
 #[derive(Debug, Serialize)]
-pub struct PayOnchainRequest {
-    pub address: String,
-    pub prepare_response: PreparePayOnchainResponse,
+pub struct ConvertRequest {
+    pub prepare_response: PrepareConvertResponse,
+    /// A token allowing the conversion to exceed the [Config::spend_limits], created with
+    /// [crate::sdk::LiquidSdk::create_spend_limit_override]
+    pub spend_limit_override: Option<String>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::prepare_refund].
@@ -787,8 +2061,10 @@ pub struct PrepareRefundRequest {
     pub swap_address: String,
     /// The address to refund the swap funds to
     pub refund_address: String,
-    /// The fee rate in sat/vB for the refund transaction
-    pub fee_rate_sat_per_vbyte: u32,
+    /// The fee rate in sat/vB for the refund transaction. Either this or `fee_speed` must be set.
+    pub fee_rate_sat_per_vbyte: Option<u32>,
+    /// The [FeeSpeed] preset for the refund transaction. Either this or `fee_rate_sat_per_vbyte` must be set.
+    pub fee_speed: Option<FeeSpeed>,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_refund].
@@ -796,6 +2072,12 @@ pub struct PrepareRefundRequest {
 pub struct PrepareRefundResponse {
     pub tx_vsize: u32,
     pub tx_fee_sat: u64,
+    /// The fee rate in sat/vB the refund transaction is built with, resolved from the request
+    pub fee_rate_sat_per_vbyte: u32,
+    /// The amount locked up in the swap, from all UTXOs
+    pub refundable_amount_sat: u64,
+    /// The amount received at the refund address, i.e. the refundable amount minus the fee
+    pub refund_amount_sat: u64,
     /// The txid of the last broadcasted refund tx, if any
     pub last_refund_tx_id: Option<String>,
 }
@@ -807,8 +2089,10 @@ pub struct RefundRequest {
     pub swap_address: String,
     /// The address to refund the swap funds to
     pub refund_address: String,
-    /// The fee rate in sat/vB for the refund transaction
-    pub fee_rate_sat_per_vbyte: u32,
+    /// The fee rate in sat/vB for the refund transaction. Either this or `fee_speed` must be set.
+    pub fee_rate_sat_per_vbyte: Option<u32>,
+    /// The [FeeSpeed] preset for the refund transaction. Either this or `fee_rate_sat_per_vbyte` must be set.
+    pub fee_speed: Option<FeeSpeed>,
 }
 
 /// Returned when calling [crate::sdk::LiquidSdk::refund].
@@ -818,13 +2102,30 @@ pub struct RefundResponse {
 }
 
 /// An asset balance to denote the balance for each asset.
+///
+/// The amounts in asset units, e.g. `balance`, are only set if the [AssetMetadata] of the asset
+/// is known, as they depend on its precision.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct AssetBalance {
     pub asset_id: String,
+    /// The confirmed balance, reduced by the unconfirmed spends
     pub balance_sat: u64,
     pub name: Option<String>,
     pub ticker: Option<String>,
     pub balance: Option<f64>,
+    /// The amount spent by unconfirmed wallet transactions
+    #[serde(default)]
+    pub pending_send_sat: u64,
+    /// The amount received by unconfirmed wallet transactions
+    #[serde(default)]
+    pub pending_receive_sat: u64,
+    #[serde(default)]
+    pub pending_send: Option<f64>,
+    #[serde(default)]
+    pub pending_receive: Option<f64>,
+    /// The `balance_sat` carrying the asset precision
+    #[serde(default)]
+    pub asset_balance: Option<AssetAmount>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -833,6 +2134,16 @@ pub struct BlockchainInfo {
     pub bitcoin_tip: u32,
 }
 
+/// A block of the Liquid or Bitcoin chain, see [SdkEvent::NewLiquidBlock] and
+/// [SdkEvent::NewBitcoinBlock]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BlockInfo {
+    pub height: u32,
+    pub hash: String,
+    /// The block timestamp, in seconds since the Unix epoch
+    pub timestamp: u32,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct WalletInfo {
     /// Usable balance. This is the confirmed onchain balance minus `pending_send_sat`.
@@ -891,6 +2202,91 @@ pub struct GetInfoResponse {
     pub blockchain_info: BlockchainInfo,
 }
 
+/// Returned when calling [crate::sdk::LiquidSdk::get_wallet_info].
+#[derive(Debug, Serialize)]
+pub struct GetWalletInfoResponse {
+    /// The CT descriptor of the wallet. As it contains the SLIP77 master blinding key, it can be
+    /// imported into a watch-only wallet that sees all the transactions and amounts.
+    pub descriptor: String,
+    /// The account xpub of the descriptor
+    pub xpub: String,
+    /// The derivation path of the account xpub
+    pub derivation_path: String,
+    /// The fingerprint of the master key
+    pub fingerprint: String,
+}
+
+/// An account registered with the AMP (Asset Management Platform) server, needed to receive
+/// and hold AMP assets. See [crate::sdk::LiquidSdk::register_amp_account].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AmpAccount {
+    /// The id of the account on the AMP server, which is shared with asset issuers to be
+    /// authorized to receive their assets
+    pub amp_id: String,
+    /// The CT descriptor of the account, a multisig of the user and AMP server keys
+    pub descriptor: String,
+}
+
+/// A remote service checked by [crate::sdk::LiquidSdk::check_health]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum HealthCheckService {
+    /// The Liquid Electrum or Esplora server
+    LiquidExplorer,
+    /// The Bitcoin Electrum or Esplora server
+    BitcoinExplorer,
+    /// The fee estimation of the Bitcoin mempool, served by the Bitcoin explorer
+    MempoolFees,
+    /// The swapper API
+    SwapperApi,
+    /// The WebSocket stream of swap updates
+    SwapperStatusStream,
+    /// The source of the fiat exchange rates
+    FiatRates,
+}
+
+/// The health of a remote service
+#[derive(Clone, Debug, Serialize)]
+pub struct ServiceHealth {
+    pub service: HealthCheckService,
+    pub is_reachable: bool,
+    /// The duration of the request to the service, if it succeeded
+    pub latency_ms: Option<u64>,
+    /// The error of the request to the service, if it failed
+    pub error: Option<String>,
+}
+
+/// The swap types offered by the swapper
+#[derive(Clone, Debug, Serialize)]
+pub struct SwapServiceStatus {
+    /// Whether Lightning payments can be sent
+    pub send_available: bool,
+    /// Whether Lightning payments can be received
+    pub receive_available: bool,
+    /// Whether Bitcoin payments can be sent
+    pub chain_send_available: bool,
+    /// Whether Bitcoin payments can be received
+    pub chain_receive_available: bool,
+}
+
+impl SwapServiceStatus {
+    pub(crate) fn is_fully_available(&self) -> bool {
+        self.send_available
+            && self.receive_available
+            && self.chain_send_available
+            && self.chain_receive_available
+    }
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::check_health].
+#[derive(Clone, Debug, Serialize)]
+pub struct CheckHealthResponse {
+    /// Whether a service is unreachable or a swap type is not offered
+    pub is_degraded: bool,
+    pub services: Vec<ServiceHealth>,
+    /// The swap types offered by the swapper, if it is reachable
+    pub swap_service_status: Option<SwapServiceStatus>,
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::sign_message].
 #[derive(Clone, Debug, PartialEq)]
 pub struct SignMessageRequest {
@@ -900,7 +2296,11 @@ pub struct SignMessageRequest {
 /// Returned when calling [crate::sdk::LiquidSdk::sign_message].
 #[derive(Clone, Debug, PartialEq)]
 pub struct SignMessageResponse {
+    /// The zbase encoded signature.
     pub signature: String,
+    /// The public key the message was signed with, to verify the signature with
+    /// [crate::sdk::LiquidSdk::check_message].
+    pub pubkey: String,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::check_message].
@@ -922,6 +2322,73 @@ pub struct CheckMessageResponse {
     pub is_valid: bool,
 }
 
+/// The maximum amount of an asset the wallet may spend in a PSET signed with
+/// [crate::sdk::LiquidSdk::sign_pset].
+#[derive(Clone, Debug, Serialize)]
+pub struct PsetSpendLimit {
+    pub asset_id: String,
+    pub max_amount_sat: u64,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::sign_pset].
+#[derive(Clone, Debug, Serialize)]
+pub struct SignPsetRequest {
+    /// The base64-encoded PSET, created outside of the SDK
+    pub pset: String,
+    /// The maximum network fee of the transaction, in satoshi
+    pub max_fee_sat: u64,
+    /// The maximum amount of each asset the wallet may spend. An asset not listed must not be
+    /// spent by the wallet. The L-BTC amount includes the network fee, if paid by the wallet.
+    pub spend_limits: Vec<PsetSpendLimit>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::sign_pset].
+#[derive(Clone, Debug, Serialize)]
+pub struct SignPsetResponse {
+    /// The base64-encoded PSET, with the wallet inputs signed
+    pub pset: String,
+    /// The network fee of the transaction, in satoshi
+    pub fees_sat: u64,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::sweep_private_key].
+#[derive(Clone, Debug, Serialize)]
+pub struct SweepPrivateKeyRequest {
+    /// A WIF encoded private key, or a confidential `elwpkh` descriptor with a WIF encoded
+    /// private key, e.g. `ct(<blinding key>,elwpkh(<WIF>))`. A WIF key is assumed to receive
+    /// on addresses blinded with its ELIP151 blinding key.
+    pub key: String,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::sweep_private_key].
+#[derive(Clone, Debug, Serialize)]
+pub struct SweepPrivateKeyResponse {
+    pub payment: Payment,
+    /// The amount of L-BTC swept into the wallet, after paying the network fee
+    pub amount_sat: u64,
+    /// The amounts of the swept assets other than L-BTC
+    pub asset_amounts: Vec<AssetAmount>,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::sync] through the bindings.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SyncRequest {
+    /// Whether to sync all the swaps that can still be updated, instead of only the ongoing
+    /// receive swaps. Defaults to true.
+    pub full: Option<bool>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::sync].
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SyncResponse {
+    /// The number of wallet txs found which were not yet known
+    pub new_tx_count: u32,
+    /// The number of swaps updated from the onchain data
+    pub updated_swap_count: u32,
+    /// The duration of the sync in milliseconds
+    pub duration_ms: u64,
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::backup].
 #[derive(Debug, Serialize)]
 pub struct BackupRequest {
@@ -940,6 +2407,66 @@ pub struct RestoreRequest {
     pub backup_path: Option<String>,
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::export_wallet].
+#[derive(Debug, Serialize)]
+pub struct ExportWalletRequest {
+    /// Path of the export file to create
+    pub export_path: String,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::import_wallet].
+#[derive(Debug, Serialize)]
+pub struct ImportWalletRequest {
+    /// Path of an export file created with [crate::sdk::LiquidSdk::export_wallet]
+    pub export_path: String,
+}
+
+/// The amount a [Nostr Wallet Connect](https://nips.nostr.com/47) connection can spend
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct NwcBudget {
+    /// The maximum amount spent, including fees
+    pub max_amount_sat: u64,
+    /// The period after which the spent amount is reset. If not set, the budget never renews.
+    pub renewal_period_sec: Option<u32>,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::add_nwc_connection].
+#[derive(Debug, Serialize)]
+pub struct AddNwcConnectionRequest {
+    /// A unique name identifying the connection, e.g. the name of the app using it
+    pub name: String,
+    /// The optional spending budget. If not set, the connection can spend the whole balance.
+    pub budget: Option<NwcBudget>,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::add_nwc_connection].
+#[derive(Debug, Serialize)]
+pub struct AddNwcConnectionResponse {
+    /// The `nostr+walletconnect://` URI to share with the app using the connection. It
+    /// contains the connection secret, which is not stored by the SDK.
+    pub connection_uri: String,
+}
+
+/// A [Nostr Wallet Connect](https://nips.nostr.com/47) connection
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct NwcConnection {
+    pub name: String,
+    /// The public key with which the app using the connection signs its requests
+    pub client_pubkey: String,
+    pub budget: Option<NwcBudget>,
+    /// The amount spent in the current budget period
+    pub spent_sat: u64,
+    pub created_at: u32,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::import_wallet].
+#[derive(Debug, Serialize)]
+pub struct ImportWalletResponse {
+    /// The number of swaps and payment metadata records imported. Records already stored
+    /// locally are not overwritten.
+    pub imported_records: u32,
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::list_payments].
 #[derive(Default)]
 pub struct ListPaymentsRequest {
@@ -955,6 +2482,51 @@ pub struct ListPaymentsRequest {
     pub sort_ascending: Option<bool>,
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::list_swaps].
+#[derive(Debug, Default)]
+pub struct ListSwapsRequest {
+    pub swap_types: Option<Vec<PaymentSwapType>>,
+    pub states: Option<Vec<PaymentState>>,
+    /// Epoch time, in seconds
+    pub from_timestamp: Option<i64>,
+    /// Epoch time, in seconds
+    pub to_timestamp: Option<i64>,
+    pub offset: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::get_report].
+#[derive(Debug, Default, Serialize)]
+pub struct GetReportRequest {
+    /// Epoch time, in seconds
+    pub from_timestamp: Option<i64>,
+    /// Epoch time, in seconds
+    pub to_timestamp: Option<i64>,
+}
+
+/// The totals of an asset in a [GetReportResponse]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct AssetReport {
+    pub asset_id: String,
+    /// The total amount received, in the asset's base units
+    pub received_amount: u64,
+    /// The total amount sent excluding fees, in the asset's base units
+    pub sent_amount: u64,
+    /// The total fees of the swaps, including their claim and lockup txs made by the swapper
+    pub swap_fees_sat: u64,
+    /// The total fees of the txs sent by the wallet
+    pub onchain_fees_sat: u64,
+    pub received_count: u32,
+    pub sent_count: u32,
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::get_report].
+#[derive(Debug, Serialize)]
+pub struct GetReportResponse {
+    /// The totals of each asset with payments in the period, ordered by asset id
+    pub assets: Vec<AssetReport>,
+}
+
 /// An argument of [ListPaymentsRequest] when calling [crate::sdk::LiquidSdk::list_payments].
 #[derive(Debug, Serialize)]
 pub enum ListPaymentDetails {
@@ -982,6 +2554,61 @@ pub enum GetPaymentRequest {
     SwapId { swap_id: String },
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::export_payment_unblinding_data].
+#[derive(Debug, Serialize)]
+pub struct ExportPaymentUnblindingDataRequest {
+    /// The id of the payment, i.e. its tx id or swap id
+    pub payment_id: String,
+    /// The base URL of the explorer to build the unblinded URL with, e.g.
+    /// `https://blockstream.info/liquid/`. Defaults to the Blockstream explorer of the network.
+    pub explorer_url: Option<String>,
+}
+
+/// The secrets of a confidential tx output owned by the wallet, which prove its asset and
+/// amount when checked against the output commitments.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UnblindedTxOut {
+    /// The outpoint of the output, as `txid:vout`
+    pub outpoint: String,
+    pub asset_id: String,
+    /// The amount, in base units of the asset
+    pub amount: u64,
+    /// The hex encoded asset blinding factor
+    pub asset_blinding_factor: String,
+    /// The hex encoded amount blinding factor
+    pub amount_blinding_factor: String,
+}
+
+impl From<&lwk_wollet::WalletTxOut> for UnblindedTxOut {
+    fn from(tx_out: &lwk_wollet::WalletTxOut) -> Self {
+        Self {
+            outpoint: tx_out.outpoint.to_string(),
+            asset_id: tx_out.unblinded.asset.to_string(),
+            amount: tx_out.unblinded.value,
+            asset_blinding_factor: tx_out.unblinded.asset_bf.to_string(),
+            amount_blinding_factor: tx_out.unblinded.value_bf.to_string(),
+        }
+    }
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::export_payment_unblinding_data].
+///
+/// It can be handed to an auditor, serialized as JSON, to prove the confidential amounts of a
+/// payment without revealing the wallet's blinding key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PaymentUnblindingData {
+    pub tx_id: String,
+    /// Data to use in the `blinded` param when unblinding the transaction in an explorer.
+    /// See: <https://docs.liquid.net/docs/unblinding-transactions>
+    pub unblinding_data: String,
+    /// The explorer URL showing the transaction unblinded, if an explorer is available
+    pub unblinded_url: Option<String>,
+    /// The wallet outputs spent by the transaction
+    pub inputs: Vec<UnblindedTxOut>,
+    /// The wallet outputs created by the transaction
+    pub outputs: Vec<UnblindedTxOut>,
+}
+
 /// Trait that can be used to react to new blocks from Bitcoin and Liquid chains
 #[sdk_macros::async_trait]
 pub(crate) trait BlockListener: MaybeSend + MaybeSync {
@@ -990,7 +2617,7 @@ pub(crate) trait BlockListener: MaybeSend + MaybeSync {
 }
 
 // A swap enum variant
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Swap {
     Chain(ChainSwap),
     Send(SendSwap),
@@ -1042,6 +2669,46 @@ impl Swap {
             | Swap::Receive(ReceiveSwap { metadata, .. }) => metadata.last_updated_at,
         }
     }
+
+    pub(crate) fn created_at(&self) -> u32 {
+        match self {
+            Swap::Chain(ChainSwap { created_at, .. })
+            | Swap::Send(SendSwap { created_at, .. })
+            | Swap::Receive(ReceiveSwap { created_at, .. }) => *created_at,
+        }
+    }
+
+    pub(crate) fn swap_type(&self) -> PaymentSwapType {
+        match self {
+            Swap::Chain(_) => PaymentSwapType::Chain,
+            Swap::Send(_) => PaymentSwapType::Send,
+            Swap::Receive(_) => PaymentSwapType::Receive,
+        }
+    }
+
+    pub(crate) fn state(&self) -> PaymentState {
+        match self {
+            Swap::Chain(ChainSwap { state, .. })
+            | Swap::Send(SendSwap { state, .. })
+            | Swap::Receive(ReceiveSwap { state, .. }) => *state,
+        }
+    }
+
+    pub(crate) fn claim_tx_id(&self) -> Option<String> {
+        match self {
+            Swap::Chain(ChainSwap { claim_tx_id, .. })
+            | Swap::Receive(ReceiveSwap { claim_tx_id, .. }) => claim_tx_id.clone(),
+            Swap::Send(_) => None,
+        }
+    }
+
+    pub(crate) fn refund_tx_id(&self) -> Option<String> {
+        match self {
+            Swap::Chain(ChainSwap { refund_tx_id, .. })
+            | Swap::Send(SendSwap { refund_tx_id, .. }) => refund_tx_id.clone(),
+            Swap::Receive(_) => None,
+        }
+    }
 }
 impl From<ChainSwap> for Swap {
     fn from(swap: ChainSwap) -> Self {
@@ -1059,6 +2726,102 @@ impl From<ReceiveSwap> for Swap {
     }
 }
 
+/// The full state of a swap, as returned by [crate::sdk::LiquidSdk::list_swaps],
+/// [crate::sdk::LiquidSdk::list_ongoing_swaps] and [crate::sdk::LiquidSdk::get_swap]
+#[derive(Clone, Debug, Serialize)]
+pub struct SwapInfo {
+    pub swap_id: String,
+    pub swap_type: PaymentSwapType,
+    /// The direction of a chain swap. Not set for Send and Receive swaps
+    pub direction: Option<Direction>,
+    pub state: PaymentState,
+    pub created_at: u32,
+    pub timeout_block_height: u64,
+    pub payer_amount_sat: u64,
+    pub receiver_amount_sat: u64,
+    /// The invoice of a Send or Receive swap
+    pub invoice: Option<String>,
+    /// The address the user funds are locked up to. Not set for Send and Receive swaps, see
+    /// `create_response_json` instead
+    pub lockup_address: Option<String>,
+    /// The lockup tx of the party paying into the swap
+    pub user_lockup_tx_id: Option<String>,
+    /// The lockup tx of the swapper
+    pub server_lockup_tx_id: Option<String>,
+    pub claim_address: Option<String>,
+    pub claim_tx_id: Option<String>,
+    pub refund_address: Option<String>,
+    pub refund_tx_id: Option<String>,
+    /// The swapper response on swap creation, containing the swap scripts
+    pub create_response_json: String,
+}
+impl From<Swap> for SwapInfo {
+    fn from(swap: Swap) -> Self {
+        let swap_id = swap.id();
+        let swap_type = swap.swap_type();
+        let state = swap.state();
+        match swap {
+            Swap::Chain(s) => SwapInfo {
+                swap_id,
+                swap_type,
+                direction: Some(s.direction),
+                state,
+                created_at: s.created_at,
+                timeout_block_height: s.timeout_block_height as u64,
+                payer_amount_sat: s.payer_amount_sat,
+                receiver_amount_sat: s.receiver_amount_sat,
+                invoice: None,
+                lockup_address: Some(s.lockup_address),
+                user_lockup_tx_id: s.user_lockup_tx_id,
+                server_lockup_tx_id: s.server_lockup_tx_id,
+                claim_address: s.claim_address,
+                claim_tx_id: s.claim_tx_id,
+                refund_address: s.refund_address,
+                refund_tx_id: s.refund_tx_id,
+                create_response_json: s.create_response_json,
+            },
+            Swap::Send(s) => SwapInfo {
+                swap_id,
+                swap_type,
+                direction: None,
+                state,
+                created_at: s.created_at,
+                timeout_block_height: s.timeout_block_height,
+                payer_amount_sat: s.payer_amount_sat,
+                receiver_amount_sat: s.receiver_amount_sat,
+                invoice: Some(s.invoice),
+                lockup_address: None,
+                user_lockup_tx_id: s.lockup_tx_id,
+                server_lockup_tx_id: None,
+                claim_address: None,
+                claim_tx_id: None,
+                refund_address: s.refund_address,
+                refund_tx_id: s.refund_tx_id,
+                create_response_json: s.create_response_json,
+            },
+            Swap::Receive(s) => SwapInfo {
+                swap_id,
+                swap_type,
+                direction: None,
+                state,
+                created_at: s.created_at,
+                timeout_block_height: s.timeout_block_height as u64,
+                payer_amount_sat: s.payer_amount_sat,
+                receiver_amount_sat: s.receiver_amount_sat,
+                invoice: Some(s.invoice),
+                lockup_address: None,
+                user_lockup_tx_id: None,
+                server_lockup_tx_id: s.lockup_tx_id,
+                claim_address: s.claim_address,
+                claim_tx_id: s.claim_tx_id,
+                refund_address: None,
+                refund_tx_id: None,
+                create_response_json: s.create_response_json,
+            },
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) enum SwapScriptV2 {
     Bitcoin(BtcSwapScript),
@@ -1155,6 +2918,11 @@ pub struct ChainSwap {
     pub(crate) claim_private_key: String,
     pub(crate) refund_private_key: String,
     pub(crate) auto_accepted_fees: bool,
+    /// When the swap last became [PaymentState::Refundable] or [PaymentState::RefundPending],
+    /// as a UNIX timestamp in seconds. Set by the local database whenever the swap enters either
+    /// state, so it is only valid when reading one from it.
+    #[derivative(PartialEq = "ignore")]
+    pub(crate) refundable_since: Option<u32>,
     /// Swap metadata that is only valid when reading one from the local database
     #[derivative(PartialEq = "ignore")]
     pub(crate) metadata: SwapMetadata,
@@ -1243,6 +3011,12 @@ impl ChainSwap {
             timestamp: self.created_at,
             amount_sat,
             last_refund_tx_id: self.refund_tx_id.clone(),
+            state: self.state,
+            refundable_since: self
+                .refundable_since
+                .unwrap_or(self.metadata.last_updated_at),
+            refund_eligible_block_height: self.timeout_block_height,
+            is_cooperative_refund_possible: self.server_lockup_tx_id.is_none(),
         }
     }
 
@@ -1398,6 +3172,8 @@ pub struct ReceiveSwap {
     pub(crate) claim_fees_sat: u64,
     /// Persisted only when a claim tx is needed
     pub(crate) claim_address: Option<String>,
+    /// The address outside of the SDK wallet chosen on swap creation to claim to, if any
+    pub(crate) external_claim_address: Option<String>,
     /// Persisted after the claim tx is broadcast
     pub(crate) claim_tx_id: Option<String>,
     /// The transaction id of the swapper's tx broadcast
@@ -1502,10 +3278,22 @@ pub struct RefundableSwap {
     pub amount_sat: u64,
     /// The txid of the last broadcasted refund tx, if any
     pub last_refund_tx_id: Option<String>,
+    /// Either [PaymentState::Refundable], or [PaymentState::RefundPending] if a refund tx was
+    /// broadcast and is not yet confirmed
+    pub state: PaymentState,
+    /// When the swap became refundable, as a UNIX timestamp in seconds
+    pub refundable_since: u32,
+    /// The Bitcoin block height from which the funds can be refunded without the cooperation
+    /// of the swapper
+    pub refund_eligible_block_height: u32,
+    /// Whether the swapper is expected to cooperate in the refund, allowing it before
+    /// `refund_eligible_block_height`. This is not the case once the swapper locked up funds
+    /// for the swap.
+    pub is_cooperative_refund_possible: bool,
 }
 
 /// The payment state of an individual payment.
-#[derive(Clone, Copy, Debug, Default, EnumString, Eq, PartialEq, Serialize, Hash)]
+#[derive(Clone, Copy, Debug, Default, EnumString, Eq, PartialEq, Serialize, Deserialize, Hash)]
 #[strum(serialize_all = "lowercase")]
 pub enum PaymentState {
     #[default]
@@ -1611,6 +3399,44 @@ impl FromSql for PaymentState {
     }
 }
 
+impl ToSql for QueuedPaymentState {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::from(*self as i8))
+    }
+}
+impl FromSql for QueuedPaymentState {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(i) => match i as u8 {
+                0 => Ok(QueuedPaymentState::Pending),
+                1 => Ok(QueuedPaymentState::Failed),
+                _ => Err(FromSqlError::OutOfRange(i)),
+            },
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+impl ToSql for ScheduledPaymentState {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::from(*self as i8))
+    }
+}
+impl FromSql for ScheduledPaymentState {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(i) => match i as u8 {
+                0 => Ok(ScheduledPaymentState::Pending),
+                1 => Ok(ScheduledPaymentState::Executed),
+                2 => Ok(ScheduledPaymentState::Failed),
+                3 => Ok(ScheduledPaymentState::InProgress),
+                _ => Err(FromSqlError::OutOfRange(i)),
+            },
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
 impl PaymentState {
     pub(crate) fn is_refundable(&self) -> bool {
         matches!(
@@ -1622,7 +3448,7 @@ impl PaymentState {
     }
 }
 
-#[derive(Debug, Copy, Clone, Eq, EnumString, Display, Hash, PartialEq, Serialize)]
+#[derive(Debug, Copy, Clone, Eq, EnumString, Display, Hash, PartialEq, Serialize, Deserialize)]
 #[strum(serialize_all = "lowercase")]
 pub enum PaymentType {
     Receive = 0,
@@ -1706,7 +3532,8 @@ pub struct PaymentTxData {
     pub unblinding_data: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Copy, EnumString, PartialEq, Serialize, Deserialize)]
+#[strum(serialize_all = "lowercase")]
 pub enum PaymentSwapType {
     Receive,
     Send,
@@ -1763,6 +3590,11 @@ pub struct LnUrlInfo {
     pub lnurl_pay_success_action: Option<SuccessActionProcessed>,
     pub lnurl_pay_unprocessed_success_action: Option<SuccessAction>,
     pub lnurl_withdraw_endpoint: Option<String>,
+    /// The [LUD-21](https://github.com/lnurl/luds/blob/luds/21.md) verify URL of the paid invoice
+    pub lnurl_pay_verify_url: Option<String>,
+    /// The preimage of the paid invoice, as confirmed by the LUD-21 verify URL. It proves the
+    /// LNURL service considers the invoice settled.
+    pub lnurl_pay_verified_preimage: Option<String>,
 }
 
 /// Configuration for asset metadata. Each asset metadata item represents an entry in the
@@ -1781,21 +3613,127 @@ pub struct AssetMetadata {
     pub precision: u8,
     /// The optional ID of the fiat currency used to represent the asset
     pub fiat_id: Option<String>,
+    /// Whether the asset is an AMP (Asset Management Platform) asset, which can only be received
+    /// into the wallet's registered AMP account. See [Config::amp_server_url].
+    pub is_amp: bool,
 }
 
 impl AssetMetadata {
+    /// Converts a decimal amount to base units, rounding to the nearest base unit so that the
+    /// float imprecision of the decimal amount doesn't make it a base unit short
     pub fn amount_to_sat(&self, amount: f64) -> u64 {
-        (amount * (10_u64.pow(self.precision.into()) as f64)) as u64
+        (amount * (10_u64.pow(self.precision.into()) as f64)).round() as u64
     }
 
     pub fn amount_from_sat(&self, amount_sat: u64) -> f64 {
         amount_sat as f64 / (10_u64.pow(self.precision.into()) as f64)
     }
+
+    /// Ensures the `amount` is of this asset and has its precision, so its base units are
+    /// interpreted as intended
+    pub(crate) fn validate_asset_amount(&self, amount: &AssetAmount) -> Result<(), PaymentError> {
+        ensure_sdk!(
+            amount.asset_id == self.asset_id,
+            PaymentError::asset_error(&format!(
+                "Amount of asset {} doesn't match asset {}",
+                amount.asset_id, self.asset_id
+            ))
+        );
+        ensure_sdk!(
+            amount.precision == self.precision,
+            PaymentError::asset_error(&format!(
+                "Amount precision {} doesn't match the precision {} of asset {}",
+                amount.precision, self.precision, self.asset_id
+            ))
+        );
+        Ok(())
+    }
+
+    /// The number of decimals to display the asset amount with. Fiat-backed assets are
+    /// displayed with [FIAT_ASSET_DISPLAY_PRECISION] decimals, other assets with their
+    /// full [precision](AssetMetadata::precision).
+    pub fn display_precision(&self) -> u8 {
+        match self.fiat_id {
+            Some(_) => self.precision.min(FIAT_ASSET_DISPLAY_PRECISION),
+            None => self.precision,
+        }
+    }
+
+    /// Creates an [AssetAmount] of this asset from its amount in base units
+    pub fn asset_amount(&self, base_units: u64) -> AssetAmount {
+        AssetAmount {
+            asset_id: self.asset_id.clone(),
+            base_units,
+            precision: self.precision,
+            display_precision: self.display_precision(),
+        }
+    }
+}
+
+/// The number of decimals used to display amounts of fiat-backed assets, e.g. USDT
+pub const FIAT_ASSET_DISPLAY_PRECISION: u8 = 2;
+
+/// An amount of a Liquid asset which carries the asset precision, so it can be converted
+/// between base units and its decimal representation without manual unit conversions.
+///
+/// For example, 1.5 USDT has 150_000_000 `base_units`, a `precision` of 8 and is displayed
+/// as "1.50" with a `display_precision` of 2.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AssetAmount {
+    pub asset_id: String,
+    /// The amount in the smallest unit of the asset, the equivalent of satoshi for L-BTC
+    pub base_units: u64,
+    /// The number of decimals the base units are shifted by
+    pub precision: u8,
+    /// The number of decimals the amount should be displayed with
+    pub display_precision: u8,
+}
+
+impl AssetAmount {
+    /// The amount having its decimal shifted to the left by the `precision`
+    pub fn to_decimal(&self) -> f64 {
+        self.base_units as f64 / (10_u64.pow(self.precision.into()) as f64)
+    }
+
+    /// The amount formatted with `display_precision` decimals, rounded half up
+    pub fn to_display_string(&self) -> String {
+        let display_precision = self.display_precision.min(self.precision);
+        let dropped_factor = 10_u64.pow((self.precision - display_precision).into());
+        let display_units = (self.base_units.saturating_add(dropped_factor / 2)) / dropped_factor;
+        if display_precision == 0 {
+            return display_units.to_string();
+        }
+        let display_factor = 10_u64.pow(display_precision.into());
+        format!(
+            "{}.{:0width$}",
+            display_units / display_factor,
+            display_units % display_factor,
+            width = display_precision as usize
+        )
+    }
+}
+
+/// The metadata of an asset registered in the
+/// [Liquid Asset Registry](https://docs.liquid.net/docs/blockstream-liquid-asset-registry),
+/// returned when calling [crate::sdk::LiquidSdk::fetch_registered_asset].
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct RegisteredAsset {
+    pub asset_id: String,
+    /// The name of the asset
+    pub name: String,
+    /// The ticker of the asset, if set by the issuer
+    pub ticker: Option<String>,
+    /// The precision used to display the asset amount
+    pub precision: u8,
+    /// The domain of the issuer, if set
+    pub domain: Option<String>,
+    /// The URL of the asset icon. Not every registered asset has an icon.
+    pub icon_url: String,
 }
 
 /// Represents the Liquid payment asset info. The asset info is derived from
 /// the available [AssetMetadata] that is set in the [Config].
-#[derive(Clone, Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AssetInfo {
     /// The name of the asset
     pub name: String,
@@ -1807,10 +3745,27 @@ pub struct AssetInfo {
     /// The optional fees when paid using the asset, having its
     /// decimal shifted to the left by the [precision](AssetMetadata::precision)
     pub fees: Option<f64>,
+    /// The amount of the transaction, carrying the asset precision
+    #[serde(default)]
+    pub asset_amount: AssetAmount,
+    /// The optional fees when paid using the asset, carrying the asset precision
+    #[serde(default)]
+    pub asset_fees: Option<AssetAmount>,
+}
+
+/// The pair of asset amounts exchanged by a conversion, see [crate::sdk::LiquidSdk::convert]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AssetConversion {
+    pub from_asset_id: String,
+    /// The amount sent, in base units of `from_asset_id`
+    pub from_amount: u64,
+    pub to_asset_id: String,
+    /// The amount received, in base units of `to_asset_id`
+    pub to_amount: u64,
 }
 
 /// The specific details of a payment, depending on its type
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum PaymentDetails {
     /// Swapping to or from Lightning
@@ -1873,6 +3828,9 @@ pub enum PaymentDetails {
 
         /// The BIP353 address used to resolve this payment
         bip353_address: Option<String>,
+
+        /// The pair of asset amounts exchanged, if the payment is an asset conversion
+        conversion: Option<AssetConversion>,
     },
     /// Swapping to or from the Bitcoin chain
     Bitcoin {
@@ -1902,6 +3860,10 @@ pub enum PaymentDetails {
 
         /// For a Send swap which was refunded, this is the refund amount
         refund_tx_amount_sat: Option<u64>,
+
+        /// For a Bitcoin bought via [crate::sdk::LiquidSdk::buy_bitcoin], this is the provider
+        /// the Bitcoin was bought from
+        buy_bitcoin_provider: Option<BuyBitcoinProvider>,
     },
 }
 
@@ -1950,7 +3912,7 @@ impl PaymentDetails {
 /// Represents an SDK payment.
 ///
 /// By default, this is an onchain tx. It may represent a swap, if swap metadata is available.
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Payment {
     /// The destination associated with the payment, if it was created via our SDK.
     /// Can be either a Liquid/Bitcoin address, a Liquid BIP21 URI or an invoice
@@ -2006,8 +3968,36 @@ pub struct Payment {
     /// The details of a payment, depending on its [destination](Payment::destination) and
     /// [type](Payment::payment_type)
     pub details: PaymentDetails,
+
+    /// The value of the payment in [fiat_currency](Payment::fiat_currency), using the exchange
+    /// rate at the time the payment completed.
+    ///
+    /// Only set for completed Bitcoin payments when [Config::fiat_currency] is set.
+    pub fiat_amount: Option<f64>,
+
+    /// The fiat currency of the [fiat_amount](Payment::fiat_amount)
+    pub fiat_currency: Option<String>,
+
+    /// The id of the [RecurringPayment] this payment is an occurrence of, if any
+    pub recurring_payment_id: Option<String>,
 }
 impl Payment {
+    /// Whether this is a Lightning receive whose invoice expired without being paid
+    pub(crate) fn is_expired_unpaid_invoice(&self) -> bool {
+        match &self.details {
+            PaymentDetails::Lightning {
+                invoice: Some(invoice),
+                claim_tx_id: None,
+                ..
+            } => {
+                self.payment_type == PaymentType::Receive
+                    && self.tx_id.is_none()
+                    && utils::is_bolt11_invoice_expired(invoice)
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn from_pending_swap(
         swap: PaymentSwapData,
         payment_type: PaymentType,
@@ -2031,6 +4021,9 @@ impl Payment {
             payment_type,
             status: swap.status,
             details: payment_details,
+            fiat_amount: None,
+            fiat_currency: None,
+            recurring_payment_id: None,
         }
     }
 
@@ -2110,6 +4103,9 @@ impl Payment {
                 },
             },
             details,
+            fiat_amount: None,
+            fiat_currency: None,
+            recurring_payment_id: None,
         }
     }
 
@@ -2135,7 +4131,7 @@ pub struct RecommendedFees {
 }
 
 /// An argument of [PrepareBuyBitcoinRequest] when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
-#[derive(Debug, Clone, Copy, EnumString, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, Display, EnumString, PartialEq, Serialize, Deserialize)]
 pub enum BuyBitcoinProvider {
     #[strum(serialize = "moonpay")]
     Moonpay,
@@ -2172,6 +4168,21 @@ pub struct BuyBitcoinRequest {
 pub struct LogEntry {
     pub line: String,
     pub level: String,
+    /// The target of the log statement, usually the module it was emitted from
+    pub target: String,
+    /// The time the log statement was emitted, as a UNIX timestamp in milliseconds
+    pub timestamp: u64,
+}
+
+impl From<&log::Record<'_>> for LogEntry {
+    fn from(record: &log::Record<'_>) -> Self {
+        LogEntry {
+            line: record.args().to_string(),
+            level: record.level().as_str().to_string(),
+            target: record.target().to_string(),
+            timestamp: utils::now_millis(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -2230,11 +4241,33 @@ pub struct PrepareLnUrlPayRequest {
     pub bip353_address: Option<String>,
     /// An optional comment for this payment
     pub comment: Option<String>,
+    /// Optional payer identity sent to the LNURL service, as described in
+    /// <https://github.com/lnurl/luds/blob/luds/18.md>
+    pub payer_data: Option<LnUrlPayerData>,
     /// Validates that, if there is a URL success action, the URL domain matches
     /// the LNURL callback domain. Defaults to `true`
     pub validate_success_action_url: Option<bool>,
 }
 
+/// The LUD-18 payer identity passed in [PrepareLnUrlPayRequest].
+///
+/// Only the fields requested by the LNURL service should be set.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct LnUrlPayerData {
+    /// The payer's name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The payer's hex encoded public key
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pubkey: Option<String>,
+    /// An internet identifier of the payer, e.g. a lightning address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// The payer's email
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
 /// Returned when calling [crate::sdk::LiquidSdk::prepare_lnurl_pay].
 #[derive(Debug, Serialize)]
 pub struct PrepareLnUrlPayResponse {
@@ -2242,6 +4275,8 @@ pub struct PrepareLnUrlPayResponse {
     pub destination: SendDestination,
     /// The fees in satoshis to send the payment
     pub fees_sat: u64,
+    /// The breakdown of `fees_sat` into its components
+    pub fee_breakdown: FeeBreakdown,
     /// The [LnUrlPayRequestData] returned by [parse]
     pub data: LnUrlPayRequestData,
     /// An optional comment for this payment
@@ -2249,6 +4284,9 @@ pub struct PrepareLnUrlPayResponse {
     /// The unprocessed LUD-09 success action. This will be processed and decrypted if
     /// needed after calling [crate::sdk::LiquidSdk::lnurl_pay]
     pub success_action: Option<SuccessAction>,
+    /// The LUD-21 verify URL of the invoice, if supported by the LNURL service. It is checked
+    /// after calling [crate::sdk::LiquidSdk::lnurl_pay] to confirm the invoice is settled.
+    pub verify_url: Option<String>,
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::lnurl_pay].
@@ -2381,6 +4419,14 @@ impl<T> History<T> {
     pub(crate) fn confirmed(&self) -> bool {
         self.height > 0
     }
+
+    /// The number of confirmations of the tx, given the current tip
+    pub(crate) fn confirmations(&self, tip_height: u32) -> u32 {
+        match self.confirmed() {
+            true => tip_height.saturating_sub(self.height as u32) + 1,
+            false => 0,
+        }
+    }
 }
 #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
 impl From<electrum_client::GetHistoryRes> for BtcHistory {
@@ -2468,3 +4514,71 @@ macro_rules! get_updated_fields {
         }
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{AssetAmount, AssetMetadata, LogEntry};
+    use crate::utils;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_asset_amount() {
+        let usdt = AssetMetadata {
+            asset_id: "usdt".to_string(),
+            name: "Tether USD".to_string(),
+            ticker: "USDt".to_string(),
+            precision: 8,
+            fiat_id: Some("USD".to_string()),
+            is_amp: false,
+        };
+        assert_eq!(usdt.display_precision(), 2);
+        assert_eq!(usdt.amount_to_sat(0.29), 29_000_000);
+        assert_eq!(usdt.amount_to_sat(1.5), 150_000_000);
+
+        let amount = usdt.asset_amount(150_000_000);
+        assert_eq!(amount.to_decimal(), 1.5);
+        assert_eq!(amount.to_display_string(), "1.50");
+        assert_eq!(usdt.asset_amount(1_995_000).to_display_string(), "0.02");
+        assert_eq!(usdt.asset_amount(0).to_display_string(), "0.00");
+
+        assert!(usdt.validate_asset_amount(&amount).is_ok());
+        let other_precision = AssetAmount {
+            precision: 2,
+            ..amount.clone()
+        };
+        assert!(usdt.validate_asset_amount(&other_precision).is_err());
+        let other_asset = AssetAmount {
+            asset_id: "lbtc".to_string(),
+            ..amount
+        };
+        assert!(usdt.validate_asset_amount(&other_asset).is_err());
+
+        let no_decimals = AssetMetadata {
+            precision: 0,
+            fiat_id: None,
+            ..usdt
+        };
+        assert_eq!(no_decimals.display_precision(), 0);
+        assert_eq!(no_decimals.asset_amount(42).to_display_string(), "42");
+    }
+
+    #[sdk_macros::test_all]
+    fn test_log_entry_from_record() {
+        let before = utils::now_millis();
+        let entry = LogEntry::from(
+            &log::Record::builder()
+                .args(format_args!("Test message"))
+                .level(log::Level::Warn)
+                .target("breez_sdk_liquid::sdk")
+                .build(),
+        );
+        let after = utils::now_millis();
+
+        assert_eq!(entry.line, "Test message");
+        assert_eq!(entry.level, "WARN");
+        assert_eq!(entry.target, "breez_sdk_liquid::sdk");
+        assert!((before..=after).contains(&entry.timestamp));
+    }
+}
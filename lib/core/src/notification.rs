@@ -0,0 +1,167 @@
+use anyhow::{anyhow, ensure, Result};
+use serde::Deserialize;
+use url::Url;
+
+use crate::model::{LiquidNetwork, Payment, PaymentDetails, PaymentState, Signer};
+
+/// The default duration of a session started with
+/// [LiquidSdk::process_notification](crate::sdk::LiquidSdk::process_notification). It's below the
+/// time an iOS Notification Service Extension is allowed to run.
+pub(crate) const DEFAULT_NOTIFICATION_TIMEOUT_SEC: u32 = 25;
+
+/// The message contained in a push notification payload, originating from the webhook
+/// registered with [LiquidSdk::register_webhook](crate::sdk::LiquidSdk::register_webhook)
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(tag = "template", content = "data", rename_all = "snake_case")]
+pub(crate) enum NotificationMessage {
+    /// The status of a swap changed. The `id` is the SHA256 hash of the swap id.
+    SwapUpdated { id: String, status: String },
+    /// A payer requested an invoice for the lightning address registered with
+    /// [LiquidSdk::register_lightning_address](crate::sdk::LiquidSdk::register_lightning_address).
+    /// The invoice commits to the hash of the LNURL-pay `metadata` and is posted to `reply_url`.
+    LnurlPayInvoice {
+        amount_msat: u64,
+        metadata: String,
+        reply_url: String,
+    },
+}
+
+impl NotificationMessage {
+    /// Decrypts a hex-encoded payload, encrypted with ECIES to the wallet pubkey
+    pub(crate) fn decrypt(signer: &dyn Signer, payload: &str) -> Result<Self> {
+        let encrypted = hex::decode(payload.trim())?;
+        let decrypted = signer
+            .ecies_decrypt(encrypted)
+            .map_err(|e| anyhow!("Could not decrypt notification payload: {e}"))?;
+        Ok(serde_json::from_slice(&decrypted)?)
+    }
+}
+
+/// Validates a [NotificationMessage::LnurlPayInvoice] request, returning its amount in sat.
+///
+/// Invoices are created for whole sat amounts, so an amount with a sub-sat part is rejected
+/// rather than truncated. The invoice is only sent to the HTTPS `reply_url` of the configured
+/// LNURL service, so a forged notification can't leak it elsewhere.
+pub(crate) fn validate_lnurl_pay_invoice_request(
+    amount_msat: u64,
+    reply_url: &str,
+    lnurl_service_url: Option<&str>,
+    network: LiquidNetwork,
+) -> Result<u64> {
+    ensure!(amount_msat > 0, "Invoice amount must be greater than 0");
+    ensure!(
+        amount_msat % 1_000 == 0,
+        "Invoice amount of {amount_msat} msat is not a whole number of sats"
+    );
+
+    let service_url =
+        Url::parse(lnurl_service_url.ok_or(anyhow!("No LNURL service is configured"))?)?;
+    let reply_url = Url::parse(reply_url).map_err(|e| anyhow!("Invalid reply URL: {e}"))?;
+    ensure!(
+        reply_url.scheme() == "https" || network == LiquidNetwork::Regtest,
+        "Reply URL must use HTTPS"
+    );
+    ensure!(
+        reply_url.host_str().is_some() && reply_url.host_str() == service_url.host_str(),
+        "Reply URL is not on the LNURL service domain"
+    );
+    Ok(amount_msat / 1_000)
+}
+
+/// Whether the SDK completed its part of the swap, so the session can end
+pub(crate) fn is_swap_resolved(payment: &Payment) -> bool {
+    match payment.status {
+        PaymentState::Complete
+        | PaymentState::Failed
+        | PaymentState::TimedOut
+        | PaymentState::Refundable
+        | PaymentState::RefundPending => true,
+        PaymentState::Pending => matches!(
+            &payment.details,
+            PaymentDetails::Lightning {
+                claim_tx_id: Some(_),
+                ..
+            } | PaymentDetails::Bitcoin {
+                claim_tx_id: Some(_),
+                ..
+            }
+        ),
+        PaymentState::Created | PaymentState::WaitingFeeAcceptance => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::{validate_lnurl_pay_invoice_request, NotificationMessage};
+    use crate::model::{LiquidNetwork, Signer};
+    use crate::test_utils::wallet::MockSigner;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_decrypt_notification() -> Result<()> {
+        let signer = MockSigner::new()?;
+        let payload =
+            r#"{"template":"swap_updated","data":{"id":"hash","status":"transaction.mempool"}}"#;
+        let encrypted = hex::encode(signer.ecies_encrypt(payload.as_bytes().to_vec())?);
+
+        assert_eq!(
+            NotificationMessage::decrypt(&signer, &encrypted)?,
+            NotificationMessage::SwapUpdated {
+                id: "hash".to_string(),
+                status: "transaction.mempool".to_string(),
+            }
+        );
+
+        // A payload encrypted to another wallet is rejected
+        let other_signer = MockSigner::new()?;
+        assert!(NotificationMessage::decrypt(&other_signer, &encrypted).is_err());
+
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_validate_lnurl_pay_invoice_request() -> Result<()> {
+        let service_url = Some("https://breez.fun");
+        let reply_url = "https://breez.fun/lnurlpay/reply/abc";
+        let network = LiquidNetwork::Mainnet;
+
+        assert_eq!(
+            validate_lnurl_pay_invoice_request(25_000, reply_url, service_url, network)?,
+            25
+        );
+
+        // Sub-sat amounts aren't truncated
+        assert!(
+            validate_lnurl_pay_invoice_request(25_500, reply_url, service_url, network).is_err()
+        );
+        assert!(validate_lnurl_pay_invoice_request(0, reply_url, service_url, network).is_err());
+
+        // The reply URL must be an HTTPS URL of the LNURL service
+        for reply_url in [
+            "http://breez.fun/lnurlpay/reply/abc",
+            "https://attacker.example/lnurlpay/reply/abc",
+            "not a url",
+        ] {
+            assert!(
+                validate_lnurl_pay_invoice_request(25_000, reply_url, service_url, network)
+                    .is_err()
+            );
+        }
+        assert!(validate_lnurl_pay_invoice_request(25_000, reply_url, None, network).is_err());
+
+        // Plain HTTP is allowed on regtest
+        assert!(validate_lnurl_pay_invoice_request(
+            25_000,
+            "http://breez.fun/lnurlpay/reply/abc",
+            service_url,
+            LiquidNetwork::Regtest
+        )
+        .is_ok());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,413 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use log::{debug, info, warn};
+use nostr_sdk::nips::nip04;
+use nostr_sdk::nips::nip47::{
+    ErrorCode, GetBalanceResponse, ListTransactionsRequest, LookupInvoiceResponse, NIP47Error,
+    PayInvoiceRequest, PayInvoiceResponse, Request, RequestParams, Response, ResponseResult,
+    TransactionType,
+};
+use nostr_sdk::{
+    Client, Event, EventBuilder, Filter, JsonUtil, Keys, Kind, RelayPoolNotification, SecretKey,
+    Tag, Timestamp,
+};
+use sdk_common::utils::Arc;
+use tokio::sync::{broadcast, watch};
+use tokio_with_wasm::alias as tokio;
+
+use crate::model::{
+    GetPaymentRequest, ListPaymentsRequest, NwcConnection, PayAmount, Payment, PaymentDetails,
+    PaymentState, PaymentType, PrepareSendRequest, SendDestination, SendPaymentRequest,
+};
+use crate::persist::Persister;
+use crate::sdk::LiquidSdk;
+
+/// The methods advertised in the info event of the service
+const SUPPORTED_METHODS: &str = "pay_invoice get_balance list_transactions";
+/// The interval at which a payment that may still complete is polled, until it reaches a final
+/// state and its budget reservation can be settled
+const PAYMENT_POLL_INTERVAL: Duration = Duration::from_secs(30);
+
+/// A [Nostr Wallet Connect](https://nips.nostr.com/47) service, executing the requests of the
+/// added [NwcConnection]s through the SDK.
+///
+/// Each connection is identified by the public key of the app using it, which signs its
+/// requests. Payments are only sent if the connection budget allows them.
+pub(crate) struct NwcService {
+    relay_url: String,
+    keys: Keys,
+    persister: Arc<Persister>,
+}
+
+impl NwcService {
+    /// Creates the service, generating its Nostr keys on first use
+    pub(crate) fn new(relay_url: String, persister: Arc<Persister>) -> Result<Self> {
+        let keys = match persister.get_nwc_secret_key()? {
+            Some(secret_key) => Keys::new(SecretKey::from_hex(&secret_key)?),
+            None => {
+                let keys = Keys::generate();
+                persister.set_nwc_secret_key(keys.secret_key().to_secret_hex())?;
+                keys
+            }
+        };
+        Ok(Self {
+            relay_url,
+            keys,
+            persister,
+        })
+    }
+
+    /// Generates the keys of a new connection, returning its public key and connection URI
+    pub(crate) fn new_connection_uri(&self) -> (String, String) {
+        let client_keys = Keys::generate();
+        let relay_url: String =
+            url::form_urlencoded::byte_serialize(self.relay_url.as_bytes()).collect();
+        let connection_uri = format!(
+            "nostr+walletconnect://{}?relay={relay_url}&secret={}",
+            self.keys.public_key().to_hex(),
+            client_keys.secret_key().to_secret_hex()
+        );
+        (client_keys.public_key().to_hex(), connection_uri)
+    }
+
+    pub(crate) fn start(self: Arc<Self>, sdk: Arc<LiquidSdk>, shutdown: watch::Receiver<()>) {
+        tokio::spawn(async move {
+            if let Err(e) = self.run(sdk, shutdown).await {
+                warn!("NWC service stopped: {e:?}");
+            }
+        });
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        sdk: Arc<LiquidSdk>,
+        mut shutdown: watch::Receiver<()>,
+    ) -> Result<()> {
+        let client = Client::new(self.keys.clone());
+        client.add_relay(&self.relay_url).await?;
+        client.connect().await;
+
+        client
+            .send_event_builder(EventBuilder::new(
+                Kind::WalletConnectInfo,
+                SUPPORTED_METHODS,
+            ))
+            .await?;
+        let filter = Filter::new()
+            .kind(Kind::WalletConnectRequest)
+            .pubkey(self.keys.public_key())
+            .since(Timestamp::now());
+        client.subscribe(filter, None).await?;
+        info!("NWC service listening on {}", self.relay_url);
+
+        let mut notifications = client.notifications();
+        loop {
+            tokio::select! {
+                notification = notifications.recv() => match notification {
+                    Ok(RelayPoolNotification::Event { event, .. }) => {
+                        // Requests are handled concurrently, as payments may take a while
+                        let service = self.clone();
+                        let sdk = sdk.clone();
+                        let client = client.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = service.handle_event(&client, &sdk, &event).await {
+                                warn!("Failed to handle NWC request {}: {e:?}", event.id);
+                            }
+                        });
+                    }
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(broadcast::error::RecvError::Closed) => {
+                        return Err(anyhow!("Relay notifications stream closed"));
+                    }
+                },
+                _ = shutdown.changed() => {
+                    info!("Received shutdown signal, exiting NWC loop");
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    async fn handle_event(
+        &self,
+        client: &Client,
+        sdk: &Arc<LiquidSdk>,
+        event: &Event,
+    ) -> Result<()> {
+        let Some(connection) = self
+            .persister
+            .fetch_nwc_connection_by_client_pubkey(&event.pubkey.to_hex())?
+        else {
+            debug!("Ignoring NWC request from unknown client {}", event.pubkey);
+            return Ok(());
+        };
+
+        let content = nip04::decrypt(self.keys.secret_key(), &event.pubkey, &event.content)?;
+        let request = Request::from_json(content)?;
+        info!(
+            "Received NWC request {} from connection {}",
+            request.method, connection.name
+        );
+        let response = match self.handle_request(sdk, &connection, request.params).await {
+            Ok(result) => Response {
+                result_type: request.method,
+                error: None,
+                result: Some(result),
+            },
+            Err(error) => Response {
+                result_type: request.method,
+                error: Some(error),
+                result: None,
+            },
+        };
+
+        self.respond(client, event, response).await
+    }
+
+    async fn respond(&self, client: &Client, request: &Event, response: Response) -> Result<()> {
+        let content = nip04::encrypt(self.keys.secret_key(), &request.pubkey, response.as_json())?;
+        let builder = EventBuilder::new(Kind::WalletConnectResponse, content)
+            .tags([Tag::public_key(request.pubkey), Tag::event(request.id)]);
+        client.send_event_builder(builder).await?;
+        Ok(())
+    }
+
+    async fn handle_request(
+        &self,
+        sdk: &Arc<LiquidSdk>,
+        connection: &NwcConnection,
+        params: RequestParams,
+    ) -> Result<ResponseResult, NIP47Error> {
+        match params {
+            RequestParams::PayInvoice(req) => self
+                .pay_invoice(sdk, connection, req)
+                .await
+                .map(ResponseResult::PayInvoice),
+            RequestParams::GetBalance => {
+                let info = sdk.get_info().await.map_err(internal_error)?;
+                Ok(ResponseResult::GetBalance(GetBalanceResponse {
+                    balance: info.wallet_info.balance_sat * 1_000,
+                }))
+            }
+            RequestParams::ListTransactions(req) => self
+                .list_transactions(sdk, req)
+                .await
+                .map(ResponseResult::ListTransactions),
+            _ => Err(NIP47Error {
+                code: ErrorCode::NotImplemented,
+                message: "Method not supported".to_string(),
+            }),
+        }
+    }
+
+    async fn pay_invoice(
+        &self,
+        sdk: &Arc<LiquidSdk>,
+        connection: &NwcConnection,
+        req: PayInvoiceRequest,
+    ) -> Result<PayInvoiceResponse, NIP47Error> {
+        let prepare_response = sdk
+            .prepare_send_payment(&PrepareSendRequest {
+                destination: req.invoice,
+                amount: req.amount.map(|amount_msat| PayAmount::Bitcoin {
+                    receiver_amount_sat: amount_msat / 1_000,
+                }),
+                fiat_amount: None,
+            })
+            .await
+            .map_err(payment_failed)?;
+        let SendDestination::Bolt11 { invoice, .. } = &prepare_response.destination else {
+            return Err(NIP47Error {
+                code: ErrorCode::Other,
+                message: "Only BOLT11 invoices are supported".to_string(),
+            });
+        };
+        let payment_hash = invoice.payment_hash.clone();
+        let amount_sat = invoice.amount_msat.or(req.amount).unwrap_or_default() / 1_000;
+        let fees_sat = prepare_response.fees_sat.unwrap_or_default();
+        let total_sat = amount_sat + fees_sat;
+
+        let is_within_budget = self
+            .persister
+            .reserve_nwc_budget(&connection.name, total_sat)
+            .map_err(internal_error)?;
+        if !is_within_budget {
+            return Err(NIP47Error {
+                code: ErrorCode::QuotaExceeded,
+                message: format!("Paying {total_sat} sat would exceed the connection budget"),
+            });
+        }
+
+        let res = sdk
+            .send_payment(&SendPaymentRequest {
+                prepare_response,
+                use_asset_fees: None,
+                max_fee_sat: Some(fees_sat),
+                spend_limit_override: None,
+                idempotency_key: None,
+            })
+            .await;
+        let payment = match res {
+            Ok(res) => res.payment,
+            Err(e) if e.is_before_broadcast() => {
+                if let Err(e) = self
+                    .persister
+                    .release_nwc_budget(&connection.name, total_sat)
+                {
+                    warn!("Failed to release NWC budget of {}: {e:?}", connection.name);
+                }
+                return Err(payment_failed(e));
+            }
+            Err(e) => {
+                // The payment may still complete, e.g. after a timeout, so the budget stays
+                // reserved until the payment reaches a final state
+                self.release_budget_on_failure(
+                    sdk.clone(),
+                    connection.name.clone(),
+                    payment_hash,
+                    total_sat,
+                );
+                return Err(payment_failed(e));
+            }
+        };
+        match payment.details {
+            PaymentDetails::Lightning {
+                preimage: Some(preimage),
+                ..
+            } => Ok(PayInvoiceResponse {
+                preimage,
+                fees_paid: Some(payment.fees_sat * 1_000),
+            }),
+            _ => {
+                // The pending payment may still fail, releasing its budget
+                self.release_budget_on_failure(
+                    sdk.clone(),
+                    connection.name.clone(),
+                    payment_hash,
+                    total_sat,
+                );
+                Err(NIP47Error {
+                    code: ErrorCode::Internal,
+                    message: "Payment is still pending".to_string(),
+                })
+            }
+        }
+    }
+
+    /// Polls a payment until it reaches a final state, releasing its reserved budget if it
+    /// failed. The reservation is kept if the payment completes or the SDK is stopped first.
+    fn release_budget_on_failure(
+        &self,
+        sdk: Arc<LiquidSdk>,
+        connection_name: String,
+        payment_hash: String,
+        amount_sat: u64,
+    ) {
+        let persister = self.persister.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(PAYMENT_POLL_INTERVAL).await;
+                let req = GetPaymentRequest::PaymentHash {
+                    payment_hash: payment_hash.clone(),
+                };
+                let status = match sdk.get_payment(&req).await {
+                    Ok(payment) => payment.map(|payment| payment.status),
+                    Err(e) => {
+                        warn!("Stopped polling NWC payment {payment_hash}: {e:?}");
+                        return;
+                    }
+                };
+                match status {
+                    // No payment was persisted, so nothing was sent
+                    Some(PaymentState::Failed | PaymentState::TimedOut) | None => break,
+                    Some(PaymentState::Complete) => return,
+                    Some(_) => {}
+                }
+            }
+
+            info!("NWC payment {payment_hash} failed, releasing its budget of {amount_sat} sat");
+            if let Err(e) = persister.release_nwc_budget(&connection_name, amount_sat) {
+                warn!("Failed to release NWC budget of {connection_name}: {e:?}");
+            }
+        });
+    }
+
+    async fn list_transactions(
+        &self,
+        sdk: &LiquidSdk,
+        req: ListTransactionsRequest,
+    ) -> Result<Vec<LookupInvoiceResponse>, NIP47Error> {
+        let payment_type = req
+            .transaction_type
+            .map(|transaction_type| match transaction_type {
+                TransactionType::Incoming => PaymentType::Receive,
+                TransactionType::Outgoing => PaymentType::Send,
+            });
+        let states = match req.unpaid {
+            Some(true) => None,
+            _ => Some(vec![PaymentState::Complete]),
+        };
+        let payments = sdk
+            .list_payments(&ListPaymentsRequest {
+                filters: payment_type.map(|payment_type| vec![payment_type]),
+                states,
+                from_timestamp: req.from.map(|from| from.as_u64() as i64),
+                to_timestamp: req.until.map(|until| until.as_u64() as i64),
+                offset: req.offset.map(|offset| offset as u32),
+                limit: req.limit.map(|limit| limit as u32),
+                details: None,
+                sort_ascending: None,
+            })
+            .await
+            .map_err(internal_error)?;
+        Ok(payments.into_iter().map(Into::into).collect())
+    }
+}
+
+impl From<Payment> for LookupInvoiceResponse {
+    fn from(payment: Payment) -> Self {
+        let (invoice, description, preimage, payment_hash) = match payment.details {
+            PaymentDetails::Lightning {
+                invoice,
+                description,
+                preimage,
+                payment_hash,
+                ..
+            } => (invoice, Some(description), preimage, payment_hash),
+            _ => (None, None, None, None),
+        };
+        let created_at = Timestamp::from(payment.timestamp as u64);
+        LookupInvoiceResponse {
+            transaction_type: Some(match payment.payment_type {
+                PaymentType::Receive => TransactionType::Incoming,
+                PaymentType::Send => TransactionType::Outgoing,
+            }),
+            invoice,
+            description,
+            description_hash: None,
+            preimage,
+            payment_hash: payment_hash.or(payment.tx_id).unwrap_or_default(),
+            amount: payment.amount_sat * 1_000,
+            fees_paid: payment.fees_sat * 1_000,
+            created_at,
+            expires_at: None,
+            settled_at: (payment.status == PaymentState::Complete).then_some(created_at),
+            metadata: None,
+        }
+    }
+}
+
+fn internal_error<E: std::fmt::Display>(err: E) -> NIP47Error {
+    NIP47Error {
+        code: ErrorCode::Internal,
+        message: err.to_string(),
+    }
+}
+
+fn payment_failed<E: std::fmt::Display>(err: E) -> NIP47Error {
+    NIP47Error {
+        code: ErrorCode::PaymentFailed,
+        message: err.to_string(),
+    }
+}
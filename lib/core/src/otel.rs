@@ -0,0 +1,48 @@
+use anyhow::{anyhow, Result};
+use opentelemetry::{trace::TracerProvider as _, KeyValue};
+use opentelemetry_otlp::WithExportConfig as _;
+use opentelemetry_sdk::{runtime, trace::TracerProvider, Resource};
+use tracing_subscriber::layer::SubscriberExt as _;
+
+/// The name under which the SDK spans are reported, unless set in [OtlpTracingConfig]
+const DEFAULT_SERVICE_NAME: &str = "breez-sdk-liquid";
+
+/// The configuration of the OpenTelemetry exporter of the SDK tracing spans.
+/// See [init_otlp_tracing]
+#[derive(Clone, Debug)]
+pub struct OtlpTracingConfig {
+    /// The OTLP/HTTP endpoint of the collector, e.g. `http://localhost:4318/v1/traces`
+    pub endpoint: String,
+    /// The service name the spans are reported under. Defaults to `breez-sdk-liquid`.
+    pub service_name: Option<String>,
+}
+
+/// Exports the spans of the SDK flows, like connecting, syncing, sending, receiving, claiming
+/// and refunding, to an OpenTelemetry collector over OTLP/HTTP. The spans are exported in
+/// batches in the background, so this must be called from within a Tokio runtime.
+///
+/// It must be called only once in the application lifecycle, as it sets the global tracing
+/// subscriber. An error is returned if one is already set.
+pub fn init_otlp_tracing(config: OtlpTracingConfig) -> Result<()> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(config.endpoint)
+        .build()?;
+    let service_name = config
+        .service_name
+        .unwrap_or(DEFAULT_SERVICE_NAME.to_string());
+    let provider = TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_resource(Resource::new(vec![KeyValue::new(
+            "service.name",
+            service_name,
+        )]))
+        .build();
+    let tracer = provider.tracer(DEFAULT_SERVICE_NAME);
+    opentelemetry::global::set_tracer_provider(provider);
+
+    let subscriber =
+        tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+    tracing::subscriber::set_global_default(subscriber)
+        .map_err(|e| anyhow!("Failed to set global tracing subscriber: {e}"))
+}
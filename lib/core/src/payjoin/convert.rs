@@ -0,0 +1,600 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use boltz_client::boltz::tokio_tungstenite_wasm::{self, Message, WebSocketStream};
+use futures_util::{SinkExt, StreamExt};
+use log::{debug, warn};
+use lwk_wollet::{
+    bitcoin::base64::{self, Engine as _},
+    elements::{self, pset::PartiallySignedTransaction, AssetId, Txid},
+};
+use maybe_sync::MaybeSend;
+use sdk_common::{ensure_sdk, utils::Arc};
+use tokio_with_wasm::alias as tokio;
+
+use super::{
+    error::{PayjoinError, PayjoinResult},
+    model::{
+        AssetPair, AssetType, GetQuoteRequest, ListMarketsRequest, LoginClientRequest,
+        MarketMessage, MarketMethod, MarketNotification, MarketRequest, MarketRequestParams,
+        MarketResponse, MarketUtxo, QuoteNotification, QuoteStatus, StartQuotesRequest,
+        TakerSignRequest, TradeDir,
+    },
+};
+use crate::{
+    model::{Config, LiquidNetwork},
+    utils,
+    wallet::{OnchainWallet, PsetWalletBalance},
+};
+
+const PRODUCTION_SIDESWAP_MARKET_URL: &str = "wss://api.sideswap.io/json-rpc-ws";
+const TESTNET_SIDESWAP_MARKET_URL: &str = "wss://api-testnet.sideswap.io/json-rpc-ws";
+/// How long to wait for the service to respond to a request or send a quote
+const MARKET_RESPONSE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A connection to the swap market of the conversion service
+#[sdk_macros::async_trait]
+pub(crate) trait MarketSession: MaybeSend {
+    /// Sends the request and waits for its response
+    async fn request(&mut self, method: MarketMethod) -> PayjoinResult<serde_json::Value>;
+
+    /// Waits for the next quote of the quote subscription
+    async fn next_quote(&mut self, quote_sub_id: u64) -> PayjoinResult<QuoteStatus>;
+}
+
+/// A session with the SideSwap swap market over its JSON-RPC WebSocket API
+pub(crate) struct SideSwapMarketSession {
+    ws_stream: WebSocketStream,
+    next_id: u64,
+    /// The quotes received while waiting for a response
+    quotes: VecDeque<QuoteNotification>,
+}
+
+impl SideSwapMarketSession {
+    pub(crate) async fn connect(config: &Config) -> PayjoinResult<Self> {
+        let url = match config.network {
+            LiquidNetwork::Mainnet => PRODUCTION_SIDESWAP_MARKET_URL,
+            LiquidNetwork::Testnet => TESTNET_SIDESWAP_MARKET_URL,
+            network => {
+                return Err(PayjoinError::generic(format!(
+                    "Asset conversion not supported on {network}"
+                )))
+            }
+        };
+        let ws_stream = tokio_tungstenite_wasm::connect(url).await.map_err(|e| {
+            PayjoinError::service_connectivity(format!("Failed to connect to SideSwap: {e}"))
+        })?;
+        let mut session = Self {
+            ws_stream,
+            next_id: 1,
+            quotes: VecDeque::new(),
+        };
+        session
+            .request(MarketMethod::LoginClient(LoginClientRequest {
+                api_key: config.sideswap_api_key.clone(),
+                cookie: None,
+                user_agent: "breezsdk".to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            }))
+            .await?;
+        Ok(session)
+    }
+
+    async fn next_message(&mut self) -> PayjoinResult<MarketMessage> {
+        loop {
+            let message = tokio::time::timeout(MARKET_RESPONSE_TIMEOUT, self.ws_stream.next())
+                .await
+                .map_err(|_| PayjoinError::service_connectivity("SideSwap did not respond"))?;
+            match message {
+                Some(Ok(Message::Text(payload))) => {
+                    debug!("Received message from SideSwap: {}", payload.as_str());
+                    return Ok(serde_json::from_str(payload.as_str())?);
+                }
+                Some(Ok(Message::Close(_))) | None => {
+                    return Err(PayjoinError::service_connectivity(
+                        "SideSwap closed the connection",
+                    ))
+                }
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => {
+                    return Err(PayjoinError::service_connectivity(format!(
+                        "Failed to receive message from SideSwap: {e}"
+                    )))
+                }
+            }
+        }
+    }
+
+    fn queue_notification(&mut self, params: serde_json::Value) {
+        match serde_json::from_value::<MarketNotification>(params) {
+            Ok(MarketNotification::Quote(quote)) => self.quotes.push_back(quote),
+            Err(_) => debug!("Ignoring SideSwap notification"),
+        }
+    }
+}
+
+#[sdk_macros::async_trait]
+impl MarketSession for SideSwapMarketSession {
+    async fn request(&mut self, method: MarketMethod) -> PayjoinResult<serde_json::Value> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let request = serde_json::to_string(&MarketRequest { id, method })?;
+        debug!("Sending request to SideSwap: {request}");
+        self.ws_stream
+            .send(Message::Text(request.into()))
+            .await
+            .map_err(|e| {
+                PayjoinError::service_connectivity(format!(
+                    "Failed to send request to SideSwap: {e}"
+                ))
+            })?;
+
+        loop {
+            let message = self.next_message().await?;
+            match (message.id, message.params) {
+                (Some(response_id), _) if response_id == id => {
+                    if let Some(error) = message.error {
+                        return Err(PayjoinError::generic(format!(
+                            "SideSwap request failed: {}",
+                            error.message
+                        )));
+                    }
+                    return Ok(message.result.unwrap_or_default());
+                }
+                (None, Some(params)) => self.queue_notification(params),
+                _ => warn!("Ignoring unexpected SideSwap response"),
+            }
+        }
+    }
+
+    async fn next_quote(&mut self, quote_sub_id: u64) -> PayjoinResult<QuoteStatus> {
+        loop {
+            if let Some(index) = self
+                .quotes
+                .iter()
+                .position(|quote| quote.quote_sub_id == quote_sub_id)
+            {
+                if let Some(quote) = self.quotes.remove(index) {
+                    return Ok(quote.status);
+                }
+            }
+            let message = self.next_message().await?;
+            if let (None, Some(params)) = (message.id, message.params) {
+                self.queue_notification(params);
+            }
+        }
+    }
+}
+
+/// A quoted conversion tx, constructed by the service with the wallet utxos
+pub(crate) struct ConvertQuote {
+    quote_id: u64,
+    pset: PartiallySignedTransaction,
+    /// The amount of the received asset paid to the wallet
+    pub(crate) receiver_amount: u64,
+    /// The L-BTC paid on top of the converted amount, in satoshi
+    pub(crate) fees_sat: u64,
+    /// The wallet address receiving the converted amount
+    pub(crate) receive_address: String,
+}
+
+/// Converts between L-BTC and a Liquid asset with an atomic swap on the SideSwap swap market.
+///
+/// The wallet offers its utxos of the sent asset and the market constructs the swap tx, so
+/// either both sides of the conversion settle in the same tx or neither does. Before signing,
+/// the tx is checked to only spend the sent amount and pay the quoted amount to the wallet.
+pub(crate) struct AssetConvertClient {
+    onchain_wallet: Arc<dyn OnchainWallet>,
+    policy_asset: AssetId,
+}
+
+impl AssetConvertClient {
+    pub(crate) fn new(config: &Config, onchain_wallet: Arc<dyn OnchainWallet>) -> Self {
+        Self {
+            onchain_wallet,
+            policy_asset: utils::lbtc_asset_id(config.network),
+        }
+    }
+
+    async fn market_request(
+        session: &mut dyn MarketSession,
+        params: MarketRequestParams,
+    ) -> PayjoinResult<MarketResponse> {
+        let result = session.request(MarketMethod::Market(params)).await?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    /// Requests a quote for converting `amount` of `from_asset` into `to_asset` and checks
+    /// the quoted tx
+    pub(crate) async fn quote(
+        &self,
+        session: &mut dyn MarketSession,
+        from_asset: AssetId,
+        to_asset: AssetId,
+        amount: u64,
+    ) -> PayjoinResult<ConvertQuote> {
+        ensure_sdk!(
+            (from_asset == self.policy_asset) != (to_asset == self.policy_asset),
+            PayjoinError::generic("Conversions must be between L-BTC and an asset")
+        );
+        let MarketResponse::ListMarkets(markets) = Self::market_request(
+            session,
+            MarketRequestParams::ListMarkets(ListMarketsRequest {}),
+        )
+        .await?
+        else {
+            return Err(PayjoinError::service_connectivity("Failed to list markets"));
+        };
+        let asset_pair = markets
+            .markets
+            .into_iter()
+            .map(|market| market.asset_pair)
+            .find(|pair| {
+                (pair.base, pair.quote) == (from_asset, to_asset)
+                    || (pair.base, pair.quote) == (to_asset, from_asset)
+            })
+            .ok_or(PayjoinError::generic("No market for the conversion assets"))?;
+
+        let utxos = self
+            .onchain_wallet
+            .asset_utxos(&from_asset)
+            .await?
+            .iter()
+            .map(MarketUtxo::from)
+            .collect::<Vec<_>>();
+        let available = utxos.iter().map(|utxo| utxo.value).sum::<u64>();
+        ensure_sdk!(available >= amount, PayjoinError::InsufficientFunds);
+
+        let receive_address = self.onchain_wallet.next_unused_address().await?;
+        let (asset_type, trade_dir) = quote_direction(&asset_pair, from_asset);
+        let MarketResponse::StartQuotes(start_quotes) = Self::market_request(
+            session,
+            MarketRequestParams::StartQuotes(StartQuotesRequest {
+                asset_pair,
+                asset_type,
+                amount,
+                trade_dir,
+                utxos,
+                receive_address: receive_address.clone(),
+                change_address: self.onchain_wallet.next_unused_change_address().await?,
+                instant_swap: true,
+            }),
+        )
+        .await?
+        else {
+            return Err(PayjoinError::service_connectivity("Failed to start quotes"));
+        };
+
+        let quote_id = match session.next_quote(start_quotes.quote_sub_id).await? {
+            QuoteStatus::Success { quote_id, .. } => quote_id,
+            QuoteStatus::LowBalance { available } => {
+                return Err(PayjoinError::generic(format!(
+                    "The service can only convert up to {available} of the received asset"
+                )))
+            }
+            QuoteStatus::Error { error_msg } => return Err(PayjoinError::generic(error_msg)),
+        };
+        let MarketResponse::GetQuote(quote) = Self::market_request(
+            session,
+            MarketRequestParams::GetQuote(GetQuoteRequest { quote_id }),
+        )
+        .await?
+        else {
+            return Err(PayjoinError::service_connectivity("Failed to get quote"));
+        };
+
+        let pset = elements::encode::deserialize::<PartiallySignedTransaction>(
+            &base64::engine::general_purpose::STANDARD.decode(&quote.pset)?,
+        )?;
+        let balance = self.onchain_wallet.pset_balance(&pset).await?;
+        let (receiver_amount, fees_sat) =
+            check_quote_balance(&balance, self.policy_asset, from_asset, to_asset, amount)?;
+        Ok(ConvertQuote {
+            quote_id,
+            pset,
+            receiver_amount,
+            fees_sat,
+            receive_address: receive_address.to_string(),
+        })
+    }
+
+    /// Signs the wallet inputs of the quoted tx, which the service then broadcasts
+    pub(crate) async fn accept(
+        &self,
+        session: &mut dyn MarketSession,
+        quote: ConvertQuote,
+    ) -> PayjoinResult<Txid> {
+        let signed_pset = self.onchain_wallet.sign_pset_inputs(quote.pset).await?;
+        let MarketResponse::TakerSign(response) = Self::market_request(
+            session,
+            MarketRequestParams::TakerSign(TakerSignRequest {
+                quote_id: quote.quote_id,
+                pset: base64::engine::general_purpose::STANDARD
+                    .encode(elements::encode::serialize(&signed_pset)),
+            }),
+        )
+        .await?
+        else {
+            return Err(PayjoinError::service_connectivity(
+                "Failed to sign conversion",
+            ));
+        };
+        Ok(response.txid)
+    }
+}
+
+/// The side of the market pair `amount` is in, and whether the base asset is sold or bought
+fn quote_direction(asset_pair: &AssetPair, from_asset: AssetId) -> (AssetType, TradeDir) {
+    match asset_pair.base == from_asset {
+        true => (AssetType::Base, TradeDir::Sell),
+        false => (AssetType::Quote, TradeDir::Buy),
+    }
+}
+
+/// Checks the effect of the quoted tx on the wallet: only `from_asset` is spent, at most
+/// `amount` of it unless it's L-BTC, and `to_asset` is received.
+///
+/// Returns the amount received and the L-BTC paid on top of `amount`, if any.
+fn check_quote_balance(
+    balance: &PsetWalletBalance,
+    policy_asset: AssetId,
+    from_asset: AssetId,
+    to_asset: AssetId,
+    amount: u64,
+) -> PayjoinResult<(u64, u64)> {
+    let balance_of = |asset: &AssetId| balance.balances.get(asset).copied().unwrap_or_default();
+    ensure_sdk!(
+        balance
+            .balances
+            .iter()
+            .all(|(asset, value)| *value >= 0 || *asset == from_asset),
+        PayjoinError::generic("The conversion tx spends other wallet assets")
+    );
+
+    let sent = balance_of(&from_asset).unsigned_abs();
+    let fees_sat = match from_asset == policy_asset {
+        true => sent.saturating_sub(amount),
+        false => 0,
+    };
+    ensure_sdk!(
+        balance_of(&from_asset) < 0 && sent - fees_sat == amount,
+        PayjoinError::generic("The conversion tx does not spend the converted amount")
+    );
+
+    let receiver_amount = balance_of(&to_asset);
+    ensure_sdk!(
+        receiver_amount > 0,
+        PayjoinError::generic("The conversion tx does not pay the received asset")
+    );
+    Ok((receiver_amount as u64, fees_sat))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, VecDeque};
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use lwk_wollet::{
+        bitcoin::base64::{self, Engine as _},
+        elements::{
+            self,
+            confidential::{AssetBlindingFactor, ValueBlindingFactor},
+            pset::PartiallySignedTransaction,
+            Address, AssetId, OutPoint, Script, TxOutSecrets, Txid,
+        },
+        Chain, WalletTxOut,
+    };
+    use sdk_common::utils::Arc;
+    use serde_json::json;
+
+    use super::{check_quote_balance, AssetConvertClient, MarketSession};
+    use crate::{
+        model::{Config, LiquidNetwork},
+        payjoin::{
+            error::{PayjoinError, PayjoinResult},
+            model::{MarketMethod, QuoteStatus},
+        },
+        test_utils::wallet::{MockSigner, MockWallet},
+        utils,
+        wallet::PsetWalletBalance,
+    };
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    const TX_ID: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+
+    /// Replies to the market requests in order, recording them
+    struct MockMarketSession {
+        responses: VecDeque<serde_json::Value>,
+        quote: Option<QuoteStatus>,
+        requests: Vec<serde_json::Value>,
+    }
+
+    #[sdk_macros::async_trait]
+    impl MarketSession for MockMarketSession {
+        async fn request(&mut self, method: MarketMethod) -> PayjoinResult<serde_json::Value> {
+            self.requests.push(serde_json::to_value(&method)?);
+            self.responses
+                .pop_front()
+                .ok_or(PayjoinError::generic("No response"))
+        }
+
+        async fn next_quote(&mut self, _quote_sub_id: u64) -> PayjoinResult<QuoteStatus> {
+            self.quote.take().ok_or(PayjoinError::generic("No quote"))
+        }
+    }
+
+    fn asset() -> AssetId {
+        AssetId::from_slice(&[2; 32]).unwrap()
+    }
+
+    fn lbtc() -> AssetId {
+        utils::lbtc_asset_id(LiquidNetwork::Testnet)
+    }
+
+    fn mock_session(quote: QuoteStatus) -> MockMarketSession {
+        let pset = base64::engine::general_purpose::STANDARD.encode(elements::encode::serialize(
+            &PartiallySignedTransaction::new_v2(),
+        ));
+        MockMarketSession {
+            responses: VecDeque::from([
+                json!({ "list_markets": { "markets": [
+                    { "asset_pair": { "base": lbtc().to_string(), "quote": asset().to_string() } }
+                ] } }),
+                json!({ "start_quotes": { "quote_sub_id": 1 } }),
+                json!({ "get_quote": { "pset": pset } }),
+                json!({ "taker_sign": { "txid": TX_ID } }),
+            ]),
+            quote: Some(quote),
+            requests: vec![],
+        }
+    }
+
+    fn create_utxo(asset: AssetId, value: u64) -> Result<WalletTxOut> {
+        Ok(WalletTxOut {
+            outpoint: OutPoint::new(Txid::from_str(TX_ID)?, 0),
+            script_pubkey: Script::from_str("76a914000000000000000000000000000000000000000088ac")?,
+            height: Some(10),
+            unblinded: TxOutSecrets {
+                asset,
+                value,
+                asset_bf: AssetBlindingFactor::zero(),
+                value_bf: ValueBlindingFactor::zero(),
+            },
+            wildcard_index: 0,
+            ext_int: Chain::Internal,
+            is_spent: false,
+            address: Address::from_str("lq1pqw8ct25kd47dejyesyvk3g2kaf8s9uhq4se7r2kj9y9hhvu9ug5thxlpn9y63s78kc2mcp6nujavckvr42q7hwkhqq9hfz46nth22hfp3em0ulm4nsuf")?,
+        })
+    }
+
+    fn new_client(
+        utxo: WalletTxOut,
+        balances: HashMap<AssetId, i64>,
+    ) -> Result<AssetConvertClient> {
+        let wallet = MockWallet::new(Arc::new(Box::new(MockSigner::new()?)))?;
+        wallet.set_utxos(vec![utxo]);
+        wallet.set_pset_balance(PsetWalletBalance {
+            fee_sat: 30,
+            balances,
+        });
+        Ok(AssetConvertClient::new(
+            &Config::testnet_esplora(None),
+            Arc::new(wallet),
+        ))
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_convert() -> Result<()> {
+        let client = new_client(
+            create_utxo(lbtc(), 20_000)?,
+            HashMap::from([(lbtc(), -10_050), (asset(), 900_000)]),
+        )?;
+        let mut session = mock_session(QuoteStatus::Success {
+            quote_id: 7,
+            base_amount: 10_000,
+            quote_amount: 900_000,
+        });
+
+        let quote = client.quote(&mut session, lbtc(), asset(), 10_000).await?;
+        assert_eq!(quote.receiver_amount, 900_000);
+        assert_eq!(quote.fees_sat, 50);
+        let txid = client.accept(&mut session, quote).await?;
+        assert_eq!(txid, Txid::from_str(TX_ID)?);
+
+        // Selling L-BTC, the base asset of the market
+        let start_quotes = &session.requests[1]["params"]["start_quotes"];
+        assert_eq!(start_quotes["asset_type"], "Base");
+        assert_eq!(start_quotes["trade_dir"], "Sell");
+        assert_eq!(start_quotes["amount"], 10_000);
+        assert_eq!(session.requests[3]["params"]["taker_sign"]["quote_id"], 7);
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_convert_rejects_failed_quote() -> Result<()> {
+        let client = new_client(create_utxo(asset(), 1_000_000)?, HashMap::new())?;
+        assert!(matches!(
+            client
+                .quote(
+                    &mut mock_session(QuoteStatus::LowBalance { available: 0 }),
+                    asset(),
+                    lbtc(),
+                    2_000_000
+                )
+                .await,
+            Err(PayjoinError::InsufficientFunds)
+        ));
+
+        let mut session = mock_session(QuoteStatus::Error {
+            error_msg: "Market closed".to_string(),
+        });
+        assert!(matches!(
+            client.quote(&mut session, asset(), lbtc(), 900_000).await,
+            Err(PayjoinError::Generic(err)) if err == "Market closed"
+        ));
+
+        // Converting between two assets
+        let mut session = mock_session(QuoteStatus::LowBalance { available: 0 });
+        assert!(client
+            .quote(&mut session, asset(), asset(), 900_000)
+            .await
+            .is_err());
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_check_quote_balance() -> Result<()> {
+        let other_asset = AssetId::from_slice(&[3; 32])?;
+        let balance = |balances: Vec<(AssetId, i64)>| PsetWalletBalance {
+            fee_sat: 30,
+            balances: balances.into_iter().collect(),
+        };
+
+        // Receiving L-BTC, the fees are deducted from the received amount
+        assert_eq!(
+            check_quote_balance(
+                &balance(vec![(asset(), -900_000), (lbtc(), 9_950)]),
+                lbtc(),
+                asset(),
+                lbtc(),
+                900_000
+            )?,
+            (9_950, 0)
+        );
+        // Spending more than the converted asset amount
+        assert!(check_quote_balance(
+            &balance(vec![(asset(), -900_001), (lbtc(), 9_950)]),
+            lbtc(),
+            asset(),
+            lbtc(),
+            900_000
+        )
+        .is_err());
+        // Spending another asset
+        assert!(check_quote_balance(
+            &balance(vec![
+                (asset(), -900_000),
+                (lbtc(), 9_950),
+                (other_asset, -1)
+            ]),
+            lbtc(),
+            asset(),
+            lbtc(),
+            900_000
+        )
+        .is_err());
+        // Not paying the received asset
+        assert!(check_quote_balance(
+            &balance(vec![(lbtc(), -10_000)]),
+            lbtc(),
+            lbtc(),
+            asset(),
+            10_000
+        )
+        .is_err());
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
+pub(crate) mod convert;
 pub(crate) mod error;
 pub(crate) mod model;
 mod network_fee;
+pub(crate) mod p2ep;
 mod pset;
 pub(crate) mod side_swap;
 mod utxo_select;
@@ -8,7 +10,7 @@ mod utxo_select;
 use error::PayjoinResult;
 use lwk_wollet::elements::Transaction;
 use maybe_sync::{MaybeSend, MaybeSync};
-use model::AcceptedAsset;
+use model::{AcceptedAsset, PayjoinFeeEstimate};
 
 #[sdk_macros::async_trait]
 pub trait PayjoinService: MaybeSend + MaybeSync {
@@ -16,7 +18,11 @@ pub trait PayjoinService: MaybeSend + MaybeSync {
     async fn fetch_accepted_assets(&self) -> PayjoinResult<Vec<AcceptedAsset>>;
 
     /// Estimate the fee for a payjoin transaction
-    async fn estimate_payjoin_tx_fee(&self, asset_id: &str, amount_sat: u64) -> PayjoinResult<f64>;
+    async fn estimate_payjoin_tx_fee(
+        &self,
+        asset_id: &str,
+        amount_sat: u64,
+    ) -> PayjoinResult<PayjoinFeeEstimate>;
 
     /// Build a payjoin transaction to send funds to a recipient using the asset to pay fees.
     /// Returns the transaction and the service fee paid in satoshi units.
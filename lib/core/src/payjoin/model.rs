@@ -39,6 +39,17 @@ pub struct AcceptedAsset {
     pub asset_id: String,
 }
 
+/// The estimated fees of a payjoin tx
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayjoinFeeEstimate {
+    /// The total fee paid in the asset, rounded up to two decimal places
+    pub asset_fee: f64,
+    /// The fixed fee charged by the payjoin service, in satoshi
+    pub service_fee_sat: u64,
+    /// The L-BTC network fee of the tx, which is paid by the payjoin service
+    pub network_fee_sat: u64,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Recipient {
     pub address: Address,
@@ -105,3 +116,221 @@ pub(crate) struct SignRequest {
 pub(crate) struct SignResponse {
     pub pset: String,
 }
+
+/// A request to the payjoin endpoint advertised by a receiver in a BIP21 URI
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum P2epRequest {
+    Start(P2epStartRequest),
+    Sign(P2epSignRequest),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum P2epResponse {
+    Start(P2epStartResponse),
+    Sign(SignResponse),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct P2epStartRequest {
+    pub asset_id: String,
+    pub amount: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct P2epStartResponse {
+    pub session_id: String,
+    /// The receiver utxos which can be contributed to the payjoin
+    pub utxos: Vec<Utxo>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct P2epSignRequest {
+    pub session_id: String,
+    pub pset: String,
+}
+
+/// A JSON-RPC request to the SideSwap API
+#[derive(Debug, Serialize)]
+pub(crate) struct MarketRequest {
+    pub id: u64,
+    #[serde(flatten)]
+    pub method: MarketMethod,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub(crate) enum MarketMethod {
+    LoginClient(LoginClientRequest),
+    Market(MarketRequestParams),
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct LoginClientRequest {
+    pub api_key: Option<String>,
+    pub cookie: Option<String>,
+    pub user_agent: String,
+    pub version: String,
+}
+
+/// A request of the SideSwap swap market
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum MarketRequestParams {
+    ListMarkets(ListMarketsRequest),
+    StartQuotes(StartQuotesRequest),
+    GetQuote(GetQuoteRequest),
+    TakerSign(TakerSignRequest),
+}
+
+/// A message received from the SideSwap API, either the response to a request or a
+/// notification
+#[derive(Debug, Deserialize)]
+pub(crate) struct MarketMessage {
+    pub id: Option<u64>,
+    pub result: Option<serde_json::Value>,
+    pub params: Option<serde_json::Value>,
+    pub error: Option<MarketError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MarketError {
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum MarketResponse {
+    ListMarkets(ListMarketsResponse),
+    StartQuotes(StartQuotesResponse),
+    GetQuote(GetQuoteResponse),
+    TakerSign(TakerSignResponse),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum MarketNotification {
+    Quote(QuoteNotification),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AssetPair {
+    pub base: AssetId,
+    pub quote: AssetId,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) enum AssetType {
+    Base,
+    Quote,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) enum TradeDir {
+    Sell,
+    Buy,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ListMarketsRequest {}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct ListMarketsResponse {
+    pub markets: Vec<MarketInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MarketInfo {
+    pub asset_pair: AssetPair,
+}
+
+/// A wallet utxo offered to the market, which the server uses to construct the swap tx
+#[derive(Debug, Serialize)]
+pub(crate) struct MarketUtxo {
+    pub txid: Txid,
+    pub vout: u32,
+    pub asset: AssetId,
+    pub asset_bf: AssetBlindingFactor,
+    pub value: u64,
+    pub value_bf: ValueBlindingFactor,
+    pub redeem_script: Option<Script>,
+}
+
+impl From<&WalletTxOut> for MarketUtxo {
+    fn from(tx_out: &WalletTxOut) -> Self {
+        Self {
+            txid: tx_out.outpoint.txid,
+            vout: tx_out.outpoint.vout,
+            asset: tx_out.unblinded.asset,
+            asset_bf: tx_out.unblinded.asset_bf,
+            value: tx_out.unblinded.value,
+            value_bf: tx_out.unblinded.value_bf,
+            redeem_script: None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct StartQuotesRequest {
+    pub asset_pair: AssetPair,
+    /// Which asset of the pair `amount` is in
+    pub asset_type: AssetType,
+    pub amount: u64,
+    /// Whether the base asset is sold or bought
+    pub trade_dir: TradeDir,
+    pub utxos: Vec<MarketUtxo>,
+    pub receive_address: Address,
+    pub change_address: Address,
+    pub instant_swap: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct StartQuotesResponse {
+    pub quote_sub_id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct QuoteNotification {
+    pub quote_sub_id: u64,
+    pub status: QuoteStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) enum QuoteStatus {
+    Success {
+        quote_id: u64,
+        base_amount: u64,
+        quote_amount: u64,
+    },
+    LowBalance {
+        available: u64,
+    },
+    Error {
+        error_msg: String,
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct GetQuoteRequest {
+    pub quote_id: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GetQuoteResponse {
+    /// The base64 encoded swap tx, constructed by the server with the wallet utxos
+    pub pset: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct TakerSignRequest {
+    pub quote_id: u64,
+    /// The base64 encoded swap tx, with the wallet inputs signed
+    pub pset: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct TakerSignResponse {
+    pub txid: Txid,
+}
@@ -0,0 +1,287 @@
+use std::{collections::HashMap, str::FromStr};
+
+use log::{debug, error};
+use lwk_wollet::{
+    bitcoin::base64::{self, Engine as _},
+    elements::{self, pset::PartiallySignedTransaction, Address, AssetId, Transaction},
+};
+use sdk_common::{
+    ensure_sdk,
+    prelude::{parse_json, RestClient},
+    utils::Arc,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use url::Url;
+
+use super::{
+    error::{PayjoinError, PayjoinResult},
+    model::{InOut, P2epRequest, P2epResponse, P2epSignRequest, P2epStartRequest, Utxo},
+    network_fee::TxFee,
+    pset::{blind::remove_explicit_values, construct_pset, ConstructPsetRequest, PsetOutput},
+    side_swap::{copy_signatures, select_utxos},
+};
+use crate::wallet::OnchainWallet;
+
+/// The BIP21 parameter with the payjoin endpoint of the receiver
+const PAYJOIN_URI_PARAM: &str = "pj";
+
+/// Returns the payjoin endpoint advertised in a BIP21 URI, if any. Only HTTPS endpoints are
+/// accepted, as the payjoin reveals the sender utxos to the endpoint.
+pub(crate) fn payjoin_endpoint(uri: &str) -> Option<String> {
+    let url = Url::parse(uri.trim()).ok()?;
+    let (_, endpoint) = url
+        .query_pairs()
+        .find(|(key, _)| key.eq_ignore_ascii_case(PAYJOIN_URI_PARAM))?;
+    endpoint
+        .starts_with("https://")
+        .then(|| endpoint.into_owned())
+}
+
+/// Builds payjoin (P2EP) transactions with the receiver of a direct Liquid send.
+///
+/// The receiver contributes an input of the sent asset, which is added to its output, so the
+/// transaction breaks the assumption that all the inputs belong to the sender. The sender
+/// constructs and blinds the transaction, so the receiver can only add its signature.
+pub(crate) struct P2epPayjoinClient {
+    onchain_wallet: Arc<dyn OnchainWallet>,
+    rest_client: Arc<dyn RestClient>,
+    policy_asset: AssetId,
+}
+
+impl P2epPayjoinClient {
+    pub(crate) fn new(
+        onchain_wallet: Arc<dyn OnchainWallet>,
+        rest_client: Arc<dyn RestClient>,
+        policy_asset: AssetId,
+    ) -> Self {
+        Self {
+            onchain_wallet,
+            rest_client,
+            policy_asset,
+        }
+    }
+
+    async fn post_request<I: Serialize, O: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        body: &I,
+    ) -> PayjoinResult<O> {
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let body = serde_json::to_string(body)?;
+        debug!("Posting payjoin request to {endpoint}: {body}");
+        let (response, status_code) = self
+            .rest_client
+            .post(endpoint, Some(headers), Some(body))
+            .await?;
+        if status_code != 200 {
+            error!("Received status code {status_code} response from payjoin endpoint");
+            return Err(PayjoinError::service_connectivity(format!(
+                "Failed to post request to payjoin endpoint: {response}"
+            )));
+        }
+        Ok(parse_json(&response)?)
+    }
+
+    /// Builds and signs a payjoin transaction sending `amount_sat` of L-BTC to the
+    /// `recipient_address`, paying at most `max_fee_sat` in network fees
+    pub(crate) async fn build_payjoin_tx(
+        &self,
+        endpoint: &str,
+        recipient_address: &str,
+        amount_sat: u64,
+        max_fee_sat: u64,
+    ) -> PayjoinResult<Transaction> {
+        let address = Address::from_str(recipient_address).map_err(|e| {
+            PayjoinError::generic(format!(
+                "Recipient address {recipient_address} is not a valid ElementsAddress: {e:?}"
+            ))
+        })?;
+        ensure_sdk!(
+            address.is_blinded(),
+            PayjoinError::generic("Recipient address is not blinded")
+        );
+
+        let start_request = P2epRequest::Start(P2epStartRequest {
+            asset_id: self.policy_asset.to_string(),
+            amount: amount_sat,
+        });
+        let response: P2epResponse = self.post_request(endpoint, &start_request).await?;
+        let P2epResponse::Start(start_response) = response else {
+            return Err(PayjoinError::service_connectivity(
+                "Failed to start payjoin",
+            ));
+        };
+        let receiver_utxo = start_response
+            .utxos
+            .into_iter()
+            .find(|utxo| utxo.asset_id == self.policy_asset)
+            .ok_or(PayjoinError::generic("Receiver contributed no L-BTC utxo"))?;
+
+        let mut wallet_utxos = self
+            .onchain_wallet
+            .asset_utxos(&self.policy_asset)
+            .await?
+            .iter()
+            .map(Utxo::from)
+            .collect::<Vec<_>>();
+        wallet_utxos.sort_by(|a, b| b.value.cmp(&a.value));
+        let (wallet_inputs, network_fee) = select_wallet_inputs(&wallet_utxos, amount_sat)?;
+        ensure_sdk!(
+            network_fee <= max_fee_sat,
+            PayjoinError::generic("Payjoin network fee exceeds the prepared fees")
+        );
+        let change_sat =
+            wallet_inputs.iter().map(|input| input.value).sum::<u64>() - amount_sat - network_fee;
+
+        let receiver_input = InOut {
+            asset_id: receiver_utxo.asset_id,
+            value: receiver_utxo.value,
+        };
+        let receiver_outpoint = (receiver_utxo.txid, receiver_utxo.vout);
+        let mut inputs = select_utxos(wallet_utxos, wallet_inputs)?;
+        inputs.append(&mut select_utxos(
+            vec![receiver_utxo],
+            vec![receiver_input],
+        )?);
+
+        // The receiver input is added to the recipient output
+        let mut outputs = vec![PsetOutput {
+            address,
+            asset_id: self.policy_asset,
+            amount: amount_sat + receiver_input.value,
+        }];
+        if change_sat > 0 {
+            outputs.push(PsetOutput {
+                address: self.onchain_wallet.next_unused_change_address().await?,
+                asset_id: self.policy_asset,
+                amount: change_sat,
+            });
+        }
+
+        let blinded_pset = construct_pset(ConstructPsetRequest {
+            policy_asset: self.policy_asset,
+            inputs,
+            outputs,
+            network_fee,
+        })?;
+
+        let mut pset = blinded_pset.clone();
+        remove_explicit_values(&mut pset);
+        let sign_request = P2epRequest::Sign(P2epSignRequest {
+            session_id: start_response.session_id,
+            pset: base64::engine::general_purpose::STANDARD
+                .encode(elements::encode::serialize(&pset)),
+        });
+        let response: P2epResponse = self.post_request(endpoint, &sign_request).await?;
+        let P2epResponse::Sign(sign_response) = response else {
+            return Err(PayjoinError::service_connectivity("Failed to sign payjoin"));
+        };
+
+        let receiver_signed_pset = elements::encode::deserialize::<PartiallySignedTransaction>(
+            &base64::engine::general_purpose::STANDARD.decode(&sign_response.pset)?,
+        )?;
+        let receiver_signed_blinded_pset = copy_signatures(blinded_pset, receiver_signed_pset)?;
+        ensure_sdk!(
+            receiver_signed_blinded_pset.inputs().iter().any(|input| {
+                (input.previous_txid, input.previous_output_index) == receiver_outpoint
+                    && input.final_script_witness.is_some()
+            }),
+            PayjoinError::generic("Receiver did not sign its input")
+        );
+
+        Ok(self
+            .onchain_wallet
+            .sign_pset(receiver_signed_blinded_pset)
+            .await?)
+    }
+}
+
+/// Selects the largest wallet utxos until they cover the amount and the network fee of a
+/// payjoin with one receiver input, a recipient output and a change output
+fn select_wallet_inputs(utxos: &[Utxo], amount_sat: u64) -> PayjoinResult<(Vec<InOut>, u64)> {
+    let mut selected = Vec::new();
+    let mut selected_sat = 0;
+    for utxo in utxos {
+        selected.push(InOut {
+            asset_id: utxo.asset_id,
+            value: utxo.value,
+        });
+        selected_sat += utxo.value;
+        let network_fee = TxFee {
+            server_inputs: 1,
+            user_inputs: selected.len(),
+            outputs: 2,
+        }
+        .fee();
+        if selected_sat >= amount_sat + network_fee {
+            return Ok((selected, network_fee));
+        }
+    }
+    Err(PayjoinError::InsufficientFunds)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use lwk_wollet::elements::{
+        confidential::{AssetBlindingFactor, ValueBlindingFactor},
+        AssetId, Script, Txid,
+    };
+
+    use super::{payjoin_endpoint, select_wallet_inputs};
+    use crate::payjoin::{error::PayjoinError, model::Utxo};
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_payjoin_endpoint() {
+        assert_eq!(
+            payjoin_endpoint(
+                "liquidnetwork:lq1addr?amount=0.0001&pj=https%3A%2F%2Fexample.com%2Fpayjoin"
+            ),
+            Some("https://example.com/payjoin".to_string())
+        );
+        assert_eq!(
+            payjoin_endpoint("liquidnetwork:lq1addr?amount=0.0001"),
+            None
+        );
+        assert_eq!(
+            payjoin_endpoint("liquidnetwork:lq1addr?pj=http://example.com"),
+            None
+        );
+        assert_eq!(payjoin_endpoint("lq1addr"), None);
+    }
+
+    #[sdk_macros::test_all]
+    fn test_select_wallet_inputs() -> Result<()> {
+        let utxo = |value| Utxo {
+            txid: Txid::from_str(
+                "0000000000000000000000000000000000000000000000000000000000000001",
+            )
+            .unwrap(),
+            vout: 0,
+            script_pub_key: Script::new(),
+            asset_id: AssetId::from_slice(&[1; 32]).unwrap(),
+            value,
+            asset_bf: AssetBlindingFactor::zero(),
+            value_bf: ValueBlindingFactor::zero(),
+        };
+        let utxos = vec![utxo(10_000), utxo(5_000)];
+
+        let (inputs, network_fee) = select_wallet_inputs(&utxos, 5_000)?;
+        assert_eq!(inputs.len(), 1);
+        assert!(network_fee > 0);
+
+        let (inputs, _) = select_wallet_inputs(&utxos, 10_000)?;
+        assert_eq!(inputs.len(), 2);
+
+        assert!(matches!(
+            select_wallet_inputs(&utxos, 15_000),
+            Err(PayjoinError::InsufficientFunds)
+        ));
+        Ok(())
+    }
+}
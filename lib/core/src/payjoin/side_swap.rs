@@ -3,8 +3,8 @@ use std::{collections::HashMap, str::FromStr};
 use super::{
     error::{PayjoinError, PayjoinResult},
     model::{
-        AcceptedAsset, AcceptedAssetsRequest, AcceptedAssetsResponse, Request, Response,
-        SignRequest, StartRequest, Utxo,
+        AcceptedAsset, AcceptedAssetsRequest, AcceptedAssetsResponse, PayjoinFeeEstimate, Request,
+        Response, SignRequest, StartRequest, Utxo,
     },
     pset::PsetInput,
     utxo_select::utxo_select,
@@ -38,7 +38,7 @@ use crate::payjoin::{
 };
 use crate::persist::Persister;
 use crate::{
-    model::{Config, LiquidNetwork},
+    model::{AssetMetadata, Config, LiquidNetwork},
     payjoin::pset::{construct_pset, ConstructPsetRequest, PsetOutput},
 };
 use crate::{utils, wallet::OnchainWallet};
@@ -125,7 +125,11 @@ impl PayjoinService for SideSwapPayjoinService {
         Ok(accepted_assets.accepted_asset.clone())
     }
 
-    async fn estimate_payjoin_tx_fee(&self, asset_id: &str, amount_sat: u64) -> PayjoinResult<f64> {
+    async fn estimate_payjoin_tx_fee(
+        &self,
+        asset_id: &str,
+        amount_sat: u64,
+    ) -> PayjoinResult<PayjoinFeeEstimate> {
         // Check the asset is accepted
         let fee_asset = AssetId::from_str(asset_id)?;
         let accepted_assets = self.fetch_accepted_assets().await?;
@@ -175,7 +179,6 @@ impl PayjoinService for SideSwapPayjoinService {
                 "No rate available for {fiat_id}"
             )))?;
 
-        let fixed_fee = (SIDESWAP_BASE_USD_FEE_SAT / usd_index_price * asset_index_price) as u64;
         // Fees assuming we have:
         // - 1 input for the server (lbtc)
         // - 1 input for the user (asset)
@@ -189,19 +192,23 @@ impl PayjoinService for SideSwapPayjoinService {
             outputs: 4,
         }
         .fee();
-        let fee_sat = (network_fee as f64 * asset_index_price) as u64 + fixed_fee;
+        let (estimate, fee_sat) = estimate_fee(
+            &asset_metadata,
+            network_fee,
+            usd_index_price,
+            asset_index_price,
+        );
         ensure_sdk!(
             wallet_asset_balance >= amount_sat + fee_sat,
             PayjoinError::InsufficientFunds
         );
 
-        // The estimation accuracy gives a fee to two decimal places
-        let mut fee = asset_metadata.amount_from_sat(fee_sat);
-        fee = (fee * 100.0).ceil() / 100.0;
-
-        debug!("Estimated payjoin server fee: {fee} ({fee_sat} satoshi units)");
+        debug!(
+            "Estimated payjoin server fee: {} ({fee_sat} satoshi units)",
+            estimate.asset_fee
+        );
 
-        Ok(fee)
+        Ok(estimate)
     }
 
     async fn build_payjoin_tx(
@@ -397,7 +404,33 @@ impl From<&Utxo> for InOut {
     }
 }
 
-fn copy_signatures(
+/// Estimates the payjoin fee from the `network_fee_sat` and the fiat index prices of USD and of
+/// the asset's fiat currency. Returns the estimate and its total fee in the asset's base units.
+fn estimate_fee(
+    asset_metadata: &AssetMetadata,
+    network_fee_sat: u64,
+    usd_index_price: f64,
+    asset_index_price: f64,
+) -> (PayjoinFeeEstimate, u64) {
+    let service_fee_sat = SIDESWAP_BASE_USD_FEE_SAT / usd_index_price;
+    let fixed_fee = (service_fee_sat * asset_index_price) as u64;
+    let fee_sat = (network_fee_sat as f64 * asset_index_price) as u64 + fixed_fee;
+
+    // The estimation accuracy gives a fee to two decimal places
+    let mut asset_fee = asset_metadata.amount_from_sat(fee_sat);
+    asset_fee = (asset_fee * 100.0).ceil() / 100.0;
+
+    (
+        PayjoinFeeEstimate {
+            asset_fee,
+            service_fee_sat: service_fee_sat as u64,
+            network_fee_sat,
+        },
+        fee_sat,
+    )
+}
+
+pub(crate) fn copy_signatures(
     mut dst_pset: PartiallySignedTransaction,
     src_pset: PartiallySignedTransaction,
 ) -> PayjoinResult<PartiallySignedTransaction> {
@@ -422,7 +455,10 @@ fn copy_signatures(
     Ok(dst_pset)
 }
 
-fn select_utxos(mut utxos: Vec<Utxo>, in_outs: Vec<InOut>) -> PayjoinResult<Vec<PsetInput>> {
+pub(crate) fn select_utxos(
+    mut utxos: Vec<Utxo>,
+    in_outs: Vec<InOut>,
+) -> PayjoinResult<Vec<PsetInput>> {
     let secp = Secp256k1::new();
     let mut selected = Vec::new();
     for in_out in in_outs {
@@ -572,6 +608,28 @@ mod tests {
         Ok(())
     }
 
+    #[sdk_macros::test_all]
+    fn test_estimate_fee() {
+        let usdt = AssetMetadata {
+            asset_id: "usdt".to_string(),
+            name: "Tether USD".to_string(),
+            ticker: "USDt".to_string(),
+            precision: 8,
+            fiat_id: Some("USD".to_string()),
+            is_amp: false,
+        };
+        let (estimate, fee_sat) = estimate_fee(&usdt, 50, 100_000.0, 100_000.0);
+        assert_eq!(estimate.service_fee_sat, 40);
+        assert_eq!(estimate.network_fee_sat, 50);
+        assert_eq!(fee_sat, 9_000_000);
+        assert_eq!(estimate.asset_fee, 0.09);
+
+        // The asset fee is rounded up to two decimal places
+        let (estimate, fee_sat) = estimate_fee(&usdt, 51, 100_000.0, 100_000.0);
+        assert_eq!(fee_sat, 9_100_000);
+        assert_eq!(estimate.asset_fee, 0.1);
+    }
+
     #[sdk_macros::async_test_all]
     async fn test_estimate_payjoin_tx_fee_error() -> Result<()> {
         create_persister!(persister);
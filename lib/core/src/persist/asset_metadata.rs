@@ -13,8 +13,8 @@ impl Persister {
         if let Some(asset_metadata) = asset_metadata {
             for am in asset_metadata {
                 con.execute(
-                    "INSERT INTO asset_metadata (asset_id, name, ticker, precision, fiat_id) VALUES (?, ?, ?, ?, ?)",
-                    (am.asset_id, am.name, am.ticker, am.precision, am.fiat_id),
+                    "INSERT INTO asset_metadata (asset_id, name, ticker, precision, fiat_id, is_amp) VALUES (?, ?, ?, ?, ?, ?)",
+                    (am.asset_id, am.name, am.ticker, am.precision, am.fiat_id, am.is_amp),
                 )?;
             }
         }
@@ -29,7 +29,8 @@ impl Persister {
             name, 
             ticker, 
             precision, 
-            fiat_id
+            fiat_id,
+            is_amp
         FROM asset_metadata",
         )?;
         let asset_metadata: Vec<AssetMetadata> = stmt
@@ -46,7 +47,8 @@ impl Persister {
             name, 
             ticker, 
             precision, 
-            fiat_id 
+            fiat_id,
+            is_amp
         FROM asset_metadata
         WHERE asset_id = ?",
         )?;
@@ -62,6 +64,7 @@ impl Persister {
             ticker: row.get(2)?,
             precision: row.get(3)?,
             fiat_id: row.get(4)?,
+            is_amp: row.get(5)?,
         })
     }
 }
@@ -0,0 +1,89 @@
+use anyhow::Result;
+use rusqlite::{params, OptionalExtension};
+
+use super::Persister;
+use crate::model::RegisteredAsset;
+
+impl Persister {
+    /// Caches the metadata of an asset fetched from the asset registry
+    pub(crate) fn insert_registered_asset(&self, asset: &RegisteredAsset) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT OR REPLACE INTO registered_assets (
+                asset_id,
+                name,
+                ticker,
+                precision,
+                domain,
+                icon_url
+            )
+            VALUES (?, ?, ?, ?, ?, ?)",
+            params![
+                asset.asset_id,
+                asset.name,
+                asset.ticker,
+                asset.precision,
+                asset.domain,
+                asset.icon_url
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    pub(crate) fn get_registered_asset(&self, asset_id: &str) -> Result<Option<RegisteredAsset>> {
+        let con = self.get_connection()?;
+        let asset = con
+            .query_row(
+                "SELECT asset_id, name, ticker, precision, domain, icon_url
+                FROM registered_assets
+                WHERE asset_id = ?",
+                [asset_id],
+                |row| {
+                    Ok(RegisteredAsset {
+                        asset_id: row.get(0)?,
+                        name: row.get(1)?,
+                        ticker: row.get(2)?,
+                        precision: row.get(3)?,
+                        domain: row.get(4)?,
+                        icon_url: row.get(5)?,
+                    })
+                },
+            )
+            .optional()?;
+
+        Ok(asset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::RegisteredAsset;
+    use crate::test_utils::persist::create_persister;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_registered_asset() -> Result<()> {
+        create_persister!(storage);
+
+        let asset = RegisteredAsset {
+            asset_id: "ce091c998b83c78bb71a632313ba3760f1763d9cfcffae02258ffa9865a37bd2"
+                .to_string(),
+            name: "Tether USD".to_string(),
+            ticker: Some("USDt".to_string()),
+            precision: 8,
+            domain: Some("tether.to".to_string()),
+            icon_url: "https://liquid.network/api/v1/asset/ce09/icon".to_string(),
+        };
+        assert!(storage.get_registered_asset(&asset.asset_id)?.is_none());
+
+        storage.insert_registered_asset(&asset)?;
+        assert_eq!(storage.get_registered_asset(&asset.asset_id)?, Some(asset));
+
+        Ok(())
+    }
+}
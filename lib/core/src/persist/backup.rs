@@ -1,10 +1,30 @@
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use lwk_wollet::hashes::{sha256, Hash};
 use rusqlite::{backup::Backup, Connection};
 
-use super::Persister;
-use crate::model::LiquidNetwork;
+use super::{migrations::current_migrations, Persister};
+use crate::{
+    ensure_sdk,
+    error::{SdkError, SdkResult},
+    model::LiquidNetwork,
+};
+
+/// The prefix of the uniquely named temporary files holding the database copied from or
+/// restored into a backup
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+const BACKUP_DB_FILE_PREFIX: &str = "backup-db-";
+
+/// Identifies the backup files, which start with a header made of this magic, the schema
+/// version of the database (4 bytes, little endian) and the SHA-256 of the database
+const BACKUP_MAGIC: &[u8] = b"BREEZLQB";
+const BACKUP_HEADER_LEN: usize = BACKUP_MAGIC.len() + 4 + 32;
+/// The header of an SQLite database, with which the backups created before the backup header
+/// was introduced start
+const SQLITE_MAGIC: &[u8] = b"SQLite format 3\0";
 
 impl Persister {
     pub(crate) fn get_default_backup_path(&self) -> PathBuf {
@@ -15,17 +35,182 @@ impl Persister {
         })
     }
 
+    /// The schema version of the database, i.e. the number of migrations applied to it
+    fn schema_version(&self) -> u32 {
+        current_migrations(self.network).len() as u32
+    }
+
+    /// Backs up the database to the given path, along with its schema version and checksum.
+    ///
+    /// The backup is written to a uniquely named temporary file which is then renamed to the
+    /// given path, so concurrent backups don't interfere and the file is never partially written.
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub(crate) fn backup(&self, backup_path: PathBuf) -> Result<()> {
+        let backup = self.encode_backup(&self.copy_db()?);
+        let backup_dir = match backup_path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let mut backup_file = tempfile::NamedTempFile::new_in(backup_dir)?;
+        backup_file.write_all(&backup)?;
+        backup_file.as_file().sync_all()?;
+        backup_file.persist(backup_path)?;
+        Ok(())
+    }
+
+    /// Copies the database through a uniquely named temporary file
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    fn copy_db(&self) -> Result<Vec<u8>> {
+        let db_file = tempfile::Builder::new()
+            .prefix(BACKUP_DB_FILE_PREFIX)
+            .tempfile_in(&self.main_db_dir)?;
+        let con = self.get_connection()?;
+        con.backup(rusqlite::DatabaseName::Main, db_file.path(), None)?;
+        Ok(std::fs::read(db_file.path())?)
+    }
+
+    /// Backs up the database to the given path of the database VFS. Such backups have no
+    /// header, as they can't be written as plain files.
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
     pub(crate) fn backup(&self, backup_path: PathBuf) -> Result<()> {
         let con = self.get_connection()?;
         con.backup(rusqlite::DatabaseName::Main, backup_path, None)?;
         Ok(())
     }
 
-    pub(crate) fn restore_from_backup<P>(&self, backup_path: P) -> Result<()>
+    fn encode_backup(&self, db: &[u8]) -> Vec<u8> {
+        let mut backup = Vec::with_capacity(BACKUP_HEADER_LEN + db.len());
+        backup.extend_from_slice(BACKUP_MAGIC);
+        backup.extend_from_slice(&self.schema_version().to_le_bytes());
+        backup.extend_from_slice(sha256::Hash::hash(db).as_ref());
+        backup.extend_from_slice(db);
+        backup
+    }
+
+    /// Checks that a backup is complete and was created by a compatible SDK version, returning
+    /// the database it contains. The backups created before the backup header was introduced
+    /// have no checksum, so they are returned as is.
+    pub(crate) fn verify_backup<'a>(&self, backup: &'a [u8]) -> SdkResult<&'a [u8]> {
+        let invalid_backup = |err: String| SdkError::InvalidBackup { err };
+
+        if backup.starts_with(SQLITE_MAGIC) {
+            return Ok(backup);
+        }
+        ensure_sdk!(
+            backup.starts_with(BACKUP_MAGIC),
+            invalid_backup("Not a backup file".to_string())
+        );
+        ensure_sdk!(
+            backup.len() >= BACKUP_HEADER_LEN,
+            invalid_backup("Backup file is truncated".to_string())
+        );
+
+        let (header, db) = backup.split_at(BACKUP_HEADER_LEN);
+        let (schema_version, checksum) = header[BACKUP_MAGIC.len()..].split_at(4);
+        let schema_version = u32::from_le_bytes(schema_version.try_into().expect("4 bytes"));
+        ensure_sdk!(
+            sha256::Hash::hash(db).as_ref() == checksum,
+            invalid_backup("Backup file is truncated or corrupted".to_string())
+        );
+        ensure_sdk!(
+            schema_version <= self.schema_version(),
+            invalid_backup(format!(
+                "Backup schema version {schema_version} is newer than the supported version {}",
+                self.schema_version()
+            ))
+        );
+        ensure_sdk!(
+            db.starts_with(SQLITE_MAGIC),
+            invalid_backup("Backup does not contain a database".to_string())
+        );
+        Ok(db)
+    }
+
+    /// Checks the backup file at the given path without restoring it. See [Persister::verify_backup].
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub(crate) fn verify_backup_file<P>(&self, backup_path: P) -> SdkResult<()>
+    where
+        P: AsRef<Path>,
+    {
+        let backup = std::fs::read(backup_path).map_err(|e| SdkError::InvalidBackup {
+            err: format!("Failed to read backup file: {e}"),
+        })?;
+        self.verify_backup(&backup)?;
+        Ok(())
+    }
+
+    /// Serializes the database, e.g. to upload it with a [BackupTransport](crate::model::BackupTransport)
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub(crate) fn backup_bytes(&self) -> Result<Vec<u8>> {
+        Ok(self.encode_backup(&self.copy_db()?))
+    }
+
+    /// Serializes the database, e.g. to upload it with a [BackupTransport](crate::model::BackupTransport)
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+    pub(crate) fn backup_bytes(&self) -> Result<Vec<u8>> {
+        Ok(self.encode_backup(&self.serialize()?))
+    }
+
+    /// Restores the database from a backup serialized with [Persister::backup_bytes]. The backup
+    /// is verified first, so that an invalid backup leaves the database untouched.
+    pub(crate) fn restore_from_bytes(&self, bytes: &[u8]) -> SdkResult<()> {
+        let db = self.verify_backup(bytes)?;
+        Ok(self.restore_from_db(db)?)
+    }
+
+    /// Restores the database from a backup file. The backup is verified first, so that an
+    /// invalid backup leaves the database untouched.
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub(crate) fn restore_from_backup<P>(&self, backup_path: P) -> SdkResult<()>
     where
         P: AsRef<Path>,
     {
-        let src_con = Connection::open(backup_path)?;
+        let backup = std::fs::read(backup_path).map_err(|e| SdkError::InvalidBackup {
+            err: format!("Failed to read backup file: {e}"),
+        })?;
+        self.restore_from_bytes(&backup)
+    }
+
+    /// Restores the database from a backup made with [Persister::backup] in the database VFS
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+    pub(crate) fn restore_from_backup<P>(&self, backup_path: P) -> SdkResult<()>
+    where
+        P: AsRef<Path>,
+    {
+        let restore = || {
+            let src_con = Connection::open(backup_path)?;
+            let mut dst_con = self.get_connection()?;
+
+            let backup = Backup::new(&src_con, &mut dst_con)?;
+            backup.run_to_completion(5, std::time::Duration::from_millis(250), None)?;
+            Ok::<(), anyhow::Error>(())
+        };
+        Ok(restore()?)
+    }
+
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    fn restore_from_db(&self, db: &[u8]) -> Result<()> {
+        let mut db_file = tempfile::Builder::new()
+            .prefix(BACKUP_DB_FILE_PREFIX)
+            .tempfile_in(&self.main_db_dir)?;
+        db_file.write_all(db)?;
+        let src_con = Connection::open(db_file.path())?;
+        let mut dst_con = self.get_connection()?;
+
+        let backup = Backup::new(&src_con, &mut dst_con)?;
+        backup.run_to_completion(5, std::time::Duration::from_millis(250), None)?;
+        Ok(())
+    }
+
+    #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+    fn restore_from_db(&self, db: &[u8]) -> Result<()> {
+        let mut src_con = Connection::open_in_memory()?;
+        src_con.deserialize_read_exact(
+            rusqlite::DatabaseName::Main,
+            std::io::Cursor::new(db),
+            db.len(),
+            false,
+        )?;
         let mut dst_con = self.get_connection()?;
 
         let backup = Backup::new(&src_con, &mut dst_con)?;
@@ -40,7 +225,9 @@ impl Persister {
 mod tests {
     use anyhow::Result;
 
+    use super::{BACKUP_DB_FILE_PREFIX, BACKUP_MAGIC};
     use crate::{
+        error::SdkError,
         model::PaymentState,
         test_utils::persist::{create_persister, new_receive_swap, new_send_swap},
     };
@@ -65,4 +252,91 @@ mod tests {
 
         Ok(())
     }
+
+    #[sdk_macros::test_not_wasm]
+    fn test_concurrent_backups() -> Result<()> {
+        create_persister!(local);
+
+        local.insert_or_update_send_swap(&new_send_swap(Some(PaymentState::Pending), None))?;
+        let backup_path = local.get_default_backup_path();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| local.backup(backup_path.clone()).unwrap());
+                scope.spawn(|| local.backup_bytes().unwrap());
+            }
+        });
+        local.verify_backup_file(&backup_path)?;
+
+        // No temporary files are left behind
+        let backup_dir = backup_path.parent().unwrap();
+        for entry in std::fs::read_dir(backup_dir)? {
+            let file_name = entry?.file_name();
+            let file_name = file_name.to_string_lossy();
+            assert!(!file_name.starts_with(BACKUP_DB_FILE_PREFIX));
+            assert!(!file_name.starts_with(".tmp"));
+        }
+
+        Ok(())
+    }
+
+    #[sdk_macros::test_not_wasm]
+    fn test_backup_and_restore_bytes() -> Result<()> {
+        create_persister!(local);
+
+        local.insert_or_update_send_swap(&new_send_swap(Some(PaymentState::Pending), None))?;
+        let bytes = local.backup_bytes()?;
+        assert!(!bytes.is_empty());
+
+        create_persister!(remote);
+
+        remote.restore_from_bytes(&bytes)?;
+        assert_eq!(remote.list_ongoing_swaps()?.len(), 1);
+
+        Ok(())
+    }
+
+    #[sdk_macros::test_not_wasm]
+    fn test_restore_rejects_invalid_backup() -> Result<()> {
+        create_persister!(local);
+
+        local.insert_or_update_send_swap(&new_send_swap(Some(PaymentState::Pending), None))?;
+        let backup_path = local.get_default_backup_path();
+        local.backup(backup_path.clone())?;
+        local.verify_backup_file(&backup_path)?;
+        let backup = std::fs::read(&backup_path)?;
+
+        create_persister!(remote);
+
+        // Truncated backup
+        let truncated = &backup[..backup.len() / 2];
+        assert!(matches!(
+            remote.restore_from_bytes(truncated),
+            Err(SdkError::InvalidBackup { .. })
+        ));
+
+        // Corrupted backup
+        let mut corrupted = backup.clone();
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 0xff;
+        assert!(matches!(
+            remote.restore_from_bytes(&corrupted),
+            Err(SdkError::InvalidBackup { .. })
+        ));
+
+        // Backup from a newer schema version
+        let mut newer = backup.clone();
+        newer[BACKUP_MAGIC.len()..BACKUP_MAGIC.len() + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            remote.verify_backup(&newer),
+            Err(SdkError::InvalidBackup { .. })
+        ));
+
+        // The database is left untouched
+        assert!(remote.list_ongoing_swaps()?.is_empty());
+
+        remote.restore_from_bytes(&backup)?;
+        assert_eq!(remote.list_ongoing_swaps()?.len(), 1);
+
+        Ok(())
+    }
 }
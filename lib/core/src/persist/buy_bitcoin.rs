@@ -0,0 +1,70 @@
+use anyhow::Result;
+use rusqlite::params;
+
+use super::Persister;
+use crate::model::BuyBitcoinProvider;
+use crate::utils;
+
+impl Persister {
+    /// Records the provider the Bitcoin of an incoming chain swap is bought from
+    pub(crate) fn insert_buy_bitcoin_swap(
+        &self,
+        swap_id: &str,
+        provider: BuyBitcoinProvider,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO buy_bitcoin_swaps (swap_id, provider, created_at)
+            VALUES (?, ?, ?)",
+            params![swap_id, provider.to_string(), utils::now()],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::{
+        BuyBitcoinProvider, Direction, GetPaymentRequest, PaymentDetails, PaymentState,
+    };
+    use crate::test_utils::chain_swap::new_chain_swap;
+    use crate::test_utils::persist::create_persister;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_buy_bitcoin_swap() -> Result<()> {
+        create_persister!(storage);
+
+        let chain_swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::Pending),
+            false,
+            Some("user-lockup-tx-id".to_string()),
+            false,
+            false,
+            None,
+        );
+        storage.insert_or_update_chain_swap(&chain_swap)?;
+        storage.insert_buy_bitcoin_swap(&chain_swap.id, BuyBitcoinProvider::Moonpay)?;
+
+        let payment = storage
+            .get_payment_by_request(&GetPaymentRequest::SwapId {
+                swap_id: chain_swap.id.clone(),
+            })?
+            .unwrap();
+        assert!(matches!(
+            payment.details,
+            PaymentDetails::Bitcoin {
+                buy_bitcoin_provider: Some(BuyBitcoinProvider::Moonpay),
+                ..
+            }
+        ));
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 use rusqlite::{OptionalExtension, Transaction, TransactionBehavior};
 use std::str::FromStr;
 
-use crate::model::GetInfoResponse;
+use crate::model::{AmpAccount, GetInfoResponse};
 use crate::sync::model::{data::LAST_DERIVATION_INDEX_DATA_ID, RecordType};
 
 use super::{BlockchainInfo, Persister, WalletInfo};
@@ -14,9 +14,13 @@ const KEY_IS_FIRST_SYNC_COMPLETE: &str = "is_first_sync_complete";
 const KEY_WEBHOOK_URL: &str = "webhook_url";
 pub(crate) const KEY_LAST_DERIVATION_INDEX: &str = "last_derivation_index";
 const KEY_LAST_SCANNED_DERIVATION_INDEX: &str = "last_scanned_derivation_index";
+const KEY_LAST_DISPATCHED_EVENT_SEQ: &str = "last_dispatched_event_seq";
+const KEY_NWC_SECRET_KEY: &str = "nwc_secret_key";
+const KEY_REUSABLE_BITCOIN_ADDRESS: &str = "reusable_bitcoin_address";
+const KEY_AMP_ACCOUNT: &str = "amp_account";
 
 impl Persister {
-    fn get_cached_item_inner(tx: &Transaction, key: &str) -> Result<Option<String>> {
+    pub(crate) fn get_cached_item_inner(tx: &Transaction, key: &str) -> Result<Option<String>> {
         let res = tx.query_row(
             "SELECT value FROM cached_items WHERE key = ?1",
             [key],
@@ -200,12 +204,49 @@ impl Persister {
         self.get_cached_item(KEY_LAST_SCANNED_DERIVATION_INDEX)
             .map(|maybe_str| maybe_str.and_then(|str| str.as_str().parse::<u32>().ok()))
     }
+
+    pub(crate) fn set_last_dispatched_event_seq(&self, seq: u64) -> Result<()> {
+        self.update_cached_item(KEY_LAST_DISPATCHED_EVENT_SEQ, seq.to_string())
+    }
+
+    pub(crate) fn get_last_dispatched_event_seq(&self) -> Result<Option<u64>> {
+        self.get_cached_item(KEY_LAST_DISPATCHED_EVENT_SEQ)
+            .map(|maybe_str| maybe_str.and_then(|str| str.as_str().parse::<u64>().ok()))
+    }
+
+    pub(crate) fn set_nwc_secret_key(&self, secret_key: String) -> Result<()> {
+        self.update_cached_item(KEY_NWC_SECRET_KEY, secret_key)
+    }
+
+    pub(crate) fn get_nwc_secret_key(&self) -> Result<Option<String>> {
+        self.get_cached_item(KEY_NWC_SECRET_KEY)
+    }
+
+    pub(crate) fn set_reusable_bitcoin_address(&self, address: String) -> Result<()> {
+        self.update_cached_item(KEY_REUSABLE_BITCOIN_ADDRESS, address)
+    }
+
+    pub(crate) fn get_reusable_bitcoin_address(&self) -> Result<Option<String>> {
+        self.get_cached_item(KEY_REUSABLE_BITCOIN_ADDRESS)
+    }
+
+    pub(crate) fn set_amp_account(&self, account: &AmpAccount) -> Result<()> {
+        let serialized_account = serde_json::to_string(account)?;
+        self.update_cached_item(KEY_AMP_ACCOUNT, serialized_account)
+    }
+
+    pub(crate) fn get_amp_account(&self) -> Result<Option<AmpAccount>> {
+        self.get_cached_item(KEY_AMP_ACCOUNT)?
+            .map(|account| serde_json::from_str(&account).map_err(Into::into))
+            .transpose()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
 
+    use crate::model::AmpAccount;
     use crate::test_utils::persist::create_persister;
 
     #[cfg(feature = "browser-tests")]
@@ -279,6 +320,21 @@ mod tests {
         assert!(maybe_last_index.is_some());
         assert_eq!(maybe_last_index, Some(54));
 
+        Ok(())
+    }
+    #[sdk_macros::test_all]
+    fn test_amp_account() -> Result<()> {
+        create_persister!(persister);
+
+        assert!(persister.get_amp_account()?.is_none());
+
+        let account = AmpAccount {
+            amp_id: "GA3DS3emT12zDF4RGywBvJqZfhefNp".to_string(),
+            descriptor: "ct(slip77(00),elwsh(multi(2,xpub1,xpub2)))".to_string(),
+        };
+        persister.set_amp_account(&account)?;
+        assert_eq!(persister.get_amp_account()?, Some(account));
+
         Ok(())
     }
 }
@@ -162,6 +162,7 @@ impl Persister {
                 auto_accepted_fees,
                 version,
                 last_updated_at,
+                refundable_since,
 
                 sync_state.is_local
             FROM chain_swaps
@@ -218,10 +219,11 @@ impl Persister {
             actual_payer_amount_sat: row.get(22)?,
             accepted_receiver_amount_sat: row.get(23)?,
             auto_accepted_fees: row.get(24)?,
+            refundable_since: row.get(27)?,
             metadata: SwapMetadata {
                 version: row.get(25)?,
                 last_updated_at: row.get(26)?,
-                is_local: row.get::<usize, Option<bool>>(27)?.unwrap_or(true),
+                is_local: row.get::<usize, Option<bool>>(28)?.unwrap_or(true),
             },
         })
     }
@@ -528,7 +530,7 @@ impl InternalCreateChainResponse {
 
 #[cfg(test)]
 mod tests {
-    use crate::model::Direction;
+    use crate::model::{ChainSwapUpdate, Direction, PaymentState};
     use crate::sync::model::SyncState;
     use crate::test_utils::chain_swap::new_chain_swap;
     use crate::test_utils::persist::create_persister;
@@ -605,4 +607,70 @@ mod tests {
 
         Ok(())
     }
+
+    #[sdk_macros::async_test_all]
+    async fn test_refundable_since() -> Result<()> {
+        create_persister!(storage);
+
+        let chain_swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::Pending),
+            false,
+            None,
+            false,
+            false,
+            None,
+        );
+        storage.insert_or_update_chain_swap(&chain_swap)?;
+        let update_state = |to_state| {
+            storage.try_handle_chain_swap_update(&ChainSwapUpdate {
+                swap_id: chain_swap.id.clone(),
+                to_state,
+                server_lockup_tx_id: None,
+                user_lockup_tx_id: None,
+                claim_address: None,
+                claim_tx_id: None,
+                refund_tx_id: None,
+            })
+        };
+        let fetch_refundable_since = || -> Result<Option<u32>> {
+            Ok(storage
+                .fetch_chain_swap_by_id(&chain_swap.id)?
+                .unwrap()
+                .refundable_since)
+        };
+        assert_eq!(fetch_refundable_since()?, None);
+
+        // Set when the swap becomes refundable, and kept while a refund is pending
+        update_state(PaymentState::Refundable)?;
+        let refundable_since = fetch_refundable_since()?;
+        assert!(refundable_since.is_some());
+        update_state(PaymentState::RefundPending)?;
+        assert_eq!(fetch_refundable_since()?, refundable_since);
+        update_state(PaymentState::Refundable)?;
+        assert_eq!(fetch_refundable_since()?, refundable_since);
+
+        // Unset once the swap is no longer refundable
+        update_state(PaymentState::Failed)?;
+        assert_eq!(fetch_refundable_since()?, None);
+
+        // Set when a swap is inserted as refundable, e.g. when recovered
+        let recovered_swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::Refundable),
+            false,
+            None,
+            false,
+            false,
+            None,
+        );
+        storage.insert_or_update_chain_swap(&recovered_swap)?;
+        assert!(storage
+            .fetch_chain_swap_by_id(&recovered_swap.id)?
+            .unwrap()
+            .refundable_since
+            .is_some());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,156 @@
+use anyhow::Result;
+use rusqlite::{params, OptionalExtension};
+
+use super::Persister;
+use crate::model::{AssetConversion, Payment};
+use crate::utils;
+
+impl Persister {
+    /// Records the pair of asset amounts exchanged by the conversion tx
+    pub(crate) fn insert_asset_conversion(
+        &self,
+        tx_id: &str,
+        conversion: &AssetConversion,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT OR REPLACE INTO asset_conversions (
+                tx_id,
+                from_asset_id,
+                from_amount,
+                to_asset_id,
+                to_amount,
+                created_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?)",
+            params![
+                tx_id,
+                conversion.from_asset_id,
+                conversion.from_amount,
+                conversion.to_asset_id,
+                conversion.to_amount,
+                utils::now()
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    pub(crate) fn fetch_asset_conversion(&self, tx_id: &str) -> Result<Option<AssetConversion>> {
+        let con = self.get_connection()?;
+        Ok(con
+            .query_row(
+                "SELECT from_asset_id, from_amount, to_asset_id, to_amount
+                FROM asset_conversions
+                WHERE tx_id = ?",
+                [tx_id],
+                |row| {
+                    Ok(AssetConversion {
+                        from_asset_id: row.get(0)?,
+                        from_amount: row.get(1)?,
+                        to_asset_id: row.get(2)?,
+                        to_amount: row.get(3)?,
+                    })
+                },
+            )
+            .optional()?)
+    }
+
+    /// Fetches both payments of the conversion tx, one per asset exchanged
+    pub(crate) fn get_conversion_payments(&self, tx_id: &str) -> Result<Vec<Payment>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(&self.select_payment_query(
+            Some("ptx.tx_id = ?1 AND ac.tx_id IS NOT NULL"),
+            None,
+            None,
+            None,
+        ))?;
+        let payments = stmt
+            .query_map([tx_id], |row| self.sql_row_to_payment(row))?
+            .collect::<Result<Vec<Payment>, _>>()?;
+        Ok(payments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::{
+        model::{AssetConversion, LiquidNetwork, ListPaymentsRequest, PaymentDetails, PaymentType},
+        test_utils::persist::{create_persister, new_payment_tx_data},
+        utils,
+    };
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_asset_conversions() -> Result<()> {
+        create_persister!(storage);
+
+        assert!(storage.fetch_asset_conversion("tx-id")?.is_none());
+
+        let conversion = AssetConversion {
+            from_asset_id: "lbtc".to_string(),
+            from_amount: 10_000,
+            to_asset_id: "usdt".to_string(),
+            to_amount: 1_000_000,
+        };
+        storage.insert_asset_conversion("tx-id", &conversion)?;
+        assert_eq!(storage.fetch_asset_conversion("tx-id")?, Some(conversion));
+
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_conversion_payments() -> Result<()> {
+        create_persister!(storage);
+
+        let lbtc_asset_id = utils::lbtc_asset_id(LiquidNetwork::Testnet).to_string();
+        let conversion = AssetConversion {
+            from_asset_id: lbtc_asset_id.clone(),
+            from_amount: 10_000,
+            to_asset_id: "usdt".to_string(),
+            to_amount: 1_000_000,
+        };
+        let mut tx_data = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Receive);
+        tx_data.asset_id = "usdt".to_string();
+        tx_data.amount = 1_000_000;
+        tx_data.fees_sat = 30;
+        let tx_id = tx_data.tx_id.clone();
+        storage.insert_or_update_payment(tx_data, None, false)?;
+        storage.insert_asset_conversion(&tx_id, &conversion)?;
+
+        // The conversion is listed as a pair of payments, one per asset
+        let payments = storage.get_conversion_payments(&tx_id)?;
+        assert_eq!(payments.len(), 2);
+        let asset_id_of = |payment: &crate::model::Payment| match &payment.details {
+            PaymentDetails::Liquid { asset_id, .. } => asset_id.clone(),
+            _ => panic!("Unexpected payment details"),
+        };
+        let received = payments
+            .iter()
+            .find(|p| p.payment_type == PaymentType::Receive)
+            .unwrap();
+        assert_eq!(asset_id_of(received), "usdt");
+        assert_eq!(received.amount_sat, 1_000_000);
+        let sent = payments
+            .iter()
+            .find(|p| p.payment_type == PaymentType::Send)
+            .unwrap();
+        assert_eq!(asset_id_of(sent), lbtc_asset_id);
+        assert_eq!(sent.amount_sat, 10_000);
+        assert_eq!(sent.fees_sat, 0);
+        assert_eq!(
+            storage.get_payments(&ListPaymentsRequest::default())?.len(),
+            2
+        );
+
+        // Fetching the payment by tx id returns the asset one only
+        let payment = storage.get_payment(&tx_id)?.unwrap();
+        assert_eq!(asset_id_of(&payment), "usdt");
+
+        Ok(())
+    }
+}
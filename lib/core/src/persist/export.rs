@@ -0,0 +1,218 @@
+use anyhow::{ensure, Result};
+use rusqlite::{Transaction, TransactionBehavior};
+use serde::{Deserialize, Serialize};
+
+use super::{cache::KEY_LAST_DERIVATION_INDEX, Persister};
+use crate::sync::model::{data::SyncData, RecordType};
+
+/// The version of the [WalletExport] format, increased on breaking changes
+const WALLET_EXPORT_VERSION: u32 = 1;
+
+/// A self-contained export of the wallet, created with
+/// [LiquidSdk::export_wallet](crate::sdk::LiquidSdk::export_wallet).
+///
+/// Besides the wallet descriptor, it contains the swaps (including their keys and scripts) and
+/// the payment metadata, in the same format used by the real-time sync. The onchain data is not
+/// included, as it's fetched again from the chain once imported.
+#[derive(Serialize, Deserialize)]
+pub(crate) struct WalletExport {
+    pub(crate) version: u32,
+    pub(crate) network: String,
+    pub(crate) fingerprint: String,
+    pub(crate) descriptor: String,
+    pub(crate) records: Vec<SyncData>,
+}
+
+impl WalletExport {
+    pub(crate) fn to_bytes(&self) -> serde_json::Result<Vec<u8>> {
+        serde_json::to_vec_pretty(self)
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let export: Self = serde_json::from_slice(bytes)?;
+        ensure!(
+            export.version <= WALLET_EXPORT_VERSION,
+            "Wallet export version {} is not supported",
+            export.version
+        );
+        Ok(export)
+    }
+}
+
+impl Persister {
+    /// Creates a [WalletExport] of the local swaps and payment metadata
+    pub(crate) fn export_wallet(
+        &self,
+        fingerprint: String,
+        descriptor: String,
+    ) -> Result<WalletExport> {
+        let con = self.get_connection()?;
+        let mut records: Vec<SyncData> = Vec::new();
+        records.extend(
+            self.list_send_swaps_where(&con, vec![])?
+                .into_iter()
+                .map(|swap| SyncData::Send(swap.into())),
+        );
+        records.extend(
+            self.list_receive_swaps_where(&con, vec![])?
+                .into_iter()
+                .map(|swap| SyncData::Receive(swap.into())),
+        );
+        records.extend(
+            self.list_chain_swaps_where(&con, vec![])?
+                .into_iter()
+                .map(|swap| SyncData::Chain(swap.into())),
+        );
+
+        let mut stmt = con.prepare("SELECT tx_id FROM payment_details")?;
+        let tx_ids: Vec<String> = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<_, _>>()?;
+        for tx_id in tx_ids {
+            if let Some(payment_details) = self.get_payment_details(&tx_id)? {
+                records.push(SyncData::PaymentDetails(payment_details.into()));
+            }
+        }
+
+        if let Some(last_derivation_index) = self.get_last_derivation_index()? {
+            records.push(SyncData::LastDerivationIndex(last_derivation_index));
+        }
+
+        Ok(WalletExport {
+            version: WALLET_EXPORT_VERSION,
+            network: self.network.to_string(),
+            fingerprint,
+            descriptor,
+            records,
+        })
+    }
+
+    /// Imports the records of a [WalletExport] in a single transaction, so a failing record
+    /// leaves the local data untouched. Records already stored locally are kept as is, as they
+    /// may be more recent than the exported ones.
+    pub(crate) fn import_wallet(&self, export: WalletExport) -> Result<ImportedRecords> {
+        ensure!(
+            export.network == self.network.to_string(),
+            "The wallet export is for {}, not {}",
+            export.network,
+            self.network
+        );
+
+        let mut con = self.get_connection()?;
+        let tx = con.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let mut imported = ImportedRecords::default();
+        for record in export.records {
+            match record {
+                SyncData::Send(data) => {
+                    if record_exists(&tx, "send_swaps", "id", &data.swap_id)? {
+                        continue;
+                    }
+                    let swap_id = data.swap_id.clone();
+                    Self::insert_or_update_send_swap_inner(&tx, &data.into())?;
+                    self.commit_outgoing(&tx, &swap_id, RecordType::Send, None)?;
+                    imported.swap_ids.push(swap_id);
+                }
+                SyncData::Receive(data) => {
+                    if record_exists(&tx, "receive_swaps", "id", &data.swap_id)? {
+                        continue;
+                    }
+                    let swap_id = data.swap_id.clone();
+                    Self::insert_or_update_receive_swap_inner(&tx, &data.into())?;
+                    self.commit_outgoing(&tx, &swap_id, RecordType::Receive, None)?;
+                    imported.swap_ids.push(swap_id);
+                }
+                SyncData::Chain(data) => {
+                    if record_exists(&tx, "chain_swaps", "id", &data.swap_id)? {
+                        continue;
+                    }
+                    let swap_id = data.swap_id.clone();
+                    Self::insert_or_update_chain_swap_inner(&tx, &data.into())?;
+                    self.commit_outgoing(&tx, &swap_id, RecordType::Chain, None)?;
+                    imported.swap_ids.push(swap_id);
+                }
+                SyncData::PaymentDetails(data) => {
+                    if record_exists(&tx, "payment_details", "tx_id", &data.tx_id)? {
+                        continue;
+                    }
+                    let tx_id = data.tx_id.clone();
+                    Self::insert_or_update_payment_details_inner(&tx, &data.into(), false)?;
+                    self.commit_outgoing(&tx, &tx_id, RecordType::PaymentDetails, None)?;
+                }
+                SyncData::LastDerivationIndex(index) => {
+                    let last_index = Self::get_cached_item_inner(&tx, KEY_LAST_DERIVATION_INDEX)?
+                        .and_then(|str| str.parse::<u32>().ok());
+                    if last_index.is_some_and(|last_index| last_index >= index) {
+                        continue;
+                    }
+                    self.set_last_derivation_index_inner(&tx, index)?;
+                }
+            };
+            imported.count += 1;
+        }
+        tx.commit()?;
+        self.trigger_sync();
+
+        Ok(imported)
+    }
+}
+
+/// The records imported with [Persister::import_wallet]
+#[derive(Default)]
+pub(crate) struct ImportedRecords {
+    /// The number of imported records
+    pub(crate) count: usize,
+    /// The ids of the imported swaps, to be tracked for status updates
+    pub(crate) swap_ids: Vec<String>,
+}
+
+fn record_exists(tx: &Transaction, table: &str, id_column: &str, id: &str) -> Result<bool> {
+    Ok(tx.query_row(
+        &format!("SELECT EXISTS(SELECT 1 FROM {table} WHERE {id_column} = ?1)"),
+        [id],
+        |row| row.get(0),
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::WalletExport;
+    use crate::{
+        model::PaymentState,
+        test_utils::persist::{create_persister, new_receive_swap, new_send_swap},
+    };
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_export_and_import_wallet() -> Result<()> {
+        create_persister!(local);
+
+        local.insert_or_update_send_swap(&new_send_swap(Some(PaymentState::Pending), None))?;
+        local
+            .insert_or_update_receive_swap(&new_receive_swap(Some(PaymentState::Pending), None))?;
+        local.set_last_derivation_index(5)?;
+
+        let export = local.export_wallet("fingerprint".to_string(), "descriptor".to_string())?;
+        let export = WalletExport::from_bytes(&export.to_bytes()?)?;
+        assert_eq!(export.records.len(), 3);
+
+        create_persister!(remote);
+
+        let imported = remote.import_wallet(export)?;
+        assert_eq!(imported.count, 3);
+        assert_eq!(imported.swap_ids.len(), 2);
+        assert_eq!(remote.list_ongoing_swaps()?.len(), 2);
+        assert_eq!(remote.get_last_derivation_index()?, Some(5));
+
+        // Importing again doesn't overwrite the local records
+        let export = local.export_wallet("fingerprint".to_string(), "descriptor".to_string())?;
+        let imported = remote.import_wallet(export)?;
+        assert_eq!(imported.count, 0);
+        assert!(imported.swap_ids.is_empty());
+
+        Ok(())
+    }
+}
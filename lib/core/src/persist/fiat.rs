@@ -0,0 +1,65 @@
+use anyhow::Result;
+use rusqlite::params;
+
+use crate::utils;
+
+use super::Persister;
+
+impl Persister {
+    /// Records the fiat value of a payment. The value is only recorded once, so that it keeps
+    /// reflecting the exchange rate at the time the payment completed.
+    pub(crate) fn insert_payment_fiat_value(
+        &self,
+        tx_id: &str,
+        fiat_currency: &str,
+        fiat_amount: f64,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT OR IGNORE INTO payment_fiat_values (
+                tx_id,
+                fiat_currency,
+                fiat_amount,
+                created_at
+            )
+            VALUES (?, ?, ?, ?)",
+            params![tx_id, fiat_currency, fiat_amount, utils::now()],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::{LiquidNetwork, PaymentType};
+    use crate::test_utils::persist::{create_persister, new_payment_tx_data};
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_payment_fiat_value() -> Result<()> {
+        create_persister!(storage);
+
+        let payment_tx_data = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Receive);
+        let tx_id = payment_tx_data.tx_id.clone();
+        storage.insert_or_update_payment(payment_tx_data, None, false)?;
+
+        let payment = storage.get_payment(&tx_id)?.unwrap();
+        assert_eq!(payment.fiat_amount, None);
+        assert_eq!(payment.fiat_currency, None);
+
+        storage.insert_payment_fiat_value(&tx_id, "USD", 10.5)?;
+        // The value recorded first is kept
+        storage.insert_payment_fiat_value(&tx_id, "USD", 20.0)?;
+
+        let payment = storage.get_payment(&tx_id)?.unwrap();
+        assert_eq!(payment.fiat_amount, Some(10.5));
+        assert_eq!(payment.fiat_currency, Some("USD".to_string()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::Result;
+use rusqlite::{params, OptionalExtension};
+
+use super::Persister;
+
+impl Persister {
+    /// Reserves the idempotency key of a send at `now`. Returns false if the key is already
+    /// reserved.
+    ///
+    /// A reservation never expires, as the payment may already be sent. Only the reservations
+    /// without a payment are released on startup, see
+    /// [Persister::delete_unlinked_idempotency_keys].
+    pub(crate) fn insert_idempotency_key(&self, key: &str, now: u32) -> Result<bool> {
+        let con = self.get_connection()?;
+        let row_count = con.execute(
+            "INSERT OR IGNORE INTO payment_idempotency_keys (key, created_at)
+            VALUES (?, ?)",
+            params![key, now],
+        )?;
+
+        Ok(row_count == 1)
+    }
+
+    /// Sets the id of the payment sent with the idempotency key, either its swap id or tx id
+    pub(crate) fn set_idempotency_key_payment_id(&self, key: &str, payment_id: &str) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "UPDATE payment_idempotency_keys SET payment_id = ? WHERE key = ?",
+            params![payment_id, key],
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the id of the payment sent with the idempotency key, if it's reserved and the
+    /// payment was sent
+    pub(crate) fn fetch_idempotency_key_payment_id(&self, key: &str) -> Result<Option<String>> {
+        let con = self.get_connection()?;
+        let payment_id: Option<Option<String>> = con
+            .query_row(
+                "SELECT payment_id FROM payment_idempotency_keys WHERE key = ?",
+                [key],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        Ok(payment_id.flatten())
+    }
+
+    pub(crate) fn delete_idempotency_key(&self, key: &str) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute("DELETE FROM payment_idempotency_keys WHERE key = ?", [key])?;
+
+        Ok(())
+    }
+
+    /// Deletes the reservations without a payment. As the payment id is set before the swap is
+    /// funded or the tx is broadcast, these belong to sends interrupted before sending anything.
+    /// Must only be called when no send is in progress, i.e. on startup.
+    pub(crate) fn delete_unlinked_idempotency_keys(&self) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "DELETE FROM payment_idempotency_keys WHERE payment_id IS NULL",
+            [],
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::test_utils::persist::create_persister;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_idempotency_keys() -> Result<()> {
+        create_persister!(storage);
+        let now = 1_000_000;
+        let later = now + 365 * 24 * 60 * 60;
+
+        assert!(storage.insert_idempotency_key("key", now)?);
+        assert!(!storage.insert_idempotency_key("key", now + 1)?);
+        assert!(storage.fetch_idempotency_key_payment_id("key")?.is_none());
+
+        // A reservation never expires
+        assert!(!storage.insert_idempotency_key("key", later)?);
+
+        // Only the reservations without a payment are released on startup
+        assert!(storage.insert_idempotency_key("unlinked", now)?);
+        storage.set_idempotency_key_payment_id("key", "swap-id")?;
+        storage.delete_unlinked_idempotency_keys()?;
+        assert!(!storage.insert_idempotency_key("key", later)?);
+        assert_eq!(
+            storage.fetch_idempotency_key_payment_id("key")?.as_deref(),
+            Some("swap-id")
+        );
+        assert!(storage.insert_idempotency_key("unlinked", later)?);
+
+        storage.delete_idempotency_key("key")?;
+        assert!(storage.fetch_idempotency_key_payment_id("key")?.is_none());
+        assert!(storage.insert_idempotency_key("key", later)?);
+
+        Ok(())
+    }
+}
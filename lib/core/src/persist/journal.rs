@@ -0,0 +1,267 @@
+use anyhow::Result;
+use rusqlite::{params, OptionalExtension, Row};
+
+use crate::model::{JournaledEvent, ReplayEventsRequest, SdkEvent};
+use crate::persist::{model::SwapEventState, where_clauses_to_string};
+use crate::utils;
+
+use super::Persister;
+
+/// The maximum number of state change events kept in the journal (see [SdkEvent::is_state_change]).
+/// Older ones are pruned on insert.
+const EVENT_JOURNAL_MAX_STATE_CHANGES: u64 = 1_000;
+/// The maximum number of other events, like syncs and new blocks, kept in the journal. They are
+/// pruned separately, so that they don't evict the state changes.
+const EVENT_JOURNAL_MAX_OTHER_EVENTS: u64 = 100;
+
+impl Persister {
+    /// Journals the event, pruning the older events of the same class beyond
+    /// [EVENT_JOURNAL_MAX_STATE_CHANGES] or [EVENT_JOURNAL_MAX_OTHER_EVENTS]. If
+    /// `keep_undispatched` is set, the events not yet dispatched to the event webhook are kept
+    /// until they are.
+    pub(crate) fn insert_journaled_event(
+        &self,
+        event: &SdkEvent,
+        keep_undispatched: bool,
+    ) -> Result<u64> {
+        let max_prune_seq = match keep_undispatched {
+            true => self.get_last_dispatched_event_seq()?.unwrap_or_default() as i64,
+            false => i64::MAX,
+        };
+        let is_state_change = event.is_state_change();
+        let max_size = match is_state_change {
+            true => EVENT_JOURNAL_MAX_STATE_CHANGES,
+            false => EVENT_JOURNAL_MAX_OTHER_EVENTS,
+        };
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        tx.execute(
+            "INSERT INTO event_journal (event, is_state_change, created_at) VALUES (?, ?, ?)",
+            params![serde_json::to_string(event)?, is_state_change, utils::now()],
+        )?;
+        let seq = tx.last_insert_rowid() as u64;
+        tx.execute(
+            "
+            DELETE FROM event_journal
+            WHERE is_state_change = ?1
+                AND seq <= ?2
+                AND seq <= (
+                    SELECT seq
+                    FROM event_journal
+                    WHERE is_state_change = ?1
+                    ORDER BY seq DESC
+                    LIMIT 1 OFFSET ?3
+                )
+            ",
+            params![is_state_change, max_prune_seq, max_size],
+        )?;
+        tx.commit()?;
+
+        Ok(seq)
+    }
+
+    pub(crate) fn list_journaled_events(
+        &self,
+        req: &ReplayEventsRequest,
+    ) -> Result<Vec<JournaledEvent>> {
+        let con = self.get_connection()?;
+        let mut where_clauses = vec![];
+        if let Some(since_seq) = req.since_seq {
+            where_clauses.push(format!("seq > {since_seq}"));
+        }
+        let where_clause_str = where_clauses_to_string(where_clauses);
+        let limit_str = req
+            .limit
+            .map(|limit| format!("LIMIT {limit}"))
+            .unwrap_or_default();
+
+        let mut stmt = con.prepare(&format!(
+            "
+            SELECT
+                seq,
+                event,
+                created_at
+            FROM event_journal
+            {where_clause_str}
+            ORDER BY seq ASC
+            {limit_str}
+        "
+        ))?;
+        let events = stmt
+            .query_map([], Self::sql_row_to_journaled_event)?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(events)
+    }
+
+    /// Returns the last seen version of the swap, if it was seen and can still change
+    pub(crate) fn fetch_swap_event_state(&self, swap_id: &str) -> Result<Option<SwapEventState>> {
+        let con = self.get_connection()?;
+        let state = con
+            .query_row(
+                "SELECT state, claim_tx_id, refund_tx_id FROM swap_event_states WHERE swap_id = ?",
+                [swap_id],
+                |row| {
+                    Ok(SwapEventState {
+                        state: row.get(0)?,
+                        claim_tx_id: row.get(1)?,
+                        refund_tx_id: row.get(2)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(state)
+    }
+
+    pub(crate) fn insert_or_update_swap_event_state(
+        &self,
+        swap_id: &str,
+        state: &SwapEventState,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT OR REPLACE INTO swap_event_states (swap_id, state, claim_tx_id, refund_tx_id)
+            VALUES (?, ?, ?, ?)",
+            params![swap_id, state.state, state.claim_tx_id, state.refund_tx_id],
+        )?;
+        Ok(())
+    }
+
+    pub(crate) fn delete_swap_event_state(&self, swap_id: &str) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute("DELETE FROM swap_event_states WHERE swap_id = ?", [swap_id])?;
+        Ok(())
+    }
+
+    fn sql_row_to_journaled_event(row: &Row) -> rusqlite::Result<JournaledEvent> {
+        let event: String = row.get(1)?;
+        let event = serde_json::from_str(&event).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(1, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+        Ok(JournaledEvent {
+            seq: row.get(0)?,
+            timestamp: row.get(2)?,
+            event,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::{EVENT_JOURNAL_MAX_OTHER_EVENTS, EVENT_JOURNAL_MAX_STATE_CHANGES};
+    use crate::model::{JournaledEvent, PaymentState, ReplayEventsRequest, SdkEvent};
+    use crate::persist::model::SwapEventState;
+    use crate::test_utils::persist::create_persister;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_event_journal() -> Result<()> {
+        create_persister!(storage);
+
+        let first_seq = storage.insert_journaled_event(&SdkEvent::SyncStarted, false)?;
+        let second_seq = storage.insert_journaled_event(
+            &SdkEvent::DataSynced {
+                did_pull_new_records: true,
+            },
+            false,
+        )?;
+        storage.insert_journaled_event(&SdkEvent::Synced, false)?;
+        assert!(second_seq > first_seq);
+
+        let events = storage.list_journaled_events(&ReplayEventsRequest::default())?;
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].event, SdkEvent::SyncStarted);
+
+        let events = storage.list_journaled_events(&ReplayEventsRequest {
+            since_seq: Some(first_seq),
+            limit: Some(1),
+        })?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].seq, second_seq);
+        assert_eq!(
+            events[0].event,
+            SdkEvent::DataSynced {
+                did_pull_new_records: true
+            }
+        );
+
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_swap_event_state() -> Result<()> {
+        create_persister!(storage);
+
+        assert!(storage.fetch_swap_event_state("swap")?.is_none());
+        let mut state = SwapEventState {
+            state: PaymentState::Pending,
+            claim_tx_id: None,
+            refund_tx_id: None,
+        };
+        storage.insert_or_update_swap_event_state("swap", &state)?;
+        assert_eq!(storage.fetch_swap_event_state("swap")?, Some(state.clone()));
+
+        state.claim_tx_id = Some("claim-tx-id".to_string());
+        storage.insert_or_update_swap_event_state("swap", &state)?;
+        assert_eq!(storage.fetch_swap_event_state("swap")?, Some(state));
+
+        storage.delete_swap_event_state("swap")?;
+        assert!(storage.fetch_swap_event_state("swap")?.is_none());
+
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_event_journal_pruning() -> Result<()> {
+        create_persister!(storage);
+        let state_change = || SdkEvent::TransactionDropped {
+            tx_id: "tx".to_string(),
+        };
+        let list_events = |is_state_change: bool| -> Result<Vec<JournaledEvent>> {
+            Ok(storage
+                .list_journaled_events(&ReplayEventsRequest::default())?
+                .into_iter()
+                .filter(|e| e.event.is_state_change() == is_state_change)
+                .collect())
+        };
+
+        // Other events are pruned without evicting the state changes
+        storage.insert_journaled_event(&state_change(), false)?;
+        for _ in 0..EVENT_JOURNAL_MAX_OTHER_EVENTS + 10 {
+            storage.insert_journaled_event(&SdkEvent::Synced, false)?;
+        }
+        let other_events = list_events(false)?;
+        assert_eq!(other_events.len() as u64, EVENT_JOURNAL_MAX_OTHER_EVENTS);
+        assert_eq!(other_events[0].seq, 12);
+        assert_eq!(list_events(true)?.len(), 1);
+
+        for _ in 0..EVENT_JOURNAL_MAX_STATE_CHANGES + 10 {
+            storage.insert_journaled_event(&state_change(), false)?;
+        }
+        let state_changes = list_events(true)?;
+        assert_eq!(state_changes.len() as u64, EVENT_JOURNAL_MAX_STATE_CHANGES);
+        assert_eq!(state_changes[0].seq, 122);
+        assert_eq!(
+            list_events(false)?.len() as u64,
+            EVENT_JOURNAL_MAX_OTHER_EVENTS
+        );
+
+        // The events not yet dispatched to the event webhook are kept
+        storage.set_last_dispatched_event_seq(125)?;
+        for _ in 0..10 {
+            storage.insert_journaled_event(&state_change(), true)?;
+        }
+        let state_changes = list_events(true)?;
+        assert_eq!(state_changes[0].seq, 126);
+        assert_eq!(
+            state_changes.len() as u64,
+            EVENT_JOURNAL_MAX_STATE_CHANGES + 6
+        );
+
+        Ok(())
+    }
+}
@@ -328,5 +328,158 @@ pub(crate) fn current_migrations(network: LiquidNetwork) -> Vec<&'static str> {
         ALTER TABLE send_swaps ADD COLUMN refund_address TEXT;
         ALTER TABLE chain_swaps ADD COLUMN refund_address TEXT;
         ",
+        "
+        CREATE TABLE IF NOT EXISTS payment_queue (
+            id TEXT NOT NULL PRIMARY KEY,
+            request TEXT NOT NULL,
+            state INTEGER NOT NULL,
+            attempts INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            next_attempt_at INTEGER NOT NULL,
+            last_error TEXT
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS event_journal (
+            seq INTEGER PRIMARY KEY AUTOINCREMENT,
+            event TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS payment_fiat_values (
+            tx_id TEXT NOT NULL PRIMARY KEY,
+            fiat_currency TEXT NOT NULL,
+            fiat_amount REAL NOT NULL,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS nwc_connections (
+            name TEXT NOT NULL PRIMARY KEY,
+            client_pubkey TEXT NOT NULL UNIQUE,
+            budget_max_amount_sat INTEGER,
+            budget_renewal_period_sec INTEGER,
+            spent_sat INTEGER NOT NULL DEFAULT 0,
+            period_started_at INTEGER NOT NULL,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS registered_assets (
+            asset_id TEXT NOT NULL PRIMARY KEY,
+            name TEXT NOT NULL,
+            ticker TEXT,
+            precision INTEGER NOT NULL,
+            domain TEXT,
+            icon_url TEXT NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS reusable_address_deposits (
+            outpoint TEXT NOT NULL PRIMARY KEY,
+            swap_id TEXT NOT NULL,
+            forward_tx_id TEXT,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS buy_bitcoin_swaps (
+            swap_id TEXT NOT NULL PRIMARY KEY,
+            provider TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS swap_intents (
+            swap_id TEXT NOT NULL,
+            kind TEXT NOT NULL,
+            tx_id TEXT,
+            created_at INTEGER NOT NULL,
+            PRIMARY KEY (swap_id, kind)
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS payment_idempotency_keys (
+            key TEXT NOT NULL PRIMARY KEY,
+            payment_id TEXT,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS asset_conversions (
+            tx_id TEXT NOT NULL PRIMARY KEY,
+            from_asset_id TEXT NOT NULL,
+            from_amount INTEGER NOT NULL,
+            to_asset_id TEXT NOT NULL,
+            to_amount INTEGER NOT NULL,
+            created_at INTEGER NOT NULL
+        ) STRICT;
+        ",
+        "ALTER TABLE asset_metadata ADD COLUMN is_amp INTEGER NOT NULL DEFAULT 0;",
+        "ALTER TABLE receive_swaps ADD COLUMN external_claim_address TEXT;",
+        "
+        CREATE TABLE IF NOT EXISTS scheduled_payments (
+            id TEXT NOT NULL PRIMARY KEY,
+            request TEXT NOT NULL,
+            state INTEGER NOT NULL,
+            attempts INTEGER NOT NULL DEFAULT 0,
+            created_at INTEGER NOT NULL,
+            next_attempt_at INTEGER NOT NULL,
+            tx_id TEXT,
+            last_error TEXT
+        ) STRICT;
+        ",
+        "
+        CREATE TABLE IF NOT EXISTS recurring_payments (
+            id TEXT NOT NULL PRIMARY KEY,
+            destination TEXT NOT NULL,
+            amount_sat INTEGER NOT NULL,
+            interval_sec INTEGER NOT NULL,
+            max_fee_sat INTEGER,
+            created_at INTEGER NOT NULL,
+            next_occurrence_at INTEGER NOT NULL
+        ) STRICT;
+        CREATE TABLE IF NOT EXISTS recurring_payment_occurrences (
+            recurring_payment_id TEXT NOT NULL,
+            occurrence_at INTEGER NOT NULL,
+            executed_at INTEGER NOT NULL,
+            tx_id TEXT,
+            error TEXT,
+            PRIMARY KEY (recurring_payment_id, occurrence_at)
+        ) STRICT;
+        ",
+        "
+        ALTER TABLE chain_swaps ADD COLUMN refundable_since INTEGER;
+        UPDATE chain_swaps SET refundable_since = last_updated_at WHERE state IN (5, 6);
+        CREATE TRIGGER IF NOT EXISTS insert_chain_swaps_refundable_since
+        AFTER INSERT ON chain_swaps
+        WHEN NEW.state IN (5, 6)
+        BEGIN
+            UPDATE chain_swaps SET refundable_since = (strftime('%s', 'now'))
+            WHERE id = NEW.id;
+        END;
+        CREATE TRIGGER IF NOT EXISTS update_chain_swaps_refundable_since
+        AFTER UPDATE OF state ON chain_swaps
+        WHEN (NEW.state IN (5, 6)) != (NEW.refundable_since IS NOT NULL)
+        BEGIN
+            UPDATE chain_swaps
+            SET refundable_since = CASE
+                WHEN NEW.state IN (5, 6) THEN (strftime('%s', 'now'))
+                ELSE NULL
+            END
+            WHERE id = NEW.id;
+        END;
+        ",
+        "ALTER TABLE payment_tx_data ADD COLUMN is_seen_in_wallet INTEGER NOT NULL DEFAULT 0;",
+        "ALTER TABLE event_journal ADD COLUMN is_state_change INTEGER NOT NULL DEFAULT 1;",
+        "
+        CREATE TABLE IF NOT EXISTS swap_event_states (
+            swap_id TEXT NOT NULL PRIMARY KEY,
+            state INTEGER NOT NULL,
+            claim_tx_id TEXT,
+            refund_tx_id TEXT
+        ) STRICT;
+        ",
     ]
 }
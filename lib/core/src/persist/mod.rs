@@ -1,12 +1,26 @@
 mod address;
 pub(crate) mod asset_metadata;
+pub(crate) mod asset_registry;
 mod backup;
+pub(crate) mod buy_bitcoin;
 pub(crate) mod cache;
 pub(crate) mod chain;
+pub(crate) mod conversion;
+pub(crate) mod export;
+pub(crate) mod fiat;
+pub(crate) mod idempotency;
+pub(crate) mod journal;
 mod migrations;
 pub(crate) mod model;
+pub(crate) mod nwc;
+pub(crate) mod queue;
 pub(crate) mod receive;
+pub(crate) mod recurring;
+pub(crate) mod report;
+pub(crate) mod reusable_address;
+pub(crate) mod scheduled;
 pub(crate) mod send;
+pub(crate) mod swap_intent;
 pub(crate) mod sync;
 
 use std::collections::{HashMap, HashSet};
@@ -232,9 +246,7 @@ impl Persister {
             .iter()
             .find(|output| output.is_some())
             .and_then(|output| output.clone().map(|o| o.script_pubkey.to_hex()));
-        let unblinding_data = tx
-            .unblinded_url("")
-            .replace(&format!("tx/{}#blinded=", tx_id), "");
+        let unblinding_data = utils::tx_unblinding_data(tx);
         self.insert_or_update_payment(
             PaymentTxData {
                 tx_id: tx_id.clone(),
@@ -287,6 +299,67 @@ impl Persister {
         Ok(payments)
     }
 
+    /// Lists the ids of the unconfirmed txs that were seen in the wallet, as opposed to those
+    /// only inserted on broadcast
+    pub(crate) fn list_unconfirmed_wallet_tx_ids(&self) -> Result<HashSet<String>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT tx_id
+            FROM payment_tx_data
+            WHERE is_confirmed = 0 AND is_seen_in_wallet = 1",
+        )?;
+        let tx_ids = stmt
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<HashSet<String>, _>>()?;
+        Ok(tx_ids)
+    }
+
+    /// Sums the L-BTC spent by the pending and complete outgoing payments since `since`,
+    /// including their fees
+    pub(crate) fn get_spent_amount_sat_since(&self, since: u32) -> Result<u64> {
+        let con = self.get_connection()?;
+        let states_clause =
+            get_where_clause_state_in(&[PaymentState::Pending, PaymentState::Complete]);
+        let spent_sat = con.query_row(
+            &format!(
+                "
+            SELECT COALESCE(SUM(spent_sat), 0) FROM (
+                -- Direct payments, of which only the fees are L-BTC when sending an asset
+                SELECT ptx.fees_sat + CASE WHEN ptx.asset_id = ?1 THEN ptx.amount ELSE 0 END AS spent_sat
+                FROM payment_tx_data AS ptx
+                WHERE ptx.payment_type = ?2
+                    AND (ptx.timestamp IS NULL OR ptx.timestamp >= ?3)
+                    AND ptx.tx_id NOT IN (SELECT lockup_tx_id FROM send_swaps WHERE lockup_tx_id NOT NULL)
+                    AND ptx.tx_id NOT IN (SELECT user_lockup_tx_id FROM chain_swaps WHERE user_lockup_tx_id NOT NULL)
+                UNION ALL
+                SELECT ss.payer_amount_sat + COALESCE(ptx.fees_sat, 0)
+                FROM send_swaps AS ss
+                LEFT JOIN payment_tx_data AS ptx ON ptx.tx_id = ss.lockup_tx_id
+                WHERE ss.created_at >= ?3 AND ss.{states_clause}
+                UNION ALL
+                SELECT cs.payer_amount_sat + COALESCE(ptx.fees_sat, 0)
+                FROM chain_swaps AS cs
+                LEFT JOIN payment_tx_data AS ptx ON ptx.tx_id = cs.user_lockup_tx_id
+                WHERE cs.direction = ?4 AND cs.created_at >= ?3 AND cs.{states_clause}
+                UNION ALL
+                -- Conversions from L-BTC, of which the tx data only records the asset received
+                SELECT ac.from_amount + COALESCE(ptx.fees_sat, 0)
+                FROM asset_conversions AS ac
+                LEFT JOIN payment_tx_data AS ptx ON ptx.tx_id = ac.tx_id
+                WHERE ac.from_asset_id = ?1 AND ac.created_at >= ?3
+            )"
+            ),
+            params![
+                utils::lbtc_asset_id(self.network).to_string(),
+                PaymentType::Send,
+                since,
+                Direction::Outgoing,
+            ],
+            |row| row.get(0),
+        )?;
+        Ok(spent_sat)
+    }
+
     pub(crate) fn insert_or_update_payment(
         &self,
         ptx: PaymentTxData,
@@ -304,9 +377,10 @@ impl Persister {
            fees_sat,
            payment_type,
            is_confirmed,
-           unblinding_data
+           unblinding_data,
+           is_seen_in_wallet
         )
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
         ON CONFLICT (tx_id)
         DO UPDATE SET timestamp = CASE WHEN excluded.is_confirmed = 1 THEN excluded.timestamp ELSE timestamp END,
                       asset_id = excluded.asset_id,
@@ -314,7 +388,8 @@ impl Persister {
                       fees_sat = excluded.fees_sat,
                       payment_type = excluded.payment_type,
                       is_confirmed = excluded.is_confirmed,
-                      unblinding_data = excluded.unblinding_data
+                      unblinding_data = excluded.unblinding_data,
+                      is_seen_in_wallet = MAX(is_seen_in_wallet, excluded.is_seen_in_wallet)
         ",
             (
                 &ptx.tx_id,
@@ -325,6 +400,7 @@ impl Persister {
                 ptx.payment_type,
                 ptx.is_confirmed,
                 ptx.unblinding_data,
+                from_wallet_tx_data,
             ),
         )?;
 
@@ -365,6 +441,19 @@ impl Persister {
         Ok(())
     }
 
+    /// Marks a confirmed payment tx as unconfirmed again, e.g. after it was reorged out of the
+    /// chain, so it is monitored again as an unconfirmed tx
+    pub(crate) fn unconfirm_payment_tx_data(&self, tx_id: &str) -> Result<()> {
+        let con = self.get_connection()?;
+
+        con.execute(
+            "UPDATE payment_tx_data SET is_confirmed = 0, timestamp = ? WHERE tx_id = ?",
+            (utils::now(), tx_id),
+        )?;
+
+        Ok(())
+    }
+
     fn insert_or_update_payment_details_inner(
         con: &Connection,
         payment_tx_details: &PaymentTxDetails,
@@ -479,6 +568,91 @@ impl Persister {
         .concat())
     }
 
+    /// Lists the swaps of all types matching the request, the most recently created first.
+    ///
+    /// The page of swap ids is selected across the swap tables in a single query, after which
+    /// only the swaps of the page are fetched.
+    pub(crate) fn list_swaps(&self, req: &ListSwapsRequest) -> Result<Vec<Swap>> {
+        let con = self.get_connection()?;
+        let mut where_clauses = vec![];
+        if let Some(states) = &req.states {
+            where_clauses.push(get_where_clause_state_in(states));
+        }
+        if let Some(from_timestamp) = req.from_timestamp {
+            where_clauses.push(format!("created_at >= {from_timestamp}"));
+        }
+        if let Some(to_timestamp) = req.to_timestamp {
+            where_clauses.push(format!("created_at <= {to_timestamp}"));
+        }
+        let where_clause_str = where_clauses_to_string(where_clauses);
+        let selects: Vec<String> = [
+            (PaymentSwapType::Send, "send_swaps"),
+            (PaymentSwapType::Receive, "receive_swaps"),
+            (PaymentSwapType::Chain, "chain_swaps"),
+        ]
+        .into_iter()
+        .filter(|(swap_type, _)| {
+            req.swap_types
+                .as_ref()
+                .is_none_or(|swap_types| swap_types.contains(swap_type))
+        })
+        .map(|(swap_type, table)| {
+            format!(
+                "SELECT id, created_at, '{swap_type:?}' AS swap_type FROM {table} {where_clause_str}"
+            )
+        })
+        .collect();
+        if selects.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // A negative limit means no limit
+        let limit = req.limit.map_or(-1, i64::from);
+        let offset = req.offset.unwrap_or(0);
+        let page: Vec<(String, String)> = con
+            .prepare(&format!(
+                "
+                SELECT id, swap_type
+                FROM ({})
+                ORDER BY created_at DESC
+                LIMIT {limit} OFFSET {offset}
+            ",
+                selects.join(" UNION ALL ")
+            ))?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_, _>>()?;
+
+        let ids_clause = |swap_type: PaymentSwapType| {
+            let ids: Vec<String> = page
+                .iter()
+                .filter(|(_, t)| *t == format!("{swap_type:?}"))
+                .map(|(id, _)| format!("'{}'", id.replace('\'', "''")))
+                .collect();
+            (!ids.is_empty()).then(|| vec![format!("id IN ({})", ids.join(", "))])
+        };
+        let mut swaps: HashMap<String, Swap> = HashMap::new();
+        if let Some(where_clauses) = ids_clause(PaymentSwapType::Send) {
+            for swap in self.list_send_swaps_where(&con, where_clauses)? {
+                swaps.insert(swap.id.clone(), Swap::Send(swap));
+            }
+        }
+        if let Some(where_clauses) = ids_clause(PaymentSwapType::Receive) {
+            for swap in self.list_receive_swaps_where(&con, where_clauses)? {
+                swaps.insert(swap.id.clone(), Swap::Receive(swap));
+            }
+        }
+        if let Some(where_clauses) = ids_clause(PaymentSwapType::Chain) {
+            for swap in self.list_chain_swaps_where(&con, where_clauses)? {
+                swaps.insert(swap.id.clone(), Swap::Chain(swap));
+            }
+        }
+
+        Ok(page
+            .into_iter()
+            .filter_map(|(id, _)| swaps.remove(&id))
+            .collect())
+    }
+
     fn select_payment_query(
         &self,
         where_clause: Option<&str>,
@@ -491,10 +665,16 @@ impl Persister {
             SELECT
                 ptx.tx_id,
                 ptx.timestamp,
-                ptx.asset_id,
-                ptx.amount,
-                ptx.fees_sat,
-                ptx.payment_type,
+                CASE WHEN cl.leg = 1 THEN '{lbtc_asset_id}' ELSE ptx.asset_id END AS leg_asset_id,
+                CASE WHEN cl.leg = 1
+                    THEN IIF(ac.from_asset_id = '{lbtc_asset_id}', ac.from_amount, ac.to_amount)
+                    ELSE ptx.amount
+                END,
+                CASE WHEN cl.leg = 1 THEN 0 ELSE ptx.fees_sat END,
+                CASE WHEN cl.leg = 1
+                    THEN IIF(ac.from_asset_id = '{lbtc_asset_id}', {send}, {receive})
+                    ELSE ptx.payment_type
+                END AS leg_payment_type,
                 ptx.is_confirmed,
                 ptx.unblinding_data,
                 rs.id,
@@ -545,10 +725,20 @@ impl Persister {
                 pd.description,
                 pd.lnurl_info_json,
                 pd.bip353_address,
-                pd.asset_fees,
+                IIF(cl.leg = 1, NULL, pd.asset_fees),
                 am.name,
                 am.ticker,
-                am.precision
+                am.precision,
+                pfv.fiat_amount,
+                pfv.fiat_currency,
+                bbs.provider,
+                am.fiat_id,
+                ac.from_asset_id,
+                ac.from_amount,
+                ac.to_asset_id,
+                ac.to_amount,
+                am.is_amp,
+                rpo.recurring_payment_id
             FROM payment_tx_data AS ptx          -- Payment tx (each tx results in a Payment)
             FULL JOIN (
                 SELECT * FROM receive_swaps
@@ -570,8 +760,18 @@ impl Persister {
                 ON rtx.tx_id in (ss.refund_tx_id, cs.refund_tx_id)
             LEFT JOIN payment_details AS pd      -- Payment details
                 ON pd.tx_id = ptx.tx_id
+            LEFT JOIN asset_conversions AS ac    -- Asset conversion
+                ON ac.tx_id = ptx.tx_id
+            LEFT JOIN (SELECT 0 AS leg UNION ALL SELECT 1 AS leg) AS cl -- Conversion legs, 1 being the L-BTC one
+                ON ac.tx_id IS NOT NULL
             LEFT JOIN asset_metadata AS am       -- Asset metadata
-                ON am.asset_id = ptx.asset_id
+                ON am.asset_id = IIF(cl.leg = 1, '{lbtc_asset_id}', ptx.asset_id)
+            LEFT JOIN payment_fiat_values AS pfv -- Fiat value at completion
+                ON pfv.tx_id = ptx.tx_id
+            LEFT JOIN buy_bitcoin_swaps AS bbs   -- Buy Bitcoin provider
+                ON bbs.swap_id = cs.id
+            LEFT JOIN recurring_payment_occurrences AS rpo -- Recurring payment occurrence
+                ON rpo.tx_id = ptx.tx_id
             WHERE                                
                 (ptx.tx_id IS NULL               -- Filter out refund txs from Chain/Send Swaps
                     OR ptx.tx_id NOT IN (SELECT refund_tx_id FROM send_swaps WHERE refund_tx_id NOT NULL)
@@ -589,6 +789,9 @@ impl Persister {
             },
             limit.unwrap_or(u32::MAX),
             offset.unwrap_or(0),
+            lbtc_asset_id = utils::lbtc_asset_id(self.network),
+            send = PaymentType::Send as u8,
+            receive = PaymentType::Receive as u8,
         )
     }
 
@@ -674,6 +877,27 @@ impl Persister {
         let maybe_asset_metadata_ticker: Option<String> = row.get(58)?;
         let maybe_asset_metadata_precision: Option<u8> = row.get(59)?;
 
+        let maybe_fiat_amount: Option<f64> = row.get(60)?;
+        let maybe_fiat_currency: Option<String> = row.get(61)?;
+
+        let maybe_buy_bitcoin_provider: Option<BuyBitcoinProvider> = row
+            .get::<_, Option<String>>(62)?
+            .and_then(|provider| BuyBitcoinProvider::from_str(&provider).ok());
+        let maybe_asset_metadata_fiat_id: Option<String> = row.get(63)?;
+        let maybe_asset_metadata_is_amp: Option<bool> = row.get(68)?;
+        let maybe_recurring_payment_id: Option<String> = row.get(69)?;
+
+        let maybe_conversion_from_asset_id: Option<String> = row.get(64)?;
+        let maybe_conversion = match maybe_conversion_from_asset_id {
+            Some(from_asset_id) => Some(AssetConversion {
+                from_asset_id,
+                from_amount: row.get(65)?,
+                to_asset_id: row.get(66)?,
+                to_amount: row.get(67)?,
+            }),
+            None => None,
+        };
+
         let (swap, payment_type) = match maybe_receive_swap_id {
             Some(receive_swap_id) => {
                 let payer_amount_sat = maybe_receive_swap_payer_amount_sat.unwrap_or(0);
@@ -872,6 +1096,7 @@ impl Persister {
                     liquid_expiration_blockheight,
                     bitcoin_expiration_blockheight,
                     auto_accepted_fees,
+                    buy_bitcoin_provider: maybe_buy_bitcoin_provider,
                 }
             }
             _ => {
@@ -891,21 +1116,21 @@ impl Persister {
                             name: name.clone(),
                             ticker: ticker.clone(),
                             precision,
-                            fiat_id: None,
+                            fiat_id: maybe_asset_metadata_fiat_id,
+                            is_amp: maybe_asset_metadata_is_amp.unwrap_or_default(),
                         };
-                        let (amount, fees) =
-                            maybe_payment_details_asset_fees.map_or((amount, None), |fees| {
-                                (
-                                    amount.saturating_sub(fees),
-                                    Some(asset_metadata.amount_from_sat(fees)),
-                                )
+                        let (amount, fees) = maybe_payment_details_asset_fees
+                            .map_or((amount, None), |fees| {
+                                (amount.saturating_sub(fees), Some(fees))
                             });
 
                         Some(AssetInfo {
                             name,
                             ticker,
                             amount: asset_metadata.amount_from_sat(amount),
-                            fees,
+                            fees: fees.map(|fees| asset_metadata.amount_from_sat(fees)),
+                            asset_amount: asset_metadata.asset_amount(amount),
+                            asset_fees: fees.map(|fees| asset_metadata.asset_amount(fees)),
                         })
                     }
                     _ => None,
@@ -920,20 +1145,23 @@ impl Persister {
                     asset_info,
                     lnurl_info: maybe_payment_details_lnurl_info,
                     bip353_address: maybe_payment_details_bip353_address,
+                    conversion: maybe_conversion,
                 }
             }
         };
 
-        match (tx, swap.clone()) {
-            (None, None) => Err(maybe_tx_tx_id.err().unwrap()),
-            (None, Some(swap)) => Ok(Payment::from_pending_swap(
-                swap,
-                payment_type,
-                payment_details,
-            )),
-            (Some(tx), None) => Ok(Payment::from_tx_data(tx, None, payment_details)),
-            (Some(tx), Some(swap)) => Ok(Payment::from_tx_data(tx, Some(swap), payment_details)),
-        }
+        let payment = match (tx, swap.clone()) {
+            (None, None) => return Err(maybe_tx_tx_id.err().unwrap()),
+            (None, Some(swap)) => Payment::from_pending_swap(swap, payment_type, payment_details),
+            (Some(tx), None) => Payment::from_tx_data(tx, None, payment_details),
+            (Some(tx), Some(swap)) => Payment::from_tx_data(tx, Some(swap), payment_details),
+        };
+        Ok(Payment {
+            fiat_amount: maybe_fiat_amount,
+            fiat_currency: maybe_fiat_currency,
+            recurring_payment_id: maybe_recurring_payment_id,
+            ..payment
+        })
     }
 
     pub fn get_payment(&self, id: &str) -> Result<Option<Payment>> {
@@ -941,7 +1169,11 @@ impl Persister {
             .get_connection()?
             .query_row(
                 &self.select_payment_query(
-                    Some("(ptx.tx_id = ?1 OR COALESCE(rs.id, ss.id, cs.id) = ?1)"),
+                    // The L-BTC leg of an asset conversion is only listed next to the asset one
+                    Some(
+                        "(ptx.tx_id = ?1 OR COALESCE(rs.id, ss.id, cs.id) = ?1) \
+                        AND cl.leg IS NOT 1",
+                    ),
                     None,
                     None,
                     None,
@@ -1043,7 +1275,7 @@ fn filter_to_where_clause(req: &ListPaymentsRequest) -> (String, Vec<Box<dyn ToS
             }
 
             where_clause.push(format!(
-                "ptx.payment_type in ({})",
+                "leg_payment_type in ({})",
                 type_filter_clause
                     .iter()
                     .map(|t| format!("{}", t))
@@ -1104,7 +1336,7 @@ fn filter_to_where_clause(req: &ListPaymentsRequest) -> (String, Vec<Box<dyn ToS
             } => {
                 where_clause.push("COALESCE(rs.id, ss.id, cs.id) IS NULL".to_string());
                 if let Some(asset_id) = asset_id {
-                    where_clause.push("ptx.asset_id = ?".to_string());
+                    where_clause.push("leg_asset_id = ?".to_string());
                     where_params.push(Box::new(asset_id));
                 }
                 if let Some(destination) = destination {
@@ -1123,12 +1355,13 @@ mod tests {
     use anyhow::Result;
 
     use crate::{
-        model::LiquidNetwork,
+        model::{LiquidNetwork, PaymentTxData},
         persist::PaymentTxDetails,
-        prelude::ListPaymentsRequest,
+        prelude::{ListPaymentsRequest, ListSwapsRequest, PaymentSwapType},
         test_utils::persist::{
             create_persister, new_payment_tx_data, new_receive_swap, new_send_swap,
         },
+        utils,
     };
 
     use super::{PaymentState, PaymentType};
@@ -1160,6 +1393,33 @@ mod tests {
         Ok(())
     }
 
+    #[sdk_macros::test_all]
+    fn test_unconfirm_payment_tx_data() -> Result<()> {
+        create_persister!(storage);
+
+        let payment_tx_data = PaymentTxData {
+            is_confirmed: true,
+            ..new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Receive)
+        };
+        storage.insert_or_update_payment(payment_tx_data.clone(), None, false)?;
+        assert!(storage.list_unconfirmed_payment_txs_data()?.is_empty());
+        assert_eq!(
+            storage.get_payment(&payment_tx_data.tx_id)?.unwrap().status,
+            PaymentState::Complete
+        );
+
+        storage.unconfirm_payment_tx_data(&payment_tx_data.tx_id)?;
+        let unconfirmed_txs_data = storage.list_unconfirmed_payment_txs_data()?;
+        assert_eq!(unconfirmed_txs_data.len(), 1);
+        assert_eq!(unconfirmed_txs_data[0].tx_id, payment_tx_data.tx_id);
+        assert_eq!(
+            storage.get_payment(&payment_tx_data.tx_id)?.unwrap().status,
+            PaymentState::Pending
+        );
+
+        Ok(())
+    }
+
     #[sdk_macros::test_all]
     fn test_list_ongoing_swaps() -> Result<()> {
         create_persister!(storage);
@@ -1172,6 +1432,89 @@ mod tests {
 
         Ok(())
     }
+
+    #[sdk_macros::test_all]
+    fn test_list_swaps() -> Result<()> {
+        create_persister!(storage);
+
+        let mut send_swap = new_send_swap(Some(PaymentState::Complete), None);
+        send_swap.created_at = 100;
+        storage.insert_or_update_send_swap(&send_swap)?;
+        let mut receive_swap = new_receive_swap(Some(PaymentState::Pending), None);
+        receive_swap.created_at = 200;
+        storage.insert_or_update_receive_swap(&receive_swap)?;
+
+        assert_eq!(storage.list_swaps(&ListSwapsRequest::default())?.len(), 2);
+        let swaps = storage.list_swaps(&ListSwapsRequest {
+            states: Some(vec![PaymentState::Complete]),
+            ..Default::default()
+        })?;
+        assert_eq!(swaps.len(), 1);
+        assert_eq!(swaps[0].swap_type(), PaymentSwapType::Send);
+        let swaps = storage.list_swaps(&ListSwapsRequest {
+            swap_types: Some(vec![PaymentSwapType::Receive, PaymentSwapType::Chain]),
+            ..Default::default()
+        })?;
+        assert_eq!(swaps.len(), 1);
+        assert_eq!(swaps[0].swap_type(), PaymentSwapType::Receive);
+
+        // The swaps are paginated across the swap types, the most recently created first
+        let swaps = storage.list_swaps(&ListSwapsRequest {
+            limit: Some(1),
+            ..Default::default()
+        })?;
+        assert_eq!(swaps.len(), 1);
+        assert_eq!(swaps[0].id(), receive_swap.id);
+        let swaps = storage.list_swaps(&ListSwapsRequest {
+            offset: Some(1),
+            limit: Some(5),
+            ..Default::default()
+        })?;
+        assert_eq!(swaps.len(), 1);
+        assert_eq!(swaps[0].id(), send_swap.id);
+
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_get_spent_amount_sat_since() -> Result<()> {
+        create_persister!(storage);
+        let since = utils::now() - 60;
+
+        let mut direct_send = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Send);
+        direct_send.amount = 1_000;
+        direct_send.fees_sat = 26;
+        storage.insert_or_update_payment(direct_send, None, false)?;
+
+        // Sent before `since`
+        let mut old_send = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Send);
+        old_send.timestamp = Some(since - 1);
+        old_send.amount = 5_000;
+        storage.insert_or_update_payment(old_send, None, false)?;
+
+        let mut receive = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Receive);
+        receive.amount = 5_000;
+        storage.insert_or_update_payment(receive, None, false)?;
+
+        // The lockup tx is counted once, as part of the swap
+        let mut send_swap = new_send_swap(Some(PaymentState::Pending), None);
+        let mut lockup = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Send);
+        lockup.amount = send_swap.payer_amount_sat;
+        lockup.fees_sat = 20;
+        send_swap.lockup_tx_id = Some(lockup.tx_id.clone());
+        storage.insert_or_update_send_swap(&send_swap)?;
+        storage.insert_or_update_payment(lockup, None, false)?;
+
+        // Not locked up
+        storage.insert_or_update_send_swap(&new_send_swap(Some(PaymentState::Created), None))?;
+
+        assert_eq!(
+            storage.get_spent_amount_sat_since(since)?,
+            1_026 + send_swap.payer_amount_sat + 20
+        );
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "test-utils")]
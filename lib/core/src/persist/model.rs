@@ -1,4 +1,4 @@
-use super::LnUrlInfo;
+use super::{LnUrlInfo, PaymentState, Swap};
 
 #[derive(Clone, Debug, Default)]
 pub(crate) struct PaymentTxDetails {
@@ -9,3 +9,21 @@ pub(crate) struct PaymentTxDetails {
     pub(crate) bip353_address: Option<String>,
     pub(crate) asset_fees: Option<u64>,
 }
+
+/// The last seen version of a swap that can still change, used to emit the swap events
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SwapEventState {
+    pub(crate) state: PaymentState,
+    pub(crate) claim_tx_id: Option<String>,
+    pub(crate) refund_tx_id: Option<String>,
+}
+
+impl From<&Swap> for SwapEventState {
+    fn from(swap: &Swap) -> Self {
+        Self {
+            state: swap.state(),
+            claim_tx_id: swap.claim_tx_id(),
+            refund_tx_id: swap.refund_tx_id(),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::Result;
+use rusqlite::{params, OptionalExtension, Row, TransactionBehavior};
+
+use super::Persister;
+use crate::model::{NwcBudget, NwcConnection};
+use crate::utils;
+
+impl Persister {
+    pub(crate) fn insert_nwc_connection(&self, connection: &NwcConnection) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO nwc_connections (
+                name,
+                client_pubkey,
+                budget_max_amount_sat,
+                budget_renewal_period_sec,
+                spent_sat,
+                period_started_at,
+                created_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)",
+            params![
+                connection.name,
+                connection.client_pubkey,
+                connection.budget.as_ref().map(|b| b.max_amount_sat),
+                connection
+                    .budget
+                    .as_ref()
+                    .and_then(|b| b.renewal_period_sec),
+                connection.spent_sat,
+                connection.created_at,
+                connection.created_at,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Removes a connection, returning whether it existed
+    pub(crate) fn delete_nwc_connection(&self, name: &str) -> Result<bool> {
+        let con = self.get_connection()?;
+        let deleted = con.execute("DELETE FROM nwc_connections WHERE name = ?", [name])?;
+        Ok(deleted > 0)
+    }
+
+    fn sql_row_to_nwc_connection(row: &Row) -> rusqlite::Result<NwcConnection> {
+        let budget_max_amount_sat: Option<u64> = row.get(2)?;
+        let budget_renewal_period_sec: Option<u32> = row.get(3)?;
+        Ok(NwcConnection {
+            name: row.get(0)?,
+            client_pubkey: row.get(1)?,
+            budget: budget_max_amount_sat.map(|max_amount_sat| NwcBudget {
+                max_amount_sat,
+                renewal_period_sec: budget_renewal_period_sec,
+            }),
+            spent_sat: row.get(4)?,
+            created_at: row.get(5)?,
+        })
+    }
+
+    pub(crate) fn list_nwc_connections(&self) -> Result<Vec<NwcConnection>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT name, client_pubkey, budget_max_amount_sat, budget_renewal_period_sec, spent_sat, created_at
+            FROM nwc_connections
+            ORDER BY created_at",
+        )?;
+        let connections = stmt
+            .query_map([], Self::sql_row_to_nwc_connection)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(connections)
+    }
+
+    pub(crate) fn fetch_nwc_connection_by_client_pubkey(
+        &self,
+        client_pubkey: &str,
+    ) -> Result<Option<NwcConnection>> {
+        let con = self.get_connection()?;
+        let connection = con
+            .query_row(
+                "SELECT name, client_pubkey, budget_max_amount_sat, budget_renewal_period_sec, spent_sat, created_at
+                FROM nwc_connections
+                WHERE client_pubkey = ?",
+                [client_pubkey],
+                Self::sql_row_to_nwc_connection,
+            )
+            .optional()?;
+        Ok(connection)
+    }
+
+    /// Reserves `amount_sat` of the connection budget before a payment, renewing the budget first
+    /// if its period elapsed. Returns false if the budget doesn't allow it.
+    pub(crate) fn reserve_nwc_budget(&self, name: &str, amount_sat: u64) -> Result<bool> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let (max_amount_sat, renewal_period_sec, spent_sat, period_started_at): (
+            Option<u64>,
+            Option<u32>,
+            u64,
+            u32,
+        ) = tx.query_row(
+            "SELECT budget_max_amount_sat, budget_renewal_period_sec, spent_sat, period_started_at
+            FROM nwc_connections
+            WHERE name = ?",
+            [name],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )?;
+
+        let now = utils::now();
+        let (spent_sat, period_started_at) = match renewal_period_sec {
+            Some(period) if now >= period_started_at.saturating_add(period) => (0, now),
+            _ => (spent_sat, period_started_at),
+        };
+        if max_amount_sat.is_some_and(|max| spent_sat.saturating_add(amount_sat) > max) {
+            return Ok(false);
+        }
+
+        tx.execute(
+            "UPDATE nwc_connections
+            SET spent_sat = ?, period_started_at = ?
+            WHERE name = ?",
+            params![spent_sat + amount_sat, period_started_at, name],
+        )?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Releases an amount reserved with [Persister::reserve_nwc_budget], e.g. if the payment failed
+    pub(crate) fn release_nwc_budget(&self, name: &str, amount_sat: u64) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "UPDATE nwc_connections
+            SET spent_sat = MAX(spent_sat - ?, 0)
+            WHERE name = ?",
+            params![amount_sat, name],
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::{NwcBudget, NwcConnection};
+    use crate::test_utils::persist::create_persister;
+    use crate::utils;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_nwc_budget() -> Result<()> {
+        create_persister!(storage);
+
+        let connection = NwcConnection {
+            name: "app".to_string(),
+            client_pubkey: "pubkey".to_string(),
+            budget: Some(NwcBudget {
+                max_amount_sat: 1_000,
+                renewal_period_sec: Some(3_600),
+            }),
+            spent_sat: 0,
+            created_at: utils::now(),
+        };
+        storage.insert_nwc_connection(&connection)?;
+        assert_eq!(
+            storage.fetch_nwc_connection_by_client_pubkey("pubkey")?,
+            Some(connection)
+        );
+
+        assert!(storage.reserve_nwc_budget("app", 600)?);
+        // Exceeds the remaining budget
+        assert!(!storage.reserve_nwc_budget("app", 600)?);
+        storage.release_nwc_budget("app", 600)?;
+        assert!(storage.reserve_nwc_budget("app", 1_000)?);
+        assert_eq!(storage.list_nwc_connections()?[0].spent_sat, 1_000);
+
+        assert!(storage.delete_nwc_connection("app")?);
+        assert!(storage.list_nwc_connections()?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use anyhow::Result;
+use rusqlite::{named_params, params, OptionalExtension, Row};
+
+use crate::model::{QueuedPayment, QueuedPaymentState};
+use crate::persist::where_clauses_to_string;
+
+use super::Persister;
+
+impl Persister {
+    pub(crate) fn insert_queued_payment(&self, queued_payment: &QueuedPayment) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO payment_queue (
+                id,
+                request,
+                state,
+                attempts,
+                created_at,
+                next_attempt_at,
+                last_error
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ",
+            params![
+                queued_payment.id,
+                serde_json::to_string(&queued_payment.request)?,
+                queued_payment.state,
+                queued_payment.attempts,
+                queued_payment.created_at,
+                queued_payment.next_attempt_at,
+                queued_payment.last_error,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Updates the attempt details of a queued payment. Does nothing if the payment was removed
+    /// from the queue in the meantime.
+    pub(crate) fn update_queued_payment(&self, queued_payment: &QueuedPayment) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "UPDATE payment_queue
+            SET
+                state = :state,
+                attempts = :attempts,
+                next_attempt_at = :next_attempt_at,
+                last_error = :last_error
+            WHERE id = :id",
+            named_params! {
+                ":id": queued_payment.id,
+                ":state": queued_payment.state,
+                ":attempts": queued_payment.attempts,
+                ":next_attempt_at": queued_payment.next_attempt_at,
+                ":last_error": queued_payment.last_error,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub(crate) fn fetch_queued_payment(&self, id: &str) -> Result<Option<QueuedPayment>> {
+        let con = self.get_connection()?;
+        let query = Self::list_queued_payments_query(vec!["id = ?1".to_string()]);
+        Ok(con
+            .query_row(&query, [id], Self::sql_row_to_queued_payment)
+            .optional()?)
+    }
+
+    pub(crate) fn list_queued_payments(&self) -> Result<Vec<QueuedPayment>> {
+        let con = self.get_connection()?;
+        let query = Self::list_queued_payments_query(vec![]);
+        let mut stmt = con.prepare(&query)?;
+        let queued_payments = stmt
+            .query_map([], Self::sql_row_to_queued_payment)?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(queued_payments)
+    }
+
+    /// Lists the pending queued payments that are due for an attempt at `now`
+    pub(crate) fn list_due_queued_payments(&self, now: u32) -> Result<Vec<QueuedPayment>> {
+        let con = self.get_connection()?;
+        let query = Self::list_queued_payments_query(vec![
+            format!("state = {}", QueuedPaymentState::Pending as i8),
+            "next_attempt_at <= ?1".to_string(),
+        ]);
+        let mut stmt = con.prepare(&query)?;
+        let queued_payments = stmt
+            .query_map([now], Self::sql_row_to_queued_payment)?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(queued_payments)
+    }
+
+    /// Removes a payment from the queue. Returns whether a payment was removed.
+    pub(crate) fn delete_queued_payment(&self, id: &str) -> Result<bool> {
+        let con = self.get_connection()?;
+        let deleted = con.execute("DELETE FROM payment_queue WHERE id = ?", [id])?;
+        Ok(deleted > 0)
+    }
+
+    fn list_queued_payments_query(where_clauses: Vec<String>) -> String {
+        let where_clause_str = where_clauses_to_string(where_clauses);
+
+        format!(
+            "
+            SELECT
+                id,
+                request,
+                state,
+                attempts,
+                created_at,
+                next_attempt_at,
+                last_error
+            FROM payment_queue
+            {where_clause_str}
+            ORDER BY created_at ASC
+        "
+        )
+    }
+
+    fn sql_row_to_queued_payment(row: &Row) -> rusqlite::Result<QueuedPayment> {
+        let request: String = row.get(1)?;
+        let request = serde_json::from_str(&request).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(1, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+        Ok(QueuedPayment {
+            id: row.get(0)?,
+            request,
+            state: row.get(2)?,
+            attempts: row.get(3)?,
+            created_at: row.get(4)?,
+            next_attempt_at: row.get(5)?,
+            last_error: row.get(6)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::{
+        PrepareSendRequest, QueuePaymentRequest, QueuedPayment, QueuedPaymentState,
+    };
+    use crate::test_utils::persist::create_persister;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn new_queued_payment(id: &str, next_attempt_at: u32) -> QueuedPayment {
+        QueuedPayment {
+            id: id.to_string(),
+            request: QueuePaymentRequest {
+                prepare_request: PrepareSendRequest {
+                    destination: "lq1qqvynd50t4tajashdguell7nu9gycuqqd869w9vsrg6kf2yxhwd9c7h59ky9r2xfw3fttkk9xj74gj4ssq5kq6qmdjvm7hvx7".to_string(),
+                    amount: None,
+                    fiat_amount: None,
+                },
+                use_asset_fees: None,
+                max_fee_sat: Some(100),
+            },
+            state: QueuedPaymentState::Pending,
+            attempts: 0,
+            created_at: next_attempt_at,
+            next_attempt_at,
+            last_error: None,
+        }
+    }
+
+    #[sdk_macros::test_all]
+    fn test_payment_queue() -> Result<()> {
+        create_persister!(storage);
+
+        storage.insert_queued_payment(&new_queued_payment("a", 100))?;
+        storage.insert_queued_payment(&new_queued_payment("b", 200))?;
+        assert_eq!(storage.list_queued_payments()?.len(), 2);
+
+        // Only the payments whose next attempt is due are listed
+        let due = storage.list_due_queued_payments(150)?;
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, "a");
+        assert_eq!(due[0].request.max_fee_sat, Some(100));
+
+        // Failed payments are not retried
+        let mut failed = due[0].clone();
+        failed.state = QueuedPaymentState::Failed;
+        failed.attempts = 1;
+        failed.last_error = Some("error".to_string());
+        storage.update_queued_payment(&failed)?;
+        assert!(storage.list_due_queued_payments(150)?.is_empty());
+        let fetched = storage.fetch_queued_payment("a")?.unwrap();
+        assert_eq!(fetched.state, QueuedPaymentState::Failed);
+        assert_eq!(fetched.attempts, 1);
+
+        assert!(storage.delete_queued_payment("a")?);
+        assert!(!storage.delete_queued_payment("a")?);
+        assert!(storage.fetch_queued_payment("a")?.is_none());
+
+        // Updating a removed payment does not add it back
+        storage.update_queued_payment(&failed)?;
+        assert!(storage.fetch_queued_payment("a")?.is_none());
+
+        Ok(())
+    }
+}
@@ -35,9 +35,10 @@ impl Persister {
                 claim_fees_sat,
                 mrh_address,
                 state,
-                pair_fees_json
+                pair_fees_json,
+                external_claim_address
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT DO NOTHING
             ",
             (
@@ -57,6 +58,7 @@ impl Persister {
                 &receive_swap.mrh_address,
                 &receive_swap.state,
                 &receive_swap.pair_fees_json,
+                &receive_swap.external_claim_address,
             ),
         )?;
 
@@ -157,6 +159,7 @@ impl Persister {
                 rs.pair_fees_json,
                 rs.version,
                 rs.last_updated_at,
+                rs.external_claim_address,
 
                 sync_state.is_local
             FROM receive_swaps AS rs
@@ -201,6 +204,7 @@ impl Persister {
             receiver_amount_sat: row.get(10)?,
             claim_fees_sat: row.get(11)?,
             claim_address: row.get(12)?,
+            external_claim_address: row.get(22)?,
             claim_tx_id: row.get(13)?,
             lockup_tx_id: row.get(14)?,
             mrh_address: row.get(15)?,
@@ -211,7 +215,7 @@ impl Persister {
             metadata: SwapMetadata {
                 version: row.get(20)?,
                 last_updated_at: row.get(21)?,
-                is_local: row.get::<usize, Option<bool>>(22)?.unwrap_or(true),
+                is_local: row.get::<usize, Option<bool>>(23)?.unwrap_or(true),
             },
         })
     }
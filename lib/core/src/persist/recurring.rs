@@ -0,0 +1,324 @@
+use anyhow::Result;
+use rusqlite::{params, Row};
+
+use crate::model::{RecurringPayment, RecurringPaymentOccurrence};
+use crate::persist::where_clauses_to_string;
+
+use super::Persister;
+
+/// The error of the occurrences of a recurring payment skipped as their window passed
+const MISSED_OCCURRENCE_ERROR: &str = "The occurrence was missed";
+
+impl Persister {
+    pub(crate) fn insert_recurring_payment(
+        &self,
+        recurring_payment: &RecurringPayment,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO recurring_payments (
+                id,
+                destination,
+                amount_sat,
+                interval_sec,
+                max_fee_sat,
+                created_at,
+                next_occurrence_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ",
+            params![
+                recurring_payment.id,
+                recurring_payment.destination,
+                recurring_payment.amount_sat,
+                recurring_payment.interval_sec,
+                recurring_payment.max_fee_sat,
+                recurring_payment.created_at,
+                recurring_payment.next_occurrence_at,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Sets the time of the next occurrence of a recurring payment. Returns whether the
+    /// recurring payment still exists, i.e. was not cancelled in the meantime.
+    pub(crate) fn set_recurring_payment_next_occurrence(
+        &self,
+        id: &str,
+        next_occurrence_at: u32,
+    ) -> Result<bool> {
+        let con = self.get_connection()?;
+        let updated = con.execute(
+            "UPDATE recurring_payments SET next_occurrence_at = ?1 WHERE id = ?2",
+            params![next_occurrence_at, id],
+        )?;
+
+        Ok(updated > 0)
+    }
+
+    pub(crate) fn list_recurring_payments(&self) -> Result<Vec<RecurringPayment>> {
+        let con = self.get_connection()?;
+        let query = Self::list_recurring_payments_query(vec![]);
+        let mut stmt = con.prepare(&query)?;
+        let recurring_payments = stmt
+            .query_map([], Self::sql_row_to_recurring_payment)?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(recurring_payments)
+    }
+
+    /// Lists the recurring payments whose next occurrence is due at `now`
+    pub(crate) fn list_due_recurring_payments(&self, now: u32) -> Result<Vec<RecurringPayment>> {
+        let con = self.get_connection()?;
+        let query =
+            Self::list_recurring_payments_query(vec!["next_occurrence_at <= ?1".to_string()]);
+        let mut stmt = con.prepare(&query)?;
+        let recurring_payments = stmt
+            .query_map([now], Self::sql_row_to_recurring_payment)?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(recurring_payments)
+    }
+
+    /// Removes a recurring payment, keeping its past occurrences. Returns whether a recurring
+    /// payment was removed.
+    pub(crate) fn delete_recurring_payment(&self, id: &str) -> Result<bool> {
+        let con = self.get_connection()?;
+        let deleted = con.execute("DELETE FROM recurring_payments WHERE id = ?", [id])?;
+        Ok(deleted > 0)
+    }
+
+    pub(crate) fn insert_recurring_payment_occurrence(
+        &self,
+        occurrence: &RecurringPaymentOccurrence,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT OR REPLACE INTO recurring_payment_occurrences (
+                recurring_payment_id,
+                occurrence_at,
+                executed_at,
+                tx_id,
+                error
+            )
+            VALUES (?, ?, ?, ?, ?)
+            ",
+            params![
+                occurrence.recurring_payment_id,
+                occurrence.occurrence_at,
+                occurrence.executed_at,
+                occurrence.tx_id,
+                occurrence.error,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Records the occurrences of a recurring payment at `occurrences_at` as missed. The
+    /// occurrences already recorded, i.e. sent or attempted, are kept as is.
+    pub(crate) fn insert_missed_recurring_payment_occurrences(
+        &self,
+        recurring_payment_id: &str,
+        occurrences_at: &[u32],
+        executed_at: u32,
+    ) -> Result<()> {
+        let mut con = self.get_connection()?;
+        let tx = con.transaction()?;
+        for occurrence_at in occurrences_at {
+            tx.execute(
+                "INSERT OR IGNORE INTO recurring_payment_occurrences (
+                    recurring_payment_id,
+                    occurrence_at,
+                    executed_at,
+                    tx_id,
+                    error
+                )
+                VALUES (?, ?, ?, NULL, ?)
+                ",
+                params![
+                    recurring_payment_id,
+                    occurrence_at,
+                    executed_at,
+                    MISSED_OCCURRENCE_ERROR,
+                ],
+            )?;
+        }
+        tx.commit()?;
+
+        Ok(())
+    }
+
+    /// Lists the occurrences of a recurring payment, oldest first
+    pub(crate) fn list_recurring_payment_occurrences(
+        &self,
+        recurring_payment_id: &str,
+    ) -> Result<Vec<RecurringPaymentOccurrence>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "
+            SELECT
+                recurring_payment_id,
+                occurrence_at,
+                executed_at,
+                tx_id,
+                error
+            FROM recurring_payment_occurrences
+            WHERE recurring_payment_id = ?1
+            ORDER BY occurrence_at ASC
+            ",
+        )?;
+        let occurrences = stmt
+            .query_map([recurring_payment_id], |row| {
+                Ok(RecurringPaymentOccurrence {
+                    recurring_payment_id: row.get(0)?,
+                    occurrence_at: row.get(1)?,
+                    executed_at: row.get(2)?,
+                    tx_id: row.get(3)?,
+                    error: row.get(4)?,
+                })
+            })?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(occurrences)
+    }
+
+    fn list_recurring_payments_query(where_clauses: Vec<String>) -> String {
+        let where_clause_str = where_clauses_to_string(where_clauses);
+
+        format!(
+            "
+            SELECT
+                id,
+                destination,
+                amount_sat,
+                interval_sec,
+                max_fee_sat,
+                created_at,
+                next_occurrence_at
+            FROM recurring_payments
+            {where_clause_str}
+            ORDER BY created_at ASC
+        "
+        )
+    }
+
+    fn sql_row_to_recurring_payment(row: &Row) -> rusqlite::Result<RecurringPayment> {
+        Ok(RecurringPayment {
+            id: row.get(0)?,
+            destination: row.get(1)?,
+            amount_sat: row.get(2)?,
+            interval_sec: row.get(3)?,
+            max_fee_sat: row.get(4)?,
+            created_at: row.get(5)?,
+            next_occurrence_at: row.get(6)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::{LiquidNetwork, PaymentType, RecurringPayment, RecurringPaymentOccurrence};
+    use crate::test_utils::persist::{create_persister, new_payment_tx_data};
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn new_recurring_payment(id: &str, next_occurrence_at: u32) -> RecurringPayment {
+        RecurringPayment {
+            id: id.to_string(),
+            destination: "alice@example.com".to_string(),
+            amount_sat: 1_000,
+            interval_sec: 86_400,
+            max_fee_sat: Some(100),
+            created_at: 0,
+            next_occurrence_at,
+        }
+    }
+
+    #[sdk_macros::test_all]
+    fn test_recurring_payments() -> Result<()> {
+        create_persister!(storage);
+
+        storage.insert_recurring_payment(&new_recurring_payment("a", 100))?;
+        storage.insert_recurring_payment(&new_recurring_payment("b", 200))?;
+        assert_eq!(storage.list_recurring_payments()?.len(), 2);
+
+        // Only the recurring payments whose next occurrence is due are listed
+        let due = storage.list_due_recurring_payments(150)?;
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, "a");
+        assert_eq!(due[0].max_fee_sat, Some(100));
+
+        assert!(storage.set_recurring_payment_next_occurrence("a", 86_500)?);
+        assert!(storage.list_due_recurring_payments(150)?.is_empty());
+
+        storage.insert_recurring_payment_occurrence(&RecurringPaymentOccurrence {
+            recurring_payment_id: "a".to_string(),
+            occurrence_at: 100,
+            executed_at: 110,
+            tx_id: Some("tx_id".to_string()),
+            error: None,
+        })?;
+        storage.insert_recurring_payment_occurrence(&RecurringPaymentOccurrence {
+            recurring_payment_id: "b".to_string(),
+            occurrence_at: 200,
+            executed_at: 210,
+            tx_id: None,
+            error: Some("error".to_string()),
+        })?;
+        let occurrences = storage.list_recurring_payment_occurrences("a")?;
+        assert_eq!(occurrences.len(), 1);
+        assert_eq!(occurrences[0].tx_id, Some("tx_id".to_string()));
+
+        // The missed occurrences don't overwrite the attempted ones
+        storage.insert_missed_recurring_payment_occurrences("b", &[200, 86_600], 90_000)?;
+        let occurrences = storage.list_recurring_payment_occurrences("b")?;
+        assert_eq!(occurrences.len(), 2);
+        assert_eq!(occurrences[0].error, Some("error".to_string()));
+        assert_eq!(occurrences[1].occurrence_at, 86_600);
+        assert_eq!(occurrences[1].tx_id, None);
+        assert!(occurrences[1].error.is_some());
+
+        // The past occurrences are kept once the recurring payment is cancelled
+        assert!(storage.delete_recurring_payment("a")?);
+        assert!(!storage.delete_recurring_payment("a")?);
+        assert!(!storage.set_recurring_payment_next_occurrence("a", 172_900)?);
+        assert_eq!(storage.list_recurring_payments()?.len(), 1);
+        assert_eq!(storage.list_recurring_payment_occurrences("a")?.len(), 1);
+
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_recurring_payment_link() -> Result<()> {
+        create_persister!(storage);
+
+        let payment_tx_data = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Send);
+        let tx_id = payment_tx_data.tx_id.clone();
+        storage.insert_or_update_payment(payment_tx_data, None, false)?;
+        assert_eq!(
+            storage.get_payment(&tx_id)?.unwrap().recurring_payment_id,
+            None
+        );
+
+        // The payment of an occurrence is linked to its recurring payment
+        storage.insert_recurring_payment(&new_recurring_payment("a", 100))?;
+        storage.insert_recurring_payment_occurrence(&RecurringPaymentOccurrence {
+            recurring_payment_id: "a".to_string(),
+            occurrence_at: 100,
+            executed_at: 110,
+            tx_id: Some(tx_id.clone()),
+            error: None,
+        })?;
+        assert_eq!(
+            storage.get_payment(&tx_id)?.unwrap().recurring_payment_id,
+            Some("a".to_string())
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use anyhow::Result;
+use rusqlite::ToSql;
+
+use super::Persister;
+use crate::model::{AssetReport, GetReportRequest};
+
+impl Persister {
+    /// Aggregates the payments of the period per asset. Failed, timed out and refunded swaps are
+    /// not included, nor are refund txs.
+    pub(crate) fn get_report(&self, req: &GetReportRequest) -> Result<Vec<AssetReport>> {
+        let mut where_clause = vec![
+            "ptx.tx_id NOT IN (SELECT refund_tx_id FROM send_swaps WHERE refund_tx_id NOT NULL)"
+                .to_string(),
+            "ptx.tx_id NOT IN (SELECT refund_tx_id FROM chain_swaps WHERE refund_tx_id NOT NULL)"
+                .to_string(),
+            // Ignore Failed, TimedOut, Refundable and RefundPending
+            "COALESCE(rs.state, ss.state, cs.state, 2) NOT IN (3, 4, 5, 6)".to_string(),
+        ];
+        let mut where_params: Vec<Box<dyn ToSql>> = vec![];
+        if let Some(t) = req.from_timestamp {
+            where_clause.push(
+                "COALESCE(ptx.timestamp, rs.created_at, ss.created_at, cs.created_at) >= ?"
+                    .to_string(),
+            );
+            where_params.push(Box::new(t));
+        }
+        if let Some(t) = req.to_timestamp {
+            where_clause.push(
+                "COALESCE(ptx.timestamp, rs.created_at, ss.created_at, cs.created_at) <= ?"
+                    .to_string(),
+            );
+            where_params.push(Box::new(t));
+        }
+
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(&format!(
+            "
+            SELECT
+                ptx.asset_id,
+                SUM(CASE WHEN ptx.payment_type = 0 THEN ptx.amount ELSE 0 END),
+                -- The lockup tx of a swap also pays the swap fees, which aren't part of the amount
+                SUM(CASE WHEN ptx.payment_type = 1 THEN COALESCE(
+                    ss.receiver_amount_sat,
+                    cs.accepted_receiver_amount_sat,
+                    cs.receiver_amount_sat,
+                    ptx.amount
+                ) ELSE 0 END),
+                -- The swap fees are what the swap kept of the claimed or locked up amount. The
+                -- lockup tx fee is paid by the wallet, so it's only counted in the onchain fees.
+                SUM(COALESCE(MAX(CASE WHEN ptx.payment_type = 0 THEN COALESCE(
+                    rs.payer_amount_sat,
+                    cs.actual_payer_amount_sat,
+                    cs.payer_amount_sat
+                ) - ptx.amount ELSE ptx.amount - COALESCE(
+                    ss.receiver_amount_sat,
+                    cs.accepted_receiver_amount_sat,
+                    cs.receiver_amount_sat
+                ) END, 0), 0)),
+                SUM(CASE WHEN ptx.payment_type = 1 THEN ptx.fees_sat ELSE 0 END),
+                SUM(ptx.payment_type = 0),
+                SUM(ptx.payment_type = 1)
+            FROM payment_tx_data AS ptx
+            LEFT JOIN receive_swaps AS rs
+                ON ptx.tx_id IN (rs.claim_tx_id, rs.mrh_tx_id)
+            LEFT JOIN send_swaps AS ss
+                ON ptx.tx_id = ss.lockup_tx_id
+            LEFT JOIN chain_swaps AS cs
+                ON ptx.tx_id IN (cs.user_lockup_tx_id, cs.claim_tx_id)
+            WHERE {}
+            GROUP BY ptx.asset_id
+            ORDER BY ptx.asset_id
+            ",
+            where_clause.join(" AND ")
+        ))?;
+        let reports = stmt
+            .query_map(
+                rusqlite::params_from_iter(where_params.iter().map(|p| p.as_ref())),
+                |row| {
+                    Ok(AssetReport {
+                        asset_id: row.get(0)?,
+                        received_amount: row.get(1)?,
+                        sent_amount: row.get(2)?,
+                        swap_fees_sat: row.get(3)?,
+                        onchain_fees_sat: row.get(4)?,
+                        received_count: row.get(5)?,
+                        sent_count: row.get(6)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(reports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::{GetReportRequest, LiquidNetwork, PaymentState, PaymentType};
+    use crate::test_utils::persist::{
+        create_persister, new_payment_tx_data, new_receive_swap, new_send_swap,
+    };
+    use crate::utils;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_get_report() -> Result<()> {
+        create_persister!(storage);
+
+        for (payment_type, amount, fees_sat, timestamp) in [
+            (PaymentType::Receive, 1_000, 0, 100),
+            (PaymentType::Receive, 2_000, 0, 200),
+            (PaymentType::Send, 500, 30, 300),
+        ] {
+            let mut payment_tx_data = new_payment_tx_data(LiquidNetwork::Testnet, payment_type);
+            payment_tx_data.amount = amount;
+            payment_tx_data.fees_sat = fees_sat;
+            payment_tx_data.timestamp = Some(timestamp);
+            payment_tx_data.is_confirmed = true;
+            storage.insert_or_update_payment(payment_tx_data, None, false)?;
+        }
+
+        // A send swap of 1_000 sat with a payer amount of 1_149 sat, locked up with a 21 sat fee
+        let mut send_swap = new_send_swap(Some(PaymentState::Complete), Some(1_000));
+        let mut lockup = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Send);
+        lockup.amount = send_swap.payer_amount_sat - 21;
+        lockup.fees_sat = 21;
+        lockup.timestamp = Some(400);
+        send_swap.lockup_tx_id = Some(lockup.tx_id.clone());
+        storage.insert_or_update_send_swap(&send_swap)?;
+        storage.insert_or_update_payment(lockup, None, false)?;
+
+        // A receive swap of an invoice of 1_000 sat, claimed with 957 sat
+        let mut receive_swap = new_receive_swap(Some(PaymentState::Complete), Some(957));
+        let mut claim = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Receive);
+        claim.amount = 957;
+        claim.timestamp = Some(500);
+        receive_swap.claim_tx_id = Some(claim.tx_id.clone());
+        storage.insert_or_update_receive_swap(&receive_swap)?;
+        storage.insert_or_update_payment(claim, None, false)?;
+
+        let reports = storage.get_report(&GetReportRequest::default())?;
+        assert_eq!(reports.len(), 1);
+        let report = &reports[0];
+        assert_eq!(
+            report.asset_id,
+            utils::lbtc_asset_id(LiquidNetwork::Testnet).to_string()
+        );
+        assert_eq!(report.received_amount, 3_957);
+        assert_eq!(report.received_count, 3);
+        // The swap fees aren't part of the sent amount
+        assert_eq!(report.sent_amount, 1_500);
+        assert_eq!(report.sent_count, 2);
+        // The lockup fee is only counted once, as an onchain fee
+        assert_eq!(report.onchain_fees_sat, 51);
+        assert_eq!(report.swap_fees_sat, 128 + 43);
+
+        // Only the payments of the period are aggregated
+        let reports = storage.get_report(&GetReportRequest {
+            from_timestamp: Some(150),
+            to_timestamp: Some(250),
+        })?;
+        assert_eq!(reports[0].received_amount, 2_000);
+        assert_eq!(reports[0].sent_count, 0);
+
+        Ok(())
+    }
+}
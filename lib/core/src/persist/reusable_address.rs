@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use rusqlite::params;
+
+use super::Persister;
+use crate::utils;
+
+/// A deposit to the reusable Bitcoin address, with the chain swap it is forwarded to
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ReusableAddressDeposit {
+    pub(crate) outpoint: String,
+    pub(crate) swap_id: String,
+    /// The txid of the tx forwarding the deposit to the swap lockup address, once broadcast
+    pub(crate) forward_tx_id: Option<String>,
+}
+
+impl Persister {
+    pub(crate) fn insert_reusable_address_deposit(
+        &self,
+        outpoint: &str,
+        swap_id: &str,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO reusable_address_deposits (outpoint, swap_id, created_at)
+            VALUES (?, ?, ?)",
+            params![outpoint, swap_id, utils::now()],
+        )?;
+
+        Ok(())
+    }
+
+    pub(crate) fn set_reusable_address_deposit_forward_tx_id(
+        &self,
+        outpoint: &str,
+        forward_tx_id: &str,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "UPDATE reusable_address_deposits SET forward_tx_id = ? WHERE outpoint = ?",
+            params![forward_tx_id, outpoint],
+        )?;
+
+        Ok(())
+    }
+
+    /// Replaces the chain swap a deposit is forwarded to, e.g. once the previous swap expired
+    /// before the deposit was forwarded
+    pub(crate) fn set_reusable_address_deposit_swap_id(
+        &self,
+        outpoint: &str,
+        swap_id: &str,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "UPDATE reusable_address_deposits
+            SET swap_id = ?, forward_tx_id = NULL
+            WHERE outpoint = ?",
+            params![swap_id, outpoint],
+        )?;
+
+        Ok(())
+    }
+
+    /// Lists the deposits to the reusable Bitcoin address, keyed by outpoint
+    pub(crate) fn list_reusable_address_deposits(
+        &self,
+    ) -> Result<HashMap<String, ReusableAddressDeposit>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "SELECT outpoint, swap_id, forward_tx_id
+            FROM reusable_address_deposits",
+        )?;
+        let deposits = stmt
+            .query_map([], |row| {
+                Ok(ReusableAddressDeposit {
+                    outpoint: row.get(0)?,
+                    swap_id: row.get(1)?,
+                    forward_tx_id: row.get(2)?,
+                })
+            })?
+            .map(|deposit| deposit.map(|deposit| (deposit.outpoint.clone(), deposit)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok(deposits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::ReusableAddressDeposit;
+    use crate::test_utils::persist::create_persister;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_reusable_address_deposits() -> Result<()> {
+        create_persister!(storage);
+
+        let outpoint = "0000000000000000000000000000000000000000000000000000000000000001:0";
+        storage.insert_reusable_address_deposit(outpoint, "swap-id")?;
+        assert!(storage
+            .insert_reusable_address_deposit(outpoint, "other-swap-id")
+            .is_err());
+
+        storage.set_reusable_address_deposit_swap_id(outpoint, "new-swap-id")?;
+        storage.set_reusable_address_deposit_forward_tx_id(outpoint, "forward-tx-id")?;
+        let deposits = storage.list_reusable_address_deposits()?;
+        assert_eq!(deposits.len(), 1);
+        assert_eq!(
+            deposits.get(outpoint),
+            Some(&ReusableAddressDeposit {
+                outpoint: outpoint.to_string(),
+                swap_id: "new-swap-id".to_string(),
+                forward_tx_id: Some("forward-tx-id".to_string()),
+            })
+        );
+
+        Ok(())
+    }
+}
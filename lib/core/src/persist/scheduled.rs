@@ -0,0 +1,273 @@
+use anyhow::Result;
+use rusqlite::{named_params, params, OptionalExtension, Row};
+
+use crate::model::{ScheduledPayment, ScheduledPaymentState};
+use crate::persist::where_clauses_to_string;
+
+use super::Persister;
+
+impl Persister {
+    pub(crate) fn insert_scheduled_payment(
+        &self,
+        scheduled_payment: &ScheduledPayment,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO scheduled_payments (
+                id,
+                request,
+                state,
+                attempts,
+                created_at,
+                next_attempt_at,
+                tx_id,
+                last_error
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            ",
+            params![
+                scheduled_payment.id,
+                serde_json::to_string(&scheduled_payment.request)?,
+                scheduled_payment.state,
+                scheduled_payment.attempts,
+                scheduled_payment.created_at,
+                scheduled_payment.next_attempt_at,
+                scheduled_payment.tx_id,
+                scheduled_payment.last_error,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Updates the attempt details of a scheduled payment. Does nothing if the payment was
+    /// cancelled in the meantime.
+    pub(crate) fn update_scheduled_payment(
+        &self,
+        scheduled_payment: &ScheduledPayment,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "UPDATE scheduled_payments
+            SET
+                state = :state,
+                attempts = :attempts,
+                next_attempt_at = :next_attempt_at,
+                tx_id = :tx_id,
+                last_error = :last_error
+            WHERE id = :id",
+            named_params! {
+                ":id": scheduled_payment.id,
+                ":state": scheduled_payment.state,
+                ":attempts": scheduled_payment.attempts,
+                ":next_attempt_at": scheduled_payment.next_attempt_at,
+                ":tx_id": scheduled_payment.tx_id,
+                ":last_error": scheduled_payment.last_error,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Marks a pending scheduled payment as [ScheduledPaymentState::InProgress] and counts the
+    /// attempt. Returns whether the payment was still pending, i.e. not cancelled in the meantime.
+    pub(crate) fn start_scheduled_payment_attempt(&self, id: &str) -> Result<bool> {
+        let con = self.get_connection()?;
+        let updated = con.execute(
+            "UPDATE scheduled_payments
+            SET
+                state = ?1,
+                attempts = attempts + 1
+            WHERE id = ?2 AND state = ?3",
+            params![
+                ScheduledPaymentState::InProgress,
+                id,
+                ScheduledPaymentState::Pending
+            ],
+        )?;
+        Ok(updated > 0)
+    }
+
+    /// Marks the scheduled payments whose attempt was interrupted as pending again, so they're
+    /// retried. Returns the number of payments reset.
+    pub(crate) fn reset_interrupted_scheduled_payments(&self) -> Result<usize> {
+        let con = self.get_connection()?;
+        let updated = con.execute(
+            "UPDATE scheduled_payments SET state = ?1 WHERE state = ?2",
+            params![
+                ScheduledPaymentState::Pending,
+                ScheduledPaymentState::InProgress
+            ],
+        )?;
+        Ok(updated)
+    }
+
+    pub(crate) fn fetch_scheduled_payment(&self, id: &str) -> Result<Option<ScheduledPayment>> {
+        let con = self.get_connection()?;
+        let query = Self::list_scheduled_payments_query(vec!["id = ?1".to_string()]);
+        Ok(con
+            .query_row(&query, [id], Self::sql_row_to_scheduled_payment)
+            .optional()?)
+    }
+
+    pub(crate) fn list_scheduled_payments(&self) -> Result<Vec<ScheduledPayment>> {
+        let con = self.get_connection()?;
+        let query = Self::list_scheduled_payments_query(vec![]);
+        let mut stmt = con.prepare(&query)?;
+        let scheduled_payments = stmt
+            .query_map([], Self::sql_row_to_scheduled_payment)?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(scheduled_payments)
+    }
+
+    /// Lists the pending scheduled payments that are due for an attempt at `now`
+    pub(crate) fn list_due_scheduled_payments(&self, now: u32) -> Result<Vec<ScheduledPayment>> {
+        let con = self.get_connection()?;
+        let query = Self::list_scheduled_payments_query(vec![
+            format!("state = {}", ScheduledPaymentState::Pending as i8),
+            "next_attempt_at <= ?1".to_string(),
+        ]);
+        let mut stmt = con.prepare(&query)?;
+        let scheduled_payments = stmt
+            .query_map([now], Self::sql_row_to_scheduled_payment)?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(scheduled_payments)
+    }
+
+    /// Removes a scheduled payment which is still pending, i.e. has no attempt running and was
+    /// neither executed nor failed. Returns whether a payment was removed.
+    pub(crate) fn delete_pending_scheduled_payment(&self, id: &str) -> Result<bool> {
+        let con = self.get_connection()?;
+        let deleted = con.execute(
+            "DELETE FROM scheduled_payments WHERE id = ?1 AND state = ?2",
+            params![id, ScheduledPaymentState::Pending],
+        )?;
+        Ok(deleted > 0)
+    }
+
+    fn list_scheduled_payments_query(where_clauses: Vec<String>) -> String {
+        let where_clause_str = where_clauses_to_string(where_clauses);
+
+        format!(
+            "
+            SELECT
+                id,
+                request,
+                state,
+                attempts,
+                created_at,
+                next_attempt_at,
+                tx_id,
+                last_error
+            FROM scheduled_payments
+            {where_clause_str}
+            ORDER BY created_at ASC
+        "
+        )
+    }
+
+    fn sql_row_to_scheduled_payment(row: &Row) -> rusqlite::Result<ScheduledPayment> {
+        let request: String = row.get(1)?;
+        let request = serde_json::from_str(&request).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(1, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+        Ok(ScheduledPayment {
+            id: row.get(0)?,
+            request,
+            state: row.get(2)?,
+            attempts: row.get(3)?,
+            created_at: row.get(4)?,
+            next_attempt_at: row.get(5)?,
+            tx_id: row.get(6)?,
+            last_error: row.get(7)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::model::{
+        PrepareSendRequest, SchedulePaymentRequest, ScheduledPayment, ScheduledPaymentState,
+    };
+    use crate::test_utils::persist::create_persister;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    fn new_scheduled_payment(id: &str, execute_at: u32) -> ScheduledPayment {
+        ScheduledPayment {
+            id: id.to_string(),
+            request: SchedulePaymentRequest {
+                prepare_request: PrepareSendRequest {
+                    destination: "lq1qqvynd50t4tajashdguell7nu9gycuqqd869w9vsrg6kf2yxhwd9c7h59ky9r2xfw3fttkk9xj74gj4ssq5kq6qmdjvm7hvx7".to_string(),
+                    amount: None,
+                    fiat_amount: None,
+                },
+                use_asset_fees: None,
+                max_fee_sat: Some(100),
+                execute_at,
+            },
+            state: ScheduledPaymentState::Pending,
+            attempts: 0,
+            created_at: 0,
+            next_attempt_at: execute_at,
+            tx_id: None,
+            last_error: None,
+        }
+    }
+
+    #[sdk_macros::test_all]
+    fn test_scheduled_payments() -> Result<()> {
+        create_persister!(storage);
+
+        storage.insert_scheduled_payment(&new_scheduled_payment("a", 100))?;
+        storage.insert_scheduled_payment(&new_scheduled_payment("b", 200))?;
+        assert_eq!(storage.list_scheduled_payments()?.len(), 2);
+
+        // Only the payments whose execution time is due are listed
+        let due = storage.list_due_scheduled_payments(150)?;
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, "a");
+        assert_eq!(due[0].request.execute_at, 100);
+
+        // Payments with a running attempt are not due anymore and can't be cancelled
+        assert!(storage.start_scheduled_payment_attempt("a")?);
+        assert!(!storage.start_scheduled_payment_attempt("a")?);
+        assert!(storage.list_due_scheduled_payments(150)?.is_empty());
+        let fetched = storage.fetch_scheduled_payment("a")?.unwrap();
+        assert_eq!(fetched.state, ScheduledPaymentState::InProgress);
+        assert_eq!(fetched.attempts, 1);
+        assert!(!storage.delete_pending_scheduled_payment("a")?);
+
+        // Interrupted attempts are due again once reset, keeping their attempt count
+        assert_eq!(storage.reset_interrupted_scheduled_payments()?, 1);
+        let due = storage.list_due_scheduled_payments(150)?;
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].attempts, 1);
+        assert!(storage.start_scheduled_payment_attempt("a")?);
+        let fetched = storage.fetch_scheduled_payment("a")?.unwrap();
+
+        // Executed payments can't be cancelled
+        let mut executed = fetched;
+        executed.state = ScheduledPaymentState::Executed;
+        executed.tx_id = Some("tx_id".to_string());
+        storage.update_scheduled_payment(&executed)?;
+        assert!(storage.list_due_scheduled_payments(150)?.is_empty());
+        let fetched = storage.fetch_scheduled_payment("a")?.unwrap();
+        assert_eq!(fetched.state, ScheduledPaymentState::Executed);
+        assert_eq!(fetched.tx_id, Some("tx_id".to_string()));
+        assert!(!storage.delete_pending_scheduled_payment("a")?);
+
+        // Pending payments can be cancelled, but can't be attempted afterwards
+        assert!(storage.delete_pending_scheduled_payment("b")?);
+        assert!(!storage.delete_pending_scheduled_payment("b")?);
+        assert!(!storage.start_scheduled_payment_attempt("b")?);
+        assert!(storage.fetch_scheduled_payment("b")?.is_none());
+
+        Ok(())
+    }
+}
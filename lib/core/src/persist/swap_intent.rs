@@ -0,0 +1,125 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use rusqlite::{params, Row};
+use strum_macros::{Display, EnumString};
+
+use super::Persister;
+use crate::utils;
+
+/// A side-effecting swap operation, journaled before it is performed so it can be reconciled at
+/// startup if the SDK stops before its outcome is persisted
+#[derive(Clone, Copy, Debug, Display, EnumString, PartialEq)]
+pub(crate) enum SwapIntentKind {
+    /// A claim tx is about to be broadcast
+    Claim,
+    /// A refund tx is about to be broadcast
+    Refund,
+    /// The fees of an amountless chain swap are about to be accepted
+    FeesAccepted,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SwapIntent {
+    pub(crate) swap_id: String,
+    pub(crate) kind: SwapIntentKind,
+    /// The id of the tx about to be broadcast, if any
+    pub(crate) tx_id: Option<String>,
+    pub(crate) created_at: u32,
+}
+
+impl Persister {
+    pub(crate) fn insert_swap_intent(
+        &self,
+        swap_id: &str,
+        kind: SwapIntentKind,
+        tx_id: Option<&str>,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT OR REPLACE INTO swap_intents (swap_id, kind, tx_id, created_at)
+            VALUES (?, ?, ?, ?)",
+            params![swap_id, kind.to_string(), tx_id, utils::now()],
+        )?;
+
+        Ok(())
+    }
+
+    pub(crate) fn delete_swap_intent(&self, swap_id: &str, kind: SwapIntentKind) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "DELETE FROM swap_intents WHERE swap_id = ? AND kind = ?",
+            params![swap_id, kind.to_string()],
+        )?;
+
+        Ok(())
+    }
+
+    pub(crate) fn list_swap_intents(&self) -> Result<Vec<SwapIntent>> {
+        let con = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "
+            SELECT
+                swap_id,
+                kind,
+                tx_id,
+                created_at
+            FROM swap_intents
+            ORDER BY created_at ASC
+        ",
+        )?;
+        let intents = stmt
+            .query_map([], Self::sql_row_to_swap_intent)?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(intents)
+    }
+
+    fn sql_row_to_swap_intent(row: &Row) -> rusqlite::Result<SwapIntent> {
+        let kind: String = row.get(1)?;
+        let kind = SwapIntentKind::from_str(&kind).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(1, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+        Ok(SwapIntent {
+            swap_id: row.get(0)?,
+            kind,
+            tx_id: row.get(2)?,
+            created_at: row.get(3)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::test_utils::persist::create_persister;
+
+    use super::SwapIntentKind;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_swap_intents() -> Result<()> {
+        create_persister!(storage);
+
+        storage.insert_swap_intent("swap-1", SwapIntentKind::Claim, Some("tx-1"))?;
+        storage.insert_swap_intent("swap-1", SwapIntentKind::Claim, Some("tx-2"))?;
+        storage.insert_swap_intent("swap-2", SwapIntentKind::FeesAccepted, None)?;
+
+        let intents = storage.list_swap_intents()?;
+        assert_eq!(intents.len(), 2);
+        let claim_intent = intents.iter().find(|i| i.swap_id == "swap-1").unwrap();
+        assert_eq!(claim_intent.kind, SwapIntentKind::Claim);
+        assert_eq!(claim_intent.tx_id.as_deref(), Some("tx-2"));
+
+        storage.delete_swap_intent("swap-1", SwapIntentKind::Claim)?;
+        let intents = storage.list_swap_intents()?;
+        assert_eq!(intents.len(), 1);
+        assert_eq!(intents[0].kind, SwapIntentKind::FeesAccepted);
+        assert!(intents[0].tx_id.is_none());
+
+        Ok(())
+    }
+}
@@ -12,8 +12,10 @@ use sdk_common::utils::Arc;
 use tokio::sync::broadcast;
 
 use crate::chain::liquid::LiquidChainService;
+use crate::in_flight::InFlightOperations;
 use crate::model::{BlockListener, PaymentState::*};
 use crate::model::{Config, PaymentTxData, PaymentType, ReceiveSwap};
+use crate::persist::swap_intent::SwapIntentKind;
 use crate::prelude::Swap;
 use crate::{ensure_sdk, utils};
 use crate::{
@@ -24,6 +26,13 @@ use crate::{
 /// The maximum acceptable amount in satoshi when claiming using zero-conf
 pub const DEFAULT_ZERO_CONF_MAX_SAT: u64 = 1_000_000;
 
+/// The minimum expiry in seconds of an invoice created when receiving via a swap
+pub const MIN_INVOICE_EXPIRY_SECS: u32 = 60;
+
+/// The maximum expiry in seconds of an invoice created when receiving via a swap.
+/// It is kept well below the swap timeout so the swap can still be claimed once paid.
+pub const MAX_INVOICE_EXPIRY_SECS: u32 = 43_200;
+
 pub(crate) struct ReceiveSwapHandler {
     config: Config,
     onchain_wallet: Arc<dyn OnchainWallet>,
@@ -31,6 +40,7 @@ pub(crate) struct ReceiveSwapHandler {
     swapper: Arc<dyn Swapper>,
     subscription_notifier: broadcast::Sender<String>,
     liquid_chain_service: Arc<dyn LiquidChainService>,
+    in_flight: Arc<InFlightOperations>,
 }
 
 #[sdk_macros::async_trait]
@@ -51,6 +61,7 @@ impl ReceiveSwapHandler {
         persister: Arc<Persister>,
         swapper: Arc<dyn Swapper>,
         liquid_chain_service: Arc<dyn LiquidChainService>,
+        in_flight: Arc<InFlightOperations>,
     ) -> Self {
         let (subscription_notifier, _) = broadcast::channel::<String>(30);
         Self {
@@ -60,6 +71,7 @@ impl ReceiveSwapHandler {
             swapper,
             subscription_notifier,
             liquid_chain_service,
+            in_flight,
         }
     }
 
@@ -78,6 +90,18 @@ impl ReceiveSwapHandler {
         info!("Handling Receive Swap transition to {swap_state:?} for swap {id}");
 
         match swap_state {
+            // A simulated swap has no lockup tx to claim, so it completes once the invoice is settled
+            RevSwapStates::TransactionMempool | RevSwapStates::TransactionConfirmed
+                if self.config.simulation.is_some() =>
+            {
+                self.update_swap_info(id, Pending, None, None, None, None)?;
+                Ok(())
+            }
+            RevSwapStates::InvoiceSettled if self.config.simulation.is_some() => {
+                self.update_swap_info(id, Complete, None, None, None, None)?;
+                Ok(())
+            }
+
             RevSwapStates::SwapExpired
             | RevSwapStates::InvoiceExpired
             | RevSwapStates::TransactionFailed
@@ -171,7 +195,7 @@ impl ReceiveSwapHandler {
 
                 if receive_swap.metadata.is_local {
                     // Only claim a local swap
-                    if let Err(err) = self.claim(id).await {
+                    if let Err(err) = self.auto_claim(id).await {
                         match err {
                             PaymentError::AlreadyClaimed => {
                                 warn!("Funds already claimed for Receive Swap {id}")
@@ -236,7 +260,7 @@ impl ReceiveSwapHandler {
 
                         if receive_swap.metadata.is_local {
                             // Only claim a local swap
-                            if let Err(err) = self.claim(id).await {
+                            if let Err(err) = self.auto_claim(id).await {
                                 match err {
                                     PaymentError::AlreadyClaimed => {
                                         warn!("Funds already claimed for Receive Swap {id}")
@@ -317,7 +341,18 @@ impl ReceiveSwapHandler {
         Ok(())
     }
 
-    async fn claim(&self, swap_id: &str) -> Result<(), PaymentError> {
+    /// Claims the swap once its lockup is verified, unless [Config::manual_claim] is set
+    #[tracing::instrument(skip(self))]
+    async fn auto_claim(&self, swap_id: &str) -> Result<(), PaymentError> {
+        if self.config.manual_claim {
+            debug!("Receive Swap {swap_id} is claimable, waiting for a manual claim");
+            return Ok(());
+        }
+        self.claim(swap_id).await
+    }
+
+    pub(crate) async fn claim(&self, swap_id: &str) -> Result<(), PaymentError> {
+        let _in_flight = self.in_flight.start();
         let swap = self.fetch_receive_swap_by_id(swap_id)?;
         ensure_sdk!(swap.claim_tx_id.is_none(), PaymentError::AlreadyClaimed);
 
@@ -325,8 +360,12 @@ impl ReceiveSwapHandler {
         let claim_address = match swap.claim_address {
             Some(ref claim_address) => claim_address.clone(),
             None => {
-                // If no claim address is set, we get an unused one
-                let address = self.onchain_wallet.next_unused_address().await?.to_string();
+                // If no claim address is set, we use the external one chosen on swap creation,
+                // otherwise we get an unused one
+                let address = match swap.external_claim_address {
+                    Some(ref external_claim_address) => external_claim_address.clone(),
+                    None => self.onchain_wallet.next_unused_address().await?.to_string(),
+                };
                 self.persister
                     .set_receive_swap_claim_address(&swap.id, &address)?;
                 address
@@ -346,7 +385,11 @@ impl ReceiveSwapHandler {
         // Set the swap claim_tx_id before broadcasting.
         // If another claim_tx_id has been set in the meantime, don't broadcast the claim tx
         let tx_id = claim_tx.txid().to_hex();
-        match self.persister.set_receive_swap_claim_tx_id(swap_id, &tx_id) {
+        // Journal the claim, so that a claim_tx_id left set without a broadcast tx is reconciled
+        // at startup
+        self.persister
+            .insert_swap_intent(swap_id, SwapIntentKind::Claim, Some(&tx_id))?;
+        let claim_res = match self.persister.set_receive_swap_claim_tx_id(swap_id, &tx_id) {
             Ok(_) => {
                 // We attempt broadcasting via chain service, then fallback to Boltz
                 let broadcast_res = match self.liquid_chain_service.broadcast(&claim_tx).await {
@@ -364,21 +407,24 @@ impl ReceiveSwapHandler {
                 match broadcast_res {
                     Ok(claim_tx_id) => {
                         // We insert a pseudo-claim-tx in case LWK fails to pick up the new mempool tx for a while
-                        // This makes the tx known to the SDK (get_info, list_payments) instantly
-                        self.persister.insert_or_update_payment(
-                            PaymentTxData {
-                                tx_id: claim_tx_id.clone(),
-                                timestamp: Some(utils::now()),
-                                asset_id: self.config.lbtc_asset_id(),
-                                amount: swap.receiver_amount_sat,
-                                fees_sat: 0,
-                                payment_type: PaymentType::Receive,
-                                is_confirmed: false,
-                                unblinding_data: None,
-                            },
-                            None,
-                            false,
-                        )?;
+                        // This makes the tx known to the SDK (get_info, list_payments) instantly.
+                        // A claim to an external address is never picked up by the wallet, so it's skipped
+                        if swap.external_claim_address.is_none() {
+                            self.persister.insert_or_update_payment(
+                                PaymentTxData {
+                                    tx_id: claim_tx_id.clone(),
+                                    timestamp: Some(utils::now()),
+                                    asset_id: self.config.lbtc_asset_id(),
+                                    amount: swap.receiver_amount_sat,
+                                    fees_sat: 0,
+                                    payment_type: PaymentType::Receive,
+                                    is_confirmed: false,
+                                    unblinding_data: None,
+                                },
+                                None,
+                                false,
+                            )?;
+                        }
 
                         info!("Successfully broadcast claim tx {claim_tx_id} for Receive Swap {swap_id}");
                         // The claim_tx_id is already set by set_receive_swap_claim_tx_id. Manually trigger notifying
@@ -403,7 +449,10 @@ impl ReceiveSwapHandler {
                 );
                 Err(err)
             }
-        }
+        };
+        self.persister
+            .delete_swap_intent(swap_id, SwapIntentKind::Claim)?;
+        claim_res
     }
 
     async fn claim_confirmed_lockups(&self, height: u32) -> Result<()> {
@@ -447,7 +496,7 @@ impl ReceiveSwapHandler {
             return Err(e);
         }
         info!("Receive Swap {swap_id} lockup tx is confirmed");
-        self.claim(swap_id)
+        self.auto_claim(swap_id)
             .await
             .map_err(|e| anyhow!("Could not claim Receive Swap {swap_id}: {e:?}"))
     }
@@ -553,12 +602,17 @@ mod tests {
     use std::collections::{HashMap, HashSet};
 
     use anyhow::Result;
+    use boltz_client::{boltz, swaps::boltz::RevSwapStates};
 
     use crate::{
-        model::PaymentState::{self, *},
+        model::{
+            Config,
+            PaymentState::{self, *},
+            ReceiveSwap, SimulationConfig,
+        },
         test_utils::{
             persist::{create_persister, new_receive_swap},
-            receive_swap::new_receive_swap_handler,
+            receive_swap::{new_receive_swap_handler, new_receive_swap_handler_with_config},
         },
     };
 
@@ -621,4 +675,98 @@ mod tests {
 
         Ok(())
     }
+
+    #[sdk_macros::async_test_all]
+    async fn test_manual_claim() -> Result<()> {
+        create_persister!(persister);
+
+        let mut config = Config::testnet_esplora(None);
+        config.manual_claim = true;
+        let receive_swap_handler = new_receive_swap_handler_with_config(persister.clone(), config)?;
+
+        let receive_swap = new_receive_swap(Some(Pending), None);
+        persister.insert_or_update_receive_swap(&receive_swap)?;
+
+        // The swap is not claimed automatically
+        receive_swap_handler.auto_claim(&receive_swap.id).await?;
+        let persisted_swap = persister
+            .fetch_receive_swap_by_id(&receive_swap.id)?
+            .expect("Expected persisted swap");
+        assert!(persisted_swap.claim_tx_id.is_none());
+
+        // The swap is claimed once requested
+        receive_swap_handler.claim(&receive_swap.id).await?;
+        let persisted_swap = persister
+            .fetch_receive_swap_by_id(&receive_swap.id)?
+            .expect("Expected persisted swap");
+        assert!(persisted_swap.claim_tx_id.is_some());
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_claim_to_external_address() -> Result<()> {
+        create_persister!(persister);
+
+        let receive_swap_handler = new_receive_swap_handler(persister.clone())?;
+
+        let external_claim_address = "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5";
+        let receive_swap = ReceiveSwap {
+            external_claim_address: Some(external_claim_address.to_string()),
+            ..new_receive_swap(Some(Pending), None)
+        };
+        persister.insert_or_update_receive_swap(&receive_swap)?;
+
+        receive_swap_handler.claim(&receive_swap.id).await?;
+        let persisted_swap = persister
+            .fetch_receive_swap_by_id(&receive_swap.id)?
+            .expect("Expected persisted swap");
+        assert_eq!(
+            persisted_swap.external_claim_address.as_deref(),
+            Some(external_claim_address)
+        );
+        assert_eq!(
+            persisted_swap.claim_address.as_deref(),
+            Some(external_claim_address)
+        );
+        assert!(persisted_swap.claim_tx_id.is_some());
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_simulated_receive_swap_completes() -> Result<()> {
+        create_persister!(persister);
+
+        let mut config = Config::testnet_esplora(None);
+        config.simulation = Some(SimulationConfig {
+            status_update_delay_sec: 0,
+            failure_rate: 0.0,
+        });
+        let receive_swap_handler = new_receive_swap_handler_with_config(persister.clone(), config)?;
+
+        let receive_swap = new_receive_swap(None, None);
+        persister.insert_or_update_receive_swap(&receive_swap)?;
+
+        // The simulated statuses carry no lockup tx, yet the swap completes without a claim
+        for (status, expected_state) in [
+            (RevSwapStates::TransactionMempool, Pending),
+            (RevSwapStates::InvoiceSettled, Complete),
+        ] {
+            receive_swap_handler
+                .on_new_status(&boltz::SwapStatus {
+                    id: receive_swap.id.clone(),
+                    status: status.to_string(),
+                    ..Default::default()
+                })
+                .await?;
+            let persisted_swap = persister
+                .fetch_receive_swap_by_id(&receive_swap.id)?
+                .expect("Expected persisted swap");
+            assert_eq!(persisted_swap.state, expected_state);
+            assert!(persisted_swap.claim_tx_id.is_none());
+        }
+
+        Ok(())
+    }
 }
@@ -174,8 +174,11 @@ impl ChainReceiveSwapHandler {
         let secp = secp256k1_zkp::Secp256k1::new();
 
         // Determine lockup and claim txs
-        let (lbtc_server_lockup_tx_id, lbtc_claim_tx_id) =
-            determine_incoming_lockup_and_claim_txs(&history.lbtc_claim_script_history, tx_map);
+        let (lbtc_server_lockup_tx_id, lbtc_claim_tx_id) = determine_incoming_lockup_and_claim_txs(
+            &history.lbtc_claim_script_history,
+            tx_map,
+            None,
+        );
 
         // Get claim address from tx
         let lbtc_claim_address = if let Some(claim_tx_id) = &lbtc_claim_tx_id {
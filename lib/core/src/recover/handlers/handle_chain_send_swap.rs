@@ -85,8 +85,12 @@ impl ChainSendSwapHandler {
         };
 
         // First obtain transaction IDs from the history
-        let recovered_data =
+        let mut recovered_data =
             Self::recover_onchain_data(&context.tx_map, swap_id, history, &claim_script)?;
+        recovered_data.require_claim_confirmations(
+            context.bitcoin_tip_height,
+            context.outgoing_min_confirmations,
+        );
 
         // Update the swap with recovered data
         Self::update_swap(
@@ -217,6 +221,22 @@ pub(crate) struct RecoveredOnchainDataChainSend {
 // after the expiration of the swap and if new funds are detected on the lockup script they are refunded.
 // Perhaps we should check in the recovery the lockup balance and set accordingly.
 impl RecoveredOnchainDataChainSend {
+    /// Treats the BTC claim tx as unconfirmed until it has `min_confirmations`, so that the swap
+    /// is only derived as Complete once the claim tx is deep enough
+    pub(crate) fn require_claim_confirmations(
+        &mut self,
+        bitcoin_tip_height: u32,
+        min_confirmations: u32,
+    ) {
+        if let Some(btc_claim_tx_id) = self.btc_claim_tx_id.as_mut() {
+            if btc_claim_tx_id.confirmed()
+                && btc_claim_tx_id.confirmations(bitcoin_tip_height) < min_confirmations
+            {
+                btc_claim_tx_id.height = 0;
+            }
+        }
+    }
+
     pub(crate) fn derive_partial_state(&self, is_expired: bool) -> Option<PaymentState> {
         match &self.lbtc_user_lockup_tx_id {
             Some(_) => match (&self.btc_claim_tx_id, &self.lbtc_refund_tx_id) {
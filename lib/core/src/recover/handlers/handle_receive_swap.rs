@@ -1,7 +1,7 @@
 use anyhow::Result;
 use boltz_client::ElementsAddress;
 use log::{debug, warn};
-use lwk_wollet::elements::Txid;
+use lwk_wollet::elements::{Script, Transaction, Txid};
 use lwk_wollet::WalletTx;
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -71,9 +71,34 @@ impl ReceiveSwapHandler {
                 .collect(),
         };
 
+        // A claim to an external address is not a wallet tx. It is recognized by its tx id if it
+        // was broadcast by this instance, otherwise by its output to the external address.
+        let known_claim_tx_id = match &receive_swap.external_claim_address {
+            Some(external_claim_address) => match receive_swap
+                .claim_tx_id
+                .as_deref()
+                .and_then(|tx_id| Txid::from_str(tx_id).ok())
+            {
+                Some(claim_tx_id) => Some(claim_tx_id),
+                None => {
+                    Self::fetch_external_claim_tx_id(
+                        context,
+                        &history.lbtc_claim_script_history,
+                        external_claim_address,
+                    )
+                    .await?
+                }
+            },
+            None => None,
+        };
+
         // First obtain recovered data from the history
-        let recovered_data =
-            Self::recover_onchain_data(&context.tx_map, &history, receive_swap.created_at)?;
+        let recovered_data = Self::recover_onchain_data(
+            &context.tx_map,
+            &history,
+            receive_swap.created_at,
+            known_claim_tx_id,
+        )?;
 
         // Update the swap with recovered data
         Self::update_swap(
@@ -84,6 +109,42 @@ impl ReceiveSwapHandler {
         )
     }
 
+    /// Looks for a claim to the external claim address among the claim script txs which are not
+    /// wallet txs
+    async fn fetch_external_claim_tx_id(
+        context: &RecoveryContext,
+        claim_script_history: &[LBtcHistory],
+        external_claim_address: &str,
+    ) -> Result<Option<Txid>> {
+        // Only a lockup followed by a claim or a swapper refund is expected
+        if claim_script_history.len() != 2 {
+            return Ok(None);
+        }
+        let tx_ids: Vec<Txid> = claim_script_history
+            .iter()
+            .map(|h| h.txid)
+            .filter(|tx_id| !context.tx_map.incoming_tx_map.contains_key(tx_id))
+            .collect();
+        if tx_ids.is_empty() {
+            return Ok(None);
+        }
+
+        let external_claim_script = ElementsAddress::from_str(external_claim_address)
+            .map_err(|_| anyhow::anyhow!("Invalid external claim address"))?
+            .script_pubkey();
+        let txs = context
+            .liquid_chain_service
+            .get_transactions(&tx_ids)
+            .await?;
+        Ok(Self::find_tx_paying_to(&txs, &external_claim_script))
+    }
+
+    fn find_tx_paying_to(txs: &[Transaction], script: &Script) -> Option<Txid> {
+        txs.iter()
+            .find(|tx| tx.output.iter().any(|out| &out.script_pubkey == script))
+            .map(|tx| tx.txid())
+    }
+
     /// Update a receive swap with recovered data
     pub fn update_swap(
         receive_swap: &mut ReceiveSwap,
@@ -134,6 +195,7 @@ impl ReceiveSwapHandler {
         tx_map: &TxMap,
         history: &ReceiveSwapHistory,
         swap_timestamp: u32,
+        known_claim_tx_id: Option<Txid>,
     ) -> Result<RecoveredOnchainDataReceive> {
         // The MRH script history txs filtered by the swap timestamp
         let mrh_txs: HashMap<Txid, WalletTx> = history
@@ -155,8 +217,11 @@ impl ReceiveSwapHandler {
             .and_then(|h| mrh_txs.get(&h.txid))
             .map(|tx| tx.balance.values().sum::<i64>().unsigned_abs());
 
-        let (lockup_tx_id, claim_tx_id) =
-            determine_incoming_lockup_and_claim_txs(&history.lbtc_claim_script_history, tx_map);
+        let (lockup_tx_id, claim_tx_id) = determine_incoming_lockup_and_claim_txs(
+            &history.lbtc_claim_script_history,
+            tx_map,
+            known_claim_tx_id,
+        );
 
         // Take only the lockup_tx_id and claim_tx_id if either are set,
         // otherwise take the mrh_tx_id and mrh_amount_sat
@@ -15,10 +15,17 @@ use super::model::TxMap;
 use crate::model::LBtcHistory;
 
 /// Helper function for determining lockup and claim transactions in incoming swaps
+///
+/// The claim tx is identified either as a known incoming wallet tx, or as the `known_claim_tx_id`
+/// when it is set, e.g. for a claim to an address outside of the wallet.
 pub(crate) fn determine_incoming_lockup_and_claim_txs(
     history: &[LBtcHistory],
     tx_map: &TxMap,
+    known_claim_tx_id: Option<Txid>,
 ) -> (Option<LBtcHistory>, Option<LBtcHistory>) {
+    let is_claim_tx = |txid: &Txid| {
+        tx_map.incoming_tx_map.contains_key::<Txid>(txid) || known_claim_tx_id == Some(*txid)
+    };
     match history.len() {
         // Only lockup tx available
         1 => (Some(history[0].clone()), None),
@@ -26,10 +33,10 @@ pub(crate) fn determine_incoming_lockup_and_claim_txs(
             let first = history[0].clone();
             let second = history[1].clone();
 
-            if tx_map.incoming_tx_map.contains_key::<Txid>(&first.txid) {
+            if is_claim_tx(&first.txid) {
                 // If the first tx is a known incoming tx, it's the claim tx and the second is the lockup
                 (Some(second), Some(first))
-            } else if tx_map.incoming_tx_map.contains_key::<Txid>(&second.txid) {
+            } else if is_claim_tx(&second.txid) {
                 // If the second tx is a known incoming tx, it's the claim tx and the first is the lockup
                 (Some(first), Some(second))
             } else {
@@ -368,6 +368,7 @@ mod test {
             claim_fees_sat: 1000,
             accept_zero_conf: true,
             auto_accepted_fees: true,
+            refundable_since: None,
         };
 
         // Create empty recovery context
@@ -382,6 +383,7 @@ mod test {
             },
             liquid_tip_height: 900, // Below timeout height
             bitcoin_tip_height: 900,
+            outgoing_min_confirmations: 1,
             master_blinding_key: MasterBlindingKey::from_seed(&[]),
             swapper: Arc::new(MockSwapper::new()),
             liquid_chain_service: Arc::new(MockLiquidChainService::new()),
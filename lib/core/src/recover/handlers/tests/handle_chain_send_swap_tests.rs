@@ -49,6 +49,32 @@ mod test {
         );
     }
 
+    #[sdk_macros::test_all]
+    fn test_require_claim_confirmations() {
+        let new_recovered_data = || RecoveredOnchainDataChainSend {
+            lbtc_user_lockup_tx_id: Some(create_lbtc_history_txid("1111", 100)),
+            lbtc_refund_tx_id: None,
+            btc_server_lockup_tx_id: Some(create_btc_history_txid("2222", 101)),
+            btc_claim_tx_id: Some(create_btc_history_txid("3333", 102)),
+        };
+
+        // The claim tx has 2 confirmations, which is enough
+        let mut recovered_data = new_recovered_data();
+        recovered_data.require_claim_confirmations(103, 2);
+        assert_eq!(
+            recovered_data.derive_partial_state(false),
+            Some(PaymentState::Complete)
+        );
+
+        // The claim tx has 2 confirmations, but 3 are required
+        let mut recovered_data = new_recovered_data();
+        recovered_data.require_claim_confirmations(103, 3);
+        assert_eq!(
+            recovered_data.derive_partial_state(false),
+            Some(PaymentState::Pending)
+        );
+    }
+
     #[sdk_macros::test_all]
     fn test_derive_partial_state_with_lockup_and_refund() {
         // Test with confirmed refund
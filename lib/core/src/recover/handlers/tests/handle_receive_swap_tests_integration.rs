@@ -5,12 +5,15 @@ mod test {
         elements,
         model::{LBtcHistory, PaymentState, ReceiveSwap, SwapMetadata},
         recover::{
-            handlers::{tests::create_mock_lbtc_wallet_tx, ReceiveSwapHandler},
+            handlers::{
+                tests::{create_empty_lbtc_transaction, create_mock_lbtc_wallet_tx},
+                ReceiveSwapHandler,
+            },
             model::{RecoveryContext, TxMap},
         },
         swapper::MockSwapper,
     };
-    use elements::{Address as ElementsAddress, Script, Txid};
+    use elements::{Address as ElementsAddress, Script, TxOut, Txid};
     use lwk_wollet::{elements_miniscript::slip77::MasterBlindingKey, WalletTx};
     use sdk_common::utils::Arc;
     use std::{collections::HashMap, str::FromStr};
@@ -58,6 +61,59 @@ mod test {
         assert_eq!(receive_swap.lockup_tx_id, Some(lockup_tx_id.to_string()));
     }
 
+    #[sdk_macros::async_test_all]
+    async fn test_recover_with_external_claim_tx() {
+        // Setup mock data, without the claim tx id as on a synced or restored instance
+        let (mut receive_swap, mut recovery_context) = setup_test_data();
+        let external_claim_address = "lq1qqvynd50t4tajashdguell7nu9gycuqqd869w9vsrg6kf2yxhwd9c7h59ky9r2xfw3fttkk9xj74gj4ssq5kq6qmdjvm7hvx7";
+        receive_swap.external_claim_address = Some(external_claim_address.to_string());
+
+        let claim_script = receive_swap.claim_script().unwrap();
+        let lockup_tx_id = "1111111111111111111111111111111111111111111111111111111111111111";
+        recovery_context = add_lockup_tx_to_context(
+            recovery_context,
+            &claim_script,
+            lockup_tx_id,
+            100, // Confirmed
+        );
+
+        // The claim tx pays to the external address, so it's not a wallet tx
+        let mut claim_tx = create_empty_lbtc_transaction();
+        claim_tx.output.push(TxOut {
+            script_pubkey: ElementsAddress::from_str(external_claim_address)
+                .unwrap()
+                .script_pubkey(),
+            ..Default::default()
+        });
+        let claim_tx_id = claim_tx.txid().to_string();
+        recovery_context = add_lockup_tx_to_context(
+            recovery_context,
+            &claim_script,
+            &claim_tx_id,
+            101, // Confirmed
+        );
+
+        let mut mock_liquid_chain_service = MockLiquidChainService::new();
+        mock_liquid_chain_service
+            .expect_get_transactions()
+            .returning(move |_| Ok(vec![claim_tx.clone()]));
+        recovery_context.liquid_chain_service = Arc::new(mock_liquid_chain_service);
+
+        // Test recover swap
+        let result = ReceiveSwapHandler::recover_swap(
+            &mut receive_swap,
+            &recovery_context,
+            false, // Not within grace period
+        )
+        .await;
+
+        // The claim is recognized instead of being taken for a swapper refund
+        assert!(result.is_ok());
+        assert_eq!(receive_swap.state, PaymentState::Complete);
+        assert_eq!(receive_swap.claim_tx_id, Some(claim_tx_id));
+        assert_eq!(receive_swap.lockup_tx_id, Some(lockup_tx_id.to_string()));
+    }
+
     #[sdk_macros::async_test_all]
     async fn test_recover_with_mrh_tx() {
         // Setup mock data
@@ -280,6 +336,7 @@ mod test {
             pair_fees_json: r#"{"id":"BTC/BTC","rate":0.997,"limits":{"maximal":2000000,"minimal":10000,"maximalZeroConf":50000},"fees":{"percentage":0.5,"miner":200}}"#.to_string(),
             claim_fees_sat: 500,
             claim_address: None,
+            external_claim_address: None,
             claim_tx_id: None,
             lockup_tx_id: None,
             mrh_address: "lq1qqvynd50t4tajashdguell7nu9gycuqqd869w8vqww9ys9dsz7szdfeu7pwe4yzzme28qsluyfyrtqmq9scl5ydw4lesx3c5qu".to_string(),
@@ -306,6 +363,7 @@ mod test {
             },
             liquid_tip_height: 900, // Below timeout height
             bitcoin_tip_height: 900,
+            outgoing_min_confirmations: 1,
             master_blinding_key: MasterBlindingKey::from_seed(&[]),
             swapper: Arc::new(MockSwapper::new()),
             liquid_chain_service: Arc::new(MockLiquidChainService::new()),
@@ -358,6 +358,7 @@ mod test {
             },
             liquid_tip_height: 900, // Below timeout height
             bitcoin_tip_height: 900,
+            outgoing_min_confirmations: 1,
             master_blinding_key: MasterBlindingKey::from_seed(&[]),
             swapper: Arc::new(MockSwapper::new()),
             liquid_chain_service: Arc::new(MockLiquidChainService::new()),
@@ -152,4 +152,6 @@ pub(crate) struct RecoveryContext {
     pub(crate) master_blinding_key: MasterBlindingKey,
     pub(crate) liquid_tip_height: u32,
     pub(crate) bitcoin_tip_height: u32,
+    /// See [crate::model::Config::outgoing_min_confirmations]
+    pub(crate) outgoing_min_confirmations: u32,
 }
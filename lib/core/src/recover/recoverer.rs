@@ -37,6 +37,7 @@ pub struct Recoverer {
     liquid_chain_service: Arc<dyn LiquidChainService>,
     bitcoin_chain_service: Arc<dyn BitcoinChainService>,
     persister: Arc<Persister>,
+    outgoing_min_confirmations: u32,
 }
 
 impl Recoverer {
@@ -47,6 +48,7 @@ impl Recoverer {
         liquid_chain_service: Arc<dyn LiquidChainService>,
         bitcoin_chain_service: Arc<dyn BitcoinChainService>,
         persister: Arc<Persister>,
+        outgoing_min_confirmations: u32,
     ) -> Result<Self> {
         Ok(Self {
             master_blinding_key: MasterBlindingKey::from_hex(
@@ -57,6 +59,7 @@ impl Recoverer {
             liquid_chain_service,
             bitcoin_chain_service,
             persister,
+            outgoing_min_confirmations,
         })
     }
 
@@ -72,6 +75,7 @@ impl Recoverer {
     /// - `swaps`: The swaps for which we want to recover onchain data.
     ///
     /// Returns the raw onchain tx map used for recovery.
+    #[tracing::instrument(skip_all, fields(swap_count = swaps.len()))]
     pub(crate) async fn recover_from_onchain(
         &self,
         swaps: &mut [Swap],
@@ -215,6 +219,7 @@ impl Recoverer {
             tx_map,
             liquid_tip_height,
             bitcoin_tip_height,
+            outgoing_min_confirmations: self.outgoing_min_confirmations,
             master_blinding_key,
             liquid_chain_service: self.liquid_chain_service.clone(),
             swapper: self.swapper.clone(),
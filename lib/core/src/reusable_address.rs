@@ -0,0 +1,160 @@
+use anyhow::{anyhow, Result};
+use sdk_common::utils::Arc;
+
+use crate::bitcoin::{
+    absolute::LockTime,
+    bip32::Xpub,
+    hashes::Hash as _,
+    sighash::{EcdsaSighashType, SighashCache},
+    transaction::Version,
+    Address, Amount, CompressedPublicKey, Network, OutPoint, Script, ScriptBuf, Sequence,
+    Transaction, TxIn, TxOut, Witness,
+};
+use crate::model::{LiquidNetwork, Signer};
+
+/// The derivation path of the key controlling the reusable address on mainnet. It uses a
+/// dedicated account, so the key is not shared with a BIP84 wallet of the same mnemonic.
+const MAINNET_DERIVATION_PATH: &str = "m/84'/0'/1776'/0/0";
+/// The derivation path of the key controlling the reusable address on testnet and regtest. It
+/// uses a dedicated account, so the key is not shared with the Liquid testnet wallet.
+const TESTNET_DERIVATION_PATH: &str = "m/84'/1'/1776'/0/0";
+/// The vsize of a forward tx, with a P2WPKH input and a P2TR swap lockup output
+const FORWARD_TX_VSIZE: u64 = 122;
+
+/// A Bitcoin address that can be paid to repeatedly. Each deposit is forwarded to the lockup
+/// address of a new incoming chain swap.
+///
+/// The address is a P2WPKH address of a key derived from the wallet signer, so deposits that
+/// can't be forwarded can be recovered with any Bitcoin wallet using the same derivation path.
+pub(crate) struct ReusableAddress {
+    signer: Arc<Box<dyn Signer>>,
+    derivation_path: &'static str,
+    public_key: CompressedPublicKey,
+    address: Address,
+}
+
+impl ReusableAddress {
+    pub(crate) fn new(signer: Arc<Box<dyn Signer>>, network: LiquidNetwork) -> Result<Self> {
+        let (derivation_path, bitcoin_network) = match network {
+            LiquidNetwork::Mainnet => (MAINNET_DERIVATION_PATH, Network::Bitcoin),
+            LiquidNetwork::Testnet => (TESTNET_DERIVATION_PATH, Network::Testnet),
+            LiquidNetwork::Regtest => (TESTNET_DERIVATION_PATH, Network::Regtest),
+        };
+        let xpub = Xpub::decode(&signer.derive_xpub(derivation_path.to_string())?)?;
+        let public_key = xpub.to_pub();
+        let address = Address::p2wpkh(&public_key, bitcoin_network);
+        Ok(Self {
+            signer,
+            derivation_path,
+            public_key,
+            address,
+        })
+    }
+
+    pub(crate) fn address(&self) -> &Address {
+        &self.address
+    }
+
+    /// The fee of a forward tx at `fee_rate_sat_per_vbyte`
+    pub(crate) fn forward_fee_sat(fee_rate_sat_per_vbyte: u64) -> u64 {
+        FORWARD_TX_VSIZE * fee_rate_sat_per_vbyte
+    }
+
+    /// The amount forwarded from a deposit of `deposit_amount_sat`, once the forward tx fee at
+    /// `fee_rate_sat_per_vbyte` is deducted, or `None` if the deposit can't cover the fee
+    pub(crate) fn forward_amount_sat(
+        deposit_amount_sat: u64,
+        fee_rate_sat_per_vbyte: u64,
+    ) -> Option<u64> {
+        deposit_amount_sat
+            .checked_sub(Self::forward_fee_sat(fee_rate_sat_per_vbyte))
+            .filter(|amount_sat| *amount_sat > 0)
+    }
+
+    /// Builds and signs a tx sending `amount_sat` of a deposit to the `recipient` script. The
+    /// rest of the deposit is paid as fee.
+    pub(crate) fn build_forward_tx(
+        &self,
+        deposit: &(OutPoint, TxOut),
+        recipient: &Script,
+        amount_sat: u64,
+    ) -> Result<Transaction> {
+        let (outpoint, txout) = deposit;
+        if amount_sat > txout.value.to_sat() {
+            return Err(anyhow!("Forwarded amount exceeds the deposit"));
+        }
+        let mut tx = Transaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: *outpoint,
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: Witness::new(),
+            }],
+            output: vec![TxOut {
+                value: Amount::from_sat(amount_sat),
+                script_pubkey: recipient.to_owned(),
+            }],
+        };
+
+        let sighash = SighashCache::new(&tx).p2wpkh_signature_hash(
+            0,
+            &txout.script_pubkey,
+            txout.value,
+            EcdsaSighashType::All,
+        )?;
+        let mut signature = self.signer.sign_ecdsa(
+            sighash.to_byte_array().to_vec(),
+            self.derivation_path.to_string(),
+        )?;
+        signature.push(EcdsaSighashType::All as u8);
+        tx.input[0].witness =
+            Witness::from_slice(&[signature, self.public_key.to_bytes().to_vec()]);
+        Ok(tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use sdk_common::utils::Arc;
+
+    use super::ReusableAddress;
+    use crate::bitcoin::{Amount, OutPoint, ScriptBuf, TxOut};
+    use crate::model::{LiquidNetwork, Signer};
+    use crate::signer::SdkSigner;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_build_forward_tx() -> Result<()> {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(SdkSigner::new(mnemonic, "", false)?));
+        let reusable_address = ReusableAddress::new(signer, LiquidNetwork::Testnet)?;
+        assert!(reusable_address.address().to_string().starts_with("tb1q"));
+
+        assert_eq!(ReusableAddress::forward_amount_sat(100, 1), None);
+        let amount_sat = ReusableAddress::forward_amount_sat(50_000, 2).unwrap();
+        assert_eq!(amount_sat, 50_000 - 2 * super::FORWARD_TX_VSIZE);
+
+        let deposit = (
+            OutPoint::null(),
+            TxOut {
+                value: Amount::from_sat(50_000),
+                script_pubkey: reusable_address.address().script_pubkey(),
+            },
+        );
+        let recipient = ScriptBuf::new_op_return([0; 32]);
+        let tx = reusable_address.build_forward_tx(&deposit, &recipient, amount_sat)?;
+        assert_eq!(tx.output[0].value.to_sat(), amount_sat);
+        assert_eq!(tx.input[0].witness.len(), 2);
+        assert!(tx.vsize() as u64 <= super::FORWARD_TX_VSIZE);
+
+        assert!(reusable_address
+            .build_forward_tx(&deposit, &recipient, 50_001)
+            .is_err());
+        Ok(())
+    }
+}
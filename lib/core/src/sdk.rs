@@ -1,56 +1,101 @@
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet};
+use std::future::Future;
+use std::hash::{Hash as _, Hasher as _};
 use std::ops::Not as _;
 use std::{path::PathBuf, str::FromStr, time::Duration};
 
 use anyhow::{anyhow, ensure, Result};
 use boltz_client::{swaps::boltz::*, util::secrets::Preimage};
 use buy::{BuyBitcoinApi, BuyBitcoinService};
-use chain::{bitcoin::BitcoinChainService, liquid::LiquidChainService};
+use chain::{
+    bitcoin::BitcoinChainService,
+    broadcaster::{
+        BroadcastingBitcoinChainService, BroadcastingLiquidChainService, SharedBroadcaster,
+    },
+    liquid::LiquidChainService,
+};
 use chain_swap::ESTIMATED_BTC_CLAIM_TX_VSIZE;
+use futures_util::future::join5;
 use futures_util::stream::select_all;
 use futures_util::{StreamExt, TryFutureExt};
 use lnurl::auth::SdkLnurlAuthSigner;
+use lnurl::pay::{
+    add_payer_data_to_callback, check_verified_preimage, fetch_pay_callback,
+    poll_verified_preimage, process_success_action, PayCallbackResult,
+};
 use log::{debug, error, info, warn};
 use lwk_wollet::bitcoin::base64::Engine as _;
-use lwk_wollet::elements::AssetId;
+use lwk_wollet::elements::{self, pset::PartiallySignedTransaction, AssetId};
 use lwk_wollet::elements_miniscript::elements::bitcoin::bip32::Xpub;
 use lwk_wollet::hashes::{sha256, Hash};
 use lwk_wollet::secp256k1::Message;
-use persist::model::PaymentTxDetails;
+use lwk_wollet::WalletTx;
+use persist::model::{PaymentTxDetails, SwapEventState};
 use recover::recoverer::Recoverer;
 use sdk_common::bitcoin::hashes::hex::ToHex;
 use sdk_common::input_parser::InputType;
 use sdk_common::liquid::LiquidAddressData;
 use sdk_common::prelude::{FiatAPI, FiatCurrency, LnUrlPayError, LnUrlWithdrawError, Rate};
 use sdk_common::utils::Arc;
-use signer::SdkSigner;
+use signer::{SdkLwkSigner, SdkSigner};
 use swapper::boltz::proxy::BoltzProxyFetcher;
-use tokio::sync::{watch, RwLock};
+use tokio::sync::{broadcast, mpsc, watch, RwLock};
 use tokio_stream::wrappers::BroadcastStream;
 use tokio_with_wasm::alias as tokio;
+use uuid::Uuid;
 use web_time::Instant;
 use x509_parser::parse_x509_certificate;
 
+use crate::amp::{self, AmpClient};
+use crate::asset_registry::AssetRegistryClient;
+use crate::bip353::{self, Bip353Name};
 use crate::chain_swap::ChainSwapHandler;
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+use crate::data_dir_lock::DataDirLock;
 use crate::ensure_sdk;
 use crate::error::SdkError;
+use crate::in_flight::InFlightOperations;
 use crate::lightning_invoice::{Bolt11Invoice, Bolt11InvoiceDescription};
+use crate::metrics::SdkMetrics;
 use crate::model::PaymentState::*;
 use crate::model::Signer;
-use crate::payjoin::{side_swap::SideSwapPayjoinService, PayjoinService};
-use crate::receive_swap::ReceiveSwapHandler;
+#[cfg(feature = "nwc")]
+use crate::nwc::NwcService;
+use crate::payjoin::{
+    convert::{AssetConvertClient, SideSwapMarketSession},
+    model::PayjoinFeeEstimate,
+    p2ep::{payjoin_endpoint, P2epPayjoinClient},
+    side_swap::SideSwapPayjoinService,
+    PayjoinService,
+};
+use crate::receive_swap::{ReceiveSwapHandler, MAX_INVOICE_EXPIRY_SECS, MIN_INVOICE_EXPIRY_SECS};
+use crate::reusable_address::ReusableAddress;
 use crate::send_swap::SendSwapHandler;
 use crate::swapper::SubscriptionHandler;
 use crate::swapper::{
-    boltz::BoltzSwapper, Swapper, SwapperStatusStream, SwapperSubscriptionHandler,
+    boltz::BoltzSwapper, simulation::SimulatedSwapper, Swapper, SwapperStatusStream,
+    SwapperSubscriptionHandler,
+};
+use crate::wallet::{
+    account_derivation_path, get_account_xpub, get_amp_account_key, get_descriptor,
+    sweep::SweepKey, LiquidOnchainWallet, OnchainWallet, PsetWalletBalance,
 };
-use crate::wallet::{LiquidOnchainWallet, OnchainWallet};
 use crate::{
     error::{PaymentError, SdkResult},
     event::EventManager,
+    event_webhook::EventWebhookDispatcher,
+    lnurl::address::{validate_username, LightningAddressClient, SignedRequest},
     model::*,
-    persist::Persister,
-    utils, *,
+    notification::{
+        is_swap_resolved, validate_lnurl_pay_invoice_request, NotificationMessage,
+        DEFAULT_NOTIFICATION_TIMEOUT_SEC,
+    },
+    persist::{
+        export::WalletExport,
+        swap_intent::{SwapIntent, SwapIntentKind},
+        Persister,
+    },
+    spend_limits, utils, *,
 };
 use sdk_common::lightning_with_bolt12::offers::invoice::Bolt12Invoice;
 
@@ -60,6 +105,25 @@ use self::sync::SyncService;
 pub const DEFAULT_DATA_DIR: &str = ".data";
 /// Number of blocks to monitor a swap after its timeout block height
 pub const CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS: u32 = 4320;
+/// The default interval in seconds between background syncs. See [Config::sync_interval_sec]
+pub const DEFAULT_SYNC_INTERVAL_SEC: u32 = 10;
+/// The default number of seconds after which a still unconfirmed tx is reported. See
+/// [Config::unconfirmed_tx_alert_sec]
+pub const DEFAULT_UNCONFIRMED_TX_ALERT_SEC: u32 = 600;
+/// The default DNS-over-HTTPS JSON API used to look up BIP353 names. See
+/// [Config::dns_over_https_url]
+pub const DEFAULT_DNS_OVER_HTTPS_URL: &str = "https://dns.google/resolve";
+/// The default timeout in seconds of the Electrum and Esplora requests. See
+/// [Config::chain_service_timeout_sec]
+pub const DEFAULT_CHAIN_SERVICE_TIMEOUT_SEC: u32 = 3;
+/// The default timeout in seconds of each retried swapper call attempt. See
+/// [Config::swapper_timeout_sec]
+pub const DEFAULT_SWAPPER_TIMEOUT_SEC: u32 = 30;
+/// The default interval in seconds between the keep-alive pings of the swapper status stream.
+/// See [Config::swapper_ping_interval_sec]
+pub const DEFAULT_SWAPPER_PING_INTERVAL_SEC: u32 = 15;
+/// The upper bound of the network timeouts and intervals set in [Config], in seconds
+pub const MAX_NETWORK_TIMEOUT_SEC: u32 = 120;
 
 /// A list of external input parsers that are used by default.
 /// To opt-out, set `use_default_external_input_parsers` in [Config] to false.
@@ -70,12 +134,40 @@ pub const DEFAULT_EXTERNAL_INPUT_PARSERS: &[(&str, &str, &str)] = &[(
 )];
 
 pub(crate) const NETWORK_PROPAGATION_GRACE_PERIOD: Duration = Duration::from_secs(30);
+/// The number of workers handling swap status updates. Updates of different swaps are handled
+/// concurrently, while the updates of a single swap are always handled in order by the same worker.
+const SWAP_UPDATE_WORKERS: usize = 8;
+/// The interval at which the due payments of the outgoing payment queue and the due scheduled
+/// payments are attempted
+const BACKGROUND_PAYMENTS_INTERVAL: Duration = Duration::from_secs(30);
+/// The maximum number of attempts to send a queued or scheduled payment before it is marked as
+/// failed
+const BACKGROUND_PAYMENT_MAX_ATTEMPTS: u32 = 10;
+/// The interval at which the due occurrences of recurring payments are sent
+const RECURRING_PAYMENTS_INTERVAL: Duration = Duration::from_secs(30);
+/// The minimum interval between the occurrences of a recurring payment, in seconds
+const MIN_RECURRING_PAYMENT_INTERVAL_SEC: u32 = 60;
+/// The interval at which failed event webhook deliveries are retried
+const EVENT_WEBHOOK_RETRY_INTERVAL: Duration = Duration::from_secs(30);
+/// The interval at which the LUD-21 verify URL of an unsettled LNURL-pay invoice is polled
+const LNURL_VERIFY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// The interval at which the swap of a processed notification is checked
+const NOTIFICATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// The maximum time [LiquidSdk::disconnect] waits for the in-flight operations to complete
+const DISCONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+/// The error returned by [parse_with_rest_client] for inputs not recognized by any parser
+const UNRECOGNIZED_INPUT_ERROR: &str = "Unrecognized input type";
+/// The explorers the unblinded URLs of [LiquidSdk::export_payment_unblinding_data] default to
+const MAINNET_EXPLORER_URL: &str = "https://blockstream.info/liquid/";
+const TESTNET_EXPLORER_URL: &str = "https://blockstream.info/liquidtestnet/";
 
 pub struct LiquidSdkBuilder {
     config: Config,
     signer: Arc<Box<dyn Signer>>,
     breez_server: Arc<BreezServer>,
     bitcoin_chain_service: Option<Arc<dyn BitcoinChainService>>,
+    broadcaster: Option<Arc<dyn Broadcaster>>,
+    fiat_api: Option<Arc<dyn FiatAPI>>,
     liquid_chain_service: Option<Arc<dyn LiquidChainService>>,
     onchain_wallet: Option<Arc<dyn OnchainWallet>>,
     payjoin_service: Option<Arc<dyn PayjoinService>>,
@@ -100,6 +192,8 @@ impl LiquidSdkBuilder {
             signer,
             breez_server,
             bitcoin_chain_service: None,
+            broadcaster: None,
+            fiat_api: None,
             liquid_chain_service: None,
             onchain_wallet: None,
             payjoin_service: None,
@@ -120,6 +214,21 @@ impl LiquidSdkBuilder {
         self
     }
 
+    /// Sets a custom [Broadcaster] of the signed Liquid and Bitcoin transactions.
+    /// Defaults to broadcasting through the Liquid and Bitcoin chain services.
+    pub fn broadcaster(&mut self, broadcaster: Arc<dyn Broadcaster>) -> &mut Self {
+        self.broadcaster = Some(broadcaster.clone());
+        self
+    }
+
+    /// Sets the source of the fiat exchange rates, used by [LiquidSdk::fetch_fiat_rates],
+    /// [LiquidSdk::list_fiat_currencies] and fiat-denominated payments.
+    /// Defaults to the Breez rate service.
+    pub fn fiat_api(&mut self, fiat_api: Arc<dyn FiatAPI>) -> &mut Self {
+        self.fiat_api = Some(fiat_api.clone());
+        self
+    }
+
     pub fn liquid_chain_service(
         &mut self,
         liquid_chain_service: Arc<dyn LiquidChainService>,
@@ -179,6 +288,43 @@ impl LiquidSdkBuilder {
         if let Some(breez_api_key) = &self.config.breez_api_key {
             LiquidSdk::validate_breez_api_key(breez_api_key)?
         }
+        ensure!(
+            self.config.sync_interval_sec != Some(0),
+            "The sync interval must be greater than zero"
+        );
+        ensure!(
+            [
+                self.config.chain_service_timeout_sec,
+                self.config.swapper_timeout_sec,
+                self.config.swapper_ping_interval_sec,
+            ]
+            .iter()
+            .flatten()
+            .all(|timeout_sec| (1..=MAX_NETWORK_TIMEOUT_SEC).contains(timeout_sec)),
+            "The network timeouts must be between 1 and {MAX_NETWORK_TIMEOUT_SEC} seconds"
+        );
+        ensure!(
+            !matches!(
+                self.config.backup_policy,
+                Some(
+                    BackupPolicy::CompletedSwaps { swaps: 0 } | BackupPolicy::Interval { hours: 0 }
+                )
+            ),
+            "The backup policy must be greater than zero"
+        );
+        if let Some(simulation) = &self.config.simulation {
+            ensure!(
+                self.config.network != LiquidNetwork::Mainnet,
+                "Swaps can't be simulated on mainnet"
+            );
+            ensure!(
+                (0.0..=1.0).contains(&simulation.failure_rate),
+                "The simulated failure rate must be between 0 and 1"
+            );
+        }
+        if let Some(claim_address) = &self.config.claim_address {
+            utils::validate_claim_address(claim_address, self.config.network)?;
+        }
 
         let fingerprint_hex: String =
             Xpub::decode(self.signer.xpub()?.as_slice())?.identifier()[0..4].to_hex();
@@ -190,6 +336,19 @@ impl LiquidSdkBuilder {
             &fingerprint_hex,
         )?;
 
+        // Fail fast if another process or SDK instance uses the same data directory, rather than
+        // concurrently writing to its LWK cache and database
+        #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+        let locked_data_dir = match self.persister {
+            Some(_) => None,
+            None => Some(self.get_working_dir()?),
+        };
+        #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+        let data_dir_lock = locked_data_dir
+            .as_deref()
+            .map(DataDirLock::acquire)
+            .transpose()?;
+
         let persister = match self.persister.clone() {
             Some(persister) => persister,
             None => {
@@ -212,17 +371,27 @@ impl LiquidSdkBuilder {
             None => Arc::new(ReqwestRestClient::new()?),
         };
 
+        // The chain services broadcast through the broadcaster once one is set, either here or
+        // later with [LiquidSdk::set_broadcaster]
+        let broadcaster: SharedBroadcaster = Arc::new(RwLock::new(self.broadcaster.clone()));
+
         let bitcoin_chain_service: Arc<dyn BitcoinChainService> =
-            match self.bitcoin_chain_service.clone() {
-                Some(bitcoin_chain_service) => bitcoin_chain_service,
-                None => self.config.bitcoin_chain_service(),
-            };
+            Arc::new(BroadcastingBitcoinChainService::new(
+                match self.bitcoin_chain_service.clone() {
+                    Some(bitcoin_chain_service) => bitcoin_chain_service,
+                    None => self.config.bitcoin_chain_service(),
+                },
+                broadcaster.clone(),
+            ));
 
         let liquid_chain_service: Arc<dyn LiquidChainService> =
-            match self.liquid_chain_service.clone() {
-                Some(liquid_chain_service) => liquid_chain_service,
-                None => self.config.liquid_chain_service()?,
-            };
+            Arc::new(BroadcastingLiquidChainService::new(
+                match self.liquid_chain_service.clone() {
+                    Some(liquid_chain_service) => liquid_chain_service,
+                    None => self.config.liquid_chain_service()?,
+                },
+                broadcaster.clone(),
+            ));
 
         let onchain_wallet: Arc<dyn OnchainWallet> = match self.onchain_wallet.clone() {
             Some(onchain_wallet) => onchain_wallet,
@@ -237,21 +406,38 @@ impl LiquidSdkBuilder {
             ),
         };
 
-        let event_manager = Arc::new(EventManager::new());
+        let event_manager = Arc::new(EventManager::new(
+            persister.clone(),
+            self.config.event_webhook.is_some(),
+        ));
         let (shutdown_sender, shutdown_receiver) = watch::channel::<()>(());
 
         let (swapper, status_stream): (Arc<dyn Swapper>, Arc<dyn SwapperStatusStream>) =
             match (self.swapper.clone(), self.status_stream.clone()) {
                 (Some(swapper), Some(status_stream)) => (swapper, status_stream),
-                (maybe_swapper, maybe_status_stream) => {
-                    let proxy_url_fetcher = Arc::new(BoltzProxyFetcher::new(persister.clone()));
-                    let boltz_swapper =
-                        Arc::new(BoltzSwapper::new(self.config.clone(), proxy_url_fetcher)?);
-                    (
-                        maybe_swapper.unwrap_or(boltz_swapper.clone()),
-                        maybe_status_stream.unwrap_or(boltz_swapper),
-                    )
-                }
+                (maybe_swapper, maybe_status_stream) => match self.config.simulation.clone() {
+                    Some(simulation) => {
+                        warn!("Simulating the swaps, no swapper is used");
+                        let simulated_swapper = Arc::new(SimulatedSwapper::new(
+                            simulation,
+                            self.config.network,
+                            self.config.outgoing_min_confirmations(),
+                        ));
+                        (
+                            maybe_swapper.unwrap_or(simulated_swapper.clone()),
+                            maybe_status_stream.unwrap_or(simulated_swapper),
+                        )
+                    }
+                    None => {
+                        let proxy_url_fetcher = Arc::new(BoltzProxyFetcher::new(persister.clone()));
+                        let boltz_swapper =
+                            Arc::new(BoltzSwapper::new(self.config.clone(), proxy_url_fetcher)?);
+                        (
+                            maybe_swapper.unwrap_or(boltz_swapper.clone()),
+                            maybe_status_stream.unwrap_or(boltz_swapper),
+                        )
+                    }
+                },
             };
 
         let recoverer = match self.recoverer.clone() {
@@ -263,6 +449,7 @@ impl LiquidSdkBuilder {
                 liquid_chain_service.clone(),
                 bitcoin_chain_service.clone(),
                 persister.clone(),
+                self.config.outgoing_min_confirmations(),
             )?),
         };
 
@@ -292,6 +479,8 @@ impl LiquidSdkBuilder {
             },
         };
 
+        let in_flight = Arc::new(InFlightOperations::default());
+
         let send_swap_handler = SendSwapHandler::new(
             self.config.clone(),
             onchain_wallet.clone(),
@@ -299,6 +488,7 @@ impl LiquidSdkBuilder {
             swapper.clone(),
             liquid_chain_service.clone(),
             recoverer.clone(),
+            in_flight.clone(),
         );
 
         let receive_swap_handler = ReceiveSwapHandler::new(
@@ -307,6 +497,7 @@ impl LiquidSdkBuilder {
             persister.clone(),
             swapper.clone(),
             liquid_chain_service.clone(),
+            in_flight.clone(),
         );
 
         let chain_swap_handler = Arc::new(ChainSwapHandler::new(
@@ -316,13 +507,19 @@ impl LiquidSdkBuilder {
             swapper.clone(),
             liquid_chain_service.clone(),
             bitcoin_chain_service.clone(),
+            in_flight.clone(),
         )?);
 
+        let fiat_api: Arc<dyn FiatAPI> = match self.fiat_api.clone() {
+            Some(fiat_api) => fiat_api,
+            None => self.breez_server.clone(),
+        };
+
         let payjoin_service = match self.payjoin_service.clone() {
             Some(payjoin_service) => payjoin_service,
             None => Arc::new(SideSwapPayjoinService::new(
                 self.config.clone(),
-                self.breez_server.clone(),
+                fiat_api.clone(),
                 persister.clone(),
                 onchain_wallet.clone(),
                 rest_client.clone(),
@@ -334,6 +531,18 @@ impl LiquidSdkBuilder {
             self.breez_server.clone(),
         ));
 
+        #[cfg(feature = "nwc")]
+        let nwc_service = self
+            .config
+            .nwc_relay_url
+            .clone()
+            .map(|relay_url| NwcService::new(relay_url, persister.clone()).map(Arc::new))
+            .transpose()?;
+        #[cfg(not(feature = "nwc"))]
+        if self.config.nwc_relay_url.is_some() {
+            warn!("The NWC relay url is set, but the NWC service requires the nwc feature");
+        }
+
         let external_input_parsers = self.config.get_all_external_input_parsers();
 
         let sdk = Arc::new(LiquidSdk {
@@ -348,7 +557,13 @@ impl LiquidSdkBuilder {
             recoverer,
             bitcoin_chain_service,
             liquid_chain_service,
-            fiat_api: self.breez_server.clone(),
+            broadcaster,
+            fiat_api,
+            fiat_rate_provider: RwLock::new(None),
+            input_parsers: RwLock::new(Vec::new()),
+            alerted_unconfirmed_tx_ids: RwLock::new(HashSet::new()),
+            metrics: SdkMetrics::default(),
+            backup_transport: RwLock::new(None),
             is_started: RwLock::new(false),
             shutdown_sender,
             shutdown_receiver,
@@ -358,7 +573,14 @@ impl LiquidSdkBuilder {
             chain_swap_handler,
             payjoin_service,
             buy_bitcoin_service,
+            #[cfg(feature = "nwc")]
+            nwc_service,
             external_input_parsers,
+            in_flight,
+            #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+            locked_data_dir,
+            #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+            data_dir_lock: RwLock::new(data_dir_lock),
         });
         Ok(sdk)
     }
@@ -376,7 +598,14 @@ pub struct LiquidSdk {
     pub(crate) recoverer: Arc<Recoverer>,
     pub(crate) liquid_chain_service: Arc<dyn LiquidChainService>,
     pub(crate) bitcoin_chain_service: Arc<dyn BitcoinChainService>,
+    pub(crate) broadcaster: SharedBroadcaster,
     pub(crate) fiat_api: Arc<dyn FiatAPI>,
+    pub(crate) fiat_rate_provider: RwLock<Option<Box<dyn FiatRateProvider>>>,
+    pub(crate) input_parsers: RwLock<Vec<Box<dyn InputParser>>>,
+    /// The unconfirmed txs for which an [SdkEvent::TransactionUnconfirmed] was already emitted
+    pub(crate) alerted_unconfirmed_tx_ids: RwLock<HashSet<String>>,
+    pub(crate) metrics: SdkMetrics,
+    pub(crate) backup_transport: RwLock<Option<Box<dyn BackupTransport>>>,
     pub(crate) is_started: RwLock<bool>,
     pub(crate) shutdown_sender: watch::Sender<()>,
     pub(crate) shutdown_receiver: watch::Receiver<()>,
@@ -386,13 +615,25 @@ pub struct LiquidSdk {
     pub(crate) chain_swap_handler: Arc<ChainSwapHandler>,
     pub(crate) payjoin_service: Arc<dyn PayjoinService>,
     pub(crate) buy_bitcoin_service: Arc<dyn BuyBitcoinApi>,
+    #[cfg(feature = "nwc")]
+    pub(crate) nwc_service: Option<Arc<NwcService>>,
     pub(crate) external_input_parsers: Vec<ExternalInputParser>,
+    pub(crate) in_flight: Arc<InFlightOperations>,
+    /// The wallet data directory locked while started, unless a custom persister is used
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub(crate) locked_data_dir: Option<String>,
+    /// The lock on the wallet data directory, held while started
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub(crate) data_dir_lock: RwLock<Option<DataDirLock>>,
 }
 
 impl LiquidSdk {
     /// Initializes the SDK services and starts the background tasks.
     /// This must be called to create the [LiquidSdk] instance.
     ///
+    /// Returns without waiting for network I/O: the initial sync with the mempool and onchain
+    /// data runs in the background, and [SdkEvent::Synced] is emitted once it completes.
+    ///
     /// # Arguments
     ///
     /// * `req` - the [ConnectRequest] containing:
@@ -400,6 +641,13 @@ impl LiquidSdk {
     ///     * `mnemonic` - the optional Liquid wallet mnemonic
     ///     * `passphrase` - the optional passphrase for the mnemonic
     ///     * `seed` - the optional Liquid wallet seed
+    ///     * `xprv` - the optional Liquid wallet master extended private key
+    ///
+    /// # Errors
+    ///
+    /// * [SdkError::DataDirLocked] - if the wallet's data directory is already used by another
+    ///   process or connected SDK instance
+    #[tracing::instrument(skip_all)]
     pub async fn connect(req: ConnectRequest) -> Result<Arc<LiquidSdk>> {
         let signer = Self::default_signer(&req)?;
 
@@ -413,17 +661,21 @@ impl LiquidSdk {
 
     pub fn default_signer(req: &ConnectRequest) -> Result<SdkSigner> {
         let is_mainnet = req.config.network == LiquidNetwork::Mainnet;
-        match (&req.mnemonic, &req.seed) {
-            (None, Some(seed)) => Ok(SdkSigner::new_with_seed(seed.clone(), is_mainnet)?),
-            (Some(mnemonic), None) => Ok(SdkSigner::new(
+        match (&req.mnemonic, &req.seed, &req.xprv) {
+            (None, Some(seed), None) => Ok(SdkSigner::new_with_seed(seed.clone(), is_mainnet)?),
+            (Some(mnemonic), None, None) => Ok(SdkSigner::new(
                 mnemonic,
                 req.passphrase.as_ref().unwrap_or(&"".to_string()).as_ref(),
                 is_mainnet,
             )?),
-            _ => Err(anyhow!("Either `mnemonic` or `seed` must be set")),
+            (None, None, Some(xprv)) => Ok(SdkSigner::new_with_xprv(xprv, is_mainnet)?),
+            _ => Err(anyhow!(
+                "Exactly one of `mnemonic`, `seed` or `xprv` must be set"
+            )),
         }
     }
 
+    #[tracing::instrument(skip_all)]
     pub async fn connect_with_signer(
         req: ConnectWithSignerRequest,
         signer: Box<dyn Signer>,
@@ -448,6 +700,174 @@ impl LiquidSdk {
         Ok(sdk)
     }
 
+    /// Handles a push notification in a short-lived SDK session, so that the Notification
+    /// Service Extension on iOS or a background worker on Android can complete a swap without
+    /// the app running.
+    ///
+    /// The session connects with the given [ConnectRequest], decrypts the payload, waits until
+    /// the SDK completed its part of the swap (e.g. broadcast the claim tx), and disconnects.
+    /// If the payload is an invoice request for the lightning address registered with
+    /// [LiquidSdk::register_lightning_address], a receive swap is created instead and its
+    /// invoice is sent to the LNURL service.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ProcessNotificationRequest] containing:
+    ///     * `connect_request` - the request used to connect the session
+    ///     * `payload` - the encrypted push payload, originating from the registered webhook
+    ///     * `accept_proposed_fees` - whether the fees proposed for an amountless chain swap
+    ///       are accepted
+    ///     * `timeout_sec` - how long the session runs at most
+    pub async fn process_notification(
+        req: ProcessNotificationRequest,
+    ) -> SdkResult<ProcessNotificationResponse> {
+        let timeout = Duration::from_secs(
+            req.timeout_sec
+                .unwrap_or(DEFAULT_NOTIFICATION_TIMEOUT_SEC)
+                .into(),
+        );
+        let accept_proposed_fees = req.accept_proposed_fees.unwrap_or(false);
+
+        let sdk = Self::connect(req.connect_request).await?;
+        let res = sdk
+            .handle_notification(&req.payload, accept_proposed_fees, timeout)
+            .await;
+        if let Err(e) = sdk.disconnect().await {
+            warn!("Failed to disconnect notification session: {e:?}");
+        }
+        Ok(res?)
+    }
+
+    async fn handle_notification(
+        &self,
+        payload: &str,
+        accept_proposed_fees: bool,
+        timeout: Duration,
+    ) -> Result<ProcessNotificationResponse> {
+        let deadline = Instant::now() + timeout;
+        match NotificationMessage::decrypt(&**self.signer, payload)? {
+            NotificationMessage::SwapUpdated { id, status } => {
+                info!("Processing notification for swap {id} with status {status}");
+                self.wait_for_swap_resolution(id, accept_proposed_fees, deadline)
+                    .await
+            }
+            NotificationMessage::LnurlPayInvoice {
+                amount_msat,
+                metadata,
+                reply_url,
+            } => {
+                info!("Processing lightning address invoice request of {amount_msat} msat");
+                self.reply_lnurl_pay_invoice(amount_msat, &metadata, &reply_url)
+                    .await
+            }
+        }
+    }
+
+    async fn wait_for_swap_resolution(
+        &self,
+        swap_id: String,
+        accept_proposed_fees: bool,
+        deadline: Instant,
+    ) -> Result<ProcessNotificationResponse> {
+        let req = GetPaymentRequest::SwapId { swap_id };
+        let mut accepted_fees = false;
+        loop {
+            let payment = self.get_payment(&req).await?;
+            if let Some(payment) = payment {
+                if accept_proposed_fees
+                    && !accepted_fees
+                    && payment.status == PaymentState::WaitingFeeAcceptance
+                {
+                    if let Some(swap_id) = payment.details.get_swap_id() {
+                        let response = self
+                            .fetch_payment_proposed_fees(&FetchPaymentProposedFeesRequest {
+                                swap_id,
+                            })
+                            .await?;
+                        self.accept_payment_proposed_fees(&AcceptPaymentProposedFeesRequest {
+                            response,
+                        })
+                        .await?;
+                        accepted_fees = true;
+                        continue;
+                    }
+                }
+
+                let is_resolved = is_swap_resolved(&payment);
+                if is_resolved || Instant::now() >= deadline {
+                    return Ok(ProcessNotificationResponse {
+                        payment,
+                        is_resolved,
+                    });
+                }
+            } else {
+                ensure!(
+                    Instant::now() < deadline,
+                    "No swap matching the notification was found"
+                );
+            }
+            tokio::time::sleep(NOTIFICATION_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Creates a receive swap for the lightning address invoice request and sends its invoice
+    /// to the LNURL service, which forwards it to the payer
+    async fn reply_lnurl_pay_invoice(
+        &self,
+        amount_msat: u64,
+        metadata: &str,
+        reply_url: &str,
+    ) -> Result<ProcessNotificationResponse> {
+        let payer_amount_sat = validate_lnurl_pay_invoice_request(
+            amount_msat,
+            reply_url,
+            self.config.lnurl_service_url.as_deref(),
+            self.config.network,
+        )?;
+        let prepare_response = self
+            .prepare_receive_payment(&PrepareReceiveRequest {
+                payment_method: PaymentMethod::Lightning,
+                amount: Some(ReceiveAmount::Bitcoin { payer_amount_sat }),
+                fiat_amount: None,
+                expiry_secs: None,
+            })
+            .await?;
+        let invoice = self
+            .receive_payment(&ReceivePaymentRequest {
+                prepare_response,
+                description: None,
+                use_description_hash: None,
+                description_hash: Some(sha256::Hash::hash(metadata.as_bytes()).to_hex()),
+                claim_address: None,
+            })
+            .await?
+            .destination;
+
+        let body = serde_json::json!({ "pr": invoice, "routes": [] }).to_string();
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let (response, status_code) = self
+            .rest_client
+            .post(reply_url, Some(headers), Some(body))
+            .await?;
+        ensure!(
+            (200..300).contains(&status_code),
+            "LNURL service rejected the invoice with status code {status_code}: {response}"
+        );
+
+        let payment_hash = self
+            .validate_bolt11_invoice(&invoice)?
+            .payment_hash()
+            .to_string();
+        let payment = self
+            .get_payment(&GetPaymentRequest::PaymentHash { payment_hash })
+            .await?
+            .ok_or(anyhow!("Receive swap of the invoice not found"))?;
+        Ok(ProcessNotificationResponse {
+            payment,
+            is_resolved: false,
+        })
+    }
+
     fn validate_breez_api_key(api_key: &str) -> Result<()> {
         let api_key_decoded = lwk_wollet::bitcoin::base64::engine::general_purpose::STANDARD
             .decode(api_key.as_bytes())
@@ -478,9 +898,35 @@ impl LiquidSdk {
     /// Should only be called once per instance.
     pub async fn start(self: &Arc<LiquidSdk>) -> SdkResult<()> {
         let mut is_started = self.is_started.write().await;
+        // The lock is released on disconnect, so it's acquired again when restarting
+        #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+        if let Some(dir) = &self.locked_data_dir {
+            let mut data_dir_lock = self.data_dir_lock.write().await;
+            if data_dir_lock.is_none() {
+                *data_dir_lock = Some(DataDirLock::acquire(dir)?);
+            }
+        }
         self.persister
             .update_send_swaps_by_state(Created, TimedOut, Some(true))
             .inspect_err(|e| error!("Failed to update send swaps by state: {:?}", e))?;
+        if let Err(e) = self.reconcile_swap_intents().await {
+            warn!("Failed to reconcile swap intents: {e:?}");
+        }
+        // No send is in progress yet, so a reservation without a payment belongs to a send
+        // interrupted before sending anything
+        self.persister
+            .delete_unlinked_idempotency_keys()
+            .inspect_err(|e| error!("Failed to delete unlinked idempotency keys: {e:?}"))?;
+        // The interrupted scheduled payment attempts are retried with the same idempotency key,
+        // so a payment that was already sent is only recorded
+        self.persister
+            .reset_interrupted_scheduled_payments()
+            .inspect_err(|e| error!("Failed to reset interrupted scheduled payments: {e:?}"))?;
+        // Hydrate the wallet info from the locally cached wallet transactions and payments, so
+        // that it's usable before the first sync, which reconciles it with the chain data
+        if let Err(e) = self.update_wallet_info().await {
+            warn!("Failed to update wallet info from local data: {e:?}");
+        }
 
         self.start_background_tasks()
             .inspect_err(|e| error!("Failed to start background tasks: {:?}", e))
@@ -489,6 +935,132 @@ impl LiquidSdk {
         Ok(())
     }
 
+    /// Reconciles the swap operations journaled before a side effect whose outcome was not
+    /// persisted, because the SDK stopped in between:
+    /// - a claim tx id that was set but never broadcast is unset, so the swap is claimed again
+    /// - a refund tx that was broadcast but not persisted is set on the swap
+    /// - fees that were accepted locally but not with the swapper are erased, so they can be
+    ///   accepted again
+    ///
+    /// If an intent can't be reconciled, e.g. because the chain service is unreachable, it's
+    /// kept and reconciled on the next start.
+    async fn reconcile_swap_intents(&self) -> Result<()> {
+        for intent in self.persister.list_swap_intents()? {
+            if let Err(e) = self.reconcile_swap_intent(intent.clone()).await {
+                warn!("Failed to reconcile swap intent {intent:?}: {e:?}");
+            }
+        }
+        Ok(())
+    }
+
+    async fn reconcile_swap_intent(&self, intent: SwapIntent) -> Result<()> {
+        let SwapIntent {
+            swap_id,
+            kind,
+            tx_id,
+            ..
+        } = intent;
+        info!("Reconciling {kind} intent for swap {swap_id}");
+        match (self.persister.fetch_swap_by_id(&swap_id), kind, tx_id) {
+            (Ok(Swap::Receive(swap)), SwapIntentKind::Claim, Some(tx_id)) => {
+                if swap.claim_tx_id.as_ref() == Some(&tx_id)
+                    && !self.is_tx_broadcast(&tx_id, true).await?
+                {
+                    info!("Unsetting claim tx {tx_id} of Receive Swap {swap_id}");
+                    self.persister
+                        .unset_receive_swap_claim_tx_id(&swap_id, &tx_id)?;
+                }
+            }
+            (Ok(Swap::Chain(swap)), SwapIntentKind::Claim, Some(tx_id)) => {
+                let is_liquid = swap.direction == Direction::Incoming;
+                if swap.claim_tx_id.as_ref() == Some(&tx_id)
+                    && !self.is_tx_broadcast(&tx_id, is_liquid).await?
+                {
+                    info!("Unsetting claim tx {tx_id} of Chain Swap {swap_id}");
+                    self.persister
+                        .unset_chain_swap_claim_tx_id(&swap_id, &tx_id)?;
+                }
+            }
+            (Ok(Swap::Send(swap)), SwapIntentKind::Refund, Some(tx_id)) => {
+                if swap.refund_tx_id.is_none() && self.is_tx_broadcast(&tx_id, true).await? {
+                    info!("Setting refund tx {tx_id} of Send Swap {swap_id}");
+                    self.send_swap_handler.update_swap_info(
+                        &swap_id,
+                        RefundPending,
+                        None,
+                        None,
+                        Some(&tx_id),
+                    )?;
+                }
+            }
+            (Ok(Swap::Chain(swap)), SwapIntentKind::Refund, Some(tx_id)) => {
+                let is_liquid = swap.direction == Direction::Outgoing;
+                if swap.refund_tx_id.is_none() && self.is_tx_broadcast(&tx_id, is_liquid).await? {
+                    info!("Setting refund tx {tx_id} of Chain Swap {swap_id}");
+                    self.chain_swap_handler.update_swap_info(&ChainSwapUpdate {
+                        swap_id: swap_id.clone(),
+                        to_state: RefundPending,
+                        refund_tx_id: Some(tx_id),
+                        ..Default::default()
+                    })?;
+                }
+            }
+            (Ok(Swap::Chain(swap)), SwapIntentKind::FeesAccepted, _) => {
+                if swap.state == WaitingFeeAcceptance && swap.accepted_receiver_amount_sat.is_some()
+                {
+                    info!("Erasing the accepted receiver amount of Chain Swap {swap_id}");
+                    self.persister
+                        .update_accepted_receiver_amount(&swap_id, None)?;
+                }
+            }
+            (Err(e), _, _) => warn!("Ignoring {kind} intent for swap {swap_id}: {e:?}"),
+            _ => warn!("Ignoring unexpected {kind} intent for swap {swap_id}"),
+        }
+        self.persister.delete_swap_intent(&swap_id, kind)
+    }
+
+    /// Returns whether the tx is known to the chain service, either in the mempool or onchain.
+    ///
+    /// The chain services fail to look up an unknown tx, so a failed lookup only means the tx
+    /// wasn't broadcast if the chain service is reachable. Otherwise this fails, as the tx may
+    /// have been broadcast.
+    async fn is_tx_broadcast(&self, tx_id: &str, is_liquid: bool) -> Result<bool> {
+        let lookup_res = match is_liquid {
+            true => {
+                let txid = elements::Txid::from_str(tx_id)?;
+                self.liquid_chain_service
+                    .get_transaction_hex(&txid)
+                    .await
+                    .map(|tx| tx.is_some())
+            }
+            false => {
+                let txid = bitcoin::Txid::from_str(tx_id)?;
+                self.bitcoin_chain_service
+                    .get_transactions(&[txid])
+                    .await
+                    .map(|txs| !txs.is_empty())
+            }
+        };
+        match lookup_res {
+            Ok(is_broadcast) => Ok(is_broadcast),
+            Err(e) => {
+                let tip_res = match is_liquid {
+                    true => self.liquid_chain_service.tip().await,
+                    false => self.bitcoin_chain_service.tip().await,
+                };
+                match tip_res {
+                    Ok(_) => {
+                        info!("Tx {tx_id} not found: {e:?}");
+                        Ok(false)
+                    }
+                    Err(tip_err) => Err(anyhow!(
+                        "Failed to look up tx {tx_id}: {e:?}, chain service unreachable: {tip_err:?}"
+                    )),
+                }
+            }
+        }
+    }
+
     /// Starts background tasks.
     ///
     /// Internal method. Should only be used as part of [LiquidSdk::start].
@@ -503,9 +1075,18 @@ impl LiquidSdk {
         if let Some(sync_service) = self.sync_service.clone() {
             sync_service.start(self.shutdown_receiver.clone());
         }
+        #[cfg(feature = "nwc")]
+        if let Some(nwc_service) = self.nwc_service.clone() {
+            nwc_service.start(self.clone(), self.shutdown_receiver.clone());
+        }
         self.track_new_blocks();
         self.track_swap_updates();
         self.track_realtime_sync_events(subscription_handler);
+        self.track_background_payments();
+        self.track_recurring_payments();
+        self.track_event_webhook();
+        self.track_backups();
+        self.track_scheduled_backups();
 
         Ok(())
     }
@@ -517,14 +1098,44 @@ impl LiquidSdk {
     }
 
     /// Disconnects the [LiquidSdk] instance and stops the background tasks.
+    ///
+    /// Waits for the in-flight lockups, claims, refunds and broadcasts to complete, up to 30
+    /// seconds, so their state is persisted before returning. The pending events are then flushed
+    /// to the [Config::event_webhook], if one is set. The lock on the data directory is then
+    /// released, so it can be used by another process. Operations still running after the
+    /// timeout may keep using the database, and are resumed on the next start if interrupted.
     pub async fn disconnect(&self) -> SdkResult<()> {
         self.ensure_is_started().await?;
 
-        let mut is_started = self.is_started.write().await;
-        self.shutdown_sender
-            .send(())
-            .map_err(|e| SdkError::generic(format!("Shutdown failed: {e}")))?;
-        *is_started = false;
+        {
+            let mut is_started = self.is_started.write().await;
+            self.shutdown_sender
+                .send(())
+                .map_err(|e| SdkError::generic(format!("Shutdown failed: {e}")))?;
+            *is_started = false;
+        }
+
+        if !self.in_flight.wait_idle(DISCONNECT_TIMEOUT).await {
+            warn!(
+                "Disconnected with {} in-flight operations, they will be resumed on the next start",
+                self.in_flight.count()
+            );
+        }
+
+        if let Some(webhook_config) = self.config.event_webhook.clone() {
+            let dispatcher = EventWebhookDispatcher::new(
+                webhook_config,
+                self.persister.clone(),
+                self.rest_client.clone(),
+            );
+            if let Err(e) = dispatcher.dispatch_pending_events().await {
+                warn!("Failed to flush events to webhook on disconnect: {e:?}");
+            }
+        }
+
+        // Let another process or SDK instance use the data directory
+        #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+        self.data_dir_lock.write().await.take();
         Ok(())
     }
 
@@ -573,10 +1184,25 @@ impl LiquidSdk {
         tokio::spawn(async move {
             let mut current_liquid_block: u32 = 0;
             let mut current_bitcoin_block: u32 = 0;
+            // Whether the chain services were reachable on the last tick
+            let mut is_online = true;
             let mut shutdown_receiver = cloned.shutdown_receiver.clone();
-            let mut interval = tokio::time::interval(Duration::from_secs(10));
+            let mut interval = tokio::time::interval(cloned.config.sync_interval());
             #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
             interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+            // Sync right away, without first waiting for the chain tips, so the wallet is ready
+            // as soon as possible after connecting. The first tick then completes immediately
+            // and only fetches the tips, as the wallet was just synced.
+            let mut is_just_synced = true;
+            tokio::select! {
+                _ = cloned.sync(false) => {}
+                _ = shutdown_receiver.changed() => {
+                    info!("Received shutdown signal, exiting track blocks loop");
+                    return;
+                }
+            }
+
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
@@ -586,6 +1212,7 @@ impl LiquidSdk {
                         let liquid_tip_res = cloned.liquid_chain_service.tip().await;
                         let duration_ms = Instant::now().duration_since(t0).as_millis();
                         info!("Fetched liquid tip at ({duration_ms} ms)");
+                        cloned.metrics.record_chain_service_call("liquid", liquid_tip_res.is_ok());
 
                         let is_new_liquid_block = match &liquid_tip_res {
                             Ok(height) => {
@@ -604,6 +1231,7 @@ impl LiquidSdk {
                         let bitcoin_tip_res = cloned.bitcoin_chain_service.tip().await;
                         let duration_ms = Instant::now().duration_since(t0).as_millis();
                         info!("Fetched bitcoin tip at ({duration_ms} ms)");
+                        cloned.metrics.record_chain_service_call("bitcoin", bitcoin_tip_res.is_ok());
                         let is_new_bitcoin_block = match &bitcoin_tip_res {
                             Ok(height) => {
                                 debug!("Got Bitcoin tip: {height}");
@@ -617,17 +1245,44 @@ impl LiquidSdk {
                             }
                         };
 
+                        let is_now_online = liquid_tip_res.is_ok() && bitcoin_tip_res.is_ok();
+                        if is_now_online != is_online {
+                            is_online = is_now_online;
+                            info!("Connectivity changed, online: {is_online}");
+                            cloned.notify_event_listeners(SdkEvent::ConnectivityChanged { is_online }).await;
+                        }
+
                         if let (Ok(liquid_tip), Ok(bitcoin_tip)) = (liquid_tip_res, bitcoin_tip_res) {
                             cloned.persister.set_blockchain_info(&BlockchainInfo {
                                 liquid_tip,
                                 bitcoin_tip
                             })
                             .unwrap_or_else(|err| warn!("Could not update local tips: {err:?}"));
+                            if is_new_liquid_block || is_new_bitcoin_block {
+                                cloned.notify_event_listeners(SdkEvent::TipsUpdated {
+                                    liquid_tip,
+                                    bitcoin_tip
+                                }).await;
+                            }
                         };
+                        if is_new_liquid_block {
+                            match cloned.liquid_chain_service.tip_block().await {
+                                Ok(block) => cloned.notify_event_listeners(SdkEvent::NewLiquidBlock { block }).await,
+                                Err(e) => warn!("Failed to fetch Liquid tip block: {e:?}"),
+                            }
+                        }
+                        if is_new_bitcoin_block {
+                            match cloned.bitcoin_chain_service.tip_block().await {
+                                Ok(block) => cloned.notify_event_listeners(SdkEvent::NewBitcoinBlock { block }).await,
+                                Err(e) => warn!("Failed to fetch Bitcoin tip block: {e:?}"),
+                            }
+                        }
 
                         // Only partial sync when there are no new Liquid or Bitcoin blocks
                         let partial_sync = (is_new_liquid_block || is_new_bitcoin_block).not();
-                        _ = cloned.sync(partial_sync).await;
+                        if !std::mem::take(&mut is_just_synced) {
+                            _ = cloned.sync(partial_sync).await;
+                        }
 
                         // Update swap handlers
                         if is_new_liquid_block {
@@ -639,6 +1294,9 @@ impl LiquidSdk {
                             cloned.chain_swap_handler.on_bitcoin_block(current_bitcoin_block).await;
                             cloned.receive_swap_handler.on_bitcoin_block(current_liquid_block).await;
                             cloned.send_swap_handler.on_bitcoin_block(current_bitcoin_block).await;
+                            if let Err(e) = cloned.forward_reusable_address_deposits().await {
+                                warn!("Failed to forward reusable address deposits: {e:?}");
+                            }
                         }
 
                         #[cfg(all(target_family = "wasm", target_os = "unknown"))]
@@ -654,6 +1312,29 @@ impl LiquidSdk {
         });
     }
 
+    /// Persists the version of the swap seen while it can still change, returning the previously
+    /// seen one. It's persisted so that the swap events emitted before a restart are not emitted
+    /// again after it.
+    fn update_swap_event_state(&self, swap: &Swap) -> Option<SwapEventState> {
+        let swap_id = swap.id();
+        let last_seen = self
+            .persister
+            .fetch_swap_event_state(&swap_id)
+            .inspect_err(|e| warn!("Failed to fetch the last seen state of swap {swap_id}: {e:?}"))
+            .ok()
+            .flatten();
+        let res = match swap.state() {
+            Complete | Failed => self.persister.delete_swap_event_state(&swap_id),
+            _ => self
+                .persister
+                .insert_or_update_swap_event_state(&swap_id, &swap.into()),
+        };
+        if let Err(e) = res {
+            warn!("Failed to persist the last seen state of swap {swap_id}: {e:?}");
+        }
+        last_seen
+    }
+
     fn track_swap_updates(self: &Arc<LiquidSdk>) {
         let cloned = self.clone();
         tokio::spawn(async move {
@@ -666,12 +1347,31 @@ impl LiquidSdk {
             ];
             let mut combined_swap_streams =
                 select_all(swaps_streams.into_iter().map(BroadcastStream::new));
+            // The workers stop once their senders are dropped, when this loop exits
+            let update_workers: Vec<_> = (0..SWAP_UPDATE_WORKERS)
+                .map(|_| {
+                    let (sender, mut receiver) = mpsc::unbounded_channel::<SwapStatus>();
+                    let sdk = cloned.clone();
+                    tokio::spawn(async move {
+                        while let Some(update) = receiver.recv().await {
+                            sdk.handle_swap_update(&update).await;
+                        }
+                    });
+                    sender
+                })
+                .collect();
             loop {
                 tokio::select! {
                     payment_id = combined_swap_streams.next() => {
                       if let Some(payment_id) = payment_id {
                         match payment_id {
                             Ok(payment_id) => {
+                              if let Ok(swap) = cloned.persister.fetch_swap_by_id(&payment_id) {
+                                let last_seen = cloned.update_swap_event_state(&swap);
+                                for event in get_swap_events(&swap, last_seen.as_ref()) {
+                                    cloned.notify_event_listeners(event).await;
+                                }
+                              }
                               if let Err(e) = cloned.emit_payment_updated(Some(payment_id)).await {
                                 error!("Failed to emit payment update: {e:?}");
                               }
@@ -682,23 +1382,9 @@ impl LiquidSdk {
                     }
                     update = updates_stream.recv() => match update {
                         Ok(update) => {
-                            let id = &update.id;
-                            match cloned.persister.fetch_swap_by_id(id) {
-                                Ok(Swap::Send(_)) => match cloned.send_swap_handler.on_new_status(&update).await {
-                                    Ok(_) => info!("Successfully handled Send Swap {id} update"),
-                                    Err(e) => error!("Failed to handle Send Swap {id} update: {e}")
-                                },
-                                Ok(Swap::Receive(_)) => match cloned.receive_swap_handler.on_new_status(&update).await {
-                                    Ok(_) => info!("Successfully handled Receive Swap {id} update"),
-                                    Err(e) => error!("Failed to handle Receive Swap {id} update: {e}")
-                                },
-                                Ok(Swap::Chain(_)) => match cloned.chain_swap_handler.on_new_status(&update).await {
-                                    Ok(_) => info!("Successfully handled Chain Swap {id} update"),
-                                    Err(e) => error!("Failed to handle Chain Swap {id} update: {e}")
-                                },
-                                _ => {
-                                    error!("Could not find Swap {id}");
-                                }
+                            let worker = &update_workers[swap_update_worker_index(&update.id)];
+                            if let Err(e) = worker.send(update) {
+                                error!("Failed to queue swap update: {e:?}");
                             }
                         }
                         Err(e) => error!("Received stream error: {e:?}"),
@@ -712,31 +1398,331 @@ impl LiquidSdk {
         });
     }
 
-    async fn notify_event_listeners(&self, e: SdkEvent) {
-        self.event_manager.notify(e).await;
+    async fn handle_swap_update(&self, update: &SwapStatus) {
+        let id = &update.id;
+        match self.persister.fetch_swap_by_id(id) {
+            Ok(Swap::Send(_)) => match self.send_swap_handler.on_new_status(update).await {
+                Ok(_) => info!("Successfully handled Send Swap {id} update"),
+                Err(e) => error!("Failed to handle Send Swap {id} update: {e}"),
+            },
+            Ok(Swap::Receive(_)) => match self.receive_swap_handler.on_new_status(update).await {
+                Ok(_) => info!("Successfully handled Receive Swap {id} update"),
+                Err(e) => error!("Failed to handle Receive Swap {id} update: {e}"),
+            },
+            Ok(Swap::Chain(_)) => match self.chain_swap_handler.on_new_status(update).await {
+                Ok(_) => info!("Successfully handled Chain Swap {id} update"),
+                Err(e) => error!("Failed to handle Chain Swap {id} update: {e}"),
+            },
+            _ => {
+                error!("Could not find Swap {id}");
+            }
+        }
     }
 
-    /// Adds an event listener to the [LiquidSdk] instance, where all [SdkEvent]'s will be emitted to.
-    /// The event listener can be removed be calling [LiquidSdk::remove_event_listener].
-    ///
-    /// # Arguments
-    ///
-    /// * `listener` - The listener which is an implementation of the [EventListener] trait
-    pub async fn add_event_listener(&self, listener: Box<dyn EventListener>) -> SdkResult<String> {
-        Ok(self.event_manager.add(listener).await?)
-    }
+    fn track_background_payments(self: &Arc<LiquidSdk>) {
+        let cloned = self.clone();
+        tokio::spawn(async move {
+            let mut shutdown_receiver = cloned.shutdown_receiver.clone();
+            let mut interval = tokio::time::interval(BACKGROUND_PAYMENTS_INTERVAL);
+            #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = cloned.process_payment_queue().await {
+                            error!("Failed to process payment queue: {e:?}");
+                        }
+                        if let Err(e) = cloned.process_scheduled_payments().await {
+                            error!("Failed to process scheduled payments: {e:?}");
+                        }
 
-    /// Removes an event listener from the [LiquidSdk] instance.
-    ///
-    /// # Arguments
-    ///
-    /// * `id` - the event listener id returned by [LiquidSdk::add_event_listener]
-    pub async fn remove_event_listener(&self, id: String) -> SdkResult<()> {
-        self.event_manager.remove(id).await;
-        Ok(())
+                        #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+                        interval.reset();
+                    }
+                    _ = shutdown_receiver.changed() => {
+                        info!("Received shutdown signal, exiting background payments loop");
+                        return;
+                    }
+                }
+            }
+        });
     }
 
-    async fn emit_payment_updated(&self, payment_id: Option<String>) -> Result<()> {
+    fn track_recurring_payments(self: &Arc<LiquidSdk>) {
+        let cloned = self.clone();
+        tokio::spawn(async move {
+            let mut shutdown_receiver = cloned.shutdown_receiver.clone();
+            let mut interval = tokio::time::interval(RECURRING_PAYMENTS_INTERVAL);
+            #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        if let Err(e) = cloned.process_recurring_payments().await {
+                            error!("Failed to process recurring payments: {e:?}");
+                        }
+
+                        #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+                        interval.reset();
+                    }
+                    _ = shutdown_receiver.changed() => {
+                        info!("Received shutdown signal, exiting recurring payments loop");
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Dispatches the emitted events to the [Config::event_webhook], if one is configured.
+    /// Failed deliveries are retried periodically.
+    fn track_event_webhook(self: &Arc<LiquidSdk>) {
+        let Some(webhook_config) = self.config.event_webhook.clone() else {
+            return;
+        };
+        let dispatcher = EventWebhookDispatcher::new(
+            webhook_config,
+            self.persister.clone(),
+            self.rest_client.clone(),
+        );
+        let mut events_stream = self.event_manager.subscribe();
+        let mut shutdown_receiver = self.shutdown_receiver.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(EVENT_WEBHOOK_RETRY_INTERVAL);
+            #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+                        interval.reset();
+                    }
+                    _ = events_stream.recv() => {}
+                    _ = shutdown_receiver.changed() => {
+                        info!("Received shutdown signal, exiting event webhook loop");
+                        return;
+                    }
+                }
+                if let Err(e) = dispatcher.dispatch_pending_events().await {
+                    warn!("Failed to dispatch events to webhook: {e:?}");
+                }
+            }
+        });
+    }
+
+    /// Uploads a backup with the [BackupTransport], if one is set, after each change of the
+    /// payments or swaps. Changes made while uploading are included in the next backup.
+    ///
+    /// If a [Config::backup_policy] is set, backups are uploaded according to it instead.
+    fn track_backups(self: &Arc<LiquidSdk>) {
+        if self.config.backup_policy.is_some() {
+            return;
+        }
+        let cloned = self.clone();
+        let mut events_stream = self.event_manager.subscribe();
+        let mut shutdown_receiver = self.shutdown_receiver.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    e = events_stream.recv() => match e {
+                        Ok(e) if !e.is_state_change() => continue,
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => return,
+                    },
+                    _ = shutdown_receiver.changed() => {
+                        info!("Received shutdown signal, exiting backup loop");
+                        return;
+                    }
+                }
+                // Coalesce the changes already pending into a single backup
+                while events_stream.try_recv().is_ok() {}
+
+                if let Err(e) = cloned.upload_backup().await {
+                    warn!("Failed to upload backup: {e:?}");
+                }
+            }
+        });
+    }
+
+    /// Backs up the local state according to the [Config::backup_policy], if one is set.
+    fn track_scheduled_backups(self: &Arc<LiquidSdk>) {
+        let Some(backup_policy) = self.config.backup_policy.clone() else {
+            return;
+        };
+        let cloned = self.clone();
+        let mut shutdown_receiver = self.shutdown_receiver.clone();
+        tokio::spawn(async move {
+            match backup_policy {
+                BackupPolicy::CompletedSwaps { swaps } => {
+                    let mut events_stream = cloned.event_manager.subscribe();
+                    let mut completed_swaps = 0;
+                    loop {
+                        tokio::select! {
+                            e = events_stream.recv() => match e {
+                                Ok(e @ SdkEvent::PaymentSucceeded { .. }) if e.swap_type().is_some() => {
+                                    completed_swaps += 1;
+                                }
+                                Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                                Err(broadcast::error::RecvError::Closed) => return,
+                            },
+                            _ = shutdown_receiver.changed() => {
+                                info!("Received shutdown signal, exiting scheduled backup loop");
+                                return;
+                            }
+                        }
+                        if completed_swaps >= swaps {
+                            completed_swaps = 0;
+                            cloned.run_scheduled_backup().await;
+                        }
+                    }
+                }
+                BackupPolicy::Interval { hours } => {
+                    let mut interval =
+                        tokio::time::interval(Duration::from_secs(hours as u64 * 3600));
+                    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+                    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+                    // The first tick completes immediately, skip it as nothing changed yet
+                    interval.tick().await;
+                    loop {
+                        tokio::select! {
+                            _ = interval.tick() => {
+                                #[cfg(all(target_family = "wasm", target_os = "unknown"))]
+                                interval.reset();
+                            }
+                            _ = shutdown_receiver.changed() => {
+                                info!("Received shutdown signal, exiting scheduled backup loop");
+                                return;
+                            }
+                        }
+                        cloned.run_scheduled_backup().await;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Backs up the local state to the default backup path and uploads it with the
+    /// [BackupTransport], if one is set, then reports the outcome with an event.
+    async fn run_scheduled_backup(&self) {
+        let e = match self.scheduled_backup().await {
+            Ok(()) => {
+                info!("Scheduled backup succeeded");
+                SdkEvent::BackupSucceeded
+            }
+            Err(e) => {
+                warn!("Scheduled backup failed: {e:?}");
+                SdkEvent::BackupFailed { err: e.to_string() }
+            }
+        };
+        self.notify_event_listeners(e).await;
+    }
+
+    async fn scheduled_backup(&self) -> SdkResult<()> {
+        #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+        self.persister
+            .backup(self.persister.get_default_backup_path())?;
+        self.upload_backup().await
+    }
+
+    async fn upload_backup(&self) -> SdkResult<()> {
+        let backup_transport = self.backup_transport.read().await;
+        let Some(backup_transport) = backup_transport.as_ref() else {
+            return Ok(());
+        };
+        let backup = self.encrypt_backup(self.persister.backup_bytes()?)?;
+        let version = backup_transport.upload(backup)?;
+        info!("Uploaded backup version {version}");
+        Ok(())
+    }
+
+    async fn notify_event_listeners(&self, e: SdkEvent) {
+        if !self.event_manager.is_paused() {
+            self.metrics.record_event(&e);
+        }
+        self.event_manager.notify(e).await;
+    }
+
+    /// Records the value of a completed payment in the [Config::fiat_currency], using the current
+    /// exchange rate. Failures are logged, as the payment itself is not affected by them.
+    async fn record_payment_fiat_value(&self, payment: Payment) -> Payment {
+        let (Some(fiat_currency), Some(tx_id)) = (&self.config.fiat_currency, &payment.tx_id)
+        else {
+            return payment;
+        };
+        if payment.fiat_amount.is_some() || !payment.details.is_lbtc_asset_id(self.config.network) {
+            return payment;
+        }
+
+        let rate = match self.fetch_fiat_rates().await {
+            Ok(rates) => rates
+                .into_iter()
+                .find(|rate| rate.coin.eq_ignore_ascii_case(fiat_currency)),
+            Err(e) => {
+                warn!("Failed to fetch fiat rates for payment {tx_id}: {e:?}");
+                return payment;
+            }
+        };
+        let Some(rate) = rate else {
+            warn!("No exchange rate found for fiat currency {fiat_currency}");
+            return payment;
+        };
+
+        let fiat_amount = utils::sat_to_fiat(payment.amount_sat, rate.value);
+        if let Err(e) = self
+            .persister
+            .insert_payment_fiat_value(tx_id, &rate.coin, fiat_amount)
+        {
+            warn!("Failed to record the fiat value of payment {tx_id}: {e:?}");
+            return payment;
+        }
+        Payment {
+            fiat_amount: Some(fiat_amount),
+            fiat_currency: Some(rate.coin),
+            ..payment
+        }
+    }
+
+    /// Adds an event listener to the [LiquidSdk] instance, where all [SdkEvent]'s will be emitted to.
+    /// The event listener can be removed be calling [LiquidSdk::remove_event_listener].
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - The listener which is an implementation of the [EventListener] trait
+    /// * `filter` - The optional [EventFilter] restricting the events emitted to the listener
+    pub async fn add_event_listener(
+        &self,
+        listener: Box<dyn EventListener>,
+        filter: Option<EventFilter>,
+    ) -> SdkResult<String> {
+        Ok(self.event_manager.add(listener, filter).await?)
+    }
+
+    /// Removes an event listener from the [LiquidSdk] instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the event listener id returned by [LiquidSdk::add_event_listener]
+    pub async fn remove_event_listener(&self, id: String) -> SdkResult<()> {
+        self.event_manager.remove(id).await;
+        Ok(())
+    }
+
+    /// Replays the events emitted so far, in the order they were emitted. Events are persisted
+    /// in a journal, so apps can catch up on the events missed while they were not running by
+    /// passing the sequence number of the last event they processed. Only the most recent events
+    /// are kept in the journal. Fewer of the periodic events, like syncs and new blocks, are kept
+    /// than of the payment, swap and tx state changes, so that the former don't evict the latter.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ReplayEventsRequest] containing:
+    ///     * `since_seq` - the optional sequence number after which to replay events
+    ///     * `limit` - the optional maximum number of events to return
+    pub async fn replay_events(&self, req: &ReplayEventsRequest) -> SdkResult<Vec<JournaledEvent>> {
+        Ok(self.persister.list_journaled_events(req)?)
+    }
+
+    async fn emit_payment_updated(&self, payment_id: Option<String>) -> Result<()> {
         if let Some(id) = payment_id {
             match self.persister.get_payment(&id)? {
                 Some(payment) => {
@@ -757,6 +1743,7 @@ impl LiquidSdk {
                     
                     match payment.status {
                         Complete => {
+                            let payment = self.record_payment_fiat_value(payment).await;
                             self.notify_event_listeners(SdkEvent::PaymentSucceeded {
                                 details: payment,
                             })
@@ -853,6 +1840,12 @@ impl LiquidSdk {
                             .await
                         }
                         Failed => match payment.payment_type {
+                            PaymentType::Receive if payment.is_expired_unpaid_invoice() => {
+                                self.notify_event_listeners(SdkEvent::PaymentExpired {
+                                    details: payment,
+                                })
+                                .await
+                            }
                             PaymentType::Receive => {
                                 self.notify_event_listeners(SdkEvent::PaymentFailed {
                                     details: payment,
@@ -876,7 +1869,61 @@ impl LiquidSdk {
         Ok(())
     }
 
-    /// Get the wallet and blockchain info from local storage
+    /// Checks the reachability and latency of the remote services used by the SDK, and which
+    /// swap types the swapper currently offers. Apps can use it to inform users of a degraded
+    /// service before they attempt a payment.
+    pub async fn check_health(&self) -> SdkResult<CheckHealthResponse> {
+        self.ensure_is_started().await?;
+
+        let (liquid_explorer, bitcoin_explorer, mempool_fees, swapper_api, fiat_rates) = join5(
+            check_service(
+                HealthCheckService::LiquidExplorer,
+                self.liquid_chain_service.tip(),
+            ),
+            check_service(
+                HealthCheckService::BitcoinExplorer,
+                self.bitcoin_chain_service.tip(),
+            ),
+            check_service(
+                HealthCheckService::MempoolFees,
+                self.bitcoin_chain_service.recommended_fees(),
+            ),
+            check_service(HealthCheckService::SwapperApi, self.swapper.fetch_status()),
+            check_service(HealthCheckService::FiatRates, self.fetch_fiat_rates()),
+        )
+        .await;
+        let is_stream_connected = self.status_stream.is_connected();
+        let status_stream = ServiceHealth {
+            service: HealthCheckService::SwapperStatusStream,
+            is_reachable: is_stream_connected,
+            latency_ms: None,
+            error: (!is_stream_connected).then(|| "Not connected".to_string()),
+        };
+
+        let (swapper_api, swap_service_status) = swapper_api;
+        let services = vec![
+            liquid_explorer.0,
+            bitcoin_explorer.0,
+            mempool_fees.0,
+            swapper_api,
+            status_stream,
+            fiat_rates.0,
+        ];
+        let is_degraded = services.iter().any(|service| !service.is_reachable)
+            || !swap_service_status
+                .as_ref()
+                .is_some_and(SwapServiceStatus::is_fully_available);
+        Ok(CheckHealthResponse {
+            is_degraded,
+            services,
+            swap_service_status,
+        })
+    }
+
+    /// Get the wallet and blockchain info from local storage.
+    ///
+    /// Available without network access: the info is restored from the local data when the
+    /// SDK starts, and updated on each sync.
     pub async fn get_info(&self) -> SdkResult<GetInfoResponse> {
         self.ensure_is_started().await?;
         let maybe_info = self.persister.get_info()?;
@@ -891,10 +1938,74 @@ impl LiquidSdk {
         }
     }
 
-    /// Sign given message with the private key. Returns a zbase encoded signature.
+    /// Get the wallet descriptor, account xpub and master fingerprint, e.g. to set up a
+    /// watch-only wallet or to check that a backup belongs to this wallet.
+    pub fn get_wallet_info(&self) -> SdkResult<GetWalletInfoResponse> {
+        let signer = SdkLwkSigner::new(self.signer.clone()).map_err(anyhow::Error::from)?;
+        let xpub = get_account_xpub(&signer, self.config.network)?;
+        Ok(GetWalletInfoResponse {
+            descriptor: self.descriptor()?,
+            xpub: xpub.to_string(),
+            derivation_path: account_derivation_path(self.config.network).to_string(),
+            fingerprint: self.onchain_wallet.fingerprint()?,
+        })
+    }
+
+    /// Registers the AMP (Asset Management Platform) account of the wallet with the
+    /// [Config::amp_server_url] server, which is needed to receive and hold AMP assets,
+    /// i.e. assets with [AssetMetadata::is_amp] set. If an account is already registered,
+    /// it is returned instead.
+    ///
+    /// Once registered, AMP assets are received into the account and its transactions and
+    /// balances are included in the wallet's. Share the returned [AmpAccount::amp_id] with an
+    /// asset issuer to be authorized to receive its assets. AMP assets can't be sent or
+    /// converted yet, as their spends need the AMP server cosigning.
+    pub async fn register_amp_account(&self) -> SdkResult<AmpAccount> {
+        self.ensure_is_started().await?;
+        if let Some(account) = self.persister.get_amp_account()? {
+            return Ok(account);
+        }
+
+        let amp_server_url = self
+            .config
+            .amp_server_url
+            .clone()
+            .ok_or(SdkError::generic("The AMP server URL is not set"))?;
+        let server_key = amp::amp_server_key(self.config.network).ok_or(SdkError::generic(
+            format!("AMP is not available on {}", self.config.network),
+        ))?;
+        let signer = SdkLwkSigner::new(self.signer.clone()).map_err(anyhow::Error::from)?;
+        let user_key = get_amp_account_key(&signer, self.config.network)?;
+        let client = AmpClient::new(
+            amp_server_url,
+            server_key.to_string(),
+            self.rest_client.clone(),
+        );
+        let descriptor = client.descriptor(&user_key)?;
+        let account = AmpAccount {
+            amp_id: client.register(&descriptor).await?,
+            descriptor: descriptor.to_string(),
+        };
+        self.persister.set_amp_account(&account)?;
+        info!("Registered AMP account {}", account.amp_id);
+
+        self.sync(false).await?;
+        Ok(account)
+    }
+
+    /// Get the registered AMP account, or `None` if no account is registered.
+    ///
+    /// See [LiquidSdk::register_amp_account].
+    pub fn get_amp_account(&self) -> SdkResult<Option<AmpAccount>> {
+        Ok(self.persister.get_amp_account()?)
+    }
+
+    /// Sign given message with the private key. Returns a zbase encoded signature, along with
+    /// the public key it can be verified with.
     pub fn sign_message(&self, req: &SignMessageRequest) -> SdkResult<SignMessageResponse> {
         let signature = self.onchain_wallet.sign_message(&req.message)?;
-        Ok(SignMessageResponse { signature })
+        let pubkey = self.onchain_wallet.pubkey()?;
+        Ok(SignMessageResponse { signature, pubkey })
     }
 
     /// Check whether given message was signed by the given
@@ -906,6 +2017,147 @@ impl LiquidSdk {
         Ok(CheckMessageResponse { is_valid })
     }
 
+    /// Signs the wallet inputs of a PSET created outside of the SDK, e.g. by a coinjoin or an
+    /// asset issuance tool. The signed PSET is returned without being finalized or broadcast.
+    ///
+    /// The PSET is only signed if its network fee and the amounts the wallet spends are within
+    /// the limits set in the request, all its inputs use `SIGHASH_ALL`, the inputs to sign spend
+    /// wallet utxos and the outputs claiming to pay to the wallet are recognized by it.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [SignPsetRequest] containing:
+    ///     * `pset` - the base64-encoded PSET
+    ///     * `max_fee_sat` - the maximum network fee of the transaction
+    ///     * `spend_limits` - the maximum amount of each asset the wallet may spend
+    pub async fn sign_pset(&self, req: &SignPsetRequest) -> Result<SignPsetResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let pset_bytes = lwk_wollet::bitcoin::base64::engine::general_purpose::STANDARD
+            .decode(req.pset.trim())
+            .map_err(|e| PaymentError::generic(&format!("Invalid PSET encoding: {e}")))?;
+        let pset = elements::encode::deserialize::<PartiallySignedTransaction>(&pset_bytes)
+            .map_err(|e| PaymentError::generic(&format!("Invalid PSET: {e}")))?;
+        ensure_sdk!(
+            pset.inputs().iter().all(|input| input
+                .sighash_type
+                .is_none_or(|ty| ty.ecdsa_hash_ty() == Some(elements::EcdsaSighashType::All))),
+            PaymentError::generic("Only PSETs signed with SIGHASH_ALL are supported")
+        );
+
+        let balance = self.onchain_wallet.pset_balance(&pset).await?;
+        ensure_pset_policy(&balance, req)?;
+
+        let signed_pset = self.onchain_wallet.sign_pset_inputs(pset).await?;
+        info!("Signed external PSET with fees_sat = {}", balance.fee_sat);
+        Ok(SignPsetResponse {
+            pset: lwk_wollet::bitcoin::base64::engine::general_purpose::STANDARD
+                .encode(elements::encode::serialize(&signed_pset)),
+            fees_sat: balance.fee_sat,
+        })
+    }
+
+    /// Sweeps all the assets controlled by a private key outside of the wallet, e.g. from a
+    /// paper wallet, into a wallet address. The network fee is paid by the swept L-BTC. The
+    /// sweep is recorded as a received Liquid payment, which like any wallet tx is shown in the
+    /// swept asset if an asset other than L-BTC was swept. The response has the swept amount
+    /// of each asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [SweepPrivateKeyRequest] containing:
+    ///     * `key` - a WIF encoded private key, or a confidential `elwpkh` descriptor with a
+    ///       WIF encoded private key. The key must be of the configured network.
+    pub async fn sweep_private_key(
+        &self,
+        req: &SweepPrivateKeyRequest,
+    ) -> Result<SweepPrivateKeyResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let _in_flight = self.in_flight.start();
+        let key = SweepKey::parse(&req.key)?;
+        ensure_sdk!(
+            key.is_for_network(self.config.network),
+            PaymentError::InvalidNetwork {
+                err: format!("Not a {} private key", self.config.network)
+            }
+        );
+        let address = self.onchain_wallet.next_unused_address().await?.to_string();
+        let sweep_tx = self
+            .onchain_wallet
+            .build_sweep_tx(&key, Some(LIQUID_FEE_RATE_MSAT_PER_VBYTE), &address)
+            .await?;
+        let tx_id = self
+            .liquid_chain_service
+            .broadcast(&sweep_tx.tx)
+            .await?
+            .to_string();
+        info!(
+            "Broadcast sweep tx {tx_id} with amount_sat = {}, asset_amounts = {:?} and fees_sat = {}",
+            sweep_tx.amount_sat, sweep_tx.asset_amounts, sweep_tx.fees_sat
+        );
+
+        // As with sends, a pseudo-tx makes the sweep known to the SDK before LWK picks it up.
+        // Like the payment of the wallet tx, it is in the first swept asset other than L-BTC.
+        let (asset_id, amount) = match sweep_tx.asset_amounts.first_key_value() {
+            Some((asset_id, amount)) => (asset_id.to_string(), *amount),
+            None => (self.config.lbtc_asset_id(), sweep_tx.amount_sat),
+        };
+        let description = "Private key sweep".to_string();
+        let tx_data = PaymentTxData {
+            tx_id: tx_id.clone(),
+            timestamp: Some(utils::now()),
+            amount,
+            fees_sat: sweep_tx.fees_sat,
+            payment_type: PaymentType::Receive,
+            is_confirmed: false,
+            unblinding_data: None,
+            asset_id: asset_id.clone(),
+        };
+        self.persister.insert_or_update_payment(
+            tx_data.clone(),
+            Some(PaymentTxDetails {
+                tx_id: tx_id.clone(),
+                destination: address.clone(),
+                description: Some(description.clone()),
+                ..Default::default()
+            }),
+            false,
+        )?;
+        self.emit_payment_updated(Some(tx_id)).await?;
+
+        let payment_details = PaymentDetails::Liquid {
+            asset_id,
+            destination: address,
+            description,
+            asset_info: None,
+            lnurl_info: None,
+            bip353_address: None,
+            conversion: None,
+        };
+        let asset_amounts = sweep_tx
+            .asset_amounts
+            .iter()
+            .map(|(asset_id, amount)| {
+                let asset_id = asset_id.to_string();
+                Ok(match self.persister.get_asset_metadata(&asset_id)? {
+                    Some(asset_metadata) => asset_metadata.asset_amount(*amount),
+                    // The base units of an unknown asset are shown as is
+                    None => AssetAmount {
+                        asset_id,
+                        base_units: *amount,
+                        ..Default::default()
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, PaymentError>>()?;
+        Ok(SweepPrivateKeyResponse {
+            payment: Payment::from_tx_data(tx_data, None, payment_details),
+            amount_sat: sweep_tx.amount_sat,
+            asset_amounts,
+        })
+    }
+
     async fn validate_bitcoin_address(&self, input: &str) -> Result<String, PaymentError> {
         match self.parse(input).await? {
             InputType::BitcoinAddress {
@@ -1000,6 +2252,16 @@ impl LiquidSdk {
         Ok(invoice_parsed)
     }
 
+    /// Ensures the fees accepted by the caller match the current ones. Otherwise the cached pairs
+    /// are dropped, so that preparing the payment again returns the current fees.
+    async fn ensure_current_fees(&self, fees_match: bool) -> Result<(), PaymentError> {
+        if !fees_match {
+            self.swapper.invalidate_pairs().await;
+            return Err(PaymentError::InvalidOrExpiredFees);
+        }
+        Ok(())
+    }
+
     /// For submarine swaps (Liquid -> LN), the output amount (invoice amount) is checked if it fits
     /// the pair limits. This is unlike all the other swap types, where the input amount is checked.
     async fn validate_submarine_pairs(
@@ -1024,6 +2286,24 @@ impl LiquidSdk {
         Ok(lbtc_pair)
     }
 
+    /// Breaks down the fees of a submarine swap. The fee of the swapper's claim tx is fixed by the
+    /// pair, while the fee of the lockup tx is estimated by the SDK.
+    fn submarine_fee_breakdown(
+        pair: &SubmarinePair,
+        receiver_amount_sat: u64,
+        lockup_fee_sat: u64,
+    ) -> FeeBreakdown {
+        FeeBreakdown {
+            service_fee_sat: pair.fees.boltz(receiver_amount_sat),
+            swapper_network_fee_sat: 0,
+            lockup_fee_sat,
+            lockup_fee_is_estimate: true,
+            claim_fee_sat: pair.fees.miner_fees,
+            claim_fee_is_estimate: false,
+            asset_fee: None,
+        }
+    }
+
     async fn get_chain_pair(&self, direction: Direction) -> Result<ChainPair, PaymentError> {
         self.swapper
             .get_chain_pair(direction)
@@ -1173,6 +2453,7 @@ impl LiquidSdk {
     ///        - [PayAmount::Drain] which uses all Bitcoin funds
     ///        - [PayAmount::Bitcoin] which sets the amount in satoshi that will be received
     ///        - [PayAmount::Asset] which sets the amount of an asset that will be received
+    ///        - [PayAmount::AssetUnits] which sets the amount of an asset that will be received, in its base units
     ///
     /// # Returns
     /// Returns a [PrepareSendResponse] containing:
@@ -1182,6 +2463,9 @@ impl LiquidSdk {
     ///     * `estimated_asset_fees` - the optional estimated fee in the asset. Is set when
     ///        [PayAmount::Asset::estimate_asset_fees] is set to `true`, the Payjoin service accepts
     ///        this asset to pay fees and there are funds available in this asset to pay fees.
+    ///     * `fee_breakdown` - the breakdown of the fees. Its `asset_fee` holds the amount of the
+    ///        asset paid to have the L-BTC network fee covered, when asset fees are estimated.
+    #[tracing::instrument(skip_all)]
     pub async fn prepare_send_payment(
         &self,
         req: &PrepareSendRequest,
@@ -1190,23 +2474,63 @@ impl LiquidSdk {
 
         let get_info_res = self.get_info().await?;
         let fees_sat;
+        let fee_breakdown;
         let estimated_asset_fees;
         let receiver_amount_sat;
         let asset_id;
         let payment_destination;
 
+        let (amount, fiat_conversion) = match (&req.amount, &req.fiat_amount) {
+            (Some(_), Some(_)) => {
+                return Err(PaymentError::generic(
+                    "Only one of amount and fiat_amount can be set",
+                ))
+            }
+            (None, Some(fiat_amount)) => {
+                let fiat_conversion = self.convert_fiat_amount(fiat_amount).await?;
+                let amount = PayAmount::Bitcoin {
+                    receiver_amount_sat: fiat_conversion.amount_sat,
+                };
+                (Some(amount), Some(fiat_conversion))
+            }
+            (amount, None) => (amount.clone(), None),
+        };
+        // A BIP353 name can't be parsed into a Liquid address or BOLT11 invoice otherwise
+        let bip353_address = Bip353Name::parse(&req.destination).map(|name| name.to_string());
+
         match self.parse(&req.destination).await {
             Ok(InputType::LiquidAddress {
                 address: mut liquid_address_data,
             }) => {
-                let amount = match (
-                    liquid_address_data.amount,
-                    liquid_address_data.amount_sat,
-                    liquid_address_data.asset_id,
-                    req.amount.clone(),
-                ) {
-                    (Some(amount), Some(amount_sat), Some(asset_id), None) => {
-                        if asset_id.eq(&self.config.lbtc_asset_id()) {
+                // A BIP21 requesting a specific asset can only be paid in that asset
+                if let (Some(bip21_asset_id), Some(amount)) =
+                    (&liquid_address_data.asset_id, &amount)
+                {
+                    let requested_asset_id = match amount {
+                        PayAmount::Asset { asset_id, .. } => asset_id.clone(),
+                        PayAmount::AssetUnits {
+                            receiver_amount, ..
+                        } => receiver_amount.asset_id.clone(),
+                        PayAmount::Bitcoin { .. } | PayAmount::Drain => self.config.lbtc_asset_id(),
+                    };
+                    ensure_sdk!(
+                        requested_asset_id.eq(bip21_asset_id),
+                        PaymentError::AssetError {
+                            err: format!(
+                                "Cannot send asset {requested_asset_id} to a destination requesting asset {bip21_asset_id}"
+                            ),
+                        }
+                    );
+                }
+
+                let amount = match (
+                    liquid_address_data.amount,
+                    liquid_address_data.amount_sat,
+                    liquid_address_data.asset_id,
+                    amount.clone(),
+                ) {
+                    (Some(amount), Some(amount_sat), Some(asset_id), None) => {
+                        if asset_id.eq(&self.config.lbtc_asset_id()) {
                             PayAmount::Bitcoin {
                                 receiver_amount_sat: amount_sat,
                             }
@@ -1240,12 +2564,8 @@ impl LiquidSdk {
                     }
                 );
 
-                (
-                    asset_id,
-                    receiver_amount_sat,
-                    fees_sat,
-                    estimated_asset_fees,
-                ) = match amount {
+                let payjoin_fee;
+                (asset_id, receiver_amount_sat, fees_sat, payjoin_fee) = match amount {
                     PayAmount::Drain => {
                         ensure_sdk!(
                             get_info_res.wallet_info.pending_receive_sat == 0
@@ -1280,18 +2600,9 @@ impl LiquidSdk {
                             .await?;
                         (asset_id, receiver_amount_sat, Some(fees_sat), None)
                     }
-                    PayAmount::Asset {
-                        asset_id,
-                        receiver_amount,
-                        estimate_asset_fees,
-                    } => {
-                        let estimate_asset_fees = estimate_asset_fees.unwrap_or(false);
-                        let asset_metadata = self.persister.get_asset_metadata(&asset_id)?.ok_or(
-                            PaymentError::AssetError {
-                                err: format!("Asset {asset_id} is not supported"),
-                            },
-                        )?;
-                        let receiver_amount_sat = asset_metadata.amount_to_sat(receiver_amount);
+                    amount @ (PayAmount::Asset { .. } | PayAmount::AssetUnits { .. }) => {
+                        let (asset_id, receiver_amount_sat, estimate_asset_fees) =
+                            self.resolve_asset_pay_amount(amount)?;
                         let fees_sat_res = self
                             .estimate_onchain_tx_or_drain_tx_fee(
                                 receiver_amount_sat,
@@ -1299,7 +2610,7 @@ impl LiquidSdk {
                                 &asset_id,
                             )
                             .await;
-                        let asset_fees = if estimate_asset_fees {
+                        let payjoin_fee = if estimate_asset_fees {
                             self.payjoin_service
                                 .estimate_payjoin_tx_fee(&asset_id, receiver_amount_sat)
                                 .await
@@ -1308,25 +2619,28 @@ impl LiquidSdk {
                         } else {
                             None
                         };
-                        let (fees_sat, asset_fees) = match (fees_sat_res, asset_fees) {
-                            (Ok(fees_sat), _) => (Some(fees_sat), asset_fees),
-                            (Err(e), Some(asset_fees)) => {
+                        let (fees_sat, payjoin_fee) = match (fees_sat_res, payjoin_fee) {
+                            (Ok(fees_sat), _) => (Some(fees_sat), payjoin_fee),
+                            (Err(e), Some(payjoin_fee)) => {
                                 debug!(
                                     "Error estimating onchain tx, but returning payjoin fees: {e}"
                                 );
-                                (None, Some(asset_fees))
+                                (None, Some(payjoin_fee))
                             }
                             (Err(e), None) => return Err(e),
                         };
-                        (asset_id, receiver_amount_sat, fees_sat, asset_fees)
+                        (asset_id, receiver_amount_sat, fees_sat, payjoin_fee)
                     }
                 };
+                estimated_asset_fees = payjoin_fee.as_ref().map(|fee| fee.asset_fee);
+                fee_breakdown = liquid_fee_breakdown(fees_sat, payjoin_fee.as_ref());
 
                 liquid_address_data.amount_sat = Some(receiver_amount_sat);
                 liquid_address_data.asset_id = Some(asset_id.clone());
                 payment_destination = SendDestination::LiquidAddress {
                     address_data: liquid_address_data,
-                    bip353_address: None,
+                    bip353_address: bip353_address.clone(),
+                    payjoin_url: payjoin_endpoint(&req.destination),
                 };
             }
             Ok(InputType::Bolt11 { invoice }) => {
@@ -1339,7 +2653,7 @@ impl LiquidSdk {
 
                 if let Some(PayAmount::Bitcoin {
                     receiver_amount_sat: amount_sat,
-                }) = req.amount
+                }) = amount
                 {
                     ensure_sdk!(
                         invoice_amount_sat == amount_sat,
@@ -1357,8 +2671,8 @@ impl LiquidSdk {
                     .map(|(address, _)| address);
                 asset_id = self.config.lbtc_asset_id();
                 estimated_asset_fees = None;
-                (receiver_amount_sat, fees_sat, payment_destination) =
-                    match (mrh_address.clone(), req.amount.clone()) {
+                (receiver_amount_sat, fee_breakdown, payment_destination) =
+                    match (mrh_address.clone(), amount.clone()) {
                         (Some(lbtc_address), Some(PayAmount::Drain)) => {
                             // The BOLT11 invoice has an MRH and it is requested that the wallet balance is to be drained,
                             // therefore we use the MRH address and drain the balance (overpaying the invoice if neccessary)
@@ -1377,9 +2691,14 @@ impl LiquidSdk {
                                     label: None,
                                     message: None,
                                 },
-                                bip353_address: None,
+                                bip353_address: bip353_address.clone(),
+                                payjoin_url: None,
                             };
-                            (drain_amount_sat, Some(drain_fees_sat), payment_destination)
+                            (
+                                drain_amount_sat,
+                                Some(FeeBreakdown::direct(drain_fees_sat)),
+                                payment_destination,
+                            )
                         }
                         (Some(lbtc_address), _) => {
                             // The BOLT11 invoice has an MRH but no drain is requested,
@@ -1393,10 +2712,10 @@ impl LiquidSdk {
                                 .await?;
                             (
                                 invoice_amount_sat,
-                                Some(fees_sat),
+                                Some(FeeBreakdown::direct(fees_sat)),
                                 SendDestination::Bolt11 {
                                     invoice,
-                                    bip353_address: None,
+                                    bip353_address: bip353_address.clone(),
                                 },
                             )
                         }
@@ -1407,23 +2726,27 @@ impl LiquidSdk {
                             let lockup_fees_sat = self
                                 .estimate_lockup_tx_or_drain_tx_fee(user_lockup_amount_sat)
                                 .await?;
-                            let fees_sat = boltz_fees_total + lockup_fees_sat;
                             (
                                 invoice_amount_sat,
-                                Some(fees_sat),
+                                Some(Self::submarine_fee_breakdown(
+                                    &lbtc_pair,
+                                    invoice_amount_sat,
+                                    lockup_fees_sat,
+                                )),
                                 SendDestination::Bolt11 {
                                     invoice,
-                                    bip353_address: None,
+                                    bip353_address: bip353_address.clone(),
                                 },
                             )
                         }
                     };
+                fees_sat = fee_breakdown.as_ref().map(FeeBreakdown::total);
             }
             Ok(InputType::Bolt12Offer {
                 offer,
                 bip353_address,
             }) => {
-                receiver_amount_sat = match req.amount {
+                receiver_amount_sat = match amount {
                     Some(PayAmount::Bitcoin {
                         receiver_amount_sat: amount_sat,
                     }) => Ok(amount_sat),
@@ -1448,6 +2771,11 @@ impl LiquidSdk {
                     .await?;
                 asset_id = self.config.lbtc_asset_id();
                 fees_sat = Some(boltz_fees_total + lockup_fees_sat);
+                fee_breakdown = Some(Self::submarine_fee_breakdown(
+                    &lbtc_pair,
+                    receiver_amount_sat,
+                    lockup_fees_sat,
+                ));
                 estimated_asset_fees = None;
 
                 payment_destination = SendDestination::Bolt12 {
@@ -1468,10 +2796,58 @@ impl LiquidSdk {
             &asset_id,
         )?;
 
+        let estimated_asset_fees_amount = match estimated_asset_fees {
+            Some(asset_fees) => self
+                .persister
+                .get_asset_metadata(&asset_id)?
+                .map(|am| am.asset_amount(am.amount_to_sat(asset_fees))),
+            None => None,
+        };
+        let fee_breakdown = match &estimated_asset_fees_amount {
+            Some(asset_fee) => Some(FeeBreakdown {
+                asset_fee: Some(asset_fee.clone()),
+                ..fee_breakdown.unwrap_or_default()
+            }),
+            None => fee_breakdown,
+        };
+
         Ok(PrepareSendResponse {
             destination: payment_destination,
             fees_sat,
+            fee_breakdown,
             estimated_asset_fees,
+            estimated_asset_fees_amount,
+            fiat_conversion,
+        })
+    }
+
+    /// Converts a [FiatAmount] to satoshi using the current exchange rate of its currency
+    async fn convert_fiat_amount(
+        &self,
+        fiat_amount: &FiatAmount,
+    ) -> Result<FiatConversion, PaymentError> {
+        ensure_sdk!(fiat_amount.amount > 0.0, PaymentError::AmountOutOfRange);
+
+        let rate = self
+            .fetch_fiat_rates()
+            .await
+            .map_err(|e| PaymentError::generic(&format!("Failed to fetch fiat rates: {e}")))?
+            .into_iter()
+            .find(|rate| rate.coin.eq_ignore_ascii_case(&fiat_amount.currency))
+            .ok_or(PaymentError::generic(&format!(
+                "No exchange rate found for currency {}",
+                fiat_amount.currency
+            )))?;
+        let amount_sat = utils::fiat_to_sat(fiat_amount.amount, rate.value);
+        info!(
+            "Converted {} {} to {amount_sat} sat at rate {}",
+            fiat_amount.amount, fiat_amount.currency, rate.value
+        );
+
+        Ok(FiatConversion {
+            fiat_amount: fiat_amount.clone(),
+            rate: rate.value,
+            amount_sat,
         })
     }
 
@@ -1482,6 +2858,15 @@ impl LiquidSdk {
         }
     }
 
+    fn ensure_onchain_send_is_not_self_transfer(&self, address: &str) -> Result<(), PaymentError> {
+        match self.persister.fetch_chain_swap_by_lockup_address(address)? {
+            Some(swap) if swap.direction == Direction::Incoming => {
+                Err(PaymentError::SelfTransferNotSupported)
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Either pays a Lightning invoice via a submarine swap or sends funds directly to an address.
     ///
     /// Depending on [Config]'s `payment_timeout_sec`, this function will return:
@@ -1493,28 +2878,93 @@ impl LiquidSdk {
     ///
     /// * `req` - A [SendPaymentRequest], containing:
     ///     * `prepare_response` - the [PrepareSendResponse] returned by [LiquidSdk::prepare_send_payment]
+    ///     * `use_asset_fees` - if set to true, the payment fees are paid using the asset
+    ///     * `max_fee_sat` - the optional maximum fees to pay
+    ///     * `spend_limit_override` - the optional token allowing the payment to exceed the
+    ///       [Config::spend_limits]
+    ///     * `idempotency_key` - the optional key identifying the payment. If a payment was
+    ///       already sent with this key, it's returned instead of sending a new one
     ///
     /// # Errors
     ///
     /// * [PaymentError::PaymentTimeout] - if the payment could not be initiated in this time
+    /// * [PaymentError::FeeLimitExceeded] - if the fees exceed `max_fee_sat`
+    /// * [PaymentError::SpendLimitExceeded] - if the payment exceeds the [Config::spend_limits]
+    /// * [PaymentError::PaymentInProgress] - if a payment with the same `idempotency_key` is
+    ///   still being sent, or was interrupted or failed after it may have been sent
+    #[tracing::instrument(skip_all)]
     pub async fn send_payment(
         &self,
         req: &SendPaymentRequest,
     ) -> Result<SendPaymentResponse, PaymentError> {
         self.ensure_is_started().await?;
 
+        let Some(idempotency_key) = &req.idempotency_key else {
+            return self.send_payment_inner(req, None).await;
+        };
+        if !self
+            .persister
+            .insert_idempotency_key(idempotency_key, utils::now())?
+        {
+            info!("Payment with idempotency key {idempotency_key} was already sent");
+            return self
+                .fetch_idempotent_payment(idempotency_key)?
+                .map(|payment| SendPaymentResponse { payment })
+                .ok_or(PaymentError::PaymentInProgress);
+        }
+
+        match self
+            .send_payment_inner(req, Some(idempotency_key.as_str()))
+            .await
+        {
+            Ok(response) => Ok(response),
+            Err(e) => {
+                // Only release the key if the payment is known not to have been sent. Otherwise
+                // it stays reserved, so a retry can't pay twice.
+                if e.is_before_broadcast() {
+                    self.persister.delete_idempotency_key(idempotency_key)?;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Fetches the payment linked to the `idempotency_key`, if it was sent
+    fn fetch_idempotent_payment(&self, idempotency_key: &str) -> Result<Option<Payment>> {
+        match self
+            .persister
+            .fetch_idempotency_key_payment_id(idempotency_key)?
+        {
+            Some(payment_id) => self.persister.get_payment(&payment_id),
+            None => Ok(None),
+        }
+    }
+
+    /// Sends the payment. If set, the `idempotency_key` is linked to the payment id, i.e. the
+    /// swap id or tx id, before the swap is funded or the tx is broadcast.
+    async fn send_payment_inner(
+        &self,
+        req: &SendPaymentRequest,
+        idempotency_key: Option<&str>,
+    ) -> Result<SendPaymentResponse, PaymentError> {
         let PrepareSendResponse {
             fees_sat,
             destination: payment_destination,
             ..
         } = &req.prepare_response;
+        let asset_pay_fees = req.use_asset_fees.unwrap_or_default();
+        self.ensure_spend_limits(
+            self.send_destination_amount_sat(payment_destination) + fees_sat.unwrap_or_default(),
+            req.spend_limit_override.as_deref(),
+        )
+        .await?;
 
         match payment_destination {
             SendDestination::LiquidAddress {
                 address_data: liquid_address_data,
                 bip353_address,
+                payjoin_url,
             } => {
-                let asset_pay_fees = req.use_asset_fees.unwrap_or_default();
                 let Some(amount_sat) = liquid_address_data.amount_sat else {
                     return Err(PaymentError::AmountMissing {
                         err: "Amount must be set when paying to a Liquid address".to_string(),
@@ -1548,12 +2998,25 @@ impl LiquidSdk {
                     )?;
 
                 let mut response = if asset_pay_fees {
-                    self.pay_liquid_payjoin(liquid_address_data.clone(), amount_sat)
-                        .await?
+                    self.pay_liquid_payjoin(
+                        liquid_address_data.clone(),
+                        amount_sat,
+                        req.max_fee_sat,
+                        idempotency_key,
+                    )
+                    .await?
                 } else {
                     let fees_sat = fees_sat.ok_or(PaymentError::InsufficientFunds)?;
-                    self.pay_liquid(liquid_address_data.clone(), amount_sat, fees_sat, true)
-                        .await?
+                    self.pay_liquid(
+                        liquid_address_data.clone(),
+                        amount_sat,
+                        fees_sat,
+                        req.max_fee_sat,
+                        true,
+                        payjoin_url.as_deref(),
+                        idempotency_key,
+                    )
+                    .await?
                 };
 
                 self.insert_bip353_payment_details(bip353_address, &mut response)?;
@@ -1564,7 +3027,9 @@ impl LiquidSdk {
                 bip353_address,
             } => {
                 let fees_sat = fees_sat.ok_or(PaymentError::InsufficientFunds)?;
-                let mut response = self.pay_bolt11_invoice(&invoice.bolt11, fees_sat).await?;
+                let mut response = self
+                    .pay_bolt11_invoice(&invoice.bolt11, fees_sat, req.max_fee_sat, idempotency_key)
+                    .await?;
                 self.insert_bip353_payment_details(bip353_address, &mut response)?;
                 Ok(response)
             }
@@ -1579,7 +3044,14 @@ impl LiquidSdk {
                     .get_bolt12_invoice(&offer.offer, *receiver_amount_sat)
                     .await?;
                 let mut response = self
-                    .pay_bolt12_invoice(offer, *receiver_amount_sat, &bolt12_invoice, fees_sat)
+                    .pay_bolt12_invoice(
+                        offer,
+                        *receiver_amount_sat,
+                        &bolt12_invoice,
+                        fees_sat,
+                        req.max_fee_sat,
+                        idempotency_key,
+                    )
                     .await?;
                 self.insert_bip353_payment_details(bip353_address, &mut response)?;
                 Ok(response)
@@ -1587,1156 +3059,2020 @@ impl LiquidSdk {
         }
     }
 
-    fn insert_bip353_payment_details(
-        &self,
-        bip353_address: &Option<String>,
-        response: &mut SendPaymentResponse,
-    ) -> Result<()> {
-        if bip353_address.is_some() {
-            if let (Some(tx_id), Some(destination)) =
-                (&response.payment.tx_id, &response.payment.destination)
-            {
-                self.persister
-                    .insert_or_update_payment_details(PaymentTxDetails {
-                        tx_id: tx_id.clone(),
-                        destination: destination.clone(),
-                        description: None,
-                        lnurl_info: None,
-                        bip353_address: bip353_address.clone(),
-                        asset_fees: None,
-                    })?;
-                // Get the payment with the bip353_address details
-                if let Some(payment) = self.persister.get_payment(tx_id)? {
-                    response.payment = payment;
-                }
-            }
-        }
-        Ok(())
+    /// Adds a payment to the persistent outgoing payment queue. Queued payments are prepared and
+    /// sent in the background while the SDK is started. Payments failing with a transient error,
+    /// e.g. while offline, are retried with an exponential backoff.
+    ///
+    /// Once sent, the payment is removed from the queue and tracked like any other payment.
+    /// Payments failing with a permanent error or after too many attempts stay in the queue
+    /// as [QueuedPaymentState::Failed] until cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - A [QueuePaymentRequest], containing:
+    ///     * `prepare_request` - the [PrepareSendRequest] used to prepare the payment on each attempt
+    ///     * `use_asset_fees` - if set to true, the payment fees are paid using the asset
+    ///     * `max_fee_sat` - the optional maximum fees to pay
+    pub async fn queue_payment(&self, req: &QueuePaymentRequest) -> SdkResult<QueuedPayment> {
+        let now = utils::now();
+        let queued_payment = QueuedPayment {
+            id: Uuid::new_v4().to_string(),
+            request: req.clone(),
+            state: QueuedPaymentState::Pending,
+            attempts: 0,
+            created_at: now,
+            next_attempt_at: now,
+            last_error: None,
+        };
+        self.persister.insert_queued_payment(&queued_payment)?;
+        info!("Queued payment {}", queued_payment.id);
+        Ok(queued_payment)
     }
 
-    async fn pay_bolt11_invoice(
-        &self,
-        invoice: &str,
-        fees_sat: u64,
-    ) -> Result<SendPaymentResponse, PaymentError> {
-        self.ensure_send_is_not_self_transfer(invoice)?;
-        let bolt11_invoice = self.validate_bolt11_invoice(invoice)?;
+    /// Lists the payments in the outgoing payment queue, oldest first.
+    pub async fn list_queued_payments(&self) -> SdkResult<Vec<QueuedPayment>> {
+        Ok(self.persister.list_queued_payments()?)
+    }
 
-        let amount_sat = get_invoice_amount!(invoice);
-        let payer_amount_sat = amount_sat + fees_sat;
+    /// Removes a payment from the outgoing payment queue. An attempt already in progress
+    /// is not interrupted.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the id of the [QueuedPayment] returned by [LiquidSdk::queue_payment]
+    pub async fn cancel_queued_payment(&self, id: String) -> SdkResult<()> {
         ensure_sdk!(
-            payer_amount_sat <= self.get_info().await?.wallet_info.balance_sat,
-            PaymentError::InsufficientFunds
+            self.persister.delete_queued_payment(&id)?,
+            SdkError::generic(format!("Queued payment {id} not found"))
         );
+        info!("Cancelled queued payment {id}");
+        Ok(())
+    }
 
-        let description = match bolt11_invoice.description() {
-            Bolt11InvoiceDescription::Direct(msg) => Some(msg.to_string()),
-            Bolt11InvoiceDescription::Hash(_) => None,
+    /// Schedules a payment to be sent at a future time. The payment is prepared at execution
+    /// time, so its fees reflect the conditions at that time, and is sent in the background
+    /// if the SDK is started. Otherwise, it is sent once the SDK is started again.
+    ///
+    /// Once sent, the payment becomes [ScheduledPaymentState::Executed] and a
+    /// [SdkEvent::ScheduledPaymentExecuted] event is emitted. Payments failing with a transient
+    /// error are retried with an exponential backoff. Payments failing with a permanent error or
+    /// after too many attempts become [ScheduledPaymentState::Failed] and a
+    /// [SdkEvent::ScheduledPaymentFailed] event is emitted.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - A [SchedulePaymentRequest], containing:
+    ///     * `prepare_request` - the [PrepareSendRequest] used to prepare the payment at execution time
+    ///     * `use_asset_fees` - if set to true, the payment fees are paid using the asset
+    ///     * `max_fee_sat` - the optional maximum fees to pay
+    ///     * `execute_at` - the execution time, as a UNIX timestamp in seconds
+    pub async fn schedule_payment(
+        &self,
+        req: &SchedulePaymentRequest,
+    ) -> SdkResult<ScheduledPayment> {
+        let now = utils::now();
+        ensure_sdk!(
+            req.execute_at > now,
+            SdkError::generic("The execution time must be in the future")
+        );
+        let scheduled_payment = ScheduledPayment {
+            id: Uuid::new_v4().to_string(),
+            request: req.clone(),
+            state: ScheduledPaymentState::Pending,
+            attempts: 0,
+            created_at: now,
+            next_attempt_at: req.execute_at,
+            tx_id: None,
+            last_error: None,
         };
-
-        match self.swapper.check_for_mrh(invoice).await? {
-            // If we find a valid MRH, extract the BIP21 address and pay to it via onchain tx
-            Some((address, _)) => {
-                info!("Found MRH for L-BTC address {address}, invoice amount_sat {amount_sat}");
-                self.pay_liquid(
-                    LiquidAddressData {
-                        address,
-                        network: self.config.network.into(),
-                        asset_id: None,
-                        amount: None,
-                        amount_sat: None,
-                        label: None,
-                        message: None,
-                    },
-                    amount_sat,
-                    fees_sat,
-                    false,
-                )
-                .await
-            }
-
-            // If no MRH found, perform usual swap
-            None => {
-                self.send_payment_via_swap(
-                    invoice,
-                    None,
-                    &bolt11_invoice.payment_hash().to_string(),
-                    description,
-                    amount_sat,
-                    fees_sat,
-                )
-                .await
-            }
-        }
+        self.persister
+            .insert_scheduled_payment(&scheduled_payment)?;
+        info!(
+            "Scheduled payment {} at {}",
+            scheduled_payment.id, req.execute_at
+        );
+        Ok(scheduled_payment)
     }
 
-    async fn pay_bolt12_invoice(
-        &self,
-        offer: &LNOffer,
-        user_specified_receiver_amount_sat: u64,
-        invoice_str: &str,
-        fees_sat: u64,
-    ) -> Result<SendPaymentResponse, PaymentError> {
-        let invoice =
-            self.validate_bolt12_invoice(offer, user_specified_receiver_amount_sat, invoice_str)?;
+    /// Lists the scheduled payments, oldest first, including the executed and failed ones.
+    pub async fn list_scheduled_payments(&self) -> SdkResult<Vec<ScheduledPayment>> {
+        Ok(self.persister.list_scheduled_payments()?)
+    }
 
-        let receiver_amount_sat = invoice.amount_msats() / 1_000;
-        let payer_amount_sat = receiver_amount_sat + fees_sat;
+    /// Cancels a [ScheduledPaymentState::Pending] scheduled payment. Fails if an attempt is in
+    /// progress, or if the payment was already executed or failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the id of the [ScheduledPayment] returned by [LiquidSdk::schedule_payment]
+    pub async fn cancel_scheduled_payment(&self, id: String) -> SdkResult<()> {
         ensure_sdk!(
-            payer_amount_sat <= self.get_info().await?.wallet_info.balance_sat,
-            PaymentError::InsufficientFunds
+            self.persister.delete_pending_scheduled_payment(&id)?,
+            SdkError::generic(format!("Pending scheduled payment {id} not found"))
         );
-
-        self.send_payment_via_swap(
-            invoice_str,
-            Some(offer.offer.clone()),
-            &invoice.payment_hash().to_string(),
-            invoice.description().map(|desc| desc.to_string()),
-            receiver_amount_sat,
-            fees_sat,
-        )
-        .await
+        info!("Cancelled scheduled payment {id}");
+        Ok(())
     }
 
-    /// Performs a Send Payment by doing an onchain tx to a Liquid address
-    async fn pay_liquid(
+    /// Creates a payment repeated at a fixed interval, e.g. for a subscription or an allowance.
+    /// Each occurrence is prepared and sent in the background while the SDK is started, and
+    /// recorded as a [RecurringPaymentOccurrence] linked to the recurring payment by its id.
+    ///
+    /// A [SdkEvent::RecurringPaymentExecuted] or [SdkEvent::RecurringPaymentFailed] event is
+    /// emitted for each occurrence. An occurrence failing with a transient error, e.g. while
+    /// offline, is retried until the next occurrence is due. If several occurrences were missed
+    /// while the SDK was not started, only the current one is sent and the others are recorded
+    /// as missed.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - A [CreateRecurringPaymentRequest], containing:
+    ///     * `destination` - a Lightning address or BOLT12 offer
+    ///     * `amount_sat` - the amount received on each occurrence
+    ///     * `interval_sec` - the interval between the occurrences, in seconds
+    ///     * `start_at` - the optional time of the first occurrence, defaulting to now
+    ///     * `max_fee_sat` - the optional maximum fees of each occurrence
+    pub async fn create_recurring_payment(
         &self,
-        address_data: LiquidAddressData,
-        receiver_amount_sat: u64,
-        fees_sat: u64,
-        skip_already_paid_check: bool,
-    ) -> Result<SendPaymentResponse, PaymentError> {
-        let destination = address_data
-            .to_uri()
-            .unwrap_or(address_data.address.clone());
-        let asset_id = address_data.asset_id.unwrap_or(self.config.lbtc_asset_id());
-        let payments = self.persister.get_payments(&ListPaymentsRequest {
-            details: Some(ListPaymentDetails::Liquid {
-                asset_id: Some(asset_id.clone()),
-                destination: Some(destination.clone()),
-            }),
-            ..Default::default()
-        })?;
+        req: &CreateRecurringPaymentRequest,
+    ) -> SdkResult<RecurringPayment> {
         ensure_sdk!(
-            skip_already_paid_check || payments.is_empty(),
-            PaymentError::AlreadyPaid
+            req.amount_sat > 0,
+            SdkError::generic("The amount must be greater than zero")
+        );
+        ensure_sdk!(
+            req.interval_sec >= MIN_RECURRING_PAYMENT_INTERVAL_SEC,
+            SdkError::generic(format!(
+                "The interval must be at least {MIN_RECURRING_PAYMENT_INTERVAL_SEC} seconds"
+            ))
+        );
+        ensure_sdk!(
+            matches!(
+                self.parse(&req.destination).await,
+                Ok(InputType::LnUrlPay { .. } | InputType::Bolt12Offer { .. })
+            ),
+            SdkError::generic("The destination must be a Lightning address or BOLT12 offer")
         );
 
-        let tx = self
-            .onchain_wallet
-            .build_tx_or_drain_tx(
-                Some(LIQUID_FEE_RATE_MSAT_PER_VBYTE),
-                &address_data.address,
-                &asset_id,
-                receiver_amount_sat,
-            )
-            .await?;
-        let tx_id = tx.txid().to_string();
-        let tx_fees_sat = tx.all_fees().values().sum::<u64>();
-        ensure_sdk!(tx_fees_sat <= fees_sat, PaymentError::InvalidOrExpiredFees);
+        let now = utils::now();
+        let recurring_payment = RecurringPayment {
+            id: Uuid::new_v4().to_string(),
+            destination: req.destination.clone(),
+            amount_sat: req.amount_sat,
+            interval_sec: req.interval_sec,
+            max_fee_sat: req.max_fee_sat,
+            created_at: now,
+            next_occurrence_at: req.start_at.unwrap_or(now),
+        };
+        self.persister
+            .insert_recurring_payment(&recurring_payment)?;
+        info!("Created recurring payment {}", recurring_payment.id);
+        Ok(recurring_payment)
+    }
 
-        info!(
-            "Built onchain Liquid tx with receiver_amount_sat = {receiver_amount_sat}, fees_sat = {fees_sat} and txid = {tx_id}"
-        );
+    /// Lists the recurring payments, oldest first.
+    pub async fn list_recurring_payments(&self) -> SdkResult<Vec<RecurringPayment>> {
+        Ok(self.persister.list_recurring_payments()?)
+    }
 
-        let tx_id = self.liquid_chain_service.broadcast(&tx).await?.to_string();
+    /// Lists the occurrences of a recurring payment, oldest first, with the tx id of each sent
+    /// payment.
+    ///
+    /// # Arguments
+    ///
+    /// * `recurring_payment_id` - the id of the [RecurringPayment]
+    pub async fn list_recurring_payment_occurrences(
+        &self,
+        recurring_payment_id: String,
+    ) -> SdkResult<Vec<RecurringPaymentOccurrence>> {
+        Ok(self
+            .persister
+            .list_recurring_payment_occurrences(&recurring_payment_id)?)
+    }
 
-        // We insert a pseudo-tx in case LWK fails to pick up the new mempool tx for a while
-        // This makes the tx known to the SDK (get_info, list_payments) instantly
-        let tx_data = PaymentTxData {
-            tx_id: tx_id.clone(),
-            timestamp: Some(utils::now()),
-            amount: receiver_amount_sat,
-            fees_sat,
-            payment_type: PaymentType::Send,
-            is_confirmed: false,
-            unblinding_data: None,
-            asset_id: asset_id.clone(),
-        };
+    /// Cancels a recurring payment, so no further occurrences are sent. An occurrence already
+    /// in progress is not interrupted. The past occurrences are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - the id of the [RecurringPayment] returned by [LiquidSdk::create_recurring_payment]
+    pub async fn cancel_recurring_payment(&self, id: String) -> SdkResult<()> {
+        ensure_sdk!(
+            self.persister.delete_recurring_payment(&id)?,
+            SdkError::generic(format!("Recurring payment {id} not found"))
+        );
+        info!("Cancelled recurring payment {id}");
+        Ok(())
+    }
 
-        let description = address_data.message;
+    /// Sends payments to multiple destinations, e.g. for payroll or payouts, emitting a single
+    /// [SdkEvent::BatchSendCompleted] event once all were processed.
+    ///
+    /// All the items are prepared first. If the prepared items together exceed the balance or
+    /// the `max_total_fee_sat`, nothing is sent. Otherwise, the L-BTC payments to Liquid addresses
+    /// are sent together in a single tx, with an output for each of them. The other items, e.g.
+    /// Lightning payments, are then each sent in order. The result of each item is returned, so a
+    /// failed item doesn't prevent the others from being sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - A [SendBatchRequest], containing:
+    ///     * `items` - the [BatchSendItem]s, each with a `destination` and optional `amount_sat`
+    ///     * `max_total_fee_sat` - the optional maximum total fees of the batch
+    pub async fn send_batch(
+        &self,
+        req: &SendBatchRequest,
+    ) -> Result<SendBatchResponse, PaymentError> {
+        self.ensure_is_started().await?;
+        ensure_sdk!(
+            !req.items.is_empty(),
+            PaymentError::generic("The batch contains no items")
+        );
 
-        self.persister.insert_or_update_payment(
-            tx_data.clone(),
-            Some(PaymentTxDetails {
-                tx_id: tx_id.clone(),
-                destination: destination.clone(),
-                description: description.clone(),
-                ..Default::default()
-            }),
-            false,
-        )?;
-        self.emit_payment_updated(Some(tx_id)).await?; // Emit Pending event
+        let mut prepared_items = Vec::with_capacity(req.items.len());
+        for item in &req.items {
+            let prepared = self
+                .prepare_batch_item(item)
+                .await
+                .inspect_err(|e| warn!("Failed to prepare batch item {}: {e}", item.destination));
+            prepared_items.push(prepared);
+        }
 
-        let asset_info = self
-            .persister
-            .get_asset_metadata(&asset_id)?
-            .map(|ref am| AssetInfo {
-                name: am.name.clone(),
-                ticker: am.ticker.clone(),
-                amount: am.amount_from_sat(receiver_amount_sat),
-                fees: None,
-            });
-        let payment_details = PaymentDetails::Liquid {
-            asset_id,
-            destination,
-            description: description.unwrap_or("Liquid transfer".to_string()),
-            asset_info,
-            lnurl_info: None,
-            bip353_address: None,
-        };
+        let (mut total_amount_sat, mut total_fees_sat) = (0, 0);
+        for prepared in prepared_items.iter().flatten() {
+            total_amount_sat += self.send_destination_amount_sat(prepared.destination());
+            total_fees_sat += prepared.fees_sat();
+        }
+        if let Some(max_fee_sat) = req.max_total_fee_sat {
+            ensure_sdk!(
+                total_fees_sat <= max_fee_sat,
+                PaymentError::FeeLimitExceeded {
+                    fees_sat: total_fees_sat,
+                    max_fee_sat,
+                }
+            );
+        }
+        let balance_sat = self.get_info().await?.wallet_info.balance_sat;
+        ensure_sdk!(
+            total_amount_sat + total_fees_sat <= balance_sat,
+            PaymentError::InsufficientFunds
+        );
+        info!(
+            "Sending batch of {} items: {total_amount_sat} sat with {total_fees_sat} sat fees",
+            req.items.len()
+        );
 
-        Ok(SendPaymentResponse {
-            payment: Payment::from_tx_data(tx_data, None, payment_details),
+        let lbtc_asset_id = self.config.lbtc_asset_id();
+        let mut item_results: Vec<Option<Result<Payment, String>>> = vec![None; req.items.len()];
+        let mut liquid_outputs = Vec::new();
+        let mut other_items = Vec::new();
+        for (index, prepared) in prepared_items.into_iter().enumerate() {
+            match prepared {
+                Ok(PreparedBatchItem::Send(PrepareSendResponse {
+                    destination: SendDestination::LiquidAddress { address_data, .. },
+                    fees_sat: Some(fees_sat),
+                    ..
+                })) if address_data.asset_id.as_ref() == Some(&lbtc_asset_id) => {
+                    liquid_outputs.push((index, address_data, fees_sat))
+                }
+                Ok(prepared) => other_items.push((index, prepared)),
+                Err(e) => item_results[index] = Some(Err(e.to_string())),
+            }
+        }
+
+        if !liquid_outputs.is_empty() {
+            let outputs: Vec<(LiquidAddressData, u64)> = liquid_outputs
+                .iter()
+                .map(|(_, address_data, fees_sat)| (address_data.clone(), *fees_sat))
+                .collect();
+            match self.pay_liquid_batch(&outputs).await {
+                Ok(payments) => {
+                    for ((index, ..), payment) in liquid_outputs.iter().zip(payments) {
+                        item_results[*index] = Some(Ok(payment));
+                    }
+                }
+                Err(e) => {
+                    warn!("Failed to send the batch Liquid tx: {e}");
+                    for (index, ..) in &liquid_outputs {
+                        item_results[*index] = Some(Err(e.to_string()));
+                    }
+                }
+            }
+        }
+        for (index, prepared) in other_items {
+            let res = self
+                .send_batch_item(prepared, None)
+                .await
+                .map_err(|e| e.to_string());
+            item_results[index] = Some(res);
+        }
+
+        let results: Vec<BatchSendItemResult> = req
+            .items
+            .iter()
+            .zip(item_results)
+            .map(|(item, res)| {
+                let res = res.unwrap_or_else(|| Err("The item was not sent".to_string()));
+                BatchSendItemResult {
+                    destination: item.destination.clone(),
+                    payment: res.as_ref().ok().cloned(),
+                    error: res.err(),
+                }
+            })
+            .collect();
+
+        self.notify_event_listeners(SdkEvent::BatchSendCompleted {
+            results: results.clone(),
         })
+        .await;
+        Ok(SendBatchResponse { results })
     }
 
-    /// Performs a Send Payment by doing a payjoin tx to a Liquid address
-    async fn pay_liquid_payjoin(
+    async fn prepare_batch_item(
         &self,
-        address_data: LiquidAddressData,
-        receiver_amount_sat: u64,
-    ) -> Result<SendPaymentResponse, PaymentError> {
-        let destination = address_data
-            .to_uri()
-            .unwrap_or(address_data.address.clone());
-        let Some(asset_id) = address_data.asset_id else {
-            return Err(PaymentError::asset_error(
-                "Asset must be set when paying to a Liquid address",
-            ));
-        };
+        item: &BatchSendItem,
+    ) -> Result<PreparedBatchItem, PaymentError> {
+        let amount = item
+            .amount_sat
+            .map(|receiver_amount_sat| PayAmount::Bitcoin {
+                receiver_amount_sat,
+            });
+        if let Ok(InputType::LnUrlPay {
+            data,
+            bip353_address,
+        }) = self.parse(&item.destination).await
+        {
+            let amount = amount.ok_or(PaymentError::AmountMissing {
+                err: "Amount must be set when paying to a Lightning address".to_string(),
+            })?;
+            let prepare_response = self
+                .prepare_lnurl_pay(PrepareLnUrlPayRequest {
+                    data,
+                    amount,
+                    bip353_address,
+                    comment: None,
+                    payer_data: None,
+                    validate_success_action_url: None,
+                })
+                .await
+                .map_err(|e| match e {
+                    LnUrlPayError::ServiceConnectivity { err } => {
+                        PaymentError::ServiceUnavailable { err }
+                    }
+                    e => PaymentError::Generic { err: e.to_string() },
+                })?;
+            return Ok(PreparedBatchItem::LnUrlPay(prepare_response));
+        }
 
-        let (tx, asset_fees) = self
-            .payjoin_service
-            .build_payjoin_tx(&address_data.address, &asset_id, receiver_amount_sat)
-            .await
-            .inspect_err(|e| error!("Error building payjoin tx: {e}"))?;
-        let tx_id = tx.txid().to_string();
-        let fees_sat = tx.all_fees().values().sum::<u64>();
+        let prepare_response = self
+            .prepare_send_payment(&PrepareSendRequest {
+                destination: item.destination.clone(),
+                amount,
+                fiat_amount: None,
+            })
+            .await?;
+        Ok(PreparedBatchItem::Send(prepare_response))
+    }
 
-        info!(
-            "Built payjoin Liquid tx with receiver_amount_sat = {receiver_amount_sat}, asset_fees = {asset_fees}, fees_sat = {fees_sat} and txid = {tx_id}"
-        );
+    /// Pays multiple Liquid addresses in L-BTC with a single tx, with an output for each of
+    /// them. Each address is given with the fees prepared for paying it on its own, which the
+    /// fees of the tx can't exceed in total.
+    ///
+    /// Returns a payment for each address, with the amount of its output and the fees of the tx.
+    async fn pay_liquid_batch(
+        &self,
+        outputs: &[(LiquidAddressData, u64)],
+    ) -> Result<Vec<Payment>, PaymentError> {
+        let _in_flight = self.in_flight.start();
+        let mut recipients = Vec::with_capacity(outputs.len());
+        for (address_data, _) in outputs {
+            ensure_sdk!(
+                address_data.network == self.config.network.into(),
+                PaymentError::InvalidNetwork {
+                    err: format!(
+                        "Cannot send payment from {} to {}",
+                        Into::<sdk_common::bitcoin::Network>::into(self.config.network),
+                        address_data.network
+                    )
+                }
+            );
+            let amount_sat = address_data.amount_sat.ok_or(PaymentError::AmountMissing {
+                err: "Amount must be set when paying to a Liquid address".to_string(),
+            })?;
+            recipients.push((address_data.address.clone(), amount_sat));
+        }
 
+        let tx = self
+            .onchain_wallet
+            .build_lbtc_batch_tx(Some(LIQUID_FEE_RATE_MSAT_PER_VBYTE), &recipients)
+            .await?;
+        let tx_fees_sat = tx.all_fees().values().sum::<u64>();
+        let prepared_fees_sat = outputs.iter().map(|(_, fees_sat)| fees_sat).sum::<u64>();
+        ensure_sdk!(
+            tx_fees_sat <= prepared_fees_sat,
+            PaymentError::InvalidOrExpiredFees
+        );
         let tx_id = self.liquid_chain_service.broadcast(&tx).await?.to_string();
+        let amount_sat = recipients.iter().map(|(_, amount_sat)| amount_sat).sum();
+        info!(
+            "Broadcast batch Liquid tx {tx_id} with {} outputs: {amount_sat} sat with {tx_fees_sat} sat fees",
+            recipients.len()
+        );
 
         // We insert a pseudo-tx in case LWK fails to pick up the new mempool tx for a while
-        // This makes the tx known to the SDK (get_info, list_payments) instantly
+        let lbtc_asset_id = self.config.lbtc_asset_id();
         let tx_data = PaymentTxData {
             tx_id: tx_id.clone(),
             timestamp: Some(utils::now()),
-            amount: receiver_amount_sat + asset_fees,
-            fees_sat,
+            amount: amount_sat,
+            fees_sat: tx_fees_sat,
             payment_type: PaymentType::Send,
             is_confirmed: false,
             unblinding_data: None,
-            asset_id: asset_id.clone(),
+            asset_id: lbtc_asset_id.clone(),
         };
-
-        let description = address_data.message;
-
-        self.persister.insert_or_update_payment(
-            tx_data.clone(),
-            Some(PaymentTxDetails {
-                tx_id: tx_id.clone(),
-                destination: destination.clone(),
-                description: description.clone(),
-                asset_fees: Some(asset_fees),
-                ..Default::default()
-            }),
-            false,
-        )?;
+        self.persister
+            .insert_or_update_payment(tx_data.clone(), None, false)?;
         self.emit_payment_updated(Some(tx_id)).await?; // Emit Pending event
 
-        let asset_info = self
-            .persister
-            .get_asset_metadata(&asset_id)?
-            .map(|ref am| AssetInfo {
-                name: am.name.clone(),
-                ticker: am.ticker.clone(),
-                amount: am.amount_from_sat(receiver_amount_sat),
-                fees: Some(am.amount_from_sat(asset_fees)),
-            });
-        let payment_details = PaymentDetails::Liquid {
-            asset_id,
-            destination,
-            description: description.unwrap_or("Liquid transfer".to_string()),
-            asset_info,
-            lnurl_info: None,
-            bip353_address: None,
-        };
+        Ok(outputs
+            .iter()
+            .zip(recipients)
+            .map(|((address_data, _), (_, amount_sat))| {
+                let payment_details = PaymentDetails::Liquid {
+                    asset_id: lbtc_asset_id.clone(),
+                    destination: address_data
+                        .to_uri()
+                        .unwrap_or(address_data.address.clone()),
+                    description: address_data
+                        .message
+                        .clone()
+                        .unwrap_or("Liquid transfer".to_string()),
+                    asset_info: None,
+                    lnurl_info: None,
+                    bip353_address: None,
+                    conversion: None,
+                };
+                let tx_data = PaymentTxData {
+                    amount: amount_sat,
+                    ..tx_data.clone()
+                };
+                Payment::from_tx_data(tx_data, None, payment_details)
+            })
+            .collect())
+    }
 
-        Ok(SendPaymentResponse {
-            payment: Payment::from_tx_data(tx_data, None, payment_details),
-        })
+    /// The L-BTC amount received by a prepared payment destination
+    fn send_destination_amount_sat(&self, destination: &SendDestination) -> u64 {
+        match destination {
+            SendDestination::LiquidAddress { address_data, .. } => match &address_data.asset_id {
+                Some(asset_id) if *asset_id != self.config.lbtc_asset_id() => 0,
+                _ => address_data.amount_sat.unwrap_or_default(),
+            },
+            SendDestination::Bolt11 { invoice, .. } => {
+                invoice.amount_msat.unwrap_or_default() / 1_000
+            }
+            SendDestination::Bolt12 {
+                receiver_amount_sat,
+                ..
+            } => *receiver_amount_sat,
+        }
     }
 
-    /// Performs a Send Payment by doing a swap (create it, fund it, track it, etc).
+    /// Sends a prepared batch item. If set, the `idempotency_key` is linked to the payment.
+    async fn send_batch_item(
+        &self,
+        prepared: PreparedBatchItem,
+        idempotency_key: Option<&str>,
+    ) -> Result<Payment, PaymentError> {
+        match prepared {
+            PreparedBatchItem::Send(prepare_response) => Ok(self
+                .send_payment(&SendPaymentRequest {
+                    prepare_response,
+                    use_asset_fees: None,
+                    max_fee_sat: None,
+                    spend_limit_override: None,
+                    idempotency_key: idempotency_key.map(str::to_string),
+                })
+                .await?
+                .payment),
+            PreparedBatchItem::LnUrlPay(prepare_response) => {
+                let payment = self
+                    .send_lnurl_payment(&prepare_response, idempotency_key)
+                    .await?;
+                // The payment is sent, so failing to store its LNURL info doesn't fail it
+                match self
+                    .complete_lnurl_pay(prepare_response, payment.clone())
+                    .await
+                {
+                    Ok(data) => Ok(data.payment),
+                    Err(e) => {
+                        warn!("Failed to complete LNURL payment: {e}");
+                        Ok(payment)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Creates a token allowing payments to exceed the [Config::spend_limits] until
+    /// `expires_at`, e.g. on an operator or parent device. The token is passed as the
+    /// [SendPaymentRequest::spend_limit_override].
     ///
-    /// If `bolt12_offer` is set, `invoice` refers to a Bolt12 invoice, otherwise it's a Bolt11 one.
-    async fn send_payment_via_swap(
+    /// # Arguments
+    ///
+    /// * `secret` - the [SpendLimits::override_secret]
+    /// * `expires_at` - the expiry of the token, as a UNIX timestamp in seconds
+    pub fn create_spend_limit_override(secret: &str, expires_at: u32) -> String {
+        spend_limits::create_override_token(secret, expires_at)
+    }
+
+    /// Checks that spending `amount_sat`, including fees, is within the [Config::spend_limits],
+    /// unless they're overridden
+    async fn ensure_spend_limits(
         &self,
-        invoice: &str,
-        bolt12_offer: Option<String>,
-        payment_hash: &str,
-        description: Option<String>,
-        receiver_amount_sat: u64,
-        fees_sat: u64,
-    ) -> Result<SendPaymentResponse, PaymentError> {
-        let lbtc_pair = self.validate_submarine_pairs(receiver_amount_sat).await?;
-        let boltz_fees_total = lbtc_pair.fees.total(receiver_amount_sat);
-        let user_lockup_amount_sat = receiver_amount_sat + boltz_fees_total;
-        let lockup_tx_fees_sat = self
-            .estimate_lockup_tx_or_drain_tx_fee(user_lockup_amount_sat)
-            .await?;
-        ensure_sdk!(
-            fees_sat == boltz_fees_total + lockup_tx_fees_sat,
-            PaymentError::InvalidOrExpiredFees
-        );
+        amount_sat: u64,
+        spend_limit_override: Option<&str>,
+    ) -> Result<(), PaymentError> {
+        let Some(spend_limits) = &self.config.spend_limits else {
+            return Ok(());
+        };
+        if let (Some(secret), Some(token)) = (&spend_limits.override_secret, spend_limit_override) {
+            ensure_sdk!(
+                spend_limits::verify_override_token(secret, token, utils::now()),
+                PaymentError::generic("The spend limit override is invalid or expired")
+            );
+            info!("Spend limits overridden");
+            return Ok(());
+        }
 
-        let swap = match self.persister.fetch_send_swap_by_invoice(invoice)? {
-            Some(swap) => match swap.state {
-                Created => swap,
-                TimedOut => {
-                    self.send_swap_handler.update_swap_info(
-                        &swap.id,
-                        PaymentState::Created,
-                        None,
-                        None,
-                        None,
-                    )?;
-                    swap
-                }
-                Pending => return Err(PaymentError::PaymentInProgress),
-                Complete => return Err(PaymentError::AlreadyPaid),
-                RefundPending | Refundable | Failed => {
-                    return Err(PaymentError::invalid_invoice(
-                        "Payment has already failed. Please try with another invoice",
-                    ))
+        if let Some(limit) = &spend_limits.per_payment {
+            ensure_spend_limit(amount_sat, self.spend_limit_sat(limit).await?)?;
+        }
+        if let Some(limit) = &spend_limits.per_day {
+            let since = utils::now().saturating_sub(24 * 60 * 60);
+            let spent_sat = self.persister.get_spent_amount_sat_since(since)?;
+            let limit_sat = self.spend_limit_sat(limit).await?;
+            ensure_spend_limit(amount_sat, limit_sat.saturating_sub(spent_sat))?;
+        }
+        Ok(())
+    }
+
+    async fn spend_limit_sat(&self, limit: &SpendLimit) -> Result<u64, PaymentError> {
+        match limit {
+            SpendLimit::Bitcoin { amount_sat } => Ok(*amount_sat),
+            SpendLimit::Fiat { amount } => Ok(self.convert_fiat_amount(amount).await?.amount_sat),
+        }
+    }
+
+    /// Attempts to send the queued payments that are due
+    async fn process_payment_queue(&self) -> Result<()> {
+        for mut queued_payment in self.persister.list_due_queued_payments(utils::now())? {
+            let id = queued_payment.id.clone();
+            let QueuePaymentRequest {
+                prepare_request,
+                use_asset_fees,
+                max_fee_sat,
+            } = &queued_payment.request;
+            match self
+                .prepare_and_send_payment(prepare_request, *use_asset_fees, *max_fee_sat, &id)
+                .await
+            {
+                Ok(_) | Err(PaymentError::PaymentInProgress) => {
+                    info!("Sent queued payment {id}");
+                    self.persister.delete_queued_payment(&id)?;
                 }
-                WaitingFeeAcceptance => {
-                    return Err(PaymentError::Generic {
-                        err: "Send swap payment cannot be in state WaitingFeeAcceptance"
-                            .to_string(),
-                    })
+                Err(e) => {
+                    queued_payment.attempts += 1;
+                    queued_payment.last_error = Some(e.to_string());
+                    match background_payment_retry_at(&e, queued_payment.attempts) {
+                        Some(retry_at) => {
+                            warn!("Queued payment {id} failed, retrying later: {e}");
+                            queued_payment.next_attempt_at = retry_at;
+                        }
+                        None => {
+                            error!("Queued payment {id} failed: {e}");
+                            queued_payment.state = QueuedPaymentState::Failed;
+                        }
+                    }
+                    self.persister.update_queued_payment(&queued_payment)?;
                 }
-            },
-            None => {
-                let keypair = utils::generate_keypair();
-                let refund_public_key = boltz_client::PublicKey {
-                    compressed: true,
-                    inner: keypair.public_key(),
-                };
-                let webhook = self.persister.get_webhook_url()?.map(|url| Webhook {
-                    url,
-                    hash_swap_id: Some(true),
-                    status: Some(vec![
-                        SubSwapStates::InvoiceFailedToPay,
-                        SubSwapStates::SwapExpired,
-                        SubSwapStates::TransactionClaimPending,
-                        SubSwapStates::TransactionLockupFailed,
-                    ]),
-                });
-                let create_response = self
-                    .swapper
-                    .create_send_swap(CreateSubmarineRequest {
-                        from: "L-BTC".to_string(),
-                        to: "BTC".to_string(),
-                        invoice: invoice.to_string(),
-                        refund_public_key,
-                        pair_hash: Some(lbtc_pair.hash.clone()),
-                        referral_id: None,
-                        webhook,
-                    })
-                    .await?;
+            }
+        }
+        Ok(())
+    }
 
-                let swap_id = &create_response.id;
-                let create_response_json =
-                    SendSwap::from_boltz_struct_to_json(&create_response, swap_id)?;
-                let destination_pubkey =
-                    utils::get_invoice_destination_pubkey(invoice, bolt12_offer.is_some())?;
+    /// Attempts to send the scheduled payments that are due
+    async fn process_scheduled_payments(&self) -> Result<()> {
+        for mut scheduled_payment in self.persister.list_due_scheduled_payments(utils::now())? {
+            let id = scheduled_payment.id.clone();
+            let SchedulePaymentRequest {
+                prepare_request,
+                use_asset_fees,
+                max_fee_sat,
+                ..
+            } = &scheduled_payment.request;
 
-                let payer_amount_sat = fees_sat + receiver_amount_sat;
-                let swap = SendSwap {
-                    id: swap_id.to_string(),
-                    invoice: invoice.to_string(),
-                    bolt12_offer,
-                    payment_hash: Some(payment_hash.to_string()),
-                    destination_pubkey: Some(destination_pubkey),
-                    timeout_block_height: create_response.timeout_block_height,
-                    description,
-                    preimage: None,
-                    payer_amount_sat,
-                    receiver_amount_sat,
-                    pair_fees_json: serde_json::to_string(&lbtc_pair).map_err(|e| {
-                        PaymentError::generic(&format!("Failed to serialize SubmarinePair: {e:?}"))
-                    })?,
-                    create_response_json,
-                    lockup_tx_id: None,
-                    refund_address: None,
-                    refund_tx_id: None,
-                    created_at: utils::now(),
-                    state: PaymentState::Created,
-                    refund_private_key: keypair.display_secret().to_string(),
-                    metadata: Default::default(),
-                };
-                self.persister.insert_or_update_send_swap(&swap)?;
-                swap
+            // The attempt is persisted before sending, so a cancelled payment is not sent. The
+            // payment id is used as idempotency key, so a retried attempt can't pay twice.
+            if !self.persister.start_scheduled_payment_attempt(&id)? {
+                info!("Scheduled payment {id} was cancelled, skipping");
+                continue;
             }
-        };
-        self.status_stream.track_swap_id(&swap.id)?;
+            scheduled_payment.attempts += 1;
+            match self
+                .prepare_and_send_payment(prepare_request, *use_asset_fees, *max_fee_sat, &id)
+                .await
+            {
+                Ok(response) => {
+                    info!("Executed scheduled payment {id}");
+                    scheduled_payment.state = ScheduledPaymentState::Executed;
+                    scheduled_payment.tx_id = response.payment.tx_id.clone();
+                    scheduled_payment.last_error = None;
+                    self.persister
+                        .update_scheduled_payment(&scheduled_payment)?;
+                    self.notify_event_listeners(SdkEvent::ScheduledPaymentExecuted {
+                        id,
+                        details: response.payment,
+                    })
+                    .await;
+                }
+                Err(PaymentError::PaymentInProgress) => {
+                    // The payment is still being sent, so it's checked again later, when the
+                    // idempotency key returns the sent payment
+                    info!("Scheduled payment {id} is already in progress, checking again later");
+                    scheduled_payment.next_attempt_at =
+                        utils::now() + utils::retry_backoff_secs(scheduled_payment.attempts);
+                    self.persister
+                        .update_scheduled_payment(&scheduled_payment)?;
+                }
+                Err(e) => {
+                    scheduled_payment.last_error = Some(e.to_string());
+                    if let Some(retry_at) =
+                        background_payment_retry_at(&e, scheduled_payment.attempts)
+                    {
+                        warn!("Scheduled payment {id} failed, retrying later: {e}");
+                        scheduled_payment.next_attempt_at = retry_at;
+                        self.persister
+                            .update_scheduled_payment(&scheduled_payment)?;
+                    } else {
+                        error!("Scheduled payment {id} failed: {e}");
+                        scheduled_payment.state = ScheduledPaymentState::Failed;
+                        self.persister
+                            .update_scheduled_payment(&scheduled_payment)?;
+                        self.notify_event_listeners(SdkEvent::ScheduledPaymentFailed {
+                            id,
+                            err: e.to_string(),
+                        })
+                        .await;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
 
-        let create_response = swap.get_boltz_create_response()?;
-        self.send_swap_handler
-            .try_lockup(&swap, &create_response)
-            .await?;
+    /// Sends the due occurrences of the recurring payments.
+    ///
+    /// An occurrence failing with a transient error is retried until the next occurrence is due.
+    /// Each occurrence is sent with its own idempotency key, so a retried or interrupted
+    /// occurrence can't pay twice. The occurrences whose window passed without being sent, e.g.
+    /// while the SDK was not started, are skipped and recorded as missed.
+    async fn process_recurring_payments(&self) -> Result<()> {
+        let now = utils::now();
+        for recurring_payment in self.persister.list_due_recurring_payments(now)? {
+            let id = recurring_payment.id.clone();
+            let interval_sec = recurring_payment.interval_sec;
+            let missed_occurrences = (now - recurring_payment.next_occurrence_at) / interval_sec;
+            let occurrence_at = recurring_payment
+                .next_occurrence_at
+                .saturating_add(missed_occurrences.saturating_mul(interval_sec));
+
+            if missed_occurrences > 0 {
+                self.skip_missed_recurring_payment_occurrences(
+                    &recurring_payment,
+                    missed_occurrences,
+                    now,
+                )?;
+            }
+            // Also checks the recurring payment was not cancelled in the meantime
+            if !self
+                .persister
+                .set_recurring_payment_next_occurrence(&id, occurrence_at)?
+            {
+                info!("Recurring payment {id} was cancelled, skipping");
+                continue;
+            }
 
-        self.wait_for_payment_with_timeout(Swap::Send(swap), create_response.accept_zero_conf)
-            .await
-            .map(|payment| SendPaymentResponse { payment })
+            let idempotency_key = recurring_payment_idempotency_key(&id, occurrence_at);
+            let res = self
+                .send_recurring_payment(&recurring_payment, &idempotency_key)
+                .await;
+            let (next_occurrence_at, e) = match res {
+                Ok(payment) => {
+                    info!("Sent occurrence {occurrence_at} of recurring payment {id}");
+                    self.persister.insert_recurring_payment_occurrence(
+                        &RecurringPaymentOccurrence {
+                            recurring_payment_id: id.clone(),
+                            occurrence_at,
+                            executed_at: utils::now(),
+                            tx_id: payment.tx_id.clone(),
+                            error: None,
+                        },
+                    )?;
+                    (
+                        occurrence_at.saturating_add(interval_sec),
+                        Some(SdkEvent::RecurringPaymentExecuted {
+                            recurring_payment_id: id.clone(),
+                            details: payment,
+                        }),
+                    )
+                }
+                Err(PaymentError::PaymentInProgress) => {
+                    // Checked again on the next run, when the idempotency key returns the payment
+                    info!("Occurrence {occurrence_at} of recurring payment {id} is in progress");
+                    (occurrence_at, None)
+                }
+                Err(err) => {
+                    self.persister.insert_recurring_payment_occurrence(
+                        &RecurringPaymentOccurrence {
+                            recurring_payment_id: id.clone(),
+                            occurrence_at,
+                            executed_at: utils::now(),
+                            tx_id: None,
+                            error: Some(err.to_string()),
+                        },
+                    )?;
+                    if err.is_transient() {
+                        warn!(
+                            "Occurrence {occurrence_at} of recurring payment {id} failed, \
+                            retrying later: {err}"
+                        );
+                        (occurrence_at, None)
+                    } else {
+                        error!(
+                            "Occurrence {occurrence_at} of recurring payment {id} failed: {err}"
+                        );
+                        (
+                            occurrence_at.saturating_add(interval_sec),
+                            Some(SdkEvent::RecurringPaymentFailed {
+                                recurring_payment_id: id.clone(),
+                                err: err.to_string(),
+                            }),
+                        )
+                    }
+                }
+            };
+            if next_occurrence_at != occurrence_at {
+                self.persister
+                    .set_recurring_payment_next_occurrence(&id, next_occurrence_at)?;
+            }
+            if let Some(e) = e {
+                self.notify_event_listeners(e).await;
+            }
+        }
+        Ok(())
     }
 
-    /// Fetch the current payment limits for [LiquidSdk::send_payment] and [LiquidSdk::receive_payment].
-    pub async fn fetch_lightning_limits(
+    /// Records the `missed_occurrences` of a recurring payment, starting at its next occurrence,
+    /// as skipped. The first one may have been attempted before its window passed, in which
+    /// case its payment or last error is kept.
+    fn skip_missed_recurring_payment_occurrences(
         &self,
-    ) -> Result<LightningPaymentLimitsResponse, PaymentError> {
-        self.ensure_is_started().await?;
-
-        let submarine_pair = self
-            .swapper
-            .get_submarine_pairs()
-            .await?
-            .ok_or(PaymentError::PairsNotFound)?;
-        let send_limits = submarine_pair.limits;
-
-        let reverse_pair = self
-            .swapper
-            .get_reverse_swap_pairs()
-            .await?
-            .ok_or(PaymentError::PairsNotFound)?;
-        let receive_limits = reverse_pair.limits;
-
-        Ok(LightningPaymentLimitsResponse {
-            send: Limits {
-                min_sat: send_limits.minimal_batched.unwrap_or(send_limits.minimal),
-                max_sat: send_limits.maximal,
-                max_zero_conf_sat: send_limits.maximal_zero_conf,
-            },
-            receive: Limits {
-                min_sat: receive_limits.minimal,
-                max_sat: receive_limits.maximal,
-                max_zero_conf_sat: self.config.zero_conf_max_amount_sat(),
-            },
-        })
+        recurring_payment: &RecurringPayment,
+        missed_occurrences: u32,
+        now: u32,
+    ) -> Result<()> {
+        let id = &recurring_payment.id;
+        let first_occurrence_at = recurring_payment.next_occurrence_at;
+        let idempotency_key = recurring_payment_idempotency_key(id, first_occurrence_at);
+        if let Some(payment) = self.fetch_idempotent_payment(&idempotency_key)? {
+            self.persister
+                .insert_recurring_payment_occurrence(&RecurringPaymentOccurrence {
+                    recurring_payment_id: id.clone(),
+                    occurrence_at: first_occurrence_at,
+                    executed_at: now,
+                    tx_id: payment.tx_id,
+                    error: None,
+                })?;
+        }
+        let occurrences: Vec<u32> = (0..missed_occurrences)
+            .map(|i| {
+                first_occurrence_at.saturating_add(i.saturating_mul(recurring_payment.interval_sec))
+            })
+            .collect();
+        warn!("Skipping {missed_occurrences} missed occurrences of recurring payment {id}");
+        self.persister
+            .insert_missed_recurring_payment_occurrences(id, &occurrences, now)
     }
 
-    /// Fetch the current payment limits for [LiquidSdk::pay_onchain] and [LiquidSdk::receive_onchain].
-    pub async fn fetch_onchain_limits(&self) -> Result<OnchainPaymentLimitsResponse, PaymentError> {
-        self.ensure_is_started().await?;
-
-        let (pair_outgoing, pair_incoming) = self.swapper.get_chain_pairs().await?;
-        let send_limits = pair_outgoing
-            .ok_or(PaymentError::PairsNotFound)
-            .map(|pair| pair.limits)?;
-        let receive_limits = pair_incoming
-            .ok_or(PaymentError::PairsNotFound)
-            .map(|pair| pair.limits)?;
+    /// Sends an occurrence of a recurring payment. Once sent, sending it again with the same
+    /// `idempotency_key` returns the sent payment.
+    async fn send_recurring_payment(
+        &self,
+        recurring_payment: &RecurringPayment,
+        idempotency_key: &str,
+    ) -> Result<Payment, PaymentError> {
+        // An already sent occurrence is returned as is, since it may not be preparable anymore
+        if let Some(payment) = self.fetch_idempotent_payment(idempotency_key)? {
+            return Ok(payment);
+        }
+        let prepared = self
+            .prepare_batch_item(&BatchSendItem {
+                destination: recurring_payment.destination.clone(),
+                amount_sat: Some(recurring_payment.amount_sat),
+            })
+            .await?;
+        ensure_fee_limit(prepared.fees_sat(), recurring_payment.max_fee_sat)?;
+        self.send_batch_item(prepared, Some(idempotency_key)).await
+    }
 
-        Ok(OnchainPaymentLimitsResponse {
-            send: Limits {
-                min_sat: send_limits.minimal,
-                max_sat: send_limits.maximal,
-                max_zero_conf_sat: send_limits.maximal_zero_conf,
-            },
-            receive: Limits {
-                min_sat: receive_limits.minimal,
-                max_sat: receive_limits.maximal,
-                max_zero_conf_sat: receive_limits.maximal_zero_conf,
-            },
+    /// Prepares and sends a payment in the background, i.e. for the payment queue and the
+    /// scheduled payments. Once the payment is sent, sending it again with the same
+    /// `idempotency_key` returns the sent payment.
+    async fn prepare_and_send_payment(
+        &self,
+        prepare_request: &PrepareSendRequest,
+        use_asset_fees: Option<bool>,
+        max_fee_sat: Option<u64>,
+        idempotency_key: &str,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        // An already sent payment is returned as is, since it may not be preparable anymore
+        if let Some(payment) = self.fetch_idempotent_payment(idempotency_key)? {
+            return Ok(SendPaymentResponse { payment });
+        }
+        let prepare_response = self.prepare_send_payment(prepare_request).await?;
+        self.send_payment(&SendPaymentRequest {
+            prepare_response,
+            use_asset_fees,
+            max_fee_sat,
+            spend_limit_override: None,
+            idempotency_key: Some(idempotency_key.to_string()),
         })
+        .await
     }
 
-    /// Prepares to pay to a Bitcoin address via a chain swap.
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [PreparePayOnchainRequest] containing:
-    ///     * `amount` - which can be of two types: [PayAmount::Drain], which uses all funds,
-    ///       and [PayAmount::Bitcoin], which sets the amount the receiver should receive
-    ///     * `fee_rate_sat_per_vbyte` - the optional fee rate of the Bitcoin claim transaction. Defaults to the swapper estimated claim fee
-    pub async fn prepare_pay_onchain(
+    fn insert_bip353_payment_details(
         &self,
-        req: &PreparePayOnchainRequest,
-    ) -> Result<PreparePayOnchainResponse, PaymentError> {
-        self.ensure_is_started().await?;
+        bip353_address: &Option<String>,
+        response: &mut SendPaymentResponse,
+    ) -> Result<()> {
+        if bip353_address.is_some() {
+            if let (Some(tx_id), Some(destination)) =
+                (&response.payment.tx_id, &response.payment.destination)
+            {
+                self.persister
+                    .insert_or_update_payment_details(PaymentTxDetails {
+                        tx_id: tx_id.clone(),
+                        destination: destination.clone(),
+                        description: None,
+                        lnurl_info: None,
+                        bip353_address: bip353_address.clone(),
+                        asset_fees: None,
+                    })?;
+                // Get the payment with the bip353_address details
+                if let Some(payment) = self.persister.get_payment(tx_id)? {
+                    response.payment = payment;
+                }
+            }
+        }
+        Ok(())
+    }
 
-        let get_info_res = self.get_info().await?;
-        let pair = self.get_chain_pair(Direction::Outgoing).await?;
-        let claim_fees_sat = match req.fee_rate_sat_per_vbyte {
-            Some(sat_per_vbyte) => ESTIMATED_BTC_CLAIM_TX_VSIZE * sat_per_vbyte as u64,
-            None => pair.clone().fees.claim_estimate(),
-        };
-        let server_fees_sat = pair.fees.server();
+    async fn pay_bolt11_invoice(
+        &self,
+        invoice: &str,
+        fees_sat: u64,
+        max_fee_sat: Option<u64>,
+        idempotency_key: Option<&str>,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        self.ensure_send_is_not_self_transfer(invoice)?;
+        let bolt11_invoice = self.validate_bolt11_invoice(invoice)?;
 
-        info!("Preparing for onchain payment of kind: {:?}", req.amount);
-        let (payer_amount_sat, receiver_amount_sat, total_fees_sat) = match req.amount {
-            PayAmount::Bitcoin {
-                receiver_amount_sat: amount_sat,
-            } => {
-                let receiver_amount_sat = amount_sat;
-
-                let user_lockup_amount_sat_without_service_fee =
-                    receiver_amount_sat + claim_fees_sat + server_fees_sat;
-
-                // The resulting invoice amount contains the service fee, which is rounded up with ceil()
-                // Therefore, when calculating the user_lockup amount, we must also round it up with ceil()
-                let user_lockup_amount_sat = (user_lockup_amount_sat_without_service_fee as f64
-                    * 100.0
-                    / (100.0 - pair.fees.percentage))
-                    .ceil() as u64;
-                self.validate_user_lockup_amount_for_chain_pair(&pair, user_lockup_amount_sat)?;
-
-                let lockup_fees_sat = self.estimate_lockup_tx_fee(user_lockup_amount_sat).await?;
+        let amount_sat = get_invoice_amount!(invoice);
+        let payer_amount_sat = amount_sat + fees_sat;
+        ensure_sdk!(
+            payer_amount_sat <= self.get_info().await?.wallet_info.balance_sat,
+            PaymentError::InsufficientFunds
+        );
 
-                let boltz_fees_sat =
-                    user_lockup_amount_sat - user_lockup_amount_sat_without_service_fee;
-                let total_fees_sat =
-                    boltz_fees_sat + lockup_fees_sat + claim_fees_sat + server_fees_sat;
-                let payer_amount_sat = receiver_amount_sat + total_fees_sat;
+        let description = match bolt11_invoice.description() {
+            Bolt11InvoiceDescription::Direct(msg) => Some(msg.to_string()),
+            Bolt11InvoiceDescription::Hash(_) => None,
+        };
 
-                (payer_amount_sat, receiver_amount_sat, total_fees_sat)
+        match self.swapper.check_for_mrh(invoice).await? {
+            // If we find a valid MRH, extract the BIP21 address and pay to it via onchain tx
+            Some((address, _)) => {
+                info!("Found MRH for L-BTC address {address}, invoice amount_sat {amount_sat}");
+                self.pay_liquid(
+                    LiquidAddressData {
+                        address,
+                        network: self.config.network.into(),
+                        asset_id: None,
+                        amount: None,
+                        amount_sat: None,
+                        label: None,
+                        message: None,
+                    },
+                    amount_sat,
+                    fees_sat,
+                    max_fee_sat,
+                    false,
+                    None,
+                    idempotency_key,
+                )
+                .await
             }
-            PayAmount::Drain => {
-                ensure_sdk!(
-                    get_info_res.wallet_info.pending_receive_sat == 0
-                        && get_info_res.wallet_info.pending_send_sat == 0,
-                    PaymentError::Generic {
-                        err: "Cannot drain while there are pending payments".to_string(),
-                    }
-                );
-                let payer_amount_sat = get_info_res.wallet_info.balance_sat;
-                let lockup_fees_sat = self.estimate_drain_tx_fee(None, None).await?;
-
-                let user_lockup_amount_sat = payer_amount_sat - lockup_fees_sat;
-                self.validate_user_lockup_amount_for_chain_pair(&pair, user_lockup_amount_sat)?;
 
-                let boltz_fees_sat = pair.fees.boltz(user_lockup_amount_sat);
-                let total_fees_sat =
-                    boltz_fees_sat + lockup_fees_sat + claim_fees_sat + server_fees_sat;
-                let receiver_amount_sat = payer_amount_sat - total_fees_sat;
-
-                (payer_amount_sat, receiver_amount_sat, total_fees_sat)
-            }
-            PayAmount::Asset { .. } => {
-                return Err(PaymentError::asset_error(
-                    "Cannot send an asset to a Bitcoin address",
-                ))
+            // If no MRH found, perform usual swap
+            None => {
+                self.send_payment_via_swap(
+                    invoice,
+                    None,
+                    &bolt11_invoice.payment_hash().to_string(),
+                    description,
+                    amount_sat,
+                    fees_sat,
+                    max_fee_sat,
+                    idempotency_key,
+                )
+                .await
             }
-        };
+        }
+    }
 
-        let res = PreparePayOnchainResponse {
-            receiver_amount_sat,
-            claim_fees_sat,
-            total_fees_sat,
-        };
+    async fn pay_bolt12_invoice(
+        &self,
+        offer: &LNOffer,
+        user_specified_receiver_amount_sat: u64,
+        invoice_str: &str,
+        fees_sat: u64,
+        max_fee_sat: Option<u64>,
+        idempotency_key: Option<&str>,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        let invoice =
+            self.validate_bolt12_invoice(offer, user_specified_receiver_amount_sat, invoice_str)?;
 
+        let receiver_amount_sat = invoice.amount_msats() / 1_000;
+        let payer_amount_sat = receiver_amount_sat + fees_sat;
         ensure_sdk!(
-            payer_amount_sat <= get_info_res.wallet_info.balance_sat,
+            payer_amount_sat <= self.get_info().await?.wallet_info.balance_sat,
             PaymentError::InsufficientFunds
         );
 
-        info!("Prepared onchain payment: {res:?}");
-        Ok(res)
+        self.send_payment_via_swap(
+            invoice_str,
+            Some(offer.offer.clone()),
+            &invoice.payment_hash().to_string(),
+            invoice.description().map(|desc| desc.to_string()),
+            receiver_amount_sat,
+            fees_sat,
+            max_fee_sat,
+            idempotency_key,
+        )
+        .await
     }
 
-    /// Pays to a Bitcoin address via a chain swap.
-    ///
-    /// Depending on [Config]'s `payment_timeout_sec`, this function will return:
-    /// * [PaymentState::Pending] payment - if the payment could be initiated but didn't yet
-    ///   complete in this time
-    /// * [PaymentState::Complete] payment - if the payment was successfully completed in this time
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [PayOnchainRequest] containing:
-    ///     * `address` - the Bitcoin address to pay to
-    ///     * `prepare_response` - the [PreparePayOnchainResponse] from calling [LiquidSdk::prepare_pay_onchain]
-    ///
-    /// # Errors
-    ///
-    /// * [PaymentError::PaymentTimeout] - if the payment could not be initiated in this time
-    pub async fn pay_onchain(
+    /// Links the idempotency key of a send, if any, to its payment id
+    fn link_idempotency_key(
         &self,
-        req: &PayOnchainRequest,
-    ) -> Result<SendPaymentResponse, PaymentError> {
-        self.ensure_is_started().await?;
-        info!("Paying onchain, request = {req:?}");
+        idempotency_key: Option<&str>,
+        payment_id: &str,
+    ) -> Result<(), PaymentError> {
+        if let Some(idempotency_key) = idempotency_key {
+            self.persister
+                .set_idempotency_key_payment_id(idempotency_key, payment_id)?;
+        }
+        Ok(())
+    }
 
-        let claim_address = self.validate_bitcoin_address(&req.address).await?;
-        let balance_sat = self.get_info().await?.wallet_info.balance_sat;
-        let receiver_amount_sat = req.prepare_response.receiver_amount_sat;
-        let pair = self.get_chain_pair(Direction::Outgoing).await?;
-        let claim_fees_sat = req.prepare_response.claim_fees_sat;
-        let server_fees_sat = pair.fees.server();
-        let server_lockup_amount_sat = receiver_amount_sat + claim_fees_sat;
+    /// Performs a Send Payment by doing an onchain tx to a Liquid address. The fees of the built
+    /// tx must not exceed the prepared `fees_sat`, nor the `max_fee_sat` if set.
+    async fn pay_liquid(
+        &self,
+        address_data: LiquidAddressData,
+        receiver_amount_sat: u64,
+        fees_sat: u64,
+        max_fee_sat: Option<u64>,
+        skip_already_paid_check: bool,
+        payjoin_url: Option<&str>,
+        idempotency_key: Option<&str>,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        let _in_flight = self.in_flight.start();
+        let destination = address_data
+            .to_uri()
+            .unwrap_or(address_data.address.clone());
+        let asset_id = address_data.asset_id.unwrap_or(self.config.lbtc_asset_id());
+        let payments = self.persister.get_payments(&ListPaymentsRequest {
+            details: Some(ListPaymentDetails::Liquid {
+                asset_id: Some(asset_id.clone()),
+                destination: Some(destination.clone()),
+            }),
+            ..Default::default()
+        })?;
+        ensure_sdk!(
+            skip_already_paid_check || payments.is_empty(),
+            PaymentError::AlreadyPaid
+        );
 
-        let user_lockup_amount_sat_without_service_fee =
-            receiver_amount_sat + claim_fees_sat + server_fees_sat;
+        let mut payjoin_tx_id = None;
+        if let Some(payjoin_url) = payjoin_url.filter(|_| asset_id == self.config.lbtc_asset_id()) {
+            match self
+                .build_liquid_payjoin_tx(
+                    payjoin_url,
+                    &address_data.address,
+                    receiver_amount_sat,
+                    fees_sat,
+                )
+                .await
+            {
+                Ok(tx) => {
+                    ensure_fee_limit(tx.all_fees().values().sum(), max_fee_sat)?;
+                    self.link_idempotency_key(idempotency_key, &tx.txid().to_string())?;
+                    payjoin_tx_id = self.broadcast_liquid_payjoin_tx(&tx).await?;
+                }
+                Err(e) => {
+                    warn!("Payjoin with {payjoin_url} failed, falling back to a regular tx: {e}")
+                }
+            }
+        }
 
-        // The resulting invoice amount contains the service fee, which is rounded up with ceil()
-        // Therefore, when calculating the user_lockup amount, we must also round it up with ceil()
-        let user_lockup_amount_sat = (user_lockup_amount_sat_without_service_fee as f64 * 100.0
-            / (100.0 - pair.fees.percentage))
-            .ceil() as u64;
-        let boltz_fee_sat = user_lockup_amount_sat - user_lockup_amount_sat_without_service_fee;
-        self.validate_user_lockup_amount_for_chain_pair(&pair, user_lockup_amount_sat)?;
+        let tx_id = match payjoin_tx_id {
+            Some(tx_id) => tx_id,
+            None => {
+                let tx = self
+                    .onchain_wallet
+                    .build_tx_or_drain_tx(
+                        Some(LIQUID_FEE_RATE_MSAT_PER_VBYTE),
+                        &address_data.address,
+                        &asset_id,
+                        receiver_amount_sat,
+                    )
+                    .await?;
+                let tx_id = tx.txid().to_string();
+                let tx_fees_sat = tx.all_fees().values().sum::<u64>();
+                ensure_fee_limit(tx_fees_sat, max_fee_sat)?;
+                ensure_sdk!(tx_fees_sat <= fees_sat, PaymentError::InvalidOrExpiredFees);
 
-        let payer_amount_sat = req.prepare_response.total_fees_sat + receiver_amount_sat;
+                info!(
+                    "Built onchain Liquid tx with receiver_amount_sat = {receiver_amount_sat}, fees_sat = {fees_sat} and txid = {tx_id}"
+                );
 
-        let lockup_fees_sat = match payer_amount_sat == balance_sat {
-            true => self.estimate_drain_tx_fee(None, None).await?,
-            false => self.estimate_lockup_tx_fee(user_lockup_amount_sat).await?,
+                self.link_idempotency_key(idempotency_key, &tx_id)?;
+                self.liquid_chain_service.broadcast(&tx).await?.to_string()
+            }
         };
 
-        ensure_sdk!(
-            req.prepare_response.total_fees_sat
-                == boltz_fee_sat + lockup_fees_sat + claim_fees_sat + server_fees_sat,
-            PaymentError::InvalidOrExpiredFees
-        );
+        // We insert a pseudo-tx in case LWK fails to pick up the new mempool tx for a while
+        // This makes the tx known to the SDK (get_info, list_payments) instantly
+        let tx_data = PaymentTxData {
+            tx_id: tx_id.clone(),
+            timestamp: Some(utils::now()),
+            amount: receiver_amount_sat,
+            fees_sat,
+            payment_type: PaymentType::Send,
+            is_confirmed: false,
+            unblinding_data: None,
+            asset_id: asset_id.clone(),
+        };
 
-        ensure_sdk!(
-            payer_amount_sat <= balance_sat,
-            PaymentError::InsufficientFunds
-        );
+        let description = address_data.message;
 
-        let preimage = Preimage::new();
-        let preimage_str = preimage.to_string().ok_or(PaymentError::InvalidPreimage)?;
+        self.persister.insert_or_update_payment(
+            tx_data.clone(),
+            Some(PaymentTxDetails {
+                tx_id: tx_id.clone(),
+                destination: destination.clone(),
+                description: description.clone(),
+                ..Default::default()
+            }),
+            false,
+        )?;
+        self.emit_payment_updated(Some(tx_id)).await?; // Emit Pending event
 
-        let claim_keypair = utils::generate_keypair();
-        let claim_public_key = boltz_client::PublicKey {
-            compressed: true,
-            inner: claim_keypair.public_key(),
+        let asset_info = self
+            .persister
+            .get_asset_metadata(&asset_id)?
+            .map(|ref am| AssetInfo {
+                name: am.name.clone(),
+                ticker: am.ticker.clone(),
+                amount: am.amount_from_sat(receiver_amount_sat),
+                fees: None,
+                asset_amount: am.asset_amount(receiver_amount_sat),
+                asset_fees: None,
+            });
+        let payment_details = PaymentDetails::Liquid {
+            asset_id,
+            destination,
+            description: description.unwrap_or("Liquid transfer".to_string()),
+            asset_info,
+            lnurl_info: None,
+            bip353_address: None,
+            conversion: None,
         };
-        let refund_keypair = utils::generate_keypair();
-        let refund_public_key = boltz_client::PublicKey {
-            compressed: true,
-            inner: refund_keypair.public_key(),
-        };
-        let webhook = self.persister.get_webhook_url()?.map(|url| Webhook {
-            url,
-            hash_swap_id: Some(true),
-            status: Some(vec![
-                ChainSwapStates::TransactionFailed,
-                ChainSwapStates::TransactionLockupFailed,
-                ChainSwapStates::TransactionServerConfirmed,
-            ]),
-        });
-        let create_response = self
-            .swapper
-            .create_chain_swap(CreateChainRequest {
-                from: "L-BTC".to_string(),
-                to: "BTC".to_string(),
-                preimage_hash: preimage.sha256,
-                claim_public_key: Some(claim_public_key),
-                refund_public_key: Some(refund_public_key),
-                user_lock_amount: None,
-                server_lock_amount: Some(server_lockup_amount_sat),
-                pair_hash: Some(pair.hash.clone()),
-                referral_id: None,
-                webhook,
-            })
-            .await?;
-
-        let create_response_json =
-            ChainSwap::from_boltz_struct_to_json(&create_response, &create_response.id)?;
-        let swap_id = create_response.id;
 
-        let accept_zero_conf = server_lockup_amount_sat <= pair.limits.maximal_zero_conf;
-        let payer_amount_sat = req.prepare_response.total_fees_sat + receiver_amount_sat;
+        Ok(SendPaymentResponse {
+            payment: Payment::from_tx_data(tx_data, None, payment_details),
+        })
+    }
 
-        let swap = ChainSwap {
-            id: swap_id.clone(),
-            direction: Direction::Outgoing,
-            claim_address: Some(claim_address),
-            lockup_address: create_response.lockup_details.lockup_address,
-            refund_address: None,
-            timeout_block_height: create_response.lockup_details.timeout_block_height,
-            preimage: preimage_str,
-            description: Some("Bitcoin transfer".to_string()),
-            payer_amount_sat,
-            actual_payer_amount_sat: None,
-            receiver_amount_sat,
-            accepted_receiver_amount_sat: None,
-            claim_fees_sat,
-            pair_fees_json: serde_json::to_string(&pair).map_err(|e| {
-                PaymentError::generic(&format!("Failed to serialize outgoing ChainPair: {e:?}"))
-            })?,
-            accept_zero_conf,
-            create_response_json,
-            claim_private_key: claim_keypair.display_secret().to_string(),
-            refund_private_key: refund_keypair.display_secret().to_string(),
-            server_lockup_tx_id: None,
-            user_lockup_tx_id: None,
-            claim_tx_id: None,
-            refund_tx_id: None,
-            created_at: utils::now(),
-            state: PaymentState::Created,
-            auto_accepted_fees: false,
-            metadata: Default::default(),
+    /// Resolves an asset [PayAmount] to its asset id, its receiver amount in base units and
+    /// whether the asset fees should be estimated
+    fn resolve_asset_pay_amount(
+        &self,
+        amount: PayAmount,
+    ) -> Result<(String, u64, bool), PaymentError> {
+        let supported_asset_metadata = |asset_id: &str| {
+            let asset_metadata =
+                self.persister
+                    .get_asset_metadata(asset_id)?
+                    .ok_or(PaymentError::AssetError {
+                        err: format!("Asset {asset_id} is not supported"),
+                    })?;
+            // AMP assets are held in the AMP account, whose spends need the AMP server cosigning
+            ensure_sdk!(
+                !asset_metadata.is_amp,
+                PaymentError::AssetError {
+                    err: format!("Sending AMP asset {asset_id} is not supported"),
+                }
+            );
+            Ok(asset_metadata)
         };
-        self.persister.insert_or_update_chain_swap(&swap)?;
-        self.status_stream.track_swap_id(&swap_id)?;
-
-        self.wait_for_payment_with_timeout(Swap::Chain(swap), accept_zero_conf)
-            .await
-            .map(|payment| SendPaymentResponse { payment })
+        match amount {
+            PayAmount::Asset {
+                asset_id,
+                receiver_amount,
+                estimate_asset_fees,
+            } => {
+                let receiver_amount_sat =
+                    supported_asset_metadata(&asset_id)?.amount_to_sat(receiver_amount);
+                Ok((
+                    asset_id,
+                    receiver_amount_sat,
+                    estimate_asset_fees.unwrap_or(false),
+                ))
+            }
+            PayAmount::AssetUnits {
+                receiver_amount,
+                estimate_asset_fees,
+            } => {
+                supported_asset_metadata(&receiver_amount.asset_id)?
+                    .validate_asset_amount(&receiver_amount)?;
+                Ok((
+                    receiver_amount.asset_id,
+                    receiver_amount.base_units,
+                    estimate_asset_fees.unwrap_or(false),
+                ))
+            }
+            PayAmount::Bitcoin { .. } | PayAmount::Drain => {
+                Err(PaymentError::generic("Expected an asset amount"))
+            }
+        }
     }
 
-    async fn wait_for_payment_with_timeout(
+    /// Builds a payjoin tx with the receiver's payjoin endpoint
+    async fn build_liquid_payjoin_tx(
         &self,
-        swap: Swap,
-        accept_zero_conf: bool,
-    ) -> Result<Payment, PaymentError> {
-        let timeout_fut = tokio::time::sleep(Duration::from_secs(self.config.payment_timeout_sec));
-        tokio::pin!(timeout_fut);
-
-        let expected_swap_id = swap.id();
-        let mut events_stream = self.event_manager.subscribe();
-        let mut maybe_payment: Option<Payment> = None;
+        payjoin_url: &str,
+        address: &str,
+        receiver_amount_sat: u64,
+        fees_sat: u64,
+    ) -> Result<elements::Transaction, PaymentError> {
+        let tx = P2epPayjoinClient::new(
+            self.onchain_wallet.clone(),
+            self.rest_client.clone(),
+            utils::lbtc_asset_id(self.config.network),
+        )
+        .build_payjoin_tx(payjoin_url, address, receiver_amount_sat, fees_sat)
+        .await?;
+        info!(
+            "Built payjoin Liquid tx with receiver_amount_sat = {receiver_amount_sat} and txid = {}",
+            tx.txid()
+        );
+        Ok(tx)
+    }
 
-        loop {
-            tokio::select! {
-                _ = &mut timeout_fut => match maybe_payment {
-                    Some(payment) => return Ok(payment),
-                    None => {
-                        debug!("Timeout occurred without payment, set swap to timed out");
-                        let update_res = match swap {
-                            Swap::Send(_) => self.send_swap_handler.update_swap_info(&expected_swap_id, TimedOut, None, None, None),
-                            Swap::Chain(_) => self.chain_swap_handler.update_swap_info(&ChainSwapUpdate {
-                                    swap_id: expected_swap_id.clone(),
-                                    to_state: TimedOut,
-                                    ..Default::default()
-                                }),
-                            _ => Ok(())
-                        };
-                        return match update_res {
-                            Ok(_) => Err(PaymentError::PaymentTimeout),
-                            Err(_) => {
-                                // Not able to transition the payment state to TimedOut, which means the payment
-                                // state progressed but we didn't see the event before the timeout
-                                self.persister.get_payment(&expected_swap_id).ok().flatten().ok_or(PaymentError::generic("Payment not found"))
-                            }
-                        }
-                    },
-                },
-                event = events_stream.recv() => match event {
-                    Ok(SdkEvent::PaymentPending { details: payment }) => {
-                        let maybe_payment_swap_id = payment.details.get_swap_id();
-                        if matches!(maybe_payment_swap_id, Some(swap_id) if swap_id == expected_swap_id) {
-                            match accept_zero_conf {
-                                true => {
-                                    debug!("Received Send Payment pending event with zero-conf accepted");
-                                    return Ok(payment)
-                                }
-                                false => {
-                                    debug!("Received Send Payment pending event, waiting for confirmation");
-                                    maybe_payment = Some(payment);
-                                }
-                            }
-                        };
-                    },
-                    Ok(SdkEvent::PaymentSucceeded { details: payment }) => {
-                        let maybe_payment_swap_id = payment.details.get_swap_id();
-                        if matches!(maybe_payment_swap_id, Some(swap_id) if swap_id == expected_swap_id) {
-                            debug!("Received Send Payment succeed event");
-                            return Ok(payment);
-                        }
-                    },
-                    Ok(event) => debug!("Unhandled event waiting for payment: {event:?}"),
-                    Err(e) => debug!("Received error waiting for payment: {e:?}"),
+    /// Broadcasts a payjoin tx, returning its tx id. As a failed broadcast may still have
+    /// reached the network, the tx is then looked up: `None` is only returned if it's known not
+    /// to be broadcast, so a regular tx can be sent instead without paying twice.
+    async fn broadcast_liquid_payjoin_tx(
+        &self,
+        tx: &elements::Transaction,
+    ) -> Result<Option<String>, PaymentError> {
+        let tx_id = tx.txid().to_string();
+        match self.liquid_chain_service.broadcast(tx).await {
+            Ok(tx_id) => Ok(Some(tx_id.to_string())),
+            Err(e) => {
+                warn!("Failed to broadcast payjoin tx {tx_id}: {e:?}");
+                match self.is_tx_broadcast(&tx_id, true).await? {
+                    true => Ok(Some(tx_id)),
+                    false => Ok(None),
                 }
             }
         }
     }
 
-    /// Prepares to receive a Lightning payment via a reverse submarine swap.
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [PrepareReceiveRequest] containing:
-    ///     * `payment_method` - the supported payment methods; either an invoice, a Liquid address or a Bitcoin address
-    ///     * `amount` - The optional amount of type [ReceiveAmount] to be paid.
-    ///        - [ReceiveAmount::Bitcoin] which sets the amount in satoshi that should be paid
-    ///        - [ReceiveAmount::Asset] which sets the amount of an asset that should be paid
-    pub async fn prepare_receive_payment(
+    /// Performs a Send Payment by doing a payjoin tx to a Liquid address
+    async fn pay_liquid_payjoin(
         &self,
-        req: &PrepareReceiveRequest,
-    ) -> Result<PrepareReceiveResponse, PaymentError> {
-        self.ensure_is_started().await?;
+        address_data: LiquidAddressData,
+        receiver_amount_sat: u64,
+        max_asset_fees: Option<u64>,
+        idempotency_key: Option<&str>,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        let _in_flight = self.in_flight.start();
+        let destination = address_data
+            .to_uri()
+            .unwrap_or(address_data.address.clone());
+        let Some(asset_id) = address_data.asset_id else {
+            return Err(PaymentError::asset_error(
+                "Asset must be set when paying to a Liquid address",
+            ));
+        };
 
-        let mut min_payer_amount_sat = None;
-        let mut max_payer_amount_sat = None;
-        let mut swapper_feerate = None;
-        let fees_sat;
-        match req.payment_method {
-            PaymentMethod::Lightning => {
-                let payer_amount_sat = match req.amount {
-                    Some(ReceiveAmount::Asset { .. }) => {
-                        return Err(PaymentError::asset_error(
-                            "Cannot receive an asset when the payment method is Lightning",
-                        ));
-                    }
-                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => payer_amount_sat,
-                    None => {
-                        return Err(PaymentError::generic(
-                            "Bitcoin payer amount must be set when the payment method is Lightning",
-                        ));
-                    }
-                };
-                let reverse_pair = self
-                    .swapper
-                    .get_reverse_swap_pairs()
-                    .await?
-                    .ok_or(PaymentError::PairsNotFound)?;
+        let (tx, asset_fees) = self
+            .payjoin_service
+            .build_payjoin_tx(&address_data.address, &asset_id, receiver_amount_sat)
+            .await
+            .inspect_err(|e| error!("Error building payjoin tx: {e}"))?;
+        ensure_fee_limit(asset_fees, max_asset_fees)?;
+        let tx_id = tx.txid().to_string();
+        let fees_sat = tx.all_fees().values().sum::<u64>();
 
-                fees_sat = reverse_pair.fees.total(payer_amount_sat);
+        info!(
+            "Built payjoin Liquid tx with receiver_amount_sat = {receiver_amount_sat}, asset_fees = {asset_fees}, fees_sat = {fees_sat} and txid = {tx_id}"
+        );
 
-                ensure_sdk!(payer_amount_sat > fees_sat, PaymentError::AmountOutOfRange);
+        self.link_idempotency_key(idempotency_key, &tx_id)?;
+        let tx_id = self.liquid_chain_service.broadcast(&tx).await?.to_string();
 
-                reverse_pair
-                    .limits
-                    .within(payer_amount_sat)
-                    .map_err(|_| PaymentError::AmountOutOfRange)?;
+        // We insert a pseudo-tx in case LWK fails to pick up the new mempool tx for a while
+        // This makes the tx known to the SDK (get_info, list_payments) instantly
+        let tx_data = PaymentTxData {
+            tx_id: tx_id.clone(),
+            timestamp: Some(utils::now()),
+            amount: receiver_amount_sat + asset_fees,
+            fees_sat,
+            payment_type: PaymentType::Send,
+            is_confirmed: false,
+            unblinding_data: None,
+            asset_id: asset_id.clone(),
+        };
 
-                min_payer_amount_sat = Some(reverse_pair.limits.minimal);
-                max_payer_amount_sat = Some(reverse_pair.limits.maximal);
-                swapper_feerate = Some(reverse_pair.fees.percentage);
+        let description = address_data.message;
 
-                debug!(
-                    "Preparing Lightning Receive Swap with: payer_amount_sat {payer_amount_sat} sat, fees_sat {fees_sat} sat"
-                );
-            }
-            PaymentMethod::BitcoinAddress => {
-                let payer_amount_sat = match req.amount {
-                    Some(ReceiveAmount::Asset { .. }) => {
-                        return Err(PaymentError::asset_error(
-                            "Cannot receive an asset when the payment method is Bitcoin",
-                        ));
-                    }
-                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => Some(payer_amount_sat),
-                    None => None,
-                };
-                let pair = self
-                    .get_and_validate_chain_pair(Direction::Incoming, payer_amount_sat)
-                    .await?;
-                let claim_fees_sat = pair.fees.claim_estimate();
-                let server_fees_sat = pair.fees.server();
-                let service_fees_sat = payer_amount_sat
-                    .map(|user_lockup_amount_sat| pair.fees.boltz(user_lockup_amount_sat))
-                    .unwrap_or_default();
+        self.persister.insert_or_update_payment(
+            tx_data.clone(),
+            Some(PaymentTxDetails {
+                tx_id: tx_id.clone(),
+                destination: destination.clone(),
+                description: description.clone(),
+                asset_fees: Some(asset_fees),
+                ..Default::default()
+            }),
+            false,
+        )?;
+        self.emit_payment_updated(Some(tx_id)).await?; // Emit Pending event
 
-                min_payer_amount_sat = Some(pair.limits.minimal);
-                max_payer_amount_sat = Some(pair.limits.maximal);
-                swapper_feerate = Some(pair.fees.percentage);
+        let asset_info = self
+            .persister
+            .get_asset_metadata(&asset_id)?
+            .map(|ref am| AssetInfo {
+                name: am.name.clone(),
+                ticker: am.ticker.clone(),
+                amount: am.amount_from_sat(receiver_amount_sat),
+                fees: Some(am.amount_from_sat(asset_fees)),
+                asset_amount: am.asset_amount(receiver_amount_sat),
+                asset_fees: Some(am.asset_amount(asset_fees)),
+            });
+        let payment_details = PaymentDetails::Liquid {
+            asset_id,
+            destination,
+            description: description.unwrap_or("Liquid transfer".to_string()),
+            asset_info,
+            lnurl_info: None,
+            bip353_address: None,
+            conversion: None,
+        };
 
-                fees_sat = service_fees_sat + claim_fees_sat + server_fees_sat;
-                debug!("Preparing Chain Receive Swap with: payer_amount_sat {payer_amount_sat:?}, fees_sat {fees_sat}");
-            }
-            PaymentMethod::LiquidAddress => {
-                let (asset_id, payer_amount, payer_amount_sat) = match req.amount.clone() {
-                    Some(ReceiveAmount::Asset {
-                        payer_amount,
-                        asset_id,
-                    }) => (asset_id, payer_amount, None),
-                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => {
-                        (self.config.lbtc_asset_id(), None, Some(payer_amount_sat))
-                    }
-                    None => (self.config.lbtc_asset_id(), None, None),
+        Ok(SendPaymentResponse {
+            payment: Payment::from_tx_data(tx_data, None, payment_details),
+        })
+    }
+
+    /// Performs a Send Payment by doing a swap (create it, fund it, track it, etc).
+    ///
+    /// If `bolt12_offer` is set, `invoice` refers to a Bolt12 invoice, otherwise it's a Bolt11 one.
+    async fn send_payment_via_swap(
+        &self,
+        invoice: &str,
+        bolt12_offer: Option<String>,
+        payment_hash: &str,
+        description: Option<String>,
+        receiver_amount_sat: u64,
+        fees_sat: u64,
+        max_fee_sat: Option<u64>,
+        idempotency_key: Option<&str>,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        let lbtc_pair = self.validate_submarine_pairs(receiver_amount_sat).await?;
+        let boltz_fees_total = lbtc_pair.fees.total(receiver_amount_sat);
+        let user_lockup_amount_sat = receiver_amount_sat + boltz_fees_total;
+        let lockup_tx_fees_sat = self
+            .estimate_lockup_tx_or_drain_tx_fee(user_lockup_amount_sat)
+            .await?;
+        ensure_fee_limit(boltz_fees_total + lockup_tx_fees_sat, max_fee_sat)?;
+        self.ensure_current_fees(fees_sat == boltz_fees_total + lockup_tx_fees_sat)
+            .await?;
+
+        let swap = match self.persister.fetch_send_swap_by_invoice(invoice)? {
+            Some(swap) => match swap.state {
+                Created => swap,
+                TimedOut => {
+                    self.send_swap_handler.update_swap_info(
+                        &swap.id,
+                        PaymentState::Created,
+                        None,
+                        None,
+                        None,
+                    )?;
+                    swap
+                }
+                Pending => return Err(PaymentError::PaymentInProgress),
+                Complete => return Err(PaymentError::AlreadyPaid),
+                RefundPending | Refundable | Failed => {
+                    return Err(PaymentError::invalid_invoice(
+                        "Payment has already failed. Please try with another invoice",
+                    ))
+                }
+                WaitingFeeAcceptance => {
+                    return Err(PaymentError::Generic {
+                        err: "Send swap payment cannot be in state WaitingFeeAcceptance"
+                            .to_string(),
+                    })
+                }
+            },
+            None => {
+                let keypair = utils::generate_keypair();
+                let refund_public_key = boltz_client::PublicKey {
+                    compressed: true,
+                    inner: keypair.public_key(),
+                };
+                let webhook = self.persister.get_webhook_url()?.map(|url| Webhook {
+                    url,
+                    hash_swap_id: Some(true),
+                    status: Some(vec![
+                        SubSwapStates::InvoiceFailedToPay,
+                        SubSwapStates::SwapExpired,
+                        SubSwapStates::TransactionClaimPending,
+                        SubSwapStates::TransactionLockupFailed,
+                    ]),
+                });
+                let create_response = self
+                    .swapper
+                    .create_send_swap(CreateSubmarineRequest {
+                        from: "L-BTC".to_string(),
+                        to: "BTC".to_string(),
+                        invoice: invoice.to_string(),
+                        refund_public_key,
+                        pair_hash: Some(lbtc_pair.hash.clone()),
+                        referral_id: None,
+                        webhook,
+                    })
+                    .await?;
+
+                let swap_id = &create_response.id;
+                let create_response_json =
+                    SendSwap::from_boltz_struct_to_json(&create_response, swap_id)?;
+                let destination_pubkey =
+                    utils::get_invoice_destination_pubkey(invoice, bolt12_offer.is_some())?;
+
+                let payer_amount_sat = fees_sat + receiver_amount_sat;
+                let swap = SendSwap {
+                    id: swap_id.to_string(),
+                    invoice: invoice.to_string(),
+                    bolt12_offer,
+                    payment_hash: Some(payment_hash.to_string()),
+                    destination_pubkey: Some(destination_pubkey),
+                    timeout_block_height: create_response.timeout_block_height,
+                    description,
+                    preimage: None,
+                    payer_amount_sat,
+                    receiver_amount_sat,
+                    pair_fees_json: serde_json::to_string(&lbtc_pair).map_err(|e| {
+                        PaymentError::generic(&format!("Failed to serialize SubmarinePair: {e:?}"))
+                    })?,
+                    create_response_json,
+                    lockup_tx_id: None,
+                    refund_address: None,
+                    refund_tx_id: None,
+                    created_at: utils::now(),
+                    state: PaymentState::Created,
+                    refund_private_key: keypair.display_secret().to_string(),
+                    metadata: Default::default(),
                 };
-                fees_sat = 0;
-                debug!("Preparing Liquid Receive with: asset_id {asset_id}, amount {payer_amount:?}, amount_sat {payer_amount_sat:?}, fees_sat {fees_sat}");
+                self.persister.insert_or_update_send_swap(&swap)?;
+                swap
             }
         };
+        self.link_idempotency_key(idempotency_key, &swap.id)?;
+        self.status_stream.track_swap_id(&swap.id)?;
 
-        Ok(PrepareReceiveResponse {
-            amount: req.amount.clone(),
-            fees_sat,
-            payment_method: req.payment_method.clone(),
-            min_payer_amount_sat,
-            max_payer_amount_sat,
-            swapper_feerate,
+        let create_response = swap.get_boltz_create_response()?;
+        self.send_swap_handler
+            .try_lockup(&swap, &create_response)
+            .await?;
+
+        self.wait_for_payment_with_timeout(Swap::Send(swap), create_response.accept_zero_conf)
+            .await
+            .map(|payment| SendPaymentResponse { payment })
+    }
+
+    /// Fetch the current payment limits for [LiquidSdk::send_payment] and [LiquidSdk::receive_payment].
+    pub async fn fetch_lightning_limits(
+        &self,
+    ) -> Result<LightningPaymentLimitsResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let submarine_pair = self
+            .swapper
+            .get_submarine_pairs()
+            .await?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let send_limits = submarine_pair.limits;
+
+        let reverse_pair = self
+            .swapper
+            .get_reverse_swap_pairs()
+            .await?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let receive_limits = reverse_pair.limits;
+
+        Ok(LightningPaymentLimitsResponse {
+            send: Limits {
+                min_sat: send_limits.minimal_batched.unwrap_or(send_limits.minimal),
+                max_sat: send_limits.maximal,
+                max_zero_conf_sat: send_limits.maximal_zero_conf,
+            },
+            receive: Limits {
+                min_sat: receive_limits.minimal,
+                max_sat: receive_limits.maximal,
+                max_zero_conf_sat: self.config.zero_conf_max_amount_sat(),
+            },
         })
     }
 
-    /// Receive a Lightning payment via a reverse submarine swap, a chain swap or via direct Liquid
-    /// payment.
+    /// Fetch the current payment limits for [LiquidSdk::pay_onchain] and [LiquidSdk::receive_onchain].
+    pub async fn fetch_onchain_limits(&self) -> Result<OnchainPaymentLimitsResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let (pair_outgoing, pair_incoming) = self.swapper.get_chain_pairs().await?;
+        let send_limits = pair_outgoing
+            .ok_or(PaymentError::PairsNotFound)
+            .map(|pair| pair.limits)?;
+        let receive_limits = pair_incoming
+            .ok_or(PaymentError::PairsNotFound)
+            .map(|pair| pair.limits)?;
+
+        Ok(OnchainPaymentLimitsResponse {
+            send: Limits {
+                min_sat: send_limits.minimal,
+                max_sat: send_limits.maximal,
+                max_zero_conf_sat: send_limits.maximal_zero_conf,
+            },
+            receive: Limits {
+                min_sat: receive_limits.minimal,
+                max_sat: receive_limits.maximal,
+                max_zero_conf_sat: receive_limits
+                    .maximal_zero_conf
+                    .min(self.config.zero_conf_max_amount_sat()),
+            },
+        })
+    }
+
+    /// Returns the maximum amount that can currently be sent with a payment method, after
+    /// deducting the swap and network fees from the balance and capping it to the swap limits.
     ///
     /// # Arguments
     ///
-    /// * `req` - the [ReceivePaymentRequest] containing:
-    ///     * `prepare_response` - the [PrepareReceiveResponse] from calling [LiquidSdk::prepare_receive_payment]
-    ///     * `description` - the optional payment description
-    ///     * `use_description_hash` - optional if true uses the hash of the description
+    /// * `req` - the [GetMaxPayableRequest] containing:
+    ///     * `payment_method` - the [PaymentMethod] to send with
     ///
     /// # Returns
-    ///
-    /// * A [ReceivePaymentResponse] containing:
-    ///     * `destination` - the final destination to be paid by the payer, either a BIP21 URI (Liquid or Bitcoin), a Liquid address or an invoice
-    pub async fn receive_payment(
+    /// Returns a [GetMaxPayableResponse] containing:
+    ///     * `receiver_amount_sat` - the maximum amount the recipient receives, or zero if the
+    ///       balance is too low to pay with this payment method
+    ///     * `fees_sat` - the total fees paid when sending this amount
+    pub async fn get_max_payable(
         &self,
-        req: &ReceivePaymentRequest,
-    ) -> Result<ReceivePaymentResponse, PaymentError> {
+        req: &GetMaxPayableRequest,
+    ) -> Result<GetMaxPayableResponse, PaymentError> {
         self.ensure_is_started().await?;
 
-        let PrepareReceiveResponse {
-            payment_method,
-            amount,
-            fees_sat,
-            ..
-        } = &req.prepare_response;
+        let balance_sat = self.get_info().await?.wallet_info.balance_sat;
+        if balance_sat == 0 {
+            return Ok(GetMaxPayableResponse::default());
+        }
+        let drain_fees_sat = match self.estimate_drain_tx_fee(None, None).await {
+            Ok(drain_fees_sat) if drain_fees_sat < balance_sat => drain_fees_sat,
+            Ok(_) | Err(PaymentError::InsufficientFunds) => {
+                return Ok(GetMaxPayableResponse::default())
+            }
+            Err(e) => return Err(e),
+        };
+        let spendable_sat = balance_sat - drain_fees_sat;
 
-        match payment_method {
+        let res = match req.payment_method {
+            PaymentMethod::LiquidAddress => GetMaxPayableResponse {
+                receiver_amount_sat: spendable_sat,
+                fees_sat: drain_fees_sat,
+            },
             PaymentMethod::Lightning => {
-                let amount_sat = match amount.clone() {
-                    Some(ReceiveAmount::Asset { .. }) => {
-                        return Err(PaymentError::asset_error(
-                            "Cannot receive an asset when the payment method is Lightning",
-                        ));
-                    }
-                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => payer_amount_sat,
-                    None => {
-                        return Err(PaymentError::generic(
-                            "Bitcoin payer amount must be set when the payment method is Lightning",
-                        ));
-                    }
-                };
-                let (description, description_hash) = match (
-                    req.description.clone(),
-                    req.use_description_hash.unwrap_or_default(),
-                ) {
-                    (Some(description), true) => (
-                        None,
-                        Some(sha256::Hash::hash(description.as_bytes()).to_hex()),
-                    ),
-                    (_, false) => (req.description.clone(), None),
-                    _ => {
-                        return Err(PaymentError::InvalidDescription {
-                            err: "Missing payment description to hash".to_string(),
-                        })
-                    }
-                };
-                self.create_receive_swap(amount_sat, *fees_sat, description, description_hash)
-                    .await
+                let pair = self
+                    .swapper
+                    .get_submarine_pairs()
+                    .await?
+                    .ok_or(PaymentError::PairsNotFound)?;
+                let receiver_amount_sat =
+                    max_amount_within_budget(spendable_sat, pair.limits.maximal, |amount_sat| {
+                        pair.fees.total(amount_sat)
+                    });
+                let lockup_fees_sat = self
+                    .estimate_lockup_tx_or_drain_tx_fee(
+                        receiver_amount_sat + pair.fees.total(receiver_amount_sat),
+                    )
+                    .await?;
+                // Unless it drains the wallet, the lockup tx has a change output and costs more
+                // than the drain tx the budget was computed with
+                let receiver_amount_sat = cap_amount_to_balance(
+                    receiver_amount_sat,
+                    pair.fees.total(receiver_amount_sat) + lockup_fees_sat,
+                    balance_sat,
+                );
+                if pair.limits.within(receiver_amount_sat).is_err() {
+                    return Ok(GetMaxPayableResponse::default());
+                }
+                let boltz_fees_sat = pair.fees.total(receiver_amount_sat);
+                GetMaxPayableResponse {
+                    receiver_amount_sat,
+                    fees_sat: boltz_fees_sat + lockup_fees_sat,
+                }
             }
             PaymentMethod::BitcoinAddress => {
-                let amount_sat = match amount.clone() {
-                    Some(ReceiveAmount::Asset { .. }) => {
-                        return Err(PaymentError::asset_error(
-                            "Cannot receive an asset when the payment method is Bitcoin",
-                        ));
-                    }
-                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => Some(payer_amount_sat),
-                    None => None,
+                let pair = self.get_chain_pair(Direction::Outgoing).await?;
+                let user_lockup_amount_sat = spendable_sat.min(pair.limits.maximal);
+                let lockup_fees_sat = match user_lockup_amount_sat == spendable_sat {
+                    true => drain_fees_sat,
+                    false => self.estimate_lockup_tx_fee(user_lockup_amount_sat).await?,
                 };
-                self.receive_onchain(amount_sat, *fees_sat).await
+                let user_lockup_amount_sat =
+                    cap_amount_to_balance(user_lockup_amount_sat, lockup_fees_sat, balance_sat);
+                if self
+                    .validate_user_lockup_amount_for_chain_pair(&pair, user_lockup_amount_sat)
+                    .is_err()
+                {
+                    return Ok(GetMaxPayableResponse::default());
+                }
+                let swap_fees_sat = pair.fees.boltz(user_lockup_amount_sat)
+                    + pair.fees.claim_estimate()
+                    + pair.fees.server();
+                GetMaxPayableResponse {
+                    receiver_amount_sat: user_lockup_amount_sat.saturating_sub(swap_fees_sat),
+                    fees_sat: swap_fees_sat + lockup_fees_sat,
+                }
             }
-            PaymentMethod::LiquidAddress => {
-                let lbtc_asset_id = self.config.lbtc_asset_id();
-                let (asset_id, amount, amount_sat) = match amount.clone() {
-                    Some(ReceiveAmount::Asset {
-                        asset_id,
-                        payer_amount,
-                    }) => (asset_id, payer_amount, None),
-                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => {
-                        (lbtc_asset_id.clone(), None, Some(payer_amount_sat))
-                    }
-                    None => (lbtc_asset_id.clone(), None, None),
-                };
+        };
+        info!("Max payable with {:?}: {res:?}", req.payment_method);
+        Ok(res)
+    }
 
-                let address = self.onchain_wallet.next_unused_address().await?.to_string();
-                let receive_destination =
-                    if asset_id.ne(&lbtc_asset_id) || amount.is_some() || amount_sat.is_some() {
-                        LiquidAddressData {
-                            address: address.to_string(),
-                            network: self.config.network.into(),
-                            amount,
-                            amount_sat,
-                            asset_id: Some(asset_id),
-                            label: None,
-                            message: req.description.clone(),
-                        }
-                        .to_uri()
-                        .map_err(|e| PaymentError::Generic {
-                            err: format!("Could not build BIP21 URI: {e:?}"),
-                        })?
-                    } else {
-                        address
-                    };
+    /// Returns the maximum amount that can currently be received with a payment method, as
+    /// limited by the swap limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [GetMaxReceivableRequest] containing:
+    ///     * `payment_method` - the [PaymentMethod] to receive with
+    ///
+    /// # Returns
+    /// Returns a [GetMaxReceivableResponse] containing:
+    ///     * `payer_amount_sat` - the maximum amount the payer can pay, to be used as the
+    ///       [ReceiveAmount] when calling [LiquidSdk::prepare_receive_payment]. Not set when
+    ///       there is no maximum, as when receiving to a Liquid address.
+    ///     * `fees_sat` - the total fees deducted when receiving this amount
+    pub async fn get_max_receivable(
+        &self,
+        req: &GetMaxReceivableRequest,
+    ) -> Result<GetMaxReceivableResponse, PaymentError> {
+        self.ensure_is_started().await?;
 
-                Ok(ReceivePaymentResponse {
-                    destination: receive_destination,
-                })
+        let res = match req.payment_method {
+            PaymentMethod::LiquidAddress => GetMaxReceivableResponse {
+                payer_amount_sat: None,
+                fees_sat: 0,
+            },
+            PaymentMethod::Lightning => {
+                let pair = self
+                    .swapper
+                    .get_reverse_swap_pairs()
+                    .await?
+                    .ok_or(PaymentError::PairsNotFound)?;
+                GetMaxReceivableResponse {
+                    payer_amount_sat: Some(pair.limits.maximal),
+                    fees_sat: pair.fees.total(pair.limits.maximal),
+                }
             }
-        }
+            PaymentMethod::BitcoinAddress => {
+                let pair = self.get_chain_pair(Direction::Incoming).await?;
+                GetMaxReceivableResponse {
+                    payer_amount_sat: Some(pair.limits.maximal),
+                    fees_sat: pair.fees.boltz(pair.limits.maximal)
+                        + pair.fees.claim_estimate()
+                        + pair.fees.server(),
+                }
+            }
+        };
+        info!("Max receivable with {:?}: {res:?}", req.payment_method);
+        Ok(res)
     }
 
-    async fn create_receive_swap(
+    /// Returns a Bitcoin address that can be paid to repeatedly.
+    ///
+    /// Each confirmed deposit to the address is forwarded to the lockup address of a new
+    /// incoming chain swap, which then completes like any other Bitcoin receive. The forwarding
+    /// tx fee is deducted from each deposit. If the swap of a deposit fails or expires before the
+    /// deposit is forwarded, the deposit is forwarded to a new swap. Deposits outside the chain
+    /// swap limits are not forwarded and can be recovered by importing the wallet mnemonic into a
+    /// Bitcoin wallet, using the dedicated `m/84'/0'/1776'/0/0` derivation path
+    /// (`m/84'/1'/1776'/0/0` on testnet and regtest).
+    pub async fn get_reusable_bitcoin_address(
         &self,
-        payer_amount_sat: u64,
-        fees_sat: u64,
-        description: Option<String>,
-        description_hash: Option<String>,
-    ) -> Result<ReceivePaymentResponse, PaymentError> {
-        let reverse_pair = self
-            .swapper
-            .get_reverse_swap_pairs()
-            .await?
-            .ok_or(PaymentError::PairsNotFound)?;
-        let new_fees_sat = reverse_pair.fees.total(payer_amount_sat);
-        ensure_sdk!(fees_sat == new_fees_sat, PaymentError::InvalidOrExpiredFees);
+    ) -> Result<ReusableBitcoinAddressResponse, PaymentError> {
+        self.ensure_is_started().await?;
 
-        debug!("Creating Receive Swap with: payer_amount_sat {payer_amount_sat} sat, fees_sat {fees_sat} sat");
+        let reusable_address = ReusableAddress::new(self.signer.clone(), self.config.network)?;
+        let address = reusable_address.address().to_string();
+        if self.persister.get_reusable_bitcoin_address()?.is_none() {
+            self.persister
+                .set_reusable_bitcoin_address(address.clone())?;
+        }
 
-        let keypair = utils::generate_keypair();
+        let pair = self.get_chain_pair(Direction::Incoming).await?;
+        let forward_fee_sat =
+            ReusableAddress::forward_fee_sat(self.recommended_fees().await?.half_hour_fee);
+        Ok(ReusableBitcoinAddressResponse {
+            address,
+            min_deposit_sat: pair.limits.minimal + forward_fee_sat,
+            max_deposit_sat: pair.limits.maximal + forward_fee_sat,
+        })
+    }
 
-        let preimage = Preimage::new();
-        let preimage_str = preimage.to_string().ok_or(PaymentError::InvalidPreimage)?;
-        let preimage_hash = preimage.sha256.to_string();
+    /// Forwards the confirmed deposits to the reusable Bitcoin address to incoming chain swaps.
+    /// Does nothing if the address was never requested.
+    async fn forward_reusable_address_deposits(&self) -> Result<()> {
+        if self.persister.get_reusable_bitcoin_address()?.is_none() {
+            return Ok(());
+        }
 
-        // Address to be used for a BIP-21 direct payment
-        let mrh_addr = self.onchain_wallet.next_unused_address().await?;
+        let reusable_address = ReusableAddress::new(self.signer.clone(), self.config.network)?;
+        let script_pubkey = reusable_address.address().script_pubkey();
+        let confirmed_txids: HashSet<_> = self
+            .bitcoin_chain_service
+            .get_script_history(&script_pubkey)
+            .await?
+            .into_iter()
+            .filter(|history| history.height > 0)
+            .map(|history| history.txid)
+            .collect();
+        let utxos = self
+            .bitcoin_chain_service
+            .get_script_utxos(&script_pubkey)
+            .await?;
+        let deposits = self.persister.list_reusable_address_deposits()?;
+        let fee_rate_sat_per_vbyte = self.recommended_fees().await?.half_hour_fee;
 
-        // Signature of the claim public key of the SHA256 hash of the address for the direct payment
-        let mrh_addr_str = mrh_addr.to_string();
-        let mrh_addr_hash = sha256::Hash::hash(mrh_addr_str.as_bytes());
-        let mrh_addr_hash_sig =
-            keypair.sign_schnorr(Message::from_digest_slice(mrh_addr_hash.as_byte_array())?);
+        for (outpoint, txout) in utxos.iter().filter_map(|utxo| utxo.as_bitcoin()) {
+            if !confirmed_txids.contains(&outpoint.txid) {
+                continue;
+            }
+            let outpoint_str = outpoint.to_string();
+            let deposit = deposits.get(&outpoint_str);
+            if deposit.is_some_and(|deposit| deposit.forward_tx_id.is_some()) {
+                continue;
+            }
+            if let Err(e) = self
+                .forward_reusable_address_deposit(
+                    &reusable_address,
+                    &(*outpoint, txout.clone()),
+                    deposit.map(|deposit| deposit.swap_id.as_str()),
+                    fee_rate_sat_per_vbyte,
+                )
+                .await
+            {
+                warn!("Failed to forward reusable address deposit {outpoint_str}: {e:?}");
+            }
+        }
+        Ok(())
+    }
 
-        let receiver_amount_sat = payer_amount_sat - fees_sat;
-        let webhook_claim_status =
-            match receiver_amount_sat > self.config.zero_conf_max_amount_sat() {
-                true => RevSwapStates::TransactionConfirmed,
-                false => RevSwapStates::TransactionMempool,
-            };
-        let webhook = self.persister.get_webhook_url()?.map(|url| Webhook {
-            url,
-            hash_swap_id: Some(true),
-            status: Some(vec![webhook_claim_status]),
-        });
+    async fn forward_reusable_address_deposit(
+        &self,
+        reusable_address: &ReusableAddress,
+        deposit: &(bitcoin::OutPoint, bitcoin::TxOut),
+        swap_id: Option<&str>,
+        fee_rate_sat_per_vbyte: u64,
+    ) -> Result<(), PaymentError> {
+        let _in_flight = self.in_flight.start();
+        let outpoint = deposit.0.to_string();
 
-        let v2_req = CreateReverseRequest {
-            invoice_amount: payer_amount_sat,
-            from: "BTC".to_string(),
-            to: "L-BTC".to_string(),
-            preimage_hash: preimage.sha256,
-            claim_public_key: keypair.public_key().into(),
-            description,
-            description_hash,
-            address: Some(mrh_addr_str.clone()),
-            address_signature: Some(mrh_addr_hash_sig.to_hex()),
-            referral_id: None,
-            webhook,
+        let existing_swap = match swap_id {
+            Some(swap_id) => Some(self.persister.fetch_chain_swap_by_id(swap_id)?.ok_or(
+                PaymentError::generic(&format!("Chain swap {swap_id} not found")),
+            )?),
+            None => None,
+        };
+        // The deposit was forwarded, but the forward tx was not recorded
+        if let Some(forward_tx_id) = existing_swap
+            .as_ref()
+            .and_then(|swap| swap.user_lockup_tx_id.as_ref())
+        {
+            self.persister
+                .set_reusable_address_deposit_forward_tx_id(&outpoint, forward_tx_id)?;
+            return Ok(());
+        }
+        let swap = match existing_swap {
+            Some(swap) if swap.state == Created => swap,
+            // The swap expired or failed before the deposit was forwarded, so the deposit is
+            // forwarded to a new swap instead
+            Some(swap) if matches!(swap.state, Failed | TimedOut) => {
+                info!(
+                    "Chain swap {} for reusable address deposit {outpoint} is {:?}, replacing it",
+                    swap.id, swap.state
+                );
+                match self
+                    .create_reusable_address_deposit_swap(deposit, fee_rate_sat_per_vbyte)
+                    .await?
+                {
+                    Some(new_swap) => {
+                        self.persister
+                            .set_reusable_address_deposit_swap_id(&outpoint, &new_swap.id)?;
+                        new_swap
+                    }
+                    None => return Ok(()),
+                }
+            }
+            // The swap is still in progress
+            Some(_) => return Ok(()),
+            None => match self
+                .create_reusable_address_deposit_swap(deposit, fee_rate_sat_per_vbyte)
+                .await?
+            {
+                Some(swap) => {
+                    self.persister
+                        .insert_reusable_address_deposit(&outpoint, &swap.id)?;
+                    swap
+                }
+                None => return Ok(()),
+            },
         };
-        let create_response = self.swapper.create_receive_swap(v2_req).await?;
-
-        // Reserve this address until the timeout block height
-        self.persister.insert_or_update_reserved_address(
-            &mrh_addr_str,
-            create_response.timeout_block_height,
-        )?;
 
-        // Check if correct MRH was added to the invoice by Boltz
-        let (bip21_lbtc_address, _bip21_amount_btc) = self
-            .swapper
-            .check_for_mrh(&create_response.invoice)
-            .await?
-            .ok_or(PaymentError::receive_error("Invoice has no MRH"))?;
-        ensure_sdk!(
-            bip21_lbtc_address == mrh_addr_str,
-            PaymentError::receive_error("Invoice has incorrect address in MRH")
+        let recipient = swap.get_receive_lockup_swap_script_pubkey(self.config.network)?;
+        let tx = reusable_address.build_forward_tx(deposit, &recipient, swap.payer_amount_sat)?;
+        let forward_tx_id = self.bitcoin_chain_service.broadcast(&tx).await?.to_string();
+        self.persister
+            .set_reusable_address_deposit_forward_tx_id(&outpoint, &forward_tx_id)?;
+        info!(
+            "Forwarded reusable address deposit {outpoint} to chain swap {} in tx {forward_tx_id}",
+            swap.id
         );
+        Ok(())
+    }
 
-        let swap_id = create_response.id.clone();
-        let invoice = Bolt11Invoice::from_str(&create_response.invoice)
-            .map_err(|err| PaymentError::invalid_invoice(&err.to_string()))?;
-        let payer_amount_sat =
-            invoice
-                .amount_milli_satoshis()
-                .ok_or(PaymentError::invalid_invoice(
-                    "Invoice does not contain an amount",
-                ))?
-                / 1000;
-        let destination_pubkey = invoice_pubkey(&invoice);
-
-        // Double check that the generated invoice includes our data
-        // https://docs.boltz.exchange/v/api/dont-trust-verify#lightning-invoice-verification
-        ensure_sdk!(
-            invoice.payment_hash().to_string() == preimage_hash,
-            PaymentError::invalid_invoice("Invalid preimage returned by swapper")
+    /// Creates an incoming chain swap for a reusable address deposit, or returns `None` if the
+    /// deposit is outside the chain swap limits once the forward tx fee is deducted.
+    async fn create_reusable_address_deposit_swap(
+        &self,
+        deposit: &(bitcoin::OutPoint, bitcoin::TxOut),
+        fee_rate_sat_per_vbyte: u64,
+    ) -> Result<Option<ChainSwap>, PaymentError> {
+        let outpoint = deposit.0;
+        let amount_sat =
+            ReusableAddress::forward_amount_sat(deposit.1.value.to_sat(), fee_rate_sat_per_vbyte)
+                .ok_or(PaymentError::AmountOutOfRange)?;
+        let pair = self.get_chain_pair(Direction::Incoming).await?;
+        if let Err(e) = self.validate_user_lockup_amount_for_chain_pair(&pair, amount_sat) {
+            warn!("Not forwarding reusable address deposit {outpoint} of {amount_sat} sat: {e:?}");
+            return Ok(None);
+        }
+        let fees_sat =
+            pair.fees.boltz(amount_sat) + pair.fees.claim_estimate() + pair.fees.server();
+        let swap = self
+            .create_receive_chain_swap(Some(amount_sat), fees_sat)
+            .await?;
+        info!(
+            "Created chain swap {} for reusable address deposit {outpoint}",
+            swap.id
         );
+        Ok(Some(swap))
+    }
 
-        let create_response_json = ReceiveSwap::from_boltz_struct_to_json(
-            &create_response,
-            &swap_id,
-            &invoice.to_string(),
-        )?;
-        let invoice_description = match invoice.description() {
-            Bolt11InvoiceDescription::Direct(msg) => Some(msg.to_string()),
-            Bolt11InvoiceDescription::Hash(_) => None,
-        };
+    /// Resolves an explicit Bitcoin fee rate or a [FeeSpeed] preset into a fee rate in sat/vB.
+    /// Returns `None` if neither is set.
+    async fn resolve_btc_fee_rate(
+        &self,
+        fee_rate_sat_per_vbyte: Option<u32>,
+        fee_speed: Option<FeeSpeed>,
+    ) -> Result<Option<u32>, SdkError> {
+        match (fee_rate_sat_per_vbyte, fee_speed) {
+            (Some(_), Some(_)) => Err(SdkError::generic(
+                "Only one of fee_rate_sat_per_vbyte and fee_speed can be set",
+            )),
+            (Some(fee_rate_sat_per_vbyte), None) => Ok(Some(fee_rate_sat_per_vbyte)),
+            (None, Some(fee_speed)) => {
+                let recommended_fees = self.recommended_fees().await?;
+                let fee_rate_sat_per_vbyte = fee_speed.fee_rate_sat_per_vbyte(&recommended_fees);
+                info!("Resolved fee speed {fee_speed:?} to {fee_rate_sat_per_vbyte} sat/vB");
+                Ok(Some(fee_rate_sat_per_vbyte))
+            }
+            (None, None) => Ok(None),
+        }
+    }
 
-        self.persister
-            .insert_or_update_receive_swap(&ReceiveSwap {
-                id: swap_id.clone(),
-                preimage: preimage_str,
-                create_response_json,
-                claim_private_key: keypair.display_secret().to_string(),
-                invoice: invoice.to_string(),
-                payment_hash: Some(preimage_hash),
-                destination_pubkey: Some(destination_pubkey),
-                timeout_block_height: create_response.timeout_block_height,
-                description: invoice_description,
-                payer_amount_sat,
-                receiver_amount_sat,
-                pair_fees_json: serde_json::to_string(&reverse_pair).map_err(|e| {
-                    PaymentError::generic(&format!("Failed to serialize ReversePair: {e:?}"))
-                })?,
-                claim_fees_sat: reverse_pair.fees.claim_estimate(),
-                lockup_tx_id: None,
-                claim_address: None,
-                claim_tx_id: None,
-                mrh_address: mrh_addr_str,
-                mrh_tx_id: None,
-                created_at: utils::now(),
-                state: PaymentState::Created,
-                metadata: Default::default(),
-            })
-            .map_err(|_| PaymentError::PersistError)?;
-        self.status_stream.track_swap_id(&swap_id)?;
-
-        Ok(ReceivePaymentResponse {
-            destination: invoice.to_string(),
-        })
+    async fn resolve_refund_fee_rate(
+        &self,
+        fee_rate_sat_per_vbyte: Option<u32>,
+        fee_speed: Option<FeeSpeed>,
+    ) -> Result<u32, SdkError> {
+        self.resolve_btc_fee_rate(fee_rate_sat_per_vbyte, fee_speed)
+            .await?
+            .ok_or(SdkError::generic(
+                "Either fee_rate_sat_per_vbyte or fee_speed must be set",
+            ))
     }
 
-    async fn create_receive_chain_swap(
+    /// Prepares to pay to a Bitcoin address via a chain swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PreparePayOnchainRequest] containing:
+    ///     * `amount` - which can be of two types: [PayAmount::Drain], which uses all funds,
+    ///       and [PayAmount::Bitcoin], which sets the amount the receiver should receive
+    ///     * `fee_rate_sat_per_vbyte` - the optional fee rate of the Bitcoin claim transaction. Defaults to the swapper estimated claim fee
+    ///     * `fee_speed` - the optional [FeeSpeed] preset of the Bitcoin claim transaction, as an alternative to `fee_rate_sat_per_vbyte`
+    pub async fn prepare_pay_onchain(
         &self,
-        user_lockup_amount_sat: Option<u64>,
-        fees_sat: u64,
-    ) -> Result<ChainSwap, PaymentError> {
-        let pair = self
-            .get_and_validate_chain_pair(Direction::Incoming, user_lockup_amount_sat)
+        req: &PreparePayOnchainRequest,
+    ) -> Result<PreparePayOnchainResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let get_info_res = self.get_info().await?;
+        let pair = self.get_chain_pair(Direction::Outgoing).await?;
+        let fee_rate_sat_per_vbyte = self
+            .resolve_btc_fee_rate(req.fee_rate_sat_per_vbyte, req.fee_speed)
             .await?;
-        let claim_fees_sat = pair.fees.claim_estimate();
+        let claim_fees_sat = match fee_rate_sat_per_vbyte {
+            Some(sat_per_vbyte) => ESTIMATED_BTC_CLAIM_TX_VSIZE * sat_per_vbyte as u64,
+            None => pair.clone().fees.claim_estimate(),
+        };
         let server_fees_sat = pair.fees.server();
-        // Service fees are 0 if this is a zero-amount swap
-        let service_fees_sat = user_lockup_amount_sat
-            .map(|user_lockup_amount_sat| pair.fees.boltz(user_lockup_amount_sat))
-            .unwrap_or_default();
+
+        info!("Preparing for onchain payment of kind: {:?}", req.amount);
+        let (payer_amount_sat, receiver_amount_sat, total_fees_sat) = match req.amount {
+            PayAmount::Bitcoin {
+                receiver_amount_sat: amount_sat,
+            } => {
+                let receiver_amount_sat = amount_sat;
+
+                let user_lockup_amount_sat_without_service_fee =
+                    receiver_amount_sat + claim_fees_sat + server_fees_sat;
+
+                // The resulting invoice amount contains the service fee, which is rounded up with ceil()
+                // Therefore, when calculating the user_lockup amount, we must also round it up with ceil()
+                let user_lockup_amount_sat = (user_lockup_amount_sat_without_service_fee as f64
+                    * 100.0
+                    / (100.0 - pair.fees.percentage))
+                    .ceil() as u64;
+                self.validate_user_lockup_amount_for_chain_pair(&pair, user_lockup_amount_sat)?;
+
+                let lockup_fees_sat = self.estimate_lockup_tx_fee(user_lockup_amount_sat).await?;
+
+                let boltz_fees_sat =
+                    user_lockup_amount_sat - user_lockup_amount_sat_without_service_fee;
+                let total_fees_sat =
+                    boltz_fees_sat + lockup_fees_sat + claim_fees_sat + server_fees_sat;
+                let payer_amount_sat = receiver_amount_sat + total_fees_sat;
+
+                (payer_amount_sat, receiver_amount_sat, total_fees_sat)
+            }
+            PayAmount::Drain => {
+                ensure_sdk!(
+                    get_info_res.wallet_info.pending_receive_sat == 0
+                        && get_info_res.wallet_info.pending_send_sat == 0,
+                    PaymentError::Generic {
+                        err: "Cannot drain while there are pending payments".to_string(),
+                    }
+                );
+                let payer_amount_sat = get_info_res.wallet_info.balance_sat;
+                let lockup_fees_sat = self.estimate_drain_tx_fee(None, None).await?;
+
+                let user_lockup_amount_sat = payer_amount_sat - lockup_fees_sat;
+                self.validate_user_lockup_amount_for_chain_pair(&pair, user_lockup_amount_sat)?;
+
+                let boltz_fees_sat = pair.fees.boltz(user_lockup_amount_sat);
+                let total_fees_sat =
+                    boltz_fees_sat + lockup_fees_sat + claim_fees_sat + server_fees_sat;
+                let receiver_amount_sat = payer_amount_sat - total_fees_sat;
+
+                (payer_amount_sat, receiver_amount_sat, total_fees_sat)
+            }
+            PayAmount::Asset { .. } | PayAmount::AssetUnits { .. } => {
+                return Err(PaymentError::asset_error(
+                    "Cannot send an asset to a Bitcoin address",
+                ))
+            }
+        };
+
+        let res = PreparePayOnchainResponse {
+            receiver_amount_sat,
+            claim_fees_sat,
+            total_fees_sat,
+        };
 
         ensure_sdk!(
-            fees_sat == service_fees_sat + claim_fees_sat + server_fees_sat,
-            PaymentError::InvalidOrExpiredFees
+            payer_amount_sat <= get_info_res.wallet_info.balance_sat,
+            PaymentError::InsufficientFunds
+        );
+
+        info!("Prepared onchain payment: {res:?}");
+        Ok(res)
+    }
+
+    /// Pays to a Bitcoin address via a chain swap.
+    ///
+    /// Depending on [Config]'s `payment_timeout_sec`, this function will return:
+    /// * [PaymentState::Pending] payment - if the payment could be initiated but didn't yet
+    ///   complete in this time
+    /// * [PaymentState::Complete] payment - if the payment was successfully completed in this time
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PayOnchainRequest] containing:
+    ///     * `address` - the Bitcoin address to pay to
+    ///     * `prepare_response` - the [PreparePayOnchainResponse] from calling [LiquidSdk::prepare_pay_onchain]
+    ///     * `spend_limit_override` - the optional token allowing the payment to exceed the
+    ///       [Config::spend_limits]
+    ///
+    /// # Errors
+    ///
+    /// * [PaymentError::PaymentTimeout] - if the payment could not be initiated in this time
+    /// * [PaymentError::SelfTransferNotSupported] - if the address is the lockup address of one
+    ///   of this wallet's incoming chain swaps
+    /// * [PaymentError::SpendLimitExceeded] - if the payment exceeds the [Config::spend_limits]
+    pub async fn pay_onchain(
+        &self,
+        req: &PayOnchainRequest,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        self.ensure_is_started().await?;
+        info!("Paying onchain, request = {req:?}");
+
+        let claim_address = self.validate_bitcoin_address(&req.address).await?;
+        self.ensure_onchain_send_is_not_self_transfer(&claim_address)?;
+        let balance_sat = self.get_info().await?.wallet_info.balance_sat;
+        let receiver_amount_sat = req.prepare_response.receiver_amount_sat;
+        let pair = self.get_chain_pair(Direction::Outgoing).await?;
+        let claim_fees_sat = req.prepare_response.claim_fees_sat;
+        let server_fees_sat = pair.fees.server();
+        let server_lockup_amount_sat = receiver_amount_sat + claim_fees_sat;
+
+        let user_lockup_amount_sat_without_service_fee =
+            receiver_amount_sat + claim_fees_sat + server_fees_sat;
+
+        // The resulting invoice amount contains the service fee, which is rounded up with ceil()
+        // Therefore, when calculating the user_lockup amount, we must also round it up with ceil()
+        let user_lockup_amount_sat = (user_lockup_amount_sat_without_service_fee as f64 * 100.0
+            / (100.0 - pair.fees.percentage))
+            .ceil() as u64;
+        let boltz_fee_sat = user_lockup_amount_sat - user_lockup_amount_sat_without_service_fee;
+        self.validate_user_lockup_amount_for_chain_pair(&pair, user_lockup_amount_sat)?;
+
+        let payer_amount_sat = req.prepare_response.total_fees_sat + receiver_amount_sat;
+        self.ensure_spend_limits(payer_amount_sat, req.spend_limit_override.as_deref())
+            .await?;
+
+        let lockup_fees_sat = match payer_amount_sat == balance_sat {
+            true => self.estimate_drain_tx_fee(None, None).await?,
+            false => self.estimate_lockup_tx_fee(user_lockup_amount_sat).await?,
+        };
+
+        self.ensure_current_fees(
+            req.prepare_response.total_fees_sat
+                == boltz_fee_sat + lockup_fees_sat + claim_fees_sat + server_fees_sat,
+        )
+        .await?;
+
+        ensure_sdk!(
+            payer_amount_sat <= balance_sat,
+            PaymentError::InsufficientFunds
         );
 
         let preimage = Preimage::new();
@@ -2764,46 +5100,42 @@ impl LiquidSdk {
         let create_response = self
             .swapper
             .create_chain_swap(CreateChainRequest {
-                from: "BTC".to_string(),
-                to: "L-BTC".to_string(),
+                from: "L-BTC".to_string(),
+                to: "BTC".to_string(),
                 preimage_hash: preimage.sha256,
                 claim_public_key: Some(claim_public_key),
                 refund_public_key: Some(refund_public_key),
-                user_lock_amount: user_lockup_amount_sat,
-                server_lock_amount: None,
+                user_lock_amount: None,
+                server_lock_amount: Some(server_lockup_amount_sat),
                 pair_hash: Some(pair.hash.clone()),
                 referral_id: None,
                 webhook,
             })
             .await?;
 
-        let swap_id = create_response.id.clone();
         let create_response_json =
-            ChainSwap::from_boltz_struct_to_json(&create_response, &swap_id)?;
+            ChainSwap::from_boltz_struct_to_json(&create_response, &create_response.id)?;
+        let swap_id = create_response.id;
 
-        let accept_zero_conf = user_lockup_amount_sat
-            .map(|user_lockup_amount_sat| user_lockup_amount_sat <= pair.limits.maximal_zero_conf)
-            .unwrap_or(false);
-        let receiver_amount_sat = user_lockup_amount_sat
-            .map(|user_lockup_amount_sat| user_lockup_amount_sat - fees_sat)
-            .unwrap_or(0);
+        let accept_zero_conf = server_lockup_amount_sat <= pair.limits.maximal_zero_conf;
+        let payer_amount_sat = req.prepare_response.total_fees_sat + receiver_amount_sat;
 
         let swap = ChainSwap {
             id: swap_id.clone(),
-            direction: Direction::Incoming,
-            claim_address: None,
+            direction: Direction::Outgoing,
+            claim_address: Some(claim_address),
             lockup_address: create_response.lockup_details.lockup_address,
             refund_address: None,
             timeout_block_height: create_response.lockup_details.timeout_block_height,
             preimage: preimage_str,
             description: Some("Bitcoin transfer".to_string()),
-            payer_amount_sat: user_lockup_amount_sat.unwrap_or(0),
+            payer_amount_sat,
             actual_payer_amount_sat: None,
             receiver_amount_sat,
             accepted_receiver_amount_sat: None,
             claim_fees_sat,
             pair_fees_json: serde_json::to_string(&pair).map_err(|e| {
-                PaymentError::generic(&format!("Failed to serialize incoming ChainPair: {e:?}"))
+                PaymentError::generic(&format!("Failed to serialize outgoing ChainPair: {e:?}"))
             })?,
             accept_zero_conf,
             create_response_json,
@@ -2816,1920 +5148,6170 @@ impl LiquidSdk {
             created_at: utils::now(),
             state: PaymentState::Created,
             auto_accepted_fees: false,
+            refundable_since: None,
             metadata: Default::default(),
         };
         self.persister.insert_or_update_chain_swap(&swap)?;
-        self.status_stream.track_swap_id(&swap.id)?;
-        Ok(swap)
+        self.status_stream.track_swap_id(&swap_id)?;
+
+        self.wait_for_payment_with_timeout(Swap::Chain(swap), accept_zero_conf)
+            .await
+            .map(|payment| SendPaymentResponse { payment })
     }
 
-    /// Receive from a Bitcoin transaction via a chain swap.
+    /// Prepares to convert between L-BTC and a Liquid asset, e.g. to rebalance the wallet
+    /// holdings between L-BTC and USDT.
     ///
-    /// If no `user_lockup_amount_sat` is specified, this is an amountless swap and `fees_sat` exclude
-    /// the service fees.
-    async fn receive_onchain(
+    /// # Arguments
+    ///
+    /// * `req` - the [PrepareConvertRequest] containing:
+    ///     * `from_asset_id` - the asset id to convert from
+    ///     * `to_asset_id` - the asset id to convert to
+    ///     * `amount` - the amount to convert, in base units of `from_asset_id`
+    ///
+    /// # Returns
+    /// Returns a [PrepareConvertResponse] containing:
+    ///     * `receiver_amount` - the estimated amount received, in base units of `to_asset_id`
+    ///     * `fees_sat` - the estimated L-BTC fees paid on top of `amount` when converting from
+    ///       L-BTC, in satoshi
+    pub async fn prepare_convert(
         &self,
-        user_lockup_amount_sat: Option<u64>,
-        fees_sat: u64,
-    ) -> Result<ReceivePaymentResponse, PaymentError> {
+        req: &PrepareConvertRequest,
+    ) -> Result<PrepareConvertResponse, PaymentError> {
         self.ensure_is_started().await?;
+        ensure_sdk!(req.amount > 0, PaymentError::AmountOutOfRange);
 
-        let swap = self
-            .create_receive_chain_swap(user_lockup_amount_sat, fees_sat)
+        let (from_asset, to_asset) =
+            self.validate_conversion_assets(&req.from_asset_id, &req.to_asset_id)?;
+        let mut session = SideSwapMarketSession::connect(&self.config).await?;
+        let quote = AssetConvertClient::new(&self.config, self.onchain_wallet.clone())
+            .quote(&mut session, from_asset, to_asset, req.amount)
             .await?;
-        let create_response = swap.get_boltz_create_response()?;
-        let address = create_response.lockup_details.lockup_address;
-
-        let amount = create_response.lockup_details.amount as f64 / 100_000_000.0;
-        let bip21 = create_response.lockup_details.bip21.unwrap_or(format!(
-            "bitcoin:{address}?amount={amount}&label=Send%20to%20L-BTC%20address"
-        ));
 
-        Ok(ReceivePaymentResponse { destination: bip21 })
+        let res = PrepareConvertResponse {
+            from_asset_id: req.from_asset_id.clone(),
+            to_asset_id: req.to_asset_id.clone(),
+            amount: req.amount,
+            receiver_amount: quote.receiver_amount,
+            fees_sat: quote.fees_sat,
+        };
+        info!("Prepared conversion: {res:?}");
+        Ok(res)
     }
 
-    /// List all failed chain swaps that need to be refunded.
-    /// They can be refunded by calling [LiquidSdk::prepare_refund] then [LiquidSdk::refund].
-    pub async fn list_refundables(&self) -> SdkResult<Vec<RefundableSwap>> {
-        let chain_swaps = self.persister.list_refundable_chain_swaps()?;
-
-        let mut lockup_script_pubkeys = vec![];
-        for swap in &chain_swaps {
-            let script_pubkey = swap.get_receive_lockup_swap_script_pubkey(self.config.network)?;
-            lockup_script_pubkeys.push(script_pubkey);
-        }
-        let lockup_scripts: Vec<&boltz_client::bitcoin::Script> = lockup_script_pubkeys
-            .iter()
-            .map(|s| s.as_script())
-            .collect();
-        let scripts_utxos = self
-            .bitcoin_chain_service
-            .get_scripts_utxos(&lockup_scripts)
-            .await?;
-
-        let mut refundables = vec![];
-        for (chain_swap, script_utxos) in chain_swaps.into_iter().zip(scripts_utxos) {
-            let swap_id = &chain_swap.id;
-            let amount_sat = script_utxos
-                .iter()
-                .filter_map(|utxo| utxo.as_bitcoin().cloned())
-                .map(|(_, txo)| txo.value.to_sat())
-                .sum();
-            info!("Incoming Chain Swap {swap_id} is refundable with {amount_sat} sats");
-
-            let refundable: RefundableSwap = chain_swap.to_refundable(amount_sat);
-            refundables.push(refundable);
-        }
-
-        Ok(refundables)
-    }
-
-    /// Prepares to refund a failed chain swap by calculating the refund transaction size and absolute fee.
+    /// Converts between L-BTC and a Liquid asset in a single atomic swap tx on the SideSwap swap
+    /// market. The conversion is recorded as a pair of Liquid payments, one sending the converted
+    /// asset and one receiving the other, whose [PaymentDetails::Liquid] `conversion` holds both
+    /// amounts exchanged.
     ///
     /// # Arguments
     ///
-    /// * `req` - the [PrepareRefundRequest] containing:
-    ///     * `swap_address` - the swap address to refund from [RefundableSwap::swap_address]
-    ///     * `refund_address` - the Bitcoin address to refund to
-    ///     * `fee_rate_sat_per_vbyte` - the fee rate at which to broadcast the refund transaction
-    pub async fn prepare_refund(
-        &self,
-        req: &PrepareRefundRequest,
-    ) -> SdkResult<PrepareRefundResponse> {
-        let refund_address = self
-            .validate_bitcoin_address(&req.refund_address)
-            .await
-            .map_err(|e| SdkError::Generic {
-                err: format!("Failed to validate refund address: {e}"),
-            })?;
-
-        let (tx_vsize, tx_fee_sat, refund_tx_id) = self
-            .chain_swap_handler
-            .prepare_refund(
-                &req.swap_address,
-                &refund_address,
-                req.fee_rate_sat_per_vbyte,
-            )
-            .await?;
-        Ok(PrepareRefundResponse {
-            tx_vsize,
-            tx_fee_sat,
-            last_refund_tx_id: refund_tx_id,
-        })
-    }
-
-    /// Refund a failed chain swap.
+    /// * `req` - the [ConvertRequest] containing:
+    ///     * `prepare_response` - the [PrepareConvertResponse] from calling [LiquidSdk::prepare_convert]
+    ///     * `spend_limit_override` - the optional token allowing the conversion to exceed the
+    ///       [Config::spend_limits]
     ///
-    /// # Arguments
+    /// # Returns
+    /// Returns a [SendPaymentResponse] containing the payment sending the converted asset
     ///
-    /// * `req` - the [RefundRequest] containing:
-    ///     * `swap_address` - the swap address to refund from [RefundableSwap::swap_address]
-    ///     * `refund_address` - the Bitcoin address to refund to
-    ///     * `fee_rate_sat_per_vbyte` - the fee rate at which to broadcast the refund transaction
-    pub async fn refund(&self, req: &RefundRequest) -> Result<RefundResponse, PaymentError> {
-        let refund_address = self
-            .validate_bitcoin_address(&req.refund_address)
-            .await
-            .map_err(|e| SdkError::Generic {
-                err: format!("Failed to validate refund address: {e}"),
-            })?;
+    /// # Errors
+    ///
+    /// * [PaymentError::Generic] - if the service quote is now worse than the prepared
+    ///   `receiver_amount`, or the fees exceed the prepared `fees_sat`
+    /// * [PaymentError::SpendLimitExceeded] - if the L-BTC converted exceeds the
+    ///   [Config::spend_limits]
+    pub async fn convert(&self, req: &ConvertRequest) -> Result<SendPaymentResponse, PaymentError> {
+        self.ensure_is_started().await?;
+        info!("Converting, request = {req:?}");
 
-        let refund_tx_id = self
-            .chain_swap_handler
-            .refund_incoming_swap(
-                &req.swap_address,
-                &refund_address,
-                req.fee_rate_sat_per_vbyte,
-                true,
-            )
-            .or_else(|e| {
-                warn!("Failed to initiate cooperative refund, switching to non-cooperative: {e:?}");
-                self.chain_swap_handler.refund_incoming_swap(
-                    &req.swap_address,
-                    &refund_address,
-                    req.fee_rate_sat_per_vbyte,
-                    false,
-                )
-            })
+        let PrepareConvertResponse {
+            from_asset_id,
+            to_asset_id,
+            amount,
+            receiver_amount,
+            fees_sat,
+        } = req.prepare_response.clone();
+        let (from_asset, to_asset) =
+            self.validate_conversion_assets(&from_asset_id, &to_asset_id)?;
+        // Only the L-BTC side of the conversion is subject to the limits
+        let spent_sat = match from_asset_id == self.config.lbtc_asset_id() {
+            true => amount + fees_sat,
+            false => fees_sat,
+        };
+        self.ensure_spend_limits(spent_sat, req.spend_limit_override.as_deref())
             .await?;
 
-        Ok(RefundResponse { refund_tx_id })
-    }
-
-    /// Rescans all expired chain swaps created from calling [LiquidSdk::receive_onchain] to check
-    /// if there are any confirmed funds available to refund.
-    ///
-    /// Since it bypasses the monitoring period, this should be called rarely or when the caller
-    /// expects there is a very old refundable chain swap. Otherwise, for relatively recent swaps
-    /// (within last [CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS] blocks = ~30 days), calling this
-    /// is not necessary as it happens automatically in the background.
-    pub async fn rescan_onchain_swaps(&self) -> SdkResult<()> {
-        let t0 = Instant::now();
-        let mut rescannable_swaps: Vec<Swap> = self
-            .persister
-            .list_chain_swaps()?
-            .into_iter()
-            .map(Into::into)
-            .collect();
-        self.recoverer
-            .recover_from_onchain(&mut rescannable_swaps)
+        let convert_client = AssetConvertClient::new(&self.config, self.onchain_wallet.clone());
+        let mut session = SideSwapMarketSession::connect(&self.config).await?;
+        let quote = convert_client
+            .quote(&mut session, from_asset, to_asset, amount)
             .await?;
-        let scanned_len = rescannable_swaps.len();
-        for swap in rescannable_swaps {
-            let swap_id = &swap.id();
-            if let Swap::Chain(chain_swap) = swap {
-                if let Err(e) = self.chain_swap_handler.update_swap(chain_swap) {
-                    error!("Error persisting rescanned Chain Swap {swap_id}: {e}");
-                }
-            }
-        }
-        info!(
-            "Rescanned {} chain swaps in {} seconds",
-            scanned_len,
-            t0.elapsed().as_millis()
-        );
-        Ok(())
-    }
-
-    fn validate_buy_bitcoin(&self, amount_sat: u64) -> Result<(), PaymentError> {
         ensure_sdk!(
-            self.config.network == LiquidNetwork::Mainnet,
-            PaymentError::invalid_network("Can only buy bitcoin on Mainnet")
+            quote.receiver_amount >= receiver_amount,
+            PaymentError::generic("Conversion amount is below the prepared amount")
         );
-        // The Moonpay API defines BTC amounts as having precision = 5, so only 5 decimals are considered
         ensure_sdk!(
-            amount_sat % 1_000 == 0,
-            PaymentError::generic("Can only buy sat amounts that are multiples of 1000")
+            quote.fees_sat <= fees_sat,
+            PaymentError::generic("Conversion fees exceed the prepared fees")
         );
-        Ok(())
+        let conversion = AssetConversion {
+            from_asset_id,
+            from_amount: amount,
+            to_asset_id,
+            to_amount: quote.receiver_amount,
+        };
+        let fees_sat = quote.fees_sat;
+        let receive_address = quote.receive_address.clone();
+        let tx_id = convert_client
+            .accept(&mut session, quote)
+            .await?
+            .to_string();
+        info!(
+            "Signed conversion tx {tx_id} of {} {} to {} {}",
+            conversion.from_amount,
+            conversion.from_asset_id,
+            conversion.to_amount,
+            conversion.to_asset_id
+        );
+
+        let payment = self.persist_conversion(&tx_id, conversion, receive_address, fees_sat)?;
+        self.emit_payment_updated(Some(tx_id)).await?; // Emit Pending event
+        Ok(SendPaymentResponse { payment })
     }
 
-    /// Prepares to buy Bitcoin via a chain swap.
+    /// Records the conversion tx, which is listed as a pair of payments: the one of the asset
+    /// exchanged, as picked up from the wallet, and the one of L-BTC.
     ///
-    /// # Arguments
-    ///
-    /// * `req` - the [PrepareBuyBitcoinRequest] containing:
-    ///     * `provider` - the [BuyBitcoinProvider] to use
-    ///     * `amount_sat` - the amount in satoshis to buy from the provider
-    pub async fn prepare_buy_bitcoin(
+    /// Returns the payment sending the converted asset.
+    fn persist_conversion(
         &self,
-        req: &PrepareBuyBitcoinRequest,
-    ) -> Result<PrepareBuyBitcoinResponse, PaymentError> {
-        self.validate_buy_bitcoin(req.amount_sat)?;
-
-        let res = self
-            .prepare_receive_payment(&PrepareReceiveRequest {
-                payment_method: PaymentMethod::BitcoinAddress,
-                amount: Some(ReceiveAmount::Bitcoin {
-                    payer_amount_sat: req.amount_sat,
-                }),
-            })
-            .await?;
-
-        let Some(ReceiveAmount::Bitcoin {
-            payer_amount_sat: amount_sat,
-        }) = res.amount
-        else {
-            return Err(PaymentError::Generic {
-                err: format!(
-                    "Error preparing receive payment, got amount: {:?}",
-                    res.amount
+        tx_id: &str,
+        conversion: AssetConversion,
+        receive_address: String,
+        fees_sat: u64,
+    ) -> Result<Payment, PaymentError> {
+        self.persister.insert_asset_conversion(tx_id, &conversion)?;
+        let (asset_id, amount, payment_type) =
+            match conversion.from_asset_id == self.config.lbtc_asset_id() {
+                true => (
+                    conversion.to_asset_id.clone(),
+                    conversion.to_amount,
+                    PaymentType::Receive,
                 ),
-            });
-        };
+                false => (
+                    conversion.from_asset_id.clone(),
+                    conversion.from_amount,
+                    PaymentType::Send,
+                ),
+            };
+        self.persister.insert_or_update_payment(
+            PaymentTxData {
+                tx_id: tx_id.to_string(),
+                timestamp: Some(utils::now()),
+                amount,
+                fees_sat,
+                payment_type,
+                is_confirmed: false,
+                unblinding_data: None,
+                asset_id,
+            },
+            Some(PaymentTxDetails {
+                tx_id: tx_id.to_string(),
+                destination: receive_address,
+                description: Some("Asset conversion".to_string()),
+                ..Default::default()
+            }),
+            false,
+        )?;
 
-        Ok(PrepareBuyBitcoinResponse {
-            provider: req.provider,
-            amount_sat,
-            fees_sat: res.fees_sat,
-        })
+        self.persister
+            .get_conversion_payments(tx_id)?
+            .into_iter()
+            .find(|payment| payment.payment_type == PaymentType::Send)
+            .ok_or(PaymentError::generic(
+                "Failed to fetch the conversion payment",
+            ))
     }
 
-    /// Generate a URL to a third party provider used to buy Bitcoin via a chain swap.
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [BuyBitcoinRequest] containing:
-    ///     * `prepare_response` - the [PrepareBuyBitcoinResponse] from calling [LiquidSdk::prepare_buy_bitcoin]
-    ///     * `redirect_url` - the optional redirect URL the provider should redirect to after purchase
-    pub async fn buy_bitcoin(&self, req: &BuyBitcoinRequest) -> Result<String, PaymentError> {
-        self.validate_buy_bitcoin(req.prepare_response.amount_sat)?;
-
-        let swap = self
-            .create_receive_chain_swap(
-                Some(req.prepare_response.amount_sat),
-                req.prepare_response.fees_sat,
-            )
-            .await?;
+    /// Ensures one of the conversion assets is L-BTC and the other a supported asset
+    fn validate_conversion_assets(
+        &self,
+        from_asset_id: &str,
+        to_asset_id: &str,
+    ) -> Result<(AssetId, AssetId), PaymentError> {
+        let lbtc_asset_id = self.config.lbtc_asset_id();
+        let asset_id = match (from_asset_id == lbtc_asset_id, to_asset_id == lbtc_asset_id) {
+            (true, false) => to_asset_id,
+            (false, true) => from_asset_id,
+            _ => {
+                return Err(PaymentError::asset_error(
+                    "Conversions must be between L-BTC and an asset",
+                ))
+            }
+        };
+        let asset_metadata =
+            self.persister
+                .get_asset_metadata(asset_id)?
+                .ok_or(PaymentError::AssetError {
+                    err: format!("Asset {asset_id} is not supported"),
+                })?;
+        ensure_sdk!(
+            !asset_metadata.is_amp,
+            PaymentError::AssetError {
+                err: format!("Converting AMP asset {asset_id} is not supported"),
+            }
+        );
 
-        Ok(self
-            .buy_bitcoin_service
-            .buy_bitcoin(
-                req.prepare_response.provider,
-                &swap,
-                req.redirect_url.clone(),
-            )
-            .await?)
+        let parse_asset_id = |asset_id: &str| {
+            AssetId::from_str(asset_id).map_err(|e| PaymentError::AssetError {
+                err: format!("Invalid asset id {asset_id}: {e}"),
+            })
+        };
+        Ok((parse_asset_id(from_asset_id)?, parse_asset_id(to_asset_id)?))
     }
 
-    pub(crate) async fn get_monitored_swaps_list(&self, partial_sync: bool) -> Result<Vec<Swap>> {
-        let receive_swaps = self
-            .persister
-            .list_recoverable_receive_swaps()?
-            .into_iter()
-            .map(Into::into)
-            .collect();
-        match partial_sync {
-            false => {
-                let bitcoin_height = self.bitcoin_chain_service.tip().await?;
-                let liquid_height = self.liquid_chain_service.tip().await?;
-                let final_swap_states = [PaymentState::Complete, PaymentState::Failed];
+    async fn wait_for_payment_with_timeout(
+        &self,
+        swap: Swap,
+        accept_zero_conf: bool,
+    ) -> Result<Payment, PaymentError> {
+        let timeout_fut = tokio::time::sleep(Duration::from_secs(self.config.payment_timeout_sec));
+        tokio::pin!(timeout_fut);
 
-                let send_swaps = self
-                    .persister
-                    .list_recoverable_send_swaps()?
-                    .into_iter()
-                    .map(Into::into)
-                    .collect();
-                let chain_swaps: Vec<Swap> = self
-                    .persister
-                    .list_chain_swaps()?
-                    .into_iter()
-                    .filter(|swap| match swap.direction {
-                        Direction::Incoming => {
-                            bitcoin_height
-                                <= swap.timeout_block_height
-                                    + CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS
-                        }
-                        Direction::Outgoing => {
-                            !final_swap_states.contains(&swap.state)
-                                && liquid_height <= swap.timeout_block_height
+        let expected_swap_id = swap.id();
+        let mut events_stream = self.event_manager.subscribe();
+        let mut maybe_payment: Option<Payment> = None;
+
+        loop {
+            tokio::select! {
+                _ = &mut timeout_fut => match maybe_payment {
+                    Some(payment) => return Ok(payment),
+                    None => {
+                        debug!("Timeout occurred without payment, set swap to timed out");
+                        let update_res = match swap {
+                            Swap::Send(_) => self.send_swap_handler.update_swap_info(&expected_swap_id, TimedOut, None, None, None),
+                            Swap::Chain(_) => self.chain_swap_handler.update_swap_info(&ChainSwapUpdate {
+                                    swap_id: expected_swap_id.clone(),
+                                    to_state: TimedOut,
+                                    ..Default::default()
+                                }),
+                            _ => Ok(())
+                        };
+                        return match update_res {
+                            Ok(_) => Err(PaymentError::PaymentTimeout),
+                            Err(_) => {
+                                // Not able to transition the payment state to TimedOut, which means the payment
+                                // state progressed but we didn't see the event before the timeout
+                                self.persister.get_payment(&expected_swap_id).ok().flatten().ok_or(PaymentError::generic("Payment not found"))
+                            }
                         }
-                    })
-                    .map(Into::into)
-                    .collect();
-                Ok([receive_swaps, send_swaps, chain_swaps].concat())
+                    },
+                },
+                event = events_stream.recv() => match event {
+                    Ok(SdkEvent::PaymentPending { details: payment }) => {
+                        let maybe_payment_swap_id = payment.details.get_swap_id();
+                        if matches!(maybe_payment_swap_id, Some(swap_id) if swap_id == expected_swap_id) {
+                            match accept_zero_conf {
+                                true => {
+                                    debug!("Received Send Payment pending event with zero-conf accepted");
+                                    return Ok(payment)
+                                }
+                                false => {
+                                    debug!("Received Send Payment pending event, waiting for confirmation");
+                                    maybe_payment = Some(payment);
+                                }
+                            }
+                        };
+                    },
+                    Ok(SdkEvent::PaymentSucceeded { details: payment }) => {
+                        let maybe_payment_swap_id = payment.details.get_swap_id();
+                        if matches!(maybe_payment_swap_id, Some(swap_id) if swap_id == expected_swap_id) {
+                            debug!("Received Send Payment succeed event");
+                            return Ok(payment);
+                        }
+                    },
+                    Ok(event) => debug!("Unhandled event waiting for payment: {event:?}"),
+                    Err(e) => debug!("Received error waiting for payment: {e:?}"),
+                }
             }
-            true => Ok(receive_swaps),
         }
     }
 
-    /// This method fetches the chain tx data (onchain and mempool) using LWK. For every wallet tx,
-    /// it inserts or updates a corresponding entry in our Payments table.
-    async fn sync_payments_with_chain_data(&self, partial_sync: bool) -> Result<()> {
-        let mut recoverable_swaps = self.get_monitored_swaps_list(partial_sync).await?;
-        let mut wallet_tx_map = self
-            .recoverer
-            .recover_from_onchain(&mut recoverable_swaps)
-            .await?;
+    /// Prepares to receive a Lightning payment via a reverse submarine swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PrepareReceiveRequest] containing:
+    ///     * `payment_method` - the supported payment methods; either an invoice, a Liquid address or a Bitcoin address
+    ///     * `amount` - The optional amount of type [ReceiveAmount] to be paid.
+    ///        - [ReceiveAmount::Bitcoin] which sets the amount in satoshi that should be paid
+    ///        - [ReceiveAmount::Asset] which sets the amount of an asset that should be paid
+    ///        - [ReceiveAmount::AssetUnits] which sets the amount of an asset that should be paid, in its base units
+    #[tracing::instrument(skip_all)]
+    pub async fn prepare_receive_payment(
+        &self,
+        req: &PrepareReceiveRequest,
+    ) -> Result<PrepareReceiveResponse, PaymentError> {
+        self.ensure_is_started().await?;
 
-        let all_wallet_tx_ids: HashSet<String> =
-            wallet_tx_map.keys().map(|txid| txid.to_string()).collect();
+        let mut min_payer_amount_sat = None;
+        let mut max_payer_amount_sat = None;
+        let mut swapper_feerate = None;
+        let fee_breakdown;
 
-        for swap in recoverable_swaps {
-            let swap_id = &swap.id();
+        let (amount, fiat_conversion) = match (&req.amount, &req.fiat_amount) {
+            (Some(_), Some(_)) => {
+                return Err(PaymentError::generic(
+                    "Only one of amount and fiat_amount can be set",
+                ))
+            }
+            (None, Some(fiat_amount)) => {
+                let fiat_conversion = self.convert_fiat_amount(fiat_amount).await?;
+                let amount = ReceiveAmount::Bitcoin {
+                    payer_amount_sat: fiat_conversion.amount_sat,
+                };
+                (Some(amount), Some(fiat_conversion))
+            }
+            (amount, None) => (amount.clone(), None),
+        };
 
-            // Update the payment wallet txs before updating the swap so the tx data is pulled into the payment
-            match swap {
-                Swap::Receive(receive_swap) => {
-                    let history_updates = vec![&receive_swap.claim_tx_id, &receive_swap.mrh_tx_id];
-                    for tx_id in history_updates
-                        .into_iter()
-                        .flatten()
-                        .collect::<Vec<&String>>()
-                    {
-                        if let Some(tx) =
-                            wallet_tx_map.remove(&lwk_wollet::elements::Txid::from_str(tx_id)?)
-                        {
-                            self.persister
-                                .insert_or_update_payment_with_wallet_tx(&tx)?;
-                        }
+        if let Some(expiry_secs) = req.expiry_secs {
+            ensure_sdk!(
+                req.payment_method == PaymentMethod::Lightning,
+                PaymentError::generic(
+                    "Invoice expiry can only be set when the payment method is Lightning"
+                )
+            );
+            ensure_sdk!(
+                (MIN_INVOICE_EXPIRY_SECS..=MAX_INVOICE_EXPIRY_SECS).contains(&expiry_secs),
+                PaymentError::generic(&format!(
+                    "Invoice expiry must be between {MIN_INVOICE_EXPIRY_SECS} and {MAX_INVOICE_EXPIRY_SECS} seconds"
+                ))
+            );
+        }
+
+        match req.payment_method {
+            PaymentMethod::Lightning => {
+                let payer_amount_sat = match amount {
+                    Some(ReceiveAmount::Asset { .. } | ReceiveAmount::AssetUnits { .. }) => {
+                        return Err(PaymentError::asset_error(
+                            "Cannot receive an asset when the payment method is Lightning",
+                        ));
                     }
-                    if let Err(e) = self.receive_swap_handler.update_swap(receive_swap) {
-                        error!("Error persisting recovered receive swap {swap_id}: {e}");
+                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => payer_amount_sat,
+                    None => {
+                        return Err(PaymentError::generic(
+                            "Bitcoin payer amount must be set when the payment method is Lightning",
+                        ));
                     }
-                }
-                Swap::Send(send_swap) => {
-                    let history_updates = vec![&send_swap.lockup_tx_id, &send_swap.refund_tx_id];
-                    for tx_id in history_updates
-                        .into_iter()
-                        .flatten()
-                        .collect::<Vec<&String>>()
-                    {
-                        if let Some(tx) =
-                            wallet_tx_map.remove(&lwk_wollet::elements::Txid::from_str(tx_id)?)
-                        {
-                            self.persister
-                                .insert_or_update_payment_with_wallet_tx(&tx)?;
-                        }
+                };
+                let reverse_pair = self
+                    .swapper
+                    .get_reverse_swap_pairs()
+                    .await?
+                    .ok_or(PaymentError::PairsNotFound)?;
+
+                // The swapper's lockup tx fee is fixed by the pair, the claim tx fee is estimated
+                fee_breakdown = FeeBreakdown {
+                    service_fee_sat: reverse_pair.fees.boltz(payer_amount_sat),
+                    swapper_network_fee_sat: 0,
+                    lockup_fee_sat: reverse_pair.fees.miner_fees.lockup,
+                    lockup_fee_is_estimate: false,
+                    claim_fee_sat: reverse_pair.fees.claim_estimate(),
+                    claim_fee_is_estimate: true,
+                    asset_fee: None,
+                };
+                let fees_sat = fee_breakdown.total();
+
+                ensure_sdk!(payer_amount_sat > fees_sat, PaymentError::AmountOutOfRange);
+
+                reverse_pair
+                    .limits
+                    .within(payer_amount_sat)
+                    .map_err(|_| PaymentError::AmountOutOfRange)?;
+
+                min_payer_amount_sat = Some(reverse_pair.limits.minimal);
+                max_payer_amount_sat = Some(reverse_pair.limits.maximal);
+                swapper_feerate = Some(reverse_pair.fees.percentage);
+
+                debug!(
+                    "Preparing Lightning Receive Swap with: payer_amount_sat {payer_amount_sat} sat, fees_sat {fees_sat} sat"
+                );
+            }
+            PaymentMethod::BitcoinAddress => {
+                let payer_amount_sat = match amount {
+                    Some(ReceiveAmount::Asset { .. } | ReceiveAmount::AssetUnits { .. }) => {
+                        return Err(PaymentError::asset_error(
+                            "Cannot receive an asset when the payment method is Bitcoin",
+                        ));
                     }
-                    if let Err(e) = self.send_swap_handler.update_swap(send_swap) {
-                        error!("Error persisting recovered send swap {swap_id}: {e}");
+                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => Some(payer_amount_sat),
+                    None => None,
+                };
+                let pair = self
+                    .get_and_validate_chain_pair(Direction::Incoming, payer_amount_sat)
+                    .await?;
+                let claim_fees_sat = pair.fees.claim_estimate();
+                let server_fees_sat = pair.fees.server();
+                let service_fees_sat = payer_amount_sat
+                    .map(|user_lockup_amount_sat| pair.fees.boltz(user_lockup_amount_sat))
+                    .unwrap_or_default();
+
+                min_payer_amount_sat = Some(pair.limits.minimal);
+                max_payer_amount_sat = Some(pair.limits.maximal);
+                swapper_feerate = Some(pair.fees.percentage);
+
+                // The swapper's fees are fixed by the pair, the claim tx fee is estimated. The
+                // payer's lockup tx is paid on top of the payer amount, so it's not part of the fees.
+                fee_breakdown = FeeBreakdown {
+                    service_fee_sat: service_fees_sat,
+                    swapper_network_fee_sat: server_fees_sat,
+                    lockup_fee_sat: 0,
+                    lockup_fee_is_estimate: false,
+                    claim_fee_sat: claim_fees_sat,
+                    claim_fee_is_estimate: true,
+                    asset_fee: None,
+                };
+                let fees_sat = fee_breakdown.total();
+                debug!("Preparing Chain Receive Swap with: payer_amount_sat {payer_amount_sat:?}, fees_sat {fees_sat}");
+            }
+            PaymentMethod::LiquidAddress => {
+                let (asset_id, payer_amount, payer_amount_sat) = match amount.clone() {
+                    Some(ReceiveAmount::Asset {
+                        payer_amount,
+                        asset_id,
+                    }) => (asset_id, payer_amount, None),
+                    Some(ReceiveAmount::AssetUnits { payer_amount }) => {
+                        ensure_sdk!(payer_amount.base_units > 0, PaymentError::AmountOutOfRange);
+                        (
+                            payer_amount.asset_id.clone(),
+                            Some(payer_amount.to_decimal()),
+                            None,
+                        )
                     }
-                }
-                Swap::Chain(chain_swap) => {
-                    let history_updates = match chain_swap.direction {
-                        Direction::Incoming => vec![&chain_swap.claim_tx_id],
-                        Direction::Outgoing => {
-                            vec![&chain_swap.user_lockup_tx_id, &chain_swap.refund_tx_id]
-                        }
-                    };
-                    for tx_id in history_updates
-                        .into_iter()
-                        .flatten()
-                        .collect::<Vec<&String>>()
-                    {
-                        if let Some(tx) =
-                            wallet_tx_map.remove(&lwk_wollet::elements::Txid::from_str(tx_id)?)
-                        {
-                            self.persister
-                                .insert_or_update_payment_with_wallet_tx(&tx)?;
-                        }
+                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => {
+                        (self.config.lbtc_asset_id(), None, Some(payer_amount_sat))
                     }
-                    if let Err(e) = self.chain_swap_handler.update_swap(chain_swap) {
-                        error!("Error persisting recovered Chain Swap {swap_id}: {e}");
+                    None => (self.config.lbtc_asset_id(), None, None),
+                };
+                if asset_id.ne(&self.config.lbtc_asset_id()) {
+                    let asset_metadata = self.persister.get_asset_metadata(&asset_id)?.ok_or(
+                        PaymentError::AssetError {
+                            err: format!("Asset {asset_id} is not supported"),
+                        },
+                    )?;
+                    if let Some(ReceiveAmount::AssetUnits { payer_amount }) = &amount {
+                        asset_metadata.validate_asset_amount(payer_amount)?;
                     }
+                    ensure_sdk!(
+                        !asset_metadata.is_amp || self.persister.get_amp_account()?.is_some(),
+                        PaymentError::AssetError {
+                            err: format!(
+                                "An AMP account must be registered to receive AMP asset {asset_id}"
+                            ),
+                        }
+                    );
                 }
-            };
-        }
+                if let Some(payer_amount) = payer_amount {
+                    ensure_sdk!(payer_amount > 0.0, PaymentError::AmountOutOfRange);
+                }
+                fee_breakdown = FeeBreakdown::default();
+                debug!("Preparing Liquid Receive with: asset_id {asset_id}, amount {payer_amount:?}, amount_sat {payer_amount_sat:?}");
+            }
+        };
 
-        let non_swap_wallet_tx_map = wallet_tx_map;
+        Ok(PrepareReceiveResponse {
+            amount,
+            fees_sat: fee_breakdown.total(),
+            fee_breakdown,
+            payment_method: req.payment_method.clone(),
+            min_payer_amount_sat,
+            max_payer_amount_sat,
+            swapper_feerate,
+            fiat_conversion,
+            expiry_secs: req.expiry_secs,
+        })
+    }
 
-        let payments = self
-            .persister
-            .get_payments_by_tx_id(&ListPaymentsRequest::default())?;
+    /// Prepares to receive a payment with every applicable [PaymentMethod] at once, so that their
+    /// fees and limits can be compared. An asset amount can only be received to a Liquid address.
+    ///
+    /// Payment methods unable to receive the amount, because it is outside of their limits or
+    /// their swap pair is unavailable, are left out of the returned quotes. Lightning is also
+    /// left out when no amount is set, as an invoice requires one. Any other error, e.g. failing
+    /// to reach the swapper, is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PrepareReceiveQuotesRequest] containing the optional `amount` or `fiat_amount`
+    pub async fn prepare_receive_payment_quotes(
+        &self,
+        req: &PrepareReceiveQuotesRequest,
+    ) -> Result<PrepareReceiveQuotesResponse, PaymentError> {
+        self.ensure_is_started().await?;
+        ensure_sdk!(
+            req.amount.is_none() || req.fiat_amount.is_none(),
+            PaymentError::generic("Only one of amount and fiat_amount can be set")
+        );
+
+        let payment_methods = match (&req.amount, &req.fiat_amount) {
+            (Some(ReceiveAmount::Asset { .. } | ReceiveAmount::AssetUnits { .. }), _) => {
+                vec![PaymentMethod::LiquidAddress]
+            }
+            (None, None) => vec![PaymentMethod::BitcoinAddress, PaymentMethod::LiquidAddress],
+            _ => vec![
+                PaymentMethod::Lightning,
+                PaymentMethod::BitcoinAddress,
+                PaymentMethod::LiquidAddress,
+            ],
+        };
+        let mut quotes = Vec::new();
+        for payment_method in payment_methods {
+            let prepare_req = PrepareReceiveRequest {
+                payment_method: payment_method.clone(),
+                amount: req.amount.clone(),
+                fiat_amount: req.fiat_amount.clone(),
+                expiry_secs: None,
+            };
+            match self.prepare_receive_payment(&prepare_req).await {
+                Ok(quote) => quotes.push(quote),
+                Err(e @ (PaymentError::AmountOutOfRange | PaymentError::PairsNotFound)) => {
+                    info!("Cannot receive the amount with {payment_method:?}: {e:?}")
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(PrepareReceiveQuotesResponse { quotes })
+    }
+
+    /// Receive a Lightning payment via a reverse submarine swap, a chain swap or via direct Liquid
+    /// payment.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ReceivePaymentRequest] containing:
+    ///     * `prepare_response` - the [PrepareReceiveResponse] from calling [LiquidSdk::prepare_receive_payment]
+    ///     * `description` - the optional payment description
+    ///     * `use_description_hash` - optional if true uses the hash of the description
+    ///     * `description_hash` - the optional precomputed hex-encoded SHA256 hash of the description,
+    ///       e.g. the hash of the LNURL-pay metadata. Cannot be combined with `description`.
+    ///
+    /// # Returns
+    ///
+    /// * A [ReceivePaymentResponse] containing:
+    ///     * `destination` - the final destination to be paid by the payer, either a BIP21 URI (Liquid or Bitcoin), a Liquid address or an invoice
+    #[tracing::instrument(skip_all)]
+    pub async fn receive_payment(
+        &self,
+        req: &ReceivePaymentRequest,
+    ) -> Result<ReceivePaymentResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let PrepareReceiveResponse {
+            payment_method,
+            amount,
+            fees_sat,
+            expiry_secs,
+            ..
+        } = &req.prepare_response;
+        ensure_sdk!(
+            req.claim_address.is_none() || *payment_method == PaymentMethod::Lightning,
+            PaymentError::generic(
+                "A claim address can only be set when the payment method is Lightning"
+            )
+        );
+
+        match payment_method {
+            PaymentMethod::Lightning => {
+                let amount_sat = match amount.clone() {
+                    Some(ReceiveAmount::Asset { .. } | ReceiveAmount::AssetUnits { .. }) => {
+                        return Err(PaymentError::asset_error(
+                            "Cannot receive an asset when the payment method is Lightning",
+                        ));
+                    }
+                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => payer_amount_sat,
+                    None => {
+                        return Err(PaymentError::generic(
+                            "Bitcoin payer amount must be set when the payment method is Lightning",
+                        ));
+                    }
+                };
+                let (description, description_hash) = match (
+                    req.description.clone(),
+                    req.use_description_hash.unwrap_or_default(),
+                    req.description_hash.clone(),
+                ) {
+                    (None, false, Some(description_hash)) => (
+                        None,
+                        Some(utils::parse_description_hash(&description_hash)?),
+                    ),
+                    (_, _, Some(_)) => {
+                        return Err(PaymentError::InvalidDescription {
+                            err: "Cannot set a description hash together with a description"
+                                .to_string(),
+                        })
+                    }
+                    (Some(description), true, None) => (
+                        None,
+                        Some(sha256::Hash::hash(description.as_bytes()).to_hex()),
+                    ),
+                    (_, false, None) => (req.description.clone(), None),
+                    _ => {
+                        return Err(PaymentError::InvalidDescription {
+                            err: "Missing payment description to hash".to_string(),
+                        })
+                    }
+                };
+                let claim_address = req
+                    .claim_address
+                    .clone()
+                    .or_else(|| self.config.claim_address.clone());
+                if let Some(claim_address) = &claim_address {
+                    utils::validate_claim_address(claim_address, self.config.network)?;
+                }
+                self.create_receive_swap(
+                    amount_sat,
+                    *fees_sat,
+                    description,
+                    description_hash,
+                    *expiry_secs,
+                    claim_address,
+                )
+                .await
+            }
+            PaymentMethod::BitcoinAddress => {
+                let amount_sat = match amount.clone() {
+                    Some(ReceiveAmount::Asset { .. } | ReceiveAmount::AssetUnits { .. }) => {
+                        return Err(PaymentError::asset_error(
+                            "Cannot receive an asset when the payment method is Bitcoin",
+                        ));
+                    }
+                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => Some(payer_amount_sat),
+                    None => None,
+                };
+                self.receive_onchain(amount_sat, *fees_sat).await
+            }
+            PaymentMethod::LiquidAddress => {
+                let lbtc_asset_id = self.config.lbtc_asset_id();
+                let (asset_id, amount, amount_sat) = match amount.clone() {
+                    Some(ReceiveAmount::Asset {
+                        asset_id,
+                        payer_amount,
+                    }) => (asset_id, payer_amount, None),
+                    Some(ReceiveAmount::AssetUnits { payer_amount }) => (
+                        payer_amount.asset_id.clone(),
+                        Some(payer_amount.to_decimal()),
+                        None,
+                    ),
+                    Some(ReceiveAmount::Bitcoin { payer_amount_sat }) => {
+                        (lbtc_asset_id.clone(), None, Some(payer_amount_sat))
+                    }
+                    None => (lbtc_asset_id.clone(), None, None),
+                };
+
+                // AMP assets can only be received into the registered AMP account
+                let is_amp_asset = self
+                    .persister
+                    .get_asset_metadata(&asset_id)?
+                    .is_some_and(|asset_metadata| asset_metadata.is_amp);
+                let address = match is_amp_asset {
+                    true => self.onchain_wallet.next_unused_amp_address().await?,
+                    false => self.onchain_wallet.next_unused_address().await?,
+                }
+                .to_string();
+                let receive_destination =
+                    if asset_id.ne(&lbtc_asset_id) || amount.is_some() || amount_sat.is_some() {
+                        LiquidAddressData {
+                            address: address.to_string(),
+                            network: self.config.network.into(),
+                            amount,
+                            amount_sat,
+                            asset_id: Some(asset_id),
+                            label: None,
+                            message: req.description.clone(),
+                        }
+                        .to_uri()
+                        .map_err(|e| PaymentError::Generic {
+                            err: format!("Could not build BIP21 URI: {e:?}"),
+                        })?
+                    } else {
+                        address
+                    };
+
+                Ok(ReceivePaymentResponse {
+                    destination: receive_destination,
+                })
+            }
+        }
+    }
+
+    async fn create_receive_swap(
+        &self,
+        payer_amount_sat: u64,
+        fees_sat: u64,
+        description: Option<String>,
+        description_hash: Option<String>,
+        expiry_secs: Option<u32>,
+        external_claim_address: Option<String>,
+    ) -> Result<ReceivePaymentResponse, PaymentError> {
+        let reverse_pair = self
+            .swapper
+            .get_reverse_swap_pairs()
+            .await?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let new_fees_sat = reverse_pair.fees.total(payer_amount_sat);
+        self.ensure_current_fees(fees_sat == new_fees_sat).await?;
+
+        debug!("Creating Receive Swap with: payer_amount_sat {payer_amount_sat} sat, fees_sat {fees_sat} sat");
+
+        let keypair = utils::generate_keypair();
+
+        let preimage = Preimage::new();
+        let preimage_str = preimage.to_string().ok_or(PaymentError::InvalidPreimage)?;
+        let preimage_hash = preimage.sha256.to_string();
+
+        // Address to be used for a BIP-21 direct payment
+        let mrh_addr = self.onchain_wallet.next_unused_address().await?;
+
+        // Signature of the claim public key of the SHA256 hash of the address for the direct payment
+        let mrh_addr_str = mrh_addr.to_string();
+        let mrh_addr_hash = sha256::Hash::hash(mrh_addr_str.as_bytes());
+        let mrh_addr_hash_sig =
+            keypair.sign_schnorr(Message::from_digest_slice(mrh_addr_hash.as_byte_array())?);
+
+        let receiver_amount_sat = payer_amount_sat - fees_sat;
+        let webhook_claim_status =
+            match receiver_amount_sat > self.config.zero_conf_max_amount_sat() {
+                true => RevSwapStates::TransactionConfirmed,
+                false => RevSwapStates::TransactionMempool,
+            };
+        let webhook = self.persister.get_webhook_url()?.map(|url| Webhook {
+            url,
+            hash_swap_id: Some(true),
+            status: Some(vec![webhook_claim_status]),
+        });
+
+        let v2_req = CreateReverseRequest {
+            invoice_amount: payer_amount_sat,
+            from: "BTC".to_string(),
+            to: "L-BTC".to_string(),
+            preimage_hash: preimage.sha256,
+            claim_public_key: keypair.public_key().into(),
+            description,
+            description_hash: description_hash.clone(),
+            address: Some(mrh_addr_str.clone()),
+            address_signature: Some(mrh_addr_hash_sig.to_hex()),
+            referral_id: None,
+            webhook,
+            invoice_expiry: expiry_secs.map(Into::into),
+        };
+        let create_response = self.swapper.create_receive_swap(v2_req).await?;
+
+        // Reserve this address until the timeout block height
+        self.persister.insert_or_update_reserved_address(
+            &mrh_addr_str,
+            create_response.timeout_block_height,
+        )?;
+
+        // Check if correct MRH was added to the invoice by Boltz
+        let (bip21_lbtc_address, _bip21_amount_btc) = self
+            .swapper
+            .check_for_mrh(&create_response.invoice)
+            .await?
+            .ok_or(PaymentError::receive_error("Invoice has no MRH"))?;
+        ensure_sdk!(
+            bip21_lbtc_address == mrh_addr_str,
+            PaymentError::receive_error("Invoice has incorrect address in MRH")
+        );
+
+        let swap_id = create_response.id.clone();
+        let invoice = Bolt11Invoice::from_str(&create_response.invoice)
+            .map_err(|err| PaymentError::invalid_invoice(&err.to_string()))?;
+        let payer_amount_sat =
+            invoice
+                .amount_milli_satoshis()
+                .ok_or(PaymentError::invalid_invoice(
+                    "Invoice does not contain an amount",
+                ))?
+                / 1000;
+        let destination_pubkey = invoice_pubkey(&invoice);
+
+        // Double check that the generated invoice includes our data
+        // https://docs.boltz.exchange/v/api/dont-trust-verify#lightning-invoice-verification
+        ensure_sdk!(
+            invoice.payment_hash().to_string() == preimage_hash,
+            PaymentError::invalid_invoice("Invalid preimage returned by swapper")
+        );
+        if let Some(description_hash) = &description_hash {
+            ensure_sdk!(
+                matches!(
+                    invoice.description(),
+                    Bolt11InvoiceDescription::Hash(hash) if &hash.0.to_string() == description_hash
+                ),
+                PaymentError::invalid_invoice("Invalid description hash returned by swapper")
+            );
+        }
+
+        let create_response_json = ReceiveSwap::from_boltz_struct_to_json(
+            &create_response,
+            &swap_id,
+            &invoice.to_string(),
+        )?;
+        let invoice_description = match invoice.description() {
+            Bolt11InvoiceDescription::Direct(msg) => Some(msg.to_string()),
+            Bolt11InvoiceDescription::Hash(_) => None,
+        };
+
+        self.persister
+            .insert_or_update_receive_swap(&ReceiveSwap {
+                id: swap_id.clone(),
+                preimage: preimage_str,
+                create_response_json,
+                claim_private_key: keypair.display_secret().to_string(),
+                invoice: invoice.to_string(),
+                payment_hash: Some(preimage_hash),
+                destination_pubkey: Some(destination_pubkey),
+                timeout_block_height: create_response.timeout_block_height,
+                description: invoice_description,
+                payer_amount_sat,
+                receiver_amount_sat,
+                pair_fees_json: serde_json::to_string(&reverse_pair).map_err(|e| {
+                    PaymentError::generic(&format!("Failed to serialize ReversePair: {e:?}"))
+                })?,
+                claim_fees_sat: reverse_pair.fees.claim_estimate(),
+                lockup_tx_id: None,
+                claim_address: None,
+                external_claim_address,
+                claim_tx_id: None,
+                mrh_address: mrh_addr_str,
+                mrh_tx_id: None,
+                created_at: utils::now(),
+                state: PaymentState::Created,
+                metadata: Default::default(),
+            })
+            .map_err(|_| PaymentError::PersistError)?;
+        self.status_stream.track_swap_id(&swap_id)?;
+
+        Ok(ReceivePaymentResponse {
+            destination: invoice.to_string(),
+        })
+    }
+
+    async fn create_receive_chain_swap(
+        &self,
+        user_lockup_amount_sat: Option<u64>,
+        fees_sat: u64,
+    ) -> Result<ChainSwap, PaymentError> {
+        let pair = self
+            .get_and_validate_chain_pair(Direction::Incoming, user_lockup_amount_sat)
+            .await?;
+        let claim_fees_sat = pair.fees.claim_estimate();
+        let server_fees_sat = pair.fees.server();
+        // Service fees are 0 if this is a zero-amount swap
+        let service_fees_sat = user_lockup_amount_sat
+            .map(|user_lockup_amount_sat| pair.fees.boltz(user_lockup_amount_sat))
+            .unwrap_or_default();
+
+        self.ensure_current_fees(fees_sat == service_fees_sat + claim_fees_sat + server_fees_sat)
+            .await?;
+
+        let preimage = Preimage::new();
+        let preimage_str = preimage.to_string().ok_or(PaymentError::InvalidPreimage)?;
+
+        let claim_keypair = utils::generate_keypair();
+        let claim_public_key = boltz_client::PublicKey {
+            compressed: true,
+            inner: claim_keypair.public_key(),
+        };
+        let refund_keypair = utils::generate_keypair();
+        let refund_public_key = boltz_client::PublicKey {
+            compressed: true,
+            inner: refund_keypair.public_key(),
+        };
+        let webhook = self.persister.get_webhook_url()?.map(|url| Webhook {
+            url,
+            hash_swap_id: Some(true),
+            status: Some(vec![
+                ChainSwapStates::TransactionFailed,
+                ChainSwapStates::TransactionLockupFailed,
+                ChainSwapStates::TransactionServerConfirmed,
+            ]),
+        });
+        let create_response = self
+            .swapper
+            .create_chain_swap(CreateChainRequest {
+                from: "BTC".to_string(),
+                to: "L-BTC".to_string(),
+                preimage_hash: preimage.sha256,
+                claim_public_key: Some(claim_public_key),
+                refund_public_key: Some(refund_public_key),
+                user_lock_amount: user_lockup_amount_sat,
+                server_lock_amount: None,
+                pair_hash: Some(pair.hash.clone()),
+                referral_id: None,
+                webhook,
+            })
+            .await?;
+
+        let swap_id = create_response.id.clone();
+        let create_response_json =
+            ChainSwap::from_boltz_struct_to_json(&create_response, &swap_id)?;
+
+        let accept_zero_conf = user_lockup_amount_sat
+            .map(|user_lockup_amount_sat| user_lockup_amount_sat <= pair.limits.maximal_zero_conf)
+            .unwrap_or(false);
+        let receiver_amount_sat = user_lockup_amount_sat
+            .map(|user_lockup_amount_sat| user_lockup_amount_sat - fees_sat)
+            .unwrap_or(0);
+
+        let swap = ChainSwap {
+            id: swap_id.clone(),
+            direction: Direction::Incoming,
+            claim_address: None,
+            lockup_address: create_response.lockup_details.lockup_address,
+            refund_address: None,
+            timeout_block_height: create_response.lockup_details.timeout_block_height,
+            preimage: preimage_str,
+            description: Some("Bitcoin transfer".to_string()),
+            payer_amount_sat: user_lockup_amount_sat.unwrap_or(0),
+            actual_payer_amount_sat: None,
+            receiver_amount_sat,
+            accepted_receiver_amount_sat: None,
+            claim_fees_sat,
+            pair_fees_json: serde_json::to_string(&pair).map_err(|e| {
+                PaymentError::generic(&format!("Failed to serialize incoming ChainPair: {e:?}"))
+            })?,
+            accept_zero_conf,
+            create_response_json,
+            claim_private_key: claim_keypair.display_secret().to_string(),
+            refund_private_key: refund_keypair.display_secret().to_string(),
+            server_lockup_tx_id: None,
+            user_lockup_tx_id: None,
+            claim_tx_id: None,
+            refund_tx_id: None,
+            created_at: utils::now(),
+            state: PaymentState::Created,
+            auto_accepted_fees: false,
+            refundable_since: None,
+            metadata: Default::default(),
+        };
+        self.persister.insert_or_update_chain_swap(&swap)?;
+        self.status_stream.track_swap_id(&swap.id)?;
+        Ok(swap)
+    }
+
+    /// Receive from a Bitcoin transaction via a chain swap.
+    ///
+    /// If no `user_lockup_amount_sat` is specified, this is an amountless swap and `fees_sat` exclude
+    /// the service fees.
+    async fn receive_onchain(
+        &self,
+        user_lockup_amount_sat: Option<u64>,
+        fees_sat: u64,
+    ) -> Result<ReceivePaymentResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let swap = self
+            .create_receive_chain_swap(user_lockup_amount_sat, fees_sat)
+            .await?;
+        let create_response = swap.get_boltz_create_response()?;
+        let address = create_response.lockup_details.lockup_address;
+
+        let amount = create_response.lockup_details.amount as f64 / 100_000_000.0;
+        let bip21 = create_response.lockup_details.bip21.unwrap_or(format!(
+            "bitcoin:{address}?amount={amount}&label=Send%20to%20L-BTC%20address"
+        ));
+
+        Ok(ReceivePaymentResponse { destination: bip21 })
+    }
+
+    /// List all failed chain swaps that need to be refunded.
+    /// They can be refunded by calling [LiquidSdk::prepare_refund] then [LiquidSdk::refund].
+    pub async fn list_refundables(&self) -> SdkResult<Vec<RefundableSwap>> {
+        let chain_swaps = self.persister.list_refundable_chain_swaps()?;
+
+        let mut lockup_script_pubkeys = vec![];
+        for swap in &chain_swaps {
+            let script_pubkey = swap.get_receive_lockup_swap_script_pubkey(self.config.network)?;
+            lockup_script_pubkeys.push(script_pubkey);
+        }
+        let lockup_scripts: Vec<&boltz_client::bitcoin::Script> = lockup_script_pubkeys
+            .iter()
+            .map(|s| s.as_script())
+            .collect();
+        let scripts_utxos = self
+            .bitcoin_chain_service
+            .get_scripts_utxos(&lockup_scripts)
+            .await?;
+
+        let mut refundables = vec![];
+        for (chain_swap, script_utxos) in chain_swaps.into_iter().zip(scripts_utxos) {
+            let swap_id = &chain_swap.id;
+            let amount_sat = script_utxos
+                .iter()
+                .filter_map(|utxo| utxo.as_bitcoin().cloned())
+                .map(|(_, txo)| txo.value.to_sat())
+                .sum();
+            info!("Incoming Chain Swap {swap_id} is refundable with {amount_sat} sats");
+
+            let refundable: RefundableSwap = chain_swap.to_refundable(amount_sat);
+            refundables.push(refundable);
+        }
+
+        Ok(refundables)
+    }
+
+    /// Prepares to refund a failed chain swap by calculating the refund transaction size and absolute fee,
+    /// as well as the amount received at the `refund_address` once the fee is deducted.
+    ///
+    /// Returns an error if the fee exceeds the refundable amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PrepareRefundRequest] containing:
+    ///     * `swap_address` - the swap address to refund from [RefundableSwap::swap_address]
+    ///     * `refund_address` - the Bitcoin address to refund to
+    ///     * `fee_rate_sat_per_vbyte` - the fee rate at which to broadcast the refund transaction
+    ///     * `fee_speed` - the [FeeSpeed] preset at which to broadcast the refund transaction,
+    ///       as an alternative to `fee_rate_sat_per_vbyte`
+    #[tracing::instrument(skip_all)]
+    pub async fn prepare_refund(
+        &self,
+        req: &PrepareRefundRequest,
+    ) -> SdkResult<PrepareRefundResponse> {
+        let refund_address = self
+            .validate_bitcoin_address(&req.refund_address)
+            .await
+            .map_err(|e| SdkError::Generic {
+                err: format!("Failed to validate refund address: {e}"),
+            })?;
+        let fee_rate_sat_per_vbyte = self
+            .resolve_refund_fee_rate(req.fee_rate_sat_per_vbyte, req.fee_speed)
+            .await?;
+
+        self.chain_swap_handler
+            .prepare_refund(&req.swap_address, &refund_address, fee_rate_sat_per_vbyte)
+            .await
+    }
+
+    /// Refund a failed chain swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [RefundRequest] containing:
+    ///     * `swap_address` - the swap address to refund from [RefundableSwap::swap_address]
+    ///     * `refund_address` - the Bitcoin address to refund to
+    ///     * `fee_rate_sat_per_vbyte` - the fee rate at which to broadcast the refund transaction
+    ///     * `fee_speed` - the [FeeSpeed] preset at which to broadcast the refund transaction,
+    ///       as an alternative to `fee_rate_sat_per_vbyte`
+    #[tracing::instrument(skip_all)]
+    pub async fn refund(&self, req: &RefundRequest) -> Result<RefundResponse, PaymentError> {
+        let refund_address = self
+            .validate_bitcoin_address(&req.refund_address)
+            .await
+            .map_err(|e| SdkError::Generic {
+                err: format!("Failed to validate refund address: {e}"),
+            })?;
+        let fee_rate_sat_per_vbyte = self
+            .resolve_refund_fee_rate(req.fee_rate_sat_per_vbyte, req.fee_speed)
+            .await?;
+
+        let refund_tx_id = self
+            .chain_swap_handler
+            .refund_incoming_swap(
+                &req.swap_address,
+                &refund_address,
+                fee_rate_sat_per_vbyte,
+                true,
+            )
+            .or_else(|e| {
+                warn!("Failed to initiate cooperative refund, switching to non-cooperative: {e:?}");
+                self.chain_swap_handler.refund_incoming_swap(
+                    &req.swap_address,
+                    &refund_address,
+                    fee_rate_sat_per_vbyte,
+                    false,
+                )
+            })
+            .await?;
+
+        Ok(RefundResponse { refund_tx_id })
+    }
+
+    /// Rescans all expired chain swaps created from calling [LiquidSdk::receive_onchain] to check
+    /// if there are any confirmed funds available to refund.
+    ///
+    /// Since it bypasses the monitoring period, this should be called rarely or when the caller
+    /// expects there is a very old refundable chain swap. Otherwise, for relatively recent swaps
+    /// (within last [CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS] blocks = ~30 days), calling this
+    /// is not necessary as it happens automatically in the background.
+    #[tracing::instrument(skip_all)]
+    pub async fn rescan_onchain_swaps(&self) -> SdkResult<()> {
+        let t0 = Instant::now();
+        let mut rescannable_swaps: Vec<Swap> = self
+            .persister
+            .list_chain_swaps()?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        self.recoverer
+            .recover_from_onchain(&mut rescannable_swaps)
+            .await?;
+        let scanned_len = rescannable_swaps.len();
+        for swap in rescannable_swaps {
+            let swap_id = &swap.id();
+            if let Swap::Chain(chain_swap) = swap {
+                if let Err(e) = self.chain_swap_handler.update_swap(chain_swap) {
+                    error!("Error persisting rescanned Chain Swap {swap_id}: {e}");
+                }
+            }
+        }
+        info!(
+            "Rescanned {} chain swaps in {} seconds",
+            scanned_len,
+            t0.elapsed().as_millis()
+        );
+        Ok(())
+    }
+
+    /// Lists the Send, Receive and Chain swaps matching the request, the most recently created
+    /// first, with their full state including timeout heights, transaction ids and scripts.
+    ///
+    /// Unlike [LiquidSdk::list_payments], all the swaps are listed, including those that never
+    /// resulted in a payment.
+    pub async fn list_swaps(&self, req: &ListSwapsRequest) -> SdkResult<Vec<SwapInfo>> {
+        self.ensure_is_started().await?;
+        Ok(self
+            .persister
+            .list_swaps(req)?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Lists the swaps that are still in progress, i.e. not yet completed, failed or refunded.
+    pub async fn list_ongoing_swaps(&self) -> SdkResult<Vec<SwapInfo>> {
+        self.ensure_is_started().await?;
+        Ok(self
+            .persister
+            .list_ongoing_swaps()?
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+
+    /// Gets the full state of a swap, including its scripts. Returns `None` if no swap with
+    /// this id exists.
+    pub async fn get_swap(&self, swap_id: &str) -> SdkResult<Option<SwapInfo>> {
+        self.ensure_is_started().await?;
+        Ok(self
+            .persister
+            .fetch_swap_by_id(swap_id)
+            .ok()
+            .map(Into::into))
+    }
+
+    /// Claims a Receive or Chain swap, without waiting for the swapper status update that
+    /// normally triggers the claim. When [Config::manual_claim] is set, this is how incoming
+    /// swaps are claimed.
+    pub async fn claim_swap(&self, swap_id: &str) -> Result<(), PaymentError> {
+        self.ensure_is_started().await?;
+        match self.persister.fetch_swap_by_id(swap_id)? {
+            Swap::Receive(_) => self.receive_swap_handler.claim(swap_id).await,
+            Swap::Chain(_) => self.chain_swap_handler.claim(swap_id).await,
+            Swap::Send(_) => Err(PaymentError::generic(
+                "Send swaps are claimed by the swapper",
+            )),
+        }
+    }
+
+    /// Refunds a failed Send swap or outgoing Chain swap, without waiting for the background
+    /// refund checks. A cooperative refund is attempted first, falling back to a non-cooperative
+    /// refund, which is only possible once the swap has expired.
+    ///
+    /// Incoming Chain swaps are refunded with [LiquidSdk::refund].
+    #[tracing::instrument(skip(self))]
+    pub async fn refund_swap(&self, swap_id: &str) -> Result<RefundResponse, PaymentError> {
+        self.ensure_is_started().await?;
+        let refund_tx_id = match self.persister.fetch_swap_by_id(swap_id)? {
+            Swap::Send(swap) => {
+                ensure_sdk!(
+                    swap.refund_tx_id.is_none(),
+                    PaymentError::generic(&format!(
+                        "A refund tx for Send Swap {swap_id} was already broadcast"
+                    ))
+                );
+                let refund_tx_id = self
+                    .send_swap_handler
+                    .refund(&swap, true)
+                    .or_else(|e| {
+                        warn!("Failed to initiate cooperative refund, switching to non-cooperative: {e:?}");
+                        self.send_swap_handler.refund(&swap, false)
+                    })
+                    .await?;
+                self.send_swap_handler.update_swap_info(
+                    swap_id,
+                    RefundPending,
+                    None,
+                    None,
+                    Some(&refund_tx_id),
+                )?;
+                refund_tx_id
+            }
+            Swap::Chain(swap) if swap.direction == Direction::Outgoing => {
+                let refund_tx_id = self
+                    .chain_swap_handler
+                    .refund_outgoing_swap(&swap, true)
+                    .or_else(|e| {
+                        warn!("Failed to initiate cooperative refund, switching to non-cooperative: {e:?}");
+                        self.chain_swap_handler.refund_outgoing_swap(&swap, false)
+                    })
+                    .await?;
+                self.chain_swap_handler.update_swap_info(&ChainSwapUpdate {
+                    swap_id: swap_id.to_string(),
+                    to_state: RefundPending,
+                    refund_tx_id: Some(refund_tx_id.clone()),
+                    ..Default::default()
+                })?;
+                refund_tx_id
+            }
+            Swap::Chain(_) => {
+                return Err(PaymentError::generic(
+                    "Incoming Chain swaps are refunded with refund",
+                ))
+            }
+            Swap::Receive(_) => {
+                return Err(PaymentError::generic(
+                    "Receive swaps are refunded by the swapper",
+                ))
+            }
+        };
+        Ok(RefundResponse { refund_tx_id })
+    }
+
+    /// Rebroadcasts the Liquid transactions of a swap that are known to the wallet, which can be
+    /// the lockup, claim or refund transactions. Returns the ids of the rebroadcast transactions.
+    pub async fn rebroadcast_swap_txs(&self, swap_id: &str) -> Result<Vec<String>, PaymentError> {
+        self.ensure_is_started().await?;
+        let swap: SwapInfo = self.persister.fetch_swap_by_id(swap_id)?.into();
+        let wallet_txs = self.onchain_wallet.transactions_by_tx_id().await?;
+
+        let mut tx_ids = vec![];
+        for tx_id in [swap.user_lockup_tx_id, swap.claim_tx_id, swap.refund_tx_id]
+            .into_iter()
+            .flatten()
+        {
+            let Some(wallet_tx) = lwk_wollet::elements::Txid::from_str(&tx_id)
+                .ok()
+                .and_then(|txid| wallet_txs.get(&txid))
+            else {
+                continue;
+            };
+            self.liquid_chain_service.broadcast(&wallet_tx.tx).await?;
+            info!("Rebroadcast tx {tx_id} for swap {swap_id}");
+            tx_ids.push(tx_id);
+        }
+        Ok(tx_ids)
+    }
+
+    fn validate_buy_bitcoin(&self, amount_sat: u64) -> Result<(), PaymentError> {
+        ensure_sdk!(
+            self.config.network == LiquidNetwork::Mainnet,
+            PaymentError::invalid_network("Can only buy bitcoin on Mainnet")
+        );
+        // The Moonpay API defines BTC amounts as having precision = 5, so only 5 decimals are considered
+        ensure_sdk!(
+            amount_sat % 1_000 == 0,
+            PaymentError::generic("Can only buy sat amounts that are multiples of 1000")
+        );
+        Ok(())
+    }
+
+    /// Prepares to buy Bitcoin via a chain swap.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PrepareBuyBitcoinRequest] containing:
+    ///     * `provider` - the [BuyBitcoinProvider] to use
+    ///     * `amount_sat` - the amount in satoshis to buy from the provider
+    pub async fn prepare_buy_bitcoin(
+        &self,
+        req: &PrepareBuyBitcoinRequest,
+    ) -> Result<PrepareBuyBitcoinResponse, PaymentError> {
+        self.validate_buy_bitcoin(req.amount_sat)?;
+
+        let res = self
+            .prepare_receive_payment(&PrepareReceiveRequest {
+                payment_method: PaymentMethod::BitcoinAddress,
+                amount: Some(ReceiveAmount::Bitcoin {
+                    payer_amount_sat: req.amount_sat,
+                }),
+                fiat_amount: None,
+                expiry_secs: None,
+            })
+            .await?;
+
+        let Some(ReceiveAmount::Bitcoin {
+            payer_amount_sat: amount_sat,
+        }) = res.amount
+        else {
+            return Err(PaymentError::Generic {
+                err: format!(
+                    "Error preparing receive payment, got amount: {:?}",
+                    res.amount
+                ),
+            });
+        };
+
+        Ok(PrepareBuyBitcoinResponse {
+            provider: req.provider,
+            amount_sat,
+            fees_sat: res.fees_sat,
+        })
+    }
+
+    /// Generate a URL to a third party provider used to buy Bitcoin via a chain swap.
+    ///
+    /// Once the provider deposits the Bitcoin, the resulting payment records the provider in
+    /// its [PaymentDetails::Bitcoin] `buy_bitcoin_provider`.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [BuyBitcoinRequest] containing:
+    ///     * `prepare_response` - the [PrepareBuyBitcoinResponse] from calling [LiquidSdk::prepare_buy_bitcoin]
+    ///     * `redirect_url` - the optional redirect URL the provider should redirect to after purchase
+    pub async fn buy_bitcoin(&self, req: &BuyBitcoinRequest) -> Result<String, PaymentError> {
+        self.validate_buy_bitcoin(req.prepare_response.amount_sat)?;
+
+        let swap = self
+            .create_receive_chain_swap(
+                Some(req.prepare_response.amount_sat),
+                req.prepare_response.fees_sat,
+            )
+            .await?;
+        self.persister
+            .insert_buy_bitcoin_swap(&swap.id, req.prepare_response.provider)?;
+
+        Ok(self
+            .buy_bitcoin_service
+            .buy_bitcoin(
+                req.prepare_response.provider,
+                &swap,
+                req.redirect_url.clone(),
+            )
+            .await?)
+    }
+
+    pub(crate) async fn get_monitored_swaps_list(&self, partial_sync: bool) -> Result<Vec<Swap>> {
+        let receive_swaps = self
+            .persister
+            .list_recoverable_receive_swaps()?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+        match partial_sync {
+            false => {
+                let bitcoin_height = self.bitcoin_chain_service.tip().await?;
+                let liquid_height = self.liquid_chain_service.tip().await?;
+                let final_swap_states = [PaymentState::Complete, PaymentState::Failed];
+
+                let send_swaps = self
+                    .persister
+                    .list_recoverable_send_swaps()?
+                    .into_iter()
+                    .map(Into::into)
+                    .collect();
+                let chain_swaps: Vec<Swap> = self
+                    .persister
+                    .list_chain_swaps()?
+                    .into_iter()
+                    .filter(|swap| match swap.direction {
+                        Direction::Incoming => {
+                            bitcoin_height
+                                <= swap.timeout_block_height
+                                    + CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS
+                        }
+                        Direction::Outgoing => {
+                            !final_swap_states.contains(&swap.state)
+                                && liquid_height <= swap.timeout_block_height
+                        }
+                    })
+                    .map(Into::into)
+                    .collect();
+                Ok([receive_swaps, send_swaps, chain_swaps].concat())
+            }
+            true => Ok(receive_swaps),
+        }
+    }
+
+    /// Treats an outgoing wallet tx as unconfirmed until it has the confirmations required by
+    /// [Config::outgoing_min_confirmations], so that its payment stays Pending until then
+    fn apply_outgoing_min_confirmations(&self, tx: &WalletTx, tip_height: u32) -> WalletTx {
+        let mut tx = tx.clone();
+        let is_outgoing = tx.balance.values().any(|balance| *balance < 0);
+        if let Some(height) = tx.height {
+            let confirmations = tip_height.saturating_sub(height) + 1;
+            if is_outgoing && confirmations < self.config.outgoing_min_confirmations() {
+                tx.height = None;
+            }
+        }
+        tx
+    }
+
+    /// This method fetches the chain tx data (onchain and mempool) using LWK. For every wallet tx,
+    /// it inserts or updates a corresponding entry in our Payments table.
+    /// Returns the sync statistics, without its duration.
+    ///
+    /// Completed payments are only rolled back for a reorg if `is_wallet_scanned`, since a wallet
+    /// that failed to scan may just be missing or behind on their txs.
+    async fn sync_payments_with_chain_data(
+        &self,
+        partial_sync: bool,
+        is_wallet_scanned: bool,
+    ) -> Result<SyncResponse> {
+        // Simulated swaps have no onchain data to recover them from
+        let mut recoverable_swaps = match self.config.simulation {
+            Some(_) => vec![],
+            None => self.get_monitored_swaps_list(partial_sync).await?,
+        };
+        let swaps_before_recovery = recoverable_swaps.clone();
+        let mut wallet_tx_map = self
+            .recoverer
+            .recover_from_onchain(&mut recoverable_swaps)
+            .await?;
+        let updated_swap_count = recoverable_swaps
+            .iter()
+            .zip(swaps_before_recovery.iter())
+            .filter(|(swap, swap_before_recovery)| swap != swap_before_recovery)
+            .count() as u32;
+        let mut new_tx_count = 0;
+
+        let all_wallet_tx_ids: HashSet<String> =
+            wallet_tx_map.keys().map(|txid| txid.to_string()).collect();
+        let unconfirmed_wallet_tx_ids: HashSet<String> = wallet_tx_map
+            .values()
+            .filter(|tx| tx.height.is_none())
+            .map(|tx| tx.txid.to_string())
+            .collect();
+
+        for swap in recoverable_swaps {
+            let swap_id = &swap.id();
+
+            // Update the payment wallet txs before updating the swap so the tx data is pulled into the payment
+            match swap {
+                Swap::Receive(receive_swap) => {
+                    let history_updates = vec![&receive_swap.claim_tx_id, &receive_swap.mrh_tx_id];
+                    for tx_id in history_updates
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<&String>>()
+                    {
+                        if let Some(tx) =
+                            wallet_tx_map.remove(&lwk_wollet::elements::Txid::from_str(tx_id)?)
+                        {
+                            self.persister
+                                .insert_or_update_payment_with_wallet_tx(&tx)?;
+                        }
+                    }
+                    if let Err(e) = self.receive_swap_handler.update_swap(receive_swap) {
+                        error!("Error persisting recovered receive swap {swap_id}: {e}");
+                    }
+                }
+                Swap::Send(send_swap) => {
+                    let history_updates = vec![&send_swap.lockup_tx_id, &send_swap.refund_tx_id];
+                    for tx_id in history_updates
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<&String>>()
+                    {
+                        if let Some(tx) =
+                            wallet_tx_map.remove(&lwk_wollet::elements::Txid::from_str(tx_id)?)
+                        {
+                            self.persister
+                                .insert_or_update_payment_with_wallet_tx(&tx)?;
+                        }
+                    }
+                    if let Err(e) = self.send_swap_handler.update_swap(send_swap) {
+                        error!("Error persisting recovered send swap {swap_id}: {e}");
+                    }
+                }
+                Swap::Chain(chain_swap) => {
+                    let history_updates = match chain_swap.direction {
+                        Direction::Incoming => vec![&chain_swap.claim_tx_id],
+                        Direction::Outgoing => {
+                            vec![&chain_swap.user_lockup_tx_id, &chain_swap.refund_tx_id]
+                        }
+                    };
+                    for tx_id in history_updates
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<&String>>()
+                    {
+                        if let Some(tx) =
+                            wallet_tx_map.remove(&lwk_wollet::elements::Txid::from_str(tx_id)?)
+                        {
+                            self.persister
+                                .insert_or_update_payment_with_wallet_tx(&tx)?;
+                        }
+                    }
+                    if let Err(e) = self.chain_swap_handler.update_swap(chain_swap) {
+                        error!("Error persisting recovered Chain Swap {swap_id}: {e}");
+                    }
+                }
+            };
+        }
+
+        let non_swap_wallet_tx_map = wallet_tx_map;
+
+        let payments = self
+            .persister
+            .get_payments_by_tx_id(&ListPaymentsRequest::default())?;
 
         // We query only these that may need update, should be a fast query.
         let unconfirmed_payment_txs_data = self.persister.list_unconfirmed_payment_txs_data()?;
         let unconfirmed_txs_by_id: HashMap<String, PaymentTxData> = unconfirmed_payment_txs_data
             .into_iter()
-            .map(|tx| (tx.tx_id.clone(), tx))
-            .collect::<HashMap<String, PaymentTxData>>();
+            .map(|tx| (tx.tx_id.clone(), tx))
+            .collect::<HashMap<String, PaymentTxData>>();
+        let seen_in_wallet_tx_ids = self.persister.list_unconfirmed_wallet_tx_ids()?;
+
+        // The completed payments whose confirmed tx was reorged out of the chain, either back to
+        // the mempool or no longer in the wallet. Swap claim txs were removed from the wallet tx
+        // map above, so the check is made against all the wallet txs. Only a tx that is actually
+        // unconfirmed was reorged: a confirmed outgoing tx still short of the required
+        // confirmations is not. Once rolled back, the txs are dropped if they don't reappear.
+        let reorged_payments: Vec<&Payment> = payments
+            .iter()
+            .filter(|(tx_id, payment)| {
+                is_wallet_scanned
+                    && payment.status == Complete
+                    && payment.tx_id.as_ref() == Some(*tx_id)
+                    && (matches!(payment.details, PaymentDetails::Liquid { .. })
+                        || payment.payment_type == PaymentType::Receive)
+                    && (unconfirmed_wallet_tx_ids.contains(*tx_id)
+                        || !all_wallet_tx_ids.contains(*tx_id))
+            })
+            .map(|(_, payment)| payment)
+            .collect();
+        let mut reorged_tx_ids = HashSet::new();
+        for payment in reorged_payments {
+            if let Err(e) = self.rollback_reorged_payment(payment).await {
+                warn!(
+                    "Failed to roll back reorged payment {:?}: {e:?}",
+                    payment.tx_id
+                );
+            }
+            reorged_tx_ids.extend(payment.tx_id.clone());
+        }
+
+        let wallet_tip = self.onchain_wallet.tip().await;
+        for wallet_tx in non_swap_wallet_tx_map.values() {
+            let tx_id = wallet_tx.txid.to_string();
+            if reorged_tx_ids.contains(&tx_id) {
+                continue;
+            }
+            let maybe_payment = payments.get(&tx_id);
+            let tx = &self.apply_outgoing_min_confirmations(wallet_tx, wallet_tip);
+            let mut updated = false;
+            match maybe_payment {
+                // When no payment is found or its a Liquid payment
+                None
+                | Some(Payment {
+                    details: PaymentDetails::Liquid { .. },
+                    ..
+                }) => {
+                    let updated_needed = maybe_payment
+                        .is_none_or(|payment| payment.status == Pending && tx.height.is_some());
+                    if updated_needed {
+                        // An unknown tx which needs inserting or a known Liquid payment tx
+                        // that was in the mempool, but is now confirmed
+                        if maybe_payment.is_none() {
+                            new_tx_count += 1;
+                        }
+                        self.persister.insert_or_update_payment_with_wallet_tx(tx)?;
+                        self.emit_payment_updated(Some(tx_id.clone())).await?;
+                        updated = true
+                    }
+                }
+
+                _ => {}
+            }
+            if !updated && unconfirmed_txs_by_id.contains_key(&tx_id) && tx.height.is_some() {
+                // An unconfirmed tx that was not found in the payments table
+                self.persister.insert_or_update_payment_with_wallet_tx(tx)?;
+            }
+        }
+
+        let unknown_unconfirmed_txs: Vec<_> = unconfirmed_txs_by_id
+            .iter()
+            .filter(|(txid, _)| !all_wallet_tx_ids.contains(*txid))
+            .map(|(_, tx)| tx)
+            .collect();
+
+        for unknown_unconfirmed_tx in unknown_unconfirmed_txs {
+            let is_dropped = match seen_in_wallet_tx_ids.contains(&unknown_unconfirmed_tx.tx_id) {
+                // An unconfirmed tx that was seen in the wallet, which was evicted from the
+                // mempool or double-spent since. It's only dropped once the wallet is scanned, as
+                // it may otherwise just be missing from a stale wallet.
+                true => is_wallet_scanned,
+                // A pseudo-tx inserted on broadcast, which is kept for a while to allow its
+                // propagation through the network
+                false => unknown_unconfirmed_tx.timestamp.is_none_or(|t| {
+                    (utils::now().saturating_sub(t))
+                        > NETWORK_PROPAGATION_GRACE_PERIOD.as_secs() as u32
+                }),
+            };
+            if is_dropped {
+                self.persister
+                    .delete_payment_tx_data(&unknown_unconfirmed_tx.tx_id)?;
+                info!(
+                    "Found an unknown unconfirmed tx and deleted it. Txid: {}",
+                    unknown_unconfirmed_tx.tx_id
+                );
+                self.notify_event_listeners(SdkEvent::TransactionDropped {
+                    tx_id: unknown_unconfirmed_tx.tx_id.clone(),
+                })
+                .await;
+            } else {
+                debug!(
+                    "Found an unknown unconfirmed tx that was inserted at {:?}. \
+                Keeping it to allow propagation through the network. Txid: {}",
+                    unknown_unconfirmed_tx.timestamp, unknown_unconfirmed_tx.tx_id
+                )
+            }
+        }
+
+        // Report the txs still unconfirmed after the alert threshold, once per tx
+        let alert_sec = self.config.unconfirmed_tx_alert_sec();
+        for tx in unconfirmed_txs_by_id.values() {
+            let Some(unconfirmed_sec) = tx.timestamp.map(|t| utils::now().saturating_sub(t)) else {
+                continue;
+            };
+            if unconfirmed_sec < alert_sec || !unconfirmed_wallet_tx_ids.contains(&tx.tx_id) {
+                continue;
+            }
+            if self
+                .alerted_unconfirmed_tx_ids
+                .write()
+                .await
+                .insert(tx.tx_id.clone())
+            {
+                warn!(
+                    "Tx is still unconfirmed after {unconfirmed_sec} seconds. Txid: {}",
+                    tx.tx_id
+                );
+                self.notify_event_listeners(SdkEvent::TransactionUnconfirmed {
+                    tx_id: tx.tx_id.clone(),
+                    unconfirmed_sec,
+                })
+                .await;
+            }
+        }
+
+        self.update_wallet_info().await?;
+        Ok(SyncResponse {
+            new_tx_count,
+            updated_swap_count,
+            ..Default::default()
+        })
+    }
+
+    /// Rolls a completed payment back to Pending after its confirmed tx was reorged out of the
+    /// chain, so it is monitored again until the tx confirms. Only the payments completed by the
+    /// confirmation of their tx are rolled back: Liquid payments and receive swaps.
+    async fn rollback_reorged_payment(&self, payment: &Payment) -> Result<()> {
+        let Some(tx_id) = &payment.tx_id else {
+            return Ok(());
+        };
+        let maybe_swap = match (&payment.details, payment.payment_type) {
+            (PaymentDetails::Liquid { .. }, _) => None,
+            (_, PaymentType::Receive) => match payment.details.get_swap_id() {
+                Some(swap_id) => Some(self.persister.fetch_swap_by_id(&swap_id)?),
+                None => return Ok(()),
+            },
+            _ => return Ok(()),
+        };
+
+        warn!("The tx of a completed payment was reorged out of the chain, rolling the payment back to Pending. Txid: {tx_id}");
+        // The swap is rolled back first, so nothing is changed if this fails
+        match maybe_swap.clone() {
+            Some(Swap::Receive(swap)) => self.receive_swap_handler.update_swap(ReceiveSwap {
+                state: Pending,
+                ..swap
+            })?,
+            Some(Swap::Chain(swap)) => self.chain_swap_handler.update_swap(ChainSwap {
+                state: Pending,
+                ..swap
+            })?,
+            _ => {}
+        }
+        if let Err(e) = self.persister.unconfirm_payment_tx_data(tx_id) {
+            // Restore the swap, so the payment and its swap stay consistent
+            match maybe_swap {
+                Some(Swap::Receive(swap)) => self.receive_swap_handler.update_swap(swap)?,
+                Some(Swap::Chain(swap)) => self.chain_swap_handler.update_swap(swap)?,
+                _ => {}
+            }
+            return Err(e);
+        }
+
+        if let Some(payment) = self.persister.get_payment(tx_id)? {
+            self.notify_event_listeners(SdkEvent::PaymentReorged { details: payment })
+                .await;
+        }
+        Ok(())
+    }
+
+    async fn update_wallet_info(&self) -> Result<()> {
+        let asset_metadata: HashMap<String, AssetMetadata> = self
+            .persister
+            .list_asset_metadata()?
+            .into_iter()
+            .map(|am| (am.asset_id.clone(), am))
+            .collect();
+        let transactions = self.onchain_wallet.transactions().await?;
+        let tx_ids = transactions
+            .iter()
+            .map(|tx| tx.txid.to_string())
+            .collect::<Vec<_>>();
+        // The confirmed balance, pending send and pending receive amounts of each asset
+        let mut balances = BTreeMap::<AssetId, (i64, u64, u64)>::new();
+        for tx in transactions {
+            for (asset_id, balance) in tx.balance {
+                let (confirmed, pending_send, pending_receive) =
+                    balances.entry(asset_id).or_default();
+                // Consider only confirmed unspent outputs (confirmed transactions output reduced by unconfirmed spent outputs)
+                if tx.height.is_some() || balance < 0 {
+                    *confirmed += balance;
+                }
+                if tx.height.is_none() {
+                    match balance < 0 {
+                        true => *pending_send += balance.unsigned_abs(),
+                        false => *pending_receive += balance.unsigned_abs(),
+                    }
+                }
+            }
+        }
+        let asset_balances = balances
+            .into_iter()
+            .map(
+                |(asset_id, (balance, pending_send_sat, pending_receive_sat))| {
+                    let asset_id = asset_id.to_hex();
+                    let balance_sat = balance.unsigned_abs();
+                    let maybe_asset_metadata = asset_metadata.get(&asset_id);
+                    AssetBalance {
+                        asset_id,
+                        balance_sat,
+                        name: maybe_asset_metadata.map(|am| am.name.clone()),
+                        ticker: maybe_asset_metadata.map(|am| am.ticker.clone()),
+                        balance: maybe_asset_metadata.map(|am| am.amount_from_sat(balance_sat)),
+                        pending_send_sat,
+                        pending_receive_sat,
+                        pending_send: maybe_asset_metadata
+                            .map(|am| am.amount_from_sat(pending_send_sat)),
+                        pending_receive: maybe_asset_metadata
+                            .map(|am| am.amount_from_sat(pending_receive_sat)),
+                        asset_balance: maybe_asset_metadata.map(|am| am.asset_amount(balance_sat)),
+                    }
+                },
+            )
+            .collect::<Vec<AssetBalance>>();
+        let mut balance_sat = asset_balances
+            .clone()
+            .into_iter()
+            .find(|ab| ab.asset_id.eq(&self.config.lbtc_asset_id()))
+            .map_or(0, |ab| ab.balance_sat);
+
+        let mut pending_send_sat = 0;
+        let mut pending_receive_sat = 0;
+        let payments = self.persister.get_payments(&ListPaymentsRequest {
+            states: Some(vec![
+                PaymentState::Pending,
+                PaymentState::RefundPending,
+                PaymentState::WaitingFeeAcceptance,
+            ]),
+            ..Default::default()
+        })?;
+
+        for payment in payments {
+            let is_lbtc_asset_id = payment.details.is_lbtc_asset_id(self.config.network);
+            match payment.payment_type {
+                PaymentType::Send => match payment.details.get_refund_tx_amount_sat() {
+                    Some(refund_tx_amount_sat) => pending_receive_sat += refund_tx_amount_sat,
+                    None => {
+                        let total_sat = if is_lbtc_asset_id {
+                            payment.amount_sat + payment.fees_sat
+                        } else {
+                            payment.fees_sat
+                        };
+                        if let Some(tx_id) = payment.tx_id {
+                            if !tx_ids.contains(&tx_id) {
+                                debug!("Deducting {total_sat} sats from balance");
+                                balance_sat = balance_sat.saturating_sub(total_sat);
+                            }
+                        }
+                        pending_send_sat += total_sat
+                    }
+                },
+                PaymentType::Receive => {
+                    if is_lbtc_asset_id {
+                        pending_receive_sat += payment.amount_sat;
+                    }
+                }
+            }
+        }
+
+        debug!("Onchain wallet balance: {balance_sat} sats");
+        let info_response = WalletInfo {
+            balance_sat,
+            pending_send_sat,
+            pending_receive_sat,
+            fingerprint: self.onchain_wallet.fingerprint()?,
+            pubkey: self.onchain_wallet.pubkey()?,
+            asset_balances,
+        };
+        self.persister.set_wallet_info(&info_response)
+    }
+
+    /// Lists the SDK payments in reverse chronological order, from newest to oldest.
+    /// The payments are determined based on onchain transactions and swaps.
+    pub async fn list_payments(
+        &self,
+        req: &ListPaymentsRequest,
+    ) -> Result<Vec<Payment>, PaymentError> {
+        self.ensure_is_started().await?;
+
+        Ok(self.persister.get_payments(req)?)
+    }
+
+    /// Returns the totals of the payments in a period, per asset: the amounts received and
+    /// sent, the swap and onchain fees, and the number of payments.
+    ///
+    /// Failed, timed out and refunded payments are not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - A [GetReportRequest], containing:
+    ///     * `from_timestamp` - the optional start of the period, as epoch time in seconds
+    ///     * `to_timestamp` - the optional end of the period, as epoch time in seconds
+    pub async fn get_report(&self, req: &GetReportRequest) -> SdkResult<GetReportResponse> {
+        self.ensure_is_started().await?;
+
+        Ok(GetReportResponse {
+            assets: self.persister.get_report(req)?,
+        })
+    }
+
+    /// Retrieves a payment.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [GetPaymentRequest] containing:
+    ///     * [GetPaymentRequest::Lightning] - the `payment_hash` of the lightning invoice
+    ///
+    /// # Returns
+    ///
+    /// Returns an `Option<Payment>` if found, or `None` if no payment matches the given request.
+    pub async fn get_payment(
+        &self,
+        req: &GetPaymentRequest,
+    ) -> Result<Option<Payment>, PaymentError> {
+        self.ensure_is_started().await?;
+
+        Ok(self.persister.get_payment_by_request(req)?)
+    }
+
+    /// Exports the unblinded amounts and blinding factors of the wallet inputs and outputs of a
+    /// payment's transaction, e.g. so an auditor can verify the amounts of a specific
+    /// confidential payment.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ExportPaymentUnblindingDataRequest] containing:
+    ///     * `payment_id` - the tx id or swap id of the payment
+    ///     * `explorer_url` - the optional base URL of the explorer to unblind the tx in
+    pub async fn export_payment_unblinding_data(
+        &self,
+        req: &ExportPaymentUnblindingDataRequest,
+    ) -> SdkResult<PaymentUnblindingData> {
+        self.ensure_is_started().await?;
+        let payment = self
+            .persister
+            .get_payment(&req.payment_id)?
+            .ok_or(SdkError::generic(format!(
+                "Payment {} not found",
+                req.payment_id
+            )))?;
+        let tx_id = payment
+            .tx_id
+            .ok_or(SdkError::generic("Payment has no transaction yet"))?;
+        let mut wallet_txs = self.onchain_wallet.transactions_by_tx_id().await?;
+        let wallet_tx = lwk_wollet::elements::Txid::from_str(&tx_id)
+            .ok()
+            .and_then(|txid| wallet_txs.remove(&txid))
+            .ok_or(SdkError::generic(format!(
+                "Transaction {tx_id} not found in the wallet"
+            )))?;
+
+        let explorer_url = match (&req.explorer_url, self.config.network) {
+            (Some(explorer_url), _) => Some(explorer_url.as_str()),
+            (None, LiquidNetwork::Mainnet) => Some(MAINNET_EXPLORER_URL),
+            (None, LiquidNetwork::Testnet) => Some(TESTNET_EXPLORER_URL),
+            (None, LiquidNetwork::Regtest) => None,
+        };
+        let unblinding_data = utils::tx_unblinding_data(&wallet_tx);
+        let unblinded_url = explorer_url.map(|explorer_url| {
+            let explorer_url = explorer_url.trim_end_matches('/');
+            format!("{explorer_url}/tx/{tx_id}#blinded={unblinding_data}")
+        });
+        Ok(PaymentUnblindingData {
+            tx_id,
+            unblinding_data,
+            unblinded_url,
+            inputs: wallet_tx.inputs.iter().flatten().map(Into::into).collect(),
+            outputs: wallet_tx.outputs.iter().flatten().map(Into::into).collect(),
+        })
+    }
+
+    /// Fetches an up-to-date fees proposal for a [Payment] that is [WaitingFeeAcceptance].
+    ///
+    /// Use [LiquidSdk::accept_payment_proposed_fees] to accept the proposed fees and proceed
+    /// with the payment.
+    pub async fn fetch_payment_proposed_fees(
+        &self,
+        req: &FetchPaymentProposedFeesRequest,
+    ) -> SdkResult<FetchPaymentProposedFeesResponse> {
+        let chain_swap =
+            self.persister
+                .fetch_chain_swap_by_id(&req.swap_id)?
+                .ok_or(SdkError::Generic {
+                    err: format!("Could not find Swap {}", req.swap_id),
+                })?;
+
+        ensure_sdk!(
+            chain_swap.state == WaitingFeeAcceptance,
+            SdkError::Generic {
+                err: "Payment is not WaitingFeeAcceptance".to_string()
+            }
+        );
+
+        let server_lockup_quote = self
+            .swapper
+            .get_zero_amount_chain_swap_quote(&req.swap_id)
+            .await?;
+
+        let actual_payer_amount_sat =
+            chain_swap
+                .actual_payer_amount_sat
+                .ok_or(SdkError::Generic {
+                    err: "No actual payer amount found when state is WaitingFeeAcceptance"
+                        .to_string(),
+                })?;
+        let fees_sat =
+            actual_payer_amount_sat - server_lockup_quote.to_sat() + chain_swap.claim_fees_sat;
+
+        Ok(FetchPaymentProposedFeesResponse {
+            swap_id: req.swap_id.clone(),
+            fees_sat,
+            payer_amount_sat: actual_payer_amount_sat,
+            receiver_amount_sat: actual_payer_amount_sat - fees_sat,
+        })
+    }
+
+    /// Accepts proposed fees for a [Payment] that is [WaitingFeeAcceptance].
+    ///
+    /// Use [LiquidSdk::fetch_payment_proposed_fees] to get an up-to-date fees proposal.
+    pub async fn accept_payment_proposed_fees(
+        &self,
+        req: &AcceptPaymentProposedFeesRequest,
+    ) -> Result<(), PaymentError> {
+        let FetchPaymentProposedFeesResponse {
+            swap_id,
+            fees_sat,
+            payer_amount_sat,
+            ..
+        } = req.clone().response;
+
+        let chain_swap =
+            self.persister
+                .fetch_chain_swap_by_id(&swap_id)?
+                .ok_or(SdkError::Generic {
+                    err: format!("Could not find Swap {}", swap_id),
+                })?;
+
+        ensure_sdk!(
+            chain_swap.state == WaitingFeeAcceptance,
+            PaymentError::Generic {
+                err: "Payment is not WaitingFeeAcceptance".to_string()
+            }
+        );
+
+        let server_lockup_quote = self
+            .swapper
+            .get_zero_amount_chain_swap_quote(&swap_id)
+            .await?;
+
+        ensure_sdk!(
+            fees_sat == payer_amount_sat - server_lockup_quote.to_sat() + chain_swap.claim_fees_sat,
+            PaymentError::InvalidOrExpiredFees
+        );
+
+        self.persister
+            .insert_swap_intent(&swap_id, SwapIntentKind::FeesAccepted, None)?;
+        self.persister
+            .update_accepted_receiver_amount(&swap_id, Some(payer_amount_sat - fees_sat))?;
+        self.swapper
+            .accept_zero_amount_chain_swap_quote(&swap_id, server_lockup_quote.to_sat())
+            .inspect_err(|e| {
+                error!("Failed to accept zero-amount swap {swap_id} quote: {e} - trying to erase the accepted receiver amount...");
+                let _ = self
+                    .persister
+                    .update_accepted_receiver_amount(&swap_id, None);
+                let _ = self
+                    .persister
+                    .delete_swap_intent(&swap_id, SwapIntentKind::FeesAccepted);
+            }).await?;
+        self.chain_swap_handler.update_swap_info(&ChainSwapUpdate {
+            swap_id: swap_id.clone(),
+            to_state: Pending,
+            ..Default::default()
+        })?;
+        self.persister
+            .delete_swap_intent(&swap_id, SwapIntentKind::FeesAccepted)?;
+        Ok(())
+    }
+
+    /// Empties the Liquid Wallet cache for the [Config::network].
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub fn empty_wallet_cache(&self) -> Result<()> {
+        let mut path = PathBuf::from(self.config.working_dir.clone());
+        path.push(Into::<lwk_wollet::ElementsNetwork>::into(self.config.network).as_str());
+        path.push("enc_cache");
+
+        std::fs::remove_dir_all(&path)?;
+        std::fs::create_dir_all(path)?;
+
+        Ok(())
+    }
+
+    /// Synchronizes the local state with the mempool and onchain data.
+    ///
+    /// The SDK already syncs periodically in the background, every [Config::sync_interval_sec].
+    /// This can be used to sync right away, e.g. when the app is brought to the foreground or
+    /// woken up by a push notification. It resolves once the sync is complete, with its
+    /// [SyncResponse] statistics.
+    ///
+    /// # Arguments
+    ///
+    /// * `partial_sync` - whether to only sync the ongoing receive swaps, instead of all the swaps
+    ///   that can still be updated
+    #[tracing::instrument(skip(self))]
+    pub async fn sync(&self, partial_sync: bool) -> SdkResult<SyncResponse> {
+        self.ensure_is_started().await?;
+
+        self.notify_event_listeners(SdkEvent::SyncStarted).await;
+        let t0 = Instant::now();
+
+        let is_wallet_scanned = match self.onchain_wallet.full_scan().await {
+            Ok(()) => true,
+            Err(err) => {
+                error!("Failed to scan wallet: {err:?}");
+                false
+            }
+        };
+
+        let is_first_sync = !self
+            .persister
+            .get_is_first_sync_complete()?
+            .unwrap_or(false);
+        let mut res = match is_first_sync {
+            true => {
+                self.event_manager.pause_notifications();
+                // Notifications are resumed even if the sync fails
+                let res = self
+                    .sync_payments_with_chain_data(partial_sync, is_wallet_scanned)
+                    .await;
+                self.event_manager.resume_notifications();
+                let res = res?;
+                self.persister.set_is_first_sync_complete(true)?;
+                res
+            }
+            false => {
+                self.sync_payments_with_chain_data(partial_sync, is_wallet_scanned)
+                    .await?
+            }
+        };
+        
+        // Explicit wallet info update to ensure balance is always up-to-date after sync
+        // Adding as specifically requested, even though sync_payments_with_chain_data already calls update_wallet_info()
+        self.update_wallet_info().await?;
+        
+        let duration = Instant::now().duration_since(t0);
+        let duration_ms = duration.as_millis();
+        info!("Synchronized (partial: {partial_sync}) with mempool and onchain data ({duration_ms} ms)");
+        res.duration_ms = duration_ms as u64;
+        self.metrics.observe_sync_duration(duration);
+
+        self.notify_event_listeners(SdkEvent::Synced).await;
+        Ok(res)
+    }
+
+    /// Returns the operational metrics of the SDK in the Prometheus text exposition format, e.g.
+    /// to be served on a metrics endpoint: the payments sent and received, the swap failures by
+    /// reason, the sync durations and the chain service reconnections since the SDK started.
+    pub fn prometheus_metrics(&self) -> String {
+        self.metrics.render()
+    }
+
+    /// Backup the local state to the provided backup path.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [BackupRequest] containing:
+    ///     * `backup_path` - the optional backup path. Defaults to [Config::working_dir]
+    pub fn backup(&self, req: BackupRequest) -> Result<()> {
+        let backup_path = req
+            .backup_path
+            .map(PathBuf::from)
+            .unwrap_or(self.persister.get_default_backup_path());
+        self.persister.backup(backup_path)
+    }
+
+    /// Restores the local state from the provided backup path.
+    ///
+    /// The backup is verified before restoring, see [LiquidSdk::verify_backup]. An invalid
+    /// backup fails with [SdkError::InvalidBackup] and leaves the local state untouched.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [RestoreRequest] containing:
+    ///     * `backup_path` - the optional backup path. Defaults to [Config::working_dir]
+    pub fn restore(&self, req: RestoreRequest) -> SdkResult<()> {
+        let backup_path = req
+            .backup_path
+            .map(PathBuf::from)
+            .unwrap_or(self.persister.get_default_backup_path());
+        ensure_sdk!(
+            backup_path.exists(),
+            SdkError::generic("Backup file does not exist")
+        );
+        self.persister.restore_from_backup(backup_path)
+    }
+
+    /// Verifies the backup at the provided backup path without restoring it. Fails with
+    /// [SdkError::InvalidBackup] if the backup is truncated or corrupted, or was created by a
+    /// newer version of the SDK.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [RestoreRequest] containing:
+    ///     * `backup_path` - the optional backup path. Defaults to [Config::working_dir]
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub fn verify_backup(&self, req: RestoreRequest) -> SdkResult<()> {
+        let backup_path = req
+            .backup_path
+            .map(PathBuf::from)
+            .unwrap_or(self.persister.get_default_backup_path());
+        self.persister.verify_backup_file(backup_path)
+    }
+
+    /// Exports the wallet to a single self-contained file, which can be imported with
+    /// [LiquidSdk::import_wallet] on another device or in another app built on the SDK.
+    ///
+    /// Besides the wallet descriptor, the file contains the swaps, including their keys and
+    /// scripts, and the payment metadata, e.g. descriptions and LNURL info. Like the synced
+    /// data, it is encrypted with a key derived from the signer, so only this wallet can read it.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ExportWalletRequest] containing:
+    ///     * `export_path` - the path of the export file to create
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub fn export_wallet(&self, req: ExportWalletRequest) -> SdkResult<()> {
+        let export = self
+            .persister
+            .export_wallet(self.onchain_wallet.fingerprint()?, self.descriptor()?)?;
+        let bytes = export.to_bytes().map_err(anyhow::Error::from)?;
+        let bytes = self
+            .signer
+            .ecies_encrypt(bytes)
+            .map_err(|e| SdkError::generic(format!("Could not encrypt export: {e:?}")))?;
+        std::fs::write(&req.export_path, bytes)
+            .map_err(|e| SdkError::generic(format!("Failed to write export file: {e}")))?;
+        Ok(())
+    }
+
+    /// Imports a wallet export file created with [LiquidSdk::export_wallet]. The export must
+    /// belong to this wallet, i.e. be created with the same mnemonic or signer. The records are
+    /// imported all at once or not at all. The imported swaps are then tracked and their
+    /// payments listed, while the onchain data is fetched again on the next [LiquidSdk::sync].
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [ImportWalletRequest] containing:
+    ///     * `export_path` - the path of the export file
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub fn import_wallet(&self, req: ImportWalletRequest) -> SdkResult<ImportWalletResponse> {
+        let bytes = std::fs::read(&req.export_path)
+            .map_err(|e| SdkError::generic(format!("Failed to read export file: {e}")))?;
+        let bytes = self.signer.ecies_decrypt(bytes).map_err(|e| {
+            SdkError::generic(format!(
+                "Could not decrypt export, it may belong to a different wallet: {e:?}"
+            ))
+        })?;
+        let export = WalletExport::from_bytes(&bytes)?;
+        ensure_sdk!(
+            export.descriptor == self.descriptor()?,
+            SdkError::generic("The wallet export belongs to a different wallet")
+        );
+        let imported = self.persister.import_wallet(export)?;
+        info!("Imported {} records from wallet export", imported.count);
+        for swap in self
+            .persister
+            .list_ongoing_swaps()?
+            .into_iter()
+            .filter(|swap| imported.swap_ids.contains(&swap.id()))
+        {
+            self.status_stream.track_swap_id(&swap.id())?;
+        }
+        Ok(ImportWalletResponse {
+            imported_records: imported.count as u32,
+        })
+    }
+
+    fn descriptor(&self) -> Result<String> {
+        let signer = SdkLwkSigner::new(self.signer.clone())?;
+        Ok(get_descriptor(&signer, self.config.network)?.to_string())
+    }
+
+    /// Adds a [Nostr Wallet Connect](https://nips.nostr.com/47) connection, allowing an app to
+    /// pay invoices, get the balance and list the transactions of this wallet. Payments are
+    /// executed through [LiquidSdk::send_payment], within the optional connection budget.
+    ///
+    /// Requires the `nwc` feature and the [Config::nwc_relay_url] to be set.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [AddNwcConnectionRequest] containing:
+    ///     * `name` - a unique name identifying the connection
+    ///     * `budget` - the optional spending budget of the connection
+    #[cfg(feature = "nwc")]
+    pub fn add_nwc_connection(
+        &self,
+        req: AddNwcConnectionRequest,
+    ) -> SdkResult<AddNwcConnectionResponse> {
+        let nwc_service = self
+            .nwc_service
+            .as_ref()
+            .ok_or(SdkError::generic("The NWC relay url is not configured"))?;
+        ensure_sdk!(
+            !self
+                .persister
+                .list_nwc_connections()?
+                .iter()
+                .any(|connection| connection.name == req.name),
+            SdkError::generic(format!(
+                "An NWC connection named {} already exists",
+                req.name
+            ))
+        );
+
+        let (client_pubkey, connection_uri) = nwc_service.new_connection_uri();
+        self.persister.insert_nwc_connection(&NwcConnection {
+            name: req.name,
+            client_pubkey,
+            budget: req.budget,
+            spent_sat: 0,
+            created_at: utils::now(),
+        })?;
+        Ok(AddNwcConnectionResponse { connection_uri })
+    }
+
+    #[cfg(not(feature = "nwc"))]
+    pub fn add_nwc_connection(
+        &self,
+        _req: AddNwcConnectionRequest,
+    ) -> SdkResult<AddNwcConnectionResponse> {
+        Err(SdkError::generic(
+            "Nostr Wallet Connect requires the nwc feature",
+        ))
+    }
+
+    /// Lists the [Nostr Wallet Connect](https://nips.nostr.com/47) connections, along with the
+    /// amount each spent in its current budget period.
+    pub fn list_nwc_connections(&self) -> SdkResult<Vec<NwcConnection>> {
+        Ok(self.persister.list_nwc_connections()?)
+    }
+
+    /// Removes a [Nostr Wallet Connect](https://nips.nostr.com/47) connection. Its requests are
+    /// ignored from then on.
+    pub fn remove_nwc_connection(&self, name: String) -> SdkResult<()> {
+        ensure_sdk!(
+            self.persister.delete_nwc_connection(&name)?,
+            SdkError::generic(format!("No NWC connection named {name} exists"))
+        );
+        Ok(())
+    }
+
+    /// Sets a custom [Broadcaster] of the signed Liquid and Bitcoin transactions, replacing the
+    /// broadcast through the Liquid and Bitcoin chain services.
+    pub async fn set_broadcaster(&self, broadcaster: Box<dyn Broadcaster>) -> SdkResult<()> {
+        *self.broadcaster.write().await = Some(Arc::from(broadcaster));
+        Ok(())
+    }
+
+    /// Sets the [BackupTransport] to which the local state is backed up. A new backup version is
+    /// uploaded after each change of the payments or swaps, or according to the
+    /// [Config::backup_policy] if one is set. The backups are encrypted with a key derived from
+    /// the signer, so they can only be restored by the same wallet.
+    pub async fn set_backup_transport(&self, transport: Box<dyn BackupTransport>) -> SdkResult<()> {
+        *self.backup_transport.write().await = Some(transport);
+        Ok(())
+    }
+
+    /// Lists the backup versions uploaded with the [BackupTransport], from newest to oldest.
+    pub async fn list_backup_versions(&self) -> SdkResult<Vec<String>> {
+        let backup_transport = self.backup_transport.read().await;
+        let backup_transport = backup_transport
+            .as_ref()
+            .ok_or(SdkError::generic("No backup transport is set"))?;
+        Ok(backup_transport.list_versions()?)
+    }
+
+    /// Restores the local state from a backup version uploaded with the [BackupTransport].
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - the optional backup version. Defaults to the latest version
+    pub async fn restore_from_backup_transport(&self, version: Option<String>) -> SdkResult<()> {
+        let backup_transport = self.backup_transport.read().await;
+        let backup_transport = backup_transport
+            .as_ref()
+            .ok_or(SdkError::generic("No backup transport is set"))?;
+        let version = match version {
+            Some(version) => version,
+            None => backup_transport
+                .list_versions()?
+                .into_iter()
+                .next()
+                .ok_or(SdkError::generic("No backup version exists"))?,
+        };
+        let backup = self.decrypt_backup(backup_transport.download(version)?)?;
+        self.persister.restore_from_bytes(&backup)?;
+        Ok(())
+    }
+
+    /// Encrypts a backup uploaded with the [BackupTransport], as it holds the swap preimages
+    /// and keys. Like the synced data, it is encrypted with a key derived from the signer.
+    fn encrypt_backup(&self, backup: Vec<u8>) -> SdkResult<Vec<u8>> {
+        self.signer
+            .ecies_encrypt(backup)
+            .map_err(|e| SdkError::generic(format!("Could not encrypt backup: {e:?}")))
+    }
+
+    fn decrypt_backup(&self, backup: Vec<u8>) -> SdkResult<Vec<u8>> {
+        self.signer
+            .ecies_decrypt(backup)
+            .map_err(|e| SdkError::InvalidBackup {
+                err: format!("Could not decrypt backup: {e:?}"),
+            })
+    }
+
+    /// Prepares to pay to an LNURL encoded pay request or lightning address.
+    ///
+    /// This is the second step of LNURL-pay flow. The first step is [LiquidSdk::parse], which also validates the LNURL
+    /// destination and generates the [LnUrlPayRequest] payload needed here.
+    ///
+    /// This call will validate the `amount_msat` and `comment` parameters of `req` against the parameters
+    /// of the LNURL endpoint (`req_data`). If they match the endpoint requirements, a [PrepareSendResponse] is
+    /// prepared for the invoice. If the receiver has encoded a Magic Routing Hint in the invoice, the
+    /// [PrepareSendResponse]'s `fees_sat` will reflect this.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [PrepareLnUrlPayRequest] containing:
+    ///     * `data` - the [LnUrlPayRequestData] returned by [LiquidSdk::parse]
+    ///     * `amount` - The optional amount of type [PayAmount].
+    ///        - [PayAmount::Drain] which uses all funds
+    ///        - [PayAmount::Receiver] which sets the amount the receiver should receive
+    ///     * `bip353_address` - A BIP353 address, in case one was used in order to fetch the LNURL
+    ///       Pay request data. Returned by [parse].
+    ///     * `comment` - an optional comment for this payment
+    ///     * `payer_data` - optional LUD-18 payer identity to send to the LNURL service
+    ///     * `validate_success_action_url` - validates that, if there is a URL success action, the URL domain matches
+    ///       the LNURL callback domain. Defaults to 'true'
+    ///
+    /// # Returns
+    /// Returns a [PrepareLnUrlPayResponse] containing:
+    ///     * `destination` - the destination of the payment
+    ///     * `fees_sat` - The fees in satoshis to send the payment
+    ///     * `fee_breakdown` - The breakdown of the fees into their components
+    ///     * `data` - The [LnUrlPayRequestData] returned by [parse]
+    ///     * `comment` - An optional comment for this payment
+    ///     * `success_action` - the optional unprocessed LUD-09 success action
+    ///     * `verify_url` - the optional LUD-21 verify URL of the invoice
+    pub async fn prepare_lnurl_pay(
+        &self,
+        req: PrepareLnUrlPayRequest,
+    ) -> Result<PrepareLnUrlPayResponse, LnUrlPayError> {
+        let amount_msat = match req.amount {
+            PayAmount::Drain => {
+                let get_info_res = self
+                    .get_info()
+                    .await
+                    .map_err(|e| LnUrlPayError::Generic { err: e.to_string() })?;
+                ensure_sdk!(
+                    get_info_res.wallet_info.pending_receive_sat == 0
+                        && get_info_res.wallet_info.pending_send_sat == 0,
+                    LnUrlPayError::Generic {
+                        err: "Cannot drain while there are pending payments".to_string(),
+                    }
+                );
+                let lbtc_pair = self
+                    .swapper
+                    .get_submarine_pairs()
+                    .await?
+                    .ok_or(PaymentError::PairsNotFound)?;
+                let drain_fees_sat = self.estimate_drain_tx_fee(None, None).await?;
+                let drain_amount_sat = get_info_res.wallet_info.balance_sat - drain_fees_sat;
+                // Get the inverse invoice amount by calculating a dummy amount then increment up to the drain amount
+                let dummy_fees_sat = lbtc_pair.fees.total(drain_amount_sat);
+                let dummy_amount_sat = drain_amount_sat - dummy_fees_sat;
+                let invoice_amount_sat = utils::increment_invoice_amount_up_to_drain_amount(
+                    dummy_amount_sat,
+                    &lbtc_pair,
+                    drain_amount_sat,
+                );
+                lbtc_pair
+                    .limits
+                    .within(invoice_amount_sat)
+                    .map_err(|e| LnUrlPayError::Generic { err: e.message() })?;
+                // Validate if we can actually drain the wallet with a swap
+                let pair_fees_sat = lbtc_pair.fees.total(invoice_amount_sat);
+                ensure_sdk!(
+                    invoice_amount_sat + pair_fees_sat == drain_amount_sat,
+                    LnUrlPayError::Generic {
+                        err: "Cannot drain without leaving a remainder".to_string(),
+                    }
+                );
+
+                invoice_amount_sat * 1000
+            }
+            PayAmount::Bitcoin {
+                receiver_amount_sat,
+            } => receiver_amount_sat * 1000,
+            PayAmount::Asset { .. } | PayAmount::AssetUnits { .. } => {
+                return Err(LnUrlPayError::Generic {
+                    err: "Cannot send an asset to a Bitcoin address".to_string(),
+                })
+            }
+        };
+
+        let callback = match &req.payer_data {
+            Some(payer_data) => add_payer_data_to_callback(&req.data.callback, payer_data)?,
+            None => req.data.callback.clone(),
+        };
+
+        match fetch_pay_callback(
+            self.rest_client.as_ref(),
+            &req.data,
+            &callback,
+            amount_msat,
+            &req.comment,
+            self.config.network.into(),
+            req.validate_success_action_url,
+        )
+        .await?
+        {
+            PayCallbackResult::EndpointError { data } => {
+                Err(LnUrlPayError::Generic { err: data.reason })
+            }
+            PayCallbackResult::EndpointSuccess { data } => {
+                let prepare_response = self
+                    .prepare_send_payment(&PrepareSendRequest {
+                        destination: data.pr.clone(),
+                        amount: Some(req.amount),
+                        fiat_amount: None,
+                    })
+                    .await
+                    .map_err(|e| LnUrlPayError::Generic { err: e.to_string() })?;
+
+                let destination = match prepare_response.destination {
+                    SendDestination::Bolt11 { invoice, .. } => SendDestination::Bolt11 {
+                        invoice,
+                        bip353_address: req.bip353_address,
+                    },
+                    SendDestination::LiquidAddress {
+                        address_data,
+                        payjoin_url,
+                        ..
+                    } => SendDestination::LiquidAddress {
+                        address_data,
+                        bip353_address: req.bip353_address,
+                        payjoin_url,
+                    },
+                    destination => destination,
+                };
+                let fees_sat = prepare_response
+                    .fees_sat
+                    .ok_or(PaymentError::InsufficientFunds)?;
+                let fee_breakdown = prepare_response
+                    .fee_breakdown
+                    .ok_or(PaymentError::InsufficientFunds)?;
+
+                Ok(PrepareLnUrlPayResponse {
+                    destination,
+                    fees_sat,
+                    fee_breakdown,
+                    data: req.data,
+                    comment: req.comment,
+                    success_action: data.success_action,
+                    verify_url: data.verify_url,
+                })
+            }
+        }
+    }
+
+    /// Pay to an LNURL encoded pay request or lightning address.
+    ///
+    /// The final step of LNURL-pay flow, called after preparing the payment with [LiquidSdk::prepare_lnurl_pay].
+    /// This call sends the payment using the [PrepareLnUrlPayResponse]'s `prepare_send_response` either via
+    /// Lightning or directly to a Liquid address if a Magic Routing Hint is included in the invoice.
+    /// Once the payment is made, the [PrepareLnUrlPayResponse]'s `success_action` is processed decrypting
+    /// the AES data if needed. If the LNURL service supports LUD-21, its `verify_url` is then polled
+    /// until the invoice settles or expires, and the verified preimage is added to the payment [LnUrlInfo].
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [LnUrlPayRequest] containing:
+    ///     * `prepare_response` - the [PrepareLnUrlPayResponse] returned by [LiquidSdk::prepare_lnurl_pay]
+    pub async fn lnurl_pay(
+        &self,
+        req: model::LnUrlPayRequest,
+    ) -> Result<LnUrlPayResult, LnUrlPayError> {
+        let prepare_response = req.prepare_response;
+        let payment = self
+            .send_lnurl_payment(&prepare_response, None)
+            .await
+            .map_err(|e| LnUrlPayError::Generic { err: e.to_string() })?;
+        Ok(LnUrlPayResult::EndpointSuccess {
+            data: self.complete_lnurl_pay(prepare_response, payment).await?,
+        })
+    }
+
+    /// Sends the payment of a prepared LNURL-pay. If set, the `idempotency_key` is linked to
+    /// the payment.
+    async fn send_lnurl_payment(
+        &self,
+        prepare_response: &PrepareLnUrlPayResponse,
+        idempotency_key: Option<&str>,
+    ) -> Result<Payment, PaymentError> {
+        Ok(self
+            .send_payment(&SendPaymentRequest {
+                prepare_response: PrepareSendResponse {
+                    destination: prepare_response.destination.clone(),
+                    fees_sat: Some(prepare_response.fees_sat),
+                    fee_breakdown: Some(prepare_response.fee_breakdown.clone()),
+                    estimated_asset_fees: None,
+                    estimated_asset_fees_amount: None,
+                    fiat_conversion: None,
+                },
+                use_asset_fees: None,
+                max_fee_sat: None,
+                spend_limit_override: None,
+                idempotency_key: idempotency_key.map(str::to_string),
+            })
+            .await?
+            .payment)
+    }
+
+    /// Processes the success action of a sent LNURL-pay payment and stores its LNURL info
+    async fn complete_lnurl_pay(
+        &self,
+        prepare_response: PrepareLnUrlPayResponse,
+        mut payment: Payment,
+    ) -> Result<model::LnUrlPaySuccessData, LnUrlPayError> {
+        // A direct Liquid payment has no preimage, so an AES success action can't be decrypted
+        let preimage = match &payment.details {
+            PaymentDetails::Lightning { preimage, .. } => preimage.as_deref(),
+            _ => None,
+        };
+        let maybe_sa_processed = match prepare_response.success_action.clone() {
+            Some(sa) => process_success_action(sa, preimage)
+                .map_err(|e| LnUrlPayError::Generic { err: e.to_string() })?,
+            None => None,
+        };
+
+        let lnurl_pay_verified_preimage = match (&prepare_response.verify_url, &payment.details) {
+            (
+                Some(verify_url),
+                PaymentDetails::Lightning {
+                    payment_hash: Some(payment_hash),
+                    ..
+                },
+            ) => check_verified_preimage(self.rest_client.as_ref(), verify_url, payment_hash).await,
+            _ => None,
+        };
+
+        // If the invoice isn't settled yet, keep polling the verify URL until it settles or expires
+        let verify_poll = match (
+            &lnurl_pay_verified_preimage,
+            &prepare_response.verify_url,
+            &payment.details,
+            &prepare_response.destination,
+        ) {
+            (
+                None,
+                Some(verify_url),
+                PaymentDetails::Lightning {
+                    payment_hash: Some(payment_hash),
+                    ..
+                },
+                SendDestination::Bolt11 { invoice, .. },
+            ) => Some((
+                verify_url.clone(),
+                payment_hash.clone(),
+                invoice.timestamp + invoice.expiry,
+            )),
+            _ => None,
+        };
+
+        let description = payment
+            .details
+            .get_description()
+            .or_else(|| extract_description_from_metadata(&prepare_response.data));
+
+        let lnurl_pay_domain = match prepare_response.data.ln_address {
+            Some(_) => None,
+            None => Some(prepare_response.data.domain),
+        };
+        if let (Some(tx_id), Some(destination)) =
+            (payment.tx_id.clone(), payment.destination.clone())
+        {
+            self.persister
+                .insert_or_update_payment_details(PaymentTxDetails {
+                    tx_id: tx_id.clone(),
+                    destination,
+                    description,
+                    lnurl_info: Some(LnUrlInfo {
+                        ln_address: prepare_response.data.ln_address,
+                        lnurl_pay_comment: prepare_response.comment,
+                        lnurl_pay_domain,
+                        lnurl_pay_metadata: Some(prepare_response.data.metadata_str),
+                        lnurl_pay_success_action: maybe_sa_processed.clone(),
+                        lnurl_pay_unprocessed_success_action: prepare_response.success_action,
+                        lnurl_withdraw_endpoint: None,
+                        lnurl_pay_verify_url: prepare_response.verify_url,
+                        lnurl_pay_verified_preimage,
+                    }),
+                    bip353_address: None,
+                    asset_fees: None,
+                })?;
+            // Get the payment with the lnurl_info details
+            payment = self.persister.get_payment(&tx_id)?.unwrap_or(payment);
+
+            if let Some((verify_url, payment_hash, expires_at)) = verify_poll {
+                self.track_verified_preimage(tx_id, verify_url, payment_hash, expires_at);
+            }
+        }
+
+        Ok(model::LnUrlPaySuccessData {
+            payment,
+            success_action: maybe_sa_processed,
+        })
+    }
+
+    /// Polls the LUD-21 `verify_url` of an LNURL-pay invoice in the background until it settles or
+    /// expires, adding the verified preimage to the payment [LnUrlInfo] once it is returned.
+    fn track_verified_preimage(
+        &self,
+        tx_id: String,
+        verify_url: String,
+        payment_hash: String,
+        expires_at: u64,
+    ) {
+        let persister = self.persister.clone();
+        let rest_client = self.rest_client.clone();
+        let mut shutdown_receiver = self.shutdown_receiver.clone();
+        tokio::spawn(async move {
+            let preimage = tokio::select! {
+                preimage = poll_verified_preimage(
+                    rest_client.as_ref(),
+                    &verify_url,
+                    &payment_hash,
+                    expires_at,
+                    LNURL_VERIFY_POLL_INTERVAL,
+                ) => preimage,
+                _ = shutdown_receiver.changed() => {
+                    info!("Received shutdown signal, exiting verify URL polling of {tx_id}");
+                    return;
+                }
+            };
+            let Some(preimage) = preimage else {
+                info!("Invoice of payment {tx_id} expired before its preimage was verified");
+                return;
+            };
+            let res = persister.get_payment_details(&tx_id).and_then(|details| {
+                let Some(mut details) = details else {
+                    return Ok(());
+                };
+                if let Some(lnurl_info) = details.lnurl_info.as_mut() {
+                    lnurl_info.lnurl_pay_verified_preimage = Some(preimage);
+                }
+                persister.insert_or_update_payment_details(details)
+            });
+            if let Err(e) = res {
+                warn!("Failed to store verified preimage of payment {tx_id}: {e:?}");
+            }
+        });
+    }
+
+    /// Second step of LNURL-withdraw. The first step is [LiquidSdk::parse], which also validates the LNURL destination
+    /// and generates the [LnUrlWithdrawRequest] payload needed here.
+    ///
+    /// This call will validate the given `amount_msat` against the parameters
+    /// of the LNURL endpoint (`data`). If they match the endpoint requirements, the LNURL withdraw
+    /// request is made. A successful result here means the endpoint started the payment.
+    pub async fn lnurl_withdraw(
+        &self,
+        req: LnUrlWithdrawRequest,
+    ) -> Result<LnUrlWithdrawResult, LnUrlWithdrawError> {
+        let prepare_response = self
+            .prepare_receive_payment(&{
+                PrepareReceiveRequest {
+                    payment_method: PaymentMethod::Lightning,
+                    amount: Some(ReceiveAmount::Bitcoin {
+                        payer_amount_sat: req.amount_msat / 1_000,
+                    }),
+                    fiat_amount: None,
+                    expiry_secs: None,
+                }
+            })
+            .await?;
+        let receive_res = self
+            .receive_payment(&ReceivePaymentRequest {
+                prepare_response,
+                description: req.description.clone(),
+                use_description_hash: Some(false),
+                description_hash: None,
+                claim_address: None,
+            })
+            .await?;
+
+        let Ok(invoice) = parse_invoice(&receive_res.destination) else {
+            return Err(LnUrlWithdrawError::Generic {
+                err: "Received unexpected output from receive request".to_string(),
+            });
+        };
+
+        let res =
+            validate_lnurl_withdraw(self.rest_client.as_ref(), req.data.clone(), invoice.clone())
+                .await?;
+        if let LnUrlWithdrawResult::Ok { data: _ } = res {
+            if let Some(ReceiveSwap {
+                claim_tx_id: Some(tx_id),
+                ..
+            }) = self
+                .persister
+                .fetch_receive_swap_by_invoice(&invoice.bolt11)?
+            {
+                self.persister
+                    .insert_or_update_payment_details(PaymentTxDetails {
+                        tx_id,
+                        destination: receive_res.destination,
+                        description: req.description,
+                        lnurl_info: Some(LnUrlInfo {
+                            lnurl_withdraw_endpoint: Some(req.data.callback),
+                            ..Default::default()
+                        }),
+                        bip353_address: None,
+                        asset_fees: None,
+                    })?;
+            }
+        }
+        Ok(res)
+    }
+
+    /// Third and last step of LNURL-auth. The first step is [LiquidSdk::parse], which also validates the LNURL destination
+    /// and generates the [LnUrlAuthRequestData] payload needed here. The second step is user approval of auth action.
+    ///
+    /// This call will sign `k1` of the LNURL endpoint (`req_data`) on `secp256k1` using `linkingPrivKey` and DER-encodes the signature.
+    /// If they match the endpoint requirements, the LNURL auth request is made. A successful result here means the client signature is verified.
+    pub async fn lnurl_auth(
+        &self,
+        req_data: LnUrlAuthRequestData,
+    ) -> Result<LnUrlCallbackStatus, LnUrlAuthError> {
+        Ok(perform_lnurl_auth(
+            self.rest_client.as_ref(),
+            &req_data,
+            &SdkLnurlAuthSigner::new(self.signer.clone()),
+        )
+        .await?)
+    }
+
+    /// Register for webhook callbacks at the given `webhook_url`. Each created swap after registering the
+    /// webhook will include the `webhook_url`.
+    ///
+    /// This method should be called every time the application is started and when the `webhook_url` changes.
+    /// For example, if the `webhook_url` contains a push notification token and the token changes after
+    /// the application was started, then this method should be called to register for callbacks at
+    /// the new correct `webhook_url`. To unregister a webhook call [LiquidSdk::unregister_webhook].
+    ///
+    /// The `webhook_url` must be a valid HTTPS URL, except on [LiquidNetwork::Regtest].
+    pub async fn register_webhook(&self, webhook_url: String) -> SdkResult<()> {
+        let url = url::Url::parse(&webhook_url)
+            .map_err(|e| SdkError::generic(format!("Invalid webhook URL: {e}")))?;
+        ensure_sdk!(
+            url.scheme() == "https" || self.config.network == LiquidNetwork::Regtest,
+            SdkError::generic("Webhook URL must use HTTPS")
+        );
+
+        info!("Registering for webhook notifications");
+        self.persister.set_webhook_url(webhook_url)?;
+        Ok(())
+    }
+
+    /// Unregister webhook callbacks. Each swap already created will continue to use the registered
+    /// `webhook_url` until complete.
+    ///
+    /// This can be called when callbacks are no longer needed or the `webhook_url`
+    /// has changed such that it needs unregistering. For example, the token is valid but the locale changes.
+    /// To register a webhook call [LiquidSdk::register_webhook].
+    pub async fn unregister_webhook(&self) -> SdkResult<()> {
+        info!("Unregistering for webhook notifications");
+        self.persister.remove_webhook_url()?;
+        Ok(())
+    }
+
+    /// Checks whether the username is still available on the [Config::lnurl_service_url]
+    /// service, before registering it with [LiquidSdk::register_lightning_address].
+    pub async fn check_lightning_address_available(&self, username: String) -> SdkResult<bool> {
+        validate_username(&username)?;
+        Ok(self
+            .lightning_address_client()?
+            .is_username_available(&username)
+            .await?)
+    }
+
+    /// Registers a lightning address with the [Config::lnurl_service_url] service, so that the
+    /// wallet can be paid at `username@domain`. Registering another username replaces the
+    /// previous lightning address.
+    ///
+    /// Invoice requests are pushed to the webhook registered with [LiquidSdk::register_webhook]
+    /// and handled with [LiquidSdk::process_notification], where each incoming payment is
+    /// received as a Lightning receive swap. This method should be called again when the
+    /// `webhook_url` changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - the [RegisterLightningAddressRequest] containing:
+    ///     * `username` - the username, containing only lowercase letters, digits, '-', '_'
+    ///       and '.'
+    pub async fn register_lightning_address(
+        &self,
+        req: &RegisterLightningAddressRequest,
+    ) -> SdkResult<LightningAddressInfo> {
+        validate_username(&req.username)?;
+        let client = self.lightning_address_client()?;
+        let signed_req = self.sign_lightning_address_request(Some(req.username.clone()))?;
+        let info = client
+            .register(&self.onchain_wallet.pubkey()?, signed_req)
+            .await?;
+        info!("Registered lightning address {}", info.lightning_address);
+        Ok(info)
+    }
+
+    /// Recovers the lightning address registered by this wallet, e.g. after restoring it on
+    /// another device. Returns `None` if no lightning address is registered.
+    pub async fn recover_lightning_address(&self) -> SdkResult<Option<LightningAddressInfo>> {
+        let client = self.lightning_address_client()?;
+        let signed_req = self.sign_lightning_address_request(None)?;
+        Ok(client
+            .recover(&self.onchain_wallet.pubkey()?, signed_req)
+            .await?)
+    }
+
+    /// Deletes the lightning address registered by this wallet, after which its username can
+    /// be registered by another wallet.
+    pub async fn delete_lightning_address(&self) -> SdkResult<()> {
+        let client = self.lightning_address_client()?;
+        let signed_req = self.sign_lightning_address_request(None)?;
+        client
+            .delete(&self.onchain_wallet.pubkey()?, signed_req)
+            .await?;
+        info!("Deleted lightning address");
+        Ok(())
+    }
+
+    fn lightning_address_client(&self) -> SdkResult<LightningAddressClient> {
+        let base_url = self
+            .config
+            .lnurl_service_url
+            .as_deref()
+            .ok_or(SdkError::generic("No LNURL service is configured"))?;
+        Ok(LightningAddressClient::new(
+            base_url,
+            self.rest_client.clone(),
+        ))
+    }
+
+    fn sign_lightning_address_request(&self, username: Option<String>) -> SdkResult<SignedRequest> {
+        let webhook_url = self.persister.get_webhook_url()?.ok_or(SdkError::generic(
+            "A webhook must be registered to receive lightning address payments",
+        ))?;
+        let time = utils::now().into();
+        let message = SignedRequest::message(time, &webhook_url, username.as_deref());
+        let signature = self.onchain_wallet.sign_message(&message)?;
+        Ok(SignedRequest {
+            time,
+            webhook_url,
+            username,
+            signature,
+        })
+    }
+
+    /// Fetches the metadata of an asset from the Liquid asset registry, e.g. to display the
+    /// balance of an asset not set in [Config::asset_metadata]. Registered assets are cached,
+    /// so they are only fetched once.
+    ///
+    /// Returns `None` if the asset is not registered.
+    pub async fn fetch_registered_asset(
+        &self,
+        asset_id: &str,
+    ) -> Result<Option<RegisteredAsset>, SdkError> {
+        AssetId::from_str(asset_id)
+            .map_err(|e| SdkError::generic(format!("Invalid asset id {asset_id}: {e}")))?;
+        if let Some(asset) = self.persister.get_registered_asset(asset_id)? {
+            return Ok(Some(asset));
+        }
+
+        let client = AssetRegistryClient::new(self.config.network, self.rest_client.clone())
+            .ok_or(SdkError::generic(
+                "The asset registry is not available on this network",
+            ))?;
+        let asset = client.fetch_asset(asset_id).await?;
+        if let Some(asset) = &asset {
+            self.persister.insert_registered_asset(asset)?;
+        }
+        Ok(asset)
+    }
+
+    /// Fetch live rates of fiat currencies, sorted by name.
+    ///
+    /// The rates are fetched from the Breez rate service, unless a different source was set
+    /// with [LiquidSdkBuilder::fiat_api] or [LiquidSdk::set_fiat_rate_provider].
+    pub async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, SdkError> {
+        if let Some(provider) = self.fiat_rate_provider.read().await.as_ref() {
+            return Ok(provider.fetch_fiat_rates()?);
+        }
+        self.fiat_api.fetch_fiat_rates().await.map_err(Into::into)
+    }
+
+    /// Sets the [FiatRateProvider] used as the source of the fiat exchange rates, instead of the
+    /// Breez rate service. The rates are used by [LiquidSdk::fetch_fiat_rates], fiat-denominated
+    /// payments and the value recorded in [Payment::fiat_amount].
+    pub async fn set_fiat_rate_provider(
+        &self,
+        provider: Box<dyn FiatRateProvider>,
+    ) -> SdkResult<()> {
+        *self.fiat_rate_provider.write().await = Some(provider);
+        Ok(())
+    }
+
+    /// List all supported fiat currencies for which there is a known exchange rate.
+    /// List is sorted by the canonical name of the currency.
+    ///
+    /// Only the currencies returned by [LiquidSdk::fetch_fiat_rates] are listed, so every
+    /// listed currency can be used for fiat-denominated payments.
+    pub async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>, SdkError> {
+        let currencies = self.fiat_api.list_fiat_currencies().await?;
+        let rates = self.fetch_fiat_rates().await?;
+        Ok(filter_fiat_currencies(currencies, &rates))
+    }
+
+    /// Get the recommended BTC fees based on the configured mempool.space instance.
+    pub async fn recommended_fees(&self) -> Result<RecommendedFees, SdkError> {
+        Ok(self.bitcoin_chain_service.recommended_fees().await?)
+    }
+
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    /// Get the full default [Config] for specific [LiquidNetwork].
+    pub fn default_config(
+        network: LiquidNetwork,
+        breez_api_key: Option<String>,
+    ) -> Result<Config, SdkError> {
+        let config = match network {
+            LiquidNetwork::Mainnet => Config::mainnet(breez_api_key),
+            LiquidNetwork::Testnet => Config::testnet(breez_api_key),
+            LiquidNetwork::Regtest => Config::regtest(),
+        };
+
+        Ok(config)
+    }
+
+    /// Parses a string into an [InputType]. See [input_parser::parse].
+    ///
+    /// A BIP353 name (`₿user@domain`) is resolved into its payment instructions with a
+    /// DNSSEC-validated lookup, see [Config::dns_over_https_url]. If the lookup fails, e.g.
+    /// because the instructions are not authenticated by DNSSEC, the input is rejected. A name
+    /// without payment instructions is parsed as a lightning address.
+    ///
+    /// Can optionally be configured to use external input parsers by providing `external_input_parsers` in [Config].
+    /// Inputs which are still not recognized are passed to the parsers added with
+    /// [LiquidSdk::add_input_parser].
+    pub async fn parse(&self, input: &str) -> Result<InputType, PaymentError> {
+        if let Some(name) = Bip353Name::parse(input) {
+            return self.parse_bip353_name(&name).await;
+        }
+
+        let external_parsers = &self.external_input_parsers;
+        let input_type =
+            match parse_with_rest_client(self.rest_client.as_ref(), input, Some(external_parsers))
+                .await
+            {
+                Ok(input_type) => input_type,
+                // Inputs which are recognized but could not be resolved, e.g. because of a
+                // network error, are not passed to the added parsers
+                Err(e) if e.to_string() != UNRECOGNIZED_INPUT_ERROR => {
+                    return Err(PaymentError::generic(&e.to_string()))
+                }
+                Err(e) => {
+                    let mut input_type = None;
+                    for parser in self.input_parsers.read().await.iter() {
+                        input_type = parser.parse_input(input.to_string()).await;
+                        if input_type.is_some() {
+                            break;
+                        }
+                    }
+                    input_type.ok_or_else(|| PaymentError::generic(&e.to_string()))?
+                }
+            };
+        self.resolve_asset_precision(input_type)
+    }
+
+    /// Adds an [InputParser] consulted by [LiquidSdk::parse] when the input is not recognized
+    /// by the built-in and external input parsers. Parsers are tried in the order they were added.
+    pub async fn add_input_parser(&self, parser: Box<dyn InputParser>) -> SdkResult<()> {
+        self.input_parsers.write().await.push(parser);
+        Ok(())
+    }
+
+    /// Parses the payment instructions of a BIP353 name, looked up with a DNSSEC-validated
+    /// query. A name without payment instructions is parsed as a lightning address, using its
+    /// LNURL-pay endpoint so the name is not looked up again without DNSSEC validation.
+    async fn parse_bip353_name(&self, name: &Bip353Name) -> Result<InputType, PaymentError> {
+        let maybe_uri = bip353::resolve(
+            self.rest_client.as_ref(),
+            self.config.dns_over_https_url(),
+            name,
+        )
+        .await
+        .map_err(|e| PaymentError::generic(&e.to_string()))?;
+        let Some(uri) = maybe_uri else {
+            let input_type =
+                parse_with_rest_client(self.rest_client.as_ref(), &name.lnurl_pay_url(), None)
+                    .await
+                    .map_err(|e| PaymentError::generic(&e.to_string()))?;
+            return match input_type {
+                InputType::LnUrlPay { mut data, .. } => {
+                    data.ln_address = Some(name.to_string());
+                    Ok(InputType::LnUrlPay {
+                        data,
+                        bip353_address: None,
+                    })
+                }
+                _ => Err(PaymentError::generic(&format!(
+                    "No payment instructions found for {name}"
+                ))),
+            };
+        };
+        let instruction = bip353::payment_instruction(&uri)?;
+        let input_type = parse_with_rest_client(self.rest_client.as_ref(), &instruction, None)
+            .await
+            .map_err(|e| PaymentError::generic(&e.to_string()))?;
+
+        let bip353_address = Some(name.to_string());
+        let input_type = match input_type {
+            InputType::Bolt12Offer { offer, .. } => InputType::Bolt12Offer {
+                offer,
+                bip353_address,
+            },
+            InputType::LnUrlPay { data, .. } => InputType::LnUrlPay {
+                data,
+                bip353_address,
+            },
+            input_type => input_type,
+        };
+        self.resolve_asset_precision(input_type)
+    }
+
+    fn resolve_asset_precision(&self, input_type: InputType) -> Result<InputType, PaymentError> {
+        let res = match input_type {
+            InputType::LiquidAddress { ref address } => match &address.asset_id {
+                Some(asset_id) if asset_id.ne(&self.config.lbtc_asset_id()) => {
+                    let asset_metadata = self.persister.get_asset_metadata(asset_id)?.ok_or(
+                        PaymentError::AssetError {
+                            err: format!("Asset {asset_id} is not supported"),
+                        },
+                    )?;
+                    let mut address = address.clone();
+                    address.set_amount_precision(asset_metadata.precision.into());
+                    InputType::LiquidAddress { address }
+                }
+                _ => input_type,
+            },
+            _ => input_type,
+        };
+        Ok(res)
+    }
+
+    /// Parses a string into an [LNInvoice]. See [invoice::parse_invoice].
+    pub fn parse_invoice(input: &str) -> Result<LNInvoice, PaymentError> {
+        parse_invoice(input).map_err(|e| PaymentError::invalid_invoice(&e.to_string()))
+    }
+
+    /// Decodes a BOLT11 or BOLT12 invoice into a [DecodedInvoice], e.g. to show its details
+    /// before calling [LiquidSdk::prepare_send_payment]. No swap is created.
+    pub fn decode_invoice(input: &str) -> Result<DecodedInvoice, PaymentError> {
+        utils::decode_invoice(input).map_err(|e| PaymentError::invalid_invoice(&e.to_string()))
+    }
+
+    /// Validates a Bitcoin or Liquid address for the given `network`, e.g. when entering a refund
+    /// or onchain payment address. Fails with [PaymentError::InvalidNetwork] if the address
+    /// belongs to another network.
+    pub fn validate_address(
+        address: &str,
+        network: LiquidNetwork,
+    ) -> Result<ValidateAddressResponse, PaymentError> {
+        utils::validate_address(address, network)
+    }
+
+    /// Generates a random English BIP39 mnemonic of `word_count` words, which must be 12, 15,
+    /// 18, 21 or 24. It can be passed as the [ConnectRequest::mnemonic].
+    pub fn generate_mnemonic(word_count: u32) -> SdkResult<String> {
+        utils::generate_mnemonic(word_count)
+    }
+
+    /// Validates the words and checksum of a BIP39 mnemonic, e.g. when restoring a wallet. A
+    /// mnemonic passing validation is accepted by [LiquidSdk::connect].
+    pub fn validate_mnemonic(phrase: &str) -> SdkResult<()> {
+        utils::validate_mnemonic(phrase)
+    }
+
+    /// Configures a global SDK logger that will log to file and will forward log events to
+    /// an optional application-specific logger.
+    ///
+    /// If called, it should be called before any SDK methods (for example, before `connect`).
+    ///
+    /// It must be called only once in the application lifecycle. Alternatively, If the application
+    /// already uses a globally-registered logger, this method shouldn't be called at all.
+    ///
+    /// ### Arguments
+    ///
+    /// - `log_dir`: Location where the the SDK log file will be created. The directory must already exist.
+    ///
+    /// - `app_logger`: Optional application logger.
+    ///
+    /// If the application is to use it's own logger, but would also like the SDK to log SDK-specific
+    /// log output to a file in the configured `log_dir`, then do not register the
+    /// app-specific logger as a global logger and instead call this method with the app logger as an arg.
+    ///
+    /// ### Errors
+    ///
+    /// An error is thrown if the log file cannot be created in the working directory.
+    ///
+    /// An error is thrown if a global logger is already configured.
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>) -> Result<()> {
+        Self::init_logging_with_config(log_dir, Default::default(), app_logger)
+    }
+
+    /// Same as [LiquidSdk::init_logging], with a [LogFileConfig] setting the log levels per
+    /// module, the rotation size and retention of the log files, and their format.
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    pub fn init_logging_with_config(
+        log_dir: &str,
+        config: crate::logger::LogFileConfig,
+        app_logger: Option<Box<dyn log::Log>>,
+    ) -> Result<()> {
+        crate::logger::init_logging(log_dir, config, app_logger)
+    }
+}
+
+/// Extracts `description` from `metadata_str`
+fn extract_description_from_metadata(request_data: &LnUrlPayRequestData) -> Option<String> {
+    let metadata = request_data.metadata_vec().ok()?;
+    metadata
+        .iter()
+        .find(|item| item.key == "text/plain")
+        .map(|item| {
+            info!("Extracted payment description: '{}'", item.value);
+            item.value.clone()
+        })
+}
+
+/// Returns the swap events to emit for an updated `swap`, given its `last_seen` version
+fn get_swap_events(swap: &Swap, last_seen: Option<&SwapEventState>) -> Vec<SdkEvent> {
+    let swap_id = swap.id();
+    let mut events = vec![];
+    if last_seen.map(|s| s.state) != Some(swap.state()) {
+        events.push(SdkEvent::SwapUpdated {
+            swap_id: swap_id.clone(),
+            swap_type: swap.swap_type(),
+            state: swap.state(),
+        });
+    }
+    if let Some(tx_id) = swap.claim_tx_id() {
+        if last_seen.and_then(|s| s.claim_tx_id.as_ref()) != Some(&tx_id) {
+            events.push(SdkEvent::SwapClaimBroadcast {
+                swap_id: swap_id.clone(),
+                tx_id,
+            });
+        }
+    }
+    if let Some(tx_id) = swap.refund_tx_id() {
+        if last_seen.and_then(|s| s.refund_tx_id.as_ref()) != Some(&tx_id) {
+            events.push(SdkEvent::SwapRefundBroadcast { swap_id, tx_id });
+        }
+    }
+    events
+}
+
+/// Returns the largest amount up to `max_amount_sat` that, with its fees, fits within the
+/// `budget_sat`, or zero if none does. The fees must not decrease as the amount increases.
+fn max_amount_within_budget(
+    budget_sat: u64,
+    max_amount_sat: u64,
+    fees_sat: impl Fn(u64) -> u64,
+) -> u64 {
+    let fits = |amount_sat: u64| amount_sat.saturating_add(fees_sat(amount_sat)) <= budget_sat;
+    let (mut low, mut high) = (0, max_amount_sat.min(budget_sat));
+    if !fits(low) {
+        return 0;
+    }
+    while low < high {
+        let mid = low + (high - low).div_ceil(2);
+        match fits(mid) {
+            true => low = mid,
+            false => high = mid - 1,
+        }
+    }
+    low
+}
+
+/// Returns `amount_sat`, reduced so that with its `fees_sat` it doesn't exceed the `balance_sat`
+fn cap_amount_to_balance(amount_sat: u64, fees_sat: u64, balance_sat: u64) -> u64 {
+    amount_sat.min(balance_sat.saturating_sub(fees_sat))
+}
+
+/// Breaks down the fees of a payment to a Liquid address. When the payment can only be paid
+/// using the asset, the breakdown holds the fees of the payjoin tx instead, whose L-BTC network
+/// fee is paid by the payjoin service.
+fn liquid_fee_breakdown(
+    fees_sat: Option<u64>,
+    payjoin_fee: Option<&PayjoinFeeEstimate>,
+) -> Option<FeeBreakdown> {
+    match (fees_sat, payjoin_fee) {
+        (Some(fees_sat), _) => Some(FeeBreakdown::direct(fees_sat)),
+        (None, Some(payjoin_fee)) => Some(FeeBreakdown {
+            service_fee_sat: payjoin_fee.service_fee_sat,
+            ..FeeBreakdown::direct(payjoin_fee.network_fee_sat)
+        }),
+        (None, None) => None,
+    }
+}
+
+/// Ensures `amount_sat` does not exceed the `remaining_sat` of a spend limit
+fn ensure_spend_limit(amount_sat: u64, remaining_sat: u64) -> Result<(), PaymentError> {
+    ensure_sdk!(
+        amount_sat <= remaining_sat,
+        PaymentError::SpendLimitExceeded {
+            amount_sat,
+            remaining_sat,
+        }
+    );
+    Ok(())
+}
+
+/// Requests a service, returning its health and the response if the request succeeded
+async fn check_service<T, E: std::fmt::Display>(
+    service: HealthCheckService,
+    request: impl Future<Output = Result<T, E>>,
+) -> (ServiceHealth, Option<T>) {
+    let started = Instant::now();
+    let res = request.await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+    match res {
+        Ok(response) => (
+            ServiceHealth {
+                service,
+                is_reachable: true,
+                latency_ms: Some(latency_ms),
+                error: None,
+            },
+            Some(response),
+        ),
+        Err(e) => {
+            warn!("Health check of {service:?} failed: {e}");
+            (
+                ServiceHealth {
+                    service,
+                    is_reachable: false,
+                    latency_ms: None,
+                    error: Some(e.to_string()),
+                },
+                None,
+            )
+        }
+    }
+}
+
+/// Ensures the effect of a PSET on the wallet is within the limits of the [SignPsetRequest]
+fn ensure_pset_policy(
+    balance: &PsetWalletBalance,
+    req: &SignPsetRequest,
+) -> Result<(), PaymentError> {
+    ensure_fee_limit(balance.fee_sat, Some(req.max_fee_sat))?;
+    for (asset_id, amount) in &balance.balances {
+        if *amount >= 0 {
+            continue;
+        }
+        let spent_sat = amount.unsigned_abs();
+        let asset_id = asset_id.to_string();
+        let max_amount_sat = req
+            .spend_limits
+            .iter()
+            .find(|limit| limit.asset_id == asset_id)
+            .map(|limit| limit.max_amount_sat)
+            .unwrap_or_default();
+        ensure_sdk!(
+            spent_sat <= max_amount_sat,
+            PaymentError::Generic {
+                err: format!(
+                    "PSET spends {spent_sat} of asset {asset_id}, above the limit of {max_amount_sat}"
+                ),
+            }
+        );
+    }
+    Ok(())
+}
+
+/// Ensures `fees_sat` does not exceed `max_fee_sat`, if set
+fn ensure_fee_limit(fees_sat: u64, max_fee_sat: Option<u64>) -> Result<(), PaymentError> {
+    match max_fee_sat {
+        Some(max_fee_sat) if fees_sat > max_fee_sat => Err(PaymentError::FeeLimitExceeded {
+            fees_sat,
+            max_fee_sat,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Returns when to retry a queued or scheduled payment whose attempt number `attempts` failed
+/// with `e`, or `None` if it should be marked as failed
+fn background_payment_retry_at(e: &PaymentError, attempts: u32) -> Option<u32> {
+    (e.is_transient() && attempts < BACKGROUND_PAYMENT_MAX_ATTEMPTS)
+        .then(|| utils::now() + utils::retry_backoff_secs(attempts))
+}
+
+/// The idempotency key of the occurrence at `occurrence_at` of the recurring payment `id`
+fn recurring_payment_idempotency_key(id: &str, occurrence_at: u32) -> String {
+    format!("recurring-payment-{id}-{occurrence_at}")
+}
+
+/// Selects the worker handling the status updates of the swap with the given `swap_id`
+fn swap_update_worker_index(swap_id: &str) -> usize {
+    let mut hasher = DefaultHasher::new();
+    swap_id.hash(&mut hasher);
+    (hasher.finish() % SWAP_UPDATE_WORKERS as u64) as usize
+}
+
+/// Retains the fiat currencies for which there is an exchange rate in `rates`
+fn filter_fiat_currencies(currencies: Vec<FiatCurrency>, rates: &[Rate]) -> Vec<FiatCurrency> {
+    currencies
+        .into_iter()
+        .filter(|currency| {
+            rates
+                .iter()
+                .any(|rate| rate.coin.eq_ignore_ascii_case(&currency.id))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, str::FromStr, time::Duration};
+
+    use anyhow::{anyhow, Result};
+    use boltz_client::{
+        boltz::{self, TransactionInfo},
+        swaps::boltz::{ChainSwapStates, RevSwapStates, SubSwapStates},
+    };
+    use lwk_wollet::hashes::hex::DisplayHex as _;
+    use lwk_wollet::{Chain, WalletTx, WalletTxOut};
+    use sdk_common::input_parser::InputType;
+    use sdk_common::liquid::LiquidAddressData;
+    use sdk_common::prelude::{
+        CurrencyInfo, FiatAPI, FiatCurrency, MockResponse, MockRestClient, Rate,
+        ServiceConnectivityError, STAGING_BREEZSERVER_URL,
+    };
+    use sdk_common::utils::Arc;
+    use tokio_with_wasm::alias as tokio;
+
+    use crate::chain_swap::ESTIMATED_BTC_LOCKUP_TX_VSIZE;
+    use crate::error::{PaymentError, SdkError};
+    use crate::persist::swap_intent::SwapIntentKind;
+    use crate::test_utils::chain_swap::{
+        TEST_BITCOIN_OUTGOING_SERVER_LOCKUP_TX, TEST_LIQUID_INCOMING_SERVER_LOCKUP_TX,
+        TEST_LIQUID_OUTGOING_USER_LOCKUP_TX,
+    };
+    use crate::test_utils::swapper::ZeroAmountSwapMockConfig;
+    use crate::test_utils::wallet::{TEST_LIQUID_RECEIVE_LOCKUP_TX, TEST_LIQUID_TX};
+    use crate::wallet::{OnchainWallet, PsetWalletBalance};
+    use crate::{
+        bitcoin, elements,
+        model::{
+            AssetMetadata, BackupPolicy, BackupTransport, BackupTransportError, BatchSendItem,
+            BlockInfo, BtcHistory, CheckMessageRequest, Config, Direction,
+            ExportPaymentUnblindingDataRequest, FeeSpeed, FiatAmount, FiatRateProvider,
+            FiatRateProviderError, HealthCheckService, InputParser, LBtcHistory, LiquidNetwork,
+            PayAmount, PaymentDetails, PaymentMethod, PaymentState, PaymentSwapType, PaymentTxData,
+            PaymentType, PaymentUnblindingData, PrepareReceiveQuotesRequest,
+            PrepareReceiveQuotesResponse, PrepareReceiveRequest, PrepareRefundRequest,
+            PrepareSendRequest, PrepareSendResponse, PsetSpendLimit, ReceiveAmount, ReceiveSwap,
+            RecurringPayment, SchedulePaymentRequest, ScheduledPayment, ScheduledPaymentState,
+            SdkEvent, SendBatchRequest, SendDestination, SendPaymentRequest, SignMessageRequest,
+            SignPsetRequest, Signer, Swap, SwapInfo, SweepPrivateKeyRequest,
+        },
+        payjoin::model::PayjoinFeeEstimate,
+        sdk::{
+            cap_amount_to_balance, ensure_fee_limit, ensure_pset_policy, ensure_spend_limit,
+            filter_fiat_currencies, get_swap_events, liquid_fee_breakdown,
+            max_amount_within_budget, LiquidSdk, LiquidSdkBuilder, DEFAULT_SYNC_INTERVAL_SEC,
+        },
+        swapper::Swapper,
+        test_utils::{
+            chain::{MockBitcoinChainService, MockLiquidChainService},
+            chain_swap::{new_chain_swap, TEST_BITCOIN_INCOMING_USER_LOCKUP_TX},
+            persist::{create_persister, new_payment_tx_data, new_receive_swap, new_send_swap},
+            sdk::{
+                new_liquid_sdk, new_liquid_sdk_builder, new_liquid_sdk_with_chain_services,
+                new_liquid_sdk_with_fiat_api, new_liquid_sdk_with_wallet,
+            },
+            status_stream::MockStatusStream,
+            swapper::MockSwapper,
+            wallet::{MockSigner, MockWallet},
+        },
+        utils,
+    };
+    use paste::paste;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    struct NewSwapArgs {
+        direction: Direction,
+        accepts_zero_conf: bool,
+        initial_payment_state: Option<PaymentState>,
+        receiver_amount_sat: Option<u64>,
+        user_lockup_tx_id: Option<String>,
+        zero_amount: bool,
+        set_actual_payer_amount: bool,
+    }
+
+    impl Default for NewSwapArgs {
+        fn default() -> Self {
+            Self {
+                accepts_zero_conf: false,
+                initial_payment_state: None,
+                direction: Direction::Outgoing,
+                receiver_amount_sat: None,
+                user_lockup_tx_id: None,
+                zero_amount: false,
+                set_actual_payer_amount: false,
+            }
+        }
+    }
+
+    impl NewSwapArgs {
+        pub fn set_direction(mut self, direction: Direction) -> Self {
+            self.direction = direction;
+            self
+        }
+
+        pub fn set_accepts_zero_conf(mut self, accepts_zero_conf: bool) -> Self {
+            self.accepts_zero_conf = accepts_zero_conf;
+            self
+        }
+
+        pub fn set_receiver_amount_sat(mut self, receiver_amount_sat: Option<u64>) -> Self {
+            self.receiver_amount_sat = receiver_amount_sat;
+            self
+        }
+
+        pub fn set_user_lockup_tx_id(mut self, user_lockup_tx_id: Option<String>) -> Self {
+            self.user_lockup_tx_id = user_lockup_tx_id;
+            self
+        }
+
+        pub fn set_initial_payment_state(mut self, payment_state: PaymentState) -> Self {
+            self.initial_payment_state = Some(payment_state);
+            self
+        }
+
+        pub fn set_zero_amount(mut self, zero_amount: bool) -> Self {
+            self.zero_amount = zero_amount;
+            self
+        }
+
+        pub fn set_set_actual_payer_amount(mut self, set_actual_payer_amount: bool) -> Self {
+            self.set_actual_payer_amount = set_actual_payer_amount;
+            self
+        }
+    }
+
+    macro_rules! trigger_swap_update {
+        (
+            $type:literal,
+            $args:expr,
+            $persister:expr,
+            $status_stream:expr,
+            $status:expr,
+            $transaction:expr,
+            $zero_conf_rejected:expr
+        ) => {{
+            let swap = match $type {
+                "chain" => {
+                    let swap = new_chain_swap(
+                        $args.direction,
+                        $args.initial_payment_state,
+                        $args.accepts_zero_conf,
+                        $args.user_lockup_tx_id,
+                        $args.zero_amount,
+                        $args.set_actual_payer_amount,
+                        $args.receiver_amount_sat,
+                    );
+                    $persister.insert_or_update_chain_swap(&swap).unwrap();
+                    Swap::Chain(swap)
+                }
+                "send" => {
+                    let swap =
+                        new_send_swap($args.initial_payment_state, $args.receiver_amount_sat);
+                    $persister.insert_or_update_send_swap(&swap).unwrap();
+                    Swap::Send(swap)
+                }
+                "receive" => {
+                    let swap =
+                        new_receive_swap($args.initial_payment_state, $args.receiver_amount_sat);
+                    $persister.insert_or_update_receive_swap(&swap).unwrap();
+                    Swap::Receive(swap)
+                }
+                _ => panic!(),
+            };
+
+            $status_stream
+                .clone()
+                .send_mock_update(boltz::SwapStatus {
+                    id: swap.id(),
+                    status: $status.to_string(),
+                    transaction: $transaction,
+                    zero_conf_rejected: $zero_conf_rejected,
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+
+            paste! {
+                $persister.[<fetch _ $type _swap_by_id>](&swap.id())
+                    .unwrap()
+                    .ok_or(anyhow!("Could not retrieve {} swap", $type))
+                    .unwrap()
+            }
+        }};
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_receive_swap_update_tracking() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
+        let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
+
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            swapper.clone(),
+            status_stream.clone(),
+            liquid_chain_service.clone(),
+            bitcoin_chain_service.clone(),
+            None,
+        )
+        .await?;
+
+        LiquidSdk::track_swap_updates(&sdk);
+
+        // We spawn a new thread since updates can only be sent when called via async runtimes
+        tokio::spawn(async move {
+            // Verify the swap becomes invalid after final states are received
+            let unrecoverable_states: [RevSwapStates; 4] = [
+                RevSwapStates::SwapExpired,
+                RevSwapStates::InvoiceExpired,
+                RevSwapStates::TransactionFailed,
+                RevSwapStates::TransactionRefunded,
+            ];
+
+            for status in unrecoverable_states {
+                let persisted_swap = trigger_swap_update!(
+                    "receive",
+                    NewSwapArgs::default(),
+                    persister,
+                    status_stream,
+                    status,
+                    None,
+                    None
+                );
+                assert_eq!(persisted_swap.state, PaymentState::Failed);
+            }
+
+            // Check that `TransactionMempool` and `TransactionConfirmed` correctly trigger the claim,
+            // which in turn sets the `claim_tx_id`
+            for status in [
+                RevSwapStates::TransactionMempool,
+                RevSwapStates::TransactionConfirmed,
+            ] {
+                let mock_tx = TEST_LIQUID_RECEIVE_LOCKUP_TX.clone();
+                let mock_tx_id = mock_tx.txid();
+                let height = (serde_json::to_string(&status).unwrap()
+                    == serde_json::to_string(&RevSwapStates::TransactionConfirmed).unwrap())
+                    as i32;
+                liquid_chain_service.set_history(vec![LBtcHistory {
+                    txid: mock_tx_id,
+                    height,
+                }]);
+
+                let persisted_swap = trigger_swap_update!(
+                    "receive",
+                    NewSwapArgs::default(),
+                    persister,
+                    status_stream,
+                    status,
+                    Some(TransactionInfo {
+                        id: mock_tx_id.to_string(),
+                        hex: Some(
+                            lwk_wollet::elements::encode::serialize(&mock_tx).to_lower_hex_string()
+                        ),
+                        eta: None,
+                    }),
+                    None
+                );
+                assert!(persisted_swap.claim_tx_id.is_some());
+            }
+
+            // Check that `TransactionMempool` and `TransactionConfirmed` checks the lockup amount
+            // and doesn't claim if not verified
+            for status in [
+                RevSwapStates::TransactionMempool,
+                RevSwapStates::TransactionConfirmed,
+            ] {
+                let mock_tx = TEST_LIQUID_RECEIVE_LOCKUP_TX.clone();
+                let mock_tx_id = mock_tx.txid();
+                let height = (serde_json::to_string(&status).unwrap()
+                    == serde_json::to_string(&RevSwapStates::TransactionConfirmed).unwrap())
+                    as i32;
+                liquid_chain_service.set_history(vec![LBtcHistory {
+                    txid: mock_tx_id,
+                    height,
+                }]);
+
+                let persisted_swap = trigger_swap_update!(
+                    "receive",
+                    NewSwapArgs::default().set_receiver_amount_sat(Some(1000)),
+                    persister,
+                    status_stream,
+                    status,
+                    Some(TransactionInfo {
+                        id: mock_tx_id.to_string(),
+                        hex: Some(
+                            lwk_wollet::elements::encode::serialize(&mock_tx).to_lower_hex_string()
+                        ),
+                        eta: None
+                    }),
+                    None
+                );
+                assert!(persisted_swap.claim_tx_id.is_none());
+            }
+        })
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_send_swap_update_tracking() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        let sdk = Arc::new(
+            new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone()).await?,
+        );
+
+        LiquidSdk::track_swap_updates(&sdk);
+
+        // We spawn a new thread since updates can only be sent when called via async runtimes
+        tokio::spawn(async move {
+            // Verify the swap becomes invalid after final states are received
+            let unrecoverable_states: [SubSwapStates; 3] = [
+                SubSwapStates::TransactionLockupFailed,
+                SubSwapStates::InvoiceFailedToPay,
+                SubSwapStates::SwapExpired,
+            ];
+
+            for status in unrecoverable_states {
+                let persisted_swap = trigger_swap_update!(
+                    "send",
+                    NewSwapArgs::default(),
+                    persister,
+                    status_stream,
+                    status,
+                    None,
+                    None
+                );
+                assert_eq!(persisted_swap.state, PaymentState::Failed);
+            }
+
+            // Verify that `TransactionClaimPending` correctly sets the state to `Complete`
+            // and stores the preimage
+            let persisted_swap = trigger_swap_update!(
+                "send",
+                NewSwapArgs::default(),
+                persister,
+                status_stream,
+                SubSwapStates::TransactionClaimPending,
+                None,
+                None
+            );
+            assert_eq!(persisted_swap.state, PaymentState::Complete);
+            assert!(persisted_swap.preimage.is_some());
+        })
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_chain_swap_update_tracking() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
+        let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
+
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            swapper.clone(),
+            status_stream.clone(),
+            liquid_chain_service.clone(),
+            bitcoin_chain_service.clone(),
+            None,
+        )
+        .await?;
+
+        LiquidSdk::track_swap_updates(&sdk);
+
+        // We spawn a new thread since updates can only be sent when called via async runtimes
+        tokio::spawn(async move {
+            let trigger_failed: [ChainSwapStates; 3] = [
+                ChainSwapStates::TransactionFailed,
+                ChainSwapStates::SwapExpired,
+                ChainSwapStates::TransactionRefunded,
+            ];
+
+            // Checks that work for both incoming and outgoing chain swaps
+            for direction in [Direction::Incoming, Direction::Outgoing] {
+                // Verify the swap becomes invalid after final states are received
+                for status in &trigger_failed {
+                    let persisted_swap = trigger_swap_update!(
+                        "chain",
+                        NewSwapArgs::default().set_direction(direction),
+                        persister,
+                        status_stream,
+                        status,
+                        None,
+                        None
+                    );
+                    assert_eq!(persisted_swap.state, PaymentState::Failed);
+                }
+
+                let (mock_user_lockup_tx_hex, mock_user_lockup_tx_id) = match direction {
+                    Direction::Outgoing => {
+                        let tx = TEST_LIQUID_OUTGOING_USER_LOCKUP_TX.clone();
+                        (
+                            lwk_wollet::elements::encode::serialize(&tx).to_lower_hex_string(),
+                            tx.txid().to_string(),
+                        )
+                    }
+                    Direction::Incoming => {
+                        let tx = TEST_BITCOIN_INCOMING_USER_LOCKUP_TX.clone();
+                        (
+                            sdk_common::bitcoin::consensus::serialize(&tx).to_lower_hex_string(),
+                            tx.txid().to_string(),
+                        )
+                    }
+                };
+
+                let (mock_server_lockup_tx_hex, mock_server_lockup_tx_id) = match direction {
+                    Direction::Incoming => {
+                        let tx = TEST_LIQUID_INCOMING_SERVER_LOCKUP_TX.clone();
+                        (
+                            lwk_wollet::elements::encode::serialize(&tx).to_lower_hex_string(),
+                            tx.txid().to_string(),
+                        )
+                    }
+                    Direction::Outgoing => {
+                        let tx = TEST_BITCOIN_OUTGOING_SERVER_LOCKUP_TX.clone();
+                        (
+                            sdk_common::bitcoin::consensus::serialize(&tx).to_lower_hex_string(),
+                            tx.txid().to_string(),
+                        )
+                    }
+                };
 
-        for tx in non_swap_wallet_tx_map.values() {
-            let tx_id = tx.txid.to_string();
-            let maybe_payment = payments.get(&tx_id);
-            let mut updated = false;
-            match maybe_payment {
-                // When no payment is found or its a Liquid payment
+                // Verify that `TransactionLockupFailed` correctly sets the state as
+                // `RefundPending`/`Refundable` or as `Failed` depending on whether or not
+                // `user_lockup_tx_id` is present
+                for user_lockup_tx_id in &[None, Some(mock_user_lockup_tx_id.clone())] {
+                    if let Some(user_lockup_tx_id) = user_lockup_tx_id {
+                        match direction {
+                            Direction::Incoming => {
+                                bitcoin_chain_service.set_history(vec![BtcHistory {
+                                    txid: bitcoin::Txid::from_str(user_lockup_tx_id).unwrap(),
+                                    height: 0,
+                                }]);
+                            }
+                            Direction::Outgoing => {
+                                liquid_chain_service.set_history(vec![LBtcHistory {
+                                    txid: elements::Txid::from_str(user_lockup_tx_id).unwrap(),
+                                    height: 0,
+                                }]);
+                            }
+                        }
+                    }
+                    let persisted_swap = trigger_swap_update!(
+                        "chain",
+                        NewSwapArgs::default()
+                            .set_direction(direction)
+                            .set_initial_payment_state(PaymentState::Pending)
+                            .set_user_lockup_tx_id(user_lockup_tx_id.clone()),
+                        persister,
+                        status_stream,
+                        ChainSwapStates::TransactionLockupFailed,
+                        None,
+                        None
+                    );
+                    let expected_state = if user_lockup_tx_id.is_some() {
+                        match direction {
+                            Direction::Incoming => PaymentState::Refundable,
+                            Direction::Outgoing => PaymentState::RefundPending,
+                        }
+                    } else {
+                        PaymentState::Failed
+                    };
+                    assert_eq!(persisted_swap.state, expected_state);
+                }
+
+                // Verify that `TransactionMempool` and `TransactionConfirmed` correctly set
+                // `user_lockup_tx_id` and `accept_zero_conf`
+                for status in [
+                    ChainSwapStates::TransactionMempool,
+                    ChainSwapStates::TransactionConfirmed,
+                ] {
+                    if direction == Direction::Incoming {
+                        bitcoin_chain_service.set_history(vec![BtcHistory {
+                            txid: bitcoin::Txid::from_str(&mock_user_lockup_tx_id).unwrap(),
+                            height: 0,
+                        }]);
+                        bitcoin_chain_service.set_transactions(&[&mock_user_lockup_tx_hex]);
+                    }
+                    let persisted_swap = trigger_swap_update!(
+                        "chain",
+                        NewSwapArgs::default().set_direction(direction),
+                        persister,
+                        status_stream,
+                        status,
+                        Some(TransactionInfo {
+                            id: mock_user_lockup_tx_id.clone(),
+                            hex: Some(mock_user_lockup_tx_hex.clone()),
+                            eta: None
+                        }), // sets `update.transaction`
+                        Some(true) // sets `update.zero_conf_rejected`
+                    );
+                    assert_eq!(
+                        persisted_swap.user_lockup_tx_id,
+                        Some(mock_user_lockup_tx_id.clone())
+                    );
+                    assert!(!persisted_swap.accept_zero_conf);
+                }
+
+                // Verify that `TransactionServerMempool` correctly:
+                // 1. Sets the payment as `Pending` and creates `server_lockup_tx_id` when
+                //    `accepts_zero_conf` is false
+                // 2. Sets the payment as `Pending` and creates `claim_tx_id` when `accepts_zero_conf`
+                //    is true
+                for accepts_zero_conf in [false, true] {
+                    let persisted_swap = trigger_swap_update!(
+                        "chain",
+                        NewSwapArgs::default()
+                            .set_direction(direction)
+                            .set_accepts_zero_conf(accepts_zero_conf)
+                            .set_set_actual_payer_amount(true),
+                        persister,
+                        status_stream,
+                        ChainSwapStates::TransactionServerMempool,
+                        Some(TransactionInfo {
+                            id: mock_server_lockup_tx_id.clone(),
+                            hex: Some(mock_server_lockup_tx_hex.clone()),
+                            eta: None,
+                        }),
+                        None
+                    );
+                    match accepts_zero_conf {
+                        false => {
+                            assert_eq!(persisted_swap.state, PaymentState::Pending);
+                            assert!(persisted_swap.server_lockup_tx_id.is_some());
+                        }
+                        true => {
+                            assert_eq!(persisted_swap.state, PaymentState::Pending);
+                            assert!(persisted_swap.claim_tx_id.is_some());
+                        }
+                    };
+                }
+
+                // Verify that `TransactionServerConfirmed` correctly
+                // sets the payment as `Pending` and creates `claim_tx_id`
+                let persisted_swap = trigger_swap_update!(
+                    "chain",
+                    NewSwapArgs::default()
+                        .set_direction(direction)
+                        .set_set_actual_payer_amount(true),
+                    persister,
+                    status_stream,
+                    ChainSwapStates::TransactionServerConfirmed,
+                    Some(TransactionInfo {
+                        id: mock_server_lockup_tx_id,
+                        hex: Some(mock_server_lockup_tx_hex),
+                        eta: None,
+                    }),
+                    None
+                );
+                assert_eq!(persisted_swap.state, PaymentState::Pending);
+                assert!(persisted_swap.claim_tx_id.is_some());
+            }
+
+            // For outgoing payments, verify that `Created` correctly sets the payment as `Pending` and creates
+            // the `user_lockup_tx_id`
+            let persisted_swap = trigger_swap_update!(
+                "chain",
+                NewSwapArgs::default().set_direction(Direction::Outgoing),
+                persister,
+                status_stream,
+                ChainSwapStates::Created,
+                None,
                 None
-                | Some(Payment {
-                    details: PaymentDetails::Liquid { .. },
-                    ..
-                }) => {
-                    let updated_needed = maybe_payment
-                        .is_none_or(|payment| payment.status == Pending && tx.height.is_some());
-                    if updated_needed {
-                        // An unknown tx which needs inserting or a known Liquid payment tx
-                        // that was in the mempool, but is now confirmed
-                        self.persister.insert_or_update_payment_with_wallet_tx(tx)?;
-                        self.emit_payment_updated(Some(tx_id.clone())).await?;
-                        updated = true
+            );
+            assert_eq!(persisted_swap.state, PaymentState::Pending);
+            assert!(persisted_swap.user_lockup_tx_id.is_some());
+        })
+        .await
+        .unwrap();
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_zero_amount_chain_swap_zero_leeway() -> Result<()> {
+        let user_lockup_sat = 50_000;
+
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::new());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
+        let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
+
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            swapper.clone(),
+            status_stream.clone(),
+            liquid_chain_service.clone(),
+            bitcoin_chain_service.clone(),
+            None,
+        )
+        .await?;
+
+        LiquidSdk::track_swap_updates(&sdk);
+
+        // We spawn a new thread since updates can only be sent when called via async runtimes
+        tokio::spawn(async move {
+            // Verify that `TransactionLockupFailed` correctly:
+            // 1. does not affect state when swapper doesn't increase fees
+            // 2. triggers a change to WaitingFeeAcceptance when there is a fee increase > 0
+            for fee_increase in [0, 1] {
+                swapper.set_zero_amount_swap_mock_config(ZeroAmountSwapMockConfig {
+                    user_lockup_sat,
+                    onchain_fee_increase_sat: fee_increase,
+                });
+                bitcoin_chain_service.set_script_balance_sat(user_lockup_sat);
+                let persisted_swap = trigger_swap_update!(
+                    "chain",
+                    NewSwapArgs::default()
+                        .set_direction(Direction::Incoming)
+                        .set_accepts_zero_conf(false)
+                        .set_zero_amount(true),
+                    persister,
+                    status_stream,
+                    ChainSwapStates::TransactionLockupFailed,
+                    None,
+                    None
+                );
+                match fee_increase {
+                    0 => {
+                        assert_eq!(persisted_swap.state, PaymentState::Created);
+                    }
+                    1 => {
+                        assert_eq!(persisted_swap.state, PaymentState::WaitingFeeAcceptance);
                     }
+                    _ => panic!("Unexpected fee_increase"),
                 }
+            }
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_zero_amount_chain_swap_with_leeway() -> Result<()> {
+        let user_lockup_sat = 50_000;
+        let onchain_fee_rate_leeway_sat_per_vbyte = 5;
+
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::new());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
+        let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
+
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            swapper.clone(),
+            status_stream.clone(),
+            liquid_chain_service.clone(),
+            bitcoin_chain_service.clone(),
+            Some(onchain_fee_rate_leeway_sat_per_vbyte),
+        )
+        .await?;
 
-                _ => {}
-            }
-            if !updated && unconfirmed_txs_by_id.contains_key(&tx_id) && tx.height.is_some() {
-                // An unconfirmed tx that was not found in the payments table
-                self.persister.insert_or_update_payment_with_wallet_tx(tx)?;
-            }
-        }
+        LiquidSdk::track_swap_updates(&sdk);
 
-        let unknown_unconfirmed_txs: Vec<_> = unconfirmed_txs_by_id
-            .iter()
-            .filter(|(txid, _)| !all_wallet_tx_ids.contains(*txid))
-            .map(|(_, tx)| tx)
-            .collect();
+        let max_fee_increase_for_auto_accept_sat =
+            onchain_fee_rate_leeway_sat_per_vbyte as u64 * ESTIMATED_BTC_LOCKUP_TX_VSIZE;
 
-        for unknown_unconfirmed_tx in unknown_unconfirmed_txs {
-            if unknown_unconfirmed_tx.timestamp.is_some_and(|t| {
-                (utils::now().saturating_sub(t)) > NETWORK_PROPAGATION_GRACE_PERIOD.as_secs() as u32
-            }) {
-                self.persister
-                    .delete_payment_tx_data(&unknown_unconfirmed_tx.tx_id)?;
-                info!(
-                    "Found an unknown unconfirmed tx and deleted it. Txid: {}",
-                    unknown_unconfirmed_tx.tx_id
+        // We spawn a new thread since updates can only be sent when called via async runtimes
+        tokio::spawn(async move {
+            // Verify that `TransactionLockupFailed` correctly:
+            // 1. does not affect state when swapper increases fee by up to sat/vbyte leeway * tx size
+            // 2. triggers a change to WaitingFeeAcceptance when it is any higher
+            for fee_increase in [
+                max_fee_increase_for_auto_accept_sat,
+                max_fee_increase_for_auto_accept_sat + 1,
+            ] {
+                swapper.set_zero_amount_swap_mock_config(ZeroAmountSwapMockConfig {
+                    user_lockup_sat,
+                    onchain_fee_increase_sat: fee_increase,
+                });
+                bitcoin_chain_service.set_script_balance_sat(user_lockup_sat);
+                let persisted_swap = trigger_swap_update!(
+                    "chain",
+                    NewSwapArgs::default()
+                        .set_direction(Direction::Incoming)
+                        .set_accepts_zero_conf(false)
+                        .set_zero_amount(true),
+                    persister,
+                    status_stream,
+                    ChainSwapStates::TransactionLockupFailed,
+                    None,
+                    None
                 );
-            } else {
-                debug!(
-                    "Found an unknown unconfirmed tx that was inserted at {:?}. \
-                Keeping it to allow propagation through the network. Txid: {}",
-                    unknown_unconfirmed_tx.timestamp, unknown_unconfirmed_tx.tx_id
-                )
+                match fee_increase {
+                    val if val == max_fee_increase_for_auto_accept_sat => {
+                        assert_eq!(persisted_swap.state, PaymentState::Created);
+                    }
+                    val if val == (max_fee_increase_for_auto_accept_sat + 1) => {
+                        assert_eq!(persisted_swap.state, PaymentState::WaitingFeeAcceptance);
+                    }
+                    _ => panic!("Unexpected fee_increase"),
+                }
             }
-        }
+        })
+        .await?;
 
-        self.update_wallet_info().await?;
         Ok(())
     }
 
-    async fn update_wallet_info(&self) -> Result<()> {
-        let asset_metadata: HashMap<String, AssetMetadata> = self
-            .persister
-            .list_asset_metadata()?
-            .into_iter()
-            .map(|am| (am.asset_id.clone(), am))
-            .collect();
-        let transactions = self.onchain_wallet.transactions().await?;
-        let tx_ids = transactions
-            .iter()
-            .map(|tx| tx.txid.to_string())
-            .collect::<Vec<_>>();
-        let asset_balances = transactions
-            .into_iter()
-            .fold(BTreeMap::<AssetId, i64>::new(), |mut acc, tx| {
-                tx.balance.into_iter().for_each(|(asset_id, balance)| {
-                    // Consider only confirmed unspent outputs (confirmed transactions output reduced by unconfirmed spent outputs)
-                    if tx.height.is_some() || balance < 0 {
-                        *acc.entry(asset_id).or_default() += balance;
-                    }
-                });
-                acc
-            })
-            .into_iter()
-            .map(|(asset_id, balance)| {
-                let asset_id = asset_id.to_hex();
-                let balance_sat = balance.unsigned_abs();
-                let maybe_asset_metadata = asset_metadata.get(&asset_id);
-                AssetBalance {
-                    asset_id,
-                    balance_sat,
-                    name: maybe_asset_metadata.map(|am| am.name.clone()),
-                    ticker: maybe_asset_metadata.map(|am| am.ticker.clone()),
-                    balance: maybe_asset_metadata.map(|am| am.amount_from_sat(balance_sat)),
-                }
+    #[sdk_macros::test_all]
+    fn test_get_swap_events() {
+        let mut receive_swap = new_receive_swap(Some(PaymentState::Created), None);
+        let swap_id = receive_swap.id.clone();
+        let created = Swap::Receive(receive_swap.clone());
+        assert_eq!(
+            get_swap_events(&created, None),
+            vec![SdkEvent::SwapUpdated {
+                swap_id: swap_id.clone(),
+                swap_type: PaymentSwapType::Receive,
+                state: PaymentState::Created,
+            }]
+        );
+        assert!(get_swap_events(&created, Some(&(&created).into())).is_empty());
+
+        receive_swap.state = PaymentState::Pending;
+        receive_swap.claim_tx_id = Some("claim-tx-id".to_string());
+        let claimed = Swap::Receive(receive_swap);
+        assert_eq!(
+            get_swap_events(&claimed, Some(&(&created).into())),
+            vec![
+                SdkEvent::SwapUpdated {
+                    swap_id: swap_id.clone(),
+                    swap_type: PaymentSwapType::Receive,
+                    state: PaymentState::Pending,
+                },
+                SdkEvent::SwapClaimBroadcast {
+                    swap_id: swap_id.clone(),
+                    tx_id: "claim-tx-id".to_string(),
+                },
+            ]
+        );
+        assert!(get_swap_events(&claimed, Some(&(&claimed).into())).is_empty());
+
+        let mut send_swap = new_send_swap(Some(PaymentState::RefundPending), None);
+        send_swap.refund_tx_id = Some("refund-tx-id".to_string());
+        let refunding = Swap::Send(send_swap.clone());
+        send_swap.state = PaymentState::Failed;
+        let failed = Swap::Send(send_swap.clone());
+        assert_eq!(
+            get_swap_events(&failed, Some(&(&refunding).into())),
+            vec![SdkEvent::SwapUpdated {
+                swap_id: send_swap.id,
+                swap_type: PaymentSwapType::Send,
+                state: PaymentState::Failed,
+            }]
+        );
+    }
+
+    #[sdk_macros::test_all]
+    fn test_ensure_fee_limit() {
+        assert!(ensure_fee_limit(100, None).is_ok());
+        assert!(ensure_fee_limit(100, Some(100)).is_ok());
+        assert!(matches!(
+            ensure_fee_limit(101, Some(100)),
+            Err(PaymentError::FeeLimitExceeded {
+                fees_sat: 101,
+                max_fee_sat: 100
             })
-            .collect::<Vec<AssetBalance>>();
-        let mut balance_sat = asset_balances
-            .clone()
-            .into_iter()
-            .find(|ab| ab.asset_id.eq(&self.config.lbtc_asset_id()))
-            .map_or(0, |ab| ab.balance_sat);
+        ));
+    }
 
-        let mut pending_send_sat = 0;
-        let mut pending_receive_sat = 0;
-        let payments = self.persister.get_payments(&ListPaymentsRequest {
-            states: Some(vec![
-                PaymentState::Pending,
-                PaymentState::RefundPending,
-                PaymentState::WaitingFeeAcceptance,
-            ]),
-            ..Default::default()
-        })?;
+    #[sdk_macros::test_all]
+    fn test_ensure_pset_policy() {
+        let lbtc_asset_id = utils::lbtc_asset_id(LiquidNetwork::Regtest);
+        let other_asset_id = elements::AssetId::from_slice(&[2; 32]).unwrap();
+        let balance = PsetWalletBalance {
+            fee_sat: 30,
+            balances: HashMap::from([(lbtc_asset_id, -1_030), (other_asset_id, 500)]),
+        };
+        let req = |max_fee_sat, max_amount_sat| SignPsetRequest {
+            pset: String::new(),
+            max_fee_sat,
+            spend_limits: vec![PsetSpendLimit {
+                asset_id: lbtc_asset_id.to_string(),
+                max_amount_sat,
+            }],
+        };
 
-        for payment in payments {
-            let is_lbtc_asset_id = payment.details.is_lbtc_asset_id(self.config.network);
-            match payment.payment_type {
-                PaymentType::Send => match payment.details.get_refund_tx_amount_sat() {
-                    Some(refund_tx_amount_sat) => pending_receive_sat += refund_tx_amount_sat,
-                    None => {
-                        let total_sat = if is_lbtc_asset_id {
-                            payment.amount_sat + payment.fees_sat
-                        } else {
-                            payment.fees_sat
-                        };
-                        if let Some(tx_id) = payment.tx_id {
-                            if !tx_ids.contains(&tx_id) {
-                                debug!("Deducting {total_sat} sats from balance");
-                                balance_sat = balance_sat.saturating_sub(total_sat);
-                            }
-                        }
-                        pending_send_sat += total_sat
-                    }
-                },
-                PaymentType::Receive => {
-                    if is_lbtc_asset_id {
-                        pending_receive_sat += payment.amount_sat;
-                    }
-                }
-            }
-        }
+        // Receiving an asset doesn't need a spend limit
+        assert!(ensure_pset_policy(&balance, &req(30, 1_030)).is_ok());
+        assert!(matches!(
+            ensure_pset_policy(&balance, &req(29, 1_030)),
+            Err(PaymentError::FeeLimitExceeded { .. })
+        ));
+        assert!(ensure_pset_policy(&balance, &req(30, 1_029)).is_err());
 
-        debug!("Onchain wallet balance: {balance_sat} sats");
-        let info_response = WalletInfo {
-            balance_sat,
-            pending_send_sat,
-            pending_receive_sat,
-            fingerprint: self.onchain_wallet.fingerprint()?,
-            pubkey: self.onchain_wallet.pubkey()?,
-            asset_balances,
+        // Spending an asset without a spend limit is rejected
+        let balance = PsetWalletBalance {
+            fee_sat: 30,
+            balances: HashMap::from([(lbtc_asset_id, -30), (other_asset_id, -500)]),
         };
-        self.persister.set_wallet_info(&info_response)
+        assert!(ensure_pset_policy(&balance, &req(30, 1_030)).is_err());
     }
 
-    /// Lists the SDK payments in reverse chronological order, from newest to oldest.
-    /// The payments are determined based on onchain transactions and swaps.
-    pub async fn list_payments(
-        &self,
-        req: &ListPaymentsRequest,
-    ) -> Result<Vec<Payment>, PaymentError> {
-        self.ensure_is_started().await?;
+    #[sdk_macros::test_all]
+    fn test_ensure_spend_limit() {
+        assert!(ensure_spend_limit(100, 100).is_ok());
+        assert!(matches!(
+            ensure_spend_limit(101, 100),
+            Err(PaymentError::SpendLimitExceeded {
+                amount_sat: 101,
+                remaining_sat: 100
+            })
+        ));
+    }
 
-        Ok(self.persister.get_payments(req)?)
+    #[sdk_macros::test_all]
+    fn test_max_amount_within_budget() {
+        // 1% fee, rounded up, plus a fixed fee of 10 sats
+        let fees_sat = |amount_sat: u64| amount_sat.div_ceil(100) + 10;
+        assert_eq!(max_amount_within_budget(10_110, 100_000, fees_sat), 10_000);
+        assert_eq!(max_amount_within_budget(10_109, 100_000, fees_sat), 9_999);
+        assert_eq!(max_amount_within_budget(10_110, 5_000, fees_sat), 5_000);
+        assert_eq!(max_amount_within_budget(10, 100_000, fees_sat), 0);
+        assert_eq!(max_amount_within_budget(5, 100_000, fees_sat), 0);
     }
 
-    /// Retrieves a payment.
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [GetPaymentRequest] containing:
-    ///     * [GetPaymentRequest::Lightning] - the `payment_hash` of the lightning invoice
-    ///
-    /// # Returns
-    ///
-    /// Returns an `Option<Payment>` if found, or `None` if no payment matches the given request.
-    pub async fn get_payment(
-        &self,
-        req: &GetPaymentRequest,
-    ) -> Result<Option<Payment>, PaymentError> {
-        self.ensure_is_started().await?;
+    #[sdk_macros::test_all]
+    fn test_cap_amount_to_balance() {
+        assert_eq!(cap_amount_to_balance(9_000, 1_000, 10_000), 9_000);
+        assert_eq!(cap_amount_to_balance(9_000, 500, 10_000), 9_000);
+
+        // A lockup tx with change costs more than the drain tx the amount was computed with
+        let balance_sat = 10_110;
+        let drain_fees_sat = 30;
+        let fees_sat = |amount_sat: u64| amount_sat.div_ceil(100) + 10;
+        let amount_sat = max_amount_within_budget(balance_sat - drain_fees_sat, 100_000, fees_sat);
+        let lockup_fees_sat = 45;
+        let capped_sat = cap_amount_to_balance(
+            amount_sat,
+            fees_sat(amount_sat) + lockup_fees_sat,
+            balance_sat,
+        );
+        assert!(capped_sat < amount_sat);
+        assert!(capped_sat + fees_sat(capped_sat) + lockup_fees_sat <= balance_sat);
 
-        Ok(self.persister.get_payment_by_request(req)?)
+        assert_eq!(cap_amount_to_balance(9_000, 11_000, 10_000), 0);
     }
 
-    /// Fetches an up-to-date fees proposal for a [Payment] that is [WaitingFeeAcceptance].
-    ///
-    /// Use [LiquidSdk::accept_payment_proposed_fees] to accept the proposed fees and proceed
-    /// with the payment.
-    pub async fn fetch_payment_proposed_fees(
-        &self,
-        req: &FetchPaymentProposedFeesRequest,
-    ) -> SdkResult<FetchPaymentProposedFeesResponse> {
-        let chain_swap =
-            self.persister
-                .fetch_chain_swap_by_id(&req.swap_id)?
-                .ok_or(SdkError::Generic {
-                    err: format!("Could not find Swap {}", req.swap_id),
-                })?;
+    #[sdk_macros::test_all]
+    fn test_liquid_fee_breakdown() {
+        let payjoin_fee = PayjoinFeeEstimate {
+            asset_fee: 0.09,
+            service_fee_sat: 40,
+            network_fee_sat: 50,
+        };
 
-        ensure_sdk!(
-            chain_swap.state == WaitingFeeAcceptance,
-            SdkError::Generic {
-                err: "Payment is not WaitingFeeAcceptance".to_string()
-            }
-        );
+        // The L-BTC fees are broken down when they can be paid
+        let fee_breakdown = liquid_fee_breakdown(Some(30), Some(&payjoin_fee)).unwrap();
+        assert_eq!(fee_breakdown.service_fee_sat, 0);
+        assert_eq!(fee_breakdown.lockup_fee_sat, 30);
+        assert!(fee_breakdown.lockup_fee_is_estimate);
+        assert_eq!(fee_breakdown.claim_fee_sat, 0);
+        assert_eq!(fee_breakdown.total(), 30);
+
+        // Otherwise the payjoin fees are broken down
+        let fee_breakdown = liquid_fee_breakdown(None, Some(&payjoin_fee)).unwrap();
+        assert_eq!(fee_breakdown.service_fee_sat, 40);
+        assert_eq!(fee_breakdown.lockup_fee_sat, 50);
+        assert!(fee_breakdown.lockup_fee_is_estimate);
+        assert_eq!(fee_breakdown.claim_fee_sat, 0);
+        assert_eq!(fee_breakdown.total(), 90);
+
+        assert!(liquid_fee_breakdown(None, None).is_none());
+    }
 
-        let server_lockup_quote = self
-            .swapper
-            .get_zero_amount_chain_swap_quote(&req.swap_id)
-            .await?;
+    #[sdk_macros::test_all]
+    fn test_swap_info() {
+        let send_swap = new_send_swap(Some(PaymentState::Pending), None);
+        let info = SwapInfo::from(Swap::Send(send_swap.clone()));
+        assert_eq!(info.swap_id, send_swap.id);
+        assert_eq!(info.swap_type, PaymentSwapType::Send);
+        assert_eq!(info.direction, None);
+        assert_eq!(info.state, PaymentState::Pending);
+        assert_eq!(info.invoice, Some(send_swap.invoice));
+        assert_eq!(info.user_lockup_tx_id, send_swap.lockup_tx_id);
+        assert_eq!(info.create_response_json, send_swap.create_response_json);
+
+        let mut receive_swap = new_receive_swap(Some(PaymentState::Pending), None);
+        receive_swap.claim_tx_id = Some("claim-tx-id".to_string());
+        let info = SwapInfo::from(Swap::Receive(receive_swap.clone()));
+        assert_eq!(info.swap_type, PaymentSwapType::Receive);
+        assert_eq!(info.server_lockup_tx_id, receive_swap.lockup_tx_id);
+        assert_eq!(info.claim_tx_id, Some("claim-tx-id".to_string()));
+        assert_eq!(info.refund_tx_id, None);
+    }
 
-        let actual_payer_amount_sat =
-            chain_swap
-                .actual_payer_amount_sat
-                .ok_or(SdkError::Generic {
-                    err: "No actual payer amount found when state is WaitingFeeAcceptance"
-                        .to_string(),
-                })?;
-        let fees_sat =
-            actual_payer_amount_sat - server_lockup_quote.to_sat() + chain_swap.claim_fees_sat;
+    #[sdk_macros::test_all]
+    fn test_swap_update_worker_index() {
+        for i in 0..100 {
+            let swap_id = format!("swap-{i}");
+            let index = swap_update_worker_index(&swap_id);
+            assert!(index < SWAP_UPDATE_WORKERS);
+            assert_eq!(index, swap_update_worker_index(&swap_id));
+        }
+    }
 
-        Ok(FetchPaymentProposedFeesResponse {
-            swap_id: req.swap_id.clone(),
-            fees_sat,
-            payer_amount_sat: actual_payer_amount_sat,
-            receiver_amount_sat: actual_payer_amount_sat - fees_sat,
-        })
+    #[sdk_macros::test_all]
+    fn test_filter_fiat_currencies() {
+        let currency = |id: &str| FiatCurrency {
+            id: id.to_string(),
+            info: CurrencyInfo {
+                name: id.to_string(),
+                fraction_size: 2,
+                spacing: None,
+                symbol: None,
+                uniq_symbol: None,
+                localized_name: vec![],
+                locale_overrides: vec![],
+            },
+        };
+        let rates = vec![Rate {
+            coin: "usd".to_string(),
+            value: 100_000.0,
+        }];
+
+        let filtered = filter_fiat_currencies(vec![currency("EUR"), currency("USD")], &rates);
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].id, "USD");
     }
 
-    /// Accepts proposed fees for a [Payment] that is [WaitingFeeAcceptance].
-    ///
-    /// Use [LiquidSdk::fetch_payment_proposed_fees] to get an up-to-date fees proposal.
-    pub async fn accept_payment_proposed_fees(
-        &self,
-        req: &AcceptPaymentProposedFeesRequest,
-    ) -> Result<(), PaymentError> {
-        let FetchPaymentProposedFeesResponse {
-            swap_id,
-            fees_sat,
-            payer_amount_sat,
-            ..
-        } = req.clone().response;
+    struct MockFiatRateProvider {}
 
-        let chain_swap =
-            self.persister
-                .fetch_chain_swap_by_id(&swap_id)?
-                .ok_or(SdkError::Generic {
-                    err: format!("Could not find Swap {}", swap_id),
-                })?;
+    impl FiatRateProvider for MockFiatRateProvider {
+        fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, FiatRateProviderError> {
+            Ok(vec![Rate {
+                coin: "USD".to_string(),
+                value: 100_000.0,
+            }])
+        }
+    }
 
-        ensure_sdk!(
-            chain_swap.state == WaitingFeeAcceptance,
-            PaymentError::Generic {
-                err: "Payment is not WaitingFeeAcceptance".to_string()
-            }
-        );
+    #[sdk_macros::async_test_all]
+    async fn test_fiat_rate_provider() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
 
-        let server_lockup_quote = self
-            .swapper
-            .get_zero_amount_chain_swap_quote(&swap_id)
+        sdk.set_fiat_rate_provider(Box::new(MockFiatRateProvider {}))
             .await?;
+        let rates = sdk.fetch_fiat_rates().await?;
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates[0].coin, "USD");
+
+        // Fiat-denominated payments use the rates of the provider
+        let fiat_conversion = sdk
+            .convert_fiat_amount(&FiatAmount {
+                amount: 5.0,
+                currency: "usd".to_string(),
+            })
+            .await?;
+        assert_eq!(fiat_conversion.amount_sat, 5_000);
+        assert!(sdk
+            .convert_fiat_amount(&FiatAmount {
+                amount: 5.0,
+                currency: "EUR".to_string(),
+            })
+            .await
+            .is_err());
 
-        ensure_sdk!(
-            fees_sat == payer_amount_sat - server_lockup_quote.to_sat() + chain_swap.claim_fees_sat,
-            PaymentError::InvalidOrExpiredFees
-        );
+        Ok(())
+    }
 
-        self.persister
-            .update_accepted_receiver_amount(&swap_id, Some(payer_amount_sat - fees_sat))?;
-        self.swapper
-            .accept_zero_amount_chain_swap_quote(&swap_id, server_lockup_quote.to_sat())
-            .inspect_err(|e| {
-                error!("Failed to accept zero-amount swap {swap_id} quote: {e} - trying to erase the accepted receiver amount...");
-                let _ = self
-                    .persister
-                    .update_accepted_receiver_amount(&swap_id, None);
-            }).await?;
-        self.chain_swap_handler.update_swap_info(&ChainSwapUpdate {
-            swap_id,
-            to_state: Pending,
-            ..Default::default()
-        })
+    struct MockInputParser {}
+
+    #[sdk_macros::async_trait]
+    impl InputParser for MockInputParser {
+        async fn parse_input(&self, input: String) -> Option<InputType> {
+            input.strip_prefix("merchant-").map(|id| InputType::Url {
+                url: format!("https://merchant.example/pay/{id}"),
+            })
+        }
     }
 
-    /// Empties the Liquid Wallet cache for the [Config::network].
-    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
-    pub fn empty_wallet_cache(&self) -> Result<()> {
-        let mut path = PathBuf::from(self.config.working_dir.clone());
-        path.push(Into::<lwk_wollet::ElementsNetwork>::into(self.config.network).as_str());
-        path.push("enc_cache");
+    #[sdk_macros::async_test_all]
+    async fn test_input_parser() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
 
-        std::fs::remove_dir_all(&path)?;
-        std::fs::create_dir_all(path)?;
+        assert!(sdk.parse("merchant-abc123").await.is_err());
+
+        sdk.add_input_parser(Box::new(MockInputParser {})).await?;
+        let input_type = sdk.parse("merchant-abc123").await?;
+        assert!(matches!(
+            input_type,
+            InputType::Url { url } if url == "https://merchant.example/pay/abc123"
+        ));
+        assert!(sdk.parse("unknown-abc123").await.is_err());
 
         Ok(())
     }
 
-    /// Synchronizes the local state with the mempool and onchain data.
-    pub async fn sync(&self, partial_sync: bool) -> SdkResult<()> {
-        self.ensure_is_started().await?;
-
-        let t0 = Instant::now();
+    struct CatchAllInputParser {}
 
-        if let Err(err) = self.onchain_wallet.full_scan().await {
-            error!("Failed to scan wallet: {err:?}");
+    #[sdk_macros::async_trait]
+    impl InputParser for CatchAllInputParser {
+        async fn parse_input(&self, input: String) -> Option<InputType> {
+            Some(InputType::Url { url: input })
         }
+    }
 
-        let is_first_sync = !self
-            .persister
-            .get_is_first_sync_complete()?
-            .unwrap_or(false);
-        match is_first_sync {
-            true => {
-                self.event_manager.pause_notifications();
-                self.sync_payments_with_chain_data(partial_sync).await?;
-                self.event_manager.resume_notifications();
-                self.persister.set_is_first_sync_complete(true)?;
-            }
-            false => {
-                self.sync_payments_with_chain_data(partial_sync).await?;
-            }
-        }
-        
-        // Explicit wallet info update to ensure balance is always up-to-date after sync
-        // Adding as specifically requested, even though sync_payments_with_chain_data already calls update_wallet_info()
-        self.update_wallet_info().await?;
-        
-        let duration_ms = Instant::now().duration_since(t0).as_millis();
-        info!("Synchronized (partial: {partial_sync}) with mempool and onchain data ({duration_ms} ms)");
+    #[sdk_macros::async_test_all]
+    async fn test_input_parser_network_error() -> Result<()> {
+        create_persister!(persister);
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+        let mut builder = new_liquid_sdk_builder(
+            persister,
+            Arc::new(MockSwapper::default()),
+            Arc::new(MockStatusStream::new()),
+            Arc::new(MockLiquidChainService::new()),
+            Arc::new(MockBitcoinChainService::new()),
+            Arc::new(MockWallet::new(signer)?),
+            None,
+        )?;
+        let rest_client = Arc::new(MockRestClient::new());
+        rest_client.add_response(MockResponse::new(500, "".to_string()));
+        builder.rest_client(rest_client);
+        let sdk = builder.build().await?;
+        sdk.add_input_parser(Box::new(CatchAllInputParser {}))
+            .await?;
+
+        // The LNURL is recognized, so its failed lookup is not passed to the added parsers
+        let lnurl = "LNURL1DP68GURN8GHJ7UM9WFMXJCM99E3K7MF0V9CXJ0M385EKVCENXC6R2C35XVUKXEFCV5MKVV34X5EKZD3EV56NYD3HXQURZEPEXEJXXEPNXSCRVWFNV9NXZCN9XQ6XYEFHVGCXXCMYXYMNSERXFQ5FNS";
+        assert!(sdk.parse(lnurl).await.is_err());
 
-        self.notify_event_listeners(SdkEvent::Synced).await;
         Ok(())
     }
 
-    /// Backup the local state to the provided backup path.
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [BackupRequest] containing:
-    ///     * `backup_path` - the optional backup path. Defaults to [Config::working_dir]
-    pub fn backup(&self, req: BackupRequest) -> Result<()> {
-        let backup_path = req
-            .backup_path
-            .map(PathBuf::from)
-            .unwrap_or(self.persister.get_default_backup_path());
-        self.persister.backup(backup_path)
-    }
+    #[sdk_macros::async_test_all]
+    async fn test_submarine_fee_breakdown() -> Result<()> {
+        let swapper = MockSwapper::default();
+        let pair = swapper.get_submarine_pairs().await?.unwrap();
+
+        let fee_breakdown = LiquidSdk::submarine_fee_breakdown(&pair, 100_000, 30);
+        assert_eq!(fee_breakdown.service_fee_sat, 100);
+        assert_eq!(fee_breakdown.claim_fee_sat, 14);
+        assert!(!fee_breakdown.claim_fee_is_estimate);
+        assert_eq!(fee_breakdown.lockup_fee_sat, 30);
+        assert!(fee_breakdown.lockup_fee_is_estimate);
+        assert_eq!(fee_breakdown.total(), pair.fees.total(100_000) + 30);
 
-    /// Restores the local state from the provided backup path.
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [RestoreRequest] containing:
-    ///     * `backup_path` - the optional backup path. Defaults to [Config::working_dir]
-    pub fn restore(&self, req: RestoreRequest) -> Result<()> {
-        let backup_path = req
-            .backup_path
-            .map(PathBuf::from)
-            .unwrap_or(self.persister.get_default_backup_path());
-        ensure_sdk!(
-            backup_path.exists(),
-            SdkError::generic("Backup file does not exist").into()
-        );
-        self.persister.restore_from_backup(backup_path)
+        Ok(())
     }
 
-    /// Prepares to pay to an LNURL encoded pay request or lightning address.
-    ///
-    /// This is the second step of LNURL-pay flow. The first step is [LiquidSdk::parse], which also validates the LNURL
-    /// destination and generates the [LnUrlPayRequest] payload needed here.
-    ///
-    /// This call will validate the `amount_msat` and `comment` parameters of `req` against the parameters
-    /// of the LNURL endpoint (`req_data`). If they match the endpoint requirements, a [PrepareSendResponse] is
-    /// prepared for the invoice. If the receiver has encoded a Magic Routing Hint in the invoice, the
-    /// [PrepareSendResponse]'s `fees_sat` will reflect this.
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [PrepareLnUrlPayRequest] containing:
-    ///     * `data` - the [LnUrlPayRequestData] returned by [LiquidSdk::parse]
-    ///     * `amount` - The optional amount of type [PayAmount].
-    ///        - [PayAmount::Drain] which uses all funds
-    ///        - [PayAmount::Receiver] which sets the amount the receiver should receive
-    ///     * `bip353_address` - A BIP353 address, in case one was used in order to fetch the LNURL
-    ///       Pay request data. Returned by [parse].
-    ///     * `comment` - an optional comment for this payment
-    ///     * `validate_success_action_url` - validates that, if there is a URL success action, the URL domain matches
-    ///       the LNURL callback domain. Defaults to 'true'
-    ///
-    /// # Returns
-    /// Returns a [PrepareLnUrlPayResponse] containing:
-    ///     * `destination` - the destination of the payment
-    ///     * `fees_sat` - The fees in satoshis to send the payment
-    ///     * `data` - The [LnUrlPayRequestData] returned by [parse]
-    ///     * `comment` - An optional comment for this payment
-    ///     * `success_action` - the optional unprocessed LUD-09 success action
-    pub async fn prepare_lnurl_pay(
-        &self,
-        req: PrepareLnUrlPayRequest,
-    ) -> Result<PrepareLnUrlPayResponse, LnUrlPayError> {
-        let amount_msat = match req.amount {
-            PayAmount::Drain => {
-                let get_info_res = self
-                    .get_info()
-                    .await
-                    .map_err(|e| LnUrlPayError::Generic { err: e.to_string() })?;
-                ensure_sdk!(
-                    get_info_res.wallet_info.pending_receive_sat == 0
-                        && get_info_res.wallet_info.pending_send_sat == 0,
-                    LnUrlPayError::Generic {
-                        err: "Cannot drain while there are pending payments".to_string(),
-                    }
-                );
-                let lbtc_pair = self
-                    .swapper
-                    .get_submarine_pairs()
-                    .await?
-                    .ok_or(PaymentError::PairsNotFound)?;
-                let drain_fees_sat = self.estimate_drain_tx_fee(None, None).await?;
-                let drain_amount_sat = get_info_res.wallet_info.balance_sat - drain_fees_sat;
-                // Get the inverse invoice amount by calculating a dummy amount then increment up to the drain amount
-                let dummy_fees_sat = lbtc_pair.fees.total(drain_amount_sat);
-                let dummy_amount_sat = drain_amount_sat - dummy_fees_sat;
-                let invoice_amount_sat = utils::increment_invoice_amount_up_to_drain_amount(
-                    dummy_amount_sat,
-                    &lbtc_pair,
-                    drain_amount_sat,
-                );
-                lbtc_pair
-                    .limits
-                    .within(invoice_amount_sat)
-                    .map_err(|e| LnUrlPayError::Generic { err: e.message() })?;
-                // Validate if we can actually drain the wallet with a swap
-                let pair_fees_sat = lbtc_pair.fees.total(invoice_amount_sat);
-                ensure_sdk!(
-                    invoice_amount_sat + pair_fees_sat == drain_amount_sat,
-                    LnUrlPayError::Generic {
-                        err: "Cannot drain without leaving a remainder".to_string(),
-                    }
-                );
+    #[sdk_macros::async_test_all]
+    async fn test_register_webhook() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
 
-                invoice_amount_sat * 1000
-            }
-            PayAmount::Bitcoin {
-                receiver_amount_sat,
-            } => receiver_amount_sat * 1000,
-            PayAmount::Asset { .. } => {
-                return Err(LnUrlPayError::Generic {
-                    err: "Cannot send an asset to a Bitcoin address".to_string(),
-                })
-            }
-        };
+        assert!(sdk.register_webhook("not a url".to_string()).await.is_err());
+        assert!(sdk
+            .register_webhook("http://example.com/notify".to_string())
+            .await
+            .is_err());
+        assert!(persister.get_webhook_url()?.is_none());
 
-        match validate_lnurl_pay(
-            self.rest_client.as_ref(),
-            amount_msat,
-            &req.comment,
-            &req.data,
-            self.config.network.into(),
-            req.validate_success_action_url,
-        )
-        .await?
-        {
-            ValidatedCallbackResponse::EndpointError { data } => {
-                Err(LnUrlPayError::Generic { err: data.reason })
-            }
-            ValidatedCallbackResponse::EndpointSuccess { data } => {
-                let prepare_response = self
-                    .prepare_send_payment(&PrepareSendRequest {
-                        destination: data.pr.clone(),
-                        amount: Some(req.amount),
-                    })
-                    .await
-                    .map_err(|e| LnUrlPayError::Generic { err: e.to_string() })?;
+        let webhook_url = "https://example.com/notify?token=abc".to_string();
+        sdk.register_webhook(webhook_url.clone()).await?;
+        assert_eq!(persister.get_webhook_url()?, Some(webhook_url));
 
-                let destination = match prepare_response.destination {
-                    SendDestination::Bolt11 { invoice, .. } => SendDestination::Bolt11 {
-                        invoice,
-                        bip353_address: req.bip353_address,
-                    },
-                    SendDestination::LiquidAddress { address_data, .. } => {
-                        SendDestination::LiquidAddress {
-                            address_data,
-                            bip353_address: req.bip353_address,
-                        }
-                    }
-                    destination => destination,
-                };
-                let fees_sat = prepare_response
-                    .fees_sat
-                    .ok_or(PaymentError::InsufficientFunds)?;
+        sdk.unregister_webhook().await?;
+        assert!(persister.get_webhook_url()?.is_none());
 
-                Ok(PrepareLnUrlPayResponse {
-                    destination,
-                    fees_sat,
-                    data: req.data,
-                    comment: req.comment,
-                    success_action: data.success_action,
-                })
-            }
-        }
+        Ok(())
     }
-
-    /// Pay to an LNURL encoded pay request or lightning address.
-    ///
-    /// The final step of LNURL-pay flow, called after preparing the payment with [LiquidSdk::prepare_lnurl_pay].
-    /// This call sends the payment using the [PrepareLnUrlPayResponse]'s `prepare_send_response` either via
-    /// Lightning or directly to a Liquid address if a Magic Routing Hint is included in the invoice.
-    /// Once the payment is made, the [PrepareLnUrlPayResponse]'s `success_action` is processed decrypting
-    /// the AES data if needed.
-    ///
-    /// # Arguments
-    ///
-    /// * `req` - the [LnUrlPayRequest] containing:
-    ///     * `prepare_response` - the [PrepareLnUrlPayResponse] returned by [LiquidSdk::prepare_lnurl_pay]
-    pub async fn lnurl_pay(
-        &self,
-        req: model::LnUrlPayRequest,
-    ) -> Result<LnUrlPayResult, LnUrlPayError> {
-        let prepare_response = req.prepare_response;
-        let mut payment = self
-            .send_payment(&SendPaymentRequest {
-                prepare_response: PrepareSendResponse {
-                    destination: prepare_response.destination.clone(),
-                    fees_sat: Some(prepare_response.fees_sat),
-                    estimated_asset_fees: None,
+
+    #[sdk_macros::async_test_all]
+    async fn test_process_scheduled_payments() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
+
+        let new_scheduled_payment = |id: &str, state| ScheduledPayment {
+            id: id.to_string(),
+            request: SchedulePaymentRequest {
+                prepare_request: PrepareSendRequest {
+                    destination: "not a destination".to_string(),
+                    amount: None,
+                    fiat_amount: None,
                 },
                 use_asset_fees: None,
-            })
+                max_fee_sat: None,
+                execute_at: 1,
+            },
+            state,
+            attempts: 0,
+            created_at: 0,
+            next_attempt_at: 1,
+            tx_id: None,
+            last_error: None,
+        };
+        persister.insert_scheduled_payment(&new_scheduled_payment(
+            "pending",
+            ScheduledPaymentState::Pending,
+        ))?;
+        persister.insert_scheduled_payment(&new_scheduled_payment(
+            "in-progress",
+            ScheduledPaymentState::InProgress,
+        ))?;
+
+        // A permanent error fails the payment after a single attempt
+        sdk.process_scheduled_payments().await?;
+        let failed = persister.fetch_scheduled_payment("pending")?.unwrap();
+        assert_eq!(failed.state, ScheduledPaymentState::Failed);
+        assert_eq!(failed.attempts, 1);
+        assert!(failed.last_error.is_some());
+
+        // An attempt in progress is neither retried nor cancellable
+        let in_progress = persister.fetch_scheduled_payment("in-progress")?.unwrap();
+        assert_eq!(in_progress.state, ScheduledPaymentState::InProgress);
+        assert_eq!(in_progress.attempts, 0);
+        assert!(sdk
+            .cancel_scheduled_payment("in-progress".to_string())
             .await
-            .map_err(|e| LnUrlPayError::Generic { err: e.to_string() })?
-            .payment;
+            .is_err());
+        assert!(sdk
+            .cancel_scheduled_payment("pending".to_string())
+            .await
+            .is_err());
+
+        // An interrupted attempt whose payment was sent is recorded once resumed
+        let payment_tx_data = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Send);
+        persister.insert_or_update_payment(payment_tx_data.clone(), None, false)?;
+        persister.insert_idempotency_key("in-progress", utils::now())?;
+        persister.set_idempotency_key_payment_id("in-progress", &payment_tx_data.tx_id)?;
+        let mut events = sdk.event_manager.subscribe();
+        persister.reset_interrupted_scheduled_payments()?;
+        sdk.process_scheduled_payments().await?;
+        let executed = persister.fetch_scheduled_payment("in-progress")?.unwrap();
+        assert_eq!(executed.state, ScheduledPaymentState::Executed);
+        assert_eq!(executed.tx_id, Some(payment_tx_data.tx_id));
+        assert!(matches!(
+            events.try_recv(),
+            Ok(SdkEvent::ScheduledPaymentExecuted { id, .. }) if id == "in-progress"
+        ));
 
-        let maybe_sa_processed: Option<SuccessActionProcessed> = match prepare_response
-            .success_action
-            .clone()
-        {
-            Some(sa) => {
-                match sa {
-                    // For AES, we decrypt the contents if the preimage is available
-                    SuccessAction::Aes { data } => {
-                        let PaymentDetails::Lightning {
-                            swap_id, preimage, ..
-                        } = &payment.details
-                        else {
-                            return Err(LnUrlPayError::Generic {
-                                err: format!("Invalid payment type: expected type `PaymentDetails::Lightning`, got payment details {:?}.", payment.details),
-                            });
-                        };
+        Ok(())
+    }
 
-                        match preimage {
-                            Some(preimage_str) => {
-                                debug!(
-                                    "Decrypting AES success action with preimage for Send Swap {}",
-                                    swap_id
-                                );
-                                let preimage =
-                                    sha256::Hash::from_str(preimage_str).map_err(|_| {
-                                        LnUrlPayError::Generic {
-                                            err: "Invalid preimage".to_string(),
-                                        }
-                                    })?;
-                                let preimage_arr = preimage.to_byte_array();
-                                let result = match (data, &preimage_arr).try_into() {
-                                    Ok(data) => AesSuccessActionDataResult::Decrypted { data },
-                                    Err(e) => AesSuccessActionDataResult::ErrorStatus {
-                                        reason: e.to_string(),
-                                    },
-                                };
-                                Some(SuccessActionProcessed::Aes { result })
-                            }
-                            None => {
-                                debug!("Preimage not yet available to decrypt AES success action for Send Swap {}", swap_id);
-                                None
-                            }
-                        }
-                    }
-                    SuccessAction::Message { data } => {
-                        Some(SuccessActionProcessed::Message { data })
-                    }
-                    SuccessAction::Url { data } => Some(SuccessActionProcessed::Url { data }),
-                }
-            }
-            None => None,
+    #[sdk_macros::async_test_all]
+    async fn test_reconcile_swap_intents() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
+        let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister.clone(),
+            swapper,
+            status_stream,
+            liquid_chain_service.clone(),
+            bitcoin_chain_service,
+            None,
+        )
+        .await?;
+
+        let claim_tx_id = "3e5ac2ec8bcb21a4f8ba4c3a5ba1c4fca3d6e1bfa2d3c2cd8b9f7c36e0b2d1a4";
+        let mut receive_swap = new_receive_swap(Some(PaymentState::Pending), None);
+        receive_swap.claim_tx_id = Some(claim_tx_id.to_string());
+        persister.insert_or_update_receive_swap(&receive_swap)?;
+        persister.insert_swap_intent(&receive_swap.id, SwapIntentKind::Claim, Some(claim_tx_id))?;
+
+        // If the chain service can't be reached, the intent is kept
+        liquid_chain_service.set_unreachable(true);
+        sdk.reconcile_swap_intents().await?;
+        assert_eq!(persister.list_swap_intents()?.len(), 1);
+        let Swap::Receive(swap) = persister.fetch_swap_by_id(&receive_swap.id)? else {
+            panic!("Expected a Receive swap");
+        };
+        assert_eq!(swap.claim_tx_id.as_deref(), Some(claim_tx_id));
+
+        // Once it's known not to be broadcast, i.e. the reachable chain service doesn't know
+        // it, the claim tx is unset
+        liquid_chain_service.set_unreachable(false);
+        sdk.reconcile_swap_intents().await?;
+        assert!(persister.list_swap_intents()?.is_empty());
+        let Swap::Receive(swap) = persister.fetch_swap_by_id(&receive_swap.id)? else {
+            panic!("Expected a Receive swap");
         };
+        assert!(swap.claim_tx_id.is_none());
 
-        let description = payment
-            .details
-            .get_description()
-            .or_else(|| extract_description_from_metadata(&prepare_response.data));
+        Ok(())
+    }
 
-        let lnurl_pay_domain = match prepare_response.data.ln_address {
-            Some(_) => None,
-            None => Some(prepare_response.data.domain),
-        };
-        if let (Some(tx_id), Some(destination)) =
-            (payment.tx_id.clone(), payment.destination.clone())
-        {
-            self.persister
-                .insert_or_update_payment_details(PaymentTxDetails {
-                    tx_id: tx_id.clone(),
-                    destination,
-                    description,
-                    lnurl_info: Some(LnUrlInfo {
-                        ln_address: prepare_response.data.ln_address,
-                        lnurl_pay_comment: prepare_response.comment,
-                        lnurl_pay_domain,
-                        lnurl_pay_metadata: Some(prepare_response.data.metadata_str),
-                        lnurl_pay_success_action: maybe_sa_processed.clone(),
-                        lnurl_pay_unprocessed_success_action: prepare_response.success_action,
-                        lnurl_withdraw_endpoint: None,
-                    }),
-                    bip353_address: None,
-                    asset_fees: None,
-                })?;
-            // Get the payment with the lnurl_info details
-            payment = self.persister.get_payment(&tx_id)?.unwrap_or(payment);
-        }
+    #[sdk_macros::async_test_all]
+    async fn test_broadcast_liquid_payjoin_tx() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
+        let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
+        let sdk = new_liquid_sdk_with_chain_services(
+            persister,
+            swapper,
+            status_stream,
+            liquid_chain_service.clone(),
+            bitcoin_chain_service,
+            None,
+        )
+        .await?;
+        let tx = TEST_LIQUID_RECEIVE_LOCKUP_TX.clone();
 
-        Ok(LnUrlPayResult::EndpointSuccess {
-            data: model::LnUrlPaySuccessData {
-                payment,
-                success_action: maybe_sa_processed,
+        assert_eq!(
+            sdk.broadcast_liquid_payjoin_tx(&tx).await?,
+            Some(tx.txid().to_string())
+        );
+
+        // A failed broadcast only falls back to a regular tx if the payjoin tx wasn't broadcast
+        liquid_chain_service.set_fail_broadcast(true);
+        assert_eq!(sdk.broadcast_liquid_payjoin_tx(&tx).await?, None);
+        liquid_chain_service.set_unreachable(true);
+        assert!(sdk.broadcast_liquid_payjoin_tx(&tx).await.is_err());
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_send_payment_idempotency_key() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+        let onchain_wallet = Arc::new(MockWallet::new(signer)?);
+        let sdk = new_liquid_sdk_builder(
+            persister.clone(),
+            swapper,
+            status_stream,
+            liquid_chain_service.clone(),
+            Arc::new(MockBitcoinChainService::new()),
+            onchain_wallet.clone(),
+            None,
+        )?
+        .build()
+        .await?;
+        *sdk.is_started.write().await = true;
+
+        let new_request = |idempotency_key: &str| SendPaymentRequest {
+            prepare_response: PrepareSendResponse {
+                destination: SendDestination::LiquidAddress {
+                    address_data: LiquidAddressData {
+                        address: "address".to_string(),
+                        network: sdk.config.network.into(),
+                        asset_id: Some(utils::lbtc_asset_id(sdk.config.network).to_string()),
+                        amount: None,
+                        amount_sat: Some(1_000),
+                        label: None,
+                        message: None,
+                    },
+                    bip353_address: None,
+                    payjoin_url: None,
+                },
+                fees_sat: Some(100),
+                fee_breakdown: None,
+                estimated_asset_fees: None,
+                estimated_asset_fees_amount: None,
+                fiat_conversion: None,
             },
-        })
+            use_asset_fees: None,
+            max_fee_sat: Some(10),
+            spend_limit_override: None,
+            idempotency_key: Some(idempotency_key.to_string()),
+        };
+
+        // A payment failing before anything is broadcast releases its key
+        assert!(matches!(
+            sdk.send_payment(&new_request("failed")).await,
+            Err(PaymentError::InsufficientFunds)
+        ));
+        assert!(persister.insert_idempotency_key("failed", utils::now())?);
+
+        // A reserved key without a payment can't be reused while the SDK runs
+        assert!(matches!(
+            sdk.send_payment(&new_request("failed")).await,
+            Err(PaymentError::PaymentInProgress)
+        ));
+
+        // A key with a payment returns it instead of sending again
+        let payment_tx_data = new_payment_tx_data(sdk.config.network, PaymentType::Send);
+        let tx_id = payment_tx_data.tx_id.clone();
+        persister.insert_or_update_payment(payment_tx_data, None, false)?;
+        assert!(persister.insert_idempotency_key("sent", utils::now())?);
+        persister.set_idempotency_key_payment_id("sent", &tx_id)?;
+        let response = sdk.send_payment(&new_request("sent")).await?;
+        assert_eq!(response.payment.tx_id, Some(tx_id));
+
+        // The key is linked to the tx before it's broadcast, so an interrupted send is never
+        // sent again
+        onchain_wallet.set_transactions(vec![WalletTx {
+            txid: elements::Txid::from_str(&format!("{:064x}", 1))?,
+            tx: TEST_LIQUID_TX.clone(),
+            height: Some(1),
+            fee: 100,
+            timestamp: None,
+            balance: [(utils::lbtc_asset_id(sdk.config.network), 100_000)]
+                .into_iter()
+                .collect(),
+            outputs: vec![],
+            inputs: vec![],
+            type_: "".to_string(),
+        }]);
+        sdk.update_wallet_info().await?;
+        liquid_chain_service.set_fail_broadcast(true);
+        let mut request = new_request("interrupted");
+        request.prepare_response.fees_sat = Some(TEST_LIQUID_TX.all_fees().values().sum());
+        request.max_fee_sat = None;
+        assert!(sdk.send_payment(&request).await.is_err());
+        assert_eq!(
+            persister.fetch_idempotency_key_payment_id("interrupted")?,
+            Some(TEST_LIQUID_TX.txid().to_string())
+        );
+        assert!(matches!(
+            sdk.send_payment(&request).await,
+            Err(PaymentError::PaymentInProgress)
+        ));
+
+        Ok(())
     }
 
-    /// Second step of LNURL-withdraw. The first step is [LiquidSdk::parse], which also validates the LNURL destination
-    /// and generates the [LnUrlWithdrawRequest] payload needed here.
-    ///
-    /// This call will validate the given `amount_msat` against the parameters
-    /// of the LNURL endpoint (`data`). If they match the endpoint requirements, the LNURL withdraw
-    /// request is made. A successful result here means the endpoint started the payment.
-    pub async fn lnurl_withdraw(
-        &self,
-        req: LnUrlWithdrawRequest,
-    ) -> Result<LnUrlWithdrawResult, LnUrlWithdrawError> {
-        let prepare_response = self
-            .prepare_receive_payment(&{
-                PrepareReceiveRequest {
-                    payment_method: PaymentMethod::Lightning,
-                    amount: Some(ReceiveAmount::Bitcoin {
-                        payer_amount_sat: req.amount_msat / 1_000,
-                    }),
-                }
-            })
-            .await?;
-        let receive_res = self
-            .receive_payment(&ReceivePaymentRequest {
-                prepare_response,
-                description: req.description.clone(),
-                use_description_hash: Some(false),
-            })
-            .await?;
+    /// Stores the uploaded backups in memory, the index of each being its version
+    struct MemoryBackupTransport {
+        backups: Arc<std::sync::Mutex<Vec<Vec<u8>>>>,
+    }
 
-        let Ok(invoice) = parse_invoice(&receive_res.destination) else {
-            return Err(LnUrlWithdrawError::Generic {
-                err: "Received unexpected output from receive request".to_string(),
-            });
-        };
+    impl BackupTransport for MemoryBackupTransport {
+        fn upload(&self, backup: Vec<u8>) -> Result<String, BackupTransportError> {
+            let mut backups = self.backups.lock().unwrap();
+            backups.push(backup);
+            Ok((backups.len() - 1).to_string())
+        }
 
-        let res =
-            validate_lnurl_withdraw(self.rest_client.as_ref(), req.data.clone(), invoice.clone())
-                .await?;
-        if let LnUrlWithdrawResult::Ok { data: _ } = res {
-            if let Some(ReceiveSwap {
-                claim_tx_id: Some(tx_id),
-                ..
-            }) = self
-                .persister
-                .fetch_receive_swap_by_invoice(&invoice.bolt11)?
-            {
-                self.persister
-                    .insert_or_update_payment_details(PaymentTxDetails {
-                        tx_id,
-                        destination: receive_res.destination,
-                        description: req.description,
-                        lnurl_info: Some(LnUrlInfo {
-                            lnurl_withdraw_endpoint: Some(req.data.callback),
-                            ..Default::default()
-                        }),
-                        bip353_address: None,
-                        asset_fees: None,
-                    })?;
-            }
+        fn download(&self, version: String) -> Result<Vec<u8>, BackupTransportError> {
+            let index: usize = version.parse().map_err(|_| BackupTransportError::Generic {
+                err: "Invalid version".to_string(),
+            })?;
+            self.backups
+                .lock()
+                .unwrap()
+                .get(index)
+                .cloned()
+                .ok_or(BackupTransportError::Generic {
+                    err: "Unknown version".to_string(),
+                })
         }
-        Ok(res)
-    }
 
-    /// Third and last step of LNURL-auth. The first step is [LiquidSdk::parse], which also validates the LNURL destination
-    /// and generates the [LnUrlAuthRequestData] payload needed here. The second step is user approval of auth action.
-    ///
-    /// This call will sign `k1` of the LNURL endpoint (`req_data`) on `secp256k1` using `linkingPrivKey` and DER-encodes the signature.
-    /// If they match the endpoint requirements, the LNURL auth request is made. A successful result here means the client signature is verified.
-    pub async fn lnurl_auth(
-        &self,
-        req_data: LnUrlAuthRequestData,
-    ) -> Result<LnUrlCallbackStatus, LnUrlAuthError> {
-        Ok(perform_lnurl_auth(
-            self.rest_client.as_ref(),
-            &req_data,
-            &SdkLnurlAuthSigner::new(self.signer.clone()),
-        )
-        .await?)
+        fn list_versions(&self) -> Result<Vec<String>, BackupTransportError> {
+            let len = self.backups.lock().unwrap().len();
+            Ok((0..len).rev().map(|index| index.to_string()).collect())
+        }
     }
 
-    /// Register for webhook callbacks at the given `webhook_url`. Each created swap after registering the
-    /// webhook will include the `webhook_url`.
-    ///
-    /// This method should be called every time the application is started and when the `webhook_url` changes.
-    /// For example, if the `webhook_url` contains a push notification token and the token changes after
-    /// the application was started, then this method should be called to register for callbacks at
-    /// the new correct `webhook_url`. To unregister a webhook call [LiquidSdk::unregister_webhook].
-    pub async fn register_webhook(&self, webhook_url: String) -> SdkResult<()> {
-        info!("Registering for webhook notifications");
-        self.persister.set_webhook_url(webhook_url)?;
+    #[sdk_macros::async_test_all]
+    async fn test_backup_transport_is_encrypted() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone()).await?;
+        let backups = Arc::new(std::sync::Mutex::new(vec![]));
+        sdk.set_backup_transport(Box::new(MemoryBackupTransport {
+            backups: backups.clone(),
+        }))
+        .await?;
+
+        persister.insert_or_update_send_swap(&new_send_swap(Some(PaymentState::Pending), None))?;
+        sdk.upload_backup().await?;
+
+        // The uploaded backup is not readable without the signer
+        let backup = backups.lock().unwrap()[0].clone();
+        assert!(matches!(
+            persister.verify_backup(&backup),
+            Err(SdkError::InvalidBackup { .. })
+        ));
+        sdk.restore_from_backup_transport(None).await?;
+        assert_eq!(persister.list_ongoing_swaps()?.len(), 1);
+
+        // Nor can it be restored by another wallet
+        create_persister!(other_persister);
+        let other_sdk = new_liquid_sdk(other_persister.clone(), swapper, status_stream).await?;
+        other_sdk
+            .set_backup_transport(Box::new(MemoryBackupTransport { backups }))
+            .await?;
+        assert!(matches!(
+            other_sdk.restore_from_backup_transport(None).await,
+            Err(SdkError::InvalidBackup { .. })
+        ));
+        assert!(other_persister.list_ongoing_swaps()?.is_empty());
+
         Ok(())
     }
 
-    /// Unregister webhook callbacks. Each swap already created will continue to use the registered
-    /// `webhook_url` until complete.
-    ///
-    /// This can be called when callbacks are no longer needed or the `webhook_url`
-    /// has changed such that it needs unregistering. For example, the token is valid but the locale changes.
-    /// To register a webhook call [LiquidSdk::register_webhook].
-    pub async fn unregister_webhook(&self) -> SdkResult<()> {
-        info!("Unregistering for webhook notifications");
-        self.persister.remove_webhook_url()?;
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    #[sdk_macros::async_test_all]
+    async fn test_wallet_export_is_encrypted() -> Result<()> {
+        use crate::{
+            model::{ExportWalletRequest, ImportWalletRequest},
+            persist::export::WalletExport,
+        };
+
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone()).await?;
+        persister.insert_or_update_send_swap(&new_send_swap(Some(PaymentState::Pending), None))?;
+
+        let export_dir = tempdir::TempDir::new("liquid-sdk-export")?;
+        let export_path = export_dir
+            .path()
+            .join("wallet.export")
+            .to_string_lossy()
+            .to_string();
+        sdk.export_wallet(ExportWalletRequest {
+            export_path: export_path.clone(),
+        })?;
+
+        // The export file is not readable without the signer
+        let bytes = std::fs::read(&export_path)?;
+        assert!(WalletExport::from_bytes(&bytes).is_err());
+
+        // Nor can it be imported by another wallet
+        create_persister!(other_persister);
+        let other_sdk = new_liquid_sdk(other_persister.clone(), swapper, status_stream).await?;
+        assert!(other_sdk
+            .import_wallet(ImportWalletRequest {
+                export_path: export_path.clone(),
+            })
+            .is_err());
+        assert!(other_persister.list_ongoing_swaps()?.is_empty());
+
+        // Importing into the same wallet keeps the existing records
+        let res = sdk.import_wallet(ImportWalletRequest { export_path })?;
+        assert_eq!(res.imported_records, 0);
+
         Ok(())
     }
 
-    /// Fetch live rates of fiat currencies, sorted by name.
-    pub async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, SdkError> {
-        self.fiat_api.fetch_fiat_rates().await.map_err(Into::into)
+    #[sdk_macros::async_test_all]
+    async fn test_sweep_private_key() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        // The SDK runs on testnet, so a mainnet key is rejected
+        let res = sdk
+            .sweep_private_key(&SweepPrivateKeyRequest {
+                key: "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn".to_string(),
+            })
+            .await;
+        assert!(matches!(res, Err(PaymentError::InvalidNetwork { .. })));
+
+        let response = sdk
+            .sweep_private_key(&SweepPrivateKeyRequest {
+                key: "cMahea7zqjxrtgAbB7LSGbcQUr1uX1ojuat9jZodMN87JcbXMTcA".to_string(),
+            })
+            .await?;
+        assert_eq!(response.payment.payment_type, PaymentType::Receive);
+        assert_eq!(response.payment.amount_sat, 1_000);
+        assert_eq!(response.payment.fees_sat, 26);
+        assert_eq!(response.amount_sat, 1_000);
+        assert!(response.asset_amounts.is_empty());
+        let tx_id = response.payment.tx_id.unwrap();
+        assert!(persister.get_payment(&tx_id)?.is_some());
+
+        Ok(())
     }
 
-    /// List all supported fiat currencies for which there is a known exchange rate.
-    /// List is sorted by the canonical name of the currency.
-    pub async fn list_fiat_currencies(&self) -> Result<Vec<FiatCurrency>, SdkError> {
-        self.fiat_api
-            .list_fiat_currencies()
+    #[sdk_macros::async_test_all]
+    async fn test_process_recurring_payments() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
+
+        let now = utils::now();
+        let new_recurring_payment = |id: &str| RecurringPayment {
+            id: id.to_string(),
+            destination: "not a destination".to_string(),
+            amount_sat: 1_000,
+            interval_sec: 100,
+            max_fee_sat: None,
+            created_at: 0,
+            next_occurrence_at: now - 250,
+        };
+        persister.insert_recurring_payment(&new_recurring_payment("active"))?;
+        persister.insert_recurring_payment(&new_recurring_payment("cancelled"))?;
+        sdk.cancel_recurring_payment("cancelled".to_string())
+            .await?;
+        assert!(sdk
+            .cancel_recurring_payment("cancelled".to_string())
             .await
-            .map_err(Into::into)
+            .is_err());
+
+        sdk.process_recurring_payments().await?;
+
+        // The missed occurrences are recorded as skipped and only the current one is sent. As
+        // it failed with a non-transient error, it's not retried and the next occurrence is set.
+        let recurring_payments = sdk.list_recurring_payments().await?;
+        assert_eq!(recurring_payments.len(), 1);
+        assert_eq!(recurring_payments[0].next_occurrence_at, now + 50);
+        let occurrences = sdk
+            .list_recurring_payment_occurrences("active".to_string())
+            .await?;
+        assert_eq!(
+            occurrences
+                .iter()
+                .map(|occurrence| occurrence.occurrence_at)
+                .collect::<Vec<_>>(),
+            vec![now - 250, now - 150, now - 50]
+        );
+        assert!(occurrences
+            .iter()
+            .all(|occurrence| occurrence.tx_id.is_none() && occurrence.error.is_some()));
+
+        // No occurrence is sent before the next one is due, nor for a cancelled payment
+        sdk.process_recurring_payments().await?;
+        assert_eq!(
+            sdk.list_recurring_payment_occurrences("active".to_string())
+                .await?
+                .len(),
+            3
+        );
+        assert!(sdk
+            .list_recurring_payment_occurrences("cancelled".to_string())
+            .await?
+            .is_empty());
+
+        Ok(())
     }
 
-    /// Get the recommended BTC fees based on the configured mempool.space instance.
-    pub async fn recommended_fees(&self) -> Result<RecommendedFees, SdkError> {
-        Ok(self.bitcoin_chain_service.recommended_fees().await?)
+    #[sdk_macros::async_test_all]
+    async fn test_parse() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+
+        let bolt11 = "lnbc10u1pnczjaupp55392fur38rc2y9vzmhdy0tclvfels0lvlmzgvmhpg6q2mndxzmrsdqqcqzzsxqyz5vqsp5ya6pvchlsvl3mzqh3zw4hg3tz5pww77q6rcwfr52qchyrp7s6krs9p4gqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqysgqgnp0sskk0ljjew8vkc3udhzgquzs79evf5wezfaex9q4gjk5qcn8m3luauyte93lgassd8skh5m90glhtt52ry2wtftzrjn4h076z7sqdjry3d";
+        match sdk.parse(bolt11).await? {
+            InputType::Bolt11 { invoice } => assert_eq!(invoice.amount_msat, Some(1_000_000)),
+            input_type => panic!("Unexpected input type {input_type:?}"),
+        }
+
+        let address = "tlq1pq2amlulhea6ltq7x3eu9atsc2nnrer7yt7xve363zxedqwu2mk6ctcyv9awl8xf28cythreqklt5q0qqwsxzlm6wu4z6d574adl9zh2zmr0h85gt534n";
+        match sdk.parse(address).await? {
+            InputType::LiquidAddress { address: data } => {
+                assert_eq!(data.address, address);
+                assert!(data.asset_id.is_none());
+            }
+            input_type => panic!("Unexpected input type {input_type:?}"),
+        }
+
+        // Assets without known metadata cannot be parsed
+        let unknown_asset_bip21 = format!(
+            "liquidtestnet:{address}?assetid=0000000000000000000000000000000000000000000000000000000000000001"
+        );
+        assert!(matches!(
+            sdk.parse(&unknown_asset_bip21).await,
+            Err(PaymentError::AssetError { .. })
+        ));
+
+        Ok(())
     }
 
-    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
-    /// Get the full default [Config] for specific [LiquidNetwork].
-    pub fn default_config(
-        network: LiquidNetwork,
-        breez_api_key: Option<String>,
-    ) -> Result<Config, SdkError> {
-        let config = match network {
-            LiquidNetwork::Mainnet => Config::mainnet(breez_api_key),
-            LiquidNetwork::Testnet => Config::testnet(breez_api_key),
-            LiquidNetwork::Regtest => Config::regtest(),
+    #[sdk_macros::async_test_all]
+    async fn test_sync_drops_evicted_wallet_tx() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
+        let mut events = sdk.event_manager.subscribe();
+
+        // An unconfirmed tx seen in the wallet, which is no longer in it
+        let evicted_tx_data = new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Send);
+        persister.insert_or_update_payment(evicted_tx_data.clone(), None, true)?;
+        // A pseudo-tx that was just broadcast, which may not have propagated yet
+        let broadcast_tx_data = PaymentTxData {
+            timestamp: Some(utils::now()),
+            ..new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Send)
         };
+        persister.insert_or_update_payment(broadcast_tx_data.clone(), None, false)?;
 
-        Ok(config)
+        // The evicted tx is kept while the wallet may be stale
+        sdk.sync_payments_with_chain_data(false, false).await?;
+        assert_eq!(persister.list_unconfirmed_payment_txs_data()?.len(), 2);
+
+        sdk.sync_payments_with_chain_data(false, true).await?;
+        let unconfirmed_tx_ids: Vec<String> = persister
+            .list_unconfirmed_payment_txs_data()?
+            .into_iter()
+            .map(|tx| tx.tx_id)
+            .collect();
+        assert_eq!(unconfirmed_tx_ids, vec![broadcast_tx_data.tx_id]);
+        let mut dropped_tx_ids = vec![];
+        while let Ok(event) = events.try_recv() {
+            if let SdkEvent::TransactionDropped { tx_id } = event {
+                dropped_tx_ids.push(tx_id);
+            }
+        }
+        assert_eq!(dropped_tx_ids, vec![evicted_tx_data.tx_id]);
+
+        Ok(())
     }
 
-    /// Parses a string into an [InputType]. See [input_parser::parse].
-    ///
-    /// Can optionally be configured to use external input parsers by providing `external_input_parsers` in [Config].
-    pub async fn parse(&self, input: &str) -> Result<InputType, PaymentError> {
-        let external_parsers = &self.external_input_parsers;
-        let input_type =
-            parse_with_rest_client(self.rest_client.as_ref(), input, Some(external_parsers))
-                .await
-                .map_err(|e| PaymentError::generic(&e.to_string()))?;
+    #[sdk_macros::async_test_all]
+    async fn test_sync_rolls_back_reorged_receive_swap() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
+        let mut events = sdk.event_manager.subscribe();
 
-        let res = match input_type {
-            InputType::LiquidAddress { ref address } => match &address.asset_id {
-                Some(asset_id) if asset_id.ne(&self.config.lbtc_asset_id()) => {
-                    let asset_metadata = self.persister.get_asset_metadata(asset_id)?.ok_or(
-                        PaymentError::AssetError {
-                            err: format!("Asset {asset_id} is not supported"),
-                        },
-                    )?;
-                    let mut address = address.clone();
-                    address.set_amount_precision(asset_metadata.precision.into());
-                    InputType::LiquidAddress { address }
-                }
-                _ => input_type,
-            },
-            _ => input_type,
+        // A completed receive swap whose confirmed claim tx is no longer in the wallet
+        let claim_tx_data = PaymentTxData {
+            is_confirmed: true,
+            ..new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Receive)
         };
-        Ok(res)
-    }
+        persister.insert_or_update_payment(claim_tx_data.clone(), None, true)?;
+        let receive_swap = ReceiveSwap {
+            claim_tx_id: Some(claim_tx_data.tx_id.clone()),
+            ..new_receive_swap(Some(PaymentState::Complete), None)
+        };
+        persister.insert_or_update_receive_swap(&receive_swap)?;
+
+        // Nothing is rolled back while the wallet may be stale
+        sdk.sync_payments_with_chain_data(false, false).await?;
+        let swap = persister
+            .fetch_receive_swap_by_id(&receive_swap.id)?
+            .ok_or(anyhow!("Swap not found"))?;
+        assert_eq!(swap.state, PaymentState::Complete);
+
+        // Both the swap and its claim tx are rolled back once the wallet is scanned
+        sdk.sync_payments_with_chain_data(false, true).await?;
+        let swap = persister
+            .fetch_receive_swap_by_id(&receive_swap.id)?
+            .ok_or(anyhow!("Swap not found"))?;
+        assert_eq!(swap.state, PaymentState::Pending);
+        let payment = persister
+            .get_payment(&claim_tx_data.tx_id)?
+            .ok_or(anyhow!("Payment not found"))?;
+        assert_eq!(payment.status, PaymentState::Pending);
+        let mut reorged_tx_ids = vec![];
+        while let Ok(event) = events.try_recv() {
+            if let SdkEvent::PaymentReorged { details } = event {
+                reorged_tx_ids.push(details.tx_id);
+            }
+        }
+        assert_eq!(reorged_tx_ids, vec![Some(claim_tx_data.tx_id)]);
 
-    /// Parses a string into an [LNInvoice]. See [invoice::parse_invoice].
-    pub fn parse_invoice(input: &str) -> Result<LNInvoice, PaymentError> {
-        parse_invoice(input).map_err(|e| PaymentError::invalid_invoice(&e.to_string()))
+        Ok(())
     }
 
-    /// Configures a global SDK logger that will log to file and will forward log events to
-    /// an optional application-specific logger.
-    ///
-    /// If called, it should be called before any SDK methods (for example, before `connect`).
-    ///
-    /// It must be called only once in the application lifecycle. Alternatively, If the application
-    /// already uses a globally-registered logger, this method shouldn't be called at all.
-    ///
-    /// ### Arguments
-    ///
-    /// - `log_dir`: Location where the the SDK log file will be created. The directory must already exist.
-    ///
-    /// - `app_logger`: Optional application logger.
-    ///
-    /// If the application is to use it's own logger, but would also like the SDK to log SDK-specific
-    /// log output to a file in the configured `log_dir`, then do not register the
-    /// app-specific logger as a global logger and instead call this method with the app logger as an arg.
-    ///
-    /// ### Errors
-    ///
-    /// An error is thrown if the log file cannot be created in the working directory.
-    ///
-    /// An error is thrown if a global logger is already configured.
-    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
-    pub fn init_logging(log_dir: &str, app_logger: Option<Box<dyn log::Log>>) -> Result<()> {
-        crate::logger::init_logging(log_dir, app_logger)
+    #[sdk_macros::async_test_all]
+    async fn test_list_refundables() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
+
+        let swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::Refundable),
+            false,
+            None,
+            false,
+            false,
+            None,
+        );
+        persister.insert_or_update_chain_swap(&swap)?;
+        let refundable_since = persister
+            .fetch_chain_swap_by_id(&swap.id)?
+            .unwrap()
+            .refundable_since
+            .unwrap();
+
+        // The mock chain service returns a single 1000 sat UTXO for the lockup script
+        let refundables = sdk.list_refundables().await?;
+        assert_eq!(refundables.len(), 1);
+        let refundable = &refundables[0];
+        assert_eq!(refundable.swap_address, swap.lockup_address);
+        assert_eq!(refundable.amount_sat, 1000);
+        assert_eq!(refundable.state, PaymentState::Refundable);
+        assert_eq!(refundable.refundable_since, refundable_since);
+        assert_eq!(
+            refundable.refund_eligible_block_height,
+            swap.timeout_block_height
+        );
+        assert!(refundable.is_cooperative_refund_possible);
+
+        Ok(())
     }
-}
 
-/// Extracts `description` from `metadata_str`
-fn extract_description_from_metadata(request_data: &LnUrlPayRequestData) -> Option<String> {
-    let metadata = request_data.metadata_vec().ok()?;
-    metadata
-        .iter()
-        .find(|item| item.key == "text/plain")
-        .map(|item| {
-            info!("Extracted payment description: '{}'", item.value);
-            item.value.clone()
-        })
-}
+    #[sdk_macros::async_test_all]
+    async fn test_prepare_refund() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
 
-#[cfg(test)]
-mod tests {
-    use std::str::FromStr;
+        let swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::Refundable),
+            false,
+            None,
+            false,
+            false,
+            None,
+        );
+        persister.insert_or_update_chain_swap(&swap)?;
+
+        // The mock chain service returns a single 1000 sat UTXO for the lockup script
+        let response = sdk
+            .prepare_refund(&PrepareRefundRequest {
+                swap_address: swap.lockup_address.clone(),
+                refund_address: "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string(),
+                fee_rate_sat_per_vbyte: Some(5),
+                fee_speed: None,
+            })
+            .await?;
+        assert_eq!(response.fee_rate_sat_per_vbyte, 5);
+        assert_eq!(response.refundable_amount_sat, 1000);
+        assert_eq!(
+            response.refund_amount_sat,
+            response.refundable_amount_sat - response.tx_fee_sat
+        );
+        assert!(response.last_refund_tx_id.is_none());
 
-    use anyhow::{anyhow, Result};
-    use boltz_client::{
-        boltz::{self, TransactionInfo},
-        swaps::boltz::{ChainSwapStates, RevSwapStates, SubSwapStates},
-    };
-    use lwk_wollet::hashes::hex::DisplayHex as _;
-    use sdk_common::utils::Arc;
-    use tokio_with_wasm::alias as tokio;
+        Ok(())
+    }
 
-    use crate::chain_swap::ESTIMATED_BTC_LOCKUP_TX_VSIZE;
-    use crate::test_utils::chain_swap::{
-        TEST_BITCOIN_OUTGOING_SERVER_LOCKUP_TX, TEST_LIQUID_INCOMING_SERVER_LOCKUP_TX,
-        TEST_LIQUID_OUTGOING_USER_LOCKUP_TX,
-    };
-    use crate::test_utils::swapper::ZeroAmountSwapMockConfig;
-    use crate::test_utils::wallet::TEST_LIQUID_RECEIVE_LOCKUP_TX;
-    use crate::{
-        bitcoin, elements,
-        model::{BtcHistory, Direction, LBtcHistory, PaymentState, Swap},
-        sdk::LiquidSdk,
-        test_utils::{
-            chain::{MockBitcoinChainService, MockLiquidChainService},
-            chain_swap::{new_chain_swap, TEST_BITCOIN_INCOMING_USER_LOCKUP_TX},
-            persist::{create_persister, new_receive_swap, new_send_swap},
-            sdk::{new_liquid_sdk, new_liquid_sdk_with_chain_services},
-            status_stream::MockStatusStream,
-            swapper::MockSwapper,
-        },
-    };
-    use paste::paste;
+    #[sdk_macros::async_test_all]
+    async fn test_export_payment_unblinding_data() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+        let onchain_wallet = Arc::new(MockWallet::new(signer)?);
+        let sdk = new_liquid_sdk_with_wallet(
+            persister.clone(),
+            swapper,
+            status_stream,
+            onchain_wallet.clone(),
+        )
+        .await?;
+        *sdk.is_started.write().await = true;
 
-    #[cfg(feature = "browser-tests")]
-    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+        let tx_id = "0000000000000000000000000000000000000000000000000000000000000001";
+        let txid = elements::Txid::from_str(tx_id)?;
+        let tx_data = PaymentTxData {
+            tx_id: tx_id.to_string(),
+            ..new_payment_tx_data(LiquidNetwork::Testnet, PaymentType::Receive)
+        };
+        persister.insert_or_update_payment(tx_data, None, false)?;
 
-    struct NewSwapArgs {
-        direction: Direction,
-        accepts_zero_conf: bool,
-        initial_payment_state: Option<PaymentState>,
-        receiver_amount_sat: Option<u64>,
-        user_lockup_tx_id: Option<String>,
-        zero_amount: bool,
-        set_actual_payer_amount: bool,
+        let request = ExportPaymentUnblindingDataRequest {
+            payment_id: tx_id.to_string(),
+            explorer_url: Some("https://explorer.example.com".to_string()),
+        };
+        // The tx is not in the wallet yet
+        assert!(sdk.export_payment_unblinding_data(&request).await.is_err());
+
+        let secrets = elements::TxOutSecrets {
+            asset: utils::lbtc_asset_id(LiquidNetwork::Testnet),
+            value: 10_000,
+            asset_bf: elements::confidential::AssetBlindingFactor::from_slice(&[2; 32])?,
+            value_bf: elements::confidential::ValueBlindingFactor::from_slice(&[3; 32])?,
+        };
+        let output = WalletTxOut {
+            outpoint: elements::OutPoint::new(txid, 0),
+            script_pubkey: elements::Script::new(),
+            height: None,
+            unblinded: secrets,
+            wildcard_index: 0,
+            ext_int: Chain::External,
+            is_spent: false,
+            address: elements::Address::from_str("lq1pqw8ct25kd47dejyesyvk3g2kaf8s9uhq4se7r2kj9y9hhvu9ug5thxlpn9y63s78kc2mcp6nujavckvr42q7hwkhqq9hfz46nth22hfp3em0ulm4nsuf")?,
+        };
+        onchain_wallet.set_transactions(vec![WalletTx {
+            txid,
+            tx: elements::Transaction {
+                version: 2,
+                lock_time: elements::LockTime::ZERO,
+                input: vec![],
+                output: vec![],
+            },
+            height: None,
+            fee: 100,
+            timestamp: None,
+            balance: Default::default(),
+            outputs: vec![Some(output)],
+            inputs: vec![],
+            type_: "incoming".to_string(),
+        }]);
+
+        let unblinding_data = sdk.export_payment_unblinding_data(&request).await?;
+        assert_eq!(unblinding_data.tx_id, tx_id);
+        assert!(unblinding_data.inputs.is_empty());
+        assert_eq!(unblinding_data.outputs.len(), 1);
+        let unblinded_output = &unblinding_data.outputs[0];
+        assert_eq!(unblinded_output.outpoint, format!("{tx_id}:0"));
+        assert_eq!(unblinded_output.asset_id, secrets.asset.to_string());
+        assert_eq!(unblinded_output.amount, secrets.value);
+        assert_eq!(
+            unblinded_output.asset_blinding_factor,
+            secrets.asset_bf.to_string()
+        );
+        assert_eq!(
+            unblinded_output.amount_blinding_factor,
+            secrets.value_bf.to_string()
+        );
+        assert_eq!(
+            unblinding_data.unblinding_data,
+            format!(
+                "{},{},{},{}",
+                secrets.value, secrets.asset, secrets.value_bf, secrets.asset_bf
+            )
+        );
+        assert_eq!(
+            unblinding_data.unblinded_url,
+            Some(format!(
+                "https://explorer.example.com/tx/{tx_id}#blinded={}",
+                unblinding_data.unblinding_data
+            ))
+        );
+
+        // The exported data survives a round-trip through its JSON serialization
+        let json = serde_json::to_string(&unblinding_data)?;
+        let deserialized: PaymentUnblindingData = serde_json::from_str(&json)?;
+        assert_eq!(deserialized, unblinding_data);
+
+        Ok(())
     }
 
-    impl Default for NewSwapArgs {
-        fn default() -> Self {
-            Self {
-                accepts_zero_conf: false,
-                initial_payment_state: None,
-                direction: Direction::Outgoing,
-                receiver_amount_sat: None,
-                user_lockup_tx_id: None,
-                zero_amount: false,
-                set_actual_payer_amount: false,
-            }
+    #[sdk_macros::async_test_all]
+    async fn test_prepare_send_bip21_asset_mismatch() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        let address = "tlq1pq2amlulhea6ltq7x3eu9atsc2nnrer7yt7xve363zxedqwu2mk6ctcyv9awl8xf28cythreqklt5q0qqwsxzlm6wu4z6d574adl9zh2zmr0h85gt534n";
+        let usdt_asset_id = "b612eb46313a2cd6ebabd8b7a8eed5696e29898b87a43bff41c94f51acef9d73";
+        let destination = format!("liquidtestnet:{address}?assetid={usdt_asset_id}");
+
+        // A BIP21 requesting USDt can't be paid in L-BTC
+        for amount in [
+            PayAmount::Bitcoin {
+                receiver_amount_sat: 1_000,
+            },
+            PayAmount::Drain,
+        ] {
+            let res = sdk
+                .prepare_send_payment(&PrepareSendRequest {
+                    destination: destination.clone(),
+                    amount: Some(amount),
+                    fiat_amount: None,
+                })
+                .await;
+            assert!(matches!(res, Err(PaymentError::AssetError { .. })));
         }
+
+        Ok(())
     }
 
-    impl NewSwapArgs {
-        pub fn set_direction(mut self, direction: Direction) -> Self {
-            self.direction = direction;
-            self
-        }
+    #[sdk_macros::async_test_all]
+    async fn test_prepare_send_amp_asset() -> Result<()> {
+        create_persister!(persister);
+        let amp_asset_id = "0000000000000000000000000000000000000000000000000000000000000001";
+        persister.replace_asset_metadata(Some(vec![AssetMetadata {
+            asset_id: amp_asset_id.to_string(),
+            name: "AMP Asset".to_string(),
+            ticker: "AMP".to_string(),
+            precision: 0,
+            fiat_id: None,
+            is_amp: true,
+        }]))?;
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        // AMP assets can be held but not sent, as their spends need the AMP server cosigning
+        let res = sdk
+            .prepare_send_payment(&PrepareSendRequest {
+                destination: "tlq1pq2amlulhea6ltq7x3eu9atsc2nnrer7yt7xve363zxedqwu2mk6ctcyv9awl8xf28cythreqklt5q0qqwsxzlm6wu4z6d574adl9zh2zmr0h85gt534n".to_string(),
+                amount: Some(PayAmount::Asset {
+                    asset_id: amp_asset_id.to_string(),
+                    receiver_amount: 10.0,
+                    estimate_asset_fees: None,
+                }),
+                fiat_amount: None,
+            })
+            .await;
+        assert!(matches!(res, Err(PaymentError::AssetError { err }) if err.contains("AMP")));
 
-        pub fn set_accepts_zero_conf(mut self, accepts_zero_conf: bool) -> Self {
-            self.accepts_zero_conf = accepts_zero_conf;
-            self
-        }
+        Ok(())
+    }
 
-        pub fn set_receiver_amount_sat(mut self, receiver_amount_sat: Option<u64>) -> Self {
-            self.receiver_amount_sat = receiver_amount_sat;
-            self
-        }
+    #[sdk_macros::async_test_all]
+    async fn test_refund_fee_speed() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
 
-        pub fn set_user_lockup_tx_id(mut self, user_lockup_tx_id: Option<String>) -> Self {
-            self.user_lockup_tx_id = user_lockup_tx_id;
-            self
-        }
+        let swap = new_chain_swap(
+            Direction::Incoming,
+            Some(PaymentState::Refundable),
+            false,
+            None,
+            false,
+            false,
+            None,
+        );
+        persister.insert_or_update_chain_swap(&swap)?;
+        let refund_request = |fee_rate_sat_per_vbyte, fee_speed| PrepareRefundRequest {
+            swap_address: swap.lockup_address.clone(),
+            refund_address: "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string(),
+            fee_rate_sat_per_vbyte,
+            fee_speed,
+        };
 
-        pub fn set_initial_payment_state(mut self, payment_state: PaymentState) -> Self {
-            self.initial_payment_state = Some(payment_state);
-            self
+        // The presets resolve to the recommended fees of the mock chain service
+        let recommended_fees = sdk.recommended_fees().await?;
+        for (fee_speed, expected_fee_rate) in [
+            (FeeSpeed::Economy, recommended_fees.economy_fee),
+            (FeeSpeed::Standard, recommended_fees.half_hour_fee),
+            (FeeSpeed::Fast, recommended_fees.fastest_fee),
+        ] {
+            let response = sdk
+                .prepare_refund(&refund_request(None, Some(fee_speed)))
+                .await?;
+            assert_eq!(response.fee_rate_sat_per_vbyte as u64, expected_fee_rate);
         }
 
-        pub fn set_zero_amount(mut self, zero_amount: bool) -> Self {
-            self.zero_amount = zero_amount;
-            self
-        }
+        // Exactly one of the fee rate and the fee speed must be set
+        assert!(sdk
+            .prepare_refund(&refund_request(Some(5), Some(FeeSpeed::Fast)))
+            .await
+            .is_err());
+        assert!(sdk
+            .prepare_refund(&refund_request(None, None))
+            .await
+            .is_err());
+        assert_eq!(
+            sdk.prepare_refund(&refund_request(Some(5), None))
+                .await?
+                .fee_rate_sat_per_vbyte,
+            5
+        );
 
-        pub fn set_set_actual_payer_amount(mut self, set_actual_payer_amount: bool) -> Self {
-            self.set_actual_payer_amount = set_actual_payer_amount;
-            self
-        }
+        Ok(())
     }
 
-    macro_rules! trigger_swap_update {
-        (
-            $type:literal,
-            $args:expr,
-            $persister:expr,
-            $status_stream:expr,
-            $status:expr,
-            $transaction:expr,
-            $zero_conf_rejected:expr
-        ) => {{
-            let swap = match $type {
-                "chain" => {
-                    let swap = new_chain_swap(
-                        $args.direction,
-                        $args.initial_payment_state,
-                        $args.accepts_zero_conf,
-                        $args.user_lockup_tx_id,
-                        $args.zero_amount,
-                        $args.set_actual_payer_amount,
-                        $args.receiver_amount_sat,
-                    );
-                    $persister.insert_or_update_chain_swap(&swap).unwrap();
-                    Swap::Chain(swap)
-                }
-                "send" => {
-                    let swap =
-                        new_send_swap($args.initial_payment_state, $args.receiver_amount_sat);
-                    $persister.insert_or_update_send_swap(&swap).unwrap();
-                    Swap::Send(swap)
-                }
-                "receive" => {
-                    let swap =
-                        new_receive_swap($args.initial_payment_state, $args.receiver_amount_sat);
-                    $persister.insert_or_update_receive_swap(&swap).unwrap();
-                    Swap::Receive(swap)
-                }
-                _ => panic!(),
-            };
+    #[sdk_macros::async_test_all]
+    async fn test_prepare_liquid_asset_receive() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        let receive_request = |asset_id: &str, payer_amount| PrepareReceiveRequest {
+            payment_method: PaymentMethod::LiquidAddress,
+            amount: Some(ReceiveAmount::Asset {
+                asset_id: asset_id.to_string(),
+                payer_amount,
+            }),
+            fiat_amount: None,
+            expiry_secs: None,
+        };
+        let usdt_asset_id = "b612eb46313a2cd6ebabd8b7a8eed5696e29898b87a43bff41c94f51acef9d73";
 
-            $status_stream
-                .clone()
-                .send_mock_update(boltz::SwapStatus {
-                    id: swap.id(),
-                    status: $status.to_string(),
-                    transaction: $transaction,
-                    zero_conf_rejected: $zero_conf_rejected,
-                    ..Default::default()
-                })
-                .await
-                .unwrap();
+        let response = sdk
+            .prepare_receive_payment(&receive_request(usdt_asset_id, Some(1.5)))
+            .await?;
+        assert_eq!(response.fees_sat, 0);
+
+        // Assets without known metadata are not supported
+        let unknown_asset_id = "0000000000000000000000000000000000000000000000000000000000000001";
+        assert!(matches!(
+            sdk.prepare_receive_payment(&receive_request(unknown_asset_id, Some(1.5)))
+                .await,
+            Err(PaymentError::AssetError { .. })
+        ));
 
-            paste! {
-                $persister.[<fetch _ $type _swap_by_id>](&swap.id())
-                    .unwrap()
-                    .ok_or(anyhow!("Could not retrieve {} swap", $type))
-                    .unwrap()
-            }
-        }};
+        // The amount, if set, must be positive
+        for payer_amount in [0.0, -1.0] {
+            assert!(matches!(
+                sdk.prepare_receive_payment(&receive_request(usdt_asset_id, Some(payer_amount)))
+                    .await,
+                Err(PaymentError::AmountOutOfRange)
+            ));
+        }
+
+        Ok(())
     }
 
     #[sdk_macros::async_test_all]
-    async fn test_receive_swap_update_tracking() -> Result<()> {
+    async fn test_pay_onchain_to_own_swap_address() -> Result<()> {
         create_persister!(persister);
         let swapper = Arc::new(MockSwapper::default());
         let status_stream = Arc::new(MockStatusStream::new());
-        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
-        let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
-
-        let sdk = new_liquid_sdk_with_chain_services(
-            persister.clone(),
-            swapper.clone(),
-            status_stream.clone(),
-            liquid_chain_service.clone(),
-            bitcoin_chain_service.clone(),
-            None,
-        )
-        .await?;
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        let swap = new_chain_swap(Direction::Incoming, None, false, None, true, false, None);
+        persister.insert_or_update_chain_swap(&swap)?;
+
+        let res = sdk
+            .pay_onchain(&PayOnchainRequest {
+                address: swap.lockup_address.clone(),
+                prepare_response: PreparePayOnchainResponse {
+                    receiver_amount_sat: 50_000,
+                    claim_fees_sat: 1_000,
+                    total_fees_sat: 2_000,
+                },
+                spend_limit_override: None,
+            })
+            .await;
+        assert!(matches!(res, Err(PaymentError::SelfTransferNotSupported)));
 
-        LiquidSdk::track_swap_updates(&sdk);
+        Ok(())
+    }
 
-        // We spawn a new thread since updates can only be sent when called via async runtimes
-        tokio::spawn(async move {
-            // Verify the swap becomes invalid after final states are received
-            let unrecoverable_states: [RevSwapStates; 4] = [
-                RevSwapStates::SwapExpired,
-                RevSwapStates::InvoiceExpired,
-                RevSwapStates::TransactionFailed,
-                RevSwapStates::TransactionRefunded,
-            ];
+    struct MockFiatApi {}
 
-            for status in unrecoverable_states {
-                let persisted_swap = trigger_swap_update!(
-                    "receive",
-                    NewSwapArgs::default(),
-                    persister,
-                    status_stream,
-                    status,
-                    None,
-                    None
-                );
-                assert_eq!(persisted_swap.state, PaymentState::Failed);
-            }
+    #[sdk_macros::async_trait]
+    impl FiatAPI for MockFiatApi {
+        async fn list_fiat_currencies(
+            &self,
+        ) -> Result<Vec<FiatCurrency>, ServiceConnectivityError> {
+            Ok(vec![])
+        }
 
-            // Check that `TransactionMempool` and `TransactionConfirmed` correctly trigger the claim,
-            // which in turn sets the `claim_tx_id`
-            for status in [
-                RevSwapStates::TransactionMempool,
-                RevSwapStates::TransactionConfirmed,
-            ] {
-                let mock_tx = TEST_LIQUID_RECEIVE_LOCKUP_TX.clone();
-                let mock_tx_id = mock_tx.txid();
-                let height = (serde_json::to_string(&status).unwrap()
-                    == serde_json::to_string(&RevSwapStates::TransactionConfirmed).unwrap())
-                    as i32;
-                liquid_chain_service.set_history(vec![LBtcHistory {
-                    txid: mock_tx_id,
-                    height,
-                }]);
+        async fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, ServiceConnectivityError> {
+            Ok(vec![Rate {
+                coin: "EUR".to_string(),
+                value: 50_000.0,
+            }])
+        }
+    }
 
-                let persisted_swap = trigger_swap_update!(
-                    "receive",
-                    NewSwapArgs::default(),
-                    persister,
-                    status_stream,
-                    status,
-                    Some(TransactionInfo {
-                        id: mock_tx_id.to_string(),
-                        hex: Some(
-                            lwk_wollet::elements::encode::serialize(&mock_tx).to_lower_hex_string()
-                        ),
-                        eta: None,
-                    }),
-                    None
-                );
-                assert!(persisted_swap.claim_tx_id.is_some());
-            }
+    #[sdk_macros::async_test_all]
+    async fn test_builder_fiat_api() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk_with_fiat_api(
+            persister,
+            swapper,
+            status_stream,
+            Arc::new(MockFiatApi {}),
+        )
+        .await?;
 
-            // Check that `TransactionMempool` and `TransactionConfirmed` checks the lockup amount
-            // and doesn't claim if not verified
-            for status in [
-                RevSwapStates::TransactionMempool,
-                RevSwapStates::TransactionConfirmed,
-            ] {
-                let mock_tx = TEST_LIQUID_RECEIVE_LOCKUP_TX.clone();
-                let mock_tx_id = mock_tx.txid();
-                let height = (serde_json::to_string(&status).unwrap()
-                    == serde_json::to_string(&RevSwapStates::TransactionConfirmed).unwrap())
-                    as i32;
-                liquid_chain_service.set_history(vec![LBtcHistory {
-                    txid: mock_tx_id,
-                    height,
-                }]);
+        let rates = sdk.fetch_fiat_rates().await?;
+        assert_eq!(rates.len(), 1);
+        assert_eq!(rates[0].coin, "EUR");
 
-                let persisted_swap = trigger_swap_update!(
-                    "receive",
-                    NewSwapArgs::default().set_receiver_amount_sat(Some(1000)),
-                    persister,
-                    status_stream,
-                    status,
-                    Some(TransactionInfo {
-                        id: mock_tx_id.to_string(),
-                        hex: Some(
-                            lwk_wollet::elements::encode::serialize(&mock_tx).to_lower_hex_string()
-                        ),
-                        eta: None
-                    }),
-                    None
-                );
-                assert!(persisted_swap.claim_tx_id.is_none());
-            }
-        })
-        .await
-        .unwrap();
+        // Fiat-denominated payments use the rates of the fiat API set on the builder
+        let fiat_conversion = sdk
+            .convert_fiat_amount(&FiatAmount {
+                amount: 5.0,
+                currency: "EUR".to_string(),
+            })
+            .await?;
+        assert_eq!(fiat_conversion.amount_sat, 10_000);
 
         Ok(())
     }
 
     #[sdk_macros::async_test_all]
-    async fn test_send_swap_update_tracking() -> Result<()> {
+    async fn test_sign_message() -> Result<()> {
         create_persister!(persister);
         let swapper = Arc::new(MockSwapper::default());
         let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
 
-        let sdk = Arc::new(
-            new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone()).await?,
-        );
-
-        LiquidSdk::track_swap_updates(&sdk);
+        let message = "Hello, Liquid!".to_string();
+        let res = sdk.sign_message(&SignMessageRequest {
+            message: message.clone(),
+        })?;
+        assert_eq!(res.pubkey, sdk.onchain_wallet.pubkey()?);
 
-        // We spawn a new thread since updates can only be sent when called via async runtimes
-        tokio::spawn(async move {
-            // Verify the swap becomes invalid after final states are received
-            let unrecoverable_states: [SubSwapStates; 3] = [
-                SubSwapStates::TransactionLockupFailed,
-                SubSwapStates::InvoiceFailedToPay,
-                SubSwapStates::SwapExpired,
-            ];
+        // The returned pubkey verifies the signature
+        let check = sdk.check_message(&CheckMessageRequest {
+            message,
+            pubkey: res.pubkey.clone(),
+            signature: res.signature.clone(),
+        })?;
+        assert!(check.is_valid);
 
-            for status in unrecoverable_states {
-                let persisted_swap = trigger_swap_update!(
-                    "send",
-                    NewSwapArgs::default(),
-                    persister,
-                    status_stream,
-                    status,
-                    None,
-                    None
-                );
-                assert_eq!(persisted_swap.state, PaymentState::Failed);
-            }
+        let check = sdk.check_message(&CheckMessageRequest {
+            message: "Wrong message".to_string(),
+            pubkey: res.pubkey,
+            signature: res.signature,
+        })?;
+        assert!(!check.is_valid);
 
-            // Verify that `TransactionClaimPending` correctly sets the state to `Complete`
-            // and stores the preimage
-            let persisted_swap = trigger_swap_update!(
-                "send",
-                NewSwapArgs::default(),
-                persister,
-                status_stream,
-                SubSwapStates::TransactionClaimPending,
-                None,
-                None
-            );
-            assert_eq!(persisted_swap.state, PaymentState::Complete);
-            assert!(persisted_swap.preimage.is_some());
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_initial_sync() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+        let mut events = sdk.event_manager.subscribe();
+
+        sdk.track_new_blocks();
+
+        // The wallet is synced right away, although the chain tips didn't change
+        let synced = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                if let Ok(SdkEvent::Synced) = events.recv().await {
+                    break;
+                }
+            }
         })
-        .await
-        .unwrap();
+        .await;
+        assert!(synced.is_ok());
+        assert!(sdk.persister.get_is_first_sync_complete()?.unwrap_or(false));
 
+        sdk.shutdown_sender.send(())?;
         Ok(())
     }
 
     #[sdk_macros::async_test_all]
-    async fn test_chain_swap_update_tracking() -> Result<()> {
+    async fn test_start_restores_wallet_info() -> Result<()> {
         create_persister!(persister);
         let swapper = Arc::new(MockSwapper::default());
         let status_stream = Arc::new(MockStatusStream::new());
-        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
-        let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
-
-        let sdk = new_liquid_sdk_with_chain_services(
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+        let onchain_wallet = Arc::new(MockWallet::new(signer)?);
+        let sdk = new_liquid_sdk_with_wallet(
             persister.clone(),
-            swapper.clone(),
-            status_stream.clone(),
-            liquid_chain_service.clone(),
-            bitcoin_chain_service.clone(),
-            None,
+            swapper,
+            status_stream,
+            onchain_wallet.clone(),
         )
         .await?;
 
-        LiquidSdk::track_swap_updates(&sdk);
+        // A confirmed tx cached by the wallet before the SDK was last stopped
+        onchain_wallet.set_transactions(vec![WalletTx {
+            txid: elements::Txid::from_str(
+                "0000000000000000000000000000000000000000000000000000000000000002",
+            )?,
+            tx: elements::Transaction {
+                version: 2,
+                lock_time: elements::LockTime::ZERO,
+                input: vec![],
+                output: vec![],
+            },
+            height: Some(1),
+            fee: 100,
+            timestamp: None,
+            balance: [(utils::lbtc_asset_id(LiquidNetwork::Testnet), 10_000)]
+                .into_iter()
+                .collect(),
+            outputs: vec![],
+            inputs: vec![],
+            type_: "incoming".to_string(),
+        }]);
+        assert!(persister.get_info()?.is_none());
+
+        // The wallet info is stored once started, before the first sync completes
+        sdk.start().await?;
+        let info = persister
+            .get_info()?
+            .ok_or(anyhow!("Wallet info not stored"))?;
+        assert_eq!(info.wallet_info.balance_sat, 10_000);
+        assert_eq!(info.wallet_info.pubkey, onchain_wallet.pubkey()?);
 
-        // We spawn a new thread since updates can only be sent when called via async runtimes
-        tokio::spawn(async move {
-            let trigger_failed: [ChainSwapStates; 3] = [
-                ChainSwapStates::TransactionFailed,
-                ChainSwapStates::SwapExpired,
-                ChainSwapStates::TransactionRefunded,
-            ];
+        sdk.disconnect().await?;
+        Ok(())
+    }
 
-            // Checks that work for both incoming and outgoing chain swaps
-            for direction in [Direction::Incoming, Direction::Outgoing] {
-                // Verify the swap becomes invalid after final states are received
-                for status in &trigger_failed {
-                    let persisted_swap = trigger_swap_update!(
-                        "chain",
-                        NewSwapArgs::default().set_direction(direction),
-                        persister,
-                        status_stream,
-                        status,
-                        None,
-                        None
-                    );
-                    assert_eq!(persisted_swap.state, PaymentState::Failed);
-                }
+    #[sdk_macros::async_test_all]
+    async fn test_sync_interval() -> Result<()> {
+        let mut config = Config::testnet_esplora(None);
+        assert_eq!(
+            config.sync_interval(),
+            Duration::from_secs(DEFAULT_SYNC_INTERVAL_SEC.into())
+        );
+        config.sync_interval_sec = Some(30);
+        assert_eq!(config.sync_interval(), Duration::from_secs(30));
 
-                let (mock_user_lockup_tx_hex, mock_user_lockup_tx_id) = match direction {
-                    Direction::Outgoing => {
-                        let tx = TEST_LIQUID_OUTGOING_USER_LOCKUP_TX.clone();
-                        (
-                            lwk_wollet::elements::encode::serialize(&tx).to_lower_hex_string(),
-                            tx.txid().to_string(),
-                        )
-                    }
-                    Direction::Incoming => {
-                        let tx = TEST_BITCOIN_INCOMING_USER_LOCKUP_TX.clone();
-                        (
-                            sdk_common::bitcoin::consensus::serialize(&tx).to_lower_hex_string(),
-                            tx.txid().to_string(),
-                        )
-                    }
-                };
+        // A zero interval is rejected when building the SDK
+        config.sync_interval_sec = Some(0);
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+        let builder = LiquidSdkBuilder::new(config, STAGING_BREEZSERVER_URL.into(), signer)?;
+        assert!(builder.build().await.is_err());
 
-                let (mock_server_lockup_tx_hex, mock_server_lockup_tx_id) = match direction {
-                    Direction::Incoming => {
-                        let tx = TEST_LIQUID_INCOMING_SERVER_LOCKUP_TX.clone();
-                        (
-                            lwk_wollet::elements::encode::serialize(&tx).to_lower_hex_string(),
-                            tx.txid().to_string(),
-                        )
-                    }
-                    Direction::Outgoing => {
-                        let tx = TEST_BITCOIN_OUTGOING_SERVER_LOCKUP_TX.clone();
-                        (
-                            sdk_common::bitcoin::consensus::serialize(&tx).to_lower_hex_string(),
-                            tx.txid().to_string(),
-                        )
-                    }
-                };
+        Ok(())
+    }
 
-                // Verify that `TransactionLockupFailed` correctly sets the state as
-                // `RefundPending`/`Refundable` or as `Failed` depending on whether or not
-                // `user_lockup_tx_id` is present
-                for user_lockup_tx_id in &[None, Some(mock_user_lockup_tx_id.clone())] {
-                    if let Some(user_lockup_tx_id) = user_lockup_tx_id {
-                        match direction {
-                            Direction::Incoming => {
-                                bitcoin_chain_service.set_history(vec![BtcHistory {
-                                    txid: bitcoin::Txid::from_str(user_lockup_tx_id).unwrap(),
-                                    height: 0,
-                                }]);
-                            }
-                            Direction::Outgoing => {
-                                liquid_chain_service.set_history(vec![LBtcHistory {
-                                    txid: elements::Txid::from_str(user_lockup_tx_id).unwrap(),
-                                    height: 0,
-                                }]);
-                            }
-                        }
-                    }
-                    let persisted_swap = trigger_swap_update!(
-                        "chain",
-                        NewSwapArgs::default()
-                            .set_direction(direction)
-                            .set_initial_payment_state(PaymentState::Pending)
-                            .set_user_lockup_tx_id(user_lockup_tx_id.clone()),
-                        persister,
-                        status_stream,
-                        ChainSwapStates::TransactionLockupFailed,
-                        None,
-                        None
-                    );
-                    let expected_state = if user_lockup_tx_id.is_some() {
-                        match direction {
-                            Direction::Incoming => PaymentState::Refundable,
-                            Direction::Outgoing => PaymentState::RefundPending,
-                        }
-                    } else {
-                        PaymentState::Failed
-                    };
-                    assert_eq!(persisted_swap.state, expected_state);
-                }
+    #[sdk_macros::async_test_all]
+    async fn test_scheduled_backup() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
 
-                // Verify that `TransactionMempool` and `TransactionConfirmed` correctly set
-                // `user_lockup_tx_id` and `accept_zero_conf`
-                for status in [
-                    ChainSwapStates::TransactionMempool,
-                    ChainSwapStates::TransactionConfirmed,
-                ] {
-                    if direction == Direction::Incoming {
-                        bitcoin_chain_service.set_history(vec![BtcHistory {
-                            txid: bitcoin::Txid::from_str(&mock_user_lockup_tx_id).unwrap(),
-                            height: 0,
-                        }]);
-                        bitcoin_chain_service.set_transactions(&[&mock_user_lockup_tx_hex]);
-                    }
-                    let persisted_swap = trigger_swap_update!(
-                        "chain",
-                        NewSwapArgs::default().set_direction(direction),
-                        persister,
-                        status_stream,
-                        status,
-                        Some(TransactionInfo {
-                            id: mock_user_lockup_tx_id.clone(),
-                            hex: Some(mock_user_lockup_tx_hex.clone()),
-                            eta: None
-                        }), // sets `update.transaction`
-                        Some(true) // sets `update.zero_conf_rejected`
-                    );
-                    assert_eq!(
-                        persisted_swap.user_lockup_tx_id,
-                        Some(mock_user_lockup_tx_id.clone())
-                    );
-                    assert!(!persisted_swap.accept_zero_conf);
-                }
+        // A zero backup policy is rejected when building the SDK
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+        let mut builder = new_liquid_sdk_builder(
+            persister.clone(),
+            swapper.clone(),
+            status_stream.clone(),
+            Arc::new(MockLiquidChainService::new()),
+            Arc::new(MockBitcoinChainService::new()),
+            Arc::new(MockWallet::new(signer)?),
+            None,
+        )?;
+        builder.config.backup_policy = Some(BackupPolicy::CompletedSwaps { swaps: 0 });
+        assert!(builder.build().await.is_err());
+
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+        let mut events = sdk.event_manager.subscribe();
+        let backups = Arc::new(std::sync::Mutex::new(vec![]));
+        sdk.set_backup_transport(Box::new(MemoryBackupTransport {
+            backups: backups.clone(),
+        }))
+        .await?;
 
-                // Verify that `TransactionServerMempool` correctly:
-                // 1. Sets the payment as `Pending` and creates `server_lockup_tx_id` when
-                //    `accepts_zero_conf` is false
-                // 2. Sets the payment as `Pending` and creates `claim_tx_id` when `accepts_zero_conf`
-                //    is true
-                for accepts_zero_conf in [false, true] {
-                    let persisted_swap = trigger_swap_update!(
-                        "chain",
-                        NewSwapArgs::default()
-                            .set_direction(direction)
-                            .set_accepts_zero_conf(accepts_zero_conf)
-                            .set_set_actual_payer_amount(true),
-                        persister,
-                        status_stream,
-                        ChainSwapStates::TransactionServerMempool,
-                        Some(TransactionInfo {
-                            id: mock_server_lockup_tx_id.clone(),
-                            hex: Some(mock_server_lockup_tx_hex.clone()),
-                            eta: None,
-                        }),
-                        None
-                    );
-                    match accepts_zero_conf {
-                        false => {
-                            assert_eq!(persisted_swap.state, PaymentState::Pending);
-                            assert!(persisted_swap.server_lockup_tx_id.is_some());
-                        }
-                        true => {
-                            assert_eq!(persisted_swap.state, PaymentState::Pending);
-                            assert!(persisted_swap.claim_tx_id.is_some());
-                        }
-                    };
-                }
+        sdk.run_scheduled_backup().await;
+        assert_eq!(backups.lock().unwrap().len(), 1);
+        assert!(matches!(events.try_recv(), Ok(SdkEvent::BackupSucceeded)));
 
-                // Verify that `TransactionServerConfirmed` correctly
-                // sets the payment as `Pending` and creates `claim_tx_id`
-                let persisted_swap = trigger_swap_update!(
-                    "chain",
-                    NewSwapArgs::default()
-                        .set_direction(direction)
-                        .set_set_actual_payer_amount(true),
-                    persister,
-                    status_stream,
-                    ChainSwapStates::TransactionServerConfirmed,
-                    Some(TransactionInfo {
-                        id: mock_server_lockup_tx_id,
-                        hex: Some(mock_server_lockup_tx_hex),
-                        eta: None,
-                    }),
-                    None
-                );
-                assert_eq!(persisted_swap.state, PaymentState::Pending);
-                assert!(persisted_swap.claim_tx_id.is_some());
-            }
+        struct FailingBackupTransport {}
 
-            // For outgoing payments, verify that `Created` correctly sets the payment as `Pending` and creates
-            // the `user_lockup_tx_id`
-            let persisted_swap = trigger_swap_update!(
-                "chain",
-                NewSwapArgs::default().set_direction(Direction::Outgoing),
-                persister,
-                status_stream,
-                ChainSwapStates::Created,
-                None,
-                None
-            );
-            assert_eq!(persisted_swap.state, PaymentState::Pending);
-            assert!(persisted_swap.user_lockup_tx_id.is_some());
-        })
-        .await
-        .unwrap();
+        impl BackupTransport for FailingBackupTransport {
+            fn upload(&self, _backup: Vec<u8>) -> Result<String, BackupTransportError> {
+                Err(BackupTransportError::Generic {
+                    err: "Upload failed".to_string(),
+                })
+            }
+
+            fn download(&self, _version: String) -> Result<Vec<u8>, BackupTransportError> {
+                Err(BackupTransportError::Generic {
+                    err: "Download failed".to_string(),
+                })
+            }
+
+            fn list_versions(&self) -> Result<Vec<String>, BackupTransportError> {
+                Err(BackupTransportError::Generic {
+                    err: "Listing the versions failed".to_string(),
+                })
+            }
+        }
+
+        // A failed upload is reported with an event
+        sdk.set_backup_transport(Box::new(FailingBackupTransport {}))
+            .await?;
+        sdk.run_scheduled_backup().await;
+        assert!(matches!(
+            events.try_recv(),
+            Ok(SdkEvent::BackupFailed { .. })
+        ));
 
         Ok(())
     }
 
     #[sdk_macros::async_test_all]
-    async fn test_zero_amount_chain_swap_zero_leeway() -> Result<()> {
-        let user_lockup_sat = 50_000;
+    async fn test_send_batch() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+        let mut events = sdk.event_manager.subscribe();
+
+        let liquid_item = BatchSendItem {
+            destination: "tlq1pq2amlulhea6ltq7x3eu9atsc2nnrer7yt7xve363zxedqwu2mk6ctcyv9awl8xf28cythreqklt5q0qqwsxzlm6wu4z6d574adl9zh2zmr0h85gt534n".to_string(),
+            amount_sat: Some(1_000),
+        };
+        let invalid_item = BatchSendItem {
+            destination: "invalid".to_string(),
+            amount_sat: Some(1_000),
+        };
+
+        assert!(matches!(
+            sdk.send_batch(&SendBatchRequest {
+                items: vec![],
+                max_total_fee_sat: None,
+            })
+            .await,
+            Err(PaymentError::Generic { .. })
+        ));
+
+        // Nothing is sent if the batch exceeds the fee limit or the balance
+        assert!(matches!(
+            sdk.send_batch(&SendBatchRequest {
+                items: vec![liquid_item.clone(), invalid_item.clone()],
+                max_total_fee_sat: Some(0),
+            })
+            .await,
+            Err(PaymentError::FeeLimitExceeded { .. })
+        ));
+        assert!(matches!(
+            sdk.send_batch(&SendBatchRequest {
+                items: vec![liquid_item, invalid_item.clone()],
+                max_total_fee_sat: None,
+            })
+            .await,
+            Err(PaymentError::InsufficientFunds)
+        ));
+        assert!(events.try_recv().is_err());
+
+        // An item that fails to be prepared is reported in the results
+        let res = sdk
+            .send_batch(&SendBatchRequest {
+                items: vec![invalid_item],
+                max_total_fee_sat: None,
+            })
+            .await?;
+        assert_eq!(res.results.len(), 1);
+        assert_eq!(res.results[0].destination, "invalid");
+        assert!(res.results[0].payment.is_none());
+        assert!(res.results[0].error.is_some());
+        assert!(matches!(
+            events.try_recv(),
+            Ok(SdkEvent::BatchSendCompleted { results }) if results == res.results
+        ));
+
+        Ok(())
+    }
 
+    #[sdk_macros::async_test_all]
+    async fn test_pay_liquid_batch() -> Result<()> {
         create_persister!(persister);
-        let swapper = Arc::new(MockSwapper::new());
+        let swapper = Arc::new(MockSwapper::default());
         let status_stream = Arc::new(MockStatusStream::new());
-        let liquid_chain_service = Arc::new(MockLiquidChainService::new());
-        let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
+        let sdk = new_liquid_sdk(persister.clone(), swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        let new_output = |address: &str, amount_sat: u64| {
+            (
+                LiquidAddressData {
+                    address: address.to_string(),
+                    network: sdk.config.network.into(),
+                    asset_id: Some(sdk.config.lbtc_asset_id()),
+                    amount: None,
+                    amount_sat: Some(amount_sat),
+                    label: None,
+                    message: None,
+                },
+                100_000,
+            )
+        };
+        let outputs = vec![new_output("address1", 1_000), new_output("address2", 2_000)];
+
+        // Both outputs are paid by the same tx, which is stored as a single payment
+        let payments = sdk.pay_liquid_batch(&outputs).await?;
+        assert_eq!(payments.len(), 2);
+        assert_eq!(payments[0].tx_id, payments[1].tx_id);
+        assert_eq!(payments[0].amount_sat, 1_000);
+        assert_eq!(payments[1].amount_sat, 2_000);
+        assert!(matches!(
+            &payments[1].details,
+            PaymentDetails::Liquid { destination, .. } if destination.contains("address2")
+        ));
+        let tx_id = payments[0].tx_id.clone().unwrap();
+        let payment = persister.get_payment(&tx_id)?.unwrap();
+        assert_eq!(payment.amount_sat, 3_000);
 
-        let sdk = new_liquid_sdk_with_chain_services(
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_asset_balances_pending_amounts() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+        let onchain_wallet = Arc::new(MockWallet::new(signer)?);
+        let sdk = new_liquid_sdk_with_wallet(
             persister.clone(),
-            swapper.clone(),
-            status_stream.clone(),
-            liquid_chain_service.clone(),
-            bitcoin_chain_service.clone(),
-            None,
+            swapper,
+            status_stream,
+            onchain_wallet.clone(),
         )
         .await?;
 
-        LiquidSdk::track_swap_updates(&sdk);
+        let usdt_asset_id = "b612eb46313a2cd6ebabd8b7a8eed5696e29898b87a43bff41c94f51acef9d73";
+        let new_wallet_tx = |index: u8, height: Option<u32>, balance: i64| -> Result<WalletTx> {
+            Ok(WalletTx {
+                txid: elements::Txid::from_str(&format!("{index:064x}"))?,
+                tx: elements::Transaction {
+                    version: 2,
+                    lock_time: elements::LockTime::ZERO,
+                    input: vec![],
+                    output: vec![],
+                },
+                height,
+                fee: 100,
+                timestamp: None,
+                balance: [(elements::AssetId::from_str(usdt_asset_id)?, balance)]
+                    .into_iter()
+                    .collect(),
+                outputs: vec![],
+                inputs: vec![],
+                type_: "".to_string(),
+            })
+        };
+        onchain_wallet.set_transactions(vec![
+            new_wallet_tx(1, Some(1), 1_000_000_000)?,
+            new_wallet_tx(2, None, -200_000_000)?,
+            new_wallet_tx(3, None, 300_000_000)?,
+        ]);
+
+        sdk.update_wallet_info().await?;
+        let info = persister
+            .get_info()?
+            .ok_or(anyhow!("Wallet info not stored"))?;
+        let usdt_balance = info
+            .wallet_info
+            .asset_balances
+            .iter()
+            .find(|ab| ab.asset_id == usdt_asset_id)
+            .ok_or(anyhow!("USDt balance not found"))?;
+        // The unconfirmed spend reduces the balance, the unconfirmed receive doesn't
+        assert_eq!(usdt_balance.balance_sat, 800_000_000);
+        assert_eq!(usdt_balance.pending_send_sat, 200_000_000);
+        assert_eq!(usdt_balance.pending_receive_sat, 300_000_000);
+        assert_eq!(usdt_balance.pending_send, Some(2.0));
+        assert_eq!(usdt_balance.pending_receive, Some(3.0));
 
-        // We spawn a new thread since updates can only be sent when called via async runtimes
-        tokio::spawn(async move {
-            // Verify that `TransactionLockupFailed` correctly:
-            // 1. does not affect state when swapper doesn't increase fees
-            // 2. triggers a change to WaitingFeeAcceptance when there is a fee increase > 0
-            for fee_increase in [0, 1] {
-                swapper.set_zero_amount_swap_mock_config(ZeroAmountSwapMockConfig {
-                    user_lockup_sat,
-                    onchain_fee_increase_sat: fee_increase,
-                });
-                bitcoin_chain_service.set_script_balance_sat(user_lockup_sat);
-                let persisted_swap = trigger_swap_update!(
-                    "chain",
-                    NewSwapArgs::default()
-                        .set_direction(Direction::Incoming)
-                        .set_accepts_zero_conf(false)
-                        .set_zero_amount(true),
-                    persister,
-                    status_stream,
-                    ChainSwapStates::TransactionLockupFailed,
-                    None,
-                    None
-                );
-                match fee_increase {
-                    0 => {
-                        assert_eq!(persisted_swap.state, PaymentState::Created);
-                    }
-                    1 => {
-                        assert_eq!(persisted_swap.state, PaymentState::WaitingFeeAcceptance);
-                    }
-                    _ => panic!("Unexpected fee_increase"),
-                }
-            }
-        })
-        .await?;
+        Ok(())
+    }
+
+    struct UnavailableFiatRateProvider {}
+
+    impl FiatRateProvider for UnavailableFiatRateProvider {
+        fn fetch_fiat_rates(&self) -> Result<Vec<Rate>, FiatRateProviderError> {
+            Err(FiatRateProviderError::Generic {
+                err: "Unavailable".to_string(),
+            })
+        }
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_check_health() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        sdk.set_fiat_rate_provider(Box::new(MockFiatRateProvider {}))
+            .await?;
+        let res = sdk.check_health().await?;
+        assert!(!res.is_degraded);
+        assert_eq!(res.services.len(), 6);
+        assert!(res.services.iter().all(|service| service.is_reachable));
+        assert!(res
+            .swap_service_status
+            .is_some_and(|status| status.is_fully_available()));
+
+        // An unreachable service degrades the health
+        sdk.set_fiat_rate_provider(Box::new(UnavailableFiatRateProvider {}))
+            .await?;
+        let res = sdk.check_health().await?;
+        assert!(res.is_degraded);
+        let unreachable: Vec<HealthCheckService> = res
+            .services
+            .iter()
+            .filter(|service| !service.is_reachable)
+            .map(|service| service.service)
+            .collect();
+        assert_eq!(unreachable, vec![HealthCheckService::FiatRates]);
 
         Ok(())
     }
 
     #[sdk_macros::async_test_all]
-    async fn test_zero_amount_chain_swap_with_leeway() -> Result<()> {
-        let user_lockup_sat = 50_000;
-        let onchain_fee_rate_leeway_sat_per_vbyte = 5;
+    async fn test_prepare_receive_chain_fee_breakdown() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper.clone(), status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        let response = sdk
+            .prepare_receive_payment(&PrepareReceiveRequest {
+                payment_method: PaymentMethod::BitcoinAddress,
+                amount: Some(ReceiveAmount::Bitcoin {
+                    payer_amount_sat: 50_000,
+                }),
+                fiat_amount: None,
+                expiry_secs: None,
+            })
+            .await?;
+
+        // The swapper's network fees are not reported as the fee of a lockup tx
+        let pair = swapper.get_chain_pair(Direction::Incoming).await?.unwrap();
+        let fee_breakdown = response.fee_breakdown;
+        assert_eq!(fee_breakdown.swapper_network_fee_sat, pair.fees.server());
+        assert_eq!(fee_breakdown.lockup_fee_sat, 0);
+        assert_eq!(fee_breakdown.claim_fee_sat, pair.fees.claim_estimate());
+        assert_eq!(fee_breakdown.total(), response.fees_sat);
+
+        Ok(())
+    }
 
+    #[sdk_macros::async_test_all]
+    async fn test_prepare_receive_payment_quotes() -> Result<()> {
         create_persister!(persister);
-        let swapper = Arc::new(MockSwapper::new());
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        let quotes_request = |amount| PrepareReceiveQuotesRequest {
+            amount: Some(amount),
+            fiat_amount: None,
+        };
+        let payment_methods = |response: PrepareReceiveQuotesResponse| {
+            response
+                .quotes
+                .into_iter()
+                .map(|quote| quote.payment_method)
+                .collect::<Vec<_>>()
+        };
+
+        // All payment methods can receive an amount within the swap limits
+        let response = sdk
+            .prepare_receive_payment_quotes(&quotes_request(ReceiveAmount::Bitcoin {
+                payer_amount_sat: 50_000,
+            }))
+            .await?;
+        assert_eq!(
+            payment_methods(response),
+            vec![
+                PaymentMethod::Lightning,
+                PaymentMethod::BitcoinAddress,
+                PaymentMethod::LiquidAddress
+            ]
+        );
+
+        // Lightning is left out below the reverse swap minimum
+        let response = sdk
+            .prepare_receive_payment_quotes(&quotes_request(ReceiveAmount::Bitcoin {
+                payer_amount_sat: 500,
+            }))
+            .await?;
+        assert_eq!(
+            payment_methods(response),
+            vec![PaymentMethod::BitcoinAddress, PaymentMethod::LiquidAddress]
+        );
+
+        // Lightning is left out without an amount
+        let response = sdk
+            .prepare_receive_payment_quotes(&PrepareReceiveQuotesRequest {
+                amount: None,
+                fiat_amount: None,
+            })
+            .await?;
+        assert_eq!(
+            payment_methods(response),
+            vec![PaymentMethod::BitcoinAddress, PaymentMethod::LiquidAddress]
+        );
+
+        // An asset can only be received to a Liquid address
+        let response = sdk
+            .prepare_receive_payment_quotes(&quotes_request(ReceiveAmount::Asset {
+                asset_id: "b612eb46313a2cd6ebabd8b7a8eed5696e29898b87a43bff41c94f51acef9d73"
+                    .to_string(),
+                payer_amount: Some(1.5),
+            }))
+            .await?;
+        assert_eq!(
+            payment_methods(response),
+            vec![PaymentMethod::LiquidAddress]
+        );
+
+        // Only one of amount and fiat_amount can be set
+        assert!(sdk
+            .prepare_receive_payment_quotes(&PrepareReceiveQuotesRequest {
+                amount: Some(ReceiveAmount::Bitcoin {
+                    payer_amount_sat: 50_000,
+                }),
+                fiat_amount: Some(FiatAmount {
+                    amount: 10.0,
+                    currency: "USD".to_string(),
+                }),
+            })
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_onchain_limits_zero_conf_threshold() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+
+        // The swapper's zero-conf limit applies when below the configured threshold
+        let sdk = new_liquid_sdk(persister.clone(), swapper.clone(), status_stream.clone()).await?;
+        *sdk.is_started.write().await = true;
+        let limits = sdk.fetch_onchain_limits().await?;
+        assert_eq!(limits.receive.max_zero_conf_sat, 100_000);
+
+        // Otherwise the configured threshold applies
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+        let mut builder = new_liquid_sdk_builder(
+            persister,
+            swapper,
+            status_stream,
+            Arc::new(MockLiquidChainService::new()),
+            Arc::new(MockBitcoinChainService::new()),
+            Arc::new(MockWallet::new(signer)?),
+            None,
+        )?;
+        builder.config.zero_conf_max_amount_sat = Some(50_000);
+        let sdk = builder.build().await?;
+        *sdk.is_started.write().await = true;
+        let limits = sdk.fetch_onchain_limits().await?;
+        assert_eq!(limits.receive.max_zero_conf_sat, 50_000);
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_new_block_events() -> Result<()> {
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
         let status_stream = Arc::new(MockStatusStream::new());
         let liquid_chain_service = Arc::new(MockLiquidChainService::new());
         let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
-
-        let sdk = new_liquid_sdk_with_chain_services(
-            persister.clone(),
-            swapper.clone(),
-            status_stream.clone(),
+        let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+        let mut builder = new_liquid_sdk_builder(
+            persister,
+            swapper,
+            status_stream,
             liquid_chain_service.clone(),
             bitcoin_chain_service.clone(),
-            Some(onchain_fee_rate_leeway_sat_per_vbyte),
-        )
+            Arc::new(MockWallet::new(signer)?),
+            None,
+        )?;
+        builder.config.sync_interval_sec = Some(1);
+        let sdk = builder.build().await?;
+        *sdk.is_started.write().await = true;
+        let mut events = sdk.event_manager.subscribe();
+
+        let liquid_block = BlockInfo {
+            height: 100,
+            hash: "liquid-block-hash".to_string(),
+            timestamp: 1_700_000_000,
+        };
+        let bitcoin_block = BlockInfo {
+            height: 200,
+            hash: "bitcoin-block-hash".to_string(),
+            timestamp: 1_700_000_060,
+        };
+        liquid_chain_service.set_tip_block(liquid_block.clone());
+        bitcoin_chain_service.set_tip_block(bitcoin_block.clone());
+
+        sdk.track_new_blocks();
+
+        // Both new blocks are notified with their details
+        let blocks = tokio::time::timeout(Duration::from_secs(5), async {
+            let (mut new_liquid_block, mut new_bitcoin_block) = (None, None);
+            while new_liquid_block.is_none() || new_bitcoin_block.is_none() {
+                match events.recv().await {
+                    Ok(SdkEvent::NewLiquidBlock { block }) => new_liquid_block = Some(block),
+                    Ok(SdkEvent::NewBitcoinBlock { block }) => new_bitcoin_block = Some(block),
+                    _ => {}
+                }
+            }
+            (new_liquid_block, new_bitcoin_block)
+        })
         .await?;
+        assert_eq!(blocks, (Some(liquid_block), Some(bitcoin_block)));
 
-        LiquidSdk::track_swap_updates(&sdk);
+        sdk.shutdown_sender.send(())?;
+        Ok(())
+    }
 
-        let max_fee_increase_for_auto_accept_sat =
-            onchain_fee_rate_leeway_sat_per_vbyte as u64 * ESTIMATED_BTC_LOCKUP_TX_VSIZE;
+    /// Records the names of the created tracing spans
+    #[derive(Default)]
+    struct SpanRecorder {
+        names: Arc<std::sync::Mutex<Vec<&'static str>>>,
+        next_id: std::sync::atomic::AtomicU64,
+    }
 
-        // We spawn a new thread since updates can only be sent when called via async runtimes
-        tokio::spawn(async move {
-            // Verify that `TransactionLockupFailed` correctly:
-            // 1. does not affect state when swapper increases fee by up to sat/vbyte leeway * tx size
-            // 2. triggers a change to WaitingFeeAcceptance when it is any higher
-            for fee_increase in [
-                max_fee_increase_for_auto_accept_sat,
-                max_fee_increase_for_auto_accept_sat + 1,
-            ] {
-                swapper.set_zero_amount_swap_mock_config(ZeroAmountSwapMockConfig {
-                    user_lockup_sat,
-                    onchain_fee_increase_sat: fee_increase,
-                });
-                bitcoin_chain_service.set_script_balance_sat(user_lockup_sat);
-                let persisted_swap = trigger_swap_update!(
-                    "chain",
-                    NewSwapArgs::default()
-                        .set_direction(Direction::Incoming)
-                        .set_accepts_zero_conf(false)
-                        .set_zero_amount(true),
-                    persister,
-                    status_stream,
-                    ChainSwapStates::TransactionLockupFailed,
-                    None,
-                    None
-                );
-                match fee_increase {
-                    val if val == max_fee_increase_for_auto_accept_sat => {
-                        assert_eq!(persisted_swap.state, PaymentState::Created);
-                    }
-                    val if val == (max_fee_increase_for_auto_accept_sat + 1) => {
-                        assert_eq!(persisted_swap.state, PaymentState::WaitingFeeAcceptance);
-                    }
-                    _ => panic!("Unexpected fee_increase"),
-                }
-            }
-        })
+    impl tracing::Subscriber for SpanRecorder {
+        fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            self.names.lock().unwrap().push(span.metadata().name());
+            let id = self
+                .next_id
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            tracing::span::Id::from_u64(id + 1)
+        }
+
+        fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+        fn event(&self, _event: &tracing::Event<'_>) {}
+
+        fn enter(&self, _span: &tracing::span::Id) {}
+
+        fn exit(&self, _span: &tracing::span::Id) {}
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_tracing_spans() -> Result<()> {
+        use tracing::instrument::WithSubscriber as _;
+
+        create_persister!(persister);
+        let swapper = Arc::new(MockSwapper::default());
+        let status_stream = Arc::new(MockStatusStream::new());
+        let sdk = new_liquid_sdk(persister, swapper, status_stream).await?;
+        *sdk.is_started.write().await = true;
+
+        let recorder = SpanRecorder::default();
+        let names = recorder.names.clone();
+        async {
+            sdk.sync(false).await?;
+            sdk.prepare_receive_payment(&PrepareReceiveRequest {
+                payment_method: PaymentMethod::LiquidAddress,
+                amount: None,
+                fiat_amount: None,
+                expiry_secs: None,
+            })
+            .await?;
+            anyhow::Ok(())
+        }
+        .with_subscriber(recorder)
         .await?;
 
+        // The main flows are each wrapped in a span
+        let names = names.lock().unwrap();
+        assert!(names.contains(&"sync"));
+        assert!(names.contains(&"prepare_receive_payment"));
+
         Ok(())
     }
 }
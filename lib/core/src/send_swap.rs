@@ -8,17 +8,19 @@ use boltz_client::swaps::{boltz::CreateSubmarineResponse, boltz::SubSwapStates};
 use futures_util::TryFutureExt;
 use log::{debug, info, warn};
 use lwk_wollet::elements::{LockTime, Transaction};
-use lwk_wollet::hashes::{sha256, Hash};
-use sdk_common::prelude::{AesSuccessActionDataResult, SuccessAction, SuccessActionProcessed};
+use sdk_common::prelude::SuccessAction;
 use sdk_common::utils::Arc;
 use tokio::sync::broadcast;
 use web_time::{SystemTime, UNIX_EPOCH};
 
 use crate::chain::liquid::LiquidChainService;
+use crate::in_flight::InFlightOperations;
+use crate::lnurl::pay::process_success_action;
 use crate::model::{
     BlockListener, Config, PaymentState::*, SendSwap, LIQUID_FEE_RATE_MSAT_PER_VBYTE,
 };
 use crate::persist::model::PaymentTxDetails;
+use crate::persist::swap_intent::SwapIntentKind;
 use crate::prelude::{PaymentTxData, PaymentType, Swap};
 use crate::recover::recoverer::Recoverer;
 use crate::swapper::Swapper;
@@ -39,6 +41,7 @@ pub(crate) struct SendSwapHandler {
     chain_service: Arc<dyn LiquidChainService>,
     subscription_notifier: broadcast::Sender<String>,
     recoverer: Arc<Recoverer>,
+    in_flight: Arc<InFlightOperations>,
 }
 
 #[sdk_macros::async_trait]
@@ -60,6 +63,7 @@ impl SendSwapHandler {
         swapper: Arc<dyn Swapper>,
         chain_service: Arc<dyn LiquidChainService>,
         recoverer: Arc<Recoverer>,
+        in_flight: Arc<InFlightOperations>,
     ) -> Self {
         let (subscription_notifier, _) = broadcast::channel::<String>(30);
         Self {
@@ -70,6 +74,7 @@ impl SendSwapHandler {
             chain_service,
             subscription_notifier,
             recoverer,
+            in_flight,
         }
     }
 
@@ -97,6 +102,13 @@ impl SendSwapHandler {
             // Boltz has detected the lockup in the mempool. If the swap is not to be batched
             // we can speed up the claim by doing so cooperatively.
             SubSwapStates::TransactionClaimPending => {
+                // A simulated swap has no lockup tx to claim, and the preimage served by the
+                // simulation doesn't match the paid invoice
+                if self.config.simulation.is_some() {
+                    let preimage = self.swapper.get_submarine_preimage(&swap.id).await?;
+                    self.update_swap_info(&swap.id, Complete, Some(&preimage), None, None)?;
+                    return Ok(());
+                }
                 if swap.metadata.is_local {
                     let preimage = match self.swapper.get_send_claim_tx_details(&swap).await {
                         Ok(claim_tx_response) => {
@@ -205,22 +217,31 @@ impl SendSwapHandler {
         }
     }
 
+    /// Builds and broadcasts the lockup tx of the swap. A simulated swap has no lockup tx, since
+    /// its lockup address is not watched by anyone, so `None` is returned.
     pub(crate) async fn try_lockup(
         &self,
         swap: &SendSwap,
         create_response: &CreateSubmarineResponse,
-    ) -> Result<Transaction, PaymentError> {
+    ) -> Result<Option<Transaction>, PaymentError> {
         if swap.lockup_tx_id.is_some() {
             debug!("Lockup tx was already broadcast for Send Swap {}", swap.id);
             return Err(PaymentError::PaymentInProgress);
         }
 
+        let _in_flight = self.in_flight.start();
         let swap_id = &swap.id;
         debug!(
             "Initiated Send Swap: send {} sats to liquid address {}",
             create_response.expected_amount, create_response.address
         );
 
+        if self.config.simulation.is_some() {
+            info!("Simulating the lockup of Send swap {swap_id}, not building a lockup tx");
+            self.update_swap_info(swap_id, Pending, None, None, None)?;
+            return Ok(None);
+        }
+
         let lockup_tx = self
             .onchain_wallet
             .build_tx_or_drain_tx(
@@ -268,7 +289,7 @@ impl SendSwapHandler {
 
         self.update_swap_info(swap_id, Pending, None, Some(&lockup_tx_id), None)?;
 
-        Ok(lockup_tx)
+        Ok(Some(lockup_tx))
     }
 
     fn fetch_send_swap_by_id(&self, swap_id: &str) -> Result<SendSwap, PaymentError> {
@@ -310,39 +331,23 @@ impl SendSwapHandler {
             let Some(ref preimage_str) = updated_swap.preimage.clone() else {
                 return Ok(false);
             };
-            if let Some(PaymentTxDetails {
-                destination,
-                description,
-                lnurl_info: Some(mut lnurl_info),
-                bip353_address,
-                ..
-            }) = self.persister.get_payment_details(&tx_id)?
-            {
-                if let Some(SuccessAction::Aes { data }) =
+            let Some(details) = self.persister.get_payment_details(&tx_id)? else {
+                return Ok(false);
+            };
+            if let Some(mut lnurl_info) = details.lnurl_info.clone() {
+                if let Some(success_action @ SuccessAction::Aes { .. }) =
                     lnurl_info.lnurl_pay_unprocessed_success_action.clone()
                 {
                     debug!(
                         "Decrypting AES success action with preimage for Send Swap {}",
                         swap.id
                     );
-                    let preimage = sha256::Hash::from_str(preimage_str)?;
-                    let preimage_arr = preimage.to_byte_array();
-                    let result = match (data, &preimage_arr).try_into() {
-                        Ok(data) => AesSuccessActionDataResult::Decrypted { data },
-                        Err(e) => AesSuccessActionDataResult::ErrorStatus {
-                            reason: e.to_string(),
-                        },
-                    };
                     lnurl_info.lnurl_pay_success_action =
-                        Some(SuccessActionProcessed::Aes { result });
+                        process_success_action(success_action, Some(preimage_str))?;
                     self.persister
                         .insert_or_update_payment_details(PaymentTxDetails {
-                            tx_id,
-                            destination,
-                            description,
                             lnurl_info: Some(lnurl_info),
-                            bip353_address,
-                            asset_fees: None,
+                            ..details
                         })?;
                     return Ok(true);
                 }
@@ -373,6 +378,11 @@ impl SendSwapHandler {
             lockup_tx_id,
             refund_tx_id,
         )?;
+        if refund_tx_id.is_some() {
+            // The refund tx id is now persisted, so the journaled refund is no longer needed
+            self.persister
+                .delete_swap_intent(swap_id, SwapIntentKind::Refund)?;
+        }
         let updated_swap = self.fetch_send_swap_by_id(swap_id)?;
         let lnurl_info_updated = self.update_swap_lnurl_info(&swap, &updated_swap)?;
         if updated_swap != swap || lnurl_info_updated {
@@ -407,11 +417,13 @@ impl SendSwapHandler {
         Ok(())
     }
 
+    #[tracing::instrument(skip_all, fields(swap_id = %swap.id))]
     pub(crate) async fn refund(
         &self,
         swap: &SendSwap,
         is_cooperative: bool,
     ) -> Result<String, PaymentError> {
+        let _in_flight = self.in_flight.start();
         info!(
             "Initiating refund for Send Swap {}, is_cooperative: {is_cooperative}",
             swap.id
@@ -453,6 +465,11 @@ impl SendSwapHandler {
                 ),
             });
         };
+        self.persister.insert_swap_intent(
+            &swap.id,
+            SwapIntentKind::Refund,
+            Some(&refund_tx.txid().to_string()),
+        )?;
         let refund_tx_id = self.chain_service.broadcast(&refund_tx).await?.to_string();
 
         info!(
@@ -584,12 +601,19 @@ mod tests {
     use std::collections::{HashMap, HashSet};
 
     use anyhow::Result;
+    use boltz_client::{boltz, swaps::boltz::SubSwapStates};
+    use sdk_common::utils::Arc;
 
     use crate::{
-        model::PaymentState::{self, *},
+        model::{
+            Config,
+            PaymentState::{self, *},
+            SimulationConfig,
+        },
         test_utils::{
+            chain::MockLiquidChainService,
             persist::{create_persister, new_send_swap},
-            send_swap::new_send_swap_handler,
+            send_swap::{new_send_swap_handler, new_send_swap_handler_with_config},
         },
     };
 
@@ -653,4 +677,62 @@ mod tests {
 
         Ok(())
     }
+
+    #[sdk_macros::async_test_all]
+    async fn test_simulated_send_swap_completes() -> Result<()> {
+        create_persister!(storage);
+        let mut config = Config::testnet_esplora(None);
+        config.simulation = Some(SimulationConfig {
+            status_update_delay_sec: 0,
+            failure_rate: 0.0,
+        });
+        // Any broadcast would fail the lockup
+        let chain_service = Arc::new(MockLiquidChainService::new());
+        chain_service.set_fail_broadcast(true);
+        let send_swap_handler =
+            new_send_swap_handler_with_config(storage.clone(), config, chain_service)?;
+
+        // No lockup tx is built nor recorded for a simulated swap
+        let send_swap = new_send_swap(None, None);
+        storage.insert_or_update_send_swap(&send_swap)?;
+        let create_response = send_swap.get_boltz_create_response()?;
+        let lockup_tx = send_swap_handler
+            .try_lockup(&send_swap, &create_response)
+            .await?;
+        assert!(lockup_tx.is_none());
+        let persisted_swap = storage.fetch_send_swap_by_id(&send_swap.id)?.unwrap();
+        assert_eq!(persisted_swap.state, Pending);
+        assert!(persisted_swap.lockup_tx_id.is_none());
+        assert!(storage.list_unconfirmed_payment_txs_data()?.is_empty());
+
+        // The swap completes with the preimage served by the simulation
+        send_swap_handler
+            .on_new_status(&boltz::SwapStatus {
+                id: send_swap.id.clone(),
+                status: SubSwapStates::TransactionClaimPending.to_string(),
+                ..Default::default()
+            })
+            .await?;
+        let persisted_swap = storage.fetch_send_swap_by_id(&send_swap.id)?.unwrap();
+        assert_eq!(persisted_swap.state, Complete);
+        assert!(persisted_swap.preimage.is_some());
+
+        // A failed simulated swap has nothing to refund
+        let failed_swap = new_send_swap(None, None);
+        storage.insert_or_update_send_swap(&failed_swap)?;
+        send_swap_handler
+            .try_lockup(&failed_swap, &failed_swap.get_boltz_create_response()?)
+            .await?;
+        send_swap_handler
+            .on_new_status(&boltz::SwapStatus {
+                id: failed_swap.id.clone(),
+                status: SubSwapStates::InvoiceFailedToPay.to_string(),
+                ..Default::default()
+            })
+            .await?;
+        let persisted_swap = storage.fetch_send_swap_by_id(&failed_swap.id)?.unwrap();
+        assert_eq!(persisted_swap.state, Failed);
+
+        Ok(())
+    }
 }
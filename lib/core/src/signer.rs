@@ -3,7 +3,6 @@ use bip39::Mnemonic;
 use boltz_client::PublicKey;
 use lwk_common::Signer as LwkSigner;
 use lwk_wollet::bitcoin::bip32::Xpriv;
-use lwk_wollet::bitcoin::Network;
 use lwk_wollet::elements_miniscript::{self, ToPublicKey as _};
 use lwk_wollet::elements_miniscript::{
     bitcoin::{self, bip32::DerivationPath},
@@ -22,6 +21,7 @@ use lwk_wollet::hashes::{sha256, HashEngine, Hmac, HmacEngine};
 use lwk_wollet::secp256k1::ecdsa::Signature;
 use lwk_wollet::secp256k1::Message;
 use sdk_common::utils::Arc;
+use std::str::FromStr;
 
 use crate::model::{Signer, SignerError};
 
@@ -100,6 +100,20 @@ impl LwkSigner for SdkLwkSigner {
     type Error = SignError;
 
     fn sign(&self, pset: &mut PartiallySignedTransaction) -> Result<u32, Self::Error> {
+        // Fixme: Take a parameter
+        let hash_ty = elements_miniscript::elements::EcdsaSighashType::All;
+
+        // The messages are computed for, and the signatures committed to, SIGHASH_ALL only
+        if let Some(index) = pset.inputs().iter().position(|input| {
+            input
+                .sighash_type
+                .is_some_and(|ty| ty.ecdsa_hash_ty() != Some(hash_ty))
+        }) {
+            return Err(SignError::Generic(anyhow!(
+                "Input {index} requests an unsupported sighash type"
+            )));
+        }
+
         let tx = pset.extract_tx()?;
         let mut sighash_cache = SighashCache::new(&tx);
         let mut signature_added = 0;
@@ -116,9 +130,6 @@ impl LwkSigner for SdkLwkSigner {
             messages.push(msg);
         }
 
-        // Fixme: Take a parameter
-        let hash_ty = elements_miniscript::elements::EcdsaSighashType::All;
-
         let signer_fingerprint = self.fingerprint()?;
         for (input, msg) in pset.inputs_mut().iter_mut().zip(messages) {
             for (want_public_key, (fingerprint, derivation_path)) in input.bip32_derivation.iter() {
@@ -172,8 +183,8 @@ impl LwkSigner for SdkLwkSigner {
 pub struct SdkSigner {
     xprv: Xpriv,
     secp: Secp256k1<All>, // could be sign only, but it is likely the caller already has the All context.
-    seed: Vec<u8>,
-    network: Network,
+    /// The seed the SLIP77 master blinding key is derived from
+    slip77_seed: Vec<u8>,
 }
 
 impl SdkSigner {
@@ -201,8 +212,32 @@ impl SdkSigner {
         Ok(Self {
             xprv,
             secp,
-            seed,
-            network,
+            slip77_seed: seed,
+        })
+    }
+
+    /// Creates a signer from a BIP32 master extended private key.
+    ///
+    /// As there is no seed to derive the SLIP77 master blinding key from, it is derived from
+    /// the serialized key instead. The resulting wallet is therefore not the same as the one
+    /// created with the seed of the key.
+    pub fn new_with_xprv(xprv: &str, is_mainnet: bool) -> Result<Self, NewError> {
+        let xprv = Xpriv::from_str(xprv.trim())?;
+        if xprv.depth != 0 {
+            return Err(NewError::Seed(anyhow!(
+                "The extended private key must be a master key"
+            )));
+        }
+        if xprv.network.is_mainnet() != is_mainnet {
+            return Err(NewError::Seed(anyhow!(
+                "The extended private key is for a different network"
+            )));
+        }
+
+        Ok(Self {
+            xprv,
+            secp: Secp256k1::new(),
+            slip77_seed: xprv.encode().to_vec(),
         })
     }
 }
@@ -232,15 +267,13 @@ impl Signer for SdkSigner {
     }
 
     fn slip77_master_blinding_key(&self) -> Result<Vec<u8>, SignerError> {
-        let master_blinding_key = MasterBlindingKey::from_seed(&self.seed);
+        let master_blinding_key = MasterBlindingKey::from_seed(&self.slip77_seed);
         Ok(master_blinding_key.as_bytes().to_vec())
     }
 
     fn sign_ecdsa_recoverable(&self, msg: Vec<u8>) -> Result<Vec<u8>, SignerError> {
         let secp = Secp256k1::new();
-        let keypair = Xpriv::new_master(self.network, &self.seed)
-            .map_err(|e| anyhow::anyhow!("Could not get signer keypair: {e}"))?
-            .to_keypair(&secp);
+        let keypair = self.xprv.to_keypair(&secp);
         let s = msg.as_slice();
 
         let msg: Message = Message::from_digest_slice(s)
@@ -382,6 +415,35 @@ mod tests {
         let mut seed2 = [0u8; 32];
         rng.fill_bytes(&mut seed2);
         assert!(SdkSigner::new_with_seed(seed2.to_vec(), false).is_ok());
+
+        assert!(SdkSigner::new_with_xprv("", false).is_err());
+    }
+
+    #[sdk_macros::test_all]
+    fn test_xprv_signer() {
+        let seed = [1u8; 64];
+        let xprv = Xpriv::new_master(bitcoin::Network::Testnet, &seed).unwrap();
+        let xprv_signer = SdkSigner::new_with_xprv(&xprv.to_string(), false).unwrap();
+        let seed_signer = SdkSigner::new_with_seed(seed.to_vec(), false).unwrap();
+
+        // Same keys, but a different blinding key
+        assert_eq!(xprv_signer.xpub().unwrap(), seed_signer.xpub().unwrap());
+        assert_ne!(
+            xprv_signer.slip77_master_blinding_key().unwrap(),
+            seed_signer.slip77_master_blinding_key().unwrap()
+        );
+
+        // Wrong network
+        assert!(SdkSigner::new_with_xprv(&xprv.to_string(), true).is_err());
+
+        // Not a master key
+        let child_xprv = xprv
+            .derive_priv(
+                &Secp256k1::new(),
+                &DerivationPath::from_str("m/0'").unwrap(),
+            )
+            .unwrap();
+        assert!(SdkSigner::new_with_xprv(&child_xprv.to_string(), false).is_err());
     }
 
     #[sdk_macros::test_all]
@@ -410,6 +472,25 @@ mod tests {
         assert_eq!(tx_sw, tx_sdk);
     }
 
+    #[sdk_macros::test_all]
+    fn test_sign_rejects_non_all_sighash() {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let (_, sdk_signer) = create_signers(mnemonic);
+
+        let mut pset = create_pset(&sdk_signer);
+        pset.inputs_mut()[0].sighash_type = Some(elements::pset::PsbtSighashType::from_u32(
+            elements::EcdsaSighashType::All as u32,
+        ));
+        assert_eq!(sdk_signer.sign(&mut pset).unwrap(), 1);
+
+        let mut pset = create_pset(&sdk_signer);
+        pset.inputs_mut()[0].sighash_type = Some(elements::pset::PsbtSighashType::from_u32(
+            elements::EcdsaSighashType::SinglePlusAnyoneCanPay as u32,
+        ));
+        assert!(sdk_signer.sign(&mut pset).is_err());
+        assert!(pset.inputs()[0].partial_sigs.is_empty());
+    }
+
     #[sdk_macros::test_all]
     fn test_slip77_master_blinding_key() {
         let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
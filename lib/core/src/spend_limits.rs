@@ -0,0 +1,55 @@
+use lwk_wollet::hashes::{sha256, HashEngine, Hmac, HmacEngine};
+
+/// Creates a token overriding the [SpendLimits](crate::model::SpendLimits) until `expires_at`.
+///
+/// The token has the format `<expires_at>:<signature>`, where the signature is the hex-encoded
+/// HMAC-SHA256 of `expires_at` keyed with the override secret.
+pub(crate) fn create_override_token(secret: &str, expires_at: u32) -> String {
+    format!("{expires_at}:{}", sign(secret, expires_at))
+}
+
+/// Whether the token was created with the override secret and has not expired
+pub(crate) fn verify_override_token(secret: &str, token: &str, now: u32) -> bool {
+    let Some((expires_at, signature)) = token.split_once(':') else {
+        return false;
+    };
+    let Ok(expires_at) = expires_at.parse::<u32>() else {
+        return false;
+    };
+    now < expires_at && constant_time_eq(sign(secret, expires_at).as_bytes(), signature.as_bytes())
+}
+
+/// Compares without short-circuiting, so the time taken doesn't leak how much of a forged
+/// signature matches
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn sign(secret: &str, expires_at: u32) -> String {
+    let mut engine = HmacEngine::<sha256::Hash>::new(secret.as_bytes());
+    engine.input(expires_at.to_string().as_bytes());
+    Hmac::<sha256::Hash>::from_engine(engine).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{create_override_token, verify_override_token};
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_override_token() {
+        let token = create_override_token("secret", 1_000);
+        assert!(verify_override_token("secret", &token, 999));
+
+        // Expired
+        assert!(!verify_override_token("secret", &token, 1_000));
+        // Created with another secret
+        assert!(!verify_override_token("other", &token, 999));
+        // Tampered expiry
+        let tampered = token.replacen("1000", "2000", 1);
+        assert!(!verify_override_token("secret", &tampered, 1_500));
+        assert!(!verify_override_token("secret", "invalid", 999));
+    }
+}
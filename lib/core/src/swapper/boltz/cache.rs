@@ -0,0 +1,95 @@
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use web_time::Instant;
+
+/// Caches a value fetched from the swapper for a limited time.
+///
+/// Concurrent requests for an expired value are coalesced: the first one fetches it, while the
+/// others wait and reuse the fetched value.
+pub(crate) struct TtlCache<T> {
+    ttl: Duration,
+    entry: Mutex<Option<(Instant, T)>>,
+}
+
+impl<T: Clone> TtlCache<T> {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entry: Mutex::new(None),
+        }
+    }
+
+    /// Returns the cached value if it's still valid, or fetches it otherwise. A failed fetch is
+    /// not cached.
+    pub(crate) async fn get_or_fetch<E, F, Fut>(&self, fetch: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut entry = self.entry.lock().await;
+        if let Some((fetched_at, value)) = entry.as_ref() {
+            if fetched_at.elapsed() < self.ttl {
+                return Ok(value.clone());
+            }
+        }
+
+        let value = fetch().await?;
+        *entry = Some((Instant::now(), value.clone()));
+        Ok(value)
+    }
+
+    /// Drops the cached value, so that the next request fetches it again
+    pub(crate) async fn invalidate(&self) {
+        *self.entry.lock().await = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use anyhow::{anyhow, Result};
+    use futures_util::future::join;
+
+    use super::TtlCache;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::async_test_all]
+    async fn test_ttl_cache() -> Result<()> {
+        let fetches = AtomicUsize::new(0);
+        let fetch = || async { Ok::<_, anyhow::Error>(fetches.fetch_add(1, Ordering::SeqCst)) };
+
+        // Concurrent requests are coalesced into a single fetch, which is then reused
+        let cache = TtlCache::new(Duration::from_secs(60));
+        let (first, second) = join(cache.get_or_fetch(fetch), cache.get_or_fetch(fetch)).await;
+        assert_eq!(first?, 0);
+        assert_eq!(second?, 0);
+        assert_eq!(cache.get_or_fetch(fetch).await?, 0);
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        // Expired values are fetched again
+        let cache = TtlCache::new(Duration::ZERO);
+        assert_eq!(cache.get_or_fetch(fetch).await?, 1);
+        assert_eq!(cache.get_or_fetch(fetch).await?, 2);
+
+        // Failed fetches are not cached
+        let cache = TtlCache::new(Duration::from_secs(60));
+        assert!(cache
+            .get_or_fetch(|| async { Err::<usize, _>(anyhow!("Unavailable")) })
+            .await
+            .is_err());
+        assert_eq!(cache.get_or_fetch(fetch).await?, 3);
+
+        // Invalidated values are fetched again, even if not yet expired
+        cache.invalidate().await;
+        assert_eq!(cache.get_or_fetch(fetch).await?, 4);
+        assert_eq!(cache.get_or_fetch(fetch).await?, 4);
+
+        Ok(())
+    }
+}
@@ -1,9 +1,14 @@
+use std::sync::atomic::AtomicBool;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 use crate::{
     error::{PaymentError, SdkError},
     model::LIQUID_FEE_RATE_SAT_PER_VBYTE,
-    prelude::{ChainSwap, Config, Direction, LiquidNetwork, SendSwap, Swap, Transaction, Utxo},
+    prelude::{
+        ChainSwap, Config, Direction, LiquidNetwork, SendSwap, Swap, SwapServiceStatus,
+        Transaction, Utxo,
+    },
 };
 use anyhow::{anyhow, Result};
 use boltz_client::{
@@ -16,20 +21,27 @@ use boltz_client::{
     network::Chain,
     Amount,
 };
+use cache::TtlCache;
 use client::{BitcoinClient, LiquidClient};
 use log::info;
 use proxy::split_proxy_url;
+use retry::RetryPolicy;
 use sdk_common::utils::Arc;
 use tokio::sync::broadcast;
 
 use super::{ProxyUrlFetcher, Swapper};
 
 pub(crate) mod bitcoin;
+mod cache;
 mod client;
 pub(crate) mod liquid;
 pub(crate) mod proxy;
+pub(crate) mod retry;
 pub mod status_stream;
 
+/// How long the fetched pairs are reused, so that successive prepare calls don't each fetch them
+const PAIRS_CACHE_TTL: Duration = Duration::from_secs(30);
+
 pub(crate) struct BoltzClient {
     url: String,
     referral_id: Option<String>,
@@ -44,6 +56,13 @@ pub struct BoltzSwapper<P: ProxyUrlFetcher> {
     proxy_url: Arc<P>,
     subscription_notifier: broadcast::Sender<String>,
     update_notifier: broadcast::Sender<boltz::SwapStatus>,
+    /// The (outgoing, incoming) chain pairs
+    chain_pairs: TtlCache<(Option<ChainPair>, Option<ChainPair>)>,
+    submarine_pairs: TtlCache<Option<SubmarinePair>>,
+    reverse_pairs: TtlCache<Option<ReversePair>>,
+    retry_policy: RetryPolicy,
+    /// Whether the status stream is connected
+    ws_connected: AtomicBool,
 }
 
 impl<P: ProxyUrlFetcher> BoltzSwapper<P> {
@@ -59,6 +78,11 @@ impl<P: ProxyUrlFetcher> BoltzSwapper<P> {
             bitcoin_client: OnceLock::new(),
             subscription_notifier,
             update_notifier,
+            chain_pairs: TtlCache::new(PAIRS_CACHE_TTL),
+            submarine_pairs: TtlCache::new(PAIRS_CACHE_TTL),
+            reverse_pairs: TtlCache::new(PAIRS_CACHE_TTL),
+            retry_policy: RetryPolicy::new(config.swapper_max_attempts(), config.swapper_timeout()),
+            ws_connected: AtomicBool::new(false),
         })
     }
 
@@ -174,12 +198,23 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         &self,
         req: CreateChainRequest,
     ) -> Result<CreateChainResponse, PaymentError> {
-        let client = self.get_boltz_client().await?;
-        let modified_req = CreateChainRequest {
-            referral_id: client.referral_id.clone(),
-            ..req.clone()
-        };
-        Ok(client.inner.post_chain_req(modified_req).await?)
+        let res = self
+            .retry_policy
+            .call_once("create_chain_swap", async {
+                let client = self.get_boltz_client().await?;
+                let modified_req = CreateChainRequest {
+                    referral_id: client.referral_id.clone(),
+                    ..req.clone()
+                };
+                Ok(client.inner.post_chain_req(modified_req).await?)
+            })
+            .await;
+        // The swapper rejects requests made with outdated pairs, so make sure the next attempt
+        // uses the current ones
+        if res.is_err() {
+            self.chain_pairs.invalidate().await;
+        }
+        res
     }
 
     /// Create a new send swap
@@ -187,27 +222,31 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         &self,
         req: CreateSubmarineRequest,
     ) -> Result<CreateSubmarineResponse, PaymentError> {
-        let client = self.get_boltz_client().await?;
-        let modified_req = CreateSubmarineRequest {
-            referral_id: client.referral_id.clone(),
-            ..req.clone()
-        };
-        Ok(client.inner.post_swap_req(&modified_req).await?)
+        let res = self
+            .retry_policy
+            .call_once("create_send_swap", async {
+                let client = self.get_boltz_client().await?;
+                let modified_req = CreateSubmarineRequest {
+                    referral_id: client.referral_id.clone(),
+                    ..req.clone()
+                };
+                Ok(client.inner.post_swap_req(&modified_req).await?)
+            })
+            .await;
+        if res.is_err() {
+            self.submarine_pairs.invalidate().await;
+        }
+        res
     }
 
     async fn get_chain_pair(
         &self,
         direction: Direction,
     ) -> Result<Option<ChainPair>, PaymentError> {
-        let pairs = self
-            .get_boltz_client()
-            .await?
-            .inner
-            .get_chain_pairs()
-            .await?;
+        let (pair_outgoing, pair_incoming) = self.get_chain_pairs().await?;
         let pair = match direction {
-            Direction::Incoming => pairs.get_btc_to_lbtc_pair(),
-            Direction::Outgoing => pairs.get_lbtc_to_btc_pair(),
+            Direction::Incoming => pair_incoming,
+            Direction::Outgoing => pair_outgoing,
         };
         Ok(pair)
     }
@@ -215,25 +254,41 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
     async fn get_chain_pairs(
         &self,
     ) -> Result<(Option<ChainPair>, Option<ChainPair>), PaymentError> {
-        let pairs = self
-            .get_boltz_client()
-            .await?
-            .inner
-            .get_chain_pairs()
-            .await?;
-        let pair_outgoing = pairs.get_lbtc_to_btc_pair();
-        let pair_incoming = pairs.get_btc_to_lbtc_pair();
-        Ok((pair_outgoing, pair_incoming))
+        self.chain_pairs
+            .get_or_fetch(|| {
+                self.retry_policy.retry("get_chain_pairs", || async {
+                    let pairs = self
+                        .get_boltz_client()
+                        .await?
+                        .inner
+                        .get_chain_pairs()
+                        .await?;
+                    let pair_outgoing = pairs.get_lbtc_to_btc_pair();
+                    let pair_incoming = pairs.get_btc_to_lbtc_pair();
+                    Ok((pair_outgoing, pair_incoming))
+                })
+            })
+            .await
+    }
+
+    async fn invalidate_pairs(&self) {
+        self.chain_pairs.invalidate().await;
+        self.submarine_pairs.invalidate().await;
+        self.reverse_pairs.invalidate().await;
     }
 
     async fn get_zero_amount_chain_swap_quote(&self, swap_id: &str) -> Result<Amount, SdkError> {
-        self.get_boltz_client()
-            .await?
-            .inner
-            .get_quote(swap_id)
+        self.retry_policy
+            .retry("get_quote", || async {
+                self.get_boltz_client()
+                    .await?
+                    .inner
+                    .get_quote(swap_id)
+                    .await
+                    .map(|r| Amount::from_sat(r.amount))
+                    .map_err(Into::into)
+            })
             .await
-            .map(|r| Amount::from_sat(r.amount))
-            .map_err(Into::into)
     }
 
     async fn accept_zero_amount_chain_swap_quote(
@@ -241,34 +296,48 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         swap_id: &str,
         server_lockup_sat: u64,
     ) -> Result<(), PaymentError> {
-        self.get_boltz_client()
-            .await?
-            .inner
-            .accept_quote(swap_id, server_lockup_sat)
+        self.retry_policy
+            .call_once("accept_quote", async {
+                self.get_boltz_client()
+                    .await?
+                    .inner
+                    .accept_quote(swap_id, server_lockup_sat)
+                    .await
+                    .map_err(Into::into)
+            })
             .await
-            .map_err(Into::into)
     }
 
     /// Get a submarine pair information
     async fn get_submarine_pairs(&self) -> Result<Option<SubmarinePair>, PaymentError> {
-        Ok(self
-            .get_boltz_client()
-            .await?
-            .inner
-            .get_submarine_pairs()
-            .await?
-            .get_lbtc_to_btc_pair())
+        self.submarine_pairs
+            .get_or_fetch(|| {
+                self.retry_policy.retry("get_submarine_pairs", || async {
+                    Ok(self
+                        .get_boltz_client()
+                        .await?
+                        .inner
+                        .get_submarine_pairs()
+                        .await?
+                        .get_lbtc_to_btc_pair())
+                })
+            })
+            .await
     }
 
     /// Get a submarine swap's preimage
     async fn get_submarine_preimage(&self, swap_id: &str) -> Result<String, PaymentError> {
-        Ok(self
-            .get_boltz_client()
-            .await?
-            .inner
-            .get_submarine_preimage(swap_id)
-            .await?
-            .preimage)
+        self.retry_policy
+            .call_once("get_submarine_preimage", async {
+                Ok(self
+                    .get_boltz_client()
+                    .await?
+                    .inner
+                    .get_submarine_preimage(swap_id)
+                    .await?
+                    .preimage)
+            })
+            .await
     }
 
     /// Get claim tx details which includes the preimage as a proof of payment.
@@ -279,10 +348,16 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         swap: &SendSwap,
     ) -> Result<SubmarineClaimTxResponse, PaymentError> {
         let claim_tx_response = self
-            .get_boltz_client()
-            .await?
-            .inner
-            .get_submarine_claim_tx_details(&swap.id)
+            .retry_policy
+            .retry("get_submarine_claim_tx_details", || async {
+                Ok::<_, PaymentError>(
+                    self.get_boltz_client()
+                        .await?
+                        .inner
+                        .get_submarine_claim_tx_details(&swap.id)
+                        .await?,
+                )
+            })
             .await?;
         info!("Received claim tx details: {:?}", &claim_tx_response);
 
@@ -298,25 +373,29 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         claim_tx_response: SubmarineClaimTxResponse,
         refund_address: &str,
     ) -> Result<(), PaymentError> {
-        let swap_id = &swap.id;
-        let keypair = swap.get_refund_keypair()?;
-        let refund_tx_wrapper = self
-            .new_lbtc_refund_wrapper(&Swap::Send(swap.clone()), refund_address)
-            .await?;
+        self.retry_policy
+            .call_once("claim_send_swap_cooperative", async {
+                let swap_id = &swap.id;
+                let keypair = swap.get_refund_keypair()?;
+                let refund_tx_wrapper = self
+                    .new_lbtc_refund_wrapper(&Swap::Send(swap.clone()), refund_address)
+                    .await?;
 
-        let (partial_sig, pub_nonce) = refund_tx_wrapper.partial_sign(
-            &keypair,
-            &claim_tx_response.pub_nonce,
-            &claim_tx_response.transaction_hash,
-        )?;
+                let (partial_sig, pub_nonce) = refund_tx_wrapper.partial_sign(
+                    &keypair,
+                    &claim_tx_response.pub_nonce,
+                    &claim_tx_response.transaction_hash,
+                )?;
 
-        self.get_boltz_client()
-            .await?
-            .inner
-            .post_submarine_claim_tx_details(&swap_id.to_string(), pub_nonce, partial_sig)
-            .await?;
-        info!("Successfully cooperatively claimed Send Swap {swap_id}");
-        Ok(())
+                self.get_boltz_client()
+                    .await?
+                    .inner
+                    .post_submarine_claim_tx_details(&swap_id.to_string(), pub_nonce, partial_sig)
+                    .await?;
+                info!("Successfully cooperatively claimed Send Swap {swap_id}");
+                Ok(())
+            })
+            .await
     }
 
     // Create a new receive swap
@@ -324,23 +403,38 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         &self,
         req: CreateReverseRequest,
     ) -> Result<CreateReverseResponse, PaymentError> {
-        let client = self.get_boltz_client().await?;
-        let modified_req = CreateReverseRequest {
-            referral_id: client.referral_id.clone(),
-            ..req.clone()
-        };
-        Ok(client.inner.post_reverse_req(modified_req).await?)
+        let res = self
+            .retry_policy
+            .call_once("create_receive_swap", async {
+                let client = self.get_boltz_client().await?;
+                let modified_req = CreateReverseRequest {
+                    referral_id: client.referral_id.clone(),
+                    ..req.clone()
+                };
+                Ok(client.inner.post_reverse_req(modified_req).await?)
+            })
+            .await;
+        if res.is_err() {
+            self.reverse_pairs.invalidate().await;
+        }
+        res
     }
 
     // Get a reverse pair information
     async fn get_reverse_swap_pairs(&self) -> Result<Option<ReversePair>, PaymentError> {
-        Ok(self
-            .get_boltz_client()
-            .await?
-            .inner
-            .get_reverse_pairs()
-            .await?
-            .get_btc_to_lbtc_pair())
+        self.reverse_pairs
+            .get_or_fetch(|| {
+                self.retry_policy.retry("get_reverse_pairs", || async {
+                    Ok(self
+                        .get_boltz_client()
+                        .await?
+                        .inner
+                        .get_reverse_pairs()
+                        .await?
+                        .get_btc_to_lbtc_pair())
+                })
+            })
+            .await
     }
 
     /// Create a claim transaction for a receive or chain swap
@@ -349,49 +443,53 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         swap: Swap,
         claim_address: Option<String>,
     ) -> Result<Transaction, PaymentError> {
-        let tx = match &swap {
-            Swap::Chain(swap) => {
-                let Some(claim_address) = claim_address else {
-                    return Err(PaymentError::Generic {
-                        err: format!(
-                            "No claim address was supplied when claiming for Chain swap {}",
-                            swap.id
-                        ),
-                    });
+        self.retry_policy
+            .call_once("create_claim_tx", async {
+                let tx = match &swap {
+                    Swap::Chain(swap) => {
+                        let Some(claim_address) = claim_address else {
+                            return Err(PaymentError::Generic {
+                                err: format!(
+                                    "No claim address was supplied when claiming for Chain swap {}",
+                                    swap.id
+                                ),
+                            });
+                        };
+                        match swap.direction {
+                            Direction::Incoming => Transaction::Liquid(
+                                self.new_incoming_chain_claim_tx(swap, claim_address)
+                                    .await?,
+                            ),
+                            Direction::Outgoing => Transaction::Bitcoin(
+                                self.new_outgoing_chain_claim_tx(swap, claim_address)
+                                    .await?,
+                            ),
+                        }
+                    }
+                    Swap::Receive(swap) => {
+                        let Some(claim_address) = claim_address else {
+                            return Err(PaymentError::Generic {
+                                err: format!(
+                                "No claim address was supplied when claiming for Receive swap {}",
+                                swap.id
+                            ),
+                            });
+                        };
+                        Transaction::Liquid(self.new_receive_claim_tx(swap, claim_address).await?)
+                    }
+                    Swap::Send(swap) => {
+                        return Err(PaymentError::Generic {
+                            err: format!(
+                                "Failed to create claim tx for Send swap {}: invalid swap type",
+                                swap.id
+                            ),
+                        });
+                    }
                 };
-                match swap.direction {
-                    Direction::Incoming => Transaction::Liquid(
-                        self.new_incoming_chain_claim_tx(swap, claim_address)
-                            .await?,
-                    ),
-                    Direction::Outgoing => Transaction::Bitcoin(
-                        self.new_outgoing_chain_claim_tx(swap, claim_address)
-                            .await?,
-                    ),
-                }
-            }
-            Swap::Receive(swap) => {
-                let Some(claim_address) = claim_address else {
-                    return Err(PaymentError::Generic {
-                        err: format!(
-                            "No claim address was supplied when claiming for Receive swap {}",
-                            swap.id
-                        ),
-                    });
-                };
-                Transaction::Liquid(self.new_receive_claim_tx(swap, claim_address).await?)
-            }
-            Swap::Send(swap) => {
-                return Err(PaymentError::Generic {
-                    err: format!(
-                        "Failed to create claim tx for Send swap {}: invalid swap type",
-                        swap.id
-                    ),
-                });
-            }
-        };
 
-        Ok(tx)
+                Ok(tx)
+            })
+            .await
     }
 
     /// Estimate the refund broadcast transaction size and fees in sats for a send or chain swap
@@ -402,32 +500,40 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         fee_rate_sat_per_vb: Option<f64>,
         is_cooperative: bool,
     ) -> Result<(u32, u64), SdkError> {
-        let refund_address = &refund_address.to_string();
-        let refund_keypair = match &swap {
-            Swap::Chain(swap) => swap.get_refund_keypair()?,
-            Swap::Send(swap) => swap.get_refund_keypair()?,
-            Swap::Receive(swap) => {
-                return Err(SdkError::generic(format!(
-                    "Cannot create refund tx for Receive swap {}: invalid swap type",
-                    swap.id
-                )));
-            }
-        };
-
-        let refund_tx_size = match self.new_lbtc_refund_wrapper(&swap, refund_address).await {
-            Ok(refund_tx_wrapper) => {
-                refund_tx_wrapper.size(&refund_keypair, is_cooperative, true)?
-            }
-            Err(_) => {
-                let refund_tx_wrapper = self.new_btc_refund_wrapper(&swap, refund_address).await?;
-                refund_tx_wrapper.size(&refund_keypair, is_cooperative)?
-            }
-        } as u32;
-
-        let fee_rate_sat_per_vb = fee_rate_sat_per_vb.unwrap_or(LIQUID_FEE_RATE_SAT_PER_VBYTE);
-        let refund_tx_fees_sat = (refund_tx_size as f64 * fee_rate_sat_per_vb).ceil() as u64;
+        self.retry_policy
+            .call_once("estimate_refund_broadcast", async {
+                let refund_address = &refund_address.to_string();
+                let refund_keypair = match &swap {
+                    Swap::Chain(swap) => swap.get_refund_keypair()?,
+                    Swap::Send(swap) => swap.get_refund_keypair()?,
+                    Swap::Receive(swap) => {
+                        return Err(SdkError::generic(format!(
+                            "Cannot create refund tx for Receive swap {}: invalid swap type",
+                            swap.id
+                        )));
+                    }
+                };
 
-        Ok((refund_tx_size, refund_tx_fees_sat))
+                let refund_tx_size = match self.new_lbtc_refund_wrapper(&swap, refund_address).await
+                {
+                    Ok(refund_tx_wrapper) => {
+                        refund_tx_wrapper.size(&refund_keypair, is_cooperative, true)?
+                    }
+                    Err(_) => {
+                        let refund_tx_wrapper =
+                            self.new_btc_refund_wrapper(&swap, refund_address).await?;
+                        refund_tx_wrapper.size(&refund_keypair, is_cooperative)?
+                    }
+                } as u32;
+
+                let fee_rate_sat_per_vb =
+                    fee_rate_sat_per_vb.unwrap_or(LIQUID_FEE_RATE_SAT_PER_VBYTE);
+                let refund_tx_fees_sat =
+                    (refund_tx_size as f64 * fee_rate_sat_per_vb).ceil() as u64;
+
+                Ok((refund_tx_size, refund_tx_fees_sat))
+            })
+            .await
     }
 
     /// Create a refund transaction for a send or chain swap
@@ -439,54 +545,64 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         broadcast_fee_rate_sat_per_vb: Option<f64>,
         is_cooperative: bool,
     ) -> Result<Transaction, PaymentError> {
-        let swap_id = swap.id();
-        let refund_address = &refund_address.to_string();
-
-        let tx = match &swap {
-            Swap::Chain(chain_swap) => match chain_swap.direction {
-                Direction::Incoming => {
-                    let Some(broadcast_fee_rate_sat_per_vb) = broadcast_fee_rate_sat_per_vb else {
-                        return Err(PaymentError::generic(&format!("No broadcast fee rate provided when refunding incoming Chain Swap {swap_id}")));
-                    };
-
-                    Transaction::Bitcoin(
-                        self.new_btc_refund_tx(
-                            chain_swap,
-                            refund_address,
-                            utxos,
-                            broadcast_fee_rate_sat_per_vb,
-                            is_cooperative,
+        self.retry_policy
+            .call_once("create_refund_tx", async {
+            let swap_id = swap.id();
+            let refund_address = &refund_address.to_string();
+
+            let tx = match &swap {
+                Swap::Chain(chain_swap) => match chain_swap.direction {
+                    Direction::Incoming => {
+                        let Some(broadcast_fee_rate_sat_per_vb) = broadcast_fee_rate_sat_per_vb else {
+                            return Err(PaymentError::generic(&format!("No broadcast fee rate provided when refunding incoming Chain Swap {swap_id}")));
+                        };
+
+                        Transaction::Bitcoin(
+                            self.new_btc_refund_tx(
+                                chain_swap,
+                                refund_address,
+                                utxos,
+                                broadcast_fee_rate_sat_per_vb,
+                                is_cooperative,
+                            )
+                            .await?,
                         )
-                        .await?,
-                    )
-                }
-                Direction::Outgoing => Transaction::Liquid(
+                    }
+                    Direction::Outgoing => Transaction::Liquid(
+                        self.new_lbtc_refund_tx(&swap, refund_address, utxos, is_cooperative)
+                            .await?,
+                    ),
+                },
+                Swap::Send(_) => Transaction::Liquid(
                     self.new_lbtc_refund_tx(&swap, refund_address, utxos, is_cooperative)
                         .await?,
                 ),
-            },
-            Swap::Send(_) => Transaction::Liquid(
-                self.new_lbtc_refund_tx(&swap, refund_address, utxos, is_cooperative)
-                    .await?,
-            ),
-            Swap::Receive(_) => {
-                return Err(PaymentError::Generic {
-                    err: format!(
-                        "Failed to create refund tx for Receive swap {swap_id}: invalid swap type",
-                    ),
-                });
-            }
-        };
+                Swap::Receive(_) => {
+                    return Err(PaymentError::Generic {
+                        err: format!(
+                            "Failed to create refund tx for Receive swap {swap_id}: invalid swap type",
+                        ),
+                    });
+                }
+            };
 
-        Ok(tx)
+            Ok(tx)
+            })
+            .await
     }
 
     async fn broadcast_tx(&self, chain: Chain, tx_hex: &str) -> Result<String, PaymentError> {
         let response = self
-            .get_boltz_client()
-            .await?
-            .inner
-            .broadcast_tx(chain, &tx_hex.into())
+            .retry_policy
+            .retry("broadcast_tx", || async {
+                Ok::<_, PaymentError>(
+                    self.get_boltz_client()
+                        .await?
+                        .inner
+                        .broadcast_tx(chain, &tx_hex.into())
+                        .await?,
+                )
+            })
             .await?;
         let err = format!("Unexpected response from Boltz server: {response}");
         let tx_id = response
@@ -501,13 +617,17 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
     }
 
     async fn check_for_mrh(&self, invoice: &str) -> Result<Option<(String, Amount)>, PaymentError> {
-        boltz_client::swaps::magic_routing::check_for_mrh(
-            &self.get_boltz_client().await?.inner,
-            invoice,
-            self.config.network.into(),
-        )
-        .await
-        .map_err(Into::into)
+        self.retry_policy
+            .call_once("check_for_mrh", async {
+                boltz_client::swaps::magic_routing::check_for_mrh(
+                    &self.get_boltz_client().await?.inner,
+                    invoice,
+                    self.config.network.into(),
+                )
+                .await
+                .map_err(Into::into)
+            })
+            .await
     }
 
     async fn get_bolt12_invoice(
@@ -515,13 +635,34 @@ impl<P: ProxyUrlFetcher> Swapper for BoltzSwapper<P> {
         offer: &str,
         amount_sat: u64,
     ) -> Result<String, PaymentError> {
-        let invoice_res = self
-            .get_boltz_client()
-            .await?
-            .inner
-            .get_bolt12_invoice(offer, amount_sat)
-            .await?;
-        info!("Received BOLT12 invoice response: {invoice_res:?}");
-        Ok(invoice_res.invoice)
+        self.retry_policy
+            .call_once("get_bolt12_invoice", async {
+                let invoice_res = self
+                    .get_boltz_client()
+                    .await?
+                    .inner
+                    .get_bolt12_invoice(offer, amount_sat)
+                    .await?;
+                info!("Received BOLT12 invoice response: {invoice_res:?}");
+                Ok(invoice_res.invoice)
+            })
+            .await
+    }
+
+    async fn fetch_status(&self) -> Result<SwapServiceStatus, PaymentError> {
+        self.retry_policy
+            .call_once("fetch_status", async {
+                let client = &self.get_boltz_client().await?.inner;
+                let submarine_pairs = client.get_submarine_pairs().await?;
+                let reverse_pairs = client.get_reverse_pairs().await?;
+                let chain_pairs = client.get_chain_pairs().await?;
+                Ok(SwapServiceStatus {
+                    send_available: submarine_pairs.get_lbtc_to_btc_pair().is_some(),
+                    receive_available: reverse_pairs.get_btc_to_lbtc_pair().is_some(),
+                    chain_send_available: chain_pairs.get_lbtc_to_btc_pair().is_some(),
+                    chain_receive_available: chain_pairs.get_btc_to_lbtc_pair().is_some(),
+                })
+            })
+            .await
     }
 }
@@ -0,0 +1,302 @@
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use bip39::rand::{self, Rng as _};
+use log::warn;
+use tokio_with_wasm::alias as tokio;
+use web_time::Instant;
+
+use crate::error::{PaymentError, SdkError};
+
+/// The default number of attempts made for a swapper call. See [Config::swapper_max_attempts](crate::model::Config::swapper_max_attempts)
+pub const DEFAULT_SWAPPER_MAX_ATTEMPTS: u32 = 3;
+/// The delay before the first retry, doubled on each subsequent retry
+const BASE_RETRY_DELAY: Duration = Duration::from_millis(500);
+/// The upper bound of the delay between retries or reconnections
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+/// The number of consecutive failed calls after which the swapper is considered unavailable
+const CIRCUIT_BREAKER_THRESHOLD: u32 = 5;
+/// How long calls fail fast once the swapper is considered unavailable
+const CIRCUIT_BREAKER_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// An error returned by a swapper call
+pub(crate) trait SwapperCallError: From<SdkError> + std::fmt::Display {
+    /// Whether the error clearly means the swapper could not be reached or did not respond,
+    /// as opposed to e.g. a rejected request
+    fn is_service_failure(&self) -> bool;
+}
+
+impl SwapperCallError for SdkError {
+    fn is_service_failure(&self) -> bool {
+        matches!(self, SdkError::ServiceConnectivity { .. })
+    }
+}
+
+impl SwapperCallError for PaymentError {
+    fn is_service_failure(&self) -> bool {
+        matches!(self, PaymentError::ServiceUnavailable { .. })
+    }
+}
+
+#[derive(Default)]
+struct CircuitBreaker {
+    consecutive_failures: u32,
+    open_until: Option<Instant>,
+}
+
+/// Retries swapper calls failing with a service failure (see [SwapperCallError::is_service_failure])
+/// with a jittered exponential backoff. Other errors, e.g. a rejected request, are returned
+/// without retrying, as retrying would fail the same way. An attempt not completing within the
+/// timeout fails as a [SdkError::ServiceConnectivity] error.
+///
+/// After [CIRCUIT_BREAKER_THRESHOLD] consecutive calls failing with a service failure (see
+/// [SwapperCallError::is_service_failure]), further calls fail fast without reaching the swapper
+/// for [CIRCUIT_BREAKER_COOLDOWN]. Once it elapsed, calls are attempted again and the first
+/// successful one resets the breaker. Calls failing with other errors leave the breaker as is.
+pub(crate) struct RetryPolicy {
+    max_attempts: u32,
+    timeout: Duration,
+    breaker: Mutex<CircuitBreaker>,
+}
+
+impl RetryPolicy {
+    pub(crate) fn new(max_attempts: u32, timeout: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            timeout,
+            breaker: Mutex::new(CircuitBreaker::default()),
+        }
+    }
+
+    /// The delay before the given retry `attempt`, starting from zero. It grows exponentially
+    /// up to [MAX_RETRY_DELAY], with a random jitter of up to half of it.
+    pub(crate) fn backoff_delay(attempt: u32) -> Duration {
+        let delay = BASE_RETRY_DELAY
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_RETRY_DELAY);
+        let jitter_ms = rand::thread_rng().gen_range(0..=delay.as_millis() as u64 / 2);
+        delay - Duration::from_millis(jitter_ms)
+    }
+
+    /// Calls `call` until it succeeds, fails with an error that is not a service failure, or the
+    /// attempts are exhausted, returning the last error.
+    pub(crate) async fn retry<T, E, F, Fut>(&self, name: &str, call: F) -> Result<T, E>
+    where
+        E: SwapperCallError,
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        if self.is_open() {
+            return Err(SdkError::ServiceConnectivity {
+                err: format!("Swapper is unavailable, not attempting {name}"),
+            }
+            .into());
+        }
+
+        let mut attempt = 0;
+        loop {
+            let err = match tokio::time::timeout(self.timeout, call()).await {
+                Ok(Ok(res)) => {
+                    self.on_success();
+                    return Ok(res);
+                }
+                Ok(Err(err)) => err,
+                Err(_) => self.timeout_error(name).into(),
+            };
+
+            if !err.is_service_failure() {
+                return Err(err);
+            }
+            attempt += 1;
+            if attempt >= self.max_attempts {
+                self.on_failure();
+                return Err(err);
+            }
+            let delay = Self::backoff_delay(attempt - 1);
+            warn!(
+                "Swapper call {name} failed (attempt {attempt} of {}), retrying in {} ms: {err}",
+                self.max_attempts,
+                delay.as_millis()
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Makes a single attempt of a call which is not safe to retry, e.g. creating a swap. It
+    /// fails as a [SdkError::ServiceConnectivity] error if it doesn't complete within the timeout.
+    pub(crate) async fn call_once<T, E, Fut>(&self, name: &str, call: Fut) -> Result<T, E>
+    where
+        E: SwapperCallError,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        match tokio::time::timeout(self.timeout, call).await {
+            Ok(res) => res,
+            Err(_) => Err(self.timeout_error(name).into()),
+        }
+    }
+
+    fn timeout_error(&self, name: &str) -> SdkError {
+        SdkError::ServiceConnectivity {
+            err: format!(
+                "Swapper call {name} timed out after {} seconds",
+                self.timeout.as_secs()
+            ),
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        let breaker = self.breaker.lock().unwrap_or_else(|e| e.into_inner());
+        breaker
+            .open_until
+            .is_some_and(|open_until| Instant::now() < open_until)
+    }
+
+    fn on_success(&self) {
+        let mut breaker = self.breaker.lock().unwrap_or_else(|e| e.into_inner());
+        *breaker = CircuitBreaker::default();
+    }
+
+    fn on_failure(&self) {
+        let mut breaker = self.breaker.lock().unwrap_or_else(|e| e.into_inner());
+        breaker.consecutive_failures += 1;
+        if breaker.consecutive_failures >= CIRCUIT_BREAKER_THRESHOLD {
+            warn!(
+                "Swapper calls failed {} times in a row, pausing calls for {} seconds",
+                breaker.consecutive_failures,
+                CIRCUIT_BREAKER_COOLDOWN.as_secs()
+            );
+            breaker.open_until = Some(Instant::now() + CIRCUIT_BREAKER_COOLDOWN);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use anyhow::Result;
+
+    use super::*;
+
+    fn unavailable() -> SdkError {
+        SdkError::ServiceConnectivity {
+            err: "Unavailable".to_string(),
+        }
+    }
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::test_all]
+    fn test_backoff_delay() {
+        for attempt in 0..20 {
+            let delay = RetryPolicy::backoff_delay(attempt);
+            assert!(delay <= MAX_RETRY_DELAY);
+            assert!(delay >= BASE_RETRY_DELAY / 2);
+        }
+        assert!(RetryPolicy::backoff_delay(0) <= BASE_RETRY_DELAY);
+        assert!(RetryPolicy::backoff_delay(20) >= MAX_RETRY_DELAY / 2);
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_retry() -> Result<()> {
+        let policy = RetryPolicy::new(2, Duration::from_secs(10));
+        let calls = AtomicU32::new(0);
+
+        // A call failing once is retried
+        let res: Result<u32, SdkError> = policy
+            .retry("test", || async {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(unavailable()),
+                    n => Ok(n),
+                }
+            })
+            .await;
+        assert_eq!(res?, 1);
+
+        // A call failing on every attempt returns the last error
+        calls.store(0, Ordering::SeqCst);
+        let res: Result<(), SdkError> = policy
+            .retry("test", || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(unavailable())
+            })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // A call failing with an error that is not a service failure is not retried
+        calls.store(0, Ordering::SeqCst);
+        let res: Result<(), PaymentError> = policy
+            .retry("test", || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(PaymentError::InvalidOrExpiredFees)
+            })
+            .await;
+        assert!(matches!(res, Err(PaymentError::InvalidOrExpiredFees)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_circuit_breaker() -> Result<()> {
+        let policy = RetryPolicy::new(1, Duration::from_secs(10));
+
+        // Errors that are not service failures do not open the breaker
+        for _ in 0..CIRCUIT_BREAKER_THRESHOLD {
+            let res: Result<(), PaymentError> = policy
+                .retry("test", || async { Err(PaymentError::InvalidOrExpiredFees) })
+                .await;
+            assert!(matches!(res, Err(PaymentError::InvalidOrExpiredFees)));
+        }
+        assert!(!policy.is_open());
+
+        for _ in 0..CIRCUIT_BREAKER_THRESHOLD {
+            let res: Result<(), SdkError> =
+                policy.retry("test", || async { Err(unavailable()) }).await;
+            assert!(res.is_err());
+        }
+        assert!(policy.is_open());
+
+        // Once open, calls fail without being attempted
+        let calls = AtomicU32::new(0);
+        let res: Result<(), SdkError> = policy
+            .retry("test", || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .await;
+        assert!(res.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_retry_timeout() -> Result<()> {
+        let policy = RetryPolicy::new(1, Duration::from_millis(10));
+        let res: Result<(), SdkError> = policy
+            .retry("test", || async {
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                Ok(())
+            })
+            .await;
+        assert!(matches!(res, Err(SdkError::ServiceConnectivity { .. })));
+
+        // A call which isn't retried is bounded by the same timeout
+        let calls = AtomicU32::new(0);
+        let res: Result<(), SdkError> = RetryPolicy::new(3, Duration::from_millis(10))
+            .call_once("test", async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                Ok(())
+            })
+            .await;
+        assert!(matches!(res, Err(SdkError::ServiceConnectivity { .. })));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    }
+}
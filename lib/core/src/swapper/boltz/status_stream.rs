@@ -1,8 +1,9 @@
 use std::collections::HashSet;
-use std::time::Duration;
+use std::sync::atomic::Ordering;
 
 use crate::swapper::{
-    boltz::BoltzSwapper, ProxyUrlFetcher, SubscriptionHandler, SwapperStatusStream,
+    boltz::{retry::RetryPolicy, BoltzSwapper},
+    ProxyUrlFetcher, SubscriptionHandler, SwapperStatusStream,
 };
 use anyhow::Result;
 use boltz_client::boltz::{
@@ -41,13 +42,16 @@ impl<P: ProxyUrlFetcher> SwapperStatusStream for BoltzSwapper<P> {
         callback: Box<dyn SubscriptionHandler>,
         mut shutdown: watch::Receiver<()>,
     ) {
-        let keep_alive_ping_interval = Duration::from_secs(15);
-        let reconnect_delay = Duration::from_secs(2);
+        let keep_alive_ping_interval = self.config.swapper_ping_interval();
 
         let swapper = Arc::clone(&self);
         tokio::spawn(async move {
+            // Reconnections are delayed increasingly while the swapper can't be reached
+            let mut reconnect_attempt = 0;
             loop {
                 debug!("Start of ws stream loop");
+                let mut reconnect_delay = RetryPolicy::backoff_delay(reconnect_attempt);
+                reconnect_attempt = reconnect_attempt.saturating_add(1);
                 let client = match swapper.get_boltz_client().await {
                     Ok(client) => client,
                     Err(e) => {
@@ -58,6 +62,9 @@ impl<P: ProxyUrlFetcher> SwapperStatusStream for BoltzSwapper<P> {
                 };
                 match client.inner.connect_ws().await {
                     Ok(ws_stream) => {
+                        swapper.ws_connected.store(true, Ordering::Relaxed);
+                        reconnect_attempt = 0;
+                        reconnect_delay = RetryPolicy::backoff_delay(reconnect_attempt);
                         let (mut sender, mut receiver) = ws_stream.split();
 
                         let mut tracked_swap_ids: HashSet<String> = HashSet::new();
@@ -73,6 +80,7 @@ impl<P: ProxyUrlFetcher> SwapperStatusStream for BoltzSwapper<P> {
                             tokio::select! {
                                 _ = shutdown.changed() => {
                                     info!("Received shutdown signal, exiting Status Stream loop");
+                                    swapper.ws_connected.store(false, Ordering::Relaxed);
                                     return;
                                 },
 
@@ -143,6 +151,7 @@ impl<P: ProxyUrlFetcher> SwapperStatusStream for BoltzSwapper<P> {
                                 }
                             }
                         }
+                        swapper.ws_connected.store(false, Ordering::Relaxed);
                     }
                     Err(e) => {
                         warn!("Error connecting to stream: {e:?}");
@@ -161,4 +170,8 @@ impl<P: ProxyUrlFetcher> SwapperStatusStream for BoltzSwapper<P> {
     fn subscribe_swap_updates(&self) -> broadcast::Receiver<boltz::SwapStatus> {
         self.update_notifier.subscribe()
     }
+
+    fn is_connected(&self) -> bool {
+        self.ws_connected.load(Ordering::Relaxed)
+    }
 }
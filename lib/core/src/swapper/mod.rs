@@ -15,10 +15,11 @@ use tokio::sync::{broadcast, watch};
 
 use crate::{
     error::{PaymentError, SdkError},
-    prelude::{Direction, SendSwap, Swap, Utxo},
+    prelude::{Direction, SendSwap, Swap, SwapServiceStatus, Utxo},
 };
 pub(crate) use subscription_handler::*;
 pub(crate) mod boltz;
+pub(crate) mod simulation;
 pub(crate) mod subscription_handler;
 
 #[automock]
@@ -44,6 +45,9 @@ pub trait Swapper: MaybeSend + MaybeSync {
     async fn get_chain_pairs(&self)
         -> Result<(Option<ChainPair>, Option<ChainPair>), PaymentError>;
 
+    /// Drop the cached pairs, so that the next request fetches the current rate, limits and fees
+    async fn invalidate_pairs(&self);
+
     /// Get the quote for a Zero-Amount Receive Chain Swap.
     ///
     /// If the user locked-up funds in the valid range this will return that amount. In all other
@@ -129,6 +133,9 @@ pub trait Swapper: MaybeSend + MaybeSync {
         offer: &str,
         amount_sat: u64,
     ) -> Result<String, PaymentError>;
+
+    /// Fetch which swap types the swapper currently offers, bypassing the cached pairs
+    async fn fetch_status(&self) -> Result<SwapServiceStatus, PaymentError>;
 }
 
 pub trait SwapperStatusStream: MaybeSend + MaybeSync {
@@ -139,6 +146,8 @@ pub trait SwapperStatusStream: MaybeSend + MaybeSync {
     );
     fn track_swap_id(&self, swap_id: &str) -> Result<()>;
     fn subscribe_swap_updates(&self) -> broadcast::Receiver<boltz_client::boltz::SwapStatus>;
+    /// Whether the stream is currently connected to the swapper
+    fn is_connected(&self) -> bool;
 }
 
 #[sdk_macros::async_trait]
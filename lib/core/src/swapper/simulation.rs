@@ -0,0 +1,718 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use anyhow::Result;
+use bip39::rand::{self, Rng as _, RngCore as _};
+use boltz_client::{
+    boltz::{
+        self, ChainFees, ChainMinerFees, ChainPair, ChainSwapDetails, ChainSwapStates,
+        CreateChainRequest, CreateChainResponse, CreateReverseRequest, CreateReverseResponse,
+        CreateSubmarineRequest, CreateSubmarineResponse, Leaf, PairLimits, PairMinerFees,
+        RevSwapStates, ReverseFees, ReverseLimits, ReversePair, SubSwapStates,
+        SubmarineClaimTxResponse, SubmarineFees, SubmarinePair, SubmarinePairLimits, SwapTree,
+    },
+    network::Chain,
+    util::secrets::Preimage,
+    Amount,
+};
+use log::{debug, info, warn};
+use lwk_wollet::elements::{Address as ElementsAddress, AddressParams};
+use sdk_common::{
+    bitcoin::{
+        hashes::sha256,
+        secp256k1::{Secp256k1, SecretKey},
+    },
+    invoice::parse_invoice,
+    lightning::ln::PaymentSecret,
+    lightning_invoice::{Currency, InvoiceBuilder},
+    utils::Arc,
+};
+use tokio::sync::{broadcast, watch};
+use tokio_with_wasm::alias as tokio;
+use uuid::Uuid;
+
+use crate::{
+    bitcoin::{Address, CompressedPublicKey, Network, PublicKey},
+    error::{PaymentError, SdkError},
+    model::{
+        Direction, LiquidNetwork, SendSwap, SimulationConfig, Swap, SwapServiceStatus, Transaction,
+        Utxo,
+    },
+    swapper::{SubscriptionHandler, Swapper, SwapperStatusStream},
+    utils,
+};
+
+/// The timeout of the simulated swaps, high enough for them to never expire
+const SIMULATED_TIMEOUT_BLOCK_HEIGHT: u32 = 100_000_000;
+
+#[derive(Clone, Copy, Debug)]
+enum SimulatedSwapType {
+    Send,
+    Receive,
+    Chain(Direction),
+}
+
+struct SimulatedSwap {
+    swap_type: SimulatedSwapType,
+    /// The invoice of a receive swap and the address of its Magic Routing Hint
+    invoice_mrh: Option<(String, String)>,
+    /// The preimage revealed when a send swap is claimed
+    preimage: Option<String>,
+}
+
+/// A swapper simulating the lifecycle of the swaps locally, enabled with
+/// [Config::simulation](crate::model::Config::simulation).
+///
+/// It serves fixed pairs, creates swaps with valid invoices and addresses, and emits their
+/// status updates with the configured delay, failing them at the configured rate. As no funds
+/// are locked up by the simulated swapper, the SDK doesn't build the lockup txs of the swaps nor
+/// claim or refund them. The preimage of a send swap is served by the simulation, so it doesn't
+/// match the payment hash of the paid invoice.
+pub(crate) struct SimulatedSwapper {
+    config: SimulationConfig,
+    network: LiquidNetwork,
+    /// See [Config::outgoing_min_confirmations](crate::model::Config::outgoing_min_confirmations)
+    outgoing_min_confirmations: u32,
+    swaps: Mutex<HashMap<String, SimulatedSwap>>,
+    update_notifier: broadcast::Sender<boltz::SwapStatus>,
+}
+
+impl SimulatedSwapper {
+    pub(crate) fn new(
+        config: SimulationConfig,
+        network: LiquidNetwork,
+        outgoing_min_confirmations: u32,
+    ) -> Self {
+        let (update_notifier, _) = broadcast::channel::<boltz::SwapStatus>(30);
+        Self {
+            config,
+            network,
+            outgoing_min_confirmations,
+            swaps: Mutex::new(HashMap::new()),
+            update_notifier,
+        }
+    }
+
+    fn insert_swap(
+        &self,
+        swap_type: SimulatedSwapType,
+        invoice_mrh: Option<(String, String)>,
+    ) -> String {
+        let swap_id = Uuid::new_v4().to_string();
+        info!("Simulating {swap_type:?} swap {swap_id}");
+        let preimage = match swap_type {
+            SimulatedSwapType::Send => Preimage::new().to_string(),
+            _ => None,
+        };
+        self.swaps.lock().unwrap().insert(
+            swap_id.clone(),
+            SimulatedSwap {
+                swap_type,
+                invoice_mrh,
+                preimage,
+            },
+        );
+        swap_id
+    }
+
+    /// The statuses emitted for a swap, ending with a failure status if it is to fail, each with
+    /// the number of status update delays before it.
+    ///
+    /// The claim tx of an outgoing chain swap is simulated to confirm one delay per block, so the
+    /// swap is only claimed once it has the outgoing confirmations.
+    fn status_sequence(&self, swap_type: SimulatedSwapType) -> Vec<(u32, String)> {
+        let fails = rand::thread_rng().gen_bool(self.config.failure_rate);
+        let statuses = match (swap_type, fails) {
+            (SimulatedSwapType::Send, false) => vec![
+                SubSwapStates::InvoiceSet.to_string(),
+                SubSwapStates::TransactionMempool.to_string(),
+                SubSwapStates::TransactionClaimPending.to_string(),
+                SubSwapStates::TransactionClaimed.to_string(),
+            ],
+            (SimulatedSwapType::Send, true) => vec![
+                SubSwapStates::InvoiceSet.to_string(),
+                SubSwapStates::InvoiceFailedToPay.to_string(),
+            ],
+            (SimulatedSwapType::Receive, false) => vec![
+                RevSwapStates::TransactionMempool.to_string(),
+                RevSwapStates::TransactionConfirmed.to_string(),
+                RevSwapStates::InvoiceSettled.to_string(),
+            ],
+            (SimulatedSwapType::Receive, true) => vec![RevSwapStates::InvoiceExpired.to_string()],
+            (SimulatedSwapType::Chain(_), false) => vec![
+                ChainSwapStates::TransactionMempool.to_string(),
+                ChainSwapStates::TransactionConfirmed.to_string(),
+                ChainSwapStates::TransactionServerMempool.to_string(),
+                ChainSwapStates::TransactionServerConfirmed.to_string(),
+            ],
+            (SimulatedSwapType::Chain(_), true) => vec![
+                ChainSwapStates::TransactionMempool.to_string(),
+                ChainSwapStates::TransactionLockupFailed.to_string(),
+            ],
+        };
+        let mut statuses: Vec<(u32, String)> =
+            statuses.into_iter().map(|status| (1, status)).collect();
+        if let (SimulatedSwapType::Chain(Direction::Outgoing), false) = (swap_type, fails) {
+            statuses.push((
+                self.outgoing_min_confirmations,
+                ChainSwapStates::TransactionClaimed.to_string(),
+            ));
+        }
+        statuses
+    }
+
+    fn new_public_key() -> boltz_client::PublicKey {
+        utils::generate_keypair().public_key().into()
+    }
+
+    fn new_bitcoin_address(&self) -> String {
+        let public_key = utils::generate_keypair().public_key();
+        let network = match self.network {
+            LiquidNetwork::Mainnet => Network::Bitcoin,
+            LiquidNetwork::Testnet => Network::Testnet,
+            LiquidNetwork::Regtest => Network::Regtest,
+        };
+        Address::p2wpkh(&CompressedPublicKey(public_key), network).to_string()
+    }
+
+    fn new_liquid_address(&self) -> String {
+        let public_key = PublicKey::new(utils::generate_keypair().public_key());
+        let params = match self.network {
+            LiquidNetwork::Mainnet => &AddressParams::LIQUID,
+            LiquidNetwork::Testnet => &AddressParams::LIQUID_TESTNET,
+            LiquidNetwork::Regtest => &AddressParams::ELEMENTS,
+        };
+        ElementsAddress::p2wpkh(&public_key, None, params).to_string()
+    }
+
+    fn new_swap_tree() -> SwapTree {
+        SwapTree {
+            claim_leaf: Leaf {
+                output: "".to_string(),
+                version: 196,
+            },
+            refund_leaf: Leaf {
+                output: "".to_string(),
+                version: 196,
+            },
+        }
+    }
+
+    fn new_chain_swap_details(lockup_address: String, amount: u64) -> ChainSwapDetails {
+        ChainSwapDetails {
+            swap_tree: Self::new_swap_tree(),
+            lockup_address,
+            server_public_key: Self::new_public_key(),
+            timeout_block_height: SIMULATED_TIMEOUT_BLOCK_HEIGHT,
+            amount,
+            blinding_key: None,
+            refund_address: None,
+            claim_address: None,
+            bip21: None,
+        }
+    }
+
+    fn new_invoice(&self, req: &CreateReverseRequest) -> Result<String, PaymentError> {
+        let currency = match self.network {
+            LiquidNetwork::Mainnet => Currency::Bitcoin,
+            LiquidNetwork::Testnet => Currency::BitcoinTestnet,
+            LiquidNetwork::Regtest => Currency::Regtest,
+        };
+        let payment_hash = sha256::Hash::from_str(&req.preimage_hash.to_string())
+            .map_err(|e| PaymentError::generic(&format!("Invalid preimage hash: {e}")))?;
+        let mut payment_secret = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut payment_secret);
+
+        let builder = InvoiceBuilder::new(currency)
+            .amount_milli_satoshis(req.invoice_amount * 1000)
+            .payment_hash(payment_hash)
+            .payment_secret(PaymentSecret(payment_secret))
+            .duration_since_epoch(Duration::from_secs(utils::now().into()))
+            .min_final_cltv_expiry_delta(144);
+        let builder = match req.invoice_expiry {
+            Some(expiry_secs) => builder.expiry_time(Duration::from_secs(expiry_secs.into())),
+            None => builder,
+        };
+        let builder = match &req.description_hash {
+            Some(description_hash) => {
+                builder.description_hash(sha256::Hash::from_str(description_hash).map_err(|e| {
+                    PaymentError::generic(&format!("Invalid description hash: {e}"))
+                })?)
+            }
+            None => builder.description(req.description.clone().unwrap_or_default()),
+        };
+        let mut node_secret = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut node_secret);
+        let node_key = SecretKey::from_slice(&node_secret)
+            .map_err(|e| PaymentError::generic(&format!("Invalid node key: {e}")))?;
+        let invoice = builder
+            .build_signed(|hash| Secp256k1::new().sign_ecdsa_recoverable(hash, &node_key))
+            .map_err(|e| PaymentError::generic(&format!("Failed to create invoice: {e}")))?;
+        Ok(invoice.to_string())
+    }
+
+    fn new_chain_pair() -> ChainPair {
+        ChainPair {
+            hash: Uuid::new_v4().simple().to_string(),
+            rate: 1.0,
+            limits: PairLimits {
+                maximal: 25_000_000,
+                minimal: 25_000,
+                maximal_zero_conf: 0,
+            },
+            fees: ChainFees {
+                percentage: 0.1,
+                miner_fees: ChainMinerFees {
+                    server: 1_000,
+                    user: PairMinerFees {
+                        lockup: 500,
+                        claim: 300,
+                    },
+                },
+            },
+        }
+    }
+
+    fn unsupported(operation: &str) -> PaymentError {
+        PaymentError::generic(&format!("{operation} is not supported in simulation mode"))
+    }
+}
+
+#[sdk_macros::async_trait]
+impl Swapper for SimulatedSwapper {
+    async fn create_chain_swap(
+        &self,
+        req: CreateChainRequest,
+    ) -> Result<CreateChainResponse, PaymentError> {
+        let pair = Self::new_chain_pair();
+        let user_lock_amount = req.user_lock_amount.unwrap_or_else(|| {
+            let server_lock_amount = req.server_lock_amount.unwrap_or_default();
+            server_lock_amount + pair.fees.boltz(server_lock_amount) + pair.fees.server()
+        });
+        let server_lock_amount = req.server_lock_amount.unwrap_or_else(|| {
+            req.user_lock_amount
+                .map(|amount| amount.saturating_sub(pair.fees.boltz(amount) + pair.fees.server()))
+                .unwrap_or_default()
+        });
+        let (lockup_address, claim_address, direction) = match req.from.as_str() {
+            "BTC" => (
+                self.new_bitcoin_address(),
+                self.new_liquid_address(),
+                Direction::Incoming,
+            ),
+            _ => (
+                self.new_liquid_address(),
+                self.new_bitcoin_address(),
+                Direction::Outgoing,
+            ),
+        };
+        Ok(CreateChainResponse {
+            id: self.insert_swap(SimulatedSwapType::Chain(direction), None),
+            claim_details: Self::new_chain_swap_details(claim_address, server_lock_amount),
+            lockup_details: Self::new_chain_swap_details(lockup_address, user_lock_amount),
+        })
+    }
+
+    async fn create_send_swap(
+        &self,
+        req: CreateSubmarineRequest,
+    ) -> Result<CreateSubmarineResponse, PaymentError> {
+        let invoice = parse_invoice(&req.invoice)
+            .map_err(|err| PaymentError::invalid_invoice(&err.to_string()))?;
+        let amount_sat = invoice.amount_msat.ok_or(PaymentError::invalid_invoice(
+            "Invoice does not contain an amount",
+        ))? / 1000;
+        let pair = self
+            .get_submarine_pairs()
+            .await?
+            .ok_or(PaymentError::PairsNotFound)?;
+        Ok(CreateSubmarineResponse {
+            accept_zero_conf: false,
+            address: self.new_liquid_address(),
+            bip21: "".to_string(),
+            claim_public_key: Self::new_public_key(),
+            expected_amount: amount_sat + pair.fees.total(amount_sat),
+            id: self.insert_swap(SimulatedSwapType::Send, None),
+            referral_id: None,
+            swap_tree: Self::new_swap_tree(),
+            timeout_block_height: SIMULATED_TIMEOUT_BLOCK_HEIGHT as u64,
+            blinding_key: None,
+        })
+    }
+
+    async fn get_chain_pair(
+        &self,
+        _direction: Direction,
+    ) -> Result<Option<ChainPair>, PaymentError> {
+        Ok(Some(Self::new_chain_pair()))
+    }
+
+    async fn get_chain_pairs(
+        &self,
+    ) -> Result<(Option<ChainPair>, Option<ChainPair>), PaymentError> {
+        Ok((Some(Self::new_chain_pair()), Some(Self::new_chain_pair())))
+    }
+
+    async fn get_zero_amount_chain_swap_quote(&self, swap_id: &str) -> Result<Amount, SdkError> {
+        Err(SdkError::generic(format!(
+            "No lockup found for simulated swap {swap_id}"
+        )))
+    }
+
+    async fn accept_zero_amount_chain_swap_quote(
+        &self,
+        _swap_id: &str,
+        _server_lockup_sat: u64,
+    ) -> Result<(), PaymentError> {
+        Err(Self::unsupported("Accepting a zero-amount swap quote"))
+    }
+
+    async fn get_submarine_pairs(&self) -> Result<Option<SubmarinePair>, PaymentError> {
+        Ok(Some(SubmarinePair {
+            hash: Uuid::new_v4().simple().to_string(),
+            rate: 1.0,
+            limits: SubmarinePairLimits {
+                maximal: 25_000_000,
+                minimal: 1_000,
+                maximal_zero_conf: 0,
+                minimal_batched: None,
+            },
+            fees: SubmarineFees {
+                percentage: 0.1,
+                miner_fees: 20,
+            },
+        }))
+    }
+
+    async fn get_submarine_preimage(&self, swap_id: &str) -> Result<String, PaymentError> {
+        self.swaps
+            .lock()
+            .unwrap()
+            .get(swap_id)
+            .and_then(|swap| swap.preimage.clone())
+            .ok_or(PaymentError::generic(&format!(
+                "No preimage found for simulated swap {swap_id}"
+            )))
+    }
+
+    async fn get_send_claim_tx_details(
+        &self,
+        _swap: &SendSwap,
+    ) -> Result<SubmarineClaimTxResponse, PaymentError> {
+        Err(Self::unsupported("Fetching the claim tx details"))
+    }
+
+    async fn claim_send_swap_cooperative(
+        &self,
+        _swap: &SendSwap,
+        _claim_tx_response: SubmarineClaimTxResponse,
+        _refund_address: &str,
+    ) -> Result<(), PaymentError> {
+        Err(Self::unsupported("Claiming cooperatively"))
+    }
+
+    async fn create_receive_swap(
+        &self,
+        req: CreateReverseRequest,
+    ) -> Result<CreateReverseResponse, PaymentError> {
+        let pair = self
+            .get_reverse_swap_pairs()
+            .await?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let invoice = self.new_invoice(&req)?;
+        let onchain_amount = req.invoice_amount - pair.fees.total(req.invoice_amount);
+        let invoice_mrh = req
+            .address
+            .clone()
+            .map(|address| (invoice.clone(), address));
+        Ok(CreateReverseResponse {
+            id: self.insert_swap(SimulatedSwapType::Receive, invoice_mrh),
+            invoice,
+            swap_tree: Self::new_swap_tree(),
+            lockup_address: self.new_liquid_address(),
+            refund_public_key: Self::new_public_key(),
+            timeout_block_height: SIMULATED_TIMEOUT_BLOCK_HEIGHT,
+            onchain_amount,
+            blinding_key: None,
+        })
+    }
+
+    async fn get_reverse_swap_pairs(&self) -> Result<Option<ReversePair>, PaymentError> {
+        Ok(Some(ReversePair {
+            hash: Uuid::new_v4().simple().to_string(),
+            rate: 1.0,
+            limits: ReverseLimits {
+                maximal: 25_000_000,
+                minimal: 1_000,
+            },
+            fees: ReverseFees {
+                percentage: 0.25,
+                miner_fees: PairMinerFees {
+                    lockup: 27,
+                    claim: 20,
+                },
+            },
+        }))
+    }
+
+    async fn create_claim_tx(
+        &self,
+        _swap: Swap,
+        _claim_address: Option<String>,
+    ) -> Result<Transaction, PaymentError> {
+        Err(Self::unsupported("Claiming"))
+    }
+
+    async fn estimate_refund_broadcast(
+        &self,
+        _swap: Swap,
+        _refund_address: &str,
+        _fee_rate_sat_per_vb: Option<f64>,
+        _is_cooperative: bool,
+    ) -> Result<(u32, u64), SdkError> {
+        Err(SdkError::generic(
+            "Refunding is not supported in simulation mode",
+        ))
+    }
+
+    async fn create_refund_tx(
+        &self,
+        _swap: Swap,
+        _refund_address: &str,
+        _utxos: Vec<Utxo>,
+        _broadcast_fee_rate_sat_per_vb: Option<f64>,
+        _is_cooperative: bool,
+    ) -> Result<Transaction, PaymentError> {
+        Err(Self::unsupported("Refunding"))
+    }
+
+    async fn broadcast_tx(&self, _chain: Chain, _tx_hex: &str) -> Result<String, PaymentError> {
+        Err(Self::unsupported("Broadcasting through the swapper"))
+    }
+
+    async fn check_for_mrh(
+        &self,
+        invoice: &str,
+    ) -> Result<Option<(String, boltz_client::bitcoin::Amount)>, PaymentError> {
+        let amount_msat = parse_invoice(invoice)
+            .map_err(|err| PaymentError::invalid_invoice(&err.to_string()))?
+            .amount_msat
+            .unwrap_or_default();
+        Ok(self
+            .swaps
+            .lock()
+            .unwrap()
+            .values()
+            .filter_map(|swap| swap.invoice_mrh.as_ref())
+            .find(|(swap_invoice, _)| swap_invoice == invoice)
+            .map(|(_, address)| {
+                (
+                    address.clone(),
+                    boltz_client::bitcoin::Amount::from_sat(amount_msat / 1000),
+                )
+            }))
+    }
+
+    async fn get_bolt12_invoice(
+        &self,
+        _offer: &str,
+        _amount_sat: u64,
+    ) -> Result<String, PaymentError> {
+        Err(Self::unsupported("Paying a BOLT12 offer"))
+    }
+
+    async fn invalidate_pairs(&self) {}
+
+    async fn fetch_status(&self) -> Result<SwapServiceStatus, PaymentError> {
+        Ok(SwapServiceStatus {
+            send_available: true,
+            receive_available: true,
+            chain_send_available: true,
+            chain_receive_available: true,
+        })
+    }
+}
+
+impl SwapperStatusStream for SimulatedSwapper {
+    fn start(
+        self: Arc<Self>,
+        callback: Box<dyn SubscriptionHandler>,
+        _shutdown: watch::Receiver<()>,
+    ) {
+        tokio::spawn(async move {
+            callback.subscribe_swaps().await;
+        });
+    }
+
+    fn track_swap_id(&self, swap_id: &str) -> Result<()> {
+        let Some(swap_type) = self
+            .swaps
+            .lock()
+            .unwrap()
+            .get(swap_id)
+            .map(|swap| swap.swap_type)
+        else {
+            // Swaps created before a restart are not known to the simulation anymore
+            debug!("Not tracking unknown simulated swap {swap_id}");
+            return Ok(());
+        };
+
+        let statuses = self.status_sequence(swap_type);
+        let delay = Duration::from_secs(self.config.status_update_delay_sec.into());
+        let update_notifier = self.update_notifier.clone();
+        let swap_id = swap_id.to_string();
+        tokio::spawn(async move {
+            for (delays, status) in statuses {
+                tokio::time::sleep(delay * delays).await;
+                info!("Simulating status {status} for swap {swap_id}");
+                let update = boltz::SwapStatus {
+                    id: swap_id.clone(),
+                    status,
+                    ..Default::default()
+                };
+                if let Err(e) = update_notifier.send(update) {
+                    warn!("Failed to send simulated status update for swap {swap_id}: {e:?}");
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn subscribe_swap_updates(&self) -> broadcast::Receiver<boltz::SwapStatus> {
+        self.update_notifier.subscribe()
+    }
+
+    fn is_connected(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use boltz_client::{
+        boltz::{CreateReverseRequest, CreateSubmarineRequest, RevSwapStates, SubSwapStates},
+        util::secrets::Preimage,
+    };
+    use sdk_common::{lightning_invoice::Bolt11Invoice, utils::Arc};
+
+    use super::SimulatedSwapper;
+    use crate::{
+        model::{LiquidNetwork, SimulationConfig},
+        swapper::{Swapper, SwapperStatusStream},
+        utils,
+    };
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    #[sdk_macros::async_test_all]
+    async fn test_simulated_receive_swap() -> Result<()> {
+        let swapper = Arc::new(SimulatedSwapper::new(
+            SimulationConfig {
+                status_update_delay_sec: 0,
+                failure_rate: 1.0,
+            },
+            LiquidNetwork::Testnet,
+            1,
+        ));
+        let mut updates = swapper.subscribe_swap_updates();
+
+        let preimage = Preimage::new();
+        let mrh_address = "mrh_address".to_string();
+        let create_response = swapper
+            .create_receive_swap(CreateReverseRequest {
+                invoice_amount: 10_000,
+                from: "BTC".to_string(),
+                to: "L-BTC".to_string(),
+                preimage_hash: preimage.sha256,
+                claim_public_key: utils::generate_keypair().public_key().into(),
+                description: Some("Simulated".to_string()),
+                description_hash: None,
+                address: Some(mrh_address.clone()),
+                address_signature: None,
+                referral_id: None,
+                webhook: None,
+                invoice_expiry: None,
+            })
+            .await?;
+
+        let invoice = Bolt11Invoice::from_str(&create_response.invoice)?;
+        assert_eq!(invoice.amount_milli_satoshis(), Some(10_000_000));
+        assert_eq!(
+            invoice.payment_hash().to_string(),
+            preimage.sha256.to_string()
+        );
+        let (address, _) = swapper
+            .check_for_mrh(&create_response.invoice)
+            .await?
+            .expect("Expected MRH");
+        assert_eq!(address, mrh_address);
+
+        // A swap that always fails emits a single failure status
+        swapper.track_swap_id(&create_response.id)?;
+        let update = updates.recv().await?;
+        assert_eq!(update.id, create_response.id);
+        assert_eq!(update.status, RevSwapStates::InvoiceExpired.to_string());
+        Ok(())
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_simulated_send_swap() -> Result<()> {
+        let swapper = Arc::new(SimulatedSwapper::new(
+            SimulationConfig {
+                status_update_delay_sec: 0,
+                failure_rate: 0.0,
+            },
+            LiquidNetwork::Testnet,
+            1,
+        ));
+        let mut updates = swapper.subscribe_swap_updates();
+
+        // A valid invoice, as created for a simulated receive swap
+        let invoice = swapper
+            .create_receive_swap(CreateReverseRequest {
+                invoice_amount: 10_000,
+                from: "BTC".to_string(),
+                to: "L-BTC".to_string(),
+                preimage_hash: Preimage::new().sha256,
+                claim_public_key: utils::generate_keypair().public_key().into(),
+                description: None,
+                description_hash: None,
+                address: None,
+                address_signature: None,
+                referral_id: None,
+                webhook: None,
+                invoice_expiry: None,
+            })
+            .await?
+            .invoice;
+        let create_response = swapper
+            .create_send_swap(CreateSubmarineRequest {
+                from: "L-BTC".to_string(),
+                to: "BTC".to_string(),
+                invoice,
+                refund_public_key: utils::generate_keypair().public_key().into(),
+                pair_hash: None,
+                referral_id: None,
+                webhook: None,
+            })
+            .await?;
+
+        // The preimage of the swap is served once it's claimed
+        swapper.track_swap_id(&create_response.id)?;
+        let mut statuses = vec![];
+        while statuses.last() != Some(&SubSwapStates::TransactionClaimed.to_string()) {
+            statuses.push(updates.recv().await?.status);
+        }
+        assert!(statuses.contains(&SubSwapStates::TransactionClaimPending.to_string()));
+        let preimage = swapper.get_submarine_preimage(&create_response.id).await?;
+        assert_eq!(preimage.len(), 64);
+        assert!(swapper.get_submarine_preimage("unknown").await.is_err());
+        Ok(())
+    }
+}
@@ -121,6 +121,7 @@ impl From<ChainSyncData> for ChainSwap {
             claim_tx_id: None,
             refund_tx_id: None,
             auto_accepted_fees: val.auto_accepted_fees,
+            refundable_since: None,
             metadata: Default::default(),
         }
     }
@@ -234,6 +235,8 @@ pub(crate) struct ReceiveSyncData {
     pub(crate) payment_hash: Option<String>,
     pub(crate) description: Option<String>,
     pub(crate) destination_pubkey: Option<String>,
+    #[serde(default)]
+    pub(crate) external_claim_address: Option<String>,
 }
 
 impl ReceiveSyncData {
@@ -269,6 +272,7 @@ impl From<ReceiveSwap> for ReceiveSyncData {
             created_at: value.created_at,
             description: value.description,
             destination_pubkey: value.destination_pubkey,
+            external_claim_address: value.external_claim_address,
         }
     }
 }
@@ -293,6 +297,7 @@ impl From<ReceiveSyncData> for ReceiveSwap {
             created_at: val.created_at,
             state: PaymentState::Created,
             claim_address: None,
+            external_claim_address: val.external_claim_address,
             claim_tx_id: None,
             lockup_tx_id: None,
             mrh_tx_id: None,
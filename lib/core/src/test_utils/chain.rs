@@ -2,7 +2,7 @@ use std::sync::Mutex;
 
 use crate::{
     bitcoin, elements,
-    model::{BtcHistory, BtcScriptBalance, LBtcHistory},
+    model::{BlockInfo, BtcHistory, BtcScriptBalance, LBtcHistory},
 };
 use anyhow::Result;
 use bitcoin::{consensus::deserialize, OutPoint, Script, TxOut};
@@ -20,6 +20,9 @@ use crate::{
 #[derive(Default)]
 pub(crate) struct MockLiquidChainService {
     history: Mutex<Vec<LBtcHistory>>,
+    fail_broadcast: Mutex<bool>,
+    unreachable: Mutex<bool>,
+    tip_block: Mutex<BlockInfo>,
 }
 
 impl MockLiquidChainService {
@@ -35,15 +38,41 @@ impl MockLiquidChainService {
     pub(crate) fn get_history(&self) -> Vec<LBtcHistory> {
         self.history.lock().unwrap().clone()
     }
+
+    pub(crate) fn set_fail_broadcast(&self, fail_broadcast: bool) -> &Self {
+        *self.fail_broadcast.lock().unwrap() = fail_broadcast;
+        self
+    }
+
+    /// Makes the chain service fail as if it couldn't be reached
+    pub(crate) fn set_unreachable(&self, unreachable: bool) -> &Self {
+        *self.unreachable.lock().unwrap() = unreachable;
+        self
+    }
+
+    pub(crate) fn set_tip_block(&self, tip_block: BlockInfo) -> &Self {
+        *self.tip_block.lock().unwrap() = tip_block;
+        self
+    }
 }
 
 #[sdk_macros::async_trait]
 impl LiquidChainService for MockLiquidChainService {
     async fn tip(&self) -> Result<u32> {
-        Ok(0)
+        if *self.unreachable.lock().unwrap() {
+            return Err(anyhow::anyhow!("Chain service unreachable"));
+        }
+        Ok(self.tip_block.lock().unwrap().height)
+    }
+
+    async fn tip_block(&self) -> Result<BlockInfo> {
+        Ok(self.tip_block.lock().unwrap().clone())
     }
 
     async fn broadcast(&self, tx: &elements::Transaction) -> Result<elements::Txid> {
+        if *self.fail_broadcast.lock().unwrap() {
+            return Err(anyhow::anyhow!("Broadcast failed"));
+        }
         Ok(tx.txid())
     }
 
@@ -51,7 +80,11 @@ impl LiquidChainService for MockLiquidChainService {
         &self,
         _txid: &elements::Txid,
     ) -> Result<Option<elements::Transaction>> {
-        unimplemented!()
+        if *self.unreachable.lock().unwrap() {
+            return Err(anyhow::anyhow!("Chain service unreachable"));
+        }
+        // Like the Electrum and Esplora clients, fail to look up an unknown tx
+        Err(anyhow::anyhow!("Transaction not found"))
     }
 
     async fn get_transactions(
@@ -102,6 +135,7 @@ pub(crate) struct MockBitcoinChainService {
     history: Mutex<Vec<BtcHistory>>,
     txs: Mutex<Vec<bitcoin::Transaction>>,
     script_balance_sat: Mutex<u64>,
+    tip_block: Mutex<BlockInfo>,
 }
 
 impl MockBitcoinChainService {
@@ -110,6 +144,7 @@ impl MockBitcoinChainService {
             history: Mutex::new(vec![]),
             txs: Mutex::new(vec![]),
             script_balance_sat: Mutex::new(0),
+            tip_block: Mutex::new(BlockInfo::default()),
         }
     }
 
@@ -130,12 +165,21 @@ impl MockBitcoinChainService {
         *self.script_balance_sat.lock().unwrap() = script_balance_sat;
         self
     }
+
+    pub(crate) fn set_tip_block(&self, tip_block: BlockInfo) -> &Self {
+        *self.tip_block.lock().unwrap() = tip_block;
+        self
+    }
 }
 
 #[sdk_macros::async_trait]
 impl BitcoinChainService for MockBitcoinChainService {
     async fn tip(&self) -> Result<u32> {
-        Ok(0)
+        Ok(self.tip_block.lock().unwrap().height)
+    }
+
+    async fn tip_block(&self) -> Result<BlockInfo> {
+        Ok(self.tip_block.lock().unwrap().clone())
     }
 
     async fn broadcast(&self, tx: &bitcoin::Transaction) -> Result<bitcoin::Txid, anyhow::Error> {
@@ -228,6 +272,12 @@ impl BitcoinChainService for MockBitcoinChainService {
     }
 
     async fn recommended_fees(&self) -> Result<RecommendedFees> {
-        unimplemented!()
+        Ok(RecommendedFees {
+            fastest_fee: 20,
+            half_hour_fee: 10,
+            hour_fee: 5,
+            economy_fee: 2,
+            minimum_fee: 1,
+        })
     }
 }
@@ -6,6 +6,7 @@ use sdk_common::utils::Arc;
 
 use crate::{
     chain_swap::ChainSwapHandler,
+    in_flight::InFlightOperations,
     model::{ChainSwap, Config, Direction, PaymentState, Signer},
     persist::Persister,
     swapper::boltz::BoltzSwapper,
@@ -27,7 +28,13 @@ lazy_static! {
 }
 
 pub(crate) fn new_chain_swap_handler(persister: Arc<Persister>) -> Result<ChainSwapHandler> {
-    let config = Config::testnet_esplora(None);
+    new_chain_swap_handler_with_config(persister, Config::testnet_esplora(None))
+}
+
+pub(crate) fn new_chain_swap_handler_with_config(
+    persister: Arc<Persister>,
+    config: Config,
+) -> Result<ChainSwapHandler> {
     let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
     let onchain_wallet = Arc::new(MockWallet::new(signer)?);
     let swapper = Arc::new(BoltzSwapper::new(
@@ -44,6 +51,7 @@ pub(crate) fn new_chain_swap_handler(persister: Arc<Persister>) -> Result<ChainS
         swapper,
         liquid_chain_service,
         bitcoin_chain_service,
+        Arc::new(InFlightOperations::default()),
     )
 }
 
@@ -147,6 +155,7 @@ pub(crate) fn new_chain_swap(
             }"#
             .to_string(),
             auto_accepted_fees: false,
+            refundable_since: None,
             metadata: Default::default(),
         };
     }
@@ -233,6 +242,7 @@ pub(crate) fn new_chain_swap(
               }
             }"#.to_string(),
             auto_accepted_fees: false,
+            refundable_since: None,
             metadata: Default::default(),
         },
         Direction::Outgoing => ChainSwap {
@@ -317,6 +327,7 @@ pub(crate) fn new_chain_swap(
               }
             }"#.to_string(),
             auto_accepted_fees: false,
+            refundable_since: None,
             metadata: Default::default(),
         }
     }
@@ -7,6 +7,8 @@ pub(crate) mod chain_swap;
 pub mod persist;
 pub(crate) mod receive_swap;
 pub(crate) mod recover;
+#[cfg(feature = "test-utils")]
+pub mod regtest;
 pub(crate) mod sdk;
 pub(crate) mod send_swap;
 pub(crate) mod status_stream;
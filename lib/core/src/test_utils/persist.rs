@@ -113,6 +113,7 @@ pub fn new_receive_swap(
         pair_fees_json: r#"{"hash":"b32246ad7d9c9b1ff499a36e226b5e2fb5b83d78cbc8a70b6d3429b80bfc5876","rate":1.0,"limits":{"maximal":25000000,"minimal":1000},"fees":{"percentage":0.25,"minerFees":{"lockup":26,"claim":14}}}"#.to_string(),
         claim_fees_sat: 14,
         claim_address: None,
+        external_claim_address: None,
         claim_tx_id: None,
         lockup_tx_id: None,
         mrh_address: "lq1qqdgpjf28g2r27urtan4grfr9206adax5jm94uv68mvpe40lye6aa36x99kklezup4tcs5fvm8sgaz329stru560s8tz65fruz".to_string(),
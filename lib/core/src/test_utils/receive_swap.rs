@@ -2,6 +2,7 @@ use anyhow::Result;
 use sdk_common::utils::Arc;
 
 use crate::{
+    in_flight::InFlightOperations,
     model::{Config, Signer},
     persist::Persister,
     receive_swap::ReceiveSwapHandler,
@@ -14,7 +15,13 @@ use super::{
 };
 
 pub(crate) fn new_receive_swap_handler(persister: Arc<Persister>) -> Result<ReceiveSwapHandler> {
-    let config = Config::testnet_esplora(None);
+    new_receive_swap_handler_with_config(persister, Config::testnet_esplora(None))
+}
+
+pub(crate) fn new_receive_swap_handler_with_config(
+    persister: Arc<Persister>,
+    config: Config,
+) -> Result<ReceiveSwapHandler> {
     let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
     let onchain_wallet = Arc::new(MockWallet::new(signer)?);
     let swapper = Arc::new(MockSwapper::default());
@@ -26,5 +33,6 @@ pub(crate) fn new_receive_swap_handler(persister: Arc<Persister>) -> Result<Rece
         persister,
         swapper,
         liquid_chain_service,
+        Arc::new(InFlightOperations::default()),
     ))
 }
@@ -23,5 +23,6 @@ pub(crate) fn new_recoverer(
         liquid_chain_service,
         bitcoin_chain_service,
         persister,
+        1,
     )
 }
@@ -0,0 +1,190 @@
+//! A harness for end-to-end tests against the local regtest stack of Bitcoin, Elements, LND
+//! and Boltz nodes, started with `make regtest-start` in `lib/core`.
+//!
+//! [SdkNodeHandle] connects an SDK instance to the stack and waits for its events, while
+//! [utils] funds it, pays it and mines blocks through the nodes. The `BITCOIND_COOKIE` and
+//! `LND_MACAROON_HEX` environment variables must be set to the credentials of the stack, as
+//! done by the `regtest-test` make target.
+
+pub mod utils;
+
+use std::{fs, path::PathBuf, time::Duration};
+
+use anyhow::{anyhow, Result};
+use sdk_common::utils::Arc;
+use tokio::sync::mpsc::{self, Receiver, Sender};
+use tokio_with_wasm::alias as tokio;
+
+use crate::{
+    model::{
+        ConnectRequest, EventListener, LiquidNetwork, ListPaymentsRequest, PayOnchainRequest,
+        Payment, PaymentMethod, PreparePayOnchainRequest, PreparePayOnchainResponse,
+        PrepareReceiveRequest, PrepareReceiveResponse, PrepareSendRequest, PrepareSendResponse,
+        ReceivePaymentRequest, ReceivePaymentResponse, SdkEvent, SendPaymentRequest,
+        SendPaymentResponse,
+    },
+    sdk::LiquidSdk,
+};
+
+/// The default time to wait for an SDK event
+pub const TIMEOUT: Duration = Duration::from_secs(15);
+
+struct ForwardingEventListener {
+    sender: Sender<SdkEvent>,
+}
+
+impl EventListener for ForwardingEventListener {
+    fn on_event(&self, e: SdkEvent) {
+        self.sender.try_send(e).unwrap();
+    }
+}
+
+/// An SDK instance connected to the regtest stack with a new wallet
+pub struct SdkNodeHandle {
+    pub sdk: Arc<LiquidSdk>,
+    receiver: Receiver<SdkEvent>,
+}
+
+impl SdkNodeHandle {
+    pub async fn init_node() -> Result<Self> {
+        let data_dir = PathBuf::from(format!("/tmp/{}", uuid::Uuid::new_v4()));
+        if data_dir.exists() {
+            fs::remove_dir_all(&data_dir)?;
+        }
+
+        let mnemonic = bip39::Mnemonic::generate_in(bip39::Language::English, 12)?;
+
+        let mut config = LiquidSdk::default_config(LiquidNetwork::Regtest, None)?;
+        config.working_dir = data_dir.to_str().unwrap().to_string();
+
+        let sdk = LiquidSdk::connect(ConnectRequest {
+            config,
+            mnemonic: Some(mnemonic.to_string()),
+            passphrase: None,
+            seed: None,
+            xprv: None,
+        })
+        .await?;
+
+        let (sender, receiver) = mpsc::channel(100);
+        let listener = ForwardingEventListener { sender };
+        sdk.add_event_listener(Box::new(listener), None).await?;
+
+        Ok(Self { sdk, receiver })
+    }
+
+    /// Funds the wallet with `amount_sat` L-BTC sent from the Elements node, waiting until
+    /// the funding payment is confirmed
+    pub async fn fund_wallet(&mut self, amount_sat: u64) -> Result<()> {
+        let (_, receive_response) = self
+            .receive_payment(&PrepareReceiveRequest {
+                payment_method: PaymentMethod::LiquidAddress,
+                amount: None,
+                fiat_amount: None,
+                expiry_secs: None,
+            })
+            .await?;
+        utils::send_to_address_elementsd(&receive_response.destination, amount_sat)
+            .await
+            .map_err(|e| anyhow!("Failed to send funds: {e}"))?;
+        self.wait_for_event(
+            |e| matches!(e, SdkEvent::PaymentWaitingConfirmation { .. }),
+            TIMEOUT,
+        )
+        .await?;
+        utils::mine_blocks(1)
+            .await
+            .map_err(|e| anyhow!("Failed to mine blocks: {e}"))?;
+        self.wait_for_event(|e| matches!(e, SdkEvent::PaymentSucceeded { .. }), TIMEOUT)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_balance_sat(&self) -> Result<u64> {
+        Ok(self.sdk.get_info().await?.wallet_info.balance_sat)
+    }
+
+    pub async fn get_pending_receive_sat(&self) -> Result<u64> {
+        Ok(self.sdk.get_info().await?.wallet_info.pending_receive_sat)
+    }
+
+    pub async fn get_pending_send_sat(&self) -> Result<u64> {
+        Ok(self.sdk.get_info().await?.wallet_info.pending_send_sat)
+    }
+
+    pub async fn get_payments(&self) -> Result<Vec<Payment>> {
+        Ok(self
+            .sdk
+            .list_payments(&ListPaymentsRequest::default())
+            .await?)
+    }
+
+    pub async fn receive_payment(
+        &self,
+        prepare_request: &PrepareReceiveRequest,
+    ) -> Result<(PrepareReceiveResponse, ReceivePaymentResponse)> {
+        let prepare_response = self.sdk.prepare_receive_payment(prepare_request).await?;
+        let receive_response = self
+            .sdk
+            .receive_payment(&ReceivePaymentRequest {
+                prepare_response: prepare_response.clone(),
+                description: None,
+                use_description_hash: None,
+                description_hash: None,
+                claim_address: None,
+            })
+            .await?;
+        Ok((prepare_response, receive_response))
+    }
+
+    pub async fn send_payment(
+        &self,
+        prepare_request: &PrepareSendRequest,
+    ) -> Result<(PrepareSendResponse, SendPaymentResponse)> {
+        let prepare_response = self.sdk.prepare_send_payment(prepare_request).await?;
+        let send_response = self
+            .sdk
+            .send_payment(&SendPaymentRequest {
+                prepare_response: prepare_response.clone(),
+                use_asset_fees: None,
+                max_fee_sat: None,
+                spend_limit_override: None,
+                idempotency_key: None,
+            })
+            .await?;
+        Ok((prepare_response, send_response))
+    }
+
+    pub async fn send_onchain_payment(
+        &self,
+        prepare_request: &PreparePayOnchainRequest,
+        address: String,
+    ) -> Result<(PreparePayOnchainResponse, SendPaymentResponse)> {
+        let prepare_response = self.sdk.prepare_pay_onchain(prepare_request).await?;
+        let send_response = self
+            .sdk
+            .pay_onchain(&PayOnchainRequest {
+                address,
+                prepare_response: prepare_response.clone(),
+                spend_limit_override: None,
+            })
+            .await?;
+        Ok((prepare_response, send_response))
+    }
+
+    /// Waits until the SDK emits an event matching the `predicate`, failing after `timeout`
+    pub async fn wait_for_event<F>(&mut self, predicate: F, timeout: Duration) -> Result<SdkEvent>
+    where
+        F: Fn(&SdkEvent) -> bool,
+    {
+        tokio::time::timeout(timeout, async {
+            while let Some(event) = self.receiver.recv().await {
+                if predicate(&event) {
+                    return Ok(event);
+                }
+            }
+            Err(anyhow!("Channel closed while waiting for event"))
+        })
+        .await?
+    }
+}
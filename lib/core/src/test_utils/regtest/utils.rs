@@ -1,9 +1,13 @@
-use base64::Engine;
-#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
-use futures::FutureExt;
+//! Helpers driving the nodes of the local regtest stack through the regtest proxy
+
+use std::error::Error;
+use std::time::Duration;
+
+use log::warn;
+use lwk_wollet::bitcoin::base64::{self, Engine as _};
 use reqwest::Client;
 use serde_json::{json, Value};
-use std::error::Error;
+use tokio_with_wasm::alias as tokio;
 
 const BITCOIND_URL: &str = "http://localhost:18443/wallet/client";
 const ELEMENTSD_URL: &str = "http://localhost:18884/wallet/client";
@@ -11,9 +15,26 @@ const LND_URL: &str = "https://localhost:8081";
 
 const PROXY_URL: &str = "http://localhost:51234/proxy";
 
-const BITCOIND_COOKIE: Option<&str> = option_env!("BITCOIND_COOKIE");
 const ELEMENTSD_COOKIE: &str = "regtest:regtest";
-const LND_MACAROON_HEX: Option<&str> = option_env!("LND_MACAROON_HEX");
+/// The time given to LND to pay an invoice started with [start_pay_invoice_lnd]
+const LND_PAY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Reads a credential of the regtest stack from the environment at runtime, falling back to
+/// the one set when compiling, e.g. for Wasm targets which have no environment
+fn regtest_env_var(name: &str, compile_time_value: Option<&str>) -> String {
+    std::env::var(name)
+        .ok()
+        .or(compile_time_value.map(ToString::to_string))
+        .unwrap_or_else(|| panic!("The {name} environment variable must be set"))
+}
+
+fn bitcoind_cookie() -> String {
+    regtest_env_var("BITCOIND_COOKIE", option_env!("BITCOIND_COOKIE"))
+}
+
+fn lnd_macaroon_hex() -> String {
+    regtest_env_var("LND_MACAROON_HEX", option_env!("LND_MACAROON_HEX"))
+}
 
 async fn json_rpc_request(
     url: &str,
@@ -57,7 +78,7 @@ async fn lnd_request(method: &str, params: Value) -> Result<Value, Box<dyn Error
 
     let res = client
         .post(PROXY_URL)
-        .header("Grpc-Metadata-macaroon", LND_MACAROON_HEX.unwrap())
+        .header("Grpc-Metadata-macaroon", lnd_macaroon_hex())
         .header("X-Proxy-URL", url)
         .json(&params)
         .send()
@@ -69,13 +90,8 @@ async fn lnd_request(method: &str, params: Value) -> Result<Value, Box<dyn Error
 }
 
 pub async fn generate_address_bitcoind() -> Result<String, Box<dyn Error>> {
-    let response = json_rpc_request(
-        BITCOIND_URL,
-        BITCOIND_COOKIE.unwrap(),
-        "getnewaddress",
-        json!([]),
-    )
-    .await?;
+    let response =
+        json_rpc_request(BITCOIND_URL, &bitcoind_cookie(), "getnewaddress", json!([])).await?;
 
     response
         .as_str()
@@ -90,7 +106,7 @@ pub async fn send_to_address_bitcoind(
     let btc_amount = (sat_amount as f64) / 100_000_000.0;
     json_rpc_request(
         BITCOIND_URL,
-        BITCOIND_COOKIE.unwrap(),
+        &bitcoind_cookie(),
         "sendtoaddress",
         json!([address, format!("{:.8}", btc_amount)]),
     )
@@ -142,35 +158,24 @@ pub async fn pay_invoice_lnd(invoice: &str) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Pays the invoice with LND in the background, e.g. to wait for the payment events meanwhile
 pub fn start_pay_invoice_lnd(invoice: String) {
-    let task = async move {
-        pay_invoice_lnd(&invoice).await.unwrap();
-    };
-
-    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
-    {
-        tokio::spawn(task);
-    }
-
-    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
-    {
-        wasm_bindgen_futures::spawn_local(async {
-            let timeout_future = gloo_timers::future::TimeoutFuture::new(5000);
-            let _ = futures::select! {
-                _ = task.fuse() => {},
-                _ = timeout_future.fuse() => {},
-            };
-        });
-    }
+    tokio::spawn(async move {
+        match tokio::time::timeout(LND_PAY_TIMEOUT, pay_invoice_lnd(&invoice)).await {
+            Ok(res) => res.unwrap(),
+            Err(_) => warn!("Timed out paying invoice with LND"),
+        }
+    });
 }
 
+/// Mines blocks on both the Bitcoin and Liquid chains
 pub async fn mine_blocks(n_blocks: u64) -> Result<(), Box<dyn Error>> {
     let address_btc = generate_address_bitcoind().await?;
     let address_lqd = generate_address_elementsd().await?;
 
     json_rpc_request(
         BITCOIND_URL,
-        BITCOIND_COOKIE.unwrap(),
+        &bitcoind_cookie(),
         "generatetoaddress",
         json!([n_blocks, address_btc]),
     )
@@ -186,3 +191,27 @@ pub async fn mine_blocks(n_blocks: u64) -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::regtest_env_var;
+
+    #[sdk_macros::test_not_wasm]
+    fn test_regtest_env_var() {
+        let name = "BREEZ_SDK_LIQUID_TEST_REGTEST_ENV_VAR";
+
+        // Without a runtime value, the compile-time value is used
+        std::env::remove_var(name);
+        assert_eq!(regtest_env_var(name, Some("compile-time")), "compile-time");
+
+        // The runtime value takes precedence
+        std::env::set_var(name, "runtime");
+        assert_eq!(regtest_env_var(name, Some("compile-time")), "runtime");
+        assert_eq!(regtest_env_var(name, None), "runtime");
+
+        // Without any value, the missing variable is reported
+        std::env::remove_var(name);
+        let res = std::panic::catch_unwind(|| regtest_env_var(name, None));
+        assert!(res.is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use sdk_common::prelude::{MockRestClient, RestClient, STAGING_BREEZSERVER_URL};
+use sdk_common::prelude::{FiatAPI, MockRestClient, RestClient, STAGING_BREEZSERVER_URL};
 use sdk_common::utils::Arc;
 
 use crate::{
@@ -36,6 +36,53 @@ pub(crate) async fn new_liquid_sdk(
     .await
 }
 
+pub(crate) async fn new_liquid_sdk_with_wallet(
+    persister: Arc<Persister>,
+    swapper: Arc<MockSwapper>,
+    status_stream: Arc<MockStatusStream>,
+    onchain_wallet: Arc<MockWallet>,
+) -> Result<Arc<LiquidSdk>> {
+    let liquid_chain_service = Arc::new(MockLiquidChainService::new());
+    let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
+
+    new_liquid_sdk_builder(
+        persister,
+        swapper,
+        status_stream,
+        liquid_chain_service,
+        bitcoin_chain_service,
+        onchain_wallet,
+        None,
+    )?
+    .build()
+    .await
+}
+
+pub(crate) async fn new_liquid_sdk_with_fiat_api(
+    persister: Arc<Persister>,
+    swapper: Arc<MockSwapper>,
+    status_stream: Arc<MockStatusStream>,
+    fiat_api: Arc<dyn FiatAPI>,
+) -> Result<Arc<LiquidSdk>> {
+    let liquid_chain_service = Arc::new(MockLiquidChainService::new());
+    let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
+    let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+    let onchain_wallet = Arc::new(MockWallet::new(signer)?);
+
+    new_liquid_sdk_builder(
+        persister,
+        swapper,
+        status_stream,
+        liquid_chain_service,
+        bitcoin_chain_service,
+        onchain_wallet,
+        None,
+    )?
+    .fiat_api(fiat_api)
+    .build()
+    .await
+}
+
 pub(crate) async fn new_liquid_sdk_with_chain_services(
     persister: Arc<Persister>,
     swapper: Arc<MockSwapper>,
@@ -44,6 +91,31 @@ pub(crate) async fn new_liquid_sdk_with_chain_services(
     bitcoin_chain_service: Arc<MockBitcoinChainService>,
     onchain_fee_rate_leeway_sat_per_vbyte: Option<u32>,
 ) -> Result<Arc<LiquidSdk>> {
+    let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
+    let onchain_wallet = Arc::new(MockWallet::new(signer)?);
+
+    new_liquid_sdk_builder(
+        persister,
+        swapper,
+        status_stream,
+        liquid_chain_service,
+        bitcoin_chain_service,
+        onchain_wallet,
+        onchain_fee_rate_leeway_sat_per_vbyte,
+    )?
+    .build()
+    .await
+}
+
+pub(crate) fn new_liquid_sdk_builder(
+    persister: Arc<Persister>,
+    swapper: Arc<MockSwapper>,
+    status_stream: Arc<MockStatusStream>,
+    liquid_chain_service: Arc<MockLiquidChainService>,
+    bitcoin_chain_service: Arc<MockBitcoinChainService>,
+    onchain_wallet: Arc<MockWallet>,
+    onchain_fee_rate_leeway_sat_per_vbyte: Option<u32>,
+) -> Result<LiquidSdkBuilder> {
     let mut config = Config::testnet_esplora(None);
     config.working_dir = persister
         .get_database_dir()
@@ -54,7 +126,6 @@ pub(crate) async fn new_liquid_sdk_with_chain_services(
 
     let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
     let rest_client: Arc<dyn RestClient> = Arc::new(MockRestClient::new());
-    let onchain_wallet = Arc::new(MockWallet::new(signer.clone())?);
     let recoverer = Arc::new(Recoverer::new(
         signer.slip77_master_blinding_key()?,
         swapper.clone(),
@@ -62,13 +133,15 @@ pub(crate) async fn new_liquid_sdk_with_chain_services(
         liquid_chain_service.clone(),
         bitcoin_chain_service.clone(),
         persister.clone(),
+        config.outgoing_min_confirmations(),
     )?);
 
     let (_incoming_tx, _outgoing_records, sync_service) =
         new_sync_service(persister.clone(), recoverer.clone(), signer.clone())?;
     let sync_service = Arc::new(sync_service);
 
-    LiquidSdkBuilder::new(config, STAGING_BREEZSERVER_URL.into(), signer)?
+    let mut builder = LiquidSdkBuilder::new(config, STAGING_BREEZSERVER_URL.into(), signer)?;
+    builder
         .bitcoin_chain_service(bitcoin_chain_service)
         .liquid_chain_service(liquid_chain_service)
         .onchain_wallet(onchain_wallet)
@@ -77,7 +150,6 @@ pub(crate) async fn new_liquid_sdk_with_chain_services(
         .rest_client(rest_client)
         .status_stream(status_stream)
         .swapper(swapper)
-        .sync_service(sync_service)
-        .build()
-        .await
+        .sync_service(sync_service);
+    Ok(builder)
 }
@@ -1,4 +1,5 @@
 use crate::{
+    in_flight::InFlightOperations,
     model::{Config, Signer},
     persist::Persister,
     recover::recoverer::Recoverer,
@@ -14,11 +15,21 @@ use super::{
 };
 
 pub(crate) fn new_send_swap_handler(persister: Arc<Persister>) -> Result<SendSwapHandler> {
-    let config = Config::testnet_esplora(None);
+    new_send_swap_handler_with_config(
+        persister,
+        Config::testnet_esplora(None),
+        Arc::new(MockLiquidChainService::new()),
+    )
+}
+
+pub(crate) fn new_send_swap_handler_with_config(
+    persister: Arc<Persister>,
+    config: Config,
+    chain_service: Arc<MockLiquidChainService>,
+) -> Result<SendSwapHandler> {
     let signer: Arc<Box<dyn Signer>> = Arc::new(Box::new(MockSigner::new()?));
     let onchain_wallet = Arc::new(MockWallet::new(signer.clone())?);
     let swapper = Arc::new(MockSwapper::default());
-    let chain_service = Arc::new(MockLiquidChainService::new());
     let liquid_chain_service = Arc::new(MockLiquidChainService::new());
     let bitcoin_chain_service = Arc::new(MockBitcoinChainService::new());
     let recoverer = Arc::new(Recoverer::new(
@@ -28,6 +39,7 @@ pub(crate) fn new_send_swap_handler(persister: Arc<Persister>) -> Result<SendSwa
         liquid_chain_service.clone(),
         bitcoin_chain_service.clone(),
         persister.clone(),
+        config.outgoing_min_confirmations(),
     )?);
 
     Ok(SendSwapHandler::new(
@@ -37,5 +49,6 @@ pub(crate) fn new_send_swap_handler(persister: Arc<Persister>) -> Result<SendSwa
         swapper,
         chain_service,
         recoverer,
+        Arc::new(InFlightOperations::default()),
     ))
 }
@@ -42,4 +42,8 @@ impl SwapperStatusStream for MockStatusStream {
     fn subscribe_swap_updates(&self) -> broadcast::Receiver<boltz::SwapStatus> {
         self.update_notifier.subscribe()
     }
+
+    fn is_connected(&self) -> bool {
+        true
+    }
 }
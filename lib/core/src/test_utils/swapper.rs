@@ -15,7 +15,7 @@ use std::sync::Mutex;
 use crate::{
     ensure_sdk,
     error::{PaymentError, SdkError},
-    model::{Direction, SendSwap, Swap, Transaction as SdkTransaction, Utxo},
+    model::{Direction, SendSwap, Swap, SwapServiceStatus, Transaction as SdkTransaction, Utxo},
     swapper::{ProxyUrlFetcher, Swapper},
     test_utils::generate_random_string,
     utils,
@@ -351,6 +351,17 @@ impl Swapper for MockSwapper {
         unimplemented!()
     }
 
+    async fn invalidate_pairs(&self) {}
+
+    async fn fetch_status(&self) -> Result<SwapServiceStatus, PaymentError> {
+        Ok(SwapServiceStatus {
+            send_available: true,
+            receive_available: true,
+            chain_send_available: true,
+            chain_receive_available: true,
+        })
+    }
+
     async fn get_zero_amount_chain_swap_quote(&self, _swap_id: &str) -> Result<Amount, SdkError> {
         let server_lockup_amount_sat = self.get_zero_amount_swap_server_lockup_sat().await;
         Ok(Amount::from_sat(server_lockup_amount_sat))
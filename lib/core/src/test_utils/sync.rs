@@ -128,6 +128,7 @@ pub(crate) fn new_receive_sync_data() -> ReceiveSyncData {
         payment_hash: None,
         description: None,
         destination_pubkey: None,
+        external_claim_address: None,
     }
 }
 
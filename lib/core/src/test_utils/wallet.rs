@@ -5,7 +5,10 @@ use crate::{
     model::{Signer, SignerError},
     signer::{NewError, SdkLwkSigner},
     utils,
-    wallet::OnchainWallet,
+    wallet::{
+        sweep::{SweepKey, SweepTx},
+        OnchainWallet, PsetWalletBalance, LN_MESSAGE_PREFIX,
+    },
 };
 use anyhow::Result;
 use bip39::Mnemonic;
@@ -21,11 +24,16 @@ use lwk_wollet::{
     secp256k1::{All, Message},
     WalletTx, WalletTxOut,
 };
+use sdk_common::bitcoin::hashes::{sha256, Hash};
+use sdk_common::bitcoin::secp256k1::PublicKey;
+use sdk_common::lightning::util::message_signing::verify;
 use sdk_common::utils::Arc;
 
 pub(crate) struct MockWallet {
     signer: SdkLwkSigner,
     utxos: Mutex<Vec<WalletTxOut>>,
+    transactions: Mutex<Vec<WalletTx>>,
+    pset_balance: Mutex<Option<PsetWalletBalance>>,
 }
 
 lazy_static! {
@@ -40,23 +48,41 @@ impl MockWallet {
         Ok(Self {
             signer,
             utxos: Mutex::new(vec![]),
+            transactions: Mutex::new(vec![]),
+            pset_balance: Mutex::new(None),
         })
     }
 
+    pub(crate) fn set_pset_balance(&self, pset_balance: PsetWalletBalance) -> &Self {
+        *self.pset_balance.lock().unwrap() = Some(pset_balance);
+        self
+    }
+
     pub(crate) fn set_utxos(&self, utxos: Vec<WalletTxOut>) -> &Self {
         *self.utxos.lock().unwrap() = utxos;
         self
     }
+
+    pub(crate) fn set_transactions(&self, transactions: Vec<WalletTx>) -> &Self {
+        *self.transactions.lock().unwrap() = transactions;
+        self
+    }
 }
 
 #[sdk_macros::async_trait]
 impl OnchainWallet for MockWallet {
     async fn transactions(&self) -> Result<Vec<WalletTx>, PaymentError> {
-        Ok(vec![])
+        Ok(self.transactions.lock().unwrap().clone())
     }
 
     async fn transactions_by_tx_id(&self) -> Result<HashMap<Txid, WalletTx>, PaymentError> {
-        Ok(Default::default())
+        Ok(self
+            .transactions
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|tx| (tx.txid, tx.clone()))
+            .collect())
     }
 
     async fn asset_utxos(&self, _asset_id: &AssetId) -> Result<Vec<WalletTxOut>, PaymentError> {
@@ -73,6 +99,14 @@ impl OnchainWallet for MockWallet {
         Ok(TEST_LIQUID_TX.clone())
     }
 
+    async fn build_lbtc_batch_tx(
+        &self,
+        _fee_rate_sats_per_kvb: Option<f32>,
+        _recipients: &[(String, u64)],
+    ) -> Result<Transaction, PaymentError> {
+        Ok(TEST_LIQUID_TX.clone())
+    }
+
     async fn build_drain_tx(
         &self,
         _fee_rate_sats_per_kvb: Option<f32>,
@@ -82,6 +116,20 @@ impl OnchainWallet for MockWallet {
         Ok(TEST_LIQUID_TX.clone())
     }
 
+    async fn build_sweep_tx(
+        &self,
+        _key: &SweepKey,
+        _fee_rate_sats_per_kvb: Option<f32>,
+        _recipient_address: &str,
+    ) -> Result<SweepTx, PaymentError> {
+        Ok(SweepTx {
+            tx: TEST_LIQUID_TX.clone(),
+            amount_sat: 1_000,
+            asset_amounts: Default::default(),
+            fees_sat: 26,
+        })
+    }
+
     async fn build_tx_or_drain_tx(
         &self,
         _fee_rate_sats_per_kvb: Option<f32>,
@@ -99,6 +147,28 @@ impl OnchainWallet for MockWallet {
         Ok(TEST_LIQUID_TX.clone())
     }
 
+    async fn pset_balance(
+        &self,
+        _pset: &PartiallySignedTransaction,
+    ) -> Result<PsetWalletBalance, PaymentError> {
+        Ok(self
+            .pset_balance
+            .lock()
+            .unwrap()
+            .clone()
+            .unwrap_or(PsetWalletBalance {
+                fee_sat: 0,
+                balances: HashMap::new(),
+            }))
+    }
+
+    async fn sign_pset_inputs(
+        &self,
+        pset: PartiallySignedTransaction,
+    ) -> Result<PartiallySignedTransaction, PaymentError> {
+        Ok(pset)
+    }
+
     async fn next_unused_address(&self) -> Result<Address, PaymentError> {
         Ok(TEST_P2TR_ADDR.clone())
     }
@@ -107,6 +177,10 @@ impl OnchainWallet for MockWallet {
         Ok(TEST_P2TR_ADDR.clone())
     }
 
+    async fn next_unused_amp_address(&self) -> Result<Address, PaymentError> {
+        Ok(TEST_P2TR_ADDR.clone())
+    }
+
     async fn tip(&self) -> u32 {
         0
     }
@@ -119,12 +193,16 @@ impl OnchainWallet for MockWallet {
         Ok(self.signer.fingerprint()?.to_hex())
     }
 
-    fn sign_message(&self, _message: &str) -> Result<String> {
-        unimplemented!()
+    fn sign_message(&self, message: &str) -> Result<String> {
+        let hashed_msg = sha256::Hash::hash(&[LN_MESSAGE_PREFIX, message.as_bytes()].concat());
+        let double_hashed_msg = Message::from_digest(sha256::Hash::hash(&hashed_msg).into_inner());
+        let recoverable_sig = self.signer.sign_ecdsa_recoverable(&double_hashed_msg)?;
+        Ok(zbase32::encode_full_bytes(recoverable_sig.as_slice()))
     }
 
-    fn check_message(&self, _message: &str, _pubkey: &str, _signature: &str) -> Result<bool> {
-        unimplemented!()
+    fn check_message(&self, message: &str, pubkey: &str, signature: &str) -> Result<bool> {
+        let pk = PublicKey::from_str(pubkey)?;
+        Ok(verify(message.as_bytes(), signature, &pk))
     }
 
     async fn full_scan(&self) -> Result<(), PaymentError> {
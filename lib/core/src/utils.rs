@@ -2,9 +2,12 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use crate::ensure_sdk;
-use crate::error::{PaymentError, SdkResult};
-use crate::prelude::LiquidNetwork;
+use crate::error::{PaymentError, SdkError, SdkResult};
+use crate::prelude::{
+    AddressType, DecodedInvoice, LNBolt12Invoice, LiquidNetwork, ValidateAddressResponse,
+};
 use anyhow::{anyhow, ensure, Result};
+use bip39::{Language, Mnemonic};
 use boltz_client::boltz::SubmarinePair;
 use boltz_client::util::secrets::Preimage;
 use boltz_client::ToHex;
@@ -13,6 +16,7 @@ use lwk_wollet::elements::encode::deserialize;
 use lwk_wollet::elements::hex::FromHex;
 use lwk_wollet::elements::AssetId;
 use lwk_wollet::elements::{
+    AddressParams,
     LockTime::{self, *},
     Transaction,
 };
@@ -20,6 +24,7 @@ use sdk_common::bitcoin::bech32;
 use sdk_common::bitcoin::bech32::FromBase32;
 use sdk_common::lightning_invoice::Bolt11Invoice;
 use sdk_common::lightning_with_bolt12::offers::invoice::Bolt12Invoice;
+use sdk_common::prelude::parse_invoice;
 use web_time::{SystemTime, UNIX_EPOCH};
 
 lazy_static! {
@@ -38,6 +43,13 @@ pub(crate) fn now() -> u32 {
         .as_secs() as u32
 }
 
+pub(crate) fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+}
+
 pub(crate) fn json_to_pubkey(json: &str) -> Result<boltz_client::PublicKey, PaymentError> {
     boltz_client::PublicKey::from_str(json).map_err(|e| PaymentError::Generic {
         err: format!("Failed to deserialize PublicKey: {e:?}"),
@@ -84,6 +96,96 @@ pub(crate) fn parse_bolt12_invoice(invoice: &str) -> Result<Bolt12Invoice> {
         .map_err(|e| anyhow!("Failed to parse BOLT12: {e:?}"))
 }
 
+/// Decodes a BOLT11 or BOLT12 invoice into its fields
+pub(crate) fn decode_invoice(input: &str) -> Result<DecodedInvoice> {
+    let input = input.trim();
+    if let Ok(invoice) = parse_invoice(input) {
+        return Ok(DecodedInvoice::Bolt11 { invoice });
+    }
+    let invoice = parse_bolt12_invoice(input)?;
+    Ok(DecodedInvoice::Bolt12 {
+        invoice: LNBolt12Invoice {
+            invoice: input.to_string(),
+            signing_pubkey: invoice.signing_pubkey().to_hex(),
+            payment_hash: invoice.payment_hash().to_string(),
+            description: invoice.description().map(|desc| desc.to_string()),
+            payer_note: invoice.payer_note().map(|note| note.to_string()),
+            amount_msat: invoice.amount_msats(),
+            created_at: invoice.created_at().as_secs(),
+            expiry: invoice.relative_expiry().as_secs(),
+            payment_path_count: invoice.payment_paths().len() as u32,
+        },
+    })
+}
+
+/// Validates a Bitcoin or Liquid address, which must belong to `network`
+pub(crate) fn validate_address(
+    address: &str,
+    network: LiquidNetwork,
+) -> std::result::Result<ValidateAddressResponse, PaymentError> {
+    let address = address.trim();
+    if let Ok(bitcoin_address) = boltz_client::bitcoin::Address::from_str(address) {
+        ensure_sdk!(
+            bitcoin_address.is_valid_for_network(network.into()),
+            PaymentError::invalid_network(&format!("Bitcoin address is not for {network:?}"))
+        );
+        return Ok(ValidateAddressResponse {
+            address_type: AddressType::Bitcoin,
+            network,
+            is_confidential: false,
+            supports_assets: false,
+        });
+    }
+
+    let liquid_address = boltz_client::ElementsAddress::from_str(address)
+        .map_err(|e| PaymentError::generic(&format!("Invalid Bitcoin or Liquid address: {e}")))?;
+    let params = match network {
+        LiquidNetwork::Mainnet => &AddressParams::LIQUID,
+        LiquidNetwork::Testnet => &AddressParams::LIQUID_TESTNET,
+        LiquidNetwork::Regtest => &AddressParams::ELEMENTS,
+    };
+    ensure_sdk!(
+        liquid_address.params == params,
+        PaymentError::invalid_network(&format!("Liquid address is not for {network:?}"))
+    );
+    Ok(ValidateAddressResponse {
+        address_type: AddressType::Liquid,
+        network,
+        is_confidential: liquid_address.is_blinded(),
+        supports_assets: true,
+    })
+}
+
+/// Validates an address to claim swaps to, which must be a Liquid address of `network`
+pub(crate) fn validate_claim_address(
+    address: &str,
+    network: LiquidNetwork,
+) -> std::result::Result<(), PaymentError> {
+    let validated = validate_address(address, network)?;
+    ensure_sdk!(
+        validated.address_type == AddressType::Liquid,
+        PaymentError::generic("The claim address must be a Liquid address")
+    );
+    Ok(())
+}
+
+/// Generates a random English BIP39 mnemonic of `word_count` words
+pub(crate) fn generate_mnemonic(word_count: u32) -> SdkResult<String> {
+    ensure_sdk!(
+        matches!(word_count, 12 | 15 | 18 | 21 | 24),
+        SdkError::generic("The word count must be 12, 15, 18, 21 or 24")
+    );
+    let mnemonic = Mnemonic::generate_in(Language::English, word_count as usize)
+        .map_err(|e| SdkError::generic(format!("Failed to generate mnemonic: {e}")))?;
+    Ok(mnemonic.to_string())
+}
+
+/// Validates the words and checksum of a BIP39 mnemonic, parsing it as the SDK signer does
+pub(crate) fn validate_mnemonic(phrase: &str) -> SdkResult<()> {
+    Mnemonic::from_str(phrase).map_err(|e| SdkError::generic(format!("Invalid mnemonic: {e}")))?;
+    Ok(())
+}
+
 /// Parse and extract the destination pubkey from the invoice.
 /// The payee pubkey for Bolt11 and signing pubkey for Bolt12.
 pub(crate) fn get_invoice_destination_pubkey(invoice: &str, is_bolt12: bool) -> Result<String> {
@@ -124,6 +226,24 @@ pub(crate) fn verify_payment_hash(
     Ok(())
 }
 
+/// Returns the secrets of the wallet inputs and outputs of a tx, as the comma separated
+/// `value,asset,value_bf,asset_bf` list used by the explorers to unblind it
+pub(crate) fn tx_unblinding_data(tx: &lwk_wollet::WalletTx) -> String {
+    tx.inputs
+        .iter()
+        .chain(tx.outputs.iter())
+        .flatten()
+        .map(|tx_out| {
+            let secrets = &tx_out.unblinded;
+            format!(
+                "{},{},{},{}",
+                secrets.value, secrets.asset, secrets.value_bf, secrets.asset_bf
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
 pub(crate) fn lbtc_asset_id(network: LiquidNetwork) -> AssetId {
     match network {
         LiquidNetwork::Mainnet => AssetId::LIQUID_BTC,
@@ -172,14 +292,128 @@ pub(crate) fn log_print_header(init_time_ms: Duration) {
     );
 }
 
+/// Whether the given BOLT11 invoice has expired. Returns `false` if it cannot be parsed.
+pub(crate) fn is_bolt11_invoice_expired(invoice: &str) -> bool {
+    match Bolt11Invoice::from_str(invoice) {
+        Ok(invoice) => {
+            let expires_at = invoice.duration_since_epoch() + invoice.expiry_time();
+            now() as u64 >= expires_at.as_secs()
+        }
+        Err(_) => false,
+    }
+}
+
+/// The delay in seconds before retrying an operation that failed `attempts` times, doubling
+/// from 30 seconds up to one hour
+pub(crate) fn retry_backoff_secs(attempts: u32) -> u32 {
+    30u32
+        .saturating_mul(2u32.saturating_pow(attempts.saturating_sub(1)))
+        .min(3_600)
+}
+
+/// Converts a fiat amount to satoshi, given the `rate` in units of the fiat currency per BTC
+pub(crate) fn fiat_to_sat(fiat_amount: f64, rate: f64) -> u64 {
+    (fiat_amount / rate * 100_000_000.0).round() as u64
+}
+
+/// Converts a satoshi amount to fiat, given the `rate` in units of the fiat currency per BTC
+pub(crate) fn sat_to_fiat(amount_sat: u64, rate: f64) -> f64 {
+    amount_sat as f64 / 100_000_000.0 * rate
+}
+
+/// Validates a hex-encoded SHA256 description hash and returns it in its normalized form
+pub(crate) fn parse_description_hash(description_hash: &str) -> Result<String, PaymentError> {
+    let bytes =
+        Vec::<u8>::from_hex(description_hash).map_err(|_| PaymentError::InvalidDescription {
+            err: "Description hash must be hex-encoded".to_string(),
+        })?;
+    ensure_sdk!(
+        bytes.len() == 32,
+        PaymentError::InvalidDescription {
+            err: "Description hash must be 32 bytes long".to_string(),
+        }
+    );
+    Ok(bytes.to_hex())
+}
+
 #[cfg(test)]
 mod tests {
+    use lwk_wollet::hashes::{sha256, Hash};
+
+    use std::str::FromStr;
+
     use crate::error::PaymentError;
-    use crate::utils::verify_payment_hash;
+    use crate::model::{AddressType, DecodedInvoice, LiquidNetwork};
+    use crate::utils::{
+        decode_invoice, fiat_to_sat, generate_mnemonic, is_bolt11_invoice_expired,
+        parse_description_hash, retry_backoff_secs, sat_to_fiat, validate_address,
+        validate_mnemonic, verify_payment_hash,
+    };
 
     #[cfg(feature = "browser-tests")]
     wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
+    #[sdk_macros::test_all]
+    fn test_validate_address() -> anyhow::Result<()> {
+        let bitcoin_address = "bc1qpvht5g3z9285z28emd0wa78r42jrrftdtkm0q0";
+        let res = validate_address(bitcoin_address, LiquidNetwork::Mainnet)?;
+        assert_eq!(res.address_type, AddressType::Bitcoin);
+        assert!(!res.supports_assets);
+        assert!(matches!(
+            validate_address(bitcoin_address, LiquidNetwork::Testnet),
+            Err(PaymentError::InvalidNetwork { .. })
+        ));
+
+        let liquid_address = "lq1qqdgpjf28g2r27urtan4grfr9206adax5jm94uv68mvpe40lye6aa36x99kklezup4tcs5fvm8sgaz329stru560s8tz65fruz";
+        let res = validate_address(liquid_address, LiquidNetwork::Mainnet)?;
+        assert_eq!(res.address_type, AddressType::Liquid);
+        assert!(res.is_confidential);
+        assert!(res.supports_assets);
+        assert!(matches!(
+            validate_address(liquid_address, LiquidNetwork::Regtest),
+            Err(PaymentError::InvalidNetwork { .. })
+        ));
+
+        let unconfidential_address = boltz_client::ElementsAddress::from_str(liquid_address)?
+            .to_unconfidential()
+            .to_string();
+        let res = validate_address(&unconfidential_address, LiquidNetwork::Mainnet)?;
+        assert!(!res.is_confidential);
+
+        assert!(matches!(
+            validate_address("not an address", LiquidNetwork::Mainnet),
+            Err(PaymentError::Generic { .. })
+        ));
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_decode_invoice() -> anyhow::Result<()> {
+        let bolt11_invoice = "lnbc10u1pnczjaupp55392fur38rc2y9vzmhdy0tclvfels0lvlmzgvmhpg6q2mndxzmrsdqqcqzzsxqyz5vqsp5ya6pvchlsvl3mzqh3zw4hg3tz5pww77q6rcwfr52qchyrp7s6krs9p4gqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqysgqgnp0sskk0ljjew8vkc3udhzgquzs79evf5wezfaex9q4gjk5qcn8m3luauyte93lgassd8skh5m90glhtt52ry2wtftzrjn4h076z7sqdjry3d";
+        let bolt12_invoice = "lni1qqg274t4yefgrj0pn3cwjz4vaacayyxvqwryaup9lh50kkranzgcdnn2fgvx390wgj5jd07rwr3vxeje0glc7quxqu8s2dggmw92army03kdxt825vqkawcz33kvrennr6g2fu6f7vpqx40040703nghrcj3pp9gax3y2my9v4kd5gtzv5k68k7vnxa3y7dlqqee9gty2hdrprsd8t04jz5nea79phgs9vyp9ruexzczwfhzg57c3yl345x3jy3kqpgr3e3u54fu0vewzjv2jq4lvj8gghjf5h8kgpw23c8tugua4qh432mylsdj3ac260fwvptzgcqpq486c0qlz6aqrj7q7k804w5mv92jqv85yszcyypft03wvpgapj7t0h58t6da6tdwx69admya2p0dl435la7wq4ljk79ql5qe5quxfmcztl0gldv8mxy3sm8x5jscdz27u39fy6luxu8zcdn9j73l3upa3vjg727ft7cwfkg4yqxyctm98hq6utue2k5k5at05azu2wgw57szq2qztaq2rnqjt6ugna4em3uj2el3cr7gj2glzuwkm346qpx93y9ruqz9fkumys35w9jdqxs45qzec44fhpy7lldwzt80y3q33sk09nkgf7h9r6etd45zp80snmz5x4uquqk7a0cusp0sluhku8md0eaxejqvkdd6mcp0gxqr6hsfwsxu4vx6lx08axqpj3fe87jeqfvdmetqxcaadn993vv3fe3qpny568lpz00dj3w6rag6gv3jyj9nnqmh6455l4h7ewe4zstwprmumemut8fexnnmgqmfzj0xwgr3mmwygw59jjqqv0h9vgc8vhkcx4g3s3av4kd48w4p4qs29zggh4vz924t23m7va0am4d7d4uur96uypayuchcgs2wxm0ktsaadewffys0jdlz245saetsd4f2m7ljp3tdxmt45qw64slkmlwaeak0h7508hftjdh6vyzr7skx2eucwwmgce0pydvgx5egmv4fnu0e7383ygyhwa0vwd4gy6zsez6kktvdezn79ejh2n8zmdtk998jvzuq7syv4gsuqqqq86qqqqqxgqfqqqqqqqqqqqp7sqqqqqqqq85ysqqqpfqyv7pxql4xqvq4rq9gyztzsk4ktppyn45peyvhfpl6lv8ewjr666gkzttspjkp8zn0g2n9f2srpapyptsrqgqqpvppq2lkfr5ytey6tnmyqh9gur47yww6st6c4dj0cxeg7u9d85hxq43yduzqrxguu82stp5egwzefmhvm9k63r0nxemf0pg54j3hdfzgt068te3dv5s089p54gcplnk778kcnfhkn6l8tggjqmgyc88vrgr6gc3gx7q";
+
+        let DecodedInvoice::Bolt11 { invoice } = decode_invoice(bolt11_invoice)? else {
+            panic!("Expected a BOLT11 invoice");
+        };
+        assert_eq!(invoice.amount_msat, Some(1_000_000));
+
+        let DecodedInvoice::Bolt12 { invoice } = decode_invoice(bolt12_invoice)? else {
+            panic!("Expected a BOLT12 invoice");
+        };
+        assert_eq!(invoice.invoice, bolt12_invoice);
+        assert_eq!(
+            invoice.payment_hash,
+            sha256::Hash::hash(&hex::decode(
+                "443c900d61ed8e90a7bfbb7958f1485a7f57e74adacd3e216deba03f8326a392"
+            )?)
+            .to_string()
+        );
+        assert!(invoice.expiry > 0);
+
+        assert!(decode_invoice("not an invoice").is_err());
+        Ok(())
+    }
+
     #[sdk_macros::test_all]
     fn test_verify_payment_hash() -> anyhow::Result<()> {
         let bolt11_invoice = "lnbc10u1pnczjaupp55392fur38rc2y9vzmhdy0tclvfels0lvlmzgvmhpg6q2mndxzmrsdqqcqzzsxqyz5vqsp5ya6pvchlsvl3mzqh3zw4hg3tz5pww77q6rcwfr52qchyrp7s6krs9p4gqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqysgqgnp0sskk0ljjew8vkc3udhzgquzs79evf5wezfaex9q4gjk5qcn8m3luauyte93lgassd8skh5m90glhtt52ry2wtftzrjn4h076z7sqdjry3d";
@@ -209,4 +443,63 @@ mod tests {
 
         Ok(())
     }
+
+    #[sdk_macros::test_all]
+    fn test_is_bolt11_invoice_expired() {
+        let expired_invoice = "lnbc10u1pnczjaupp55392fur38rc2y9vzmhdy0tclvfels0lvlmzgvmhpg6q2mndxzmrsdqqcqzzsxqyz5vqsp5ya6pvchlsvl3mzqh3zw4hg3tz5pww77q6rcwfr52qchyrp7s6krs9p4gqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqpqysgqgnp0sskk0ljjew8vkc3udhzgquzs79evf5wezfaex9q4gjk5qcn8m3luauyte93lgassd8skh5m90glhtt52ry2wtftzrjn4h076z7sqdjry3d";
+        assert!(is_bolt11_invoice_expired(expired_invoice));
+        assert!(!is_bolt11_invoice_expired("not an invoice"));
+    }
+
+    #[sdk_macros::test_all]
+    fn test_fiat_to_sat() {
+        assert_eq!(fiat_to_sat(5.0, 100_000.0), 5_000);
+        assert_eq!(fiat_to_sat(1.0, 30_000.0), 3_333);
+        assert_eq!(fiat_to_sat(0.02, 30_000.0), 67);
+    }
+
+    #[sdk_macros::test_all]
+    fn test_sat_to_fiat() {
+        assert_eq!(sat_to_fiat(5_000, 100_000.0), 5.0);
+        assert_eq!(sat_to_fiat(0, 100_000.0), 0.0);
+    }
+
+    #[sdk_macros::test_all]
+    fn test_parse_description_hash() {
+        let hash = "3925B6F67E2C340036ED12093DD44E0368DF1B6EA26C53DBE4811F58FD5DB8C1";
+        assert_eq!(parse_description_hash(hash).unwrap(), hash.to_lowercase());
+        assert!(matches!(
+            parse_description_hash("not hex"),
+            Err(PaymentError::InvalidDescription { .. })
+        ));
+        assert!(matches!(
+            parse_description_hash("3925b6f67e2c3400"),
+            Err(PaymentError::InvalidDescription { .. })
+        ));
+    }
+
+    #[sdk_macros::test_all]
+    fn test_retry_backoff_secs() {
+        assert_eq!(retry_backoff_secs(1), 30);
+        assert_eq!(retry_backoff_secs(2), 60);
+        assert_eq!(retry_backoff_secs(5), 480);
+        assert_eq!(retry_backoff_secs(8), 3_600);
+        assert_eq!(retry_backoff_secs(100), 3_600);
+    }
+    #[sdk_macros::test_all]
+    fn test_mnemonic() -> anyhow::Result<()> {
+        for word_count in [12, 24] {
+            let mnemonic = generate_mnemonic(word_count)?;
+            assert_eq!(mnemonic.split_whitespace().count(), word_count as usize);
+            validate_mnemonic(&mnemonic)?;
+        }
+        assert!(generate_mnemonic(13).is_err());
+
+        validate_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about")?;
+        // Bad checksum
+        assert!(validate_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon").is_err());
+        // Unknown word
+        assert!(validate_mnemonic("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon bitcoins").is_err());
+        Ok(())
+    }
 }
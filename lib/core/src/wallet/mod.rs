@@ -1,6 +1,7 @@
 pub mod persister;
+pub(crate) mod sweep;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::Write;
 use std::str::FromStr;
 
@@ -10,6 +11,7 @@ use log::{debug, error, info, warn};
 use lwk_common::Signer as LwkSigner;
 use lwk_common::{singlesig_desc, Singlesig};
 use lwk_wollet::asyncr::{EsploraClient, EsploraClientBuilder};
+use lwk_wollet::bitcoin::bip32::{DerivationPath, Fingerprint, Xpub};
 use lwk_wollet::elements::hex::ToHex;
 use lwk_wollet::elements::pset::PartiallySignedTransaction;
 use lwk_wollet::elements::{Address, AssetId, OutPoint, Transaction, TxOut, Txid};
@@ -19,7 +21,7 @@ use maybe_sync::{MaybeSend, MaybeSync};
 use sdk_common::bitcoin::hashes::{sha256, Hash};
 use sdk_common::bitcoin::secp256k1::PublicKey;
 use sdk_common::lightning::util::message_signing::verify;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, MutexGuard};
 use web_time::Instant;
 
 use crate::model::{BlockchainExplorer, Signer, BREEZ_LIQUID_ESPLORA_URL};
@@ -35,10 +37,11 @@ use sdk_common::utils::Arc;
 use crate::wallet::persister::{
     FsWalletCachePersister, NoWalletCachePersister, WalletCachePersister,
 };
+use crate::wallet::sweep::{SweepKey, SweepTx};
 #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
 use lwk_wollet::blocking::BlockchainBackend;
 
-static LN_MESSAGE_PREFIX: &[u8] = b"Lightning Signed Message:";
+pub(crate) static LN_MESSAGE_PREFIX: &[u8] = b"Lightning Signed Message:";
 
 #[sdk_macros::async_trait]
 pub trait OnchainWallet: MaybeSend + MaybeSync {
@@ -60,6 +63,13 @@ pub trait OnchainWallet: MaybeSend + MaybeSync {
         amount_sat: u64,
     ) -> Result<Transaction, PaymentError>;
 
+    /// Build a transaction to send L-BTC to multiple recipients, each paid with its own output
+    async fn build_lbtc_batch_tx(
+        &self,
+        fee_rate_sats_per_kvb: Option<f32>,
+        recipients: &[(String, u64)],
+    ) -> Result<Transaction, PaymentError>;
+
     /// Builds a drain tx.
     ///
     /// ### Arguments
@@ -85,18 +95,46 @@ pub trait OnchainWallet: MaybeSend + MaybeSync {
         amount_sat: u64,
     ) -> Result<Transaction, PaymentError>;
 
+    /// Builds a tx sweeping all the assets controlled by a key outside of the wallet.
+    ///
+    /// ### Arguments
+    /// - `key`: the key controlling the funds
+    /// - `fee_rate_sats_per_kvb`: custom sweep tx feerate
+    /// - `recipient_address`: sweep tx recipient
+    async fn build_sweep_tx(
+        &self,
+        key: &SweepKey,
+        fee_rate_sats_per_kvb: Option<f32>,
+        recipient_address: &str,
+    ) -> Result<SweepTx, PaymentError>;
+
     /// Sign a partially signed transaction
     async fn sign_pset(
         &self,
         pset: PartiallySignedTransaction,
     ) -> Result<Transaction, PaymentError>;
 
+    /// Get the effect of a partially signed transaction on the wallet balances
+    async fn pset_balance(
+        &self,
+        pset: &PartiallySignedTransaction,
+    ) -> Result<PsetWalletBalance, PaymentError>;
+
+    /// Sign the wallet inputs of a partially signed transaction, without finalizing it
+    async fn sign_pset_inputs(
+        &self,
+        pset: PartiallySignedTransaction,
+    ) -> Result<PartiallySignedTransaction, PaymentError>;
+
     /// Get the next unused address in the wallet
     async fn next_unused_address(&self) -> Result<Address, PaymentError>;
 
     /// Get the next unused change address in the wallet
     async fn next_unused_change_address(&self) -> Result<Address, PaymentError>;
 
+    /// Get the next unused address of the registered AMP account
+    async fn next_unused_amp_address(&self) -> Result<Address, PaymentError>;
+
     /// Get the current tip of the blockchain the wallet is aware of
     async fn tip(&self) -> u32;
 
@@ -118,6 +156,15 @@ pub trait OnchainWallet: MaybeSend + MaybeSync {
     async fn full_scan(&self) -> Result<(), PaymentError>;
 }
 
+/// The effect of a partially signed transaction on the wallet
+#[derive(Clone)]
+pub struct PsetWalletBalance {
+    /// The network fee of the transaction
+    pub fee_sat: u64,
+    /// The change of the wallet balance per asset, negative if the asset is spent
+    pub balances: HashMap<AssetId, i64>,
+}
+
 pub enum WalletClient {
     #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
     Electrum(Box<lwk_wollet::ElectrumClient>),
@@ -151,7 +198,12 @@ impl WalletClient {
                         }
                     };
                 }
-                let client = Box::new(builder.timeout(3).waterfalls(waterfalls).build());
+                let client = Box::new(
+                    builder
+                        .timeout(config.chain_service_timeout_sec())
+                        .waterfalls(waterfalls)
+                        .build(),
+                );
                 Ok(Self::Esplora(client))
             }
         }
@@ -185,6 +237,9 @@ pub struct LiquidOnchainWallet {
     persister: Arc<Persister>,
     wallet: Arc<Mutex<Wollet>>,
     client: Mutex<Option<WalletClient>>,
+    /// The wallet of the registered AMP account, loaded once the account is registered. Its
+    /// cache is persisted alongside the wallet cache.
+    amp_wallet: Mutex<Option<Wollet>>,
     pub(crate) signer: SdkLwkSigner,
     wallet_cache_persister: Arc<dyn WalletCachePersister>,
 }
@@ -217,6 +272,7 @@ impl LiquidOnchainWallet {
             persister,
             wallet: Arc::new(Mutex::new(wollet)),
             client: Mutex::new(None),
+            amp_wallet: Mutex::new(None),
             signer,
             wallet_cache_persister,
         })
@@ -240,6 +296,7 @@ impl LiquidOnchainWallet {
             persister,
             wallet: Arc::new(Mutex::new(wollet)),
             client: Mutex::new(None),
+            amp_wallet: Mutex::new(None),
             signer,
             wallet_cache_persister,
         })
@@ -260,6 +317,7 @@ impl LiquidOnchainWallet {
             persister,
             wallet: Arc::new(Mutex::new(wollet)),
             client: Mutex::new(None),
+            amp_wallet: Mutex::new(None),
             signer,
             wallet_cache_persister,
         })
@@ -296,6 +354,32 @@ impl LiquidOnchainWallet {
         }
     }
 
+    /// Returns the wallet of the registered AMP account, or `None` if no account is registered
+    async fn amp_wallet(&self) -> Result<MutexGuard<'_, Option<Wollet>>, PaymentError> {
+        let mut amp_wallet = self.amp_wallet.lock().await;
+        if amp_wallet.is_none() {
+            if let Some(account) = self.persister.get_amp_account()? {
+                let descriptor: WolletDescriptor = account.descriptor.parse()?;
+                let lwk_persister = self
+                    .wallet_cache_persister
+                    .get_descriptor_lwk_persister(&descriptor)?;
+                let wollet = match Wollet::new(
+                    self.config.network.into(),
+                    lwk_persister,
+                    descriptor.clone(),
+                ) {
+                    Ok(wollet) => wollet,
+                    Err(e) => {
+                        warn!("Error loading the cached AMP wallet, scanning it again: {e:?}");
+                        Wollet::without_persist(self.config.network.into(), descriptor)?
+                    }
+                };
+                *amp_wallet = Some(wollet);
+            }
+        }
+        Ok(amp_wallet)
+    }
+
     async fn get_txout(&self, wallet: &Wollet, outpoint: &OutPoint) -> Result<TxOut> {
         let wallet_tx = wallet
             .transaction(&outpoint.txid)?
@@ -307,6 +391,33 @@ impl LiquidOnchainWallet {
             .ok_or(anyhow!("Output not found"))?;
         Ok(tx_out.clone())
     }
+
+    /// Adds the witness utxo of the wallet inputs and the wallet details to the PSET
+    async fn add_pset_details(
+        &self,
+        wallet: &Wollet,
+        pset: &mut PartiallySignedTransaction,
+    ) -> Result<(), PaymentError> {
+        // Get the tx_out for each input and add the rangeproof/witness utxo
+        for input in pset.inputs_mut().iter_mut() {
+            let tx_out_res = self
+                .get_txout(
+                    wallet,
+                    &OutPoint {
+                        txid: input.previous_txid,
+                        vout: input.previous_output_index,
+                    },
+                )
+                .await;
+            if let Ok(mut tx_out) = tx_out_res {
+                input.in_utxo_rangeproof = tx_out.witness.rangeproof.take();
+                input.witness_utxo = Some(tx_out);
+            }
+        }
+
+        wallet.add_details(pset)?;
+        Ok(())
+    }
 }
 
 pub fn get_descriptor(
@@ -324,14 +435,108 @@ pub fn get_descriptor(
     Ok(descriptor_str.parse()?)
 }
 
+/// The derivation path of the account xpub in the descriptor returned by [get_descriptor]
+pub(crate) fn account_derivation_path(network: LiquidNetwork) -> &'static str {
+    match network {
+        LiquidNetwork::Mainnet => "m/84'/1776'/0'",
+        LiquidNetwork::Testnet | LiquidNetwork::Regtest => "m/84'/1'/0'",
+    }
+}
+
+/// Returns the account xpub in the descriptor returned by [get_descriptor]
+pub(crate) fn get_account_xpub(signer: &SdkLwkSigner, network: LiquidNetwork) -> Result<Xpub> {
+    let derivation_path = DerivationPath::from_str(account_derivation_path(network))?;
+    Ok(signer.derive_xpub(&derivation_path)?)
+}
+
+/// The derivation path of the xpub registered as the user key of the AMP account
+pub(crate) fn amp_account_derivation_path(network: LiquidNetwork) -> &'static str {
+    match network {
+        LiquidNetwork::Mainnet => "m/87'/1776'/0'",
+        LiquidNetwork::Testnet | LiquidNetwork::Regtest => "m/87'/1'/0'",
+    }
+}
+
+/// Returns the user key of the AMP account, as a key origin prefixed xpub
+pub(crate) fn get_amp_account_key(signer: &SdkLwkSigner, network: LiquidNetwork) -> Result<String> {
+    let derivation_path = amp_account_derivation_path(network);
+    let xpub = signer.derive_xpub(&DerivationPath::from_str(derivation_path)?)?;
+    Ok(format!(
+        "[{}/{}]{xpub}",
+        signer.fingerprint()?,
+        derivation_path.trim_start_matches("m/")
+    ))
+}
+
+/// Merges the transactions of the AMP account into the transactions of the main wallet.
+/// The balances of a transaction involving both wallets are summed per asset.
+fn merge_wallet_transactions(transactions: &mut Vec<WalletTx>, amp_transactions: Vec<WalletTx>) {
+    for amp_tx in amp_transactions {
+        let Some(tx) = transactions.iter_mut().find(|tx| tx.txid == amp_tx.txid) else {
+            transactions.push(amp_tx);
+            continue;
+        };
+        for (asset_id, amount) in amp_tx.balance {
+            *tx.balance.entry(asset_id).or_default() += amount;
+        }
+        for (input, amp_input) in tx.inputs.iter_mut().zip(amp_tx.inputs) {
+            if input.is_none() {
+                *input = amp_input;
+            }
+        }
+        for (output, amp_output) in tx.outputs.iter_mut().zip(amp_tx.outputs) {
+            if output.is_none() {
+                *output = amp_output;
+            }
+        }
+    }
+}
+
+/// Ensures the inputs of the PSET the wallet would sign spend its own utxos, and the outputs
+/// claiming to pay to the wallet are recognized as its own. Otherwise the balance the PSET
+/// is checked against wouldn't reflect what the wallet actually spends and receives.
+fn ensure_pset_ownership(
+    pset: &PartiallySignedTransaction,
+    fingerprint: Fingerprint,
+    wallet_utxos: &HashSet<OutPoint>,
+    recipient_vouts: &HashSet<u32>,
+) -> Result<(), PaymentError> {
+    let has_wallet_key = |derivations: &BTreeMap<_, (Fingerprint, _)>| {
+        derivations.values().any(|(f, _)| *f == fingerprint)
+    };
+    for (index, input) in pset.inputs().iter().enumerate() {
+        let outpoint = OutPoint::new(input.previous_txid, input.previous_output_index);
+        ensure_sdk!(
+            !has_wallet_key(&input.bip32_derivation) || wallet_utxos.contains(&outpoint),
+            PaymentError::generic(&format!(
+                "Input {index} is signed by the wallet but doesn't spend a wallet utxo"
+            ))
+        );
+    }
+    for (vout, output) in pset.outputs().iter().enumerate() {
+        ensure_sdk!(
+            !has_wallet_key(&output.bip32_derivation) || !recipient_vouts.contains(&(vout as u32)),
+            PaymentError::generic(&format!(
+                "Output {vout} claims to pay to the wallet but isn't recognized by it"
+            ))
+        );
+    }
+    Ok(())
+}
+
 #[sdk_macros::async_trait]
 impl OnchainWallet for LiquidOnchainWallet {
     /// List all transactions in the wallet
     async fn transactions(&self) -> Result<Vec<WalletTx>, PaymentError> {
-        let wallet = self.wallet.lock().await;
-        wallet.transactions().map_err(|e| PaymentError::Generic {
+        let map_err = |e: lwk_wollet::Error| PaymentError::Generic {
             err: format!("Failed to fetch wallet transactions: {e:?}"),
-        })
+        };
+        let mut transactions = self.wallet.lock().await.transactions().map_err(map_err)?;
+        if let Some(amp_wallet) = self.amp_wallet().await?.as_ref() {
+            let amp_transactions = amp_wallet.transactions().map_err(map_err)?;
+            merge_wallet_transactions(&mut transactions, amp_transactions);
+        }
+        Ok(transactions)
     }
 
     /// List all transactions in the wallet mapped by tx id
@@ -389,6 +594,34 @@ impl OnchainWallet for LiquidOnchainWallet {
         Ok(lwk_wollet.finalize(&mut pset)?)
     }
 
+    async fn build_lbtc_batch_tx(
+        &self,
+        fee_rate_sats_per_kvb: Option<f32>,
+        recipients: &[(String, u64)],
+    ) -> Result<Transaction, PaymentError> {
+        let lwk_wollet = self.wallet.lock().await;
+        let mut tx_builder = lwk_wollet::TxBuilder::new(self.config.network.into())
+            .fee_rate(fee_rate_sats_per_kvb)
+            .enable_ct_discount();
+        for (recipient_address, amount_sat) in recipients {
+            let address = ElementsAddress::from_str(recipient_address).map_err(|e| {
+                PaymentError::Generic {
+                    err: format!(
+                        "Recipient address {recipient_address} is not a valid ElementsAddress: {e:?}"
+                    ),
+                }
+            })?;
+            tx_builder = tx_builder.add_lbtc_recipient(&address, *amount_sat)?;
+        }
+        let mut pset = tx_builder.finish(&lwk_wollet)?;
+        self.signer
+            .sign(&mut pset)
+            .map_err(|e| PaymentError::Generic {
+                err: format!("Failed to sign transaction: {e:?}"),
+            })?;
+        Ok(lwk_wollet.finalize(&mut pset)?)
+    }
+
     async fn build_drain_tx(
         &self,
         fee_rate_sats_per_kvb: Option<f32>,
@@ -462,30 +695,85 @@ impl OnchainWallet for LiquidOnchainWallet {
         }
     }
 
+    async fn build_sweep_tx(
+        &self,
+        key: &SweepKey,
+        fee_rate_sats_per_kvb: Option<f32>,
+        recipient_address: &str,
+    ) -> Result<SweepTx, PaymentError> {
+        let address =
+            ElementsAddress::from_str(recipient_address).map_err(|e| PaymentError::Generic {
+                err: format!(
+                    "Recipient address {recipient_address} is not a valid ElementsAddress: {e:?}"
+                ),
+            })?;
+
+        // The funds of the key are scanned with a separate, in-memory wallet
+        let mut sweep_wollet =
+            Wollet::without_persist(self.config.network.into(), key.descriptor().clone())?;
+        WalletClient::from_config(&self.config)?
+            .full_scan_to_index(&mut sweep_wollet, 0)
+            .await?;
+        let utxos = sweep_wollet.utxos()?;
+        ensure_sdk!(
+            !utxos.is_empty(),
+            PaymentError::generic("No funds found for the private key")
+        );
+
+        // The other assets are swept in full, with the network fee paid by the swept L-BTC
+        let policy_asset = sweep_wollet.policy_asset();
+        let mut asset_amounts: BTreeMap<AssetId, u64> = BTreeMap::new();
+        for utxo in utxos
+            .iter()
+            .filter(|utxo| utxo.unblinded.asset != policy_asset)
+        {
+            *asset_amounts.entry(utxo.unblinded.asset).or_default() += utxo.unblinded.value;
+        }
+        ensure_sdk!(
+            utxos
+                .iter()
+                .any(|utxo| utxo.unblinded.asset == policy_asset),
+            PaymentError::generic("No L-BTC found for the private key to pay the network fee")
+        );
+
+        let mut tx_builder = sweep_wollet
+            .tx_builder()
+            .drain_lbtc_wallet()
+            .drain_lbtc_to(address.clone())
+            .fee_rate(fee_rate_sats_per_kvb)
+            .enable_ct_discount();
+        for (asset, amount_sat) in &asset_amounts {
+            tx_builder = tx_builder.add_recipient(&address, *amount_sat, *asset)?;
+        }
+        let mut pset = tx_builder.finish()?;
+        let pset_details = sweep_wollet.get_details(&pset)?;
+        let spent_sat = pset_details
+            .balance
+            .balances
+            .get(&sweep_wollet.policy_asset())
+            .map(|balance| balance.unsigned_abs())
+            .unwrap_or_default();
+        let fees_sat = pset_details.balance.fee;
+
+        let signatures_added = key.sign(&mut pset)?;
+        ensure_sdk!(
+            signatures_added > 0,
+            PaymentError::generic("Failed to sign the sweep transaction")
+        );
+        Ok(SweepTx {
+            tx: sweep_wollet.finalize(&mut pset)?,
+            amount_sat: spent_sat.saturating_sub(fees_sat),
+            asset_amounts,
+            fees_sat,
+        })
+    }
+
     async fn sign_pset(
         &self,
         mut pset: PartiallySignedTransaction,
     ) -> Result<Transaction, PaymentError> {
         let lwk_wollet = self.wallet.lock().await;
-
-        // Get the tx_out for each input and add the rangeproof/witness utxo
-        for input in pset.inputs_mut().iter_mut() {
-            let tx_out_res = self
-                .get_txout(
-                    &lwk_wollet,
-                    &OutPoint {
-                        txid: input.previous_txid,
-                        vout: input.previous_output_index,
-                    },
-                )
-                .await;
-            if let Ok(mut tx_out) = tx_out_res {
-                input.in_utxo_rangeproof = tx_out.witness.rangeproof.take();
-                input.witness_utxo = Some(tx_out);
-            }
-        }
-
-        lwk_wollet.add_details(&mut pset)?;
+        self.add_pset_details(&lwk_wollet, &mut pset).await?;
 
         self.signer
             .sign(&mut pset)
@@ -506,6 +794,58 @@ impl OnchainWallet for LiquidOnchainWallet {
         Ok(tx)
     }
 
+    async fn pset_balance(
+        &self,
+        pset: &PartiallySignedTransaction,
+    ) -> Result<PsetWalletBalance, PaymentError> {
+        let lwk_wollet = self.wallet.lock().await;
+        let mut pset = pset.clone();
+        self.add_pset_details(&lwk_wollet, &mut pset).await?;
+        let balance = lwk_wollet.get_details(&pset)?.balance;
+        Ok(PsetWalletBalance {
+            fee_sat: balance.fee,
+            balances: balance.balances.into_iter().collect(),
+        })
+    }
+
+    async fn sign_pset_inputs(
+        &self,
+        mut pset: PartiallySignedTransaction,
+    ) -> Result<PartiallySignedTransaction, PaymentError> {
+        let lwk_wollet = self.wallet.lock().await;
+        self.add_pset_details(&lwk_wollet, &mut pset).await?;
+
+        let wallet_utxos = lwk_wollet
+            .utxos()?
+            .into_iter()
+            .map(|utxo| utxo.outpoint)
+            .collect();
+        let recipient_vouts = lwk_wollet
+            .get_details(&pset)?
+            .balance
+            .recipients
+            .iter()
+            .map(|recipient| recipient.vout)
+            .collect();
+        let fingerprint = self
+            .signer
+            .fingerprint()
+            .map_err(|e| PaymentError::SignerError { err: e.to_string() })?;
+        ensure_pset_ownership(&pset, fingerprint, &wallet_utxos, &recipient_vouts)?;
+
+        let signatures_added = self
+            .signer
+            .sign(&mut pset)
+            .map_err(|e| PaymentError::Generic {
+                err: format!("Failed to sign transaction: {e:?}"),
+            })?;
+        ensure_sdk!(
+            signatures_added > 0,
+            PaymentError::generic("The transaction has no wallet inputs to sign")
+        );
+        Ok(pset)
+    }
+
     /// Get the next unused address in the wallet
     async fn next_unused_address(&self) -> Result<Address, PaymentError> {
         let tip = self.tip().await;
@@ -544,6 +884,18 @@ impl OnchainWallet for LiquidOnchainWallet {
         Ok(address)
     }
 
+    /// Get the next unused address of the registered AMP account
+    async fn next_unused_amp_address(&self) -> Result<Address, PaymentError> {
+        let amp_wallet = self.amp_wallet().await?;
+        let amp_wallet = amp_wallet.as_ref().ok_or(PaymentError::Generic {
+            err: "No AMP account registered".to_string(),
+        })?;
+        let address = amp_wallet.address(None)?.address().clone();
+        debug!("Got unused AMP address {address}");
+
+        Ok(address)
+    }
+
     /// Get the current tip of the blockchain the wallet is aware of
     async fn tip(&self) -> u32 {
         self.wallet.lock().await.tip().height()
@@ -560,6 +912,7 @@ impl OnchainWallet for LiquidOnchainWallet {
     }
 
     /// Perform a full scan of the wallet
+    #[tracing::instrument(skip_all)]
     async fn full_scan(&self) -> Result<(), PaymentError> {
         let full_scan_started = Instant::now();
 
@@ -605,6 +958,12 @@ impl OnchainWallet for LiquidOnchainWallet {
         self.persister
             .set_last_scanned_derivation_index(last_derivation_index)?;
 
+        if res.is_ok() {
+            if let Some(amp_wallet) = self.amp_wallet().await?.as_mut() {
+                client.full_scan_to_index(amp_wallet, 0).await?;
+            }
+        }
+
         let duration_ms = Instant::now().duration_since(full_scan_started).as_millis();
         info!("lwk wallet full_scan duration: ({duration_ms} ms)");
         res
@@ -631,15 +990,123 @@ impl OnchainWallet for LiquidOnchainWallet {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::Config;
+    use crate::model::{AmpAccount, Config};
     use crate::signer::SdkSigner;
     use crate::test_utils::persist::create_persister;
+    use crate::wallet::persister::LwkPersister;
     use crate::wallet::LiquidOnchainWallet;
     use anyhow::Result;
 
     #[cfg(feature = "browser-tests")]
     wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 
+    #[sdk_macros::test_all]
+    fn test_account_xpub() -> Result<()> {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let sdk_signer: Box<dyn Signer> = Box::new(SdkSigner::new(mnemonic, "", false)?);
+        let signer = SdkLwkSigner::new(Arc::new(sdk_signer))?;
+
+        let descriptor = get_descriptor(&signer, LiquidNetwork::Testnet)?.to_string();
+        let xpub = get_account_xpub(&signer, LiquidNetwork::Testnet)?;
+        assert!(descriptor.contains(&format!("[{}/", signer.fingerprint()?)));
+        assert!(descriptor.contains(&format!("]{xpub}/")));
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_merge_wallet_transactions() -> Result<()> {
+        let wallet_tx = |txid: &str, balance: Vec<(AssetId, i64)>| WalletTx {
+            txid: Txid::from_str(txid).unwrap(),
+            tx: Transaction {
+                version: 2,
+                lock_time: lwk_wollet::elements::LockTime::ZERO,
+                input: vec![],
+                output: vec![],
+            },
+            height: None,
+            fee: 100,
+            timestamp: None,
+            balance: balance.into_iter().collect(),
+            outputs: vec![],
+            inputs: vec![],
+            type_: "".to_string(),
+        };
+        let lbtc = AssetId::from_slice(&[0; 32])?;
+        let amp_asset = AssetId::from_slice(&[1; 32])?;
+        let shared_txid = "0000000000000000000000000000000000000000000000000000000000000001";
+        let amp_txid = "0000000000000000000000000000000000000000000000000000000000000002";
+
+        let mut transactions = vec![wallet_tx(shared_txid, vec![(lbtc, -1_100)])];
+        merge_wallet_transactions(
+            &mut transactions,
+            vec![
+                wallet_tx(shared_txid, vec![(amp_asset, 500)]),
+                wallet_tx(amp_txid, vec![(amp_asset, 200)]),
+            ],
+        );
+
+        assert_eq!(transactions.len(), 2);
+        assert_eq!(transactions[0].balance.get(&lbtc), Some(&-1_100));
+        assert_eq!(transactions[0].balance.get(&amp_asset), Some(&500));
+        assert_eq!(transactions[1].txid, Txid::from_str(amp_txid)?);
+        assert_eq!(transactions[1].balance.get(&amp_asset), Some(&200));
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_ensure_pset_ownership() -> Result<()> {
+        use lwk_wollet::elements::bitcoin::PublicKey;
+        use lwk_wollet::elements::pset::{Input, Output};
+
+        let fingerprint = Fingerprint::from([1; 4]);
+        let other_fingerprint = Fingerprint::from([2; 4]);
+        let public_key = PublicKey::from_str(
+            "02a1633cafcc01ebfb6d78e39f687a1f0995c62fc95f51ead10a02ee0be551b5dc",
+        )?;
+        let wallet_outpoint = OutPoint::new(
+            Txid::from_str("0000000000000000000000000000000000000000000000000000000000000001")?,
+            0,
+        );
+        let other_outpoint = OutPoint::new(
+            Txid::from_str("0000000000000000000000000000000000000000000000000000000000000002")?,
+            0,
+        );
+        let new_pset = |outpoint, input_fingerprint, output_fingerprint| {
+            let mut pset = PartiallySignedTransaction::new_v2();
+            let mut input = Input::from_prevout(outpoint);
+            input
+                .bip32_derivation
+                .insert(public_key, (input_fingerprint, DerivationPath::master()));
+            pset.add_input(input);
+            let mut output = Output::default();
+            output
+                .bip32_derivation
+                .insert(public_key, (output_fingerprint, DerivationPath::master()));
+            pset.add_output(output);
+            pset
+        };
+        let wallet_utxos = HashSet::from([wallet_outpoint]);
+        let no_recipients = HashSet::new();
+
+        let pset = new_pset(wallet_outpoint, fingerprint, fingerprint);
+        assert!(ensure_pset_ownership(&pset, fingerprint, &wallet_utxos, &no_recipients).is_ok());
+
+        // The wallet doesn't sign an input that doesn't spend one of its utxos
+        let pset = new_pset(other_outpoint, fingerprint, fingerprint);
+        assert!(ensure_pset_ownership(&pset, fingerprint, &wallet_utxos, &no_recipients).is_err());
+        let pset = new_pset(other_outpoint, other_fingerprint, fingerprint);
+        assert!(ensure_pset_ownership(&pset, fingerprint, &wallet_utxos, &no_recipients).is_ok());
+
+        // An output claiming to pay to the wallet must be recognized by it
+        let pset = new_pset(wallet_outpoint, fingerprint, fingerprint);
+        let recipients = HashSet::from([0]);
+        assert!(ensure_pset_ownership(&pset, fingerprint, &wallet_utxos, &recipients).is_err());
+        let pset = new_pset(wallet_outpoint, fingerprint, other_fingerprint);
+        assert!(ensure_pset_ownership(&pset, fingerprint, &wallet_utxos, &recipients).is_ok());
+
+        Ok(())
+    }
+
     #[sdk_macros::async_test_all]
     async fn test_sign_and_check_message() -> Result<()> {
         let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
@@ -720,4 +1187,68 @@ mod tests {
         // The temporary directory will be automatically deleted when temp_dir goes out of scope
         Ok(())
     }
+
+    /// Records the descriptors of the wallets it is asked to persist
+    #[derive(Default)]
+    struct RecordingWalletCachePersister {
+        descriptors: std::sync::Mutex<Vec<String>>,
+    }
+
+    #[sdk_macros::async_trait]
+    impl WalletCachePersister for RecordingWalletCachePersister {
+        fn get_lwk_persister(&self) -> LwkPersister {
+            lwk_wollet::NoPersist::new()
+        }
+
+        fn get_descriptor_lwk_persister(
+            &self,
+            descriptor: &WolletDescriptor,
+        ) -> anyhow::Result<LwkPersister> {
+            self.descriptors
+                .lock()
+                .unwrap()
+                .push(descriptor.to_string());
+            Ok(lwk_wollet::NoPersist::new())
+        }
+
+        async fn clear_cache(&self) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[sdk_macros::async_test_all]
+    async fn test_amp_wallet_is_persisted() -> Result<()> {
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let sdk_signer: Box<dyn Signer> = Box::new(SdkSigner::new(mnemonic, "", false)?);
+        let config = Config::testnet_esplora(None);
+        create_persister!(storage);
+        let wallet_cache_persister = Arc::new(RecordingWalletCachePersister::default());
+        let wallet = LiquidOnchainWallet::new_with_cache_persister(
+            config.clone(),
+            storage.clone(),
+            Arc::new(sdk_signer),
+            wallet_cache_persister.clone(),
+        )
+        .await?;
+        assert!(wallet.amp_wallet().await?.is_none());
+
+        let server_xpub = get_account_xpub(&wallet.signer, config.network)?;
+        let user_key = get_amp_account_key(&wallet.signer, config.network)?;
+        let descriptor = format!(
+            "ct(slip77(9c8e4f05c7711a98c838be228bcb84924d4570ca53f35fa1c793e58841d47023),elwsh(multi(2,{server_xpub}/<0;1>/*,{user_key}/<0;1>/*)))"
+        );
+        storage.set_amp_account(&AmpAccount {
+            amp_id: "GA2zxWdhAYtREeYCVFTGRhHQmYMPAP".to_string(),
+            descriptor: descriptor.clone(),
+        })?;
+
+        // The AMP wallet is loaded with its own persister, once
+        assert!(wallet.amp_wallet().await?.is_some());
+        assert!(wallet.amp_wallet().await?.is_some());
+        let descriptors = wallet_cache_persister.descriptors.lock().unwrap().clone();
+        assert_eq!(descriptors.len(), 1);
+        assert!(descriptors[0].starts_with(&descriptor));
+
+        Ok(())
+    }
 }
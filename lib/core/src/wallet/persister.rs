@@ -1,5 +1,5 @@
 use log::warn;
-use lwk_wollet::{ElementsNetwork, FsPersister, NoPersist};
+use lwk_wollet::{ElementsNetwork, FsPersister, NoPersist, WolletDescriptor};
 use maybe_sync::{MaybeSend, MaybeSync};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -12,6 +12,15 @@ pub type LwkPersister = std::sync::Arc<dyn lwk_wollet::Persister + Send + Sync>;
 pub trait WalletCachePersister: MaybeSend + MaybeSync {
     fn get_lwk_persister(&self) -> LwkPersister;
 
+    /// Returns the persister of the wallet of another `descriptor`, e.g. the AMP account.
+    /// By default the wallet is not persisted, so it is scanned from scratch on every start.
+    fn get_descriptor_lwk_persister(
+        &self,
+        _descriptor: &WolletDescriptor,
+    ) -> anyhow::Result<LwkPersister> {
+        Ok(NoPersist::new())
+    }
+
     async fn clear_cache(&self) -> anyhow::Result<()>;
 }
 
@@ -47,6 +56,17 @@ impl WalletCachePersister for FsWalletCachePersister {
         self.persister.clone()
     }
 
+    fn get_descriptor_lwk_persister(
+        &self,
+        descriptor: &WolletDescriptor,
+    ) -> anyhow::Result<LwkPersister> {
+        Ok(FsPersister::new(
+            &self.working_dir,
+            self.elements_network,
+            descriptor,
+        )?)
+    }
+
     async fn clear_cache(&self) -> anyhow::Result<()> {
         let mut path = std::path::PathBuf::from(&self.working_dir);
         path.push(self.elements_network.as_str());
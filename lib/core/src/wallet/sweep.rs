@@ -0,0 +1,182 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use anyhow::{anyhow, ensure, Result};
+use lwk_wollet::{
+    bitcoin::{secp256k1::Secp256k1, NetworkKind, PrivateKey, PublicKey},
+    elements::{
+        pset::PartiallySignedTransaction, sighash::SighashCache, Address, AddressParams, AssetId,
+        EcdsaSighashType, Transaction,
+    },
+    secp256k1::Message,
+    WolletDescriptor,
+};
+
+use crate::model::LiquidNetwork;
+
+/// The wrapper of the single key descriptors that can be swept
+const WPKH_PREFIX: &str = "elwpkh(";
+
+/// A private key controlling funds outside of the wallet, e.g. from a paper wallet.
+///
+/// It is either a WIF encoded key, whose outputs are blinded with the
+/// [ELIP151](https://github.com/ElementsProject/ELIPs/blob/main/elip-0151.mediawiki) blinding key,
+/// or a confidential descriptor with a WIF encoded key, e.g. `ct(<blinding key>,elwpkh(<WIF>))`.
+pub struct SweepKey {
+    private_key: PrivateKey,
+    descriptor: WolletDescriptor,
+}
+
+/// A transaction sweeping the funds of a [SweepKey] into the wallet
+pub struct SweepTx {
+    pub tx: Transaction,
+    /// The amount of L-BTC received by the wallet
+    pub amount_sat: u64,
+    /// The amounts of the other assets received by the wallet
+    pub asset_amounts: BTreeMap<AssetId, u64>,
+    /// The network fee, paid by the swept funds
+    pub fees_sat: u64,
+}
+
+impl SweepKey {
+    pub(crate) fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        let (private_key, descriptor) = match PrivateKey::from_wif(input) {
+            Ok(private_key) => {
+                let public_key = Self::public_key(&private_key)?;
+                (private_key, format!("ct(elip151,elwpkh({public_key}))"))
+            }
+            Err(_) => {
+                // Strip the checksum, as it changes once the private key is replaced
+                let descriptor = input.split('#').next().unwrap_or(input);
+                let start = descriptor
+                    .find(WPKH_PREFIX)
+                    .ok_or(anyhow!("Expected a WIF key or an elwpkh descriptor"))?
+                    + WPKH_PREFIX.len();
+                let end = descriptor[start..]
+                    .find(')')
+                    .ok_or(anyhow!("Invalid descriptor"))?
+                    + start;
+                let private_key = PrivateKey::from_wif(&descriptor[start..end])
+                    .map_err(|e| anyhow!("Descriptor key is not a WIF private key: {e}"))?;
+                let public_key = Self::public_key(&private_key)?;
+                let descriptor =
+                    format!("{}{public_key}{}", &descriptor[..start], &descriptor[end..]);
+                (private_key, descriptor)
+            }
+        };
+        Ok(Self {
+            private_key,
+            descriptor: WolletDescriptor::from_str(&descriptor)
+                .map_err(|e| anyhow!("Invalid descriptor: {e}"))?,
+        })
+    }
+
+    fn public_key(private_key: &PrivateKey) -> Result<PublicKey> {
+        ensure!(
+            private_key.compressed,
+            "Uncompressed private keys are not supported"
+        );
+        Ok(private_key.public_key(&Secp256k1::new()))
+    }
+
+    /// Whether the key is encoded for the `network`. Liquid keys share the WIF prefixes of
+    /// the Bitcoin mainnet and testnet keys.
+    pub(crate) fn is_for_network(&self, network: LiquidNetwork) -> bool {
+        let network_kind = match network {
+            LiquidNetwork::Mainnet => NetworkKind::Main,
+            LiquidNetwork::Testnet | LiquidNetwork::Regtest => NetworkKind::Test,
+        };
+        self.private_key.network == network_kind
+    }
+
+    /// The watch-only descriptor of the funds controlled by the key
+    pub(crate) fn descriptor(&self) -> &WolletDescriptor {
+        &self.descriptor
+    }
+
+    /// Adds a signature to the inputs spending outputs of the key, returning the number of
+    /// signatures added
+    pub(crate) fn sign(&self, pset: &mut PartiallySignedTransaction) -> Result<u32> {
+        let secp = Secp256k1::new();
+        let public_key = self.private_key.public_key(&secp);
+        let script_pubkey =
+            Address::p2wpkh(&public_key, None, &AddressParams::LIQUID).script_pubkey();
+        let script_code = Address::p2pkh(&public_key, None, &AddressParams::LIQUID).script_pubkey();
+
+        let tx = pset.extract_tx()?;
+        let mut sighash_cache = SighashCache::new(&tx);
+        let mut signatures_added = 0;
+        for (index, input) in pset.inputs_mut().iter_mut().enumerate() {
+            let Some(witness_utxo) = &input.witness_utxo else {
+                continue;
+            };
+            if witness_utxo.script_pubkey != script_pubkey {
+                continue;
+            }
+            let sighash = sighash_cache.segwitv0_sighash(
+                index,
+                &script_code,
+                witness_utxo.value,
+                EcdsaSighashType::All,
+            );
+            let message = Message::from_digest_slice(&sighash[..])?;
+            let signature = secp.sign_ecdsa_low_r(&message, &self.private_key.inner);
+            let mut signature = signature.serialize_der().to_vec();
+            signature.push(EcdsaSighashType::All as u8);
+            input.partial_sigs.insert(public_key, signature);
+            signatures_added += 1;
+        }
+        Ok(signatures_added)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use super::SweepKey;
+    use crate::model::LiquidNetwork;
+
+    #[cfg(feature = "browser-tests")]
+    wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
+
+    /// The WIF of the private key 1, whose public key is the generator point
+    const TEST_WIF: &str = "KwDiBf89QgGbjEhKnhXJuH7LrciVrZi3qYjgd9M7rFU73sVHnoWn";
+    const TEST_PUBKEY: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+    /// The testnet WIF of the private key 1
+    const TEST_TESTNET_WIF: &str = "cMahea7zqjxrtgAbB7LSGbcQUr1uX1ojuat9jZodMN87JcbXMTcA";
+
+    #[sdk_macros::test_all]
+    fn test_parse_sweep_key() -> Result<()> {
+        let key = SweepKey::parse(TEST_WIF)?;
+        assert!(key
+            .descriptor()
+            .to_string()
+            .starts_with(&format!("ct(elip151,elwpkh({TEST_PUBKEY}))")));
+
+        let blinding_key = "c25deb86fa11e49d651d7eae27c220ef930fbd86ea023eebfa73e54875647963";
+        let key = SweepKey::parse(&format!("ct({blinding_key},elwpkh({TEST_WIF}))#checksum"))?;
+        assert!(key
+            .descriptor()
+            .to_string()
+            .starts_with(&format!("ct({blinding_key},elwpkh({TEST_PUBKEY}))")));
+
+        assert!(SweepKey::parse("not a key").is_err());
+        assert!(SweepKey::parse(&format!("ct(elip151,elwpkh({TEST_PUBKEY}))")).is_err());
+        Ok(())
+    }
+
+    #[sdk_macros::test_all]
+    fn test_sweep_key_network() -> Result<()> {
+        let key = SweepKey::parse(TEST_WIF)?;
+        assert!(key.is_for_network(LiquidNetwork::Mainnet));
+        assert!(!key.is_for_network(LiquidNetwork::Testnet));
+        assert!(!key.is_for_network(LiquidNetwork::Regtest));
+
+        let key = SweepKey::parse(&format!("ct(elip151,elwpkh({TEST_TESTNET_WIF}))"))?;
+        assert!(!key.is_for_network(LiquidNetwork::Mainnet));
+        assert!(key.is_for_network(LiquidNetwork::Testnet));
+        assert!(key.is_for_network(LiquidNetwork::Regtest));
+        Ok(())
+    }
+}
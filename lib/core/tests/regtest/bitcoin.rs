@@ -23,6 +23,8 @@ async fn bitcoin() {
         .receive_payment(&PrepareReceiveRequest {
             payment_method: PaymentMethod::BitcoinAddress,
             amount: Some(ReceiveAmount::Bitcoin { payer_amount_sat }),
+            fiat_amount: None,
+            expiry_secs: None,
         })
         .await
         .unwrap();
@@ -98,6 +100,7 @@ async fn bitcoin() {
                     receiver_amount_sat,
                 },
                 fee_rate_sat_per_vbyte: None,
+                fee_speed: None,
             },
             address,
         )
@@ -156,6 +159,8 @@ async fn bitcoin() {
         .receive_payment(&PrepareReceiveRequest {
             payment_method: PaymentMethod::BitcoinAddress,
             amount: Some(ReceiveAmount::Bitcoin { payer_amount_sat }),
+            fiat_amount: None,
+            expiry_secs: None,
         })
         .await
         .unwrap();
@@ -189,7 +194,8 @@ async fn bitcoin() {
         .refund(&RefundRequest {
             swap_address: address.to_string(),
             refund_address: refund_address.clone(),
-            fee_rate_sat_per_vbyte: refund_fee_rate,
+            fee_rate_sat_per_vbyte: Some(refund_fee_rate),
+            fee_speed: None,
         })
         .await
         .unwrap();
@@ -218,7 +224,8 @@ async fn bitcoin() {
         .prepare_refund(&PrepareRefundRequest {
             swap_address: address.to_string(),
             refund_address: refund_address.clone(),
-            fee_rate_sat_per_vbyte: refund_rbf_fee_rate,
+            fee_rate_sat_per_vbyte: Some(refund_rbf_fee_rate),
+            fee_speed: None,
         })
         .await
         .unwrap();
@@ -228,7 +235,8 @@ async fn bitcoin() {
         .refund(&RefundRequest {
             swap_address: address.to_string(),
             refund_address,
-            fee_rate_sat_per_vbyte: refund_rbf_fee_rate,
+            fee_rate_sat_per_vbyte: Some(refund_rbf_fee_rate),
+            fee_speed: None,
         })
         .await
         .unwrap();
@@ -27,6 +27,8 @@ async fn bolt11() {
             amount: Some(breez_sdk_liquid::model::ReceiveAmount::Bitcoin {
                 payer_amount_sat: payer_amount_sat,
             }),
+            fiat_amount: None,
+            expiry_secs: None,
         })
         .await
         .unwrap();
@@ -88,6 +90,7 @@ async fn bolt11() {
         .send_payment(&PrepareSendRequest {
             destination: invoice,
             amount: None,
+            fiat_amount: None,
         })
         .await
         .unwrap();
@@ -146,6 +149,8 @@ async fn bolt11() {
             amount: Some(breez_sdk_liquid::model::ReceiveAmount::Bitcoin {
                 payer_amount_sat: receiver_amount_sat,
             }),
+            fiat_amount: None,
+            expiry_secs: None,
         })
         .await
         .unwrap();
@@ -155,6 +160,7 @@ async fn bolt11() {
         .send_payment(&PrepareSendRequest {
             destination: invoice,
             amount: None,
+            fiat_amount: None,
         })
         .await
         .unwrap();
@@ -20,6 +20,8 @@ async fn liquid() {
         .receive_payment(&PrepareReceiveRequest {
             payment_method: PaymentMethod::LiquidAddress,
             amount: None,
+            fiat_amount: None,
+            expiry_secs: None,
         })
         .await
         .unwrap();
@@ -76,6 +78,7 @@ async fn liquid() {
             amount: Some(PayAmount::Bitcoin {
                 receiver_amount_sat,
             }),
+            fiat_amount: None,
         })
         .await
         .unwrap();
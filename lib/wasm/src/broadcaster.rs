@@ -0,0 +1,59 @@
+use breez_sdk_liquid::prelude::BroadcasterError;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+
+pub struct WasmBroadcaster {
+    pub broadcaster: Broadcaster,
+}
+
+impl WasmBroadcaster {
+    async fn resolve_tx_id(
+        promise: Result<js_sys::Promise, js_sys::Error>,
+    ) -> Result<String, BroadcasterError> {
+        let tx_id = match promise {
+            Ok(promise) => JsFuture::from(promise).await,
+            Err(e) => Err(e.into()),
+        }
+        .map_err(|e| BroadcasterError::Generic {
+            err: js_sys::Error::from(e).to_string().into(),
+        })?;
+        tx_id.as_string().ok_or(BroadcasterError::Generic {
+            err: "Broadcaster did not return a txid".to_string(),
+        })
+    }
+}
+
+#[sdk_macros::async_trait]
+impl breez_sdk_liquid::prelude::Broadcaster for WasmBroadcaster {
+    async fn broadcast_liquid_tx(&self, tx_hex: String) -> Result<String, BroadcasterError> {
+        Self::resolve_tx_id(self.broadcaster.broadcast_liquid_tx(tx_hex)).await
+    }
+
+    async fn broadcast_bitcoin_tx(&self, tx_hex: String) -> Result<String, BroadcasterError> {
+        Self::resolve_tx_id(self.broadcaster.broadcast_bitcoin_tx(tx_hex)).await
+    }
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const BROADCASTER_INTERFACE: &'static str = r#"export interface Broadcaster {
+    broadcastLiquidTx: (txHex: string) => Promise<string>;
+    broadcastBitcoinTx: (txHex: string) => Promise<string>;
+}"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "Broadcaster")]
+    pub type Broadcaster;
+
+    #[wasm_bindgen(structural, catch, method, js_name = broadcastLiquidTx)]
+    fn broadcast_liquid_tx(
+        this: &Broadcaster,
+        tx_hex: String,
+    ) -> Result<js_sys::Promise, js_sys::Error>;
+
+    #[wasm_bindgen(structural, catch, method, js_name = broadcastBitcoinTx)]
+    fn broadcast_bitcoin_tx(
+        this: &Broadcaster,
+        tx_hex: String,
+    ) -> Result<js_sys::Promise, js_sys::Error>;
+}
@@ -0,0 +1,35 @@
+use breez_sdk_liquid::prelude::FiatRateProviderError;
+use wasm_bindgen::prelude::*;
+
+use crate::model::Rate;
+
+pub struct WasmFiatRateProvider {
+    pub provider: FiatRateProvider,
+}
+
+impl breez_sdk_liquid::prelude::FiatRateProvider for WasmFiatRateProvider {
+    fn fetch_fiat_rates(
+        &self,
+    ) -> Result<Vec<breez_sdk_liquid::prelude::Rate>, FiatRateProviderError> {
+        self.provider
+            .fetch_fiat_rates()
+            .map(|rates| rates.into_iter().map(Into::into).collect())
+            .map_err(|e| FiatRateProviderError::Generic {
+                err: e.to_string().into(),
+            })
+    }
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const FIAT_RATE_PROVIDER_INTERFACE: &'static str = r#"export interface FiatRateProvider {
+    fetchFiatRates: () => Rate[];
+}"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "FiatRateProvider")]
+    pub type FiatRateProvider;
+
+    #[wasm_bindgen(structural, catch, method, js_name = fetchFiatRates)]
+    fn fetch_fiat_rates(this: &FiatRateProvider) -> Result<Vec<Rate>, js_sys::Error>;
+}
@@ -0,0 +1,28 @@
+use wasm_bindgen::prelude::*;
+
+use crate::model::InputType;
+
+pub struct WasmInputParser {
+    pub parser: InputParser,
+}
+
+#[sdk_macros::async_trait]
+impl breez_sdk_liquid::prelude::InputParser for WasmInputParser {
+    async fn parse_input(&self, input: String) -> Option<breez_sdk_liquid::prelude::InputType> {
+        self.parser.parse_input(input).map(Into::into)
+    }
+}
+
+#[wasm_bindgen(typescript_custom_section)]
+const INPUT_PARSER_INTERFACE: &'static str = r#"export interface InputParser {
+    parseInput: (input: string) => InputType | undefined;
+}"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "InputParser")]
+    pub type InputParser;
+
+    #[wasm_bindgen(structural, method, js_name = parseInput)]
+    fn parse_input(this: &InputParser, input: String) -> Option<InputType>;
+}
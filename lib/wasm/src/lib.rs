@@ -1,5 +1,8 @@
+mod broadcaster;
 mod error;
 mod event;
+mod fiat;
+mod input_parser;
 mod logger;
 pub mod model;
 mod platform;
@@ -9,7 +12,10 @@ use std::path::PathBuf;
 use std::rc::Rc;
 use std::str::FromStr;
 
+use crate::broadcaster::{Broadcaster, WasmBroadcaster};
 use crate::event::{EventListener, WasmEventListener};
+use crate::fiat::{FiatRateProvider, WasmFiatRateProvider};
+use crate::input_parser::{InputParser, WasmInputParser};
 use crate::model::*;
 
 use anyhow::anyhow;
@@ -101,9 +107,9 @@ async fn connect_inner(
     sdk.start().await?;
 
     if let Some(db_backup_persister) = maybe_db_backup_persister {
-        let (sender, receiver) = tokio::sync::mpsc::channel(20);
+        let (sender, receiver) = tokio::sync::mpsc::channel(100);
         let listener = platform::db_backup_common::ForwardingEventListener::new(sender);
-        sdk.add_event_listener(Box::new(listener)).await?;
+        sdk.add_event_listener(Box::new(listener), None).await?;
 
         db_backup_persister.start_backup_task(persister, receiver);
     }
@@ -127,6 +133,34 @@ pub fn parse_invoice(input: String) -> WasmResult<LNInvoice> {
     Ok(LiquidSdk::parse_invoice(&input)?.into())
 }
 
+#[wasm_bindgen(js_name = "decodeInvoice")]
+pub fn decode_invoice(input: String) -> WasmResult<DecodedInvoice> {
+    Ok(LiquidSdk::decode_invoice(&input)?.into())
+}
+
+#[wasm_bindgen(js_name = "validateAddress")]
+pub fn validate_address(
+    address: String,
+    network: LiquidNetwork,
+) -> WasmResult<ValidateAddressResponse> {
+    Ok(LiquidSdk::validate_address(&address, network.into())?.into())
+}
+
+#[wasm_bindgen(js_name = "generateMnemonic")]
+pub fn generate_mnemonic(word_count: u32) -> WasmResult<String> {
+    Ok(LiquidSdk::generate_mnemonic(word_count)?)
+}
+
+#[wasm_bindgen(js_name = "validateMnemonic")]
+pub fn validate_mnemonic(phrase: String) -> WasmResult<()> {
+    Ok(LiquidSdk::validate_mnemonic(&phrase)?)
+}
+
+#[wasm_bindgen(js_name = "createSpendLimitOverride")]
+pub fn create_spend_limit_override(secret: String, expires_at: u32) -> String {
+    LiquidSdk::create_spend_limit_override(&secret, expires_at)
+}
+
 #[wasm_bindgen(js_name = "setLogger")]
 pub fn set_logger(logger: Logger) -> WasmResult<()> {
     crate::logger::WASM_LOGGER.set(Some(logger));
@@ -144,6 +178,26 @@ impl BindingLiquidSdk {
         Ok(self.sdk.get_info().await?.into())
     }
 
+    #[wasm_bindgen(js_name = "checkHealth")]
+    pub async fn check_health(&self) -> WasmResult<CheckHealthResponse> {
+        Ok(self.sdk.check_health().await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "getWalletInfo")]
+    pub fn get_wallet_info(&self) -> WasmResult<GetWalletInfoResponse> {
+        Ok(self.sdk.get_wallet_info()?.into())
+    }
+
+    #[wasm_bindgen(js_name = "registerAmpAccount")]
+    pub async fn register_amp_account(&self) -> WasmResult<AmpAccount> {
+        Ok(self.sdk.register_amp_account().await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "getAmpAccount")]
+    pub fn get_amp_account(&self) -> WasmResult<Option<AmpAccount>> {
+        Ok(self.sdk.get_amp_account()?.map(Into::into))
+    }
+
     #[wasm_bindgen(js_name = "signMessage")]
     pub fn sign_message(&self, req: SignMessageRequest) -> WasmResult<SignMessageResponse> {
         Ok(self.sdk.sign_message(&req.into())?.into())
@@ -154,16 +208,36 @@ impl BindingLiquidSdk {
         Ok(self.sdk.check_message(&req.into())?.into())
     }
 
+    #[wasm_bindgen(js_name = "signPset")]
+    pub async fn sign_pset(&self, req: SignPsetRequest) -> WasmResult<SignPsetResponse> {
+        Ok(self.sdk.sign_pset(&req.into()).await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "sweepPrivateKey")]
+    pub async fn sweep_private_key(
+        &self,
+        req: SweepPrivateKeyRequest,
+    ) -> WasmResult<SweepPrivateKeyResponse> {
+        Ok(self.sdk.sweep_private_key(&req.into()).await?.into())
+    }
+
     #[wasm_bindgen(js_name = "parse")]
     pub async fn parse(&self, input: String) -> WasmResult<InputType> {
         Ok(self.sdk.parse(&input).await?.into())
     }
 
     #[wasm_bindgen(js_name = "addEventListener")]
-    pub async fn add_event_listener(&self, listener: EventListener) -> WasmResult<String> {
+    pub async fn add_event_listener(
+        &self,
+        listener: EventListener,
+        filter: Option<EventFilter>,
+    ) -> WasmResult<String> {
         Ok(self
             .sdk
-            .add_event_listener(Box::new(WasmEventListener { listener }))
+            .add_event_listener(
+                Box::new(WasmEventListener { listener }),
+                filter.map(Into::into),
+            )
             .await?)
     }
 
@@ -173,6 +247,17 @@ impl BindingLiquidSdk {
         Ok(())
     }
 
+    #[wasm_bindgen(js_name = "replayEvents")]
+    pub async fn replay_events(&self, req: ReplayEventsRequest) -> WasmResult<Vec<JournaledEvent>> {
+        Ok(self
+            .sdk
+            .replay_events(&req.into())
+            .await?
+            .into_iter()
+            .map(|e| e.into())
+            .collect())
+    }
+
     #[wasm_bindgen(js_name = "prepareSendPayment")]
     pub async fn prepare_send_payment(
         &self,
@@ -186,6 +271,97 @@ impl BindingLiquidSdk {
         Ok(self.sdk.send_payment(&req.into()).await?.into())
     }
 
+    #[wasm_bindgen(js_name = "sendBatch")]
+    pub async fn send_batch(&self, req: SendBatchRequest) -> WasmResult<SendBatchResponse> {
+        Ok(self.sdk.send_batch(&req.into()).await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "queuePayment")]
+    pub async fn queue_payment(&self, req: QueuePaymentRequest) -> WasmResult<QueuedPayment> {
+        Ok(self.sdk.queue_payment(&req.into()).await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "listQueuedPayments")]
+    pub async fn list_queued_payments(&self) -> WasmResult<Vec<QueuedPayment>> {
+        Ok(self
+            .sdk
+            .list_queued_payments()
+            .await?
+            .into_iter()
+            .map(|p| p.into())
+            .collect())
+    }
+
+    #[wasm_bindgen(js_name = "cancelQueuedPayment")]
+    pub async fn cancel_queued_payment(&self, id: String) -> WasmResult<()> {
+        self.sdk.cancel_queued_payment(id).await?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "schedulePayment")]
+    pub async fn schedule_payment(
+        &self,
+        req: SchedulePaymentRequest,
+    ) -> WasmResult<ScheduledPayment> {
+        Ok(self.sdk.schedule_payment(&req.into()).await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "listScheduledPayments")]
+    pub async fn list_scheduled_payments(&self) -> WasmResult<Vec<ScheduledPayment>> {
+        Ok(self
+            .sdk
+            .list_scheduled_payments()
+            .await?
+            .into_iter()
+            .map(|p| p.into())
+            .collect())
+    }
+
+    #[wasm_bindgen(js_name = "cancelScheduledPayment")]
+    pub async fn cancel_scheduled_payment(&self, id: String) -> WasmResult<()> {
+        self.sdk.cancel_scheduled_payment(id).await?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "createRecurringPayment")]
+    pub async fn create_recurring_payment(
+        &self,
+        req: CreateRecurringPaymentRequest,
+    ) -> WasmResult<RecurringPayment> {
+        Ok(self.sdk.create_recurring_payment(&req.into()).await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "listRecurringPayments")]
+    pub async fn list_recurring_payments(&self) -> WasmResult<Vec<RecurringPayment>> {
+        Ok(self
+            .sdk
+            .list_recurring_payments()
+            .await?
+            .into_iter()
+            .map(|p| p.into())
+            .collect())
+    }
+
+    #[wasm_bindgen(js_name = "listRecurringPaymentOccurrences")]
+    pub async fn list_recurring_payment_occurrences(
+        &self,
+        recurring_payment_id: String,
+    ) -> WasmResult<Vec<RecurringPaymentOccurrence>> {
+        Ok(self
+            .sdk
+            .list_recurring_payment_occurrences(recurring_payment_id)
+            .await?
+            .into_iter()
+            .map(|o| o.into())
+            .collect())
+    }
+
+    #[wasm_bindgen(js_name = "cancelRecurringPayment")]
+    pub async fn cancel_recurring_payment(&self, id: String) -> WasmResult<()> {
+        self.sdk.cancel_recurring_payment(id).await?;
+        Ok(())
+    }
+
     #[wasm_bindgen(js_name = "prepareReceivePayment")]
     pub async fn prepare_receive_payment(
         &self,
@@ -194,6 +370,18 @@ impl BindingLiquidSdk {
         Ok(self.sdk.prepare_receive_payment(&req.into()).await?.into())
     }
 
+    #[wasm_bindgen(js_name = "prepareReceivePaymentQuotes")]
+    pub async fn prepare_receive_payment_quotes(
+        &self,
+        req: PrepareReceiveQuotesRequest,
+    ) -> WasmResult<PrepareReceiveQuotesResponse> {
+        Ok(self
+            .sdk
+            .prepare_receive_payment_quotes(&req.into())
+            .await?
+            .into())
+    }
+
     #[wasm_bindgen(js_name = "receivePayment")]
     pub async fn receive_payment(
         &self,
@@ -212,6 +400,27 @@ impl BindingLiquidSdk {
         Ok(self.sdk.fetch_onchain_limits().await?.into())
     }
 
+    #[wasm_bindgen(js_name = "getMaxPayable")]
+    pub async fn get_max_payable(
+        &self,
+        req: GetMaxPayableRequest,
+    ) -> WasmResult<GetMaxPayableResponse> {
+        Ok(self.sdk.get_max_payable(&req.into()).await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "getMaxReceivable")]
+    pub async fn get_max_receivable(
+        &self,
+        req: GetMaxReceivableRequest,
+    ) -> WasmResult<GetMaxReceivableResponse> {
+        Ok(self.sdk.get_max_receivable(&req.into()).await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "getReusableBitcoinAddress")]
+    pub async fn get_reusable_bitcoin_address(&self) -> WasmResult<ReusableBitcoinAddressResponse> {
+        Ok(self.sdk.get_reusable_bitcoin_address().await?.into())
+    }
+
     #[wasm_bindgen(js_name = "preparePayOnchain")]
     pub async fn prepare_pay_onchain(
         &self,
@@ -225,6 +434,19 @@ impl BindingLiquidSdk {
         Ok(self.sdk.pay_onchain(&req.into()).await?.into())
     }
 
+    #[wasm_bindgen(js_name = "prepareConvert")]
+    pub async fn prepare_convert(
+        &self,
+        req: PrepareConvertRequest,
+    ) -> WasmResult<PrepareConvertResponse> {
+        Ok(self.sdk.prepare_convert(&req.into()).await?.into())
+    }
+
+    #[wasm_bindgen(js_name = "convert")]
+    pub async fn convert(&self, req: ConvertRequest) -> WasmResult<SendPaymentResponse> {
+        Ok(self.sdk.convert(&req.into()).await?.into())
+    }
+
     #[wasm_bindgen(js_name = "prepareBuyBitcoin")]
     pub async fn prepare_buy_bitcoin(
         &self,
@@ -249,11 +471,39 @@ impl BindingLiquidSdk {
             .collect())
     }
 
+    #[wasm_bindgen(js_name = "listSwaps")]
+    pub async fn list_swaps(&self, req: ListSwapsRequest) -> WasmResult<Vec<SwapInfo>> {
+        Ok(self
+            .sdk
+            .list_swaps(&req.into())
+            .await?
+            .into_iter()
+            .map(|s| s.into())
+            .collect())
+    }
+
+    #[wasm_bindgen(js_name = "getReport")]
+    pub async fn get_report(&self, req: GetReportRequest) -> WasmResult<GetReportResponse> {
+        Ok(self.sdk.get_report(&req.into()).await?.into())
+    }
+
     #[wasm_bindgen(js_name = "getPayment")]
     pub async fn get_payment(&self, req: GetPaymentRequest) -> WasmResult<Option<Payment>> {
         Ok(self.sdk.get_payment(&req.into()).await?.map(|r| r.into()))
     }
 
+    #[wasm_bindgen(js_name = "exportPaymentUnblindingData")]
+    pub async fn export_payment_unblinding_data(
+        &self,
+        req: ExportPaymentUnblindingDataRequest,
+    ) -> WasmResult<PaymentUnblindingData> {
+        Ok(self
+            .sdk
+            .export_payment_unblinding_data(&req.into())
+            .await?
+            .into())
+    }
+
     #[wasm_bindgen(js_name = "fetchPaymentProposedFees")]
     pub async fn fetch_payment_proposed_fees(
         &self,
@@ -275,6 +525,12 @@ impl BindingLiquidSdk {
         Ok(())
     }
 
+    #[wasm_bindgen(js_name = "claimSwap")]
+    pub async fn claim_swap(&self, swap_id: String) -> WasmResult<()> {
+        self.sdk.claim_swap(&swap_id).await?;
+        Ok(())
+    }
+
     #[wasm_bindgen(js_name = "prepareLnurlPay")]
     pub async fn prepare_lnurl_pay(
         &self,
@@ -316,6 +572,46 @@ impl BindingLiquidSdk {
         Ok(())
     }
 
+    #[wasm_bindgen(js_name = "checkLightningAddressAvailable")]
+    pub async fn check_lightning_address_available(&self, username: String) -> WasmResult<bool> {
+        Ok(self.sdk.check_lightning_address_available(username).await?)
+    }
+
+    #[wasm_bindgen(js_name = "registerLightningAddress")]
+    pub async fn register_lightning_address(
+        &self,
+        req: RegisterLightningAddressRequest,
+    ) -> WasmResult<LightningAddressInfo> {
+        Ok(self
+            .sdk
+            .register_lightning_address(&req.into())
+            .await?
+            .into())
+    }
+
+    #[wasm_bindgen(js_name = "recoverLightningAddress")]
+    pub async fn recover_lightning_address(&self) -> WasmResult<Option<LightningAddressInfo>> {
+        Ok(self.sdk.recover_lightning_address().await?.map(Into::into))
+    }
+
+    #[wasm_bindgen(js_name = "deleteLightningAddress")]
+    pub async fn delete_lightning_address(&self) -> WasmResult<()> {
+        self.sdk.delete_lightning_address().await?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "fetchRegisteredAsset")]
+    pub async fn fetch_registered_asset(
+        &self,
+        asset_id: String,
+    ) -> WasmResult<Option<RegisteredAsset>> {
+        Ok(self
+            .sdk
+            .fetch_registered_asset(&asset_id)
+            .await?
+            .map(Into::into))
+    }
+
     #[wasm_bindgen(js_name = "fetchFiatRates")]
     pub async fn fetch_fiat_rates(&self) -> WasmResult<Vec<Rate>> {
         Ok(self
@@ -338,6 +634,54 @@ impl BindingLiquidSdk {
             .collect())
     }
 
+    #[wasm_bindgen(js_name = "setFiatRateProvider")]
+    pub async fn set_fiat_rate_provider(&self, provider: FiatRateProvider) -> WasmResult<()> {
+        self.sdk
+            .set_fiat_rate_provider(Box::new(WasmFiatRateProvider { provider }))
+            .await?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "setBroadcaster")]
+    pub async fn set_broadcaster(&self, broadcaster: Broadcaster) -> WasmResult<()> {
+        self.sdk
+            .set_broadcaster(Box::new(WasmBroadcaster { broadcaster }))
+            .await?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "addInputParser")]
+    pub async fn add_input_parser(&self, parser: InputParser) -> WasmResult<()> {
+        self.sdk
+            .add_input_parser(Box::new(WasmInputParser { parser }))
+            .await?;
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "addNwcConnection")]
+    pub fn add_nwc_connection(
+        &self,
+        req: AddNwcConnectionRequest,
+    ) -> WasmResult<AddNwcConnectionResponse> {
+        Ok(self.sdk.add_nwc_connection(req.into())?.into())
+    }
+
+    #[wasm_bindgen(js_name = "listNwcConnections")]
+    pub fn list_nwc_connections(&self) -> WasmResult<Vec<NwcConnection>> {
+        Ok(self
+            .sdk
+            .list_nwc_connections()?
+            .into_iter()
+            .map(|c| c.into())
+            .collect())
+    }
+
+    #[wasm_bindgen(js_name = "removeNwcConnection")]
+    pub fn remove_nwc_connection(&self, name: String) -> WasmResult<()> {
+        self.sdk.remove_nwc_connection(name)?;
+        Ok(())
+    }
+
     #[wasm_bindgen(js_name = "listRefundables")]
     pub async fn list_refundables(&self) -> WasmResult<Vec<RefundableSwap>> {
         Ok(self
@@ -369,9 +713,9 @@ impl BindingLiquidSdk {
     }
 
     #[wasm_bindgen(js_name = "sync")]
-    pub async fn sync(&self) -> WasmResult<()> {
-        self.sdk.sync(false).await?;
-        Ok(())
+    pub async fn sync(&self, req: Option<SyncRequest>) -> WasmResult<SyncResponse> {
+        let full = req.and_then(|req| req.full).unwrap_or(true);
+        Ok(self.sdk.sync(!full).await?.into())
     }
 
     #[wasm_bindgen(js_name = "recommendedFees")]
@@ -17,10 +17,7 @@ impl Log for WasmLogger {
     fn log(&self, record: &Record) {
         WASM_LOGGER.with_borrow(|logger| {
             if let Some(logger) = logger.as_ref() {
-                logger.log(LogEntry {
-                    line: record.args().to_string(),
-                    level: record.level().as_str().to_string(),
-                })
+                logger.log(breez_sdk_liquid::prelude::LogEntry::from(record).into())
             }
         });
     }
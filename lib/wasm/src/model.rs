@@ -34,6 +34,39 @@ pub struct LNInvoice {
     pub min_final_cltv_expiry_delta: u64,
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::LNBolt12Invoice)]
+pub struct LNBolt12Invoice {
+    pub invoice: String,
+    pub signing_pubkey: String,
+    pub payment_hash: String,
+    pub description: Option<String>,
+    pub payer_note: Option<String>,
+    pub amount_msat: u64,
+    pub created_at: u64,
+    pub expiry: u64,
+    pub payment_path_count: u32,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::DecodedInvoice)]
+pub enum DecodedInvoice {
+    Bolt11 { invoice: LNInvoice },
+    Bolt12 { invoice: LNBolt12Invoice },
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::AddressType)]
+pub enum AddressType {
+    Bitcoin,
+    Liquid,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ValidateAddressResponse)]
+pub struct ValidateAddressResponse {
+    pub address_type: AddressType,
+    pub network: LiquidNetwork,
+    pub is_confidential: bool,
+    pub supports_assets: bool,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::RouteHint)]
 pub struct RouteHint {
     pub hops: Vec<RouteHintHop>,
@@ -315,6 +348,61 @@ pub struct Config {
     pub onchain_fee_rate_leeway_sat_per_vbyte: Option<u32>,
     pub asset_metadata: Option<Vec<AssetMetadata>>,
     pub sideswap_api_key: Option<String>,
+    pub event_webhook: Option<EventWebhookConfig>,
+    pub fiat_currency: Option<String>,
+    pub sync_interval_sec: Option<u32>,
+    pub bitcoin_explorer_authorization: Option<String>,
+    pub swapper_max_attempts: Option<u32>,
+    pub backup_policy: Option<BackupPolicy>,
+    pub nwc_relay_url: Option<String>,
+    pub spend_limits: Option<SpendLimits>,
+    pub lnurl_service_url: Option<String>,
+    pub outgoing_min_confirmations: Option<u32>,
+    pub unconfirmed_tx_alert_sec: Option<u32>,
+    pub chain_service_timeout_sec: Option<u32>,
+    pub swapper_timeout_sec: Option<u32>,
+    pub swapper_ping_interval_sec: Option<u32>,
+    pub amp_server_url: Option<String>,
+    pub simulation: Option<SimulationConfig>,
+    pub manual_claim: bool,
+    pub claim_address: Option<String>,
+    pub dns_over_https_url: Option<String>,
+}
+
+#[derive(Clone)]
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SpendLimit)]
+pub enum SpendLimit {
+    Bitcoin { amount_sat: u64 },
+    Fiat { amount: FiatAmount },
+}
+
+#[derive(Clone)]
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SimulationConfig)]
+pub struct SimulationConfig {
+    pub status_update_delay_sec: u32,
+    pub failure_rate: f64,
+}
+
+#[derive(Clone)]
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SpendLimits)]
+pub struct SpendLimits {
+    pub per_payment: Option<SpendLimit>,
+    pub per_day: Option<SpendLimit>,
+    pub override_secret: Option<String>,
+}
+
+#[derive(Clone)]
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::BackupPolicy)]
+pub enum BackupPolicy {
+    CompletedSwaps { swaps: u32 },
+    Interval { hours: u32 },
+}
+
+#[derive(Clone)]
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::EventWebhookConfig)]
+pub struct EventWebhookConfig {
+    pub url: String,
+    pub secret: String,
 }
 
 #[derive(Clone)]
@@ -327,16 +415,157 @@ pub enum LiquidNetwork {
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SdkEvent)]
 pub enum SdkEvent {
-    PaymentFailed { details: Payment },
-    PaymentPending { details: Payment },
-    PaymentRefundable { details: Payment },
-    PaymentRefunded { details: Payment },
-    PaymentRefundPending { details: Payment },
-    PaymentSucceeded { details: Payment },
-    PaymentWaitingConfirmation { details: Payment },
-    PaymentWaitingFeeAcceptance { details: Payment },
+    PaymentFailed {
+        details: Payment,
+    },
+    PaymentPending {
+        details: Payment,
+    },
+    PaymentRefundable {
+        details: Payment,
+    },
+    PaymentRefunded {
+        details: Payment,
+    },
+    PaymentRefundPending {
+        details: Payment,
+    },
+    PaymentSucceeded {
+        details: Payment,
+    },
+    PaymentWaitingConfirmation {
+        details: Payment,
+    },
+    PaymentWaitingFeeAcceptance {
+        details: Payment,
+    },
+    PaymentExpired {
+        details: Payment,
+    },
+    PaymentReorged {
+        details: Payment,
+    },
+    SwapUpdated {
+        swap_id: String,
+        swap_type: PaymentSwapType,
+        state: PaymentState,
+    },
+    SwapClaimBroadcast {
+        swap_id: String,
+        tx_id: String,
+    },
+    SwapRefundBroadcast {
+        swap_id: String,
+        tx_id: String,
+    },
+    TipsUpdated {
+        liquid_tip: u32,
+        bitcoin_tip: u32,
+    },
+    SyncStarted,
+    Synced,
+    DataSynced {
+        did_pull_new_records: bool,
+    },
+    ConnectivityChanged {
+        is_online: bool,
+    },
+    BackupSucceeded,
+    BackupFailed {
+        err: String,
+    },
+    BatchSendCompleted {
+        results: Vec<BatchSendItemResult>,
+    },
+    ScheduledPaymentExecuted {
+        id: String,
+        details: Payment,
+    },
+    ScheduledPaymentFailed {
+        id: String,
+        err: String,
+    },
+    RecurringPaymentExecuted {
+        recurring_payment_id: String,
+        details: Payment,
+    },
+    RecurringPaymentFailed {
+        recurring_payment_id: String,
+        err: String,
+    },
+    TransactionDropped {
+        tx_id: String,
+    },
+    TransactionUnconfirmed {
+        tx_id: String,
+        unconfirmed_sec: u32,
+    },
+    NewLiquidBlock {
+        block: BlockInfo,
+    },
+    NewBitcoinBlock {
+        block: BlockInfo,
+    },
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SdkEventKind)]
+pub enum SdkEventKind {
+    PaymentFailed,
+    PaymentPending,
+    PaymentRefundable,
+    PaymentRefunded,
+    PaymentRefundPending,
+    PaymentSucceeded,
+    PaymentWaitingConfirmation,
+    PaymentWaitingFeeAcceptance,
+    PaymentExpired,
+    PaymentReorged,
+    SwapUpdated,
+    SwapClaimBroadcast,
+    SwapRefundBroadcast,
+    TipsUpdated,
+    SyncStarted,
     Synced,
-    DataSynced { did_pull_new_records: bool },
+    DataSynced,
+    ConnectivityChanged,
+    BackupSucceeded,
+    BackupFailed,
+    BatchSendCompleted,
+    ScheduledPaymentExecuted,
+    ScheduledPaymentFailed,
+    RecurringPaymentExecuted,
+    RecurringPaymentFailed,
+    TransactionDropped,
+    TransactionUnconfirmed,
+    NewLiquidBlock,
+    NewBitcoinBlock,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::EventFilter)]
+pub struct EventFilter {
+    pub kinds: Option<Vec<SdkEventKind>>,
+    pub payment_ids: Option<Vec<String>>,
+    pub swap_types: Option<Vec<PaymentSwapType>>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ReplayEventsRequest)]
+pub struct ReplayEventsRequest {
+    pub since_seq: Option<u64>,
+    pub limit: Option<u32>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::JournaledEvent)]
+pub struct JournaledEvent {
+    pub seq: u64,
+    pub timestamp: u32,
+    pub event: SdkEvent,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PaymentSwapType)]
+pub enum PaymentSwapType {
+    Receive,
+    Send,
+    Chain,
 }
 
 #[derive(Clone)]
@@ -346,6 +575,7 @@ pub struct ConnectRequest {
     pub mnemonic: Option<String>,
     pub passphrase: Option<String>,
     pub seed: Option<Vec<u8>>,
+    pub xprv: Option<String>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ConnectWithSignerRequest)]
@@ -369,12 +599,17 @@ pub enum ReceiveAmount {
         asset_id: String,
         payer_amount: Option<f64>,
     },
+    AssetUnits {
+        payer_amount: AssetAmount,
+    },
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareReceiveRequest)]
 pub struct PrepareReceiveRequest {
     pub payment_method: PaymentMethod,
     pub amount: Option<ReceiveAmount>,
+    pub fiat_amount: Option<FiatAmount>,
+    pub expiry_secs: Option<u32>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareReceiveResponse)]
@@ -382,9 +617,23 @@ pub struct PrepareReceiveResponse {
     pub payment_method: PaymentMethod,
     pub amount: Option<ReceiveAmount>,
     pub fees_sat: u64,
+    pub fee_breakdown: FeeBreakdown,
     pub min_payer_amount_sat: Option<u64>,
     pub max_payer_amount_sat: Option<u64>,
     pub swapper_feerate: Option<f64>,
+    pub fiat_conversion: Option<FiatConversion>,
+    pub expiry_secs: Option<u32>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareReceiveQuotesRequest)]
+pub struct PrepareReceiveQuotesRequest {
+    pub amount: Option<ReceiveAmount>,
+    pub fiat_amount: Option<FiatAmount>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareReceiveQuotesResponse)]
+pub struct PrepareReceiveQuotesResponse {
+    pub quotes: Vec<PrepareReceiveResponse>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ReceivePaymentRequest)]
@@ -392,6 +641,8 @@ pub struct ReceivePaymentRequest {
     pub prepare_response: PrepareReceiveResponse,
     pub description: Option<String>,
     pub use_description_hash: Option<bool>,
+    pub description_hash: Option<String>,
+    pub claim_address: Option<String>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ReceivePaymentResponse)]
@@ -418,10 +669,54 @@ pub struct OnchainPaymentLimitsResponse {
     pub receive: Limits,
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::GetMaxPayableRequest)]
+pub struct GetMaxPayableRequest {
+    pub payment_method: PaymentMethod,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::GetMaxPayableResponse)]
+pub struct GetMaxPayableResponse {
+    pub receiver_amount_sat: u64,
+    pub fees_sat: u64,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::GetMaxReceivableRequest)]
+pub struct GetMaxReceivableRequest {
+    pub payment_method: PaymentMethod,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::GetMaxReceivableResponse)]
+pub struct GetMaxReceivableResponse {
+    pub payer_amount_sat: Option<u64>,
+    pub fees_sat: u64,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ReusableBitcoinAddressResponse)]
+pub struct ReusableBitcoinAddressResponse {
+    pub address: String,
+    pub min_deposit_sat: u64,
+    pub max_deposit_sat: u64,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareSendRequest)]
 pub struct PrepareSendRequest {
     pub destination: String,
     pub amount: Option<PayAmount>,
+    pub fiat_amount: Option<FiatAmount>,
+}
+
+#[derive(Clone)]
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::FiatAmount)]
+pub struct FiatAmount {
+    pub amount: f64,
+    pub currency: String,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::FiatConversion)]
+pub struct FiatConversion {
+    pub fiat_amount: FiatAmount,
+    pub rate: f64,
+    pub amount_sat: u64,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SendDestination)]
@@ -429,6 +724,7 @@ pub enum SendDestination {
     LiquidAddress {
         address_data: LiquidAddressData,
         bip353_address: Option<String>,
+        payjoin_url: Option<String>,
     },
     Bolt11 {
         invoice: LNInvoice,
@@ -441,17 +737,34 @@ pub enum SendDestination {
     },
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::FeeBreakdown)]
+pub struct FeeBreakdown {
+    pub service_fee_sat: u64,
+    pub swapper_network_fee_sat: u64,
+    pub lockup_fee_sat: u64,
+    pub lockup_fee_is_estimate: bool,
+    pub claim_fee_sat: u64,
+    pub claim_fee_is_estimate: bool,
+    pub asset_fee: Option<AssetAmount>,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareSendResponse)]
 pub struct PrepareSendResponse {
     pub destination: SendDestination,
     pub fees_sat: Option<u64>,
+    pub fee_breakdown: Option<FeeBreakdown>,
     pub estimated_asset_fees: Option<f64>,
+    pub estimated_asset_fees_amount: Option<AssetAmount>,
+    pub fiat_conversion: Option<FiatConversion>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SendPaymentRequest)]
 pub struct SendPaymentRequest {
     pub prepare_response: PrepareSendResponse,
     pub use_asset_fees: Option<bool>,
+    pub max_fee_sat: Option<u64>,
+    pub spend_limit_override: Option<String>,
+    pub idempotency_key: Option<String>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SendPaymentResponse)]
@@ -459,6 +772,111 @@ pub struct SendPaymentResponse {
     pub payment: Payment,
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::QueuePaymentRequest)]
+pub struct QueuePaymentRequest {
+    pub prepare_request: PrepareSendRequest,
+    pub use_asset_fees: Option<bool>,
+    pub max_fee_sat: Option<u64>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::QueuedPaymentState)]
+pub enum QueuedPaymentState {
+    Pending = 0,
+    Failed = 1,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::BatchSendItem)]
+pub struct BatchSendItem {
+    pub destination: String,
+    pub amount_sat: Option<u64>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SendBatchRequest)]
+pub struct SendBatchRequest {
+    pub items: Vec<BatchSendItem>,
+    pub max_total_fee_sat: Option<u64>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::BatchSendItemResult)]
+pub struct BatchSendItemResult {
+    pub destination: String,
+    pub payment: Option<Payment>,
+    pub error: Option<String>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SendBatchResponse)]
+pub struct SendBatchResponse {
+    pub results: Vec<BatchSendItemResult>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::QueuedPayment)]
+pub struct QueuedPayment {
+    pub id: String,
+    pub request: QueuePaymentRequest,
+    pub state: QueuedPaymentState,
+    pub attempts: u32,
+    pub created_at: u32,
+    pub next_attempt_at: u32,
+    pub last_error: Option<String>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SchedulePaymentRequest)]
+pub struct SchedulePaymentRequest {
+    pub prepare_request: PrepareSendRequest,
+    pub use_asset_fees: Option<bool>,
+    pub max_fee_sat: Option<u64>,
+    pub execute_at: u32,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ScheduledPaymentState)]
+pub enum ScheduledPaymentState {
+    Pending = 0,
+    Executed = 1,
+    Failed = 2,
+    InProgress = 3,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ScheduledPayment)]
+pub struct ScheduledPayment {
+    pub id: String,
+    pub request: SchedulePaymentRequest,
+    pub state: ScheduledPaymentState,
+    pub attempts: u32,
+    pub created_at: u32,
+    pub next_attempt_at: u32,
+    pub tx_id: Option<String>,
+    pub last_error: Option<String>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::CreateRecurringPaymentRequest)]
+pub struct CreateRecurringPaymentRequest {
+    pub destination: String,
+    pub amount_sat: u64,
+    pub interval_sec: u32,
+    pub start_at: Option<u32>,
+    pub max_fee_sat: Option<u64>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::RecurringPayment)]
+pub struct RecurringPayment {
+    pub id: String,
+    pub destination: String,
+    pub amount_sat: u64,
+    pub interval_sec: u32,
+    pub max_fee_sat: Option<u64>,
+    pub created_at: u32,
+    pub next_occurrence_at: u32,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::RecurringPaymentOccurrence)]
+pub struct RecurringPaymentOccurrence {
+    pub recurring_payment_id: String,
+    pub occurrence_at: u32,
+    pub executed_at: u32,
+    pub tx_id: Option<String>,
+    pub error: Option<String>,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PayAmount)]
 pub enum PayAmount {
     Bitcoin {
@@ -469,6 +887,10 @@ pub enum PayAmount {
         receiver_amount: f64,
         estimate_asset_fees: Option<bool>,
     },
+    AssetUnits {
+        receiver_amount: AssetAmount,
+        estimate_asset_fees: Option<bool>,
+    },
     Drain,
 }
 
@@ -476,6 +898,14 @@ pub enum PayAmount {
 pub struct PreparePayOnchainRequest {
     pub amount: PayAmount,
     pub fee_rate_sat_per_vbyte: Option<u32>,
+    pub fee_speed: Option<FeeSpeed>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::FeeSpeed)]
+pub enum FeeSpeed {
+    Economy,
+    Standard,
+    Fast,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PreparePayOnchainResponse)]
@@ -489,19 +919,46 @@ pub struct PreparePayOnchainResponse {
 pub struct PayOnchainRequest {
     pub address: String,
     pub prepare_response: PreparePayOnchainResponse,
+    pub spend_limit_override: Option<String>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareConvertRequest)]
+pub struct PrepareConvertRequest {
+    pub from_asset_id: String,
+    pub to_asset_id: String,
+    pub amount: u64,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareConvertResponse)]
+pub struct PrepareConvertResponse {
+    pub from_asset_id: String,
+    pub to_asset_id: String,
+    pub amount: u64,
+    pub receiver_amount: u64,
+    pub fees_sat: u64,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ConvertRequest)]
+pub struct ConvertRequest {
+    pub prepare_response: PrepareConvertResponse,
+    pub spend_limit_override: Option<String>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareRefundRequest)]
 pub struct PrepareRefundRequest {
     pub swap_address: String,
     pub refund_address: String,
-    pub fee_rate_sat_per_vbyte: u32,
+    pub fee_rate_sat_per_vbyte: Option<u32>,
+    pub fee_speed: Option<FeeSpeed>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareRefundResponse)]
 pub struct PrepareRefundResponse {
     pub tx_vsize: u32,
     pub tx_fee_sat: u64,
+    pub fee_rate_sat_per_vbyte: u32,
+    pub refundable_amount_sat: u64,
+    pub refund_amount_sat: u64,
     pub last_refund_tx_id: Option<String>,
 }
 
@@ -509,7 +966,8 @@ pub struct PrepareRefundResponse {
 pub struct RefundRequest {
     pub swap_address: String,
     pub refund_address: String,
-    pub fee_rate_sat_per_vbyte: u32,
+    pub fee_rate_sat_per_vbyte: Option<u32>,
+    pub fee_speed: Option<FeeSpeed>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::RefundResponse)]
@@ -524,6 +982,11 @@ pub struct AssetBalance {
     pub name: Option<String>,
     pub ticker: Option<String>,
     pub balance: Option<f64>,
+    pub pending_send_sat: u64,
+    pub pending_receive_sat: u64,
+    pub pending_send: Option<f64>,
+    pub pending_receive: Option<f64>,
+    pub asset_balance: Option<AssetAmount>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::BlockchainInfo)]
@@ -532,6 +995,13 @@ pub struct BlockchainInfo {
     pub bitcoin_tip: u32,
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::BlockInfo)]
+pub struct BlockInfo {
+    pub height: u32,
+    pub hash: String,
+    pub timestamp: u32,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::WalletInfo)]
 pub struct WalletInfo {
     pub balance_sat: u64,
@@ -548,6 +1018,53 @@ pub struct GetInfoResponse {
     pub blockchain_info: BlockchainInfo,
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::GetWalletInfoResponse)]
+pub struct GetWalletInfoResponse {
+    pub descriptor: String,
+    pub xpub: String,
+    pub derivation_path: String,
+    pub fingerprint: String,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::AmpAccount)]
+pub struct AmpAccount {
+    pub amp_id: String,
+    pub descriptor: String,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::HealthCheckService)]
+pub enum HealthCheckService {
+    LiquidExplorer,
+    BitcoinExplorer,
+    MempoolFees,
+    SwapperApi,
+    SwapperStatusStream,
+    FiatRates,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ServiceHealth)]
+pub struct ServiceHealth {
+    pub service: HealthCheckService,
+    pub is_reachable: bool,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SwapServiceStatus)]
+pub struct SwapServiceStatus {
+    pub send_available: bool,
+    pub receive_available: bool,
+    pub chain_send_available: bool,
+    pub chain_receive_available: bool,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::CheckHealthResponse)]
+pub struct CheckHealthResponse {
+    pub is_degraded: bool,
+    pub services: Vec<ServiceHealth>,
+    pub swap_service_status: Option<SwapServiceStatus>,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SignMessageRequest)]
 pub struct SignMessageRequest {
     pub message: String,
@@ -556,6 +1073,7 @@ pub struct SignMessageRequest {
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SignMessageResponse)]
 pub struct SignMessageResponse {
     pub signature: String,
+    pub pubkey: String,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::CheckMessageRequest)]
@@ -570,6 +1088,49 @@ pub struct CheckMessageResponse {
     pub is_valid: bool,
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PsetSpendLimit)]
+pub struct PsetSpendLimit {
+    pub asset_id: String,
+    pub max_amount_sat: u64,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SignPsetRequest)]
+pub struct SignPsetRequest {
+    pub pset: String,
+    pub max_fee_sat: u64,
+    pub spend_limits: Vec<PsetSpendLimit>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SignPsetResponse)]
+pub struct SignPsetResponse {
+    pub pset: String,
+    pub fees_sat: u64,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SweepPrivateKeyRequest)]
+pub struct SweepPrivateKeyRequest {
+    pub key: String,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SweepPrivateKeyResponse)]
+pub struct SweepPrivateKeyResponse {
+    pub payment: Payment,
+    pub amount_sat: u64,
+    pub asset_amounts: Vec<AssetAmount>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SyncRequest)]
+pub struct SyncRequest {
+    pub full: Option<bool>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SyncResponse)]
+pub struct SyncResponse {
+    pub new_tx_count: u32,
+    pub updated_swap_count: u32,
+    pub duration_ms: u64,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::BackupRequest)]
 pub struct BackupRequest {
     pub backup_path: Option<String>,
@@ -580,6 +1141,67 @@ pub struct RestoreRequest {
     pub backup_path: Option<String>,
 }
 
+#[derive(Clone)]
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::NwcBudget)]
+pub struct NwcBudget {
+    pub max_amount_sat: u64,
+    pub renewal_period_sec: Option<u32>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::AddNwcConnectionRequest)]
+pub struct AddNwcConnectionRequest {
+    pub name: String,
+    pub budget: Option<NwcBudget>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::AddNwcConnectionResponse)]
+pub struct AddNwcConnectionResponse {
+    pub connection_uri: String,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::NwcConnection)]
+pub struct NwcConnection {
+    pub name: String,
+    pub client_pubkey: String,
+    pub budget: Option<NwcBudget>,
+    pub spent_sat: u64,
+    pub created_at: u32,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::GetReportRequest)]
+pub struct GetReportRequest {
+    pub from_timestamp: Option<i64>,
+    pub to_timestamp: Option<i64>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::AssetReport)]
+pub struct AssetReport {
+    pub asset_id: String,
+    pub received_amount: u64,
+    pub sent_amount: u64,
+    pub swap_fees_sat: u64,
+    pub onchain_fees_sat: u64,
+    pub received_count: u32,
+    pub sent_count: u32,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::GetReportResponse)]
+pub struct GetReportResponse {
+    pub assets: Vec<AssetReport>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::RegisterLightningAddressRequest)]
+pub struct RegisterLightningAddressRequest {
+    pub username: String,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::LightningAddressInfo)]
+pub struct LightningAddressInfo {
+    pub username: String,
+    pub lightning_address: String,
+    pub lnurl: String,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ListPaymentsRequest)]
 pub struct ListPaymentsRequest {
     pub filters: Option<Vec<PaymentType>>,
@@ -592,6 +1214,43 @@ pub struct ListPaymentsRequest {
     pub sort_ascending: Option<bool>,
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ListSwapsRequest)]
+pub struct ListSwapsRequest {
+    pub swap_types: Option<Vec<PaymentSwapType>>,
+    pub states: Option<Vec<PaymentState>>,
+    pub from_timestamp: Option<i64>,
+    pub to_timestamp: Option<i64>,
+    pub offset: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::Direction)]
+pub enum Direction {
+    Incoming = 0,
+    Outgoing = 1,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::SwapInfo)]
+pub struct SwapInfo {
+    pub swap_id: String,
+    pub swap_type: PaymentSwapType,
+    pub direction: Option<Direction>,
+    pub state: PaymentState,
+    pub created_at: u32,
+    pub timeout_block_height: u64,
+    pub payer_amount_sat: u64,
+    pub receiver_amount_sat: u64,
+    pub invoice: Option<String>,
+    pub lockup_address: Option<String>,
+    pub user_lockup_tx_id: Option<String>,
+    pub server_lockup_tx_id: Option<String>,
+    pub claim_address: Option<String>,
+    pub claim_tx_id: Option<String>,
+    pub refund_address: Option<String>,
+    pub refund_tx_id: Option<String>,
+    pub create_response_json: String,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ListPaymentDetails)]
 pub enum ListPaymentDetails {
     Liquid {
@@ -609,12 +1268,40 @@ pub enum GetPaymentRequest {
     SwapId { swap_id: String },
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::ExportPaymentUnblindingDataRequest)]
+pub struct ExportPaymentUnblindingDataRequest {
+    pub payment_id: String,
+    pub explorer_url: Option<String>,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::UnblindedTxOut)]
+pub struct UnblindedTxOut {
+    pub outpoint: String,
+    pub asset_id: String,
+    pub amount: u64,
+    pub asset_blinding_factor: String,
+    pub amount_blinding_factor: String,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PaymentUnblindingData)]
+pub struct PaymentUnblindingData {
+    pub tx_id: String,
+    pub unblinding_data: String,
+    pub unblinded_url: Option<String>,
+    pub inputs: Vec<UnblindedTxOut>,
+    pub outputs: Vec<UnblindedTxOut>,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::RefundableSwap)]
 pub struct RefundableSwap {
     pub swap_address: String,
     pub timestamp: u32,
     pub amount_sat: u64,
     pub last_refund_tx_id: Option<String>,
+    pub state: PaymentState,
+    pub refundable_since: u32,
+    pub refund_eligible_block_height: u32,
+    pub is_cooperative_refund_possible: bool,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PaymentState)]
@@ -650,6 +1337,8 @@ pub struct LnUrlInfo {
     pub lnurl_pay_success_action: Option<SuccessActionProcessed>,
     pub lnurl_pay_unprocessed_success_action: Option<SuccessAction>,
     pub lnurl_withdraw_endpoint: Option<String>,
+    pub lnurl_pay_verify_url: Option<String>,
+    pub lnurl_pay_verified_preimage: Option<String>,
 }
 
 #[derive(Clone)]
@@ -660,6 +1349,33 @@ pub struct AssetMetadata {
     pub ticker: String,
     pub precision: u8,
     pub fiat_id: Option<String>,
+    pub is_amp: bool,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::RegisteredAsset)]
+pub struct RegisteredAsset {
+    pub asset_id: String,
+    pub name: String,
+    pub ticker: Option<String>,
+    pub precision: u8,
+    pub domain: Option<String>,
+    pub icon_url: String,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::AssetAmount)]
+pub struct AssetAmount {
+    pub asset_id: String,
+    pub base_units: u64,
+    pub precision: u8,
+    pub display_precision: u8,
+}
+
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::AssetConversion)]
+pub struct AssetConversion {
+    pub from_asset_id: String,
+    pub from_amount: u64,
+    pub to_asset_id: String,
+    pub to_amount: u64,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::AssetInfo)]
@@ -668,6 +1384,8 @@ pub struct AssetInfo {
     pub ticker: String,
     pub amount: f64,
     pub fees: Option<f64>,
+    pub asset_amount: AssetAmount,
+    pub asset_fees: Option<AssetAmount>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PaymentDetails)]
@@ -695,6 +1413,7 @@ pub enum PaymentDetails {
         asset_info: Option<AssetInfo>,
         lnurl_info: Option<LnUrlInfo>,
         bip353_address: Option<String>,
+        conversion: Option<AssetConversion>,
     },
     Bitcoin {
         swap_id: String,
@@ -705,6 +1424,7 @@ pub enum PaymentDetails {
         claim_tx_id: Option<String>,
         refund_tx_id: Option<String>,
         refund_tx_amount_sat: Option<u64>,
+        buy_bitcoin_provider: Option<BuyBitcoinProvider>,
     },
 }
 
@@ -720,6 +1440,9 @@ pub struct Payment {
     pub payment_type: PaymentType,
     pub status: PaymentState,
     pub details: PaymentDetails,
+    pub fiat_amount: Option<f64>,
+    pub fiat_currency: Option<String>,
+    pub recurring_payment_id: Option<String>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::RecommendedFees)]
@@ -759,6 +1482,8 @@ pub struct BuyBitcoinRequest {
 pub struct LogEntry {
     pub line: String,
     pub level: String,
+    pub target: String,
+    pub timestamp: u64,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareLnUrlPayRequest)]
@@ -767,16 +1492,27 @@ pub struct PrepareLnUrlPayRequest {
     pub amount: PayAmount,
     pub bip353_address: Option<String>,
     pub comment: Option<String>,
+    pub payer_data: Option<LnUrlPayerData>,
     pub validate_success_action_url: Option<bool>,
 }
 
+#[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::LnUrlPayerData)]
+pub struct LnUrlPayerData {
+    pub name: Option<String>,
+    pub pubkey: Option<String>,
+    pub identifier: Option<String>,
+    pub email: Option<String>,
+}
+
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::prelude::PrepareLnUrlPayResponse)]
 pub struct PrepareLnUrlPayResponse {
     pub destination: SendDestination,
     pub fees_sat: u64,
+    pub fee_breakdown: FeeBreakdown,
     pub data: LnUrlPayRequestData,
     pub comment: Option<String>,
     pub success_action: Option<SuccessAction>,
+    pub verify_url: Option<String>,
 }
 
 #[sdk_macros::extern_wasm_bindgen(breez_sdk_liquid::model::LnUrlPayRequest)]
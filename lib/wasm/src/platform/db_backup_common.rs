@@ -46,6 +46,12 @@ impl BackupPersister {
         let storage = self.storage.clone();
         wasm_bindgen_futures::spawn_local(async move {
             while let Some(e) = receiver.recv().await {
+                if !should_backup(&e) {
+                    continue;
+                }
+                // A single backup covers the changes of all the events received in the meantime
+                while receiver.try_recv().is_ok() {}
+
                 let start = web_time::Instant::now();
 
                 let bytes = match persister.serialize() {
@@ -56,15 +62,9 @@ impl BackupPersister {
                     }
                 };
 
-                let res = match e {
-                    SdkEvent::Synced => storage.backup(&bytes).await,
-                    SdkEvent::DataSynced {
-                        did_pull_new_records,
-                    } if did_pull_new_records => storage.backup(&bytes).await,
-                    _ => continue,
-                };
-                if let Err(e) = res {
+                if let Err(e) = storage.backup(&bytes).await {
                     log::error!("Failed to backup to IndexedDB: {:?}", e);
+                    continue;
                 };
 
                 let backup_duration_ms = start.elapsed().as_millis();
@@ -78,13 +78,34 @@ impl BackupPersister {
     }
 }
 
+/// Whether the event follows a change of the persisted state. Payment and swap updates are
+/// backed up right away, so that they survive a page reload before the next sync.
+fn should_backup(e: &SdkEvent) -> bool {
+    match e {
+        SdkEvent::SyncStarted
+        | SdkEvent::TipsUpdated { .. }
+        | SdkEvent::ConnectivityChanged { .. }
+        | SdkEvent::BackupSucceeded
+        | SdkEvent::BackupFailed { .. }
+        | SdkEvent::BatchSendCompleted { .. }
+        | SdkEvent::TransactionUnconfirmed { .. }
+        | SdkEvent::NewLiquidBlock { .. }
+        | SdkEvent::NewBitcoinBlock { .. } => false,
+        SdkEvent::DataSynced {
+            did_pull_new_records,
+        } => *did_pull_new_records,
+        _ => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
     use std::str::FromStr;
 
+    use super::should_backup;
     use crate::platform::create_db_backup_persister;
-    use breez_sdk_liquid::model::PaymentState;
+    use breez_sdk_liquid::model::{PaymentState, PaymentSwapType, SdkEvent};
     use breez_sdk_liquid::persist::Persister;
     use breez_sdk_liquid::prelude::LiquidNetwork;
     use breez_sdk_liquid::test_utils::persist::{
@@ -132,4 +153,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[sdk_macros::async_test_wasm]
+    async fn test_should_backup() -> anyhow::Result<()> {
+        // Swap updates change the persisted state and are backed up right away
+        assert!(should_backup(&SdkEvent::SwapUpdated {
+            swap_id: "swap-id".to_string(),
+            swap_type: PaymentSwapType::Receive,
+            state: PaymentState::Pending,
+        }));
+        assert!(should_backup(&SdkEvent::SwapClaimBroadcast {
+            swap_id: "swap-id".to_string(),
+            tx_id: "tx-id".to_string(),
+        }));
+        assert!(should_backup(&SdkEvent::Synced));
+        assert!(should_backup(&SdkEvent::DataSynced {
+            did_pull_new_records: true,
+        }));
+
+        // Events that don't follow a change of the persisted state are skipped
+        assert!(!should_backup(&SdkEvent::SyncStarted));
+        assert!(!should_backup(&SdkEvent::TipsUpdated {
+            liquid_tip: 1,
+            bitcoin_tip: 1,
+        }));
+        assert!(!should_backup(&SdkEvent::DataSynced {
+            did_pull_new_records: false,
+        }));
+
+        Ok(())
+    }
 }
@@ -11,13 +11,17 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'bindings.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `init`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DartBindingLogger`
+// These functions are ignored because they are not marked as `pub`: `forward_events`, `init`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BindingEventListener`, `DartBindingLogger`, `DartBroadcaster`, `DartInputParser`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `enabled`, `flush`, `log`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `broadcast_bitcoin_tx`, `broadcast_liquid_tx`, `on_event`, `parse_input`
 
 Future<BindingLiquidSdk> connect({required ConnectRequest req}) =>
     RustLib.instance.api.crateBindingsConnect(req: req);
 
+Future<ProcessNotificationResponse> processNotification({required ProcessNotificationRequest req}) =>
+    RustLib.instance.api.crateBindingsProcessNotification(req: req);
+
 /// If used, this must be called before `connect`. It can only be called once.
 Stream<LogEntry> breezLogStream() => RustLib.instance.api.crateBindingsBreezLogStream();
 
@@ -27,22 +31,65 @@ Config defaultConfig({required LiquidNetwork network, String? breezApiKey}) =>
 LNInvoice parseInvoice({required String input}) =>
     RustLib.instance.api.crateBindingsParseInvoice(input: input);
 
+DecodedInvoice decodeInvoice({required String input}) =>
+    RustLib.instance.api.crateBindingsDecodeInvoice(input: input);
+
+ValidateAddressResponse validateAddress({required String address, required LiquidNetwork network}) =>
+    RustLib.instance.api.crateBindingsValidateAddress(address: address, network: network);
+
+String generateMnemonic({required int wordCount}) =>
+    RustLib.instance.api.crateBindingsGenerateMnemonic(wordCount: wordCount);
+
+void validateMnemonic({required String phrase}) =>
+    RustLib.instance.api.crateBindingsValidateMnemonic(phrase: phrase);
+
+String createSpendLimitOverride({required String secret, required int expiresAt}) =>
+    RustLib.instance.api.crateBindingsCreateSpendLimitOverride(secret: secret, expiresAt: expiresAt);
+
 // Rust type: RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>
 abstract class BindingLiquidSdk implements RustOpaqueInterface {
   Future<void> acceptPaymentProposedFees({required AcceptPaymentProposedFeesRequest req});
 
-  Stream<SdkEvent> addEventListener();
+  Stream<SdkEvent> addEventListener({EventFilter? filter});
+
+  Future<void> addInputParser({required FutureOr<InputType?> Function(String) parseInput});
+
+  AddNwcConnectionResponse addNwcConnection({required AddNwcConnectionRequest req});
 
   void backup({required BackupRequest req});
 
   Future<String> buyBitcoin({required BuyBitcoinRequest req});
 
+  Future<void> cancelQueuedPayment({required String id});
+
+  Future<void> cancelRecurringPayment({required String id});
+
+  Future<void> cancelScheduledPayment({required String id});
+
+  Future<CheckHealthResponse> checkHealth();
+
+  Future<bool> checkLightningAddressAvailable({required String username});
+
   CheckMessageResponse checkMessage({required CheckMessageRequest req});
 
+  Future<void> claimSwap({required String swapId});
+
+  Future<SendPaymentResponse> convert({required ConvertRequest req});
+
+  Future<RecurringPayment> createRecurringPayment({required CreateRecurringPaymentRequest req});
+
+  Future<void> deleteLightningAddress();
+
   Future<void> disconnect();
 
   void emptyWalletCache();
 
+  Future<PaymentUnblindingData> exportPaymentUnblindingData({
+    required ExportPaymentUnblindingDataRequest req,
+  });
+
+  void exportWallet({required ExportWalletRequest req});
+
   Future<List<Rate>> fetchFiatRates();
 
   Future<LightningPaymentLimitsResponse> fetchLightningLimits();
@@ -53,16 +100,46 @@ abstract class BindingLiquidSdk implements RustOpaqueInterface {
     required FetchPaymentProposedFeesRequest req,
   });
 
+  Future<RegisteredAsset?> fetchRegisteredAsset({required String assetId});
+
+  AmpAccount? getAmpAccount();
+
   Future<GetInfoResponse> getInfo();
 
+  Future<GetMaxPayableResponse> getMaxPayable({required GetMaxPayableRequest req});
+
+  Future<GetMaxReceivableResponse> getMaxReceivable({required GetMaxReceivableRequest req});
+
   Future<Payment?> getPayment({required GetPaymentRequest req});
 
+  Future<GetReportResponse> getReport({required GetReportRequest req});
+
+  Future<ReusableBitcoinAddressResponse> getReusableBitcoinAddress();
+
+  GetWalletInfoResponse getWalletInfo();
+
+  ImportWalletResponse importWallet({required ImportWalletRequest req});
+
   Future<List<FiatCurrency>> listFiatCurrencies();
 
+  List<NwcConnection> listNwcConnections();
+
   Future<List<Payment>> listPayments({required ListPaymentsRequest req});
 
+  Future<List<QueuedPayment>> listQueuedPayments();
+
+  Future<List<RecurringPaymentOccurrence>> listRecurringPaymentOccurrences({
+    required String recurringPaymentId,
+  });
+
+  Future<List<RecurringPayment>> listRecurringPayments();
+
   Future<List<RefundableSwap>> listRefundables();
 
+  Future<List<ScheduledPayment>> listScheduledPayments();
+
+  Future<List<SwapInfo>> listSwaps({required ListSwapsRequest req});
+
   Future<LnUrlCallbackStatus> lnurlAuth({required LnUrlAuthRequestData reqData});
 
   Future<LnUrlPayResult> lnurlPay({required LnUrlPayRequest req});
@@ -75,35 +152,68 @@ abstract class BindingLiquidSdk implements RustOpaqueInterface {
 
   Future<PrepareBuyBitcoinResponse> prepareBuyBitcoin({required PrepareBuyBitcoinRequest req});
 
+  Future<PrepareConvertResponse> prepareConvert({required PrepareConvertRequest req});
+
   Future<PrepareLnUrlPayResponse> prepareLnurlPay({required PrepareLnUrlPayRequest req});
 
   Future<PreparePayOnchainResponse> preparePayOnchain({required PreparePayOnchainRequest req});
 
   Future<PrepareReceiveResponse> prepareReceivePayment({required PrepareReceiveRequest req});
 
+  Future<PrepareReceiveQuotesResponse> prepareReceivePaymentQuotes({
+    required PrepareReceiveQuotesRequest req,
+  });
+
   Future<PrepareRefundResponse> prepareRefund({required PrepareRefundRequest req});
 
   Future<PrepareSendResponse> prepareSendPayment({required PrepareSendRequest req});
 
+  Future<QueuedPayment> queuePayment({required QueuePaymentRequest req});
+
   Future<ReceivePaymentResponse> receivePayment({required ReceivePaymentRequest req});
 
   Future<RecommendedFees> recommendedFees();
 
+  Future<LightningAddressInfo?> recoverLightningAddress();
+
   Future<RefundResponse> refund({required RefundRequest req});
 
+  Future<AmpAccount> registerAmpAccount();
+
+  Future<LightningAddressInfo> registerLightningAddress({required RegisterLightningAddressRequest req});
+
   Future<void> registerWebhook({required String webhookUrl});
 
+  void removeNwcConnection({required String name});
+
+  Future<List<JournaledEvent>> replayEvents({required ReplayEventsRequest req});
+
   Future<void> rescanOnchainSwaps();
 
   void restore({required RestoreRequest req});
 
+  Future<ScheduledPayment> schedulePayment({required SchedulePaymentRequest req});
+
+  Future<SendBatchResponse> sendBatch({required SendBatchRequest req});
+
   Future<SendPaymentResponse> sendPayment({required SendPaymentRequest req});
 
+  Future<void> setBroadcaster({
+    required FutureOr<String> Function(String) broadcastLiquidTx,
+    required FutureOr<String> Function(String) broadcastBitcoinTx,
+  });
+
   SignMessageResponse signMessage({required SignMessageRequest req});
 
-  Future<void> sync();
+  Future<SignPsetResponse> signPset({required SignPsetRequest req});
+
+  Future<SweepPrivateKeyResponse> sweepPrivateKey({required SweepPrivateKeyRequest req});
+
+  Future<SyncResponse> sync({SyncRequest? req});
 
   Future<void> unregisterWebhook();
+
+  void verifyBackup({required RestoreRequest req});
 }
 
 class AesSuccessActionData {
@@ -163,23 +273,6 @@ sealed class Amount with _$Amount {
       Amount_Currency;
 }
 
-class BindingEventListener {
-  final RustStreamSink<SdkEvent> stream;
-
-  const BindingEventListener({required this.stream});
-
-  Future<void> onEvent({required SdkEvent e}) =>
-      RustLib.instance.api.crateBindingsBindingEventListenerOnEvent(that: this, e: e);
-
-  @override
-  int get hashCode => stream.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is BindingEventListener && runtimeType == other.runtimeType && stream == other.stream;
-}
-
 class BitcoinAddressData {
   final String address;
   final Network network;
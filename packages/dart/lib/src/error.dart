@@ -21,7 +21,11 @@ sealed class PaymentError with _$PaymentError implements FrbException {
   const factory PaymentError.invalidNetwork({required String err}) = PaymentError_InvalidNetwork;
   const factory PaymentError.generic({required String err}) = PaymentError_Generic;
   const factory PaymentError.invalidOrExpiredFees() = PaymentError_InvalidOrExpiredFees;
+  const factory PaymentError.feeLimitExceeded({required BigInt feesSat, required BigInt maxFeeSat}) =
+      PaymentError_FeeLimitExceeded;
   const factory PaymentError.insufficientFunds() = PaymentError_InsufficientFunds;
+  const factory PaymentError.spendLimitExceeded({required BigInt amountSat, required BigInt remainingSat}) =
+      PaymentError_SpendLimitExceeded;
   const factory PaymentError.invalidDescription({required String err}) = PaymentError_InvalidDescription;
   const factory PaymentError.invalidInvoice({required String err}) = PaymentError_InvalidInvoice;
   const factory PaymentError.invalidPreimage() = PaymentError_InvalidPreimage;
@@ -34,6 +38,7 @@ sealed class PaymentError with _$PaymentError implements FrbException {
   const factory PaymentError.selfTransferNotSupported() = PaymentError_SelfTransferNotSupported;
   const factory PaymentError.sendError({required String err}) = PaymentError_SendError;
   const factory PaymentError.signerError({required String err}) = PaymentError_SignerError;
+  const factory PaymentError.serviceUnavailable({required String err}) = PaymentError_ServiceUnavailable;
 }
 
 @freezed
@@ -41,7 +46,9 @@ sealed class SdkError with _$SdkError implements FrbException {
   const SdkError._();
 
   const factory SdkError.alreadyStarted() = SdkError_AlreadyStarted;
+  const factory SdkError.dataDirLocked({required String dir}) = SdkError_DataDirLocked;
   const factory SdkError.generic({required String err}) = SdkError_Generic;
+  const factory SdkError.invalidBackup({required String err}) = SdkError_InvalidBackup;
   const factory SdkError.notStarted() = SdkError_NotStarted;
   const factory SdkError.serviceConnectivity({required String err}) = SdkError_ServiceConnectivity;
 }
@@ -466,6 +466,74 @@ String toString() {
 
 
 
+/// @nodoc
+
+
+class PaymentError_FeeLimitExceeded extends PaymentError {
+  const PaymentError_FeeLimitExceeded({required this.feesSat, required this.maxFeeSat}): super._();
+  
+
+ final  BigInt feesSat;
+ final  BigInt maxFeeSat;
+
+/// Create a copy of PaymentError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PaymentError_FeeLimitExceededCopyWith<PaymentError_FeeLimitExceeded> get copyWith => _$PaymentError_FeeLimitExceededCopyWithImpl<PaymentError_FeeLimitExceeded>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PaymentError_FeeLimitExceeded&&(identical(other.feesSat, feesSat) || other.feesSat == feesSat)&&(identical(other.maxFeeSat, maxFeeSat) || other.maxFeeSat == maxFeeSat));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,feesSat,maxFeeSat);
+
+@override
+String toString() {
+  return 'PaymentError.feeLimitExceeded(feesSat: $feesSat, maxFeeSat: $maxFeeSat)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PaymentError_FeeLimitExceededCopyWith<$Res> implements $PaymentErrorCopyWith<$Res> {
+  factory $PaymentError_FeeLimitExceededCopyWith(PaymentError_FeeLimitExceeded value, $Res Function(PaymentError_FeeLimitExceeded) _then) = _$PaymentError_FeeLimitExceededCopyWithImpl;
+@useResult
+$Res call({
+ BigInt feesSat, BigInt maxFeeSat
+});
+
+
+
+
+}
+/// @nodoc
+class _$PaymentError_FeeLimitExceededCopyWithImpl<$Res>
+    implements $PaymentError_FeeLimitExceededCopyWith<$Res> {
+  _$PaymentError_FeeLimitExceededCopyWithImpl(this._self, this._then);
+
+  final PaymentError_FeeLimitExceeded _self;
+  final $Res Function(PaymentError_FeeLimitExceeded) _then;
+
+/// Create a copy of PaymentError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? feesSat = null,Object? maxFeeSat = null,}) {
+  return _then(PaymentError_FeeLimitExceeded(
+feesSat: null == feesSat ? _self.feesSat : feesSat // ignore: cast_nullable_to_non_nullable
+as BigInt,maxFeeSat: null == maxFeeSat ? _self.maxFeeSat : maxFeeSat // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
 /// @nodoc
 
 
@@ -498,6 +566,74 @@ String toString() {
 
 
 
+/// @nodoc
+
+
+class PaymentError_SpendLimitExceeded extends PaymentError {
+  const PaymentError_SpendLimitExceeded({required this.amountSat, required this.remainingSat}): super._();
+  
+
+ final  BigInt amountSat;
+ final  BigInt remainingSat;
+
+/// Create a copy of PaymentError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PaymentError_SpendLimitExceededCopyWith<PaymentError_SpendLimitExceeded> get copyWith => _$PaymentError_SpendLimitExceededCopyWithImpl<PaymentError_SpendLimitExceeded>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PaymentError_SpendLimitExceeded&&(identical(other.amountSat, amountSat) || other.amountSat == amountSat)&&(identical(other.remainingSat, remainingSat) || other.remainingSat == remainingSat));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,amountSat,remainingSat);
+
+@override
+String toString() {
+  return 'PaymentError.spendLimitExceeded(amountSat: $amountSat, remainingSat: $remainingSat)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PaymentError_SpendLimitExceededCopyWith<$Res> implements $PaymentErrorCopyWith<$Res> {
+  factory $PaymentError_SpendLimitExceededCopyWith(PaymentError_SpendLimitExceeded value, $Res Function(PaymentError_SpendLimitExceeded) _then) = _$PaymentError_SpendLimitExceededCopyWithImpl;
+@useResult
+$Res call({
+ BigInt amountSat, BigInt remainingSat
+});
+
+
+
+
+}
+/// @nodoc
+class _$PaymentError_SpendLimitExceededCopyWithImpl<$Res>
+    implements $PaymentError_SpendLimitExceededCopyWith<$Res> {
+  _$PaymentError_SpendLimitExceededCopyWithImpl(this._self, this._then);
+
+  final PaymentError_SpendLimitExceeded _self;
+  final $Res Function(PaymentError_SpendLimitExceeded) _then;
+
+/// Create a copy of PaymentError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? amountSat = null,Object? remainingSat = null,}) {
+  return _then(PaymentError_SpendLimitExceeded(
+amountSat: null == amountSat ? _self.amountSat : amountSat // ignore: cast_nullable_to_non_nullable
+as BigInt,remainingSat: null == remainingSat ? _self.remainingSat : remainingSat // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
 /// @nodoc
 
 
@@ -1054,6 +1190,72 @@ as String,
 }
 
 
+}
+
+/// @nodoc
+
+
+class PaymentError_ServiceUnavailable extends PaymentError {
+  const PaymentError_ServiceUnavailable({required this.err}): super._();
+  
+
+ final  String err;
+
+/// Create a copy of PaymentError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PaymentError_ServiceUnavailableCopyWith<PaymentError_ServiceUnavailable> get copyWith => _$PaymentError_ServiceUnavailableCopyWithImpl<PaymentError_ServiceUnavailable>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PaymentError_ServiceUnavailable&&(identical(other.err, err) || other.err == err));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,err);
+
+@override
+String toString() {
+  return 'PaymentError.serviceUnavailable(err: $err)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $PaymentError_ServiceUnavailableCopyWith<$Res> implements $PaymentErrorCopyWith<$Res> {
+  factory $PaymentError_ServiceUnavailableCopyWith(PaymentError_ServiceUnavailable value, $Res Function(PaymentError_ServiceUnavailable) _then) = _$PaymentError_ServiceUnavailableCopyWithImpl;
+@useResult
+$Res call({
+ String err
+});
+
+
+
+
+}
+/// @nodoc
+class _$PaymentError_ServiceUnavailableCopyWithImpl<$Res>
+    implements $PaymentError_ServiceUnavailableCopyWith<$Res> {
+  _$PaymentError_ServiceUnavailableCopyWithImpl(this._self, this._then);
+
+  final PaymentError_ServiceUnavailable _self;
+  final $Res Function(PaymentError_ServiceUnavailable) _then;
+
+/// Create a copy of PaymentError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? err = null,}) {
+  return _then(PaymentError_ServiceUnavailable(
+err: null == err ? _self.err : err // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
 }
 
 /// @nodoc
@@ -1118,6 +1320,72 @@ String toString() {
 
 
 
+/// @nodoc
+
+
+class SdkError_DataDirLocked extends SdkError {
+  const SdkError_DataDirLocked({required this.dir}): super._();
+  
+
+ final  String dir;
+
+/// Create a copy of SdkError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkError_DataDirLockedCopyWith<SdkError_DataDirLocked> get copyWith => _$SdkError_DataDirLockedCopyWithImpl<SdkError_DataDirLocked>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkError_DataDirLocked&&(identical(other.dir, dir) || other.dir == dir));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,dir);
+
+@override
+String toString() {
+  return 'SdkError.dataDirLocked(dir: $dir)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkError_DataDirLockedCopyWith<$Res> implements $SdkErrorCopyWith<$Res> {
+  factory $SdkError_DataDirLockedCopyWith(SdkError_DataDirLocked value, $Res Function(SdkError_DataDirLocked) _then) = _$SdkError_DataDirLockedCopyWithImpl;
+@useResult
+$Res call({
+ String dir
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkError_DataDirLockedCopyWithImpl<$Res>
+    implements $SdkError_DataDirLockedCopyWith<$Res> {
+  _$SdkError_DataDirLockedCopyWithImpl(this._self, this._then);
+
+  final SdkError_DataDirLocked _self;
+  final $Res Function(SdkError_DataDirLocked) _then;
+
+/// Create a copy of SdkError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? dir = null,}) {
+  return _then(SdkError_DataDirLocked(
+dir: null == dir ? _self.dir : dir // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
 /// @nodoc
 
 
@@ -1187,6 +1455,72 @@ as String,
 /// @nodoc
 
 
+class SdkError_InvalidBackup extends SdkError {
+  const SdkError_InvalidBackup({required this.err}): super._();
+  
+
+ final  String err;
+
+/// Create a copy of SdkError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkError_InvalidBackupCopyWith<SdkError_InvalidBackup> get copyWith => _$SdkError_InvalidBackupCopyWithImpl<SdkError_InvalidBackup>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkError_InvalidBackup&&(identical(other.err, err) || other.err == err));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,err);
+
+@override
+String toString() {
+  return 'SdkError.invalidBackup(err: $err)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkError_InvalidBackupCopyWith<$Res> implements $SdkErrorCopyWith<$Res> {
+  factory $SdkError_InvalidBackupCopyWith(SdkError_InvalidBackup value, $Res Function(SdkError_InvalidBackup) _then) = _$SdkError_InvalidBackupCopyWithImpl;
+@useResult
+$Res call({
+ String err
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkError_InvalidBackupCopyWithImpl<$Res>
+    implements $SdkError_InvalidBackupCopyWith<$Res> {
+  _$SdkError_InvalidBackupCopyWithImpl(this._self, this._then);
+
+  final SdkError_InvalidBackup _self;
+  final $Res Function(SdkError_InvalidBackup) _then;
+
+/// Create a copy of SdkError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? err = null,}) {
+  return _then(SdkError_InvalidBackup(
+err: null == err ? _self.err : err // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
 class SdkError_NotStarted extends SdkError {
   const SdkError_NotStarted(): super._();
   
@@ -53,7 +53,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.9.0';
 
   @override
-  int get rustContentHash => 1264782025;
+  int get rustContentHash => 1371610740;
 
   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
     stem: 'breez_sdk_liquid',
@@ -68,7 +68,20 @@ abstract class RustLibApi extends BaseApi {
     required AcceptPaymentProposedFeesRequest req,
   });
 
-  Stream<SdkEvent> crateBindingsBindingLiquidSdkAddEventListener({required BindingLiquidSdk that});
+  Stream<SdkEvent> crateBindingsBindingLiquidSdkAddEventListener({
+    required BindingLiquidSdk that,
+    EventFilter? filter,
+  });
+
+  Future<void> crateBindingsBindingLiquidSdkAddInputParser({
+    required BindingLiquidSdk that,
+    required FutureOr<InputType?> Function(String) parseInput,
+  });
+
+  AddNwcConnectionResponse crateBindingsBindingLiquidSdkAddNwcConnection({
+    required BindingLiquidSdk that,
+    required AddNwcConnectionRequest req,
+  });
 
   void crateBindingsBindingLiquidSdkBackup({required BindingLiquidSdk that, required BackupRequest req});
 
@@ -77,15 +90,64 @@ abstract class RustLibApi extends BaseApi {
     required BuyBitcoinRequest req,
   });
 
+  Future<void> crateBindingsBindingLiquidSdkCancelQueuedPayment({
+    required BindingLiquidSdk that,
+    required String id,
+  });
+
+  Future<void> crateBindingsBindingLiquidSdkCancelRecurringPayment({
+    required BindingLiquidSdk that,
+    required String id,
+  });
+
+  Future<void> crateBindingsBindingLiquidSdkCancelScheduledPayment({
+    required BindingLiquidSdk that,
+    required String id,
+  });
+
+  Future<CheckHealthResponse> crateBindingsBindingLiquidSdkCheckHealth({required BindingLiquidSdk that});
+
+  Future<bool> crateBindingsBindingLiquidSdkCheckLightningAddressAvailable({
+    required BindingLiquidSdk that,
+    required String username,
+  });
+
   CheckMessageResponse crateBindingsBindingLiquidSdkCheckMessage({
     required BindingLiquidSdk that,
     required CheckMessageRequest req,
   });
 
+  Future<void> crateBindingsBindingLiquidSdkClaimSwap({
+    required BindingLiquidSdk that,
+    required String swapId,
+  });
+
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkConvert({
+    required BindingLiquidSdk that,
+    required ConvertRequest req,
+  });
+
+  Future<RecurringPayment> crateBindingsBindingLiquidSdkCreateRecurringPayment({
+    required BindingLiquidSdk that,
+    required CreateRecurringPaymentRequest req,
+  });
+
+  Future<void> crateBindingsBindingLiquidSdkDeleteLightningAddress({required BindingLiquidSdk that});
+
   Future<void> crateBindingsBindingLiquidSdkDisconnect({required BindingLiquidSdk that});
 
   void crateBindingsBindingLiquidSdkEmptyWalletCache({required BindingLiquidSdk that});
 
+  Future<PaymentUnblindingData> crateBindingsBindingLiquidSdkExportPaymentUnblindingData({
+    required BindingLiquidSdk that,
+    required ExportPaymentUnblindingDataRequest req,
+  });
+
+  void crateBindingsBindingLiquidSdkExportWallet({
+    required BindingLiquidSdk that,
+    required ExportWalletRequest req,
+  });
+
   Future<List<Rate>> crateBindingsBindingLiquidSdkFetchFiatRates({required BindingLiquidSdk that});
 
   Future<LightningPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchLightningLimits({
@@ -101,24 +163,81 @@ abstract class RustLibApi extends BaseApi {
     required FetchPaymentProposedFeesRequest req,
   });
 
+  Future<RegisteredAsset?> crateBindingsBindingLiquidSdkFetchRegisteredAsset({
+    required BindingLiquidSdk that,
+    required String assetId,
+  });
+
+  AmpAccount? crateBindingsBindingLiquidSdkGetAmpAccount({required BindingLiquidSdk that});
+
   Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that});
 
+  Future<GetMaxPayableResponse> crateBindingsBindingLiquidSdkGetMaxPayable({
+    required BindingLiquidSdk that,
+    required GetMaxPayableRequest req,
+  });
+
+  Future<GetMaxReceivableResponse> crateBindingsBindingLiquidSdkGetMaxReceivable({
+    required BindingLiquidSdk that,
+    required GetMaxReceivableRequest req,
+  });
+
   Future<Payment?> crateBindingsBindingLiquidSdkGetPayment({
     required BindingLiquidSdk that,
     required GetPaymentRequest req,
   });
 
+  Future<GetReportResponse> crateBindingsBindingLiquidSdkGetReport({
+    required BindingLiquidSdk that,
+    required GetReportRequest req,
+  });
+
+  Future<ReusableBitcoinAddressResponse> crateBindingsBindingLiquidSdkGetReusableBitcoinAddress({
+    required BindingLiquidSdk that,
+  });
+
+  GetWalletInfoResponse crateBindingsBindingLiquidSdkGetWalletInfo({required BindingLiquidSdk that});
+
+  ImportWalletResponse crateBindingsBindingLiquidSdkImportWallet({
+    required BindingLiquidSdk that,
+    required ImportWalletRequest req,
+  });
+
   Future<List<FiatCurrency>> crateBindingsBindingLiquidSdkListFiatCurrencies({
     required BindingLiquidSdk that,
   });
 
+  List<NwcConnection> crateBindingsBindingLiquidSdkListNwcConnections({required BindingLiquidSdk that});
+
   Future<List<Payment>> crateBindingsBindingLiquidSdkListPayments({
     required BindingLiquidSdk that,
     required ListPaymentsRequest req,
   });
 
+  Future<List<QueuedPayment>> crateBindingsBindingLiquidSdkListQueuedPayments({
+    required BindingLiquidSdk that,
+  });
+
+  Future<List<RecurringPaymentOccurrence>> crateBindingsBindingLiquidSdkListRecurringPaymentOccurrences({
+    required BindingLiquidSdk that,
+    required String recurringPaymentId,
+  });
+
+  Future<List<RecurringPayment>> crateBindingsBindingLiquidSdkListRecurringPayments({
+    required BindingLiquidSdk that,
+  });
+
   Future<List<RefundableSwap>> crateBindingsBindingLiquidSdkListRefundables({required BindingLiquidSdk that});
 
+  Future<List<ScheduledPayment>> crateBindingsBindingLiquidSdkListScheduledPayments({
+    required BindingLiquidSdk that,
+  });
+
+  Future<List<SwapInfo>> crateBindingsBindingLiquidSdkListSwaps({
+    required BindingLiquidSdk that,
+    required ListSwapsRequest req,
+  });
+
   Future<LnUrlCallbackStatus> crateBindingsBindingLiquidSdkLnurlAuth({
     required BindingLiquidSdk that,
     required LnUrlAuthRequestData reqData,
@@ -149,6 +268,11 @@ abstract class RustLibApi extends BaseApi {
     required PrepareBuyBitcoinRequest req,
   });
 
+  Future<PrepareConvertResponse> crateBindingsBindingLiquidSdkPrepareConvert({
+    required BindingLiquidSdk that,
+    required PrepareConvertRequest req,
+  });
+
   Future<PrepareLnUrlPayResponse> crateBindingsBindingLiquidSdkPrepareLnurlPay({
     required BindingLiquidSdk that,
     required PrepareLnUrlPayRequest req,
@@ -164,6 +288,11 @@ abstract class RustLibApi extends BaseApi {
     required PrepareReceiveRequest req,
   });
 
+  Future<PrepareReceiveQuotesResponse> crateBindingsBindingLiquidSdkPrepareReceivePaymentQuotes({
+    required BindingLiquidSdk that,
+    required PrepareReceiveQuotesRequest req,
+  });
+
   Future<PrepareRefundResponse> crateBindingsBindingLiquidSdkPrepareRefund({
     required BindingLiquidSdk that,
     required PrepareRefundRequest req,
@@ -174,6 +303,11 @@ abstract class RustLibApi extends BaseApi {
     required PrepareSendRequest req,
   });
 
+  Future<QueuedPayment> crateBindingsBindingLiquidSdkQueuePayment({
+    required BindingLiquidSdk that,
+    required QueuePaymentRequest req,
+  });
+
   Future<ReceivePaymentResponse> crateBindingsBindingLiquidSdkReceivePayment({
     required BindingLiquidSdk that,
     required ReceivePaymentRequest req,
@@ -181,47 +315,111 @@ abstract class RustLibApi extends BaseApi {
 
   Future<RecommendedFees> crateBindingsBindingLiquidSdkRecommendedFees({required BindingLiquidSdk that});
 
+  Future<LightningAddressInfo?> crateBindingsBindingLiquidSdkRecoverLightningAddress({
+    required BindingLiquidSdk that,
+  });
+
   Future<RefundResponse> crateBindingsBindingLiquidSdkRefund({
     required BindingLiquidSdk that,
     required RefundRequest req,
   });
 
+  Future<AmpAccount> crateBindingsBindingLiquidSdkRegisterAmpAccount({required BindingLiquidSdk that});
+
+  Future<LightningAddressInfo> crateBindingsBindingLiquidSdkRegisterLightningAddress({
+    required BindingLiquidSdk that,
+    required RegisterLightningAddressRequest req,
+  });
+
   Future<void> crateBindingsBindingLiquidSdkRegisterWebhook({
     required BindingLiquidSdk that,
     required String webhookUrl,
   });
 
+  void crateBindingsBindingLiquidSdkRemoveNwcConnection({
+    required BindingLiquidSdk that,
+    required String name,
+  });
+
+  Future<List<JournaledEvent>> crateBindingsBindingLiquidSdkReplayEvents({
+    required BindingLiquidSdk that,
+    required ReplayEventsRequest req,
+  });
+
   Future<void> crateBindingsBindingLiquidSdkRescanOnchainSwaps({required BindingLiquidSdk that});
 
   void crateBindingsBindingLiquidSdkRestore({required BindingLiquidSdk that, required RestoreRequest req});
 
+  Future<ScheduledPayment> crateBindingsBindingLiquidSdkSchedulePayment({
+    required BindingLiquidSdk that,
+    required SchedulePaymentRequest req,
+  });
+
+  Future<SendBatchResponse> crateBindingsBindingLiquidSdkSendBatch({
+    required BindingLiquidSdk that,
+    required SendBatchRequest req,
+  });
+
   Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendPayment({
     required BindingLiquidSdk that,
     required SendPaymentRequest req,
   });
 
+  Future<void> crateBindingsBindingLiquidSdkSetBroadcaster({
+    required BindingLiquidSdk that,
+    required FutureOr<String> Function(String) broadcastLiquidTx,
+    required FutureOr<String> Function(String) broadcastBitcoinTx,
+  });
+
   SignMessageResponse crateBindingsBindingLiquidSdkSignMessage({
     required BindingLiquidSdk that,
     required SignMessageRequest req,
   });
 
-  Future<void> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that});
+  Future<SignPsetResponse> crateBindingsBindingLiquidSdkSignPset({
+    required BindingLiquidSdk that,
+    required SignPsetRequest req,
+  });
+
+  Future<SweepPrivateKeyResponse> crateBindingsBindingLiquidSdkSweepPrivateKey({
+    required BindingLiquidSdk that,
+    required SweepPrivateKeyRequest req,
+  });
+
+  Future<SyncResponse> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that, SyncRequest? req});
 
   Future<void> crateBindingsBindingLiquidSdkUnregisterWebhook({required BindingLiquidSdk that});
 
-  Future<void> crateBindingsBindingEventListenerOnEvent({
-    required BindingEventListener that,
-    required SdkEvent e,
+  void crateBindingsBindingLiquidSdkVerifyBackup({
+    required BindingLiquidSdk that,
+    required RestoreRequest req,
   });
 
   Stream<LogEntry> crateBindingsBreezLogStream();
 
   Future<BindingLiquidSdk> crateBindingsConnect({required ConnectRequest req});
 
+  String crateBindingsCreateSpendLimitOverride({required String secret, required int expiresAt});
+
+  DecodedInvoice crateBindingsDecodeInvoice({required String input});
+
   Config crateBindingsDefaultConfig({required LiquidNetwork network, String? breezApiKey});
 
+  String crateBindingsGenerateMnemonic({required int wordCount});
+
   LNInvoice crateBindingsParseInvoice({required String input});
 
+  Future<ProcessNotificationResponse> crateBindingsProcessNotification({
+    required ProcessNotificationRequest req,
+  });
+
+  ValidateAddressResponse crateBindingsValidateAddress({
+    required String address,
+    required LiquidNetwork network,
+  });
+
+  void crateBindingsValidateMnemonic({required String phrase});
+
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BindingLiquidSdk;
 
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BindingLiquidSdk;
@@ -266,7 +464,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Stream<SdkEvent> crateBindingsBindingLiquidSdkAddEventListener({required BindingLiquidSdk that}) {
+  Stream<SdkEvent> crateBindingsBindingLiquidSdkAddEventListener({
+    required BindingLiquidSdk that,
+    EventFilter? filter,
+  }) {
     final listener = RustStreamSink<SdkEvent>();
     unawaited(
       handler.executeNormal(
@@ -277,11 +478,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
                   that,
                 );
             var arg1 = cst_encode_StreamSink_sdk_event_Dco(listener);
-            return wire.wire__crate__bindings__BindingLiquidSdk_add_event_listener(port_, arg0, arg1);
+            var arg2 = cst_encode_opt_box_autoadd_event_filter(filter);
+            return wire.wire__crate__bindings__BindingLiquidSdk_add_event_listener(port_, arg0, arg1, arg2);
           },
           codec: DcoCodec(decodeSuccessData: dco_decode_String, decodeErrorData: dco_decode_sdk_error),
           constMeta: kCrateBindingsBindingLiquidSdkAddEventListenerConstMeta,
-          argValues: [that, listener],
+          argValues: [that, listener, filter],
           apiImpl: this,
         ),
       ),
@@ -289,8 +491,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return listener.stream;
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkAddEventListenerConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_add_event_listener", argNames: ["that", "listener"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkAddEventListenerConstMeta => const TaskConstMeta(
+    debugName: "BindingLiquidSdk_add_event_listener",
+    argNames: ["that", "listener", "filter"],
+  );
+
+  @override
+  Future<void> crateBindingsBindingLiquidSdkAddInputParser({
+    required BindingLiquidSdk that,
+    required FutureOr<InputType?> Function(String) parseInput,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(
+            parseInput,
+          );
+          return wire.wire__crate__bindings__BindingLiquidSdk_add_input_parser(port_, arg0, arg1);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkAddInputParserConstMeta,
+        argValues: [that, parseInput],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkAddInputParserConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_add_input_parser", argNames: ["that", "parseInput"]);
+
+  @override
+  AddNwcConnectionResponse crateBindingsBindingLiquidSdkAddNwcConnection({
+    required BindingLiquidSdk that,
+    required AddNwcConnectionRequest req,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_add_nwc_connection_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_add_nwc_connection(arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_add_nwc_connection_response,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkAddNwcConnectionConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkAddNwcConnectionConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_add_nwc_connection", argNames: ["that", "req"]);
 
   @override
   void crateBindingsBindingLiquidSdkBackup({required BindingLiquidSdk that, required BackupRequest req}) {
@@ -342,36 +603,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "BindingLiquidSdk_buy_bitcoin", argNames: ["that", "req"]);
 
   @override
-  CheckMessageResponse crateBindingsBindingLiquidSdkCheckMessage({
+  Future<void> crateBindingsBindingLiquidSdkCancelQueuedPayment({
     required BindingLiquidSdk that,
-    required CheckMessageRequest req,
+    required String id,
   }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           var arg0 =
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_check_message_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_check_message(arg0, arg1);
+          var arg1 = cst_encode_String(id);
+          return wire.wire__crate__bindings__BindingLiquidSdk_cancel_queued_payment(port_, arg0, arg1);
         },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_check_message_response,
-          decodeErrorData: dco_decode_sdk_error,
-        ),
-        constMeta: kCrateBindingsBindingLiquidSdkCheckMessageConstMeta,
-        argValues: [that, req],
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkCancelQueuedPaymentConstMeta,
+        argValues: [that, id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkCheckMessageConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_check_message", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkCancelQueuedPaymentConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_cancel_queued_payment", argNames: ["that", "id"]);
 
   @override
-  Future<void> crateBindingsBindingLiquidSdkDisconnect({required BindingLiquidSdk that}) {
+  Future<void> crateBindingsBindingLiquidSdkCancelRecurringPayment({
+    required BindingLiquidSdk that,
+    required String id,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -379,43 +640,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          return wire.wire__crate__bindings__BindingLiquidSdk_disconnect(port_, arg0);
+          var arg1 = cst_encode_String(id);
+          return wire.wire__crate__bindings__BindingLiquidSdk_cancel_recurring_payment(port_, arg0, arg1);
         },
         codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
-        constMeta: kCrateBindingsBindingLiquidSdkDisconnectConstMeta,
-        argValues: [that],
+        constMeta: kCrateBindingsBindingLiquidSdkCancelRecurringPaymentConstMeta,
+        argValues: [that, id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkDisconnectConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_disconnect", argNames: ["that"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkCancelRecurringPaymentConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_cancel_recurring_payment", argNames: ["that", "id"]);
 
   @override
-  void crateBindingsBindingLiquidSdkEmptyWalletCache({required BindingLiquidSdk that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<void> crateBindingsBindingLiquidSdkCancelScheduledPayment({
+    required BindingLiquidSdk that,
+    required String id,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           var arg0 =
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          return wire.wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(arg0);
+          var arg1 = cst_encode_String(id);
+          return wire.wire__crate__bindings__BindingLiquidSdk_cancel_scheduled_payment(port_, arg0, arg1);
         },
         codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
-        constMeta: kCrateBindingsBindingLiquidSdkEmptyWalletCacheConstMeta,
-        argValues: [that],
+        constMeta: kCrateBindingsBindingLiquidSdkCancelScheduledPaymentConstMeta,
+        argValues: [that, id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkEmptyWalletCacheConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_empty_wallet_cache", argNames: ["that"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkCancelScheduledPaymentConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_cancel_scheduled_payment", argNames: ["that", "id"]);
 
   @override
-  Future<List<Rate>> crateBindingsBindingLiquidSdkFetchFiatRates({required BindingLiquidSdk that}) {
+  Future<CheckHealthResponse> crateBindingsBindingLiquidSdkCheckHealth({required BindingLiquidSdk that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -423,22 +689,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          return wire.wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(port_, arg0);
+          return wire.wire__crate__bindings__BindingLiquidSdk_check_health(port_, arg0);
         },
-        codec: DcoCodec(decodeSuccessData: dco_decode_list_rate, decodeErrorData: dco_decode_sdk_error),
-        constMeta: kCrateBindingsBindingLiquidSdkFetchFiatRatesConstMeta,
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_check_health_response,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkCheckHealthConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchFiatRatesConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_fetch_fiat_rates", argNames: ["that"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkCheckHealthConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_check_health", argNames: ["that"]);
 
   @override
-  Future<LightningPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchLightningLimits({
+  Future<bool> crateBindingsBindingLiquidSdkCheckLightningAddressAvailable({
     required BindingLiquidSdk that,
+    required String username,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -447,53 +717,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          return wire.wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits(port_, arg0);
+          var arg1 = cst_encode_String(username);
+          return wire.wire__crate__bindings__BindingLiquidSdk_check_lightning_address_available(
+            port_,
+            arg0,
+            arg1,
+          );
         },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_lightning_payment_limits_response,
-          decodeErrorData: dco_decode_payment_error,
-        ),
-        constMeta: kCrateBindingsBindingLiquidSdkFetchLightningLimitsConstMeta,
-        argValues: [that],
+        codec: DcoCodec(decodeSuccessData: dco_decode_bool, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkCheckLightningAddressAvailableConstMeta,
+        argValues: [that, username],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchLightningLimitsConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_fetch_lightning_limits", argNames: ["that"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkCheckLightningAddressAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "BindingLiquidSdk_check_lightning_address_available",
+        argNames: ["that", "username"],
+      );
 
   @override
-  Future<OnchainPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchOnchainLimits({
+  CheckMessageResponse crateBindingsBindingLiquidSdkCheckMessage({
     required BindingLiquidSdk that,
+    required CheckMessageRequest req,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           var arg0 =
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          return wire.wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits(port_, arg0);
+          var arg1 = cst_encode_box_autoadd_check_message_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_check_message(arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_onchain_payment_limits_response,
-          decodeErrorData: dco_decode_payment_error,
+          decodeSuccessData: dco_decode_check_message_response,
+          decodeErrorData: dco_decode_sdk_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkFetchOnchainLimitsConstMeta,
-        argValues: [that],
+        constMeta: kCrateBindingsBindingLiquidSdkCheckMessageConstMeta,
+        argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchOnchainLimitsConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_fetch_onchain_limits", argNames: ["that"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkCheckMessageConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_check_message", argNames: ["that", "req"]);
 
   @override
-  Future<FetchPaymentProposedFeesResponse> crateBindingsBindingLiquidSdkFetchPaymentProposedFees({
+  Future<void> crateBindingsBindingLiquidSdkClaimSwap({
     required BindingLiquidSdk that,
-    required FetchPaymentProposedFeesRequest req,
+    required String swapId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -502,27 +779,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_fetch_payment_proposed_fees_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees(port_, arg0, arg1);
+          var arg1 = cst_encode_String(swapId);
+          return wire.wire__crate__bindings__BindingLiquidSdk_claim_swap(port_, arg0, arg1);
         },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_fetch_payment_proposed_fees_response,
-          decodeErrorData: dco_decode_sdk_error,
-        ),
-        constMeta: kCrateBindingsBindingLiquidSdkFetchPaymentProposedFeesConstMeta,
-        argValues: [that, req],
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_payment_error),
+        constMeta: kCrateBindingsBindingLiquidSdkClaimSwapConstMeta,
+        argValues: [that, swapId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchPaymentProposedFeesConstMeta => const TaskConstMeta(
-    debugName: "BindingLiquidSdk_fetch_payment_proposed_fees",
-    argNames: ["that", "req"],
-  );
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkClaimSwapConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_claim_swap", argNames: ["that", "swapId"]);
 
   @override
-  Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that}) {
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkConvert({
+    required BindingLiquidSdk that,
+    required ConvertRequest req,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -530,26 +805,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          return wire.wire__crate__bindings__BindingLiquidSdk_get_info(port_, arg0);
+          var arg1 = cst_encode_box_autoadd_convert_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_convert(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_get_info_response,
-          decodeErrorData: dco_decode_sdk_error,
+          decodeSuccessData: dco_decode_send_payment_response,
+          decodeErrorData: dco_decode_payment_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkGetInfoConstMeta,
-        argValues: [that],
+        constMeta: kCrateBindingsBindingLiquidSdkConvertConstMeta,
+        argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetInfoConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_get_info", argNames: ["that"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkConvertConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_convert", argNames: ["that", "req"]);
 
   @override
-  Future<Payment?> crateBindingsBindingLiquidSdkGetPayment({
+  Future<RecurringPayment> crateBindingsBindingLiquidSdkCreateRecurringPayment({
     required BindingLiquidSdk that,
-    required GetPaymentRequest req,
+    required CreateRecurringPaymentRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -558,27 +834,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_get_payment_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_get_payment(port_, arg0, arg1);
+          var arg1 = cst_encode_box_autoadd_create_recurring_payment_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_create_recurring_payment(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_opt_box_autoadd_payment,
-          decodeErrorData: dco_decode_payment_error,
+          decodeSuccessData: dco_decode_recurring_payment,
+          decodeErrorData: dco_decode_sdk_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkGetPaymentConstMeta,
+        constMeta: kCrateBindingsBindingLiquidSdkCreateRecurringPaymentConstMeta,
         argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetPaymentConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_get_payment", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkCreateRecurringPaymentConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_create_recurring_payment", argNames: ["that", "req"]);
 
   @override
-  Future<List<FiatCurrency>> crateBindingsBindingLiquidSdkListFiatCurrencies({
-    required BindingLiquidSdk that,
-  }) {
+  Future<void> crateBindingsBindingLiquidSdkDeleteLightningAddress({required BindingLiquidSdk that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -586,27 +860,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          return wire.wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(port_, arg0);
+          return wire.wire__crate__bindings__BindingLiquidSdk_delete_lightning_address(port_, arg0);
         },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_fiat_currency,
-          decodeErrorData: dco_decode_sdk_error,
-        ),
-        constMeta: kCrateBindingsBindingLiquidSdkListFiatCurrenciesConstMeta,
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkDeleteLightningAddressConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkListFiatCurrenciesConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_list_fiat_currencies", argNames: ["that"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkDeleteLightningAddressConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_delete_lightning_address", argNames: ["that"]);
 
   @override
-  Future<List<Payment>> crateBindingsBindingLiquidSdkListPayments({
-    required BindingLiquidSdk that,
-    required ListPaymentsRequest req,
-  }) {
+  Future<void> crateBindingsBindingLiquidSdkDisconnect({required BindingLiquidSdk that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -614,54 +882,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_list_payments_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_list_payments(port_, arg0, arg1);
+          return wire.wire__crate__bindings__BindingLiquidSdk_disconnect(port_, arg0);
         },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_payment,
-          decodeErrorData: dco_decode_payment_error,
-        ),
-        constMeta: kCrateBindingsBindingLiquidSdkListPaymentsConstMeta,
-        argValues: [that, req],
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkDisconnectConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkListPaymentsConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_list_payments", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkDisconnectConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_disconnect", argNames: ["that"]);
 
   @override
-  Future<List<RefundableSwap>> crateBindingsBindingLiquidSdkListRefundables({
-    required BindingLiquidSdk that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  void crateBindingsBindingLiquidSdkEmptyWalletCache({required BindingLiquidSdk that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           var arg0 =
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          return wire.wire__crate__bindings__BindingLiquidSdk_list_refundables(port_, arg0);
+          return wire.wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(arg0);
         },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_list_refundable_swap,
-          decodeErrorData: dco_decode_sdk_error,
-        ),
-        constMeta: kCrateBindingsBindingLiquidSdkListRefundablesConstMeta,
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkEmptyWalletCacheConstMeta,
         argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkListRefundablesConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_list_refundables", argNames: ["that"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkEmptyWalletCacheConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_empty_wallet_cache", argNames: ["that"]);
 
   @override
-  Future<LnUrlCallbackStatus> crateBindingsBindingLiquidSdkLnurlAuth({
+  Future<PaymentUnblindingData> crateBindingsBindingLiquidSdkExportPaymentUnblindingData({
     required BindingLiquidSdk that,
-    required LnUrlAuthRequestData reqData,
+    required ExportPaymentUnblindingDataRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -670,57 +929,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_ln_url_auth_request_data(reqData);
-          return wire.wire__crate__bindings__BindingLiquidSdk_lnurl_auth(port_, arg0, arg1);
+          var arg1 = cst_encode_box_autoadd_export_payment_unblinding_data_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_export_payment_unblinding_data(
+            port_,
+            arg0,
+            arg1,
+          );
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_ln_url_callback_status,
-          decodeErrorData: dco_decode_ln_url_auth_error,
+          decodeSuccessData: dco_decode_payment_unblinding_data,
+          decodeErrorData: dco_decode_sdk_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkLnurlAuthConstMeta,
-        argValues: [that, reqData],
+        constMeta: kCrateBindingsBindingLiquidSdkExportPaymentUnblindingDataConstMeta,
+        argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkLnurlAuthConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_lnurl_auth", argNames: ["that", "reqData"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkExportPaymentUnblindingDataConstMeta => const TaskConstMeta(
+    debugName: "BindingLiquidSdk_export_payment_unblinding_data",
+    argNames: ["that", "req"],
+  );
 
   @override
-  Future<LnUrlPayResult> crateBindingsBindingLiquidSdkLnurlPay({
+  void crateBindingsBindingLiquidSdkExportWallet({
     required BindingLiquidSdk that,
-    required LnUrlPayRequest req,
+    required ExportWalletRequest req,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           var arg0 =
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_ln_url_pay_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_lnurl_pay(port_, arg0, arg1);
+          var arg1 = cst_encode_box_autoadd_export_wallet_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_export_wallet(arg0, arg1);
         },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_ln_url_pay_result,
-          decodeErrorData: dco_decode_ln_url_pay_error,
-        ),
-        constMeta: kCrateBindingsBindingLiquidSdkLnurlPayConstMeta,
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkExportWalletConstMeta,
         argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkLnurlPayConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_lnurl_pay", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkExportWalletConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_export_wallet", argNames: ["that", "req"]);
 
   @override
-  Future<LnUrlWithdrawResult> crateBindingsBindingLiquidSdkLnurlWithdraw({
-    required BindingLiquidSdk that,
-    required LnUrlWithdrawRequest req,
-  }) {
+  Future<List<Rate>> crateBindingsBindingLiquidSdkFetchFiatRates({required BindingLiquidSdk that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -728,27 +987,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_ln_url_withdraw_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(port_, arg0, arg1);
+          return wire.wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(port_, arg0);
         },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_ln_url_withdraw_result,
-          decodeErrorData: dco_decode_ln_url_withdraw_error,
-        ),
-        constMeta: kCrateBindingsBindingLiquidSdkLnurlWithdrawConstMeta,
-        argValues: [that, req],
+        codec: DcoCodec(decodeSuccessData: dco_decode_list_rate, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkFetchFiatRatesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkLnurlWithdrawConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_lnurl_withdraw", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchFiatRatesConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_fetch_fiat_rates", argNames: ["that"]);
 
   @override
-  Future<InputType> crateBindingsBindingLiquidSdkParse({
+  Future<LightningPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchLightningLimits({
     required BindingLiquidSdk that,
-    required String input,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -757,24 +1011,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_String(input);
-          return wire.wire__crate__bindings__BindingLiquidSdk_parse(port_, arg0, arg1);
+          return wire.wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits(port_, arg0);
         },
-        codec: DcoCodec(decodeSuccessData: dco_decode_input_type, decodeErrorData: dco_decode_payment_error),
-        constMeta: kCrateBindingsBindingLiquidSdkParseConstMeta,
-        argValues: [that, input],
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_lightning_payment_limits_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkFetchLightningLimitsConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkParseConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_parse", argNames: ["that", "input"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchLightningLimitsConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_fetch_lightning_limits", argNames: ["that"]);
 
   @override
-  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkPayOnchain({
+  Future<OnchainPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchOnchainLimits({
     required BindingLiquidSdk that,
-    required PayOnchainRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -783,27 +1038,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_pay_onchain_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_pay_onchain(port_, arg0, arg1);
+          return wire.wire__crate__bindings__BindingLiquidSdk_fetch_onchain_limits(port_, arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_send_payment_response,
+          decodeSuccessData: dco_decode_onchain_payment_limits_response,
           decodeErrorData: dco_decode_payment_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkPayOnchainConstMeta,
-        argValues: [that, req],
+        constMeta: kCrateBindingsBindingLiquidSdkFetchOnchainLimitsConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkPayOnchainConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_pay_onchain", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchOnchainLimitsConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_fetch_onchain_limits", argNames: ["that"]);
 
   @override
-  Future<PrepareBuyBitcoinResponse> crateBindingsBindingLiquidSdkPrepareBuyBitcoin({
+  Future<FetchPaymentProposedFeesResponse> crateBindingsBindingLiquidSdkFetchPaymentProposedFees({
     required BindingLiquidSdk that,
-    required PrepareBuyBitcoinRequest req,
+    required FetchPaymentProposedFeesRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -812,27 +1066,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_prepare_buy_bitcoin_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_buy_bitcoin(port_, arg0, arg1);
+          var arg1 = cst_encode_box_autoadd_fetch_payment_proposed_fees_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_fees(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_prepare_buy_bitcoin_response,
-          decodeErrorData: dco_decode_payment_error,
+          decodeSuccessData: dco_decode_fetch_payment_proposed_fees_response,
+          decodeErrorData: dco_decode_sdk_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkPrepareBuyBitcoinConstMeta,
+        constMeta: kCrateBindingsBindingLiquidSdkFetchPaymentProposedFeesConstMeta,
         argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareBuyBitcoinConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_buy_bitcoin", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchPaymentProposedFeesConstMeta => const TaskConstMeta(
+    debugName: "BindingLiquidSdk_fetch_payment_proposed_fees",
+    argNames: ["that", "req"],
+  );
 
   @override
-  Future<PrepareLnUrlPayResponse> crateBindingsBindingLiquidSdkPrepareLnurlPay({
+  Future<RegisteredAsset?> crateBindingsBindingLiquidSdkFetchRegisteredAsset({
     required BindingLiquidSdk that,
-    required PrepareLnUrlPayRequest req,
+    required String assetId,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -841,57 +1097,52 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_prepare_ln_url_pay_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(port_, arg0, arg1);
+          var arg1 = cst_encode_String(assetId);
+          return wire.wire__crate__bindings__BindingLiquidSdk_fetch_registered_asset(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_prepare_ln_url_pay_response,
-          decodeErrorData: dco_decode_ln_url_pay_error,
+          decodeSuccessData: dco_decode_opt_box_autoadd_registered_asset,
+          decodeErrorData: dco_decode_sdk_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkPrepareLnurlPayConstMeta,
-        argValues: [that, req],
+        constMeta: kCrateBindingsBindingLiquidSdkFetchRegisteredAssetConstMeta,
+        argValues: [that, assetId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareLnurlPayConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_lnurl_pay", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchRegisteredAssetConstMeta => const TaskConstMeta(
+    debugName: "BindingLiquidSdk_fetch_registered_asset",
+    argNames: ["that", "assetId"],
+  );
 
   @override
-  Future<PreparePayOnchainResponse> crateBindingsBindingLiquidSdkPreparePayOnchain({
-    required BindingLiquidSdk that,
-    required PreparePayOnchainRequest req,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  AmpAccount? crateBindingsBindingLiquidSdkGetAmpAccount({required BindingLiquidSdk that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           var arg0 =
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_prepare_pay_onchain_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain(port_, arg0, arg1);
+          return wire.wire__crate__bindings__BindingLiquidSdk_get_amp_account(arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_prepare_pay_onchain_response,
-          decodeErrorData: dco_decode_payment_error,
+          decodeSuccessData: dco_decode_opt_box_autoadd_amp_account,
+          decodeErrorData: dco_decode_sdk_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkPreparePayOnchainConstMeta,
-        argValues: [that, req],
+        constMeta: kCrateBindingsBindingLiquidSdkGetAmpAccountConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkPreparePayOnchainConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_pay_onchain", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetAmpAccountConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_get_amp_account", argNames: ["that"]);
 
   @override
-  Future<PrepareReceiveResponse> crateBindingsBindingLiquidSdkPrepareReceivePayment({
-    required BindingLiquidSdk that,
-    required PrepareReceiveRequest req,
-  }) {
+  Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -899,27 +1150,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_prepare_receive_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment(port_, arg0, arg1);
+          return wire.wire__crate__bindings__BindingLiquidSdk_get_info(port_, arg0);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_prepare_receive_response,
-          decodeErrorData: dco_decode_payment_error,
+          decodeSuccessData: dco_decode_get_info_response,
+          decodeErrorData: dco_decode_sdk_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkPrepareReceivePaymentConstMeta,
-        argValues: [that, req],
+        constMeta: kCrateBindingsBindingLiquidSdkGetInfoConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareReceivePaymentConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_receive_payment", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetInfoConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_get_info", argNames: ["that"]);
 
   @override
-  Future<PrepareRefundResponse> crateBindingsBindingLiquidSdkPrepareRefund({
+  Future<GetMaxPayableResponse> crateBindingsBindingLiquidSdkGetMaxPayable({
     required BindingLiquidSdk that,
-    required PrepareRefundRequest req,
+    required GetMaxPayableRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -928,27 +1178,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_prepare_refund_request(req);
-          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_refund(port_, arg0, arg1);
+          var arg1 = cst_encode_box_autoadd_get_max_payable_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_get_max_payable(port_, arg0, arg1);
         },
         codec: DcoCodec(
-          decodeSuccessData: dco_decode_prepare_refund_response,
-          decodeErrorData: dco_decode_sdk_error,
+          decodeSuccessData: dco_decode_get_max_payable_response,
+          decodeErrorData: dco_decode_payment_error,
         ),
-        constMeta: kCrateBindingsBindingLiquidSdkPrepareRefundConstMeta,
+        constMeta: kCrateBindingsBindingLiquidSdkGetMaxPayableConstMeta,
         argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareRefundConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_refund", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetMaxPayableConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_get_max_payable", argNames: ["that", "req"]);
 
   @override
-  Future<PrepareSendResponse> crateBindingsBindingLiquidSdkPrepareSendPayment({
+  Future<GetMaxReceivableResponse> crateBindingsBindingLiquidSdkGetMaxReceivable({
     required BindingLiquidSdk that,
-    required PrepareSendRequest req,
+    required GetMaxReceivableRequest req,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -957,22 +1207,818 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          var arg1 = cst_encode_box_autoadd_prepare_send_request(req);
+          var arg1 = cst_encode_box_autoadd_get_max_receivable_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_get_max_receivable(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_get_max_receivable_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkGetMaxReceivableConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetMaxReceivableConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_get_max_receivable", argNames: ["that", "req"]);
+
+  @override
+  Future<Payment?> crateBindingsBindingLiquidSdkGetPayment({
+    required BindingLiquidSdk that,
+    required GetPaymentRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_get_payment_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_get_payment(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_payment,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkGetPaymentConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetPaymentConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_get_payment", argNames: ["that", "req"]);
+
+  @override
+  Future<GetReportResponse> crateBindingsBindingLiquidSdkGetReport({
+    required BindingLiquidSdk that,
+    required GetReportRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_get_report_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_get_report(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_get_report_response,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkGetReportConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetReportConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_get_report", argNames: ["that", "req"]);
+
+  @override
+  Future<ReusableBitcoinAddressResponse> crateBindingsBindingLiquidSdkGetReusableBitcoinAddress({
+    required BindingLiquidSdk that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_get_reusable_bitcoin_address(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_reusable_bitcoin_address_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkGetReusableBitcoinAddressConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetReusableBitcoinAddressConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_get_reusable_bitcoin_address", argNames: ["that"]);
+
+  @override
+  GetWalletInfoResponse crateBindingsBindingLiquidSdkGetWalletInfo({required BindingLiquidSdk that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_get_wallet_info(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_get_wallet_info_response,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkGetWalletInfoConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetWalletInfoConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_get_wallet_info", argNames: ["that"]);
+
+  @override
+  ImportWalletResponse crateBindingsBindingLiquidSdkImportWallet({
+    required BindingLiquidSdk that,
+    required ImportWalletRequest req,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_import_wallet_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_import_wallet(arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_import_wallet_response,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkImportWalletConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkImportWalletConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_import_wallet", argNames: ["that", "req"]);
+
+  @override
+  Future<List<FiatCurrency>> crateBindingsBindingLiquidSdkListFiatCurrencies({
+    required BindingLiquidSdk that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_fiat_currency,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkListFiatCurrenciesConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListFiatCurrenciesConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_list_fiat_currencies", argNames: ["that"]);
+
+  @override
+  List<NwcConnection> crateBindingsBindingLiquidSdkListNwcConnections({required BindingLiquidSdk that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_list_nwc_connections(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_nwc_connection,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkListNwcConnectionsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListNwcConnectionsConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_list_nwc_connections", argNames: ["that"]);
+
+  @override
+  Future<List<Payment>> crateBindingsBindingLiquidSdkListPayments({
+    required BindingLiquidSdk that,
+    required ListPaymentsRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_list_payments_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_list_payments(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_payment,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkListPaymentsConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListPaymentsConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_list_payments", argNames: ["that", "req"]);
+
+  @override
+  Future<List<QueuedPayment>> crateBindingsBindingLiquidSdkListQueuedPayments({
+    required BindingLiquidSdk that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_list_queued_payments(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_queued_payment,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkListQueuedPaymentsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListQueuedPaymentsConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_list_queued_payments", argNames: ["that"]);
+
+  @override
+  Future<List<RecurringPaymentOccurrence>> crateBindingsBindingLiquidSdkListRecurringPaymentOccurrences({
+    required BindingLiquidSdk that,
+    required String recurringPaymentId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_String(recurringPaymentId);
+          return wire.wire__crate__bindings__BindingLiquidSdk_list_recurring_payment_occurrences(
+            port_,
+            arg0,
+            arg1,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_recurring_payment_occurrence,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkListRecurringPaymentOccurrencesConstMeta,
+        argValues: [that, recurringPaymentId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListRecurringPaymentOccurrencesConstMeta =>
+      const TaskConstMeta(
+        debugName: "BindingLiquidSdk_list_recurring_payment_occurrences",
+        argNames: ["that", "recurringPaymentId"],
+      );
+
+  @override
+  Future<List<RecurringPayment>> crateBindingsBindingLiquidSdkListRecurringPayments({
+    required BindingLiquidSdk that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_list_recurring_payments(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_recurring_payment,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkListRecurringPaymentsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListRecurringPaymentsConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_list_recurring_payments", argNames: ["that"]);
+
+  @override
+  Future<List<RefundableSwap>> crateBindingsBindingLiquidSdkListRefundables({
+    required BindingLiquidSdk that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_list_refundables(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_refundable_swap,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkListRefundablesConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListRefundablesConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_list_refundables", argNames: ["that"]);
+
+  @override
+  Future<List<ScheduledPayment>> crateBindingsBindingLiquidSdkListScheduledPayments({
+    required BindingLiquidSdk that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_list_scheduled_payments(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_scheduled_payment,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkListScheduledPaymentsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListScheduledPaymentsConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_list_scheduled_payments", argNames: ["that"]);
+
+  @override
+  Future<List<SwapInfo>> crateBindingsBindingLiquidSdkListSwaps({
+    required BindingLiquidSdk that,
+    required ListSwapsRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_list_swaps_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_list_swaps(port_, arg0, arg1);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_list_swap_info, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkListSwapsConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListSwapsConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_list_swaps", argNames: ["that", "req"]);
+
+  @override
+  Future<LnUrlCallbackStatus> crateBindingsBindingLiquidSdkLnurlAuth({
+    required BindingLiquidSdk that,
+    required LnUrlAuthRequestData reqData,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_ln_url_auth_request_data(reqData);
+          return wire.wire__crate__bindings__BindingLiquidSdk_lnurl_auth(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_ln_url_callback_status,
+          decodeErrorData: dco_decode_ln_url_auth_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkLnurlAuthConstMeta,
+        argValues: [that, reqData],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkLnurlAuthConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_lnurl_auth", argNames: ["that", "reqData"]);
+
+  @override
+  Future<LnUrlPayResult> crateBindingsBindingLiquidSdkLnurlPay({
+    required BindingLiquidSdk that,
+    required LnUrlPayRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_ln_url_pay_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_lnurl_pay(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_ln_url_pay_result,
+          decodeErrorData: dco_decode_ln_url_pay_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkLnurlPayConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkLnurlPayConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_lnurl_pay", argNames: ["that", "req"]);
+
+  @override
+  Future<LnUrlWithdrawResult> crateBindingsBindingLiquidSdkLnurlWithdraw({
+    required BindingLiquidSdk that,
+    required LnUrlWithdrawRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_ln_url_withdraw_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_ln_url_withdraw_result,
+          decodeErrorData: dco_decode_ln_url_withdraw_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkLnurlWithdrawConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkLnurlWithdrawConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_lnurl_withdraw", argNames: ["that", "req"]);
+
+  @override
+  Future<InputType> crateBindingsBindingLiquidSdkParse({
+    required BindingLiquidSdk that,
+    required String input,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_String(input);
+          return wire.wire__crate__bindings__BindingLiquidSdk_parse(port_, arg0, arg1);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_input_type, decodeErrorData: dco_decode_payment_error),
+        constMeta: kCrateBindingsBindingLiquidSdkParseConstMeta,
+        argValues: [that, input],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkParseConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_parse", argNames: ["that", "input"]);
+
+  @override
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkPayOnchain({
+    required BindingLiquidSdk that,
+    required PayOnchainRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_pay_onchain_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_pay_onchain(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_send_payment_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkPayOnchainConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPayOnchainConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_pay_onchain", argNames: ["that", "req"]);
+
+  @override
+  Future<PrepareBuyBitcoinResponse> crateBindingsBindingLiquidSdkPrepareBuyBitcoin({
+    required BindingLiquidSdk that,
+    required PrepareBuyBitcoinRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_prepare_buy_bitcoin_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_buy_bitcoin(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_prepare_buy_bitcoin_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkPrepareBuyBitcoinConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareBuyBitcoinConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_buy_bitcoin", argNames: ["that", "req"]);
+
+  @override
+  Future<PrepareConvertResponse> crateBindingsBindingLiquidSdkPrepareConvert({
+    required BindingLiquidSdk that,
+    required PrepareConvertRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_prepare_convert_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_convert(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_prepare_convert_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkPrepareConvertConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareConvertConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_convert", argNames: ["that", "req"]);
+
+  @override
+  Future<PrepareLnUrlPayResponse> crateBindingsBindingLiquidSdkPrepareLnurlPay({
+    required BindingLiquidSdk that,
+    required PrepareLnUrlPayRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_prepare_ln_url_pay_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_prepare_ln_url_pay_response,
+          decodeErrorData: dco_decode_ln_url_pay_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkPrepareLnurlPayConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareLnurlPayConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_lnurl_pay", argNames: ["that", "req"]);
+
+  @override
+  Future<PreparePayOnchainResponse> crateBindingsBindingLiquidSdkPreparePayOnchain({
+    required BindingLiquidSdk that,
+    required PreparePayOnchainRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_prepare_pay_onchain_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_prepare_pay_onchain_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkPreparePayOnchainConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPreparePayOnchainConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_pay_onchain", argNames: ["that", "req"]);
+
+  @override
+  Future<PrepareReceiveResponse> crateBindingsBindingLiquidSdkPrepareReceivePayment({
+    required BindingLiquidSdk that,
+    required PrepareReceiveRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_prepare_receive_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_prepare_receive_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkPrepareReceivePaymentConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareReceivePaymentConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_receive_payment", argNames: ["that", "req"]);
+
+  @override
+  Future<PrepareReceiveQuotesResponse> crateBindingsBindingLiquidSdkPrepareReceivePaymentQuotes({
+    required BindingLiquidSdk that,
+    required PrepareReceiveQuotesRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_prepare_receive_quotes_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_quotes(
+            port_,
+            arg0,
+            arg1,
+          );
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_prepare_receive_quotes_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkPrepareReceivePaymentQuotesConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareReceivePaymentQuotesConstMeta => const TaskConstMeta(
+    debugName: "BindingLiquidSdk_prepare_receive_payment_quotes",
+    argNames: ["that", "req"],
+  );
+
+  @override
+  Future<PrepareRefundResponse> crateBindingsBindingLiquidSdkPrepareRefund({
+    required BindingLiquidSdk that,
+    required PrepareRefundRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_prepare_refund_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_prepare_refund(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_prepare_refund_response,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkPrepareRefundConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareRefundConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_refund", argNames: ["that", "req"]);
+
+  @override
+  Future<PrepareSendResponse> crateBindingsBindingLiquidSdkPrepareSendPayment({
+    required BindingLiquidSdk that,
+    required PrepareSendRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_prepare_send_request(req);
           return wire.wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(port_, arg0, arg1);
         },
-        codec: DcoCodec(
-          decodeSuccessData: dco_decode_prepare_send_response,
-          decodeErrorData: dco_decode_payment_error,
-        ),
-        constMeta: kCrateBindingsBindingLiquidSdkPrepareSendPaymentConstMeta,
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_prepare_send_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkPrepareSendPaymentConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareSendPaymentConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_send_payment", argNames: ["that", "req"]);
+
+  @override
+  Future<QueuedPayment> crateBindingsBindingLiquidSdkQueuePayment({
+    required BindingLiquidSdk that,
+    required QueuePaymentRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_queue_payment_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_queue_payment(port_, arg0, arg1);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_queued_payment, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkQueuePaymentConstMeta,
         argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareSendPaymentConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_prepare_send_payment", argNames: ["that", "req"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkQueuePaymentConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_queue_payment", argNames: ["that", "req"]);
 
   @override
   Future<ReceivePaymentResponse> crateBindingsBindingLiquidSdkReceivePayment({
@@ -1028,6 +2074,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateBindingsBindingLiquidSdkRecommendedFeesConstMeta =>
       const TaskConstMeta(debugName: "BindingLiquidSdk_recommended_fees", argNames: ["that"]);
 
+  @override
+  Future<LightningAddressInfo?> crateBindingsBindingLiquidSdkRecoverLightningAddress({
+    required BindingLiquidSdk that,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_recover_lightning_address(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_opt_box_autoadd_lightning_address_info,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkRecoverLightningAddressConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkRecoverLightningAddressConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_recover_lightning_address", argNames: ["that"]);
+
   @override
   Future<RefundResponse> crateBindingsBindingLiquidSdkRefund({
     required BindingLiquidSdk that,
@@ -1057,6 +2130,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateBindingsBindingLiquidSdkRefundConstMeta =>
       const TaskConstMeta(debugName: "BindingLiquidSdk_refund", argNames: ["that", "req"]);
 
+  @override
+  Future<AmpAccount> crateBindingsBindingLiquidSdkRegisterAmpAccount({required BindingLiquidSdk that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          return wire.wire__crate__bindings__BindingLiquidSdk_register_amp_account(port_, arg0);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_amp_account, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkRegisterAmpAccountConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkRegisterAmpAccountConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_register_amp_account", argNames: ["that"]);
+
+  @override
+  Future<LightningAddressInfo> crateBindingsBindingLiquidSdkRegisterLightningAddress({
+    required BindingLiquidSdk that,
+    required RegisterLightningAddressRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_register_lightning_address_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_register_lightning_address(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_lightning_address_info,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkRegisterLightningAddressConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkRegisterLightningAddressConstMeta => const TaskConstMeta(
+    debugName: "BindingLiquidSdk_register_lightning_address",
+    argNames: ["that", "req"],
+  );
+
   @override
   Future<void> crateBindingsBindingLiquidSdkRegisterWebhook({
     required BindingLiquidSdk that,
@@ -1083,6 +2209,61 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateBindingsBindingLiquidSdkRegisterWebhookConstMeta =>
       const TaskConstMeta(debugName: "BindingLiquidSdk_register_webhook", argNames: ["that", "webhookUrl"]);
 
+  @override
+  void crateBindingsBindingLiquidSdkRemoveNwcConnection({
+    required BindingLiquidSdk that,
+    required String name,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_String(name);
+          return wire.wire__crate__bindings__BindingLiquidSdk_remove_nwc_connection(arg0, arg1);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkRemoveNwcConnectionConstMeta,
+        argValues: [that, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkRemoveNwcConnectionConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_remove_nwc_connection", argNames: ["that", "name"]);
+
+  @override
+  Future<List<JournaledEvent>> crateBindingsBindingLiquidSdkReplayEvents({
+    required BindingLiquidSdk that,
+    required ReplayEventsRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_replay_events_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_replay_events(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_list_journaled_event,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkReplayEventsConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkReplayEventsConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_replay_events", argNames: ["that", "req"]);
+
   @override
   Future<void> crateBindingsBindingLiquidSdkRescanOnchainSwaps({required BindingLiquidSdk that}) {
     return handler.executeNormal(
@@ -1128,6 +2309,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateBindingsBindingLiquidSdkRestoreConstMeta =>
       const TaskConstMeta(debugName: "BindingLiquidSdk_restore", argNames: ["that", "req"]);
 
+  @override
+  Future<ScheduledPayment> crateBindingsBindingLiquidSdkSchedulePayment({
+    required BindingLiquidSdk that,
+    required SchedulePaymentRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_schedule_payment_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_schedule_payment(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_scheduled_payment,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkSchedulePaymentConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSchedulePaymentConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_schedule_payment", argNames: ["that", "req"]);
+
+  @override
+  Future<SendBatchResponse> crateBindingsBindingLiquidSdkSendBatch({
+    required BindingLiquidSdk that,
+    required SendBatchRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_send_batch_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_send_batch(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_send_batch_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkSendBatchConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSendBatchConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_send_batch", argNames: ["that", "req"]);
+
   @override
   Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendPayment({
     required BindingLiquidSdk that,
@@ -1157,6 +2396,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateBindingsBindingLiquidSdkSendPaymentConstMeta =>
       const TaskConstMeta(debugName: "BindingLiquidSdk_send_payment", argNames: ["that", "req"]);
 
+  @override
+  Future<void> crateBindingsBindingLiquidSdkSetBroadcaster({
+    required BindingLiquidSdk that,
+    required FutureOr<String> Function(String) broadcastLiquidTx,
+    required FutureOr<String> Function(String) broadcastBitcoinTx,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_DartFn_Inputs_String_Output_String_AnyhowException(broadcastLiquidTx);
+          var arg2 = cst_encode_DartFn_Inputs_String_Output_String_AnyhowException(broadcastBitcoinTx);
+          return wire.wire__crate__bindings__BindingLiquidSdk_set_broadcaster(port_, arg0, arg1, arg2);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkSetBroadcasterConstMeta,
+        argValues: [that, broadcastLiquidTx, broadcastBitcoinTx],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSetBroadcasterConstMeta => const TaskConstMeta(
+    debugName: "BindingLiquidSdk_set_broadcaster",
+    argNames: ["that", "broadcastLiquidTx", "broadcastBitcoinTx"],
+  );
+
   @override
   SignMessageResponse crateBindingsBindingLiquidSdkSignMessage({
     required BindingLiquidSdk that,
@@ -1187,7 +2456,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "BindingLiquidSdk_sign_message", argNames: ["that", "req"]);
 
   @override
-  Future<void> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that}) {
+  Future<SignPsetResponse> crateBindingsBindingLiquidSdkSignPset({
+    required BindingLiquidSdk that,
+    required SignPsetRequest req,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1195,18 +2467,74 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that,
               );
-          return wire.wire__crate__bindings__BindingLiquidSdk_sync(port_, arg0);
+          var arg1 = cst_encode_box_autoadd_sign_pset_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_sign_pset(port_, arg0, arg1);
         },
-        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_sign_pset_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkSignPsetConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSignPsetConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_sign_pset", argNames: ["that", "req"]);
+
+  @override
+  Future<SweepPrivateKeyResponse> crateBindingsBindingLiquidSdkSweepPrivateKey({
+    required BindingLiquidSdk that,
+    required SweepPrivateKeyRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_sweep_private_key_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_sweep_private_key(port_, arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_sweep_private_key_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsBindingLiquidSdkSweepPrivateKeyConstMeta,
+        argValues: [that, req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSweepPrivateKeyConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_sweep_private_key", argNames: ["that", "req"]);
+
+  @override
+  Future<SyncResponse> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that, SyncRequest? req}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_opt_box_autoadd_sync_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_sync(port_, arg0, arg1);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_sync_response, decodeErrorData: dco_decode_sdk_error),
         constMeta: kCrateBindingsBindingLiquidSdkSyncConstMeta,
-        argValues: [that],
+        argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
   TaskConstMeta get kCrateBindingsBindingLiquidSdkSyncConstMeta =>
-      const TaskConstMeta(debugName: "BindingLiquidSdk_sync(dart_style=sync)", argNames: ["that"]);
+      const TaskConstMeta(debugName: "BindingLiquidSdk_sync(dart_style=sync)", argNames: ["that", "req"]);
 
   @override
   Future<void> crateBindingsBindingLiquidSdkUnregisterWebhook({required BindingLiquidSdk that}) {
@@ -1231,27 +2559,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       const TaskConstMeta(debugName: "BindingLiquidSdk_unregister_webhook", argNames: ["that"]);
 
   @override
-  Future<void> crateBindingsBindingEventListenerOnEvent({
-    required BindingEventListener that,
-    required SdkEvent e,
+  void crateBindingsBindingLiquidSdkVerifyBackup({
+    required BindingLiquidSdk that,
+    required RestoreRequest req,
   }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          var arg0 = cst_encode_box_autoadd_binding_event_listener(that);
-          var arg1 = cst_encode_box_autoadd_sdk_event(e);
-          return wire.wire__crate__bindings__binding_event_listener_on_event(port_, arg0, arg1);
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 =
+              cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that,
+              );
+          var arg1 = cst_encode_box_autoadd_restore_request(req);
+          return wire.wire__crate__bindings__BindingLiquidSdk_verify_backup(arg0, arg1);
         },
-        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: null),
-        constMeta: kCrateBindingsBindingEventListenerOnEventConstMeta,
-        argValues: [that, e],
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsBindingLiquidSdkVerifyBackupConstMeta,
+        argValues: [that, req],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsBindingEventListenerOnEventConstMeta =>
-      const TaskConstMeta(debugName: "binding_event_listener_on_event", argNames: ["that", "e"]);
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkVerifyBackupConstMeta =>
+      const TaskConstMeta(debugName: "BindingLiquidSdk_verify_backup", argNames: ["that", "req"]);
 
   @override
   Stream<LogEntry> crateBindingsBreezLogStream() {
@@ -1289,15 +2620,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
               dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk,
           decodeErrorData: dco_decode_sdk_error,
         ),
-        constMeta: kCrateBindingsConnectConstMeta,
-        argValues: [req],
+        constMeta: kCrateBindingsConnectConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsConnectConstMeta =>
+      const TaskConstMeta(debugName: "connect", argNames: ["req"]);
+
+  @override
+  String crateBindingsCreateSpendLimitOverride({required String secret, required int expiresAt}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 = cst_encode_String(secret);
+          var arg1 = cst_encode_u_32(expiresAt);
+          return wire.wire__crate__bindings__create_spend_limit_override(arg0, arg1);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_String, decodeErrorData: null),
+        constMeta: kCrateBindingsCreateSpendLimitOverrideConstMeta,
+        argValues: [secret, expiresAt],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsCreateSpendLimitOverrideConstMeta =>
+      const TaskConstMeta(debugName: "create_spend_limit_override", argNames: ["secret", "expiresAt"]);
+
+  @override
+  DecodedInvoice crateBindingsDecodeInvoice({required String input}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 = cst_encode_String(input);
+          return wire.wire__crate__bindings__decode_invoice(arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_decoded_invoice,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsDecodeInvoiceConstMeta,
+        argValues: [input],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateBindingsConnectConstMeta =>
-      const TaskConstMeta(debugName: "connect", argNames: ["req"]);
+  TaskConstMeta get kCrateBindingsDecodeInvoiceConstMeta =>
+      const TaskConstMeta(debugName: "decode_invoice", argNames: ["input"]);
 
   @override
   Config crateBindingsDefaultConfig({required LiquidNetwork network, String? breezApiKey}) {
@@ -1319,6 +2692,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateBindingsDefaultConfigConstMeta =>
       const TaskConstMeta(debugName: "default_config", argNames: ["network", "breezApiKey"]);
 
+  @override
+  String crateBindingsGenerateMnemonic({required int wordCount}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 = cst_encode_u_32(wordCount);
+          return wire.wire__crate__bindings__generate_mnemonic(arg0);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_String, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsGenerateMnemonicConstMeta,
+        argValues: [wordCount],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsGenerateMnemonicConstMeta =>
+      const TaskConstMeta(debugName: "generate_mnemonic", argNames: ["wordCount"]);
+
   @override
   LNInvoice crateBindingsParseInvoice({required String input}) {
     return handler.executeSync(
@@ -1338,6 +2730,143 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateBindingsParseInvoiceConstMeta =>
       const TaskConstMeta(debugName: "parse_invoice", argNames: ["input"]);
 
+  @override
+  Future<ProcessNotificationResponse> crateBindingsProcessNotification({
+    required ProcessNotificationRequest req,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          var arg0 = cst_encode_box_autoadd_process_notification_request(req);
+          return wire.wire__crate__bindings__process_notification(port_, arg0);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_process_notification_response,
+          decodeErrorData: dco_decode_sdk_error,
+        ),
+        constMeta: kCrateBindingsProcessNotificationConstMeta,
+        argValues: [req],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsProcessNotificationConstMeta =>
+      const TaskConstMeta(debugName: "process_notification", argNames: ["req"]);
+
+  @override
+  ValidateAddressResponse crateBindingsValidateAddress({
+    required String address,
+    required LiquidNetwork network,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 = cst_encode_String(address);
+          var arg1 = cst_encode_liquid_network(network);
+          return wire.wire__crate__bindings__validate_address(arg0, arg1);
+        },
+        codec: DcoCodec(
+          decodeSuccessData: dco_decode_validate_address_response,
+          decodeErrorData: dco_decode_payment_error,
+        ),
+        constMeta: kCrateBindingsValidateAddressConstMeta,
+        argValues: [address, network],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsValidateAddressConstMeta =>
+      const TaskConstMeta(debugName: "validate_address", argNames: ["address", "network"]);
+
+  @override
+  void crateBindingsValidateMnemonic({required String phrase}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          var arg0 = cst_encode_String(phrase);
+          return wire.wire__crate__bindings__validate_mnemonic(arg0);
+        },
+        codec: DcoCodec(decodeSuccessData: dco_decode_unit, decodeErrorData: dco_decode_sdk_error),
+        constMeta: kCrateBindingsValidateMnemonicConstMeta,
+        argValues: [phrase],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateBindingsValidateMnemonicConstMeta =>
+      const TaskConstMeta(debugName: "validate_mnemonic", argNames: ["phrase"]);
+
+  Future<void> Function(int, dynamic) encode_DartFn_Inputs_String_Output_String_AnyhowException(
+    FutureOr<String> Function(String) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<String>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_String(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(
+    FutureOr<InputType?> Function(String) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<InputType?>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_opt_box_autoadd_input_type(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BindingLiquidSdk =>
       wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk;
 
@@ -1368,6 +2897,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return BindingLiquidSdkImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  FutureOr<String> Function(String) dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<InputType?> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return decodeDartOpaque(raw, generalizedFrbRustBinding);
+  }
+
   @protected
   BindingLiquidSdk dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     dynamic raw,
@@ -1404,6 +2954,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  AddNwcConnectionRequest dco_decode_add_nwc_connection_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AddNwcConnectionRequest(
+      name: dco_decode_String(arr[0]),
+      budget: dco_decode_opt_box_autoadd_nwc_budget(arr[1]),
+    );
+  }
+
+  @protected
+  AddNwcConnectionResponse dco_decode_add_nwc_connection_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return AddNwcConnectionResponse(connectionUri: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  AddressType dco_decode_address_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AddressType.values[raw as int];
+  }
+
   @protected
   AesSuccessActionData dco_decode_aes_success_action_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1458,30 +3033,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AmpAccount dco_decode_amp_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AmpAccount(ampId: dco_decode_String(arr[0]), descriptor: dco_decode_String(arr[1]));
+  }
+
+  @protected
+  AssetAmount dco_decode_asset_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AssetAmount(
+      assetId: dco_decode_String(arr[0]),
+      baseUnits: dco_decode_u_64(arr[1]),
+      precision: dco_decode_u_8(arr[2]),
+      displayPrecision: dco_decode_u_8(arr[3]),
+    );
+  }
+
   @protected
   AssetBalance dco_decode_asset_balance(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
     return AssetBalance(
       assetId: dco_decode_String(arr[0]),
       balanceSat: dco_decode_u_64(arr[1]),
       name: dco_decode_opt_String(arr[2]),
       ticker: dco_decode_opt_String(arr[3]),
       balance: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      pendingSendSat: dco_decode_u_64(arr[5]),
+      pendingReceiveSat: dco_decode_u_64(arr[6]),
+      pendingSend: dco_decode_opt_box_autoadd_f_64(arr[7]),
+      pendingReceive: dco_decode_opt_box_autoadd_f_64(arr[8]),
+      assetBalance: dco_decode_opt_box_autoadd_asset_amount(arr[9]),
     );
   }
 
   @protected
-  AssetInfo dco_decode_asset_info(dynamic raw) {
+  AssetConversion dco_decode_asset_conversion(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AssetConversion(
+      fromAssetId: dco_decode_String(arr[0]),
+      fromAmount: dco_decode_u_64(arr[1]),
+      toAssetId: dco_decode_String(arr[2]),
+      toAmount: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  AssetInfo dco_decode_asset_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return AssetInfo(
       name: dco_decode_String(arr[0]),
       ticker: dco_decode_String(arr[1]),
       amount: dco_decode_f_64(arr[2]),
       fees: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      assetAmount: dco_decode_asset_amount(arr[4]),
+      assetFees: dco_decode_opt_box_autoadd_asset_amount(arr[5]),
     );
   }
 
@@ -1489,16 +3105,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   AssetMetadata dco_decode_asset_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return AssetMetadata(
       assetId: dco_decode_String(arr[0]),
       name: dco_decode_String(arr[1]),
       ticker: dco_decode_String(arr[2]),
       precision: dco_decode_u_8(arr[3]),
       fiatId: dco_decode_opt_String(arr[4]),
+      isAmp: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  AssetReport dco_decode_asset_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return AssetReport(
+      assetId: dco_decode_String(arr[0]),
+      receivedAmount: dco_decode_u_64(arr[1]),
+      sentAmount: dco_decode_u_64(arr[2]),
+      swapFeesSat: dco_decode_u_64(arr[3]),
+      onchainFeesSat: dco_decode_u_64(arr[4]),
+      receivedCount: dco_decode_u_32(arr[5]),
+      sentCount: dco_decode_u_32(arr[6]),
     );
   }
 
+  @protected
+  BackupPolicy dco_decode_backup_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return BackupPolicy_CompletedSwaps(swaps: dco_decode_u_32(raw[1]));
+      case 1:
+        return BackupPolicy_Interval(hours: dco_decode_u_32(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   BackupRequest dco_decode_backup_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1508,11 +3154,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  BindingEventListener dco_decode_binding_event_listener(dynamic raw) {
+  BatchSendItem dco_decode_batch_send_item(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return BindingEventListener(stream: dco_decode_StreamSink_sdk_event_Dco(arr[0]));
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BatchSendItem(
+      destination: dco_decode_String(arr[0]),
+      amountSat: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  BatchSendItemResult dco_decode_batch_send_item_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BatchSendItemResult(
+      destination: dco_decode_String(arr[0]),
+      payment: dco_decode_opt_box_autoadd_payment(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
   }
 
   @protected
@@ -1529,6 +3190,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BlockInfo dco_decode_block_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return BlockInfo(
+      height: dco_decode_u_32(arr[0]),
+      hash: dco_decode_String(arr[1]),
+      timestamp: dco_decode_u_32(arr[2]),
+    );
+  }
+
   @protected
   BlockchainExplorer dco_decode_blockchain_explorer(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1554,99 +3227,215 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AcceptPaymentProposedFeesRequest dco_decode_box_autoadd_accept_payment_proposed_fees_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_accept_payment_proposed_fees_request(raw);
+  }
+
+  @protected
+  AddNwcConnectionRequest dco_decode_box_autoadd_add_nwc_connection_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_add_nwc_connection_request(raw);
+  }
+
+  @protected
+  AesSuccessActionData dco_decode_box_autoadd_aes_success_action_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_aes_success_action_data(raw);
+  }
+
+  @protected
+  AesSuccessActionDataDecrypted dco_decode_box_autoadd_aes_success_action_data_decrypted(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_aes_success_action_data_decrypted(raw);
+  }
+
+  @protected
+  AesSuccessActionDataResult dco_decode_box_autoadd_aes_success_action_data_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_aes_success_action_data_result(raw);
+  }
+
+  @protected
+  Amount dco_decode_box_autoadd_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_amount(raw);
+  }
+
+  @protected
+  AmpAccount dco_decode_box_autoadd_amp_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_amp_account(raw);
+  }
+
+  @protected
+  AssetAmount dco_decode_box_autoadd_asset_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_asset_amount(raw);
+  }
+
+  @protected
+  AssetConversion dco_decode_box_autoadd_asset_conversion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_asset_conversion(raw);
+  }
+
+  @protected
+  AssetInfo dco_decode_box_autoadd_asset_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_asset_info(raw);
+  }
+
+  @protected
+  BackupPolicy dco_decode_box_autoadd_backup_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_backup_policy(raw);
+  }
+
+  @protected
+  BackupRequest dco_decode_box_autoadd_backup_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_backup_request(raw);
+  }
+
+  @protected
+  BitcoinAddressData dco_decode_box_autoadd_bitcoin_address_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bitcoin_address_data(raw);
+  }
+
+  @protected
+  BlockInfo dco_decode_box_autoadd_block_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_block_info(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  BuyBitcoinProvider dco_decode_box_autoadd_buy_bitcoin_provider(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_buy_bitcoin_provider(raw);
+  }
+
+  @protected
+  BuyBitcoinRequest dco_decode_box_autoadd_buy_bitcoin_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_buy_bitcoin_request(raw);
+  }
+
+  @protected
+  CheckMessageRequest dco_decode_box_autoadd_check_message_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_check_message_request(raw);
+  }
+
+  @protected
+  ConnectRequest dco_decode_box_autoadd_connect_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+    return dco_decode_connect_request(raw);
   }
 
   @protected
-  AcceptPaymentProposedFeesRequest dco_decode_box_autoadd_accept_payment_proposed_fees_request(dynamic raw) {
+  ConvertRequest dco_decode_box_autoadd_convert_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_accept_payment_proposed_fees_request(raw);
+    return dco_decode_convert_request(raw);
   }
 
   @protected
-  AesSuccessActionData dco_decode_box_autoadd_aes_success_action_data(dynamic raw) {
+  CreateRecurringPaymentRequest dco_decode_box_autoadd_create_recurring_payment_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_aes_success_action_data(raw);
+    return dco_decode_create_recurring_payment_request(raw);
   }
 
   @protected
-  AesSuccessActionDataDecrypted dco_decode_box_autoadd_aes_success_action_data_decrypted(dynamic raw) {
+  Direction dco_decode_box_autoadd_direction(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_aes_success_action_data_decrypted(raw);
+    return dco_decode_direction(raw);
   }
 
   @protected
-  AesSuccessActionDataResult dco_decode_box_autoadd_aes_success_action_data_result(dynamic raw) {
+  EventFilter dco_decode_box_autoadd_event_filter(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_aes_success_action_data_result(raw);
+    return dco_decode_event_filter(raw);
   }
 
   @protected
-  Amount dco_decode_box_autoadd_amount(dynamic raw) {
+  EventWebhookConfig dco_decode_box_autoadd_event_webhook_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_amount(raw);
+    return dco_decode_event_webhook_config(raw);
   }
 
   @protected
-  AssetInfo dco_decode_box_autoadd_asset_info(dynamic raw) {
+  ExportPaymentUnblindingDataRequest dco_decode_box_autoadd_export_payment_unblinding_data_request(
+    dynamic raw,
+  ) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_asset_info(raw);
+    return dco_decode_export_payment_unblinding_data_request(raw);
   }
 
   @protected
-  BackupRequest dco_decode_box_autoadd_backup_request(dynamic raw) {
+  ExportWalletRequest dco_decode_box_autoadd_export_wallet_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_backup_request(raw);
+    return dco_decode_export_wallet_request(raw);
   }
 
   @protected
-  BindingEventListener dco_decode_box_autoadd_binding_event_listener(dynamic raw) {
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_binding_event_listener(raw);
+    return raw as double;
   }
 
   @protected
-  BitcoinAddressData dco_decode_box_autoadd_bitcoin_address_data(dynamic raw) {
+  FeeBreakdown dco_decode_box_autoadd_fee_breakdown(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_bitcoin_address_data(raw);
+    return dco_decode_fee_breakdown(raw);
   }
 
   @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw) {
+  FeeSpeed dco_decode_box_autoadd_fee_speed(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+    return dco_decode_fee_speed(raw);
   }
 
   @protected
-  BuyBitcoinRequest dco_decode_box_autoadd_buy_bitcoin_request(dynamic raw) {
+  FetchPaymentProposedFeesRequest dco_decode_box_autoadd_fetch_payment_proposed_fees_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_buy_bitcoin_request(raw);
+    return dco_decode_fetch_payment_proposed_fees_request(raw);
   }
 
   @protected
-  CheckMessageRequest dco_decode_box_autoadd_check_message_request(dynamic raw) {
+  FiatAmount dco_decode_box_autoadd_fiat_amount(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_check_message_request(raw);
+    return dco_decode_fiat_amount(raw);
   }
 
   @protected
-  ConnectRequest dco_decode_box_autoadd_connect_request(dynamic raw) {
+  FiatConversion dco_decode_box_autoadd_fiat_conversion(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_connect_request(raw);
+    return dco_decode_fiat_conversion(raw);
   }
 
   @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw) {
+  GetMaxPayableRequest dco_decode_box_autoadd_get_max_payable_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+    return dco_decode_get_max_payable_request(raw);
   }
 
   @protected
-  FetchPaymentProposedFeesRequest dco_decode_box_autoadd_fetch_payment_proposed_fees_request(dynamic raw) {
+  GetMaxReceivableRequest dco_decode_box_autoadd_get_max_receivable_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_fetch_payment_proposed_fees_request(raw);
+    return dco_decode_get_max_receivable_request(raw);
   }
 
   @protected
@@ -1655,12 +3444,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_get_payment_request(raw);
   }
 
+  @protected
+  GetReportRequest dco_decode_box_autoadd_get_report_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_get_report_request(raw);
+  }
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_i_64(raw);
   }
 
+  @protected
+  ImportWalletRequest dco_decode_box_autoadd_import_wallet_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_import_wallet_request(raw);
+  }
+
+  @protected
+  InputType dco_decode_box_autoadd_input_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_input_type(raw);
+  }
+
+  @protected
+  LightningAddressInfo dco_decode_box_autoadd_lightning_address_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_lightning_address_info(raw);
+  }
+
   @protected
   LiquidAddressData dco_decode_box_autoadd_liquid_address_data(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1679,6 +3492,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_list_payments_request(raw);
   }
 
+  @protected
+  ListSwapsRequest dco_decode_box_autoadd_list_swaps_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_list_swaps_request(raw);
+  }
+
+  @protected
+  LNBolt12Invoice dco_decode_box_autoadd_ln_bolt_12_invoice(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ln_bolt_12_invoice(raw);
+  }
+
   @protected
   LNInvoice dco_decode_box_autoadd_ln_invoice(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1733,6 +3558,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_ln_url_pay_success_data(raw);
   }
 
+  @protected
+  LnUrlPayerData dco_decode_box_autoadd_ln_url_payer_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ln_url_payer_data(raw);
+  }
+
   @protected
   LnUrlWithdrawRequest dco_decode_box_autoadd_ln_url_withdraw_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1757,6 +3588,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_message_success_action_data(raw);
   }
 
+  @protected
+  NwcBudget dco_decode_box_autoadd_nwc_budget(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_nwc_budget(raw);
+  }
+
   @protected
   PayAmount dco_decode_box_autoadd_pay_amount(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1781,6 +3618,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_prepare_buy_bitcoin_request(raw);
   }
 
+  @protected
+  PrepareConvertRequest dco_decode_box_autoadd_prepare_convert_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_convert_request(raw);
+  }
+
   @protected
   PrepareLnUrlPayRequest dco_decode_box_autoadd_prepare_ln_url_pay_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1793,6 +3636,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_prepare_pay_onchain_request(raw);
   }
 
+  @protected
+  PrepareReceiveQuotesRequest dco_decode_box_autoadd_prepare_receive_quotes_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_receive_quotes_request(raw);
+  }
+
   @protected
   PrepareReceiveRequest dco_decode_box_autoadd_prepare_receive_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1811,6 +3660,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_prepare_send_request(raw);
   }
 
+  @protected
+  ProcessNotificationRequest dco_decode_box_autoadd_process_notification_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_process_notification_request(raw);
+  }
+
+  @protected
+  QueuePaymentRequest dco_decode_box_autoadd_queue_payment_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_queue_payment_request(raw);
+  }
+
   @protected
   ReceiveAmount dco_decode_box_autoadd_receive_amount(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1829,6 +3690,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_refund_request(raw);
   }
 
+  @protected
+  RegisterLightningAddressRequest dco_decode_box_autoadd_register_lightning_address_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_register_lightning_address_request(raw);
+  }
+
+  @protected
+  RegisteredAsset dco_decode_box_autoadd_registered_asset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_registered_asset(raw);
+  }
+
+  @protected
+  ReplayEventsRequest dco_decode_box_autoadd_replay_events_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_replay_events_request(raw);
+  }
+
   @protected
   RestoreRequest dco_decode_box_autoadd_restore_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1836,9 +3715,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  SdkEvent dco_decode_box_autoadd_sdk_event(dynamic raw) {
+  SchedulePaymentRequest dco_decode_box_autoadd_schedule_payment_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_schedule_payment_request(raw);
+  }
+
+  @protected
+  SendBatchRequest dco_decode_box_autoadd_send_batch_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_sdk_event(raw);
+    return dco_decode_send_batch_request(raw);
   }
 
   @protected
@@ -1853,6 +3738,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_sign_message_request(raw);
   }
 
+  @protected
+  SignPsetRequest dco_decode_box_autoadd_sign_pset_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sign_pset_request(raw);
+  }
+
+  @protected
+  SimulationConfig dco_decode_box_autoadd_simulation_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_simulation_config(raw);
+  }
+
+  @protected
+  SpendLimit dco_decode_box_autoadd_spend_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_spend_limit(raw);
+  }
+
+  @protected
+  SpendLimits dco_decode_box_autoadd_spend_limits(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_spend_limits(raw);
+  }
+
   @protected
   SuccessAction dco_decode_box_autoadd_success_action(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1865,12 +3774,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_success_action_processed(raw);
   }
 
+  @protected
+  SwapServiceStatus dco_decode_box_autoadd_swap_service_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_swap_service_status(raw);
+  }
+
+  @protected
+  SweepPrivateKeyRequest dco_decode_box_autoadd_sweep_private_key_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sweep_private_key_request(raw);
+  }
+
   @protected
   Symbol dco_decode_box_autoadd_symbol(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_symbol(raw);
   }
 
+  @protected
+  SyncRequest dco_decode_box_autoadd_sync_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_request(raw);
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1906,6 +3833,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  CheckHealthResponse dco_decode_check_health_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CheckHealthResponse(
+      isDegraded: dco_decode_bool(arr[0]),
+      services: dco_decode_list_service_health(arr[1]),
+      swapServiceStatus: dco_decode_opt_box_autoadd_swap_service_status(arr[2]),
+    );
+  }
+
   @protected
   CheckMessageRequest dco_decode_check_message_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1930,7 +3869,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Config dco_decode_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    if (arr.length != 33) throw Exception('unexpected arr length: expect 33 but see ${arr.length}');
     return Config(
       liquidExplorer: dco_decode_blockchain_explorer(arr[0]),
       bitcoinExplorer: dco_decode_blockchain_explorer(arr[1]),
@@ -1946,6 +3885,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       onchainFeeRateLeewaySatPerVbyte: dco_decode_opt_box_autoadd_u_32(arr[11]),
       assetMetadata: dco_decode_opt_list_asset_metadata(arr[12]),
       sideswapApiKey: dco_decode_opt_String(arr[13]),
+      eventWebhook: dco_decode_opt_box_autoadd_event_webhook_config(arr[14]),
+      fiatCurrency: dco_decode_opt_String(arr[15]),
+      syncIntervalSec: dco_decode_opt_box_autoadd_u_32(arr[16]),
+      bitcoinExplorerAuthorization: dco_decode_opt_String(arr[17]),
+      swapperMaxAttempts: dco_decode_opt_box_autoadd_u_32(arr[18]),
+      backupPolicy: dco_decode_opt_box_autoadd_backup_policy(arr[19]),
+      nwcRelayUrl: dco_decode_opt_String(arr[20]),
+      spendLimits: dco_decode_opt_box_autoadd_spend_limits(arr[21]),
+      lnurlServiceUrl: dco_decode_opt_String(arr[22]),
+      outgoingMinConfirmations: dco_decode_opt_box_autoadd_u_32(arr[23]),
+      unconfirmedTxAlertSec: dco_decode_opt_box_autoadd_u_32(arr[24]),
+      chainServiceTimeoutSec: dco_decode_opt_box_autoadd_u_32(arr[25]),
+      swapperTimeoutSec: dco_decode_opt_box_autoadd_u_32(arr[26]),
+      swapperPingIntervalSec: dco_decode_opt_box_autoadd_u_32(arr[27]),
+      ampServerUrl: dco_decode_opt_String(arr[28]),
+      simulation: dco_decode_opt_box_autoadd_simulation_config(arr[29]),
+      manualClaim: dco_decode_bool(arr[30]),
+      claimAddress: dco_decode_opt_String(arr[31]),
+      dnsOverHttpsUrl: dco_decode_opt_String(arr[32]),
     );
   }
 
@@ -1953,12 +3911,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ConnectRequest dco_decode_connect_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return ConnectRequest(
       config: dco_decode_config(arr[0]),
       mnemonic: dco_decode_opt_String(arr[1]),
       passphrase: dco_decode_opt_String(arr[2]),
       seed: dco_decode_opt_list_prim_u_8_strict(arr[3]),
+      xprv: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ConvertRequest dco_decode_convert_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ConvertRequest(
+      prepareResponse: dco_decode_prepare_convert_response(arr[0]),
+      spendLimitOverride: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  CreateRecurringPaymentRequest dco_decode_create_recurring_payment_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CreateRecurringPaymentRequest(
+      destination: dco_decode_String(arr[0]),
+      amountSat: dco_decode_u_64(arr[1]),
+      intervalSec: dco_decode_u_32(arr[2]),
+      startAt: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      maxFeeSat: dco_decode_opt_box_autoadd_u_64(arr[4]),
     );
   }
 
@@ -1978,6 +3962,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  DecodedInvoice dco_decode_decoded_invoice(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DecodedInvoice_Bolt11(invoice: dco_decode_box_autoadd_ln_invoice(raw[1]));
+      case 1:
+        return DecodedInvoice_Bolt12(invoice: dco_decode_box_autoadd_ln_bolt_12_invoice(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  Direction dco_decode_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Direction.values[raw as int];
+  }
+
+  @protected
+  EventFilter dco_decode_event_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EventFilter(
+      kinds: dco_decode_opt_list_sdk_event_kind(arr[0]),
+      paymentIds: dco_decode_opt_list_String(arr[1]),
+      swapTypes: dco_decode_opt_list_payment_swap_type(arr[2]),
+    );
+  }
+
+  @protected
+  EventWebhookConfig dco_decode_event_webhook_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EventWebhookConfig(url: dco_decode_String(arr[0]), secret: dco_decode_String(arr[1]));
+  }
+
+  @protected
+  ExportPaymentUnblindingDataRequest dco_decode_export_payment_unblinding_data_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ExportPaymentUnblindingDataRequest(
+      paymentId: dco_decode_String(arr[0]),
+      explorerUrl: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  ExportWalletRequest dco_decode_export_wallet_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ExportWalletRequest(exportPath: dco_decode_String(arr[0]));
+  }
+
   @protected
   ExternalInputParser dco_decode_external_input_parser(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1996,6 +4038,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  FeeBreakdown dco_decode_fee_breakdown(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return FeeBreakdown(
+      serviceFeeSat: dco_decode_u_64(arr[0]),
+      swapperNetworkFeeSat: dco_decode_u_64(arr[1]),
+      lockupFeeSat: dco_decode_u_64(arr[2]),
+      lockupFeeIsEstimate: dco_decode_bool(arr[3]),
+      claimFeeSat: dco_decode_u_64(arr[4]),
+      claimFeeIsEstimate: dco_decode_bool(arr[5]),
+      assetFee: dco_decode_opt_box_autoadd_asset_amount(arr[6]),
+    );
+  }
+
+  @protected
+  FeeSpeed dco_decode_fee_speed(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FeeSpeed.values[raw as int];
+  }
+
   @protected
   FetchPaymentProposedFeesRequest dco_decode_fetch_payment_proposed_fees_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2018,21 +4082,79 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  FiatCurrency dco_decode_fiat_currency(dynamic raw) {
+  FiatAmount dco_decode_fiat_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FiatAmount(amount: dco_decode_f_64(arr[0]), currency: dco_decode_String(arr[1]));
+  }
+
+  @protected
+  FiatConversion dco_decode_fiat_conversion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FiatConversion(
+      fiatAmount: dco_decode_fiat_amount(arr[0]),
+      rate: dco_decode_f_64(arr[1]),
+      amountSat: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  FiatCurrency dco_decode_fiat_currency(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FiatCurrency(id: dco_decode_String(arr[0]), info: dco_decode_currency_info(arr[1]));
+  }
+
+  @protected
+  GetInfoResponse dco_decode_get_info_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GetInfoResponse(
+      walletInfo: dco_decode_wallet_info(arr[0]),
+      blockchainInfo: dco_decode_blockchain_info(arr[1]),
+    );
+  }
+
+  @protected
+  GetMaxPayableRequest dco_decode_get_max_payable_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return GetMaxPayableRequest(paymentMethod: dco_decode_payment_method(arr[0]));
+  }
+
+  @protected
+  GetMaxPayableResponse dco_decode_get_max_payable_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GetMaxPayableResponse(
+      receiverAmountSat: dco_decode_u_64(arr[0]),
+      feesSat: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  GetMaxReceivableRequest dco_decode_get_max_receivable_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return FiatCurrency(id: dco_decode_String(arr[0]), info: dco_decode_currency_info(arr[1]));
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return GetMaxReceivableRequest(paymentMethod: dco_decode_payment_method(arr[0]));
   }
 
   @protected
-  GetInfoResponse dco_decode_get_info_response(dynamic raw) {
+  GetMaxReceivableResponse dco_decode_get_max_receivable_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return GetInfoResponse(
-      walletInfo: dco_decode_wallet_info(arr[0]),
-      blockchainInfo: dco_decode_blockchain_info(arr[1]),
+    return GetMaxReceivableResponse(
+      payerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      feesSat: dco_decode_u_64(arr[1]),
     );
   }
 
@@ -2049,6 +4171,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  GetReportRequest dco_decode_get_report_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GetReportRequest(
+      fromTimestamp: dco_decode_opt_box_autoadd_i_64(arr[0]),
+      toTimestamp: dco_decode_opt_box_autoadd_i_64(arr[1]),
+    );
+  }
+
+  @protected
+  GetReportResponse dco_decode_get_report_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return GetReportResponse(assets: dco_decode_list_asset_report(arr[0]));
+  }
+
+  @protected
+  GetWalletInfoResponse dco_decode_get_wallet_info_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return GetWalletInfoResponse(
+      descriptor: dco_decode_String(arr[0]),
+      xpub: dco_decode_String(arr[1]),
+      derivationPath: dco_decode_String(arr[2]),
+      fingerprint: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  HealthCheckService dco_decode_health_check_service(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HealthCheckService.values[raw as int];
+  }
+
   @protected
   int dco_decode_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2061,6 +4221,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  ImportWalletRequest dco_decode_import_wallet_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ImportWalletRequest(exportPath: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  ImportWalletResponse dco_decode_import_wallet_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ImportWalletResponse(importedRecords: dco_decode_u_32(arr[0]));
+  }
+
   @protected
   InputType dco_decode_input_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2096,6 +4272,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  PlatformInt64 dco_decode_isize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  JournaledEvent dco_decode_journaled_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JournaledEvent(
+      seq: dco_decode_u_64(arr[0]),
+      timestamp: dco_decode_u_32(arr[1]),
+      event: dco_decode_sdk_event(arr[2]),
+    );
+  }
+
+  @protected
+  LightningAddressInfo dco_decode_lightning_address_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LightningAddressInfo(
+      username: dco_decode_String(arr[0]),
+      lightningAddress: dco_decode_String(arr[1]),
+      lnurl: dco_decode_String(arr[2]),
+    );
+  }
+
   @protected
   LightningPaymentLimitsResponse dco_decode_lightning_payment_limits_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2147,6 +4353,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<AssetAmount> dco_decode_list_asset_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_asset_amount).toList();
+  }
+
   @protected
   List<AssetBalance> dco_decode_list_asset_balance(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2159,6 +4371,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_asset_metadata).toList();
   }
 
+  @protected
+  List<AssetReport> dco_decode_list_asset_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_asset_report).toList();
+  }
+
+  @protected
+  List<BatchSendItem> dco_decode_list_batch_send_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_batch_send_item).toList();
+  }
+
+  @protected
+  List<BatchSendItemResult> dco_decode_list_batch_send_item_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_batch_send_item_result).toList();
+  }
+
   @protected
   List<ExternalInputParser> dco_decode_list_external_input_parser(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2171,6 +4401,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_fiat_currency).toList();
   }
 
+  @protected
+  List<JournaledEvent> dco_decode_list_journaled_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_journaled_event).toList();
+  }
+
   @protected
   List<LnOfferBlindedPath> dco_decode_list_ln_offer_blinded_path(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2189,6 +4425,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_localized_name).toList();
   }
 
+  @protected
+  List<NwcConnection> dco_decode_list_nwc_connection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_nwc_connection).toList();
+  }
+
   @protected
   List<Payment> dco_decode_list_payment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2217,6 +4459,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_payment_state).toList();
   }
 
+  @protected
+  List<PaymentSwapType> dco_decode_list_payment_swap_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payment_swap_type).toList();
+  }
+
   @protected
   List<PaymentType> dco_decode_list_payment_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2240,18 +4488,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  List<PrepareReceiveResponse> dco_decode_list_prepare_receive_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_prepare_receive_response).toList();
+  }
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as Uint8List;
   }
 
+  @protected
+  List<PsetSpendLimit> dco_decode_list_pset_spend_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pset_spend_limit).toList();
+  }
+
+  @protected
+  List<QueuedPayment> dco_decode_list_queued_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_queued_payment).toList();
+  }
+
   @protected
   List<Rate> dco_decode_list_rate(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return (raw as List<dynamic>).map(dco_decode_rate).toList();
   }
 
+  @protected
+  List<RecurringPayment> dco_decode_list_recurring_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_recurring_payment).toList();
+  }
+
+  @protected
+  List<RecurringPaymentOccurrence> dco_decode_list_recurring_payment_occurrence(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_recurring_payment_occurrence).toList();
+  }
+
   @protected
   List<RefundableSwap> dco_decode_list_refundable_swap(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2270,6 +4548,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_route_hint_hop).toList();
   }
 
+  @protected
+  List<ScheduledPayment> dco_decode_list_scheduled_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_scheduled_payment).toList();
+  }
+
+  @protected
+  List<SdkEventKind> dco_decode_list_sdk_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sdk_event_kind).toList();
+  }
+
+  @protected
+  List<ServiceHealth> dco_decode_list_service_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_service_health).toList();
+  }
+
+  @protected
+  List<SwapInfo> dco_decode_list_swap_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_swap_info).toList();
+  }
+
+  @protected
+  ListSwapsRequest dco_decode_list_swaps_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ListSwapsRequest(
+      swapTypes: dco_decode_opt_list_payment_swap_type(arr[0]),
+      states: dco_decode_opt_list_payment_state(arr[1]),
+      fromTimestamp: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      toTimestamp: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      offset: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  List<UnblindedTxOut> dco_decode_list_unblinded_tx_out(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_unblinded_tx_out).toList();
+  }
+
+  @protected
+  LNBolt12Invoice dco_decode_ln_bolt_12_invoice(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return LNBolt12Invoice(
+      invoice: dco_decode_String(arr[0]),
+      signingPubkey: dco_decode_String(arr[1]),
+      paymentHash: dco_decode_String(arr[2]),
+      description: dco_decode_opt_String(arr[3]),
+      payerNote: dco_decode_opt_String(arr[4]),
+      amountMsat: dco_decode_u_64(arr[5]),
+      createdAt: dco_decode_u_64(arr[6]),
+      expiry: dco_decode_u_64(arr[7]),
+      paymentPathCount: dco_decode_u_32(arr[8]),
+    );
+  }
+
   @protected
   LNInvoice dco_decode_ln_invoice(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2369,7 +4710,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   LnUrlInfo dco_decode_ln_url_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
     return LnUrlInfo(
       lnAddress: dco_decode_opt_String(arr[0]),
       lnurlPayComment: dco_decode_opt_String(arr[1]),
@@ -2378,6 +4719,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       lnurlPaySuccessAction: dco_decode_opt_box_autoadd_success_action_processed(arr[4]),
       lnurlPayUnprocessedSuccessAction: dco_decode_opt_box_autoadd_success_action(arr[5]),
       lnurlWithdrawEndpoint: dco_decode_opt_String(arr[6]),
+      lnurlPayVerifyUrl: dco_decode_opt_String(arr[7]),
+      lnurlPayVerifiedPreimage: dco_decode_opt_String(arr[8]),
     );
   }
 
@@ -2474,6 +4817,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  LnUrlPayerData dco_decode_ln_url_payer_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LnUrlPayerData(
+      name: dco_decode_opt_String(arr[0]),
+      pubkey: dco_decode_opt_String(arr[1]),
+      identifier: dco_decode_opt_String(arr[2]),
+      email: dco_decode_opt_String(arr[3]),
+    );
+  }
+
   @protected
   LnUrlWithdrawError dco_decode_ln_url_withdraw_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2557,77 +4913,191 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  LocalizedName dco_decode_localized_name(dynamic raw) {
+  LocalizedName dco_decode_localized_name(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LocalizedName(locale: dco_decode_String(arr[0]), name: dco_decode_String(arr[1]));
+  }
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LogEntry(
+      line: dco_decode_String(arr[0]),
+      level: dco_decode_String(arr[1]),
+      target: dco_decode_String(arr[2]),
+      timestamp: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  MessageSuccessActionData dco_decode_message_success_action_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return MessageSuccessActionData(message: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  Network dco_decode_network(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Network.values[raw as int];
+  }
+
+  @protected
+  NwcBudget dco_decode_nwc_budget(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NwcBudget(
+      maxAmountSat: dco_decode_u_64(arr[0]),
+      renewalPeriodSec: dco_decode_opt_box_autoadd_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  NwcConnection dco_decode_nwc_connection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return NwcConnection(
+      name: dco_decode_String(arr[0]),
+      clientPubkey: dco_decode_String(arr[1]),
+      budget: dco_decode_opt_box_autoadd_nwc_budget(arr[2]),
+      spentSat: dco_decode_u_64(arr[3]),
+      createdAt: dco_decode_u_32(arr[4]),
+    );
+  }
+
+  @protected
+  OnchainPaymentLimitsResponse dco_decode_onchain_payment_limits_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return OnchainPaymentLimitsResponse(send: dco_decode_limits(arr[0]), receive: dco_decode_limits(arr[1]));
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  Amount? dco_decode_opt_box_autoadd_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_amount(raw);
+  }
+
+  @protected
+  AmpAccount? dco_decode_opt_box_autoadd_amp_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_amp_account(raw);
+  }
+
+  @protected
+  AssetAmount? dco_decode_opt_box_autoadd_asset_amount(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_asset_amount(raw);
+  }
+
+  @protected
+  AssetConversion? dco_decode_opt_box_autoadd_asset_conversion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_asset_conversion(raw);
+  }
+
+  @protected
+  AssetInfo? dco_decode_opt_box_autoadd_asset_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_asset_info(raw);
+  }
+
+  @protected
+  BackupPolicy? dco_decode_opt_box_autoadd_backup_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_backup_policy(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  BuyBitcoinProvider? dco_decode_opt_box_autoadd_buy_bitcoin_provider(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return LocalizedName(locale: dco_decode_String(arr[0]), name: dco_decode_String(arr[1]));
+    return raw == null ? null : dco_decode_box_autoadd_buy_bitcoin_provider(raw);
   }
 
   @protected
-  LogEntry dco_decode_log_entry(dynamic raw) {
+  Direction? dco_decode_opt_box_autoadd_direction(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return LogEntry(line: dco_decode_String(arr[0]), level: dco_decode_String(arr[1]));
+    return raw == null ? null : dco_decode_box_autoadd_direction(raw);
   }
 
   @protected
-  MessageSuccessActionData dco_decode_message_success_action_data(dynamic raw) {
+  EventFilter? dco_decode_opt_box_autoadd_event_filter(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return MessageSuccessActionData(message: dco_decode_String(arr[0]));
+    return raw == null ? null : dco_decode_box_autoadd_event_filter(raw);
   }
 
   @protected
-  Network dco_decode_network(dynamic raw) {
+  EventWebhookConfig? dco_decode_opt_box_autoadd_event_webhook_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Network.values[raw as int];
+    return raw == null ? null : dco_decode_box_autoadd_event_webhook_config(raw);
   }
 
   @protected
-  OnchainPaymentLimitsResponse dco_decode_onchain_payment_limits_response(dynamic raw) {
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return OnchainPaymentLimitsResponse(send: dco_decode_limits(arr[0]), receive: dco_decode_limits(arr[1]));
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
+  FeeBreakdown? dco_decode_opt_box_autoadd_fee_breakdown(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+    return raw == null ? null : dco_decode_box_autoadd_fee_breakdown(raw);
   }
 
   @protected
-  Amount? dco_decode_opt_box_autoadd_amount(dynamic raw) {
+  FeeSpeed? dco_decode_opt_box_autoadd_fee_speed(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_amount(raw);
+    return raw == null ? null : dco_decode_box_autoadd_fee_speed(raw);
   }
 
   @protected
-  AssetInfo? dco_decode_opt_box_autoadd_asset_info(dynamic raw) {
+  FiatAmount? dco_decode_opt_box_autoadd_fiat_amount(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_asset_info(raw);
+    return raw == null ? null : dco_decode_box_autoadd_fiat_amount(raw);
   }
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+  FiatConversion? dco_decode_opt_box_autoadd_fiat_conversion(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+    return raw == null ? null : dco_decode_box_autoadd_fiat_conversion(raw);
   }
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
   }
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+  InputType? dco_decode_opt_box_autoadd_input_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+    return raw == null ? null : dco_decode_box_autoadd_input_type(raw);
+  }
+
+  @protected
+  LightningAddressInfo? dco_decode_opt_box_autoadd_lightning_address_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_lightning_address_info(raw);
   }
 
   @protected
@@ -2642,6 +5112,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_ln_url_info(raw);
   }
 
+  @protected
+  LnUrlPayerData? dco_decode_opt_box_autoadd_ln_url_payer_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_ln_url_payer_data(raw);
+  }
+
+  @protected
+  NwcBudget? dco_decode_opt_box_autoadd_nwc_budget(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_nwc_budget(raw);
+  }
+
   @protected
   PayAmount? dco_decode_opt_box_autoadd_pay_amount(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2660,6 +5142,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_receive_amount(raw);
   }
 
+  @protected
+  RegisteredAsset? dco_decode_opt_box_autoadd_registered_asset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_registered_asset(raw);
+  }
+
+  @protected
+  SimulationConfig? dco_decode_opt_box_autoadd_simulation_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_simulation_config(raw);
+  }
+
+  @protected
+  SpendLimit? dco_decode_opt_box_autoadd_spend_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_spend_limit(raw);
+  }
+
+  @protected
+  SpendLimits? dco_decode_opt_box_autoadd_spend_limits(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_spend_limits(raw);
+  }
+
   @protected
   SuccessAction? dco_decode_opt_box_autoadd_success_action(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2672,12 +5178,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_success_action_processed(raw);
   }
 
+  @protected
+  SwapServiceStatus? dco_decode_opt_box_autoadd_swap_service_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_swap_service_status(raw);
+  }
+
   @protected
   Symbol? dco_decode_opt_box_autoadd_symbol(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_symbol(raw);
   }
 
+  @protected
+  SyncRequest? dco_decode_opt_box_autoadd_sync_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_sync_request(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2690,6 +5208,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
   @protected
   List<AssetMetadata>? dco_decode_opt_list_asset_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2708,6 +5232,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_list_payment_state(raw);
   }
 
+  @protected
+  List<PaymentSwapType>? dco_decode_opt_list_payment_swap_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_payment_swap_type(raw);
+  }
+
   @protected
   List<PaymentType>? dco_decode_opt_list_payment_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2720,6 +5250,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
   }
 
+  @protected
+  List<SdkEventKind>? dco_decode_opt_list_sdk_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_sdk_event_kind(raw);
+  }
+
   @protected
   PayAmount dco_decode_pay_amount(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2733,6 +5269,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           estimateAssetFees: dco_decode_opt_box_autoadd_bool(raw[3]),
         );
       case 2:
+        return PayAmount_AssetUnits(
+          receiverAmount: dco_decode_box_autoadd_asset_amount(raw[1]),
+          estimateAssetFees: dco_decode_opt_box_autoadd_bool(raw[2]),
+        );
+      case 3:
         return PayAmount_Drain();
       default:
         throw Exception("unreachable");
@@ -2743,10 +5284,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PayOnchainRequest dco_decode_pay_onchain_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return PayOnchainRequest(
       address: dco_decode_String(arr[0]),
       prepareResponse: dco_decode_prepare_pay_onchain_response(arr[1]),
+      spendLimitOverride: dco_decode_opt_String(arr[2]),
     );
   }
 
@@ -2754,7 +5296,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   Payment dco_decode_payment(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
     return Payment(
       destination: dco_decode_opt_String(arr[0]),
       txId: dco_decode_opt_String(arr[1]),
@@ -2766,6 +5308,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       paymentType: dco_decode_payment_type(arr[7]),
       status: dco_decode_payment_state(arr[8]),
       details: dco_decode_payment_details(arr[9]),
+      fiatAmount: dco_decode_opt_box_autoadd_f_64(arr[10]),
+      fiatCurrency: dco_decode_opt_String(arr[11]),
+      recurringPaymentId: dco_decode_opt_String(arr[12]),
     );
   }
 
@@ -2797,6 +5342,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           assetInfo: dco_decode_opt_box_autoadd_asset_info(raw[4]),
           lnurlInfo: dco_decode_opt_box_autoadd_ln_url_info(raw[5]),
           bip353Address: dco_decode_opt_String(raw[6]),
+          conversion: dco_decode_opt_box_autoadd_asset_conversion(raw[7]),
         );
       case 2:
         return PaymentDetails_Bitcoin(
@@ -2808,6 +5354,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           claimTxId: dco_decode_opt_String(raw[6]),
           refundTxId: dco_decode_opt_String(raw[7]),
           refundTxAmountSat: dco_decode_opt_box_autoadd_u_64(raw[8]),
+          buyBitcoinProvider: dco_decode_opt_box_autoadd_buy_bitcoin_provider(raw[9]),
         );
       default:
         throw Exception("unreachable");
@@ -2837,29 +5384,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 8:
         return PaymentError_InvalidOrExpiredFees();
       case 9:
-        return PaymentError_InsufficientFunds();
+        return PaymentError_FeeLimitExceeded(
+          feesSat: dco_decode_u_64(raw[1]),
+          maxFeeSat: dco_decode_u_64(raw[2]),
+        );
       case 10:
-        return PaymentError_InvalidDescription(err: dco_decode_String(raw[1]));
+        return PaymentError_InsufficientFunds();
       case 11:
-        return PaymentError_InvalidInvoice(err: dco_decode_String(raw[1]));
+        return PaymentError_SpendLimitExceeded(
+          amountSat: dco_decode_u_64(raw[1]),
+          remainingSat: dco_decode_u_64(raw[2]),
+        );
       case 12:
-        return PaymentError_InvalidPreimage();
+        return PaymentError_InvalidDescription(err: dco_decode_String(raw[1]));
       case 13:
-        return PaymentError_PairsNotFound();
+        return PaymentError_InvalidInvoice(err: dco_decode_String(raw[1]));
       case 14:
-        return PaymentError_PaymentTimeout();
+        return PaymentError_InvalidPreimage();
       case 15:
-        return PaymentError_PersistError();
+        return PaymentError_PairsNotFound();
       case 16:
-        return PaymentError_ReceiveError(err: dco_decode_String(raw[1]));
+        return PaymentError_PaymentTimeout();
       case 17:
-        return PaymentError_Refunded(err: dco_decode_String(raw[1]), refundTxId: dco_decode_String(raw[2]));
+        return PaymentError_PersistError();
       case 18:
-        return PaymentError_SelfTransferNotSupported();
+        return PaymentError_ReceiveError(err: dco_decode_String(raw[1]));
       case 19:
-        return PaymentError_SendError(err: dco_decode_String(raw[1]));
+        return PaymentError_Refunded(err: dco_decode_String(raw[1]), refundTxId: dco_decode_String(raw[2]));
       case 20:
+        return PaymentError_SelfTransferNotSupported();
+      case 21:
+        return PaymentError_SendError(err: dco_decode_String(raw[1]));
+      case 22:
         return PaymentError_SignerError(err: dco_decode_String(raw[1]));
+      case 23:
+        return PaymentError_ServiceUnavailable(err: dco_decode_String(raw[1]));
       default:
         throw Exception("unreachable");
     }
@@ -2877,12 +5436,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return PaymentState.values[raw as int];
   }
 
+  @protected
+  PaymentSwapType dco_decode_payment_swap_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PaymentSwapType.values[raw as int];
+  }
+
   @protected
   PaymentType dco_decode_payment_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return PaymentType.values[raw as int];
   }
 
+  @protected
+  PaymentUnblindingData dco_decode_payment_unblinding_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PaymentUnblindingData(
+      txId: dco_decode_String(arr[0]),
+      unblindingData: dco_decode_String(arr[1]),
+      unblindedUrl: dco_decode_opt_String(arr[2]),
+      inputs: dco_decode_list_unblinded_tx_out(arr[3]),
+      outputs: dco_decode_list_unblinded_tx_out(arr[4]),
+    );
+  }
+
   @protected
   PrepareBuyBitcoinRequest dco_decode_prepare_buy_bitcoin_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2907,16 +5486,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  PrepareLnUrlPayRequest dco_decode_prepare_ln_url_pay_request(dynamic raw) {
+  PrepareConvertRequest dco_decode_prepare_convert_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PrepareConvertRequest(
+      fromAssetId: dco_decode_String(arr[0]),
+      toAssetId: dco_decode_String(arr[1]),
+      amount: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  PrepareConvertResponse dco_decode_prepare_convert_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PrepareConvertResponse(
+      fromAssetId: dco_decode_String(arr[0]),
+      toAssetId: dco_decode_String(arr[1]),
+      amount: dco_decode_u_64(arr[2]),
+      receiverAmount: dco_decode_u_64(arr[3]),
+      feesSat: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  PrepareLnUrlPayRequest dco_decode_prepare_ln_url_pay_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return PrepareLnUrlPayRequest(
       data: dco_decode_ln_url_pay_request_data(arr[0]),
       amount: dco_decode_pay_amount(arr[1]),
       bip353Address: dco_decode_opt_String(arr[2]),
       comment: dco_decode_opt_String(arr[3]),
-      validateSuccessActionUrl: dco_decode_opt_box_autoadd_bool(arr[4]),
+      payerData: dco_decode_opt_box_autoadd_ln_url_payer_data(arr[4]),
+      validateSuccessActionUrl: dco_decode_opt_box_autoadd_bool(arr[5]),
     );
   }
 
@@ -2924,13 +5530,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareLnUrlPayResponse dco_decode_prepare_ln_url_pay_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
     return PrepareLnUrlPayResponse(
       destination: dco_decode_send_destination(arr[0]),
       feesSat: dco_decode_u_64(arr[1]),
-      data: dco_decode_ln_url_pay_request_data(arr[2]),
-      comment: dco_decode_opt_String(arr[3]),
-      successAction: dco_decode_opt_box_autoadd_success_action(arr[4]),
+      feeBreakdown: dco_decode_fee_breakdown(arr[2]),
+      data: dco_decode_ln_url_pay_request_data(arr[3]),
+      comment: dco_decode_opt_String(arr[4]),
+      successAction: dco_decode_opt_box_autoadd_success_action(arr[5]),
+      verifyUrl: dco_decode_opt_String(arr[6]),
     );
   }
 
@@ -2938,10 +5546,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PreparePayOnchainRequest dco_decode_prepare_pay_onchain_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return PreparePayOnchainRequest(
       amount: dco_decode_pay_amount(arr[0]),
       feeRateSatPerVbyte: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      feeSpeed: dco_decode_opt_box_autoadd_fee_speed(arr[2]),
     );
   }
 
@@ -2958,13 +5567,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  PrepareReceiveRequest dco_decode_prepare_receive_request(dynamic raw) {
+  PrepareReceiveQuotesRequest dco_decode_prepare_receive_quotes_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
     if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PrepareReceiveQuotesRequest(
+      amount: dco_decode_opt_box_autoadd_receive_amount(arr[0]),
+      fiatAmount: dco_decode_opt_box_autoadd_fiat_amount(arr[1]),
+    );
+  }
+
+  @protected
+  PrepareReceiveQuotesResponse dco_decode_prepare_receive_quotes_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return PrepareReceiveQuotesResponse(quotes: dco_decode_list_prepare_receive_response(arr[0]));
+  }
+
+  @protected
+  PrepareReceiveRequest dco_decode_prepare_receive_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return PrepareReceiveRequest(
       paymentMethod: dco_decode_payment_method(arr[0]),
       amount: dco_decode_opt_box_autoadd_receive_amount(arr[1]),
+      fiatAmount: dco_decode_opt_box_autoadd_fiat_amount(arr[2]),
+      expirySecs: dco_decode_opt_box_autoadd_u_32(arr[3]),
     );
   }
 
@@ -2972,14 +5602,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareReceiveResponse dco_decode_prepare_receive_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
     return PrepareReceiveResponse(
       paymentMethod: dco_decode_payment_method(arr[0]),
       amount: dco_decode_opt_box_autoadd_receive_amount(arr[1]),
       feesSat: dco_decode_u_64(arr[2]),
-      minPayerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[3]),
-      maxPayerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[4]),
-      swapperFeerate: dco_decode_opt_box_autoadd_f_64(arr[5]),
+      feeBreakdown: dco_decode_fee_breakdown(arr[3]),
+      minPayerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      maxPayerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      swapperFeerate: dco_decode_opt_box_autoadd_f_64(arr[6]),
+      fiatConversion: dco_decode_opt_box_autoadd_fiat_conversion(arr[7]),
+      expirySecs: dco_decode_opt_box_autoadd_u_32(arr[8]),
     );
   }
 
@@ -2987,11 +5620,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareRefundRequest dco_decode_prepare_refund_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return PrepareRefundRequest(
       swapAddress: dco_decode_String(arr[0]),
       refundAddress: dco_decode_String(arr[1]),
-      feeRateSatPerVbyte: dco_decode_u_32(arr[2]),
+      feeRateSatPerVbyte: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      feeSpeed: dco_decode_opt_box_autoadd_fee_speed(arr[3]),
     );
   }
 
@@ -2999,11 +5633,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareRefundResponse dco_decode_prepare_refund_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return PrepareRefundResponse(
       txVsize: dco_decode_u_32(arr[0]),
       txFeeSat: dco_decode_u_64(arr[1]),
-      lastRefundTxId: dco_decode_opt_String(arr[2]),
+      feeRateSatPerVbyte: dco_decode_u_32(arr[2]),
+      refundableAmountSat: dco_decode_u_64(arr[3]),
+      refundAmountSat: dco_decode_u_64(arr[4]),
+      lastRefundTxId: dco_decode_opt_String(arr[5]),
     );
   }
 
@@ -3011,10 +5648,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareSendRequest dco_decode_prepare_send_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
     return PrepareSendRequest(
       destination: dco_decode_String(arr[0]),
       amount: dco_decode_opt_box_autoadd_pay_amount(arr[1]),
+      fiatAmount: dco_decode_opt_box_autoadd_fiat_amount(arr[2]),
     );
   }
 
@@ -3022,14 +5660,83 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   PrepareSendResponse dco_decode_prepare_send_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return PrepareSendResponse(
       destination: dco_decode_send_destination(arr[0]),
       feesSat: dco_decode_opt_box_autoadd_u_64(arr[1]),
-      estimatedAssetFees: dco_decode_opt_box_autoadd_f_64(arr[2]),
+      feeBreakdown: dco_decode_opt_box_autoadd_fee_breakdown(arr[2]),
+      estimatedAssetFees: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      estimatedAssetFeesAmount: dco_decode_opt_box_autoadd_asset_amount(arr[4]),
+      fiatConversion: dco_decode_opt_box_autoadd_fiat_conversion(arr[5]),
+    );
+  }
+
+  @protected
+  ProcessNotificationRequest dco_decode_process_notification_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ProcessNotificationRequest(
+      connectRequest: dco_decode_connect_request(arr[0]),
+      payload: dco_decode_String(arr[1]),
+      acceptProposedFees: dco_decode_opt_box_autoadd_bool(arr[2]),
+      timeoutSec: dco_decode_opt_box_autoadd_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  ProcessNotificationResponse dco_decode_process_notification_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ProcessNotificationResponse(
+      payment: dco_decode_payment(arr[0]),
+      isResolved: dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  PsetSpendLimit dco_decode_pset_spend_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PsetSpendLimit(assetId: dco_decode_String(arr[0]), maxAmountSat: dco_decode_u_64(arr[1]));
+  }
+
+  @protected
+  QueuePaymentRequest dco_decode_queue_payment_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QueuePaymentRequest(
+      prepareRequest: dco_decode_prepare_send_request(arr[0]),
+      useAssetFees: dco_decode_opt_box_autoadd_bool(arr[1]),
+      maxFeeSat: dco_decode_opt_box_autoadd_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  QueuedPayment dco_decode_queued_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return QueuedPayment(
+      id: dco_decode_String(arr[0]),
+      request: dco_decode_queue_payment_request(arr[1]),
+      state: dco_decode_queued_payment_state(arr[2]),
+      attempts: dco_decode_u_32(arr[3]),
+      createdAt: dco_decode_u_32(arr[4]),
+      nextAttemptAt: dco_decode_u_32(arr[5]),
+      lastError: dco_decode_opt_String(arr[6]),
     );
   }
 
+  @protected
+  QueuedPaymentState dco_decode_queued_payment_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QueuedPaymentState.values[raw as int];
+  }
+
   @protected
   Rate dco_decode_rate(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3049,6 +5756,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           assetId: dco_decode_String(raw[1]),
           payerAmount: dco_decode_opt_box_autoadd_f_64(raw[2]),
         );
+      case 2:
+        return ReceiveAmount_AssetUnits(payerAmount: dco_decode_box_autoadd_asset_amount(raw[1]));
       default:
         throw Exception("unreachable");
     }
@@ -3058,11 +5767,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ReceivePaymentRequest dco_decode_receive_payment_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return ReceivePaymentRequest(
       prepareResponse: dco_decode_prepare_receive_response(arr[0]),
       description: dco_decode_opt_String(arr[1]),
       useDescriptionHash: dco_decode_opt_box_autoadd_bool(arr[2]),
+      descriptionHash: dco_decode_opt_String(arr[3]),
+      claimAddress: dco_decode_opt_String(arr[4]),
     );
   }
 
@@ -3088,15 +5799,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RecurringPayment dco_decode_recurring_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return RecurringPayment(
+      id: dco_decode_String(arr[0]),
+      destination: dco_decode_String(arr[1]),
+      amountSat: dco_decode_u_64(arr[2]),
+      intervalSec: dco_decode_u_32(arr[3]),
+      maxFeeSat: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      createdAt: dco_decode_u_32(arr[5]),
+      nextOccurrenceAt: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  RecurringPaymentOccurrence dco_decode_recurring_payment_occurrence(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RecurringPaymentOccurrence(
+      recurringPaymentId: dco_decode_String(arr[0]),
+      occurrenceAt: dco_decode_u_32(arr[1]),
+      executedAt: dco_decode_u_32(arr[2]),
+      txId: dco_decode_opt_String(arr[3]),
+      error: dco_decode_opt_String(arr[4]),
+    );
+  }
+
   @protected
   RefundRequest dco_decode_refund_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return RefundRequest(
       swapAddress: dco_decode_String(arr[0]),
       refundAddress: dco_decode_String(arr[1]),
-      feeRateSatPerVbyte: dco_decode_u_32(arr[2]),
+      feeRateSatPerVbyte: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      feeSpeed: dco_decode_opt_box_autoadd_fee_speed(arr[3]),
     );
   }
 
@@ -3109,15 +5851,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  RefundableSwap dco_decode_refundable_swap(dynamic raw) {
+  RefundableSwap dco_decode_refundable_swap(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return RefundableSwap(
+      swapAddress: dco_decode_String(arr[0]),
+      timestamp: dco_decode_u_32(arr[1]),
+      amountSat: dco_decode_u_64(arr[2]),
+      lastRefundTxId: dco_decode_opt_String(arr[3]),
+      state: dco_decode_payment_state(arr[4]),
+      refundableSince: dco_decode_u_32(arr[5]),
+      refundEligibleBlockHeight: dco_decode_u_32(arr[6]),
+      isCooperativeRefundPossible: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  RegisterLightningAddressRequest dco_decode_register_lightning_address_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return RegisterLightningAddressRequest(username: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  RegisteredAsset dco_decode_registered_asset(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RegisteredAsset(
+      assetId: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      ticker: dco_decode_opt_String(arr[2]),
+      precision: dco_decode_u_8(arr[3]),
+      domain: dco_decode_opt_String(arr[4]),
+      iconUrl: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  ReplayEventsRequest dco_decode_replay_events_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return RefundableSwap(
-      swapAddress: dco_decode_String(arr[0]),
-      timestamp: dco_decode_u_32(arr[1]),
-      amountSat: dco_decode_u_64(arr[2]),
-      lastRefundTxId: dco_decode_opt_String(arr[3]),
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReplayEventsRequest(
+      sinceSeq: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[1]),
     );
   }
 
@@ -3129,6 +5909,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RestoreRequest(backupPath: dco_decode_opt_String(arr[0]));
   }
 
+  @protected
+  ReusableBitcoinAddressResponse dco_decode_reusable_bitcoin_address_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReusableBitcoinAddressResponse(
+      address: dco_decode_String(arr[0]),
+      minDepositSat: dco_decode_u_64(arr[1]),
+      maxDepositSat: dco_decode_u_64(arr[2]),
+    );
+  }
+
   @protected
   RouteHint dco_decode_route_hint(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3153,6 +5945,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SchedulePaymentRequest dco_decode_schedule_payment_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SchedulePaymentRequest(
+      prepareRequest: dco_decode_prepare_send_request(arr[0]),
+      useAssetFees: dco_decode_opt_box_autoadd_bool(arr[1]),
+      maxFeeSat: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      executeAt: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  ScheduledPayment dco_decode_scheduled_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ScheduledPayment(
+      id: dco_decode_String(arr[0]),
+      request: dco_decode_schedule_payment_request(arr[1]),
+      state: dco_decode_scheduled_payment_state(arr[2]),
+      attempts: dco_decode_u_32(arr[3]),
+      createdAt: dco_decode_u_32(arr[4]),
+      nextAttemptAt: dco_decode_u_32(arr[5]),
+      txId: dco_decode_opt_String(arr[6]),
+      lastError: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  ScheduledPaymentState dco_decode_scheduled_payment_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ScheduledPaymentState.values[raw as int];
+  }
+
   @protected
   SdkError dco_decode_sdk_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3160,10 +5988,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 0:
         return SdkError_AlreadyStarted();
       case 1:
-        return SdkError_Generic(err: dco_decode_String(raw[1]));
+        return SdkError_DataDirLocked(dir: dco_decode_String(raw[1]));
       case 2:
-        return SdkError_NotStarted();
+        return SdkError_Generic(err: dco_decode_String(raw[1]));
       case 3:
+        return SdkError_InvalidBackup(err: dco_decode_String(raw[1]));
+      case 4:
+        return SdkError_NotStarted();
+      case 5:
         return SdkError_ServiceConnectivity(err: dco_decode_String(raw[1]));
       default:
         throw Exception("unreachable");
@@ -3191,14 +6023,99 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 7:
         return SdkEvent_PaymentWaitingFeeAcceptance(details: dco_decode_box_autoadd_payment(raw[1]));
       case 8:
-        return SdkEvent_Synced();
+        return SdkEvent_PaymentExpired(details: dco_decode_box_autoadd_payment(raw[1]));
       case 9:
+        return SdkEvent_PaymentReorged(details: dco_decode_box_autoadd_payment(raw[1]));
+      case 10:
+        return SdkEvent_SwapUpdated(
+          swapId: dco_decode_String(raw[1]),
+          swapType: dco_decode_payment_swap_type(raw[2]),
+          state: dco_decode_payment_state(raw[3]),
+        );
+      case 11:
+        return SdkEvent_SwapClaimBroadcast(
+          swapId: dco_decode_String(raw[1]),
+          txId: dco_decode_String(raw[2]),
+        );
+      case 12:
+        return SdkEvent_SwapRefundBroadcast(
+          swapId: dco_decode_String(raw[1]),
+          txId: dco_decode_String(raw[2]),
+        );
+      case 13:
+        return SdkEvent_TipsUpdated(liquidTip: dco_decode_u_32(raw[1]), bitcoinTip: dco_decode_u_32(raw[2]));
+      case 14:
+        return SdkEvent_SyncStarted();
+      case 15:
+        return SdkEvent_Synced();
+      case 16:
         return SdkEvent_DataSynced(didPullNewRecords: dco_decode_bool(raw[1]));
+      case 17:
+        return SdkEvent_ConnectivityChanged(isOnline: dco_decode_bool(raw[1]));
+      case 18:
+        return SdkEvent_BackupSucceeded();
+      case 19:
+        return SdkEvent_BackupFailed(err: dco_decode_String(raw[1]));
+      case 20:
+        return SdkEvent_BatchSendCompleted(results: dco_decode_list_batch_send_item_result(raw[1]));
+      case 21:
+        return SdkEvent_ScheduledPaymentExecuted(
+          id: dco_decode_String(raw[1]),
+          details: dco_decode_box_autoadd_payment(raw[2]),
+        );
+      case 22:
+        return SdkEvent_ScheduledPaymentFailed(id: dco_decode_String(raw[1]), err: dco_decode_String(raw[2]));
+      case 23:
+        return SdkEvent_RecurringPaymentExecuted(
+          recurringPaymentId: dco_decode_String(raw[1]),
+          details: dco_decode_box_autoadd_payment(raw[2]),
+        );
+      case 24:
+        return SdkEvent_RecurringPaymentFailed(
+          recurringPaymentId: dco_decode_String(raw[1]),
+          err: dco_decode_String(raw[2]),
+        );
+      case 25:
+        return SdkEvent_TransactionDropped(txId: dco_decode_String(raw[1]));
+      case 26:
+        return SdkEvent_TransactionUnconfirmed(
+          txId: dco_decode_String(raw[1]),
+          unconfirmedSec: dco_decode_u_32(raw[2]),
+        );
+      case 27:
+        return SdkEvent_NewLiquidBlock(block: dco_decode_box_autoadd_block_info(raw[1]));
+      case 28:
+        return SdkEvent_NewBitcoinBlock(block: dco_decode_box_autoadd_block_info(raw[1]));
       default:
         throw Exception("unreachable");
     }
   }
 
+  @protected
+  SdkEventKind dco_decode_sdk_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SdkEventKind.values[raw as int];
+  }
+
+  @protected
+  SendBatchRequest dco_decode_send_batch_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SendBatchRequest(
+      items: dco_decode_list_batch_send_item(arr[0]),
+      maxTotalFeeSat: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  SendBatchResponse dco_decode_send_batch_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return SendBatchResponse(results: dco_decode_list_batch_send_item_result(arr[0]));
+  }
+
   @protected
   SendDestination dco_decode_send_destination(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3207,6 +6124,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return SendDestination_LiquidAddress(
           addressData: dco_decode_box_autoadd_liquid_address_data(raw[1]),
           bip353Address: dco_decode_opt_String(raw[2]),
+          payjoinUrl: dco_decode_opt_String(raw[3]),
         );
       case 1:
         return SendDestination_Bolt11(
@@ -3228,10 +6146,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   SendPaymentRequest dco_decode_send_payment_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
     return SendPaymentRequest(
       prepareResponse: dco_decode_prepare_send_response(arr[0]),
       useAssetFees: dco_decode_opt_box_autoadd_bool(arr[1]),
+      maxFeeSat: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      spendLimitOverride: dco_decode_opt_String(arr[3]),
+      idempotencyKey: dco_decode_opt_String(arr[4]),
     );
   }
 
@@ -3243,6 +6164,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return SendPaymentResponse(payment: dco_decode_payment(arr[0]));
   }
 
+  @protected
+  ServiceHealth dco_decode_service_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ServiceHealth(
+      service: dco_decode_health_check_service(arr[0]),
+      isReachable: dco_decode_bool(arr[1]),
+      latencyMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      error: dco_decode_opt_String(arr[3]),
+    );
+  }
+
   @protected
   SignMessageRequest dco_decode_sign_message_request(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3255,8 +6189,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   SignMessageResponse dco_decode_sign_message_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return SignMessageResponse(signature: dco_decode_String(arr[0]));
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SignMessageResponse(signature: dco_decode_String(arr[0]), pubkey: dco_decode_String(arr[1]));
+  }
+
+  @protected
+  SignPsetRequest dco_decode_sign_pset_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SignPsetRequest(
+      pset: dco_decode_String(arr[0]),
+      maxFeeSat: dco_decode_u_64(arr[1]),
+      spendLimits: dco_decode_list_pset_spend_limit(arr[2]),
+    );
+  }
+
+  @protected
+  SignPsetResponse dco_decode_sign_pset_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SignPsetResponse(pset: dco_decode_String(arr[0]), feesSat: dco_decode_u_64(arr[1]));
+  }
+
+  @protected
+  SimulationConfig dco_decode_simulation_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SimulationConfig(
+      statusUpdateDelaySec: dco_decode_u_32(arr[0]),
+      failureRate: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  SpendLimit dco_decode_spend_limit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SpendLimit_Bitcoin(amountSat: dco_decode_u_64(raw[1]));
+      case 1:
+        return SpendLimit_Fiat(amount: dco_decode_box_autoadd_fiat_amount(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SpendLimits dco_decode_spend_limits(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SpendLimits(
+      perPayment: dco_decode_opt_box_autoadd_spend_limit(arr[0]),
+      perDay: dco_decode_opt_box_autoadd_spend_limit(arr[1]),
+      overrideSecret: dco_decode_opt_String(arr[2]),
+    );
   }
 
   @protected
@@ -3293,6 +6283,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  SwapInfo dco_decode_swap_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17) throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return SwapInfo(
+      swapId: dco_decode_String(arr[0]),
+      swapType: dco_decode_payment_swap_type(arr[1]),
+      direction: dco_decode_opt_box_autoadd_direction(arr[2]),
+      state: dco_decode_payment_state(arr[3]),
+      createdAt: dco_decode_u_32(arr[4]),
+      timeoutBlockHeight: dco_decode_u_64(arr[5]),
+      payerAmountSat: dco_decode_u_64(arr[6]),
+      receiverAmountSat: dco_decode_u_64(arr[7]),
+      invoice: dco_decode_opt_String(arr[8]),
+      lockupAddress: dco_decode_opt_String(arr[9]),
+      userLockupTxId: dco_decode_opt_String(arr[10]),
+      serverLockupTxId: dco_decode_opt_String(arr[11]),
+      claimAddress: dco_decode_opt_String(arr[12]),
+      claimTxId: dco_decode_opt_String(arr[13]),
+      refundAddress: dco_decode_opt_String(arr[14]),
+      refundTxId: dco_decode_opt_String(arr[15]),
+      createResponseJson: dco_decode_String(arr[16]),
+    );
+  }
+
+  @protected
+  SwapServiceStatus dco_decode_swap_service_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SwapServiceStatus(
+      sendAvailable: dco_decode_bool(arr[0]),
+      receiveAvailable: dco_decode_bool(arr[1]),
+      chainSendAvailable: dco_decode_bool(arr[2]),
+      chainReceiveAvailable: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  SweepPrivateKeyRequest dco_decode_sweep_private_key_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return SweepPrivateKeyRequest(key: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  SweepPrivateKeyResponse dco_decode_sweep_private_key_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SweepPrivateKeyResponse(
+      payment: dco_decode_payment(arr[0]),
+      amountSat: dco_decode_u_64(arr[1]),
+      assetAmounts: dco_decode_list_asset_amount(arr[2]),
+    );
+  }
+
   @protected
   Symbol dco_decode_symbol(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3306,6 +6355,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SyncRequest dco_decode_sync_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return SyncRequest(full: dco_decode_opt_box_autoadd_bool(arr[0]));
+  }
+
+  @protected
+  SyncResponse dco_decode_sync_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SyncResponse(
+      newTxCount: dco_decode_u_32(arr[0]),
+      updatedSwapCount: dco_decode_u_32(arr[1]),
+      durationMs: dco_decode_u_64(arr[2]),
+    );
+  }
+
   @protected
   int dco_decode_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3330,6 +6399,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as int;
   }
 
+  @protected
+  UnblindedTxOut dco_decode_unblinded_tx_out(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return UnblindedTxOut(
+      outpoint: dco_decode_String(arr[0]),
+      assetId: dco_decode_String(arr[1]),
+      amount: dco_decode_u_64(arr[2]),
+      assetBlindingFactor: dco_decode_String(arr[3]),
+      amountBlindingFactor: dco_decode_String(arr[4]),
+    );
+  }
+
   @protected
   void dco_decode_unit(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3354,6 +6437,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeU64(raw);
   }
 
+  @protected
+  ValidateAddressResponse dco_decode_validate_address_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ValidateAddressResponse(
+      addressType: dco_decode_address_type(arr[0]),
+      network: dco_decode_liquid_network(arr[1]),
+      isConfidential: dco_decode_bool(arr[2]),
+      supportsAssets: dco_decode_bool(arr[3]),
+    );
+  }
+
   @protected
   WalletInfo dco_decode_wallet_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -3400,6 +6496,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_isize(deserializer);
+    return decodeDartOpaque(inner, generalizedFrbRustBinding);
+  }
+
   @protected
   BindingLiquidSdk sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     SseDeserializer deserializer,
@@ -3439,6 +6542,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return AcceptPaymentProposedFeesRequest(response: var_response);
   }
 
+  @protected
+  AddNwcConnectionRequest sse_decode_add_nwc_connection_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_budget = sse_decode_opt_box_autoadd_nwc_budget(deserializer);
+    return AddNwcConnectionRequest(name: var_name, budget: var_budget);
+  }
+
+  @protected
+  AddNwcConnectionResponse sse_decode_add_nwc_connection_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connectionUri = sse_decode_String(deserializer);
+    return AddNwcConnectionResponse(connectionUri: var_connectionUri);
+  }
+
+  @protected
+  AddressType sse_decode_address_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AddressType.values[inner];
+  }
+
   @protected
   AesSuccessActionData sse_decode_aes_success_action_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3491,6 +6616,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AmpAccount sse_decode_amp_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ampId = sse_decode_String(deserializer);
+    var var_descriptor = sse_decode_String(deserializer);
+    return AmpAccount(ampId: var_ampId, descriptor: var_descriptor);
+  }
+
+  @protected
+  AssetAmount sse_decode_asset_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assetId = sse_decode_String(deserializer);
+    var var_baseUnits = sse_decode_u_64(deserializer);
+    var var_precision = sse_decode_u_8(deserializer);
+    var var_displayPrecision = sse_decode_u_8(deserializer);
+    return AssetAmount(
+      assetId: var_assetId,
+      baseUnits: var_baseUnits,
+      precision: var_precision,
+      displayPrecision: var_displayPrecision,
+    );
+  }
+
   @protected
   AssetBalance sse_decode_asset_balance(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3499,12 +6647,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_name = sse_decode_opt_String(deserializer);
     var var_ticker = sse_decode_opt_String(deserializer);
     var var_balance = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_pendingSendSat = sse_decode_u_64(deserializer);
+    var var_pendingReceiveSat = sse_decode_u_64(deserializer);
+    var var_pendingSend = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_pendingReceive = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_assetBalance = sse_decode_opt_box_autoadd_asset_amount(deserializer);
     return AssetBalance(
       assetId: var_assetId,
       balanceSat: var_balanceSat,
       name: var_name,
       ticker: var_ticker,
       balance: var_balance,
+      pendingSendSat: var_pendingSendSat,
+      pendingReceiveSat: var_pendingReceiveSat,
+      pendingSend: var_pendingSend,
+      pendingReceive: var_pendingReceive,
+      assetBalance: var_assetBalance,
+    );
+  }
+
+  @protected
+  AssetConversion sse_decode_asset_conversion(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fromAssetId = sse_decode_String(deserializer);
+    var var_fromAmount = sse_decode_u_64(deserializer);
+    var var_toAssetId = sse_decode_String(deserializer);
+    var var_toAmount = sse_decode_u_64(deserializer);
+    return AssetConversion(
+      fromAssetId: var_fromAssetId,
+      fromAmount: var_fromAmount,
+      toAssetId: var_toAssetId,
+      toAmount: var_toAmount,
     );
   }
 
@@ -3515,7 +6688,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_ticker = sse_decode_String(deserializer);
     var var_amount = sse_decode_f_64(deserializer);
     var var_fees = sse_decode_opt_box_autoadd_f_64(deserializer);
-    return AssetInfo(name: var_name, ticker: var_ticker, amount: var_amount, fees: var_fees);
+    var var_assetAmount = sse_decode_asset_amount(deserializer);
+    var var_assetFees = sse_decode_opt_box_autoadd_asset_amount(deserializer);
+    return AssetInfo(
+      name: var_name,
+      ticker: var_ticker,
+      amount: var_amount,
+      fees: var_fees,
+      assetAmount: var_assetAmount,
+      assetFees: var_assetFees,
+    );
   }
 
   @protected
@@ -3526,15 +6708,55 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_ticker = sse_decode_String(deserializer);
     var var_precision = sse_decode_u_8(deserializer);
     var var_fiatId = sse_decode_opt_String(deserializer);
+    var var_isAmp = sse_decode_bool(deserializer);
     return AssetMetadata(
       assetId: var_assetId,
       name: var_name,
       ticker: var_ticker,
       precision: var_precision,
       fiatId: var_fiatId,
+      isAmp: var_isAmp,
+    );
+  }
+
+  @protected
+  AssetReport sse_decode_asset_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assetId = sse_decode_String(deserializer);
+    var var_receivedAmount = sse_decode_u_64(deserializer);
+    var var_sentAmount = sse_decode_u_64(deserializer);
+    var var_swapFeesSat = sse_decode_u_64(deserializer);
+    var var_onchainFeesSat = sse_decode_u_64(deserializer);
+    var var_receivedCount = sse_decode_u_32(deserializer);
+    var var_sentCount = sse_decode_u_32(deserializer);
+    return AssetReport(
+      assetId: var_assetId,
+      receivedAmount: var_receivedAmount,
+      sentAmount: var_sentAmount,
+      swapFeesSat: var_swapFeesSat,
+      onchainFeesSat: var_onchainFeesSat,
+      receivedCount: var_receivedCount,
+      sentCount: var_sentCount,
     );
   }
 
+  @protected
+  BackupPolicy sse_decode_backup_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_swaps = sse_decode_u_32(deserializer);
+        return BackupPolicy_CompletedSwaps(swaps: var_swaps);
+      case 1:
+        var var_hours = sse_decode_u_32(deserializer);
+        return BackupPolicy_Interval(hours: var_hours);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   BackupRequest sse_decode_backup_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3543,10 +6765,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  BindingEventListener sse_decode_binding_event_listener(SseDeserializer deserializer) {
+  BatchSendItem sse_decode_batch_send_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_destination = sse_decode_String(deserializer);
+    var var_amountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return BatchSendItem(destination: var_destination, amountSat: var_amountSat);
+  }
+
+  @protected
+  BatchSendItemResult sse_decode_batch_send_item_result(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_stream = sse_decode_StreamSink_sdk_event_Dco(deserializer);
-    return BindingEventListener(stream: var_stream);
+    var var_destination = sse_decode_String(deserializer);
+    var var_payment = sse_decode_opt_box_autoadd_payment(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return BatchSendItemResult(destination: var_destination, payment: var_payment, error: var_error);
   }
 
   @protected
@@ -3566,6 +6798,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  BlockInfo sse_decode_block_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_height = sse_decode_u_32(deserializer);
+    var var_hash = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_u_32(deserializer);
+    return BlockInfo(height: var_height, hash: var_hash, timestamp: var_timestamp);
+  }
+
   @protected
   BlockchainExplorer sse_decode_blockchain_explorer(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3606,6 +6847,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_accept_payment_proposed_fees_request(deserializer));
   }
 
+  @protected
+  AddNwcConnectionRequest sse_decode_box_autoadd_add_nwc_connection_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_add_nwc_connection_request(deserializer));
+  }
+
   @protected
   AesSuccessActionData sse_decode_box_autoadd_aes_success_action_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3634,6 +6881,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_amount(deserializer));
   }
 
+  @protected
+  AmpAccount sse_decode_box_autoadd_amp_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_amp_account(deserializer));
+  }
+
+  @protected
+  AssetAmount sse_decode_box_autoadd_asset_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_asset_amount(deserializer));
+  }
+
+  @protected
+  AssetConversion sse_decode_box_autoadd_asset_conversion(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_asset_conversion(deserializer));
+  }
+
   @protected
   AssetInfo sse_decode_box_autoadd_asset_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3641,15 +6906,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  BackupRequest sse_decode_box_autoadd_backup_request(SseDeserializer deserializer) {
+  BackupPolicy sse_decode_box_autoadd_backup_policy(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_backup_request(deserializer));
+    return (sse_decode_backup_policy(deserializer));
   }
 
   @protected
-  BindingEventListener sse_decode_box_autoadd_binding_event_listener(SseDeserializer deserializer) {
+  BackupRequest sse_decode_box_autoadd_backup_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_binding_event_listener(deserializer));
+    return (sse_decode_backup_request(deserializer));
   }
 
   @protected
@@ -3658,12 +6923,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_bitcoin_address_data(deserializer));
   }
 
+  @protected
+  BlockInfo sse_decode_box_autoadd_block_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_block_info(deserializer));
+  }
+
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_bool(deserializer));
   }
 
+  @protected
+  BuyBitcoinProvider sse_decode_box_autoadd_buy_bitcoin_provider(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_buy_bitcoin_provider(deserializer));
+  }
+
   @protected
   BuyBitcoinRequest sse_decode_box_autoadd_buy_bitcoin_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3682,12 +6959,70 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_connect_request(deserializer));
   }
 
+  @protected
+  ConvertRequest sse_decode_box_autoadd_convert_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_convert_request(deserializer));
+  }
+
+  @protected
+  CreateRecurringPaymentRequest sse_decode_box_autoadd_create_recurring_payment_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_create_recurring_payment_request(deserializer));
+  }
+
+  @protected
+  Direction sse_decode_box_autoadd_direction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_direction(deserializer));
+  }
+
+  @protected
+  EventFilter sse_decode_box_autoadd_event_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_event_filter(deserializer));
+  }
+
+  @protected
+  EventWebhookConfig sse_decode_box_autoadd_event_webhook_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_event_webhook_config(deserializer));
+  }
+
+  @protected
+  ExportPaymentUnblindingDataRequest sse_decode_box_autoadd_export_payment_unblinding_data_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_export_payment_unblinding_data_request(deserializer));
+  }
+
+  @protected
+  ExportWalletRequest sse_decode_box_autoadd_export_wallet_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_export_wallet_request(deserializer));
+  }
+
   @protected
   double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_f_64(deserializer));
   }
 
+  @protected
+  FeeBreakdown sse_decode_box_autoadd_fee_breakdown(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fee_breakdown(deserializer));
+  }
+
+  @protected
+  FeeSpeed sse_decode_box_autoadd_fee_speed(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fee_speed(deserializer));
+  }
+
   @protected
   FetchPaymentProposedFeesRequest sse_decode_box_autoadd_fetch_payment_proposed_fees_request(
     SseDeserializer deserializer,
@@ -3696,18 +7031,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_fetch_payment_proposed_fees_request(deserializer));
   }
 
+  @protected
+  FiatAmount sse_decode_box_autoadd_fiat_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fiat_amount(deserializer));
+  }
+
+  @protected
+  FiatConversion sse_decode_box_autoadd_fiat_conversion(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fiat_conversion(deserializer));
+  }
+
+  @protected
+  GetMaxPayableRequest sse_decode_box_autoadd_get_max_payable_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_get_max_payable_request(deserializer));
+  }
+
+  @protected
+  GetMaxReceivableRequest sse_decode_box_autoadd_get_max_receivable_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_get_max_receivable_request(deserializer));
+  }
+
   @protected
   GetPaymentRequest sse_decode_box_autoadd_get_payment_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_get_payment_request(deserializer));
   }
 
+  @protected
+  GetReportRequest sse_decode_box_autoadd_get_report_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_get_report_request(deserializer));
+  }
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_i_64(deserializer));
   }
 
+  @protected
+  ImportWalletRequest sse_decode_box_autoadd_import_wallet_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_import_wallet_request(deserializer));
+  }
+
+  @protected
+  InputType sse_decode_box_autoadd_input_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_input_type(deserializer));
+  }
+
+  @protected
+  LightningAddressInfo sse_decode_box_autoadd_lightning_address_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_lightning_address_info(deserializer));
+  }
+
   @protected
   LiquidAddressData sse_decode_box_autoadd_liquid_address_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3726,6 +7109,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_list_payments_request(deserializer));
   }
 
+  @protected
+  ListSwapsRequest sse_decode_box_autoadd_list_swaps_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_list_swaps_request(deserializer));
+  }
+
+  @protected
+  LNBolt12Invoice sse_decode_box_autoadd_ln_bolt_12_invoice(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ln_bolt_12_invoice(deserializer));
+  }
+
   @protected
   LNInvoice sse_decode_box_autoadd_ln_invoice(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3780,6 +7175,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_ln_url_pay_success_data(deserializer));
   }
 
+  @protected
+  LnUrlPayerData sse_decode_box_autoadd_ln_url_payer_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ln_url_payer_data(deserializer));
+  }
+
   @protected
   LnUrlWithdrawRequest sse_decode_box_autoadd_ln_url_withdraw_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3804,6 +7205,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_message_success_action_data(deserializer));
   }
 
+  @protected
+  NwcBudget sse_decode_box_autoadd_nwc_budget(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_nwc_budget(deserializer));
+  }
+
   @protected
   PayAmount sse_decode_box_autoadd_pay_amount(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3828,6 +7235,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_prepare_buy_bitcoin_request(deserializer));
   }
 
+  @protected
+  PrepareConvertRequest sse_decode_box_autoadd_prepare_convert_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_convert_request(deserializer));
+  }
+
   @protected
   PrepareLnUrlPayRequest sse_decode_box_autoadd_prepare_ln_url_pay_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3840,6 +7253,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_prepare_pay_onchain_request(deserializer));
   }
 
+  @protected
+  PrepareReceiveQuotesRequest sse_decode_box_autoadd_prepare_receive_quotes_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_receive_quotes_request(deserializer));
+  }
+
   @protected
   PrepareReceiveRequest sse_decode_box_autoadd_prepare_receive_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3858,6 +7279,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_prepare_send_request(deserializer));
   }
 
+  @protected
+  ProcessNotificationRequest sse_decode_box_autoadd_process_notification_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_process_notification_request(deserializer));
+  }
+
+  @protected
+  QueuePaymentRequest sse_decode_box_autoadd_queue_payment_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_queue_payment_request(deserializer));
+  }
+
   @protected
   ReceiveAmount sse_decode_box_autoadd_receive_amount(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3876,6 +7311,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_refund_request(deserializer));
   }
 
+  @protected
+  RegisterLightningAddressRequest sse_decode_box_autoadd_register_lightning_address_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_register_lightning_address_request(deserializer));
+  }
+
+  @protected
+  RegisteredAsset sse_decode_box_autoadd_registered_asset(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_registered_asset(deserializer));
+  }
+
+  @protected
+  ReplayEventsRequest sse_decode_box_autoadd_replay_events_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_replay_events_request(deserializer));
+  }
+
   @protected
   RestoreRequest sse_decode_box_autoadd_restore_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3883,9 +7338,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  SdkEvent sse_decode_box_autoadd_sdk_event(SseDeserializer deserializer) {
+  SchedulePaymentRequest sse_decode_box_autoadd_schedule_payment_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_schedule_payment_request(deserializer));
+  }
+
+  @protected
+  SendBatchRequest sse_decode_box_autoadd_send_batch_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_sdk_event(deserializer));
+    return (sse_decode_send_batch_request(deserializer));
   }
 
   @protected
@@ -3900,6 +7361,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_sign_message_request(deserializer));
   }
 
+  @protected
+  SignPsetRequest sse_decode_box_autoadd_sign_pset_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sign_pset_request(deserializer));
+  }
+
+  @protected
+  SimulationConfig sse_decode_box_autoadd_simulation_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_simulation_config(deserializer));
+  }
+
+  @protected
+  SpendLimit sse_decode_box_autoadd_spend_limit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_spend_limit(deserializer));
+  }
+
+  @protected
+  SpendLimits sse_decode_box_autoadd_spend_limits(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_spend_limits(deserializer));
+  }
+
   @protected
   SuccessAction sse_decode_box_autoadd_success_action(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3912,12 +7397,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_success_action_processed(deserializer));
   }
 
+  @protected
+  SwapServiceStatus sse_decode_box_autoadd_swap_service_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_swap_service_status(deserializer));
+  }
+
+  @protected
+  SweepPrivateKeyRequest sse_decode_box_autoadd_sweep_private_key_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sweep_private_key_request(deserializer));
+  }
+
   @protected
   Symbol sse_decode_box_autoadd_symbol(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_symbol(deserializer));
   }
 
+  @protected
+  SyncRequest sse_decode_box_autoadd_sync_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_request(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3951,6 +7454,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return BuyBitcoinRequest(prepareResponse: var_prepareResponse, redirectUrl: var_redirectUrl);
   }
 
+  @protected
+  CheckHealthResponse sse_decode_check_health_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_isDegraded = sse_decode_bool(deserializer);
+    var var_services = sse_decode_list_service_health(deserializer);
+    var var_swapServiceStatus = sse_decode_opt_box_autoadd_swap_service_status(deserializer);
+    return CheckHealthResponse(
+      isDegraded: var_isDegraded,
+      services: var_services,
+      swapServiceStatus: var_swapServiceStatus,
+    );
+  }
+
   @protected
   CheckMessageRequest sse_decode_check_message_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3984,6 +7500,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_onchainFeeRateLeewaySatPerVbyte = sse_decode_opt_box_autoadd_u_32(deserializer);
     var var_assetMetadata = sse_decode_opt_list_asset_metadata(deserializer);
     var var_sideswapApiKey = sse_decode_opt_String(deserializer);
+    var var_eventWebhook = sse_decode_opt_box_autoadd_event_webhook_config(deserializer);
+    var var_fiatCurrency = sse_decode_opt_String(deserializer);
+    var var_syncIntervalSec = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bitcoinExplorerAuthorization = sse_decode_opt_String(deserializer);
+    var var_swapperMaxAttempts = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_backupPolicy = sse_decode_opt_box_autoadd_backup_policy(deserializer);
+    var var_nwcRelayUrl = sse_decode_opt_String(deserializer);
+    var var_spendLimits = sse_decode_opt_box_autoadd_spend_limits(deserializer);
+    var var_lnurlServiceUrl = sse_decode_opt_String(deserializer);
+    var var_outgoingMinConfirmations = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_unconfirmedTxAlertSec = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_chainServiceTimeoutSec = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_swapperTimeoutSec = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_swapperPingIntervalSec = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_ampServerUrl = sse_decode_opt_String(deserializer);
+    var var_simulation = sse_decode_opt_box_autoadd_simulation_config(deserializer);
+    var var_manualClaim = sse_decode_bool(deserializer);
+    var var_claimAddress = sse_decode_opt_String(deserializer);
+    var var_dnsOverHttpsUrl = sse_decode_opt_String(deserializer);
     return Config(
       liquidExplorer: var_liquidExplorer,
       bitcoinExplorer: var_bitcoinExplorer,
@@ -3999,6 +7534,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       onchainFeeRateLeewaySatPerVbyte: var_onchainFeeRateLeewaySatPerVbyte,
       assetMetadata: var_assetMetadata,
       sideswapApiKey: var_sideswapApiKey,
+      eventWebhook: var_eventWebhook,
+      fiatCurrency: var_fiatCurrency,
+      syncIntervalSec: var_syncIntervalSec,
+      bitcoinExplorerAuthorization: var_bitcoinExplorerAuthorization,
+      swapperMaxAttempts: var_swapperMaxAttempts,
+      backupPolicy: var_backupPolicy,
+      nwcRelayUrl: var_nwcRelayUrl,
+      spendLimits: var_spendLimits,
+      lnurlServiceUrl: var_lnurlServiceUrl,
+      outgoingMinConfirmations: var_outgoingMinConfirmations,
+      unconfirmedTxAlertSec: var_unconfirmedTxAlertSec,
+      chainServiceTimeoutSec: var_chainServiceTimeoutSec,
+      swapperTimeoutSec: var_swapperTimeoutSec,
+      swapperPingIntervalSec: var_swapperPingIntervalSec,
+      ampServerUrl: var_ampServerUrl,
+      simulation: var_simulation,
+      manualClaim: var_manualClaim,
+      claimAddress: var_claimAddress,
+      dnsOverHttpsUrl: var_dnsOverHttpsUrl,
     );
   }
 
@@ -4009,11 +7563,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_mnemonic = sse_decode_opt_String(deserializer);
     var var_passphrase = sse_decode_opt_String(deserializer);
     var var_seed = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_xprv = sse_decode_opt_String(deserializer);
     return ConnectRequest(
       config: var_config,
       mnemonic: var_mnemonic,
       passphrase: var_passphrase,
       seed: var_seed,
+      xprv: var_xprv,
+    );
+  }
+
+  @protected
+  ConvertRequest sse_decode_convert_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_prepareResponse = sse_decode_prepare_convert_response(deserializer);
+    var var_spendLimitOverride = sse_decode_opt_String(deserializer);
+    return ConvertRequest(prepareResponse: var_prepareResponse, spendLimitOverride: var_spendLimitOverride);
+  }
+
+  @protected
+  CreateRecurringPaymentRequest sse_decode_create_recurring_payment_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_destination = sse_decode_String(deserializer);
+    var var_amountSat = sse_decode_u_64(deserializer);
+    var var_intervalSec = sse_decode_u_32(deserializer);
+    var var_startAt = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_maxFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return CreateRecurringPaymentRequest(
+      destination: var_destination,
+      amountSat: var_amountSat,
+      intervalSec: var_intervalSec,
+      startAt: var_startAt,
+      maxFeeSat: var_maxFeeSat,
     );
   }
 
@@ -4038,6 +7619,64 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  DecodedInvoice sse_decode_decoded_invoice(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_invoice = sse_decode_box_autoadd_ln_invoice(deserializer);
+        return DecodedInvoice_Bolt11(invoice: var_invoice);
+      case 1:
+        var var_invoice = sse_decode_box_autoadd_ln_bolt_12_invoice(deserializer);
+        return DecodedInvoice_Bolt12(invoice: var_invoice);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  Direction sse_decode_direction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Direction.values[inner];
+  }
+
+  @protected
+  EventFilter sse_decode_event_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kinds = sse_decode_opt_list_sdk_event_kind(deserializer);
+    var var_paymentIds = sse_decode_opt_list_String(deserializer);
+    var var_swapTypes = sse_decode_opt_list_payment_swap_type(deserializer);
+    return EventFilter(kinds: var_kinds, paymentIds: var_paymentIds, swapTypes: var_swapTypes);
+  }
+
+  @protected
+  EventWebhookConfig sse_decode_event_webhook_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_secret = sse_decode_String(deserializer);
+    return EventWebhookConfig(url: var_url, secret: var_secret);
+  }
+
+  @protected
+  ExportPaymentUnblindingDataRequest sse_decode_export_payment_unblinding_data_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_paymentId = sse_decode_String(deserializer);
+    var var_explorerUrl = sse_decode_opt_String(deserializer);
+    return ExportPaymentUnblindingDataRequest(paymentId: var_paymentId, explorerUrl: var_explorerUrl);
+  }
+
+  @protected
+  ExportWalletRequest sse_decode_export_wallet_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exportPath = sse_decode_String(deserializer);
+    return ExportWalletRequest(exportPath: var_exportPath);
+  }
+
   @protected
   ExternalInputParser sse_decode_external_input_parser(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4057,6 +7696,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  FeeBreakdown sse_decode_fee_breakdown(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_serviceFeeSat = sse_decode_u_64(deserializer);
+    var var_swapperNetworkFeeSat = sse_decode_u_64(deserializer);
+    var var_lockupFeeSat = sse_decode_u_64(deserializer);
+    var var_lockupFeeIsEstimate = sse_decode_bool(deserializer);
+    var var_claimFeeSat = sse_decode_u_64(deserializer);
+    var var_claimFeeIsEstimate = sse_decode_bool(deserializer);
+    var var_assetFee = sse_decode_opt_box_autoadd_asset_amount(deserializer);
+    return FeeBreakdown(
+      serviceFeeSat: var_serviceFeeSat,
+      swapperNetworkFeeSat: var_swapperNetworkFeeSat,
+      lockupFeeSat: var_lockupFeeSat,
+      lockupFeeIsEstimate: var_lockupFeeIsEstimate,
+      claimFeeSat: var_claimFeeSat,
+      claimFeeIsEstimate: var_claimFeeIsEstimate,
+      assetFee: var_assetFee,
+    );
+  }
+
+  @protected
+  FeeSpeed sse_decode_fee_speed(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FeeSpeed.values[inner];
+  }
+
   @protected
   FetchPaymentProposedFeesRequest sse_decode_fetch_payment_proposed_fees_request(
     SseDeserializer deserializer,
@@ -4083,6 +7750,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  FiatAmount sse_decode_fiat_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_f_64(deserializer);
+    var var_currency = sse_decode_String(deserializer);
+    return FiatAmount(amount: var_amount, currency: var_currency);
+  }
+
+  @protected
+  FiatConversion sse_decode_fiat_conversion(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fiatAmount = sse_decode_fiat_amount(deserializer);
+    var var_rate = sse_decode_f_64(deserializer);
+    var var_amountSat = sse_decode_u_64(deserializer);
+    return FiatConversion(fiatAmount: var_fiatAmount, rate: var_rate, amountSat: var_amountSat);
+  }
+
   @protected
   FiatCurrency sse_decode_fiat_currency(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4099,6 +7783,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return GetInfoResponse(walletInfo: var_walletInfo, blockchainInfo: var_blockchainInfo);
   }
 
+  @protected
+  GetMaxPayableRequest sse_decode_get_max_payable_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_paymentMethod = sse_decode_payment_method(deserializer);
+    return GetMaxPayableRequest(paymentMethod: var_paymentMethod);
+  }
+
+  @protected
+  GetMaxPayableResponse sse_decode_get_max_payable_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_receiverAmountSat = sse_decode_u_64(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    return GetMaxPayableResponse(receiverAmountSat: var_receiverAmountSat, feesSat: var_feesSat);
+  }
+
+  @protected
+  GetMaxReceivableRequest sse_decode_get_max_receivable_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_paymentMethod = sse_decode_payment_method(deserializer);
+    return GetMaxReceivableRequest(paymentMethod: var_paymentMethod);
+  }
+
+  @protected
+  GetMaxReceivableResponse sse_decode_get_max_receivable_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payerAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    return GetMaxReceivableResponse(payerAmountSat: var_payerAmountSat, feesSat: var_feesSat);
+  }
+
   @protected
   GetPaymentRequest sse_decode_get_payment_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4116,6 +7830,43 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  GetReportRequest sse_decode_get_report_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fromTimestamp = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_toTimestamp = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return GetReportRequest(fromTimestamp: var_fromTimestamp, toTimestamp: var_toTimestamp);
+  }
+
+  @protected
+  GetReportResponse sse_decode_get_report_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assets = sse_decode_list_asset_report(deserializer);
+    return GetReportResponse(assets: var_assets);
+  }
+
+  @protected
+  GetWalletInfoResponse sse_decode_get_wallet_info_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_descriptor = sse_decode_String(deserializer);
+    var var_xpub = sse_decode_String(deserializer);
+    var var_derivationPath = sse_decode_String(deserializer);
+    var var_fingerprint = sse_decode_String(deserializer);
+    return GetWalletInfoResponse(
+      descriptor: var_descriptor,
+      xpub: var_xpub,
+      derivationPath: var_derivationPath,
+      fingerprint: var_fingerprint,
+    );
+  }
+
+  @protected
+  HealthCheckService sse_decode_health_check_service(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return HealthCheckService.values[inner];
+  }
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4128,6 +7879,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  ImportWalletRequest sse_decode_import_wallet_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_exportPath = sse_decode_String(deserializer);
+    return ImportWalletRequest(exportPath: var_exportPath);
+  }
+
+  @protected
+  ImportWalletResponse sse_decode_import_wallet_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_importedRecords = sse_decode_u_32(deserializer);
+    return ImportWalletResponse(importedRecords: var_importedRecords);
+  }
+
   @protected
   InputType sse_decode_input_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4171,6 +7936,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  JournaledEvent sse_decode_journaled_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_seq = sse_decode_u_64(deserializer);
+    var var_timestamp = sse_decode_u_32(deserializer);
+    var var_event = sse_decode_sdk_event(deserializer);
+    return JournaledEvent(seq: var_seq, timestamp: var_timestamp, event: var_event);
+  }
+
+  @protected
+  LightningAddressInfo sse_decode_lightning_address_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_username = sse_decode_String(deserializer);
+    var var_lightningAddress = sse_decode_String(deserializer);
+    var var_lnurl = sse_decode_String(deserializer);
+    return LightningAddressInfo(
+      username: var_username,
+      lightningAddress: var_lightningAddress,
+      lnurl: var_lnurl,
+    );
+  }
+
   @protected
   LightningPaymentLimitsResponse sse_decode_lightning_payment_limits_response(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4228,6 +8021,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<AssetAmount> sse_decode_list_asset_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AssetAmount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_asset_amount(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<AssetBalance> sse_decode_list_asset_balance(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4252,6 +8057,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<AssetReport> sse_decode_list_asset_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AssetReport>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_asset_report(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BatchSendItem> sse_decode_list_batch_send_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BatchSendItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_batch_send_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BatchSendItemResult> sse_decode_list_batch_send_item_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BatchSendItemResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_batch_send_item_result(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<ExternalInputParser> sse_decode_list_external_input_parser(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4276,6 +8117,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<JournaledEvent> sse_decode_list_journaled_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <JournaledEvent>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_journaled_event(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<LnOfferBlindedPath> sse_decode_list_ln_offer_blinded_path(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4312,6 +8165,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<NwcConnection> sse_decode_list_nwc_connection(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NwcConnection>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_nwc_connection(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Payment> sse_decode_list_payment(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4354,6 +8219,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<PaymentSwapType> sse_decode_list_payment_swap_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentSwapType>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_swap_type(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<PaymentType> sse_decode_list_payment_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4389,6 +8266,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  List<PrepareReceiveResponse> sse_decode_list_prepare_receive_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PrepareReceiveResponse>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_prepare_receive_response(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4396,6 +8285,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8List(len_);
   }
 
+  @protected
+  List<PsetSpendLimit> sse_decode_list_pset_spend_limit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PsetSpendLimit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pset_spend_limit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<QueuedPayment> sse_decode_list_queued_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <QueuedPayment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_queued_payment(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<Rate> sse_decode_list_rate(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4408,6 +8321,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<RecurringPayment> sse_decode_list_recurring_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RecurringPayment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_recurring_payment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RecurringPaymentOccurrence> sse_decode_list_recurring_payment_occurrence(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RecurringPaymentOccurrence>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_recurring_payment_occurrence(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<RefundableSwap> sse_decode_list_refundable_swap(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4421,27 +8360,131 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  List<RouteHint> sse_decode_list_route_hint(SseDeserializer deserializer) {
+  List<RouteHint> sse_decode_list_route_hint(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RouteHint>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_route_hint(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RouteHintHop> sse_decode_list_route_hint_hop(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RouteHintHop>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_route_hint_hop(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ScheduledPayment> sse_decode_list_scheduled_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ScheduledPayment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_scheduled_payment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SdkEventKind> sse_decode_list_sdk_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SdkEventKind>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sdk_event_kind(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ServiceHealth> sse_decode_list_service_health(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ServiceHealth>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_service_health(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SwapInfo> sse_decode_list_swap_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SwapInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_swap_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  ListSwapsRequest sse_decode_list_swaps_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapTypes = sse_decode_opt_list_payment_swap_type(deserializer);
+    var var_states = sse_decode_opt_list_payment_state(deserializer);
+    var var_fromTimestamp = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_toTimestamp = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_offset = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return ListSwapsRequest(
+      swapTypes: var_swapTypes,
+      states: var_states,
+      fromTimestamp: var_fromTimestamp,
+      toTimestamp: var_toTimestamp,
+      offset: var_offset,
+      limit: var_limit,
+    );
+  }
+
+  @protected
+  List<UnblindedTxOut> sse_decode_list_unblinded_tx_out(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RouteHint>[];
+    var ans_ = <UnblindedTxOut>[];
     for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_route_hint(deserializer));
+      ans_.add(sse_decode_unblinded_tx_out(deserializer));
     }
     return ans_;
   }
 
   @protected
-  List<RouteHintHop> sse_decode_list_route_hint_hop(SseDeserializer deserializer) {
+  LNBolt12Invoice sse_decode_ln_bolt_12_invoice(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RouteHintHop>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_route_hint_hop(deserializer));
-    }
-    return ans_;
+    var var_invoice = sse_decode_String(deserializer);
+    var var_signingPubkey = sse_decode_String(deserializer);
+    var var_paymentHash = sse_decode_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_payerNote = sse_decode_opt_String(deserializer);
+    var var_amountMsat = sse_decode_u_64(deserializer);
+    var var_createdAt = sse_decode_u_64(deserializer);
+    var var_expiry = sse_decode_u_64(deserializer);
+    var var_paymentPathCount = sse_decode_u_32(deserializer);
+    return LNBolt12Invoice(
+      invoice: var_invoice,
+      signingPubkey: var_signingPubkey,
+      paymentHash: var_paymentHash,
+      description: var_description,
+      payerNote: var_payerNote,
+      amountMsat: var_amountMsat,
+      createdAt: var_createdAt,
+      expiry: var_expiry,
+      paymentPathCount: var_paymentPathCount,
+    );
   }
 
   @protected
@@ -4568,6 +8611,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_lnurlPaySuccessAction = sse_decode_opt_box_autoadd_success_action_processed(deserializer);
     var var_lnurlPayUnprocessedSuccessAction = sse_decode_opt_box_autoadd_success_action(deserializer);
     var var_lnurlWithdrawEndpoint = sse_decode_opt_String(deserializer);
+    var var_lnurlPayVerifyUrl = sse_decode_opt_String(deserializer);
+    var var_lnurlPayVerifiedPreimage = sse_decode_opt_String(deserializer);
     return LnUrlInfo(
       lnAddress: var_lnAddress,
       lnurlPayComment: var_lnurlPayComment,
@@ -4576,6 +8621,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       lnurlPaySuccessAction: var_lnurlPaySuccessAction,
       lnurlPayUnprocessedSuccessAction: var_lnurlPayUnprocessedSuccessAction,
       lnurlWithdrawEndpoint: var_lnurlWithdrawEndpoint,
+      lnurlPayVerifyUrl: var_lnurlPayVerifyUrl,
+      lnurlPayVerifiedPreimage: var_lnurlPayVerifiedPreimage,
     );
   }
 
@@ -4693,6 +8740,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return LnUrlPaySuccessData(payment: var_payment, successAction: var_successAction);
   }
 
+  @protected
+  LnUrlPayerData sse_decode_ln_url_payer_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_pubkey = sse_decode_opt_String(deserializer);
+    var var_identifier = sse_decode_opt_String(deserializer);
+    var var_email = sse_decode_opt_String(deserializer);
+    return LnUrlPayerData(name: var_name, pubkey: var_pubkey, identifier: var_identifier, email: var_email);
+  }
+
   @protected
   LnUrlWithdrawError sse_decode_ln_url_withdraw_error(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4797,7 +8854,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_line = sse_decode_String(deserializer);
     var var_level = sse_decode_String(deserializer);
-    return LogEntry(line: var_line, level: var_level);
+    var var_target = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_u_64(deserializer);
+    return LogEntry(line: var_line, level: var_level, target: var_target, timestamp: var_timestamp);
   }
 
   @protected
@@ -4814,6 +8873,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Network.values[inner];
   }
 
+  @protected
+  NwcBudget sse_decode_nwc_budget(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxAmountSat = sse_decode_u_64(deserializer);
+    var var_renewalPeriodSec = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return NwcBudget(maxAmountSat: var_maxAmountSat, renewalPeriodSec: var_renewalPeriodSec);
+  }
+
+  @protected
+  NwcConnection sse_decode_nwc_connection(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_clientPubkey = sse_decode_String(deserializer);
+    var var_budget = sse_decode_opt_box_autoadd_nwc_budget(deserializer);
+    var var_spentSat = sse_decode_u_64(deserializer);
+    var var_createdAt = sse_decode_u_32(deserializer);
+    return NwcConnection(
+      name: var_name,
+      clientPubkey: var_clientPubkey,
+      budget: var_budget,
+      spentSat: var_spentSat,
+      createdAt: var_createdAt,
+    );
+  }
+
   @protected
   OnchainPaymentLimitsResponse sse_decode_onchain_payment_limits_response(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4823,154 +8907,396 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Amount? sse_decode_opt_box_autoadd_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_amount(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AmpAccount? sse_decode_opt_box_autoadd_amp_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_amp_account(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AssetAmount? sse_decode_opt_box_autoadd_asset_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_asset_amount(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AssetConversion? sse_decode_opt_box_autoadd_asset_conversion(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_asset_conversion(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AssetInfo? sse_decode_opt_box_autoadd_asset_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_asset_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BackupPolicy? sse_decode_opt_box_autoadd_backup_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_backup_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BuyBitcoinProvider? sse_decode_opt_box_autoadd_buy_bitcoin_provider(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_buy_bitcoin_provider(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Direction? sse_decode_opt_box_autoadd_direction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_direction(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EventFilter? sse_decode_opt_box_autoadd_event_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_event_filter(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EventWebhookConfig? sse_decode_opt_box_autoadd_event_webhook_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_event_webhook_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FeeBreakdown? sse_decode_opt_box_autoadd_fee_breakdown(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fee_breakdown(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FeeSpeed? sse_decode_opt_box_autoadd_fee_speed(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fee_speed(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FiatAmount? sse_decode_opt_box_autoadd_fiat_amount(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fiat_amount(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FiatConversion? sse_decode_opt_box_autoadd_fiat_conversion(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fiat_conversion(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  InputType? sse_decode_opt_box_autoadd_input_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_input_type(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LightningAddressInfo? sse_decode_opt_box_autoadd_lightning_address_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_lightning_address_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ListPaymentDetails? sse_decode_opt_box_autoadd_list_payment_details(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_list_payment_details(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LnUrlInfo? sse_decode_opt_box_autoadd_ln_url_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_ln_url_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LnUrlPayerData? sse_decode_opt_box_autoadd_ln_url_payer_data(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
+      return (sse_decode_box_autoadd_ln_url_payer_data(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  Amount? sse_decode_opt_box_autoadd_amount(SseDeserializer deserializer) {
+  NwcBudget? sse_decode_opt_box_autoadd_nwc_budget(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_amount(deserializer));
+      return (sse_decode_box_autoadd_nwc_budget(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  AssetInfo? sse_decode_opt_box_autoadd_asset_info(SseDeserializer deserializer) {
+  PayAmount? sse_decode_opt_box_autoadd_pay_amount(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_asset_info(deserializer));
+      return (sse_decode_box_autoadd_pay_amount(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+  Payment? sse_decode_opt_box_autoadd_payment(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
+      return (sse_decode_box_autoadd_payment(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+  ReceiveAmount? sse_decode_opt_box_autoadd_receive_amount(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
+      return (sse_decode_box_autoadd_receive_amount(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+  RegisteredAsset? sse_decode_opt_box_autoadd_registered_asset(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
+      return (sse_decode_box_autoadd_registered_asset(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  ListPaymentDetails? sse_decode_opt_box_autoadd_list_payment_details(SseDeserializer deserializer) {
+  SimulationConfig? sse_decode_opt_box_autoadd_simulation_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_list_payment_details(deserializer));
+      return (sse_decode_box_autoadd_simulation_config(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  LnUrlInfo? sse_decode_opt_box_autoadd_ln_url_info(SseDeserializer deserializer) {
+  SpendLimit? sse_decode_opt_box_autoadd_spend_limit(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_ln_url_info(deserializer));
+      return (sse_decode_box_autoadd_spend_limit(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  PayAmount? sse_decode_opt_box_autoadd_pay_amount(SseDeserializer deserializer) {
+  SpendLimits? sse_decode_opt_box_autoadd_spend_limits(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_pay_amount(deserializer));
+      return (sse_decode_box_autoadd_spend_limits(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  Payment? sse_decode_opt_box_autoadd_payment(SseDeserializer deserializer) {
+  SuccessAction? sse_decode_opt_box_autoadd_success_action(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_payment(deserializer));
+      return (sse_decode_box_autoadd_success_action(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  ReceiveAmount? sse_decode_opt_box_autoadd_receive_amount(SseDeserializer deserializer) {
+  SuccessActionProcessed? sse_decode_opt_box_autoadd_success_action_processed(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_receive_amount(deserializer));
+      return (sse_decode_box_autoadd_success_action_processed(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  SuccessAction? sse_decode_opt_box_autoadd_success_action(SseDeserializer deserializer) {
+  SwapServiceStatus? sse_decode_opt_box_autoadd_swap_service_status(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_success_action(deserializer));
+      return (sse_decode_box_autoadd_swap_service_status(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  SuccessActionProcessed? sse_decode_opt_box_autoadd_success_action_processed(SseDeserializer deserializer) {
+  Symbol? sse_decode_opt_box_autoadd_symbol(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_success_action_processed(deserializer));
+      return (sse_decode_box_autoadd_symbol(deserializer));
     } else {
       return null;
     }
   }
 
   @protected
-  Symbol? sse_decode_opt_box_autoadd_symbol(SseDeserializer deserializer) {
+  SyncRequest? sse_decode_opt_box_autoadd_sync_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_symbol(deserializer));
+      return (sse_decode_box_autoadd_sync_request(deserializer));
     } else {
       return null;
     }
@@ -4998,6 +9324,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   List<AssetMetadata>? sse_decode_opt_list_asset_metadata(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5031,6 +9368,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  List<PaymentSwapType>? sse_decode_opt_list_payment_swap_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_payment_swap_type(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   List<PaymentType>? sse_decode_opt_list_payment_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5053,6 +9401,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  List<SdkEventKind>? sse_decode_opt_list_sdk_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_sdk_event_kind(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   PayAmount sse_decode_pay_amount(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5072,6 +9431,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           estimateAssetFees: var_estimateAssetFees,
         );
       case 2:
+        var var_receiverAmount = sse_decode_box_autoadd_asset_amount(deserializer);
+        var var_estimateAssetFees = sse_decode_opt_box_autoadd_bool(deserializer);
+        return PayAmount_AssetUnits(
+          receiverAmount: var_receiverAmount,
+          estimateAssetFees: var_estimateAssetFees,
+        );
+      case 3:
         return PayAmount_Drain();
       default:
         throw UnimplementedError('');
@@ -5083,7 +9449,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_address = sse_decode_String(deserializer);
     var var_prepareResponse = sse_decode_prepare_pay_onchain_response(deserializer);
-    return PayOnchainRequest(address: var_address, prepareResponse: var_prepareResponse);
+    var var_spendLimitOverride = sse_decode_opt_String(deserializer);
+    return PayOnchainRequest(
+      address: var_address,
+      prepareResponse: var_prepareResponse,
+      spendLimitOverride: var_spendLimitOverride,
+    );
   }
 
   @protected
@@ -5099,6 +9470,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_paymentType = sse_decode_payment_type(deserializer);
     var var_status = sse_decode_payment_state(deserializer);
     var var_details = sse_decode_payment_details(deserializer);
+    var var_fiatAmount = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_fiatCurrency = sse_decode_opt_String(deserializer);
+    var var_recurringPaymentId = sse_decode_opt_String(deserializer);
     return Payment(
       destination: var_destination,
       txId: var_txId,
@@ -5110,6 +9484,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       paymentType: var_paymentType,
       status: var_status,
       details: var_details,
+      fiatAmount: var_fiatAmount,
+      fiatCurrency: var_fiatCurrency,
+      recurringPaymentId: var_recurringPaymentId,
     );
   }
 
@@ -5155,6 +9532,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         var var_assetInfo = sse_decode_opt_box_autoadd_asset_info(deserializer);
         var var_lnurlInfo = sse_decode_opt_box_autoadd_ln_url_info(deserializer);
         var var_bip353Address = sse_decode_opt_String(deserializer);
+        var var_conversion = sse_decode_opt_box_autoadd_asset_conversion(deserializer);
         return PaymentDetails_Liquid(
           destination: var_destination,
           description: var_description,
@@ -5162,6 +9540,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           assetInfo: var_assetInfo,
           lnurlInfo: var_lnurlInfo,
           bip353Address: var_bip353Address,
+          conversion: var_conversion,
         );
       case 2:
         var var_swapId = sse_decode_String(deserializer);
@@ -5172,6 +9551,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         var var_claimTxId = sse_decode_opt_String(deserializer);
         var var_refundTxId = sse_decode_opt_String(deserializer);
         var var_refundTxAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+        var var_buyBitcoinProvider = sse_decode_opt_box_autoadd_buy_bitcoin_provider(deserializer);
         return PaymentDetails_Bitcoin(
           swapId: var_swapId,
           description: var_description,
@@ -5181,6 +9561,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           claimTxId: var_claimTxId,
           refundTxId: var_refundTxId,
           refundTxAmountSat: var_refundTxAmountSat,
+          buyBitcoinProvider: var_buyBitcoinProvider,
         );
       default:
         throw UnimplementedError('');
@@ -5216,36 +9597,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 8:
         return PaymentError_InvalidOrExpiredFees();
       case 9:
-        return PaymentError_InsufficientFunds();
+        var var_feesSat = sse_decode_u_64(deserializer);
+        var var_maxFeeSat = sse_decode_u_64(deserializer);
+        return PaymentError_FeeLimitExceeded(feesSat: var_feesSat, maxFeeSat: var_maxFeeSat);
       case 10:
+        return PaymentError_InsufficientFunds();
+      case 11:
+        var var_amountSat = sse_decode_u_64(deserializer);
+        var var_remainingSat = sse_decode_u_64(deserializer);
+        return PaymentError_SpendLimitExceeded(amountSat: var_amountSat, remainingSat: var_remainingSat);
+      case 12:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_InvalidDescription(err: var_err);
-      case 11:
+      case 13:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_InvalidInvoice(err: var_err);
-      case 12:
+      case 14:
         return PaymentError_InvalidPreimage();
-      case 13:
+      case 15:
         return PaymentError_PairsNotFound();
-      case 14:
+      case 16:
         return PaymentError_PaymentTimeout();
-      case 15:
+      case 17:
         return PaymentError_PersistError();
-      case 16:
+      case 18:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_ReceiveError(err: var_err);
-      case 17:
+      case 19:
         var var_err = sse_decode_String(deserializer);
         var var_refundTxId = sse_decode_String(deserializer);
         return PaymentError_Refunded(err: var_err, refundTxId: var_refundTxId);
-      case 18:
+      case 20:
         return PaymentError_SelfTransferNotSupported();
-      case 19:
+      case 21:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_SendError(err: var_err);
-      case 20:
+      case 22:
         var var_err = sse_decode_String(deserializer);
         return PaymentError_SignerError(err: var_err);
+      case 23:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_ServiceUnavailable(err: var_err);
       default:
         throw UnimplementedError('');
     }
@@ -5265,6 +9657,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return PaymentState.values[inner];
   }
 
+  @protected
+  PaymentSwapType sse_decode_payment_swap_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PaymentSwapType.values[inner];
+  }
+
   @protected
   PaymentType sse_decode_payment_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5272,6 +9671,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return PaymentType.values[inner];
   }
 
+  @protected
+  PaymentUnblindingData sse_decode_payment_unblinding_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txId = sse_decode_String(deserializer);
+    var var_unblindingData = sse_decode_String(deserializer);
+    var var_unblindedUrl = sse_decode_opt_String(deserializer);
+    var var_inputs = sse_decode_list_unblinded_tx_out(deserializer);
+    var var_outputs = sse_decode_list_unblinded_tx_out(deserializer);
+    return PaymentUnblindingData(
+      txId: var_txId,
+      unblindingData: var_unblindingData,
+      unblindedUrl: var_unblindedUrl,
+      inputs: var_inputs,
+      outputs: var_outputs,
+    );
+  }
+
   @protected
   PrepareBuyBitcoinRequest sse_decode_prepare_buy_bitcoin_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5289,6 +9705,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return PrepareBuyBitcoinResponse(provider: var_provider, amountSat: var_amountSat, feesSat: var_feesSat);
   }
 
+  @protected
+  PrepareConvertRequest sse_decode_prepare_convert_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fromAssetId = sse_decode_String(deserializer);
+    var var_toAssetId = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    return PrepareConvertRequest(fromAssetId: var_fromAssetId, toAssetId: var_toAssetId, amount: var_amount);
+  }
+
+  @protected
+  PrepareConvertResponse sse_decode_prepare_convert_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fromAssetId = sse_decode_String(deserializer);
+    var var_toAssetId = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_receiverAmount = sse_decode_u_64(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    return PrepareConvertResponse(
+      fromAssetId: var_fromAssetId,
+      toAssetId: var_toAssetId,
+      amount: var_amount,
+      receiverAmount: var_receiverAmount,
+      feesSat: var_feesSat,
+    );
+  }
+
   @protected
   PrepareLnUrlPayRequest sse_decode_prepare_ln_url_pay_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5296,12 +9738,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_amount = sse_decode_pay_amount(deserializer);
     var var_bip353Address = sse_decode_opt_String(deserializer);
     var var_comment = sse_decode_opt_String(deserializer);
+    var var_payerData = sse_decode_opt_box_autoadd_ln_url_payer_data(deserializer);
     var var_validateSuccessActionUrl = sse_decode_opt_box_autoadd_bool(deserializer);
     return PrepareLnUrlPayRequest(
       data: var_data,
       amount: var_amount,
       bip353Address: var_bip353Address,
       comment: var_comment,
+      payerData: var_payerData,
       validateSuccessActionUrl: var_validateSuccessActionUrl,
     );
   }
@@ -5311,15 +9755,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_destination = sse_decode_send_destination(deserializer);
     var var_feesSat = sse_decode_u_64(deserializer);
+    var var_feeBreakdown = sse_decode_fee_breakdown(deserializer);
     var var_data = sse_decode_ln_url_pay_request_data(deserializer);
     var var_comment = sse_decode_opt_String(deserializer);
     var var_successAction = sse_decode_opt_box_autoadd_success_action(deserializer);
+    var var_verifyUrl = sse_decode_opt_String(deserializer);
     return PrepareLnUrlPayResponse(
       destination: var_destination,
       feesSat: var_feesSat,
+      feeBreakdown: var_feeBreakdown,
       data: var_data,
       comment: var_comment,
       successAction: var_successAction,
+      verifyUrl: var_verifyUrl,
     );
   }
 
@@ -5328,7 +9776,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_amount = sse_decode_pay_amount(deserializer);
     var var_feeRateSatPerVbyte = sse_decode_opt_box_autoadd_u_32(deserializer);
-    return PreparePayOnchainRequest(amount: var_amount, feeRateSatPerVbyte: var_feeRateSatPerVbyte);
+    var var_feeSpeed = sse_decode_opt_box_autoadd_fee_speed(deserializer);
+    return PreparePayOnchainRequest(
+      amount: var_amount,
+      feeRateSatPerVbyte: var_feeRateSatPerVbyte,
+      feeSpeed: var_feeSpeed,
+    );
   }
 
   @protected
@@ -5344,12 +9797,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  PrepareReceiveQuotesRequest sse_decode_prepare_receive_quotes_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amount = sse_decode_opt_box_autoadd_receive_amount(deserializer);
+    var var_fiatAmount = sse_decode_opt_box_autoadd_fiat_amount(deserializer);
+    return PrepareReceiveQuotesRequest(amount: var_amount, fiatAmount: var_fiatAmount);
+  }
+
+  @protected
+  PrepareReceiveQuotesResponse sse_decode_prepare_receive_quotes_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_quotes = sse_decode_list_prepare_receive_response(deserializer);
+    return PrepareReceiveQuotesResponse(quotes: var_quotes);
+  }
+
   @protected
   PrepareReceiveRequest sse_decode_prepare_receive_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_paymentMethod = sse_decode_payment_method(deserializer);
     var var_amount = sse_decode_opt_box_autoadd_receive_amount(deserializer);
-    return PrepareReceiveRequest(paymentMethod: var_paymentMethod, amount: var_amount);
+    var var_fiatAmount = sse_decode_opt_box_autoadd_fiat_amount(deserializer);
+    var var_expirySecs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return PrepareReceiveRequest(
+      paymentMethod: var_paymentMethod,
+      amount: var_amount,
+      fiatAmount: var_fiatAmount,
+      expirySecs: var_expirySecs,
+    );
   }
 
   @protected
@@ -5358,16 +9833,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_paymentMethod = sse_decode_payment_method(deserializer);
     var var_amount = sse_decode_opt_box_autoadd_receive_amount(deserializer);
     var var_feesSat = sse_decode_u_64(deserializer);
+    var var_feeBreakdown = sse_decode_fee_breakdown(deserializer);
     var var_minPayerAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_maxPayerAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
     var var_swapperFeerate = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_fiatConversion = sse_decode_opt_box_autoadd_fiat_conversion(deserializer);
+    var var_expirySecs = sse_decode_opt_box_autoadd_u_32(deserializer);
     return PrepareReceiveResponse(
       paymentMethod: var_paymentMethod,
       amount: var_amount,
       feesSat: var_feesSat,
+      feeBreakdown: var_feeBreakdown,
       minPayerAmountSat: var_minPayerAmountSat,
       maxPayerAmountSat: var_maxPayerAmountSat,
       swapperFeerate: var_swapperFeerate,
+      fiatConversion: var_fiatConversion,
+      expirySecs: var_expirySecs,
     );
   }
 
@@ -5376,11 +9857,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_swapAddress = sse_decode_String(deserializer);
     var var_refundAddress = sse_decode_String(deserializer);
-    var var_feeRateSatPerVbyte = sse_decode_u_32(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_feeSpeed = sse_decode_opt_box_autoadd_fee_speed(deserializer);
     return PrepareRefundRequest(
       swapAddress: var_swapAddress,
       refundAddress: var_refundAddress,
       feeRateSatPerVbyte: var_feeRateSatPerVbyte,
+      feeSpeed: var_feeSpeed,
     );
   }
 
@@ -5389,10 +9872,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_txVsize = sse_decode_u_32(deserializer);
     var var_txFeeSat = sse_decode_u_64(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_u_32(deserializer);
+    var var_refundableAmountSat = sse_decode_u_64(deserializer);
+    var var_refundAmountSat = sse_decode_u_64(deserializer);
     var var_lastRefundTxId = sse_decode_opt_String(deserializer);
     return PrepareRefundResponse(
       txVsize: var_txVsize,
       txFeeSat: var_txFeeSat,
+      feeRateSatPerVbyte: var_feeRateSatPerVbyte,
+      refundableAmountSat: var_refundableAmountSat,
+      refundAmountSat: var_refundAmountSat,
       lastRefundTxId: var_lastRefundTxId,
     );
   }
@@ -5402,7 +9891,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_destination = sse_decode_String(deserializer);
     var var_amount = sse_decode_opt_box_autoadd_pay_amount(deserializer);
-    return PrepareSendRequest(destination: var_destination, amount: var_amount);
+    var var_fiatAmount = sse_decode_opt_box_autoadd_fiat_amount(deserializer);
+    return PrepareSendRequest(destination: var_destination, amount: var_amount, fiatAmount: var_fiatAmount);
   }
 
   @protected
@@ -5410,14 +9900,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_destination = sse_decode_send_destination(deserializer);
     var var_feesSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_feeBreakdown = sse_decode_opt_box_autoadd_fee_breakdown(deserializer);
     var var_estimatedAssetFees = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_estimatedAssetFeesAmount = sse_decode_opt_box_autoadd_asset_amount(deserializer);
+    var var_fiatConversion = sse_decode_opt_box_autoadd_fiat_conversion(deserializer);
     return PrepareSendResponse(
       destination: var_destination,
       feesSat: var_feesSat,
+      feeBreakdown: var_feeBreakdown,
       estimatedAssetFees: var_estimatedAssetFees,
+      estimatedAssetFeesAmount: var_estimatedAssetFeesAmount,
+      fiatConversion: var_fiatConversion,
+    );
+  }
+
+  @protected
+  ProcessNotificationRequest sse_decode_process_notification_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connectRequest = sse_decode_connect_request(deserializer);
+    var var_payload = sse_decode_String(deserializer);
+    var var_acceptProposedFees = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_timeoutSec = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return ProcessNotificationRequest(
+      connectRequest: var_connectRequest,
+      payload: var_payload,
+      acceptProposedFees: var_acceptProposedFees,
+      timeoutSec: var_timeoutSec,
+    );
+  }
+
+  @protected
+  ProcessNotificationResponse sse_decode_process_notification_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payment = sse_decode_payment(deserializer);
+    var var_isResolved = sse_decode_bool(deserializer);
+    return ProcessNotificationResponse(payment: var_payment, isResolved: var_isResolved);
+  }
+
+  @protected
+  PsetSpendLimit sse_decode_pset_spend_limit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assetId = sse_decode_String(deserializer);
+    var var_maxAmountSat = sse_decode_u_64(deserializer);
+    return PsetSpendLimit(assetId: var_assetId, maxAmountSat: var_maxAmountSat);
+  }
+
+  @protected
+  QueuePaymentRequest sse_decode_queue_payment_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_prepareRequest = sse_decode_prepare_send_request(deserializer);
+    var var_useAssetFees = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_maxFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return QueuePaymentRequest(
+      prepareRequest: var_prepareRequest,
+      useAssetFees: var_useAssetFees,
+      maxFeeSat: var_maxFeeSat,
+    );
+  }
+
+  @protected
+  QueuedPayment sse_decode_queued_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_request = sse_decode_queue_payment_request(deserializer);
+    var var_state = sse_decode_queued_payment_state(deserializer);
+    var var_attempts = sse_decode_u_32(deserializer);
+    var var_createdAt = sse_decode_u_32(deserializer);
+    var var_nextAttemptAt = sse_decode_u_32(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    return QueuedPayment(
+      id: var_id,
+      request: var_request,
+      state: var_state,
+      attempts: var_attempts,
+      createdAt: var_createdAt,
+      nextAttemptAt: var_nextAttemptAt,
+      lastError: var_lastError,
     );
   }
 
+  @protected
+  QueuedPaymentState sse_decode_queued_payment_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QueuedPaymentState.values[inner];
+  }
+
   @protected
   Rate sse_decode_rate(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5439,6 +10007,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         var var_assetId = sse_decode_String(deserializer);
         var var_payerAmount = sse_decode_opt_box_autoadd_f_64(deserializer);
         return ReceiveAmount_Asset(assetId: var_assetId, payerAmount: var_payerAmount);
+      case 2:
+        var var_payerAmount = sse_decode_box_autoadd_asset_amount(deserializer);
+        return ReceiveAmount_AssetUnits(payerAmount: var_payerAmount);
       default:
         throw UnimplementedError('');
     }
@@ -5450,10 +10021,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_prepareResponse = sse_decode_prepare_receive_response(deserializer);
     var var_description = sse_decode_opt_String(deserializer);
     var var_useDescriptionHash = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_descriptionHash = sse_decode_opt_String(deserializer);
+    var var_claimAddress = sse_decode_opt_String(deserializer);
     return ReceivePaymentRequest(
       prepareResponse: var_prepareResponse,
       description: var_description,
       useDescriptionHash: var_useDescriptionHash,
+      descriptionHash: var_descriptionHash,
+      claimAddress: var_claimAddress,
     );
   }
 
@@ -5481,16 +10056,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RecurringPayment sse_decode_recurring_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_destination = sse_decode_String(deserializer);
+    var var_amountSat = sse_decode_u_64(deserializer);
+    var var_intervalSec = sse_decode_u_32(deserializer);
+    var var_maxFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_createdAt = sse_decode_u_32(deserializer);
+    var var_nextOccurrenceAt = sse_decode_u_32(deserializer);
+    return RecurringPayment(
+      id: var_id,
+      destination: var_destination,
+      amountSat: var_amountSat,
+      intervalSec: var_intervalSec,
+      maxFeeSat: var_maxFeeSat,
+      createdAt: var_createdAt,
+      nextOccurrenceAt: var_nextOccurrenceAt,
+    );
+  }
+
+  @protected
+  RecurringPaymentOccurrence sse_decode_recurring_payment_occurrence(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_recurringPaymentId = sse_decode_String(deserializer);
+    var var_occurrenceAt = sse_decode_u_32(deserializer);
+    var var_executedAt = sse_decode_u_32(deserializer);
+    var var_txId = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return RecurringPaymentOccurrence(
+      recurringPaymentId: var_recurringPaymentId,
+      occurrenceAt: var_occurrenceAt,
+      executedAt: var_executedAt,
+      txId: var_txId,
+      error: var_error,
+    );
+  }
+
   @protected
   RefundRequest sse_decode_refund_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_swapAddress = sse_decode_String(deserializer);
     var var_refundAddress = sse_decode_String(deserializer);
-    var var_feeRateSatPerVbyte = sse_decode_u_32(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_feeSpeed = sse_decode_opt_box_autoadd_fee_speed(deserializer);
     return RefundRequest(
       swapAddress: var_swapAddress,
       refundAddress: var_refundAddress,
       feeRateSatPerVbyte: var_feeRateSatPerVbyte,
+      feeSpeed: var_feeSpeed,
     );
   }
 
@@ -5508,14 +10123,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_timestamp = sse_decode_u_32(deserializer);
     var var_amountSat = sse_decode_u_64(deserializer);
     var var_lastRefundTxId = sse_decode_opt_String(deserializer);
+    var var_state = sse_decode_payment_state(deserializer);
+    var var_refundableSince = sse_decode_u_32(deserializer);
+    var var_refundEligibleBlockHeight = sse_decode_u_32(deserializer);
+    var var_isCooperativeRefundPossible = sse_decode_bool(deserializer);
     return RefundableSwap(
       swapAddress: var_swapAddress,
       timestamp: var_timestamp,
       amountSat: var_amountSat,
       lastRefundTxId: var_lastRefundTxId,
+      state: var_state,
+      refundableSince: var_refundableSince,
+      refundEligibleBlockHeight: var_refundEligibleBlockHeight,
+      isCooperativeRefundPossible: var_isCooperativeRefundPossible,
+    );
+  }
+
+  @protected
+  RegisterLightningAddressRequest sse_decode_register_lightning_address_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_username = sse_decode_String(deserializer);
+    return RegisterLightningAddressRequest(username: var_username);
+  }
+
+  @protected
+  RegisteredAsset sse_decode_registered_asset(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assetId = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_ticker = sse_decode_opt_String(deserializer);
+    var var_precision = sse_decode_u_8(deserializer);
+    var var_domain = sse_decode_opt_String(deserializer);
+    var var_iconUrl = sse_decode_String(deserializer);
+    return RegisteredAsset(
+      assetId: var_assetId,
+      name: var_name,
+      ticker: var_ticker,
+      precision: var_precision,
+      domain: var_domain,
+      iconUrl: var_iconUrl,
     );
   }
 
+  @protected
+  ReplayEventsRequest sse_decode_replay_events_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sinceSeq = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return ReplayEventsRequest(sinceSeq: var_sinceSeq, limit: var_limit);
+  }
+
   @protected
   RestoreRequest sse_decode_restore_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5523,6 +10182,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RestoreRequest(backupPath: var_backupPath);
   }
 
+  @protected
+  ReusableBitcoinAddressResponse sse_decode_reusable_bitcoin_address_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_minDepositSat = sse_decode_u_64(deserializer);
+    var var_maxDepositSat = sse_decode_u_64(deserializer);
+    return ReusableBitcoinAddressResponse(
+      address: var_address,
+      minDepositSat: var_minDepositSat,
+      maxDepositSat: var_maxDepositSat,
+    );
+  }
+
   @protected
   RouteHint sse_decode_route_hint(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5551,6 +10223,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  SchedulePaymentRequest sse_decode_schedule_payment_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_prepareRequest = sse_decode_prepare_send_request(deserializer);
+    var var_useAssetFees = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_maxFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_executeAt = sse_decode_u_32(deserializer);
+    return SchedulePaymentRequest(
+      prepareRequest: var_prepareRequest,
+      useAssetFees: var_useAssetFees,
+      maxFeeSat: var_maxFeeSat,
+      executeAt: var_executeAt,
+    );
+  }
+
+  @protected
+  ScheduledPayment sse_decode_scheduled_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_request = sse_decode_schedule_payment_request(deserializer);
+    var var_state = sse_decode_scheduled_payment_state(deserializer);
+    var var_attempts = sse_decode_u_32(deserializer);
+    var var_createdAt = sse_decode_u_32(deserializer);
+    var var_nextAttemptAt = sse_decode_u_32(deserializer);
+    var var_txId = sse_decode_opt_String(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    return ScheduledPayment(
+      id: var_id,
+      request: var_request,
+      state: var_state,
+      attempts: var_attempts,
+      createdAt: var_createdAt,
+      nextAttemptAt: var_nextAttemptAt,
+      txId: var_txId,
+      lastError: var_lastError,
+    );
+  }
+
+  @protected
+  ScheduledPaymentState sse_decode_scheduled_payment_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ScheduledPaymentState.values[inner];
+  }
+
   @protected
   SdkError sse_decode_sdk_error(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5560,11 +10277,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 0:
         return SdkError_AlreadyStarted();
       case 1:
+        var var_dir = sse_decode_String(deserializer);
+        return SdkError_DataDirLocked(dir: var_dir);
+      case 2:
         var var_err = sse_decode_String(deserializer);
         return SdkError_Generic(err: var_err);
-      case 2:
-        return SdkError_NotStarted();
       case 3:
+        var var_err = sse_decode_String(deserializer);
+        return SdkError_InvalidBackup(err: var_err);
+      case 4:
+        return SdkError_NotStarted();
+      case 5:
         var var_err = sse_decode_String(deserializer);
         return SdkError_ServiceConnectivity(err: var_err);
       default:
@@ -5603,15 +10326,105 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         var var_details = sse_decode_box_autoadd_payment(deserializer);
         return SdkEvent_PaymentWaitingFeeAcceptance(details: var_details);
       case 8:
-        return SdkEvent_Synced();
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return SdkEvent_PaymentExpired(details: var_details);
       case 9:
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return SdkEvent_PaymentReorged(details: var_details);
+      case 10:
+        var var_swapId = sse_decode_String(deserializer);
+        var var_swapType = sse_decode_payment_swap_type(deserializer);
+        var var_state = sse_decode_payment_state(deserializer);
+        return SdkEvent_SwapUpdated(swapId: var_swapId, swapType: var_swapType, state: var_state);
+      case 11:
+        var var_swapId = sse_decode_String(deserializer);
+        var var_txId = sse_decode_String(deserializer);
+        return SdkEvent_SwapClaimBroadcast(swapId: var_swapId, txId: var_txId);
+      case 12:
+        var var_swapId = sse_decode_String(deserializer);
+        var var_txId = sse_decode_String(deserializer);
+        return SdkEvent_SwapRefundBroadcast(swapId: var_swapId, txId: var_txId);
+      case 13:
+        var var_liquidTip = sse_decode_u_32(deserializer);
+        var var_bitcoinTip = sse_decode_u_32(deserializer);
+        return SdkEvent_TipsUpdated(liquidTip: var_liquidTip, bitcoinTip: var_bitcoinTip);
+      case 14:
+        return SdkEvent_SyncStarted();
+      case 15:
+        return SdkEvent_Synced();
+      case 16:
         var var_didPullNewRecords = sse_decode_bool(deserializer);
         return SdkEvent_DataSynced(didPullNewRecords: var_didPullNewRecords);
+      case 17:
+        var var_isOnline = sse_decode_bool(deserializer);
+        return SdkEvent_ConnectivityChanged(isOnline: var_isOnline);
+      case 18:
+        return SdkEvent_BackupSucceeded();
+      case 19:
+        var var_err = sse_decode_String(deserializer);
+        return SdkEvent_BackupFailed(err: var_err);
+      case 20:
+        var var_results = sse_decode_list_batch_send_item_result(deserializer);
+        return SdkEvent_BatchSendCompleted(results: var_results);
+      case 21:
+        var var_id = sse_decode_String(deserializer);
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return SdkEvent_ScheduledPaymentExecuted(id: var_id, details: var_details);
+      case 22:
+        var var_id = sse_decode_String(deserializer);
+        var var_err = sse_decode_String(deserializer);
+        return SdkEvent_ScheduledPaymentFailed(id: var_id, err: var_err);
+      case 23:
+        var var_recurringPaymentId = sse_decode_String(deserializer);
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return SdkEvent_RecurringPaymentExecuted(
+          recurringPaymentId: var_recurringPaymentId,
+          details: var_details,
+        );
+      case 24:
+        var var_recurringPaymentId = sse_decode_String(deserializer);
+        var var_err = sse_decode_String(deserializer);
+        return SdkEvent_RecurringPaymentFailed(recurringPaymentId: var_recurringPaymentId, err: var_err);
+      case 25:
+        var var_txId = sse_decode_String(deserializer);
+        return SdkEvent_TransactionDropped(txId: var_txId);
+      case 26:
+        var var_txId = sse_decode_String(deserializer);
+        var var_unconfirmedSec = sse_decode_u_32(deserializer);
+        return SdkEvent_TransactionUnconfirmed(txId: var_txId, unconfirmedSec: var_unconfirmedSec);
+      case 27:
+        var var_block = sse_decode_box_autoadd_block_info(deserializer);
+        return SdkEvent_NewLiquidBlock(block: var_block);
+      case 28:
+        var var_block = sse_decode_box_autoadd_block_info(deserializer);
+        return SdkEvent_NewBitcoinBlock(block: var_block);
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  SdkEventKind sse_decode_sdk_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SdkEventKind.values[inner];
+  }
+
+  @protected
+  SendBatchRequest sse_decode_send_batch_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_items = sse_decode_list_batch_send_item(deserializer);
+    var var_maxTotalFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return SendBatchRequest(items: var_items, maxTotalFeeSat: var_maxTotalFeeSat);
+  }
+
+  @protected
+  SendBatchResponse sse_decode_send_batch_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_results = sse_decode_list_batch_send_item_result(deserializer);
+    return SendBatchResponse(results: var_results);
+  }
+
   @protected
   SendDestination sse_decode_send_destination(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5621,7 +10434,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 0:
         var var_addressData = sse_decode_box_autoadd_liquid_address_data(deserializer);
         var var_bip353Address = sse_decode_opt_String(deserializer);
-        return SendDestination_LiquidAddress(addressData: var_addressData, bip353Address: var_bip353Address);
+        var var_payjoinUrl = sse_decode_opt_String(deserializer);
+        return SendDestination_LiquidAddress(
+          addressData: var_addressData,
+          bip353Address: var_bip353Address,
+          payjoinUrl: var_payjoinUrl,
+        );
       case 1:
         var var_invoice = sse_decode_box_autoadd_ln_invoice(deserializer);
         var var_bip353Address = sse_decode_opt_String(deserializer);
@@ -5645,7 +10463,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_prepareResponse = sse_decode_prepare_send_response(deserializer);
     var var_useAssetFees = sse_decode_opt_box_autoadd_bool(deserializer);
-    return SendPaymentRequest(prepareResponse: var_prepareResponse, useAssetFees: var_useAssetFees);
+    var var_maxFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_spendLimitOverride = sse_decode_opt_String(deserializer);
+    var var_idempotencyKey = sse_decode_opt_String(deserializer);
+    return SendPaymentRequest(
+      prepareResponse: var_prepareResponse,
+      useAssetFees: var_useAssetFees,
+      maxFeeSat: var_maxFeeSat,
+      spendLimitOverride: var_spendLimitOverride,
+      idempotencyKey: var_idempotencyKey,
+    );
   }
 
   @protected
@@ -5655,6 +10482,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return SendPaymentResponse(payment: var_payment);
   }
 
+  @protected
+  ServiceHealth sse_decode_service_health(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_service = sse_decode_health_check_service(deserializer);
+    var var_isReachable = sse_decode_bool(deserializer);
+    var var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ServiceHealth(
+      service: var_service,
+      isReachable: var_isReachable,
+      latencyMs: var_latencyMs,
+      error: var_error,
+    );
+  }
+
   @protected
   SignMessageRequest sse_decode_sign_message_request(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5666,7 +10508,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   SignMessageResponse sse_decode_sign_message_response(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_signature = sse_decode_String(deserializer);
-    return SignMessageResponse(signature: var_signature);
+    var var_pubkey = sse_decode_String(deserializer);
+    return SignMessageResponse(signature: var_signature, pubkey: var_pubkey);
+  }
+
+  @protected
+  SignPsetRequest sse_decode_sign_pset_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pset = sse_decode_String(deserializer);
+    var var_maxFeeSat = sse_decode_u_64(deserializer);
+    var var_spendLimits = sse_decode_list_pset_spend_limit(deserializer);
+    return SignPsetRequest(pset: var_pset, maxFeeSat: var_maxFeeSat, spendLimits: var_spendLimits);
+  }
+
+  @protected
+  SignPsetResponse sse_decode_sign_pset_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pset = sse_decode_String(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    return SignPsetResponse(pset: var_pset, feesSat: var_feesSat);
+  }
+
+  @protected
+  SimulationConfig sse_decode_simulation_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_statusUpdateDelaySec = sse_decode_u_32(deserializer);
+    var var_failureRate = sse_decode_f_64(deserializer);
+    return SimulationConfig(statusUpdateDelaySec: var_statusUpdateDelaySec, failureRate: var_failureRate);
+  }
+
+  @protected
+  SpendLimit sse_decode_spend_limit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_amountSat = sse_decode_u_64(deserializer);
+        return SpendLimit_Bitcoin(amountSat: var_amountSat);
+      case 1:
+        var var_amount = sse_decode_box_autoadd_fiat_amount(deserializer);
+        return SpendLimit_Fiat(amount: var_amount);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  SpendLimits sse_decode_spend_limits(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_perPayment = sse_decode_opt_box_autoadd_spend_limit(deserializer);
+    var var_perDay = sse_decode_opt_box_autoadd_spend_limit(deserializer);
+    var var_overrideSecret = sse_decode_opt_String(deserializer);
+    return SpendLimits(perPayment: var_perPayment, perDay: var_perDay, overrideSecret: var_overrideSecret);
   }
 
   @protected
@@ -5709,6 +10603,82 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  SwapInfo sse_decode_swap_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapId = sse_decode_String(deserializer);
+    var var_swapType = sse_decode_payment_swap_type(deserializer);
+    var var_direction = sse_decode_opt_box_autoadd_direction(deserializer);
+    var var_state = sse_decode_payment_state(deserializer);
+    var var_createdAt = sse_decode_u_32(deserializer);
+    var var_timeoutBlockHeight = sse_decode_u_64(deserializer);
+    var var_payerAmountSat = sse_decode_u_64(deserializer);
+    var var_receiverAmountSat = sse_decode_u_64(deserializer);
+    var var_invoice = sse_decode_opt_String(deserializer);
+    var var_lockupAddress = sse_decode_opt_String(deserializer);
+    var var_userLockupTxId = sse_decode_opt_String(deserializer);
+    var var_serverLockupTxId = sse_decode_opt_String(deserializer);
+    var var_claimAddress = sse_decode_opt_String(deserializer);
+    var var_claimTxId = sse_decode_opt_String(deserializer);
+    var var_refundAddress = sse_decode_opt_String(deserializer);
+    var var_refundTxId = sse_decode_opt_String(deserializer);
+    var var_createResponseJson = sse_decode_String(deserializer);
+    return SwapInfo(
+      swapId: var_swapId,
+      swapType: var_swapType,
+      direction: var_direction,
+      state: var_state,
+      createdAt: var_createdAt,
+      timeoutBlockHeight: var_timeoutBlockHeight,
+      payerAmountSat: var_payerAmountSat,
+      receiverAmountSat: var_receiverAmountSat,
+      invoice: var_invoice,
+      lockupAddress: var_lockupAddress,
+      userLockupTxId: var_userLockupTxId,
+      serverLockupTxId: var_serverLockupTxId,
+      claimAddress: var_claimAddress,
+      claimTxId: var_claimTxId,
+      refundAddress: var_refundAddress,
+      refundTxId: var_refundTxId,
+      createResponseJson: var_createResponseJson,
+    );
+  }
+
+  @protected
+  SwapServiceStatus sse_decode_swap_service_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sendAvailable = sse_decode_bool(deserializer);
+    var var_receiveAvailable = sse_decode_bool(deserializer);
+    var var_chainSendAvailable = sse_decode_bool(deserializer);
+    var var_chainReceiveAvailable = sse_decode_bool(deserializer);
+    return SwapServiceStatus(
+      sendAvailable: var_sendAvailable,
+      receiveAvailable: var_receiveAvailable,
+      chainSendAvailable: var_chainSendAvailable,
+      chainReceiveAvailable: var_chainReceiveAvailable,
+    );
+  }
+
+  @protected
+  SweepPrivateKeyRequest sse_decode_sweep_private_key_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    return SweepPrivateKeyRequest(key: var_key);
+  }
+
+  @protected
+  SweepPrivateKeyResponse sse_decode_sweep_private_key_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payment = sse_decode_payment(deserializer);
+    var var_amountSat = sse_decode_u_64(deserializer);
+    var var_assetAmounts = sse_decode_list_asset_amount(deserializer);
+    return SweepPrivateKeyResponse(
+      payment: var_payment,
+      amountSat: var_amountSat,
+      assetAmounts: var_assetAmounts,
+    );
+  }
+
   @protected
   Symbol sse_decode_symbol(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5719,6 +10689,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Symbol(grapheme: var_grapheme, template: var_template, rtl: var_rtl, position: var_position);
   }
 
+  @protected
+  SyncRequest sse_decode_sync_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_full = sse_decode_opt_box_autoadd_bool(deserializer);
+    return SyncRequest(full: var_full);
+  }
+
+  @protected
+  SyncResponse sse_decode_sync_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_newTxCount = sse_decode_u_32(deserializer);
+    var var_updatedSwapCount = sse_decode_u_32(deserializer);
+    var var_durationMs = sse_decode_u_64(deserializer);
+    return SyncResponse(
+      newTxCount: var_newTxCount,
+      updatedSwapCount: var_updatedSwapCount,
+      durationMs: var_durationMs,
+    );
+  }
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5743,6 +10733,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getUint8();
   }
 
+  @protected
+  UnblindedTxOut sse_decode_unblinded_tx_out(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_outpoint = sse_decode_String(deserializer);
+    var var_assetId = sse_decode_String(deserializer);
+    var var_amount = sse_decode_u_64(deserializer);
+    var var_assetBlindingFactor = sse_decode_String(deserializer);
+    var var_amountBlindingFactor = sse_decode_String(deserializer);
+    return UnblindedTxOut(
+      outpoint: var_outpoint,
+      assetId: var_assetId,
+      amount: var_amount,
+      assetBlindingFactor: var_assetBlindingFactor,
+      amountBlindingFactor: var_amountBlindingFactor,
+    );
+  }
+
   @protected
   void sse_decode_unit(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5767,6 +10774,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getBigUint64();
   }
 
+  @protected
+  ValidateAddressResponse sse_decode_validate_address_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_addressType = sse_decode_address_type(deserializer);
+    var var_network = sse_decode_liquid_network(deserializer);
+    var var_isConfidential = sse_decode_bool(deserializer);
+    var var_supportsAssets = sse_decode_bool(deserializer);
+    return ValidateAddressResponse(
+      addressType: var_addressType,
+      network: var_network,
+      isConfidential: var_isConfidential,
+      supportsAssets: var_supportsAssets,
+    );
+  }
+
   @protected
   WalletInfo sse_decode_wallet_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5804,6 +10826,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as BindingLiquidSdkImpl).frbInternalCstEncode(move: false);
   }
 
+  @protected
+  PlatformPointer cst_encode_DartFn_Inputs_String_Output_String_AnyhowException(
+    FutureOr<String> Function(String) raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_DartOpaque(encode_DartFn_Inputs_String_Output_String_AnyhowException(raw));
+  }
+
+  @protected
+  PlatformPointer cst_encode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(
+    FutureOr<InputType?> Function(String) raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_DartOpaque(
+      encode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(raw),
+    );
+  }
+
+  @protected
+  PlatformPointer cst_encode_DartOpaque(Object raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return encodeDartOpaque(raw, portManager.dartHandlerPort, generalizedFrbRustBinding);
+  }
+
   @protected
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     BindingLiquidSdk raw,
@@ -5813,6 +10859,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as BindingLiquidSdkImpl).frbInternalCstEncode();
   }
 
+  @protected
+  int cst_encode_address_type(AddressType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   bool cst_encode_bool(bool raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -5825,12 +10877,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return cst_encode_i_32(raw.index);
   }
 
+  @protected
+  int cst_encode_direction(Direction raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   double cst_encode_f_64(double raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return raw;
   }
 
+  @protected
+  int cst_encode_fee_speed(FeeSpeed raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_health_check_service(HealthCheckService raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   int cst_encode_i_32(int raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -5861,12 +10931,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return cst_encode_i_32(raw.index);
   }
 
+  @protected
+  int cst_encode_payment_swap_type(PaymentSwapType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   int cst_encode_payment_type(PaymentType raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return cst_encode_i_32(raw.index);
   }
 
+  @protected
+  int cst_encode_queued_payment_state(QueuedPaymentState raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_scheduled_payment_state(ScheduledPaymentState raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
+  @protected
+  int cst_encode_sdk_event_kind(SdkEventKind raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
+  }
+
   @protected
   int cst_encode_u_16(int raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -5915,6 +11009,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize((self as BindingLiquidSdkImpl).frbInternalSseEncode(move: false), serializer);
   }
 
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(
+    FutureOr<String> Function(String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_String_AnyhowException(self), serializer);
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(
+    FutureOr<InputType?> Function(String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(self),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_isize(
+      PlatformPointerUtil.ptrToPlatformInt64(
+        encodeDartOpaque(self, portManager.dartHandlerPort, generalizedFrbRustBinding),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     BindingLiquidSdk self,
@@ -5961,6 +11087,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_fetch_payment_proposed_fees_response(self.response, serializer);
   }
 
+  @protected
+  void sse_encode_add_nwc_connection_request(AddNwcConnectionRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_nwc_budget(self.budget, serializer);
+  }
+
+  @protected
+  void sse_encode_add_nwc_connection_response(AddNwcConnectionResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.connectionUri, serializer);
+  }
+
+  @protected
+  void sse_encode_address_type(AddressType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_aes_success_action_data(AesSuccessActionData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6006,6 +11151,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_amp_account(AmpAccount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.ampId, serializer);
+    sse_encode_String(self.descriptor, serializer);
+  }
+
+  @protected
+  void sse_encode_asset_amount(AssetAmount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.assetId, serializer);
+    sse_encode_u_64(self.baseUnits, serializer);
+    sse_encode_u_8(self.precision, serializer);
+    sse_encode_u_8(self.displayPrecision, serializer);
+  }
+
   @protected
   void sse_encode_asset_balance(AssetBalance self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6014,6 +11175,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.name, serializer);
     sse_encode_opt_String(self.ticker, serializer);
     sse_encode_opt_box_autoadd_f_64(self.balance, serializer);
+    sse_encode_u_64(self.pendingSendSat, serializer);
+    sse_encode_u_64(self.pendingReceiveSat, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.pendingSend, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.pendingReceive, serializer);
+    sse_encode_opt_box_autoadd_asset_amount(self.assetBalance, serializer);
+  }
+
+  @protected
+  void sse_encode_asset_conversion(AssetConversion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fromAssetId, serializer);
+    sse_encode_u_64(self.fromAmount, serializer);
+    sse_encode_String(self.toAssetId, serializer);
+    sse_encode_u_64(self.toAmount, serializer);
   }
 
   @protected
@@ -6023,6 +11198,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.ticker, serializer);
     sse_encode_f_64(self.amount, serializer);
     sse_encode_opt_box_autoadd_f_64(self.fees, serializer);
+    sse_encode_asset_amount(self.assetAmount, serializer);
+    sse_encode_opt_box_autoadd_asset_amount(self.assetFees, serializer);
   }
 
   @protected
@@ -6033,18 +11210,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.ticker, serializer);
     sse_encode_u_8(self.precision, serializer);
     sse_encode_opt_String(self.fiatId, serializer);
+    sse_encode_bool(self.isAmp, serializer);
+  }
+
+  @protected
+  void sse_encode_asset_report(AssetReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.assetId, serializer);
+    sse_encode_u_64(self.receivedAmount, serializer);
+    sse_encode_u_64(self.sentAmount, serializer);
+    sse_encode_u_64(self.swapFeesSat, serializer);
+    sse_encode_u_64(self.onchainFeesSat, serializer);
+    sse_encode_u_32(self.receivedCount, serializer);
+    sse_encode_u_32(self.sentCount, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_policy(BackupPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case BackupPolicy_CompletedSwaps(swaps: final swaps):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_32(swaps, serializer);
+      case BackupPolicy_Interval(hours: final hours):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(hours, serializer);
+    }
   }
 
   @protected
   void sse_encode_backup_request(BackupRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.backupPath, serializer);
+    sse_encode_opt_String(self.backupPath, serializer);
+  }
+
+  @protected
+  void sse_encode_batch_send_item(BatchSendItem self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.destination, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountSat, serializer);
   }
 
   @protected
-  void sse_encode_binding_event_listener(BindingEventListener self, SseSerializer serializer) {
+  void sse_encode_batch_send_item_result(BatchSendItemResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_StreamSink_sdk_event_Dco(self.stream, serializer);
+    sse_encode_String(self.destination, serializer);
+    sse_encode_opt_box_autoadd_payment(self.payment, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
@@ -6057,6 +11269,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.message, serializer);
   }
 
+  @protected
+  void sse_encode_block_info(BlockInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_String(self.hash, serializer);
+    sse_encode_u_32(self.timestamp, serializer);
+  }
+
   @protected
   void sse_encode_blockchain_explorer(BlockchainExplorer self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6093,6 +11313,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_accept_payment_proposed_fees_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_add_nwc_connection_request(
+    AddNwcConnectionRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_add_nwc_connection_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_aes_success_action_data(AesSuccessActionData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6123,6 +11352,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_amount(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_amp_account(AmpAccount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_amp_account(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_asset_amount(AssetAmount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_asset_amount(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_asset_conversion(AssetConversion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_asset_conversion(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_asset_info(AssetInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6130,15 +11377,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer) {
+  void sse_encode_box_autoadd_backup_policy(BackupPolicy self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_backup_request(self, serializer);
+    sse_encode_backup_policy(self, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_binding_event_listener(BindingEventListener self, SseSerializer serializer) {
+  void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_binding_event_listener(self, serializer);
+    sse_encode_backup_request(self, serializer);
   }
 
   @protected
@@ -6147,12 +11394,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_bitcoin_address_data(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_block_info(BlockInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_block_info(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_buy_bitcoin_provider(BuyBitcoinProvider self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_buy_bitcoin_provider(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_buy_bitcoin_request(BuyBitcoinRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6171,12 +11430,72 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_connect_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_convert_request(ConvertRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_convert_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_create_recurring_payment_request(
+    CreateRecurringPaymentRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_create_recurring_payment_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_direction(Direction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_direction(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_event_filter(EventFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_event_filter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_event_webhook_config(EventWebhookConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_event_webhook_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_export_payment_unblinding_data_request(
+    ExportPaymentUnblindingDataRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_export_payment_unblinding_data_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_export_wallet_request(ExportWalletRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_export_wallet_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_f_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_fee_breakdown(FeeBreakdown self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fee_breakdown(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_fee_speed(FeeSpeed self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fee_speed(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_fetch_payment_proposed_fees_request(
     FetchPaymentProposedFeesRequest self,
@@ -6186,18 +11505,69 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_fetch_payment_proposed_fees_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_fiat_amount(FiatAmount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fiat_amount(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_fiat_conversion(FiatConversion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fiat_conversion(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_get_max_payable_request(GetMaxPayableRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_get_max_payable_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_get_max_receivable_request(
+    GetMaxReceivableRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_get_max_receivable_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_get_payment_request(GetPaymentRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_get_payment_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_get_report_request(GetReportRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_get_report_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_64(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_import_wallet_request(ImportWalletRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_import_wallet_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_input_type(InputType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_input_type(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_lightning_address_info(LightningAddressInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_lightning_address_info(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_liquid_address_data(LiquidAddressData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6216,6 +11586,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_payments_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_list_swaps_request(ListSwapsRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_swaps_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_ln_bolt_12_invoice(LNBolt12Invoice self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ln_bolt_12_invoice(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_ln_invoice(LNInvoice self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6270,6 +11652,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_ln_url_pay_success_data(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_ln_url_payer_data(LnUrlPayerData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ln_url_payer_data(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_ln_url_withdraw_request(LnUrlWithdrawRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6303,6 +11691,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_message_success_action_data(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_nwc_budget(NwcBudget self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_nwc_budget(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_pay_amount(PayAmount self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6330,6 +11724,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_prepare_buy_bitcoin_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_prepare_convert_request(PrepareConvertRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_convert_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_prepare_ln_url_pay_request(
     PrepareLnUrlPayRequest self,
@@ -6348,6 +11748,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_prepare_pay_onchain_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_prepare_receive_quotes_request(
+    PrepareReceiveQuotesRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_receive_quotes_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6366,6 +11775,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_prepare_send_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_process_notification_request(
+    ProcessNotificationRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_process_notification_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_queue_payment_request(QueuePaymentRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_queue_payment_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_receive_amount(ReceiveAmount self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6384,6 +11808,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_refund_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_register_lightning_address_request(
+    RegisterLightningAddressRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_register_lightning_address_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_registered_asset(RegisteredAsset self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_registered_asset(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_replay_events_request(ReplayEventsRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_replay_events_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_restore_request(RestoreRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6391,9 +11836,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_box_autoadd_sdk_event(SdkEvent self, SseSerializer serializer) {
+  void sse_encode_box_autoadd_schedule_payment_request(
+    SchedulePaymentRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_schedule_payment_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_send_batch_request(SendBatchRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_sdk_event(self, serializer);
+    sse_encode_send_batch_request(self, serializer);
   }
 
   @protected
@@ -6408,6 +11862,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_sign_message_request(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_sign_pset_request(SignPsetRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sign_pset_request(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_simulation_config(SimulationConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_simulation_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_spend_limit(SpendLimit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_spend_limit(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_spend_limits(SpendLimits self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_spend_limits(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_success_action(SuccessAction self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6423,12 +11901,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_success_action_processed(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_swap_service_status(SwapServiceStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_swap_service_status(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sweep_private_key_request(
+    SweepPrivateKeyRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sweep_private_key_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_symbol(Symbol self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_symbol(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_sync_request(SyncRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_request(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6460,6 +11959,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.redirectUrl, serializer);
   }
 
+  @protected
+  void sse_encode_check_health_response(CheckHealthResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.isDegraded, serializer);
+    sse_encode_list_service_health(self.services, serializer);
+    sse_encode_opt_box_autoadd_swap_service_status(self.swapServiceStatus, serializer);
+  }
+
   @protected
   void sse_encode_check_message_request(CheckMessageRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6491,27 +11998,117 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_32(self.onchainFeeRateLeewaySatPerVbyte, serializer);
     sse_encode_opt_list_asset_metadata(self.assetMetadata, serializer);
     sse_encode_opt_String(self.sideswapApiKey, serializer);
+    sse_encode_opt_box_autoadd_event_webhook_config(self.eventWebhook, serializer);
+    sse_encode_opt_String(self.fiatCurrency, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.syncIntervalSec, serializer);
+    sse_encode_opt_String(self.bitcoinExplorerAuthorization, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.swapperMaxAttempts, serializer);
+    sse_encode_opt_box_autoadd_backup_policy(self.backupPolicy, serializer);
+    sse_encode_opt_String(self.nwcRelayUrl, serializer);
+    sse_encode_opt_box_autoadd_spend_limits(self.spendLimits, serializer);
+    sse_encode_opt_String(self.lnurlServiceUrl, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.outgoingMinConfirmations, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.unconfirmedTxAlertSec, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.chainServiceTimeoutSec, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.swapperTimeoutSec, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.swapperPingIntervalSec, serializer);
+    sse_encode_opt_String(self.ampServerUrl, serializer);
+    sse_encode_opt_box_autoadd_simulation_config(self.simulation, serializer);
+    sse_encode_bool(self.manualClaim, serializer);
+    sse_encode_opt_String(self.claimAddress, serializer);
+    sse_encode_opt_String(self.dnsOverHttpsUrl, serializer);
+  }
+
+  @protected
+  void sse_encode_connect_request(ConnectRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_config(self.config, serializer);
+    sse_encode_opt_String(self.mnemonic, serializer);
+    sse_encode_opt_String(self.passphrase, serializer);
+    sse_encode_opt_list_prim_u_8_strict(self.seed, serializer);
+    sse_encode_opt_String(self.xprv, serializer);
+  }
+
+  @protected
+  void sse_encode_convert_request(ConvertRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_convert_response(self.prepareResponse, serializer);
+    sse_encode_opt_String(self.spendLimitOverride, serializer);
+  }
+
+  @protected
+  void sse_encode_create_recurring_payment_request(
+    CreateRecurringPaymentRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.destination, serializer);
+    sse_encode_u_64(self.amountSat, serializer);
+    sse_encode_u_32(self.intervalSec, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.startAt, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFeeSat, serializer);
+  }
+
+  @protected
+  void sse_encode_currency_info(CurrencyInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.fractionSize, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.spacing, serializer);
+    sse_encode_opt_box_autoadd_symbol(self.symbol, serializer);
+    sse_encode_opt_box_autoadd_symbol(self.uniqSymbol, serializer);
+    sse_encode_list_localized_name(self.localizedName, serializer);
+    sse_encode_list_locale_overrides(self.localeOverrides, serializer);
+  }
+
+  @protected
+  void sse_encode_decoded_invoice(DecodedInvoice self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DecodedInvoice_Bolt11(invoice: final invoice):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_ln_invoice(invoice, serializer);
+      case DecodedInvoice_Bolt12(invoice: final invoice):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_ln_bolt_12_invoice(invoice, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_direction(Direction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_event_filter(EventFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_list_sdk_event_kind(self.kinds, serializer);
+    sse_encode_opt_list_String(self.paymentIds, serializer);
+    sse_encode_opt_list_payment_swap_type(self.swapTypes, serializer);
+  }
+
+  @protected
+  void sse_encode_event_webhook_config(EventWebhookConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.secret, serializer);
   }
 
   @protected
-  void sse_encode_connect_request(ConnectRequest self, SseSerializer serializer) {
+  void sse_encode_export_payment_unblinding_data_request(
+    ExportPaymentUnblindingDataRequest self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_config(self.config, serializer);
-    sse_encode_opt_String(self.mnemonic, serializer);
-    sse_encode_opt_String(self.passphrase, serializer);
-    sse_encode_opt_list_prim_u_8_strict(self.seed, serializer);
+    sse_encode_String(self.paymentId, serializer);
+    sse_encode_opt_String(self.explorerUrl, serializer);
   }
 
   @protected
-  void sse_encode_currency_info(CurrencyInfo self, SseSerializer serializer) {
+  void sse_encode_export_wallet_request(ExportWalletRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_u_32(self.fractionSize, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.spacing, serializer);
-    sse_encode_opt_box_autoadd_symbol(self.symbol, serializer);
-    sse_encode_opt_box_autoadd_symbol(self.uniqSymbol, serializer);
-    sse_encode_list_localized_name(self.localizedName, serializer);
-    sse_encode_list_locale_overrides(self.localeOverrides, serializer);
+    sse_encode_String(self.exportPath, serializer);
   }
 
   @protected
@@ -6528,6 +12125,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_fee_breakdown(FeeBreakdown self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.serviceFeeSat, serializer);
+    sse_encode_u_64(self.swapperNetworkFeeSat, serializer);
+    sse_encode_u_64(self.lockupFeeSat, serializer);
+    sse_encode_bool(self.lockupFeeIsEstimate, serializer);
+    sse_encode_u_64(self.claimFeeSat, serializer);
+    sse_encode_bool(self.claimFeeIsEstimate, serializer);
+    sse_encode_opt_box_autoadd_asset_amount(self.assetFee, serializer);
+  }
+
+  @protected
+  void sse_encode_fee_speed(FeeSpeed self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_fetch_payment_proposed_fees_request(
     FetchPaymentProposedFeesRequest self,
@@ -6549,6 +12164,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.receiverAmountSat, serializer);
   }
 
+  @protected
+  void sse_encode_fiat_amount(FiatAmount self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.amount, serializer);
+    sse_encode_String(self.currency, serializer);
+  }
+
+  @protected
+  void sse_encode_fiat_conversion(FiatConversion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fiat_amount(self.fiatAmount, serializer);
+    sse_encode_f_64(self.rate, serializer);
+    sse_encode_u_64(self.amountSat, serializer);
+  }
+
   @protected
   void sse_encode_fiat_currency(FiatCurrency self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6563,6 +12193,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_blockchain_info(self.blockchainInfo, serializer);
   }
 
+  @protected
+  void sse_encode_get_max_payable_request(GetMaxPayableRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_payment_method(self.paymentMethod, serializer);
+  }
+
+  @protected
+  void sse_encode_get_max_payable_response(GetMaxPayableResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.receiverAmountSat, serializer);
+    sse_encode_u_64(self.feesSat, serializer);
+  }
+
+  @protected
+  void sse_encode_get_max_receivable_request(GetMaxReceivableRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_payment_method(self.paymentMethod, serializer);
+  }
+
+  @protected
+  void sse_encode_get_max_receivable_response(GetMaxReceivableResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.payerAmountSat, serializer);
+    sse_encode_u_64(self.feesSat, serializer);
+  }
+
   @protected
   void sse_encode_get_payment_request(GetPaymentRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6576,6 +12232,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_get_report_request(GetReportRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_i_64(self.fromTimestamp, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.toTimestamp, serializer);
+  }
+
+  @protected
+  void sse_encode_get_report_response(GetReportResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_asset_report(self.assets, serializer);
+  }
+
+  @protected
+  void sse_encode_get_wallet_info_response(GetWalletInfoResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.descriptor, serializer);
+    sse_encode_String(self.xpub, serializer);
+    sse_encode_String(self.derivationPath, serializer);
+    sse_encode_String(self.fingerprint, serializer);
+  }
+
+  @protected
+  void sse_encode_health_check_service(HealthCheckService self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6588,6 +12272,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_import_wallet_request(ImportWalletRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.exportPath, serializer);
+  }
+
+  @protected
+  void sse_encode_import_wallet_response(ImportWalletResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.importedRecords, serializer);
+  }
+
   @protected
   void sse_encode_input_type(InputType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6627,6 +12323,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_journaled_event(JournaledEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.seq, serializer);
+    sse_encode_u_32(self.timestamp, serializer);
+    sse_encode_sdk_event(self.event, serializer);
+  }
+
+  @protected
+  void sse_encode_lightning_address_info(LightningAddressInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.username, serializer);
+    sse_encode_String(self.lightningAddress, serializer);
+    sse_encode_String(self.lnurl, serializer);
+  }
+
   @protected
   void sse_encode_lightning_payment_limits_response(
     LightningPaymentLimitsResponse self,
@@ -6672,6 +12390,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_asset_amount(List<AssetAmount> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_asset_amount(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_asset_balance(List<AssetBalance> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6690,6 +12417,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_asset_report(List<AssetReport> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_asset_report(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_batch_send_item(List<BatchSendItem> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_batch_send_item(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_batch_send_item_result(List<BatchSendItemResult> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_batch_send_item_result(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_external_input_parser(List<ExternalInputParser> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6708,6 +12462,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_journaled_event(List<JournaledEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_journaled_event(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_ln_offer_blinded_path(List<LnOfferBlindedPath> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -6735,101 +12498,237 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_nwc_connection(List<NwcConnection> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_nwc_connection(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_payment(List<Payment> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_payment(item, serializer);
+      sse_encode_payment(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_payment_details(ListPaymentDetails self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ListPaymentDetails_Liquid(assetId: final assetId, destination: final destination):
+        sse_encode_i_32(0, serializer);
+        sse_encode_opt_String(assetId, serializer);
+        sse_encode_opt_String(destination, serializer);
+      case ListPaymentDetails_Bitcoin(address: final address):
+        sse_encode_i_32(1, serializer);
+        sse_encode_opt_String(address, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_payment_state(List<PaymentState> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment_state(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_payment_swap_type(List<PaymentSwapType> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment_swap_type(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_payment_type(List<PaymentType> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment_type(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_payments_request(ListPaymentsRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_list_payment_type(self.filters, serializer);
+    sse_encode_opt_list_payment_state(self.states, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.fromTimestamp, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.toTimestamp, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.offset, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
+    sse_encode_opt_box_autoadd_list_payment_details(self.details, serializer);
+    sse_encode_opt_box_autoadd_bool(self.sortAscending, serializer);
+  }
+
+  @protected
+  void sse_encode_list_prepare_receive_response(List<PrepareReceiveResponse> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_prepare_receive_response(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
+
+  @protected
+  void sse_encode_list_pset_spend_limit(List<PsetSpendLimit> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pset_spend_limit(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_queued_payment(List<QueuedPayment> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_queued_payment(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_rate(List<Rate> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_rate(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_recurring_payment(List<RecurringPayment> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_recurring_payment(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_recurring_payment_occurrence(
+    List<RecurringPaymentOccurrence> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_recurring_payment_occurrence(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_refundable_swap(List<RefundableSwap> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_refundable_swap(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_payment_details(ListPaymentDetails self, SseSerializer serializer) {
+  void sse_encode_list_route_hint(List<RouteHint> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case ListPaymentDetails_Liquid(assetId: final assetId, destination: final destination):
-        sse_encode_i_32(0, serializer);
-        sse_encode_opt_String(assetId, serializer);
-        sse_encode_opt_String(destination, serializer);
-      case ListPaymentDetails_Bitcoin(address: final address):
-        sse_encode_i_32(1, serializer);
-        sse_encode_opt_String(address, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_route_hint(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_payment_state(List<PaymentState> self, SseSerializer serializer) {
+  void sse_encode_list_route_hint_hop(List<RouteHintHop> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_payment_state(item, serializer);
+      sse_encode_route_hint_hop(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_payment_type(List<PaymentType> self, SseSerializer serializer) {
+  void sse_encode_list_scheduled_payment(List<ScheduledPayment> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_payment_type(item, serializer);
+      sse_encode_scheduled_payment(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_payments_request(ListPaymentsRequest self, SseSerializer serializer) {
+  void sse_encode_list_sdk_event_kind(List<SdkEventKind> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_list_payment_type(self.filters, serializer);
-    sse_encode_opt_list_payment_state(self.states, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.fromTimestamp, serializer);
-    sse_encode_opt_box_autoadd_i_64(self.toTimestamp, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.offset, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
-    sse_encode_opt_box_autoadd_list_payment_details(self.details, serializer);
-    sse_encode_opt_box_autoadd_bool(self.sortAscending, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sdk_event_kind(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer) {
+  void sse_encode_list_service_health(List<ServiceHealth> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    for (final item in self) {
+      sse_encode_service_health(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_rate(List<Rate> self, SseSerializer serializer) {
+  void sse_encode_list_swap_info(List<SwapInfo> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_rate(item, serializer);
+      sse_encode_swap_info(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_refundable_swap(List<RefundableSwap> self, SseSerializer serializer) {
+  void sse_encode_list_swaps_request(ListSwapsRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_refundable_swap(item, serializer);
-    }
+    sse_encode_opt_list_payment_swap_type(self.swapTypes, serializer);
+    sse_encode_opt_list_payment_state(self.states, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.fromTimestamp, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.toTimestamp, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.offset, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
   }
 
   @protected
-  void sse_encode_list_route_hint(List<RouteHint> self, SseSerializer serializer) {
+  void sse_encode_list_unblinded_tx_out(List<UnblindedTxOut> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.length, serializer);
     for (final item in self) {
-      sse_encode_route_hint(item, serializer);
+      sse_encode_unblinded_tx_out(item, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_route_hint_hop(List<RouteHintHop> self, SseSerializer serializer) {
+  void sse_encode_ln_bolt_12_invoice(LNBolt12Invoice self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_route_hint_hop(item, serializer);
-    }
+    sse_encode_String(self.invoice, serializer);
+    sse_encode_String(self.signingPubkey, serializer);
+    sse_encode_String(self.paymentHash, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.payerNote, serializer);
+    sse_encode_u_64(self.amountMsat, serializer);
+    sse_encode_u_64(self.createdAt, serializer);
+    sse_encode_u_64(self.expiry, serializer);
+    sse_encode_u_32(self.paymentPathCount, serializer);
   }
 
   @protected
@@ -6921,6 +12820,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_success_action_processed(self.lnurlPaySuccessAction, serializer);
     sse_encode_opt_box_autoadd_success_action(self.lnurlPayUnprocessedSuccessAction, serializer);
     sse_encode_opt_String(self.lnurlWithdrawEndpoint, serializer);
+    sse_encode_opt_String(self.lnurlPayVerifyUrl, serializer);
+    sse_encode_opt_String(self.lnurlPayVerifiedPreimage, serializer);
   }
 
   @protected
@@ -7009,228 +12910,459 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_ln_url_pay_success_data(LnUrlPaySuccessData self, SseSerializer serializer) {
+  void sse_encode_ln_url_pay_success_data(LnUrlPaySuccessData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_payment(self.payment, serializer);
+    sse_encode_opt_box_autoadd_success_action_processed(self.successAction, serializer);
+  }
+
+  @protected
+  void sse_encode_ln_url_payer_data(LnUrlPayerData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_String(self.pubkey, serializer);
+    sse_encode_opt_String(self.identifier, serializer);
+    sse_encode_opt_String(self.email, serializer);
+  }
+
+  @protected
+  void sse_encode_ln_url_withdraw_error(LnUrlWithdrawError self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case LnUrlWithdrawError_Generic(err: final err):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(err, serializer);
+      case LnUrlWithdrawError_InvalidAmount(err: final err):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(err, serializer);
+      case LnUrlWithdrawError_InvalidInvoice(err: final err):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(err, serializer);
+      case LnUrlWithdrawError_InvalidUri(err: final err):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(err, serializer);
+      case LnUrlWithdrawError_InvoiceNoRoutingHints(err: final err):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(err, serializer);
+      case LnUrlWithdrawError_ServiceConnectivity(err: final err):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(err, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_ln_url_withdraw_request(LnUrlWithdrawRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ln_url_withdraw_request_data(self.data, serializer);
+    sse_encode_u_64(self.amountMsat, serializer);
+    sse_encode_opt_String(self.description, serializer);
+  }
+
+  @protected
+  void sse_encode_ln_url_withdraw_request_data(LnUrlWithdrawRequestData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.callback, serializer);
+    sse_encode_String(self.k1, serializer);
+    sse_encode_String(self.defaultDescription, serializer);
+    sse_encode_u_64(self.minWithdrawable, serializer);
+    sse_encode_u_64(self.maxWithdrawable, serializer);
+  }
+
+  @protected
+  void sse_encode_ln_url_withdraw_result(LnUrlWithdrawResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case LnUrlWithdrawResult_Ok(data: final data):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_ln_url_withdraw_success_data(data, serializer);
+      case LnUrlWithdrawResult_Timeout(data: final data):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_ln_url_withdraw_success_data(data, serializer);
+      case LnUrlWithdrawResult_ErrorStatus(data: final data):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_ln_url_error_data(data, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_ln_url_withdraw_success_data(LnUrlWithdrawSuccessData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ln_invoice(self.invoice, serializer);
+  }
+
+  @protected
+  void sse_encode_locale_overrides(LocaleOverrides self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.locale, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.spacing, serializer);
+    sse_encode_symbol(self.symbol, serializer);
+  }
+
+  @protected
+  void sse_encode_localized_name(LocalizedName self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.locale, serializer);
+    sse_encode_String(self.name, serializer);
+  }
+
+  @protected
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.line, serializer);
+    sse_encode_String(self.level, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_u_64(self.timestamp, serializer);
+  }
+
+  @protected
+  void sse_encode_message_success_action_data(MessageSuccessActionData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_network(Network self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_nwc_budget(NwcBudget self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.maxAmountSat, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.renewalPeriodSec, serializer);
+  }
+
+  @protected
+  void sse_encode_nwc_connection(NwcConnection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.clientPubkey, serializer);
+    sse_encode_opt_box_autoadd_nwc_budget(self.budget, serializer);
+    sse_encode_u_64(self.spentSat, serializer);
+    sse_encode_u_32(self.createdAt, serializer);
+  }
+
+  @protected
+  void sse_encode_onchain_payment_limits_response(
+    OnchainPaymentLimitsResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_limits(self.send, serializer);
+    sse_encode_limits(self.receive, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_amount(Amount? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_amount(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_amp_account(AmpAccount? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_amp_account(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_asset_amount(AssetAmount? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_asset_amount(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_asset_conversion(AssetConversion? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_asset_conversion(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_asset_info(AssetInfo? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_asset_info(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_backup_policy(BackupPolicy? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_backup_policy(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_buy_bitcoin_provider(BuyBitcoinProvider? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_payment(self.payment, serializer);
-    sse_encode_opt_box_autoadd_success_action_processed(self.successAction, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_buy_bitcoin_provider(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_ln_url_withdraw_error(LnUrlWithdrawError self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_direction(Direction? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case LnUrlWithdrawError_Generic(err: final err):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(err, serializer);
-      case LnUrlWithdrawError_InvalidAmount(err: final err):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(err, serializer);
-      case LnUrlWithdrawError_InvalidInvoice(err: final err):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(err, serializer);
-      case LnUrlWithdrawError_InvalidUri(err: final err):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(err, serializer);
-      case LnUrlWithdrawError_InvoiceNoRoutingHints(err: final err):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(err, serializer);
-      case LnUrlWithdrawError_ServiceConnectivity(err: final err):
-        sse_encode_i_32(5, serializer);
-        sse_encode_String(err, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_direction(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_ln_url_withdraw_request(LnUrlWithdrawRequest self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_event_filter(EventFilter? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ln_url_withdraw_request_data(self.data, serializer);
-    sse_encode_u_64(self.amountMsat, serializer);
-    sse_encode_opt_String(self.description, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_event_filter(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_ln_url_withdraw_request_data(LnUrlWithdrawRequestData self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_event_webhook_config(EventWebhookConfig? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.callback, serializer);
-    sse_encode_String(self.k1, serializer);
-    sse_encode_String(self.defaultDescription, serializer);
-    sse_encode_u_64(self.minWithdrawable, serializer);
-    sse_encode_u_64(self.maxWithdrawable, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_event_webhook_config(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_ln_url_withdraw_result(LnUrlWithdrawResult self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case LnUrlWithdrawResult_Ok(data: final data):
-        sse_encode_i_32(0, serializer);
-        sse_encode_box_autoadd_ln_url_withdraw_success_data(data, serializer);
-      case LnUrlWithdrawResult_Timeout(data: final data):
-        sse_encode_i_32(1, serializer);
-        sse_encode_box_autoadd_ln_url_withdraw_success_data(data, serializer);
-      case LnUrlWithdrawResult_ErrorStatus(data: final data):
-        sse_encode_i_32(2, serializer);
-        sse_encode_box_autoadd_ln_url_error_data(data, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_ln_url_withdraw_success_data(LnUrlWithdrawSuccessData self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_fee_breakdown(FeeBreakdown? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ln_invoice(self.invoice, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fee_breakdown(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_locale_overrides(LocaleOverrides self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_fee_speed(FeeSpeed? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.locale, serializer);
-    sse_encode_opt_box_autoadd_u_32(self.spacing, serializer);
-    sse_encode_symbol(self.symbol, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fee_speed(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_localized_name(LocalizedName self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_fiat_amount(FiatAmount? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.locale, serializer);
-    sse_encode_String(self.name, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fiat_amount(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_fiat_conversion(FiatConversion? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.line, serializer);
-    sse_encode_String(self.level, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fiat_conversion(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_message_success_action_data(MessageSuccessActionData self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_network(Network self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_input_type(InputType? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_input_type(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_onchain_payment_limits_response(
-    OnchainPaymentLimitsResponse self,
+  void sse_encode_opt_box_autoadd_lightning_address_info(
+    LightningAddressInfo? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_limits(self.send, serializer);
-    sse_encode_limits(self.receive, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_lightning_address_info(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_list_payment_details(ListPaymentDetails? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_box_autoadd_list_payment_details(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_amount(Amount? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_ln_url_info(LnUrlInfo? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_amount(self, serializer);
+      sse_encode_box_autoadd_ln_url_info(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_asset_info(AssetInfo? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_ln_url_payer_data(LnUrlPayerData? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_asset_info(self, serializer);
+      sse_encode_box_autoadd_ln_url_payer_data(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_nwc_budget(NwcBudget? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
+      sse_encode_box_autoadd_nwc_budget(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_pay_amount(PayAmount? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
+      sse_encode_box_autoadd_pay_amount(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_payment(Payment? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
+      sse_encode_box_autoadd_payment(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_list_payment_details(ListPaymentDetails? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_receive_amount(ReceiveAmount? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_list_payment_details(self, serializer);
+      sse_encode_box_autoadd_receive_amount(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_ln_url_info(LnUrlInfo? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_registered_asset(RegisteredAsset? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_ln_url_info(self, serializer);
+      sse_encode_box_autoadd_registered_asset(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_pay_amount(PayAmount? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_simulation_config(SimulationConfig? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_pay_amount(self, serializer);
+      sse_encode_box_autoadd_simulation_config(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_payment(Payment? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_spend_limit(SpendLimit? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_payment(self, serializer);
+      sse_encode_box_autoadd_spend_limit(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_receive_amount(ReceiveAmount? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_spend_limits(SpendLimits? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_receive_amount(self, serializer);
+      sse_encode_box_autoadd_spend_limits(self, serializer);
     }
   }
 
@@ -7257,6 +13389,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_swap_service_status(SwapServiceStatus? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_swap_service_status(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_symbol(Symbol? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7267,6 +13409,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_sync_request(SyncRequest? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_sync_request(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7287,6 +13439,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_asset_metadata(List<AssetMetadata>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7317,6 +13479,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_payment_swap_type(List<PaymentSwapType>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_payment_swap_type(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_payment_type(List<PaymentType>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7337,6 +13509,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_list_sdk_event_kind(List<SdkEventKind>? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_sdk_event_kind(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_pay_amount(PayAmount self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7353,8 +13535,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(assetId, serializer);
         sse_encode_f_64(receiverAmount, serializer);
         sse_encode_opt_box_autoadd_bool(estimateAssetFees, serializer);
-      case PayAmount_Drain():
+      case PayAmount_AssetUnits(
+        receiverAmount: final receiverAmount,
+        estimateAssetFees: final estimateAssetFees,
+      ):
         sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_asset_amount(receiverAmount, serializer);
+        sse_encode_opt_box_autoadd_bool(estimateAssetFees, serializer);
+      case PayAmount_Drain():
+        sse_encode_i_32(3, serializer);
     }
   }
 
@@ -7363,6 +13552,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.address, serializer);
     sse_encode_prepare_pay_onchain_response(self.prepareResponse, serializer);
+    sse_encode_opt_String(self.spendLimitOverride, serializer);
   }
 
   @protected
@@ -7378,6 +13568,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_payment_type(self.paymentType, serializer);
     sse_encode_payment_state(self.status, serializer);
     sse_encode_payment_details(self.details, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.fiatAmount, serializer);
+    sse_encode_opt_String(self.fiatCurrency, serializer);
+    sse_encode_opt_String(self.recurringPaymentId, serializer);
   }
 
   @protected
@@ -7420,6 +13613,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         assetInfo: final assetInfo,
         lnurlInfo: final lnurlInfo,
         bip353Address: final bip353Address,
+        conversion: final conversion,
       ):
         sse_encode_i_32(1, serializer);
         sse_encode_String(destination, serializer);
@@ -7428,6 +13622,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_opt_box_autoadd_asset_info(assetInfo, serializer);
         sse_encode_opt_box_autoadd_ln_url_info(lnurlInfo, serializer);
         sse_encode_opt_String(bip353Address, serializer);
+        sse_encode_opt_box_autoadd_asset_conversion(conversion, serializer);
       case PaymentDetails_Bitcoin(
         swapId: final swapId,
         description: final description,
@@ -7437,6 +13632,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         claimTxId: final claimTxId,
         refundTxId: final refundTxId,
         refundTxAmountSat: final refundTxAmountSat,
+        buyBitcoinProvider: final buyBitcoinProvider,
       ):
         sse_encode_i_32(2, serializer);
         sse_encode_String(swapId, serializer);
@@ -7447,6 +13643,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_opt_String(claimTxId, serializer);
         sse_encode_opt_String(refundTxId, serializer);
         sse_encode_opt_box_autoadd_u_64(refundTxAmountSat, serializer);
+        sse_encode_opt_box_autoadd_buy_bitcoin_provider(buyBitcoinProvider, serializer);
     }
   }
 
@@ -7476,36 +13673,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(err, serializer);
       case PaymentError_InvalidOrExpiredFees():
         sse_encode_i_32(8, serializer);
-      case PaymentError_InsufficientFunds():
+      case PaymentError_FeeLimitExceeded(feesSat: final feesSat, maxFeeSat: final maxFeeSat):
         sse_encode_i_32(9, serializer);
-      case PaymentError_InvalidDescription(err: final err):
+        sse_encode_u_64(feesSat, serializer);
+        sse_encode_u_64(maxFeeSat, serializer);
+      case PaymentError_InsufficientFunds():
         sse_encode_i_32(10, serializer);
+      case PaymentError_SpendLimitExceeded(amountSat: final amountSat, remainingSat: final remainingSat):
+        sse_encode_i_32(11, serializer);
+        sse_encode_u_64(amountSat, serializer);
+        sse_encode_u_64(remainingSat, serializer);
+      case PaymentError_InvalidDescription(err: final err):
+        sse_encode_i_32(12, serializer);
         sse_encode_String(err, serializer);
       case PaymentError_InvalidInvoice(err: final err):
-        sse_encode_i_32(11, serializer);
+        sse_encode_i_32(13, serializer);
         sse_encode_String(err, serializer);
       case PaymentError_InvalidPreimage():
-        sse_encode_i_32(12, serializer);
+        sse_encode_i_32(14, serializer);
       case PaymentError_PairsNotFound():
-        sse_encode_i_32(13, serializer);
+        sse_encode_i_32(15, serializer);
       case PaymentError_PaymentTimeout():
-        sse_encode_i_32(14, serializer);
+        sse_encode_i_32(16, serializer);
       case PaymentError_PersistError():
-        sse_encode_i_32(15, serializer);
+        sse_encode_i_32(17, serializer);
       case PaymentError_ReceiveError(err: final err):
-        sse_encode_i_32(16, serializer);
+        sse_encode_i_32(18, serializer);
         sse_encode_String(err, serializer);
       case PaymentError_Refunded(err: final err, refundTxId: final refundTxId):
-        sse_encode_i_32(17, serializer);
+        sse_encode_i_32(19, serializer);
         sse_encode_String(err, serializer);
         sse_encode_String(refundTxId, serializer);
       case PaymentError_SelfTransferNotSupported():
-        sse_encode_i_32(18, serializer);
+        sse_encode_i_32(20, serializer);
       case PaymentError_SendError(err: final err):
-        sse_encode_i_32(19, serializer);
+        sse_encode_i_32(21, serializer);
         sse_encode_String(err, serializer);
       case PaymentError_SignerError(err: final err):
-        sse_encode_i_32(20, serializer);
+        sse_encode_i_32(22, serializer);
+        sse_encode_String(err, serializer);
+      case PaymentError_ServiceUnavailable(err: final err):
+        sse_encode_i_32(23, serializer);
         sse_encode_String(err, serializer);
     }
   }
@@ -7522,12 +13730,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_payment_swap_type(PaymentSwapType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_payment_type(PaymentType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_payment_unblinding_data(PaymentUnblindingData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.txId, serializer);
+    sse_encode_String(self.unblindingData, serializer);
+    sse_encode_opt_String(self.unblindedUrl, serializer);
+    sse_encode_list_unblinded_tx_out(self.inputs, serializer);
+    sse_encode_list_unblinded_tx_out(self.outputs, serializer);
+  }
+
   @protected
   void sse_encode_prepare_buy_bitcoin_request(PrepareBuyBitcoinRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7543,6 +13767,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.feesSat, serializer);
   }
 
+  @protected
+  void sse_encode_prepare_convert_request(PrepareConvertRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fromAssetId, serializer);
+    sse_encode_String(self.toAssetId, serializer);
+    sse_encode_u_64(self.amount, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_convert_response(PrepareConvertResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fromAssetId, serializer);
+    sse_encode_String(self.toAssetId, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_u_64(self.receiverAmount, serializer);
+    sse_encode_u_64(self.feesSat, serializer);
+  }
+
   @protected
   void sse_encode_prepare_ln_url_pay_request(PrepareLnUrlPayRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7550,6 +13792,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_pay_amount(self.amount, serializer);
     sse_encode_opt_String(self.bip353Address, serializer);
     sse_encode_opt_String(self.comment, serializer);
+    sse_encode_opt_box_autoadd_ln_url_payer_data(self.payerData, serializer);
     sse_encode_opt_box_autoadd_bool(self.validateSuccessActionUrl, serializer);
   }
 
@@ -7558,9 +13801,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_send_destination(self.destination, serializer);
     sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_fee_breakdown(self.feeBreakdown, serializer);
     sse_encode_ln_url_pay_request_data(self.data, serializer);
     sse_encode_opt_String(self.comment, serializer);
     sse_encode_opt_box_autoadd_success_action(self.successAction, serializer);
+    sse_encode_opt_String(self.verifyUrl, serializer);
   }
 
   @protected
@@ -7568,14 +13813,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_pay_amount(self.amount, serializer);
     sse_encode_opt_box_autoadd_u_32(self.feeRateSatPerVbyte, serializer);
+    sse_encode_opt_box_autoadd_fee_speed(self.feeSpeed, serializer);
   }
 
   @protected
   void sse_encode_prepare_pay_onchain_response(PreparePayOnchainResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.receiverAmountSat, serializer);
-    sse_encode_u_64(self.claimFeesSat, serializer);
-    sse_encode_u_64(self.totalFeesSat, serializer);
+    sse_encode_u_64(self.receiverAmountSat, serializer);
+    sse_encode_u_64(self.claimFeesSat, serializer);
+    sse_encode_u_64(self.totalFeesSat, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_receive_quotes_request(PrepareReceiveQuotesRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_receive_amount(self.amount, serializer);
+    sse_encode_opt_box_autoadd_fiat_amount(self.fiatAmount, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_receive_quotes_response(
+    PrepareReceiveQuotesResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prepare_receive_response(self.quotes, serializer);
   }
 
   @protected
@@ -7583,6 +13845,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_payment_method(self.paymentMethod, serializer);
     sse_encode_opt_box_autoadd_receive_amount(self.amount, serializer);
+    sse_encode_opt_box_autoadd_fiat_amount(self.fiatAmount, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.expirySecs, serializer);
   }
 
   @protected
@@ -7591,9 +13855,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_payment_method(self.paymentMethod, serializer);
     sse_encode_opt_box_autoadd_receive_amount(self.amount, serializer);
     sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_fee_breakdown(self.feeBreakdown, serializer);
     sse_encode_opt_box_autoadd_u_64(self.minPayerAmountSat, serializer);
     sse_encode_opt_box_autoadd_u_64(self.maxPayerAmountSat, serializer);
     sse_encode_opt_box_autoadd_f_64(self.swapperFeerate, serializer);
+    sse_encode_opt_box_autoadd_fiat_conversion(self.fiatConversion, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.expirySecs, serializer);
   }
 
   @protected
@@ -7601,7 +13868,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.swapAddress, serializer);
     sse_encode_String(self.refundAddress, serializer);
-    sse_encode_u_32(self.feeRateSatPerVbyte, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.feeRateSatPerVbyte, serializer);
+    sse_encode_opt_box_autoadd_fee_speed(self.feeSpeed, serializer);
   }
 
   @protected
@@ -7609,6 +13877,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_32(self.txVsize, serializer);
     sse_encode_u_64(self.txFeeSat, serializer);
+    sse_encode_u_32(self.feeRateSatPerVbyte, serializer);
+    sse_encode_u_64(self.refundableAmountSat, serializer);
+    sse_encode_u_64(self.refundAmountSat, serializer);
     sse_encode_opt_String(self.lastRefundTxId, serializer);
   }
 
@@ -7617,6 +13888,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.destination, serializer);
     sse_encode_opt_box_autoadd_pay_amount(self.amount, serializer);
+    sse_encode_opt_box_autoadd_fiat_amount(self.fiatAmount, serializer);
   }
 
   @protected
@@ -7624,7 +13896,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_send_destination(self.destination, serializer);
     sse_encode_opt_box_autoadd_u_64(self.feesSat, serializer);
+    sse_encode_opt_box_autoadd_fee_breakdown(self.feeBreakdown, serializer);
     sse_encode_opt_box_autoadd_f_64(self.estimatedAssetFees, serializer);
+    sse_encode_opt_box_autoadd_asset_amount(self.estimatedAssetFeesAmount, serializer);
+    sse_encode_opt_box_autoadd_fiat_conversion(self.fiatConversion, serializer);
+  }
+
+  @protected
+  void sse_encode_process_notification_request(ProcessNotificationRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_connect_request(self.connectRequest, serializer);
+    sse_encode_String(self.payload, serializer);
+    sse_encode_opt_box_autoadd_bool(self.acceptProposedFees, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.timeoutSec, serializer);
+  }
+
+  @protected
+  void sse_encode_process_notification_response(ProcessNotificationResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_payment(self.payment, serializer);
+    sse_encode_bool(self.isResolved, serializer);
+  }
+
+  @protected
+  void sse_encode_pset_spend_limit(PsetSpendLimit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.assetId, serializer);
+    sse_encode_u_64(self.maxAmountSat, serializer);
+  }
+
+  @protected
+  void sse_encode_queue_payment_request(QueuePaymentRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_send_request(self.prepareRequest, serializer);
+    sse_encode_opt_box_autoadd_bool(self.useAssetFees, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFeeSat, serializer);
+  }
+
+  @protected
+  void sse_encode_queued_payment(QueuedPayment self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_queue_payment_request(self.request, serializer);
+    sse_encode_queued_payment_state(self.state, serializer);
+    sse_encode_u_32(self.attempts, serializer);
+    sse_encode_u_32(self.createdAt, serializer);
+    sse_encode_u_32(self.nextAttemptAt, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
+  }
+
+  @protected
+  void sse_encode_queued_payment_state(QueuedPaymentState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -7645,6 +13969,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_32(1, serializer);
         sse_encode_String(assetId, serializer);
         sse_encode_opt_box_autoadd_f_64(payerAmount, serializer);
+      case ReceiveAmount_AssetUnits(payerAmount: final payerAmount):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_asset_amount(payerAmount, serializer);
     }
   }
 
@@ -7654,6 +13981,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_prepare_receive_response(self.prepareResponse, serializer);
     sse_encode_opt_String(self.description, serializer);
     sse_encode_opt_box_autoadd_bool(self.useDescriptionHash, serializer);
+    sse_encode_opt_String(self.descriptionHash, serializer);
+    sse_encode_opt_String(self.claimAddress, serializer);
   }
 
   @protected
@@ -7672,12 +14001,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.minimumFee, serializer);
   }
 
+  @protected
+  void sse_encode_recurring_payment(RecurringPayment self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.destination, serializer);
+    sse_encode_u_64(self.amountSat, serializer);
+    sse_encode_u_32(self.intervalSec, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFeeSat, serializer);
+    sse_encode_u_32(self.createdAt, serializer);
+    sse_encode_u_32(self.nextOccurrenceAt, serializer);
+  }
+
+  @protected
+  void sse_encode_recurring_payment_occurrence(RecurringPaymentOccurrence self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.recurringPaymentId, serializer);
+    sse_encode_u_32(self.occurrenceAt, serializer);
+    sse_encode_u_32(self.executedAt, serializer);
+    sse_encode_opt_String(self.txId, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
   @protected
   void sse_encode_refund_request(RefundRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.swapAddress, serializer);
     sse_encode_String(self.refundAddress, serializer);
-    sse_encode_u_32(self.feeRateSatPerVbyte, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.feeRateSatPerVbyte, serializer);
+    sse_encode_opt_box_autoadd_fee_speed(self.feeSpeed, serializer);
   }
 
   @protected
@@ -7693,6 +14045,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_32(self.timestamp, serializer);
     sse_encode_u_64(self.amountSat, serializer);
     sse_encode_opt_String(self.lastRefundTxId, serializer);
+    sse_encode_payment_state(self.state, serializer);
+    sse_encode_u_32(self.refundableSince, serializer);
+    sse_encode_u_32(self.refundEligibleBlockHeight, serializer);
+    sse_encode_bool(self.isCooperativeRefundPossible, serializer);
+  }
+
+  @protected
+  void sse_encode_register_lightning_address_request(
+    RegisterLightningAddressRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.username, serializer);
+  }
+
+  @protected
+  void sse_encode_registered_asset(RegisteredAsset self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.assetId, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.ticker, serializer);
+    sse_encode_u_8(self.precision, serializer);
+    sse_encode_opt_String(self.domain, serializer);
+    sse_encode_String(self.iconUrl, serializer);
+  }
+
+  @protected
+  void sse_encode_replay_events_request(ReplayEventsRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.sinceSeq, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
   }
 
   @protected
@@ -7701,6 +14084,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.backupPath, serializer);
   }
 
+  @protected
+  void sse_encode_reusable_bitcoin_address_response(
+    ReusableBitcoinAddressResponse self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_u_64(self.minDepositSat, serializer);
+    sse_encode_u_64(self.maxDepositSat, serializer);
+  }
+
   @protected
   void sse_encode_route_hint(RouteHint self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7719,19 +14113,53 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_64(self.htlcMaximumMsat, serializer);
   }
 
+  @protected
+  void sse_encode_schedule_payment_request(SchedulePaymentRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_send_request(self.prepareRequest, serializer);
+    sse_encode_opt_box_autoadd_bool(self.useAssetFees, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFeeSat, serializer);
+    sse_encode_u_32(self.executeAt, serializer);
+  }
+
+  @protected
+  void sse_encode_scheduled_payment(ScheduledPayment self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_schedule_payment_request(self.request, serializer);
+    sse_encode_scheduled_payment_state(self.state, serializer);
+    sse_encode_u_32(self.attempts, serializer);
+    sse_encode_u_32(self.createdAt, serializer);
+    sse_encode_u_32(self.nextAttemptAt, serializer);
+    sse_encode_opt_String(self.txId, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
+  }
+
+  @protected
+  void sse_encode_scheduled_payment_state(ScheduledPaymentState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_sdk_error(SdkError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     switch (self) {
       case SdkError_AlreadyStarted():
         sse_encode_i_32(0, serializer);
-      case SdkError_Generic(err: final err):
+      case SdkError_DataDirLocked(dir: final dir):
         sse_encode_i_32(1, serializer);
+        sse_encode_String(dir, serializer);
+      case SdkError_Generic(err: final err):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(err, serializer);
+      case SdkError_InvalidBackup(err: final err):
+        sse_encode_i_32(3, serializer);
         sse_encode_String(err, serializer);
       case SdkError_NotStarted():
-        sse_encode_i_32(2, serializer);
+        sse_encode_i_32(4, serializer);
       case SdkError_ServiceConnectivity(err: final err):
-        sse_encode_i_32(3, serializer);
+        sse_encode_i_32(5, serializer);
         sse_encode_String(err, serializer);
     }
   }
@@ -7764,22 +14192,114 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case SdkEvent_PaymentWaitingFeeAcceptance(details: final details):
         sse_encode_i_32(7, serializer);
         sse_encode_box_autoadd_payment(details, serializer);
-      case SdkEvent_Synced():
+      case SdkEvent_PaymentExpired(details: final details):
         sse_encode_i_32(8, serializer);
-      case SdkEvent_DataSynced(didPullNewRecords: final didPullNewRecords):
+        sse_encode_box_autoadd_payment(details, serializer);
+      case SdkEvent_PaymentReorged(details: final details):
         sse_encode_i_32(9, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case SdkEvent_SwapUpdated(swapId: final swapId, swapType: final swapType, state: final state):
+        sse_encode_i_32(10, serializer);
+        sse_encode_String(swapId, serializer);
+        sse_encode_payment_swap_type(swapType, serializer);
+        sse_encode_payment_state(state, serializer);
+      case SdkEvent_SwapClaimBroadcast(swapId: final swapId, txId: final txId):
+        sse_encode_i_32(11, serializer);
+        sse_encode_String(swapId, serializer);
+        sse_encode_String(txId, serializer);
+      case SdkEvent_SwapRefundBroadcast(swapId: final swapId, txId: final txId):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(swapId, serializer);
+        sse_encode_String(txId, serializer);
+      case SdkEvent_TipsUpdated(liquidTip: final liquidTip, bitcoinTip: final bitcoinTip):
+        sse_encode_i_32(13, serializer);
+        sse_encode_u_32(liquidTip, serializer);
+        sse_encode_u_32(bitcoinTip, serializer);
+      case SdkEvent_SyncStarted():
+        sse_encode_i_32(14, serializer);
+      case SdkEvent_Synced():
+        sse_encode_i_32(15, serializer);
+      case SdkEvent_DataSynced(didPullNewRecords: final didPullNewRecords):
+        sse_encode_i_32(16, serializer);
         sse_encode_bool(didPullNewRecords, serializer);
+      case SdkEvent_ConnectivityChanged(isOnline: final isOnline):
+        sse_encode_i_32(17, serializer);
+        sse_encode_bool(isOnline, serializer);
+      case SdkEvent_BackupSucceeded():
+        sse_encode_i_32(18, serializer);
+      case SdkEvent_BackupFailed(err: final err):
+        sse_encode_i_32(19, serializer);
+        sse_encode_String(err, serializer);
+      case SdkEvent_BatchSendCompleted(results: final results):
+        sse_encode_i_32(20, serializer);
+        sse_encode_list_batch_send_item_result(results, serializer);
+      case SdkEvent_ScheduledPaymentExecuted(id: final id, details: final details):
+        sse_encode_i_32(21, serializer);
+        sse_encode_String(id, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case SdkEvent_ScheduledPaymentFailed(id: final id, err: final err):
+        sse_encode_i_32(22, serializer);
+        sse_encode_String(id, serializer);
+        sse_encode_String(err, serializer);
+      case SdkEvent_RecurringPaymentExecuted(
+        recurringPaymentId: final recurringPaymentId,
+        details: final details,
+      ):
+        sse_encode_i_32(23, serializer);
+        sse_encode_String(recurringPaymentId, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case SdkEvent_RecurringPaymentFailed(recurringPaymentId: final recurringPaymentId, err: final err):
+        sse_encode_i_32(24, serializer);
+        sse_encode_String(recurringPaymentId, serializer);
+        sse_encode_String(err, serializer);
+      case SdkEvent_TransactionDropped(txId: final txId):
+        sse_encode_i_32(25, serializer);
+        sse_encode_String(txId, serializer);
+      case SdkEvent_TransactionUnconfirmed(txId: final txId, unconfirmedSec: final unconfirmedSec):
+        sse_encode_i_32(26, serializer);
+        sse_encode_String(txId, serializer);
+        sse_encode_u_32(unconfirmedSec, serializer);
+      case SdkEvent_NewLiquidBlock(block: final block):
+        sse_encode_i_32(27, serializer);
+        sse_encode_box_autoadd_block_info(block, serializer);
+      case SdkEvent_NewBitcoinBlock(block: final block):
+        sse_encode_i_32(28, serializer);
+        sse_encode_box_autoadd_block_info(block, serializer);
     }
   }
 
+  @protected
+  void sse_encode_sdk_event_kind(SdkEventKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_send_batch_request(SendBatchRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_batch_send_item(self.items, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxTotalFeeSat, serializer);
+  }
+
+  @protected
+  void sse_encode_send_batch_response(SendBatchResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_batch_send_item_result(self.results, serializer);
+  }
+
   @protected
   void sse_encode_send_destination(SendDestination self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     switch (self) {
-      case SendDestination_LiquidAddress(addressData: final addressData, bip353Address: final bip353Address):
+      case SendDestination_LiquidAddress(
+        addressData: final addressData,
+        bip353Address: final bip353Address,
+        payjoinUrl: final payjoinUrl,
+      ):
         sse_encode_i_32(0, serializer);
         sse_encode_box_autoadd_liquid_address_data(addressData, serializer);
         sse_encode_opt_String(bip353Address, serializer);
+        sse_encode_opt_String(payjoinUrl, serializer);
       case SendDestination_Bolt11(invoice: final invoice, bip353Address: final bip353Address):
         sse_encode_i_32(1, serializer);
         sse_encode_box_autoadd_ln_invoice(invoice, serializer);
@@ -7801,6 +14321,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_prepare_send_response(self.prepareResponse, serializer);
     sse_encode_opt_box_autoadd_bool(self.useAssetFees, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFeeSat, serializer);
+    sse_encode_opt_String(self.spendLimitOverride, serializer);
+    sse_encode_opt_String(self.idempotencyKey, serializer);
   }
 
   @protected
@@ -7809,6 +14332,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_payment(self.payment, serializer);
   }
 
+  @protected
+  void sse_encode_service_health(ServiceHealth self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_health_check_service(self.service, serializer);
+    sse_encode_bool(self.isReachable, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
   @protected
   void sse_encode_sign_message_request(SignMessageRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7819,6 +14351,50 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_sign_message_response(SignMessageResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.signature, serializer);
+    sse_encode_String(self.pubkey, serializer);
+  }
+
+  @protected
+  void sse_encode_sign_pset_request(SignPsetRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.pset, serializer);
+    sse_encode_u_64(self.maxFeeSat, serializer);
+    sse_encode_list_pset_spend_limit(self.spendLimits, serializer);
+  }
+
+  @protected
+  void sse_encode_sign_pset_response(SignPsetResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.pset, serializer);
+    sse_encode_u_64(self.feesSat, serializer);
+  }
+
+  @protected
+  void sse_encode_simulation_config(SimulationConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.statusUpdateDelaySec, serializer);
+    sse_encode_f_64(self.failureRate, serializer);
+  }
+
+  @protected
+  void sse_encode_spend_limit(SpendLimit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SpendLimit_Bitcoin(amountSat: final amountSat):
+        sse_encode_i_32(0, serializer);
+        sse_encode_u_64(amountSat, serializer);
+      case SpendLimit_Fiat(amount: final amount):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_fiat_amount(amount, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_spend_limits(SpendLimits self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_spend_limit(self.perPayment, serializer);
+    sse_encode_opt_box_autoadd_spend_limit(self.perDay, serializer);
+    sse_encode_opt_String(self.overrideSecret, serializer);
   }
 
   @protected
@@ -7853,6 +14429,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_swap_info(SwapInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.swapId, serializer);
+    sse_encode_payment_swap_type(self.swapType, serializer);
+    sse_encode_opt_box_autoadd_direction(self.direction, serializer);
+    sse_encode_payment_state(self.state, serializer);
+    sse_encode_u_32(self.createdAt, serializer);
+    sse_encode_u_64(self.timeoutBlockHeight, serializer);
+    sse_encode_u_64(self.payerAmountSat, serializer);
+    sse_encode_u_64(self.receiverAmountSat, serializer);
+    sse_encode_opt_String(self.invoice, serializer);
+    sse_encode_opt_String(self.lockupAddress, serializer);
+    sse_encode_opt_String(self.userLockupTxId, serializer);
+    sse_encode_opt_String(self.serverLockupTxId, serializer);
+    sse_encode_opt_String(self.claimAddress, serializer);
+    sse_encode_opt_String(self.claimTxId, serializer);
+    sse_encode_opt_String(self.refundAddress, serializer);
+    sse_encode_opt_String(self.refundTxId, serializer);
+    sse_encode_String(self.createResponseJson, serializer);
+  }
+
+  @protected
+  void sse_encode_swap_service_status(SwapServiceStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.sendAvailable, serializer);
+    sse_encode_bool(self.receiveAvailable, serializer);
+    sse_encode_bool(self.chainSendAvailable, serializer);
+    sse_encode_bool(self.chainReceiveAvailable, serializer);
+  }
+
+  @protected
+  void sse_encode_sweep_private_key_request(SweepPrivateKeyRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+  }
+
+  @protected
+  void sse_encode_sweep_private_key_response(SweepPrivateKeyResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_payment(self.payment, serializer);
+    sse_encode_u_64(self.amountSat, serializer);
+    sse_encode_list_asset_amount(self.assetAmounts, serializer);
+  }
+
   @protected
   void sse_encode_symbol(Symbol self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7862,6 +14483,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_box_autoadd_u_32(self.position, serializer);
   }
 
+  @protected
+  void sse_encode_sync_request(SyncRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_bool(self.full, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_response(SyncResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.newTxCount, serializer);
+    sse_encode_u_32(self.updatedSwapCount, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7886,6 +14521,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint8(self);
   }
 
+  @protected
+  void sse_encode_unblinded_tx_out(UnblindedTxOut self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.outpoint, serializer);
+    sse_encode_String(self.assetId, serializer);
+    sse_encode_u_64(self.amount, serializer);
+    sse_encode_String(self.assetBlindingFactor, serializer);
+    sse_encode_String(self.amountBlindingFactor, serializer);
+  }
+
   @protected
   void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7905,6 +14550,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putBigUint64(self);
   }
 
+  @protected
+  void sse_encode_validate_address_response(ValidateAddressResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_address_type(self.addressType, serializer);
+    sse_encode_liquid_network(self.network, serializer);
+    sse_encode_bool(self.isConfidential, serializer);
+    sse_encode_bool(self.supportsAssets, serializer);
+  }
+
   @protected
   void sse_encode_wallet_info(WalletInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -7936,8 +14590,14 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   Future<void> acceptPaymentProposedFees({required AcceptPaymentProposedFeesRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkAcceptPaymentProposedFees(that: this, req: req);
 
-  Stream<SdkEvent> addEventListener() =>
-      RustLib.instance.api.crateBindingsBindingLiquidSdkAddEventListener(that: this);
+  Stream<SdkEvent> addEventListener({EventFilter? filter}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkAddEventListener(that: this, filter: filter);
+
+  Future<void> addInputParser({required FutureOr<InputType?> Function(String) parseInput}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkAddInputParser(that: this, parseInput: parseInput);
+
+  AddNwcConnectionResponse addNwcConnection({required AddNwcConnectionRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkAddNwcConnection(that: this, req: req);
 
   void backup({required BackupRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkBackup(that: this, req: req);
@@ -7945,13 +14605,50 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   Future<String> buyBitcoin({required BuyBitcoinRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkBuyBitcoin(that: this, req: req);
 
+  Future<void> cancelQueuedPayment({required String id}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkCancelQueuedPayment(that: this, id: id);
+
+  Future<void> cancelRecurringPayment({required String id}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkCancelRecurringPayment(that: this, id: id);
+
+  Future<void> cancelScheduledPayment({required String id}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkCancelScheduledPayment(that: this, id: id);
+
+  Future<CheckHealthResponse> checkHealth() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkCheckHealth(that: this);
+
+  Future<bool> checkLightningAddressAvailable({required String username}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkCheckLightningAddressAvailable(
+        that: this,
+        username: username,
+      );
+
   CheckMessageResponse checkMessage({required CheckMessageRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkCheckMessage(that: this, req: req);
 
+  Future<void> claimSwap({required String swapId}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkClaimSwap(that: this, swapId: swapId);
+
+  Future<SendPaymentResponse> convert({required ConvertRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkConvert(that: this, req: req);
+
+  Future<RecurringPayment> createRecurringPayment({required CreateRecurringPaymentRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkCreateRecurringPayment(that: this, req: req);
+
+  Future<void> deleteLightningAddress() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkDeleteLightningAddress(that: this);
+
   Future<void> disconnect() => RustLib.instance.api.crateBindingsBindingLiquidSdkDisconnect(that: this);
 
   void emptyWalletCache() => RustLib.instance.api.crateBindingsBindingLiquidSdkEmptyWalletCache(that: this);
 
+  Future<PaymentUnblindingData> exportPaymentUnblindingData({
+    required ExportPaymentUnblindingDataRequest req,
+  }) => RustLib.instance.api.crateBindingsBindingLiquidSdkExportPaymentUnblindingData(that: this, req: req);
+
+  void exportWallet({required ExportWalletRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkExportWallet(that: this, req: req);
+
   Future<List<Rate>> fetchFiatRates() =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkFetchFiatRates(that: this);
 
@@ -7965,20 +14662,65 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
     required FetchPaymentProposedFeesRequest req,
   }) => RustLib.instance.api.crateBindingsBindingLiquidSdkFetchPaymentProposedFees(that: this, req: req);
 
+  Future<RegisteredAsset?> fetchRegisteredAsset({required String assetId}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkFetchRegisteredAsset(that: this, assetId: assetId);
+
+  AmpAccount? getAmpAccount() => RustLib.instance.api.crateBindingsBindingLiquidSdkGetAmpAccount(that: this);
+
   Future<GetInfoResponse> getInfo() => RustLib.instance.api.crateBindingsBindingLiquidSdkGetInfo(that: this);
 
+  Future<GetMaxPayableResponse> getMaxPayable({required GetMaxPayableRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetMaxPayable(that: this, req: req);
+
+  Future<GetMaxReceivableResponse> getMaxReceivable({required GetMaxReceivableRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetMaxReceivable(that: this, req: req);
+
   Future<Payment?> getPayment({required GetPaymentRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkGetPayment(that: this, req: req);
 
+  Future<GetReportResponse> getReport({required GetReportRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetReport(that: this, req: req);
+
+  Future<ReusableBitcoinAddressResponse> getReusableBitcoinAddress() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetReusableBitcoinAddress(that: this);
+
+  GetWalletInfoResponse getWalletInfo() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetWalletInfo(that: this);
+
+  ImportWalletResponse importWallet({required ImportWalletRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkImportWallet(that: this, req: req);
+
   Future<List<FiatCurrency>> listFiatCurrencies() =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkListFiatCurrencies(that: this);
 
+  List<NwcConnection> listNwcConnections() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListNwcConnections(that: this);
+
   Future<List<Payment>> listPayments({required ListPaymentsRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkListPayments(that: this, req: req);
 
+  Future<List<QueuedPayment>> listQueuedPayments() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListQueuedPayments(that: this);
+
+  Future<List<RecurringPaymentOccurrence>> listRecurringPaymentOccurrences({
+    required String recurringPaymentId,
+  }) => RustLib.instance.api.crateBindingsBindingLiquidSdkListRecurringPaymentOccurrences(
+    that: this,
+    recurringPaymentId: recurringPaymentId,
+  );
+
+  Future<List<RecurringPayment>> listRecurringPayments() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListRecurringPayments(that: this);
+
   Future<List<RefundableSwap>> listRefundables() =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkListRefundables(that: this);
 
+  Future<List<ScheduledPayment>> listScheduledPayments() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListScheduledPayments(that: this);
+
+  Future<List<SwapInfo>> listSwaps({required ListSwapsRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListSwaps(that: this, req: req);
+
   Future<LnUrlCallbackStatus> lnurlAuth({required LnUrlAuthRequestData reqData}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkLnurlAuth(that: this, reqData: reqData);
 
@@ -7997,6 +14739,9 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   Future<PrepareBuyBitcoinResponse> prepareBuyBitcoin({required PrepareBuyBitcoinRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareBuyBitcoin(that: this, req: req);
 
+  Future<PrepareConvertResponse> prepareConvert({required PrepareConvertRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareConvert(that: this, req: req);
+
   Future<PrepareLnUrlPayResponse> prepareLnurlPay({required PrepareLnUrlPayRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareLnurlPay(that: this, req: req);
 
@@ -8006,38 +14751,85 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
   Future<PrepareReceiveResponse> prepareReceivePayment({required PrepareReceiveRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareReceivePayment(that: this, req: req);
 
+  Future<PrepareReceiveQuotesResponse> prepareReceivePaymentQuotes({
+    required PrepareReceiveQuotesRequest req,
+  }) => RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareReceivePaymentQuotes(that: this, req: req);
+
   Future<PrepareRefundResponse> prepareRefund({required PrepareRefundRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareRefund(that: this, req: req);
 
   Future<PrepareSendResponse> prepareSendPayment({required PrepareSendRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareSendPayment(that: this, req: req);
 
+  Future<QueuedPayment> queuePayment({required QueuePaymentRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkQueuePayment(that: this, req: req);
+
   Future<ReceivePaymentResponse> receivePayment({required ReceivePaymentRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkReceivePayment(that: this, req: req);
 
   Future<RecommendedFees> recommendedFees() =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkRecommendedFees(that: this);
 
+  Future<LightningAddressInfo?> recoverLightningAddress() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkRecoverLightningAddress(that: this);
+
   Future<RefundResponse> refund({required RefundRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkRefund(that: this, req: req);
 
+  Future<AmpAccount> registerAmpAccount() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkRegisterAmpAccount(that: this);
+
+  Future<LightningAddressInfo> registerLightningAddress({required RegisterLightningAddressRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkRegisterLightningAddress(that: this, req: req);
+
   Future<void> registerWebhook({required String webhookUrl}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkRegisterWebhook(that: this, webhookUrl: webhookUrl);
 
+  void removeNwcConnection({required String name}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkRemoveNwcConnection(that: this, name: name);
+
+  Future<List<JournaledEvent>> replayEvents({required ReplayEventsRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkReplayEvents(that: this, req: req);
+
   Future<void> rescanOnchainSwaps() =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkRescanOnchainSwaps(that: this);
 
   void restore({required RestoreRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkRestore(that: this, req: req);
 
+  Future<ScheduledPayment> schedulePayment({required SchedulePaymentRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSchedulePayment(that: this, req: req);
+
+  Future<SendBatchResponse> sendBatch({required SendBatchRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSendBatch(that: this, req: req);
+
   Future<SendPaymentResponse> sendPayment({required SendPaymentRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkSendPayment(that: this, req: req);
 
+  Future<void> setBroadcaster({
+    required FutureOr<String> Function(String) broadcastLiquidTx,
+    required FutureOr<String> Function(String) broadcastBitcoinTx,
+  }) => RustLib.instance.api.crateBindingsBindingLiquidSdkSetBroadcaster(
+    that: this,
+    broadcastLiquidTx: broadcastLiquidTx,
+    broadcastBitcoinTx: broadcastBitcoinTx,
+  );
+
   SignMessageResponse signMessage({required SignMessageRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkSignMessage(that: this, req: req);
 
-  Future<void> sync() => RustLib.instance.api.crateBindingsBindingLiquidSdkSync(that: this);
+  Future<SignPsetResponse> signPset({required SignPsetRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSignPset(that: this, req: req);
+
+  Future<SweepPrivateKeyResponse> sweepPrivateKey({required SweepPrivateKeyRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSweepPrivateKey(that: this, req: req);
+
+  Future<SyncResponse> sync({SyncRequest? req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSync(that: this, req: req);
 
   Future<void> unregisterWebhook() =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkUnregisterWebhook(that: this);
+
+  void verifyBackup({required RestoreRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkVerifyBackup(that: this, req: req);
 }
@@ -39,6 +39,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  FutureOr<String> Function(String) dco_decode_DartFn_Inputs_String_Output_String_AnyhowException(
+    dynamic raw,
+  );
+
+  @protected
+  FutureOr<InputType?> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(dynamic raw);
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw);
+
   @protected
   BindingLiquidSdk dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     dynamic raw,
@@ -56,6 +68,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AcceptPaymentProposedFeesRequest dco_decode_accept_payment_proposed_fees_request(dynamic raw);
 
+  @protected
+  AddNwcConnectionRequest dco_decode_add_nwc_connection_request(dynamic raw);
+
+  @protected
+  AddNwcConnectionResponse dco_decode_add_nwc_connection_response(dynamic raw);
+
+  @protected
+  AddressType dco_decode_address_type(dynamic raw);
+
   @protected
   AesSuccessActionData dco_decode_aes_success_action_data(dynamic raw);
 
@@ -68,24 +89,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Amount dco_decode_amount(dynamic raw);
 
+  @protected
+  AmpAccount dco_decode_amp_account(dynamic raw);
+
+  @protected
+  AssetAmount dco_decode_asset_amount(dynamic raw);
+
   @protected
   AssetBalance dco_decode_asset_balance(dynamic raw);
 
+  @protected
+  AssetConversion dco_decode_asset_conversion(dynamic raw);
+
   @protected
   AssetInfo dco_decode_asset_info(dynamic raw);
 
   @protected
   AssetMetadata dco_decode_asset_metadata(dynamic raw);
 
+  @protected
+  AssetReport dco_decode_asset_report(dynamic raw);
+
+  @protected
+  BackupPolicy dco_decode_backup_policy(dynamic raw);
+
   @protected
   BackupRequest dco_decode_backup_request(dynamic raw);
 
   @protected
-  BindingEventListener dco_decode_binding_event_listener(dynamic raw);
+  BatchSendItem dco_decode_batch_send_item(dynamic raw);
+
+  @protected
+  BatchSendItemResult dco_decode_batch_send_item_result(dynamic raw);
 
   @protected
   BitcoinAddressData dco_decode_bitcoin_address_data(dynamic raw);
 
+  @protected
+  BlockInfo dco_decode_block_info(dynamic raw);
+
   @protected
   BlockchainExplorer dco_decode_blockchain_explorer(dynamic raw);
 
@@ -98,6 +140,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AcceptPaymentProposedFeesRequest dco_decode_box_autoadd_accept_payment_proposed_fees_request(dynamic raw);
 
+  @protected
+  AddNwcConnectionRequest dco_decode_box_autoadd_add_nwc_connection_request(dynamic raw);
+
   @protected
   AesSuccessActionData dco_decode_box_autoadd_aes_success_action_data(dynamic raw);
 
@@ -110,21 +155,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Amount dco_decode_box_autoadd_amount(dynamic raw);
 
+  @protected
+  AmpAccount dco_decode_box_autoadd_amp_account(dynamic raw);
+
+  @protected
+  AssetAmount dco_decode_box_autoadd_asset_amount(dynamic raw);
+
+  @protected
+  AssetConversion dco_decode_box_autoadd_asset_conversion(dynamic raw);
+
   @protected
   AssetInfo dco_decode_box_autoadd_asset_info(dynamic raw);
 
   @protected
-  BackupRequest dco_decode_box_autoadd_backup_request(dynamic raw);
+  BackupPolicy dco_decode_box_autoadd_backup_policy(dynamic raw);
 
   @protected
-  BindingEventListener dco_decode_box_autoadd_binding_event_listener(dynamic raw);
+  BackupRequest dco_decode_box_autoadd_backup_request(dynamic raw);
 
   @protected
   BitcoinAddressData dco_decode_box_autoadd_bitcoin_address_data(dynamic raw);
 
+  @protected
+  BlockInfo dco_decode_box_autoadd_block_info(dynamic raw);
+
   @protected
   bool dco_decode_box_autoadd_bool(dynamic raw);
 
+  @protected
+  BuyBitcoinProvider dco_decode_box_autoadd_buy_bitcoin_provider(dynamic raw);
+
   @protected
   BuyBitcoinRequest dco_decode_box_autoadd_buy_bitcoin_request(dynamic raw);
 
@@ -134,18 +194,71 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConnectRequest dco_decode_box_autoadd_connect_request(dynamic raw);
 
+  @protected
+  ConvertRequest dco_decode_box_autoadd_convert_request(dynamic raw);
+
+  @protected
+  CreateRecurringPaymentRequest dco_decode_box_autoadd_create_recurring_payment_request(dynamic raw);
+
+  @protected
+  Direction dco_decode_box_autoadd_direction(dynamic raw);
+
+  @protected
+  EventFilter dco_decode_box_autoadd_event_filter(dynamic raw);
+
+  @protected
+  EventWebhookConfig dco_decode_box_autoadd_event_webhook_config(dynamic raw);
+
+  @protected
+  ExportPaymentUnblindingDataRequest dco_decode_box_autoadd_export_payment_unblinding_data_request(
+    dynamic raw,
+  );
+
+  @protected
+  ExportWalletRequest dco_decode_box_autoadd_export_wallet_request(dynamic raw);
+
   @protected
   double dco_decode_box_autoadd_f_64(dynamic raw);
 
+  @protected
+  FeeBreakdown dco_decode_box_autoadd_fee_breakdown(dynamic raw);
+
+  @protected
+  FeeSpeed dco_decode_box_autoadd_fee_speed(dynamic raw);
+
   @protected
   FetchPaymentProposedFeesRequest dco_decode_box_autoadd_fetch_payment_proposed_fees_request(dynamic raw);
 
+  @protected
+  FiatAmount dco_decode_box_autoadd_fiat_amount(dynamic raw);
+
+  @protected
+  FiatConversion dco_decode_box_autoadd_fiat_conversion(dynamic raw);
+
+  @protected
+  GetMaxPayableRequest dco_decode_box_autoadd_get_max_payable_request(dynamic raw);
+
+  @protected
+  GetMaxReceivableRequest dco_decode_box_autoadd_get_max_receivable_request(dynamic raw);
+
   @protected
   GetPaymentRequest dco_decode_box_autoadd_get_payment_request(dynamic raw);
 
+  @protected
+  GetReportRequest dco_decode_box_autoadd_get_report_request(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
+  @protected
+  ImportWalletRequest dco_decode_box_autoadd_import_wallet_request(dynamic raw);
+
+  @protected
+  InputType dco_decode_box_autoadd_input_type(dynamic raw);
+
+  @protected
+  LightningAddressInfo dco_decode_box_autoadd_lightning_address_info(dynamic raw);
+
   @protected
   LiquidAddressData dco_decode_box_autoadd_liquid_address_data(dynamic raw);
 
@@ -155,6 +268,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ListPaymentsRequest dco_decode_box_autoadd_list_payments_request(dynamic raw);
 
+  @protected
+  ListSwapsRequest dco_decode_box_autoadd_list_swaps_request(dynamic raw);
+
+  @protected
+  LNBolt12Invoice dco_decode_box_autoadd_ln_bolt_12_invoice(dynamic raw);
+
   @protected
   LNInvoice dco_decode_box_autoadd_ln_invoice(dynamic raw);
 
@@ -182,6 +301,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LnUrlPaySuccessData dco_decode_box_autoadd_ln_url_pay_success_data(dynamic raw);
 
+  @protected
+  LnUrlPayerData dco_decode_box_autoadd_ln_url_payer_data(dynamic raw);
+
   @protected
   LnUrlWithdrawRequest dco_decode_box_autoadd_ln_url_withdraw_request(dynamic raw);
 
@@ -194,6 +316,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MessageSuccessActionData dco_decode_box_autoadd_message_success_action_data(dynamic raw);
 
+  @protected
+  NwcBudget dco_decode_box_autoadd_nwc_budget(dynamic raw);
+
   @protected
   PayAmount dco_decode_box_autoadd_pay_amount(dynamic raw);
 
@@ -206,12 +331,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareBuyBitcoinRequest dco_decode_box_autoadd_prepare_buy_bitcoin_request(dynamic raw);
 
+  @protected
+  PrepareConvertRequest dco_decode_box_autoadd_prepare_convert_request(dynamic raw);
+
   @protected
   PrepareLnUrlPayRequest dco_decode_box_autoadd_prepare_ln_url_pay_request(dynamic raw);
 
   @protected
   PreparePayOnchainRequest dco_decode_box_autoadd_prepare_pay_onchain_request(dynamic raw);
 
+  @protected
+  PrepareReceiveQuotesRequest dco_decode_box_autoadd_prepare_receive_quotes_request(dynamic raw);
+
   @protected
   PrepareReceiveRequest dco_decode_box_autoadd_prepare_receive_request(dynamic raw);
 
@@ -221,6 +352,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareSendRequest dco_decode_box_autoadd_prepare_send_request(dynamic raw);
 
+  @protected
+  ProcessNotificationRequest dco_decode_box_autoadd_process_notification_request(dynamic raw);
+
+  @protected
+  QueuePaymentRequest dco_decode_box_autoadd_queue_payment_request(dynamic raw);
+
   @protected
   ReceiveAmount dco_decode_box_autoadd_receive_amount(dynamic raw);
 
@@ -230,11 +367,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RefundRequest dco_decode_box_autoadd_refund_request(dynamic raw);
 
+  @protected
+  RegisterLightningAddressRequest dco_decode_box_autoadd_register_lightning_address_request(dynamic raw);
+
+  @protected
+  RegisteredAsset dco_decode_box_autoadd_registered_asset(dynamic raw);
+
+  @protected
+  ReplayEventsRequest dco_decode_box_autoadd_replay_events_request(dynamic raw);
+
   @protected
   RestoreRequest dco_decode_box_autoadd_restore_request(dynamic raw);
 
   @protected
-  SdkEvent dco_decode_box_autoadd_sdk_event(dynamic raw);
+  SchedulePaymentRequest dco_decode_box_autoadd_schedule_payment_request(dynamic raw);
+
+  @protected
+  SendBatchRequest dco_decode_box_autoadd_send_batch_request(dynamic raw);
 
   @protected
   SendPaymentRequest dco_decode_box_autoadd_send_payment_request(dynamic raw);
@@ -242,15 +391,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SignMessageRequest dco_decode_box_autoadd_sign_message_request(dynamic raw);
 
+  @protected
+  SignPsetRequest dco_decode_box_autoadd_sign_pset_request(dynamic raw);
+
+  @protected
+  SimulationConfig dco_decode_box_autoadd_simulation_config(dynamic raw);
+
+  @protected
+  SpendLimit dco_decode_box_autoadd_spend_limit(dynamic raw);
+
+  @protected
+  SpendLimits dco_decode_box_autoadd_spend_limits(dynamic raw);
+
   @protected
   SuccessAction dco_decode_box_autoadd_success_action(dynamic raw);
 
   @protected
   SuccessActionProcessed dco_decode_box_autoadd_success_action_processed(dynamic raw);
 
+  @protected
+  SwapServiceStatus dco_decode_box_autoadd_swap_service_status(dynamic raw);
+
+  @protected
+  SweepPrivateKeyRequest dco_decode_box_autoadd_sweep_private_key_request(dynamic raw);
+
   @protected
   Symbol dco_decode_box_autoadd_symbol(dynamic raw);
 
+  @protected
+  SyncRequest dco_decode_box_autoadd_sync_request(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
@@ -266,6 +436,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BuyBitcoinRequest dco_decode_buy_bitcoin_request(dynamic raw);
 
+  @protected
+  CheckHealthResponse dco_decode_check_health_response(dynamic raw);
+
   @protected
   CheckMessageRequest dco_decode_check_message_request(dynamic raw);
 
@@ -278,39 +451,114 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConnectRequest dco_decode_connect_request(dynamic raw);
 
+  @protected
+  ConvertRequest dco_decode_convert_request(dynamic raw);
+
+  @protected
+  CreateRecurringPaymentRequest dco_decode_create_recurring_payment_request(dynamic raw);
+
   @protected
   CurrencyInfo dco_decode_currency_info(dynamic raw);
 
+  @protected
+  DecodedInvoice dco_decode_decoded_invoice(dynamic raw);
+
+  @protected
+  Direction dco_decode_direction(dynamic raw);
+
+  @protected
+  EventFilter dco_decode_event_filter(dynamic raw);
+
+  @protected
+  EventWebhookConfig dco_decode_event_webhook_config(dynamic raw);
+
+  @protected
+  ExportPaymentUnblindingDataRequest dco_decode_export_payment_unblinding_data_request(dynamic raw);
+
+  @protected
+  ExportWalletRequest dco_decode_export_wallet_request(dynamic raw);
+
   @protected
   ExternalInputParser dco_decode_external_input_parser(dynamic raw);
 
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FeeBreakdown dco_decode_fee_breakdown(dynamic raw);
+
+  @protected
+  FeeSpeed dco_decode_fee_speed(dynamic raw);
+
   @protected
   FetchPaymentProposedFeesRequest dco_decode_fetch_payment_proposed_fees_request(dynamic raw);
 
   @protected
   FetchPaymentProposedFeesResponse dco_decode_fetch_payment_proposed_fees_response(dynamic raw);
 
+  @protected
+  FiatAmount dco_decode_fiat_amount(dynamic raw);
+
+  @protected
+  FiatConversion dco_decode_fiat_conversion(dynamic raw);
+
   @protected
   FiatCurrency dco_decode_fiat_currency(dynamic raw);
 
   @protected
   GetInfoResponse dco_decode_get_info_response(dynamic raw);
 
+  @protected
+  GetMaxPayableRequest dco_decode_get_max_payable_request(dynamic raw);
+
+  @protected
+  GetMaxPayableResponse dco_decode_get_max_payable_response(dynamic raw);
+
+  @protected
+  GetMaxReceivableRequest dco_decode_get_max_receivable_request(dynamic raw);
+
+  @protected
+  GetMaxReceivableResponse dco_decode_get_max_receivable_response(dynamic raw);
+
   @protected
   GetPaymentRequest dco_decode_get_payment_request(dynamic raw);
 
+  @protected
+  GetReportRequest dco_decode_get_report_request(dynamic raw);
+
+  @protected
+  GetReportResponse dco_decode_get_report_response(dynamic raw);
+
+  @protected
+  GetWalletInfoResponse dco_decode_get_wallet_info_response(dynamic raw);
+
+  @protected
+  HealthCheckService dco_decode_health_check_service(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  ImportWalletRequest dco_decode_import_wallet_request(dynamic raw);
+
+  @protected
+  ImportWalletResponse dco_decode_import_wallet_response(dynamic raw);
+
   @protected
   InputType dco_decode_input_type(dynamic raw);
 
+  @protected
+  PlatformInt64 dco_decode_isize(dynamic raw);
+
+  @protected
+  JournaledEvent dco_decode_journaled_event(dynamic raw);
+
+  @protected
+  LightningAddressInfo dco_decode_lightning_address_info(dynamic raw);
+
   @protected
   LightningPaymentLimitsResponse dco_decode_lightning_payment_limits_response(dynamic raw);
 
@@ -326,18 +574,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AssetAmount> dco_decode_list_asset_amount(dynamic raw);
+
   @protected
   List<AssetBalance> dco_decode_list_asset_balance(dynamic raw);
 
   @protected
   List<AssetMetadata> dco_decode_list_asset_metadata(dynamic raw);
 
+  @protected
+  List<AssetReport> dco_decode_list_asset_report(dynamic raw);
+
+  @protected
+  List<BatchSendItem> dco_decode_list_batch_send_item(dynamic raw);
+
+  @protected
+  List<BatchSendItemResult> dco_decode_list_batch_send_item_result(dynamic raw);
+
   @protected
   List<ExternalInputParser> dco_decode_list_external_input_parser(dynamic raw);
 
   @protected
   List<FiatCurrency> dco_decode_list_fiat_currency(dynamic raw);
 
+  @protected
+  List<JournaledEvent> dco_decode_list_journaled_event(dynamic raw);
+
   @protected
   List<LnOfferBlindedPath> dco_decode_list_ln_offer_blinded_path(dynamic raw);
 
@@ -347,6 +610,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<LocalizedName> dco_decode_list_localized_name(dynamic raw);
 
+  @protected
+  List<NwcConnection> dco_decode_list_nwc_connection(dynamic raw);
+
   @protected
   List<Payment> dco_decode_list_payment(dynamic raw);
 
@@ -356,18 +622,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<PaymentState> dco_decode_list_payment_state(dynamic raw);
 
+  @protected
+  List<PaymentSwapType> dco_decode_list_payment_swap_type(dynamic raw);
+
   @protected
   List<PaymentType> dco_decode_list_payment_type(dynamic raw);
 
   @protected
   ListPaymentsRequest dco_decode_list_payments_request(dynamic raw);
 
+  @protected
+  List<PrepareReceiveResponse> dco_decode_list_prepare_receive_response(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<PsetSpendLimit> dco_decode_list_pset_spend_limit(dynamic raw);
+
+  @protected
+  List<QueuedPayment> dco_decode_list_queued_payment(dynamic raw);
+
   @protected
   List<Rate> dco_decode_list_rate(dynamic raw);
 
+  @protected
+  List<RecurringPayment> dco_decode_list_recurring_payment(dynamic raw);
+
+  @protected
+  List<RecurringPaymentOccurrence> dco_decode_list_recurring_payment_occurrence(dynamic raw);
+
   @protected
   List<RefundableSwap> dco_decode_list_refundable_swap(dynamic raw);
 
@@ -377,6 +661,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<RouteHintHop> dco_decode_list_route_hint_hop(dynamic raw);
 
+  @protected
+  List<ScheduledPayment> dco_decode_list_scheduled_payment(dynamic raw);
+
+  @protected
+  List<SdkEventKind> dco_decode_list_sdk_event_kind(dynamic raw);
+
+  @protected
+  List<ServiceHealth> dco_decode_list_service_health(dynamic raw);
+
+  @protected
+  List<SwapInfo> dco_decode_list_swap_info(dynamic raw);
+
+  @protected
+  ListSwapsRequest dco_decode_list_swaps_request(dynamic raw);
+
+  @protected
+  List<UnblindedTxOut> dco_decode_list_unblinded_tx_out(dynamic raw);
+
+  @protected
+  LNBolt12Invoice dco_decode_ln_bolt_12_invoice(dynamic raw);
+
   @protected
   LNInvoice dco_decode_ln_invoice(dynamic raw);
 
@@ -419,6 +724,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LnUrlPaySuccessData dco_decode_ln_url_pay_success_data(dynamic raw);
 
+  @protected
+  LnUrlPayerData dco_decode_ln_url_payer_data(dynamic raw);
+
   @protected
   LnUrlWithdrawError dco_decode_ln_url_withdraw_error(dynamic raw);
 
@@ -449,6 +757,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Network dco_decode_network(dynamic raw);
 
+  @protected
+  NwcBudget dco_decode_nwc_budget(dynamic raw);
+
+  @protected
+  NwcConnection dco_decode_nwc_connection(dynamic raw);
+
   @protected
   OnchainPaymentLimitsResponse dco_decode_onchain_payment_limits_response(dynamic raw);
 
@@ -459,103 +773,196 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   Amount? dco_decode_opt_box_autoadd_amount(dynamic raw);
 
   @protected
-  AssetInfo? dco_decode_opt_box_autoadd_asset_info(dynamic raw);
+  AmpAccount? dco_decode_opt_box_autoadd_amp_account(dynamic raw);
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+  AssetAmount? dco_decode_opt_box_autoadd_asset_amount(dynamic raw);
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+  AssetConversion? dco_decode_opt_box_autoadd_asset_conversion(dynamic raw);
 
   @protected
-  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+  AssetInfo? dco_decode_opt_box_autoadd_asset_info(dynamic raw);
 
   @protected
-  ListPaymentDetails? dco_decode_opt_box_autoadd_list_payment_details(dynamic raw);
+  BackupPolicy? dco_decode_opt_box_autoadd_backup_policy(dynamic raw);
 
   @protected
-  LnUrlInfo? dco_decode_opt_box_autoadd_ln_url_info(dynamic raw);
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
   @protected
-  PayAmount? dco_decode_opt_box_autoadd_pay_amount(dynamic raw);
+  BuyBitcoinProvider? dco_decode_opt_box_autoadd_buy_bitcoin_provider(dynamic raw);
 
   @protected
-  Payment? dco_decode_opt_box_autoadd_payment(dynamic raw);
+  Direction? dco_decode_opt_box_autoadd_direction(dynamic raw);
 
   @protected
-  ReceiveAmount? dco_decode_opt_box_autoadd_receive_amount(dynamic raw);
+  EventFilter? dco_decode_opt_box_autoadd_event_filter(dynamic raw);
 
   @protected
-  SuccessAction? dco_decode_opt_box_autoadd_success_action(dynamic raw);
+  EventWebhookConfig? dco_decode_opt_box_autoadd_event_webhook_config(dynamic raw);
 
   @protected
-  SuccessActionProcessed? dco_decode_opt_box_autoadd_success_action_processed(dynamic raw);
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
   @protected
-  Symbol? dco_decode_opt_box_autoadd_symbol(dynamic raw);
+  FeeBreakdown? dco_decode_opt_box_autoadd_fee_breakdown(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  FeeSpeed? dco_decode_opt_box_autoadd_fee_speed(dynamic raw);
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+  FiatAmount? dco_decode_opt_box_autoadd_fiat_amount(dynamic raw);
 
   @protected
-  List<AssetMetadata>? dco_decode_opt_list_asset_metadata(dynamic raw);
+  FiatConversion? dco_decode_opt_box_autoadd_fiat_conversion(dynamic raw);
 
   @protected
-  List<ExternalInputParser>? dco_decode_opt_list_external_input_parser(dynamic raw);
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
 
   @protected
-  List<PaymentState>? dco_decode_opt_list_payment_state(dynamic raw);
+  InputType? dco_decode_opt_box_autoadd_input_type(dynamic raw);
 
   @protected
-  List<PaymentType>? dco_decode_opt_list_payment_type(dynamic raw);
+  LightningAddressInfo? dco_decode_opt_box_autoadd_lightning_address_info(dynamic raw);
 
   @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+  ListPaymentDetails? dco_decode_opt_box_autoadd_list_payment_details(dynamic raw);
 
   @protected
-  PayAmount dco_decode_pay_amount(dynamic raw);
+  LnUrlInfo? dco_decode_opt_box_autoadd_ln_url_info(dynamic raw);
 
   @protected
-  PayOnchainRequest dco_decode_pay_onchain_request(dynamic raw);
+  LnUrlPayerData? dco_decode_opt_box_autoadd_ln_url_payer_data(dynamic raw);
 
   @protected
-  Payment dco_decode_payment(dynamic raw);
+  NwcBudget? dco_decode_opt_box_autoadd_nwc_budget(dynamic raw);
 
   @protected
-  PaymentDetails dco_decode_payment_details(dynamic raw);
+  PayAmount? dco_decode_opt_box_autoadd_pay_amount(dynamic raw);
 
   @protected
-  PaymentError dco_decode_payment_error(dynamic raw);
+  Payment? dco_decode_opt_box_autoadd_payment(dynamic raw);
 
   @protected
-  PaymentMethod dco_decode_payment_method(dynamic raw);
+  ReceiveAmount? dco_decode_opt_box_autoadd_receive_amount(dynamic raw);
 
   @protected
-  PaymentState dco_decode_payment_state(dynamic raw);
+  RegisteredAsset? dco_decode_opt_box_autoadd_registered_asset(dynamic raw);
 
   @protected
-  PaymentType dco_decode_payment_type(dynamic raw);
+  SimulationConfig? dco_decode_opt_box_autoadd_simulation_config(dynamic raw);
 
   @protected
-  PrepareBuyBitcoinRequest dco_decode_prepare_buy_bitcoin_request(dynamic raw);
+  SpendLimit? dco_decode_opt_box_autoadd_spend_limit(dynamic raw);
 
   @protected
-  PrepareBuyBitcoinResponse dco_decode_prepare_buy_bitcoin_response(dynamic raw);
+  SpendLimits? dco_decode_opt_box_autoadd_spend_limits(dynamic raw);
 
   @protected
-  PrepareLnUrlPayRequest dco_decode_prepare_ln_url_pay_request(dynamic raw);
+  SuccessAction? dco_decode_opt_box_autoadd_success_action(dynamic raw);
 
   @protected
-  PrepareLnUrlPayResponse dco_decode_prepare_ln_url_pay_response(dynamic raw);
+  SuccessActionProcessed? dco_decode_opt_box_autoadd_success_action_processed(dynamic raw);
 
   @protected
-  PreparePayOnchainRequest dco_decode_prepare_pay_onchain_request(dynamic raw);
+  SwapServiceStatus? dco_decode_opt_box_autoadd_swap_service_status(dynamic raw);
 
   @protected
-  PreparePayOnchainResponse dco_decode_prepare_pay_onchain_response(dynamic raw);
+  Symbol? dco_decode_opt_box_autoadd_symbol(dynamic raw);
+
+  @protected
+  SyncRequest? dco_decode_opt_box_autoadd_sync_request(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  List<AssetMetadata>? dco_decode_opt_list_asset_metadata(dynamic raw);
+
+  @protected
+  List<ExternalInputParser>? dco_decode_opt_list_external_input_parser(dynamic raw);
+
+  @protected
+  List<PaymentState>? dco_decode_opt_list_payment_state(dynamic raw);
+
+  @protected
+  List<PaymentSwapType>? dco_decode_opt_list_payment_swap_type(dynamic raw);
+
+  @protected
+  List<PaymentType>? dco_decode_opt_list_payment_type(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<SdkEventKind>? dco_decode_opt_list_sdk_event_kind(dynamic raw);
+
+  @protected
+  PayAmount dco_decode_pay_amount(dynamic raw);
+
+  @protected
+  PayOnchainRequest dco_decode_pay_onchain_request(dynamic raw);
+
+  @protected
+  Payment dco_decode_payment(dynamic raw);
+
+  @protected
+  PaymentDetails dco_decode_payment_details(dynamic raw);
+
+  @protected
+  PaymentError dco_decode_payment_error(dynamic raw);
+
+  @protected
+  PaymentMethod dco_decode_payment_method(dynamic raw);
+
+  @protected
+  PaymentState dco_decode_payment_state(dynamic raw);
+
+  @protected
+  PaymentSwapType dco_decode_payment_swap_type(dynamic raw);
+
+  @protected
+  PaymentType dco_decode_payment_type(dynamic raw);
+
+  @protected
+  PaymentUnblindingData dco_decode_payment_unblinding_data(dynamic raw);
+
+  @protected
+  PrepareBuyBitcoinRequest dco_decode_prepare_buy_bitcoin_request(dynamic raw);
+
+  @protected
+  PrepareBuyBitcoinResponse dco_decode_prepare_buy_bitcoin_response(dynamic raw);
+
+  @protected
+  PrepareConvertRequest dco_decode_prepare_convert_request(dynamic raw);
+
+  @protected
+  PrepareConvertResponse dco_decode_prepare_convert_response(dynamic raw);
+
+  @protected
+  PrepareLnUrlPayRequest dco_decode_prepare_ln_url_pay_request(dynamic raw);
+
+  @protected
+  PrepareLnUrlPayResponse dco_decode_prepare_ln_url_pay_response(dynamic raw);
+
+  @protected
+  PreparePayOnchainRequest dco_decode_prepare_pay_onchain_request(dynamic raw);
+
+  @protected
+  PreparePayOnchainResponse dco_decode_prepare_pay_onchain_response(dynamic raw);
+
+  @protected
+  PrepareReceiveQuotesRequest dco_decode_prepare_receive_quotes_request(dynamic raw);
+
+  @protected
+  PrepareReceiveQuotesResponse dco_decode_prepare_receive_quotes_response(dynamic raw);
 
   @protected
   PrepareReceiveRequest dco_decode_prepare_receive_request(dynamic raw);
@@ -575,6 +982,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareSendResponse dco_decode_prepare_send_response(dynamic raw);
 
+  @protected
+  ProcessNotificationRequest dco_decode_process_notification_request(dynamic raw);
+
+  @protected
+  ProcessNotificationResponse dco_decode_process_notification_response(dynamic raw);
+
+  @protected
+  PsetSpendLimit dco_decode_pset_spend_limit(dynamic raw);
+
+  @protected
+  QueuePaymentRequest dco_decode_queue_payment_request(dynamic raw);
+
+  @protected
+  QueuedPayment dco_decode_queued_payment(dynamic raw);
+
+  @protected
+  QueuedPaymentState dco_decode_queued_payment_state(dynamic raw);
+
   @protected
   Rate dco_decode_rate(dynamic raw);
 
@@ -590,6 +1015,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RecommendedFees dco_decode_recommended_fees(dynamic raw);
 
+  @protected
+  RecurringPayment dco_decode_recurring_payment(dynamic raw);
+
+  @protected
+  RecurringPaymentOccurrence dco_decode_recurring_payment_occurrence(dynamic raw);
+
   @protected
   RefundRequest dco_decode_refund_request(dynamic raw);
 
@@ -599,21 +1030,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RefundableSwap dco_decode_refundable_swap(dynamic raw);
 
+  @protected
+  RegisterLightningAddressRequest dco_decode_register_lightning_address_request(dynamic raw);
+
+  @protected
+  RegisteredAsset dco_decode_registered_asset(dynamic raw);
+
+  @protected
+  ReplayEventsRequest dco_decode_replay_events_request(dynamic raw);
+
   @protected
   RestoreRequest dco_decode_restore_request(dynamic raw);
 
+  @protected
+  ReusableBitcoinAddressResponse dco_decode_reusable_bitcoin_address_response(dynamic raw);
+
   @protected
   RouteHint dco_decode_route_hint(dynamic raw);
 
   @protected
   RouteHintHop dco_decode_route_hint_hop(dynamic raw);
 
+  @protected
+  SchedulePaymentRequest dco_decode_schedule_payment_request(dynamic raw);
+
+  @protected
+  ScheduledPayment dco_decode_scheduled_payment(dynamic raw);
+
+  @protected
+  ScheduledPaymentState dco_decode_scheduled_payment_state(dynamic raw);
+
   @protected
   SdkError dco_decode_sdk_error(dynamic raw);
 
   @protected
   SdkEvent dco_decode_sdk_event(dynamic raw);
 
+  @protected
+  SdkEventKind dco_decode_sdk_event_kind(dynamic raw);
+
+  @protected
+  SendBatchRequest dco_decode_send_batch_request(dynamic raw);
+
+  @protected
+  SendBatchResponse dco_decode_send_batch_response(dynamic raw);
+
   @protected
   SendDestination dco_decode_send_destination(dynamic raw);
 
@@ -623,21 +1084,57 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SendPaymentResponse dco_decode_send_payment_response(dynamic raw);
 
+  @protected
+  ServiceHealth dco_decode_service_health(dynamic raw);
+
   @protected
   SignMessageRequest dco_decode_sign_message_request(dynamic raw);
 
   @protected
   SignMessageResponse dco_decode_sign_message_response(dynamic raw);
 
+  @protected
+  SignPsetRequest dco_decode_sign_pset_request(dynamic raw);
+
+  @protected
+  SignPsetResponse dco_decode_sign_pset_response(dynamic raw);
+
+  @protected
+  SimulationConfig dco_decode_simulation_config(dynamic raw);
+
+  @protected
+  SpendLimit dco_decode_spend_limit(dynamic raw);
+
+  @protected
+  SpendLimits dco_decode_spend_limits(dynamic raw);
+
   @protected
   SuccessAction dco_decode_success_action(dynamic raw);
 
   @protected
   SuccessActionProcessed dco_decode_success_action_processed(dynamic raw);
 
+  @protected
+  SwapInfo dco_decode_swap_info(dynamic raw);
+
+  @protected
+  SwapServiceStatus dco_decode_swap_service_status(dynamic raw);
+
+  @protected
+  SweepPrivateKeyRequest dco_decode_sweep_private_key_request(dynamic raw);
+
+  @protected
+  SweepPrivateKeyResponse dco_decode_sweep_private_key_response(dynamic raw);
+
   @protected
   Symbol dco_decode_symbol(dynamic raw);
 
+  @protected
+  SyncRequest dco_decode_sync_request(dynamic raw);
+
+  @protected
+  SyncResponse dco_decode_sync_response(dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -650,6 +1147,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int dco_decode_u_8(dynamic raw);
 
+  @protected
+  UnblindedTxOut dco_decode_unblinded_tx_out(dynamic raw);
+
   @protected
   void dco_decode_unit(dynamic raw);
 
@@ -659,6 +1159,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  ValidateAddressResponse dco_decode_validate_address_response(dynamic raw);
+
   @protected
   WalletInfo dco_decode_wallet_info(dynamic raw);
 
@@ -677,6 +1180,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
   @protected
   BindingLiquidSdk sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     SseDeserializer deserializer,
@@ -696,6 +1202,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  AddNwcConnectionRequest sse_decode_add_nwc_connection_request(SseDeserializer deserializer);
+
+  @protected
+  AddNwcConnectionResponse sse_decode_add_nwc_connection_response(SseDeserializer deserializer);
+
+  @protected
+  AddressType sse_decode_address_type(SseDeserializer deserializer);
+
   @protected
   AesSuccessActionData sse_decode_aes_success_action_data(SseDeserializer deserializer);
 
@@ -708,24 +1223,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Amount sse_decode_amount(SseDeserializer deserializer);
 
+  @protected
+  AmpAccount sse_decode_amp_account(SseDeserializer deserializer);
+
+  @protected
+  AssetAmount sse_decode_asset_amount(SseDeserializer deserializer);
+
   @protected
   AssetBalance sse_decode_asset_balance(SseDeserializer deserializer);
 
+  @protected
+  AssetConversion sse_decode_asset_conversion(SseDeserializer deserializer);
+
   @protected
   AssetInfo sse_decode_asset_info(SseDeserializer deserializer);
 
   @protected
   AssetMetadata sse_decode_asset_metadata(SseDeserializer deserializer);
 
+  @protected
+  AssetReport sse_decode_asset_report(SseDeserializer deserializer);
+
+  @protected
+  BackupPolicy sse_decode_backup_policy(SseDeserializer deserializer);
+
   @protected
   BackupRequest sse_decode_backup_request(SseDeserializer deserializer);
 
   @protected
-  BindingEventListener sse_decode_binding_event_listener(SseDeserializer deserializer);
+  BatchSendItem sse_decode_batch_send_item(SseDeserializer deserializer);
+
+  @protected
+  BatchSendItemResult sse_decode_batch_send_item_result(SseDeserializer deserializer);
 
   @protected
   BitcoinAddressData sse_decode_bitcoin_address_data(SseDeserializer deserializer);
 
+  @protected
+  BlockInfo sse_decode_block_info(SseDeserializer deserializer);
+
   @protected
   BlockchainExplorer sse_decode_blockchain_explorer(SseDeserializer deserializer);
 
@@ -740,6 +1276,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  AddNwcConnectionRequest sse_decode_box_autoadd_add_nwc_connection_request(SseDeserializer deserializer);
+
   @protected
   AesSuccessActionData sse_decode_box_autoadd_aes_success_action_data(SseDeserializer deserializer);
 
@@ -756,21 +1295,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Amount sse_decode_box_autoadd_amount(SseDeserializer deserializer);
 
+  @protected
+  AmpAccount sse_decode_box_autoadd_amp_account(SseDeserializer deserializer);
+
+  @protected
+  AssetAmount sse_decode_box_autoadd_asset_amount(SseDeserializer deserializer);
+
+  @protected
+  AssetConversion sse_decode_box_autoadd_asset_conversion(SseDeserializer deserializer);
+
   @protected
   AssetInfo sse_decode_box_autoadd_asset_info(SseDeserializer deserializer);
 
   @protected
-  BackupRequest sse_decode_box_autoadd_backup_request(SseDeserializer deserializer);
+  BackupPolicy sse_decode_box_autoadd_backup_policy(SseDeserializer deserializer);
 
   @protected
-  BindingEventListener sse_decode_box_autoadd_binding_event_listener(SseDeserializer deserializer);
+  BackupRequest sse_decode_box_autoadd_backup_request(SseDeserializer deserializer);
 
   @protected
   BitcoinAddressData sse_decode_box_autoadd_bitcoin_address_data(SseDeserializer deserializer);
 
+  @protected
+  BlockInfo sse_decode_box_autoadd_block_info(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  BuyBitcoinProvider sse_decode_box_autoadd_buy_bitcoin_provider(SseDeserializer deserializer);
+
   @protected
   BuyBitcoinRequest sse_decode_box_autoadd_buy_bitcoin_request(SseDeserializer deserializer);
 
@@ -780,20 +1334,75 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConnectRequest sse_decode_box_autoadd_connect_request(SseDeserializer deserializer);
 
+  @protected
+  ConvertRequest sse_decode_box_autoadd_convert_request(SseDeserializer deserializer);
+
+  @protected
+  CreateRecurringPaymentRequest sse_decode_box_autoadd_create_recurring_payment_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Direction sse_decode_box_autoadd_direction(SseDeserializer deserializer);
+
+  @protected
+  EventFilter sse_decode_box_autoadd_event_filter(SseDeserializer deserializer);
+
+  @protected
+  EventWebhookConfig sse_decode_box_autoadd_event_webhook_config(SseDeserializer deserializer);
+
+  @protected
+  ExportPaymentUnblindingDataRequest sse_decode_box_autoadd_export_payment_unblinding_data_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExportWalletRequest sse_decode_box_autoadd_export_wallet_request(SseDeserializer deserializer);
+
   @protected
   double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  FeeBreakdown sse_decode_box_autoadd_fee_breakdown(SseDeserializer deserializer);
+
+  @protected
+  FeeSpeed sse_decode_box_autoadd_fee_speed(SseDeserializer deserializer);
+
   @protected
   FetchPaymentProposedFeesRequest sse_decode_box_autoadd_fetch_payment_proposed_fees_request(
     SseDeserializer deserializer,
   );
 
+  @protected
+  FiatAmount sse_decode_box_autoadd_fiat_amount(SseDeserializer deserializer);
+
+  @protected
+  FiatConversion sse_decode_box_autoadd_fiat_conversion(SseDeserializer deserializer);
+
+  @protected
+  GetMaxPayableRequest sse_decode_box_autoadd_get_max_payable_request(SseDeserializer deserializer);
+
+  @protected
+  GetMaxReceivableRequest sse_decode_box_autoadd_get_max_receivable_request(SseDeserializer deserializer);
+
   @protected
   GetPaymentRequest sse_decode_box_autoadd_get_payment_request(SseDeserializer deserializer);
 
+  @protected
+  GetReportRequest sse_decode_box_autoadd_get_report_request(SseDeserializer deserializer);
+
   @protected
   PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  ImportWalletRequest sse_decode_box_autoadd_import_wallet_request(SseDeserializer deserializer);
+
+  @protected
+  InputType sse_decode_box_autoadd_input_type(SseDeserializer deserializer);
+
+  @protected
+  LightningAddressInfo sse_decode_box_autoadd_lightning_address_info(SseDeserializer deserializer);
+
   @protected
   LiquidAddressData sse_decode_box_autoadd_liquid_address_data(SseDeserializer deserializer);
 
@@ -803,6 +1412,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ListPaymentsRequest sse_decode_box_autoadd_list_payments_request(SseDeserializer deserializer);
 
+  @protected
+  ListSwapsRequest sse_decode_box_autoadd_list_swaps_request(SseDeserializer deserializer);
+
+  @protected
+  LNBolt12Invoice sse_decode_box_autoadd_ln_bolt_12_invoice(SseDeserializer deserializer);
+
   @protected
   LNInvoice sse_decode_box_autoadd_ln_invoice(SseDeserializer deserializer);
 
@@ -830,6 +1445,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LnUrlPaySuccessData sse_decode_box_autoadd_ln_url_pay_success_data(SseDeserializer deserializer);
 
+  @protected
+  LnUrlPayerData sse_decode_box_autoadd_ln_url_payer_data(SseDeserializer deserializer);
+
   @protected
   LnUrlWithdrawRequest sse_decode_box_autoadd_ln_url_withdraw_request(SseDeserializer deserializer);
 
@@ -842,6 +1460,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   MessageSuccessActionData sse_decode_box_autoadd_message_success_action_data(SseDeserializer deserializer);
 
+  @protected
+  NwcBudget sse_decode_box_autoadd_nwc_budget(SseDeserializer deserializer);
+
   @protected
   PayAmount sse_decode_box_autoadd_pay_amount(SseDeserializer deserializer);
 
@@ -854,12 +1475,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareBuyBitcoinRequest sse_decode_box_autoadd_prepare_buy_bitcoin_request(SseDeserializer deserializer);
 
+  @protected
+  PrepareConvertRequest sse_decode_box_autoadd_prepare_convert_request(SseDeserializer deserializer);
+
   @protected
   PrepareLnUrlPayRequest sse_decode_box_autoadd_prepare_ln_url_pay_request(SseDeserializer deserializer);
 
   @protected
   PreparePayOnchainRequest sse_decode_box_autoadd_prepare_pay_onchain_request(SseDeserializer deserializer);
 
+  @protected
+  PrepareReceiveQuotesRequest sse_decode_box_autoadd_prepare_receive_quotes_request(
+    SseDeserializer deserializer,
+  );
+
   @protected
   PrepareReceiveRequest sse_decode_box_autoadd_prepare_receive_request(SseDeserializer deserializer);
 
@@ -869,6 +1498,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareSendRequest sse_decode_box_autoadd_prepare_send_request(SseDeserializer deserializer);
 
+  @protected
+  ProcessNotificationRequest sse_decode_box_autoadd_process_notification_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QueuePaymentRequest sse_decode_box_autoadd_queue_payment_request(SseDeserializer deserializer);
+
   @protected
   ReceiveAmount sse_decode_box_autoadd_receive_amount(SseDeserializer deserializer);
 
@@ -879,34 +1516,69 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RefundRequest sse_decode_box_autoadd_refund_request(SseDeserializer deserializer);
 
   @protected
-  RestoreRequest sse_decode_box_autoadd_restore_request(SseDeserializer deserializer);
+  RegisterLightningAddressRequest sse_decode_box_autoadd_register_lightning_address_request(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  SdkEvent sse_decode_box_autoadd_sdk_event(SseDeserializer deserializer);
+  RegisteredAsset sse_decode_box_autoadd_registered_asset(SseDeserializer deserializer);
 
   @protected
-  SendPaymentRequest sse_decode_box_autoadd_send_payment_request(SseDeserializer deserializer);
+  ReplayEventsRequest sse_decode_box_autoadd_replay_events_request(SseDeserializer deserializer);
 
   @protected
-  SignMessageRequest sse_decode_box_autoadd_sign_message_request(SseDeserializer deserializer);
+  RestoreRequest sse_decode_box_autoadd_restore_request(SseDeserializer deserializer);
 
   @protected
-  SuccessAction sse_decode_box_autoadd_success_action(SseDeserializer deserializer);
+  SchedulePaymentRequest sse_decode_box_autoadd_schedule_payment_request(SseDeserializer deserializer);
 
   @protected
-  SuccessActionProcessed sse_decode_box_autoadd_success_action_processed(SseDeserializer deserializer);
+  SendBatchRequest sse_decode_box_autoadd_send_batch_request(SseDeserializer deserializer);
 
   @protected
-  Symbol sse_decode_box_autoadd_symbol(SseDeserializer deserializer);
+  SendPaymentRequest sse_decode_box_autoadd_send_payment_request(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  SignMessageRequest sse_decode_box_autoadd_sign_message_request(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+  SignPsetRequest sse_decode_box_autoadd_sign_pset_request(SseDeserializer deserializer);
 
   @protected
-  UrlSuccessActionData sse_decode_box_autoadd_url_success_action_data(SseDeserializer deserializer);
+  SimulationConfig sse_decode_box_autoadd_simulation_config(SseDeserializer deserializer);
+
+  @protected
+  SpendLimit sse_decode_box_autoadd_spend_limit(SseDeserializer deserializer);
+
+  @protected
+  SpendLimits sse_decode_box_autoadd_spend_limits(SseDeserializer deserializer);
+
+  @protected
+  SuccessAction sse_decode_box_autoadd_success_action(SseDeserializer deserializer);
+
+  @protected
+  SuccessActionProcessed sse_decode_box_autoadd_success_action_processed(SseDeserializer deserializer);
+
+  @protected
+  SwapServiceStatus sse_decode_box_autoadd_swap_service_status(SseDeserializer deserializer);
+
+  @protected
+  SweepPrivateKeyRequest sse_decode_box_autoadd_sweep_private_key_request(SseDeserializer deserializer);
+
+  @protected
+  Symbol sse_decode_box_autoadd_symbol(SseDeserializer deserializer);
+
+  @protected
+  SyncRequest sse_decode_box_autoadd_sync_request(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  UrlSuccessActionData sse_decode_box_autoadd_url_success_action_data(SseDeserializer deserializer);
 
   @protected
   BuyBitcoinProvider sse_decode_buy_bitcoin_provider(SseDeserializer deserializer);
@@ -914,6 +1586,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BuyBitcoinRequest sse_decode_buy_bitcoin_request(SseDeserializer deserializer);
 
+  @protected
+  CheckHealthResponse sse_decode_check_health_response(SseDeserializer deserializer);
+
   @protected
   CheckMessageRequest sse_decode_check_message_request(SseDeserializer deserializer);
 
@@ -926,15 +1601,47 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConnectRequest sse_decode_connect_request(SseDeserializer deserializer);
 
+  @protected
+  ConvertRequest sse_decode_convert_request(SseDeserializer deserializer);
+
+  @protected
+  CreateRecurringPaymentRequest sse_decode_create_recurring_payment_request(SseDeserializer deserializer);
+
   @protected
   CurrencyInfo sse_decode_currency_info(SseDeserializer deserializer);
 
+  @protected
+  DecodedInvoice sse_decode_decoded_invoice(SseDeserializer deserializer);
+
+  @protected
+  Direction sse_decode_direction(SseDeserializer deserializer);
+
+  @protected
+  EventFilter sse_decode_event_filter(SseDeserializer deserializer);
+
+  @protected
+  EventWebhookConfig sse_decode_event_webhook_config(SseDeserializer deserializer);
+
+  @protected
+  ExportPaymentUnblindingDataRequest sse_decode_export_payment_unblinding_data_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExportWalletRequest sse_decode_export_wallet_request(SseDeserializer deserializer);
+
   @protected
   ExternalInputParser sse_decode_external_input_parser(SseDeserializer deserializer);
 
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  FeeBreakdown sse_decode_fee_breakdown(SseDeserializer deserializer);
+
+  @protected
+  FeeSpeed sse_decode_fee_speed(SseDeserializer deserializer);
+
   @protected
   FetchPaymentProposedFeesRequest sse_decode_fetch_payment_proposed_fees_request(
     SseDeserializer deserializer,
@@ -945,24 +1652,69 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  FiatAmount sse_decode_fiat_amount(SseDeserializer deserializer);
+
+  @protected
+  FiatConversion sse_decode_fiat_conversion(SseDeserializer deserializer);
+
   @protected
   FiatCurrency sse_decode_fiat_currency(SseDeserializer deserializer);
 
   @protected
   GetInfoResponse sse_decode_get_info_response(SseDeserializer deserializer);
 
+  @protected
+  GetMaxPayableRequest sse_decode_get_max_payable_request(SseDeserializer deserializer);
+
+  @protected
+  GetMaxPayableResponse sse_decode_get_max_payable_response(SseDeserializer deserializer);
+
+  @protected
+  GetMaxReceivableRequest sse_decode_get_max_receivable_request(SseDeserializer deserializer);
+
+  @protected
+  GetMaxReceivableResponse sse_decode_get_max_receivable_response(SseDeserializer deserializer);
+
   @protected
   GetPaymentRequest sse_decode_get_payment_request(SseDeserializer deserializer);
 
+  @protected
+  GetReportRequest sse_decode_get_report_request(SseDeserializer deserializer);
+
+  @protected
+  GetReportResponse sse_decode_get_report_response(SseDeserializer deserializer);
+
+  @protected
+  GetWalletInfoResponse sse_decode_get_wallet_info_response(SseDeserializer deserializer);
+
+  @protected
+  HealthCheckService sse_decode_health_check_service(SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
   @protected
   PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
+  @protected
+  ImportWalletRequest sse_decode_import_wallet_request(SseDeserializer deserializer);
+
+  @protected
+  ImportWalletResponse sse_decode_import_wallet_response(SseDeserializer deserializer);
+
   @protected
   InputType sse_decode_input_type(SseDeserializer deserializer);
 
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+  @protected
+  JournaledEvent sse_decode_journaled_event(SseDeserializer deserializer);
+
+  @protected
+  LightningAddressInfo sse_decode_lightning_address_info(SseDeserializer deserializer);
+
   @protected
   LightningPaymentLimitsResponse sse_decode_lightning_payment_limits_response(SseDeserializer deserializer);
 
@@ -978,18 +1730,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<AssetAmount> sse_decode_list_asset_amount(SseDeserializer deserializer);
+
   @protected
   List<AssetBalance> sse_decode_list_asset_balance(SseDeserializer deserializer);
 
   @protected
   List<AssetMetadata> sse_decode_list_asset_metadata(SseDeserializer deserializer);
 
+  @protected
+  List<AssetReport> sse_decode_list_asset_report(SseDeserializer deserializer);
+
+  @protected
+  List<BatchSendItem> sse_decode_list_batch_send_item(SseDeserializer deserializer);
+
+  @protected
+  List<BatchSendItemResult> sse_decode_list_batch_send_item_result(SseDeserializer deserializer);
+
   @protected
   List<ExternalInputParser> sse_decode_list_external_input_parser(SseDeserializer deserializer);
 
   @protected
   List<FiatCurrency> sse_decode_list_fiat_currency(SseDeserializer deserializer);
 
+  @protected
+  List<JournaledEvent> sse_decode_list_journaled_event(SseDeserializer deserializer);
+
   @protected
   List<LnOfferBlindedPath> sse_decode_list_ln_offer_blinded_path(SseDeserializer deserializer);
 
@@ -999,6 +1766,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<LocalizedName> sse_decode_list_localized_name(SseDeserializer deserializer);
 
+  @protected
+  List<NwcConnection> sse_decode_list_nwc_connection(SseDeserializer deserializer);
+
   @protected
   List<Payment> sse_decode_list_payment(SseDeserializer deserializer);
 
@@ -1008,18 +1778,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<PaymentState> sse_decode_list_payment_state(SseDeserializer deserializer);
 
+  @protected
+  List<PaymentSwapType> sse_decode_list_payment_swap_type(SseDeserializer deserializer);
+
   @protected
   List<PaymentType> sse_decode_list_payment_type(SseDeserializer deserializer);
 
   @protected
   ListPaymentsRequest sse_decode_list_payments_request(SseDeserializer deserializer);
 
+  @protected
+  List<PrepareReceiveResponse> sse_decode_list_prepare_receive_response(SseDeserializer deserializer);
+
   @protected
   Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<PsetSpendLimit> sse_decode_list_pset_spend_limit(SseDeserializer deserializer);
+
+  @protected
+  List<QueuedPayment> sse_decode_list_queued_payment(SseDeserializer deserializer);
+
   @protected
   List<Rate> sse_decode_list_rate(SseDeserializer deserializer);
 
+  @protected
+  List<RecurringPayment> sse_decode_list_recurring_payment(SseDeserializer deserializer);
+
+  @protected
+  List<RecurringPaymentOccurrence> sse_decode_list_recurring_payment_occurrence(SseDeserializer deserializer);
+
   @protected
   List<RefundableSwap> sse_decode_list_refundable_swap(SseDeserializer deserializer);
 
@@ -1029,6 +1817,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<RouteHintHop> sse_decode_list_route_hint_hop(SseDeserializer deserializer);
 
+  @protected
+  List<ScheduledPayment> sse_decode_list_scheduled_payment(SseDeserializer deserializer);
+
+  @protected
+  List<SdkEventKind> sse_decode_list_sdk_event_kind(SseDeserializer deserializer);
+
+  @protected
+  List<ServiceHealth> sse_decode_list_service_health(SseDeserializer deserializer);
+
+  @protected
+  List<SwapInfo> sse_decode_list_swap_info(SseDeserializer deserializer);
+
+  @protected
+  ListSwapsRequest sse_decode_list_swaps_request(SseDeserializer deserializer);
+
+  @protected
+  List<UnblindedTxOut> sse_decode_list_unblinded_tx_out(SseDeserializer deserializer);
+
+  @protected
+  LNBolt12Invoice sse_decode_ln_bolt_12_invoice(SseDeserializer deserializer);
+
   @protected
   LNInvoice sse_decode_ln_invoice(SseDeserializer deserializer);
 
@@ -1071,6 +1880,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LnUrlPaySuccessData sse_decode_ln_url_pay_success_data(SseDeserializer deserializer);
 
+  @protected
+  LnUrlPayerData sse_decode_ln_url_payer_data(SseDeserializer deserializer);
+
   @protected
   LnUrlWithdrawError sse_decode_ln_url_withdraw_error(SseDeserializer deserializer);
 
@@ -1101,6 +1913,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Network sse_decode_network(SseDeserializer deserializer);
 
+  @protected
+  NwcBudget sse_decode_nwc_budget(SseDeserializer deserializer);
+
+  @protected
+  NwcConnection sse_decode_nwc_connection(SseDeserializer deserializer);
+
   @protected
   OnchainPaymentLimitsResponse sse_decode_onchain_payment_limits_response(SseDeserializer deserializer);
 
@@ -1110,24 +1928,72 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   Amount? sse_decode_opt_box_autoadd_amount(SseDeserializer deserializer);
 
+  @protected
+  AmpAccount? sse_decode_opt_box_autoadd_amp_account(SseDeserializer deserializer);
+
+  @protected
+  AssetAmount? sse_decode_opt_box_autoadd_asset_amount(SseDeserializer deserializer);
+
+  @protected
+  AssetConversion? sse_decode_opt_box_autoadd_asset_conversion(SseDeserializer deserializer);
+
   @protected
   AssetInfo? sse_decode_opt_box_autoadd_asset_info(SseDeserializer deserializer);
 
+  @protected
+  BackupPolicy? sse_decode_opt_box_autoadd_backup_policy(SseDeserializer deserializer);
+
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
 
+  @protected
+  BuyBitcoinProvider? sse_decode_opt_box_autoadd_buy_bitcoin_provider(SseDeserializer deserializer);
+
+  @protected
+  Direction? sse_decode_opt_box_autoadd_direction(SseDeserializer deserializer);
+
+  @protected
+  EventFilter? sse_decode_opt_box_autoadd_event_filter(SseDeserializer deserializer);
+
+  @protected
+  EventWebhookConfig? sse_decode_opt_box_autoadd_event_webhook_config(SseDeserializer deserializer);
+
   @protected
   double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
 
+  @protected
+  FeeBreakdown? sse_decode_opt_box_autoadd_fee_breakdown(SseDeserializer deserializer);
+
+  @protected
+  FeeSpeed? sse_decode_opt_box_autoadd_fee_speed(SseDeserializer deserializer);
+
+  @protected
+  FiatAmount? sse_decode_opt_box_autoadd_fiat_amount(SseDeserializer deserializer);
+
+  @protected
+  FiatConversion? sse_decode_opt_box_autoadd_fiat_conversion(SseDeserializer deserializer);
+
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
 
+  @protected
+  InputType? sse_decode_opt_box_autoadd_input_type(SseDeserializer deserializer);
+
+  @protected
+  LightningAddressInfo? sse_decode_opt_box_autoadd_lightning_address_info(SseDeserializer deserializer);
+
   @protected
   ListPaymentDetails? sse_decode_opt_box_autoadd_list_payment_details(SseDeserializer deserializer);
 
   @protected
   LnUrlInfo? sse_decode_opt_box_autoadd_ln_url_info(SseDeserializer deserializer);
 
+  @protected
+  LnUrlPayerData? sse_decode_opt_box_autoadd_ln_url_payer_data(SseDeserializer deserializer);
+
+  @protected
+  NwcBudget? sse_decode_opt_box_autoadd_nwc_budget(SseDeserializer deserializer);
+
   @protected
   PayAmount? sse_decode_opt_box_autoadd_pay_amount(SseDeserializer deserializer);
 
@@ -1137,21 +2003,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ReceiveAmount? sse_decode_opt_box_autoadd_receive_amount(SseDeserializer deserializer);
 
+  @protected
+  RegisteredAsset? sse_decode_opt_box_autoadd_registered_asset(SseDeserializer deserializer);
+
+  @protected
+  SimulationConfig? sse_decode_opt_box_autoadd_simulation_config(SseDeserializer deserializer);
+
+  @protected
+  SpendLimit? sse_decode_opt_box_autoadd_spend_limit(SseDeserializer deserializer);
+
+  @protected
+  SpendLimits? sse_decode_opt_box_autoadd_spend_limits(SseDeserializer deserializer);
+
   @protected
   SuccessAction? sse_decode_opt_box_autoadd_success_action(SseDeserializer deserializer);
 
   @protected
   SuccessActionProcessed? sse_decode_opt_box_autoadd_success_action_processed(SseDeserializer deserializer);
 
+  @protected
+  SwapServiceStatus? sse_decode_opt_box_autoadd_swap_service_status(SseDeserializer deserializer);
+
   @protected
   Symbol? sse_decode_opt_box_autoadd_symbol(SseDeserializer deserializer);
 
+  @protected
+  SyncRequest? sse_decode_opt_box_autoadd_sync_request(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
   @protected
   List<AssetMetadata>? sse_decode_opt_list_asset_metadata(SseDeserializer deserializer);
 
@@ -1161,12 +2048,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<PaymentState>? sse_decode_opt_list_payment_state(SseDeserializer deserializer);
 
+  @protected
+  List<PaymentSwapType>? sse_decode_opt_list_payment_swap_type(SseDeserializer deserializer);
+
   @protected
   List<PaymentType>? sse_decode_opt_list_payment_type(SseDeserializer deserializer);
 
   @protected
   Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
 
+  @protected
+  List<SdkEventKind>? sse_decode_opt_list_sdk_event_kind(SseDeserializer deserializer);
+
   @protected
   PayAmount sse_decode_pay_amount(SseDeserializer deserializer);
 
@@ -1188,15 +2081,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PaymentState sse_decode_payment_state(SseDeserializer deserializer);
 
+  @protected
+  PaymentSwapType sse_decode_payment_swap_type(SseDeserializer deserializer);
+
   @protected
   PaymentType sse_decode_payment_type(SseDeserializer deserializer);
 
+  @protected
+  PaymentUnblindingData sse_decode_payment_unblinding_data(SseDeserializer deserializer);
+
   @protected
   PrepareBuyBitcoinRequest sse_decode_prepare_buy_bitcoin_request(SseDeserializer deserializer);
 
   @protected
   PrepareBuyBitcoinResponse sse_decode_prepare_buy_bitcoin_response(SseDeserializer deserializer);
 
+  @protected
+  PrepareConvertRequest sse_decode_prepare_convert_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareConvertResponse sse_decode_prepare_convert_response(SseDeserializer deserializer);
+
   @protected
   PrepareLnUrlPayRequest sse_decode_prepare_ln_url_pay_request(SseDeserializer deserializer);
 
@@ -1209,6 +2114,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PreparePayOnchainResponse sse_decode_prepare_pay_onchain_response(SseDeserializer deserializer);
 
+  @protected
+  PrepareReceiveQuotesRequest sse_decode_prepare_receive_quotes_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareReceiveQuotesResponse sse_decode_prepare_receive_quotes_response(SseDeserializer deserializer);
+
   @protected
   PrepareReceiveRequest sse_decode_prepare_receive_request(SseDeserializer deserializer);
 
@@ -1227,6 +2138,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PrepareSendResponse sse_decode_prepare_send_response(SseDeserializer deserializer);
 
+  @protected
+  ProcessNotificationRequest sse_decode_process_notification_request(SseDeserializer deserializer);
+
+  @protected
+  ProcessNotificationResponse sse_decode_process_notification_response(SseDeserializer deserializer);
+
+  @protected
+  PsetSpendLimit sse_decode_pset_spend_limit(SseDeserializer deserializer);
+
+  @protected
+  QueuePaymentRequest sse_decode_queue_payment_request(SseDeserializer deserializer);
+
+  @protected
+  QueuedPayment sse_decode_queued_payment(SseDeserializer deserializer);
+
+  @protected
+  QueuedPaymentState sse_decode_queued_payment_state(SseDeserializer deserializer);
+
   @protected
   Rate sse_decode_rate(SseDeserializer deserializer);
 
@@ -1242,6 +2171,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RecommendedFees sse_decode_recommended_fees(SseDeserializer deserializer);
 
+  @protected
+  RecurringPayment sse_decode_recurring_payment(SseDeserializer deserializer);
+
+  @protected
+  RecurringPaymentOccurrence sse_decode_recurring_payment_occurrence(SseDeserializer deserializer);
+
   @protected
   RefundRequest sse_decode_refund_request(SseDeserializer deserializer);
 
@@ -1251,21 +2186,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RefundableSwap sse_decode_refundable_swap(SseDeserializer deserializer);
 
+  @protected
+  RegisterLightningAddressRequest sse_decode_register_lightning_address_request(SseDeserializer deserializer);
+
+  @protected
+  RegisteredAsset sse_decode_registered_asset(SseDeserializer deserializer);
+
+  @protected
+  ReplayEventsRequest sse_decode_replay_events_request(SseDeserializer deserializer);
+
   @protected
   RestoreRequest sse_decode_restore_request(SseDeserializer deserializer);
 
+  @protected
+  ReusableBitcoinAddressResponse sse_decode_reusable_bitcoin_address_response(SseDeserializer deserializer);
+
   @protected
   RouteHint sse_decode_route_hint(SseDeserializer deserializer);
 
   @protected
   RouteHintHop sse_decode_route_hint_hop(SseDeserializer deserializer);
 
+  @protected
+  SchedulePaymentRequest sse_decode_schedule_payment_request(SseDeserializer deserializer);
+
+  @protected
+  ScheduledPayment sse_decode_scheduled_payment(SseDeserializer deserializer);
+
+  @protected
+  ScheduledPaymentState sse_decode_scheduled_payment_state(SseDeserializer deserializer);
+
   @protected
   SdkError sse_decode_sdk_error(SseDeserializer deserializer);
 
   @protected
   SdkEvent sse_decode_sdk_event(SseDeserializer deserializer);
 
+  @protected
+  SdkEventKind sse_decode_sdk_event_kind(SseDeserializer deserializer);
+
+  @protected
+  SendBatchRequest sse_decode_send_batch_request(SseDeserializer deserializer);
+
+  @protected
+  SendBatchResponse sse_decode_send_batch_response(SseDeserializer deserializer);
+
   @protected
   SendDestination sse_decode_send_destination(SseDeserializer deserializer);
 
@@ -1275,21 +2240,57 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SendPaymentResponse sse_decode_send_payment_response(SseDeserializer deserializer);
 
+  @protected
+  ServiceHealth sse_decode_service_health(SseDeserializer deserializer);
+
   @protected
   SignMessageRequest sse_decode_sign_message_request(SseDeserializer deserializer);
 
   @protected
   SignMessageResponse sse_decode_sign_message_response(SseDeserializer deserializer);
 
+  @protected
+  SignPsetRequest sse_decode_sign_pset_request(SseDeserializer deserializer);
+
+  @protected
+  SignPsetResponse sse_decode_sign_pset_response(SseDeserializer deserializer);
+
+  @protected
+  SimulationConfig sse_decode_simulation_config(SseDeserializer deserializer);
+
+  @protected
+  SpendLimit sse_decode_spend_limit(SseDeserializer deserializer);
+
+  @protected
+  SpendLimits sse_decode_spend_limits(SseDeserializer deserializer);
+
   @protected
   SuccessAction sse_decode_success_action(SseDeserializer deserializer);
 
   @protected
   SuccessActionProcessed sse_decode_success_action_processed(SseDeserializer deserializer);
 
+  @protected
+  SwapInfo sse_decode_swap_info(SseDeserializer deserializer);
+
+  @protected
+  SwapServiceStatus sse_decode_swap_service_status(SseDeserializer deserializer);
+
+  @protected
+  SweepPrivateKeyRequest sse_decode_sweep_private_key_request(SseDeserializer deserializer);
+
+  @protected
+  SweepPrivateKeyResponse sse_decode_sweep_private_key_response(SseDeserializer deserializer);
+
   @protected
   Symbol sse_decode_symbol(SseDeserializer deserializer);
 
+  @protected
+  SyncRequest sse_decode_sync_request(SseDeserializer deserializer);
+
+  @protected
+  SyncResponse sse_decode_sync_response(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -1302,6 +2303,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int sse_decode_u_8(SseDeserializer deserializer);
 
+  @protected
+  UnblindedTxOut sse_decode_unblinded_tx_out(SseDeserializer deserializer);
+
   @protected
   void sse_decode_unit(SseDeserializer deserializer);
 
@@ -1311,6 +2315,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  ValidateAddressResponse sse_decode_validate_address_response(SseDeserializer deserializer);
+
   @protected
   WalletInfo sse_decode_wallet_info(SseDeserializer deserializer);
 
@@ -1360,12 +2367,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  ffi.Pointer<wire_cst_aes_success_action_data> cst_encode_box_autoadd_aes_success_action_data(
-    AesSuccessActionData raw,
+  ffi.Pointer<wire_cst_add_nwc_connection_request> cst_encode_box_autoadd_add_nwc_connection_request(
+    AddNwcConnectionRequest raw,
   ) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_aes_success_action_data();
-    cst_api_fill_to_wire_aes_success_action_data(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_add_nwc_connection_request();
+    cst_api_fill_to_wire_add_nwc_connection_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_aes_success_action_data> cst_encode_box_autoadd_aes_success_action_data(
+    AesSuccessActionData raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_aes_success_action_data();
+    cst_api_fill_to_wire_aes_success_action_data(raw, ptr.ref);
     return ptr;
   }
 
@@ -1396,6 +2413,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_amp_account> cst_encode_box_autoadd_amp_account(AmpAccount raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_amp_account();
+    cst_api_fill_to_wire_amp_account(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_asset_amount> cst_encode_box_autoadd_asset_amount(AssetAmount raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_asset_amount();
+    cst_api_fill_to_wire_asset_amount(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_asset_conversion> cst_encode_box_autoadd_asset_conversion(AssetConversion raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_asset_conversion();
+    cst_api_fill_to_wire_asset_conversion(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_asset_info> cst_encode_box_autoadd_asset_info(AssetInfo raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1405,20 +2446,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  ffi.Pointer<wire_cst_backup_request> cst_encode_box_autoadd_backup_request(BackupRequest raw) {
+  ffi.Pointer<wire_cst_backup_policy> cst_encode_box_autoadd_backup_policy(BackupPolicy raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_backup_request();
-    cst_api_fill_to_wire_backup_request(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_backup_policy();
+    cst_api_fill_to_wire_backup_policy(raw, ptr.ref);
     return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_binding_event_listener> cst_encode_box_autoadd_binding_event_listener(
-    BindingEventListener raw,
-  ) {
+  ffi.Pointer<wire_cst_backup_request> cst_encode_box_autoadd_backup_request(BackupRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_binding_event_listener();
-    cst_api_fill_to_wire_binding_event_listener(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_backup_request();
+    cst_api_fill_to_wire_backup_request(raw, ptr.ref);
     return ptr;
   }
 
@@ -1432,12 +2471,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_block_info> cst_encode_box_autoadd_block_info(BlockInfo raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_block_info();
+    cst_api_fill_to_wire_block_info(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<ffi.Bool> cst_encode_box_autoadd_bool(bool raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return wire.cst_new_box_autoadd_bool(cst_encode_bool(raw));
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_buy_bitcoin_provider(BuyBitcoinProvider raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_buy_bitcoin_provider(cst_encode_buy_bitcoin_provider(raw));
+  }
+
   @protected
   ffi.Pointer<wire_cst_buy_bitcoin_request> cst_encode_box_autoadd_buy_bitcoin_request(
     BuyBitcoinRequest raw,
@@ -1466,12 +2519,86 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_convert_request> cst_encode_box_autoadd_convert_request(ConvertRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_convert_request();
+    cst_api_fill_to_wire_convert_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_create_recurring_payment_request>
+  cst_encode_box_autoadd_create_recurring_payment_request(CreateRecurringPaymentRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_create_recurring_payment_request();
+    cst_api_fill_to_wire_create_recurring_payment_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_direction(Direction raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_direction(cst_encode_direction(raw));
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_event_filter> cst_encode_box_autoadd_event_filter(EventFilter raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_event_filter();
+    cst_api_fill_to_wire_event_filter(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_event_webhook_config> cst_encode_box_autoadd_event_webhook_config(
+    EventWebhookConfig raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_event_webhook_config();
+    cst_api_fill_to_wire_event_webhook_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_export_payment_unblinding_data_request>
+  cst_encode_box_autoadd_export_payment_unblinding_data_request(ExportPaymentUnblindingDataRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_export_payment_unblinding_data_request();
+    cst_api_fill_to_wire_export_payment_unblinding_data_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_export_wallet_request> cst_encode_box_autoadd_export_wallet_request(
+    ExportWalletRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_export_wallet_request();
+    cst_api_fill_to_wire_export_wallet_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<ffi.Double> cst_encode_box_autoadd_f_64(double raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return wire.cst_new_box_autoadd_f_64(cst_encode_f_64(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_cst_fee_breakdown> cst_encode_box_autoadd_fee_breakdown(FeeBreakdown raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_fee_breakdown();
+    cst_api_fill_to_wire_fee_breakdown(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_box_autoadd_fee_speed(FeeSpeed raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_fee_speed(cst_encode_fee_speed(raw));
+  }
+
   @protected
   ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>
   cst_encode_box_autoadd_fetch_payment_proposed_fees_request(FetchPaymentProposedFeesRequest raw) {
@@ -1481,6 +2608,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_fiat_amount> cst_encode_box_autoadd_fiat_amount(FiatAmount raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_fiat_amount();
+    cst_api_fill_to_wire_fiat_amount(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_fiat_conversion> cst_encode_box_autoadd_fiat_conversion(FiatConversion raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_fiat_conversion();
+    cst_api_fill_to_wire_fiat_conversion(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_get_max_payable_request> cst_encode_box_autoadd_get_max_payable_request(
+    GetMaxPayableRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_get_max_payable_request();
+    cst_api_fill_to_wire_get_max_payable_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_get_max_receivable_request> cst_encode_box_autoadd_get_max_receivable_request(
+    GetMaxReceivableRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_get_max_receivable_request();
+    cst_api_fill_to_wire_get_max_receivable_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_get_payment_request> cst_encode_box_autoadd_get_payment_request(
     GetPaymentRequest raw,
@@ -1491,12 +2654,48 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_get_report_request> cst_encode_box_autoadd_get_report_request(GetReportRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_get_report_request();
+    cst_api_fill_to_wire_get_report_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<ffi.Int64> cst_encode_box_autoadd_i_64(PlatformInt64 raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return wire.cst_new_box_autoadd_i_64(cst_encode_i_64(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_cst_import_wallet_request> cst_encode_box_autoadd_import_wallet_request(
+    ImportWalletRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_import_wallet_request();
+    cst_api_fill_to_wire_import_wallet_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_input_type> cst_encode_box_autoadd_input_type(InputType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_input_type();
+    cst_api_fill_to_wire_input_type(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_lightning_address_info> cst_encode_box_autoadd_lightning_address_info(
+    LightningAddressInfo raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_lightning_address_info();
+    cst_api_fill_to_wire_lightning_address_info(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_liquid_address_data> cst_encode_box_autoadd_liquid_address_data(
     LiquidAddressData raw,
@@ -1527,6 +2726,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_swaps_request> cst_encode_box_autoadd_list_swaps_request(ListSwapsRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_list_swaps_request();
+    cst_api_fill_to_wire_list_swaps_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_ln_bolt_12_invoice> cst_encode_box_autoadd_ln_bolt_12_invoice(LNBolt12Invoice raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_ln_bolt_12_invoice();
+    cst_api_fill_to_wire_ln_bolt_12_invoice(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_ln_invoice> cst_encode_box_autoadd_ln_invoice(LNInvoice raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1607,6 +2822,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_ln_url_payer_data> cst_encode_box_autoadd_ln_url_payer_data(LnUrlPayerData raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_ln_url_payer_data();
+    cst_api_fill_to_wire_ln_url_payer_data(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_ln_url_withdraw_request> cst_encode_box_autoadd_ln_url_withdraw_request(
     LnUrlWithdrawRequest raw,
@@ -1647,6 +2870,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_nwc_budget> cst_encode_box_autoadd_nwc_budget(NwcBudget raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_nwc_budget();
+    cst_api_fill_to_wire_nwc_budget(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_pay_amount> cst_encode_box_autoadd_pay_amount(PayAmount raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1683,6 +2914,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_prepare_convert_request> cst_encode_box_autoadd_prepare_convert_request(
+    PrepareConvertRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_convert_request();
+    cst_api_fill_to_wire_prepare_convert_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_prepare_ln_url_pay_request> cst_encode_box_autoadd_prepare_ln_url_pay_request(
     PrepareLnUrlPayRequest raw,
@@ -1703,6 +2944,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_prepare_receive_quotes_request> cst_encode_box_autoadd_prepare_receive_quotes_request(
+    PrepareReceiveQuotesRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_receive_quotes_request();
+    cst_api_fill_to_wire_prepare_receive_quotes_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_prepare_receive_request> cst_encode_box_autoadd_prepare_receive_request(
     PrepareReceiveRequest raw,
@@ -1733,6 +2984,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_process_notification_request> cst_encode_box_autoadd_process_notification_request(
+    ProcessNotificationRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_process_notification_request();
+    cst_api_fill_to_wire_process_notification_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_queue_payment_request> cst_encode_box_autoadd_queue_payment_request(
+    QueuePaymentRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_queue_payment_request();
+    cst_api_fill_to_wire_queue_payment_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_receive_amount> cst_encode_box_autoadd_receive_amount(ReceiveAmount raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1759,6 +3030,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_register_lightning_address_request>
+  cst_encode_box_autoadd_register_lightning_address_request(RegisterLightningAddressRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_register_lightning_address_request();
+    cst_api_fill_to_wire_register_lightning_address_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_registered_asset> cst_encode_box_autoadd_registered_asset(RegisteredAsset raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_registered_asset();
+    cst_api_fill_to_wire_registered_asset(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_replay_events_request> cst_encode_box_autoadd_replay_events_request(
+    ReplayEventsRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_replay_events_request();
+    cst_api_fill_to_wire_replay_events_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_restore_request> cst_encode_box_autoadd_restore_request(RestoreRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1768,10 +3066,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  ffi.Pointer<wire_cst_sdk_event> cst_encode_box_autoadd_sdk_event(SdkEvent raw) {
+  ffi.Pointer<wire_cst_schedule_payment_request> cst_encode_box_autoadd_schedule_payment_request(
+    SchedulePaymentRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_schedule_payment_request();
+    cst_api_fill_to_wire_schedule_payment_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_send_batch_request> cst_encode_box_autoadd_send_batch_request(SendBatchRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_sdk_event();
-    cst_api_fill_to_wire_sdk_event(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_send_batch_request();
+    cst_api_fill_to_wire_send_batch_request(raw, ptr.ref);
     return ptr;
   }
 
@@ -1795,6 +3103,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_sign_pset_request> cst_encode_box_autoadd_sign_pset_request(SignPsetRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_sign_pset_request();
+    cst_api_fill_to_wire_sign_pset_request(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_simulation_config> cst_encode_box_autoadd_simulation_config(SimulationConfig raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_simulation_config();
+    cst_api_fill_to_wire_simulation_config(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_spend_limit> cst_encode_box_autoadd_spend_limit(SpendLimit raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_spend_limit();
+    cst_api_fill_to_wire_spend_limit(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_spend_limits> cst_encode_box_autoadd_spend_limits(SpendLimits raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_spend_limits();
+    cst_api_fill_to_wire_spend_limits(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_success_action> cst_encode_box_autoadd_success_action(SuccessAction raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1813,6 +3153,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_swap_service_status> cst_encode_box_autoadd_swap_service_status(
+    SwapServiceStatus raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_swap_service_status();
+    cst_api_fill_to_wire_swap_service_status(raw, ptr.ref);
+    return ptr;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_sweep_private_key_request> cst_encode_box_autoadd_sweep_private_key_request(
+    SweepPrivateKeyRequest raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_sweep_private_key_request();
+    cst_api_fill_to_wire_sweep_private_key_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_symbol> cst_encode_box_autoadd_symbol(Symbol raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1821,6 +3181,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ptr;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_sync_request> cst_encode_box_autoadd_sync_request(SyncRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_sync_request();
+    cst_api_fill_to_wire_sync_request(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1849,6 +3217,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw.toInt();
   }
 
+  @protected
+  int cst_encode_isize(PlatformInt64 raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.toInt();
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_String> cst_encode_list_String(List<String> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1859,6 +3233,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_asset_amount> cst_encode_list_asset_amount(List<AssetAmount> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_asset_amount(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_asset_amount(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_asset_balance> cst_encode_list_asset_balance(List<AssetBalance> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1880,33 +3264,75 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_external_input_parser> cst_encode_list_external_input_parser(
-    List<ExternalInputParser> raw,
-  ) {
+  ffi.Pointer<wire_cst_list_asset_report> cst_encode_list_asset_report(List<AssetReport> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_external_input_parser(raw.length);
+    final ans = wire.cst_new_list_asset_report(raw.length);
     for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_external_input_parser(raw[i], ans.ref.ptr[i]);
+      cst_api_fill_to_wire_asset_report(raw[i], ans.ref.ptr[i]);
     }
     return ans;
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_fiat_currency> cst_encode_list_fiat_currency(List<FiatCurrency> raw) {
+  ffi.Pointer<wire_cst_list_batch_send_item> cst_encode_list_batch_send_item(List<BatchSendItem> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_fiat_currency(raw.length);
+    final ans = wire.cst_new_list_batch_send_item(raw.length);
     for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_fiat_currency(raw[i], ans.ref.ptr[i]);
+      cst_api_fill_to_wire_batch_send_item(raw[i], ans.ref.ptr[i]);
     }
     return ans;
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_ln_offer_blinded_path> cst_encode_list_ln_offer_blinded_path(
-    List<LnOfferBlindedPath> raw,
+  ffi.Pointer<wire_cst_list_batch_send_item_result> cst_encode_list_batch_send_item_result(
+    List<BatchSendItemResult> raw,
   ) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_ln_offer_blinded_path(raw.length);
+    final ans = wire.cst_new_list_batch_send_item_result(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_batch_send_item_result(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_external_input_parser> cst_encode_list_external_input_parser(
+    List<ExternalInputParser> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_external_input_parser(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_external_input_parser(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_fiat_currency> cst_encode_list_fiat_currency(List<FiatCurrency> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_fiat_currency(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_fiat_currency(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_journaled_event> cst_encode_list_journaled_event(List<JournaledEvent> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_journaled_event(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_journaled_event(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_ln_offer_blinded_path> cst_encode_list_ln_offer_blinded_path(
+    List<LnOfferBlindedPath> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_ln_offer_blinded_path(raw.length);
     for (var i = 0; i < raw.length; ++i) {
       cst_api_fill_to_wire_ln_offer_blinded_path(raw[i], ans.ref.ptr[i]);
     }
@@ -1933,6 +3359,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_nwc_connection> cst_encode_list_nwc_connection(List<NwcConnection> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_nwc_connection(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_nwc_connection(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_payment> cst_encode_list_payment(List<Payment> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1953,6 +3389,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_payment_swap_type> cst_encode_list_payment_swap_type(List<PaymentSwapType> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_payment_swap_type(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_payment_swap_type(raw[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_payment_type> cst_encode_list_payment_type(List<PaymentType> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1963,6 +3409,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_prepare_receive_response> cst_encode_list_prepare_receive_response(
+    List<PrepareReceiveResponse> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prepare_receive_response(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_prepare_receive_response(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(Uint8List raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1971,6 +3429,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_pset_spend_limit> cst_encode_list_pset_spend_limit(List<PsetSpendLimit> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_pset_spend_limit(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_pset_spend_limit(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_queued_payment> cst_encode_list_queued_payment(List<QueuedPayment> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_queued_payment(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_queued_payment(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_rate> cst_encode_list_rate(List<Rate> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -1981,6 +3459,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_recurring_payment> cst_encode_list_recurring_payment(List<RecurringPayment> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_recurring_payment(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_recurring_payment(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_recurring_payment_occurrence> cst_encode_list_recurring_payment_occurrence(
+    List<RecurringPaymentOccurrence> raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_recurring_payment_occurrence(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_recurring_payment_occurrence(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_refundable_swap> cst_encode_list_refundable_swap(List<RefundableSwap> raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -2011,6 +3511,56 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return ans;
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_scheduled_payment> cst_encode_list_scheduled_payment(List<ScheduledPayment> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_scheduled_payment(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_scheduled_payment(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_sdk_event_kind> cst_encode_list_sdk_event_kind(List<SdkEventKind> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_sdk_event_kind(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_sdk_event_kind(raw[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_service_health> cst_encode_list_service_health(List<ServiceHealth> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_service_health(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_service_health(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_swap_info> cst_encode_list_swap_info(List<SwapInfo> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_swap_info(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_swap_info(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_unblinded_tx_out> cst_encode_list_unblinded_tx_out(List<UnblindedTxOut> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_unblinded_tx_out(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_unblinded_tx_out(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(String? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -2023,30 +3573,118 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_amount(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_amp_account> cst_encode_opt_box_autoadd_amp_account(AmpAccount? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_amp_account(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_asset_amount> cst_encode_opt_box_autoadd_asset_amount(AssetAmount? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_asset_amount(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_asset_conversion> cst_encode_opt_box_autoadd_asset_conversion(AssetConversion? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_asset_conversion(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_asset_info> cst_encode_opt_box_autoadd_asset_info(AssetInfo? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_asset_info(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_backup_policy> cst_encode_opt_box_autoadd_backup_policy(BackupPolicy? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_backup_policy(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Bool> cst_encode_opt_box_autoadd_bool(bool? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_bool(raw);
   }
 
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_buy_bitcoin_provider(BuyBitcoinProvider? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_buy_bitcoin_provider(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_direction(Direction? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_direction(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_event_filter> cst_encode_opt_box_autoadd_event_filter(EventFilter? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_event_filter(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_event_webhook_config> cst_encode_opt_box_autoadd_event_webhook_config(
+    EventWebhookConfig? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_event_webhook_config(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Double> cst_encode_opt_box_autoadd_f_64(double? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_f_64(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_fee_breakdown> cst_encode_opt_box_autoadd_fee_breakdown(FeeBreakdown? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_fee_breakdown(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Int32> cst_encode_opt_box_autoadd_fee_speed(FeeSpeed? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_fee_speed(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_fiat_amount> cst_encode_opt_box_autoadd_fiat_amount(FiatAmount? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_fiat_amount(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_fiat_conversion> cst_encode_opt_box_autoadd_fiat_conversion(FiatConversion? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_fiat_conversion(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Int64> cst_encode_opt_box_autoadd_i_64(PlatformInt64? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_i_64(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_input_type> cst_encode_opt_box_autoadd_input_type(InputType? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_input_type(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_lightning_address_info> cst_encode_opt_box_autoadd_lightning_address_info(
+    LightningAddressInfo? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_lightning_address_info(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_payment_details> cst_encode_opt_box_autoadd_list_payment_details(
     ListPaymentDetails? raw,
@@ -2061,6 +3699,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_ln_url_info(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_ln_url_payer_data> cst_encode_opt_box_autoadd_ln_url_payer_data(LnUrlPayerData? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_ln_url_payer_data(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_nwc_budget> cst_encode_opt_box_autoadd_nwc_budget(NwcBudget? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_nwc_budget(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_pay_amount> cst_encode_opt_box_autoadd_pay_amount(PayAmount? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -2079,6 +3729,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_receive_amount(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_registered_asset> cst_encode_opt_box_autoadd_registered_asset(RegisteredAsset? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_registered_asset(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_simulation_config> cst_encode_opt_box_autoadd_simulation_config(
+    SimulationConfig? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_simulation_config(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_spend_limit> cst_encode_opt_box_autoadd_spend_limit(SpendLimit? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_spend_limit(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_spend_limits> cst_encode_opt_box_autoadd_spend_limits(SpendLimits? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_spend_limits(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_success_action> cst_encode_opt_box_autoadd_success_action(SuccessAction? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -2093,12 +3769,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_success_action_processed(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_swap_service_status> cst_encode_opt_box_autoadd_swap_service_status(
+    SwapServiceStatus? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_swap_service_status(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_symbol> cst_encode_opt_box_autoadd_symbol(Symbol? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_symbol(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_sync_request> cst_encode_opt_box_autoadd_sync_request(SyncRequest? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_sync_request(raw);
+  }
+
   @protected
   ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -2111,6 +3801,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_String> cst_encode_opt_list_String(List<String>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_String(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_asset_metadata> cst_encode_opt_list_asset_metadata(List<AssetMetadata>? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -2131,6 +3827,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_list_payment_state(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_payment_swap_type> cst_encode_opt_list_payment_swap_type(
+    List<PaymentSwapType>? raw,
+  ) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_payment_swap_type(raw);
+  }
+
   @protected
   ffi.Pointer<wire_cst_list_payment_type> cst_encode_opt_list_payment_type(List<PaymentType>? raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -2143,6 +3847,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return raw == null ? ffi.nullptr : cst_encode_list_prim_u_8_strict(raw);
   }
 
+  @protected
+  ffi.Pointer<wire_cst_list_sdk_event_kind> cst_encode_opt_list_sdk_event_kind(List<SdkEventKind>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_sdk_event_kind(raw);
+  }
+
   @protected
   int cst_encode_u_64(BigInt raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -2163,6 +3873,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_fetch_payment_proposed_fees_response(apiObj.response, wireObj.response);
   }
 
+  @protected
+  void cst_api_fill_to_wire_add_nwc_connection_request(
+    AddNwcConnectionRequest apiObj,
+    wire_cst_add_nwc_connection_request wireObj,
+  ) {
+    wireObj.name = cst_encode_String(apiObj.name);
+    wireObj.budget = cst_encode_opt_box_autoadd_nwc_budget(apiObj.budget);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_add_nwc_connection_response(
+    AddNwcConnectionResponse apiObj,
+    wire_cst_add_nwc_connection_response wireObj,
+  ) {
+    wireObj.connection_uri = cst_encode_String(apiObj.connectionUri);
+  }
+
   @protected
   void cst_api_fill_to_wire_aes_success_action_data(
     AesSuccessActionData apiObj,
@@ -2219,6 +3946,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     }
   }
 
+  @protected
+  void cst_api_fill_to_wire_amp_account(AmpAccount apiObj, wire_cst_amp_account wireObj) {
+    wireObj.amp_id = cst_encode_String(apiObj.ampId);
+    wireObj.descriptor = cst_encode_String(apiObj.descriptor);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_asset_amount(AssetAmount apiObj, wire_cst_asset_amount wireObj) {
+    wireObj.asset_id = cst_encode_String(apiObj.assetId);
+    wireObj.base_units = cst_encode_u_64(apiObj.baseUnits);
+    wireObj.precision = cst_encode_u_8(apiObj.precision);
+    wireObj.display_precision = cst_encode_u_8(apiObj.displayPrecision);
+  }
+
   @protected
   void cst_api_fill_to_wire_asset_balance(AssetBalance apiObj, wire_cst_asset_balance wireObj) {
     wireObj.asset_id = cst_encode_String(apiObj.assetId);
@@ -2226,6 +3967,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.name = cst_encode_opt_String(apiObj.name);
     wireObj.ticker = cst_encode_opt_String(apiObj.ticker);
     wireObj.balance = cst_encode_opt_box_autoadd_f_64(apiObj.balance);
+    wireObj.pending_send_sat = cst_encode_u_64(apiObj.pendingSendSat);
+    wireObj.pending_receive_sat = cst_encode_u_64(apiObj.pendingReceiveSat);
+    wireObj.pending_send = cst_encode_opt_box_autoadd_f_64(apiObj.pendingSend);
+    wireObj.pending_receive = cst_encode_opt_box_autoadd_f_64(apiObj.pendingReceive);
+    wireObj.asset_balance = cst_encode_opt_box_autoadd_asset_amount(apiObj.assetBalance);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_asset_conversion(AssetConversion apiObj, wire_cst_asset_conversion wireObj) {
+    wireObj.from_asset_id = cst_encode_String(apiObj.fromAssetId);
+    wireObj.from_amount = cst_encode_u_64(apiObj.fromAmount);
+    wireObj.to_asset_id = cst_encode_String(apiObj.toAssetId);
+    wireObj.to_amount = cst_encode_u_64(apiObj.toAmount);
   }
 
   @protected
@@ -2234,6 +3988,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.ticker = cst_encode_String(apiObj.ticker);
     wireObj.amount = cst_encode_f_64(apiObj.amount);
     wireObj.fees = cst_encode_opt_box_autoadd_f_64(apiObj.fees);
+    cst_api_fill_to_wire_asset_amount(apiObj.assetAmount, wireObj.asset_amount);
+    wireObj.asset_fees = cst_encode_opt_box_autoadd_asset_amount(apiObj.assetFees);
   }
 
   @protected
@@ -2243,19 +3999,55 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.ticker = cst_encode_String(apiObj.ticker);
     wireObj.precision = cst_encode_u_8(apiObj.precision);
     wireObj.fiat_id = cst_encode_opt_String(apiObj.fiatId);
+    wireObj.is_amp = cst_encode_bool(apiObj.isAmp);
   }
 
   @protected
-  void cst_api_fill_to_wire_backup_request(BackupRequest apiObj, wire_cst_backup_request wireObj) {
-    wireObj.backup_path = cst_encode_opt_String(apiObj.backupPath);
+  void cst_api_fill_to_wire_asset_report(AssetReport apiObj, wire_cst_asset_report wireObj) {
+    wireObj.asset_id = cst_encode_String(apiObj.assetId);
+    wireObj.received_amount = cst_encode_u_64(apiObj.receivedAmount);
+    wireObj.sent_amount = cst_encode_u_64(apiObj.sentAmount);
+    wireObj.swap_fees_sat = cst_encode_u_64(apiObj.swapFeesSat);
+    wireObj.onchain_fees_sat = cst_encode_u_64(apiObj.onchainFeesSat);
+    wireObj.received_count = cst_encode_u_32(apiObj.receivedCount);
+    wireObj.sent_count = cst_encode_u_32(apiObj.sentCount);
   }
 
   @protected
-  void cst_api_fill_to_wire_binding_event_listener(
-    BindingEventListener apiObj,
-    wire_cst_binding_event_listener wireObj,
+  void cst_api_fill_to_wire_backup_policy(BackupPolicy apiObj, wire_cst_backup_policy wireObj) {
+    if (apiObj is BackupPolicy_CompletedSwaps) {
+      var pre_swaps = cst_encode_u_32(apiObj.swaps);
+      wireObj.tag = 0;
+      wireObj.kind.CompletedSwaps.swaps = pre_swaps;
+      return;
+    }
+    if (apiObj is BackupPolicy_Interval) {
+      var pre_hours = cst_encode_u_32(apiObj.hours);
+      wireObj.tag = 1;
+      wireObj.kind.Interval.hours = pre_hours;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_backup_request(BackupRequest apiObj, wire_cst_backup_request wireObj) {
+    wireObj.backup_path = cst_encode_opt_String(apiObj.backupPath);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_batch_send_item(BatchSendItem apiObj, wire_cst_batch_send_item wireObj) {
+    wireObj.destination = cst_encode_String(apiObj.destination);
+    wireObj.amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_batch_send_item_result(
+    BatchSendItemResult apiObj,
+    wire_cst_batch_send_item_result wireObj,
   ) {
-    wireObj.stream = cst_encode_StreamSink_sdk_event_Dco(apiObj.stream);
+    wireObj.destination = cst_encode_String(apiObj.destination);
+    wireObj.payment = cst_encode_opt_box_autoadd_payment(apiObj.payment);
+    wireObj.error = cst_encode_opt_String(apiObj.error);
   }
 
   @protected
@@ -2270,6 +4062,13 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.message = cst_encode_opt_String(apiObj.message);
   }
 
+  @protected
+  void cst_api_fill_to_wire_block_info(BlockInfo apiObj, wire_cst_block_info wireObj) {
+    wireObj.height = cst_encode_u_32(apiObj.height);
+    wireObj.hash = cst_encode_String(apiObj.hash);
+    wireObj.timestamp = cst_encode_u_32(apiObj.timestamp);
+  }
+
   @protected
   void cst_api_fill_to_wire_blockchain_explorer(
     BlockchainExplorer apiObj,
@@ -2305,6 +4104,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_accept_payment_proposed_fees_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_add_nwc_connection_request(
+    AddNwcConnectionRequest apiObj,
+    ffi.Pointer<wire_cst_add_nwc_connection_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_add_nwc_connection_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_aes_success_action_data(
     AesSuccessActionData apiObj,
@@ -2334,6 +4141,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_amount(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_amp_account(
+    AmpAccount apiObj,
+    ffi.Pointer<wire_cst_amp_account> wireObj,
+  ) {
+    cst_api_fill_to_wire_amp_account(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_asset_amount(
+    AssetAmount apiObj,
+    ffi.Pointer<wire_cst_asset_amount> wireObj,
+  ) {
+    cst_api_fill_to_wire_asset_amount(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_asset_conversion(
+    AssetConversion apiObj,
+    ffi.Pointer<wire_cst_asset_conversion> wireObj,
+  ) {
+    cst_api_fill_to_wire_asset_conversion(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_asset_info(
     AssetInfo apiObj,
@@ -2343,19 +4174,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_backup_request(
-    BackupRequest apiObj,
-    ffi.Pointer<wire_cst_backup_request> wireObj,
+  void cst_api_fill_to_wire_box_autoadd_backup_policy(
+    BackupPolicy apiObj,
+    ffi.Pointer<wire_cst_backup_policy> wireObj,
   ) {
-    cst_api_fill_to_wire_backup_request(apiObj, wireObj.ref);
+    cst_api_fill_to_wire_backup_policy(apiObj, wireObj.ref);
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_binding_event_listener(
-    BindingEventListener apiObj,
-    ffi.Pointer<wire_cst_binding_event_listener> wireObj,
+  void cst_api_fill_to_wire_box_autoadd_backup_request(
+    BackupRequest apiObj,
+    ffi.Pointer<wire_cst_backup_request> wireObj,
   ) {
-    cst_api_fill_to_wire_binding_event_listener(apiObj, wireObj.ref);
+    cst_api_fill_to_wire_backup_request(apiObj, wireObj.ref);
   }
 
   @protected
@@ -2366,6 +4197,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_bitcoin_address_data(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_block_info(
+    BlockInfo apiObj,
+    ffi.Pointer<wire_cst_block_info> wireObj,
+  ) {
+    cst_api_fill_to_wire_block_info(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_buy_bitcoin_request(
     BuyBitcoinRequest apiObj,
@@ -2390,6 +4229,62 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_connect_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_convert_request(
+    ConvertRequest apiObj,
+    ffi.Pointer<wire_cst_convert_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_convert_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_create_recurring_payment_request(
+    CreateRecurringPaymentRequest apiObj,
+    ffi.Pointer<wire_cst_create_recurring_payment_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_create_recurring_payment_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_event_filter(
+    EventFilter apiObj,
+    ffi.Pointer<wire_cst_event_filter> wireObj,
+  ) {
+    cst_api_fill_to_wire_event_filter(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_event_webhook_config(
+    EventWebhookConfig apiObj,
+    ffi.Pointer<wire_cst_event_webhook_config> wireObj,
+  ) {
+    cst_api_fill_to_wire_event_webhook_config(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_export_payment_unblinding_data_request(
+    ExportPaymentUnblindingDataRequest apiObj,
+    ffi.Pointer<wire_cst_export_payment_unblinding_data_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_export_payment_unblinding_data_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_export_wallet_request(
+    ExportWalletRequest apiObj,
+    ffi.Pointer<wire_cst_export_wallet_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_export_wallet_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_fee_breakdown(
+    FeeBreakdown apiObj,
+    ffi.Pointer<wire_cst_fee_breakdown> wireObj,
+  ) {
+    cst_api_fill_to_wire_fee_breakdown(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_fetch_payment_proposed_fees_request(
     FetchPaymentProposedFeesRequest apiObj,
@@ -2398,6 +4293,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_fetch_payment_proposed_fees_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_fiat_amount(
+    FiatAmount apiObj,
+    ffi.Pointer<wire_cst_fiat_amount> wireObj,
+  ) {
+    cst_api_fill_to_wire_fiat_amount(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_fiat_conversion(
+    FiatConversion apiObj,
+    ffi.Pointer<wire_cst_fiat_conversion> wireObj,
+  ) {
+    cst_api_fill_to_wire_fiat_conversion(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_get_max_payable_request(
+    GetMaxPayableRequest apiObj,
+    ffi.Pointer<wire_cst_get_max_payable_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_get_max_payable_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_get_max_receivable_request(
+    GetMaxReceivableRequest apiObj,
+    ffi.Pointer<wire_cst_get_max_receivable_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_get_max_receivable_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_get_payment_request(
     GetPaymentRequest apiObj,
@@ -2406,6 +4333,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_get_payment_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_get_report_request(
+    GetReportRequest apiObj,
+    ffi.Pointer<wire_cst_get_report_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_get_report_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_import_wallet_request(
+    ImportWalletRequest apiObj,
+    ffi.Pointer<wire_cst_import_wallet_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_import_wallet_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_input_type(
+    InputType apiObj,
+    ffi.Pointer<wire_cst_input_type> wireObj,
+  ) {
+    cst_api_fill_to_wire_input_type(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_lightning_address_info(
+    LightningAddressInfo apiObj,
+    ffi.Pointer<wire_cst_lightning_address_info> wireObj,
+  ) {
+    cst_api_fill_to_wire_lightning_address_info(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_liquid_address_data(
     LiquidAddressData apiObj,
@@ -2430,6 +4389,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_list_payments_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_list_swaps_request(
+    ListSwapsRequest apiObj,
+    ffi.Pointer<wire_cst_list_swaps_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_list_swaps_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_ln_bolt_12_invoice(
+    LNBolt12Invoice apiObj,
+    ffi.Pointer<wire_cst_ln_bolt_12_invoice> wireObj,
+  ) {
+    cst_api_fill_to_wire_ln_bolt_12_invoice(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_ln_invoice(
     LNInvoice apiObj,
@@ -2499,6 +4474,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_ln_url_pay_success_data(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_ln_url_payer_data(
+    LnUrlPayerData apiObj,
+    ffi.Pointer<wire_cst_ln_url_payer_data> wireObj,
+  ) {
+    cst_api_fill_to_wire_ln_url_payer_data(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_ln_url_withdraw_request(
     LnUrlWithdrawRequest apiObj,
@@ -2531,6 +4514,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_message_success_action_data(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_nwc_budget(
+    NwcBudget apiObj,
+    ffi.Pointer<wire_cst_nwc_budget> wireObj,
+  ) {
+    cst_api_fill_to_wire_nwc_budget(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_pay_amount(
     PayAmount apiObj,
@@ -2560,6 +4551,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_prepare_buy_bitcoin_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_convert_request(
+    PrepareConvertRequest apiObj,
+    ffi.Pointer<wire_cst_prepare_convert_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_prepare_convert_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_prepare_ln_url_pay_request(
     PrepareLnUrlPayRequest apiObj,
@@ -2576,6 +4575,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_prepare_pay_onchain_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_receive_quotes_request(
+    PrepareReceiveQuotesRequest apiObj,
+    ffi.Pointer<wire_cst_prepare_receive_quotes_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_prepare_receive_quotes_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_prepare_receive_request(
     PrepareReceiveRequest apiObj,
@@ -2600,6 +4607,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_prepare_send_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_process_notification_request(
+    ProcessNotificationRequest apiObj,
+    ffi.Pointer<wire_cst_process_notification_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_process_notification_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_queue_payment_request(
+    QueuePaymentRequest apiObj,
+    ffi.Pointer<wire_cst_queue_payment_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_queue_payment_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_receive_amount(
     ReceiveAmount apiObj,
@@ -2624,6 +4647,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_refund_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_register_lightning_address_request(
+    RegisterLightningAddressRequest apiObj,
+    ffi.Pointer<wire_cst_register_lightning_address_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_register_lightning_address_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_registered_asset(
+    RegisteredAsset apiObj,
+    ffi.Pointer<wire_cst_registered_asset> wireObj,
+  ) {
+    cst_api_fill_to_wire_registered_asset(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_replay_events_request(
+    ReplayEventsRequest apiObj,
+    ffi.Pointer<wire_cst_replay_events_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_replay_events_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_restore_request(
     RestoreRequest apiObj,
@@ -2633,8 +4680,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_sdk_event(SdkEvent apiObj, ffi.Pointer<wire_cst_sdk_event> wireObj) {
-    cst_api_fill_to_wire_sdk_event(apiObj, wireObj.ref);
+  void cst_api_fill_to_wire_box_autoadd_schedule_payment_request(
+    SchedulePaymentRequest apiObj,
+    ffi.Pointer<wire_cst_schedule_payment_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_schedule_payment_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_send_batch_request(
+    SendBatchRequest apiObj,
+    ffi.Pointer<wire_cst_send_batch_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_send_batch_request(apiObj, wireObj.ref);
   }
 
   @protected
@@ -2653,6 +4711,38 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_sign_message_request(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_sign_pset_request(
+    SignPsetRequest apiObj,
+    ffi.Pointer<wire_cst_sign_pset_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_sign_pset_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_simulation_config(
+    SimulationConfig apiObj,
+    ffi.Pointer<wire_cst_simulation_config> wireObj,
+  ) {
+    cst_api_fill_to_wire_simulation_config(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_spend_limit(
+    SpendLimit apiObj,
+    ffi.Pointer<wire_cst_spend_limit> wireObj,
+  ) {
+    cst_api_fill_to_wire_spend_limit(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_spend_limits(
+    SpendLimits apiObj,
+    ffi.Pointer<wire_cst_spend_limits> wireObj,
+  ) {
+    cst_api_fill_to_wire_spend_limits(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_success_action(
     SuccessAction apiObj,
@@ -2669,11 +4759,35 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_success_action_processed(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_swap_service_status(
+    SwapServiceStatus apiObj,
+    ffi.Pointer<wire_cst_swap_service_status> wireObj,
+  ) {
+    cst_api_fill_to_wire_swap_service_status(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_sweep_private_key_request(
+    SweepPrivateKeyRequest apiObj,
+    ffi.Pointer<wire_cst_sweep_private_key_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_sweep_private_key_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_symbol(Symbol apiObj, ffi.Pointer<wire_cst_symbol> wireObj) {
     cst_api_fill_to_wire_symbol(apiObj, wireObj.ref);
   }
 
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_sync_request(
+    SyncRequest apiObj,
+    ffi.Pointer<wire_cst_sync_request> wireObj,
+  ) {
+    cst_api_fill_to_wire_sync_request(apiObj, wireObj.ref);
+  }
+
   @protected
   void cst_api_fill_to_wire_box_autoadd_url_success_action_data(
     UrlSuccessActionData apiObj,
@@ -2691,6 +4805,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.redirect_url = cst_encode_opt_String(apiObj.redirectUrl);
   }
 
+  @protected
+  void cst_api_fill_to_wire_check_health_response(
+    CheckHealthResponse apiObj,
+    wire_cst_check_health_response wireObj,
+  ) {
+    wireObj.is_degraded = cst_encode_bool(apiObj.isDegraded);
+    wireObj.services = cst_encode_list_service_health(apiObj.services);
+    wireObj.swap_service_status = cst_encode_opt_box_autoadd_swap_service_status(apiObj.swapServiceStatus);
+  }
+
   @protected
   void cst_api_fill_to_wire_check_message_request(
     CheckMessageRequest apiObj,
@@ -2727,6 +4851,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     );
     wireObj.asset_metadata = cst_encode_opt_list_asset_metadata(apiObj.assetMetadata);
     wireObj.sideswap_api_key = cst_encode_opt_String(apiObj.sideswapApiKey);
+    wireObj.event_webhook = cst_encode_opt_box_autoadd_event_webhook_config(apiObj.eventWebhook);
+    wireObj.fiat_currency = cst_encode_opt_String(apiObj.fiatCurrency);
+    wireObj.sync_interval_sec = cst_encode_opt_box_autoadd_u_32(apiObj.syncIntervalSec);
+    wireObj.bitcoin_explorer_authorization = cst_encode_opt_String(apiObj.bitcoinExplorerAuthorization);
+    wireObj.swapper_max_attempts = cst_encode_opt_box_autoadd_u_32(apiObj.swapperMaxAttempts);
+    wireObj.backup_policy = cst_encode_opt_box_autoadd_backup_policy(apiObj.backupPolicy);
+    wireObj.nwc_relay_url = cst_encode_opt_String(apiObj.nwcRelayUrl);
+    wireObj.spend_limits = cst_encode_opt_box_autoadd_spend_limits(apiObj.spendLimits);
+    wireObj.lnurl_service_url = cst_encode_opt_String(apiObj.lnurlServiceUrl);
+    wireObj.outgoing_min_confirmations = cst_encode_opt_box_autoadd_u_32(apiObj.outgoingMinConfirmations);
+    wireObj.unconfirmed_tx_alert_sec = cst_encode_opt_box_autoadd_u_32(apiObj.unconfirmedTxAlertSec);
+    wireObj.chain_service_timeout_sec = cst_encode_opt_box_autoadd_u_32(apiObj.chainServiceTimeoutSec);
+    wireObj.swapper_timeout_sec = cst_encode_opt_box_autoadd_u_32(apiObj.swapperTimeoutSec);
+    wireObj.swapper_ping_interval_sec = cst_encode_opt_box_autoadd_u_32(apiObj.swapperPingIntervalSec);
+    wireObj.amp_server_url = cst_encode_opt_String(apiObj.ampServerUrl);
+    wireObj.simulation = cst_encode_opt_box_autoadd_simulation_config(apiObj.simulation);
+    wireObj.manual_claim = cst_encode_bool(apiObj.manualClaim);
+    wireObj.claim_address = cst_encode_opt_String(apiObj.claimAddress);
+    wireObj.dns_over_https_url = cst_encode_opt_String(apiObj.dnsOverHttpsUrl);
   }
 
   @protected
@@ -2735,6 +4878,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.mnemonic = cst_encode_opt_String(apiObj.mnemonic);
     wireObj.passphrase = cst_encode_opt_String(apiObj.passphrase);
     wireObj.seed = cst_encode_opt_list_prim_u_8_strict(apiObj.seed);
+    wireObj.xprv = cst_encode_opt_String(apiObj.xprv);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_convert_request(ConvertRequest apiObj, wire_cst_convert_request wireObj) {
+    cst_api_fill_to_wire_prepare_convert_response(apiObj.prepareResponse, wireObj.prepare_response);
+    wireObj.spend_limit_override = cst_encode_opt_String(apiObj.spendLimitOverride);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_create_recurring_payment_request(
+    CreateRecurringPaymentRequest apiObj,
+    wire_cst_create_recurring_payment_request wireObj,
+  ) {
+    wireObj.destination = cst_encode_String(apiObj.destination);
+    wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
+    wireObj.interval_sec = cst_encode_u_32(apiObj.intervalSec);
+    wireObj.start_at = cst_encode_opt_box_autoadd_u_32(apiObj.startAt);
+    wireObj.max_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxFeeSat);
   }
 
   @protected
@@ -2748,6 +4910,55 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.locale_overrides = cst_encode_list_locale_overrides(apiObj.localeOverrides);
   }
 
+  @protected
+  void cst_api_fill_to_wire_decoded_invoice(DecodedInvoice apiObj, wire_cst_decoded_invoice wireObj) {
+    if (apiObj is DecodedInvoice_Bolt11) {
+      var pre_invoice = cst_encode_box_autoadd_ln_invoice(apiObj.invoice);
+      wireObj.tag = 0;
+      wireObj.kind.Bolt11.invoice = pre_invoice;
+      return;
+    }
+    if (apiObj is DecodedInvoice_Bolt12) {
+      var pre_invoice = cst_encode_box_autoadd_ln_bolt_12_invoice(apiObj.invoice);
+      wireObj.tag = 1;
+      wireObj.kind.Bolt12.invoice = pre_invoice;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_event_filter(EventFilter apiObj, wire_cst_event_filter wireObj) {
+    wireObj.kinds = cst_encode_opt_list_sdk_event_kind(apiObj.kinds);
+    wireObj.payment_ids = cst_encode_opt_list_String(apiObj.paymentIds);
+    wireObj.swap_types = cst_encode_opt_list_payment_swap_type(apiObj.swapTypes);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_event_webhook_config(
+    EventWebhookConfig apiObj,
+    wire_cst_event_webhook_config wireObj,
+  ) {
+    wireObj.url = cst_encode_String(apiObj.url);
+    wireObj.secret = cst_encode_String(apiObj.secret);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_export_payment_unblinding_data_request(
+    ExportPaymentUnblindingDataRequest apiObj,
+    wire_cst_export_payment_unblinding_data_request wireObj,
+  ) {
+    wireObj.payment_id = cst_encode_String(apiObj.paymentId);
+    wireObj.explorer_url = cst_encode_opt_String(apiObj.explorerUrl);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_export_wallet_request(
+    ExportWalletRequest apiObj,
+    wire_cst_export_wallet_request wireObj,
+  ) {
+    wireObj.export_path = cst_encode_String(apiObj.exportPath);
+  }
+
   @protected
   void cst_api_fill_to_wire_external_input_parser(
     ExternalInputParser apiObj,
@@ -2758,6 +4969,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.parser_url = cst_encode_String(apiObj.parserUrl);
   }
 
+  @protected
+  void cst_api_fill_to_wire_fee_breakdown(FeeBreakdown apiObj, wire_cst_fee_breakdown wireObj) {
+    wireObj.service_fee_sat = cst_encode_u_64(apiObj.serviceFeeSat);
+    wireObj.swapper_network_fee_sat = cst_encode_u_64(apiObj.swapperNetworkFeeSat);
+    wireObj.lockup_fee_sat = cst_encode_u_64(apiObj.lockupFeeSat);
+    wireObj.lockup_fee_is_estimate = cst_encode_bool(apiObj.lockupFeeIsEstimate);
+    wireObj.claim_fee_sat = cst_encode_u_64(apiObj.claimFeeSat);
+    wireObj.claim_fee_is_estimate = cst_encode_bool(apiObj.claimFeeIsEstimate);
+    wireObj.asset_fee = cst_encode_opt_box_autoadd_asset_amount(apiObj.assetFee);
+  }
+
   @protected
   void cst_api_fill_to_wire_fetch_payment_proposed_fees_request(
     FetchPaymentProposedFeesRequest apiObj,
@@ -2777,6 +4999,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
   }
 
+  @protected
+  void cst_api_fill_to_wire_fiat_amount(FiatAmount apiObj, wire_cst_fiat_amount wireObj) {
+    wireObj.amount = cst_encode_f_64(apiObj.amount);
+    wireObj.currency = cst_encode_String(apiObj.currency);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_fiat_conversion(FiatConversion apiObj, wire_cst_fiat_conversion wireObj) {
+    cst_api_fill_to_wire_fiat_amount(apiObj.fiatAmount, wireObj.fiat_amount);
+    wireObj.rate = cst_encode_f_64(apiObj.rate);
+    wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
+  }
+
   @protected
   void cst_api_fill_to_wire_fiat_currency(FiatCurrency apiObj, wire_cst_fiat_currency wireObj) {
     wireObj.id = cst_encode_String(apiObj.id);
@@ -2790,22 +5025,97 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  void cst_api_fill_to_wire_get_payment_request(
-    GetPaymentRequest apiObj,
-    wire_cst_get_payment_request wireObj,
+  void cst_api_fill_to_wire_get_max_payable_request(
+    GetMaxPayableRequest apiObj,
+    wire_cst_get_max_payable_request wireObj,
   ) {
-    if (apiObj is GetPaymentRequest_PaymentHash) {
-      var pre_payment_hash = cst_encode_String(apiObj.paymentHash);
-      wireObj.tag = 0;
-      wireObj.kind.PaymentHash.payment_hash = pre_payment_hash;
-      return;
-    }
-    if (apiObj is GetPaymentRequest_SwapId) {
-      var pre_swap_id = cst_encode_String(apiObj.swapId);
-      wireObj.tag = 1;
-      wireObj.kind.SwapId.swap_id = pre_swap_id;
-      return;
-    }
+    wireObj.payment_method = cst_encode_payment_method(apiObj.paymentMethod);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_get_max_payable_response(
+    GetMaxPayableResponse apiObj,
+    wire_cst_get_max_payable_response wireObj,
+  ) {
+    wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_get_max_receivable_request(
+    GetMaxReceivableRequest apiObj,
+    wire_cst_get_max_receivable_request wireObj,
+  ) {
+    wireObj.payment_method = cst_encode_payment_method(apiObj.paymentMethod);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_get_max_receivable_response(
+    GetMaxReceivableResponse apiObj,
+    wire_cst_get_max_receivable_response wireObj,
+  ) {
+    wireObj.payer_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.payerAmountSat);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_get_payment_request(
+    GetPaymentRequest apiObj,
+    wire_cst_get_payment_request wireObj,
+  ) {
+    if (apiObj is GetPaymentRequest_PaymentHash) {
+      var pre_payment_hash = cst_encode_String(apiObj.paymentHash);
+      wireObj.tag = 0;
+      wireObj.kind.PaymentHash.payment_hash = pre_payment_hash;
+      return;
+    }
+    if (apiObj is GetPaymentRequest_SwapId) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      wireObj.tag = 1;
+      wireObj.kind.SwapId.swap_id = pre_swap_id;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_get_report_request(GetReportRequest apiObj, wire_cst_get_report_request wireObj) {
+    wireObj.from_timestamp = cst_encode_opt_box_autoadd_i_64(apiObj.fromTimestamp);
+    wireObj.to_timestamp = cst_encode_opt_box_autoadd_i_64(apiObj.toTimestamp);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_get_report_response(
+    GetReportResponse apiObj,
+    wire_cst_get_report_response wireObj,
+  ) {
+    wireObj.assets = cst_encode_list_asset_report(apiObj.assets);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_get_wallet_info_response(
+    GetWalletInfoResponse apiObj,
+    wire_cst_get_wallet_info_response wireObj,
+  ) {
+    wireObj.descriptor = cst_encode_String(apiObj.descriptor);
+    wireObj.xpub = cst_encode_String(apiObj.xpub);
+    wireObj.derivation_path = cst_encode_String(apiObj.derivationPath);
+    wireObj.fingerprint = cst_encode_String(apiObj.fingerprint);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_import_wallet_request(
+    ImportWalletRequest apiObj,
+    wire_cst_import_wallet_request wireObj,
+  ) {
+    wireObj.export_path = cst_encode_String(apiObj.exportPath);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_import_wallet_response(
+    ImportWalletResponse apiObj,
+    wire_cst_import_wallet_response wireObj,
+  ) {
+    wireObj.imported_records = cst_encode_u_32(apiObj.importedRecords);
   }
 
   @protected
@@ -2876,6 +5186,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     }
   }
 
+  @protected
+  void cst_api_fill_to_wire_journaled_event(JournaledEvent apiObj, wire_cst_journaled_event wireObj) {
+    wireObj.seq = cst_encode_u_64(apiObj.seq);
+    wireObj.timestamp = cst_encode_u_32(apiObj.timestamp);
+    cst_api_fill_to_wire_sdk_event(apiObj.event, wireObj.event);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_lightning_address_info(
+    LightningAddressInfo apiObj,
+    wire_cst_lightning_address_info wireObj,
+  ) {
+    wireObj.username = cst_encode_String(apiObj.username);
+    wireObj.lightning_address = cst_encode_String(apiObj.lightningAddress);
+    wireObj.lnurl = cst_encode_String(apiObj.lnurl);
+  }
+
   @protected
   void cst_api_fill_to_wire_lightning_payment_limits_response(
     LightningPaymentLimitsResponse apiObj,
@@ -2942,6 +5269,29 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.sort_ascending = cst_encode_opt_box_autoadd_bool(apiObj.sortAscending);
   }
 
+  @protected
+  void cst_api_fill_to_wire_list_swaps_request(ListSwapsRequest apiObj, wire_cst_list_swaps_request wireObj) {
+    wireObj.swap_types = cst_encode_opt_list_payment_swap_type(apiObj.swapTypes);
+    wireObj.states = cst_encode_opt_list_payment_state(apiObj.states);
+    wireObj.from_timestamp = cst_encode_opt_box_autoadd_i_64(apiObj.fromTimestamp);
+    wireObj.to_timestamp = cst_encode_opt_box_autoadd_i_64(apiObj.toTimestamp);
+    wireObj.offset = cst_encode_opt_box_autoadd_u_32(apiObj.offset);
+    wireObj.limit = cst_encode_opt_box_autoadd_u_32(apiObj.limit);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_ln_bolt_12_invoice(LNBolt12Invoice apiObj, wire_cst_ln_bolt_12_invoice wireObj) {
+    wireObj.invoice = cst_encode_String(apiObj.invoice);
+    wireObj.signing_pubkey = cst_encode_String(apiObj.signingPubkey);
+    wireObj.payment_hash = cst_encode_String(apiObj.paymentHash);
+    wireObj.description = cst_encode_opt_String(apiObj.description);
+    wireObj.payer_note = cst_encode_opt_String(apiObj.payerNote);
+    wireObj.amount_msat = cst_encode_u_64(apiObj.amountMsat);
+    wireObj.created_at = cst_encode_u_64(apiObj.createdAt);
+    wireObj.expiry = cst_encode_u_64(apiObj.expiry);
+    wireObj.payment_path_count = cst_encode_u_32(apiObj.paymentPathCount);
+  }
+
   @protected
   void cst_api_fill_to_wire_ln_invoice(LNInvoice apiObj, wire_cst_ln_invoice wireObj) {
     wireObj.bolt11 = cst_encode_String(apiObj.bolt11);
@@ -3046,6 +5396,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       apiObj.lnurlPayUnprocessedSuccessAction,
     );
     wireObj.lnurl_withdraw_endpoint = cst_encode_opt_String(apiObj.lnurlWithdrawEndpoint);
+    wireObj.lnurl_pay_verify_url = cst_encode_opt_String(apiObj.lnurlPayVerifyUrl);
+    wireObj.lnurl_pay_verified_preimage = cst_encode_opt_String(apiObj.lnurlPayVerifiedPreimage);
   }
 
   @protected
@@ -3183,6 +5535,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.success_action = cst_encode_opt_box_autoadd_success_action_processed(apiObj.successAction);
   }
 
+  @protected
+  void cst_api_fill_to_wire_ln_url_payer_data(LnUrlPayerData apiObj, wire_cst_ln_url_payer_data wireObj) {
+    wireObj.name = cst_encode_opt_String(apiObj.name);
+    wireObj.pubkey = cst_encode_opt_String(apiObj.pubkey);
+    wireObj.identifier = cst_encode_opt_String(apiObj.identifier);
+    wireObj.email = cst_encode_opt_String(apiObj.email);
+  }
+
   @protected
   void cst_api_fill_to_wire_ln_url_withdraw_error(
     LnUrlWithdrawError apiObj,
@@ -3298,6 +5658,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void cst_api_fill_to_wire_log_entry(LogEntry apiObj, wire_cst_log_entry wireObj) {
     wireObj.line = cst_encode_String(apiObj.line);
     wireObj.level = cst_encode_String(apiObj.level);
+    wireObj.target = cst_encode_String(apiObj.target);
+    wireObj.timestamp = cst_encode_u_64(apiObj.timestamp);
   }
 
   @protected
@@ -3308,6 +5670,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.message = cst_encode_String(apiObj.message);
   }
 
+  @protected
+  void cst_api_fill_to_wire_nwc_budget(NwcBudget apiObj, wire_cst_nwc_budget wireObj) {
+    wireObj.max_amount_sat = cst_encode_u_64(apiObj.maxAmountSat);
+    wireObj.renewal_period_sec = cst_encode_opt_box_autoadd_u_32(apiObj.renewalPeriodSec);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_nwc_connection(NwcConnection apiObj, wire_cst_nwc_connection wireObj) {
+    wireObj.name = cst_encode_String(apiObj.name);
+    wireObj.client_pubkey = cst_encode_String(apiObj.clientPubkey);
+    wireObj.budget = cst_encode_opt_box_autoadd_nwc_budget(apiObj.budget);
+    wireObj.spent_sat = cst_encode_u_64(apiObj.spentSat);
+    wireObj.created_at = cst_encode_u_32(apiObj.createdAt);
+  }
+
   @protected
   void cst_api_fill_to_wire_onchain_payment_limits_response(
     OnchainPaymentLimitsResponse apiObj,
@@ -3335,8 +5712,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.kind.Asset.estimate_asset_fees = pre_estimate_asset_fees;
       return;
     }
-    if (apiObj is PayAmount_Drain) {
+    if (apiObj is PayAmount_AssetUnits) {
+      var pre_receiver_amount = cst_encode_box_autoadd_asset_amount(apiObj.receiverAmount);
+      var pre_estimate_asset_fees = cst_encode_opt_box_autoadd_bool(apiObj.estimateAssetFees);
       wireObj.tag = 2;
+      wireObj.kind.AssetUnits.receiver_amount = pre_receiver_amount;
+      wireObj.kind.AssetUnits.estimate_asset_fees = pre_estimate_asset_fees;
+      return;
+    }
+    if (apiObj is PayAmount_Drain) {
+      wireObj.tag = 3;
       return;
     }
   }
@@ -3348,6 +5733,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ) {
     wireObj.address = cst_encode_String(apiObj.address);
     cst_api_fill_to_wire_prepare_pay_onchain_response(apiObj.prepareResponse, wireObj.prepare_response);
+    wireObj.spend_limit_override = cst_encode_opt_String(apiObj.spendLimitOverride);
   }
 
   @protected
@@ -3362,6 +5748,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.payment_type = cst_encode_payment_type(apiObj.paymentType);
     wireObj.status = cst_encode_payment_state(apiObj.status);
     cst_api_fill_to_wire_payment_details(apiObj.details, wireObj.details);
+    wireObj.fiat_amount = cst_encode_opt_box_autoadd_f_64(apiObj.fiatAmount);
+    wireObj.fiat_currency = cst_encode_opt_String(apiObj.fiatCurrency);
+    wireObj.recurring_payment_id = cst_encode_opt_String(apiObj.recurringPaymentId);
   }
 
   @protected
@@ -3403,6 +5792,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       var pre_asset_info = cst_encode_opt_box_autoadd_asset_info(apiObj.assetInfo);
       var pre_lnurl_info = cst_encode_opt_box_autoadd_ln_url_info(apiObj.lnurlInfo);
       var pre_bip353_address = cst_encode_opt_String(apiObj.bip353Address);
+      var pre_conversion = cst_encode_opt_box_autoadd_asset_conversion(apiObj.conversion);
       wireObj.tag = 1;
       wireObj.kind.Liquid.destination = pre_destination;
       wireObj.kind.Liquid.description = pre_description;
@@ -3410,6 +5800,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.kind.Liquid.asset_info = pre_asset_info;
       wireObj.kind.Liquid.lnurl_info = pre_lnurl_info;
       wireObj.kind.Liquid.bip353_address = pre_bip353_address;
+      wireObj.kind.Liquid.conversion = pre_conversion;
       return;
     }
     if (apiObj is PaymentDetails_Bitcoin) {
@@ -3425,6 +5816,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       var pre_claim_tx_id = cst_encode_opt_String(apiObj.claimTxId);
       var pre_refund_tx_id = cst_encode_opt_String(apiObj.refundTxId);
       var pre_refund_tx_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.refundTxAmountSat);
+      var pre_buy_bitcoin_provider = cst_encode_opt_box_autoadd_buy_bitcoin_provider(
+        apiObj.buyBitcoinProvider,
+      );
       wireObj.tag = 2;
       wireObj.kind.Bitcoin.swap_id = pre_swap_id;
       wireObj.kind.Bitcoin.description = pre_description;
@@ -3434,6 +5828,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.kind.Bitcoin.claim_tx_id = pre_claim_tx_id;
       wireObj.kind.Bitcoin.refund_tx_id = pre_refund_tx_id;
       wireObj.kind.Bitcoin.refund_tx_amount_sat = pre_refund_tx_amount_sat;
+      wireObj.kind.Bitcoin.buy_bitcoin_provider = pre_buy_bitcoin_provider;
       return;
     }
   }
@@ -3484,68 +5879,102 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.tag = 8;
       return;
     }
-    if (apiObj is PaymentError_InsufficientFunds) {
+    if (apiObj is PaymentError_FeeLimitExceeded) {
+      var pre_fees_sat = cst_encode_u_64(apiObj.feesSat);
+      var pre_max_fee_sat = cst_encode_u_64(apiObj.maxFeeSat);
       wireObj.tag = 9;
+      wireObj.kind.FeeLimitExceeded.fees_sat = pre_fees_sat;
+      wireObj.kind.FeeLimitExceeded.max_fee_sat = pre_max_fee_sat;
+      return;
+    }
+    if (apiObj is PaymentError_InsufficientFunds) {
+      wireObj.tag = 10;
+      return;
+    }
+    if (apiObj is PaymentError_SpendLimitExceeded) {
+      var pre_amount_sat = cst_encode_u_64(apiObj.amountSat);
+      var pre_remaining_sat = cst_encode_u_64(apiObj.remainingSat);
+      wireObj.tag = 11;
+      wireObj.kind.SpendLimitExceeded.amount_sat = pre_amount_sat;
+      wireObj.kind.SpendLimitExceeded.remaining_sat = pre_remaining_sat;
       return;
     }
     if (apiObj is PaymentError_InvalidDescription) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 10;
+      wireObj.tag = 12;
       wireObj.kind.InvalidDescription.err = pre_err;
       return;
     }
     if (apiObj is PaymentError_InvalidInvoice) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 11;
+      wireObj.tag = 13;
       wireObj.kind.InvalidInvoice.err = pre_err;
       return;
     }
     if (apiObj is PaymentError_InvalidPreimage) {
-      wireObj.tag = 12;
+      wireObj.tag = 14;
       return;
     }
     if (apiObj is PaymentError_PairsNotFound) {
-      wireObj.tag = 13;
+      wireObj.tag = 15;
       return;
     }
     if (apiObj is PaymentError_PaymentTimeout) {
-      wireObj.tag = 14;
+      wireObj.tag = 16;
       return;
     }
     if (apiObj is PaymentError_PersistError) {
-      wireObj.tag = 15;
+      wireObj.tag = 17;
       return;
     }
     if (apiObj is PaymentError_ReceiveError) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 16;
+      wireObj.tag = 18;
       wireObj.kind.ReceiveError.err = pre_err;
       return;
     }
     if (apiObj is PaymentError_Refunded) {
       var pre_err = cst_encode_String(apiObj.err);
       var pre_refund_tx_id = cst_encode_String(apiObj.refundTxId);
-      wireObj.tag = 17;
+      wireObj.tag = 19;
       wireObj.kind.Refunded.err = pre_err;
       wireObj.kind.Refunded.refund_tx_id = pre_refund_tx_id;
       return;
     }
     if (apiObj is PaymentError_SelfTransferNotSupported) {
-      wireObj.tag = 18;
+      wireObj.tag = 20;
       return;
     }
     if (apiObj is PaymentError_SendError) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 19;
+      wireObj.tag = 21;
       wireObj.kind.SendError.err = pre_err;
       return;
     }
     if (apiObj is PaymentError_SignerError) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 20;
+      wireObj.tag = 22;
       wireObj.kind.SignerError.err = pre_err;
       return;
     }
+    if (apiObj is PaymentError_ServiceUnavailable) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 23;
+      wireObj.kind.ServiceUnavailable.err = pre_err;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_payment_unblinding_data(
+    PaymentUnblindingData apiObj,
+    wire_cst_payment_unblinding_data wireObj,
+  ) {
+    wireObj.tx_id = cst_encode_String(apiObj.txId);
+    wireObj.unblinding_data = cst_encode_String(apiObj.unblindingData);
+    wireObj.unblinded_url = cst_encode_opt_String(apiObj.unblindedUrl);
+    wireObj.inputs = cst_encode_list_unblinded_tx_out(apiObj.inputs);
+    wireObj.outputs = cst_encode_list_unblinded_tx_out(apiObj.outputs);
   }
 
   @protected
@@ -3567,6 +5996,28 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
   }
 
+  @protected
+  void cst_api_fill_to_wire_prepare_convert_request(
+    PrepareConvertRequest apiObj,
+    wire_cst_prepare_convert_request wireObj,
+  ) {
+    wireObj.from_asset_id = cst_encode_String(apiObj.fromAssetId);
+    wireObj.to_asset_id = cst_encode_String(apiObj.toAssetId);
+    wireObj.amount = cst_encode_u_64(apiObj.amount);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_convert_response(
+    PrepareConvertResponse apiObj,
+    wire_cst_prepare_convert_response wireObj,
+  ) {
+    wireObj.from_asset_id = cst_encode_String(apiObj.fromAssetId);
+    wireObj.to_asset_id = cst_encode_String(apiObj.toAssetId);
+    wireObj.amount = cst_encode_u_64(apiObj.amount);
+    wireObj.receiver_amount = cst_encode_u_64(apiObj.receiverAmount);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+  }
+
   @protected
   void cst_api_fill_to_wire_prepare_ln_url_pay_request(
     PrepareLnUrlPayRequest apiObj,
@@ -3576,6 +6027,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_pay_amount(apiObj.amount, wireObj.amount);
     wireObj.bip353_address = cst_encode_opt_String(apiObj.bip353Address);
     wireObj.comment = cst_encode_opt_String(apiObj.comment);
+    wireObj.payer_data = cst_encode_opt_box_autoadd_ln_url_payer_data(apiObj.payerData);
     wireObj.validate_success_action_url = cst_encode_opt_box_autoadd_bool(apiObj.validateSuccessActionUrl);
   }
 
@@ -3586,9 +6038,11 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ) {
     cst_api_fill_to_wire_send_destination(apiObj.destination, wireObj.destination);
     wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    cst_api_fill_to_wire_fee_breakdown(apiObj.feeBreakdown, wireObj.fee_breakdown);
     cst_api_fill_to_wire_ln_url_pay_request_data(apiObj.data, wireObj.data);
     wireObj.comment = cst_encode_opt_String(apiObj.comment);
     wireObj.success_action = cst_encode_opt_box_autoadd_success_action(apiObj.successAction);
+    wireObj.verify_url = cst_encode_opt_String(apiObj.verifyUrl);
   }
 
   @protected
@@ -3598,6 +6052,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ) {
     cst_api_fill_to_wire_pay_amount(apiObj.amount, wireObj.amount);
     wireObj.fee_rate_sat_per_vbyte = cst_encode_opt_box_autoadd_u_32(apiObj.feeRateSatPerVbyte);
+    wireObj.fee_speed = cst_encode_opt_box_autoadd_fee_speed(apiObj.feeSpeed);
   }
 
   @protected
@@ -3610,6 +6065,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.total_fees_sat = cst_encode_u_64(apiObj.totalFeesSat);
   }
 
+  @protected
+  void cst_api_fill_to_wire_prepare_receive_quotes_request(
+    PrepareReceiveQuotesRequest apiObj,
+    wire_cst_prepare_receive_quotes_request wireObj,
+  ) {
+    wireObj.amount = cst_encode_opt_box_autoadd_receive_amount(apiObj.amount);
+    wireObj.fiat_amount = cst_encode_opt_box_autoadd_fiat_amount(apiObj.fiatAmount);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_receive_quotes_response(
+    PrepareReceiveQuotesResponse apiObj,
+    wire_cst_prepare_receive_quotes_response wireObj,
+  ) {
+    wireObj.quotes = cst_encode_list_prepare_receive_response(apiObj.quotes);
+  }
+
   @protected
   void cst_api_fill_to_wire_prepare_receive_request(
     PrepareReceiveRequest apiObj,
@@ -3617,6 +6089,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ) {
     wireObj.payment_method = cst_encode_payment_method(apiObj.paymentMethod);
     wireObj.amount = cst_encode_opt_box_autoadd_receive_amount(apiObj.amount);
+    wireObj.fiat_amount = cst_encode_opt_box_autoadd_fiat_amount(apiObj.fiatAmount);
+    wireObj.expiry_secs = cst_encode_opt_box_autoadd_u_32(apiObj.expirySecs);
   }
 
   @protected
@@ -3627,9 +6101,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.payment_method = cst_encode_payment_method(apiObj.paymentMethod);
     wireObj.amount = cst_encode_opt_box_autoadd_receive_amount(apiObj.amount);
     wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    cst_api_fill_to_wire_fee_breakdown(apiObj.feeBreakdown, wireObj.fee_breakdown);
     wireObj.min_payer_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.minPayerAmountSat);
     wireObj.max_payer_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxPayerAmountSat);
     wireObj.swapper_feerate = cst_encode_opt_box_autoadd_f_64(apiObj.swapperFeerate);
+    wireObj.fiat_conversion = cst_encode_opt_box_autoadd_fiat_conversion(apiObj.fiatConversion);
+    wireObj.expiry_secs = cst_encode_opt_box_autoadd_u_32(apiObj.expirySecs);
   }
 
   @protected
@@ -3639,7 +6116,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ) {
     wireObj.swap_address = cst_encode_String(apiObj.swapAddress);
     wireObj.refund_address = cst_encode_String(apiObj.refundAddress);
-    wireObj.fee_rate_sat_per_vbyte = cst_encode_u_32(apiObj.feeRateSatPerVbyte);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_opt_box_autoadd_u_32(apiObj.feeRateSatPerVbyte);
+    wireObj.fee_speed = cst_encode_opt_box_autoadd_fee_speed(apiObj.feeSpeed);
   }
 
   @protected
@@ -3649,6 +6127,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ) {
     wireObj.tx_vsize = cst_encode_u_32(apiObj.txVsize);
     wireObj.tx_fee_sat = cst_encode_u_64(apiObj.txFeeSat);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_u_32(apiObj.feeRateSatPerVbyte);
+    wireObj.refundable_amount_sat = cst_encode_u_64(apiObj.refundableAmountSat);
+    wireObj.refund_amount_sat = cst_encode_u_64(apiObj.refundAmountSat);
     wireObj.last_refund_tx_id = cst_encode_opt_String(apiObj.lastRefundTxId);
   }
 
@@ -3659,6 +6140,7 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ) {
     wireObj.destination = cst_encode_String(apiObj.destination);
     wireObj.amount = cst_encode_opt_box_autoadd_pay_amount(apiObj.amount);
+    wireObj.fiat_amount = cst_encode_opt_box_autoadd_fiat_amount(apiObj.fiatAmount);
   }
 
   @protected
@@ -3668,7 +6150,59 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ) {
     cst_api_fill_to_wire_send_destination(apiObj.destination, wireObj.destination);
     wireObj.fees_sat = cst_encode_opt_box_autoadd_u_64(apiObj.feesSat);
+    wireObj.fee_breakdown = cst_encode_opt_box_autoadd_fee_breakdown(apiObj.feeBreakdown);
     wireObj.estimated_asset_fees = cst_encode_opt_box_autoadd_f_64(apiObj.estimatedAssetFees);
+    wireObj.estimated_asset_fees_amount = cst_encode_opt_box_autoadd_asset_amount(
+      apiObj.estimatedAssetFeesAmount,
+    );
+    wireObj.fiat_conversion = cst_encode_opt_box_autoadd_fiat_conversion(apiObj.fiatConversion);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_process_notification_request(
+    ProcessNotificationRequest apiObj,
+    wire_cst_process_notification_request wireObj,
+  ) {
+    cst_api_fill_to_wire_connect_request(apiObj.connectRequest, wireObj.connect_request);
+    wireObj.payload = cst_encode_String(apiObj.payload);
+    wireObj.accept_proposed_fees = cst_encode_opt_box_autoadd_bool(apiObj.acceptProposedFees);
+    wireObj.timeout_sec = cst_encode_opt_box_autoadd_u_32(apiObj.timeoutSec);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_process_notification_response(
+    ProcessNotificationResponse apiObj,
+    wire_cst_process_notification_response wireObj,
+  ) {
+    cst_api_fill_to_wire_payment(apiObj.payment, wireObj.payment);
+    wireObj.is_resolved = cst_encode_bool(apiObj.isResolved);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_pset_spend_limit(PsetSpendLimit apiObj, wire_cst_pset_spend_limit wireObj) {
+    wireObj.asset_id = cst_encode_String(apiObj.assetId);
+    wireObj.max_amount_sat = cst_encode_u_64(apiObj.maxAmountSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_queue_payment_request(
+    QueuePaymentRequest apiObj,
+    wire_cst_queue_payment_request wireObj,
+  ) {
+    cst_api_fill_to_wire_prepare_send_request(apiObj.prepareRequest, wireObj.prepare_request);
+    wireObj.use_asset_fees = cst_encode_opt_box_autoadd_bool(apiObj.useAssetFees);
+    wireObj.max_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxFeeSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_queued_payment(QueuedPayment apiObj, wire_cst_queued_payment wireObj) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    cst_api_fill_to_wire_queue_payment_request(apiObj.request, wireObj.request);
+    wireObj.state = cst_encode_queued_payment_state(apiObj.state);
+    wireObj.attempts = cst_encode_u_32(apiObj.attempts);
+    wireObj.created_at = cst_encode_u_32(apiObj.createdAt);
+    wireObj.next_attempt_at = cst_encode_u_32(apiObj.nextAttemptAt);
+    wireObj.last_error = cst_encode_opt_String(apiObj.lastError);
   }
 
   @protected
@@ -3693,6 +6227,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.kind.Asset.payer_amount = pre_payer_amount;
       return;
     }
+    if (apiObj is ReceiveAmount_AssetUnits) {
+      var pre_payer_amount = cst_encode_box_autoadd_asset_amount(apiObj.payerAmount);
+      wireObj.tag = 2;
+      wireObj.kind.AssetUnits.payer_amount = pre_payer_amount;
+      return;
+    }
   }
 
   @protected
@@ -3703,6 +6243,8 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_prepare_receive_response(apiObj.prepareResponse, wireObj.prepare_response);
     wireObj.description = cst_encode_opt_String(apiObj.description);
     wireObj.use_description_hash = cst_encode_opt_box_autoadd_bool(apiObj.useDescriptionHash);
+    wireObj.description_hash = cst_encode_opt_String(apiObj.descriptionHash);
+    wireObj.claim_address = cst_encode_opt_String(apiObj.claimAddress);
   }
 
   @protected
@@ -3722,11 +6264,35 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.minimum_fee = cst_encode_u_64(apiObj.minimumFee);
   }
 
+  @protected
+  void cst_api_fill_to_wire_recurring_payment(RecurringPayment apiObj, wire_cst_recurring_payment wireObj) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.destination = cst_encode_String(apiObj.destination);
+    wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
+    wireObj.interval_sec = cst_encode_u_32(apiObj.intervalSec);
+    wireObj.max_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxFeeSat);
+    wireObj.created_at = cst_encode_u_32(apiObj.createdAt);
+    wireObj.next_occurrence_at = cst_encode_u_32(apiObj.nextOccurrenceAt);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_recurring_payment_occurrence(
+    RecurringPaymentOccurrence apiObj,
+    wire_cst_recurring_payment_occurrence wireObj,
+  ) {
+    wireObj.recurring_payment_id = cst_encode_String(apiObj.recurringPaymentId);
+    wireObj.occurrence_at = cst_encode_u_32(apiObj.occurrenceAt);
+    wireObj.executed_at = cst_encode_u_32(apiObj.executedAt);
+    wireObj.tx_id = cst_encode_opt_String(apiObj.txId);
+    wireObj.error = cst_encode_opt_String(apiObj.error);
+  }
+
   @protected
   void cst_api_fill_to_wire_refund_request(RefundRequest apiObj, wire_cst_refund_request wireObj) {
     wireObj.swap_address = cst_encode_String(apiObj.swapAddress);
     wireObj.refund_address = cst_encode_String(apiObj.refundAddress);
-    wireObj.fee_rate_sat_per_vbyte = cst_encode_u_32(apiObj.feeRateSatPerVbyte);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_opt_box_autoadd_u_32(apiObj.feeRateSatPerVbyte);
+    wireObj.fee_speed = cst_encode_opt_box_autoadd_fee_speed(apiObj.feeSpeed);
   }
 
   @protected
@@ -3740,6 +6306,37 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.timestamp = cst_encode_u_32(apiObj.timestamp);
     wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
     wireObj.last_refund_tx_id = cst_encode_opt_String(apiObj.lastRefundTxId);
+    wireObj.state = cst_encode_payment_state(apiObj.state);
+    wireObj.refundable_since = cst_encode_u_32(apiObj.refundableSince);
+    wireObj.refund_eligible_block_height = cst_encode_u_32(apiObj.refundEligibleBlockHeight);
+    wireObj.is_cooperative_refund_possible = cst_encode_bool(apiObj.isCooperativeRefundPossible);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_register_lightning_address_request(
+    RegisterLightningAddressRequest apiObj,
+    wire_cst_register_lightning_address_request wireObj,
+  ) {
+    wireObj.username = cst_encode_String(apiObj.username);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_registered_asset(RegisteredAsset apiObj, wire_cst_registered_asset wireObj) {
+    wireObj.asset_id = cst_encode_String(apiObj.assetId);
+    wireObj.name = cst_encode_String(apiObj.name);
+    wireObj.ticker = cst_encode_opt_String(apiObj.ticker);
+    wireObj.precision = cst_encode_u_8(apiObj.precision);
+    wireObj.domain = cst_encode_opt_String(apiObj.domain);
+    wireObj.icon_url = cst_encode_String(apiObj.iconUrl);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_replay_events_request(
+    ReplayEventsRequest apiObj,
+    wire_cst_replay_events_request wireObj,
+  ) {
+    wireObj.since_seq = cst_encode_opt_box_autoadd_u_64(apiObj.sinceSeq);
+    wireObj.limit = cst_encode_opt_box_autoadd_u_32(apiObj.limit);
   }
 
   @protected
@@ -3747,6 +6344,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.backup_path = cst_encode_opt_String(apiObj.backupPath);
   }
 
+  @protected
+  void cst_api_fill_to_wire_reusable_bitcoin_address_response(
+    ReusableBitcoinAddressResponse apiObj,
+    wire_cst_reusable_bitcoin_address_response wireObj,
+  ) {
+    wireObj.address = cst_encode_String(apiObj.address);
+    wireObj.min_deposit_sat = cst_encode_u_64(apiObj.minDepositSat);
+    wireObj.max_deposit_sat = cst_encode_u_64(apiObj.maxDepositSat);
+  }
+
   @protected
   void cst_api_fill_to_wire_route_hint(RouteHint apiObj, wire_cst_route_hint wireObj) {
     wireObj.hops = cst_encode_list_route_hint_hop(apiObj.hops);
@@ -3763,25 +6370,60 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.htlc_maximum_msat = cst_encode_opt_box_autoadd_u_64(apiObj.htlcMaximumMsat);
   }
 
+  @protected
+  void cst_api_fill_to_wire_schedule_payment_request(
+    SchedulePaymentRequest apiObj,
+    wire_cst_schedule_payment_request wireObj,
+  ) {
+    cst_api_fill_to_wire_prepare_send_request(apiObj.prepareRequest, wireObj.prepare_request);
+    wireObj.use_asset_fees = cst_encode_opt_box_autoadd_bool(apiObj.useAssetFees);
+    wireObj.max_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxFeeSat);
+    wireObj.execute_at = cst_encode_u_32(apiObj.executeAt);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_scheduled_payment(ScheduledPayment apiObj, wire_cst_scheduled_payment wireObj) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    cst_api_fill_to_wire_schedule_payment_request(apiObj.request, wireObj.request);
+    wireObj.state = cst_encode_scheduled_payment_state(apiObj.state);
+    wireObj.attempts = cst_encode_u_32(apiObj.attempts);
+    wireObj.created_at = cst_encode_u_32(apiObj.createdAt);
+    wireObj.next_attempt_at = cst_encode_u_32(apiObj.nextAttemptAt);
+    wireObj.tx_id = cst_encode_opt_String(apiObj.txId);
+    wireObj.last_error = cst_encode_opt_String(apiObj.lastError);
+  }
+
   @protected
   void cst_api_fill_to_wire_sdk_error(SdkError apiObj, wire_cst_sdk_error wireObj) {
     if (apiObj is SdkError_AlreadyStarted) {
       wireObj.tag = 0;
       return;
     }
+    if (apiObj is SdkError_DataDirLocked) {
+      var pre_dir = cst_encode_String(apiObj.dir);
+      wireObj.tag = 1;
+      wireObj.kind.DataDirLocked.dir = pre_dir;
+      return;
+    }
     if (apiObj is SdkError_Generic) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 1;
+      wireObj.tag = 2;
       wireObj.kind.Generic.err = pre_err;
       return;
     }
+    if (apiObj is SdkError_InvalidBackup) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 3;
+      wireObj.kind.InvalidBackup.err = pre_err;
+      return;
+    }
     if (apiObj is SdkError_NotStarted) {
-      wireObj.tag = 2;
+      wireObj.tag = 4;
       return;
     }
     if (apiObj is SdkError_ServiceConnectivity) {
       var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 3;
+      wireObj.tag = 5;
       wireObj.kind.ServiceConnectivity.err = pre_err;
       return;
     }
@@ -3837,27 +6479,173 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
       wireObj.kind.PaymentWaitingFeeAcceptance.details = pre_details;
       return;
     }
-    if (apiObj is SdkEvent_Synced) {
+    if (apiObj is SdkEvent_PaymentExpired) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
       wireObj.tag = 8;
+      wireObj.kind.PaymentExpired.details = pre_details;
       return;
     }
-    if (apiObj is SdkEvent_DataSynced) {
-      var pre_did_pull_new_records = cst_encode_bool(apiObj.didPullNewRecords);
+    if (apiObj is SdkEvent_PaymentReorged) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
       wireObj.tag = 9;
-      wireObj.kind.DataSynced.did_pull_new_records = pre_did_pull_new_records;
+      wireObj.kind.PaymentReorged.details = pre_details;
       return;
     }
-  }
-
-  @protected
-  void cst_api_fill_to_wire_send_destination(SendDestination apiObj, wire_cst_send_destination wireObj) {
-    if (apiObj is SendDestination_LiquidAddress) {
-      var pre_address_data = cst_encode_box_autoadd_liquid_address_data(apiObj.addressData);
-      var pre_bip353_address = cst_encode_opt_String(apiObj.bip353Address);
-      wireObj.tag = 0;
-      wireObj.kind.LiquidAddress.address_data = pre_address_data;
-      wireObj.kind.LiquidAddress.bip353_address = pre_bip353_address;
-      return;
+    if (apiObj is SdkEvent_SwapUpdated) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      var pre_swap_type = cst_encode_payment_swap_type(apiObj.swapType);
+      var pre_state = cst_encode_payment_state(apiObj.state);
+      wireObj.tag = 10;
+      wireObj.kind.SwapUpdated.swap_id = pre_swap_id;
+      wireObj.kind.SwapUpdated.swap_type = pre_swap_type;
+      wireObj.kind.SwapUpdated.state = pre_state;
+      return;
+    }
+    if (apiObj is SdkEvent_SwapClaimBroadcast) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      var pre_tx_id = cst_encode_String(apiObj.txId);
+      wireObj.tag = 11;
+      wireObj.kind.SwapClaimBroadcast.swap_id = pre_swap_id;
+      wireObj.kind.SwapClaimBroadcast.tx_id = pre_tx_id;
+      return;
+    }
+    if (apiObj is SdkEvent_SwapRefundBroadcast) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      var pre_tx_id = cst_encode_String(apiObj.txId);
+      wireObj.tag = 12;
+      wireObj.kind.SwapRefundBroadcast.swap_id = pre_swap_id;
+      wireObj.kind.SwapRefundBroadcast.tx_id = pre_tx_id;
+      return;
+    }
+    if (apiObj is SdkEvent_TipsUpdated) {
+      var pre_liquid_tip = cst_encode_u_32(apiObj.liquidTip);
+      var pre_bitcoin_tip = cst_encode_u_32(apiObj.bitcoinTip);
+      wireObj.tag = 13;
+      wireObj.kind.TipsUpdated.liquid_tip = pre_liquid_tip;
+      wireObj.kind.TipsUpdated.bitcoin_tip = pre_bitcoin_tip;
+      return;
+    }
+    if (apiObj is SdkEvent_SyncStarted) {
+      wireObj.tag = 14;
+      return;
+    }
+    if (apiObj is SdkEvent_Synced) {
+      wireObj.tag = 15;
+      return;
+    }
+    if (apiObj is SdkEvent_DataSynced) {
+      var pre_did_pull_new_records = cst_encode_bool(apiObj.didPullNewRecords);
+      wireObj.tag = 16;
+      wireObj.kind.DataSynced.did_pull_new_records = pre_did_pull_new_records;
+      return;
+    }
+    if (apiObj is SdkEvent_ConnectivityChanged) {
+      var pre_is_online = cst_encode_bool(apiObj.isOnline);
+      wireObj.tag = 17;
+      wireObj.kind.ConnectivityChanged.is_online = pre_is_online;
+      return;
+    }
+    if (apiObj is SdkEvent_BackupSucceeded) {
+      wireObj.tag = 18;
+      return;
+    }
+    if (apiObj is SdkEvent_BackupFailed) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 19;
+      wireObj.kind.BackupFailed.err = pre_err;
+      return;
+    }
+    if (apiObj is SdkEvent_BatchSendCompleted) {
+      var pre_results = cst_encode_list_batch_send_item_result(apiObj.results);
+      wireObj.tag = 20;
+      wireObj.kind.BatchSendCompleted.results = pre_results;
+      return;
+    }
+    if (apiObj is SdkEvent_ScheduledPaymentExecuted) {
+      var pre_id = cst_encode_String(apiObj.id);
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 21;
+      wireObj.kind.ScheduledPaymentExecuted.id = pre_id;
+      wireObj.kind.ScheduledPaymentExecuted.details = pre_details;
+      return;
+    }
+    if (apiObj is SdkEvent_ScheduledPaymentFailed) {
+      var pre_id = cst_encode_String(apiObj.id);
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 22;
+      wireObj.kind.ScheduledPaymentFailed.id = pre_id;
+      wireObj.kind.ScheduledPaymentFailed.err = pre_err;
+      return;
+    }
+    if (apiObj is SdkEvent_RecurringPaymentExecuted) {
+      var pre_recurring_payment_id = cst_encode_String(apiObj.recurringPaymentId);
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 23;
+      wireObj.kind.RecurringPaymentExecuted.recurring_payment_id = pre_recurring_payment_id;
+      wireObj.kind.RecurringPaymentExecuted.details = pre_details;
+      return;
+    }
+    if (apiObj is SdkEvent_RecurringPaymentFailed) {
+      var pre_recurring_payment_id = cst_encode_String(apiObj.recurringPaymentId);
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 24;
+      wireObj.kind.RecurringPaymentFailed.recurring_payment_id = pre_recurring_payment_id;
+      wireObj.kind.RecurringPaymentFailed.err = pre_err;
+      return;
+    }
+    if (apiObj is SdkEvent_TransactionDropped) {
+      var pre_tx_id = cst_encode_String(apiObj.txId);
+      wireObj.tag = 25;
+      wireObj.kind.TransactionDropped.tx_id = pre_tx_id;
+      return;
+    }
+    if (apiObj is SdkEvent_TransactionUnconfirmed) {
+      var pre_tx_id = cst_encode_String(apiObj.txId);
+      var pre_unconfirmed_sec = cst_encode_u_32(apiObj.unconfirmedSec);
+      wireObj.tag = 26;
+      wireObj.kind.TransactionUnconfirmed.tx_id = pre_tx_id;
+      wireObj.kind.TransactionUnconfirmed.unconfirmed_sec = pre_unconfirmed_sec;
+      return;
+    }
+    if (apiObj is SdkEvent_NewLiquidBlock) {
+      var pre_block = cst_encode_box_autoadd_block_info(apiObj.block);
+      wireObj.tag = 27;
+      wireObj.kind.NewLiquidBlock.block = pre_block;
+      return;
+    }
+    if (apiObj is SdkEvent_NewBitcoinBlock) {
+      var pre_block = cst_encode_box_autoadd_block_info(apiObj.block);
+      wireObj.tag = 28;
+      wireObj.kind.NewBitcoinBlock.block = pre_block;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_send_batch_request(SendBatchRequest apiObj, wire_cst_send_batch_request wireObj) {
+    wireObj.items = cst_encode_list_batch_send_item(apiObj.items);
+    wireObj.max_total_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxTotalFeeSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_send_batch_response(
+    SendBatchResponse apiObj,
+    wire_cst_send_batch_response wireObj,
+  ) {
+    wireObj.results = cst_encode_list_batch_send_item_result(apiObj.results);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_send_destination(SendDestination apiObj, wire_cst_send_destination wireObj) {
+    if (apiObj is SendDestination_LiquidAddress) {
+      var pre_address_data = cst_encode_box_autoadd_liquid_address_data(apiObj.addressData);
+      var pre_bip353_address = cst_encode_opt_String(apiObj.bip353Address);
+      var pre_payjoin_url = cst_encode_opt_String(apiObj.payjoinUrl);
+      wireObj.tag = 0;
+      wireObj.kind.LiquidAddress.address_data = pre_address_data;
+      wireObj.kind.LiquidAddress.bip353_address = pre_bip353_address;
+      wireObj.kind.LiquidAddress.payjoin_url = pre_payjoin_url;
+      return;
     }
     if (apiObj is SendDestination_Bolt11) {
       var pre_invoice = cst_encode_box_autoadd_ln_invoice(apiObj.invoice);
@@ -3886,6 +6674,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   ) {
     cst_api_fill_to_wire_prepare_send_response(apiObj.prepareResponse, wireObj.prepare_response);
     wireObj.use_asset_fees = cst_encode_opt_box_autoadd_bool(apiObj.useAssetFees);
+    wireObj.max_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxFeeSat);
+    wireObj.spend_limit_override = cst_encode_opt_String(apiObj.spendLimitOverride);
+    wireObj.idempotency_key = cst_encode_opt_String(apiObj.idempotencyKey);
   }
 
   @protected
@@ -3896,6 +6687,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     cst_api_fill_to_wire_payment(apiObj.payment, wireObj.payment);
   }
 
+  @protected
+  void cst_api_fill_to_wire_service_health(ServiceHealth apiObj, wire_cst_service_health wireObj) {
+    wireObj.service = cst_encode_health_check_service(apiObj.service);
+    wireObj.is_reachable = cst_encode_bool(apiObj.isReachable);
+    wireObj.latency_ms = cst_encode_opt_box_autoadd_u_64(apiObj.latencyMs);
+    wireObj.error = cst_encode_opt_String(apiObj.error);
+  }
+
   @protected
   void cst_api_fill_to_wire_sign_message_request(
     SignMessageRequest apiObj,
@@ -3910,6 +6709,49 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wire_cst_sign_message_response wireObj,
   ) {
     wireObj.signature = cst_encode_String(apiObj.signature);
+    wireObj.pubkey = cst_encode_String(apiObj.pubkey);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_sign_pset_request(SignPsetRequest apiObj, wire_cst_sign_pset_request wireObj) {
+    wireObj.pset = cst_encode_String(apiObj.pset);
+    wireObj.max_fee_sat = cst_encode_u_64(apiObj.maxFeeSat);
+    wireObj.spend_limits = cst_encode_list_pset_spend_limit(apiObj.spendLimits);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_sign_pset_response(SignPsetResponse apiObj, wire_cst_sign_pset_response wireObj) {
+    wireObj.pset = cst_encode_String(apiObj.pset);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_simulation_config(SimulationConfig apiObj, wire_cst_simulation_config wireObj) {
+    wireObj.status_update_delay_sec = cst_encode_u_32(apiObj.statusUpdateDelaySec);
+    wireObj.failure_rate = cst_encode_f_64(apiObj.failureRate);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_spend_limit(SpendLimit apiObj, wire_cst_spend_limit wireObj) {
+    if (apiObj is SpendLimit_Bitcoin) {
+      var pre_amount_sat = cst_encode_u_64(apiObj.amountSat);
+      wireObj.tag = 0;
+      wireObj.kind.Bitcoin.amount_sat = pre_amount_sat;
+      return;
+    }
+    if (apiObj is SpendLimit_Fiat) {
+      var pre_amount = cst_encode_box_autoadd_fiat_amount(apiObj.amount);
+      wireObj.tag = 1;
+      wireObj.kind.Fiat.amount = pre_amount;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_spend_limits(SpendLimits apiObj, wire_cst_spend_limits wireObj) {
+    wireObj.per_payment = cst_encode_opt_box_autoadd_spend_limit(apiObj.perPayment);
+    wireObj.per_day = cst_encode_opt_box_autoadd_spend_limit(apiObj.perDay);
+    wireObj.override_secret = cst_encode_opt_String(apiObj.overrideSecret);
   }
 
   @protected
@@ -3959,6 +6801,56 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     }
   }
 
+  @protected
+  void cst_api_fill_to_wire_swap_info(SwapInfo apiObj, wire_cst_swap_info wireObj) {
+    wireObj.swap_id = cst_encode_String(apiObj.swapId);
+    wireObj.swap_type = cst_encode_payment_swap_type(apiObj.swapType);
+    wireObj.direction = cst_encode_opt_box_autoadd_direction(apiObj.direction);
+    wireObj.state = cst_encode_payment_state(apiObj.state);
+    wireObj.created_at = cst_encode_u_32(apiObj.createdAt);
+    wireObj.timeout_block_height = cst_encode_u_64(apiObj.timeoutBlockHeight);
+    wireObj.payer_amount_sat = cst_encode_u_64(apiObj.payerAmountSat);
+    wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
+    wireObj.invoice = cst_encode_opt_String(apiObj.invoice);
+    wireObj.lockup_address = cst_encode_opt_String(apiObj.lockupAddress);
+    wireObj.user_lockup_tx_id = cst_encode_opt_String(apiObj.userLockupTxId);
+    wireObj.server_lockup_tx_id = cst_encode_opt_String(apiObj.serverLockupTxId);
+    wireObj.claim_address = cst_encode_opt_String(apiObj.claimAddress);
+    wireObj.claim_tx_id = cst_encode_opt_String(apiObj.claimTxId);
+    wireObj.refund_address = cst_encode_opt_String(apiObj.refundAddress);
+    wireObj.refund_tx_id = cst_encode_opt_String(apiObj.refundTxId);
+    wireObj.create_response_json = cst_encode_String(apiObj.createResponseJson);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_swap_service_status(
+    SwapServiceStatus apiObj,
+    wire_cst_swap_service_status wireObj,
+  ) {
+    wireObj.send_available = cst_encode_bool(apiObj.sendAvailable);
+    wireObj.receive_available = cst_encode_bool(apiObj.receiveAvailable);
+    wireObj.chain_send_available = cst_encode_bool(apiObj.chainSendAvailable);
+    wireObj.chain_receive_available = cst_encode_bool(apiObj.chainReceiveAvailable);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_sweep_private_key_request(
+    SweepPrivateKeyRequest apiObj,
+    wire_cst_sweep_private_key_request wireObj,
+  ) {
+    wireObj.key = cst_encode_String(apiObj.key);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_sweep_private_key_response(
+    SweepPrivateKeyResponse apiObj,
+    wire_cst_sweep_private_key_response wireObj,
+  ) {
+    cst_api_fill_to_wire_payment(apiObj.payment, wireObj.payment);
+    wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
+    wireObj.asset_amounts = cst_encode_list_asset_amount(apiObj.assetAmounts);
+  }
+
   @protected
   void cst_api_fill_to_wire_symbol(Symbol apiObj, wire_cst_symbol wireObj) {
     wireObj.grapheme = cst_encode_opt_String(apiObj.grapheme);
@@ -3967,6 +6859,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.position = cst_encode_opt_box_autoadd_u_32(apiObj.position);
   }
 
+  @protected
+  void cst_api_fill_to_wire_sync_request(SyncRequest apiObj, wire_cst_sync_request wireObj) {
+    wireObj.full = cst_encode_opt_box_autoadd_bool(apiObj.full);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_sync_response(SyncResponse apiObj, wire_cst_sync_response wireObj) {
+    wireObj.new_tx_count = cst_encode_u_32(apiObj.newTxCount);
+    wireObj.updated_swap_count = cst_encode_u_32(apiObj.updatedSwapCount);
+    wireObj.duration_ms = cst_encode_u_64(apiObj.durationMs);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_unblinded_tx_out(UnblindedTxOut apiObj, wire_cst_unblinded_tx_out wireObj) {
+    wireObj.outpoint = cst_encode_String(apiObj.outpoint);
+    wireObj.asset_id = cst_encode_String(apiObj.assetId);
+    wireObj.amount = cst_encode_u_64(apiObj.amount);
+    wireObj.asset_blinding_factor = cst_encode_String(apiObj.assetBlindingFactor);
+    wireObj.amount_blinding_factor = cst_encode_String(apiObj.amountBlindingFactor);
+  }
+
   @protected
   void cst_api_fill_to_wire_url_success_action_data(
     UrlSuccessActionData apiObj,
@@ -3977,6 +6890,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     wireObj.matches_callback_domain = cst_encode_bool(apiObj.matchesCallbackDomain);
   }
 
+  @protected
+  void cst_api_fill_to_wire_validate_address_response(
+    ValidateAddressResponse apiObj,
+    wire_cst_validate_address_response wireObj,
+  ) {
+    wireObj.address_type = cst_encode_address_type(apiObj.addressType);
+    wireObj.network = cst_encode_liquid_network(apiObj.network);
+    wireObj.is_confidential = cst_encode_bool(apiObj.isConfidential);
+    wireObj.supports_assets = cst_encode_bool(apiObj.supportsAssets);
+  }
+
   @protected
   void cst_api_fill_to_wire_wallet_info(WalletInfo apiObj, wire_cst_wallet_info wireObj) {
     wireObj.balance_sat = cst_encode_u_64(apiObj.balanceSat);
@@ -3997,20 +6921,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     BindingLiquidSdk raw,
   );
 
+  @protected
+  PlatformPointer cst_encode_DartFn_Inputs_String_Output_String_AnyhowException(
+    FutureOr<String> Function(String) raw,
+  );
+
+  @protected
+  PlatformPointer cst_encode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(
+    FutureOr<InputType?> Function(String) raw,
+  );
+
+  @protected
+  PlatformPointer cst_encode_DartOpaque(Object raw);
+
   @protected
   int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     BindingLiquidSdk raw,
   );
 
+  @protected
+  int cst_encode_address_type(AddressType raw);
+
   @protected
   bool cst_encode_bool(bool raw);
 
   @protected
   int cst_encode_buy_bitcoin_provider(BuyBitcoinProvider raw);
 
+  @protected
+  int cst_encode_direction(Direction raw);
+
   @protected
   double cst_encode_f_64(double raw);
 
+  @protected
+  int cst_encode_fee_speed(FeeSpeed raw);
+
+  @protected
+  int cst_encode_health_check_service(HealthCheckService raw);
+
   @protected
   int cst_encode_i_32(int raw);
 
@@ -4026,9 +6975,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int cst_encode_payment_state(PaymentState raw);
 
+  @protected
+  int cst_encode_payment_swap_type(PaymentSwapType raw);
+
   @protected
   int cst_encode_payment_type(PaymentType raw);
 
+  @protected
+  int cst_encode_queued_payment_state(QueuedPaymentState raw);
+
+  @protected
+  int cst_encode_scheduled_payment_state(ScheduledPaymentState raw);
+
+  @protected
+  int cst_encode_sdk_event_kind(SdkEventKind raw);
+
   @protected
   int cst_encode_u_16(int raw);
 
@@ -4056,6 +7017,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_String_AnyhowException(
+    FutureOr<String> Function(String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_opt_box_autoadd_input_type_AnyhowException(
+    FutureOr<InputType?> Function(String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
   @protected
   void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     BindingLiquidSdk self,
@@ -4077,6 +7053,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_add_nwc_connection_request(AddNwcConnectionRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_add_nwc_connection_response(AddNwcConnectionResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_address_type(AddressType self, SseSerializer serializer);
+
   @protected
   void sse_encode_aes_success_action_data(AesSuccessActionData self, SseSerializer serializer);
 
@@ -4092,24 +7077,45 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_amount(Amount self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_amp_account(AmpAccount self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_asset_amount(AssetAmount self, SseSerializer serializer);
+
   @protected
   void sse_encode_asset_balance(AssetBalance self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_asset_conversion(AssetConversion self, SseSerializer serializer);
+
   @protected
   void sse_encode_asset_info(AssetInfo self, SseSerializer serializer);
 
   @protected
   void sse_encode_asset_metadata(AssetMetadata self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_asset_report(AssetReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_policy(BackupPolicy self, SseSerializer serializer);
+
   @protected
   void sse_encode_backup_request(BackupRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_binding_event_listener(BindingEventListener self, SseSerializer serializer);
+  void sse_encode_batch_send_item(BatchSendItem self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_batch_send_item_result(BatchSendItemResult self, SseSerializer serializer);
 
   @protected
   void sse_encode_bitcoin_address_data(BitcoinAddressData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_block_info(BlockInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_blockchain_explorer(BlockchainExplorer self, SseSerializer serializer);
 
@@ -4125,6 +7131,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_add_nwc_connection_request(
+    AddNwcConnectionRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_aes_success_action_data(AesSuccessActionData self, SseSerializer serializer);
 
@@ -4143,21 +7155,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_amount(Amount self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_amp_account(AmpAccount self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_asset_amount(AssetAmount self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_asset_conversion(AssetConversion self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_asset_info(AssetInfo self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer);
+  void sse_encode_box_autoadd_backup_policy(BackupPolicy self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_binding_event_listener(BindingEventListener self, SseSerializer serializer);
+  void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer);
 
   @protected
   void sse_encode_box_autoadd_bitcoin_address_data(BitcoinAddressData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_block_info(BlockInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_buy_bitcoin_provider(BuyBitcoinProvider self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_buy_bitcoin_request(BuyBitcoinRequest self, SseSerializer serializer);
 
@@ -4167,21 +7194,81 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_connect_request(ConnectRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_convert_request(ConvertRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_create_recurring_payment_request(
+    CreateRecurringPaymentRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_direction(Direction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_event_filter(EventFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_event_webhook_config(EventWebhookConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_export_payment_unblinding_data_request(
+    ExportPaymentUnblindingDataRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_export_wallet_request(ExportWalletRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_fee_breakdown(FeeBreakdown self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_fee_speed(FeeSpeed self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_fetch_payment_proposed_fees_request(
     FetchPaymentProposedFeesRequest self,
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_fiat_amount(FiatAmount self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_fiat_conversion(FiatConversion self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_get_max_payable_request(GetMaxPayableRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_get_max_receivable_request(
+    GetMaxReceivableRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_get_payment_request(GetPaymentRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_get_report_request(GetReportRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_import_wallet_request(ImportWalletRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_input_type(InputType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_lightning_address_info(LightningAddressInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_liquid_address_data(LiquidAddressData self, SseSerializer serializer);
 
@@ -4191,6 +7278,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_list_payments_request(ListPaymentsRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_list_swaps_request(ListSwapsRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_ln_bolt_12_invoice(LNBolt12Invoice self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_ln_invoice(LNInvoice self, SseSerializer serializer);
 
@@ -4218,6 +7311,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_ln_url_pay_success_data(LnUrlPaySuccessData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_ln_url_payer_data(LnUrlPayerData self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_ln_url_withdraw_request(LnUrlWithdrawRequest self, SseSerializer serializer);
 
@@ -4239,6 +7335,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_nwc_budget(NwcBudget self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_pay_amount(PayAmount self, SseSerializer serializer);
 
@@ -4254,6 +7353,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_prepare_convert_request(PrepareConvertRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_prepare_ln_url_pay_request(
     PrepareLnUrlPayRequest self,
@@ -4266,6 +7368,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_prepare_receive_quotes_request(
+    PrepareReceiveQuotesRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer);
 
@@ -4275,6 +7383,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_prepare_send_request(PrepareSendRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_process_notification_request(
+    ProcessNotificationRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_queue_payment_request(QueuePaymentRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_receive_amount(ReceiveAmount self, SseSerializer serializer);
 
@@ -4284,11 +7401,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_refund_request(RefundRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_register_lightning_address_request(
+    RegisterLightningAddressRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_registered_asset(RegisteredAsset self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_replay_events_request(ReplayEventsRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_restore_request(RestoreRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_sdk_event(SdkEvent self, SseSerializer serializer);
+  void sse_encode_box_autoadd_schedule_payment_request(SchedulePaymentRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_send_batch_request(SendBatchRequest self, SseSerializer serializer);
 
   @protected
   void sse_encode_box_autoadd_send_payment_request(SendPaymentRequest self, SseSerializer serializer);
@@ -4296,15 +7428,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_sign_message_request(SignMessageRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_sign_pset_request(SignPsetRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_simulation_config(SimulationConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_spend_limit(SpendLimit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_spend_limits(SpendLimits self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_success_action(SuccessAction self, SseSerializer serializer);
 
   @protected
   void sse_encode_box_autoadd_success_action_processed(SuccessActionProcessed self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_swap_service_status(SwapServiceStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_sweep_private_key_request(
+    SweepPrivateKeyRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_box_autoadd_symbol(Symbol self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_sync_request(SyncRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
@@ -4320,6 +7476,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_buy_bitcoin_request(BuyBitcoinRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_check_health_response(CheckHealthResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_check_message_request(CheckMessageRequest self, SseSerializer serializer);
 
@@ -4332,15 +7491,51 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_connect_request(ConnectRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_convert_request(ConvertRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_create_recurring_payment_request(
+    CreateRecurringPaymentRequest self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_currency_info(CurrencyInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_decoded_invoice(DecodedInvoice self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_direction(Direction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_event_filter(EventFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_event_webhook_config(EventWebhookConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_export_payment_unblinding_data_request(
+    ExportPaymentUnblindingDataRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_export_wallet_request(ExportWalletRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_external_input_parser(ExternalInputParser self, SseSerializer serializer);
 
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_fee_breakdown(FeeBreakdown self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fee_speed(FeeSpeed self, SseSerializer serializer);
+
   @protected
   void sse_encode_fetch_payment_proposed_fees_request(
     FetchPaymentProposedFeesRequest self,
@@ -4353,24 +7548,69 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_fiat_amount(FiatAmount self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fiat_conversion(FiatConversion self, SseSerializer serializer);
+
   @protected
   void sse_encode_fiat_currency(FiatCurrency self, SseSerializer serializer);
 
   @protected
   void sse_encode_get_info_response(GetInfoResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_get_max_payable_request(GetMaxPayableRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_get_max_payable_response(GetMaxPayableResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_get_max_receivable_request(GetMaxReceivableRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_get_max_receivable_response(GetMaxReceivableResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_get_payment_request(GetPaymentRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_get_report_request(GetReportRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_get_report_response(GetReportResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_get_wallet_info_response(GetWalletInfoResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_health_check_service(HealthCheckService self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
   @protected
   void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_import_wallet_request(ImportWalletRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_wallet_response(ImportWalletResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_input_type(InputType self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_journaled_event(JournaledEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lightning_address_info(LightningAddressInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_lightning_payment_limits_response(
     LightningPaymentLimitsResponse self,
@@ -4389,18 +7629,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_asset_amount(List<AssetAmount> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_asset_balance(List<AssetBalance> self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_asset_metadata(List<AssetMetadata> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_asset_report(List<AssetReport> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_batch_send_item(List<BatchSendItem> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_batch_send_item_result(List<BatchSendItemResult> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_external_input_parser(List<ExternalInputParser> self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_fiat_currency(List<FiatCurrency> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_journaled_event(List<JournaledEvent> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_ln_offer_blinded_path(List<LnOfferBlindedPath> self, SseSerializer serializer);
 
@@ -4410,6 +7665,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_localized_name(List<LocalizedName> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_nwc_connection(List<NwcConnection> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_payment(List<Payment> self, SseSerializer serializer);
 
@@ -4419,18 +7677,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_payment_state(List<PaymentState> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_payment_swap_type(List<PaymentSwapType> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_payment_type(List<PaymentType> self, SseSerializer serializer);
 
   @protected
   void sse_encode_list_payments_request(ListPaymentsRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_prepare_receive_response(List<PrepareReceiveResponse> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_pset_spend_limit(List<PsetSpendLimit> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_queued_payment(List<QueuedPayment> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_rate(List<Rate> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_recurring_payment(List<RecurringPayment> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_recurring_payment_occurrence(
+    List<RecurringPaymentOccurrence> self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_list_refundable_swap(List<RefundableSwap> self, SseSerializer serializer);
 
@@ -4440,6 +7719,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_route_hint_hop(List<RouteHintHop> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_scheduled_payment(List<ScheduledPayment> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_sdk_event_kind(List<SdkEventKind> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_service_health(List<ServiceHealth> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_swap_info(List<SwapInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_swaps_request(ListSwapsRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_unblinded_tx_out(List<UnblindedTxOut> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ln_bolt_12_invoice(LNBolt12Invoice self, SseSerializer serializer);
+
   @protected
   void sse_encode_ln_invoice(LNInvoice self, SseSerializer serializer);
 
@@ -4482,6 +7782,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_ln_url_pay_success_data(LnUrlPaySuccessData self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_ln_url_payer_data(LnUrlPayerData self, SseSerializer serializer);
+
   @protected
   void sse_encode_ln_url_withdraw_error(LnUrlWithdrawError self, SseSerializer serializer);
 
@@ -4512,6 +7815,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_network(Network self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_nwc_budget(NwcBudget self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_nwc_connection(NwcConnection self, SseSerializer serializer);
+
   @protected
   void sse_encode_onchain_payment_limits_response(
     OnchainPaymentLimitsResponse self,
@@ -4524,24 +7833,75 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_amount(Amount? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_amp_account(AmpAccount? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_asset_amount(AssetAmount? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_asset_conversion(AssetConversion? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_asset_info(AssetInfo? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_backup_policy(BackupPolicy? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_buy_bitcoin_provider(BuyBitcoinProvider? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_direction(Direction? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_event_filter(EventFilter? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_event_webhook_config(EventWebhookConfig? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_fee_breakdown(FeeBreakdown? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_fee_speed(FeeSpeed? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_fiat_amount(FiatAmount? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_fiat_conversion(FiatConversion? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_input_type(InputType? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_lightning_address_info(
+    LightningAddressInfo? self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_opt_box_autoadd_list_payment_details(ListPaymentDetails? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_ln_url_info(LnUrlInfo? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_ln_url_payer_data(LnUrlPayerData? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_nwc_budget(NwcBudget? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_pay_amount(PayAmount? self, SseSerializer serializer);
 
@@ -4551,6 +7911,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_box_autoadd_receive_amount(ReceiveAmount? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_registered_asset(RegisteredAsset? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_simulation_config(SimulationConfig? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_spend_limit(SpendLimit? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_spend_limits(SpendLimits? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_success_action(SuccessAction? self, SseSerializer serializer);
 
@@ -4560,15 +7932,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_swap_service_status(SwapServiceStatus? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_symbol(Symbol? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_sync_request(SyncRequest? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_list_asset_metadata(List<AssetMetadata>? self, SseSerializer serializer);
 
@@ -4578,12 +7959,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_opt_list_payment_state(List<PaymentState>? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_list_payment_swap_type(List<PaymentSwapType>? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_list_payment_type(List<PaymentType>? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_list_sdk_event_kind(List<SdkEventKind>? self, SseSerializer serializer);
+
   @protected
   void sse_encode_pay_amount(PayAmount self, SseSerializer serializer);
 
@@ -4605,15 +7992,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_payment_state(PaymentState self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_payment_swap_type(PaymentSwapType self, SseSerializer serializer);
+
   @protected
   void sse_encode_payment_type(PaymentType self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_payment_unblinding_data(PaymentUnblindingData self, SseSerializer serializer);
+
   @protected
   void sse_encode_prepare_buy_bitcoin_request(PrepareBuyBitcoinRequest self, SseSerializer serializer);
 
   @protected
   void sse_encode_prepare_buy_bitcoin_response(PrepareBuyBitcoinResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_prepare_convert_request(PrepareConvertRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_prepare_convert_response(PrepareConvertResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_prepare_ln_url_pay_request(PrepareLnUrlPayRequest self, SseSerializer serializer);
 
@@ -4626,6 +8025,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_prepare_pay_onchain_response(PreparePayOnchainResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_prepare_receive_quotes_request(PrepareReceiveQuotesRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_prepare_receive_quotes_response(
+    PrepareReceiveQuotesResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer);
 
@@ -4644,6 +8052,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_prepare_send_response(PrepareSendResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_process_notification_request(ProcessNotificationRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_process_notification_response(ProcessNotificationResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pset_spend_limit(PsetSpendLimit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_queue_payment_request(QueuePaymentRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_queued_payment(QueuedPayment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_queued_payment_state(QueuedPaymentState self, SseSerializer serializer);
+
   @protected
   void sse_encode_rate(Rate self, SseSerializer serializer);
 
@@ -4659,6 +8085,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_recommended_fees(RecommendedFees self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_recurring_payment(RecurringPayment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_recurring_payment_occurrence(RecurringPaymentOccurrence self, SseSerializer serializer);
+
   @protected
   void sse_encode_refund_request(RefundRequest self, SseSerializer serializer);
 
@@ -4668,21 +8100,57 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_refundable_swap(RefundableSwap self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_register_lightning_address_request(
+    RegisterLightningAddressRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_registered_asset(RegisteredAsset self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_replay_events_request(ReplayEventsRequest self, SseSerializer serializer);
+
   @protected
   void sse_encode_restore_request(RestoreRequest self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_reusable_bitcoin_address_response(
+    ReusableBitcoinAddressResponse self,
+    SseSerializer serializer,
+  );
+
   @protected
   void sse_encode_route_hint(RouteHint self, SseSerializer serializer);
 
   @protected
   void sse_encode_route_hint_hop(RouteHintHop self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_schedule_payment_request(SchedulePaymentRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scheduled_payment(ScheduledPayment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_scheduled_payment_state(ScheduledPaymentState self, SseSerializer serializer);
+
   @protected
   void sse_encode_sdk_error(SdkError self, SseSerializer serializer);
 
   @protected
   void sse_encode_sdk_event(SdkEvent self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_sdk_event_kind(SdkEventKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_send_batch_request(SendBatchRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_send_batch_response(SendBatchResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_send_destination(SendDestination self, SseSerializer serializer);
 
@@ -4692,6 +8160,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_send_payment_response(SendPaymentResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_service_health(ServiceHealth self, SseSerializer serializer);
+
   @protected
   void sse_encode_sign_message_request(SignMessageRequest self, SseSerializer serializer);
 
@@ -4699,26 +8170,62 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_sign_message_response(SignMessageResponse self, SseSerializer serializer);
 
   @protected
-  void sse_encode_success_action(SuccessAction self, SseSerializer serializer);
+  void sse_encode_sign_pset_request(SignPsetRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_success_action_processed(SuccessActionProcessed self, SseSerializer serializer);
+  void sse_encode_sign_pset_response(SignPsetResponse self, SseSerializer serializer);
 
   @protected
-  void sse_encode_symbol(Symbol self, SseSerializer serializer);
+  void sse_encode_simulation_config(SimulationConfig self, SseSerializer serializer);
 
   @protected
-  void sse_encode_u_16(int self, SseSerializer serializer);
+  void sse_encode_spend_limit(SpendLimit self, SseSerializer serializer);
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  void sse_encode_spend_limits(SpendLimits self, SseSerializer serializer);
 
   @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+  void sse_encode_success_action(SuccessAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_success_action_processed(SuccessActionProcessed self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_swap_info(SwapInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_swap_service_status(SwapServiceStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sweep_private_key_request(SweepPrivateKeyRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sweep_private_key_response(SweepPrivateKeyResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_symbol(Symbol self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_request(SyncRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_response(SyncResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_unblinded_tx_out(UnblindedTxOut self, SseSerializer serializer);
+
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
@@ -4728,6 +8235,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_validate_address_response(ValidateAddressResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_wallet_info(WalletInfo self, SseSerializer serializer);
 }
@@ -4784,16 +8294,63 @@ class RustLibWire implements BaseWire {
     int port_,
     int that,
     ffi.Pointer<wire_cst_list_prim_u_8_strict> listener,
+    ffi.Pointer<wire_cst_event_filter> filter,
   ) {
-    return _wire__crate__bindings__BindingLiquidSdk_add_event_listener(port_, that, listener);
+    return _wire__crate__bindings__BindingLiquidSdk_add_event_listener(port_, that, listener, filter);
   }
 
   late final _wire__crate__bindings__BindingLiquidSdk_add_event_listenerPtr = _lookup<
-    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>
+    ffi.NativeFunction<
+      ffi.Void Function(
+        ffi.Int64,
+        ffi.UintPtr,
+        ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+        ffi.Pointer<wire_cst_event_filter>,
+      )
+    >
   >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener');
   late final _wire__crate__bindings__BindingLiquidSdk_add_event_listener =
       _wire__crate__bindings__BindingLiquidSdk_add_event_listenerPtr
-          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+          .asFunction<
+            void Function(
+              int,
+              int,
+              ffi.Pointer<wire_cst_list_prim_u_8_strict>,
+              ffi.Pointer<wire_cst_event_filter>,
+            )
+          >();
+
+  void wire__crate__bindings__BindingLiquidSdk_add_input_parser(
+    int port_,
+    int that,
+    ffi.Pointer<ffi.Void> parse_input,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_add_input_parser(port_, that, parse_input);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_add_input_parserPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<ffi.Void>)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_input_parser',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_add_input_parser =
+      _wire__crate__bindings__BindingLiquidSdk_add_input_parserPtr
+          .asFunction<void Function(int, int, ffi.Pointer<ffi.Void>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_add_nwc_connection(
+    int that,
+    ffi.Pointer<wire_cst_add_nwc_connection_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_add_nwc_connection(that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_add_nwc_connectionPtr = _lookup<
+    ffi.NativeFunction<
+      WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_add_nwc_connection_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_nwc_connection');
+  late final _wire__crate__bindings__BindingLiquidSdk_add_nwc_connection =
+      _wire__crate__bindings__BindingLiquidSdk_add_nwc_connectionPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_add_nwc_connection_request>)>();
 
   WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_backup(
     int that,
@@ -4824,6 +8381,77 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_buy_bitcoinPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_buy_bitcoin_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_cancel_queued_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_cancel_queued_payment(port_, that, id);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_cancel_queued_paymentPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_cancel_queued_payment');
+  late final _wire__crate__bindings__BindingLiquidSdk_cancel_queued_payment =
+      _wire__crate__bindings__BindingLiquidSdk_cancel_queued_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_cancel_recurring_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_cancel_recurring_payment(port_, that, id);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_cancel_recurring_paymentPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_cancel_recurring_payment');
+  late final _wire__crate__bindings__BindingLiquidSdk_cancel_recurring_payment =
+      _wire__crate__bindings__BindingLiquidSdk_cancel_recurring_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_cancel_scheduled_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_cancel_scheduled_payment(port_, that, id);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_cancel_scheduled_paymentPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_cancel_scheduled_payment');
+  late final _wire__crate__bindings__BindingLiquidSdk_cancel_scheduled_payment =
+      _wire__crate__bindings__BindingLiquidSdk_cancel_scheduled_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_check_health(int port_, int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_check_health(port_, that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_check_healthPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_check_health',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_check_health =
+      _wire__crate__bindings__BindingLiquidSdk_check_healthPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_check_lightning_address_available(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> username,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_check_lightning_address_available(port_, that, username);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_check_lightning_address_availablePtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_check_lightning_address_available');
+  late final _wire__crate__bindings__BindingLiquidSdk_check_lightning_address_available =
+      _wire__crate__bindings__BindingLiquidSdk_check_lightning_address_availablePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_check_message(
     int that,
     ffi.Pointer<wire_cst_check_message_request> req,
@@ -4840,6 +8468,65 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_check_messagePtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_check_message_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_claim_swap(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_claim_swap(port_, that, swap_id);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_claim_swapPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_claim_swap');
+  late final _wire__crate__bindings__BindingLiquidSdk_claim_swap =
+      _wire__crate__bindings__BindingLiquidSdk_claim_swapPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_convert(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_convert_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_convert(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_convertPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_convert_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_convert');
+  late final _wire__crate__bindings__BindingLiquidSdk_convert =
+      _wire__crate__bindings__BindingLiquidSdk_convertPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_convert_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_create_recurring_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_create_recurring_payment_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_create_recurring_payment(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_create_recurring_paymentPtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_create_recurring_payment_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_create_recurring_payment');
+  late final _wire__crate__bindings__BindingLiquidSdk_create_recurring_payment =
+      _wire__crate__bindings__BindingLiquidSdk_create_recurring_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_create_recurring_payment_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_delete_lightning_address(int port_, int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_delete_lightning_address(port_, that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_delete_lightning_addressPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_delete_lightning_address',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_delete_lightning_address =
+      _wire__crate__bindings__BindingLiquidSdk_delete_lightning_addressPtr
+          .asFunction<void Function(int, int)>();
+
   void wire__crate__bindings__BindingLiquidSdk_disconnect(int port_, int that) {
     return _wire__crate__bindings__BindingLiquidSdk_disconnect(port_, that);
   }
@@ -4863,6 +8550,41 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr
           .asFunction<WireSyncRust2DartDco Function(int)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_export_payment_unblinding_data(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_export_payment_unblinding_data_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_export_payment_unblinding_data(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_export_payment_unblinding_dataPtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_export_payment_unblinding_data_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payment_unblinding_data');
+  late final _wire__crate__bindings__BindingLiquidSdk_export_payment_unblinding_data =
+      _wire__crate__bindings__BindingLiquidSdk_export_payment_unblinding_dataPtr
+          .asFunction<
+            void Function(int, int, ffi.Pointer<wire_cst_export_payment_unblinding_data_request>)
+          >();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_export_wallet(
+    int that,
+    ffi.Pointer<wire_cst_export_wallet_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_export_wallet(that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_export_walletPtr = _lookup<
+    ffi.NativeFunction<
+      WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_export_wallet_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_wallet');
+  late final _wire__crate__bindings__BindingLiquidSdk_export_wallet =
+      _wire__crate__bindings__BindingLiquidSdk_export_walletPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_export_wallet_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(int port_, int that) {
     return _wire__crate__bindings__BindingLiquidSdk_fetch_fiat_rates(port_, that);
   }
@@ -4914,6 +8636,33 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_fetch_payment_proposed_feesPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_fetch_registered_asset(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_fetch_registered_asset(port_, that, asset_id);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_fetch_registered_assetPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_registered_asset');
+  late final _wire__crate__bindings__BindingLiquidSdk_fetch_registered_asset =
+      _wire__crate__bindings__BindingLiquidSdk_fetch_registered_assetPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_get_amp_account(int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_amp_account(that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_amp_accountPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_amp_account',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_get_amp_account =
+      _wire__crate__bindings__BindingLiquidSdk_get_amp_accountPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
   void wire__crate__bindings__BindingLiquidSdk_get_info(int port_, int that) {
     return _wire__crate__bindings__BindingLiquidSdk_get_info(port_, that);
   }
@@ -4925,6 +8674,40 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_get_info =
       _wire__crate__bindings__BindingLiquidSdk_get_infoPtr.asFunction<void Function(int, int)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_get_max_payable(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_get_max_payable_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_max_payable(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_max_payablePtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_get_max_payable_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_max_payable');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_max_payable =
+      _wire__crate__bindings__BindingLiquidSdk_get_max_payablePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_get_max_payable_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_get_max_receivable(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_get_max_receivable_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_max_receivable(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_max_receivablePtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_get_max_receivable_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_max_receivable');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_max_receivable =
+      _wire__crate__bindings__BindingLiquidSdk_get_max_receivablePtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_get_max_receivable_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_get_payment(
     int port_,
     int that,
@@ -4940,6 +8723,61 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_get_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_get_payment_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_get_report(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_get_report_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_report(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_reportPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_get_report_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_report');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_report =
+      _wire__crate__bindings__BindingLiquidSdk_get_reportPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_get_report_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_get_reusable_bitcoin_address(int port_, int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_reusable_bitcoin_address(port_, that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_reusable_bitcoin_addressPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_reusable_bitcoin_address',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_get_reusable_bitcoin_address =
+      _wire__crate__bindings__BindingLiquidSdk_get_reusable_bitcoin_addressPtr
+          .asFunction<void Function(int, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_get_wallet_info(int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_wallet_info(that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_wallet_infoPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_wallet_info',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_get_wallet_info =
+      _wire__crate__bindings__BindingLiquidSdk_get_wallet_infoPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_import_wallet(
+    int that,
+    ffi.Pointer<wire_cst_import_wallet_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_import_wallet(that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_import_walletPtr = _lookup<
+    ffi.NativeFunction<
+      WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_import_wallet_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_import_wallet');
+  late final _wire__crate__bindings__BindingLiquidSdk_import_wallet =
+      _wire__crate__bindings__BindingLiquidSdk_import_walletPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_import_wallet_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(int port_, int that) {
     return _wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies(port_, that);
   }
@@ -4951,6 +8789,18 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_list_fiat_currencies =
       _wire__crate__bindings__BindingLiquidSdk_list_fiat_currenciesPtr.asFunction<void Function(int, int)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_list_nwc_connections(int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_nwc_connections(that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_nwc_connectionsPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_nwc_connections',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_list_nwc_connections =
+      _wire__crate__bindings__BindingLiquidSdk_list_nwc_connectionsPtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
   void wire__crate__bindings__BindingLiquidSdk_list_payments(
     int port_,
     int that,
@@ -4966,6 +8816,48 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_payments_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_list_queued_payments(int port_, int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_queued_payments(port_, that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_queued_paymentsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_queued_payments',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_list_queued_payments =
+      _wire__crate__bindings__BindingLiquidSdk_list_queued_paymentsPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_list_recurring_payment_occurrences(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> recurring_payment_id,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_recurring_payment_occurrences(
+      port_,
+      that,
+      recurring_payment_id,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_recurring_payment_occurrencesPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_recurring_payment_occurrences');
+  late final _wire__crate__bindings__BindingLiquidSdk_list_recurring_payment_occurrences =
+      _wire__crate__bindings__BindingLiquidSdk_list_recurring_payment_occurrencesPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_list_recurring_payments(int port_, int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_recurring_payments(port_, that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_recurring_paymentsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_recurring_payments',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_list_recurring_payments =
+      _wire__crate__bindings__BindingLiquidSdk_list_recurring_paymentsPtr
+          .asFunction<void Function(int, int)>();
+
   void wire__crate__bindings__BindingLiquidSdk_list_refundables(int port_, int that) {
     return _wire__crate__bindings__BindingLiquidSdk_list_refundables(port_, that);
   }
@@ -4977,6 +8869,33 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_list_refundables =
       _wire__crate__bindings__BindingLiquidSdk_list_refundablesPtr.asFunction<void Function(int, int)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_list_scheduled_payments(int port_, int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_scheduled_payments(port_, that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_scheduled_paymentsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_scheduled_payments',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_list_scheduled_payments =
+      _wire__crate__bindings__BindingLiquidSdk_list_scheduled_paymentsPtr
+          .asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_list_swaps(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_swaps_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_swaps(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_swapsPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_swaps_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_swaps');
+  late final _wire__crate__bindings__BindingLiquidSdk_list_swaps =
+      _wire__crate__bindings__BindingLiquidSdk_list_swapsPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_swaps_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_lnurl_auth(
     int port_,
     int that,
@@ -5073,6 +8992,23 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_prepare_buy_bitcoinPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_buy_bitcoin_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_prepare_convert(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_convert_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_prepare_convert(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_convertPtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_convert_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_convert');
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_convert =
+      _wire__crate__bindings__BindingLiquidSdk_prepare_convertPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_convert_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(
     int port_,
     int that,
@@ -5124,6 +9060,23 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_prepare_receive_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_quotes(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_receive_quotes_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_quotes(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_quotesPtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_receive_quotes_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_quotes');
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_quotes =
+      _wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_quotesPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_quotes_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_prepare_refund(
     int port_,
     int that,
@@ -5156,6 +9109,21 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_prepare_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_queue_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_queue_payment_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_queue_payment(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_queue_paymentPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_queue_payment_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_queue_payment');
+  late final _wire__crate__bindings__BindingLiquidSdk_queue_payment =
+      _wire__crate__bindings__BindingLiquidSdk_queue_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_queue_payment_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_receive_payment(
     int port_,
     int that,
@@ -5184,10 +9152,22 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_recommended_fees =
       _wire__crate__bindings__BindingLiquidSdk_recommended_feesPtr.asFunction<void Function(int, int)>();
 
-  void wire__crate__bindings__BindingLiquidSdk_refund(
-    int port_,
-    int that,
-    ffi.Pointer<wire_cst_refund_request> req,
+  void wire__crate__bindings__BindingLiquidSdk_recover_lightning_address(int port_, int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_recover_lightning_address(port_, that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_recover_lightning_addressPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_recover_lightning_address',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_recover_lightning_address =
+      _wire__crate__bindings__BindingLiquidSdk_recover_lightning_addressPtr
+          .asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_refund(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_refund_request> req,
   ) {
     return _wire__crate__bindings__BindingLiquidSdk_refund(port_, that, req);
   }
@@ -5199,6 +9179,34 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_refundPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_refund_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_register_amp_account(int port_, int that) {
+    return _wire__crate__bindings__BindingLiquidSdk_register_amp_account(port_, that);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_register_amp_accountPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_register_amp_account',
+      );
+  late final _wire__crate__bindings__BindingLiquidSdk_register_amp_account =
+      _wire__crate__bindings__BindingLiquidSdk_register_amp_accountPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_register_lightning_address(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_register_lightning_address_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_register_lightning_address(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_register_lightning_addressPtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_register_lightning_address_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_register_lightning_address');
+  late final _wire__crate__bindings__BindingLiquidSdk_register_lightning_address =
+      _wire__crate__bindings__BindingLiquidSdk_register_lightning_addressPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_register_lightning_address_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_register_webhook(
     int port_,
     int that,
@@ -5214,6 +9222,35 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_register_webhookPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_remove_nwc_connection(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> name,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_remove_nwc_connection(that, name);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_remove_nwc_connectionPtr = _lookup<
+    ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_remove_nwc_connection');
+  late final _wire__crate__bindings__BindingLiquidSdk_remove_nwc_connection =
+      _wire__crate__bindings__BindingLiquidSdk_remove_nwc_connectionPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_replay_events(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_replay_events_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_replay_events(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_replay_eventsPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_replay_events_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_replay_events');
+  late final _wire__crate__bindings__BindingLiquidSdk_replay_events =
+      _wire__crate__bindings__BindingLiquidSdk_replay_eventsPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_replay_events_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps(int port_, int that) {
     return _wire__crate__bindings__BindingLiquidSdk_rescan_onchain_swaps(port_, that);
   }
@@ -5239,6 +9276,38 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_restorePtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_restore_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_schedule_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_schedule_payment_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_schedule_payment(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_schedule_paymentPtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_schedule_payment_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_schedule_payment');
+  late final _wire__crate__bindings__BindingLiquidSdk_schedule_payment =
+      _wire__crate__bindings__BindingLiquidSdk_schedule_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_schedule_payment_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_send_batch(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_send_batch_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_send_batch(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_send_batchPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_send_batch_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_batch');
+  late final _wire__crate__bindings__BindingLiquidSdk_send_batch =
+      _wire__crate__bindings__BindingLiquidSdk_send_batchPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_send_batch_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_send_payment(
     int port_,
     int that,
@@ -5254,6 +9323,29 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_send_payment_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_set_broadcaster(
+    int port_,
+    int that,
+    ffi.Pointer<ffi.Void> broadcast_liquid_tx,
+    ffi.Pointer<ffi.Void> broadcast_bitcoin_tx,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_set_broadcaster(
+      port_,
+      that,
+      broadcast_liquid_tx,
+      broadcast_bitcoin_tx,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_set_broadcasterPtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<ffi.Void>, ffi.Pointer<ffi.Void>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_broadcaster');
+  late final _wire__crate__bindings__BindingLiquidSdk_set_broadcaster =
+      _wire__crate__bindings__BindingLiquidSdk_set_broadcasterPtr
+          .asFunction<void Function(int, int, ffi.Pointer<ffi.Void>, ffi.Pointer<ffi.Void>)>();
+
   WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_sign_message(
     int that,
     ffi.Pointer<wire_cst_sign_message_request> req,
@@ -5268,16 +9360,52 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_sign_messagePtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_sign_message_request>)>();
 
-  void wire__crate__bindings__BindingLiquidSdk_sync(int port_, int that) {
-    return _wire__crate__bindings__BindingLiquidSdk_sync(port_, that);
+  void wire__crate__bindings__BindingLiquidSdk_sign_pset(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_sign_pset_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_sign_pset(port_, that, req);
   }
 
-  late final _wire__crate__bindings__BindingLiquidSdk_syncPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
-        'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync',
-      );
+  late final _wire__crate__bindings__BindingLiquidSdk_sign_psetPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_sign_pset_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sign_pset');
+  late final _wire__crate__bindings__BindingLiquidSdk_sign_pset =
+      _wire__crate__bindings__BindingLiquidSdk_sign_psetPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_sign_pset_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_sweep_private_key(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_sweep_private_key_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_sweep_private_key(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_sweep_private_keyPtr = _lookup<
+    ffi.NativeFunction<
+      ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_sweep_private_key_request>)
+    >
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sweep_private_key');
+  late final _wire__crate__bindings__BindingLiquidSdk_sweep_private_key =
+      _wire__crate__bindings__BindingLiquidSdk_sweep_private_keyPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_sweep_private_key_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_sync(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_sync_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_sync(port_, that, req);
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_syncPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_sync_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync');
   late final _wire__crate__bindings__BindingLiquidSdk_sync =
-      _wire__crate__bindings__BindingLiquidSdk_syncPtr.asFunction<void Function(int, int)>();
+      _wire__crate__bindings__BindingLiquidSdk_syncPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_sync_request>)>();
 
   void wire__crate__bindings__BindingLiquidSdk_unregister_webhook(int port_, int that) {
     return _wire__crate__bindings__BindingLiquidSdk_unregister_webhook(port_, that);
@@ -5290,28 +9418,19 @@ class RustLibWire implements BaseWire {
   late final _wire__crate__bindings__BindingLiquidSdk_unregister_webhook =
       _wire__crate__bindings__BindingLiquidSdk_unregister_webhookPtr.asFunction<void Function(int, int)>();
 
-  void wire__crate__bindings__binding_event_listener_on_event(
-    int port_,
-    ffi.Pointer<wire_cst_binding_event_listener> that,
-    ffi.Pointer<wire_cst_sdk_event> e,
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_verify_backup(
+    int that,
+    ffi.Pointer<wire_cst_restore_request> req,
   ) {
-    return _wire__crate__bindings__binding_event_listener_on_event(port_, that, e);
+    return _wire__crate__bindings__BindingLiquidSdk_verify_backup(that, req);
   }
 
-  late final _wire__crate__bindings__binding_event_listener_on_eventPtr = _lookup<
-    ffi.NativeFunction<
-      ffi.Void Function(
-        ffi.Int64,
-        ffi.Pointer<wire_cst_binding_event_listener>,
-        ffi.Pointer<wire_cst_sdk_event>,
-      )
-    >
-  >('frbgen_breez_liquid_wire__crate__bindings__binding_event_listener_on_event');
-  late final _wire__crate__bindings__binding_event_listener_on_event =
-      _wire__crate__bindings__binding_event_listener_on_eventPtr
-          .asFunction<
-            void Function(int, ffi.Pointer<wire_cst_binding_event_listener>, ffi.Pointer<wire_cst_sdk_event>)
-          >();
+  late final _wire__crate__bindings__BindingLiquidSdk_verify_backupPtr = _lookup<
+    ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_restore_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_verify_backup');
+  late final _wire__crate__bindings__BindingLiquidSdk_verify_backup =
+      _wire__crate__bindings__BindingLiquidSdk_verify_backupPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_restore_request>)>();
 
   void wire__crate__bindings__breez_log_stream(int port_, ffi.Pointer<wire_cst_list_prim_u_8_strict> s) {
     return _wire__crate__bindings__breez_log_stream(port_, s);
@@ -5337,6 +9456,34 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__connectPtr
           .asFunction<void Function(int, ffi.Pointer<wire_cst_connect_request>)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__create_spend_limit_override(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> secret,
+    int expires_at,
+  ) {
+    return _wire__crate__bindings__create_spend_limit_override(secret, expires_at);
+  }
+
+  late final _wire__crate__bindings__create_spend_limit_overridePtr = _lookup<
+    ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Uint32)>
+  >('frbgen_breez_liquid_wire__crate__bindings__create_spend_limit_override');
+  late final _wire__crate__bindings__create_spend_limit_override =
+      _wire__crate__bindings__create_spend_limit_overridePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__decode_invoice(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
+  ) {
+    return _wire__crate__bindings__decode_invoice(input);
+  }
+
+  late final _wire__crate__bindings__decode_invoicePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__decode_invoice',
+      );
+  late final _wire__crate__bindings__decode_invoice =
+      _wire__crate__bindings__decode_invoicePtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   WireSyncRust2DartDco wire__crate__bindings__default_config(
     int network,
     ffi.Pointer<wire_cst_list_prim_u_8_strict> breez_api_key,
@@ -5351,6 +9498,17 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__default_configPtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
+  WireSyncRust2DartDco wire__crate__bindings__generate_mnemonic(int word_count) {
+    return _wire__crate__bindings__generate_mnemonic(word_count);
+  }
+
+  late final _wire__crate__bindings__generate_mnemonicPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Uint32)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__generate_mnemonic',
+      );
+  late final _wire__crate__bindings__generate_mnemonic =
+      _wire__crate__bindings__generate_mnemonicPtr.asFunction<WireSyncRust2DartDco Function(int)>();
+
   WireSyncRust2DartDco wire__crate__bindings__parse_invoice(
     ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
   ) {
@@ -5365,6 +9523,48 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__parse_invoicePtr
           .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
+  void wire__crate__bindings__process_notification(
+    int port_,
+    ffi.Pointer<wire_cst_process_notification_request> req,
+  ) {
+    return _wire__crate__bindings__process_notification(port_, req);
+  }
+
+  late final _wire__crate__bindings__process_notificationPtr = _lookup<
+    ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.Pointer<wire_cst_process_notification_request>)>
+  >('frbgen_breez_liquid_wire__crate__bindings__process_notification');
+  late final _wire__crate__bindings__process_notification =
+      _wire__crate__bindings__process_notificationPtr
+          .asFunction<void Function(int, ffi.Pointer<wire_cst_process_notification_request>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__validate_address(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> address,
+    int network,
+  ) {
+    return _wire__crate__bindings__validate_address(address, network);
+  }
+
+  late final _wire__crate__bindings__validate_addressPtr = _lookup<
+    ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, ffi.Int32)>
+  >('frbgen_breez_liquid_wire__crate__bindings__validate_address');
+  late final _wire__crate__bindings__validate_address =
+      _wire__crate__bindings__validate_addressPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__validate_mnemonic(
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> phrase,
+  ) {
+    return _wire__crate__bindings__validate_mnemonic(phrase);
+  }
+
+  late final _wire__crate__bindings__validate_mnemonicPtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+        'frbgen_breez_liquid_wire__crate__bindings__validate_mnemonic',
+      );
+  late final _wire__crate__bindings__validate_mnemonic =
+      _wire__crate__bindings__validate_mnemonicPtr
+          .asFunction<WireSyncRust2DartDco Function(ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
     ffi.Pointer<ffi.Void> ptr,
@@ -5412,6 +9612,18 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_accept_payment_proposed_fees_requestPtr
           .asFunction<ffi.Pointer<wire_cst_accept_payment_proposed_fees_request> Function()>();
 
+  ffi.Pointer<wire_cst_add_nwc_connection_request> cst_new_box_autoadd_add_nwc_connection_request() {
+    return _cst_new_box_autoadd_add_nwc_connection_request();
+  }
+
+  late final _cst_new_box_autoadd_add_nwc_connection_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_add_nwc_connection_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_add_nwc_connection_request',
+      );
+  late final _cst_new_box_autoadd_add_nwc_connection_request =
+      _cst_new_box_autoadd_add_nwc_connection_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_add_nwc_connection_request> Function()>();
+
   ffi.Pointer<wire_cst_aes_success_action_data> cst_new_box_autoadd_aes_success_action_data() {
     return _cst_new_box_autoadd_aes_success_action_data();
   }
@@ -5460,6 +9672,40 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_amount =
       _cst_new_box_autoadd_amountPtr.asFunction<ffi.Pointer<wire_cst_amount> Function()>();
 
+  ffi.Pointer<wire_cst_amp_account> cst_new_box_autoadd_amp_account() {
+    return _cst_new_box_autoadd_amp_account();
+  }
+
+  late final _cst_new_box_autoadd_amp_accountPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_amp_account> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_amp_account',
+      );
+  late final _cst_new_box_autoadd_amp_account =
+      _cst_new_box_autoadd_amp_accountPtr.asFunction<ffi.Pointer<wire_cst_amp_account> Function()>();
+
+  ffi.Pointer<wire_cst_asset_amount> cst_new_box_autoadd_asset_amount() {
+    return _cst_new_box_autoadd_asset_amount();
+  }
+
+  late final _cst_new_box_autoadd_asset_amountPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_asset_amount> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_asset_amount',
+      );
+  late final _cst_new_box_autoadd_asset_amount =
+      _cst_new_box_autoadd_asset_amountPtr.asFunction<ffi.Pointer<wire_cst_asset_amount> Function()>();
+
+  ffi.Pointer<wire_cst_asset_conversion> cst_new_box_autoadd_asset_conversion() {
+    return _cst_new_box_autoadd_asset_conversion();
+  }
+
+  late final _cst_new_box_autoadd_asset_conversionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_asset_conversion> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_asset_conversion',
+      );
+  late final _cst_new_box_autoadd_asset_conversion =
+      _cst_new_box_autoadd_asset_conversionPtr
+          .asFunction<ffi.Pointer<wire_cst_asset_conversion> Function()>();
+
   ffi.Pointer<wire_cst_asset_info> cst_new_box_autoadd_asset_info() {
     return _cst_new_box_autoadd_asset_info();
   }
@@ -5471,6 +9717,17 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_asset_info =
       _cst_new_box_autoadd_asset_infoPtr.asFunction<ffi.Pointer<wire_cst_asset_info> Function()>();
 
+  ffi.Pointer<wire_cst_backup_policy> cst_new_box_autoadd_backup_policy() {
+    return _cst_new_box_autoadd_backup_policy();
+  }
+
+  late final _cst_new_box_autoadd_backup_policyPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_backup_policy> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_backup_policy',
+      );
+  late final _cst_new_box_autoadd_backup_policy =
+      _cst_new_box_autoadd_backup_policyPtr.asFunction<ffi.Pointer<wire_cst_backup_policy> Function()>();
+
   ffi.Pointer<wire_cst_backup_request> cst_new_box_autoadd_backup_request() {
     return _cst_new_box_autoadd_backup_request();
   }
@@ -5482,18 +9739,6 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_backup_request =
       _cst_new_box_autoadd_backup_requestPtr.asFunction<ffi.Pointer<wire_cst_backup_request> Function()>();
 
-  ffi.Pointer<wire_cst_binding_event_listener> cst_new_box_autoadd_binding_event_listener() {
-    return _cst_new_box_autoadd_binding_event_listener();
-  }
-
-  late final _cst_new_box_autoadd_binding_event_listenerPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_binding_event_listener> Function()>>(
-        'frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listener',
-      );
-  late final _cst_new_box_autoadd_binding_event_listener =
-      _cst_new_box_autoadd_binding_event_listenerPtr
-          .asFunction<ffi.Pointer<wire_cst_binding_event_listener> Function()>();
-
   ffi.Pointer<wire_cst_bitcoin_address_data> cst_new_box_autoadd_bitcoin_address_data() {
     return _cst_new_box_autoadd_bitcoin_address_data();
   }
@@ -5506,6 +9751,17 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_bitcoin_address_dataPtr
           .asFunction<ffi.Pointer<wire_cst_bitcoin_address_data> Function()>();
 
+  ffi.Pointer<wire_cst_block_info> cst_new_box_autoadd_block_info() {
+    return _cst_new_box_autoadd_block_info();
+  }
+
+  late final _cst_new_box_autoadd_block_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_block_info> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_block_info',
+      );
+  late final _cst_new_box_autoadd_block_info =
+      _cst_new_box_autoadd_block_infoPtr.asFunction<ffi.Pointer<wire_cst_block_info> Function()>();
+
   ffi.Pointer<ffi.Bool> cst_new_box_autoadd_bool(bool value) {
     return _cst_new_box_autoadd_bool(value);
   }
@@ -5517,6 +9773,17 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_bool =
       _cst_new_box_autoadd_boolPtr.asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
 
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_buy_bitcoin_provider(int value) {
+    return _cst_new_box_autoadd_buy_bitcoin_provider(value);
+  }
+
+  late final _cst_new_box_autoadd_buy_bitcoin_providerPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_buy_bitcoin_provider',
+      );
+  late final _cst_new_box_autoadd_buy_bitcoin_provider =
+      _cst_new_box_autoadd_buy_bitcoin_providerPtr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
   ffi.Pointer<wire_cst_buy_bitcoin_request> cst_new_box_autoadd_buy_bitcoin_request() {
     return _cst_new_box_autoadd_buy_bitcoin_request();
   }
@@ -5552,6 +9819,88 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_connect_request =
       _cst_new_box_autoadd_connect_requestPtr.asFunction<ffi.Pointer<wire_cst_connect_request> Function()>();
 
+  ffi.Pointer<wire_cst_convert_request> cst_new_box_autoadd_convert_request() {
+    return _cst_new_box_autoadd_convert_request();
+  }
+
+  late final _cst_new_box_autoadd_convert_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_convert_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_convert_request',
+      );
+  late final _cst_new_box_autoadd_convert_request =
+      _cst_new_box_autoadd_convert_requestPtr.asFunction<ffi.Pointer<wire_cst_convert_request> Function()>();
+
+  ffi.Pointer<wire_cst_create_recurring_payment_request> cst_new_box_autoadd_create_recurring_payment_request() {
+    return _cst_new_box_autoadd_create_recurring_payment_request();
+  }
+
+  late final _cst_new_box_autoadd_create_recurring_payment_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_create_recurring_payment_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_create_recurring_payment_request',
+      );
+  late final _cst_new_box_autoadd_create_recurring_payment_request =
+      _cst_new_box_autoadd_create_recurring_payment_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_create_recurring_payment_request> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_direction(int value) {
+    return _cst_new_box_autoadd_direction(value);
+  }
+
+  late final _cst_new_box_autoadd_directionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_direction',
+      );
+  late final _cst_new_box_autoadd_direction =
+      _cst_new_box_autoadd_directionPtr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
+  ffi.Pointer<wire_cst_event_filter> cst_new_box_autoadd_event_filter() {
+    return _cst_new_box_autoadd_event_filter();
+  }
+
+  late final _cst_new_box_autoadd_event_filterPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_event_filter> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_event_filter',
+      );
+  late final _cst_new_box_autoadd_event_filter =
+      _cst_new_box_autoadd_event_filterPtr.asFunction<ffi.Pointer<wire_cst_event_filter> Function()>();
+
+  ffi.Pointer<wire_cst_event_webhook_config> cst_new_box_autoadd_event_webhook_config() {
+    return _cst_new_box_autoadd_event_webhook_config();
+  }
+
+  late final _cst_new_box_autoadd_event_webhook_configPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_event_webhook_config> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_event_webhook_config',
+      );
+  late final _cst_new_box_autoadd_event_webhook_config =
+      _cst_new_box_autoadd_event_webhook_configPtr
+          .asFunction<ffi.Pointer<wire_cst_event_webhook_config> Function()>();
+
+  ffi.Pointer<wire_cst_export_payment_unblinding_data_request>
+  cst_new_box_autoadd_export_payment_unblinding_data_request() {
+    return _cst_new_box_autoadd_export_payment_unblinding_data_request();
+  }
+
+  late final _cst_new_box_autoadd_export_payment_unblinding_data_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_export_payment_unblinding_data_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_export_payment_unblinding_data_request',
+      );
+  late final _cst_new_box_autoadd_export_payment_unblinding_data_request =
+      _cst_new_box_autoadd_export_payment_unblinding_data_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_export_payment_unblinding_data_request> Function()>();
+
+  ffi.Pointer<wire_cst_export_wallet_request> cst_new_box_autoadd_export_wallet_request() {
+    return _cst_new_box_autoadd_export_wallet_request();
+  }
+
+  late final _cst_new_box_autoadd_export_wallet_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_export_wallet_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_export_wallet_request',
+      );
+  late final _cst_new_box_autoadd_export_wallet_request =
+      _cst_new_box_autoadd_export_wallet_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_export_wallet_request> Function()>();
+
   ffi.Pointer<ffi.Double> cst_new_box_autoadd_f_64(double value) {
     return _cst_new_box_autoadd_f_64(value);
   }
@@ -5563,6 +9912,28 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_f_64 =
       _cst_new_box_autoadd_f_64Ptr.asFunction<ffi.Pointer<ffi.Double> Function(double)>();
 
+  ffi.Pointer<wire_cst_fee_breakdown> cst_new_box_autoadd_fee_breakdown() {
+    return _cst_new_box_autoadd_fee_breakdown();
+  }
+
+  late final _cst_new_box_autoadd_fee_breakdownPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fee_breakdown> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown',
+      );
+  late final _cst_new_box_autoadd_fee_breakdown =
+      _cst_new_box_autoadd_fee_breakdownPtr.asFunction<ffi.Pointer<wire_cst_fee_breakdown> Function()>();
+
+  ffi.Pointer<ffi.Int32> cst_new_box_autoadd_fee_speed(int value) {
+    return _cst_new_box_autoadd_fee_speed(value);
+  }
+
+  late final _cst_new_box_autoadd_fee_speedPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Int32> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_fee_speed',
+      );
+  late final _cst_new_box_autoadd_fee_speed =
+      _cst_new_box_autoadd_fee_speedPtr.asFunction<ffi.Pointer<ffi.Int32> Function(int)>();
+
   ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request>
   cst_new_box_autoadd_fetch_payment_proposed_fees_request() {
     return _cst_new_box_autoadd_fetch_payment_proposed_fees_request();
@@ -5576,6 +9947,52 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_fetch_payment_proposed_fees_requestPtr
           .asFunction<ffi.Pointer<wire_cst_fetch_payment_proposed_fees_request> Function()>();
 
+  ffi.Pointer<wire_cst_fiat_amount> cst_new_box_autoadd_fiat_amount() {
+    return _cst_new_box_autoadd_fiat_amount();
+  }
+
+  late final _cst_new_box_autoadd_fiat_amountPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fiat_amount> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_fiat_amount',
+      );
+  late final _cst_new_box_autoadd_fiat_amount =
+      _cst_new_box_autoadd_fiat_amountPtr.asFunction<ffi.Pointer<wire_cst_fiat_amount> Function()>();
+
+  ffi.Pointer<wire_cst_fiat_conversion> cst_new_box_autoadd_fiat_conversion() {
+    return _cst_new_box_autoadd_fiat_conversion();
+  }
+
+  late final _cst_new_box_autoadd_fiat_conversionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fiat_conversion> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_fiat_conversion',
+      );
+  late final _cst_new_box_autoadd_fiat_conversion =
+      _cst_new_box_autoadd_fiat_conversionPtr.asFunction<ffi.Pointer<wire_cst_fiat_conversion> Function()>();
+
+  ffi.Pointer<wire_cst_get_max_payable_request> cst_new_box_autoadd_get_max_payable_request() {
+    return _cst_new_box_autoadd_get_max_payable_request();
+  }
+
+  late final _cst_new_box_autoadd_get_max_payable_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_get_max_payable_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_get_max_payable_request',
+      );
+  late final _cst_new_box_autoadd_get_max_payable_request =
+      _cst_new_box_autoadd_get_max_payable_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_get_max_payable_request> Function()>();
+
+  ffi.Pointer<wire_cst_get_max_receivable_request> cst_new_box_autoadd_get_max_receivable_request() {
+    return _cst_new_box_autoadd_get_max_receivable_request();
+  }
+
+  late final _cst_new_box_autoadd_get_max_receivable_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_get_max_receivable_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_get_max_receivable_request',
+      );
+  late final _cst_new_box_autoadd_get_max_receivable_request =
+      _cst_new_box_autoadd_get_max_receivable_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_get_max_receivable_request> Function()>();
+
   ffi.Pointer<wire_cst_get_payment_request> cst_new_box_autoadd_get_payment_request() {
     return _cst_new_box_autoadd_get_payment_request();
   }
@@ -5588,6 +10005,18 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_get_payment_requestPtr
           .asFunction<ffi.Pointer<wire_cst_get_payment_request> Function()>();
 
+  ffi.Pointer<wire_cst_get_report_request> cst_new_box_autoadd_get_report_request() {
+    return _cst_new_box_autoadd_get_report_request();
+  }
+
+  late final _cst_new_box_autoadd_get_report_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_get_report_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_get_report_request',
+      );
+  late final _cst_new_box_autoadd_get_report_request =
+      _cst_new_box_autoadd_get_report_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_get_report_request> Function()>();
+
   ffi.Pointer<ffi.Int64> cst_new_box_autoadd_i_64(int value) {
     return _cst_new_box_autoadd_i_64(value);
   }
@@ -5599,6 +10028,41 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_i_64 =
       _cst_new_box_autoadd_i_64Ptr.asFunction<ffi.Pointer<ffi.Int64> Function(int)>();
 
+  ffi.Pointer<wire_cst_import_wallet_request> cst_new_box_autoadd_import_wallet_request() {
+    return _cst_new_box_autoadd_import_wallet_request();
+  }
+
+  late final _cst_new_box_autoadd_import_wallet_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_import_wallet_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_import_wallet_request',
+      );
+  late final _cst_new_box_autoadd_import_wallet_request =
+      _cst_new_box_autoadd_import_wallet_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_import_wallet_request> Function()>();
+
+  ffi.Pointer<wire_cst_input_type> cst_new_box_autoadd_input_type() {
+    return _cst_new_box_autoadd_input_type();
+  }
+
+  late final _cst_new_box_autoadd_input_typePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_input_type> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_input_type',
+      );
+  late final _cst_new_box_autoadd_input_type =
+      _cst_new_box_autoadd_input_typePtr.asFunction<ffi.Pointer<wire_cst_input_type> Function()>();
+
+  ffi.Pointer<wire_cst_lightning_address_info> cst_new_box_autoadd_lightning_address_info() {
+    return _cst_new_box_autoadd_lightning_address_info();
+  }
+
+  late final _cst_new_box_autoadd_lightning_address_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_lightning_address_info> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_lightning_address_info',
+      );
+  late final _cst_new_box_autoadd_lightning_address_info =
+      _cst_new_box_autoadd_lightning_address_infoPtr
+          .asFunction<ffi.Pointer<wire_cst_lightning_address_info> Function()>();
+
   ffi.Pointer<wire_cst_liquid_address_data> cst_new_box_autoadd_liquid_address_data() {
     return _cst_new_box_autoadd_liquid_address_data();
   }
@@ -5635,6 +10099,30 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_list_payments_requestPtr
           .asFunction<ffi.Pointer<wire_cst_list_payments_request> Function()>();
 
+  ffi.Pointer<wire_cst_list_swaps_request> cst_new_box_autoadd_list_swaps_request() {
+    return _cst_new_box_autoadd_list_swaps_request();
+  }
+
+  late final _cst_new_box_autoadd_list_swaps_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_swaps_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_list_swaps_request',
+      );
+  late final _cst_new_box_autoadd_list_swaps_request =
+      _cst_new_box_autoadd_list_swaps_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_list_swaps_request> Function()>();
+
+  ffi.Pointer<wire_cst_ln_bolt_12_invoice> cst_new_box_autoadd_ln_bolt_12_invoice() {
+    return _cst_new_box_autoadd_ln_bolt_12_invoice();
+  }
+
+  late final _cst_new_box_autoadd_ln_bolt_12_invoicePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_ln_bolt_12_invoice> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_ln_bolt_12_invoice',
+      );
+  late final _cst_new_box_autoadd_ln_bolt_12_invoice =
+      _cst_new_box_autoadd_ln_bolt_12_invoicePtr
+          .asFunction<ffi.Pointer<wire_cst_ln_bolt_12_invoice> Function()>();
+
   ffi.Pointer<wire_cst_ln_invoice> cst_new_box_autoadd_ln_invoice() {
     return _cst_new_box_autoadd_ln_invoice();
   }
@@ -5740,6 +10228,18 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_ln_url_pay_success_dataPtr
           .asFunction<ffi.Pointer<wire_cst_ln_url_pay_success_data> Function()>();
 
+  ffi.Pointer<wire_cst_ln_url_payer_data> cst_new_box_autoadd_ln_url_payer_data() {
+    return _cst_new_box_autoadd_ln_url_payer_data();
+  }
+
+  late final _cst_new_box_autoadd_ln_url_payer_dataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_ln_url_payer_data> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_ln_url_payer_data',
+      );
+  late final _cst_new_box_autoadd_ln_url_payer_data =
+      _cst_new_box_autoadd_ln_url_payer_dataPtr
+          .asFunction<ffi.Pointer<wire_cst_ln_url_payer_data> Function()>();
+
   ffi.Pointer<wire_cst_ln_url_withdraw_request> cst_new_box_autoadd_ln_url_withdraw_request() {
     return _cst_new_box_autoadd_ln_url_withdraw_request();
   }
@@ -5788,6 +10288,17 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_message_success_action_dataPtr
           .asFunction<ffi.Pointer<wire_cst_message_success_action_data> Function()>();
 
+  ffi.Pointer<wire_cst_nwc_budget> cst_new_box_autoadd_nwc_budget() {
+    return _cst_new_box_autoadd_nwc_budget();
+  }
+
+  late final _cst_new_box_autoadd_nwc_budgetPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_nwc_budget> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_nwc_budget',
+      );
+  late final _cst_new_box_autoadd_nwc_budget =
+      _cst_new_box_autoadd_nwc_budgetPtr.asFunction<ffi.Pointer<wire_cst_nwc_budget> Function()>();
+
   ffi.Pointer<wire_cst_pay_amount> cst_new_box_autoadd_pay_amount() {
     return _cst_new_box_autoadd_pay_amount();
   }
@@ -5834,6 +10345,18 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_prepare_buy_bitcoin_requestPtr
           .asFunction<ffi.Pointer<wire_cst_prepare_buy_bitcoin_request> Function()>();
 
+  ffi.Pointer<wire_cst_prepare_convert_request> cst_new_box_autoadd_prepare_convert_request() {
+    return _cst_new_box_autoadd_prepare_convert_request();
+  }
+
+  late final _cst_new_box_autoadd_prepare_convert_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_convert_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_prepare_convert_request',
+      );
+  late final _cst_new_box_autoadd_prepare_convert_request =
+      _cst_new_box_autoadd_prepare_convert_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_convert_request> Function()>();
+
   ffi.Pointer<wire_cst_prepare_ln_url_pay_request> cst_new_box_autoadd_prepare_ln_url_pay_request() {
     return _cst_new_box_autoadd_prepare_ln_url_pay_request();
   }
@@ -5858,6 +10381,18 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_prepare_pay_onchain_requestPtr
           .asFunction<ffi.Pointer<wire_cst_prepare_pay_onchain_request> Function()>();
 
+  ffi.Pointer<wire_cst_prepare_receive_quotes_request> cst_new_box_autoadd_prepare_receive_quotes_request() {
+    return _cst_new_box_autoadd_prepare_receive_quotes_request();
+  }
+
+  late final _cst_new_box_autoadd_prepare_receive_quotes_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_receive_quotes_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_quotes_request',
+      );
+  late final _cst_new_box_autoadd_prepare_receive_quotes_request =
+      _cst_new_box_autoadd_prepare_receive_quotes_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_receive_quotes_request> Function()>();
+
   ffi.Pointer<wire_cst_prepare_receive_request> cst_new_box_autoadd_prepare_receive_request() {
     return _cst_new_box_autoadd_prepare_receive_request();
   }
@@ -5894,6 +10429,30 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_prepare_send_requestPtr
           .asFunction<ffi.Pointer<wire_cst_prepare_send_request> Function()>();
 
+  ffi.Pointer<wire_cst_process_notification_request> cst_new_box_autoadd_process_notification_request() {
+    return _cst_new_box_autoadd_process_notification_request();
+  }
+
+  late final _cst_new_box_autoadd_process_notification_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_process_notification_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_process_notification_request',
+      );
+  late final _cst_new_box_autoadd_process_notification_request =
+      _cst_new_box_autoadd_process_notification_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_process_notification_request> Function()>();
+
+  ffi.Pointer<wire_cst_queue_payment_request> cst_new_box_autoadd_queue_payment_request() {
+    return _cst_new_box_autoadd_queue_payment_request();
+  }
+
+  late final _cst_new_box_autoadd_queue_payment_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_queue_payment_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_queue_payment_request',
+      );
+  late final _cst_new_box_autoadd_queue_payment_request =
+      _cst_new_box_autoadd_queue_payment_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_queue_payment_request> Function()>();
+
   ffi.Pointer<wire_cst_receive_amount> cst_new_box_autoadd_receive_amount() {
     return _cst_new_box_autoadd_receive_amount();
   }
@@ -5928,27 +10487,77 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_refund_request =
       _cst_new_box_autoadd_refund_requestPtr.asFunction<ffi.Pointer<wire_cst_refund_request> Function()>();
 
-  ffi.Pointer<wire_cst_restore_request> cst_new_box_autoadd_restore_request() {
-    return _cst_new_box_autoadd_restore_request();
+  ffi.Pointer<wire_cst_register_lightning_address_request>
+  cst_new_box_autoadd_register_lightning_address_request() {
+    return _cst_new_box_autoadd_register_lightning_address_request();
   }
 
-  late final _cst_new_box_autoadd_restore_requestPtr =
+  late final _cst_new_box_autoadd_register_lightning_address_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_register_lightning_address_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_register_lightning_address_request',
+      );
+  late final _cst_new_box_autoadd_register_lightning_address_request =
+      _cst_new_box_autoadd_register_lightning_address_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_register_lightning_address_request> Function()>();
+
+  ffi.Pointer<wire_cst_registered_asset> cst_new_box_autoadd_registered_asset() {
+    return _cst_new_box_autoadd_registered_asset();
+  }
+
+  late final _cst_new_box_autoadd_registered_assetPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_registered_asset> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_registered_asset',
+      );
+  late final _cst_new_box_autoadd_registered_asset =
+      _cst_new_box_autoadd_registered_assetPtr
+          .asFunction<ffi.Pointer<wire_cst_registered_asset> Function()>();
+
+  ffi.Pointer<wire_cst_replay_events_request> cst_new_box_autoadd_replay_events_request() {
+    return _cst_new_box_autoadd_replay_events_request();
+  }
+
+  late final _cst_new_box_autoadd_replay_events_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_replay_events_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_replay_events_request',
+      );
+  late final _cst_new_box_autoadd_replay_events_request =
+      _cst_new_box_autoadd_replay_events_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_replay_events_request> Function()>();
+
+  ffi.Pointer<wire_cst_restore_request> cst_new_box_autoadd_restore_request() {
+    return _cst_new_box_autoadd_restore_request();
+  }
+
+  late final _cst_new_box_autoadd_restore_requestPtr =
       _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_restore_request> Function()>>(
         'frbgen_breez_liquid_cst_new_box_autoadd_restore_request',
       );
   late final _cst_new_box_autoadd_restore_request =
       _cst_new_box_autoadd_restore_requestPtr.asFunction<ffi.Pointer<wire_cst_restore_request> Function()>();
 
-  ffi.Pointer<wire_cst_sdk_event> cst_new_box_autoadd_sdk_event() {
-    return _cst_new_box_autoadd_sdk_event();
+  ffi.Pointer<wire_cst_schedule_payment_request> cst_new_box_autoadd_schedule_payment_request() {
+    return _cst_new_box_autoadd_schedule_payment_request();
+  }
+
+  late final _cst_new_box_autoadd_schedule_payment_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_schedule_payment_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_schedule_payment_request',
+      );
+  late final _cst_new_box_autoadd_schedule_payment_request =
+      _cst_new_box_autoadd_schedule_payment_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_schedule_payment_request> Function()>();
+
+  ffi.Pointer<wire_cst_send_batch_request> cst_new_box_autoadd_send_batch_request() {
+    return _cst_new_box_autoadd_send_batch_request();
   }
 
-  late final _cst_new_box_autoadd_sdk_eventPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sdk_event> Function()>>(
-        'frbgen_breez_liquid_cst_new_box_autoadd_sdk_event',
+  late final _cst_new_box_autoadd_send_batch_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_send_batch_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_send_batch_request',
       );
-  late final _cst_new_box_autoadd_sdk_event =
-      _cst_new_box_autoadd_sdk_eventPtr.asFunction<ffi.Pointer<wire_cst_sdk_event> Function()>();
+  late final _cst_new_box_autoadd_send_batch_request =
+      _cst_new_box_autoadd_send_batch_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_send_batch_request> Function()>();
 
   ffi.Pointer<wire_cst_send_payment_request> cst_new_box_autoadd_send_payment_request() {
     return _cst_new_box_autoadd_send_payment_request();
@@ -5974,6 +10583,52 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_sign_message_requestPtr
           .asFunction<ffi.Pointer<wire_cst_sign_message_request> Function()>();
 
+  ffi.Pointer<wire_cst_sign_pset_request> cst_new_box_autoadd_sign_pset_request() {
+    return _cst_new_box_autoadd_sign_pset_request();
+  }
+
+  late final _cst_new_box_autoadd_sign_pset_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sign_pset_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_sign_pset_request',
+      );
+  late final _cst_new_box_autoadd_sign_pset_request =
+      _cst_new_box_autoadd_sign_pset_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_sign_pset_request> Function()>();
+
+  ffi.Pointer<wire_cst_simulation_config> cst_new_box_autoadd_simulation_config() {
+    return _cst_new_box_autoadd_simulation_config();
+  }
+
+  late final _cst_new_box_autoadd_simulation_configPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_simulation_config> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_simulation_config',
+      );
+  late final _cst_new_box_autoadd_simulation_config =
+      _cst_new_box_autoadd_simulation_configPtr
+          .asFunction<ffi.Pointer<wire_cst_simulation_config> Function()>();
+
+  ffi.Pointer<wire_cst_spend_limit> cst_new_box_autoadd_spend_limit() {
+    return _cst_new_box_autoadd_spend_limit();
+  }
+
+  late final _cst_new_box_autoadd_spend_limitPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_spend_limit> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_spend_limit',
+      );
+  late final _cst_new_box_autoadd_spend_limit =
+      _cst_new_box_autoadd_spend_limitPtr.asFunction<ffi.Pointer<wire_cst_spend_limit> Function()>();
+
+  ffi.Pointer<wire_cst_spend_limits> cst_new_box_autoadd_spend_limits() {
+    return _cst_new_box_autoadd_spend_limits();
+  }
+
+  late final _cst_new_box_autoadd_spend_limitsPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_spend_limits> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_spend_limits',
+      );
+  late final _cst_new_box_autoadd_spend_limits =
+      _cst_new_box_autoadd_spend_limitsPtr.asFunction<ffi.Pointer<wire_cst_spend_limits> Function()>();
+
   ffi.Pointer<wire_cst_success_action> cst_new_box_autoadd_success_action() {
     return _cst_new_box_autoadd_success_action();
   }
@@ -5997,6 +10652,30 @@ class RustLibWire implements BaseWire {
       _cst_new_box_autoadd_success_action_processedPtr
           .asFunction<ffi.Pointer<wire_cst_success_action_processed> Function()>();
 
+  ffi.Pointer<wire_cst_swap_service_status> cst_new_box_autoadd_swap_service_status() {
+    return _cst_new_box_autoadd_swap_service_status();
+  }
+
+  late final _cst_new_box_autoadd_swap_service_statusPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_swap_service_status> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_swap_service_status',
+      );
+  late final _cst_new_box_autoadd_swap_service_status =
+      _cst_new_box_autoadd_swap_service_statusPtr
+          .asFunction<ffi.Pointer<wire_cst_swap_service_status> Function()>();
+
+  ffi.Pointer<wire_cst_sweep_private_key_request> cst_new_box_autoadd_sweep_private_key_request() {
+    return _cst_new_box_autoadd_sweep_private_key_request();
+  }
+
+  late final _cst_new_box_autoadd_sweep_private_key_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sweep_private_key_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_sweep_private_key_request',
+      );
+  late final _cst_new_box_autoadd_sweep_private_key_request =
+      _cst_new_box_autoadd_sweep_private_key_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_sweep_private_key_request> Function()>();
+
   ffi.Pointer<wire_cst_symbol> cst_new_box_autoadd_symbol() {
     return _cst_new_box_autoadd_symbol();
   }
@@ -6008,6 +10687,17 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_symbol =
       _cst_new_box_autoadd_symbolPtr.asFunction<ffi.Pointer<wire_cst_symbol> Function()>();
 
+  ffi.Pointer<wire_cst_sync_request> cst_new_box_autoadd_sync_request() {
+    return _cst_new_box_autoadd_sync_request();
+  }
+
+  late final _cst_new_box_autoadd_sync_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_sync_request> Function()>>(
+        'frbgen_breez_liquid_cst_new_box_autoadd_sync_request',
+      );
+  late final _cst_new_box_autoadd_sync_request =
+      _cst_new_box_autoadd_sync_requestPtr.asFunction<ffi.Pointer<wire_cst_sync_request> Function()>();
+
   ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(int value) {
     return _cst_new_box_autoadd_u_32(value);
   }
@@ -6053,6 +10743,17 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_String =
       _cst_new_list_StringPtr.asFunction<ffi.Pointer<wire_cst_list_String> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_asset_amount> cst_new_list_asset_amount(int len) {
+    return _cst_new_list_asset_amount(len);
+  }
+
+  late final _cst_new_list_asset_amountPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_amount> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_asset_amount',
+      );
+  late final _cst_new_list_asset_amount =
+      _cst_new_list_asset_amountPtr.asFunction<ffi.Pointer<wire_cst_list_asset_amount> Function(int)>();
+
   ffi.Pointer<wire_cst_list_asset_balance> cst_new_list_asset_balance(int len) {
     return _cst_new_list_asset_balance(len);
   }
@@ -6075,6 +10776,40 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_asset_metadata =
       _cst_new_list_asset_metadataPtr.asFunction<ffi.Pointer<wire_cst_list_asset_metadata> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_asset_report> cst_new_list_asset_report(int len) {
+    return _cst_new_list_asset_report(len);
+  }
+
+  late final _cst_new_list_asset_reportPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_report> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_asset_report',
+      );
+  late final _cst_new_list_asset_report =
+      _cst_new_list_asset_reportPtr.asFunction<ffi.Pointer<wire_cst_list_asset_report> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_batch_send_item> cst_new_list_batch_send_item(int len) {
+    return _cst_new_list_batch_send_item(len);
+  }
+
+  late final _cst_new_list_batch_send_itemPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_batch_send_item> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_batch_send_item',
+      );
+  late final _cst_new_list_batch_send_item =
+      _cst_new_list_batch_send_itemPtr.asFunction<ffi.Pointer<wire_cst_list_batch_send_item> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_batch_send_item_result> cst_new_list_batch_send_item_result(int len) {
+    return _cst_new_list_batch_send_item_result(len);
+  }
+
+  late final _cst_new_list_batch_send_item_resultPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_batch_send_item_result> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_batch_send_item_result',
+      );
+  late final _cst_new_list_batch_send_item_result =
+      _cst_new_list_batch_send_item_resultPtr
+          .asFunction<ffi.Pointer<wire_cst_list_batch_send_item_result> Function(int)>();
+
   ffi.Pointer<wire_cst_list_external_input_parser> cst_new_list_external_input_parser(int len) {
     return _cst_new_list_external_input_parser(len);
   }
@@ -6098,6 +10833,17 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_fiat_currency =
       _cst_new_list_fiat_currencyPtr.asFunction<ffi.Pointer<wire_cst_list_fiat_currency> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_journaled_event> cst_new_list_journaled_event(int len) {
+    return _cst_new_list_journaled_event(len);
+  }
+
+  late final _cst_new_list_journaled_eventPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_journaled_event> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_journaled_event',
+      );
+  late final _cst_new_list_journaled_event =
+      _cst_new_list_journaled_eventPtr.asFunction<ffi.Pointer<wire_cst_list_journaled_event> Function(int)>();
+
   ffi.Pointer<wire_cst_list_ln_offer_blinded_path> cst_new_list_ln_offer_blinded_path(int len) {
     return _cst_new_list_ln_offer_blinded_path(len);
   }
@@ -6133,6 +10879,17 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_localized_name =
       _cst_new_list_localized_namePtr.asFunction<ffi.Pointer<wire_cst_list_localized_name> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_nwc_connection> cst_new_list_nwc_connection(int len) {
+    return _cst_new_list_nwc_connection(len);
+  }
+
+  late final _cst_new_list_nwc_connectionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_nwc_connection> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_nwc_connection',
+      );
+  late final _cst_new_list_nwc_connection =
+      _cst_new_list_nwc_connectionPtr.asFunction<ffi.Pointer<wire_cst_list_nwc_connection> Function(int)>();
+
   ffi.Pointer<wire_cst_list_payment> cst_new_list_payment(int len) {
     return _cst_new_list_payment(len);
   }
@@ -6155,6 +10912,18 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_payment_state =
       _cst_new_list_payment_statePtr.asFunction<ffi.Pointer<wire_cst_list_payment_state> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_payment_swap_type> cst_new_list_payment_swap_type(int len) {
+    return _cst_new_list_payment_swap_type(len);
+  }
+
+  late final _cst_new_list_payment_swap_typePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_swap_type> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_payment_swap_type',
+      );
+  late final _cst_new_list_payment_swap_type =
+      _cst_new_list_payment_swap_typePtr
+          .asFunction<ffi.Pointer<wire_cst_list_payment_swap_type> Function(int)>();
+
   ffi.Pointer<wire_cst_list_payment_type> cst_new_list_payment_type(int len) {
     return _cst_new_list_payment_type(len);
   }
@@ -6166,6 +10935,18 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_payment_type =
       _cst_new_list_payment_typePtr.asFunction<ffi.Pointer<wire_cst_list_payment_type> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_prepare_receive_response> cst_new_list_prepare_receive_response(int len) {
+    return _cst_new_list_prepare_receive_response(len);
+  }
+
+  late final _cst_new_list_prepare_receive_responsePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_prepare_receive_response> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_prepare_receive_response',
+      );
+  late final _cst_new_list_prepare_receive_response =
+      _cst_new_list_prepare_receive_responsePtr
+          .asFunction<ffi.Pointer<wire_cst_list_prepare_receive_response> Function(int)>();
+
   ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_new_list_prim_u_8_strict(int len) {
     return _cst_new_list_prim_u_8_strict(len);
   }
@@ -6177,6 +10958,29 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_prim_u_8_strict =
       _cst_new_list_prim_u_8_strictPtr.asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_pset_spend_limit> cst_new_list_pset_spend_limit(int len) {
+    return _cst_new_list_pset_spend_limit(len);
+  }
+
+  late final _cst_new_list_pset_spend_limitPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_pset_spend_limit> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_pset_spend_limit',
+      );
+  late final _cst_new_list_pset_spend_limit =
+      _cst_new_list_pset_spend_limitPtr
+          .asFunction<ffi.Pointer<wire_cst_list_pset_spend_limit> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_queued_payment> cst_new_list_queued_payment(int len) {
+    return _cst_new_list_queued_payment(len);
+  }
+
+  late final _cst_new_list_queued_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_queued_payment> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_queued_payment',
+      );
+  late final _cst_new_list_queued_payment =
+      _cst_new_list_queued_paymentPtr.asFunction<ffi.Pointer<wire_cst_list_queued_payment> Function(int)>();
+
   ffi.Pointer<wire_cst_list_rate> cst_new_list_rate(int len) {
     return _cst_new_list_rate(len);
   }
@@ -6188,6 +10992,29 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_rate =
       _cst_new_list_ratePtr.asFunction<ffi.Pointer<wire_cst_list_rate> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_recurring_payment> cst_new_list_recurring_payment(int len) {
+    return _cst_new_list_recurring_payment(len);
+  }
+
+  late final _cst_new_list_recurring_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_recurring_payment> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_recurring_payment',
+      );
+  late final _cst_new_list_recurring_payment =
+      _cst_new_list_recurring_paymentPtr
+          .asFunction<ffi.Pointer<wire_cst_list_recurring_payment> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_recurring_payment_occurrence> cst_new_list_recurring_payment_occurrence(int len) {
+    return _cst_new_list_recurring_payment_occurrence(len);
+  }
+
+  late final _cst_new_list_recurring_payment_occurrencePtr = _lookup<
+    ffi.NativeFunction<ffi.Pointer<wire_cst_list_recurring_payment_occurrence> Function(ffi.Int32)>
+  >('frbgen_breez_liquid_cst_new_list_recurring_payment_occurrence');
+  late final _cst_new_list_recurring_payment_occurrence =
+      _cst_new_list_recurring_payment_occurrencePtr
+          .asFunction<ffi.Pointer<wire_cst_list_recurring_payment_occurrence> Function(int)>();
+
   ffi.Pointer<wire_cst_list_refundable_swap> cst_new_list_refundable_swap(int len) {
     return _cst_new_list_refundable_swap(len);
   }
@@ -6221,6 +11048,63 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_route_hint_hop =
       _cst_new_list_route_hint_hopPtr.asFunction<ffi.Pointer<wire_cst_list_route_hint_hop> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_scheduled_payment> cst_new_list_scheduled_payment(int len) {
+    return _cst_new_list_scheduled_payment(len);
+  }
+
+  late final _cst_new_list_scheduled_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_scheduled_payment> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_scheduled_payment',
+      );
+  late final _cst_new_list_scheduled_payment =
+      _cst_new_list_scheduled_paymentPtr
+          .asFunction<ffi.Pointer<wire_cst_list_scheduled_payment> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_sdk_event_kind> cst_new_list_sdk_event_kind(int len) {
+    return _cst_new_list_sdk_event_kind(len);
+  }
+
+  late final _cst_new_list_sdk_event_kindPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_sdk_event_kind> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_sdk_event_kind',
+      );
+  late final _cst_new_list_sdk_event_kind =
+      _cst_new_list_sdk_event_kindPtr.asFunction<ffi.Pointer<wire_cst_list_sdk_event_kind> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_service_health> cst_new_list_service_health(int len) {
+    return _cst_new_list_service_health(len);
+  }
+
+  late final _cst_new_list_service_healthPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_service_health> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_service_health',
+      );
+  late final _cst_new_list_service_health =
+      _cst_new_list_service_healthPtr.asFunction<ffi.Pointer<wire_cst_list_service_health> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_swap_info> cst_new_list_swap_info(int len) {
+    return _cst_new_list_swap_info(len);
+  }
+
+  late final _cst_new_list_swap_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_swap_info> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_swap_info',
+      );
+  late final _cst_new_list_swap_info =
+      _cst_new_list_swap_infoPtr.asFunction<ffi.Pointer<wire_cst_list_swap_info> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_unblinded_tx_out> cst_new_list_unblinded_tx_out(int len) {
+    return _cst_new_list_unblinded_tx_out(len);
+  }
+
+  late final _cst_new_list_unblinded_tx_outPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_unblinded_tx_out> Function(ffi.Int32)>>(
+        'frbgen_breez_liquid_cst_new_list_unblinded_tx_out',
+      );
+  late final _cst_new_list_unblinded_tx_out =
+      _cst_new_list_unblinded_tx_outPtr
+          .asFunction<ffi.Pointer<wire_cst_list_unblinded_tx_out> Function(int)>();
+
   int dummy_method_to_enforce_bundling() {
     return _dummy_method_to_enforce_bundling();
   }
@@ -6263,6 +11147,48 @@ final class wire_cst_accept_payment_proposed_fees_request extends ffi.Struct {
   external wire_cst_fetch_payment_proposed_fees_response response;
 }
 
+final class wire_cst_list_sdk_event_kind extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_String extends ffi.Struct {
+  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_payment_swap_type extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_event_filter extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_sdk_event_kind> kinds;
+
+  external ffi.Pointer<wire_cst_list_String> payment_ids;
+
+  external ffi.Pointer<wire_cst_list_payment_swap_type> swap_types;
+}
+
+final class wire_cst_nwc_budget extends ffi.Struct {
+  @ffi.Uint64()
+  external int max_amount_sat;
+
+  external ffi.Pointer<ffi.Uint32> renewal_period_sec;
+}
+
+final class wire_cst_add_nwc_connection_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_nwc_budget> budget;
+}
+
 final class wire_cst_backup_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
 }
@@ -6292,10 +11218,65 @@ final class wire_cst_check_message_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> signature;
 }
 
+final class wire_cst_prepare_convert_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> from_asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> to_asset_id;
+
+  @ffi.Uint64()
+  external int amount;
+
+  @ffi.Uint64()
+  external int receiver_amount;
+
+  @ffi.Uint64()
+  external int fees_sat;
+}
+
+final class wire_cst_convert_request extends ffi.Struct {
+  external wire_cst_prepare_convert_response prepare_response;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> spend_limit_override;
+}
+
+final class wire_cst_create_recurring_payment_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  @ffi.Uint64()
+  external int amount_sat;
+
+  @ffi.Uint32()
+  external int interval_sec;
+
+  external ffi.Pointer<ffi.Uint32> start_at;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+}
+
+final class wire_cst_export_payment_unblinding_data_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> explorer_url;
+}
+
+final class wire_cst_export_wallet_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> export_path;
+}
+
 final class wire_cst_fetch_payment_proposed_fees_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
 }
 
+final class wire_cst_get_max_payable_request extends ffi.Struct {
+  @ffi.Int32()
+  external int payment_method;
+}
+
+final class wire_cst_get_max_receivable_request extends ffi.Struct {
+  @ffi.Int32()
+  external int payment_method;
+}
+
 final class wire_cst_GetPaymentRequest_PaymentHash extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
 }
@@ -6317,6 +11298,16 @@ final class wire_cst_get_payment_request extends ffi.Struct {
   external GetPaymentRequestKind kind;
 }
 
+final class wire_cst_get_report_request extends ffi.Struct {
+  external ffi.Pointer<ffi.Int64> from_timestamp;
+
+  external ffi.Pointer<ffi.Int64> to_timestamp;
+}
+
+final class wire_cst_import_wallet_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> export_path;
+}
+
 final class wire_cst_list_payment_type extends ffi.Struct {
   external ffi.Pointer<ffi.Int32> ptr;
 
@@ -6372,6 +11363,20 @@ final class wire_cst_list_payments_request extends ffi.Struct {
   external ffi.Pointer<ffi.Bool> sort_ascending;
 }
 
+final class wire_cst_list_swaps_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_payment_swap_type> swap_types;
+
+  external ffi.Pointer<wire_cst_list_payment_state> states;
+
+  external ffi.Pointer<ffi.Int64> from_timestamp;
+
+  external ffi.Pointer<ffi.Int64> to_timestamp;
+
+  external ffi.Pointer<ffi.Uint32> offset;
+
+  external ffi.Pointer<ffi.Uint32> limit;
+}
+
 final class wire_cst_ln_url_auth_request_data extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> k1;
 
@@ -6403,6 +11408,8 @@ final class wire_cst_SendDestination_LiquidAddress extends ffi.Struct {
   external ffi.Pointer<wire_cst_liquid_address_data> address_data;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payjoin_url;
 }
 
 final class wire_cst_route_hint_hop extends ffi.Struct {
@@ -6478,13 +11485,6 @@ final class wire_cst_SendDestination_Bolt11 extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
 }
 
-final class wire_cst_list_String extends ffi.Struct {
-  external ffi.Pointer<ffi.Pointer<wire_cst_list_prim_u_8_strict>> ptr;
-
-  @ffi.Int32()
-  external int len;
-}
-
 final class wire_cst_Amount_Bitcoin extends ffi.Struct {
   @ffi.Uint64()
   external int amount_msat;
@@ -6563,6 +11563,41 @@ final class wire_cst_send_destination extends ffi.Struct {
   external SendDestinationKind kind;
 }
 
+final class wire_cst_asset_amount extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  @ffi.Uint64()
+  external int base_units;
+
+  @ffi.Uint8()
+  external int precision;
+
+  @ffi.Uint8()
+  external int display_precision;
+}
+
+final class wire_cst_fee_breakdown extends ffi.Struct {
+  @ffi.Uint64()
+  external int service_fee_sat;
+
+  @ffi.Uint64()
+  external int swapper_network_fee_sat;
+
+  @ffi.Uint64()
+  external int lockup_fee_sat;
+
+  @ffi.Bool()
+  external bool lockup_fee_is_estimate;
+
+  @ffi.Uint64()
+  external int claim_fee_sat;
+
+  @ffi.Bool()
+  external bool claim_fee_is_estimate;
+
+  external ffi.Pointer<wire_cst_asset_amount> asset_fee;
+}
+
 final class wire_cst_ln_url_pay_request_data extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> callback;
 
@@ -6641,11 +11676,15 @@ final class wire_cst_prepare_ln_url_pay_response extends ffi.Struct {
   @ffi.Uint64()
   external int fees_sat;
 
+  external wire_cst_fee_breakdown fee_breakdown;
+
   external wire_cst_ln_url_pay_request_data data;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> comment;
 
   external ffi.Pointer<wire_cst_success_action> success_action;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> verify_url;
 }
 
 final class wire_cst_ln_url_pay_request extends ffi.Struct {
@@ -6690,6 +11729,8 @@ final class wire_cst_pay_onchain_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
 
   external wire_cst_prepare_pay_onchain_response prepare_response;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> spend_limit_override;
 }
 
 final class wire_cst_prepare_buy_bitcoin_request extends ffi.Struct {
@@ -6700,6 +11741,15 @@ final class wire_cst_prepare_buy_bitcoin_request extends ffi.Struct {
   external int amount_sat;
 }
 
+final class wire_cst_prepare_convert_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> from_asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> to_asset_id;
+
+  @ffi.Uint64()
+  external int amount;
+}
+
 final class wire_cst_PayAmount_Bitcoin extends ffi.Struct {
   @ffi.Uint64()
   external int receiver_amount_sat;
@@ -6714,10 +11764,18 @@ final class wire_cst_PayAmount_Asset extends ffi.Struct {
   external ffi.Pointer<ffi.Bool> estimate_asset_fees;
 }
 
+final class wire_cst_PayAmount_AssetUnits extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_amount> receiver_amount;
+
+  external ffi.Pointer<ffi.Bool> estimate_asset_fees;
+}
+
 final class PayAmountKind extends ffi.Union {
   external wire_cst_PayAmount_Bitcoin Bitcoin;
 
   external wire_cst_PayAmount_Asset Asset;
+
+  external wire_cst_PayAmount_AssetUnits AssetUnits;
 }
 
 final class wire_cst_pay_amount extends ffi.Struct {
@@ -6727,6 +11785,16 @@ final class wire_cst_pay_amount extends ffi.Struct {
   external PayAmountKind kind;
 }
 
+final class wire_cst_ln_url_payer_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pubkey;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> identifier;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> email;
+}
+
 final class wire_cst_prepare_ln_url_pay_request extends ffi.Struct {
   external wire_cst_ln_url_pay_request_data data;
 
@@ -6736,6 +11804,8 @@ final class wire_cst_prepare_ln_url_pay_request extends ffi.Struct {
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> comment;
 
+  external ffi.Pointer<wire_cst_ln_url_payer_data> payer_data;
+
   external ffi.Pointer<ffi.Bool> validate_success_action_url;
 }
 
@@ -6743,6 +11813,8 @@ final class wire_cst_prepare_pay_onchain_request extends ffi.Struct {
   external wire_cst_pay_amount amount;
 
   external ffi.Pointer<ffi.Uint32> fee_rate_sat_per_vbyte;
+
+  external ffi.Pointer<ffi.Int32> fee_speed;
 }
 
 final class wire_cst_ReceiveAmount_Bitcoin extends ffi.Struct {
@@ -6756,10 +11828,16 @@ final class wire_cst_ReceiveAmount_Asset extends ffi.Struct {
   external ffi.Pointer<ffi.Double> payer_amount;
 }
 
+final class wire_cst_ReceiveAmount_AssetUnits extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_amount> payer_amount;
+}
+
 final class ReceiveAmountKind extends ffi.Union {
   external wire_cst_ReceiveAmount_Bitcoin Bitcoin;
 
   external wire_cst_ReceiveAmount_Asset Asset;
+
+  external wire_cst_ReceiveAmount_AssetUnits AssetUnits;
 }
 
 final class wire_cst_receive_amount extends ffi.Struct {
@@ -6769,11 +11847,28 @@ final class wire_cst_receive_amount extends ffi.Struct {
   external ReceiveAmountKind kind;
 }
 
+final class wire_cst_fiat_amount extends ffi.Struct {
+  @ffi.Double()
+  external double amount;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> currency;
+}
+
 final class wire_cst_prepare_receive_request extends ffi.Struct {
   @ffi.Int32()
   external int payment_method;
 
   external ffi.Pointer<wire_cst_receive_amount> amount;
+
+  external ffi.Pointer<wire_cst_fiat_amount> fiat_amount;
+
+  external ffi.Pointer<ffi.Uint32> expiry_secs;
+}
+
+final class wire_cst_prepare_receive_quotes_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_receive_amount> amount;
+
+  external ffi.Pointer<wire_cst_fiat_amount> fiat_amount;
 }
 
 final class wire_cst_prepare_refund_request extends ffi.Struct {
@@ -6781,14 +11876,35 @@ final class wire_cst_prepare_refund_request extends ffi.Struct {
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_address;
 
-  @ffi.Uint32()
-  external int fee_rate_sat_per_vbyte;
+  external ffi.Pointer<ffi.Uint32> fee_rate_sat_per_vbyte;
+
+  external ffi.Pointer<ffi.Int32> fee_speed;
 }
 
 final class wire_cst_prepare_send_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
 
   external ffi.Pointer<wire_cst_pay_amount> amount;
+
+  external ffi.Pointer<wire_cst_fiat_amount> fiat_amount;
+}
+
+final class wire_cst_queue_payment_request extends ffi.Struct {
+  external wire_cst_prepare_send_request prepare_request;
+
+  external ffi.Pointer<ffi.Bool> use_asset_fees;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+}
+
+final class wire_cst_fiat_conversion extends ffi.Struct {
+  external wire_cst_fiat_amount fiat_amount;
+
+  @ffi.Double()
+  external double rate;
+
+  @ffi.Uint64()
+  external int amount_sat;
 }
 
 final class wire_cst_prepare_receive_response extends ffi.Struct {
@@ -6800,11 +11916,17 @@ final class wire_cst_prepare_receive_response extends ffi.Struct {
   @ffi.Uint64()
   external int fees_sat;
 
+  external wire_cst_fee_breakdown fee_breakdown;
+
   external ffi.Pointer<ffi.Uint64> min_payer_amount_sat;
 
   external ffi.Pointer<ffi.Uint64> max_payer_amount_sat;
 
   external ffi.Pointer<ffi.Double> swapper_feerate;
+
+  external ffi.Pointer<wire_cst_fiat_conversion> fiat_conversion;
+
+  external ffi.Pointer<ffi.Uint32> expiry_secs;
 }
 
 final class wire_cst_receive_payment_request extends ffi.Struct {
@@ -6813,6 +11935,10 @@ final class wire_cst_receive_payment_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
 
   external ffi.Pointer<ffi.Bool> use_description_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_address;
 }
 
 final class wire_cst_refund_request extends ffi.Struct {
@@ -6820,219 +11946,886 @@ final class wire_cst_refund_request extends ffi.Struct {
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_address;
 
-  @ffi.Uint32()
-  external int fee_rate_sat_per_vbyte;
+  external ffi.Pointer<ffi.Uint32> fee_rate_sat_per_vbyte;
+
+  external ffi.Pointer<ffi.Int32> fee_speed;
+}
+
+final class wire_cst_register_lightning_address_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> username;
+}
+
+final class wire_cst_replay_events_request extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> since_seq;
+
+  external ffi.Pointer<ffi.Uint32> limit;
 }
 
 final class wire_cst_restore_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
 }
 
+final class wire_cst_schedule_payment_request extends ffi.Struct {
+  external wire_cst_prepare_send_request prepare_request;
+
+  external ffi.Pointer<ffi.Bool> use_asset_fees;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+
+  @ffi.Uint32()
+  external int execute_at;
+}
+
+final class wire_cst_batch_send_item extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+}
+
+final class wire_cst_list_batch_send_item extends ffi.Struct {
+  external ffi.Pointer<wire_cst_batch_send_item> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_send_batch_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_batch_send_item> items;
+
+  external ffi.Pointer<ffi.Uint64> max_total_fee_sat;
+}
+
 final class wire_cst_prepare_send_response extends ffi.Struct {
   external wire_cst_send_destination destination;
 
   external ffi.Pointer<ffi.Uint64> fees_sat;
 
+  external ffi.Pointer<wire_cst_fee_breakdown> fee_breakdown;
+
   external ffi.Pointer<ffi.Double> estimated_asset_fees;
+
+  external ffi.Pointer<wire_cst_asset_amount> estimated_asset_fees_amount;
+
+  external ffi.Pointer<wire_cst_fiat_conversion> fiat_conversion;
 }
 
 final class wire_cst_send_payment_request extends ffi.Struct {
   external wire_cst_prepare_send_response prepare_response;
 
   external ffi.Pointer<ffi.Bool> use_asset_fees;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> spend_limit_override;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> idempotency_key;
 }
 
 final class wire_cst_sign_message_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
 }
 
-final class wire_cst_binding_event_listener extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> stream;
+final class wire_cst_pset_spend_limit extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  @ffi.Uint64()
+  external int max_amount_sat;
 }
 
-final class wire_cst_aes_success_action_data_decrypted extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+final class wire_cst_list_pset_spend_limit extends ffi.Struct {
+  external ffi.Pointer<wire_cst_pset_spend_limit> ptr;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_AesSuccessActionDataResult_Decrypted extends ffi.Struct {
-  external ffi.Pointer<wire_cst_aes_success_action_data_decrypted> data;
+final class wire_cst_sign_pset_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pset;
+
+  @ffi.Uint64()
+  external int max_fee_sat;
+
+  external ffi.Pointer<wire_cst_list_pset_spend_limit> spend_limits;
 }
 
-final class wire_cst_AesSuccessActionDataResult_ErrorStatus extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+final class wire_cst_sweep_private_key_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> key;
 }
 
-final class AesSuccessActionDataResultKind extends ffi.Union {
-  external wire_cst_AesSuccessActionDataResult_Decrypted Decrypted;
+final class wire_cst_sync_request extends ffi.Struct {
+  external ffi.Pointer<ffi.Bool> full;
+}
 
-  external wire_cst_AesSuccessActionDataResult_ErrorStatus ErrorStatus;
+final class wire_cst_BlockchainExplorer_Electrum extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
 }
 
-final class wire_cst_aes_success_action_data_result extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
+final class wire_cst_BlockchainExplorer_Esplora extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
 
-  external AesSuccessActionDataResultKind kind;
+  @ffi.Bool()
+  external bool use_waterfalls;
 }
 
-final class wire_cst_SuccessActionProcessed_Aes extends ffi.Struct {
-  external ffi.Pointer<wire_cst_aes_success_action_data_result> result;
-}
+final class BlockchainExplorerKind extends ffi.Union {
+  external wire_cst_BlockchainExplorer_Electrum Electrum;
 
-final class wire_cst_SuccessActionProcessed_Message extends ffi.Struct {
-  external ffi.Pointer<wire_cst_message_success_action_data> data;
+  external wire_cst_BlockchainExplorer_Esplora Esplora;
 }
 
-final class wire_cst_SuccessActionProcessed_Url extends ffi.Struct {
-  external ffi.Pointer<wire_cst_url_success_action_data> data;
+final class wire_cst_blockchain_explorer extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external BlockchainExplorerKind kind;
 }
 
-final class SuccessActionProcessedKind extends ffi.Union {
-  external wire_cst_SuccessActionProcessed_Aes Aes;
+final class wire_cst_external_input_parser extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> provider_id;
 
-  external wire_cst_SuccessActionProcessed_Message Message;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> input_regex;
 
-  external wire_cst_SuccessActionProcessed_Url Url;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> parser_url;
 }
 
-final class wire_cst_success_action_processed extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
+final class wire_cst_list_external_input_parser extends ffi.Struct {
+  external ffi.Pointer<wire_cst_external_input_parser> ptr;
 
-  external SuccessActionProcessedKind kind;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_ln_url_info extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ln_address;
+final class wire_cst_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_pay_comment;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_pay_domain;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_pay_metadata;
+  @ffi.Uint8()
+  external int precision;
 
-  external ffi.Pointer<wire_cst_success_action_processed> lnurl_pay_success_action;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> fiat_id;
 
-  external ffi.Pointer<wire_cst_success_action> lnurl_pay_unprocessed_success_action;
+  @ffi.Bool()
+  external bool is_amp;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_withdraw_endpoint;
+final class wire_cst_list_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_metadata> ptr;
+
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_PaymentDetails_Lightning extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+final class wire_cst_event_webhook_config extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> secret;
+}
 
+final class wire_cst_BackupPolicy_CompletedSwaps extends ffi.Struct {
   @ffi.Uint32()
-  external int liquid_expiration_blockheight;
+  external int swaps;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> preimage;
+final class wire_cst_BackupPolicy_Interval extends ffi.Struct {
+  @ffi.Uint32()
+  external int hours;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+final class BackupPolicyKind extends ffi.Union {
+  external wire_cst_BackupPolicy_CompletedSwaps CompletedSwaps;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bolt12_offer;
+  external wire_cst_BackupPolicy_Interval Interval;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+final class wire_cst_backup_policy extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination_pubkey;
+  external BackupPolicyKind kind;
+}
 
-  external ffi.Pointer<wire_cst_ln_url_info> lnurl_info;
+final class wire_cst_SpendLimit_Bitcoin extends ffi.Struct {
+  @ffi.Uint64()
+  external int amount_sat;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
+final class wire_cst_SpendLimit_Fiat extends ffi.Struct {
+  external ffi.Pointer<wire_cst_fiat_amount> amount;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_tx_id;
+final class SpendLimitKind extends ffi.Union {
+  external wire_cst_SpendLimit_Bitcoin Bitcoin;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+  external wire_cst_SpendLimit_Fiat Fiat;
+}
 
-  external ffi.Pointer<ffi.Uint64> refund_tx_amount_sat;
+final class wire_cst_spend_limit extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SpendLimitKind kind;
 }
 
-final class wire_cst_asset_info extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+final class wire_cst_spend_limits extends ffi.Struct {
+  external ffi.Pointer<wire_cst_spend_limit> per_payment;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+  external ffi.Pointer<wire_cst_spend_limit> per_day;
 
-  @ffi.Double()
-  external double amount;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> override_secret;
+}
 
-  external ffi.Pointer<ffi.Double> fees;
+final class wire_cst_simulation_config extends ffi.Struct {
+  @ffi.Uint32()
+  external int status_update_delay_sec;
+
+  @ffi.Double()
+  external double failure_rate;
 }
 
-final class wire_cst_PaymentDetails_Liquid extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+final class wire_cst_config extends ffi.Struct {
+  external wire_cst_blockchain_explorer liquid_explorer;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+  external wire_cst_blockchain_explorer bitcoin_explorer;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> working_dir;
 
-  external ffi.Pointer<wire_cst_asset_info> asset_info;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> cache_dir;
 
-  external ffi.Pointer<wire_cst_ln_url_info> lnurl_info;
+  @ffi.Int32()
+  external int network;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
-}
+  @ffi.Uint64()
+  external int payment_timeout_sec;
 
-final class wire_cst_PaymentDetails_Bitcoin extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sync_service_url;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+  external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> breez_api_key;
+
+  external ffi.Pointer<wire_cst_list_external_input_parser> external_input_parsers;
 
   @ffi.Bool()
-  external bool auto_accepted_fees;
+  external bool use_default_external_input_parsers;
 
-  external ffi.Pointer<ffi.Uint32> liquid_expiration_blockheight;
+  external ffi.Pointer<ffi.Uint32> onchain_fee_rate_leeway_sat_per_vbyte;
 
-  external ffi.Pointer<ffi.Uint32> bitcoin_expiration_blockheight;
+  external ffi.Pointer<wire_cst_list_asset_metadata> asset_metadata;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_tx_id;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sideswap_api_key;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+  external ffi.Pointer<wire_cst_event_webhook_config> event_webhook;
 
-  external ffi.Pointer<ffi.Uint64> refund_tx_amount_sat;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> fiat_currency;
 
-final class PaymentDetailsKind extends ffi.Union {
-  external wire_cst_PaymentDetails_Lightning Lightning;
+  external ffi.Pointer<ffi.Uint32> sync_interval_sec;
 
-  external wire_cst_PaymentDetails_Liquid Liquid;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bitcoin_explorer_authorization;
 
-  external wire_cst_PaymentDetails_Bitcoin Bitcoin;
-}
+  external ffi.Pointer<ffi.Uint32> swapper_max_attempts;
 
-final class wire_cst_payment_details extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
+  external ffi.Pointer<wire_cst_backup_policy> backup_policy;
 
-  external PaymentDetailsKind kind;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> nwc_relay_url;
 
-final class wire_cst_payment extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+  external ffi.Pointer<wire_cst_spend_limits> spend_limits;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_service_url;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> unblinding_data;
+  external ffi.Pointer<ffi.Uint32> outgoing_min_confirmations;
 
-  @ffi.Uint32()
-  external int timestamp;
+  external ffi.Pointer<ffi.Uint32> unconfirmed_tx_alert_sec;
 
-  @ffi.Uint64()
-  external int amount_sat;
+  external ffi.Pointer<ffi.Uint32> chain_service_timeout_sec;
 
-  @ffi.Uint64()
-  external int fees_sat;
+  external ffi.Pointer<ffi.Uint32> swapper_timeout_sec;
 
-  external ffi.Pointer<ffi.Uint64> swapper_fees_sat;
+  external ffi.Pointer<ffi.Uint32> swapper_ping_interval_sec;
 
-  @ffi.Int32()
-  external int payment_type;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> amp_server_url;
 
-  @ffi.Int32()
-  external int status;
+  external ffi.Pointer<wire_cst_simulation_config> simulation;
+
+  @ffi.Bool()
+  external bool manual_claim;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> dns_over_https_url;
+}
+
+final class wire_cst_connect_request extends ffi.Struct {
+  external wire_cst_config config;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> mnemonic;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> seed;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> xprv;
+}
+
+final class wire_cst_process_notification_request extends ffi.Struct {
+  external wire_cst_connect_request connect_request;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payload;
+
+  external ffi.Pointer<ffi.Bool> accept_proposed_fees;
+
+  external ffi.Pointer<ffi.Uint32> timeout_sec;
+}
+
+final class wire_cst_aes_success_action_data_decrypted extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext;
+}
+
+final class wire_cst_AesSuccessActionDataResult_Decrypted extends ffi.Struct {
+  external ffi.Pointer<wire_cst_aes_success_action_data_decrypted> data;
+}
+
+final class wire_cst_AesSuccessActionDataResult_ErrorStatus extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+}
+
+final class AesSuccessActionDataResultKind extends ffi.Union {
+  external wire_cst_AesSuccessActionDataResult_Decrypted Decrypted;
+
+  external wire_cst_AesSuccessActionDataResult_ErrorStatus ErrorStatus;
+}
+
+final class wire_cst_aes_success_action_data_result extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external AesSuccessActionDataResultKind kind;
+}
+
+final class wire_cst_amp_account extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> amp_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> descriptor;
+}
+
+final class wire_cst_asset_conversion extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> from_asset_id;
+
+  @ffi.Uint64()
+  external int from_amount;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> to_asset_id;
+
+  @ffi.Uint64()
+  external int to_amount;
+}
+
+final class wire_cst_asset_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+
+  @ffi.Double()
+  external double amount;
+
+  external ffi.Pointer<ffi.Double> fees;
+
+  external wire_cst_asset_amount asset_amount;
+
+  external ffi.Pointer<wire_cst_asset_amount> asset_fees;
+}
+
+final class wire_cst_bitcoin_address_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  @ffi.Int32()
+  external int network;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class wire_cst_block_info extends ffi.Struct {
+  @ffi.Uint32()
+  external int height;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> hash;
+
+  @ffi.Uint32()
+  external int timestamp;
+}
+
+final class wire_cst_InputType_BitcoinAddress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_bitcoin_address_data> address;
+}
+
+final class wire_cst_InputType_LiquidAddress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_liquid_address_data> address;
+}
+
+final class wire_cst_InputType_Bolt11 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_invoice> invoice;
+}
+
+final class wire_cst_InputType_Bolt12Offer extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_offer> offer;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
+}
+
+final class wire_cst_InputType_NodeId extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
+}
+
+final class wire_cst_InputType_Url extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class wire_cst_InputType_LnUrlPay extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_url_pay_request_data> data;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
+}
+
+final class wire_cst_InputType_LnUrlWithdraw extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_url_withdraw_request_data> data;
+}
+
+final class wire_cst_InputType_LnUrlAuth extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_url_auth_request_data> data;
+}
+
+final class wire_cst_ln_url_error_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+}
+
+final class wire_cst_InputType_LnUrlError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_url_error_data> data;
+}
+
+final class InputTypeKind extends ffi.Union {
+  external wire_cst_InputType_BitcoinAddress BitcoinAddress;
+
+  external wire_cst_InputType_LiquidAddress LiquidAddress;
+
+  external wire_cst_InputType_Bolt11 Bolt11;
+
+  external wire_cst_InputType_Bolt12Offer Bolt12Offer;
+
+  external wire_cst_InputType_NodeId NodeId;
+
+  external wire_cst_InputType_Url Url;
+
+  external wire_cst_InputType_LnUrlPay LnUrlPay;
+
+  external wire_cst_InputType_LnUrlWithdraw LnUrlWithdraw;
+
+  external wire_cst_InputType_LnUrlAuth LnUrlAuth;
+
+  external wire_cst_InputType_LnUrlError LnUrlError;
+}
+
+final class wire_cst_input_type extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external InputTypeKind kind;
+}
+
+final class wire_cst_lightning_address_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> username;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lightning_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl;
+}
+
+final class wire_cst_ln_bolt_12_invoice extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> signing_pubkey;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payer_note;
+
+  @ffi.Uint64()
+  external int amount_msat;
+
+  @ffi.Uint64()
+  external int created_at;
+
+  @ffi.Uint64()
+  external int expiry;
+
+  @ffi.Uint32()
+  external int payment_path_count;
+}
+
+final class wire_cst_SuccessActionProcessed_Aes extends ffi.Struct {
+  external ffi.Pointer<wire_cst_aes_success_action_data_result> result;
+}
+
+final class wire_cst_SuccessActionProcessed_Message extends ffi.Struct {
+  external ffi.Pointer<wire_cst_message_success_action_data> data;
+}
+
+final class wire_cst_SuccessActionProcessed_Url extends ffi.Struct {
+  external ffi.Pointer<wire_cst_url_success_action_data> data;
+}
+
+final class SuccessActionProcessedKind extends ffi.Union {
+  external wire_cst_SuccessActionProcessed_Aes Aes;
+
+  external wire_cst_SuccessActionProcessed_Message Message;
+
+  external wire_cst_SuccessActionProcessed_Url Url;
+}
+
+final class wire_cst_success_action_processed extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SuccessActionProcessedKind kind;
+}
+
+final class wire_cst_ln_url_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ln_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_pay_comment;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_pay_domain;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_pay_metadata;
+
+  external ffi.Pointer<wire_cst_success_action_processed> lnurl_pay_success_action;
+
+  external ffi.Pointer<wire_cst_success_action> lnurl_pay_unprocessed_success_action;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_withdraw_endpoint;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_pay_verify_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl_pay_verified_preimage;
+}
+
+final class wire_cst_ln_url_pay_error_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+}
+
+final class wire_cst_PaymentDetails_Lightning extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  @ffi.Uint32()
+  external int liquid_expiration_blockheight;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> preimage;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bolt12_offer;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination_pubkey;
+
+  external ffi.Pointer<wire_cst_ln_url_info> lnurl_info;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+
+  external ffi.Pointer<ffi.Uint64> refund_tx_amount_sat;
+}
+
+final class wire_cst_PaymentDetails_Liquid extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_asset_info> asset_info;
+
+  external ffi.Pointer<wire_cst_ln_url_info> lnurl_info;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
+
+  external ffi.Pointer<wire_cst_asset_conversion> conversion;
+}
+
+final class wire_cst_PaymentDetails_Bitcoin extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  @ffi.Bool()
+  external bool auto_accepted_fees;
+
+  external ffi.Pointer<ffi.Uint32> liquid_expiration_blockheight;
+
+  external ffi.Pointer<ffi.Uint32> bitcoin_expiration_blockheight;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+
+  external ffi.Pointer<ffi.Uint64> refund_tx_amount_sat;
+
+  external ffi.Pointer<ffi.Int32> buy_bitcoin_provider;
+}
+
+final class PaymentDetailsKind extends ffi.Union {
+  external wire_cst_PaymentDetails_Lightning Lightning;
+
+  external wire_cst_PaymentDetails_Liquid Liquid;
+
+  external wire_cst_PaymentDetails_Bitcoin Bitcoin;
+}
+
+final class wire_cst_payment_details extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external PaymentDetailsKind kind;
+}
+
+final class wire_cst_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> unblinding_data;
+
+  @ffi.Uint32()
+  external int timestamp;
+
+  @ffi.Uint64()
+  external int amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<ffi.Uint64> swapper_fees_sat;
+
+  @ffi.Int32()
+  external int payment_type;
+
+  @ffi.Int32()
+  external int status;
 
   external wire_cst_payment_details details;
+
+  external ffi.Pointer<ffi.Double> fiat_amount;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> fiat_currency;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> recurring_payment_id;
+}
+
+final class wire_cst_ln_url_pay_success_data extends ffi.Struct {
+  external wire_cst_payment payment;
+
+  external ffi.Pointer<wire_cst_success_action_processed> success_action;
+}
+
+final class wire_cst_ln_url_withdraw_success_data extends ffi.Struct {
+  external wire_cst_ln_invoice invoice;
+}
+
+final class wire_cst_registered_asset extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+
+  @ffi.Uint8()
+  external int precision;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> domain;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> icon_url;
+}
+
+final class wire_cst_swap_service_status extends ffi.Struct {
+  @ffi.Bool()
+  external bool send_available;
+
+  @ffi.Bool()
+  external bool receive_available;
+
+  @ffi.Bool()
+  external bool chain_send_available;
+
+  @ffi.Bool()
+  external bool chain_receive_available;
+}
+
+final class wire_cst_symbol extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> grapheme;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> template_;
+
+  external ffi.Pointer<ffi.Bool> rtl;
+
+  external ffi.Pointer<ffi.Uint32> position;
+}
+
+final class wire_cst_list_asset_amount extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_amount> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  @ffi.Uint64()
+  external int balance_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+
+  external ffi.Pointer<ffi.Double> balance;
+
+  @ffi.Uint64()
+  external int pending_send_sat;
+
+  @ffi.Uint64()
+  external int pending_receive_sat;
+
+  external ffi.Pointer<ffi.Double> pending_send;
+
+  external ffi.Pointer<ffi.Double> pending_receive;
+
+  external ffi.Pointer<wire_cst_asset_amount> asset_balance;
+}
+
+final class wire_cst_list_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_balance> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_asset_report extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  @ffi.Uint64()
+  external int received_amount;
+
+  @ffi.Uint64()
+  external int sent_amount;
+
+  @ffi.Uint64()
+  external int swap_fees_sat;
+
+  @ffi.Uint64()
+  external int onchain_fees_sat;
+
+  @ffi.Uint32()
+  external int received_count;
+
+  @ffi.Uint32()
+  external int sent_count;
+}
+
+final class wire_cst_list_asset_report extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_report> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_batch_send_item_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<wire_cst_payment> payment;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> error;
+}
+
+final class wire_cst_list_batch_send_item_result extends ffi.Struct {
+  external ffi.Pointer<wire_cst_batch_send_item_result> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_localized_name extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+}
+
+final class wire_cst_list_localized_name extends ffi.Struct {
+  external ffi.Pointer<wire_cst_localized_name> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_locale_overrides extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
+
+  external ffi.Pointer<ffi.Uint32> spacing;
+
+  external wire_cst_symbol symbol;
+}
+
+final class wire_cst_list_locale_overrides extends ffi.Struct {
+  external ffi.Pointer<wire_cst_locale_overrides> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_currency_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  @ffi.Uint32()
+  external int fraction_size;
+
+  external ffi.Pointer<ffi.Uint32> spacing;
+
+  external ffi.Pointer<wire_cst_symbol> symbol;
+
+  external ffi.Pointer<wire_cst_symbol> uniq_symbol;
+
+  external ffi.Pointer<wire_cst_list_localized_name> localized_name;
+
+  external ffi.Pointer<wire_cst_list_locale_overrides> locale_overrides;
+}
+
+final class wire_cst_fiat_currency extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external wire_cst_currency_info info;
+}
+
+final class wire_cst_list_fiat_currency extends ffi.Struct {
+  external ffi.Pointer<wire_cst_fiat_currency> ptr;
+
+  @ffi.Int32()
+  external int len;
 }
 
 final class wire_cst_SdkEvent_PaymentFailed extends ffi.Struct {
@@ -7067,11 +12860,105 @@ final class wire_cst_SdkEvent_PaymentWaitingFeeAcceptance extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
 
+final class wire_cst_SdkEvent_PaymentExpired extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_SdkEvent_PaymentReorged extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_SdkEvent_SwapUpdated extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  @ffi.Int32()
+  external int swap_type;
+
+  @ffi.Int32()
+  external int state;
+}
+
+final class wire_cst_SdkEvent_SwapClaimBroadcast extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+}
+
+final class wire_cst_SdkEvent_SwapRefundBroadcast extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+}
+
+final class wire_cst_SdkEvent_TipsUpdated extends ffi.Struct {
+  @ffi.Uint32()
+  external int liquid_tip;
+
+  @ffi.Uint32()
+  external int bitcoin_tip;
+}
+
 final class wire_cst_SdkEvent_DataSynced extends ffi.Struct {
   @ffi.Bool()
   external bool did_pull_new_records;
 }
 
+final class wire_cst_SdkEvent_ConnectivityChanged extends ffi.Struct {
+  @ffi.Bool()
+  external bool is_online;
+}
+
+final class wire_cst_SdkEvent_BackupFailed extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkEvent_BatchSendCompleted extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_batch_send_item_result> results;
+}
+
+final class wire_cst_SdkEvent_ScheduledPaymentExecuted extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_SdkEvent_ScheduledPaymentFailed extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkEvent_RecurringPaymentExecuted extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> recurring_payment_id;
+
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_SdkEvent_RecurringPaymentFailed extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> recurring_payment_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_SdkEvent_TransactionDropped extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+}
+
+final class wire_cst_SdkEvent_TransactionUnconfirmed extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+
+  @ffi.Uint32()
+  external int unconfirmed_sec;
+}
+
+final class wire_cst_SdkEvent_NewLiquidBlock extends ffi.Struct {
+  external ffi.Pointer<wire_cst_block_info> block;
+}
+
+final class wire_cst_SdkEvent_NewBitcoinBlock extends ffi.Struct {
+  external ffi.Pointer<wire_cst_block_info> block;
+}
+
 final class SdkEventKind extends ffi.Union {
   external wire_cst_SdkEvent_PaymentFailed PaymentFailed;
 
@@ -7089,279 +12976,343 @@ final class SdkEventKind extends ffi.Union {
 
   external wire_cst_SdkEvent_PaymentWaitingFeeAcceptance PaymentWaitingFeeAcceptance;
 
+  external wire_cst_SdkEvent_PaymentExpired PaymentExpired;
+
+  external wire_cst_SdkEvent_PaymentReorged PaymentReorged;
+
+  external wire_cst_SdkEvent_SwapUpdated SwapUpdated;
+
+  external wire_cst_SdkEvent_SwapClaimBroadcast SwapClaimBroadcast;
+
+  external wire_cst_SdkEvent_SwapRefundBroadcast SwapRefundBroadcast;
+
+  external wire_cst_SdkEvent_TipsUpdated TipsUpdated;
+
   external wire_cst_SdkEvent_DataSynced DataSynced;
-}
 
-final class wire_cst_sdk_event extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
+  external wire_cst_SdkEvent_ConnectivityChanged ConnectivityChanged;
 
-  external SdkEventKind kind;
-}
+  external wire_cst_SdkEvent_BackupFailed BackupFailed;
 
-final class wire_cst_BlockchainExplorer_Electrum extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
-}
+  external wire_cst_SdkEvent_BatchSendCompleted BatchSendCompleted;
 
-final class wire_cst_BlockchainExplorer_Esplora extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+  external wire_cst_SdkEvent_ScheduledPaymentExecuted ScheduledPaymentExecuted;
 
-  @ffi.Bool()
-  external bool use_waterfalls;
-}
+  external wire_cst_SdkEvent_ScheduledPaymentFailed ScheduledPaymentFailed;
 
-final class BlockchainExplorerKind extends ffi.Union {
-  external wire_cst_BlockchainExplorer_Electrum Electrum;
+  external wire_cst_SdkEvent_RecurringPaymentExecuted RecurringPaymentExecuted;
 
-  external wire_cst_BlockchainExplorer_Esplora Esplora;
+  external wire_cst_SdkEvent_RecurringPaymentFailed RecurringPaymentFailed;
+
+  external wire_cst_SdkEvent_TransactionDropped TransactionDropped;
+
+  external wire_cst_SdkEvent_TransactionUnconfirmed TransactionUnconfirmed;
+
+  external wire_cst_SdkEvent_NewLiquidBlock NewLiquidBlock;
+
+  external wire_cst_SdkEvent_NewBitcoinBlock NewBitcoinBlock;
 }
 
-final class wire_cst_blockchain_explorer extends ffi.Struct {
+final class wire_cst_sdk_event extends ffi.Struct {
   @ffi.Int32()
   external int tag;
 
-  external BlockchainExplorerKind kind;
+  external SdkEventKind kind;
 }
 
-final class wire_cst_external_input_parser extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> provider_id;
+final class wire_cst_journaled_event extends ffi.Struct {
+  @ffi.Uint64()
+  external int seq;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> input_regex;
+  @ffi.Uint32()
+  external int timestamp;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> parser_url;
+  external wire_cst_sdk_event event;
 }
 
-final class wire_cst_list_external_input_parser extends ffi.Struct {
-  external ffi.Pointer<wire_cst_external_input_parser> ptr;
+final class wire_cst_list_journaled_event extends ffi.Struct {
+  external ffi.Pointer<wire_cst_journaled_event> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_asset_metadata extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
-
+final class wire_cst_nwc_connection extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> client_pubkey;
 
-  @ffi.Uint8()
-  external int precision;
+  external ffi.Pointer<wire_cst_nwc_budget> budget;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> fiat_id;
+  @ffi.Uint64()
+  external int spent_sat;
+
+  @ffi.Uint32()
+  external int created_at;
 }
 
-final class wire_cst_list_asset_metadata extends ffi.Struct {
-  external ffi.Pointer<wire_cst_asset_metadata> ptr;
+final class wire_cst_list_nwc_connection extends ffi.Struct {
+  external ffi.Pointer<wire_cst_nwc_connection> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_config extends ffi.Struct {
-  external wire_cst_blockchain_explorer liquid_explorer;
-
-  external wire_cst_blockchain_explorer bitcoin_explorer;
+final class wire_cst_list_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> ptr;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> working_dir;
+  @ffi.Int32()
+  external int len;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> cache_dir;
+final class wire_cst_list_prepare_receive_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_prepare_receive_response> ptr;
 
   @ffi.Int32()
-  external int network;
+  external int len;
+}
 
-  @ffi.Uint64()
-  external int payment_timeout_sec;
+final class wire_cst_queued_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sync_service_url;
+  external wire_cst_queue_payment_request request;
 
-  external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
+  @ffi.Int32()
+  external int state;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> breez_api_key;
+  @ffi.Uint32()
+  external int attempts;
 
-  external ffi.Pointer<wire_cst_list_external_input_parser> external_input_parsers;
+  @ffi.Uint32()
+  external int created_at;
 
-  @ffi.Bool()
-  external bool use_default_external_input_parsers;
+  @ffi.Uint32()
+  external int next_attempt_at;
 
-  external ffi.Pointer<ffi.Uint32> onchain_fee_rate_leeway_sat_per_vbyte;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_error;
+}
 
-  external ffi.Pointer<wire_cst_list_asset_metadata> asset_metadata;
+final class wire_cst_list_queued_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_queued_payment> ptr;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> sideswap_api_key;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_connect_request extends ffi.Struct {
-  external wire_cst_config config;
+final class wire_cst_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> coin;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> mnemonic;
+  @ffi.Double()
+  external double value;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> passphrase;
+final class wire_cst_list_rate extends ffi.Struct {
+  external ffi.Pointer<wire_cst_rate> ptr;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> seed;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_bitcoin_address_data extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+final class wire_cst_recurring_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
 
-  @ffi.Int32()
-  external int network;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
 
-  external ffi.Pointer<ffi.Uint64> amount_sat;
+  @ffi.Uint64()
+  external int amount_sat;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+  @ffi.Uint32()
+  external int interval_sec;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
-}
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
 
-final class wire_cst_ln_url_error_data extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+  @ffi.Uint32()
+  external int created_at;
+
+  @ffi.Uint32()
+  external int next_occurrence_at;
 }
 
-final class wire_cst_ln_url_pay_error_data extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+final class wire_cst_list_recurring_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_recurring_payment> ptr;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> reason;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_ln_url_pay_success_data extends ffi.Struct {
-  external wire_cst_payment payment;
+final class wire_cst_recurring_payment_occurrence extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> recurring_payment_id;
 
-  external ffi.Pointer<wire_cst_success_action_processed> success_action;
-}
+  @ffi.Uint32()
+  external int occurrence_at;
 
-final class wire_cst_ln_url_withdraw_success_data extends ffi.Struct {
-  external wire_cst_ln_invoice invoice;
-}
+  @ffi.Uint32()
+  external int executed_at;
 
-final class wire_cst_symbol extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> grapheme;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> template_;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> error;
+}
 
-  external ffi.Pointer<ffi.Bool> rtl;
+final class wire_cst_list_recurring_payment_occurrence extends ffi.Struct {
+  external ffi.Pointer<wire_cst_recurring_payment_occurrence> ptr;
 
-  external ffi.Pointer<ffi.Uint32> position;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_asset_balance extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+final class wire_cst_refundable_swap extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_address;
+
+  @ffi.Uint32()
+  external int timestamp;
 
   @ffi.Uint64()
-  external int balance_sat;
+  external int amount_sat;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_refund_tx_id;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+  @ffi.Int32()
+  external int state;
 
-  external ffi.Pointer<ffi.Double> balance;
+  @ffi.Uint32()
+  external int refundable_since;
+
+  @ffi.Uint32()
+  external int refund_eligible_block_height;
+
+  @ffi.Bool()
+  external bool is_cooperative_refund_possible;
 }
 
-final class wire_cst_list_asset_balance extends ffi.Struct {
-  external ffi.Pointer<wire_cst_asset_balance> ptr;
+final class wire_cst_list_refundable_swap extends ffi.Struct {
+  external ffi.Pointer<wire_cst_refundable_swap> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_localized_name extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
+final class wire_cst_scheduled_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external wire_cst_schedule_payment_request request;
+
+  @ffi.Int32()
+  external int state;
+
+  @ffi.Uint32()
+  external int attempts;
+
+  @ffi.Uint32()
+  external int created_at;
+
+  @ffi.Uint32()
+  external int next_attempt_at;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_error;
 }
 
-final class wire_cst_list_localized_name extends ffi.Struct {
-  external ffi.Pointer<wire_cst_localized_name> ptr;
+final class wire_cst_list_scheduled_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_scheduled_payment> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_locale_overrides extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> locale;
+final class wire_cst_service_health extends ffi.Struct {
+  @ffi.Int32()
+  external int service;
 
-  external ffi.Pointer<ffi.Uint32> spacing;
+  @ffi.Bool()
+  external bool is_reachable;
 
-  external wire_cst_symbol symbol;
+  external ffi.Pointer<ffi.Uint64> latency_ms;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> error;
 }
 
-final class wire_cst_list_locale_overrides extends ffi.Struct {
-  external ffi.Pointer<wire_cst_locale_overrides> ptr;
+final class wire_cst_list_service_health extends ffi.Struct {
+  external ffi.Pointer<wire_cst_service_health> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_currency_info extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+final class wire_cst_swap_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
 
-  @ffi.Uint32()
-  external int fraction_size;
+  @ffi.Int32()
+  external int swap_type;
 
-  external ffi.Pointer<ffi.Uint32> spacing;
+  external ffi.Pointer<ffi.Int32> direction;
 
-  external ffi.Pointer<wire_cst_symbol> symbol;
+  @ffi.Int32()
+  external int state;
 
-  external ffi.Pointer<wire_cst_symbol> uniq_symbol;
+  @ffi.Uint32()
+  external int created_at;
 
-  external ffi.Pointer<wire_cst_list_localized_name> localized_name;
+  @ffi.Uint64()
+  external int timeout_block_height;
 
-  external ffi.Pointer<wire_cst_list_locale_overrides> locale_overrides;
-}
+  @ffi.Uint64()
+  external int payer_amount_sat;
 
-final class wire_cst_fiat_currency extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+  @ffi.Uint64()
+  external int receiver_amount_sat;
 
-  external wire_cst_currency_info info;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
 
-final class wire_cst_list_fiat_currency extends ffi.Struct {
-  external ffi.Pointer<wire_cst_fiat_currency> ptr;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lockup_address;
 
-  @ffi.Int32()
-  external int len;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> user_lockup_tx_id;
 
-final class wire_cst_list_payment extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> ptr;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> server_lockup_tx_id;
 
-  @ffi.Int32()
-  external int len;
-}
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_address;
 
-final class wire_cst_rate extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> coin;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_tx_id;
 
-  @ffi.Double()
-  external double value;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> create_response_json;
 }
 
-final class wire_cst_list_rate extends ffi.Struct {
-  external ffi.Pointer<wire_cst_rate> ptr;
+final class wire_cst_list_swap_info extends ffi.Struct {
+  external ffi.Pointer<wire_cst_swap_info> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
-final class wire_cst_refundable_swap extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_address;
+final class wire_cst_unblinded_tx_out extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> outpoint;
 
-  @ffi.Uint32()
-  external int timestamp;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
 
   @ffi.Uint64()
-  external int amount_sat;
+  external int amount;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_refund_tx_id;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_blinding_factor;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> amount_blinding_factor;
 }
 
-final class wire_cst_list_refundable_swap extends ffi.Struct {
-  external ffi.Pointer<wire_cst_refundable_swap> ptr;
+final class wire_cst_list_unblinded_tx_out extends ffi.Struct {
+  external ffi.Pointer<wire_cst_unblinded_tx_out> ptr;
 
   @ffi.Int32()
   external int len;
 }
 
+final class wire_cst_add_nwc_connection_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> connection_uri;
+}
+
 final class wire_cst_blockchain_info extends ffi.Struct {
   @ffi.Uint32()
   external int liquid_tip;
@@ -7370,11 +13321,41 @@ final class wire_cst_blockchain_info extends ffi.Struct {
   external int bitcoin_tip;
 }
 
+final class wire_cst_check_health_response extends ffi.Struct {
+  @ffi.Bool()
+  external bool is_degraded;
+
+  external ffi.Pointer<wire_cst_list_service_health> services;
+
+  external ffi.Pointer<wire_cst_swap_service_status> swap_service_status;
+}
+
 final class wire_cst_check_message_response extends ffi.Struct {
   @ffi.Bool()
   external bool is_valid;
 }
 
+final class wire_cst_DecodedInvoice_Bolt11 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_invoice> invoice;
+}
+
+final class wire_cst_DecodedInvoice_Bolt12 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_bolt_12_invoice> invoice;
+}
+
+final class DecodedInvoiceKind extends ffi.Union {
+  external wire_cst_DecodedInvoice_Bolt11 Bolt11;
+
+  external wire_cst_DecodedInvoice_Bolt12 Bolt12;
+}
+
+final class wire_cst_decoded_invoice extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external DecodedInvoiceKind kind;
+}
+
 final class wire_cst_wallet_info extends ffi.Struct {
   @ffi.Uint64()
   external int balance_sat;
@@ -7398,77 +13379,38 @@ final class wire_cst_get_info_response extends ffi.Struct {
   external wire_cst_blockchain_info blockchain_info;
 }
 
-final class wire_cst_InputType_BitcoinAddress extends ffi.Struct {
-  external ffi.Pointer<wire_cst_bitcoin_address_data> address;
-}
-
-final class wire_cst_InputType_LiquidAddress extends ffi.Struct {
-  external ffi.Pointer<wire_cst_liquid_address_data> address;
-}
-
-final class wire_cst_InputType_Bolt11 extends ffi.Struct {
-  external ffi.Pointer<wire_cst_ln_invoice> invoice;
-}
-
-final class wire_cst_InputType_Bolt12Offer extends ffi.Struct {
-  external ffi.Pointer<wire_cst_ln_offer> offer;
-
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
-}
-
-final class wire_cst_InputType_NodeId extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> node_id;
-}
-
-final class wire_cst_InputType_Url extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
-}
-
-final class wire_cst_InputType_LnUrlPay extends ffi.Struct {
-  external ffi.Pointer<wire_cst_ln_url_pay_request_data> data;
+final class wire_cst_get_max_payable_response extends ffi.Struct {
+  @ffi.Uint64()
+  external int receiver_amount_sat;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip353_address;
+  @ffi.Uint64()
+  external int fees_sat;
 }
 
-final class wire_cst_InputType_LnUrlWithdraw extends ffi.Struct {
-  external ffi.Pointer<wire_cst_ln_url_withdraw_request_data> data;
-}
+final class wire_cst_get_max_receivable_response extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> payer_amount_sat;
 
-final class wire_cst_InputType_LnUrlAuth extends ffi.Struct {
-  external ffi.Pointer<wire_cst_ln_url_auth_request_data> data;
+  @ffi.Uint64()
+  external int fees_sat;
 }
 
-final class wire_cst_InputType_LnUrlError extends ffi.Struct {
-  external ffi.Pointer<wire_cst_ln_url_error_data> data;
+final class wire_cst_get_report_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_asset_report> assets;
 }
 
-final class InputTypeKind extends ffi.Union {
-  external wire_cst_InputType_BitcoinAddress BitcoinAddress;
-
-  external wire_cst_InputType_LiquidAddress LiquidAddress;
-
-  external wire_cst_InputType_Bolt11 Bolt11;
-
-  external wire_cst_InputType_Bolt12Offer Bolt12Offer;
-
-  external wire_cst_InputType_NodeId NodeId;
-
-  external wire_cst_InputType_Url Url;
-
-  external wire_cst_InputType_LnUrlPay LnUrlPay;
+final class wire_cst_get_wallet_info_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> descriptor;
 
-  external wire_cst_InputType_LnUrlWithdraw LnUrlWithdraw;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> xpub;
 
-  external wire_cst_InputType_LnUrlAuth LnUrlAuth;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> derivation_path;
 
-  external wire_cst_InputType_LnUrlError LnUrlError;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> fingerprint;
 }
 
-final class wire_cst_input_type extends ffi.Struct {
-  @ffi.Int32()
-  external int tag;
-
-  external InputTypeKind kind;
+final class wire_cst_import_wallet_response extends ffi.Struct {
+  @ffi.Uint32()
+  external int imported_records;
 }
 
 final class wire_cst_limits extends ffi.Struct {
@@ -7708,6 +13650,11 @@ final class wire_cst_log_entry extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> line;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> level;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> target;
+
+  @ffi.Uint64()
+  external int timestamp;
 }
 
 final class wire_cst_onchain_payment_limits_response extends ffi.Struct {
@@ -7732,6 +13679,22 @@ final class wire_cst_PaymentError_Generic extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
+final class wire_cst_PaymentError_FeeLimitExceeded extends ffi.Struct {
+  @ffi.Uint64()
+  external int fees_sat;
+
+  @ffi.Uint64()
+  external int max_fee_sat;
+}
+
+final class wire_cst_PaymentError_SpendLimitExceeded extends ffi.Struct {
+  @ffi.Uint64()
+  external int amount_sat;
+
+  @ffi.Uint64()
+  external int remaining_sat;
+}
+
 final class wire_cst_PaymentError_InvalidDescription extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
@@ -7758,6 +13721,10 @@ final class wire_cst_PaymentError_SignerError extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
+final class wire_cst_PaymentError_ServiceUnavailable extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
 final class PaymentErrorKind extends ffi.Union {
   external wire_cst_PaymentError_AmountMissing AmountMissing;
 
@@ -7767,6 +13734,10 @@ final class PaymentErrorKind extends ffi.Union {
 
   external wire_cst_PaymentError_Generic Generic;
 
+  external wire_cst_PaymentError_FeeLimitExceeded FeeLimitExceeded;
+
+  external wire_cst_PaymentError_SpendLimitExceeded SpendLimitExceeded;
+
   external wire_cst_PaymentError_InvalidDescription InvalidDescription;
 
   external wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
@@ -7778,6 +13749,8 @@ final class PaymentErrorKind extends ffi.Union {
   external wire_cst_PaymentError_SendError SendError;
 
   external wire_cst_PaymentError_SignerError SignerError;
+
+  external wire_cst_PaymentError_ServiceUnavailable ServiceUnavailable;
 }
 
 final class wire_cst_payment_error extends ffi.Struct {
@@ -7787,6 +13760,22 @@ final class wire_cst_payment_error extends ffi.Struct {
   external PaymentErrorKind kind;
 }
 
+final class wire_cst_payment_unblinding_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> unblinding_data;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> unblinded_url;
+
+  external ffi.Pointer<wire_cst_list_unblinded_tx_out> inputs;
+
+  external ffi.Pointer<wire_cst_list_unblinded_tx_out> outputs;
+}
+
+final class wire_cst_prepare_receive_quotes_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prepare_receive_response> quotes;
+}
+
 final class wire_cst_prepare_refund_response extends ffi.Struct {
   @ffi.Uint32()
   external int tx_vsize;
@@ -7794,9 +13783,25 @@ final class wire_cst_prepare_refund_response extends ffi.Struct {
   @ffi.Uint64()
   external int tx_fee_sat;
 
+  @ffi.Uint32()
+  external int fee_rate_sat_per_vbyte;
+
+  @ffi.Uint64()
+  external int refundable_amount_sat;
+
+  @ffi.Uint64()
+  external int refund_amount_sat;
+
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_refund_tx_id;
 }
 
+final class wire_cst_process_notification_response extends ffi.Struct {
+  external wire_cst_payment payment;
+
+  @ffi.Bool()
+  external bool is_resolved;
+}
+
 final class wire_cst_receive_payment_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
 }
@@ -7822,17 +13827,39 @@ final class wire_cst_refund_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 }
 
+final class wire_cst_reusable_bitcoin_address_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  @ffi.Uint64()
+  external int min_deposit_sat;
+
+  @ffi.Uint64()
+  external int max_deposit_sat;
+}
+
+final class wire_cst_SdkError_DataDirLocked extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> dir;
+}
+
 final class wire_cst_SdkError_Generic extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
+final class wire_cst_SdkError_InvalidBackup extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
 final class wire_cst_SdkError_ServiceConnectivity extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
 final class SdkErrorKind extends ffi.Union {
+  external wire_cst_SdkError_DataDirLocked DataDirLocked;
+
   external wire_cst_SdkError_Generic Generic;
 
+  external wire_cst_SdkError_InvalidBackup InvalidBackup;
+
   external wire_cst_SdkError_ServiceConnectivity ServiceConnectivity;
 }
 
@@ -7843,22 +13870,75 @@ final class wire_cst_sdk_error extends ffi.Struct {
   external SdkErrorKind kind;
 }
 
+final class wire_cst_send_batch_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_batch_send_item_result> results;
+}
+
 final class wire_cst_send_payment_response extends ffi.Struct {
   external wire_cst_payment payment;
 }
 
 final class wire_cst_sign_message_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> signature;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pubkey;
+}
+
+final class wire_cst_sign_pset_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pset;
+
+  @ffi.Uint64()
+  external int fees_sat;
+}
+
+final class wire_cst_sweep_private_key_response extends ffi.Struct {
+  external wire_cst_payment payment;
+
+  @ffi.Uint64()
+  external int amount_sat;
+
+  external ffi.Pointer<wire_cst_list_asset_amount> asset_amounts;
+}
+
+final class wire_cst_sync_response extends ffi.Struct {
+  @ffi.Uint32()
+  external int new_tx_count;
+
+  @ffi.Uint32()
+  external int updated_swap_count;
+
+  @ffi.Uint64()
+  external int duration_ms;
+}
+
+final class wire_cst_validate_address_response extends ffi.Struct {
+  @ffi.Int32()
+  external int address_type;
+
+  @ffi.Int32()
+  external int network;
+
+  @ffi.Bool()
+  external bool is_confidential;
+
+  @ffi.Bool()
+  external bool supports_assets;
 }
 
 const int ESTIMATED_BTC_CLAIM_TX_VSIZE = 111;
 
 const int ESTIMATED_BTC_LOCKUP_TX_VSIZE = 154;
 
+const int DEFAULT_MAX_LOG_FILE_SIZE_BYTES = 10485760;
+
+const int DEFAULT_MAX_ROTATED_LOG_FILES = 5;
+
 const double LIQUID_FEE_RATE_SAT_PER_VBYTE = 0.1;
 
 const double LIQUID_FEE_RATE_MSAT_PER_VBYTE = 100.0;
 
+const int FIAT_ASSET_DISPLAY_PRECISION = 2;
+
 const double MIN_FEE_RATE = 0.1;
 
 const int WEIGHT_FIXED = 222;
@@ -7871,4 +13951,22 @@ const int WEIGHT_VOUT_NESTED = 270;
 
 const int DEFAULT_ZERO_CONF_MAX_SAT = 1000000;
 
+const int MIN_INVOICE_EXPIRY_SECS = 60;
+
+const int MAX_INVOICE_EXPIRY_SECS = 43200;
+
 const int CHAIN_SWAP_MONITORING_PERIOD_BITCOIN_BLOCKS = 4320;
+
+const int DEFAULT_SYNC_INTERVAL_SEC = 10;
+
+const int DEFAULT_UNCONFIRMED_TX_ALERT_SEC = 600;
+
+const int DEFAULT_CHAIN_SERVICE_TIMEOUT_SEC = 3;
+
+const int DEFAULT_SWAPPER_TIMEOUT_SEC = 30;
+
+const int DEFAULT_SWAPPER_PING_INTERVAL_SEC = 15;
+
+const int MAX_NETWORK_TIMEOUT_SEC = 120;
+
+const int DEFAULT_SWAPPER_MAX_ATTEMPTS = 3;
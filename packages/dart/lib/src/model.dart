@@ -26,19 +26,161 @@ class AcceptPaymentProposedFeesRequest {
           response == other.response;
 }
 
+/// An argument when calling [crate::sdk::LiquidSdk::add_nwc_connection].
+class AddNwcConnectionRequest {
+  /// A unique name identifying the connection, e.g. the name of the app using it
+  final String name;
+
+  /// The optional spending budget. If not set, the connection can spend the whole balance.
+  final NwcBudget? budget;
+
+  const AddNwcConnectionRequest({required this.name, this.budget});
+
+  @override
+  int get hashCode => name.hashCode ^ budget.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AddNwcConnectionRequest &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          budget == other.budget;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::add_nwc_connection].
+class AddNwcConnectionResponse {
+  /// The `nostr+walletconnect://` URI to share with the app using the connection. It
+  /// contains the connection secret, which is not stored by the SDK.
+  final String connectionUri;
+
+  const AddNwcConnectionResponse({required this.connectionUri});
+
+  @override
+  int get hashCode => connectionUri.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AddNwcConnectionResponse &&
+          runtimeType == other.runtimeType &&
+          connectionUri == other.connectionUri;
+}
+
+/// The chain of an address validated by [crate::sdk::LiquidSdk::validate_address]
+enum AddressType { bitcoin, liquid }
+
+/// An account registered with the AMP (Asset Management Platform) server, needed to receive
+/// and hold AMP assets. See [crate::sdk::LiquidSdk::register_amp_account].
+class AmpAccount {
+  /// The id of the account on the AMP server, which is shared with asset issuers to be
+  /// authorized to receive their assets
+  final String ampId;
+
+  /// The CT descriptor of the account, a multisig of the user and AMP server keys
+  final String descriptor;
+
+  const AmpAccount({required this.ampId, required this.descriptor});
+
+  @override
+  int get hashCode => ampId.hashCode ^ descriptor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AmpAccount &&
+          runtimeType == other.runtimeType &&
+          ampId == other.ampId &&
+          descriptor == other.descriptor;
+}
+
+/// An amount of a Liquid asset which carries the asset precision, so it can be converted
+/// between base units and its decimal representation without manual unit conversions.
+///
+/// For example, 1.5 USDT has 150_000_000 `base_units`, a `precision` of 8 and is displayed
+/// as "1.50" with a `display_precision` of 2.
+class AssetAmount {
+  final String assetId;
+
+  /// The amount in the smallest unit of the asset, the equivalent of satoshi for L-BTC
+  final BigInt baseUnits;
+
+  /// The number of decimals the base units are shifted by
+  final int precision;
+
+  /// The number of decimals the amount should be displayed with
+  final int displayPrecision;
+
+  const AssetAmount({
+    required this.assetId,
+    required this.baseUnits,
+    required this.precision,
+    required this.displayPrecision,
+  });
+
+  @override
+  int get hashCode => assetId.hashCode ^ baseUnits.hashCode ^ precision.hashCode ^ displayPrecision.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AssetAmount &&
+          runtimeType == other.runtimeType &&
+          assetId == other.assetId &&
+          baseUnits == other.baseUnits &&
+          precision == other.precision &&
+          displayPrecision == other.displayPrecision;
+}
+
 /// An asset balance to denote the balance for each asset.
+///
+/// The amounts in asset units, e.g. `balance`, are only set if the [AssetMetadata] of the asset
+/// is known, as they depend on its precision.
 class AssetBalance {
   final String assetId;
+
+  /// The confirmed balance, reduced by the unconfirmed spends
   final BigInt balanceSat;
   final String? name;
   final String? ticker;
   final double? balance;
 
-  const AssetBalance({required this.assetId, required this.balanceSat, this.name, this.ticker, this.balance});
+  /// The amount spent by unconfirmed wallet transactions
+  final BigInt pendingSendSat;
+
+  /// The amount received by unconfirmed wallet transactions
+  final BigInt pendingReceiveSat;
+  final double? pendingSend;
+  final double? pendingReceive;
+
+  /// The `balance_sat` carrying the asset precision
+  final AssetAmount? assetBalance;
+
+  const AssetBalance({
+    required this.assetId,
+    required this.balanceSat,
+    this.name,
+    this.ticker,
+    this.balance,
+    required this.pendingSendSat,
+    required this.pendingReceiveSat,
+    this.pendingSend,
+    this.pendingReceive,
+    this.assetBalance,
+  });
 
   @override
   int get hashCode =>
-      assetId.hashCode ^ balanceSat.hashCode ^ name.hashCode ^ ticker.hashCode ^ balance.hashCode;
+      assetId.hashCode ^
+      balanceSat.hashCode ^
+      name.hashCode ^
+      ticker.hashCode ^
+      balance.hashCode ^
+      pendingSendSat.hashCode ^
+      pendingReceiveSat.hashCode ^
+      pendingSend.hashCode ^
+      pendingReceive.hashCode ^
+      assetBalance.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -49,7 +191,44 @@ class AssetBalance {
           balanceSat == other.balanceSat &&
           name == other.name &&
           ticker == other.ticker &&
-          balance == other.balance;
+          balance == other.balance &&
+          pendingSendSat == other.pendingSendSat &&
+          pendingReceiveSat == other.pendingReceiveSat &&
+          pendingSend == other.pendingSend &&
+          pendingReceive == other.pendingReceive &&
+          assetBalance == other.assetBalance;
+}
+
+/// The pair of asset amounts exchanged by a conversion, see [crate::sdk::LiquidSdk::convert]
+class AssetConversion {
+  final String fromAssetId;
+
+  /// The amount sent, in base units of `from_asset_id`
+  final BigInt fromAmount;
+  final String toAssetId;
+
+  /// The amount received, in base units of `to_asset_id`
+  final BigInt toAmount;
+
+  const AssetConversion({
+    required this.fromAssetId,
+    required this.fromAmount,
+    required this.toAssetId,
+    required this.toAmount,
+  });
+
+  @override
+  int get hashCode => fromAssetId.hashCode ^ fromAmount.hashCode ^ toAssetId.hashCode ^ toAmount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AssetConversion &&
+          runtimeType == other.runtimeType &&
+          fromAssetId == other.fromAssetId &&
+          fromAmount == other.fromAmount &&
+          toAssetId == other.toAssetId &&
+          toAmount == other.toAmount;
 }
 
 /// Represents the Liquid payment asset info. The asset info is derived from
@@ -69,10 +248,29 @@ class AssetInfo {
   /// decimal shifted to the left by the [precision](AssetMetadata::precision)
   final double? fees;
 
-  const AssetInfo({required this.name, required this.ticker, required this.amount, this.fees});
+  /// The amount of the transaction, carrying the asset precision
+  final AssetAmount assetAmount;
+
+  /// The optional fees when paid using the asset, carrying the asset precision
+  final AssetAmount? assetFees;
+
+  const AssetInfo({
+    required this.name,
+    required this.ticker,
+    required this.amount,
+    this.fees,
+    required this.assetAmount,
+    this.assetFees,
+  });
 
   @override
-  int get hashCode => name.hashCode ^ ticker.hashCode ^ amount.hashCode ^ fees.hashCode;
+  int get hashCode =>
+      name.hashCode ^
+      ticker.hashCode ^
+      amount.hashCode ^
+      fees.hashCode ^
+      assetAmount.hashCode ^
+      assetFees.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -82,7 +280,9 @@ class AssetInfo {
           name == other.name &&
           ticker == other.ticker &&
           amount == other.amount &&
-          fees == other.fees;
+          fees == other.fees &&
+          assetAmount == other.assetAmount &&
+          assetFees == other.assetFees;
 }
 
 /// Configuration for asset metadata. Each asset metadata item represents an entry in the
@@ -105,17 +305,27 @@ class AssetMetadata {
   /// The optional ID of the fiat currency used to represent the asset
   final String? fiatId;
 
+  /// Whether the asset is an AMP (Asset Management Platform) asset, which can only be received
+  /// into the wallet's registered AMP account. See [Config::amp_server_url].
+  final bool isAmp;
+
   const AssetMetadata({
     required this.assetId,
     required this.name,
     required this.ticker,
     required this.precision,
     this.fiatId,
+    required this.isAmp,
   });
 
   @override
   int get hashCode =>
-      assetId.hashCode ^ name.hashCode ^ ticker.hashCode ^ precision.hashCode ^ fiatId.hashCode;
+      assetId.hashCode ^
+      name.hashCode ^
+      ticker.hashCode ^
+      precision.hashCode ^
+      fiatId.hashCode ^
+      isAmp.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -126,7 +336,71 @@ class AssetMetadata {
           name == other.name &&
           ticker == other.ticker &&
           precision == other.precision &&
-          fiatId == other.fiatId;
+          fiatId == other.fiatId &&
+          isAmp == other.isAmp;
+}
+
+/// The totals of an asset in a [GetReportResponse]
+class AssetReport {
+  final String assetId;
+
+  /// The total amount received, in the asset's base units
+  final BigInt receivedAmount;
+
+  /// The total amount sent excluding fees, in the asset's base units
+  final BigInt sentAmount;
+
+  /// The total fees of the swaps, including their claim and lockup txs made by the swapper
+  final BigInt swapFeesSat;
+
+  /// The total fees of the txs sent by the wallet
+  final BigInt onchainFeesSat;
+  final int receivedCount;
+  final int sentCount;
+
+  const AssetReport({
+    required this.assetId,
+    required this.receivedAmount,
+    required this.sentAmount,
+    required this.swapFeesSat,
+    required this.onchainFeesSat,
+    required this.receivedCount,
+    required this.sentCount,
+  });
+
+  @override
+  int get hashCode =>
+      assetId.hashCode ^
+      receivedAmount.hashCode ^
+      sentAmount.hashCode ^
+      swapFeesSat.hashCode ^
+      onchainFeesSat.hashCode ^
+      receivedCount.hashCode ^
+      sentCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AssetReport &&
+          runtimeType == other.runtimeType &&
+          assetId == other.assetId &&
+          receivedAmount == other.receivedAmount &&
+          sentAmount == other.sentAmount &&
+          swapFeesSat == other.swapFeesSat &&
+          onchainFeesSat == other.onchainFeesSat &&
+          receivedCount == other.receivedCount &&
+          sentCount == other.sentCount;
+}
+
+@freezed
+sealed class BackupPolicy with _$BackupPolicy {
+  const BackupPolicy._();
+
+  /// After every `swaps` completed swaps
+  const factory BackupPolicy.completedSwaps({required int swaps}) = BackupPolicy_CompletedSwaps;
+
+  /// Every `hours` hours
+  const factory BackupPolicy.interval({required int hours}) = BackupPolicy_Interval;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::backup].
@@ -150,6 +424,78 @@ class BackupRequest {
       other is BackupRequest && runtimeType == other.runtimeType && backupPath == other.backupPath;
 }
 
+/// A destination of a [SendBatchRequest]
+class BatchSendItem {
+  /// A Lightning invoice or offer, a Liquid address or BIP21 URI, a BIP353 address or a
+  /// Lightning address
+  final String destination;
+
+  /// The amount received. Required unless the destination already contains it.
+  final BigInt? amountSat;
+
+  const BatchSendItem({required this.destination, this.amountSat});
+
+  @override
+  int get hashCode => destination.hashCode ^ amountSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchSendItem &&
+          runtimeType == other.runtimeType &&
+          destination == other.destination &&
+          amountSat == other.amountSat;
+}
+
+/// The result of a [BatchSendItem]
+class BatchSendItemResult {
+  final String destination;
+
+  /// The payment, if it was sent
+  final Payment? payment;
+
+  /// The error, if the item failed to be prepared or sent
+  final String? error;
+
+  const BatchSendItemResult({required this.destination, this.payment, this.error});
+
+  @override
+  int get hashCode => destination.hashCode ^ payment.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BatchSendItemResult &&
+          runtimeType == other.runtimeType &&
+          destination == other.destination &&
+          payment == other.payment &&
+          error == other.error;
+}
+
+/// A block of the Liquid or Bitcoin chain, see [SdkEvent::NewLiquidBlock] and
+/// [SdkEvent::NewBitcoinBlock]
+class BlockInfo {
+  final int height;
+  final String hash;
+
+  /// The block timestamp, in seconds since the Unix epoch
+  final int timestamp;
+
+  const BlockInfo({required this.height, required this.hash, required this.timestamp});
+
+  @override
+  int get hashCode => height.hashCode ^ hash.hashCode ^ timestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BlockInfo &&
+          runtimeType == other.runtimeType &&
+          height == other.height &&
+          hash == other.hash &&
+          timestamp == other.timestamp;
+}
+
 @freezed
 sealed class BlockchainExplorer with _$BlockchainExplorer {
   const BlockchainExplorer._();
@@ -207,6 +553,30 @@ class BuyBitcoinRequest {
           redirectUrl == other.redirectUrl;
 }
 
+/// Returned when calling [crate::sdk::LiquidSdk::check_health].
+class CheckHealthResponse {
+  /// Whether a service is unreachable or a swap type is not offered
+  final bool isDegraded;
+  final List<ServiceHealth> services;
+
+  /// The swap types offered by the swapper, if it is reachable
+  final SwapServiceStatus? swapServiceStatus;
+
+  const CheckHealthResponse({required this.isDegraded, required this.services, this.swapServiceStatus});
+
+  @override
+  int get hashCode => isDegraded.hashCode ^ services.hashCode ^ swapServiceStatus.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CheckHealthResponse &&
+          runtimeType == other.runtimeType &&
+          isDegraded == other.isDegraded &&
+          services == other.services &&
+          swapServiceStatus == other.swapServiceStatus;
+}
+
 /// An argument when calling [crate::sdk::LiquidSdk::check_message].
 class CheckMessageRequest {
   /// The message that was signed.
@@ -271,7 +641,9 @@ class Config {
   /// Setting this field to `None` will disable the service
   final String? syncServiceUrl;
 
-  /// Maximum amount in satoshi to accept zero-conf payments with
+  /// Maximum amount in satoshi to accept zero-conf payments with, i.e. to claim incoming
+  /// Lightning and Bitcoin swaps before the swapper's lockup tx is confirmed. Larger payments
+  /// complete once the lockup tx is confirmed. Set to 0 to always wait for confirmation.
   /// Defaults to [DEFAULT_ZERO_CONF_MAX_SAT]
   final BigInt? zeroConfMaxAmountSat;
 
@@ -305,6 +677,150 @@ class Config {
   /// The SideSwap API key used for making requests to the SideSwap payjoin service
   final String? sideswapApiKey;
 
+  /// An optional webhook to which every [SdkEvent] is dispatched, turning the SDK into an
+  /// event source for backend systems. See [EventWebhookConfig] for more details.
+  ///
+  /// Defaults to `None`.
+  final EventWebhookConfig? eventWebhook;
+
+  /// The fiat currency in which the value of each payment is recorded when it completes,
+  /// e.g. `USD`. See [Payment::fiat_amount].
+  ///
+  /// Defaults to `None`.
+  final String? fiatCurrency;
+
+  /// The interval in seconds at which the SDK checks for new blocks and syncs with the
+  /// mempool and onchain data. A sync can also be triggered at any time with
+  /// [LiquidSdk::sync](crate::sdk::LiquidSdk::sync), e.g. when the app is brought to the foreground.
+  ///
+  /// Defaults to [DEFAULT_SYNC_INTERVAL_SEC](crate::sdk::DEFAULT_SYNC_INTERVAL_SEC).
+  final int? syncIntervalSec;
+
+  /// The value of the `Authorization` header sent with each request to the Bitcoin
+  /// [Esplora](BlockchainExplorer::Esplora) explorer, e.g. `Bearer <token>`. Allows using a
+  /// self-hosted Esplora or mempool.space instance that requires authentication.
+  ///
+  /// Defaults to `None`.
+  final String? bitcoinExplorerAuthorization;
+
+  /// The maximum number of attempts made for a swapper call that fails because the swapper is
+  /// temporarily unreachable or doesn't respond. Calls rejected by the swapper are not retried.
+  /// Retries are delayed with a jittered exponential backoff.
+  ///
+  /// Defaults to [DEFAULT_SWAPPER_MAX_ATTEMPTS].
+  final int? swapperMaxAttempts;
+
+  /// An optional policy to automatically back up the local state, so that a recent backup is
+  /// available for restore even if [LiquidSdk::backup](crate::sdk::LiquidSdk::backup) is
+  /// never called. The backup is written to the default backup path and uploaded with the
+  /// [BackupTransport], if one is set. Each backup is reported with an
+  /// [SdkEvent::BackupSucceeded] or [SdkEvent::BackupFailed] event.
+  ///
+  /// Defaults to `None`.
+  final BackupPolicy? backupPolicy;
+
+  /// The Nostr relay on which the [Nostr Wallet Connect](https://nips.nostr.com/47) service
+  /// listens for requests, e.g. `wss://relay.getalby.com/v1`. Once set, connections can be
+  /// added with [LiquidSdk::add_nwc_connection](crate::sdk::LiquidSdk::add_nwc_connection).
+  ///
+  /// The service is only available with the `nwc` feature. Defaults to `None`, which disables
+  /// the service.
+  final String? nwcRelayUrl;
+
+  /// The limits on the amounts sent, e.g. for kiosk or allowance wallets. See [SpendLimits].
+  ///
+  /// Defaults to `None`, which doesn't limit the payments.
+  final SpendLimits? spendLimits;
+
+  /// The LNURL service hosting the lightning address registered with
+  /// [LiquidSdk::register_lightning_address](crate::sdk::LiquidSdk::register_lightning_address).
+  ///
+  /// Defaults to `None`, which disables the lightning address APIs.
+  final String? lnurlServiceUrl;
+
+  /// The number of confirmations required before an outgoing payment is marked as
+  /// [Complete](PaymentState::Complete): the confirmations of the tx of a direct Liquid payment,
+  /// or of the Bitcoin claim tx of an outgoing chain swap. Until then, the payment stays
+  /// [Pending](PaymentState::Pending) and [SdkEvent::PaymentWaitingConfirmation] is emitted once
+  /// the tx is seen in the mempool, followed by [SdkEvent::PaymentSucceeded] once it has enough
+  /// confirmations.
+  ///
+  /// Defaults to 1.
+  final int? outgoingMinConfirmations;
+
+  /// The number of seconds after which an [SdkEvent::TransactionUnconfirmed] is emitted for a
+  /// tx of the wallet that is still not confirmed.
+  ///
+  /// Defaults to [DEFAULT_UNCONFIRMED_TX_ALERT_SEC](crate::sdk::DEFAULT_UNCONFIRMED_TX_ALERT_SEC).
+  final int? unconfirmedTxAlertSec;
+
+  /// The timeout in seconds of the requests to the Electrum or Esplora
+  /// [BlockchainExplorer]s. Can be raised on high-latency networks. It must be between 1 and
+  /// [MAX_NETWORK_TIMEOUT_SEC](crate::sdk::MAX_NETWORK_TIMEOUT_SEC).
+  ///
+  /// Defaults to [DEFAULT_CHAIN_SERVICE_TIMEOUT_SEC](crate::sdk::DEFAULT_CHAIN_SERVICE_TIMEOUT_SEC).
+  final int? chainServiceTimeoutSec;
+
+  /// The timeout in seconds of each swapper call. It must be between 1 and
+  /// [MAX_NETWORK_TIMEOUT_SEC](crate::sdk::MAX_NETWORK_TIMEOUT_SEC).
+  ///
+  /// The calls that are safe to retry, e.g. fetching the pairs or broadcasting txs, are retried
+  /// up to [Config::swapper_max_attempts] times after timing out. The other calls, e.g. creating
+  /// a swap, fail once timed out.
+  ///
+  /// Defaults to [DEFAULT_SWAPPER_TIMEOUT_SEC](crate::sdk::DEFAULT_SWAPPER_TIMEOUT_SEC).
+  final int? swapperTimeoutSec;
+
+  /// The interval in seconds between the keep-alive pings sent on the swapper status stream.
+  /// It must be between 1 and [MAX_NETWORK_TIMEOUT_SEC](crate::sdk::MAX_NETWORK_TIMEOUT_SEC).
+  ///
+  /// Defaults to [DEFAULT_SWAPPER_PING_INTERVAL_SEC](crate::sdk::DEFAULT_SWAPPER_PING_INTERVAL_SEC).
+  final int? swapperPingIntervalSec;
+
+  /// The URL of the AMP2 (Asset Management Platform) server used to register the AMP account
+  /// needed to receive and hold AMP-restricted assets, e.g. `https://amp2.testnet.blockstream.com`.
+  /// AMP2 is only available on testnet. If not set, AMP assets are not supported.
+  ///
+  /// See [LiquidSdk::register_amp_account](crate::sdk::LiquidSdk::register_amp_account).
+  final String? ampServerUrl;
+
+  /// Replaces the swapper with a local simulation of the swap lifecycles, to build and demo
+  /// apps without using the swapper or real funds. See [SimulationConfig]. It can't be used on
+  /// [LiquidNetwork::Mainnet].
+  ///
+  /// Defaults to `None`, which uses the swapper.
+  final SimulationConfig? simulation;
+
+  /// Disables the automatic claim of the incoming swaps, i.e. Receive swaps and incoming
+  /// Chain swaps. Once the swapper lockup is verified, the swap stays
+  /// [Pending](PaymentState::Pending) until it is claimed with
+  /// [LiquidSdk::claim_swap](crate::sdk::LiquidSdk::claim_swap), e.g. to batch claims at
+  /// low-fee times or after an operator approval. Claimable swaps are those of
+  /// [LiquidSdk::list_ongoing_swaps](crate::sdk::LiquidSdk::list_ongoing_swaps) with a
+  /// `server_lockup_tx_id` and no `claim_tx_id`.
+  ///
+  /// Note that an unclaimed swap can be refunded by the swapper once it expires.
+  ///
+  /// Defaults to `false`.
+  final bool manualClaim;
+
+  /// A Liquid address of [Config::network] where the Receive swaps are claimed to, e.g. a cold
+  /// storage or treasury wallet, instead of the SDK wallet. It can be overridden per swap with
+  /// [ReceivePaymentRequest::claim_address].
+  ///
+  /// Note that direct payments to the magic routing hint address of the invoice, as well as
+  /// Chain swaps, are still received in the SDK wallet.
+  ///
+  /// Defaults to `None`, which claims to the SDK wallet.
+  final String? claimAddress;
+
+  /// The DNS-over-HTTPS JSON API used to look up BIP353 names, e.g. `₿user@domain`. It must
+  /// validate the DNSSEC chain and report it in the `AD` flag of the response, as names that
+  /// are not authenticated by DNSSEC are rejected.
+  ///
+  /// Defaults to [DEFAULT_DNS_OVER_HTTPS_URL](crate::sdk::DEFAULT_DNS_OVER_HTTPS_URL).
+  final String? dnsOverHttpsUrl;
+
   const Config({
     required this.liquidExplorer,
     required this.bitcoinExplorer,
@@ -320,6 +836,25 @@ class Config {
     this.onchainFeeRateLeewaySatPerVbyte,
     this.assetMetadata,
     this.sideswapApiKey,
+    this.eventWebhook,
+    this.fiatCurrency,
+    this.syncIntervalSec,
+    this.bitcoinExplorerAuthorization,
+    this.swapperMaxAttempts,
+    this.backupPolicy,
+    this.nwcRelayUrl,
+    this.spendLimits,
+    this.lnurlServiceUrl,
+    this.outgoingMinConfirmations,
+    this.unconfirmedTxAlertSec,
+    this.chainServiceTimeoutSec,
+    this.swapperTimeoutSec,
+    this.swapperPingIntervalSec,
+    this.ampServerUrl,
+    this.simulation,
+    required this.manualClaim,
+    this.claimAddress,
+    this.dnsOverHttpsUrl,
   });
 
   @override
@@ -337,7 +872,26 @@ class Config {
       useDefaultExternalInputParsers.hashCode ^
       onchainFeeRateLeewaySatPerVbyte.hashCode ^
       assetMetadata.hashCode ^
-      sideswapApiKey.hashCode;
+      sideswapApiKey.hashCode ^
+      eventWebhook.hashCode ^
+      fiatCurrency.hashCode ^
+      syncIntervalSec.hashCode ^
+      bitcoinExplorerAuthorization.hashCode ^
+      swapperMaxAttempts.hashCode ^
+      backupPolicy.hashCode ^
+      nwcRelayUrl.hashCode ^
+      spendLimits.hashCode ^
+      lnurlServiceUrl.hashCode ^
+      outgoingMinConfirmations.hashCode ^
+      unconfirmedTxAlertSec.hashCode ^
+      chainServiceTimeoutSec.hashCode ^
+      swapperTimeoutSec.hashCode ^
+      swapperPingIntervalSec.hashCode ^
+      ampServerUrl.hashCode ^
+      simulation.hashCode ^
+      manualClaim.hashCode ^
+      claimAddress.hashCode ^
+      dnsOverHttpsUrl.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -357,11 +911,30 @@ class Config {
           useDefaultExternalInputParsers == other.useDefaultExternalInputParsers &&
           onchainFeeRateLeewaySatPerVbyte == other.onchainFeeRateLeewaySatPerVbyte &&
           assetMetadata == other.assetMetadata &&
-          sideswapApiKey == other.sideswapApiKey;
+          sideswapApiKey == other.sideswapApiKey &&
+          eventWebhook == other.eventWebhook &&
+          fiatCurrency == other.fiatCurrency &&
+          syncIntervalSec == other.syncIntervalSec &&
+          bitcoinExplorerAuthorization == other.bitcoinExplorerAuthorization &&
+          swapperMaxAttempts == other.swapperMaxAttempts &&
+          backupPolicy == other.backupPolicy &&
+          nwcRelayUrl == other.nwcRelayUrl &&
+          spendLimits == other.spendLimits &&
+          lnurlServiceUrl == other.lnurlServiceUrl &&
+          outgoingMinConfirmations == other.outgoingMinConfirmations &&
+          unconfirmedTxAlertSec == other.unconfirmedTxAlertSec &&
+          chainServiceTimeoutSec == other.chainServiceTimeoutSec &&
+          swapperTimeoutSec == other.swapperTimeoutSec &&
+          swapperPingIntervalSec == other.swapperPingIntervalSec &&
+          ampServerUrl == other.ampServerUrl &&
+          simulation == other.simulation &&
+          manualClaim == other.manualClaim &&
+          claimAddress == other.claimAddress &&
+          dnsOverHttpsUrl == other.dnsOverHttpsUrl;
 }
 
 /// An argument when calling [crate::sdk::LiquidSdk::connect].
-/// The resquest takes either a `mnemonic` and `passphrase`, or a `seed`.
+/// The resquest takes either a `mnemonic` and `passphrase`, a `seed`, or an `xprv`.
 class ConnectRequest {
   /// The SDK [Config]
   final Config config;
@@ -372,13 +945,19 @@ class ConnectRequest {
   /// The optional passphrase for the mnemonic
   final String? passphrase;
 
-  /// The optional Liquid wallet seed
+  /// The optional Liquid wallet seed, e.g. the 64-byte BIP39 seed
   final Uint8List? seed;
 
-  const ConnectRequest({required this.config, this.mnemonic, this.passphrase, this.seed});
+  /// The optional BIP32 master extended private key of the Liquid wallet, for key management
+  /// not based on a mnemonic or seed.
+  /// See [SdkSigner::new_with_xprv](crate::signer::SdkSigner::new_with_xprv).
+  final String? xprv;
+
+  const ConnectRequest({required this.config, this.mnemonic, this.passphrase, this.seed, this.xprv});
 
   @override
-  int get hashCode => config.hashCode ^ mnemonic.hashCode ^ passphrase.hashCode ^ seed.hashCode;
+  int get hashCode =>
+      config.hashCode ^ mnemonic.hashCode ^ passphrase.hashCode ^ seed.hashCode ^ xprv.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -388,587 +967,1405 @@ class ConnectRequest {
           config == other.config &&
           mnemonic == other.mnemonic &&
           passphrase == other.passphrase &&
-          seed == other.seed;
+          seed == other.seed &&
+          xprv == other.xprv;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::fetch_payment_proposed_fees].
-class FetchPaymentProposedFeesRequest {
-  final String swapId;
+/// An argument when calling [crate::sdk::LiquidSdk::convert].
+class ConvertRequest {
+  final PrepareConvertResponse prepareResponse;
 
-  const FetchPaymentProposedFeesRequest({required this.swapId});
+  /// A token allowing the conversion to exceed the [Config::spend_limits], created with
+  /// [crate::sdk::LiquidSdk::create_spend_limit_override]
+  final String? spendLimitOverride;
+
+  const ConvertRequest({required this.prepareResponse, this.spendLimitOverride});
 
   @override
-  int get hashCode => swapId.hashCode;
+  int get hashCode => prepareResponse.hashCode ^ spendLimitOverride.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is FetchPaymentProposedFeesRequest && runtimeType == other.runtimeType && swapId == other.swapId;
+      other is ConvertRequest &&
+          runtimeType == other.runtimeType &&
+          prepareResponse == other.prepareResponse &&
+          spendLimitOverride == other.spendLimitOverride;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::fetch_payment_proposed_fees].
-class FetchPaymentProposedFeesResponse {
-  final String swapId;
-  final BigInt feesSat;
+/// An argument when calling [crate::sdk::LiquidSdk::create_recurring_payment].
+class CreateRecurringPaymentRequest {
+  /// A Lightning address or BOLT12 offer, which can be paid repeatedly
+  final String destination;
 
-  /// Amount sent by the swap payer
-  final BigInt payerAmountSat;
+  /// The amount received on each occurrence
+  final BigInt amountSat;
 
-  /// Amount that will be received if these fees are accepted
-  final BigInt receiverAmountSat;
+  /// The interval between the occurrences, in seconds
+  final int intervalSec;
 
-  const FetchPaymentProposedFeesResponse({
-    required this.swapId,
-    required this.feesSat,
-    required this.payerAmountSat,
-    required this.receiverAmountSat,
+  /// The time of the first occurrence, as a UNIX timestamp in seconds. Defaults to now.
+  final int? startAt;
+
+  /// The maximum fees of each occurrence. See [SendPaymentRequest::max_fee_sat].
+  final BigInt? maxFeeSat;
+
+  const CreateRecurringPaymentRequest({
+    required this.destination,
+    required this.amountSat,
+    required this.intervalSec,
+    this.startAt,
+    this.maxFeeSat,
   });
 
   @override
   int get hashCode =>
-      swapId.hashCode ^ feesSat.hashCode ^ payerAmountSat.hashCode ^ receiverAmountSat.hashCode;
+      destination.hashCode ^
+      amountSat.hashCode ^
+      intervalSec.hashCode ^
+      startAt.hashCode ^
+      maxFeeSat.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is FetchPaymentProposedFeesResponse &&
+      other is CreateRecurringPaymentRequest &&
           runtimeType == other.runtimeType &&
-          swapId == other.swapId &&
-          feesSat == other.feesSat &&
-          payerAmountSat == other.payerAmountSat &&
-          receiverAmountSat == other.receiverAmountSat;
+          destination == other.destination &&
+          amountSat == other.amountSat &&
+          intervalSec == other.intervalSec &&
+          startAt == other.startAt &&
+          maxFeeSat == other.maxFeeSat;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::get_info].
-class GetInfoResponse {
-  /// The wallet information, such as the balance, fingerprint and public key
-  final WalletInfo walletInfo;
+@freezed
+sealed class DecodedInvoice with _$DecodedInvoice {
+  const DecodedInvoice._();
 
-  /// The latest synced blockchain information, such as the Liquid/Bitcoin tips
-  final BlockchainInfo blockchainInfo;
+  const factory DecodedInvoice.bolt11({required LNInvoice invoice}) = DecodedInvoice_Bolt11;
+  const factory DecodedInvoice.bolt12({required LNBolt12Invoice invoice}) = DecodedInvoice_Bolt12;
+}
 
-  const GetInfoResponse({required this.walletInfo, required this.blockchainInfo});
+enum Direction { incoming, outgoing }
+
+/// A filter for the events received by an [EventListener]. See
+/// [crate::sdk::LiquidSdk::add_event_listener]. An event matches the filter if it matches
+/// all of the set criteria.
+class EventFilter {
+  /// Only events of these kinds
+  final List<SdkEventKind>? kinds;
+
+  /// Only events related to a payment or swap with one of these ids, either swap ids or tx ids
+  final List<String>? paymentIds;
+
+  /// Only events related to a swap of one of these types
+  final List<PaymentSwapType>? swapTypes;
+
+  const EventFilter({this.kinds, this.paymentIds, this.swapTypes});
 
   @override
-  int get hashCode => walletInfo.hashCode ^ blockchainInfo.hashCode;
+  int get hashCode => kinds.hashCode ^ paymentIds.hashCode ^ swapTypes.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is GetInfoResponse &&
+      other is EventFilter &&
           runtimeType == other.runtimeType &&
-          walletInfo == other.walletInfo &&
-          blockchainInfo == other.blockchainInfo;
+          kinds == other.kinds &&
+          paymentIds == other.paymentIds &&
+          swapTypes == other.swapTypes;
 }
 
-@freezed
-sealed class GetPaymentRequest with _$GetPaymentRequest {
-  const GetPaymentRequest._();
+/// An endpoint to which the SDK events are POSTed as [JournaledEvent]s, in the order they were
+/// emitted. Each event is delivered at least once: failed deliveries are retried until the
+/// endpoint responds with a success status, so the endpoint should deduplicate events by their
+/// sequence number. The undelivered events are kept in the event journal until delivered.
+class EventWebhookConfig {
+  /// The url of the endpoint
+  final String url;
 
-  /// The payment hash of a Lightning payment
-  const factory GetPaymentRequest.paymentHash({required String paymentHash}) = GetPaymentRequest_PaymentHash;
+  /// The secret used to sign the requests. The hex-encoded HMAC-SHA256 of the request body
+  /// is sent in the `X-Breez-Signature` header.
+  final String secret;
 
-  /// A swap id or its SHA256 hash
-  const factory GetPaymentRequest.swapId({required String swapId}) = GetPaymentRequest_SwapId;
+  const EventWebhookConfig({required this.url, required this.secret});
+
+  @override
+  int get hashCode => url.hashCode ^ secret.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventWebhookConfig &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          secret == other.secret;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::fetch_lightning_limits].
-class LightningPaymentLimitsResponse {
-  /// Amount limits for a Send Payment to be valid
-  final Limits send;
+/// An argument when calling [crate::sdk::LiquidSdk::export_payment_unblinding_data].
+class ExportPaymentUnblindingDataRequest {
+  /// The id of the payment, i.e. its tx id or swap id
+  final String paymentId;
 
-  /// Amount limits for a Receive Payment to be valid
-  final Limits receive;
+  /// The base URL of the explorer to build the unblinded URL with, e.g.
+  /// `https://blockstream.info/liquid/`. Defaults to the Blockstream explorer of the network.
+  final String? explorerUrl;
 
-  const LightningPaymentLimitsResponse({required this.send, required this.receive});
+  const ExportPaymentUnblindingDataRequest({required this.paymentId, this.explorerUrl});
 
   @override
-  int get hashCode => send.hashCode ^ receive.hashCode;
+  int get hashCode => paymentId.hashCode ^ explorerUrl.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is LightningPaymentLimitsResponse &&
+      other is ExportPaymentUnblindingDataRequest &&
           runtimeType == other.runtimeType &&
-          send == other.send &&
-          receive == other.receive;
+          paymentId == other.paymentId &&
+          explorerUrl == other.explorerUrl;
 }
 
-/// The minimum and maximum in satoshis of a Lightning or onchain payment.
-class Limits {
-  final BigInt minSat;
-  final BigInt maxSat;
-  final BigInt maxZeroConfSat;
+/// An argument when calling [crate::sdk::LiquidSdk::export_wallet].
+class ExportWalletRequest {
+  /// Path of the export file to create
+  final String exportPath;
 
-  const Limits({required this.minSat, required this.maxSat, required this.maxZeroConfSat});
+  const ExportWalletRequest({required this.exportPath});
 
   @override
-  int get hashCode => minSat.hashCode ^ maxSat.hashCode ^ maxZeroConfSat.hashCode;
+  int get hashCode => exportPath.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is Limits &&
+      other is ExportWalletRequest && runtimeType == other.runtimeType && exportPath == other.exportPath;
+}
+
+/// The breakdown of the fees of a payment into the swapper service fee and the onchain fees
+/// of the swapper's, lockup and claim txs. The fees sum up to the `fees_sat` of the prepare
+/// response.
+///
+/// When the payment fees can be paid using the asset, `asset_fee` holds the cost of having
+/// the payjoin service pay the L-BTC network fee in exchange for an amount of the asset. If
+/// the payment can only be paid using the asset, `fees_sat` is not set and the breakdown holds
+/// the payjoin service fee and the network fee it pays instead.
+class FeeBreakdown {
+  /// The fee charged by the swapper for its service. It is fixed once the swap is created.
+  final BigInt serviceFeeSat;
+
+  /// The onchain fees of the swapper's own txs in a chain swap, i.e. its lockup tx and its claim
+  /// of the payer's lockup tx, which the swapper charges on top of its service fee. It is fixed
+  /// by the swapper.
+  final BigInt swapperNetworkFeeSat;
+
+  /// The fee of the tx locking up the funds of the swap, or of the tx paying the destination
+  /// directly when no swap is used
+  final BigInt lockupFeeSat;
+
+  /// Whether `lockup_fee_sat` is an estimate, which can change until the tx is broadcast,
+  /// rather than a fee fixed by the swapper
+  final bool lockupFeeIsEstimate;
+
+  /// The fee of the tx claiming the funds of the swap
+  final BigInt claimFeeSat;
+
+  /// Whether `claim_fee_sat` is an estimate, which can change until the tx is broadcast,
+  /// rather than a fee fixed by the swapper
+  final bool claimFeeIsEstimate;
+
+  /// The estimated fee paid in the asset instead of L-BTC. Is set when
+  /// [PayAmount::Asset::estimate_asset_fees] is set to `true` and the asset can pay fees.
+  final AssetAmount? assetFee;
+
+  const FeeBreakdown({
+    required this.serviceFeeSat,
+    required this.swapperNetworkFeeSat,
+    required this.lockupFeeSat,
+    required this.lockupFeeIsEstimate,
+    required this.claimFeeSat,
+    required this.claimFeeIsEstimate,
+    this.assetFee,
+  });
+
+  @override
+  int get hashCode =>
+      serviceFeeSat.hashCode ^
+      swapperNetworkFeeSat.hashCode ^
+      lockupFeeSat.hashCode ^
+      lockupFeeIsEstimate.hashCode ^
+      claimFeeSat.hashCode ^
+      claimFeeIsEstimate.hashCode ^
+      assetFee.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FeeBreakdown &&
           runtimeType == other.runtimeType &&
-          minSat == other.minSat &&
-          maxSat == other.maxSat &&
-          maxZeroConfSat == other.maxZeroConfSat;
+          serviceFeeSat == other.serviceFeeSat &&
+          swapperNetworkFeeSat == other.swapperNetworkFeeSat &&
+          lockupFeeSat == other.lockupFeeSat &&
+          lockupFeeIsEstimate == other.lockupFeeIsEstimate &&
+          claimFeeSat == other.claimFeeSat &&
+          claimFeeIsEstimate == other.claimFeeIsEstimate &&
+          assetFee == other.assetFee;
 }
 
-/// Network chosen for this Liquid SDK instance. Note that it represents both the Liquid and the
-/// Bitcoin network used.
-enum LiquidNetwork {
-  /// Mainnet Bitcoin and Liquid chains
-  mainnet,
+/// A fee rate preset, resolved using the current [RecommendedFees] of the Bitcoin chain service
+enum FeeSpeed {
+  /// Targets confirmation within about a day
+  economy,
 
-  /// Testnet Bitcoin and Liquid chains
-  testnet,
+  /// Targets confirmation within about half an hour
+  standard,
 
-  /// Regtest Bitcoin and Liquid chains
-  regtest,
+  /// Targets confirmation in the next block
+  fast,
 }
 
-@freezed
-sealed class ListPaymentDetails with _$ListPaymentDetails {
-  const ListPaymentDetails._();
+/// An argument when calling [crate::sdk::LiquidSdk::fetch_payment_proposed_fees].
+class FetchPaymentProposedFeesRequest {
+  final String swapId;
 
-  /// A Liquid payment
-  const factory ListPaymentDetails.liquid({
-    /// Optional asset id
-    String? assetId,
+  const FetchPaymentProposedFeesRequest({required this.swapId});
 
-    /// Optional BIP21 URI or address
-    String? destination,
-  }) = ListPaymentDetails_Liquid;
+  @override
+  int get hashCode => swapId.hashCode;
 
-  /// A Bitcoin payment
-  const factory ListPaymentDetails.bitcoin({
-    /// Optional address
-    String? address,
-  }) = ListPaymentDetails_Bitcoin;
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FetchPaymentProposedFeesRequest && runtimeType == other.runtimeType && swapId == other.swapId;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::list_payments].
-class ListPaymentsRequest {
-  final List<PaymentType>? filters;
-  final List<PaymentState>? states;
+/// Returned when calling [crate::sdk::LiquidSdk::fetch_payment_proposed_fees].
+class FetchPaymentProposedFeesResponse {
+  final String swapId;
+  final BigInt feesSat;
 
-  /// Epoch time, in seconds
-  final PlatformInt64? fromTimestamp;
+  /// Amount sent by the swap payer
+  final BigInt payerAmountSat;
 
-  /// Epoch time, in seconds
-  final PlatformInt64? toTimestamp;
-  final int? offset;
-  final int? limit;
-  final ListPaymentDetails? details;
-  final bool? sortAscending;
+  /// Amount that will be received if these fees are accepted
+  final BigInt receiverAmountSat;
 
-  const ListPaymentsRequest({
-    this.filters,
-    this.states,
-    this.fromTimestamp,
-    this.toTimestamp,
-    this.offset,
-    this.limit,
-    this.details,
-    this.sortAscending,
+  const FetchPaymentProposedFeesResponse({
+    required this.swapId,
+    required this.feesSat,
+    required this.payerAmountSat,
+    required this.receiverAmountSat,
   });
 
   @override
   int get hashCode =>
-      filters.hashCode ^
-      states.hashCode ^
-      fromTimestamp.hashCode ^
-      toTimestamp.hashCode ^
-      offset.hashCode ^
-      limit.hashCode ^
-      details.hashCode ^
-      sortAscending.hashCode;
+      swapId.hashCode ^ feesSat.hashCode ^ payerAmountSat.hashCode ^ receiverAmountSat.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is ListPaymentsRequest &&
+      other is FetchPaymentProposedFeesResponse &&
           runtimeType == other.runtimeType &&
-          filters == other.filters &&
-          states == other.states &&
-          fromTimestamp == other.fromTimestamp &&
-          toTimestamp == other.toTimestamp &&
-          offset == other.offset &&
-          limit == other.limit &&
-          details == other.details &&
-          sortAscending == other.sortAscending;
+          swapId == other.swapId &&
+          feesSat == other.feesSat &&
+          payerAmountSat == other.payerAmountSat &&
+          receiverAmountSat == other.receiverAmountSat;
 }
 
-/// Represents the payment LNURL info
-class LnUrlInfo {
-  final String? lnAddress;
-  final String? lnurlPayComment;
-  final String? lnurlPayDomain;
-  final String? lnurlPayMetadata;
-  final SuccessActionProcessed? lnurlPaySuccessAction;
-  final SuccessAction? lnurlPayUnprocessedSuccessAction;
-  final String? lnurlWithdrawEndpoint;
+/// An amount denominated in a fiat currency
+class FiatAmount {
+  /// The amount in the fiat currency, e.g. `5.00`
+  final double amount;
 
-  const LnUrlInfo({
-    this.lnAddress,
-    this.lnurlPayComment,
-    this.lnurlPayDomain,
-    this.lnurlPayMetadata,
-    this.lnurlPaySuccessAction,
-    this.lnurlPayUnprocessedSuccessAction,
-    this.lnurlWithdrawEndpoint,
-  });
+  /// The fiat currency code, e.g. `USD`
+  final String currency;
+
+  const FiatAmount({required this.amount, required this.currency});
 
   @override
-  int get hashCode =>
-      lnAddress.hashCode ^
-      lnurlPayComment.hashCode ^
-      lnurlPayDomain.hashCode ^
-      lnurlPayMetadata.hashCode ^
-      lnurlPaySuccessAction.hashCode ^
-      lnurlPayUnprocessedSuccessAction.hashCode ^
-      lnurlWithdrawEndpoint.hashCode;
+  int get hashCode => amount.hashCode ^ currency.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is LnUrlInfo &&
+      other is FiatAmount &&
           runtimeType == other.runtimeType &&
-          lnAddress == other.lnAddress &&
-          lnurlPayComment == other.lnurlPayComment &&
-          lnurlPayDomain == other.lnurlPayDomain &&
-          lnurlPayMetadata == other.lnurlPayMetadata &&
-          lnurlPaySuccessAction == other.lnurlPaySuccessAction &&
-          lnurlPayUnprocessedSuccessAction == other.lnurlPayUnprocessedSuccessAction &&
-          lnurlWithdrawEndpoint == other.lnurlWithdrawEndpoint;
+          amount == other.amount &&
+          currency == other.currency;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::lnurl_pay].
-class LnUrlPayRequest {
-  /// The response from calling [crate::sdk::LiquidSdk::prepare_lnurl_pay]
-  final PrepareLnUrlPayResponse prepareResponse;
+/// The conversion of a [FiatAmount] to satoshi, done when preparing a payment
+class FiatConversion {
+  /// The requested fiat amount
+  final FiatAmount fiatAmount;
 
-  const LnUrlPayRequest({required this.prepareResponse});
+  /// The exchange rate used, in units of the fiat currency per BTC
+  final double rate;
+
+  /// The resulting amount in satoshi
+  final BigInt amountSat;
+
+  const FiatConversion({required this.fiatAmount, required this.rate, required this.amountSat});
 
   @override
-  int get hashCode => prepareResponse.hashCode;
+  int get hashCode => fiatAmount.hashCode ^ rate.hashCode ^ amountSat.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is LnUrlPayRequest &&
+      other is FiatConversion &&
           runtimeType == other.runtimeType &&
-          prepareResponse == other.prepareResponse;
+          fiatAmount == other.fiatAmount &&
+          rate == other.rate &&
+          amountSat == other.amountSat;
 }
 
-@freezed
-sealed class LnUrlPayResult with _$LnUrlPayResult {
-  const LnUrlPayResult._();
-
-  const factory LnUrlPayResult.endpointSuccess({required LnUrlPaySuccessData data}) =
-      LnUrlPayResult_EndpointSuccess;
-  const factory LnUrlPayResult.endpointError({required LnUrlErrorData data}) = LnUrlPayResult_EndpointError;
-  const factory LnUrlPayResult.payError({required LnUrlPayErrorData data}) = LnUrlPayResult_PayError;
-}
+/// Returned when calling [crate::sdk::LiquidSdk::get_info].
+class GetInfoResponse {
+  /// The wallet information, such as the balance, fingerprint and public key
+  final WalletInfo walletInfo;
 
-class LnUrlPaySuccessData {
-  final Payment payment;
-  final SuccessActionProcessed? successAction;
+  /// The latest synced blockchain information, such as the Liquid/Bitcoin tips
+  final BlockchainInfo blockchainInfo;
 
-  const LnUrlPaySuccessData({required this.payment, this.successAction});
+  const GetInfoResponse({required this.walletInfo, required this.blockchainInfo});
 
   @override
-  int get hashCode => payment.hashCode ^ successAction.hashCode;
+  int get hashCode => walletInfo.hashCode ^ blockchainInfo.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is LnUrlPaySuccessData &&
+      other is GetInfoResponse &&
           runtimeType == other.runtimeType &&
-          payment == other.payment &&
-          successAction == other.successAction;
+          walletInfo == other.walletInfo &&
+          blockchainInfo == other.blockchainInfo;
 }
 
-/// Internal SDK log entry used in the Uniffi and Dart bindings
-class LogEntry {
-  final String line;
-  final String level;
+/// An argument when calling [crate::sdk::LiquidSdk::get_max_payable].
+class GetMaxPayableRequest {
+  final PaymentMethod paymentMethod;
 
-  const LogEntry({required this.line, required this.level});
+  const GetMaxPayableRequest({required this.paymentMethod});
 
   @override
-  int get hashCode => line.hashCode ^ level.hashCode;
+  int get hashCode => paymentMethod.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is LogEntry && runtimeType == other.runtimeType && line == other.line && level == other.level;
+      other is GetMaxPayableRequest &&
+          runtimeType == other.runtimeType &&
+          paymentMethod == other.paymentMethod;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::fetch_onchain_limits].
-class OnchainPaymentLimitsResponse {
-  /// Amount limits for a Send Onchain Payment to be valid
-  final Limits send;
+/// Returned when calling [crate::sdk::LiquidSdk::get_max_payable].
+class GetMaxPayableResponse {
+  /// The maximum amount the recipient can receive, or zero if the balance is too low to pay
+  /// with this payment method
+  final BigInt receiverAmountSat;
 
-  /// Amount limits for a Receive Onchain Payment to be valid
-  final Limits receive;
+  /// The total fees paid when sending the maximum amount
+  final BigInt feesSat;
 
-  const OnchainPaymentLimitsResponse({required this.send, required this.receive});
+  const GetMaxPayableResponse({required this.receiverAmountSat, required this.feesSat});
 
   @override
-  int get hashCode => send.hashCode ^ receive.hashCode;
+  int get hashCode => receiverAmountSat.hashCode ^ feesSat.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is OnchainPaymentLimitsResponse &&
+      other is GetMaxPayableResponse &&
           runtimeType == other.runtimeType &&
-          send == other.send &&
-          receive == other.receive;
+          receiverAmountSat == other.receiverAmountSat &&
+          feesSat == other.feesSat;
 }
 
-@freezed
-sealed class PayAmount with _$PayAmount {
-  const PayAmount._();
+/// An argument when calling [crate::sdk::LiquidSdk::get_max_receivable].
+class GetMaxReceivableRequest {
+  final PaymentMethod paymentMethod;
 
-  /// The amount in satoshi that will be received
-  const factory PayAmount.bitcoin({required BigInt receiverAmountSat}) = PayAmount_Bitcoin;
+  const GetMaxReceivableRequest({required this.paymentMethod});
 
-  /// The amount of an asset that will be received
-  const factory PayAmount.asset({
-    required String assetId,
-    required double receiverAmount,
-    bool? estimateAssetFees,
-  }) = PayAmount_Asset;
+  @override
+  int get hashCode => paymentMethod.hashCode;
 
-  /// Indicates that all available Bitcoin funds should be sent
-  const factory PayAmount.drain() = PayAmount_Drain;
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GetMaxReceivableRequest &&
+          runtimeType == other.runtimeType &&
+          paymentMethod == other.paymentMethod;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::pay_onchain].
-class PayOnchainRequest {
-  final String address;
-  final PreparePayOnchainResponse prepareResponse;
+/// Returned when calling [crate::sdk::LiquidSdk::get_max_receivable].
+class GetMaxReceivableResponse {
+  /// The maximum amount the payer can pay, or `None` if there is no maximum, as when
+  /// receiving to a Liquid address
+  final BigInt? payerAmountSat;
+
+  /// The total fees deducted when receiving the maximum amount
+  final BigInt feesSat;
 
-  const PayOnchainRequest({required this.address, required this.prepareResponse});
+  const GetMaxReceivableResponse({this.payerAmountSat, required this.feesSat});
 
   @override
-  int get hashCode => address.hashCode ^ prepareResponse.hashCode;
+  int get hashCode => payerAmountSat.hashCode ^ feesSat.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PayOnchainRequest &&
+      other is GetMaxReceivableResponse &&
           runtimeType == other.runtimeType &&
-          address == other.address &&
-          prepareResponse == other.prepareResponse;
+          payerAmountSat == other.payerAmountSat &&
+          feesSat == other.feesSat;
 }
 
-/// Represents an SDK payment.
-///
-/// By default, this is an onchain tx. It may represent a swap, if swap metadata is available.
-class Payment {
-  /// The destination associated with the payment, if it was created via our SDK.
-  /// Can be either a Liquid/Bitcoin address, a Liquid BIP21 URI or an invoice
-  final String? destination;
-  final String? txId;
+@freezed
+sealed class GetPaymentRequest with _$GetPaymentRequest {
+  const GetPaymentRequest._();
 
-  /// Data to use in the `blinded` param when unblinding the transaction in an explorer.
-  /// See: <https://docs.liquid.net/docs/unblinding-transactions>
-  final String? unblindingData;
+  /// The payment hash of a Lightning payment
+  const factory GetPaymentRequest.paymentHash({required String paymentHash}) = GetPaymentRequest_PaymentHash;
 
-  /// Composite timestamp that can be used for sorting or displaying the payment.
-  ///
-  /// If this payment has an associated swap, it is the swap creation time. Otherwise, the point
-  /// in time when the underlying tx was included in a block. If there is no associated swap
-  /// available and the underlying tx is not yet confirmed, the value is `now()`.
-  final int timestamp;
+  /// A swap id or its SHA256 hash
+  const factory GetPaymentRequest.swapId({required String swapId}) = GetPaymentRequest_SwapId;
+}
 
-  /// The payment amount, which corresponds to the onchain tx amount.
-  ///
-  /// In case of an outbound payment (Send), this is the payer amount. Otherwise it's the receiver amount.
-  final BigInt amountSat;
+/// An argument when calling [crate::sdk::LiquidSdk::get_report].
+class GetReportRequest {
+  /// Epoch time, in seconds
+  final PlatformInt64? fromTimestamp;
 
-  /// Represents the fees paid by this wallet for this payment.
-  ///
-  /// ### Swaps
-  /// If there is an associated Send Swap, these fees represent the total fees paid by this wallet
-  /// (the sender). It is the difference between the amount that was sent and the amount received.
-  ///
-  /// If there is an associated Receive Swap, these fees represent the total fees paid by this wallet
-  /// (the receiver). It is also the difference between the amount that was sent and the amount received.
-  ///
-  /// ### Pure onchain txs
-  /// If no swap is associated with this payment:
-  /// - for Send payments, this is the onchain tx fee
-  /// - for Receive payments, this is zero
-  final BigInt feesSat;
+  /// Epoch time, in seconds
+  final PlatformInt64? toTimestamp;
 
-  /// Service fees paid to the swapper service. This is only set for swaps (i.e. doesn't apply to
-  /// direct Liquid payments).
-  final BigInt? swapperFeesSat;
+  const GetReportRequest({this.fromTimestamp, this.toTimestamp});
 
-  /// If it is a `Send` or `Receive` payment
-  final PaymentType paymentType;
+  @override
+  int get hashCode => fromTimestamp.hashCode ^ toTimestamp.hashCode;
 
-  /// Composite status representing the overall status of the payment.
-  ///
-  /// If the tx has no associated swap, this reflects the onchain tx status (confirmed or not).
-  ///
-  /// If the tx has an associated swap, this is determined by the swap status (pending or complete).
-  final PaymentState status;
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GetReportRequest &&
+          runtimeType == other.runtimeType &&
+          fromTimestamp == other.fromTimestamp &&
+          toTimestamp == other.toTimestamp;
+}
 
-  /// The details of a payment, depending on its [destination](Payment::destination) and
-  /// [type](Payment::payment_type)
-  final PaymentDetails details;
+/// Returned when calling [crate::sdk::LiquidSdk::get_report].
+class GetReportResponse {
+  /// The totals of each asset with payments in the period, ordered by asset id
+  final List<AssetReport> assets;
 
-  const Payment({
-    this.destination,
-    this.txId,
-    this.unblindingData,
-    required this.timestamp,
-    required this.amountSat,
-    required this.feesSat,
-    this.swapperFeesSat,
-    required this.paymentType,
-    required this.status,
-    required this.details,
+  const GetReportResponse({required this.assets});
+
+  @override
+  int get hashCode => assets.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GetReportResponse && runtimeType == other.runtimeType && assets == other.assets;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::get_wallet_info].
+class GetWalletInfoResponse {
+  /// The CT descriptor of the wallet. As it contains the SLIP77 master blinding key, it can be
+  /// imported into a watch-only wallet that sees all the transactions and amounts.
+  final String descriptor;
+
+  /// The account xpub of the descriptor
+  final String xpub;
+
+  /// The derivation path of the account xpub
+  final String derivationPath;
+
+  /// The fingerprint of the master key
+  final String fingerprint;
+
+  const GetWalletInfoResponse({
+    required this.descriptor,
+    required this.xpub,
+    required this.derivationPath,
+    required this.fingerprint,
   });
 
   @override
-  int get hashCode =>
-      destination.hashCode ^
-      txId.hashCode ^
-      unblindingData.hashCode ^
-      timestamp.hashCode ^
-      amountSat.hashCode ^
-      feesSat.hashCode ^
-      swapperFeesSat.hashCode ^
-      paymentType.hashCode ^
-      status.hashCode ^
-      details.hashCode;
+  int get hashCode => descriptor.hashCode ^ xpub.hashCode ^ derivationPath.hashCode ^ fingerprint.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is Payment &&
+      other is GetWalletInfoResponse &&
           runtimeType == other.runtimeType &&
-          destination == other.destination &&
-          txId == other.txId &&
-          unblindingData == other.unblindingData &&
-          timestamp == other.timestamp &&
-          amountSat == other.amountSat &&
-          feesSat == other.feesSat &&
-          swapperFeesSat == other.swapperFeesSat &&
-          paymentType == other.paymentType &&
-          status == other.status &&
-          details == other.details;
+          descriptor == other.descriptor &&
+          xpub == other.xpub &&
+          derivationPath == other.derivationPath &&
+          fingerprint == other.fingerprint;
 }
 
-@freezed
-sealed class PaymentDetails with _$PaymentDetails {
-  const PaymentDetails._();
+/// A remote service checked by [crate::sdk::LiquidSdk::check_health]
+enum HealthCheckService {
+  /// The Liquid Electrum or Esplora server
+  liquidExplorer,
 
-  /// Swapping to or from Lightning
-  const factory PaymentDetails.lightning({
-    required String swapId,
+  /// The Bitcoin Electrum or Esplora server
+  bitcoinExplorer,
 
-    /// Represents the invoice description
-    required String description,
+  /// The fee estimation of the Bitcoin mempool, served by the Bitcoin explorer
+  mempoolFees,
 
-    /// The height of the block at which the swap will no longer be valid
-    required int liquidExpirationBlockheight,
+  /// The swapper API
+  swapperApi,
 
-    /// The preimage of the paid invoice (proof of payment).
-    String? preimage,
+  /// The WebSocket stream of swap updates
+  swapperStatusStream,
 
-    /// Represents the Bolt11/Bolt12 invoice associated with a payment
-    /// In the case of a Send payment, this is the invoice paid by the swapper
-    /// In the case of a Receive payment, this is the invoice paid by the user
-    String? invoice,
-    String? bolt12Offer,
+  /// The source of the fiat exchange rates
+  fiatRates,
+}
 
-    /// The payment hash of the invoice
-    String? paymentHash,
+/// An argument when calling [crate::sdk::LiquidSdk::import_wallet].
+class ImportWalletRequest {
+  /// Path of an export file created with [crate::sdk::LiquidSdk::export_wallet]
+  final String exportPath;
 
-    /// The invoice destination/payee pubkey
-    String? destinationPubkey,
+  const ImportWalletRequest({required this.exportPath});
 
-    /// The payment LNURL info
-    LnUrlInfo? lnurlInfo,
+  @override
+  int get hashCode => exportPath.hashCode;
 
-    /// The BIP353 address used to resolve this payment
-    String? bip353Address,
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportWalletRequest && runtimeType == other.runtimeType && exportPath == other.exportPath;
+}
 
-    /// For a Receive payment, this is the claim tx id in case it has already been broadcast
-    String? claimTxId,
+/// Returned when calling [crate::sdk::LiquidSdk::import_wallet].
+class ImportWalletResponse {
+  /// The number of swaps and payment metadata records imported. Records already stored
+  /// locally are not overwritten.
+  final int importedRecords;
 
-    /// For a Send swap which was refunded, this is the refund tx id
-    String? refundTxId,
+  const ImportWalletResponse({required this.importedRecords});
 
-    /// For a Send swap which was refunded, this is the refund amount
-    BigInt? refundTxAmountSat,
-  }) = PaymentDetails_Lightning;
+  @override
+  int get hashCode => importedRecords.hashCode;
 
-  /// Direct onchain payment to a Liquid address
-  const factory PaymentDetails.liquid({
-    /// Represents either a Liquid BIP21 URI or pure address
-    required String destination,
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportWalletResponse &&
+          runtimeType == other.runtimeType &&
+          importedRecords == other.importedRecords;
+}
 
-    /// Represents the BIP21 `message` field
-    required String description,
+/// An [SdkEvent] persisted in the event journal. See [crate::sdk::LiquidSdk::replay_events].
+class JournaledEvent {
+  /// The sequence number of the event, increasing in the order events were emitted
+  final BigInt seq;
 
-    /// The asset id
-    required String assetId,
+  /// The time the event was emitted, as a UNIX timestamp in seconds
+  final int timestamp;
+  final SdkEvent event;
 
-    /// The asset info derived from the [AssetMetadata]
-    AssetInfo? assetInfo,
+  const JournaledEvent({required this.seq, required this.timestamp, required this.event});
 
-    /// The payment LNURL info
-    LnUrlInfo? lnurlInfo,
+  @override
+  int get hashCode => seq.hashCode ^ timestamp.hashCode ^ event.hashCode;
 
-    /// The BIP353 address used to resolve this payment
-    String? bip353Address,
-  }) = PaymentDetails_Liquid;
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JournaledEvent &&
+          runtimeType == other.runtimeType &&
+          seq == other.seq &&
+          timestamp == other.timestamp &&
+          event == other.event;
+}
 
-  /// Swapping to or from the Bitcoin chain
-  const factory PaymentDetails.bitcoin({
-    required String swapId,
+/// A lightning address registered with the [Config::lnurl_service_url] service.
+class LightningAddressInfo {
+  final String username;
 
-    /// Represents the invoice description
-    required String description,
+  /// The lightning address, e.g. `satoshi@example.com`
+  final String lightningAddress;
 
-    /// For an amountless receive swap, this indicates if fees were automatically accepted.
-    /// Fees are auto accepted when the swapper proposes fees that are within the initial
-    /// estimate, plus the `onchain_fee_rate_leeway_sat_per_vbyte` set in the [Config], if any.
-    required bool autoAcceptedFees,
+  /// The bech32-encoded LNURL-pay of the lightning address
+  final String lnurl;
 
-    /// The height of the Liquid block at which the swap will no longer be valid
-    /// It should always be populated in case of an outgoing chain swap
-    int? liquidExpirationBlockheight,
+  const LightningAddressInfo({required this.username, required this.lightningAddress, required this.lnurl});
 
-    /// The height of the Bitcoin block at which the swap will no longer be valid
-    /// It should always be populated in case of an incoming chain swap
-    int? bitcoinExpirationBlockheight,
+  @override
+  int get hashCode => username.hashCode ^ lightningAddress.hashCode ^ lnurl.hashCode;
 
-    /// The claim tx id in case it has already been broadcast
-    String? claimTxId,
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LightningAddressInfo &&
+          runtimeType == other.runtimeType &&
+          username == other.username &&
+          lightningAddress == other.lightningAddress &&
+          lnurl == other.lnurl;
+}
 
-    /// For a Send swap which was refunded, this is the refund tx id
-    String? refundTxId,
+/// Returned when calling [crate::sdk::LiquidSdk::fetch_lightning_limits].
+class LightningPaymentLimitsResponse {
+  /// Amount limits for a Send Payment to be valid
+  final Limits send;
 
-    /// For a Send swap which was refunded, this is the refund amount
-    BigInt? refundTxAmountSat,
-  }) = PaymentDetails_Bitcoin;
+  /// Amount limits for a Receive Payment to be valid
+  final Limits receive;
+
+  const LightningPaymentLimitsResponse({required this.send, required this.receive});
+
+  @override
+  int get hashCode => send.hashCode ^ receive.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LightningPaymentLimitsResponse &&
+          runtimeType == other.runtimeType &&
+          send == other.send &&
+          receive == other.receive;
 }
 
-/// The send/receive methods supported by the SDK
-enum PaymentMethod { lightning, bitcoinAddress, liquidAddress }
+/// The minimum and maximum in satoshis of a Lightning or onchain payment.
+class Limits {
+  final BigInt minSat;
+  final BigInt maxSat;
+  final BigInt maxZeroConfSat;
 
-/// The payment state of an individual payment.
-enum PaymentState {
-  created,
+  const Limits({required this.minSat, required this.maxSat, required this.maxZeroConfSat});
+
+  @override
+  int get hashCode => minSat.hashCode ^ maxSat.hashCode ^ maxZeroConfSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Limits &&
+          runtimeType == other.runtimeType &&
+          minSat == other.minSat &&
+          maxSat == other.maxSat &&
+          maxZeroConfSat == other.maxZeroConfSat;
+}
+
+/// Network chosen for this Liquid SDK instance. Note that it represents both the Liquid and the
+/// Bitcoin network used.
+enum LiquidNetwork {
+  /// Mainnet Bitcoin and Liquid chains
+  mainnet,
+
+  /// Testnet Bitcoin and Liquid chains
+  testnet,
+
+  /// Regtest Bitcoin and Liquid chains
+  regtest,
+}
+
+@freezed
+sealed class ListPaymentDetails with _$ListPaymentDetails {
+  const ListPaymentDetails._();
+
+  /// A Liquid payment
+  const factory ListPaymentDetails.liquid({
+    /// Optional asset id
+    String? assetId,
+
+    /// Optional BIP21 URI or address
+    String? destination,
+  }) = ListPaymentDetails_Liquid;
+
+  /// A Bitcoin payment
+  const factory ListPaymentDetails.bitcoin({
+    /// Optional address
+    String? address,
+  }) = ListPaymentDetails_Bitcoin;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::list_payments].
+class ListPaymentsRequest {
+  final List<PaymentType>? filters;
+  final List<PaymentState>? states;
+
+  /// Epoch time, in seconds
+  final PlatformInt64? fromTimestamp;
+
+  /// Epoch time, in seconds
+  final PlatformInt64? toTimestamp;
+  final int? offset;
+  final int? limit;
+  final ListPaymentDetails? details;
+  final bool? sortAscending;
+
+  const ListPaymentsRequest({
+    this.filters,
+    this.states,
+    this.fromTimestamp,
+    this.toTimestamp,
+    this.offset,
+    this.limit,
+    this.details,
+    this.sortAscending,
+  });
+
+  @override
+  int get hashCode =>
+      filters.hashCode ^
+      states.hashCode ^
+      fromTimestamp.hashCode ^
+      toTimestamp.hashCode ^
+      offset.hashCode ^
+      limit.hashCode ^
+      details.hashCode ^
+      sortAscending.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ListPaymentsRequest &&
+          runtimeType == other.runtimeType &&
+          filters == other.filters &&
+          states == other.states &&
+          fromTimestamp == other.fromTimestamp &&
+          toTimestamp == other.toTimestamp &&
+          offset == other.offset &&
+          limit == other.limit &&
+          details == other.details &&
+          sortAscending == other.sortAscending;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::list_swaps].
+class ListSwapsRequest {
+  final List<PaymentSwapType>? swapTypes;
+  final List<PaymentState>? states;
+
+  /// Epoch time, in seconds
+  final PlatformInt64? fromTimestamp;
+
+  /// Epoch time, in seconds
+  final PlatformInt64? toTimestamp;
+  final int? offset;
+  final int? limit;
+
+  const ListSwapsRequest({
+    this.swapTypes,
+    this.states,
+    this.fromTimestamp,
+    this.toTimestamp,
+    this.offset,
+    this.limit,
+  });
+
+  @override
+  int get hashCode =>
+      swapTypes.hashCode ^
+      states.hashCode ^
+      fromTimestamp.hashCode ^
+      toTimestamp.hashCode ^
+      offset.hashCode ^
+      limit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ListSwapsRequest &&
+          runtimeType == other.runtimeType &&
+          swapTypes == other.swapTypes &&
+          states == other.states &&
+          fromTimestamp == other.fromTimestamp &&
+          toTimestamp == other.toTimestamp &&
+          offset == other.offset &&
+          limit == other.limit;
+}
+
+/// A BOLT12 invoice decoded by [crate::sdk::LiquidSdk::decode_invoice]
+class LNBolt12Invoice {
+  final String invoice;
+
+  /// The pubkey the invoice is signed with
+  final String signingPubkey;
+  final String paymentHash;
+  final String? description;
+
+  /// The note set by the payer when requesting the invoice
+  final String? payerNote;
+  final BigInt amountMsat;
+
+  /// The creation time of the invoice, in seconds since the Unix epoch
+  final BigInt createdAt;
+
+  /// The number of seconds after `created_at` at which the invoice expires
+  final BigInt expiry;
+
+  /// The number of blinded payment paths, which replace the BOLT11 routing hints
+  final int paymentPathCount;
+
+  const LNBolt12Invoice({
+    required this.invoice,
+    required this.signingPubkey,
+    required this.paymentHash,
+    this.description,
+    this.payerNote,
+    required this.amountMsat,
+    required this.createdAt,
+    required this.expiry,
+    required this.paymentPathCount,
+  });
+
+  @override
+  int get hashCode =>
+      invoice.hashCode ^
+      signingPubkey.hashCode ^
+      paymentHash.hashCode ^
+      description.hashCode ^
+      payerNote.hashCode ^
+      amountMsat.hashCode ^
+      createdAt.hashCode ^
+      expiry.hashCode ^
+      paymentPathCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LNBolt12Invoice &&
+          runtimeType == other.runtimeType &&
+          invoice == other.invoice &&
+          signingPubkey == other.signingPubkey &&
+          paymentHash == other.paymentHash &&
+          description == other.description &&
+          payerNote == other.payerNote &&
+          amountMsat == other.amountMsat &&
+          createdAt == other.createdAt &&
+          expiry == other.expiry &&
+          paymentPathCount == other.paymentPathCount;
+}
+
+/// Represents the payment LNURL info
+class LnUrlInfo {
+  final String? lnAddress;
+  final String? lnurlPayComment;
+  final String? lnurlPayDomain;
+  final String? lnurlPayMetadata;
+  final SuccessActionProcessed? lnurlPaySuccessAction;
+  final SuccessAction? lnurlPayUnprocessedSuccessAction;
+  final String? lnurlWithdrawEndpoint;
+
+  /// The [LUD-21](https://github.com/lnurl/luds/blob/luds/21.md) verify URL of the paid invoice
+  final String? lnurlPayVerifyUrl;
+
+  /// The preimage of the paid invoice, as confirmed by the LUD-21 verify URL. It proves the
+  /// LNURL service considers the invoice settled.
+  final String? lnurlPayVerifiedPreimage;
+
+  const LnUrlInfo({
+    this.lnAddress,
+    this.lnurlPayComment,
+    this.lnurlPayDomain,
+    this.lnurlPayMetadata,
+    this.lnurlPaySuccessAction,
+    this.lnurlPayUnprocessedSuccessAction,
+    this.lnurlWithdrawEndpoint,
+    this.lnurlPayVerifyUrl,
+    this.lnurlPayVerifiedPreimage,
+  });
+
+  @override
+  int get hashCode =>
+      lnAddress.hashCode ^
+      lnurlPayComment.hashCode ^
+      lnurlPayDomain.hashCode ^
+      lnurlPayMetadata.hashCode ^
+      lnurlPaySuccessAction.hashCode ^
+      lnurlPayUnprocessedSuccessAction.hashCode ^
+      lnurlWithdrawEndpoint.hashCode ^
+      lnurlPayVerifyUrl.hashCode ^
+      lnurlPayVerifiedPreimage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LnUrlInfo &&
+          runtimeType == other.runtimeType &&
+          lnAddress == other.lnAddress &&
+          lnurlPayComment == other.lnurlPayComment &&
+          lnurlPayDomain == other.lnurlPayDomain &&
+          lnurlPayMetadata == other.lnurlPayMetadata &&
+          lnurlPaySuccessAction == other.lnurlPaySuccessAction &&
+          lnurlPayUnprocessedSuccessAction == other.lnurlPayUnprocessedSuccessAction &&
+          lnurlWithdrawEndpoint == other.lnurlWithdrawEndpoint &&
+          lnurlPayVerifyUrl == other.lnurlPayVerifyUrl &&
+          lnurlPayVerifiedPreimage == other.lnurlPayVerifiedPreimage;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::lnurl_pay].
+class LnUrlPayRequest {
+  /// The response from calling [crate::sdk::LiquidSdk::prepare_lnurl_pay]
+  final PrepareLnUrlPayResponse prepareResponse;
+
+  const LnUrlPayRequest({required this.prepareResponse});
+
+  @override
+  int get hashCode => prepareResponse.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LnUrlPayRequest &&
+          runtimeType == other.runtimeType &&
+          prepareResponse == other.prepareResponse;
+}
+
+@freezed
+sealed class LnUrlPayResult with _$LnUrlPayResult {
+  const LnUrlPayResult._();
+
+  const factory LnUrlPayResult.endpointSuccess({required LnUrlPaySuccessData data}) =
+      LnUrlPayResult_EndpointSuccess;
+  const factory LnUrlPayResult.endpointError({required LnUrlErrorData data}) = LnUrlPayResult_EndpointError;
+  const factory LnUrlPayResult.payError({required LnUrlPayErrorData data}) = LnUrlPayResult_PayError;
+}
+
+class LnUrlPaySuccessData {
+  final Payment payment;
+  final SuccessActionProcessed? successAction;
+
+  const LnUrlPaySuccessData({required this.payment, this.successAction});
+
+  @override
+  int get hashCode => payment.hashCode ^ successAction.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LnUrlPaySuccessData &&
+          runtimeType == other.runtimeType &&
+          payment == other.payment &&
+          successAction == other.successAction;
+}
+
+/// The LUD-18 payer identity passed in [PrepareLnUrlPayRequest].
+///
+/// Only the fields requested by the LNURL service should be set.
+class LnUrlPayerData {
+  /// The payer's name
+  final String? name;
+
+  /// The payer's hex encoded public key
+  final String? pubkey;
+
+  /// An internet identifier of the payer, e.g. a lightning address
+  final String? identifier;
+
+  /// The payer's email
+  final String? email;
+
+  const LnUrlPayerData({this.name, this.pubkey, this.identifier, this.email});
+
+  @override
+  int get hashCode => name.hashCode ^ pubkey.hashCode ^ identifier.hashCode ^ email.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LnUrlPayerData &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          pubkey == other.pubkey &&
+          identifier == other.identifier &&
+          email == other.email;
+}
+
+/// Internal SDK log entry used in the Uniffi and Dart bindings
+class LogEntry {
+  final String line;
+  final String level;
+
+  /// The target of the log statement, usually the module it was emitted from
+  final String target;
+
+  /// The time the log statement was emitted, as a UNIX timestamp in milliseconds
+  final BigInt timestamp;
+
+  const LogEntry({required this.line, required this.level, required this.target, required this.timestamp});
+
+  @override
+  int get hashCode => line.hashCode ^ level.hashCode ^ target.hashCode ^ timestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogEntry &&
+          runtimeType == other.runtimeType &&
+          line == other.line &&
+          level == other.level &&
+          target == other.target &&
+          timestamp == other.timestamp;
+}
+
+/// The amount a [Nostr Wallet Connect](https://nips.nostr.com/47) connection can spend
+class NwcBudget {
+  /// The maximum amount spent, including fees
+  final BigInt maxAmountSat;
+
+  /// The period after which the spent amount is reset. If not set, the budget never renews.
+  final int? renewalPeriodSec;
+
+  const NwcBudget({required this.maxAmountSat, this.renewalPeriodSec});
+
+  @override
+  int get hashCode => maxAmountSat.hashCode ^ renewalPeriodSec.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NwcBudget &&
+          runtimeType == other.runtimeType &&
+          maxAmountSat == other.maxAmountSat &&
+          renewalPeriodSec == other.renewalPeriodSec;
+}
+
+/// A [Nostr Wallet Connect](https://nips.nostr.com/47) connection
+class NwcConnection {
+  final String name;
+
+  /// The public key with which the app using the connection signs its requests
+  final String clientPubkey;
+  final NwcBudget? budget;
+
+  /// The amount spent in the current budget period
+  final BigInt spentSat;
+  final int createdAt;
+
+  const NwcConnection({
+    required this.name,
+    required this.clientPubkey,
+    this.budget,
+    required this.spentSat,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^ clientPubkey.hashCode ^ budget.hashCode ^ spentSat.hashCode ^ createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NwcConnection &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          clientPubkey == other.clientPubkey &&
+          budget == other.budget &&
+          spentSat == other.spentSat &&
+          createdAt == other.createdAt;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::fetch_onchain_limits].
+class OnchainPaymentLimitsResponse {
+  /// Amount limits for a Send Onchain Payment to be valid
+  final Limits send;
+
+  /// Amount limits for a Receive Onchain Payment to be valid
+  final Limits receive;
+
+  const OnchainPaymentLimitsResponse({required this.send, required this.receive});
+
+  @override
+  int get hashCode => send.hashCode ^ receive.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OnchainPaymentLimitsResponse &&
+          runtimeType == other.runtimeType &&
+          send == other.send &&
+          receive == other.receive;
+}
+
+@freezed
+sealed class PayAmount with _$PayAmount {
+  const PayAmount._();
+
+  /// The amount in satoshi that will be received
+  const factory PayAmount.bitcoin({required BigInt receiverAmountSat}) = PayAmount_Bitcoin;
+
+  /// The amount of an asset that will be received
+  const factory PayAmount.asset({
+    required String assetId,
+    required double receiverAmount,
+    bool? estimateAssetFees,
+  }) = PayAmount_Asset;
+
+  /// The amount of an asset that will be received, in its base units. Its precision must
+  /// match the precision of the asset.
+  const factory PayAmount.assetUnits({required AssetAmount receiverAmount, bool? estimateAssetFees}) =
+      PayAmount_AssetUnits;
+
+  /// Indicates that all available Bitcoin funds should be sent
+  const factory PayAmount.drain() = PayAmount_Drain;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::pay_onchain].
+class PayOnchainRequest {
+  final String address;
+  final PreparePayOnchainResponse prepareResponse;
+
+  /// A token allowing the payment to exceed the [Config::spend_limits], created with
+  /// [crate::sdk::LiquidSdk::create_spend_limit_override]
+  final String? spendLimitOverride;
+
+  const PayOnchainRequest({required this.address, required this.prepareResponse, this.spendLimitOverride});
+
+  @override
+  int get hashCode => address.hashCode ^ prepareResponse.hashCode ^ spendLimitOverride.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PayOnchainRequest &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          prepareResponse == other.prepareResponse &&
+          spendLimitOverride == other.spendLimitOverride;
+}
+
+/// Represents an SDK payment.
+///
+/// By default, this is an onchain tx. It may represent a swap, if swap metadata is available.
+class Payment {
+  /// The destination associated with the payment, if it was created via our SDK.
+  /// Can be either a Liquid/Bitcoin address, a Liquid BIP21 URI or an invoice
+  final String? destination;
+  final String? txId;
+
+  /// Data to use in the `blinded` param when unblinding the transaction in an explorer.
+  /// See: <https://docs.liquid.net/docs/unblinding-transactions>
+  final String? unblindingData;
+
+  /// Composite timestamp that can be used for sorting or displaying the payment.
+  ///
+  /// If this payment has an associated swap, it is the swap creation time. Otherwise, the point
+  /// in time when the underlying tx was included in a block. If there is no associated swap
+  /// available and the underlying tx is not yet confirmed, the value is `now()`.
+  final int timestamp;
+
+  /// The payment amount, which corresponds to the onchain tx amount.
+  ///
+  /// In case of an outbound payment (Send), this is the payer amount. Otherwise it's the receiver amount.
+  final BigInt amountSat;
+
+  /// Represents the fees paid by this wallet for this payment.
+  ///
+  /// ### Swaps
+  /// If there is an associated Send Swap, these fees represent the total fees paid by this wallet
+  /// (the sender). It is the difference between the amount that was sent and the amount received.
+  ///
+  /// If there is an associated Receive Swap, these fees represent the total fees paid by this wallet
+  /// (the receiver). It is also the difference between the amount that was sent and the amount received.
+  ///
+  /// ### Pure onchain txs
+  /// If no swap is associated with this payment:
+  /// - for Send payments, this is the onchain tx fee
+  /// - for Receive payments, this is zero
+  final BigInt feesSat;
+
+  /// Service fees paid to the swapper service. This is only set for swaps (i.e. doesn't apply to
+  /// direct Liquid payments).
+  final BigInt? swapperFeesSat;
+
+  /// If it is a `Send` or `Receive` payment
+  final PaymentType paymentType;
+
+  /// Composite status representing the overall status of the payment.
+  ///
+  /// If the tx has no associated swap, this reflects the onchain tx status (confirmed or not).
+  ///
+  /// If the tx has an associated swap, this is determined by the swap status (pending or complete).
+  final PaymentState status;
+
+  /// The details of a payment, depending on its [destination](Payment::destination) and
+  /// [type](Payment::payment_type)
+  final PaymentDetails details;
+
+  /// The value of the payment in [fiat_currency](Payment::fiat_currency), using the exchange
+  /// rate at the time the payment completed.
+  ///
+  /// Only set for completed Bitcoin payments when [Config::fiat_currency] is set.
+  final double? fiatAmount;
+
+  /// The fiat currency of the [fiat_amount](Payment::fiat_amount)
+  final String? fiatCurrency;
+
+  /// The id of the [RecurringPayment] this payment is an occurrence of, if any
+  final String? recurringPaymentId;
+
+  const Payment({
+    this.destination,
+    this.txId,
+    this.unblindingData,
+    required this.timestamp,
+    required this.amountSat,
+    required this.feesSat,
+    this.swapperFeesSat,
+    required this.paymentType,
+    required this.status,
+    required this.details,
+    this.fiatAmount,
+    this.fiatCurrency,
+    this.recurringPaymentId,
+  });
+
+  @override
+  int get hashCode =>
+      destination.hashCode ^
+      txId.hashCode ^
+      unblindingData.hashCode ^
+      timestamp.hashCode ^
+      amountSat.hashCode ^
+      feesSat.hashCode ^
+      swapperFeesSat.hashCode ^
+      paymentType.hashCode ^
+      status.hashCode ^
+      details.hashCode ^
+      fiatAmount.hashCode ^
+      fiatCurrency.hashCode ^
+      recurringPaymentId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Payment &&
+          runtimeType == other.runtimeType &&
+          destination == other.destination &&
+          txId == other.txId &&
+          unblindingData == other.unblindingData &&
+          timestamp == other.timestamp &&
+          amountSat == other.amountSat &&
+          feesSat == other.feesSat &&
+          swapperFeesSat == other.swapperFeesSat &&
+          paymentType == other.paymentType &&
+          status == other.status &&
+          details == other.details &&
+          fiatAmount == other.fiatAmount &&
+          fiatCurrency == other.fiatCurrency &&
+          recurringPaymentId == other.recurringPaymentId;
+}
+
+@freezed
+sealed class PaymentDetails with _$PaymentDetails {
+  const PaymentDetails._();
+
+  /// Swapping to or from Lightning
+  const factory PaymentDetails.lightning({
+    required String swapId,
+
+    /// Represents the invoice description
+    required String description,
+
+    /// The height of the block at which the swap will no longer be valid
+    required int liquidExpirationBlockheight,
+
+    /// The preimage of the paid invoice (proof of payment).
+    String? preimage,
+
+    /// Represents the Bolt11/Bolt12 invoice associated with a payment
+    /// In the case of a Send payment, this is the invoice paid by the swapper
+    /// In the case of a Receive payment, this is the invoice paid by the user
+    String? invoice,
+    String? bolt12Offer,
+
+    /// The payment hash of the invoice
+    String? paymentHash,
+
+    /// The invoice destination/payee pubkey
+    String? destinationPubkey,
+
+    /// The payment LNURL info
+    LnUrlInfo? lnurlInfo,
+
+    /// The BIP353 address used to resolve this payment
+    String? bip353Address,
+
+    /// For a Receive payment, this is the claim tx id in case it has already been broadcast
+    String? claimTxId,
+
+    /// For a Send swap which was refunded, this is the refund tx id
+    String? refundTxId,
+
+    /// For a Send swap which was refunded, this is the refund amount
+    BigInt? refundTxAmountSat,
+  }) = PaymentDetails_Lightning;
+
+  /// Direct onchain payment to a Liquid address
+  const factory PaymentDetails.liquid({
+    /// Represents either a Liquid BIP21 URI or pure address
+    required String destination,
+
+    /// Represents the BIP21 `message` field
+    required String description,
+
+    /// The asset id
+    required String assetId,
+
+    /// The asset info derived from the [AssetMetadata]
+    AssetInfo? assetInfo,
+
+    /// The payment LNURL info
+    LnUrlInfo? lnurlInfo,
+
+    /// The BIP353 address used to resolve this payment
+    String? bip353Address,
+
+    /// The pair of asset amounts exchanged, if the payment is an asset conversion
+    AssetConversion? conversion,
+  }) = PaymentDetails_Liquid;
+
+  /// Swapping to or from the Bitcoin chain
+  const factory PaymentDetails.bitcoin({
+    required String swapId,
+
+    /// Represents the invoice description
+    required String description,
+
+    /// For an amountless receive swap, this indicates if fees were automatically accepted.
+    /// Fees are auto accepted when the swapper proposes fees that are within the initial
+    /// estimate, plus the `onchain_fee_rate_leeway_sat_per_vbyte` set in the [Config], if any.
+    required bool autoAcceptedFees,
+
+    /// The height of the Liquid block at which the swap will no longer be valid
+    /// It should always be populated in case of an outgoing chain swap
+    int? liquidExpirationBlockheight,
+
+    /// The height of the Bitcoin block at which the swap will no longer be valid
+    /// It should always be populated in case of an incoming chain swap
+    int? bitcoinExpirationBlockheight,
+
+    /// The claim tx id in case it has already been broadcast
+    String? claimTxId,
+
+    /// For a Send swap which was refunded, this is the refund tx id
+    String? refundTxId,
+
+    /// For a Send swap which was refunded, this is the refund amount
+    BigInt? refundTxAmountSat,
+
+    /// For a Bitcoin bought via [crate::sdk::LiquidSdk::buy_bitcoin], this is the provider
+    /// the Bitcoin was bought from
+    BuyBitcoinProvider? buyBitcoinProvider,
+  }) = PaymentDetails_Bitcoin;
+}
+
+/// The send/receive methods supported by the SDK
+enum PaymentMethod { lightning, bitcoinAddress, liquidAddress }
+
+/// The payment state of an individual payment.
+enum PaymentState {
+  created,
 
   /// ## Receive Swaps
   ///
@@ -976,735 +2373,2199 @@ enum PaymentState {
   /// - the lockup tx is seen in the mempool or
   /// - our claim tx is broadcast
   ///
-  /// When the claim tx is broadcast, `claim_tx_id` is set in the swap.
+  /// When the claim tx is broadcast, `claim_tx_id` is set in the swap.
+  ///
+  /// ## Send Swaps
+  ///
+  /// This is the status when our lockup tx was broadcast
+  ///
+  /// ## Chain Swaps
+  ///
+  /// This is the status when the user lockup tx was broadcast
+  ///
+  /// ## No swap data available
+  ///
+  /// If no associated swap is found, this indicates the underlying tx is not confirmed yet.
+  pending,
+
+  /// ## Receive Swaps
+  ///
+  /// Covers the case when the claim tx is confirmed.
+  ///
+  /// ## Send and Chain Swaps
+  ///
+  /// This is the status when the claim tx is broadcast and we see it in the mempool.
+  ///
+  /// ## No swap data available
+  ///
+  /// If no associated swap is found, this indicates the underlying tx is confirmed.
+  complete,
+
+  /// ## Receive Swaps
+  ///
+  /// This is the status when the swap failed for any reason and the Receive could not complete.
+  ///
+  /// ## Send and Chain Swaps
+  ///
+  /// This is the status when a swap refund was initiated and the refund tx is confirmed.
+  failed,
+
+  /// ## Send and Outgoing Chain Swaps
+  ///
+  /// This covers the case when the swap state is still Created and the swap fails to reach the
+  /// Pending state in time. The TimedOut state indicates the lockup tx should never be broadcast.
+  timedOut,
+
+  /// ## Incoming Chain Swaps
+  ///
+  /// This covers the case when the swap failed for any reason and there is a user lockup tx.
+  /// The swap in this case has to be manually refunded with a provided Bitcoin address
+  refundable,
+
+  /// ## Send and Chain Swaps
+  ///
+  /// This is the status when a refund was initiated and/or our refund tx was broadcast
+  ///
+  /// When the refund tx is broadcast, `refund_tx_id` is set in the swap.
+  refundPending,
+
+  /// ## Chain Swaps
+  ///
+  /// This is the state when the user needs to accept new fees before the payment can proceed.
+  ///
+  /// Use [LiquidSdk::fetch_payment_proposed_fees](crate::sdk::LiquidSdk::fetch_payment_proposed_fees)
+  /// to find out the current fees and
+  /// [LiquidSdk::accept_payment_proposed_fees](crate::sdk::LiquidSdk::accept_payment_proposed_fees)
+  /// to accept them, allowing the payment to proceed.
+  ///
+  /// Otherwise, this payment can be immediately refunded using
+  /// [prepare_refund](crate::sdk::LiquidSdk::prepare_refund)/[refund](crate::sdk::LiquidSdk::refund).
+  waitingFeeAcceptance,
+}
+
+enum PaymentSwapType { receive, send, chain }
+
+enum PaymentType { receive, send }
+
+/// Returned when calling [crate::sdk::LiquidSdk::export_payment_unblinding_data].
+///
+/// It can be handed to an auditor, serialized as JSON, to prove the confidential amounts of a
+/// payment without revealing the wallet's blinding key.
+class PaymentUnblindingData {
+  final String txId;
+
+  /// Data to use in the `blinded` param when unblinding the transaction in an explorer.
+  /// See: <https://docs.liquid.net/docs/unblinding-transactions>
+  final String unblindingData;
+
+  /// The explorer URL showing the transaction unblinded, if an explorer is available
+  final String? unblindedUrl;
+
+  /// The wallet outputs spent by the transaction
+  final List<UnblindedTxOut> inputs;
+
+  /// The wallet outputs created by the transaction
+  final List<UnblindedTxOut> outputs;
+
+  const PaymentUnblindingData({
+    required this.txId,
+    required this.unblindingData,
+    this.unblindedUrl,
+    required this.inputs,
+    required this.outputs,
+  });
+
+  @override
+  int get hashCode =>
+      txId.hashCode ^ unblindingData.hashCode ^ unblindedUrl.hashCode ^ inputs.hashCode ^ outputs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentUnblindingData &&
+          runtimeType == other.runtimeType &&
+          txId == other.txId &&
+          unblindingData == other.unblindingData &&
+          unblindedUrl == other.unblindedUrl &&
+          inputs == other.inputs &&
+          outputs == other.outputs;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
+class PrepareBuyBitcoinRequest {
+  final BuyBitcoinProvider provider;
+  final BigInt amountSat;
+
+  const PrepareBuyBitcoinRequest({required this.provider, required this.amountSat});
+
+  @override
+  int get hashCode => provider.hashCode ^ amountSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareBuyBitcoinRequest &&
+          runtimeType == other.runtimeType &&
+          provider == other.provider &&
+          amountSat == other.amountSat;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
+class PrepareBuyBitcoinResponse {
+  final BuyBitcoinProvider provider;
+  final BigInt amountSat;
+  final BigInt feesSat;
+
+  const PrepareBuyBitcoinResponse({required this.provider, required this.amountSat, required this.feesSat});
+
+  @override
+  int get hashCode => provider.hashCode ^ amountSat.hashCode ^ feesSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareBuyBitcoinResponse &&
+          runtimeType == other.runtimeType &&
+          provider == other.provider &&
+          amountSat == other.amountSat &&
+          feesSat == other.feesSat;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_convert].
+///
+/// One of the assets has to be L-BTC and the other a Liquid asset set in the
+/// [Config::asset_metadata].
+class PrepareConvertRequest {
+  /// The asset id to convert from
+  final String fromAssetId;
+
+  /// The asset id to convert to
+  final String toAssetId;
+
+  /// The amount to convert, in base units of `from_asset_id`
+  final BigInt amount;
+
+  const PrepareConvertRequest({required this.fromAssetId, required this.toAssetId, required this.amount});
+
+  @override
+  int get hashCode => fromAssetId.hashCode ^ toAssetId.hashCode ^ amount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareConvertRequest &&
+          runtimeType == other.runtimeType &&
+          fromAssetId == other.fromAssetId &&
+          toAssetId == other.toAssetId &&
+          amount == other.amount;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_convert].
+class PrepareConvertResponse {
+  final String fromAssetId;
+  final String toAssetId;
+
+  /// The amount to convert, in base units of `from_asset_id`
+  final BigInt amount;
+
+  /// The quoted amount received, in base units of `to_asset_id`. When converting to
+  /// L-BTC, the network fee is already deducted from it.
+  final BigInt receiverAmount;
+
+  /// The L-BTC paid on top of `amount` when converting from L-BTC, in satoshi
+  final BigInt feesSat;
+
+  const PrepareConvertResponse({
+    required this.fromAssetId,
+    required this.toAssetId,
+    required this.amount,
+    required this.receiverAmount,
+    required this.feesSat,
+  });
+
+  @override
+  int get hashCode =>
+      fromAssetId.hashCode ^
+      toAssetId.hashCode ^
+      amount.hashCode ^
+      receiverAmount.hashCode ^
+      feesSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareConvertResponse &&
+          runtimeType == other.runtimeType &&
+          fromAssetId == other.fromAssetId &&
+          toAssetId == other.toAssetId &&
+          amount == other.amount &&
+          receiverAmount == other.receiverAmount &&
+          feesSat == other.feesSat;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_lnurl_pay].
+class PrepareLnUrlPayRequest {
+  /// The [LnUrlPayRequestData] returned by [parse]
+  final LnUrlPayRequestData data;
+
+  /// The amount to send
+  final PayAmount amount;
+
+  /// A BIP353 address, in case one was used in order to fetch the LNURL Pay request data.
+  /// Returned by [parse].
+  final String? bip353Address;
+
+  /// An optional comment for this payment
+  final String? comment;
+
+  /// Optional payer identity sent to the LNURL service, as described in
+  /// <https://github.com/lnurl/luds/blob/luds/18.md>
+  final LnUrlPayerData? payerData;
+
+  /// Validates that, if there is a URL success action, the URL domain matches
+  /// the LNURL callback domain. Defaults to `true`
+  final bool? validateSuccessActionUrl;
+
+  const PrepareLnUrlPayRequest({
+    required this.data,
+    required this.amount,
+    this.bip353Address,
+    this.comment,
+    this.payerData,
+    this.validateSuccessActionUrl,
+  });
+
+  @override
+  int get hashCode =>
+      data.hashCode ^
+      amount.hashCode ^
+      bip353Address.hashCode ^
+      comment.hashCode ^
+      payerData.hashCode ^
+      validateSuccessActionUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareLnUrlPayRequest &&
+          runtimeType == other.runtimeType &&
+          data == other.data &&
+          amount == other.amount &&
+          bip353Address == other.bip353Address &&
+          comment == other.comment &&
+          payerData == other.payerData &&
+          validateSuccessActionUrl == other.validateSuccessActionUrl;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_lnurl_pay].
+class PrepareLnUrlPayResponse {
+  /// The destination of the payment
+  final SendDestination destination;
+
+  /// The fees in satoshis to send the payment
+  final BigInt feesSat;
+
+  /// The breakdown of `fees_sat` into its components
+  final FeeBreakdown feeBreakdown;
+
+  /// The [LnUrlPayRequestData] returned by [parse]
+  final LnUrlPayRequestData data;
+
+  /// An optional comment for this payment
+  final String? comment;
+
+  /// The unprocessed LUD-09 success action. This will be processed and decrypted if
+  /// needed after calling [crate::sdk::LiquidSdk::lnurl_pay]
+  final SuccessAction? successAction;
+
+  /// The LUD-21 verify URL of the invoice, if supported by the LNURL service. It is checked
+  /// after calling [crate::sdk::LiquidSdk::lnurl_pay] to confirm the invoice is settled.
+  final String? verifyUrl;
+
+  const PrepareLnUrlPayResponse({
+    required this.destination,
+    required this.feesSat,
+    required this.feeBreakdown,
+    required this.data,
+    this.comment,
+    this.successAction,
+    this.verifyUrl,
+  });
+
+  @override
+  int get hashCode =>
+      destination.hashCode ^
+      feesSat.hashCode ^
+      feeBreakdown.hashCode ^
+      data.hashCode ^
+      comment.hashCode ^
+      successAction.hashCode ^
+      verifyUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareLnUrlPayResponse &&
+          runtimeType == other.runtimeType &&
+          destination == other.destination &&
+          feesSat == other.feesSat &&
+          feeBreakdown == other.feeBreakdown &&
+          data == other.data &&
+          comment == other.comment &&
+          successAction == other.successAction &&
+          verifyUrl == other.verifyUrl;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
+class PreparePayOnchainRequest {
+  /// The amount to send
+  final PayAmount amount;
+
+  /// The optional fee rate of the Bitcoin claim transaction in sat/vB. Defaults to the swapper estimated claim fee.
+  final int? feeRateSatPerVbyte;
+
+  /// The optional [FeeSpeed] preset of the Bitcoin claim transaction. Cannot be set together
+  /// with `fee_rate_sat_per_vbyte`.
+  final FeeSpeed? feeSpeed;
+
+  const PreparePayOnchainRequest({required this.amount, this.feeRateSatPerVbyte, this.feeSpeed});
+
+  @override
+  int get hashCode => amount.hashCode ^ feeRateSatPerVbyte.hashCode ^ feeSpeed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PreparePayOnchainRequest &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte &&
+          feeSpeed == other.feeSpeed;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
+class PreparePayOnchainResponse {
+  final BigInt receiverAmountSat;
+  final BigInt claimFeesSat;
+  final BigInt totalFeesSat;
+
+  const PreparePayOnchainResponse({
+    required this.receiverAmountSat,
+    required this.claimFeesSat,
+    required this.totalFeesSat,
+  });
+
+  @override
+  int get hashCode => receiverAmountSat.hashCode ^ claimFeesSat.hashCode ^ totalFeesSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PreparePayOnchainResponse &&
+          runtimeType == other.runtimeType &&
+          receiverAmountSat == other.receiverAmountSat &&
+          claimFeesSat == other.claimFeesSat &&
+          totalFeesSat == other.totalFeesSat;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_payment_quotes].
+class PrepareReceiveQuotesRequest {
+  /// The amount to be paid in either Bitcoin or another asset
+  final ReceiveAmount? amount;
+
+  /// The amount to be paid denominated in a fiat currency. Cannot be set together with `amount`.
+  final FiatAmount? fiatAmount;
+
+  const PrepareReceiveQuotesRequest({this.amount, this.fiatAmount});
+
+  @override
+  int get hashCode => amount.hashCode ^ fiatAmount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareReceiveQuotesRequest &&
+          runtimeType == other.runtimeType &&
+          amount == other.amount &&
+          fiatAmount == other.fiatAmount;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_receive_payment_quotes].
+class PrepareReceiveQuotesResponse {
+  /// The prepared receive of each payment method able to receive the amount
+  final List<PrepareReceiveResponse> quotes;
+
+  const PrepareReceiveQuotesResponse({required this.quotes});
+
+  @override
+  int get hashCode => quotes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareReceiveQuotesResponse && runtimeType == other.runtimeType && quotes == other.quotes;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
+class PrepareReceiveRequest {
+  final PaymentMethod paymentMethod;
+
+  /// The amount to be paid in either Bitcoin or another asset
+  final ReceiveAmount? amount;
+
+  /// The amount to be paid denominated in a fiat currency. It is converted to a Bitcoin amount
+  /// at preparation time. Cannot be set together with `amount`.
+  final FiatAmount? fiatAmount;
+
+  /// The expiry of the generated invoice in seconds, between [crate::receive_swap::MIN_INVOICE_EXPIRY_SECS]
+  /// and [crate::receive_swap::MAX_INVOICE_EXPIRY_SECS]. Only supported when the method is [PaymentMethod::Lightning].
+  /// Defaults to the swapper's invoice expiry.
+  final int? expirySecs;
+
+  const PrepareReceiveRequest({required this.paymentMethod, this.amount, this.fiatAmount, this.expirySecs});
+
+  @override
+  int get hashCode => paymentMethod.hashCode ^ amount.hashCode ^ fiatAmount.hashCode ^ expirySecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareReceiveRequest &&
+          runtimeType == other.runtimeType &&
+          paymentMethod == other.paymentMethod &&
+          amount == other.amount &&
+          fiatAmount == other.fiatAmount &&
+          expirySecs == other.expirySecs;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
+class PrepareReceiveResponse {
+  final PaymentMethod paymentMethod;
+  final ReceiveAmount? amount;
+
+  /// Generally represents the total fees that would be paid to send or receive this payment.
+  ///
+  /// In case of Zero-Amount Receive Chain swaps, the swapper service fee (`swapper_feerate` times
+  /// the amount) is paid in addition to `fees_sat`. The swapper service feerate is already known
+  /// in the beginning, but the exact swapper service fee will only be known when the
+  /// `payer_amount_sat` is known.
   ///
-  /// ## Send Swaps
+  /// In all other types of swaps, the swapper service fee is included in `fees_sat`.
+  final BigInt feesSat;
+
+  /// The breakdown of `fees_sat` into its components
+  final FeeBreakdown feeBreakdown;
+
+  /// The minimum amount the payer can send for this swap to succeed.
   ///
-  /// This is the status when our lockup tx was broadcast
+  /// When the method is [PaymentMethod::LiquidAddress], this is empty.
+  final BigInt? minPayerAmountSat;
+
+  /// The maximum amount the payer can send for this swap to succeed.
   ///
-  /// ## Chain Swaps
+  /// When the method is [PaymentMethod::LiquidAddress], this is empty.
+  final BigInt? maxPayerAmountSat;
+
+  /// The percentage of the sent amount that will count towards the service fee.
   ///
-  /// This is the status when the user lockup tx was broadcast
+  /// When the method is [PaymentMethod::LiquidAddress], this is empty.
+  final double? swapperFeerate;
+
+  /// The fiat conversion used, if the amount was requested in a fiat currency
+  final FiatConversion? fiatConversion;
+
+  /// The expiry of the generated invoice in seconds, if set in the request
+  final int? expirySecs;
+
+  const PrepareReceiveResponse({
+    required this.paymentMethod,
+    this.amount,
+    required this.feesSat,
+    required this.feeBreakdown,
+    this.minPayerAmountSat,
+    this.maxPayerAmountSat,
+    this.swapperFeerate,
+    this.fiatConversion,
+    this.expirySecs,
+  });
+
+  @override
+  int get hashCode =>
+      paymentMethod.hashCode ^
+      amount.hashCode ^
+      feesSat.hashCode ^
+      feeBreakdown.hashCode ^
+      minPayerAmountSat.hashCode ^
+      maxPayerAmountSat.hashCode ^
+      swapperFeerate.hashCode ^
+      fiatConversion.hashCode ^
+      expirySecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareReceiveResponse &&
+          runtimeType == other.runtimeType &&
+          paymentMethod == other.paymentMethod &&
+          amount == other.amount &&
+          feesSat == other.feesSat &&
+          feeBreakdown == other.feeBreakdown &&
+          minPayerAmountSat == other.minPayerAmountSat &&
+          maxPayerAmountSat == other.maxPayerAmountSat &&
+          swapperFeerate == other.swapperFeerate &&
+          fiatConversion == other.fiatConversion &&
+          expirySecs == other.expirySecs;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_refund].
+class PrepareRefundRequest {
+  /// The address where the swap funds are locked up
+  final String swapAddress;
+
+  /// The address to refund the swap funds to
+  final String refundAddress;
+
+  /// The fee rate in sat/vB for the refund transaction. Either this or `fee_speed` must be set.
+  final int? feeRateSatPerVbyte;
+
+  /// The [FeeSpeed] preset for the refund transaction. Either this or `fee_rate_sat_per_vbyte` must be set.
+  final FeeSpeed? feeSpeed;
+
+  const PrepareRefundRequest({
+    required this.swapAddress,
+    required this.refundAddress,
+    this.feeRateSatPerVbyte,
+    this.feeSpeed,
+  });
+
+  @override
+  int get hashCode =>
+      swapAddress.hashCode ^ refundAddress.hashCode ^ feeRateSatPerVbyte.hashCode ^ feeSpeed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareRefundRequest &&
+          runtimeType == other.runtimeType &&
+          swapAddress == other.swapAddress &&
+          refundAddress == other.refundAddress &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte &&
+          feeSpeed == other.feeSpeed;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_refund].
+class PrepareRefundResponse {
+  final int txVsize;
+  final BigInt txFeeSat;
+
+  /// The fee rate in sat/vB the refund transaction is built with, resolved from the request
+  final int feeRateSatPerVbyte;
+
+  /// The amount locked up in the swap, from all UTXOs
+  final BigInt refundableAmountSat;
+
+  /// The amount received at the refund address, i.e. the refundable amount minus the fee
+  final BigInt refundAmountSat;
+
+  /// The txid of the last broadcasted refund tx, if any
+  final String? lastRefundTxId;
+
+  const PrepareRefundResponse({
+    required this.txVsize,
+    required this.txFeeSat,
+    required this.feeRateSatPerVbyte,
+    required this.refundableAmountSat,
+    required this.refundAmountSat,
+    this.lastRefundTxId,
+  });
+
+  @override
+  int get hashCode =>
+      txVsize.hashCode ^
+      txFeeSat.hashCode ^
+      feeRateSatPerVbyte.hashCode ^
+      refundableAmountSat.hashCode ^
+      refundAmountSat.hashCode ^
+      lastRefundTxId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareRefundResponse &&
+          runtimeType == other.runtimeType &&
+          txVsize == other.txVsize &&
+          txFeeSat == other.txFeeSat &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte &&
+          refundableAmountSat == other.refundableAmountSat &&
+          refundAmountSat == other.refundAmountSat &&
+          lastRefundTxId == other.lastRefundTxId;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::prepare_send_payment].
+class PrepareSendRequest {
+  /// The destination we intend to pay to.
+  /// Supports BIP21 URIs, BOLT11 invoices, BOLT12 offers and Liquid addresses
+  final String destination;
+
+  /// Should only be set when paying directly onchain or to a BIP21 URI
+  /// where no amount is specified, or when the caller wishes to drain
+  final PayAmount? amount;
+
+  /// The amount to send denominated in a fiat currency. It is converted to a Bitcoin amount
+  /// at preparation time. Cannot be set together with `amount`.
+  final FiatAmount? fiatAmount;
+
+  const PrepareSendRequest({required this.destination, this.amount, this.fiatAmount});
+
+  @override
+  int get hashCode => destination.hashCode ^ amount.hashCode ^ fiatAmount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareSendRequest &&
+          runtimeType == other.runtimeType &&
+          destination == other.destination &&
+          amount == other.amount &&
+          fiatAmount == other.fiatAmount;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::prepare_send_payment].
+class PrepareSendResponse {
+  final SendDestination destination;
+
+  /// The optional estimated fee in satoshi. Is set when there is Bitcoin available
+  /// to pay fees. When not set, there are asset fees available to pay fees.
+  final BigInt? feesSat;
+
+  /// The breakdown of `fees_sat` into its components. Is set when `fees_sat` or
+  /// `estimated_asset_fees` is set.
+  final FeeBreakdown? feeBreakdown;
+
+  /// The optional estimated fee in the asset. Is set when [PayAmount::Asset::estimate_asset_fees]
+  /// is set to `true`, the Payjoin service accepts this asset to pay fees and there
+  /// are funds available in this asset to pay fees.
+  final double? estimatedAssetFees;
+
+  /// The `estimated_asset_fees` carrying the asset precision
+  final AssetAmount? estimatedAssetFeesAmount;
+
+  /// The fiat conversion used, if the amount was requested in a fiat currency
+  final FiatConversion? fiatConversion;
+
+  const PrepareSendResponse({
+    required this.destination,
+    this.feesSat,
+    this.feeBreakdown,
+    this.estimatedAssetFees,
+    this.estimatedAssetFeesAmount,
+    this.fiatConversion,
+  });
+
+  @override
+  int get hashCode =>
+      destination.hashCode ^
+      feesSat.hashCode ^
+      feeBreakdown.hashCode ^
+      estimatedAssetFees.hashCode ^
+      estimatedAssetFeesAmount.hashCode ^
+      fiatConversion.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareSendResponse &&
+          runtimeType == other.runtimeType &&
+          destination == other.destination &&
+          feesSat == other.feesSat &&
+          feeBreakdown == other.feeBreakdown &&
+          estimatedAssetFees == other.estimatedAssetFees &&
+          estimatedAssetFeesAmount == other.estimatedAssetFeesAmount &&
+          fiatConversion == other.fiatConversion;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::process_notification].
+class ProcessNotificationRequest {
+  /// The request used to connect the short-lived SDK session
+  final ConnectRequest connectRequest;
+
+  /// The hex-encoded push payload, encrypted with ECIES to the wallet pubkey
+  /// ([WalletInfo::pubkey])
+  final String payload;
+
+  /// Whether the fees proposed by the swapper for an amountless chain swap are accepted, see
+  /// [crate::sdk::LiquidSdk::accept_payment_proposed_fees].
   ///
-  /// ## No swap data available
+  /// Defaults to `false`, leaving the decision to the user.
+  final bool? acceptProposedFees;
+
+  /// How long the session runs at most.
   ///
-  /// If no associated swap is found, this indicates the underlying tx is not confirmed yet.
+  /// Defaults to 25 seconds.
+  final int? timeoutSec;
+
+  const ProcessNotificationRequest({
+    required this.connectRequest,
+    required this.payload,
+    this.acceptProposedFees,
+    this.timeoutSec,
+  });
+
+  @override
+  int get hashCode =>
+      connectRequest.hashCode ^ payload.hashCode ^ acceptProposedFees.hashCode ^ timeoutSec.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessNotificationRequest &&
+          runtimeType == other.runtimeType &&
+          connectRequest == other.connectRequest &&
+          payload == other.payload &&
+          acceptProposedFees == other.acceptProposedFees &&
+          timeoutSec == other.timeoutSec;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::process_notification].
+class ProcessNotificationResponse {
+  /// The payment of the swap the notification is about, in the state reached when the
+  /// session ended. For a lightning address invoice request, it's the created receive swap.
+  final Payment payment;
+
+  /// Whether the SDK completed its part of the swap, e.g. by broadcasting the claim tx.
+  /// Otherwise, the swap is handled the next time the SDK runs.
+  final bool isResolved;
+
+  const ProcessNotificationResponse({required this.payment, required this.isResolved});
+
+  @override
+  int get hashCode => payment.hashCode ^ isResolved.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessNotificationResponse &&
+          runtimeType == other.runtimeType &&
+          payment == other.payment &&
+          isResolved == other.isResolved;
+}
+
+/// The maximum amount of an asset the wallet may spend in a PSET signed with
+/// [crate::sdk::LiquidSdk::sign_pset].
+class PsetSpendLimit {
+  final String assetId;
+  final BigInt maxAmountSat;
+
+  const PsetSpendLimit({required this.assetId, required this.maxAmountSat});
+
+  @override
+  int get hashCode => assetId.hashCode ^ maxAmountSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PsetSpendLimit &&
+          runtimeType == other.runtimeType &&
+          assetId == other.assetId &&
+          maxAmountSat == other.maxAmountSat;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::queue_payment].
+class QueuePaymentRequest {
+  /// The request used to prepare the payment on each attempt
+  final PrepareSendRequest prepareRequest;
+  final bool? useAssetFees;
+
+  /// See [SendPaymentRequest::max_fee_sat]
+  final BigInt? maxFeeSat;
+
+  const QueuePaymentRequest({required this.prepareRequest, this.useAssetFees, this.maxFeeSat});
+
+  @override
+  int get hashCode => prepareRequest.hashCode ^ useAssetFees.hashCode ^ maxFeeSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueuePaymentRequest &&
+          runtimeType == other.runtimeType &&
+          prepareRequest == other.prepareRequest &&
+          useAssetFees == other.useAssetFees &&
+          maxFeeSat == other.maxFeeSat;
+}
+
+/// A payment in the outgoing payment queue. See [crate::sdk::LiquidSdk::queue_payment].
+class QueuedPayment {
+  final String id;
+  final QueuePaymentRequest request;
+  final QueuedPaymentState state;
+
+  /// The number of send attempts made so far
+  final int attempts;
+  final int createdAt;
+
+  /// The earliest time of the next attempt, as a UNIX timestamp in seconds
+  final int nextAttemptAt;
+
+  /// The error returned by the last attempt, if any
+  final String? lastError;
+
+  const QueuedPayment({
+    required this.id,
+    required this.request,
+    required this.state,
+    required this.attempts,
+    required this.createdAt,
+    required this.nextAttemptAt,
+    this.lastError,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      request.hashCode ^
+      state.hashCode ^
+      attempts.hashCode ^
+      createdAt.hashCode ^
+      nextAttemptAt.hashCode ^
+      lastError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QueuedPayment &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          request == other.request &&
+          state == other.state &&
+          attempts == other.attempts &&
+          createdAt == other.createdAt &&
+          nextAttemptAt == other.nextAttemptAt &&
+          lastError == other.lastError;
+}
+
+/// The state of a [QueuedPayment]
+enum QueuedPaymentState {
+  /// The payment is waiting for its next attempt
   pending,
 
-  /// ## Receive Swaps
-  ///
-  /// Covers the case when the claim tx is confirmed.
-  ///
-  /// ## Send and Chain Swaps
-  ///
-  /// This is the status when the claim tx is broadcast and we see it in the mempool.
-  ///
-  /// ## No swap data available
-  ///
-  /// If no associated swap is found, this indicates the underlying tx is confirmed.
-  complete,
+  /// The payment failed with a permanent error or ran out of attempts
+  failed,
+}
+
+@freezed
+sealed class ReceiveAmount with _$ReceiveAmount {
+  const ReceiveAmount._();
+
+  /// The amount in satoshi that should be paid
+  const factory ReceiveAmount.bitcoin({required BigInt payerAmountSat}) = ReceiveAmount_Bitcoin;
+
+  /// The amount of an asset that should be paid
+  const factory ReceiveAmount.asset({required String assetId, double? payerAmount}) = ReceiveAmount_Asset;
+
+  /// The amount of an asset that should be paid, in its base units. Its precision must match
+  /// the precision of the asset.
+  const factory ReceiveAmount.assetUnits({required AssetAmount payerAmount}) = ReceiveAmount_AssetUnits;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::receive_payment].
+class ReceivePaymentRequest {
+  final PrepareReceiveResponse prepareResponse;
+
+  /// The description for this payment request.
+  final String? description;
+
+  /// If set to true, then the hash of the description will be used.
+  final bool? useDescriptionHash;
+
+  /// A precomputed hex-encoded SHA256 hash to commit to in the invoice instead of a description,
+  /// as required by LNURL-pay servers (LUD-06). Only valid for [PaymentMethod::Lightning].
+  final String? descriptionHash;
+
+  /// A Liquid address where the swap is claimed to instead of the SDK wallet, overriding
+  /// [Config::claim_address]. Only valid for [PaymentMethod::Lightning].
+  final String? claimAddress;
+
+  const ReceivePaymentRequest({
+    required this.prepareResponse,
+    this.description,
+    this.useDescriptionHash,
+    this.descriptionHash,
+    this.claimAddress,
+  });
+
+  @override
+  int get hashCode =>
+      prepareResponse.hashCode ^
+      description.hashCode ^
+      useDescriptionHash.hashCode ^
+      descriptionHash.hashCode ^
+      claimAddress.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceivePaymentRequest &&
+          runtimeType == other.runtimeType &&
+          prepareResponse == other.prepareResponse &&
+          description == other.description &&
+          useDescriptionHash == other.useDescriptionHash &&
+          descriptionHash == other.descriptionHash &&
+          claimAddress == other.claimAddress;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::receive_payment].
+class ReceivePaymentResponse {
+  /// Either a BIP21 URI (Liquid or Bitcoin), a Liquid address
+  /// or an invoice, depending on the [PrepareReceiveResponse] parameters
+  final String destination;
+
+  const ReceivePaymentResponse({required this.destination});
+
+  @override
+  int get hashCode => destination.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceivePaymentResponse && runtimeType == other.runtimeType && destination == other.destination;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::recommended_fees].
+class RecommendedFees {
+  final BigInt fastestFee;
+  final BigInt halfHourFee;
+  final BigInt hourFee;
+  final BigInt economyFee;
+  final BigInt minimumFee;
+
+  const RecommendedFees({
+    required this.fastestFee,
+    required this.halfHourFee,
+    required this.hourFee,
+    required this.economyFee,
+    required this.minimumFee,
+  });
+
+  @override
+  int get hashCode =>
+      fastestFee.hashCode ^
+      halfHourFee.hashCode ^
+      hourFee.hashCode ^
+      economyFee.hashCode ^
+      minimumFee.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecommendedFees &&
+          runtimeType == other.runtimeType &&
+          fastestFee == other.fastestFee &&
+          halfHourFee == other.halfHourFee &&
+          hourFee == other.hourFee &&
+          economyFee == other.economyFee &&
+          minimumFee == other.minimumFee;
+}
 
-  /// ## Receive Swaps
-  ///
-  /// This is the status when the swap failed for any reason and the Receive could not complete.
-  ///
-  /// ## Send and Chain Swaps
-  ///
-  /// This is the status when a swap refund was initiated and the refund tx is confirmed.
-  failed,
+/// A payment repeated at a fixed interval, e.g. for a subscription or an allowance. See
+/// [crate::sdk::LiquidSdk::create_recurring_payment].
+class RecurringPayment {
+  final String id;
+  final String destination;
+  final BigInt amountSat;
+  final int intervalSec;
+  final BigInt? maxFeeSat;
+  final int createdAt;
 
-  /// ## Send and Outgoing Chain Swaps
-  ///
-  /// This covers the case when the swap state is still Created and the swap fails to reach the
-  /// Pending state in time. The TimedOut state indicates the lockup tx should never be broadcast.
-  timedOut,
+  /// The time of the next occurrence, as a UNIX timestamp in seconds
+  final int nextOccurrenceAt;
 
-  /// ## Incoming Chain Swaps
-  ///
-  /// This covers the case when the swap failed for any reason and there is a user lockup tx.
-  /// The swap in this case has to be manually refunded with a provided Bitcoin address
-  refundable,
+  const RecurringPayment({
+    required this.id,
+    required this.destination,
+    required this.amountSat,
+    required this.intervalSec,
+    this.maxFeeSat,
+    required this.createdAt,
+    required this.nextOccurrenceAt,
+  });
 
-  /// ## Send and Chain Swaps
-  ///
-  /// This is the status when a refund was initiated and/or our refund tx was broadcast
-  ///
-  /// When the refund tx is broadcast, `refund_tx_id` is set in the swap.
-  refundPending,
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      destination.hashCode ^
+      amountSat.hashCode ^
+      intervalSec.hashCode ^
+      maxFeeSat.hashCode ^
+      createdAt.hashCode ^
+      nextOccurrenceAt.hashCode;
 
-  /// ## Chain Swaps
-  ///
-  /// This is the state when the user needs to accept new fees before the payment can proceed.
-  ///
-  /// Use [LiquidSdk::fetch_payment_proposed_fees](crate::sdk::LiquidSdk::fetch_payment_proposed_fees)
-  /// to find out the current fees and
-  /// [LiquidSdk::accept_payment_proposed_fees](crate::sdk::LiquidSdk::accept_payment_proposed_fees)
-  /// to accept them, allowing the payment to proceed.
-  ///
-  /// Otherwise, this payment can be immediately refunded using
-  /// [prepare_refund](crate::sdk::LiquidSdk::prepare_refund)/[refund](crate::sdk::LiquidSdk::refund).
-  waitingFeeAcceptance,
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecurringPayment &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          destination == other.destination &&
+          amountSat == other.amountSat &&
+          intervalSec == other.intervalSec &&
+          maxFeeSat == other.maxFeeSat &&
+          createdAt == other.createdAt &&
+          nextOccurrenceAt == other.nextOccurrenceAt;
 }
 
-enum PaymentType { receive, send }
+/// A payment made for an occurrence of a [RecurringPayment]
+class RecurringPaymentOccurrence {
+  final String recurringPaymentId;
 
-/// An argument when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
-class PrepareBuyBitcoinRequest {
-  final BuyBitcoinProvider provider;
-  final BigInt amountSat;
+  /// The time the occurrence was due, as a UNIX timestamp in seconds
+  final int occurrenceAt;
 
-  const PrepareBuyBitcoinRequest({required this.provider, required this.amountSat});
+  /// The time the payment was made, as a UNIX timestamp in seconds
+  final int executedAt;
+
+  /// The tx id of the payment, if it was sent
+  final String? txId;
+
+  /// The error of the payment, if it failed
+  final String? error;
+
+  const RecurringPaymentOccurrence({
+    required this.recurringPaymentId,
+    required this.occurrenceAt,
+    required this.executedAt,
+    this.txId,
+    this.error,
+  });
 
   @override
-  int get hashCode => provider.hashCode ^ amountSat.hashCode;
+  int get hashCode =>
+      recurringPaymentId.hashCode ^
+      occurrenceAt.hashCode ^
+      executedAt.hashCode ^
+      txId.hashCode ^
+      error.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareBuyBitcoinRequest &&
+      other is RecurringPaymentOccurrence &&
           runtimeType == other.runtimeType &&
-          provider == other.provider &&
-          amountSat == other.amountSat;
+          recurringPaymentId == other.recurringPaymentId &&
+          occurrenceAt == other.occurrenceAt &&
+          executedAt == other.executedAt &&
+          txId == other.txId &&
+          error == other.error;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::prepare_buy_bitcoin].
-class PrepareBuyBitcoinResponse {
-  final BuyBitcoinProvider provider;
+/// An argument when calling [crate::sdk::LiquidSdk::refund].
+class RefundRequest {
+  /// The address where the swap funds are locked up
+  final String swapAddress;
+
+  /// The address to refund the swap funds to
+  final String refundAddress;
+
+  /// The fee rate in sat/vB for the refund transaction. Either this or `fee_speed` must be set.
+  final int? feeRateSatPerVbyte;
+
+  /// The [FeeSpeed] preset for the refund transaction. Either this or `fee_rate_sat_per_vbyte` must be set.
+  final FeeSpeed? feeSpeed;
+
+  const RefundRequest({
+    required this.swapAddress,
+    required this.refundAddress,
+    this.feeRateSatPerVbyte,
+    this.feeSpeed,
+  });
+
+  @override
+  int get hashCode =>
+      swapAddress.hashCode ^ refundAddress.hashCode ^ feeRateSatPerVbyte.hashCode ^ feeSpeed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RefundRequest &&
+          runtimeType == other.runtimeType &&
+          swapAddress == other.swapAddress &&
+          refundAddress == other.refundAddress &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte &&
+          feeSpeed == other.feeSpeed;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::refund].
+class RefundResponse {
+  final String refundTxId;
+
+  const RefundResponse({required this.refundTxId});
+
+  @override
+  int get hashCode => refundTxId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RefundResponse && runtimeType == other.runtimeType && refundTxId == other.refundTxId;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::list_refundables].
+class RefundableSwap {
+  final String swapAddress;
+  final int timestamp;
+
+  /// Amount that is refundable, from all UTXOs
   final BigInt amountSat;
-  final BigInt feesSat;
 
-  const PrepareBuyBitcoinResponse({required this.provider, required this.amountSat, required this.feesSat});
+  /// The txid of the last broadcasted refund tx, if any
+  final String? lastRefundTxId;
+
+  /// Either [PaymentState::Refundable], or [PaymentState::RefundPending] if a refund tx was
+  /// broadcast and is not yet confirmed
+  final PaymentState state;
+
+  /// When the swap became refundable, as a UNIX timestamp in seconds
+  final int refundableSince;
+
+  /// The Bitcoin block height from which the funds can be refunded without the cooperation
+  /// of the swapper
+  final int refundEligibleBlockHeight;
+
+  /// Whether the swapper is expected to cooperate in the refund, allowing it before
+  /// `refund_eligible_block_height`. This is not the case once the swapper locked up funds
+  /// for the swap.
+  final bool isCooperativeRefundPossible;
+
+  const RefundableSwap({
+    required this.swapAddress,
+    required this.timestamp,
+    required this.amountSat,
+    this.lastRefundTxId,
+    required this.state,
+    required this.refundableSince,
+    required this.refundEligibleBlockHeight,
+    required this.isCooperativeRefundPossible,
+  });
 
   @override
-  int get hashCode => provider.hashCode ^ amountSat.hashCode ^ feesSat.hashCode;
+  int get hashCode =>
+      swapAddress.hashCode ^
+      timestamp.hashCode ^
+      amountSat.hashCode ^
+      lastRefundTxId.hashCode ^
+      state.hashCode ^
+      refundableSince.hashCode ^
+      refundEligibleBlockHeight.hashCode ^
+      isCooperativeRefundPossible.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareBuyBitcoinResponse &&
+      other is RefundableSwap &&
           runtimeType == other.runtimeType &&
-          provider == other.provider &&
+          swapAddress == other.swapAddress &&
+          timestamp == other.timestamp &&
           amountSat == other.amountSat &&
-          feesSat == other.feesSat;
+          lastRefundTxId == other.lastRefundTxId &&
+          state == other.state &&
+          refundableSince == other.refundableSince &&
+          refundEligibleBlockHeight == other.refundEligibleBlockHeight &&
+          isCooperativeRefundPossible == other.isCooperativeRefundPossible;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::prepare_lnurl_pay].
-class PrepareLnUrlPayRequest {
-  /// The [LnUrlPayRequestData] returned by [parse]
-  final LnUrlPayRequestData data;
+/// An argument when calling [crate::sdk::LiquidSdk::register_lightning_address].
+class RegisterLightningAddressRequest {
+  /// The username of the lightning address, i.e. `username` in `username@domain`
+  final String username;
 
-  /// The amount to send
-  final PayAmount amount;
+  const RegisterLightningAddressRequest({required this.username});
+
+  @override
+  int get hashCode => username.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RegisterLightningAddressRequest &&
+          runtimeType == other.runtimeType &&
+          username == other.username;
+}
+
+/// The metadata of an asset registered in the
+/// [Liquid Asset Registry](https://docs.liquid.net/docs/blockstream-liquid-asset-registry),
+/// returned when calling [crate::sdk::LiquidSdk::fetch_registered_asset].
+class RegisteredAsset {
+  final String assetId;
+
+  /// The name of the asset
+  final String name;
+
+  /// The ticker of the asset, if set by the issuer
+  final String? ticker;
+
+  /// The precision used to display the asset amount
+  final int precision;
+
+  /// The domain of the issuer, if set
+  final String? domain;
+
+  /// The URL of the asset icon. Not every registered asset has an icon.
+  final String iconUrl;
+
+  const RegisteredAsset({
+    required this.assetId,
+    required this.name,
+    this.ticker,
+    required this.precision,
+    this.domain,
+    required this.iconUrl,
+  });
+
+  @override
+  int get hashCode =>
+      assetId.hashCode ^
+      name.hashCode ^
+      ticker.hashCode ^
+      precision.hashCode ^
+      domain.hashCode ^
+      iconUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RegisteredAsset &&
+          runtimeType == other.runtimeType &&
+          assetId == other.assetId &&
+          name == other.name &&
+          ticker == other.ticker &&
+          precision == other.precision &&
+          domain == other.domain &&
+          iconUrl == other.iconUrl;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::replay_events].
+class ReplayEventsRequest {
+  /// Only events with a greater sequence number are returned. If not set, all journaled
+  /// events are returned.
+  final BigInt? sinceSeq;
+
+  /// The maximum number of events to return
+  final int? limit;
+
+  const ReplayEventsRequest({this.sinceSeq, this.limit});
+
+  @override
+  int get hashCode => sinceSeq.hashCode ^ limit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReplayEventsRequest &&
+          runtimeType == other.runtimeType &&
+          sinceSeq == other.sinceSeq &&
+          limit == other.limit;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::restore].
+class RestoreRequest {
+  final String? backupPath;
+
+  const RestoreRequest({this.backupPath});
+
+  @override
+  int get hashCode => backupPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RestoreRequest && runtimeType == other.runtimeType && backupPath == other.backupPath;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::get_reusable_bitcoin_address].
+class ReusableBitcoinAddressResponse {
+  /// The Bitcoin address, which can be paid to repeatedly
+  final String address;
+
+  /// The minimum deposit that can be forwarded to a chain swap. The forwarding tx fee is
+  /// deducted from each deposit before the chain swap limits apply.
+  final BigInt minDepositSat;
+
+  /// The maximum deposit that can be forwarded to a chain swap
+  final BigInt maxDepositSat;
+
+  const ReusableBitcoinAddressResponse({
+    required this.address,
+    required this.minDepositSat,
+    required this.maxDepositSat,
+  });
+
+  @override
+  int get hashCode => address.hashCode ^ minDepositSat.hashCode ^ maxDepositSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReusableBitcoinAddressResponse &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          minDepositSat == other.minDepositSat &&
+          maxDepositSat == other.maxDepositSat;
+}
 
-  /// A BIP353 address, in case one was used in order to fetch the LNURL Pay request data.
-  /// Returned by [parse].
-  final String? bip353Address;
+/// An argument when calling [crate::sdk::LiquidSdk::schedule_payment].
+class SchedulePaymentRequest {
+  /// The request used to prepare the payment at execution time
+  final PrepareSendRequest prepareRequest;
+  final bool? useAssetFees;
 
-  /// An optional comment for this payment
-  final String? comment;
+  /// See [SendPaymentRequest::max_fee_sat]
+  final BigInt? maxFeeSat;
 
-  /// Validates that, if there is a URL success action, the URL domain matches
-  /// the LNURL callback domain. Defaults to `true`
-  final bool? validateSuccessActionUrl;
+  /// The time at which the payment is executed, as a UNIX timestamp in seconds
+  final int executeAt;
 
-  const PrepareLnUrlPayRequest({
-    required this.data,
-    required this.amount,
-    this.bip353Address,
-    this.comment,
-    this.validateSuccessActionUrl,
+  const SchedulePaymentRequest({
+    required this.prepareRequest,
+    this.useAssetFees,
+    this.maxFeeSat,
+    required this.executeAt,
   });
 
   @override
   int get hashCode =>
-      data.hashCode ^
-      amount.hashCode ^
-      bip353Address.hashCode ^
-      comment.hashCode ^
-      validateSuccessActionUrl.hashCode;
+      prepareRequest.hashCode ^ useAssetFees.hashCode ^ maxFeeSat.hashCode ^ executeAt.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareLnUrlPayRequest &&
+      other is SchedulePaymentRequest &&
           runtimeType == other.runtimeType &&
-          data == other.data &&
-          amount == other.amount &&
-          bip353Address == other.bip353Address &&
-          comment == other.comment &&
-          validateSuccessActionUrl == other.validateSuccessActionUrl;
+          prepareRequest == other.prepareRequest &&
+          useAssetFees == other.useAssetFees &&
+          maxFeeSat == other.maxFeeSat &&
+          executeAt == other.executeAt;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::prepare_lnurl_pay].
-class PrepareLnUrlPayResponse {
-  /// The destination of the payment
-  final SendDestination destination;
+/// A future-dated payment. See [crate::sdk::LiquidSdk::schedule_payment].
+class ScheduledPayment {
+  final String id;
+  final SchedulePaymentRequest request;
+  final ScheduledPaymentState state;
 
-  /// The fees in satoshis to send the payment
-  final BigInt feesSat;
+  /// The number of send attempts made so far
+  final int attempts;
+  final int createdAt;
 
-  /// The [LnUrlPayRequestData] returned by [parse]
-  final LnUrlPayRequestData data;
+  /// The earliest time of the next attempt, as a UNIX timestamp in seconds
+  final int nextAttemptAt;
 
-  /// An optional comment for this payment
-  final String? comment;
+  /// The tx id of the sent payment, once [Executed](ScheduledPaymentState::Executed)
+  final String? txId;
 
-  /// The unprocessed LUD-09 success action. This will be processed and decrypted if
-  /// needed after calling [crate::sdk::LiquidSdk::lnurl_pay]
-  final SuccessAction? successAction;
+  /// The error returned by the last attempt, if any
+  final String? lastError;
 
-  const PrepareLnUrlPayResponse({
-    required this.destination,
-    required this.feesSat,
-    required this.data,
-    this.comment,
-    this.successAction,
+  const ScheduledPayment({
+    required this.id,
+    required this.request,
+    required this.state,
+    required this.attempts,
+    required this.createdAt,
+    required this.nextAttemptAt,
+    this.txId,
+    this.lastError,
   });
 
   @override
   int get hashCode =>
-      destination.hashCode ^ feesSat.hashCode ^ data.hashCode ^ comment.hashCode ^ successAction.hashCode;
+      id.hashCode ^
+      request.hashCode ^
+      state.hashCode ^
+      attempts.hashCode ^
+      createdAt.hashCode ^
+      nextAttemptAt.hashCode ^
+      txId.hashCode ^
+      lastError.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareLnUrlPayResponse &&
+      other is ScheduledPayment &&
           runtimeType == other.runtimeType &&
-          destination == other.destination &&
-          feesSat == other.feesSat &&
-          data == other.data &&
-          comment == other.comment &&
-          successAction == other.successAction;
+          id == other.id &&
+          request == other.request &&
+          state == other.state &&
+          attempts == other.attempts &&
+          createdAt == other.createdAt &&
+          nextAttemptAt == other.nextAttemptAt &&
+          txId == other.txId &&
+          lastError == other.lastError;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
-class PreparePayOnchainRequest {
-  /// The amount to send
-  final PayAmount amount;
+/// The state of a [ScheduledPayment]
+enum ScheduledPaymentState {
+  /// The payment is waiting for its execution time or for a retry
+  pending,
 
-  /// The optional fee rate of the Bitcoin claim transaction in sat/vB. Defaults to the swapper estimated claim fee.
-  final int? feeRateSatPerVbyte;
+  /// The payment was sent
+  executed,
 
-  const PreparePayOnchainRequest({required this.amount, this.feeRateSatPerVbyte});
+  /// The payment failed with a permanent error or ran out of attempts
+  failed,
+
+  /// An attempt is running. If the attempt was interrupted, it's resumed on the next start,
+  /// without sending the payment twice.
+  inProgress,
+}
+
+@freezed
+sealed class SdkEvent with _$SdkEvent {
+  const SdkEvent._();
+
+  const factory SdkEvent.paymentFailed({required Payment details}) = SdkEvent_PaymentFailed;
+  const factory SdkEvent.paymentPending({required Payment details}) = SdkEvent_PaymentPending;
+  const factory SdkEvent.paymentRefundable({required Payment details}) = SdkEvent_PaymentRefundable;
+  const factory SdkEvent.paymentRefunded({required Payment details}) = SdkEvent_PaymentRefunded;
+  const factory SdkEvent.paymentRefundPending({required Payment details}) = SdkEvent_PaymentRefundPending;
+  const factory SdkEvent.paymentSucceeded({required Payment details}) = SdkEvent_PaymentSucceeded;
+  const factory SdkEvent.paymentWaitingConfirmation({required Payment details}) =
+      SdkEvent_PaymentWaitingConfirmation;
+  const factory SdkEvent.paymentWaitingFeeAcceptance({required Payment details}) =
+      SdkEvent_PaymentWaitingFeeAcceptance;
+
+  /// A Lightning invoice created when receiving a payment expired without being paid
+  const factory SdkEvent.paymentExpired({required Payment details}) = SdkEvent_PaymentExpired;
+
+  /// The confirmed tx of a completed payment was reorged out of the chain. The payment is
+  /// pending again until its tx confirms again.
+  const factory SdkEvent.paymentReorged({required Payment details}) = SdkEvent_PaymentReorged;
+
+  /// The state of a swap changed
+  const factory SdkEvent.swapUpdated({
+    required String swapId,
+    required PaymentSwapType swapType,
+    required PaymentState state,
+  }) = SdkEvent_SwapUpdated;
+
+  /// A claim transaction was broadcast for a swap
+  const factory SdkEvent.swapClaimBroadcast({required String swapId, required String txId}) =
+      SdkEvent_SwapClaimBroadcast;
+
+  /// A refund transaction was broadcast for a swap
+  const factory SdkEvent.swapRefundBroadcast({required String swapId, required String txId}) =
+      SdkEvent_SwapRefundBroadcast;
+
+  /// A new Liquid or Bitcoin block was found
+  const factory SdkEvent.tipsUpdated({required int liquidTip, required int bitcoinTip}) =
+      SdkEvent_TipsUpdated;
+
+  /// Started syncing with mempool and onchain data
+  const factory SdkEvent.syncStarted() = SdkEvent_SyncStarted;
+
+  /// Synced with mempool and onchain data
+  const factory SdkEvent.synced() = SdkEvent_Synced;
+
+  /// Synced with real-time data sync
+  const factory SdkEvent.dataSynced({
+    /// Indicates new data was pulled from other instances.
+    required bool didPullNewRecords,
+  }) = SdkEvent_DataSynced;
+
+  /// The chain services became reachable again, or stopped being reachable. While offline,
+  /// the calls relying on local data keep working, while the others fail with a
+  /// [ServiceUnavailable](crate::error::PaymentError::ServiceUnavailable) error.
+  const factory SdkEvent.connectivityChanged({required bool isOnline}) = SdkEvent_ConnectivityChanged;
+
+  /// The local state was backed up according to the [Config::backup_policy]
+  const factory SdkEvent.backupSucceeded() = SdkEvent_BackupSucceeded;
+
+  /// The local state failed to be backed up according to the [Config::backup_policy]
+  const factory SdkEvent.backupFailed({required String err}) = SdkEvent_BackupFailed;
+
+  /// All the items of a [crate::sdk::LiquidSdk::send_batch] call were processed
+  const factory SdkEvent.batchSendCompleted({required List<BatchSendItemResult> results}) =
+      SdkEvent_BatchSendCompleted;
+
+  /// A [ScheduledPayment] was sent at its execution time
+  const factory SdkEvent.scheduledPaymentExecuted({required String id, required Payment details}) =
+      SdkEvent_ScheduledPaymentExecuted;
+
+  /// A [ScheduledPayment] failed with a permanent error or ran out of attempts
+  const factory SdkEvent.scheduledPaymentFailed({required String id, required String err}) =
+      SdkEvent_ScheduledPaymentFailed;
+
+  /// An occurrence of a [RecurringPayment] was sent
+  const factory SdkEvent.recurringPaymentExecuted({
+    required String recurringPaymentId,
+    required Payment details,
+  }) = SdkEvent_RecurringPaymentExecuted;
+
+  /// An occurrence of a [RecurringPayment] failed. The next occurrences are still attempted.
+  const factory SdkEvent.recurringPaymentFailed({required String recurringPaymentId, required String err}) =
+      SdkEvent_RecurringPaymentFailed;
+
+  /// An unconfirmed tx of the wallet, e.g. a payment or a swap lockup or claim tx, is no
+  /// longer in the mempool because it was evicted or double-spent
+  const factory SdkEvent.transactionDropped({required String txId}) = SdkEvent_TransactionDropped;
+
+  /// A tx of the wallet is still unconfirmed after [Config::unconfirmed_tx_alert_sec].
+  /// Emitted once per tx.
+  const factory SdkEvent.transactionUnconfirmed({
+    required String txId,
+
+    /// The number of seconds since the tx was first seen
+    required int unconfirmedSec,
+  }) = SdkEvent_TransactionUnconfirmed;
+
+  /// A new Liquid block was found
+  const factory SdkEvent.newLiquidBlock({required BlockInfo block}) = SdkEvent_NewLiquidBlock;
+
+  /// A new Bitcoin block was found
+  const factory SdkEvent.newBitcoinBlock({required BlockInfo block}) = SdkEvent_NewBitcoinBlock;
+}
+
+/// The kind of an [SdkEvent], used to filter events with an [EventFilter]
+enum SdkEventKind {
+  paymentFailed,
+  paymentPending,
+  paymentRefundable,
+  paymentRefunded,
+  paymentRefundPending,
+  paymentSucceeded,
+  paymentWaitingConfirmation,
+  paymentWaitingFeeAcceptance,
+  paymentExpired,
+  paymentReorged,
+  swapUpdated,
+  swapClaimBroadcast,
+  swapRefundBroadcast,
+  tipsUpdated,
+  syncStarted,
+  synced,
+  dataSynced,
+  connectivityChanged,
+  backupSucceeded,
+  backupFailed,
+  batchSendCompleted,
+  scheduledPaymentExecuted,
+  scheduledPaymentFailed,
+  recurringPaymentExecuted,
+  recurringPaymentFailed,
+  transactionDropped,
+  transactionUnconfirmed,
+  newLiquidBlock,
+  newBitcoinBlock,
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::send_batch].
+class SendBatchRequest {
+  final List<BatchSendItem> items;
+
+  /// The maximum total fees of the batch. If the estimated fees exceed it, nothing is sent.
+  final BigInt? maxTotalFeeSat;
+
+  const SendBatchRequest({required this.items, this.maxTotalFeeSat});
 
   @override
-  int get hashCode => amount.hashCode ^ feeRateSatPerVbyte.hashCode;
+  int get hashCode => items.hashCode ^ maxTotalFeeSat.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PreparePayOnchainRequest &&
+      other is SendBatchRequest &&
           runtimeType == other.runtimeType &&
-          amount == other.amount &&
-          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
+          items == other.items &&
+          maxTotalFeeSat == other.maxTotalFeeSat;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::prepare_pay_onchain].
-class PreparePayOnchainResponse {
-  final BigInt receiverAmountSat;
-  final BigInt claimFeesSat;
-  final BigInt totalFeesSat;
+/// Returned when calling [crate::sdk::LiquidSdk::send_batch].
+class SendBatchResponse {
+  /// The result of each item, in the order of the request
+  final List<BatchSendItemResult> results;
 
-  const PreparePayOnchainResponse({
-    required this.receiverAmountSat,
-    required this.claimFeesSat,
-    required this.totalFeesSat,
-  });
+  const SendBatchResponse({required this.results});
 
   @override
-  int get hashCode => receiverAmountSat.hashCode ^ claimFeesSat.hashCode ^ totalFeesSat.hashCode;
+  int get hashCode => results.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PreparePayOnchainResponse &&
-          runtimeType == other.runtimeType &&
-          receiverAmountSat == other.receiverAmountSat &&
-          claimFeesSat == other.claimFeesSat &&
-          totalFeesSat == other.totalFeesSat;
+      other is SendBatchResponse && runtimeType == other.runtimeType && results == other.results;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
-class PrepareReceiveRequest {
-  final PaymentMethod paymentMethod;
+@freezed
+sealed class SendDestination with _$SendDestination {
+  const SendDestination._();
 
-  /// The amount to be paid in either Bitcoin or another asset
-  final ReceiveAmount? amount;
+  const factory SendDestination.liquidAddress({
+    required LiquidAddressData addressData,
+
+    /// A BIP353 address, in case one was used to resolve this Liquid address
+    String? bip353Address,
+
+    /// The payjoin endpoint advertised with the `pj` parameter of the BIP21 URI. If set, an
+    /// L-BTC payment is sent as a payjoin, falling back to a regular transaction on failure.
+    String? payjoinUrl,
+  }) = SendDestination_LiquidAddress;
+  const factory SendDestination.bolt11({
+    required LNInvoice invoice,
+
+    /// A BIP353 address, in case one was used to resolve this BOLT11
+    String? bip353Address,
+  }) = SendDestination_Bolt11;
+  const factory SendDestination.bolt12({
+    required LNOffer offer,
+    required BigInt receiverAmountSat,
 
-  const PrepareReceiveRequest({required this.paymentMethod, this.amount});
+    /// A BIP353 address, in case one was used to resolve this BOLT12
+    String? bip353Address,
+  }) = SendDestination_Bolt12;
+}
+
+/// An argument when calling [crate::sdk::LiquidSdk::send_payment].
+class SendPaymentRequest {
+  final PrepareSendResponse prepareResponse;
+  final bool? useAssetFees;
+
+  /// The maximum fees to pay. If the fees computed when sending, which may have changed since
+  /// the payment was prepared, would exceed it, the payment fails with
+  /// [PaymentError::FeeLimitExceeded]. When `use_asset_fees` is set, the limit applies to the
+  /// asset fees, in the asset's base units.
+  final BigInt? maxFeeSat;
+
+  /// A token allowing the payment to exceed the [Config::spend_limits], created with
+  /// [crate::sdk::LiquidSdk::create_spend_limit_override]
+  final String? spendLimitOverride;
+
+  /// A caller-supplied key identifying the payment. If a payment was already sent with the
+  /// same key, e.g. before an app crash or timeout, it's returned instead of being sent again.
+  final String? idempotencyKey;
+
+  const SendPaymentRequest({
+    required this.prepareResponse,
+    this.useAssetFees,
+    this.maxFeeSat,
+    this.spendLimitOverride,
+    this.idempotencyKey,
+  });
 
   @override
-  int get hashCode => paymentMethod.hashCode ^ amount.hashCode;
+  int get hashCode =>
+      prepareResponse.hashCode ^
+      useAssetFees.hashCode ^
+      maxFeeSat.hashCode ^
+      spendLimitOverride.hashCode ^
+      idempotencyKey.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareReceiveRequest &&
+      other is SendPaymentRequest &&
           runtimeType == other.runtimeType &&
-          paymentMethod == other.paymentMethod &&
-          amount == other.amount;
+          prepareResponse == other.prepareResponse &&
+          useAssetFees == other.useAssetFees &&
+          maxFeeSat == other.maxFeeSat &&
+          spendLimitOverride == other.spendLimitOverride &&
+          idempotencyKey == other.idempotencyKey;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::prepare_receive_payment].
-class PrepareReceiveResponse {
-  final PaymentMethod paymentMethod;
-  final ReceiveAmount? amount;
+/// Returned when calling [crate::sdk::LiquidSdk::send_payment].
+class SendPaymentResponse {
+  final Payment payment;
 
-  /// Generally represents the total fees that would be paid to send or receive this payment.
-  ///
-  /// In case of Zero-Amount Receive Chain swaps, the swapper service fee (`swapper_feerate` times
-  /// the amount) is paid in addition to `fees_sat`. The swapper service feerate is already known
-  /// in the beginning, but the exact swapper service fee will only be known when the
-  /// `payer_amount_sat` is known.
-  ///
-  /// In all other types of swaps, the swapper service fee is included in `fees_sat`.
-  final BigInt feesSat;
+  const SendPaymentResponse({required this.payment});
 
-  /// The minimum amount the payer can send for this swap to succeed.
-  ///
-  /// When the method is [PaymentMethod::LiquidAddress], this is empty.
-  final BigInt? minPayerAmountSat;
+  @override
+  int get hashCode => payment.hashCode;
 
-  /// The maximum amount the payer can send for this swap to succeed.
-  ///
-  /// When the method is [PaymentMethod::LiquidAddress], this is empty.
-  final BigInt? maxPayerAmountSat;
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SendPaymentResponse && runtimeType == other.runtimeType && payment == other.payment;
+}
 
-  /// The percentage of the sent amount that will count towards the service fee.
-  ///
-  /// When the method is [PaymentMethod::LiquidAddress], this is empty.
-  final double? swapperFeerate;
+/// The health of a remote service
+class ServiceHealth {
+  final HealthCheckService service;
+  final bool isReachable;
 
-  const PrepareReceiveResponse({
-    required this.paymentMethod,
-    this.amount,
-    required this.feesSat,
-    this.minPayerAmountSat,
-    this.maxPayerAmountSat,
-    this.swapperFeerate,
-  });
+  /// The duration of the request to the service, if it succeeded
+  final BigInt? latencyMs;
+
+  /// The error of the request to the service, if it failed
+  final String? error;
+
+  const ServiceHealth({required this.service, required this.isReachable, this.latencyMs, this.error});
 
   @override
-  int get hashCode =>
-      paymentMethod.hashCode ^
-      amount.hashCode ^
-      feesSat.hashCode ^
-      minPayerAmountSat.hashCode ^
-      maxPayerAmountSat.hashCode ^
-      swapperFeerate.hashCode;
+  int get hashCode => service.hashCode ^ isReachable.hashCode ^ latencyMs.hashCode ^ error.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareReceiveResponse &&
+      other is ServiceHealth &&
           runtimeType == other.runtimeType &&
-          paymentMethod == other.paymentMethod &&
-          amount == other.amount &&
-          feesSat == other.feesSat &&
-          minPayerAmountSat == other.minPayerAmountSat &&
-          maxPayerAmountSat == other.maxPayerAmountSat &&
-          swapperFeerate == other.swapperFeerate;
+          service == other.service &&
+          isReachable == other.isReachable &&
+          latencyMs == other.latencyMs &&
+          error == other.error;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::prepare_refund].
-class PrepareRefundRequest {
-  /// The address where the swap funds are locked up
-  final String swapAddress;
+/// An argument when calling [crate::sdk::LiquidSdk::sign_message].
+class SignMessageRequest {
+  final String message;
 
-  /// The address to refund the swap funds to
-  final String refundAddress;
+  const SignMessageRequest({required this.message});
+
+  @override
+  int get hashCode => message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SignMessageRequest && runtimeType == other.runtimeType && message == other.message;
+}
+
+/// Returned when calling [crate::sdk::LiquidSdk::sign_message].
+class SignMessageResponse {
+  /// The zbase encoded signature.
+  final String signature;
 
-  /// The fee rate in sat/vB for the refund transaction
-  final int feeRateSatPerVbyte;
+  /// The public key the message was signed with, to verify the signature with
+  /// [crate::sdk::LiquidSdk::check_message].
+  final String pubkey;
 
-  const PrepareRefundRequest({
-    required this.swapAddress,
-    required this.refundAddress,
-    required this.feeRateSatPerVbyte,
-  });
+  const SignMessageResponse({required this.signature, required this.pubkey});
 
   @override
-  int get hashCode => swapAddress.hashCode ^ refundAddress.hashCode ^ feeRateSatPerVbyte.hashCode;
+  int get hashCode => signature.hashCode ^ pubkey.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareRefundRequest &&
+      other is SignMessageResponse &&
           runtimeType == other.runtimeType &&
-          swapAddress == other.swapAddress &&
-          refundAddress == other.refundAddress &&
-          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
+          signature == other.signature &&
+          pubkey == other.pubkey;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::prepare_refund].
-class PrepareRefundResponse {
-  final int txVsize;
-  final BigInt txFeeSat;
+/// An argument when calling [crate::sdk::LiquidSdk::sign_pset].
+class SignPsetRequest {
+  /// The base64-encoded PSET, created outside of the SDK
+  final String pset;
 
-  /// The txid of the last broadcasted refund tx, if any
-  final String? lastRefundTxId;
+  /// The maximum network fee of the transaction, in satoshi
+  final BigInt maxFeeSat;
 
-  const PrepareRefundResponse({required this.txVsize, required this.txFeeSat, this.lastRefundTxId});
+  /// The maximum amount of each asset the wallet may spend. An asset not listed must not be
+  /// spent by the wallet. The L-BTC amount includes the network fee, if paid by the wallet.
+  final List<PsetSpendLimit> spendLimits;
+
+  const SignPsetRequest({required this.pset, required this.maxFeeSat, required this.spendLimits});
 
   @override
-  int get hashCode => txVsize.hashCode ^ txFeeSat.hashCode ^ lastRefundTxId.hashCode;
+  int get hashCode => pset.hashCode ^ maxFeeSat.hashCode ^ spendLimits.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareRefundResponse &&
+      other is SignPsetRequest &&
           runtimeType == other.runtimeType &&
-          txVsize == other.txVsize &&
-          txFeeSat == other.txFeeSat &&
-          lastRefundTxId == other.lastRefundTxId;
+          pset == other.pset &&
+          maxFeeSat == other.maxFeeSat &&
+          spendLimits == other.spendLimits;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::prepare_send_payment].
-class PrepareSendRequest {
-  /// The destination we intend to pay to.
-  /// Supports BIP21 URIs, BOLT11 invoices, BOLT12 offers and Liquid addresses
-  final String destination;
+/// Returned when calling [crate::sdk::LiquidSdk::sign_pset].
+class SignPsetResponse {
+  /// The base64-encoded PSET, with the wallet inputs signed
+  final String pset;
 
-  /// Should only be set when paying directly onchain or to a BIP21 URI
-  /// where no amount is specified, or when the caller wishes to drain
-  final PayAmount? amount;
+  /// The network fee of the transaction, in satoshi
+  final BigInt feesSat;
 
-  const PrepareSendRequest({required this.destination, this.amount});
+  const SignPsetResponse({required this.pset, required this.feesSat});
 
   @override
-  int get hashCode => destination.hashCode ^ amount.hashCode;
+  int get hashCode => pset.hashCode ^ feesSat.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareSendRequest &&
+      other is SignPsetResponse &&
           runtimeType == other.runtimeType &&
-          destination == other.destination &&
-          amount == other.amount;
+          pset == other.pset &&
+          feesSat == other.feesSat;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::prepare_send_payment].
-class PrepareSendResponse {
-  final SendDestination destination;
-
-  /// The optional estimated fee in satoshi. Is set when there is Bitcoin available
-  /// to pay fees. When not set, there are asset fees available to pay fees.
-  final BigInt? feesSat;
+/// The simulated swap lifecycles, see [Config::simulation].
+///
+/// Each swap goes through the status updates of the swapper, ending either in success or in
+/// failure. No funds are moved: a successful Send swap is completed without a lockup tx, and a
+/// successful Receive swap without a claim tx. Failed swaps are marked as failed.
+class SimulationConfig {
+  /// The delay in seconds between the status updates of a swap
+  final int statusUpdateDelaySec;
 
-  /// The optional estimated fee in the asset. Is set when [PayAmount::Asset::estimate_asset_fees]
-  /// is set to `true`, the Payjoin service accepts this asset to pay fees and there
-  /// are funds available in this asset to pay fees.
-  final double? estimatedAssetFees;
+  /// The probability that a swap fails, between 0 and 1
+  final double failureRate;
 
-  const PrepareSendResponse({required this.destination, this.feesSat, this.estimatedAssetFees});
+  const SimulationConfig({required this.statusUpdateDelaySec, required this.failureRate});
 
   @override
-  int get hashCode => destination.hashCode ^ feesSat.hashCode ^ estimatedAssetFees.hashCode;
+  int get hashCode => statusUpdateDelaySec.hashCode ^ failureRate.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareSendResponse &&
+      other is SimulationConfig &&
           runtimeType == other.runtimeType &&
-          destination == other.destination &&
-          feesSat == other.feesSat &&
-          estimatedAssetFees == other.estimatedAssetFees;
+          statusUpdateDelaySec == other.statusUpdateDelaySec &&
+          failureRate == other.failureRate;
 }
 
 @freezed
-sealed class ReceiveAmount with _$ReceiveAmount {
-  const ReceiveAmount._();
+sealed class SpendLimit with _$SpendLimit {
+  const SpendLimit._();
 
-  /// The amount in satoshi that should be paid
-  const factory ReceiveAmount.bitcoin({required BigInt payerAmountSat}) = ReceiveAmount_Bitcoin;
+  const factory SpendLimit.bitcoin({required BigInt amountSat}) = SpendLimit_Bitcoin;
 
-  /// The amount of an asset that should be paid
-  const factory ReceiveAmount.asset({required String assetId, double? payerAmount}) = ReceiveAmount_Asset;
+  /// Converted to satoshi at the current exchange rate whenever a payment is sent
+  const factory SpendLimit.fiat({required FiatAmount amount}) = SpendLimit_Fiat;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::receive_payment].
-class ReceivePaymentRequest {
-  final PrepareReceiveResponse prepareResponse;
+/// The limits on the amounts sent, enforced by
+/// [LiquidSdk::send_payment](crate::sdk::LiquidSdk::send_payment),
+/// [LiquidSdk::pay_onchain](crate::sdk::LiquidSdk::pay_onchain) and
+/// [LiquidSdk::convert](crate::sdk::LiquidSdk::convert). A payment exceeding them fails with
+/// [PaymentError::SpendLimitExceeded](crate::error::PaymentError::SpendLimitExceeded), unless a
+/// valid `spend_limit_override` is given.
+class SpendLimits {
+  /// The maximum amount of a single payment, including fees
+  final SpendLimit? perPayment;
 
-  /// The description for this payment request.
-  final String? description;
+  /// The maximum amount sent in the last 24 hours, including fees
+  final SpendLimit? perDay;
 
-  /// If set to true, then the hash of the description will be used.
-  final bool? useDescriptionHash;
+  /// The secret with which override tokens are created, see
+  /// [LiquidSdk::create_spend_limit_override](crate::sdk::LiquidSdk::create_spend_limit_override).
+  /// If not set, the limits can't be overridden.
+  final String? overrideSecret;
 
-  const ReceivePaymentRequest({required this.prepareResponse, this.description, this.useDescriptionHash});
+  const SpendLimits({this.perPayment, this.perDay, this.overrideSecret});
 
   @override
-  int get hashCode => prepareResponse.hashCode ^ description.hashCode ^ useDescriptionHash.hashCode;
+  int get hashCode => perPayment.hashCode ^ perDay.hashCode ^ overrideSecret.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is ReceivePaymentRequest &&
+      other is SpendLimits &&
           runtimeType == other.runtimeType &&
-          prepareResponse == other.prepareResponse &&
-          description == other.description &&
-          useDescriptionHash == other.useDescriptionHash;
+          perPayment == other.perPayment &&
+          perDay == other.perDay &&
+          overrideSecret == other.overrideSecret;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::receive_payment].
-class ReceivePaymentResponse {
-  /// Either a BIP21 URI (Liquid or Bitcoin), a Liquid address
-  /// or an invoice, depending on the [PrepareReceiveResponse] parameters
-  final String destination;
+/// The full state of a swap, as returned by [crate::sdk::LiquidSdk::list_swaps],
+/// [crate::sdk::LiquidSdk::list_ongoing_swaps] and [crate::sdk::LiquidSdk::get_swap]
+class SwapInfo {
+  final String swapId;
+  final PaymentSwapType swapType;
 
-  const ReceivePaymentResponse({required this.destination});
+  /// The direction of a chain swap. Not set for Send and Receive swaps
+  final Direction? direction;
+  final PaymentState state;
+  final int createdAt;
+  final BigInt timeoutBlockHeight;
+  final BigInt payerAmountSat;
+  final BigInt receiverAmountSat;
 
-  @override
-  int get hashCode => destination.hashCode;
+  /// The invoice of a Send or Receive swap
+  final String? invoice;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ReceivePaymentResponse && runtimeType == other.runtimeType && destination == other.destination;
-}
+  /// The address the user funds are locked up to. Not set for Send and Receive swaps, see
+  /// `create_response_json` instead
+  final String? lockupAddress;
 
-/// Returned when calling [crate::sdk::LiquidSdk::recommended_fees].
-class RecommendedFees {
-  final BigInt fastestFee;
-  final BigInt halfHourFee;
-  final BigInt hourFee;
-  final BigInt economyFee;
-  final BigInt minimumFee;
+  /// The lockup tx of the party paying into the swap
+  final String? userLockupTxId;
 
-  const RecommendedFees({
-    required this.fastestFee,
-    required this.halfHourFee,
-    required this.hourFee,
-    required this.economyFee,
-    required this.minimumFee,
+  /// The lockup tx of the swapper
+  final String? serverLockupTxId;
+  final String? claimAddress;
+  final String? claimTxId;
+  final String? refundAddress;
+  final String? refundTxId;
+
+  /// The swapper response on swap creation, containing the swap scripts
+  final String createResponseJson;
+
+  const SwapInfo({
+    required this.swapId,
+    required this.swapType,
+    this.direction,
+    required this.state,
+    required this.createdAt,
+    required this.timeoutBlockHeight,
+    required this.payerAmountSat,
+    required this.receiverAmountSat,
+    this.invoice,
+    this.lockupAddress,
+    this.userLockupTxId,
+    this.serverLockupTxId,
+    this.claimAddress,
+    this.claimTxId,
+    this.refundAddress,
+    this.refundTxId,
+    required this.createResponseJson,
   });
 
   @override
   int get hashCode =>
-      fastestFee.hashCode ^
-      halfHourFee.hashCode ^
-      hourFee.hashCode ^
-      economyFee.hashCode ^
-      minimumFee.hashCode;
+      swapId.hashCode ^
+      swapType.hashCode ^
+      direction.hashCode ^
+      state.hashCode ^
+      createdAt.hashCode ^
+      timeoutBlockHeight.hashCode ^
+      payerAmountSat.hashCode ^
+      receiverAmountSat.hashCode ^
+      invoice.hashCode ^
+      lockupAddress.hashCode ^
+      userLockupTxId.hashCode ^
+      serverLockupTxId.hashCode ^
+      claimAddress.hashCode ^
+      claimTxId.hashCode ^
+      refundAddress.hashCode ^
+      refundTxId.hashCode ^
+      createResponseJson.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is RecommendedFees &&
+      other is SwapInfo &&
           runtimeType == other.runtimeType &&
-          fastestFee == other.fastestFee &&
-          halfHourFee == other.halfHourFee &&
-          hourFee == other.hourFee &&
-          economyFee == other.economyFee &&
-          minimumFee == other.minimumFee;
+          swapId == other.swapId &&
+          swapType == other.swapType &&
+          direction == other.direction &&
+          state == other.state &&
+          createdAt == other.createdAt &&
+          timeoutBlockHeight == other.timeoutBlockHeight &&
+          payerAmountSat == other.payerAmountSat &&
+          receiverAmountSat == other.receiverAmountSat &&
+          invoice == other.invoice &&
+          lockupAddress == other.lockupAddress &&
+          userLockupTxId == other.userLockupTxId &&
+          serverLockupTxId == other.serverLockupTxId &&
+          claimAddress == other.claimAddress &&
+          claimTxId == other.claimTxId &&
+          refundAddress == other.refundAddress &&
+          refundTxId == other.refundTxId &&
+          createResponseJson == other.createResponseJson;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::refund].
-class RefundRequest {
-  /// The address where the swap funds are locked up
-  final String swapAddress;
+/// The swap types offered by the swapper
+class SwapServiceStatus {
+  /// Whether Lightning payments can be sent
+  final bool sendAvailable;
 
-  /// The address to refund the swap funds to
-  final String refundAddress;
+  /// Whether Lightning payments can be received
+  final bool receiveAvailable;
 
-  /// The fee rate in sat/vB for the refund transaction
-  final int feeRateSatPerVbyte;
+  /// Whether Bitcoin payments can be sent
+  final bool chainSendAvailable;
 
-  const RefundRequest({
-    required this.swapAddress,
-    required this.refundAddress,
-    required this.feeRateSatPerVbyte,
+  /// Whether Bitcoin payments can be received
+  final bool chainReceiveAvailable;
+
+  const SwapServiceStatus({
+    required this.sendAvailable,
+    required this.receiveAvailable,
+    required this.chainSendAvailable,
+    required this.chainReceiveAvailable,
   });
 
   @override
-  int get hashCode => swapAddress.hashCode ^ refundAddress.hashCode ^ feeRateSatPerVbyte.hashCode;
+  int get hashCode =>
+      sendAvailable.hashCode ^
+      receiveAvailable.hashCode ^
+      chainSendAvailable.hashCode ^
+      chainReceiveAvailable.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is RefundRequest &&
+      other is SwapServiceStatus &&
           runtimeType == other.runtimeType &&
-          swapAddress == other.swapAddress &&
-          refundAddress == other.refundAddress &&
-          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
+          sendAvailable == other.sendAvailable &&
+          receiveAvailable == other.receiveAvailable &&
+          chainSendAvailable == other.chainSendAvailable &&
+          chainReceiveAvailable == other.chainReceiveAvailable;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::refund].
-class RefundResponse {
-  final String refundTxId;
+/// An argument when calling [crate::sdk::LiquidSdk::sweep_private_key].
+class SweepPrivateKeyRequest {
+  /// A WIF encoded private key, or a confidential `elwpkh` descriptor with a WIF encoded
+  /// private key, e.g. `ct(<blinding key>,elwpkh(<WIF>))`. A WIF key is assumed to receive
+  /// on addresses blinded with its ELIP151 blinding key.
+  final String key;
 
-  const RefundResponse({required this.refundTxId});
+  const SweepPrivateKeyRequest({required this.key});
 
   @override
-  int get hashCode => refundTxId.hashCode;
+  int get hashCode => key.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is RefundResponse && runtimeType == other.runtimeType && refundTxId == other.refundTxId;
+      other is SweepPrivateKeyRequest && runtimeType == other.runtimeType && key == other.key;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::list_refundables].
-class RefundableSwap {
-  final String swapAddress;
-  final int timestamp;
+/// Returned when calling [crate::sdk::LiquidSdk::sweep_private_key].
+class SweepPrivateKeyResponse {
+  final Payment payment;
 
-  /// Amount that is refundable, from all UTXOs
+  /// The amount of L-BTC swept into the wallet, after paying the network fee
   final BigInt amountSat;
 
-  /// The txid of the last broadcasted refund tx, if any
-  final String? lastRefundTxId;
+  /// The amounts of the swept assets other than L-BTC
+  final List<AssetAmount> assetAmounts;
 
-  const RefundableSwap({
-    required this.swapAddress,
-    required this.timestamp,
-    required this.amountSat,
-    this.lastRefundTxId,
-  });
+  const SweepPrivateKeyResponse({required this.payment, required this.amountSat, required this.assetAmounts});
 
   @override
-  int get hashCode =>
-      swapAddress.hashCode ^ timestamp.hashCode ^ amountSat.hashCode ^ lastRefundTxId.hashCode;
+  int get hashCode => payment.hashCode ^ amountSat.hashCode ^ assetAmounts.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is RefundableSwap &&
+      other is SweepPrivateKeyResponse &&
           runtimeType == other.runtimeType &&
-          swapAddress == other.swapAddress &&
-          timestamp == other.timestamp &&
+          payment == other.payment &&
           amountSat == other.amountSat &&
-          lastRefundTxId == other.lastRefundTxId;
+          assetAmounts == other.assetAmounts;
 }
 
-/// An argument when calling [crate::sdk::LiquidSdk::restore].
-class RestoreRequest {
-  final String? backupPath;
+/// An argument when calling [crate::sdk::LiquidSdk::sync] through the bindings.
+class SyncRequest {
+  /// Whether to sync all the swaps that can still be updated, instead of only the ongoing
+  /// receive swaps. Defaults to true.
+  final bool? full;
 
-  const RestoreRequest({this.backupPath});
+  const SyncRequest({this.full});
 
   @override
-  int get hashCode => backupPath.hashCode;
+  int get hashCode => full.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is RestoreRequest && runtimeType == other.runtimeType && backupPath == other.backupPath;
-}
-
-@freezed
-sealed class SdkEvent with _$SdkEvent {
-  const SdkEvent._();
-
-  const factory SdkEvent.paymentFailed({required Payment details}) = SdkEvent_PaymentFailed;
-  const factory SdkEvent.paymentPending({required Payment details}) = SdkEvent_PaymentPending;
-  const factory SdkEvent.paymentRefundable({required Payment details}) = SdkEvent_PaymentRefundable;
-  const factory SdkEvent.paymentRefunded({required Payment details}) = SdkEvent_PaymentRefunded;
-  const factory SdkEvent.paymentRefundPending({required Payment details}) = SdkEvent_PaymentRefundPending;
-  const factory SdkEvent.paymentSucceeded({required Payment details}) = SdkEvent_PaymentSucceeded;
-  const factory SdkEvent.paymentWaitingConfirmation({required Payment details}) =
-      SdkEvent_PaymentWaitingConfirmation;
-  const factory SdkEvent.paymentWaitingFeeAcceptance({required Payment details}) =
-      SdkEvent_PaymentWaitingFeeAcceptance;
-
-  /// Synced with mempool and onchain data
-  const factory SdkEvent.synced() = SdkEvent_Synced;
-
-  /// Synced with real-time data sync
-  const factory SdkEvent.dataSynced({
-    /// Indicates new data was pulled from other instances.
-    required bool didPullNewRecords,
-  }) = SdkEvent_DataSynced;
+      other is SyncRequest && runtimeType == other.runtimeType && full == other.full;
 }
 
-@freezed
-sealed class SendDestination with _$SendDestination {
-  const SendDestination._();
-
-  const factory SendDestination.liquidAddress({
-    required LiquidAddressData addressData,
-
-    /// A BIP353 address, in case one was used to resolve this Liquid address
-    String? bip353Address,
-  }) = SendDestination_LiquidAddress;
-  const factory SendDestination.bolt11({
-    required LNInvoice invoice,
-
-    /// A BIP353 address, in case one was used to resolve this BOLT11
-    String? bip353Address,
-  }) = SendDestination_Bolt11;
-  const factory SendDestination.bolt12({
-    required LNOffer offer,
-    required BigInt receiverAmountSat,
+/// Returned when calling [crate::sdk::LiquidSdk::sync].
+class SyncResponse {
+  /// The number of wallet txs found which were not yet known
+  final int newTxCount;
 
-    /// A BIP353 address, in case one was used to resolve this BOLT12
-    String? bip353Address,
-  }) = SendDestination_Bolt12;
-}
+  /// The number of swaps updated from the onchain data
+  final int updatedSwapCount;
 
-/// An argument when calling [crate::sdk::LiquidSdk::send_payment].
-class SendPaymentRequest {
-  final PrepareSendResponse prepareResponse;
-  final bool? useAssetFees;
+  /// The duration of the sync in milliseconds
+  final BigInt durationMs;
 
-  const SendPaymentRequest({required this.prepareResponse, this.useAssetFees});
+  const SyncResponse({required this.newTxCount, required this.updatedSwapCount, required this.durationMs});
 
   @override
-  int get hashCode => prepareResponse.hashCode ^ useAssetFees.hashCode;
+  int get hashCode => newTxCount.hashCode ^ updatedSwapCount.hashCode ^ durationMs.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is SendPaymentRequest &&
+      other is SyncResponse &&
           runtimeType == other.runtimeType &&
-          prepareResponse == other.prepareResponse &&
-          useAssetFees == other.useAssetFees;
+          newTxCount == other.newTxCount &&
+          updatedSwapCount == other.updatedSwapCount &&
+          durationMs == other.durationMs;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::send_payment].
-class SendPaymentResponse {
-  final Payment payment;
-
-  const SendPaymentResponse({required this.payment});
+/// The secrets of a confidential tx output owned by the wallet, which prove its asset and
+/// amount when checked against the output commitments.
+class UnblindedTxOut {
+  /// The outpoint of the output, as `txid:vout`
+  final String outpoint;
+  final String assetId;
 
-  @override
-  int get hashCode => payment.hashCode;
+  /// The amount, in base units of the asset
+  final BigInt amount;
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SendPaymentResponse && runtimeType == other.runtimeType && payment == other.payment;
-}
+  /// The hex encoded asset blinding factor
+  final String assetBlindingFactor;
 
-/// An argument when calling [crate::sdk::LiquidSdk::sign_message].
-class SignMessageRequest {
-  final String message;
+  /// The hex encoded amount blinding factor
+  final String amountBlindingFactor;
 
-  const SignMessageRequest({required this.message});
+  const UnblindedTxOut({
+    required this.outpoint,
+    required this.assetId,
+    required this.amount,
+    required this.assetBlindingFactor,
+    required this.amountBlindingFactor,
+  });
 
   @override
-  int get hashCode => message.hashCode;
+  int get hashCode =>
+      outpoint.hashCode ^
+      assetId.hashCode ^
+      amount.hashCode ^
+      assetBlindingFactor.hashCode ^
+      amountBlindingFactor.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is SignMessageRequest && runtimeType == other.runtimeType && message == other.message;
+      other is UnblindedTxOut &&
+          runtimeType == other.runtimeType &&
+          outpoint == other.outpoint &&
+          assetId == other.assetId &&
+          amount == other.amount &&
+          assetBlindingFactor == other.assetBlindingFactor &&
+          amountBlindingFactor == other.amountBlindingFactor;
 }
 
-/// Returned when calling [crate::sdk::LiquidSdk::sign_message].
-class SignMessageResponse {
-  final String signature;
+/// Returned when calling [crate::sdk::LiquidSdk::validate_address].
+class ValidateAddressResponse {
+  final AddressType addressType;
+  final LiquidNetwork network;
+
+  /// Whether the address is a confidential Liquid address, i.e. includes a blinding key
+  final bool isConfidential;
 
-  const SignMessageResponse({required this.signature});
+  /// Whether the address can receive Liquid assets other than L-BTC
+  final bool supportsAssets;
+
+  const ValidateAddressResponse({
+    required this.addressType,
+    required this.network,
+    required this.isConfidential,
+    required this.supportsAssets,
+  });
 
   @override
-  int get hashCode => signature.hashCode;
+  int get hashCode =>
+      addressType.hashCode ^ network.hashCode ^ isConfidential.hashCode ^ supportsAssets.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is SignMessageResponse && runtimeType == other.runtimeType && signature == other.signature;
+      other is ValidateAddressResponse &&
+          runtimeType == other.runtimeType &&
+          addressType == other.addressType &&
+          network == other.network &&
+          isConfidential == other.isConfidential &&
+          supportsAssets == other.supportsAssets;
 }
 
 class WalletInfo {
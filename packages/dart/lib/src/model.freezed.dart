@@ -12,6 +12,168 @@ part of 'model.dart';
 
 // dart format off
 T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$BackupPolicy {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is BackupPolicy);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'BackupPolicy()';
+}
+
+
+}
+
+/// @nodoc
+class $BackupPolicyCopyWith<$Res>  {
+$BackupPolicyCopyWith(BackupPolicy _, $Res Function(BackupPolicy) __);
+}
+
+
+/// @nodoc
+
+
+class BackupPolicy_CompletedSwaps extends BackupPolicy {
+  const BackupPolicy_CompletedSwaps({required this.swaps}): super._();
+  
+
+ final  int swaps;
+
+/// Create a copy of BackupPolicy
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$BackupPolicy_CompletedSwapsCopyWith<BackupPolicy_CompletedSwaps> get copyWith => _$BackupPolicy_CompletedSwapsCopyWithImpl<BackupPolicy_CompletedSwaps>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is BackupPolicy_CompletedSwaps&&(identical(other.swaps, swaps) || other.swaps == swaps));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,swaps);
+
+@override
+String toString() {
+  return 'BackupPolicy.completedSwaps(swaps: $swaps)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $BackupPolicy_CompletedSwapsCopyWith<$Res> implements $BackupPolicyCopyWith<$Res> {
+  factory $BackupPolicy_CompletedSwapsCopyWith(BackupPolicy_CompletedSwaps value, $Res Function(BackupPolicy_CompletedSwaps) _then) = _$BackupPolicy_CompletedSwapsCopyWithImpl;
+@useResult
+$Res call({
+ int swaps
+});
+
+
+
+
+}
+/// @nodoc
+class _$BackupPolicy_CompletedSwapsCopyWithImpl<$Res>
+    implements $BackupPolicy_CompletedSwapsCopyWith<$Res> {
+  _$BackupPolicy_CompletedSwapsCopyWithImpl(this._self, this._then);
+
+  final BackupPolicy_CompletedSwaps _self;
+  final $Res Function(BackupPolicy_CompletedSwaps) _then;
+
+/// Create a copy of BackupPolicy
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? swaps = null,}) {
+  return _then(BackupPolicy_CompletedSwaps(
+swaps: null == swaps ? _self.swaps : swaps // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class BackupPolicy_Interval extends BackupPolicy {
+  const BackupPolicy_Interval({required this.hours}): super._();
+  
+
+ final  int hours;
+
+/// Create a copy of BackupPolicy
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$BackupPolicy_IntervalCopyWith<BackupPolicy_Interval> get copyWith => _$BackupPolicy_IntervalCopyWithImpl<BackupPolicy_Interval>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is BackupPolicy_Interval&&(identical(other.hours, hours) || other.hours == hours));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,hours);
+
+@override
+String toString() {
+  return 'BackupPolicy.interval(hours: $hours)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $BackupPolicy_IntervalCopyWith<$Res> implements $BackupPolicyCopyWith<$Res> {
+  factory $BackupPolicy_IntervalCopyWith(BackupPolicy_Interval value, $Res Function(BackupPolicy_Interval) _then) = _$BackupPolicy_IntervalCopyWithImpl;
+@useResult
+$Res call({
+ int hours
+});
+
+
+
+
+}
+/// @nodoc
+class _$BackupPolicy_IntervalCopyWithImpl<$Res>
+    implements $BackupPolicy_IntervalCopyWith<$Res> {
+  _$BackupPolicy_IntervalCopyWithImpl(this._self, this._then);
+
+  final BackupPolicy_Interval _self;
+  final $Res Function(BackupPolicy_Interval) _then;
+
+/// Create a copy of BackupPolicy
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? hours = null,}) {
+  return _then(BackupPolicy_Interval(
+hours: null == hours ? _self.hours : hours // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
 /// @nodoc
 mixin _$BlockchainExplorer {
 
@@ -206,6 +368,168 @@ as bool,
 }
 
 
+}
+
+/// @nodoc
+mixin _$DecodedInvoice {
+
+ Object get invoice;
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DecodedInvoice&&const DeepCollectionEquality().equals(other.invoice, invoice));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(invoice));
+
+@override
+String toString() {
+  return 'DecodedInvoice(invoice: $invoice)';
+}
+
+
+}
+
+/// @nodoc
+class $DecodedInvoiceCopyWith<$Res>  {
+$DecodedInvoiceCopyWith(DecodedInvoice _, $Res Function(DecodedInvoice) __);
+}
+
+
+/// @nodoc
+
+
+class DecodedInvoice_Bolt11 extends DecodedInvoice {
+  const DecodedInvoice_Bolt11({required this.invoice}): super._();
+  
+
+@override final  LNInvoice invoice;
+
+/// Create a copy of DecodedInvoice
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DecodedInvoice_Bolt11CopyWith<DecodedInvoice_Bolt11> get copyWith => _$DecodedInvoice_Bolt11CopyWithImpl<DecodedInvoice_Bolt11>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DecodedInvoice_Bolt11&&(identical(other.invoice, invoice) || other.invoice == invoice));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,invoice);
+
+@override
+String toString() {
+  return 'DecodedInvoice.bolt11(invoice: $invoice)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DecodedInvoice_Bolt11CopyWith<$Res> implements $DecodedInvoiceCopyWith<$Res> {
+  factory $DecodedInvoice_Bolt11CopyWith(DecodedInvoice_Bolt11 value, $Res Function(DecodedInvoice_Bolt11) _then) = _$DecodedInvoice_Bolt11CopyWithImpl;
+@useResult
+$Res call({
+ LNInvoice invoice
+});
+
+
+
+
+}
+/// @nodoc
+class _$DecodedInvoice_Bolt11CopyWithImpl<$Res>
+    implements $DecodedInvoice_Bolt11CopyWith<$Res> {
+  _$DecodedInvoice_Bolt11CopyWithImpl(this._self, this._then);
+
+  final DecodedInvoice_Bolt11 _self;
+  final $Res Function(DecodedInvoice_Bolt11) _then;
+
+/// Create a copy of DecodedInvoice
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? invoice = null,}) {
+  return _then(DecodedInvoice_Bolt11(
+invoice: null == invoice ? _self.invoice : invoice // ignore: cast_nullable_to_non_nullable
+as LNInvoice,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class DecodedInvoice_Bolt12 extends DecodedInvoice {
+  const DecodedInvoice_Bolt12({required this.invoice}): super._();
+  
+
+@override final  LNBolt12Invoice invoice;
+
+/// Create a copy of DecodedInvoice
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$DecodedInvoice_Bolt12CopyWith<DecodedInvoice_Bolt12> get copyWith => _$DecodedInvoice_Bolt12CopyWithImpl<DecodedInvoice_Bolt12>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is DecodedInvoice_Bolt12&&(identical(other.invoice, invoice) || other.invoice == invoice));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,invoice);
+
+@override
+String toString() {
+  return 'DecodedInvoice.bolt12(invoice: $invoice)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $DecodedInvoice_Bolt12CopyWith<$Res> implements $DecodedInvoiceCopyWith<$Res> {
+  factory $DecodedInvoice_Bolt12CopyWith(DecodedInvoice_Bolt12 value, $Res Function(DecodedInvoice_Bolt12) _then) = _$DecodedInvoice_Bolt12CopyWithImpl;
+@useResult
+$Res call({
+ LNBolt12Invoice invoice
+});
+
+
+
+
+}
+/// @nodoc
+class _$DecodedInvoice_Bolt12CopyWithImpl<$Res>
+    implements $DecodedInvoice_Bolt12CopyWith<$Res> {
+  _$DecodedInvoice_Bolt12CopyWithImpl(this._self, this._then);
+
+  final DecodedInvoice_Bolt12 _self;
+  final $Res Function(DecodedInvoice_Bolt12) _then;
+
+/// Create a copy of DecodedInvoice
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? invoice = null,}) {
+  return _then(DecodedInvoice_Bolt12(
+invoice: null == invoice ? _self.invoice : invoice // ignore: cast_nullable_to_non_nullable
+as LNBolt12Invoice,
+  ));
+}
+
+
 }
 
 /// @nodoc
@@ -934,45 +1258,113 @@ as bool?,
 /// @nodoc
 
 
-class PayAmount_Drain extends PayAmount {
-  const PayAmount_Drain(): super._();
+class PayAmount_AssetUnits extends PayAmount {
+  const PayAmount_AssetUnits({required this.receiverAmount, this.estimateAssetFees}): super._();
   
 
+ final  AssetAmount receiverAmount;
+ final  bool? estimateAssetFees;
 
-
+/// Create a copy of PayAmount
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PayAmount_AssetUnitsCopyWith<PayAmount_AssetUnits> get copyWith => _$PayAmount_AssetUnitsCopyWithImpl<PayAmount_AssetUnits>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is PayAmount_Drain);
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PayAmount_AssetUnits&&(identical(other.receiverAmount, receiverAmount) || other.receiverAmount == receiverAmount)&&(identical(other.estimateAssetFees, estimateAssetFees) || other.estimateAssetFees == estimateAssetFees));
 }
 
 
 @override
-int get hashCode => runtimeType.hashCode;
+int get hashCode => Object.hash(runtimeType,receiverAmount,estimateAssetFees);
 
 @override
 String toString() {
-  return 'PayAmount.drain()';
+  return 'PayAmount.assetUnits(receiverAmount: $receiverAmount, estimateAssetFees: $estimateAssetFees)';
 }
 
 
 }
 
+/// @nodoc
+abstract mixin class $PayAmount_AssetUnitsCopyWith<$Res> implements $PayAmountCopyWith<$Res> {
+  factory $PayAmount_AssetUnitsCopyWith(PayAmount_AssetUnits value, $Res Function(PayAmount_AssetUnits) _then) = _$PayAmount_AssetUnitsCopyWithImpl;
+@useResult
+$Res call({
+ AssetAmount receiverAmount, bool? estimateAssetFees
+});
 
 
 
-/// @nodoc
-mixin _$PaymentDetails {
 
-/// Represents the invoice description
- String get description;
-/// Create a copy of PaymentDetails
-/// with the given fields replaced by the non-null parameter values.
-@JsonKey(includeFromJson: false, includeToJson: false)
-@pragma('vm:prefer-inline')
-$PaymentDetailsCopyWith<PaymentDetails> get copyWith => _$PaymentDetailsCopyWithImpl<PaymentDetails>(this as PaymentDetails, _$identity);
+}
+/// @nodoc
+class _$PayAmount_AssetUnitsCopyWithImpl<$Res>
+    implements $PayAmount_AssetUnitsCopyWith<$Res> {
+  _$PayAmount_AssetUnitsCopyWithImpl(this._self, this._then);
+
+  final PayAmount_AssetUnits _self;
+  final $Res Function(PayAmount_AssetUnits) _then;
+
+/// Create a copy of PayAmount
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? receiverAmount = null,Object? estimateAssetFees = freezed,}) {
+  return _then(PayAmount_AssetUnits(
+receiverAmount: null == receiverAmount ? _self.receiverAmount : receiverAmount // ignore: cast_nullable_to_non_nullable
+as AssetAmount,estimateAssetFees: freezed == estimateAssetFees ? _self.estimateAssetFees : estimateAssetFees // ignore: cast_nullable_to_non_nullable
+as bool?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class PayAmount_Drain extends PayAmount {
+  const PayAmount_Drain(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PayAmount_Drain);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'PayAmount.drain()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+mixin _$PaymentDetails {
+
+/// Represents the invoice description
+ String get description;
+/// Create a copy of PaymentDetails
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$PaymentDetailsCopyWith<PaymentDetails> get copyWith => _$PaymentDetailsCopyWithImpl<PaymentDetails>(this as PaymentDetails, _$identity);
 
 
 
@@ -1132,7 +1524,7 @@ as BigInt?,
 
 
 class PaymentDetails_Liquid extends PaymentDetails {
-  const PaymentDetails_Liquid({required this.destination, required this.description, required this.assetId, this.assetInfo, this.lnurlInfo, this.bip353Address}): super._();
+  const PaymentDetails_Liquid({required this.destination, required this.description, required this.assetId, this.assetInfo, this.lnurlInfo, this.bip353Address, this.conversion}): super._();
   
 
 /// Represents either a Liquid BIP21 URI or pure address
@@ -1147,6 +1539,8 @@ class PaymentDetails_Liquid extends PaymentDetails {
  final  LnUrlInfo? lnurlInfo;
 /// The BIP353 address used to resolve this payment
  final  String? bip353Address;
+/// The pair of asset amounts exchanged, if the payment is an asset conversion
+ final  AssetConversion? conversion;
 
 /// Create a copy of PaymentDetails
 /// with the given fields replaced by the non-null parameter values.
@@ -1158,16 +1552,16 @@ $PaymentDetails_LiquidCopyWith<PaymentDetails_Liquid> get copyWith => _$PaymentD
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is PaymentDetails_Liquid&&(identical(other.destination, destination) || other.destination == destination)&&(identical(other.description, description) || other.description == description)&&(identical(other.assetId, assetId) || other.assetId == assetId)&&(identical(other.assetInfo, assetInfo) || other.assetInfo == assetInfo)&&(identical(other.lnurlInfo, lnurlInfo) || other.lnurlInfo == lnurlInfo)&&(identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PaymentDetails_Liquid&&(identical(other.destination, destination) || other.destination == destination)&&(identical(other.description, description) || other.description == description)&&(identical(other.assetId, assetId) || other.assetId == assetId)&&(identical(other.assetInfo, assetInfo) || other.assetInfo == assetInfo)&&(identical(other.lnurlInfo, lnurlInfo) || other.lnurlInfo == lnurlInfo)&&(identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address)&&(identical(other.conversion, conversion) || other.conversion == conversion));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,destination,description,assetId,assetInfo,lnurlInfo,bip353Address);
+int get hashCode => Object.hash(runtimeType,destination,description,assetId,assetInfo,lnurlInfo,bip353Address,conversion);
 
 @override
 String toString() {
-  return 'PaymentDetails.liquid(destination: $destination, description: $description, assetId: $assetId, assetInfo: $assetInfo, lnurlInfo: $lnurlInfo, bip353Address: $bip353Address)';
+  return 'PaymentDetails.liquid(destination: $destination, description: $description, assetId: $assetId, assetInfo: $assetInfo, lnurlInfo: $lnurlInfo, bip353Address: $bip353Address, conversion: $conversion)';
 }
 
 
@@ -1178,7 +1572,7 @@ abstract mixin class $PaymentDetails_LiquidCopyWith<$Res> implements $PaymentDet
   factory $PaymentDetails_LiquidCopyWith(PaymentDetails_Liquid value, $Res Function(PaymentDetails_Liquid) _then) = _$PaymentDetails_LiquidCopyWithImpl;
 @override @useResult
 $Res call({
- String destination, String description, String assetId, AssetInfo? assetInfo, LnUrlInfo? lnurlInfo, String? bip353Address
+ String destination, String description, String assetId, AssetInfo? assetInfo, LnUrlInfo? lnurlInfo, String? bip353Address, AssetConversion? conversion
 });
 
 
@@ -1195,7 +1589,7 @@ class _$PaymentDetails_LiquidCopyWithImpl<$Res>
 
 /// Create a copy of PaymentDetails
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? destination = null,Object? description = null,Object? assetId = null,Object? assetInfo = freezed,Object? lnurlInfo = freezed,Object? bip353Address = freezed,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? destination = null,Object? description = null,Object? assetId = null,Object? assetInfo = freezed,Object? lnurlInfo = freezed,Object? bip353Address = freezed,Object? conversion = freezed,}) {
   return _then(PaymentDetails_Liquid(
 destination: null == destination ? _self.destination : destination // ignore: cast_nullable_to_non_nullable
 as String,description: null == description ? _self.description : description // ignore: cast_nullable_to_non_nullable
@@ -1203,7 +1597,8 @@ as String,assetId: null == assetId ? _self.assetId : assetId // ignore: cast_nul
 as String,assetInfo: freezed == assetInfo ? _self.assetInfo : assetInfo // ignore: cast_nullable_to_non_nullable
 as AssetInfo?,lnurlInfo: freezed == lnurlInfo ? _self.lnurlInfo : lnurlInfo // ignore: cast_nullable_to_non_nullable
 as LnUrlInfo?,bip353Address: freezed == bip353Address ? _self.bip353Address : bip353Address // ignore: cast_nullable_to_non_nullable
-as String?,
+as String?,conversion: freezed == conversion ? _self.conversion : conversion // ignore: cast_nullable_to_non_nullable
+as AssetConversion?,
   ));
 }
 
@@ -1214,7 +1609,7 @@ as String?,
 
 
 class PaymentDetails_Bitcoin extends PaymentDetails {
-  const PaymentDetails_Bitcoin({required this.swapId, required this.description, required this.autoAcceptedFees, this.liquidExpirationBlockheight, this.bitcoinExpirationBlockheight, this.claimTxId, this.refundTxId, this.refundTxAmountSat}): super._();
+  const PaymentDetails_Bitcoin({required this.swapId, required this.description, required this.autoAcceptedFees, this.liquidExpirationBlockheight, this.bitcoinExpirationBlockheight, this.claimTxId, this.refundTxId, this.refundTxAmountSat, this.buyBitcoinProvider}): super._();
   
 
  final  String swapId;
@@ -1236,6 +1631,9 @@ class PaymentDetails_Bitcoin extends PaymentDetails {
  final  String? refundTxId;
 /// For a Send swap which was refunded, this is the refund amount
  final  BigInt? refundTxAmountSat;
+/// For a Bitcoin bought via [crate::sdk::LiquidSdk::buy_bitcoin], this is the provider
+/// the Bitcoin was bought from
+ final  BuyBitcoinProvider? buyBitcoinProvider;
 
 /// Create a copy of PaymentDetails
 /// with the given fields replaced by the non-null parameter values.
@@ -1247,16 +1645,16 @@ $PaymentDetails_BitcoinCopyWith<PaymentDetails_Bitcoin> get copyWith => _$Paymen
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is PaymentDetails_Bitcoin&&(identical(other.swapId, swapId) || other.swapId == swapId)&&(identical(other.description, description) || other.description == description)&&(identical(other.autoAcceptedFees, autoAcceptedFees) || other.autoAcceptedFees == autoAcceptedFees)&&(identical(other.liquidExpirationBlockheight, liquidExpirationBlockheight) || other.liquidExpirationBlockheight == liquidExpirationBlockheight)&&(identical(other.bitcoinExpirationBlockheight, bitcoinExpirationBlockheight) || other.bitcoinExpirationBlockheight == bitcoinExpirationBlockheight)&&(identical(other.claimTxId, claimTxId) || other.claimTxId == claimTxId)&&(identical(other.refundTxId, refundTxId) || other.refundTxId == refundTxId)&&(identical(other.refundTxAmountSat, refundTxAmountSat) || other.refundTxAmountSat == refundTxAmountSat));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is PaymentDetails_Bitcoin&&(identical(other.swapId, swapId) || other.swapId == swapId)&&(identical(other.description, description) || other.description == description)&&(identical(other.autoAcceptedFees, autoAcceptedFees) || other.autoAcceptedFees == autoAcceptedFees)&&(identical(other.liquidExpirationBlockheight, liquidExpirationBlockheight) || other.liquidExpirationBlockheight == liquidExpirationBlockheight)&&(identical(other.bitcoinExpirationBlockheight, bitcoinExpirationBlockheight) || other.bitcoinExpirationBlockheight == bitcoinExpirationBlockheight)&&(identical(other.claimTxId, claimTxId) || other.claimTxId == claimTxId)&&(identical(other.refundTxId, refundTxId) || other.refundTxId == refundTxId)&&(identical(other.refundTxAmountSat, refundTxAmountSat) || other.refundTxAmountSat == refundTxAmountSat)&&(identical(other.buyBitcoinProvider, buyBitcoinProvider) || other.buyBitcoinProvider == buyBitcoinProvider));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,swapId,description,autoAcceptedFees,liquidExpirationBlockheight,bitcoinExpirationBlockheight,claimTxId,refundTxId,refundTxAmountSat);
+int get hashCode => Object.hash(runtimeType,swapId,description,autoAcceptedFees,liquidExpirationBlockheight,bitcoinExpirationBlockheight,claimTxId,refundTxId,refundTxAmountSat,buyBitcoinProvider);
 
 @override
 String toString() {
-  return 'PaymentDetails.bitcoin(swapId: $swapId, description: $description, autoAcceptedFees: $autoAcceptedFees, liquidExpirationBlockheight: $liquidExpirationBlockheight, bitcoinExpirationBlockheight: $bitcoinExpirationBlockheight, claimTxId: $claimTxId, refundTxId: $refundTxId, refundTxAmountSat: $refundTxAmountSat)';
+  return 'PaymentDetails.bitcoin(swapId: $swapId, description: $description, autoAcceptedFees: $autoAcceptedFees, liquidExpirationBlockheight: $liquidExpirationBlockheight, bitcoinExpirationBlockheight: $bitcoinExpirationBlockheight, claimTxId: $claimTxId, refundTxId: $refundTxId, refundTxAmountSat: $refundTxAmountSat, buyBitcoinProvider: $buyBitcoinProvider)';
 }
 
 
@@ -1267,7 +1665,7 @@ abstract mixin class $PaymentDetails_BitcoinCopyWith<$Res> implements $PaymentDe
   factory $PaymentDetails_BitcoinCopyWith(PaymentDetails_Bitcoin value, $Res Function(PaymentDetails_Bitcoin) _then) = _$PaymentDetails_BitcoinCopyWithImpl;
 @override @useResult
 $Res call({
- String swapId, String description, bool autoAcceptedFees, int? liquidExpirationBlockheight, int? bitcoinExpirationBlockheight, String? claimTxId, String? refundTxId, BigInt? refundTxAmountSat
+ String swapId, String description, bool autoAcceptedFees, int? liquidExpirationBlockheight, int? bitcoinExpirationBlockheight, String? claimTxId, String? refundTxId, BigInt? refundTxAmountSat, BuyBitcoinProvider? buyBitcoinProvider
 });
 
 
@@ -1284,7 +1682,7 @@ class _$PaymentDetails_BitcoinCopyWithImpl<$Res>
 
 /// Create a copy of PaymentDetails
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? swapId = null,Object? description = null,Object? autoAcceptedFees = null,Object? liquidExpirationBlockheight = freezed,Object? bitcoinExpirationBlockheight = freezed,Object? claimTxId = freezed,Object? refundTxId = freezed,Object? refundTxAmountSat = freezed,}) {
+@override @pragma('vm:prefer-inline') $Res call({Object? swapId = null,Object? description = null,Object? autoAcceptedFees = null,Object? liquidExpirationBlockheight = freezed,Object? bitcoinExpirationBlockheight = freezed,Object? claimTxId = freezed,Object? refundTxId = freezed,Object? refundTxAmountSat = freezed,Object? buyBitcoinProvider = freezed,}) {
   return _then(PaymentDetails_Bitcoin(
 swapId: null == swapId ? _self.swapId : swapId // ignore: cast_nullable_to_non_nullable
 as String,description: null == description ? _self.description : description // ignore: cast_nullable_to_non_nullable
@@ -1294,7 +1692,8 @@ as int?,bitcoinExpirationBlockheight: freezed == bitcoinExpirationBlockheight ?
 as int?,claimTxId: freezed == claimTxId ? _self.claimTxId : claimTxId // ignore: cast_nullable_to_non_nullable
 as String?,refundTxId: freezed == refundTxId ? _self.refundTxId : refundTxId // ignore: cast_nullable_to_non_nullable
 as String?,refundTxAmountSat: freezed == refundTxAmountSat ? _self.refundTxAmountSat : refundTxAmountSat // ignore: cast_nullable_to_non_nullable
-as BigInt?,
+as BigInt?,buyBitcoinProvider: freezed == buyBitcoinProvider ? _self.buyBitcoinProvider : buyBitcoinProvider // ignore: cast_nullable_to_non_nullable
+as BuyBitcoinProvider?,
   ));
 }
 
@@ -1463,6 +1862,72 @@ as double?,
 }
 
 
+}
+
+/// @nodoc
+
+
+class ReceiveAmount_AssetUnits extends ReceiveAmount {
+  const ReceiveAmount_AssetUnits({required this.payerAmount}): super._();
+  
+
+ final  AssetAmount payerAmount;
+
+/// Create a copy of ReceiveAmount
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ReceiveAmount_AssetUnitsCopyWith<ReceiveAmount_AssetUnits> get copyWith => _$ReceiveAmount_AssetUnitsCopyWithImpl<ReceiveAmount_AssetUnits>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ReceiveAmount_AssetUnits&&(identical(other.payerAmount, payerAmount) || other.payerAmount == payerAmount));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,payerAmount);
+
+@override
+String toString() {
+  return 'ReceiveAmount.assetUnits(payerAmount: $payerAmount)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ReceiveAmount_AssetUnitsCopyWith<$Res> implements $ReceiveAmountCopyWith<$Res> {
+  factory $ReceiveAmount_AssetUnitsCopyWith(ReceiveAmount_AssetUnits value, $Res Function(ReceiveAmount_AssetUnits) _then) = _$ReceiveAmount_AssetUnitsCopyWithImpl;
+@useResult
+$Res call({
+ AssetAmount payerAmount
+});
+
+
+
+
+}
+/// @nodoc
+class _$ReceiveAmount_AssetUnitsCopyWithImpl<$Res>
+    implements $ReceiveAmount_AssetUnitsCopyWith<$Res> {
+  _$ReceiveAmount_AssetUnitsCopyWithImpl(this._self, this._then);
+
+  final ReceiveAmount_AssetUnits _self;
+  final $Res Function(ReceiveAmount_AssetUnits) _then;
+
+/// Create a copy of ReceiveAmount
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? payerAmount = null,}) {
+  return _then(ReceiveAmount_AssetUnits(
+payerAmount: null == payerAmount ? _self.payerAmount : payerAmount // ignore: cast_nullable_to_non_nullable
+as AssetAmount,
+  ));
+}
+
+
 }
 
 /// @nodoc
@@ -2026,76 +2491,109 @@ as Payment,
 /// @nodoc
 
 
-class SdkEvent_Synced extends SdkEvent {
-  const SdkEvent_Synced(): super._();
+class SdkEvent_PaymentExpired extends SdkEvent {
+  const SdkEvent_PaymentExpired({required this.details}): super._();
   
 
+ final  Payment details;
 
-
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_PaymentExpiredCopyWith<SdkEvent_PaymentExpired> get copyWith => _$SdkEvent_PaymentExpiredCopyWithImpl<SdkEvent_PaymentExpired>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_Synced);
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_PaymentExpired&&(identical(other.details, details) || other.details == details));
 }
 
 
 @override
-int get hashCode => runtimeType.hashCode;
+int get hashCode => Object.hash(runtimeType,details);
 
 @override
 String toString() {
-  return 'SdkEvent.synced()';
+  return 'SdkEvent.paymentExpired(details: $details)';
 }
 
 
 }
 
+/// @nodoc
+abstract mixin class $SdkEvent_PaymentExpiredCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_PaymentExpiredCopyWith(SdkEvent_PaymentExpired value, $Res Function(SdkEvent_PaymentExpired) _then) = _$SdkEvent_PaymentExpiredCopyWithImpl;
+@useResult
+$Res call({
+ Payment details
+});
+
 
 
 
+}
 /// @nodoc
+class _$SdkEvent_PaymentExpiredCopyWithImpl<$Res>
+    implements $SdkEvent_PaymentExpiredCopyWith<$Res> {
+  _$SdkEvent_PaymentExpiredCopyWithImpl(this._self, this._then);
 
+  final SdkEvent_PaymentExpired _self;
+  final $Res Function(SdkEvent_PaymentExpired) _then;
 
-class SdkEvent_DataSynced extends SdkEvent {
-  const SdkEvent_DataSynced({required this.didPullNewRecords}): super._();
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(SdkEvent_PaymentExpired(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as Payment,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_PaymentReorged extends SdkEvent {
+  const SdkEvent_PaymentReorged({required this.details}): super._();
   
 
-/// Indicates new data was pulled from other instances.
- final  bool didPullNewRecords;
+ final  Payment details;
 
 /// Create a copy of SdkEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$SdkEvent_DataSyncedCopyWith<SdkEvent_DataSynced> get copyWith => _$SdkEvent_DataSyncedCopyWithImpl<SdkEvent_DataSynced>(this, _$identity);
+$SdkEvent_PaymentReorgedCopyWith<SdkEvent_PaymentReorged> get copyWith => _$SdkEvent_PaymentReorgedCopyWithImpl<SdkEvent_PaymentReorged>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_DataSynced&&(identical(other.didPullNewRecords, didPullNewRecords) || other.didPullNewRecords == didPullNewRecords));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_PaymentReorged&&(identical(other.details, details) || other.details == details));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,didPullNewRecords);
+int get hashCode => Object.hash(runtimeType,details);
 
 @override
 String toString() {
-  return 'SdkEvent.dataSynced(didPullNewRecords: $didPullNewRecords)';
+  return 'SdkEvent.paymentReorged(details: $details)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $SdkEvent_DataSyncedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
-  factory $SdkEvent_DataSyncedCopyWith(SdkEvent_DataSynced value, $Res Function(SdkEvent_DataSynced) _then) = _$SdkEvent_DataSyncedCopyWithImpl;
+abstract mixin class $SdkEvent_PaymentReorgedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_PaymentReorgedCopyWith(SdkEvent_PaymentReorged value, $Res Function(SdkEvent_PaymentReorged) _then) = _$SdkEvent_PaymentReorgedCopyWithImpl;
 @useResult
 $Res call({
- bool didPullNewRecords
+ Payment details
 });
 
 
@@ -2103,19 +2601,19 @@ $Res call({
 
 }
 /// @nodoc
-class _$SdkEvent_DataSyncedCopyWithImpl<$Res>
-    implements $SdkEvent_DataSyncedCopyWith<$Res> {
-  _$SdkEvent_DataSyncedCopyWithImpl(this._self, this._then);
+class _$SdkEvent_PaymentReorgedCopyWithImpl<$Res>
+    implements $SdkEvent_PaymentReorgedCopyWith<$Res> {
+  _$SdkEvent_PaymentReorgedCopyWithImpl(this._self, this._then);
 
-  final SdkEvent_DataSynced _self;
-  final $Res Function(SdkEvent_DataSynced) _then;
+  final SdkEvent_PaymentReorged _self;
+  final $Res Function(SdkEvent_PaymentReorged) _then;
 
 /// Create a copy of SdkEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? didPullNewRecords = null,}) {
-  return _then(SdkEvent_DataSynced(
-didPullNewRecords: null == didPullNewRecords ? _self.didPullNewRecords : didPullNewRecords // ignore: cast_nullable_to_non_nullable
-as bool,
+@pragma('vm:prefer-inline') $Res call({Object? details = null,}) {
+  return _then(SdkEvent_PaymentReorged(
+details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as Payment,
   ));
 }
 
@@ -2123,41 +2621,47 @@ as bool,
 }
 
 /// @nodoc
-mixin _$SendDestination {
 
-/// A BIP353 address, in case one was used to resolve this Liquid address
- String? get bip353Address;
-/// Create a copy of SendDestination
+
+class SdkEvent_SwapUpdated extends SdkEvent {
+  const SdkEvent_SwapUpdated({required this.swapId, required this.swapType, required this.state}): super._();
+  
+
+ final  String swapId;
+ final  PaymentSwapType swapType;
+ final  PaymentState state;
+
+/// Create a copy of SdkEvent
 /// with the given fields replaced by the non-null parameter values.
 @JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$SendDestinationCopyWith<SendDestination> get copyWith => _$SendDestinationCopyWithImpl<SendDestination>(this as SendDestination, _$identity);
+$SdkEvent_SwapUpdatedCopyWith<SdkEvent_SwapUpdated> get copyWith => _$SdkEvent_SwapUpdatedCopyWithImpl<SdkEvent_SwapUpdated>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is SendDestination&&(identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_SwapUpdated&&(identical(other.swapId, swapId) || other.swapId == swapId)&&(identical(other.swapType, swapType) || other.swapType == swapType)&&(identical(other.state, state) || other.state == state));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,bip353Address);
+int get hashCode => Object.hash(runtimeType,swapId,swapType,state);
 
 @override
 String toString() {
-  return 'SendDestination(bip353Address: $bip353Address)';
+  return 'SdkEvent.swapUpdated(swapId: $swapId, swapType: $swapType, state: $state)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $SendDestinationCopyWith<$Res>  {
-  factory $SendDestinationCopyWith(SendDestination value, $Res Function(SendDestination) _then) = _$SendDestinationCopyWithImpl;
+abstract mixin class $SdkEvent_SwapUpdatedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_SwapUpdatedCopyWith(SdkEvent_SwapUpdated value, $Res Function(SdkEvent_SwapUpdated) _then) = _$SdkEvent_SwapUpdatedCopyWithImpl;
 @useResult
 $Res call({
- String? bip353Address
+ String swapId, PaymentSwapType swapType, PaymentState state
 });
 
 
@@ -2165,67 +2669,68 @@ $Res call({
 
 }
 /// @nodoc
-class _$SendDestinationCopyWithImpl<$Res>
-    implements $SendDestinationCopyWith<$Res> {
-  _$SendDestinationCopyWithImpl(this._self, this._then);
+class _$SdkEvent_SwapUpdatedCopyWithImpl<$Res>
+    implements $SdkEvent_SwapUpdatedCopyWith<$Res> {
+  _$SdkEvent_SwapUpdatedCopyWithImpl(this._self, this._then);
 
-  final SendDestination _self;
-  final $Res Function(SendDestination) _then;
+  final SdkEvent_SwapUpdated _self;
+  final $Res Function(SdkEvent_SwapUpdated) _then;
 
-/// Create a copy of SendDestination
+/// Create a copy of SdkEvent
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? bip353Address = freezed,}) {
-  return _then(_self.copyWith(
-bip353Address: freezed == bip353Address ? _self.bip353Address : bip353Address // ignore: cast_nullable_to_non_nullable
-as String?,
+@pragma('vm:prefer-inline') $Res call({Object? swapId = null,Object? swapType = null,Object? state = null,}) {
+  return _then(SdkEvent_SwapUpdated(
+swapId: null == swapId ? _self.swapId : swapId // ignore: cast_nullable_to_non_nullable
+as String,swapType: null == swapType ? _self.swapType : swapType // ignore: cast_nullable_to_non_nullable
+as PaymentSwapType,state: null == state ? _self.state : state // ignore: cast_nullable_to_non_nullable
+as PaymentState,
   ));
 }
 
-}
 
+}
 
 /// @nodoc
 
 
-class SendDestination_LiquidAddress extends SendDestination {
-  const SendDestination_LiquidAddress({required this.addressData, this.bip353Address}): super._();
+class SdkEvent_SwapClaimBroadcast extends SdkEvent {
+  const SdkEvent_SwapClaimBroadcast({required this.swapId, required this.txId}): super._();
   
 
- final  LiquidAddressData addressData;
-/// A BIP353 address, in case one was used to resolve this Liquid address
-@override final  String? bip353Address;
+ final  String swapId;
+ final  String txId;
 
-/// Create a copy of SendDestination
+/// Create a copy of SdkEvent
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$SendDestination_LiquidAddressCopyWith<SendDestination_LiquidAddress> get copyWith => _$SendDestination_LiquidAddressCopyWithImpl<SendDestination_LiquidAddress>(this, _$identity);
+$SdkEvent_SwapClaimBroadcastCopyWith<SdkEvent_SwapClaimBroadcast> get copyWith => _$SdkEvent_SwapClaimBroadcastCopyWithImpl<SdkEvent_SwapClaimBroadcast>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is SendDestination_LiquidAddress&&(identical(other.addressData, addressData) || other.addressData == addressData)&&(identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_SwapClaimBroadcast&&(identical(other.swapId, swapId) || other.swapId == swapId)&&(identical(other.txId, txId) || other.txId == txId));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,addressData,bip353Address);
+int get hashCode => Object.hash(runtimeType,swapId,txId);
 
 @override
 String toString() {
-  return 'SendDestination.liquidAddress(addressData: $addressData, bip353Address: $bip353Address)';
+  return 'SdkEvent.swapClaimBroadcast(swapId: $swapId, txId: $txId)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $SendDestination_LiquidAddressCopyWith<$Res> implements $SendDestinationCopyWith<$Res> {
-  factory $SendDestination_LiquidAddressCopyWith(SendDestination_LiquidAddress value, $Res Function(SendDestination_LiquidAddress) _then) = _$SendDestination_LiquidAddressCopyWithImpl;
-@override @useResult
+abstract mixin class $SdkEvent_SwapClaimBroadcastCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_SwapClaimBroadcastCopyWith(SdkEvent_SwapClaimBroadcast value, $Res Function(SdkEvent_SwapClaimBroadcast) _then) = _$SdkEvent_SwapClaimBroadcastCopyWithImpl;
+@useResult
 $Res call({
- LiquidAddressData addressData, String? bip353Address
+ String swapId, String txId
 });
 
 
@@ -2233,20 +2738,20 @@ $Res call({
 
 }
 /// @nodoc
-class _$SendDestination_LiquidAddressCopyWithImpl<$Res>
-    implements $SendDestination_LiquidAddressCopyWith<$Res> {
-  _$SendDestination_LiquidAddressCopyWithImpl(this._self, this._then);
+class _$SdkEvent_SwapClaimBroadcastCopyWithImpl<$Res>
+    implements $SdkEvent_SwapClaimBroadcastCopyWith<$Res> {
+  _$SdkEvent_SwapClaimBroadcastCopyWithImpl(this._self, this._then);
 
-  final SendDestination_LiquidAddress _self;
-  final $Res Function(SendDestination_LiquidAddress) _then;
+  final SdkEvent_SwapClaimBroadcast _self;
+  final $Res Function(SdkEvent_SwapClaimBroadcast) _then;
 
-/// Create a copy of SendDestination
+/// Create a copy of SdkEvent
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? addressData = null,Object? bip353Address = freezed,}) {
-  return _then(SendDestination_LiquidAddress(
-addressData: null == addressData ? _self.addressData : addressData // ignore: cast_nullable_to_non_nullable
-as LiquidAddressData,bip353Address: freezed == bip353Address ? _self.bip353Address : bip353Address // ignore: cast_nullable_to_non_nullable
-as String?,
+@pragma('vm:prefer-inline') $Res call({Object? swapId = null,Object? txId = null,}) {
+  return _then(SdkEvent_SwapClaimBroadcast(
+swapId: null == swapId ? _self.swapId : swapId // ignore: cast_nullable_to_non_nullable
+as String,txId: null == txId ? _self.txId : txId // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
@@ -2256,45 +2761,44 @@ as String?,
 /// @nodoc
 
 
-class SendDestination_Bolt11 extends SendDestination {
-  const SendDestination_Bolt11({required this.invoice, this.bip353Address}): super._();
+class SdkEvent_SwapRefundBroadcast extends SdkEvent {
+  const SdkEvent_SwapRefundBroadcast({required this.swapId, required this.txId}): super._();
   
 
- final  LNInvoice invoice;
-/// A BIP353 address, in case one was used to resolve this BOLT11
-@override final  String? bip353Address;
+ final  String swapId;
+ final  String txId;
 
-/// Create a copy of SendDestination
+/// Create a copy of SdkEvent
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
-$SendDestination_Bolt11CopyWith<SendDestination_Bolt11> get copyWith => _$SendDestination_Bolt11CopyWithImpl<SendDestination_Bolt11>(this, _$identity);
+$SdkEvent_SwapRefundBroadcastCopyWith<SdkEvent_SwapRefundBroadcast> get copyWith => _$SdkEvent_SwapRefundBroadcastCopyWithImpl<SdkEvent_SwapRefundBroadcast>(this, _$identity);
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is SendDestination_Bolt11&&(identical(other.invoice, invoice) || other.invoice == invoice)&&(identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_SwapRefundBroadcast&&(identical(other.swapId, swapId) || other.swapId == swapId)&&(identical(other.txId, txId) || other.txId == txId));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,invoice,bip353Address);
+int get hashCode => Object.hash(runtimeType,swapId,txId);
 
 @override
 String toString() {
-  return 'SendDestination.bolt11(invoice: $invoice, bip353Address: $bip353Address)';
+  return 'SdkEvent.swapRefundBroadcast(swapId: $swapId, txId: $txId)';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $SendDestination_Bolt11CopyWith<$Res> implements $SendDestinationCopyWith<$Res> {
-  factory $SendDestination_Bolt11CopyWith(SendDestination_Bolt11 value, $Res Function(SendDestination_Bolt11) _then) = _$SendDestination_Bolt11CopyWithImpl;
-@override @useResult
+abstract mixin class $SdkEvent_SwapRefundBroadcastCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_SwapRefundBroadcastCopyWith(SdkEvent_SwapRefundBroadcast value, $Res Function(SdkEvent_SwapRefundBroadcast) _then) = _$SdkEvent_SwapRefundBroadcastCopyWithImpl;
+@useResult
 $Res call({
- LNInvoice invoice, String? bip353Address
+ String swapId, String txId
 });
 
 
@@ -2302,20 +2806,20 @@ $Res call({
 
 }
 /// @nodoc
-class _$SendDestination_Bolt11CopyWithImpl<$Res>
-    implements $SendDestination_Bolt11CopyWith<$Res> {
-  _$SendDestination_Bolt11CopyWithImpl(this._self, this._then);
+class _$SdkEvent_SwapRefundBroadcastCopyWithImpl<$Res>
+    implements $SdkEvent_SwapRefundBroadcastCopyWith<$Res> {
+  _$SdkEvent_SwapRefundBroadcastCopyWithImpl(this._self, this._then);
 
-  final SendDestination_Bolt11 _self;
-  final $Res Function(SendDestination_Bolt11) _then;
+  final SdkEvent_SwapRefundBroadcast _self;
+  final $Res Function(SdkEvent_SwapRefundBroadcast) _then;
 
-/// Create a copy of SendDestination
+/// Create a copy of SdkEvent
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? invoice = null,Object? bip353Address = freezed,}) {
-  return _then(SendDestination_Bolt11(
-invoice: null == invoice ? _self.invoice : invoice // ignore: cast_nullable_to_non_nullable
-as LNInvoice,bip353Address: freezed == bip353Address ? _self.bip353Address : bip353Address // ignore: cast_nullable_to_non_nullable
-as String?,
+@pragma('vm:prefer-inline') $Res call({Object? swapId = null,Object? txId = null,}) {
+  return _then(SdkEvent_SwapRefundBroadcast(
+swapId: null == swapId ? _self.swapId : swapId // ignore: cast_nullable_to_non_nullable
+as String,txId: null == txId ? _self.txId : txId // ignore: cast_nullable_to_non_nullable
+as String,
   ));
 }
 
@@ -2325,12 +2829,1190 @@ as String?,
 /// @nodoc
 
 
-class SendDestination_Bolt12 extends SendDestination {
-  const SendDestination_Bolt12({required this.offer, required this.receiverAmountSat, this.bip353Address}): super._();
+class SdkEvent_TipsUpdated extends SdkEvent {
+  const SdkEvent_TipsUpdated({required this.liquidTip, required this.bitcoinTip}): super._();
   
 
- final  LNOffer offer;
- final  BigInt receiverAmountSat;
+ final  int liquidTip;
+ final  int bitcoinTip;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_TipsUpdatedCopyWith<SdkEvent_TipsUpdated> get copyWith => _$SdkEvent_TipsUpdatedCopyWithImpl<SdkEvent_TipsUpdated>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_TipsUpdated&&(identical(other.liquidTip, liquidTip) || other.liquidTip == liquidTip)&&(identical(other.bitcoinTip, bitcoinTip) || other.bitcoinTip == bitcoinTip));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,liquidTip,bitcoinTip);
+
+@override
+String toString() {
+  return 'SdkEvent.tipsUpdated(liquidTip: $liquidTip, bitcoinTip: $bitcoinTip)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_TipsUpdatedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_TipsUpdatedCopyWith(SdkEvent_TipsUpdated value, $Res Function(SdkEvent_TipsUpdated) _then) = _$SdkEvent_TipsUpdatedCopyWithImpl;
+@useResult
+$Res call({
+ int liquidTip, int bitcoinTip
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_TipsUpdatedCopyWithImpl<$Res>
+    implements $SdkEvent_TipsUpdatedCopyWith<$Res> {
+  _$SdkEvent_TipsUpdatedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_TipsUpdated _self;
+  final $Res Function(SdkEvent_TipsUpdated) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? liquidTip = null,Object? bitcoinTip = null,}) {
+  return _then(SdkEvent_TipsUpdated(
+liquidTip: null == liquidTip ? _self.liquidTip : liquidTip // ignore: cast_nullable_to_non_nullable
+as int,bitcoinTip: null == bitcoinTip ? _self.bitcoinTip : bitcoinTip // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_SyncStarted extends SdkEvent {
+  const SdkEvent_SyncStarted(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_SyncStarted);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SdkEvent.syncStarted()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class SdkEvent_Synced extends SdkEvent {
+  const SdkEvent_Synced(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_Synced);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SdkEvent.synced()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class SdkEvent_DataSynced extends SdkEvent {
+  const SdkEvent_DataSynced({required this.didPullNewRecords}): super._();
+  
+
+/// Indicates new data was pulled from other instances.
+ final  bool didPullNewRecords;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_DataSyncedCopyWith<SdkEvent_DataSynced> get copyWith => _$SdkEvent_DataSyncedCopyWithImpl<SdkEvent_DataSynced>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_DataSynced&&(identical(other.didPullNewRecords, didPullNewRecords) || other.didPullNewRecords == didPullNewRecords));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,didPullNewRecords);
+
+@override
+String toString() {
+  return 'SdkEvent.dataSynced(didPullNewRecords: $didPullNewRecords)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_DataSyncedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_DataSyncedCopyWith(SdkEvent_DataSynced value, $Res Function(SdkEvent_DataSynced) _then) = _$SdkEvent_DataSyncedCopyWithImpl;
+@useResult
+$Res call({
+ bool didPullNewRecords
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_DataSyncedCopyWithImpl<$Res>
+    implements $SdkEvent_DataSyncedCopyWith<$Res> {
+  _$SdkEvent_DataSyncedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_DataSynced _self;
+  final $Res Function(SdkEvent_DataSynced) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? didPullNewRecords = null,}) {
+  return _then(SdkEvent_DataSynced(
+didPullNewRecords: null == didPullNewRecords ? _self.didPullNewRecords : didPullNewRecords // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_ConnectivityChanged extends SdkEvent {
+  const SdkEvent_ConnectivityChanged({required this.isOnline}): super._();
+  
+
+ final  bool isOnline;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_ConnectivityChangedCopyWith<SdkEvent_ConnectivityChanged> get copyWith => _$SdkEvent_ConnectivityChangedCopyWithImpl<SdkEvent_ConnectivityChanged>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_ConnectivityChanged&&(identical(other.isOnline, isOnline) || other.isOnline == isOnline));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,isOnline);
+
+@override
+String toString() {
+  return 'SdkEvent.connectivityChanged(isOnline: $isOnline)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_ConnectivityChangedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_ConnectivityChangedCopyWith(SdkEvent_ConnectivityChanged value, $Res Function(SdkEvent_ConnectivityChanged) _then) = _$SdkEvent_ConnectivityChangedCopyWithImpl;
+@useResult
+$Res call({
+ bool isOnline
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_ConnectivityChangedCopyWithImpl<$Res>
+    implements $SdkEvent_ConnectivityChangedCopyWith<$Res> {
+  _$SdkEvent_ConnectivityChangedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_ConnectivityChanged _self;
+  final $Res Function(SdkEvent_ConnectivityChanged) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? isOnline = null,}) {
+  return _then(SdkEvent_ConnectivityChanged(
+isOnline: null == isOnline ? _self.isOnline : isOnline // ignore: cast_nullable_to_non_nullable
+as bool,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_BackupSucceeded extends SdkEvent {
+  const SdkEvent_BackupSucceeded(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_BackupSucceeded);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SdkEvent.backupSucceeded()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class SdkEvent_BackupFailed extends SdkEvent {
+  const SdkEvent_BackupFailed({required this.err}): super._();
+  
+
+ final  String err;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_BackupFailedCopyWith<SdkEvent_BackupFailed> get copyWith => _$SdkEvent_BackupFailedCopyWithImpl<SdkEvent_BackupFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_BackupFailed&&(identical(other.err, err) || other.err == err));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,err);
+
+@override
+String toString() {
+  return 'SdkEvent.backupFailed(err: $err)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_BackupFailedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_BackupFailedCopyWith(SdkEvent_BackupFailed value, $Res Function(SdkEvent_BackupFailed) _then) = _$SdkEvent_BackupFailedCopyWithImpl;
+@useResult
+$Res call({
+ String err
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_BackupFailedCopyWithImpl<$Res>
+    implements $SdkEvent_BackupFailedCopyWith<$Res> {
+  _$SdkEvent_BackupFailedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_BackupFailed _self;
+  final $Res Function(SdkEvent_BackupFailed) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? err = null,}) {
+  return _then(SdkEvent_BackupFailed(
+err: null == err ? _self.err : err // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_BatchSendCompleted extends SdkEvent {
+  const SdkEvent_BatchSendCompleted({required final  List<BatchSendItemResult> results}): _results = results,super._();
+  
+
+ final  List<BatchSendItemResult> _results;
+ List<BatchSendItemResult> get results {
+  if (_results is EqualityUnmodifiableListView) return _results;
+  // ignore: implicit_dynamic_type
+  return EqualityUnmodifiableListView(_results);
+}
+
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_BatchSendCompletedCopyWith<SdkEvent_BatchSendCompleted> get copyWith => _$SdkEvent_BatchSendCompletedCopyWithImpl<SdkEvent_BatchSendCompleted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_BatchSendCompleted&&const DeepCollectionEquality().equals(other._results, _results));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(_results));
+
+@override
+String toString() {
+  return 'SdkEvent.batchSendCompleted(results: $results)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_BatchSendCompletedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_BatchSendCompletedCopyWith(SdkEvent_BatchSendCompleted value, $Res Function(SdkEvent_BatchSendCompleted) _then) = _$SdkEvent_BatchSendCompletedCopyWithImpl;
+@useResult
+$Res call({
+ List<BatchSendItemResult> results
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_BatchSendCompletedCopyWithImpl<$Res>
+    implements $SdkEvent_BatchSendCompletedCopyWith<$Res> {
+  _$SdkEvent_BatchSendCompletedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_BatchSendCompleted _self;
+  final $Res Function(SdkEvent_BatchSendCompleted) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? results = null,}) {
+  return _then(SdkEvent_BatchSendCompleted(
+results: null == results ? _self._results : results // ignore: cast_nullable_to_non_nullable
+as List<BatchSendItemResult>,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_ScheduledPaymentExecuted extends SdkEvent {
+  const SdkEvent_ScheduledPaymentExecuted({required this.id, required this.details}): super._();
+  
+
+ final  String id;
+ final  Payment details;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_ScheduledPaymentExecutedCopyWith<SdkEvent_ScheduledPaymentExecuted> get copyWith => _$SdkEvent_ScheduledPaymentExecutedCopyWithImpl<SdkEvent_ScheduledPaymentExecuted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_ScheduledPaymentExecuted&&(identical(other.id, id) || other.id == id)&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,details);
+
+@override
+String toString() {
+  return 'SdkEvent.scheduledPaymentExecuted(id: $id, details: $details)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_ScheduledPaymentExecutedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_ScheduledPaymentExecutedCopyWith(SdkEvent_ScheduledPaymentExecuted value, $Res Function(SdkEvent_ScheduledPaymentExecuted) _then) = _$SdkEvent_ScheduledPaymentExecutedCopyWithImpl;
+@useResult
+$Res call({
+ String id, Payment details
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_ScheduledPaymentExecutedCopyWithImpl<$Res>
+    implements $SdkEvent_ScheduledPaymentExecutedCopyWith<$Res> {
+  _$SdkEvent_ScheduledPaymentExecutedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_ScheduledPaymentExecuted _self;
+  final $Res Function(SdkEvent_ScheduledPaymentExecuted) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? id = null,Object? details = null,}) {
+  return _then(SdkEvent_ScheduledPaymentExecuted(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as Payment,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_ScheduledPaymentFailed extends SdkEvent {
+  const SdkEvent_ScheduledPaymentFailed({required this.id, required this.err}): super._();
+  
+
+ final  String id;
+ final  String err;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_ScheduledPaymentFailedCopyWith<SdkEvent_ScheduledPaymentFailed> get copyWith => _$SdkEvent_ScheduledPaymentFailedCopyWithImpl<SdkEvent_ScheduledPaymentFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_ScheduledPaymentFailed&&(identical(other.id, id) || other.id == id)&&(identical(other.err, err) || other.err == err));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,id,err);
+
+@override
+String toString() {
+  return 'SdkEvent.scheduledPaymentFailed(id: $id, err: $err)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_ScheduledPaymentFailedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_ScheduledPaymentFailedCopyWith(SdkEvent_ScheduledPaymentFailed value, $Res Function(SdkEvent_ScheduledPaymentFailed) _then) = _$SdkEvent_ScheduledPaymentFailedCopyWithImpl;
+@useResult
+$Res call({
+ String id, String err
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_ScheduledPaymentFailedCopyWithImpl<$Res>
+    implements $SdkEvent_ScheduledPaymentFailedCopyWith<$Res> {
+  _$SdkEvent_ScheduledPaymentFailedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_ScheduledPaymentFailed _self;
+  final $Res Function(SdkEvent_ScheduledPaymentFailed) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? id = null,Object? err = null,}) {
+  return _then(SdkEvent_ScheduledPaymentFailed(
+id: null == id ? _self.id : id // ignore: cast_nullable_to_non_nullable
+as String,err: null == err ? _self.err : err // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_RecurringPaymentExecuted extends SdkEvent {
+  const SdkEvent_RecurringPaymentExecuted({required this.recurringPaymentId, required this.details}): super._();
+  
+
+ final  String recurringPaymentId;
+ final  Payment details;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_RecurringPaymentExecutedCopyWith<SdkEvent_RecurringPaymentExecuted> get copyWith => _$SdkEvent_RecurringPaymentExecutedCopyWithImpl<SdkEvent_RecurringPaymentExecuted>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_RecurringPaymentExecuted&&(identical(other.recurringPaymentId, recurringPaymentId) || other.recurringPaymentId == recurringPaymentId)&&(identical(other.details, details) || other.details == details));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,recurringPaymentId,details);
+
+@override
+String toString() {
+  return 'SdkEvent.recurringPaymentExecuted(recurringPaymentId: $recurringPaymentId, details: $details)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_RecurringPaymentExecutedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_RecurringPaymentExecutedCopyWith(SdkEvent_RecurringPaymentExecuted value, $Res Function(SdkEvent_RecurringPaymentExecuted) _then) = _$SdkEvent_RecurringPaymentExecutedCopyWithImpl;
+@useResult
+$Res call({
+ String recurringPaymentId, Payment details
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_RecurringPaymentExecutedCopyWithImpl<$Res>
+    implements $SdkEvent_RecurringPaymentExecutedCopyWith<$Res> {
+  _$SdkEvent_RecurringPaymentExecutedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_RecurringPaymentExecuted _self;
+  final $Res Function(SdkEvent_RecurringPaymentExecuted) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? recurringPaymentId = null,Object? details = null,}) {
+  return _then(SdkEvent_RecurringPaymentExecuted(
+recurringPaymentId: null == recurringPaymentId ? _self.recurringPaymentId : recurringPaymentId // ignore: cast_nullable_to_non_nullable
+as String,details: null == details ? _self.details : details // ignore: cast_nullable_to_non_nullable
+as Payment,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_RecurringPaymentFailed extends SdkEvent {
+  const SdkEvent_RecurringPaymentFailed({required this.recurringPaymentId, required this.err}): super._();
+  
+
+ final  String recurringPaymentId;
+ final  String err;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_RecurringPaymentFailedCopyWith<SdkEvent_RecurringPaymentFailed> get copyWith => _$SdkEvent_RecurringPaymentFailedCopyWithImpl<SdkEvent_RecurringPaymentFailed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_RecurringPaymentFailed&&(identical(other.recurringPaymentId, recurringPaymentId) || other.recurringPaymentId == recurringPaymentId)&&(identical(other.err, err) || other.err == err));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,recurringPaymentId,err);
+
+@override
+String toString() {
+  return 'SdkEvent.recurringPaymentFailed(recurringPaymentId: $recurringPaymentId, err: $err)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_RecurringPaymentFailedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_RecurringPaymentFailedCopyWith(SdkEvent_RecurringPaymentFailed value, $Res Function(SdkEvent_RecurringPaymentFailed) _then) = _$SdkEvent_RecurringPaymentFailedCopyWithImpl;
+@useResult
+$Res call({
+ String recurringPaymentId, String err
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_RecurringPaymentFailedCopyWithImpl<$Res>
+    implements $SdkEvent_RecurringPaymentFailedCopyWith<$Res> {
+  _$SdkEvent_RecurringPaymentFailedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_RecurringPaymentFailed _self;
+  final $Res Function(SdkEvent_RecurringPaymentFailed) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? recurringPaymentId = null,Object? err = null,}) {
+  return _then(SdkEvent_RecurringPaymentFailed(
+recurringPaymentId: null == recurringPaymentId ? _self.recurringPaymentId : recurringPaymentId // ignore: cast_nullable_to_non_nullable
+as String,err: null == err ? _self.err : err // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_TransactionDropped extends SdkEvent {
+  const SdkEvent_TransactionDropped({required this.txId}): super._();
+  
+
+ final  String txId;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_TransactionDroppedCopyWith<SdkEvent_TransactionDropped> get copyWith => _$SdkEvent_TransactionDroppedCopyWithImpl<SdkEvent_TransactionDropped>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_TransactionDropped&&(identical(other.txId, txId) || other.txId == txId));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,txId);
+
+@override
+String toString() {
+  return 'SdkEvent.transactionDropped(txId: $txId)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_TransactionDroppedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_TransactionDroppedCopyWith(SdkEvent_TransactionDropped value, $Res Function(SdkEvent_TransactionDropped) _then) = _$SdkEvent_TransactionDroppedCopyWithImpl;
+@useResult
+$Res call({
+ String txId
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_TransactionDroppedCopyWithImpl<$Res>
+    implements $SdkEvent_TransactionDroppedCopyWith<$Res> {
+  _$SdkEvent_TransactionDroppedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_TransactionDropped _self;
+  final $Res Function(SdkEvent_TransactionDropped) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? txId = null,}) {
+  return _then(SdkEvent_TransactionDropped(
+txId: null == txId ? _self.txId : txId // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_TransactionUnconfirmed extends SdkEvent {
+  const SdkEvent_TransactionUnconfirmed({required this.txId, required this.unconfirmedSec}): super._();
+  
+
+ final  String txId;
+/// The number of seconds since the tx was first seen
+ final  int unconfirmedSec;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_TransactionUnconfirmedCopyWith<SdkEvent_TransactionUnconfirmed> get copyWith => _$SdkEvent_TransactionUnconfirmedCopyWithImpl<SdkEvent_TransactionUnconfirmed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_TransactionUnconfirmed&&(identical(other.txId, txId) || other.txId == txId)&&(identical(other.unconfirmedSec, unconfirmedSec) || other.unconfirmedSec == unconfirmedSec));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,txId,unconfirmedSec);
+
+@override
+String toString() {
+  return 'SdkEvent.transactionUnconfirmed(txId: $txId, unconfirmedSec: $unconfirmedSec)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_TransactionUnconfirmedCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_TransactionUnconfirmedCopyWith(SdkEvent_TransactionUnconfirmed value, $Res Function(SdkEvent_TransactionUnconfirmed) _then) = _$SdkEvent_TransactionUnconfirmedCopyWithImpl;
+@useResult
+$Res call({
+ String txId, int unconfirmedSec
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_TransactionUnconfirmedCopyWithImpl<$Res>
+    implements $SdkEvent_TransactionUnconfirmedCopyWith<$Res> {
+  _$SdkEvent_TransactionUnconfirmedCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_TransactionUnconfirmed _self;
+  final $Res Function(SdkEvent_TransactionUnconfirmed) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? txId = null,Object? unconfirmedSec = null,}) {
+  return _then(SdkEvent_TransactionUnconfirmed(
+txId: null == txId ? _self.txId : txId // ignore: cast_nullable_to_non_nullable
+as String,unconfirmedSec: null == unconfirmedSec ? _self.unconfirmedSec : unconfirmedSec // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_NewLiquidBlock extends SdkEvent {
+  const SdkEvent_NewLiquidBlock({required this.block}): super._();
+  
+
+ final  BlockInfo block;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_NewLiquidBlockCopyWith<SdkEvent_NewLiquidBlock> get copyWith => _$SdkEvent_NewLiquidBlockCopyWithImpl<SdkEvent_NewLiquidBlock>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_NewLiquidBlock&&(identical(other.block, block) || other.block == block));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,block);
+
+@override
+String toString() {
+  return 'SdkEvent.newLiquidBlock(block: $block)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_NewLiquidBlockCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_NewLiquidBlockCopyWith(SdkEvent_NewLiquidBlock value, $Res Function(SdkEvent_NewLiquidBlock) _then) = _$SdkEvent_NewLiquidBlockCopyWithImpl;
+@useResult
+$Res call({
+ BlockInfo block
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_NewLiquidBlockCopyWithImpl<$Res>
+    implements $SdkEvent_NewLiquidBlockCopyWith<$Res> {
+  _$SdkEvent_NewLiquidBlockCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_NewLiquidBlock _self;
+  final $Res Function(SdkEvent_NewLiquidBlock) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? block = null,}) {
+  return _then(SdkEvent_NewLiquidBlock(
+block: null == block ? _self.block : block // ignore: cast_nullable_to_non_nullable
+as BlockInfo,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SdkEvent_NewBitcoinBlock extends SdkEvent {
+  const SdkEvent_NewBitcoinBlock({required this.block}): super._();
+  
+
+ final  BlockInfo block;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SdkEvent_NewBitcoinBlockCopyWith<SdkEvent_NewBitcoinBlock> get copyWith => _$SdkEvent_NewBitcoinBlockCopyWithImpl<SdkEvent_NewBitcoinBlock>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SdkEvent_NewBitcoinBlock&&(identical(other.block, block) || other.block == block));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,block);
+
+@override
+String toString() {
+  return 'SdkEvent.newBitcoinBlock(block: $block)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SdkEvent_NewBitcoinBlockCopyWith<$Res> implements $SdkEventCopyWith<$Res> {
+  factory $SdkEvent_NewBitcoinBlockCopyWith(SdkEvent_NewBitcoinBlock value, $Res Function(SdkEvent_NewBitcoinBlock) _then) = _$SdkEvent_NewBitcoinBlockCopyWithImpl;
+@useResult
+$Res call({
+ BlockInfo block
+});
+
+
+
+
+}
+/// @nodoc
+class _$SdkEvent_NewBitcoinBlockCopyWithImpl<$Res>
+    implements $SdkEvent_NewBitcoinBlockCopyWith<$Res> {
+  _$SdkEvent_NewBitcoinBlockCopyWithImpl(this._self, this._then);
+
+  final SdkEvent_NewBitcoinBlock _self;
+  final $Res Function(SdkEvent_NewBitcoinBlock) _then;
+
+/// Create a copy of SdkEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? block = null,}) {
+  return _then(SdkEvent_NewBitcoinBlock(
+block: null == block ? _self.block : block // ignore: cast_nullable_to_non_nullable
+as BlockInfo,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$SendDestination {
+
+/// A BIP353 address, in case one was used to resolve this Liquid address
+ String? get bip353Address;
+/// Create a copy of SendDestination
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SendDestinationCopyWith<SendDestination> get copyWith => _$SendDestinationCopyWithImpl<SendDestination>(this as SendDestination, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SendDestination&&(identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,bip353Address);
+
+@override
+String toString() {
+  return 'SendDestination(bip353Address: $bip353Address)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SendDestinationCopyWith<$Res>  {
+  factory $SendDestinationCopyWith(SendDestination value, $Res Function(SendDestination) _then) = _$SendDestinationCopyWithImpl;
+@useResult
+$Res call({
+ String? bip353Address
+});
+
+
+
+
+}
+/// @nodoc
+class _$SendDestinationCopyWithImpl<$Res>
+    implements $SendDestinationCopyWith<$Res> {
+  _$SendDestinationCopyWithImpl(this._self, this._then);
+
+  final SendDestination _self;
+  final $Res Function(SendDestination) _then;
+
+/// Create a copy of SendDestination
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') @override $Res call({Object? bip353Address = freezed,}) {
+  return _then(_self.copyWith(
+bip353Address: freezed == bip353Address ? _self.bip353Address : bip353Address // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+}
+
+
+/// @nodoc
+
+
+class SendDestination_LiquidAddress extends SendDestination {
+  const SendDestination_LiquidAddress({required this.addressData, this.bip353Address, this.payjoinUrl}): super._();
+  
+
+ final  LiquidAddressData addressData;
+/// A BIP353 address, in case one was used to resolve this Liquid address
+@override final  String? bip353Address;
+/// The payjoin endpoint advertised with the `pj` parameter of the BIP21 URI. If set, an
+/// L-BTC payment is sent as a payjoin, falling back to a regular transaction on failure.
+ final  String? payjoinUrl;
+
+/// Create a copy of SendDestination
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SendDestination_LiquidAddressCopyWith<SendDestination_LiquidAddress> get copyWith => _$SendDestination_LiquidAddressCopyWithImpl<SendDestination_LiquidAddress>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SendDestination_LiquidAddress&&(identical(other.addressData, addressData) || other.addressData == addressData)&&(identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address)&&(identical(other.payjoinUrl, payjoinUrl) || other.payjoinUrl == payjoinUrl));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,addressData,bip353Address,payjoinUrl);
+
+@override
+String toString() {
+  return 'SendDestination.liquidAddress(addressData: $addressData, bip353Address: $bip353Address, payjoinUrl: $payjoinUrl)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SendDestination_LiquidAddressCopyWith<$Res> implements $SendDestinationCopyWith<$Res> {
+  factory $SendDestination_LiquidAddressCopyWith(SendDestination_LiquidAddress value, $Res Function(SendDestination_LiquidAddress) _then) = _$SendDestination_LiquidAddressCopyWithImpl;
+@override @useResult
+$Res call({
+ LiquidAddressData addressData, String? bip353Address, String? payjoinUrl
+});
+
+
+
+
+}
+/// @nodoc
+class _$SendDestination_LiquidAddressCopyWithImpl<$Res>
+    implements $SendDestination_LiquidAddressCopyWith<$Res> {
+  _$SendDestination_LiquidAddressCopyWithImpl(this._self, this._then);
+
+  final SendDestination_LiquidAddress _self;
+  final $Res Function(SendDestination_LiquidAddress) _then;
+
+/// Create a copy of SendDestination
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? addressData = null,Object? bip353Address = freezed,Object? payjoinUrl = freezed,}) {
+  return _then(SendDestination_LiquidAddress(
+addressData: null == addressData ? _self.addressData : addressData // ignore: cast_nullable_to_non_nullable
+as LiquidAddressData,bip353Address: freezed == bip353Address ? _self.bip353Address : bip353Address // ignore: cast_nullable_to_non_nullable
+as String?,payjoinUrl: freezed == payjoinUrl ? _self.payjoinUrl : payjoinUrl // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SendDestination_Bolt11 extends SendDestination {
+  const SendDestination_Bolt11({required this.invoice, this.bip353Address}): super._();
+  
+
+ final  LNInvoice invoice;
+/// A BIP353 address, in case one was used to resolve this BOLT11
+@override final  String? bip353Address;
+
+/// Create a copy of SendDestination
+/// with the given fields replaced by the non-null parameter values.
+@override @JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SendDestination_Bolt11CopyWith<SendDestination_Bolt11> get copyWith => _$SendDestination_Bolt11CopyWithImpl<SendDestination_Bolt11>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SendDestination_Bolt11&&(identical(other.invoice, invoice) || other.invoice == invoice)&&(identical(other.bip353Address, bip353Address) || other.bip353Address == bip353Address));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,invoice,bip353Address);
+
+@override
+String toString() {
+  return 'SendDestination.bolt11(invoice: $invoice, bip353Address: $bip353Address)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SendDestination_Bolt11CopyWith<$Res> implements $SendDestinationCopyWith<$Res> {
+  factory $SendDestination_Bolt11CopyWith(SendDestination_Bolt11 value, $Res Function(SendDestination_Bolt11) _then) = _$SendDestination_Bolt11CopyWithImpl;
+@override @useResult
+$Res call({
+ LNInvoice invoice, String? bip353Address
+});
+
+
+
+
+}
+/// @nodoc
+class _$SendDestination_Bolt11CopyWithImpl<$Res>
+    implements $SendDestination_Bolt11CopyWith<$Res> {
+  _$SendDestination_Bolt11CopyWithImpl(this._self, this._then);
+
+  final SendDestination_Bolt11 _self;
+  final $Res Function(SendDestination_Bolt11) _then;
+
+/// Create a copy of SendDestination
+/// with the given fields replaced by the non-null parameter values.
+@override @pragma('vm:prefer-inline') $Res call({Object? invoice = null,Object? bip353Address = freezed,}) {
+  return _then(SendDestination_Bolt11(
+invoice: null == invoice ? _self.invoice : invoice // ignore: cast_nullable_to_non_nullable
+as LNInvoice,bip353Address: freezed == bip353Address ? _self.bip353Address : bip353Address // ignore: cast_nullable_to_non_nullable
+as String?,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SendDestination_Bolt12 extends SendDestination {
+  const SendDestination_Bolt12({required this.offer, required this.receiverAmountSat, this.bip353Address}): super._();
+  
+
+ final  LNOffer offer;
+ final  BigInt receiverAmountSat;
 /// A BIP353 address, in case one was used to resolve this BOLT12
 @override final  String? bip353Address;
 
@@ -2391,6 +4073,168 @@ as String?,
 }
 
 
+}
+
+/// @nodoc
+mixin _$SpendLimit {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SpendLimit);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'SpendLimit()';
+}
+
+
+}
+
+/// @nodoc
+class $SpendLimitCopyWith<$Res>  {
+$SpendLimitCopyWith(SpendLimit _, $Res Function(SpendLimit) __);
+}
+
+
+/// @nodoc
+
+
+class SpendLimit_Bitcoin extends SpendLimit {
+  const SpendLimit_Bitcoin({required this.amountSat}): super._();
+  
+
+ final  BigInt amountSat;
+
+/// Create a copy of SpendLimit
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SpendLimit_BitcoinCopyWith<SpendLimit_Bitcoin> get copyWith => _$SpendLimit_BitcoinCopyWithImpl<SpendLimit_Bitcoin>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SpendLimit_Bitcoin&&(identical(other.amountSat, amountSat) || other.amountSat == amountSat));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,amountSat);
+
+@override
+String toString() {
+  return 'SpendLimit.bitcoin(amountSat: $amountSat)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SpendLimit_BitcoinCopyWith<$Res> implements $SpendLimitCopyWith<$Res> {
+  factory $SpendLimit_BitcoinCopyWith(SpendLimit_Bitcoin value, $Res Function(SpendLimit_Bitcoin) _then) = _$SpendLimit_BitcoinCopyWithImpl;
+@useResult
+$Res call({
+ BigInt amountSat
+});
+
+
+
+
+}
+/// @nodoc
+class _$SpendLimit_BitcoinCopyWithImpl<$Res>
+    implements $SpendLimit_BitcoinCopyWith<$Res> {
+  _$SpendLimit_BitcoinCopyWithImpl(this._self, this._then);
+
+  final SpendLimit_Bitcoin _self;
+  final $Res Function(SpendLimit_Bitcoin) _then;
+
+/// Create a copy of SpendLimit
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? amountSat = null,}) {
+  return _then(SpendLimit_Bitcoin(
+amountSat: null == amountSat ? _self.amountSat : amountSat // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class SpendLimit_Fiat extends SpendLimit {
+  const SpendLimit_Fiat({required this.amount}): super._();
+  
+
+ final  FiatAmount amount;
+
+/// Create a copy of SpendLimit
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$SpendLimit_FiatCopyWith<SpendLimit_Fiat> get copyWith => _$SpendLimit_FiatCopyWithImpl<SpendLimit_Fiat>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is SpendLimit_Fiat&&(identical(other.amount, amount) || other.amount == amount));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,amount);
+
+@override
+String toString() {
+  return 'SpendLimit.fiat(amount: $amount)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $SpendLimit_FiatCopyWith<$Res> implements $SpendLimitCopyWith<$Res> {
+  factory $SpendLimit_FiatCopyWith(SpendLimit_Fiat value, $Res Function(SpendLimit_Fiat) _then) = _$SpendLimit_FiatCopyWithImpl;
+@useResult
+$Res call({
+ FiatAmount amount
+});
+
+
+
+
+}
+/// @nodoc
+class _$SpendLimit_FiatCopyWithImpl<$Res>
+    implements $SpendLimit_FiatCopyWith<$Res> {
+  _$SpendLimit_FiatCopyWithImpl(this._self, this._then);
+
+  final SpendLimit_Fiat _self;
+  final $Res Function(SpendLimit_Fiat) _then;
+
+/// Create a copy of SpendLimit
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? amount = null,}) {
+  return _then(SpendLimit_Fiat(
+amount: null == amount ? _self.amount : amount // ignore: cast_nullable_to_non_nullable
+as FiatAmount,
+  ));
+}
+
+
 }
 
 // dart format on
@@ -1,16 +1,32 @@
 package com.breezsdkliquid
 
-import android.view.View
-import com.facebook.react.ReactPackage
+import com.facebook.react.TurboReactPackage
 import com.facebook.react.bridge.NativeModule
 import com.facebook.react.bridge.ReactApplicationContext
-import com.facebook.react.uimanager.ReactShadowNode
-import com.facebook.react.uimanager.ViewManager
+import com.facebook.react.module.model.ReactModuleInfo
+import com.facebook.react.module.model.ReactModuleInfoProvider
 
-class BreezSDKLiquidPackage : ReactPackage {
-    override fun createViewManagers(reactContext: ReactApplicationContext): MutableList<ViewManager<View, ReactShadowNode<*>>> =
-        mutableListOf()
+class BreezSDKLiquidPackage : TurboReactPackage() {
+    override fun getModule(name: String, reactContext: ReactApplicationContext): NativeModule? =
+        if (name == BreezSDKLiquidModule.TAG) {
+            BreezSDKLiquidModule(reactContext)
+        } else {
+            null
+        }
 
-    override fun createNativeModules(reactContext: ReactApplicationContext): MutableList<NativeModule> =
-        listOf(BreezSDKLiquidModule(reactContext)).toMutableList()
+    override fun getReactModuleInfoProvider(): ReactModuleInfoProvider =
+        ReactModuleInfoProvider {
+            mapOf(
+                BreezSDKLiquidModule.TAG to
+                    ReactModuleInfo(
+                        BreezSDKLiquidModule.TAG,
+                        BreezSDKLiquidModule.TAG,
+                        false, // canOverrideExistingModule
+                        false, // needsEagerInit
+                        true, // hasConstants
+                        false, // isCxxModule
+                        BuildConfig.IS_NEW_ARCHITECTURE_ENABLED, // isTurboModule
+                    ),
+            )
+        }
 }
@@ -0,0 +1,5 @@
+package com.breezsdkliquid
+
+import com.facebook.react.bridge.ReactApplicationContext
+
+abstract class BreezSDKLiquidSpec(reactContext: ReactApplicationContext) : NativeBreezSDKLiquidSpec(reactContext)